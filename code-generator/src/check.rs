@@ -0,0 +1,242 @@
+//! Compatibility check between two dictionary sets.
+//!
+//! This reports the added/removed/retyped attributes and values, and diffs the public items of the code
+//! that would be generated from each set, so that the dictionary upgrade which breaks the public API is noticed
+//! before regenerating.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::filter::AttributeFilter;
+use crate::{
+    extract_public_items, generate, parse_dict_file, OutputConfig, RadiusAttribute, RadiusValue,
+    MANIFEST_FILE_NAME,
+};
+
+const DICT_FILE_PREFIX: &str = "dictionary.";
+
+/// A writer that shares the buffer, to collect the generated code in memory.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+struct DictionarySet {
+    /// attribute name => (module name, attribute)
+    attributes: BTreeMap<String, (String, RadiusAttribute)>,
+    /// (attribute name, value name) => value
+    values: BTreeMap<(String, String), u16>,
+    /// (module name, item name) => signature
+    public_items: BTreeMap<(String, String), String>,
+}
+
+impl DictionarySet {
+    fn load(dict_file_paths: &[&Path]) -> Self {
+        let mut attributes = BTreeMap::new();
+        let mut values = BTreeMap::new();
+        for dict_file_path in dict_file_paths {
+            let ((radius_attributes, radius_attribute_to_values_map), _) =
+                parse_dict_file(dict_file_path).unwrap();
+            let rfc_name = dict_file_path.extension().unwrap().to_str().unwrap();
+
+            for attr in radius_attributes {
+                attributes.insert(attr.name.clone(), (rfc_name.to_owned(), attr));
+            }
+            for (attr_name, radius_values) in radius_attribute_to_values_map {
+                for RadiusValue { name, value } in radius_values {
+                    values.insert((attr_name.clone(), name), value);
+                }
+            }
+        }
+
+        let mut modules: BTreeMap<String, SharedBuffer> = BTreeMap::new();
+        generate(
+            dict_file_paths,
+            &OutputConfig::default(),
+            &AttributeFilter::default(),
+            &mut |file_name| {
+                let buffer = SharedBuffer::default();
+                if file_name != MANIFEST_FILE_NAME {
+                    let module_name = file_name.trim_end_matches(".rs");
+                    modules.insert(module_name.to_owned(), buffer.clone());
+                }
+                Box::new(buffer)
+            },
+        );
+        let public_items = modules
+            .into_iter()
+            .flat_map(|(module_name, buffer)| {
+                let code = String::from_utf8(buffer.0.take()).unwrap();
+                extract_public_items(&code)
+                    .into_iter()
+                    .map(move |(name, signature)| ((module_name.clone(), name), signature))
+            })
+            .collect();
+
+        DictionarySet {
+            attributes,
+            values,
+            public_items,
+        }
+    }
+}
+
+fn describe_attribute(attr: &RadiusAttribute) -> String {
+    let mut options = vec![];
+    if let Some(vendor) = &attr.vendor {
+        options.push(format!("vendor={}", vendor.name));
+    }
+    if let Some(length) = attr.fixed_octets_length {
+        options.push(format!("length={length}"));
+    }
+    if attr.concat_octets {
+        options.push("concat".to_owned());
+    }
+    if attr.has_tag {
+        options.push("has_tag".to_owned());
+    }
+    if let Some(max_occurrences) = attr.max_occurrences {
+        options.push(format!("max_occurs={max_occurrences}"));
+    }
+
+    let mut description = format!("type={} {:?}", attr.typ, attr.value_type);
+    if !options.is_empty() {
+        description += &format!(" [{}]", options.join(","));
+    }
+    description
+}
+
+fn list_dict_files(dicts_dir: &Path) -> Vec<PathBuf> {
+    let mut dict_file_paths = fs::read_dir(dicts_dir)
+        .unwrap_or_else(|e| panic!("failed to read the directory {}: {e}", dicts_dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(DICT_FILE_PREFIX))
+        })
+        .collect::<Vec<PathBuf>>();
+    dict_file_paths.sort();
+    dict_file_paths
+}
+
+/// Diff the dictionaries against the old ones in the directory and print the report.
+///
+/// It returns whether the public API of the generated code is compatible, i.e. no item is removed or changed.
+pub(crate) fn check(old_dicts_dir: &Path, new_dict_file_paths: &[&Path]) -> bool {
+    let old_dict_file_paths = list_dict_files(old_dicts_dir);
+    let old = DictionarySet::load(
+        &old_dict_file_paths
+            .iter()
+            .map(PathBuf::as_path)
+            .collect::<Vec<&Path>>(),
+    );
+    let new = DictionarySet::load(new_dict_file_paths);
+
+    println!("attributes:");
+    for (name, (rfc_name, attr)) in &new.attributes {
+        match old.attributes.get(name) {
+            None => {
+                // an attribute that has the same type in the same module is regarded as the renamed one
+                let renamed = old
+                    .attributes
+                    .iter()
+                    .find(|(old_name, (old_rfc_name, old_attr))| {
+                        old_rfc_name == rfc_name
+                            && old_attr.typ == attr.typ
+                            && !new.attributes.contains_key(*old_name)
+                    });
+                match renamed {
+                    Some((old_name, _)) => println!("  ~ {rfc_name} {old_name} renamed to {name}"),
+                    None => println!("  + {rfc_name} {name} ({})", describe_attribute(attr)),
+                }
+            }
+            Some((old_rfc_name, old_attr)) => {
+                if old_rfc_name != rfc_name {
+                    println!("  ~ {name} moved from {old_rfc_name} to {rfc_name}");
+                }
+                let (old_description, description) =
+                    (describe_attribute(old_attr), describe_attribute(attr));
+                if old_description != description {
+                    println!("  ~ {rfc_name} {name} retyped: {old_description} => {description}");
+                }
+            }
+        }
+    }
+    for (name, (rfc_name, attr)) in &old.attributes {
+        let is_renamed = new
+            .attributes
+            .iter()
+            .any(|(new_name, (new_rfc_name, new_attr))| {
+                new_rfc_name == rfc_name
+                    && new_attr.typ == attr.typ
+                    && !old.attributes.contains_key(new_name)
+            });
+        if !new.attributes.contains_key(name) && !is_renamed {
+            println!("  - {rfc_name} {name} ({})", describe_attribute(attr));
+        }
+    }
+
+    println!("values:");
+    for ((attr_name, name), value) in &new.values {
+        match old.values.get(&(attr_name.clone(), name.clone())) {
+            None => println!("  + {attr_name} {name} = {value}"),
+            Some(old_value) if old_value != value => {
+                println!("  ~ {attr_name} {name}: {old_value} => {value}")
+            }
+            _ => {}
+        }
+    }
+    for ((attr_name, name), value) in &old.values {
+        if !new.values.contains_key(&(attr_name.clone(), name.clone())) {
+            println!("  - {attr_name} {name} = {value}");
+        }
+    }
+
+    println!("public API:");
+    let mut is_compatible = true;
+    for ((module_name, name), signature) in &new.public_items {
+        match old.public_items.get(&(module_name.clone(), name.clone())) {
+            None => println!("  + {module_name}::{name}"),
+            Some(old_signature) if old_signature != signature => {
+                is_compatible = false;
+                println!("  ~ {module_name}::{name}");
+                println!("      old: {old_signature}");
+                println!("      new: {signature}");
+            }
+            _ => {}
+        }
+    }
+    for (module_name, name) in old.public_items.keys() {
+        if !new
+            .public_items
+            .contains_key(&(module_name.clone(), name.clone()))
+        {
+            is_compatible = false;
+            println!("  - {module_name}::{name}");
+        }
+    }
+
+    if is_compatible {
+        println!("result: compatible");
+    } else {
+        println!(
+            "result: BREAKING; regenerating the code changes or removes the public items above"
+        );
+    }
+    is_compatible
+}
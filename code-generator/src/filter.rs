@@ -0,0 +1,67 @@
+//! Filter of the attributes to generate.
+
+use std::fs;
+use std::path::Path;
+
+/// The filter of the attributes by the glob patterns (`*` and `?`), that are matched case-insensitively.
+///
+/// An attribute is generated when it matches any of the include patterns (or there is no include pattern)
+/// and doesn't match any of the exclude patterns.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AttributeFilter {
+    includes: Vec<String>,
+    excludes: Vec<String>,
+}
+
+impl AttributeFilter {
+    pub(crate) fn add_includes(&mut self, arg: &str) {
+        self.includes.extend(parse_patterns(arg));
+    }
+
+    pub(crate) fn add_excludes(&mut self, arg: &str) {
+        self.excludes.extend(parse_patterns(arg));
+    }
+
+    pub(crate) fn matches(&self, attr_name: &str) -> bool {
+        let matches_any = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| matches_glob(pattern.as_bytes(), attr_name.as_bytes()))
+        };
+        (self.includes.is_empty() || matches_any(&self.includes)) && !matches_any(&self.excludes)
+    }
+}
+
+/// Parse the argument, that is a path to the list file (a pattern per line, `#` for comments)
+/// or the comma separated patterns.
+fn parse_patterns(arg: &str) -> Vec<String> {
+    let path = Path::new(arg);
+    let patterns = if path.is_file() {
+        fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("failed to read the attribute list file {arg}: {e}"))
+            .lines()
+            .map(|line| line.split('#').next().unwrap().trim().to_owned())
+            .collect::<Vec<String>>()
+    } else {
+        arg.split(',')
+            .map(|pattern| pattern.trim().to_owned())
+            .collect()
+    };
+    patterns
+        .into_iter()
+        .filter(|pattern| !pattern.is_empty())
+        .collect()
+}
+
+fn matches_glob(pattern: &[u8], name: &[u8]) -> bool {
+    match (pattern.first(), name.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            matches_glob(&pattern[1..], name)
+                || (!name.is_empty() && matches_glob(pattern, &name[1..]))
+        }
+        (Some(b'?'), Some(_)) => matches_glob(&pattern[1..], &name[1..]),
+        (Some(p), Some(n)) if p.eq_ignore_ascii_case(n) => matches_glob(&pattern[1..], &name[1..]),
+        _ => false,
+    }
+}
@@ -1,7 +1,8 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
-use std::fs::File;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
 use std::io::{BufRead, BufWriter, Write};
 use std::path::Path;
+use std::process::{Command, Stdio};
 use std::str::FromStr;
 use std::{env, io, process};
 
@@ -9,8 +10,21 @@ use getopts::Options;
 use inflector::Inflector;
 use regex::Regex;
 
+use crate::filter::AttributeFilter;
+
+mod check;
+mod filter;
+
+const GENERATED_CODE_HEADER: &str = "// Code generated by machine generator; DO NOT EDIT.\n";
+const MANIFEST_FILE_NAME: &str = "generated.manifest";
+const DEFAULT_MODULE_PREFIX: &str = "crate::core";
+const DEFAULT_CORE_PATH: &str = "crate::core";
+
 const ATTRIBUTE_KIND: &str = "ATTRIBUTE";
 const VALUE_KIND: &str = "VALUE";
+const VENDOR_KIND: &str = "VENDOR";
+const BEGIN_VENDOR_KIND: &str = "BEGIN-VENDOR";
+const END_VENDOR_KIND: &str = "END-VENDOR";
 
 const RADIUS_VALUE_TYPE: &str = "u32";
 
@@ -18,6 +32,12 @@ const USER_PASSWORD_TYPE_OPT: &str = "encrypt=1";
 const TUNNEL_PASSWORD_TYPE_OPT: &str = "encrypt=2";
 const HAS_TAG_TYPE_OPT: &str = "has_tag";
 const CONCAT_TYPE_OPT: &str = "concat";
+const MAX_OCCURRENCES_TYPE_OPT_PREFIX: &str = "max_occurs=";
+const MAX_LENGTH_TYPE_OPT_PREFIX: &str = "max_length=";
+const GROUP_TYPE_OPT_PREFIX: &str = "group=";
+const FLAGS_TYPE_OPT: &str = "flags";
+const DURATION_TYPE_OPT: &str = "duration";
+const VENDOR_FORMAT_OPT_PREFIX: &str = "format=";
 
 #[derive(Debug)]
 enum EncryptionType {
@@ -25,14 +45,43 @@ enum EncryptionType {
     TunnelPassword,
 }
 
+/// The vendor of the vendor-specific attributes, i.e. `VENDOR` line.
+#[derive(Debug, Clone, PartialEq)]
+struct RadiusVendor {
+    name: String,
+    id: u32,
+    /// The width of the type field of the sub-attributes in octets (i.e. `t` of `format=t,l`).
+    type_length: usize,
+    /// The width of the length field of the sub-attributes in octets (i.e. `l` of `format=t,l`).
+    length_length: usize,
+    /// Whether the sub-attributes have the continuation octet (i.e. `c` of `format=t,l,c`).
+    continuation: bool,
+}
+
+impl RadiusVendor {
+    /// Returns the identifier of the constant of the vendor (e.g. `ERX_VENDOR`).
+    fn identifier(&self) -> String {
+        format!("{}_VENDOR", self.name.to_screaming_snake_case())
+    }
+}
+
 #[derive(Debug)]
 struct RadiusAttribute {
     name: String,
-    typ: u8,
+    typ: u32,
+    /// The vendor of the attribute if that is defined in `BEGIN-VENDOR` block; such an attribute is a sub-attribute of `Vendor-Specific`.
+    vendor: Option<RadiusVendor>,
     value_type: RadiusAttributeValueType,
     fixed_octets_length: Option<usize>,
     concat_octets: bool,
     has_tag: bool,
+    max_occurrences: Option<usize>,
+    max_length: Option<usize>,
+    group: Option<String>,
+    /// Whether the values of the integer attribute are the bits of a bitmask (i.e. `flags` option).
+    flags: bool,
+    /// Whether the values of the integer attribute are the seconds of a duration (i.e. `duration` option).
+    duration: bool,
 }
 
 #[derive(Debug)]
@@ -55,8 +104,14 @@ enum RadiusAttributeValueType {
     IfId,
     Date,
     Integer,
+    Signed,
     Short,
+    Ether,
+    ABinary,
+    ComboIp,
     VSA,
+    /// The data type that this generator doesn't implement (e.g. `tlv`); the value is handled as the raw octets.
+    Unsupported(String),
 }
 
 impl FromStr for RadiusAttributeValueType {
@@ -72,15 +127,122 @@ impl FromStr for RadiusAttributeValueType {
             "ifid" => Ok(RadiusAttributeValueType::IfId),
             "date" => Ok(RadiusAttributeValueType::Date),
             "integer" => Ok(RadiusAttributeValueType::Integer),
+            "signed" => Ok(RadiusAttributeValueType::Signed),
             "short" => Ok(RadiusAttributeValueType::Short),
+            "ether" => Ok(RadiusAttributeValueType::Ether),
+            "abinary" => Ok(RadiusAttributeValueType::ABinary),
+            "combo-ip" => Ok(RadiusAttributeValueType::ComboIp),
             "vsa" => Ok(RadiusAttributeValueType::VSA),
             _ => Err(()),
         }
     }
 }
 
+/// The layout of the generated files.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Layout {
+    /// All of the modules are in the output directory (e.g. `rfc2865.rs`, `registry.rs`).
+    Flat,
+    /// The modules are in `generated/rfc/` and `generated/vendor/` with the `mod.rs` aggregators.
+    Nested,
+}
+
+impl FromStr for Layout {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "flat" => Ok(Layout::Flat),
+            "nested" => Ok(Layout::Nested),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The configuration of where the generated modules are placed and what they refer to.
+#[derive(Debug, Clone)]
+struct OutputConfig {
+    layout: Layout,
+    /// The module path that has the generated modules (for `Layout::Flat`) or the `generated` module (for `Layout::Nested`).
+    module_prefix: String,
+    /// The module path of the `core` module of the radius crate.
+    core_path: String,
+    /// Whether each dictionary module is gated behind the cargo feature of the same name (e.g. `rfc2866`).
+    feature_gates: bool,
+    /// The modules that are always compiled even if `feature_gates` is enabled (e.g. `rfc2865`).
+    ungated_modules: BTreeSet<String>,
+}
+
+impl Default for OutputConfig {
+    fn default() -> Self {
+        OutputConfig {
+            layout: Layout::Flat,
+            module_prefix: DEFAULT_MODULE_PREFIX.to_owned(),
+            core_path: DEFAULT_CORE_PATH.to_owned(),
+            feature_gates: false,
+            ungated_modules: BTreeSet::new(),
+        }
+    }
+}
+
+impl OutputConfig {
+    /// Returns `rfc` for the RFC dictionaries, otherwise `vendor`.
+    fn module_group(module_name: &str) -> &'static str {
+        if module_name.starts_with("rfc") {
+            "rfc"
+        } else {
+            "vendor"
+        }
+    }
+
+    /// Returns the module path of the dictionary module (e.g. `crate::core::rfc2865`).
+    fn module_path(&self, module_name: &str) -> String {
+        match self.layout {
+            Layout::Flat => format!("{}::{module_name}", self.module_prefix),
+            Layout::Nested => format!(
+                "{}::generated::{}::{module_name}",
+                self.module_prefix,
+                Self::module_group(module_name)
+            ),
+        }
+    }
+
+    /// Returns the file name of the dictionary module that is relative to the output directory.
+    fn module_file_name(&self, module_name: &str) -> String {
+        match self.layout {
+            Layout::Flat => format!("{module_name}.rs"),
+            Layout::Nested => format!(
+                "generated/{}/{module_name}.rs",
+                Self::module_group(module_name)
+            ),
+        }
+    }
+
+    /// Returns whether the dictionary module is gated behind the cargo feature.
+    fn is_gated(&self, module_name: &str) -> bool {
+        self.feature_gates && !self.ungated_modules.contains(module_name)
+    }
+
+    /// Returns the `#[cfg(feature = "...")]` attribute (with the trailing newline and the indent)
+    /// to put before the item that refers to the dictionary module, or the empty string if that isn't gated.
+    fn cfg_attribute(&self, module_name: &str, indent: &str) -> String {
+        if self.is_gated(module_name) {
+            format!("#[cfg(feature = \"{module_name}\")]\n{indent}")
+        } else {
+            String::new()
+        }
+    }
+
+    /// Returns the file name of the module that isn't of a dictionary (e.g. `registry.rs`, `generated.manifest`).
+    fn file_name(&self, file_name: &str) -> String {
+        match self.layout {
+            Layout::Flat => file_name.to_owned(),
+            Layout::Nested => format!("generated/{file_name}"),
+        }
+    }
+}
+
 fn print_usage(program: &str, opts: &Options) {
-    let brief = format!("Usage: {program} [options] DICT_FILE OUT_FILE");
+    let brief = format!("Usage: {program} [options] DICT_FILE...");
     print!("{}", opts.usage(&brief));
     process::exit(0);
 }
@@ -105,18 +267,59 @@ fn main() {
         "[mandatory] a directory to out the generated code",
         "/path/to/out/",
     );
+    opts.optopt(
+        "",
+        "check",
+        "diff the given dictionaries against the old ones in the directory and report the compatibility of the generated API, instead of generating the code",
+        "/path/to/old/dicts/",
+    );
+    opts.optopt(
+        "",
+        "layout",
+        "the layout of the generated files; `flat` puts all of the modules into the out dir (default), and `nested` makes `generated/{rfc,vendor}/` tree with `mod.rs`",
+        "flat|nested",
+    );
+    opts.optopt(
+        "",
+        "module-prefix",
+        "the module path where the generated modules are placed (default: `crate::core`)",
+        "crate::path::to::module",
+    );
+    opts.optopt(
+        "",
+        "core-path",
+        "the module path of the `core` module of the radius crate; e.g. `radius::core` for the code generated outside of the crate (default: `crate::core`)",
+        "radius::core",
+    );
+    opts.optmulti(
+        "",
+        "include-attrs",
+        "generate only the attributes that match the glob patterns; comma separated patterns or a path to the list file",
+        "Framed-*,User-Name|/path/to/list",
+    );
+    opts.optmulti(
+        "",
+        "exclude-attrs",
+        "don't generate the attributes that match the glob patterns; comma separated patterns or a path to the list file",
+        "Ascend-*|/path/to/list",
+    );
+    opts.optflag(
+        "",
+        "feature-gates",
+        "gate each dictionary module behind the cargo feature of the same name (e.g. `#[cfg(feature = \"rfc2866\")]`)",
+    );
+    opts.optopt(
+        "",
+        "ungated-modules",
+        "the comma separated dictionary modules that are always compiled even with `--feature-gates`",
+        "rfc2865",
+    );
     let matches = opts.parse(&args[1..]).unwrap_or_else(|f| panic!("{}", f));
 
     if matches.opt_present("h") {
         print_usage(&program, &opts);
     }
 
-    let out_dir_str = match matches.opt_str("o") {
-        Some(o) => o,
-        None => panic!("mandatory parameter `-o` (`--out-dir`) is missing"),
-    };
-    let out_dir = Path::new(&out_dir_str);
-
     let mut dict_file_paths: Vec<&Path> = matches
         .free
         .iter()
@@ -128,46 +331,454 @@ fn main() {
             true
         })
         .collect();
-    dict_file_paths.sort();
+    // the RFC dictionaries come first since the vendor dictionaries may define the values of the RFC attributes
+    dict_file_paths.sort_by_key(|path| {
+        let module_name = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+        (OutputConfig::module_group(module_name) != "rfc", *path)
+    });
+
+    if let Some(old_dicts_dir) = matches.opt_str("check") {
+        let is_compatible = check::check(Path::new(&old_dicts_dir), &dict_file_paths);
+        process::exit(if is_compatible { 0 } else { 1 });
+    }
+
+    let out_dir_str = match matches.opt_str("o") {
+        Some(o) => o,
+        None => panic!("mandatory parameter `-o` (`--out-dir`) is missing"),
+    };
+    let out_dir = Path::new(&out_dir_str);
+
+    let mut output_config = OutputConfig::default();
+    if let Some(layout) = matches.opt_str("layout") {
+        output_config.layout = Layout::from_str(&layout)
+            .unwrap_or_else(|_| panic!("invalid layout has come => {layout}"));
+    }
+    if let Some(module_prefix) = matches.opt_str("module-prefix") {
+        output_config.module_prefix = module_prefix;
+    }
+    if let Some(core_path) = matches.opt_str("core-path") {
+        output_config.core_path = core_path;
+    }
+    output_config.feature_gates = matches.opt_present("feature-gates");
+    if let Some(ungated_modules) = matches.opt_str("ungated-modules") {
+        output_config.ungated_modules = ungated_modules
+            .split(',')
+            .map(|module_name| module_name.trim().to_owned())
+            .filter(|module_name| !module_name.is_empty())
+            .collect();
+    }
+
+    let mut attribute_filter = AttributeFilter::default();
+    for include in matches.opt_strs("include-attrs") {
+        attribute_filter.add_includes(&include);
+    }
+    for exclude in matches.opt_strs("exclude-attrs") {
+        attribute_filter.add_excludes(&exclude);
+    }
+
+    generate(
+        &dict_file_paths,
+        &output_config,
+        &attribute_filter,
+        &mut |file_name| {
+            let path = out_dir.join(file_name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            Box::new(BufWriter::new(File::create(path).unwrap()))
+        },
+    );
+}
 
+/// Generate the code of the dictionaries and the manifest of the generated items;
+/// `create_writer` gives the writer for each file (e.g. `rfc2865.rs`, `registry.rs`, `generated.manifest`).
+///
+/// The output is formatted by rustfmt and doesn't depend on the iteration order of hash maps,
+/// so the same dictionaries always produce the same code.
+fn generate(
+    dict_file_paths: &[&Path],
+    output_config: &OutputConfig,
+    attribute_filter: &AttributeFilter,
+    create_writer: &mut dyn FnMut(&str) -> Box<dyn Write>,
+) {
     let mut rfc_names: Vec<String> = Vec::new();
-    let mut attribute_name_to_rfc_name: HashMap<String, String> = HashMap::new();
+    let mut attribute_name_to_rfc_name: BTreeMap<String, String> = BTreeMap::new();
+    let mut registered_attributes: Vec<(String, String)> = Vec::new();
+    let mut manifest = GENERATED_CODE_HEADER.to_owned();
 
     for dict_file_path in dict_file_paths {
-        let ((radius_attributes, radius_attribute_to_values_map), dict_file_lines) =
+        let ((mut radius_attributes, mut radius_attribute_to_values_map), dict_file_lines) =
             parse_dict_file(dict_file_path).unwrap();
+        radius_attributes.retain(|attr| attribute_filter.matches(&attr.name));
+        radius_attribute_to_values_map.retain(|attr_name, _| attribute_filter.matches(attr_name));
 
         let value_defined_attributes_set = radius_attribute_to_values_map
             .keys()
-            .collect::<HashSet<&String>>();
+            .collect::<BTreeSet<&String>>();
 
         let rfc_name = dict_file_path.extension().unwrap().to_str().unwrap();
-        let mut w = BufWriter::new(File::create(out_dir.join(format!("{rfc_name}.rs"))).unwrap());
 
-        generate_header(&mut w, &rfc_names, rfc_name, dict_file_lines);
-        generate_attributes_code(&mut w, &radius_attributes, &value_defined_attributes_set);
+        let mut body: Vec<u8> = Vec::new();
+        generate_attributes_code(&mut body, &radius_attributes, &value_defined_attributes_set);
+        generate_groups_code(&mut body, &radius_attributes, &value_defined_attributes_set);
+        generate_flags_code(
+            &mut body,
+            &radius_attributes,
+            &radius_attribute_to_values_map,
+        );
+        generate_attribute_table_code(&mut body, &radius_attributes);
         generate_values_code(
-            &mut w,
+            &mut body,
+            &radius_attributes,
+            &radius_attribute_to_values_map,
+            &attribute_name_to_rfc_name,
+        );
+        generate_value_table_code(
+            &mut body,
+            &radius_attributes,
             &radius_attribute_to_values_map,
             &attribute_name_to_rfc_name,
         );
+        let body = String::from_utf8(body).unwrap();
+
+        let mut code: Vec<u8> = Vec::new();
+        generate_header(&mut code, rfc_name, dict_file_lines);
+        let required_modules = generate_imports(&mut code, output_config, &rfc_names, &body);
+        code.extend(body.as_bytes());
+
+        let file_name = output_config.module_file_name(rfc_name);
+        write_module(
+            create_writer,
+            &mut manifest,
+            &file_name,
+            dict_file_path.file_name().unwrap().to_str(),
+            &code,
+        );
+        if output_config.is_gated(rfc_name) {
+            // this line is the same format as `[features]` of Cargo.toml
+            let required_features = required_modules
+                .iter()
+                .filter(|module_name| output_config.is_gated(module_name))
+                .map(|module_name| format!("\"{module_name}\""))
+                .collect::<Vec<String>>();
+            manifest.push_str(&format!(
+                "    feature: {rfc_name} = [{}]\n",
+                required_features.join(", ")
+            ));
+        }
 
-        for attr in &radius_attributes {
+        // the vendor-specific attributes are not in the registry since those are not identified by `AVPType`
+        for attr in radius_attributes
+            .iter()
+            .filter(|attr| attr.vendor.is_none())
+        {
             attribute_name_to_rfc_name.insert(attr.name.clone(), rfc_name.to_owned());
+            registered_attributes.push((rfc_name.to_owned(), attr.name.clone()));
         }
         rfc_names.push(rfc_name.to_owned());
     }
+
+    let mut code: Vec<u8> = Vec::new();
+    generate_registry_code(&mut code, output_config, &rfc_names, &registered_attributes);
+    let registry_file_name = output_config.file_name("registry.rs");
+    write_module(
+        create_writer,
+        &mut manifest,
+        &registry_file_name,
+        None,
+        &code,
+    );
+
+    if output_config.layout == Layout::Nested {
+        generate_mod_files(create_writer, output_config, &rfc_names);
+    }
+
+    create_writer(&output_config.file_name(MANIFEST_FILE_NAME))
+        .write_all(manifest.as_bytes())
+        .unwrap();
 }
 
-fn generate_header(
-    w: &mut BufWriter<File>,
+/// Generate the `mod.rs` files that aggregate the modules of `Layout::Nested`.
+fn generate_mod_files(
+    create_writer: &mut dyn FnMut(&str) -> Box<dyn Write>,
+    output_config: &OutputConfig,
     rfc_names: &[String],
+) {
+    let mut groups: BTreeMap<&str, Vec<&String>> = BTreeMap::new();
+    for rfc_name in rfc_names {
+        groups
+            .entry(OutputConfig::module_group(rfc_name))
+            .or_default()
+            .push(rfc_name);
+    }
+
+    let mut mods = vec!["registry"];
+    for (group, module_names) in &groups {
+        let code = module_names
+            .iter()
+            .map(|module_name| {
+                format!(
+                    "{}pub mod {module_name};\n",
+                    output_config.cfg_attribute(module_name, "")
+                )
+            })
+            .collect::<String>();
+        create_writer(&format!("generated/{group}/mod.rs"))
+            .write_all(format!("{GENERATED_CODE_HEADER}\n{code}").as_bytes())
+            .unwrap();
+        mods.push(group);
+    }
+    mods.sort();
+
+    let code = mods
+        .iter()
+        .map(|module_name| format!("pub mod {module_name};\n"))
+        .collect::<String>();
+    create_writer("generated/mod.rs")
+        .write_all(format!("{GENERATED_CODE_HEADER}\n{code}").as_bytes())
+        .unwrap();
+}
+
+/// Format the code of the module and write that, and add the public items of that to the manifest.
+fn write_module(
+    create_writer: &mut dyn FnMut(&str) -> Box<dyn Write>,
+    manifest: &mut String,
+    file_name: &str,
+    dict_file_name: Option<&str>,
+    code: &[u8],
+) {
+    let code = format_code(code);
+    create_writer(file_name).write_all(code.as_bytes()).unwrap();
+
+    manifest.push('\n');
+    match dict_file_name {
+        Some(dict_file_name) => manifest.push_str(&format!("{file_name} <= {dict_file_name}\n")),
+        None => manifest.push_str(&format!("{file_name}\n")),
+    }
+    for (_, signature) in extract_public_items(&code) {
+        manifest.push_str(&format!("    {signature}\n"));
+    }
+}
+
+/// Format the code by rustfmt; the command can be overridden by `RUSTFMT` environment variable as well as cargo.
+fn format_code(code: &[u8]) -> String {
+    let rustfmt = env::var("RUSTFMT").unwrap_or_else(|_| "rustfmt".to_owned());
+    let mut child = Command::new(&rustfmt)
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| panic!("failed to run {rustfmt}: {e}"));
+    child.stdin.take().unwrap().write_all(code).unwrap();
+    let output = child.wait_with_output().unwrap();
+    if !output.status.success() {
+        panic!("{rustfmt} failed to format the generated code");
+    }
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Returns the public items of the generated code as the pairs of the name and the signature.
+///
+/// The values of the constants are not a part of the signature, and a signature that is wrapped by rustfmt
+/// is joined into a line.
+fn extract_public_items(code: &str) -> Vec<(String, String)> {
+    let mut items = Vec::new();
+    let mut lines = code.lines();
+    while let Some(line) = lines.next() {
+        let Some((kind, rest)) = [
+            "pub fn ",
+            "pub const fn ",
+            "pub const ",
+            "pub static ",
+            "pub struct ",
+            "pub enum ",
+            "pub type ",
+        ]
+        .iter()
+        .find_map(|kind| line.strip_prefix(kind).map(|rest| (*kind, rest))) else {
+            continue;
+        };
+        let name = rest
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .next()
+            .unwrap();
+
+        let signature = match kind {
+            "pub fn " | "pub const fn " => {
+                let mut signature = line.to_owned();
+                while !signature.ends_with('{') {
+                    match lines.next() {
+                        Some(line) => signature.push_str(line.trim()),
+                        None => break,
+                    }
+                }
+                signature
+                    .trim_end_matches('{')
+                    .trim_end()
+                    .replace(",)", ")")
+                    .replace(',', ", ")
+                    .replace(",  ", ", ")
+            }
+            "pub type " => line.trim_end_matches(';').to_owned(),
+            "pub struct " | "pub enum " => line.trim_end_matches('{').trim_end().to_owned(),
+            _ => line.split(" =").next().unwrap().to_owned(),
+        };
+        items.push((name.to_owned(), signature));
+    }
+    items
+}
+
+fn generate_registry_code(
+    w: &mut dyn Write,
+    output_config: &OutputConfig,
+    rfc_names: &[String],
+    registered_attributes: &[(String, String)],
+) {
+    let name_arms = registered_attributes
+        .iter()
+        .map(|(rfc_name, attr_name)| {
+            format!(
+                "        {cfg}{rfc_name}::{type_identifier_prefix}_TYPE => Some(\"{attr_name}\"),\n",
+                cfg = output_config.cfg_attribute(rfc_name, "        "),
+                type_identifier_prefix = attr_name.to_screaming_snake_case(),
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    let type_arms = registered_attributes
+        .iter()
+        .map(|(rfc_name, attr_name)| {
+            format!(
+                "        {cfg}b\"{attr_name}\" => Some({rfc_name}::{type_identifier_prefix}_TYPE),\n",
+                cfg = output_config.cfg_attribute(rfc_name, "        "),
+                type_identifier_prefix = attr_name.to_screaming_snake_case(),
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    let mut table_indices: BTreeMap<&str, usize> = BTreeMap::new();
+    let metadata_arms = registered_attributes
+        .iter()
+        .map(|(rfc_name, attr_name)| {
+            // the entries of `ATTRIBUTE_TABLE` are in the same order as the registered attributes of each module
+            let index = table_indices.entry(rfc_name).or_default();
+            let arm = format!(
+                "        {cfg}{rfc_name}::{type_identifier_prefix}_TYPE => Some(&{rfc_name}::ATTRIBUTE_TABLE[{index}]),\n",
+                cfg = output_config.cfg_attribute(rfc_name, "        "),
+                type_identifier_prefix = attr_name.to_screaming_snake_case(),
+            );
+            *index += 1;
+            arm
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    let tables = rfc_names
+        .iter()
+        .map(|rfc_name| {
+            format!(
+                "        {}{rfc_name}::ATTRIBUTE_TABLE,\n",
+                output_config.cfg_attribute(rfc_name, "        ")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    let value_tables = rfc_names
+        .iter()
+        .map(|rfc_name| {
+            format!(
+                "        {}{rfc_name}::VALUE_TABLE,\n",
+                output_config.cfg_attribute(rfc_name, "        ")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    let uses = rfc_names
+        .iter()
+        .map(|rfc_name| {
+            format!(
+                "{}use {};\n",
+                output_config.cfg_attribute(rfc_name, ""),
+                output_config.module_path(rfc_name)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    let core_path = &output_config.core_path;
+
+    let code = format!(
+        "{GENERATED_CODE_HEADER}
+//! Registry of the attributes across all of the dictionary modules.
+
+use {core_path}::avp::AVPType;
+use {core_path}::metadata::{{AttributeMetadata, ValueMetadata}};
+
+{uses}
+/// The metadata tables of all of the dictionary modules.
+pub const ATTRIBUTE_TABLES: &[&[AttributeMetadata]] = &[
+{tables}];
+
+/// The named value tables of all of the dictionary modules.
+pub const VALUE_TABLES: &[&[ValueMetadata]] = &[
+{value_tables}];
+
+/// Returns the name of the attribute (e.g. `User-Name`) for the given AVP type.
+///
+/// If the AVP type is not defined in any dictionary, it returns `None`.
+pub const fn attribute_name(typ: AVPType) -> Option<&'static str> {{
+    match typ {{
+{name_arms}        _ => None,
+    }}
+}}
+
+/// Returns the AVP type for the given attribute name (e.g. `User-Name`).
+///
+/// If the name is not defined in any dictionary, it returns `None`.
+pub const fn attribute_type(name: &str) -> Option<AVPType> {{
+    match name.as_bytes() {{
+{type_arms}        _ => None,
+    }}
+}}
+
+/// Returns the metadata of the attribute for the given AVP type.
+///
+/// If the AVP type is not defined in any dictionary, it returns `None`.
+pub const fn attribute_metadata(typ: AVPType) -> Option<&'static AttributeMetadata> {{
+    match typ {{
+{metadata_arms}        _ => None,
+    }}
+}}
+
+/// Returns the name of the value (e.g. `Framed-User` of `Service-Type`) for the given AVP type and value.
+///
+/// If the value is not named in any dictionary, it returns `None`.
+pub fn value_name(typ: AVPType, value: u32) -> Option<&'static str> {{
+    VALUE_TABLES
+        .iter()
+        .flat_map(|table| table.iter())
+        .find(|metadata| metadata.typ == typ && metadata.value == value)
+        .map(|metadata| metadata.name)
+}}
+
+/// Returns the value for the given AVP type and the name of the value (e.g. `Framed-User` of `Service-Type`).
+///
+/// If the value is not named in any dictionary, it returns `None`.
+pub fn value_by_name(typ: AVPType, name: &str) -> Option<u32> {{
+    VALUE_TABLES
+        .iter()
+        .flat_map(|table| table.iter())
+        .find(|metadata| metadata.typ == typ && metadata.name == name)
+        .map(|metadata| metadata.value)
+}}
+"
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
+fn generate_header(
+    w: &mut dyn Write,
     rfc_name: &str,
     dict_file_lines: io::Lines<io::BufReader<File>>,
 ) {
     let code = format!(
-        "// Code generated by machine generator; DO NOT EDIT.
-
+        "{GENERATED_CODE_HEADER}
 //! Utility for {rfc_name} packet.
 //!
 //! This module handles the packet according to the following definition:
@@ -175,14 +786,6 @@ fn generate_header(
 //! {dict_file_contents}
 //! ```
 
-use std::net::{{Ipv4Addr, Ipv6Addr}};
-
-use chrono::{{DateTime, Utc}};
-
-use crate::core::avp::{{AVP, AVPType, AVPError}};
-use crate::core::packet::Packet;
-use crate::core::tag::Tag;
-
 ",
         rfc_name = rfc_name,
         dict_file_contents = dict_file_lines
@@ -192,81 +795,514 @@ use crate::core::tag::Tag;
     );
 
     w.write_all(code.as_bytes()).unwrap();
+}
 
-    for rfc_name in rfc_names {
-        w.write_all(format!("use crate::core::{rfc_name};\n").as_bytes())
-            .unwrap();
+/// Generate only the imports that are used by the body, so that the generated code doesn't need `cargo fix`.
+///
+/// It returns the dictionary modules that the body refers to.
+fn generate_imports(
+    w: &mut dyn Write,
+    output_config: &OutputConfig,
+    rfc_names: &[String],
+    body: &str,
+) -> Vec<String> {
+    let core_path = &output_config.core_path;
+    // the identifier in a path (e.g. `AttributeDataType::IpAddr`) is not the imported one
+    let is_used = |identifier: &str| {
+        Regex::new(&format!(r"(?:^|[^:\w]){identifier}\b"))
+            .unwrap()
+            .is_match(body)
+    };
+    let use_items = |path: &str, identifiers: &[&str]| {
+        let used = identifiers
+            .iter()
+            .filter(|identifier| is_used(identifier))
+            .cloned()
+            .collect::<Vec<&str>>();
+        match used.len() {
+            0 => None,
+            1 => Some(format!("use {path}::{};\n", used[0])),
+            _ => Some(format!("use {path}::{{{}}};\n", used.join(", "))),
+        }
+    };
+
+    let required_modules = rfc_names
+        .iter()
+        .filter(|rfc_name| body.contains(&format!("{rfc_name}::")))
+        .cloned()
+        .collect::<Vec<String>>();
+
+    let groups = [
+        vec![
+            use_items("std::net", &["IpAddr", "Ipv4Addr", "Ipv6Addr"]),
+            use_items("std::time", &["Duration"]),
+        ],
+        vec![use_items("chrono", &["DateTime", "Utc"])],
+        vec![
+            use_items(
+                &format!("{core_path}::avp"),
+                &[
+                    "AVPError",
+                    "AVPType",
+                    "JumboAttributePolicy",
+                    "AVP",
+                    "MAX_VALUE_LENGTH",
+                ],
+            ),
+            use_items(
+                &format!("{core_path}::metadata"),
+                &["AttributeDataType", "AttributeMetadata", "ValueMetadata"],
+            ),
+            use_items(&format!("{core_path}::packet"), &["Packet"]),
+            use_items(&format!("{core_path}::tag"), &["Tag"]),
+            // `rfc2865` defines `VENDOR_SPECIFIC_TYPE` by itself, so these are imported only for the vendor-specific attributes
+            if body.contains("_vsa(&") {
+                use_items(
+                    &format!("{core_path}::vsa"),
+                    &[
+                        "Vendor",
+                        "VendorFormat",
+                        "VendorType",
+                        "VENDOR_SPECIFIC_TYPE",
+                    ],
+                )
+            } else {
+                None
+            },
+            use_items(&format!("{core_path}::bitflags"), &["bitflags"]),
+        ],
+        required_modules
+            .iter()
+            .map(|rfc_name| Some(format!("use {};\n", output_config.module_path(rfc_name))))
+            .collect(),
+    ];
+    for group in groups {
+        let imports = group.into_iter().flatten().collect::<String>();
+        if !imports.is_empty() {
+            w.write_all(format!("{imports}\n").as_bytes()).unwrap();
+        }
     }
+    required_modules
 }
 
 fn generate_values_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attrs: &[RadiusAttribute],
     attr_to_values_map: &BTreeMap<String, Vec<RadiusValue>>,
-    attr_name_to_rfc_name: &HashMap<String, String>,
+    attr_name_to_rfc_name: &BTreeMap<String, String>,
 ) {
     for (attr, values) in attr_to_values_map {
+        // the values of the flags attribute are the constants of the bitflags (see `generate_flags_code()`)
+        if attrs.iter().any(|a| &a.name == attr && a.flags) {
+            continue;
+        }
         generate_values_for_attribute_code(w, attr, values, attr_name_to_rfc_name.get(attr));
     }
 }
 
+/// Generate the bitflags type of each flags attribute (i.e. `flags` option), that has the values as the flags.
+fn generate_flags_code(
+    w: &mut dyn Write,
+    attrs: &[RadiusAttribute],
+    attr_to_values_map: &BTreeMap<String, Vec<RadiusValue>>,
+) {
+    for attr in attrs.iter().filter(|attr| attr.flags) {
+        if attr.value_type != RadiusAttributeValueType::Integer || attr.has_tag {
+            panic!(
+                "the flags attribute {} must be an untagged integer",
+                attr.name
+            );
+        }
+        if attr.vendor.is_some() {
+            panic!(
+                "the flags attribute {} must not be a vendor-specific attribute",
+                attr.name
+            );
+        }
+
+        let flags = attr_to_values_map
+            .get(&attr.name)
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|v| {
+                        format!(
+                            "        const {name} = {value};\n",
+                            name = v.name.to_screaming_snake_case(),
+                            value = v.value,
+                        )
+                    })
+                    .collect::<String>()
+            })
+            .unwrap_or_default();
+
+        let code = format!(
+            "
+bitflags! {{
+    /// The flags of `{attr_name}`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct {type_name}: {RADIUS_VALUE_TYPE} {{
+{flags}        // the bits that aren't defined in the dictionary are retained as they are
+        const _ = !0;
+    }}
+}}
+",
+            attr_name = attr.name,
+            type_name = attr.name.to_pascal_case(),
+        );
+        w.write_all(code.as_bytes()).unwrap();
+    }
+}
+
+fn generate_value_table_code(
+    w: &mut dyn Write,
+    attrs: &[RadiusAttribute],
+    attr_to_values_map: &BTreeMap<String, Vec<RadiusValue>>,
+    attr_name_to_rfc_name: &BTreeMap<String, String>,
+) {
+    let entries = attr_to_values_map
+        .iter()
+        .filter_map(|(attr, values)| {
+            // the values of an attribute that isn't defined in any module have no AVP type to refer to
+            let type_identifier = match attr_name_to_rfc_name.get(attr) {
+                Some(rfc_name) => format!("{rfc_name}::{}_TYPE", attr.to_screaming_snake_case()),
+                None if attrs.iter().any(|a| &a.name == attr && a.vendor.is_none()) => {
+                    format!("{}_TYPE", attr.to_screaming_snake_case())
+                }
+                None => return None,
+            };
+            Some(
+                values
+                    .iter()
+                    .map(|v| {
+                        format!(
+                            "    ValueMetadata {{
+        typ: {type_identifier},
+        name: \"{name}\",
+        value: {value},
+    }},
+",
+                            name = v.name,
+                            value = v.value,
+                        )
+                    })
+                    .collect::<String>(),
+            )
+        })
+        .collect::<String>();
+
+    let code = format!(
+        "
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+{entries}];
+"
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
 fn generate_values_for_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
     attr: &str,
     values: &[RadiusValue],
     maybe_rfc_name: Option<&String>,
 ) {
     let type_name = attr.to_pascal_case();
+    let type_name_prefix = type_name.to_screaming_snake_case();
 
-    if maybe_rfc_name.is_none() {
-        w.write_all(format!("\npub type {type_name} = {RADIUS_VALUE_TYPE};\n").as_bytes())
-            .unwrap();
-    }
-
-    for v in values {
-        if let Some(rfc_name) = maybe_rfc_name {
-            w.write_all(
-                format!(
-                "pub const {type_name_prefix}_{value_name}: {rfc_name}::{type_name} = {value};\n",
-                type_name_prefix = type_name.to_screaming_snake_case(),
-                value_name = v.name.to_screaming_snake_case(),
-                rfc_name = rfc_name,
-                type_name = type_name,
-                value = v.value,
-            )
-                .as_bytes(),
-            )
-            .unwrap()
-        } else {
+    // the values that are added to an attribute of another module are the constants of the type of that module,
+    // which are `Unknown` for the type unless the module defines those values too
+    if let Some(rfc_name) = maybe_rfc_name {
+        for v in values {
             w.write_all(
                 format!(
-                    "pub const {type_name_prefix}_{value_name}: {type_name} = {value};\n",
-                    type_name_prefix = type_name.to_screaming_snake_case(),
+                    "pub const {type_name_prefix}_{value_name}: {rfc_name}::{type_name} = {rfc_name}::{type_name}::from_u32({value});\n",
                     value_name = v.name.to_screaming_snake_case(),
-                    type_name = type_name,
                     value = v.value,
                 )
                 .as_bytes(),
             )
             .unwrap();
         }
+        w.write_all(b"\n").unwrap();
+        return;
+    }
+
+    // the first name of a value is the variant, and the other names of the same value are the aliases of that
+    let mut variants: Vec<(String, u16)> = Vec::new();
+    for v in values {
+        if !variants.iter().any(|(_, value)| *value == v.value) {
+            let variant = v.name.to_pascal_case();
+            if variant == "Unknown" || variants.iter().any(|(name, _)| *name == variant) {
+                panic!(
+                    "the value {} of {attr} conflicts with another variant",
+                    v.name
+                );
+            }
+            variants.push((variant, v.value));
+        }
+    }
+    let variant_of = |value: u16| &variants.iter().find(|(_, v)| *v == value).unwrap().0;
+
+    let variant_lines = variants
+        .iter()
+        .map(|(variant, _)| format!("    {variant},\n"))
+        .collect::<String>();
+    let from_arms = variants
+        .iter()
+        .map(|(variant, value)| format!("            {value} => {type_name}::{variant},\n"))
+        .collect::<String>();
+    let to_arms = variants
+        .iter()
+        .map(|(variant, value)| format!("            {type_name}::{variant} => {value},\n"))
+        .collect::<String>();
+    let name_arms = values
+        .iter()
+        .map(|v| {
+            format!(
+                "            \"{name}\" => Ok({type_name}::{variant}),\n",
+                name = v.name,
+                variant = variant_of(v.value),
+            )
+        })
+        .collect::<String>();
+    let code = format!(
+        "
+/// The values of `{attr}`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum {type_name} {{
+{variant_lines}    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown({RADIUS_VALUE_TYPE}),
+}}
+impl {type_name} {{
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: {RADIUS_VALUE_TYPE}) -> Self {{
+        match value {{
+{from_arms}            value => {type_name}::Unknown(value),
+        }}
+    }}
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> {RADIUS_VALUE_TYPE} {{
+        match self {{
+{to_arms}            {type_name}::Unknown(value) => value,
+        }}
+    }}
+}}
+impl From<{RADIUS_VALUE_TYPE}> for {type_name} {{
+    fn from(value: {RADIUS_VALUE_TYPE}) -> Self {{
+        {type_name}::from_u32(value)
+    }}
+}}
+impl From<{type_name}> for {RADIUS_VALUE_TYPE} {{
+    fn from(value: {type_name}) -> Self {{
+        value.to_u32()
+    }}
+}}
+impl TryFrom<&str> for {type_name} {{
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `{example_name}`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {{
+        match name {{
+{name_arms}            _ => Err(AVPError::DecodingError(format!(\"unknown value name of `{attr}`: {{name}}\"))),
+        }}
+    }}
+}}
+",
+        example_name = values[0].name,
+    );
+    w.write_all(code.as_bytes()).unwrap();
+
+    for v in values {
+        w.write_all(
+            format!(
+                "pub const {type_name_prefix}_{value_name}: {type_name} = {type_name}::{variant};\n",
+                value_name = v.name.to_screaming_snake_case(),
+                variant = variant_of(v.value),
+            )
+            .as_bytes(),
+        )
+        .unwrap();
     }
     w.write_all(b"\n").unwrap();
 }
 
 fn generate_attributes_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
     attrs: &[RadiusAttribute],
-    value_defined_attributes_set: &HashSet<&String>,
+    value_defined_attributes_set: &BTreeSet<&String>,
 ) {
+    let mut vendors: Vec<&RadiusVendor> = Vec::new();
+    for vendor in attrs.iter().filter_map(|attr| attr.vendor.as_ref()) {
+        if !vendors.contains(&vendor) {
+            vendors.push(vendor);
+        }
+    }
+    for vendor in vendors {
+        let format = match (
+            vendor.type_length,
+            vendor.length_length,
+            vendor.continuation,
+        ) {
+            (1, 1, false) => "VendorFormat::DEFAULT".to_owned(),
+            (t, l, false) => format!("VendorFormat::new({t}, {l})"),
+            (t, l, true) => format!("VendorFormat::new_with_continuation({t}, {l})"),
+        };
+        let code = format!(
+            "
+/// The vendor of the `{name}` attributes, that are the sub-attributes of `Vendor-Specific`.
+pub const {identifier}: Vendor = Vendor::new({id}, {format});
+",
+            name = vendor.name,
+            identifier = vendor.identifier(),
+            id = vendor.id,
+        );
+        w.write_all(code.as_bytes()).unwrap();
+    }
+
+    for attr in attrs {
+        match &attr.vendor {
+            None => generate_attribute_code(w, attr, value_defined_attributes_set),
+            Some(vendor) => {
+                let mut code: Vec<u8> = Vec::new();
+                generate_attribute_code(&mut code, attr, value_defined_attributes_set);
+                let code = vendorize_attribute_code(
+                    &String::from_utf8(code).unwrap(),
+                    vendor,
+                    &format!("{}_TYPE", attr.name.to_screaming_snake_case()),
+                );
+                w.write_all(code.as_bytes()).unwrap();
+            }
+        }
+    }
+}
+
+/// Rewrite the code of the attribute into that of the vendor-specific attribute, i.e. the accessors handle
+/// the sub-attribute of `Vendor-Specific` by the VSA accessors of `Packet` (e.g. `packet.add_vsa()`).
+///
+/// The value of the sub-attribute is made by the constructor of `AVP` as well as the attribute,
+/// and the AVP type of that is just a placeholder.
+fn vendorize_attribute_code(code: &str, vendor: &RadiusVendor, type_identifier: &str) -> String {
+    let vendor_identifier = vendor.identifier();
+    let mut code = code.replace(
+        &format!("pub const {type_identifier}: AVPType"),
+        &format!("pub const {type_identifier}: VendorType"),
+    );
+    code = Regex::new(&format!(r"(AVP::\w+)\({type_identifier},"))
+        .unwrap()
+        .replace_all(&code, "${1}(VENDOR_SPECIFIC_TYPE,")
+        .into_owned();
+    code = code.replace(
+        "packet.add(",
+        &format!("packet.add_vsa(&{vendor_identifier}, {type_identifier}, "),
+    );
+    code = code.replace(
+        "MAX_VALUE_LENGTH",
+        &format!("{vendor_identifier}.max_value_length()"),
+    );
+    for method in ["delete", "lookup_all", "lookup", "contains", "count"] {
+        code = code.replace(
+            &format!("packet.{method}({type_identifier})"),
+            &format!("packet.{method}_vsa(&{vendor_identifier}, {type_identifier})"),
+        );
+    }
+    code
+}
+
+/// Generate the accessor of each group of the tagged attributes (i.e. `group=` option),
+/// that looks up the values of the attributes which share the same tag at once.
+fn generate_groups_code(
+    w: &mut dyn Write,
+    attrs: &[RadiusAttribute],
+    value_defined_attributes_set: &BTreeSet<&String>,
+) {
+    let mut groups: BTreeMap<&str, Vec<&RadiusAttribute>> = BTreeMap::new();
     for attr in attrs {
-        generate_attribute_code(w, attr, value_defined_attributes_set);
+        if let Some(group) = &attr.group {
+            groups.entry(group).or_default().push(attr);
+        }
+    }
+
+    for (group, members) in groups {
+        let mut fields = Vec::new();
+        let mut lookups = Vec::new();
+        for attr in members {
+            if !attr.has_tag {
+                panic!("the grouped attribute {} must have has_tag", attr.name);
+            }
+            let method_identifier = attr.name.to_snake_case();
+            let (value_type, tag_matcher) = match attr.value_type {
+                RadiusAttributeValueType::Integer
+                    if value_defined_attributes_set.contains(&attr.name) =>
+                {
+                    (attr.name.to_pascal_case(), "t == tag")
+                }
+                RadiusAttributeValueType::Integer => ("u32".to_owned(), "t == tag"),
+                RadiusAttributeValueType::TunnelPassword => ("Vec<u8>".to_owned(), "t == tag"),
+                RadiusAttributeValueType::IpAddr => ("Ipv4Addr".to_owned(), "t == tag"),
+                RadiusAttributeValueType::Date => ("DateTime<Utc>".to_owned(), "t == tag"),
+                RadiusAttributeValueType::Short => ("u16".to_owned(), "t == tag"),
+                // an untagged string is regarded as the zero tag
+                RadiusAttributeValueType::String => (
+                    "String".to_owned(),
+                    "t.as_ref().map_or(tag.is_zero(), |t| t == tag)",
+                ),
+                RadiusAttributeValueType::Octets
+                    if attr.fixed_octets_length.is_none() && !attr.concat_octets =>
+                {
+                    (
+                        "Vec<u8>".to_owned(),
+                        "t.as_ref().map_or(tag.is_zero(), |t| t == tag)",
+                    )
+                }
+                _ => {
+                    eprintln!(
+                        "warning: the data type `{:?}` of {} is not supported in a group; it is skipped from `{}`",
+                        attr.value_type, attr.name, group
+                    );
+                    continue;
+                }
+            };
+            fields.push(format!(
+                "    /// The value of `{attr_name}`.\n    pub {method_identifier}: Option<{value_type}>,\n",
+                attr_name = attr.name,
+            ));
+            lookups.push(format!(
+                "        {method_identifier}: lookup_all_{method_identifier}(packet)?
+            .into_iter()
+            .find(|(_, t)| {tag_matcher})
+            .map(|(v, _)| v),\n"
+            ));
+        }
+        if fields.is_empty() {
+            continue;
+        }
+
+        let code = format!(
+            "/// This struct represents the `{group}` attributes that share the same tag.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct {struct_name} {{
+{fields}}}
+/// Lookup the `{group}` attributes that have the given tag from a packet.
+///
+/// Each field has the first looked up value of the attribute with the tag; it is `None` if there is no such attribute.
+pub fn {group}_group(packet: &Packet, tag: &Tag) -> Result<{struct_name}, AVPError> {{
+    Ok({struct_name} {{
+{lookups}    }})
+}}
+",
+            struct_name = format!("{}Group", group.to_pascal_case()),
+            fields = fields.concat(),
+            lookups = lookups.concat(),
+        );
+        w.write_all(code.as_bytes()).unwrap();
     }
 }
 
 fn generate_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
     attr: &RadiusAttribute,
-    value_defined_attributes_set: &HashSet<&String>,
+    value_defined_attributes_set: &BTreeSet<&String>,
 ) {
     let attr_name = attr.name.clone();
     let type_identifier = format!("{}_TYPE", attr_name.to_screaming_snake_case());
@@ -274,23 +1310,47 @@ fn generate_attribute_code(
     let method_identifier = attr_name.to_snake_case();
 
     generate_common_attribute_code(w, &attr_name, &type_identifier, type_value);
+    if let Some(data_type) = unsupported_data_type(attr) {
+        generate_unsupported_attribute_code(w, &method_identifier, &type_identifier, &data_type);
+        return;
+    }
     match attr.value_type {
         RadiusAttributeValueType::String => match attr.has_tag {
-            true => generate_tagged_string_attribute_code(w, &method_identifier, &type_identifier),
-            false => generate_string_attribute_code(w, &method_identifier, &type_identifier),
+            true => generate_tagged_string_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
+            false => {
+                generate_string_attribute_code(w, &attr_name, &method_identifier, &type_identifier)
+            }
         },
         RadiusAttributeValueType::UserPassword => match attr.has_tag {
-            true => unimplemented!("tagged-user-password"),
-            false => generate_user_password_attribute_code(w, &method_identifier, &type_identifier),
+            true => unreachable!(),
+            false => generate_user_password_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
         },
         RadiusAttributeValueType::TunnelPassword => match attr.has_tag {
-            true => {
-                generate_tunnel_password_attribute_code(w, &method_identifier, &type_identifier)
-            }
-            false => unimplemented!("tunnel-password"),
+            true => generate_tunnel_password_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
+            false => unreachable!(),
         },
         RadiusAttributeValueType::Octets => match attr.has_tag {
-            true => unimplemented!("tagged-octets"),
+            true => generate_tagged_octets_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
             false => match attr.fixed_octets_length {
                 Some(fixed_octets_length) => generate_fixed_length_octets_attribute_code(
                     w,
@@ -301,8 +1361,12 @@ fn generate_attribute_code(
                 None => match attr.concat_octets {
                     true => generate_concat_octets_attribute_code(
                         w,
+                        &attr_name,
                         &method_identifier,
                         &type_identifier,
+                        attr.max_length.unwrap_or_else(|| {
+                            panic!("the concat attribute {attr_name} must have max_length")
+                        }),
                     ),
                     false => {
                         generate_octets_attribute_code(w, &method_identifier, &type_identifier)
@@ -311,23 +1375,45 @@ fn generate_attribute_code(
             },
         },
         RadiusAttributeValueType::IpAddr => match attr.has_tag {
-            true => unimplemented!("tagged-ip-addr"),
-            false => generate_ipaddr_attribute_code(w, &method_identifier, &type_identifier),
+            true => generate_tagged_ipaddr_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
+            false => {
+                generate_ipaddr_attribute_code(w, &attr_name, &method_identifier, &type_identifier)
+            }
         },
         RadiusAttributeValueType::Ipv4Prefix => match attr.has_tag {
-            true => unimplemented!("tagged-ip-addr"),
-            false => generate_ipv4_prefix_attribute_code(w, &method_identifier, &type_identifier),
+            true => unreachable!(),
+            false => generate_ipv4_prefix_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
         },
         RadiusAttributeValueType::Ipv6Addr => match attr.has_tag {
-            true => unimplemented!("tagged-ip-v6-addr"),
-            false => generate_ipv6addr_attribute_code(w, &method_identifier, &type_identifier),
+            true => unreachable!(),
+            false => generate_ipv6addr_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
         },
         RadiusAttributeValueType::Ipv6Prefix => match attr.has_tag {
-            true => unimplemented!("tagged-ipv6-prefix"),
-            false => generate_ipv6_prefix_attribute_code(w, &method_identifier, &type_identifier),
+            true => unreachable!(),
+            false => generate_ipv6_prefix_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
         },
         RadiusAttributeValueType::IfId => match attr.has_tag {
-            true => unimplemented!("tagged-ifid"),
+            true => unreachable!(),
             false => generate_fixed_length_octets_attribute_code(
                 w,
                 &method_identifier,
@@ -336,20 +1422,36 @@ fn generate_attribute_code(
             ),
         },
         RadiusAttributeValueType::Date => match attr.has_tag {
-            true => unimplemented!("tagged-date"),
-            false => generate_date_attribute_code(w, &method_identifier, &type_identifier),
+            true => generate_tagged_date_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
+            false => {
+                generate_date_attribute_code(w, &attr_name, &method_identifier, &type_identifier)
+            }
         },
+        RadiusAttributeValueType::Integer if attr.flags => generate_flags_integer_attribute_code(
+            w,
+            &attr_name,
+            &method_identifier,
+            &type_identifier,
+            &attr_name.to_pascal_case(),
+        ),
         RadiusAttributeValueType::Integer => {
             match value_defined_attributes_set.contains(&attr_name) {
                 true => match attr.has_tag {
                     true => generate_tagged_value_defined_integer_attribute_code(
                         w,
+                        &attr_name,
                         &method_identifier,
                         &type_identifier,
                         &attr_name.to_pascal_case(),
                     ),
                     false => generate_value_defined_integer_attribute_code(
                         w,
+                        &attr_name,
                         &method_identifier,
                         &type_identifier,
                         &attr_name.to_pascal_case(),
@@ -358,28 +1460,169 @@ fn generate_attribute_code(
                 false => match attr.has_tag {
                     true => generate_tagged_integer_attribute_code(
                         w,
+                        &attr_name,
                         &method_identifier,
                         &type_identifier,
                     ),
                     false => {
-                        generate_integer_attribute_code(w, &method_identifier, &type_identifier)
+                        generate_integer_attribute_code(
+                            w,
+                            &attr_name,
+                            &method_identifier,
+                            &type_identifier,
+                        );
+                        if attr.duration {
+                            generate_duration_attribute_code(
+                                w,
+                                &attr_name,
+                                &method_identifier,
+                                &type_identifier,
+                            );
+                        }
                     }
                 },
             }
         }
+        RadiusAttributeValueType::Signed => {
+            generate_signed_attribute_code(w, &attr_name, &method_identifier, &type_identifier)
+        }
         RadiusAttributeValueType::Short => match attr.has_tag {
-            true => unimplemented!("tagged-short"),
-            false => generate_short_attribute_code(w, &method_identifier, &type_identifier),
+            true => generate_tagged_short_attribute_code(
+                w,
+                &attr_name,
+                &method_identifier,
+                &type_identifier,
+            ),
+            false => {
+                generate_short_attribute_code(w, &attr_name, &method_identifier, &type_identifier)
+            }
         },
+        RadiusAttributeValueType::Ether => {
+            generate_ether_attribute_code(w, &attr_name, &method_identifier, &type_identifier)
+        }
+        RadiusAttributeValueType::ABinary => {
+            generate_abinary_attribute_code(w, &method_identifier, &type_identifier)
+        }
+        RadiusAttributeValueType::ComboIp => {
+            generate_combo_ip_attribute_code(w, &attr_name, &method_identifier, &type_identifier)
+        }
         RadiusAttributeValueType::VSA => generate_vsa_attribute_code(),
+        RadiusAttributeValueType::Unsupported(_) => unreachable!(),
     }
 }
 
+/// Returns the name of the data type if the attribute cannot be handled by the typed accessors,
+/// i.e. the data type is unknown or the combination of the data type and the options is not implemented (e.g. tagged octets).
+fn unsupported_data_type(attr: &RadiusAttribute) -> Option<String> {
+    let data_type = match (&attr.value_type, attr.has_tag) {
+        (RadiusAttributeValueType::Unsupported(data_type), _) => return Some(data_type.clone()),
+        (RadiusAttributeValueType::UserPassword, true) => "tagged user-password",
+        (RadiusAttributeValueType::TunnelPassword, false) => "untagged tunnel-password",
+        (RadiusAttributeValueType::Octets, true)
+            if attr.fixed_octets_length.is_some() || attr.concat_octets =>
+        {
+            "tagged octets"
+        }
+        (RadiusAttributeValueType::Ipv4Prefix, true) => "tagged ipv4prefix",
+        (RadiusAttributeValueType::Ipv6Addr, true) => "tagged ipv6addr",
+        (RadiusAttributeValueType::Ipv6Prefix, true) => "tagged ipv6prefix",
+        (RadiusAttributeValueType::IfId, true) => "tagged ifid",
+        (RadiusAttributeValueType::Signed, true) => "tagged signed",
+        (RadiusAttributeValueType::Ether, true) => "tagged ether",
+        (RadiusAttributeValueType::ABinary, true) => "tagged abinary",
+        (RadiusAttributeValueType::ComboIp, true) => "tagged combo-ip",
+        (RadiusAttributeValueType::Octets, _) if attr.concat_octets && attr.vendor.is_some() => {
+            "vendor-specific concat octets"
+        }
+        _ => return None,
+    };
+    Some(data_type.to_owned())
+}
+
+fn generate_attribute_table_code(w: &mut dyn Write, attrs: &[RadiusAttribute]) {
+    let entries = attrs
+        .iter()
+        .filter(|attr| attr.vendor.is_none())
+        .map(|attr| {
+            let (data_type, encrypted, max_length) = match attr.value_type {
+                _ if unsupported_data_type(attr).is_some() => ("Unsupported", false, Some(253)),
+                RadiusAttributeValueType::String => ("String", false, Some(253)),
+                RadiusAttributeValueType::UserPassword => ("String", true, Some(128)),
+                RadiusAttributeValueType::TunnelPassword => ("String", true, Some(253)),
+                RadiusAttributeValueType::Octets => (
+                    "Octets",
+                    false,
+                    match (attr.fixed_octets_length, attr.concat_octets) {
+                        (Some(fixed_octets_length), _) => Some(fixed_octets_length),
+                        (None, true) => attr.max_length,
+                        (None, false) => Some(253),
+                    },
+                ),
+                RadiusAttributeValueType::IpAddr => {
+                    ("IpAddr", false, Some(if attr.has_tag { 5 } else { 4 }))
+                }
+                RadiusAttributeValueType::Ipv4Prefix => ("Ipv4Prefix", false, Some(6)),
+                RadiusAttributeValueType::Ipv6Addr => ("Ipv6Addr", false, Some(16)),
+                RadiusAttributeValueType::Ipv6Prefix => ("Ipv6Prefix", false, Some(18)),
+                RadiusAttributeValueType::IfId => ("IfId", false, Some(8)),
+                RadiusAttributeValueType::Date => {
+                    ("Date", false, Some(if attr.has_tag { 5 } else { 4 }))
+                }
+                RadiusAttributeValueType::Integer => {
+                    ("Integer", false, Some(if attr.has_tag { 5 } else { 4 }))
+                }
+                RadiusAttributeValueType::Signed => ("Signed", false, Some(4)),
+                RadiusAttributeValueType::Short => {
+                    ("Short", false, Some(if attr.has_tag { 3 } else { 2 }))
+                }
+                RadiusAttributeValueType::Ether => ("Ether", false, Some(6)),
+                RadiusAttributeValueType::ABinary => ("ABinary", false, Some(253)),
+                RadiusAttributeValueType::ComboIp => ("ComboIp", false, Some(16)),
+                RadiusAttributeValueType::VSA => ("VSA", false, Some(253)),
+                RadiusAttributeValueType::Unsupported(_) => unreachable!(),
+            };
+            let option_to_code = |v: Option<usize>| match v {
+                Some(v) => format!("Some({v})"),
+                None => "None".to_owned(),
+            };
+
+            format!(
+                "    AttributeMetadata {{
+        name: \"{name}\",
+        typ: {type_identifier_prefix}_TYPE,
+        data_type: AttributeDataType::{data_type},
+        encrypted: {encrypted},
+        has_tag: {has_tag},
+        max_length: {max_length},
+        max_occurrences: {max_occurrences},
+    }},
+",
+                name = attr.name,
+                type_identifier_prefix = attr.name.to_screaming_snake_case(),
+                has_tag = attr.has_tag,
+                max_length = option_to_code(max_length),
+                max_occurrences = option_to_code(attr.max_occurrences),
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let code = format!(
+        "
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+{entries}];
+
+",
+        entries = entries.join(""),
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
 fn generate_common_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
     attr_name: &str,
     type_identifier: &str,
-    type_value: u8,
+    type_value: u32,
 ) {
     let code = format!(
         "
@@ -388,6 +1631,14 @@ pub const {type_identifier}: AVPType = {type_value};
 pub fn delete_{method_identifier}(packet: &mut Packet) {{
     packet.delete({type_identifier});
 }}
+/// Returns whether a packet has `{method_identifier}` without decoding the value.
+pub fn has_{method_identifier}(packet: &Packet) -> bool {{
+    packet.contains({type_identifier})
+}}
+/// Returns the number of `{method_identifier}` attributes in a packet.
+pub fn count_{method_identifier}(packet: &Packet) -> usize {{
+    packet.count({type_identifier})
+}}
 ",
         method_identifier = attr_name.to_snake_case(),
         type_identifier = type_identifier,
@@ -397,26 +1648,28 @@ pub fn delete_{method_identifier}(packet: &mut Packet) {{
 }
 
 fn generate_string_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
     let code = format!(
         "/// Add `{method_identifier}` string value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, value: &str) {{
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<str>) {{
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
     packet.add(AVP::from_string({type_identifier}, value));
 }}
 /// Lookup a `{method_identifier}` string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<String, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_string())
+    packet.lookup({type_identifier}).map(|v| v.decode_string().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` string value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<String>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_string()?)
+        vec.push(avp.decode_string().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -426,26 +1679,28 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<String>, AV
 }
 
 fn generate_tagged_string_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
     let code = format!(
         "/// Add `{method_identifier}` tagged string value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: &str) {{
+pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {{
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH - usize::from(tag.is_some()));
     packet.add(AVP::from_tagged_string({type_identifier}, tag, value));
 }}
 /// Lookup a `{method_identifier}` tagged string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<(String, Option<Tag>), AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_tagged_string())
+    packet.lookup({type_identifier}).map(|v| v.decode_tagged_string().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` tagged string value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(String, Option<Tag>)>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_tagged_string()?)
+        vec.push(avp.decode_tagged_string().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -455,13 +1710,15 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(String, Op
 }
 
 fn generate_user_password_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
     let code = format!(
         "/// Add `{method_identifier}` user-password value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {{
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
     packet.add(AVP::from_user_password({type_identifier}, value, packet.get_secret(), packet.get_authenticator())?);
     Ok(())
 }}
@@ -469,13 +1726,13 @@ pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) -> Result<(),
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_user_password(packet.get_secret(), packet.get_authenticator()))
+    packet.lookup({type_identifier}).map(|v| v.decode_user_password(packet.get_secret(), packet.get_authenticator()).map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` user-password value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_user_password(packet.get_secret(), packet.get_authenticator())?)
+        vec.push(avp.decode_user_password(packet.get_secret(), packet.get_authenticator()).map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -484,85 +1741,176 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Vec<u8>>, A
 }
 
 fn generate_tunnel_password_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
     let code = format!(
         "/// Add `{method_identifier}` tunnel-password value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: &[u8]) -> Result<(), AVPError> {{
-    packet.add(AVP::from_tunnel_password({type_identifier}, tag, value, packet.get_secret(), packet.get_authenticator())?);
+pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
+    packet.add(AVP::from_tunnel_password_with_random_source({type_identifier}, tag, value, packet.get_secret(), packet.get_authenticator(), packet.get_random_source().as_ref())?);
     Ok(())
 }}
 /// Lookup a `{method_identifier}` tunnel-password value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<(Vec<u8>, Tag), AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_tunnel_password(packet.get_secret(), packet.get_authenticator()))
+    packet.lookup({type_identifier}).map(|v| v.decode_tunnel_password(packet.get_secret(), packet.get_authenticator()).map_err(|e| e.with_attribute_name(\"{attr_name}\")))
+}}
+/// Lookup all of the `{method_identifier}` tunnel-password value from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(Vec<u8>, Tag)>, AVPError> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_tunnel_password(packet.get_secret(), packet.get_authenticator()).map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
+    }}
+    Ok(vec)
+}}
+");
+    w.write_all(code.as_bytes()).unwrap();
+}
+
+fn generate_octets_attribute_code(
+    w: &mut dyn Write,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes({type_identifier}, value)?);
+    Ok(())
+}}
+/// Lookup a `{method_identifier}` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Vec<u8>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_bytes())
+}}
+/// Lookup all of the `{method_identifier}` octets value from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Vec<Vec<u8>> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_bytes())
+    }}
+    vec
+}}
+");
+    w.write_all(code.as_bytes()).unwrap();
+}
+
+fn generate_unsupported_attribute_code(
+    w: &mut dyn Write,
+    method_identifier: &str,
+    type_identifier: &str,
+    data_type: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` value to a packet as the raw octets.
+///
+/// **Warning**: the data type `{data_type}` of this attribute is not supported yet,
+/// so the value is neither validated nor encoded; it is put into the packet as it is.
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes({type_identifier}, value)?);
+    Ok(())
+}}
+/// Lookup a `{method_identifier}` value from a packet as the raw octets.
+///
+/// **Warning**: the data type `{data_type}` of this attribute is not supported yet, so the value is not decoded.
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Vec<u8>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_bytes())
 }}
-/// Lookup all of the `{method_identifier}` tunnel-password value from a packet.
-pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(Vec<u8>, Tag)>, AVPError> {{
+/// Lookup all of the `{method_identifier}` values from a packet as the raw octets.
+///
+/// **Warning**: the data type `{data_type}` of this attribute is not supported yet, so the values are not decoded.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Vec<Vec<u8>> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_tunnel_password(packet.get_secret(), packet.get_authenticator())?)
+        vec.push(avp.decode_bytes())
     }}
-    Ok(vec)
+    vec
 }}
 ");
     w.write_all(code.as_bytes()).unwrap();
 }
 
-fn generate_octets_attribute_code(
-    w: &mut BufWriter<File>,
+fn generate_tagged_octets_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
     let code = format!(
-        "/// Add `{method_identifier}` octets value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) {{
-    packet.add(AVP::from_bytes({type_identifier}, value));
+        "/// Add `{method_identifier}` tagged octets value to a packet.
+///
+/// The tag octet is omitted if the tag is `None`. It returns an error if the value exceeds 253 bytes including the tag octet.
+pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
+    packet.add(AVP::from_tagged_bytes({type_identifier}, tag, value)?);
+    Ok(())
 }}
-/// Lookup a `{method_identifier}` octets value from a packet.
+/// Lookup a `{method_identifier}` tagged octets value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
-pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Vec<u8>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_bytes())
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<(Vec<u8>, Option<Tag>), AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_tagged_bytes().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
-/// Lookup all of the `{method_identifier}` octets value from a packet.
-pub fn lookup_all_{method_identifier}(packet: &Packet) -> Vec<Vec<u8>> {{
+/// Lookup all of the `{method_identifier}` tagged octets value from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(Vec<u8>, Option<Tag>)>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_tagged_bytes().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
-    vec
+    Ok(vec)
 }}
-");
+",
+    );
     w.write_all(code.as_bytes()).unwrap();
 }
 
 fn generate_concat_octets_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
+    max_length: usize,
 ) {
     let code = format!(
-        "pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) {{
-    packet.extend(
-        value
-            .chunks(253)
-            .map(|chunk| AVP::from_bytes({type_identifier}, chunk))
-            .collect(),
-    );
+        "/// Add `{method_identifier}` octets value to a packet; the value is split into the multiple attributes.
+///
+/// It returns an error if the value exceeds {max_length} bytes.
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
+    if value.len() > {max_length} {{
+        return Err(AVPError::InvalidAttributeLengthError(\"<= {max_length} bytes\".to_owned(), value.len()));
+    }}
+    packet.add_with_policy({type_identifier}, value, JumboAttributePolicy::Concat)
 }}
-pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Vec<u8>> {{
+/// Lookup a `{method_identifier}` octets value from a packet; the values of the attributes are concatenated.
+///
+/// If there is no associated value with `{method_identifier}`, it returns `None`.
+/// It returns an error if the concatenated value exceeds {max_length} bytes.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {{
     let avps = packet.lookup_all({type_identifier});
-    match avps.is_empty() {{
-        true => None,
-        false => Some(avps.into_iter().fold(Vec::new(), |mut acc, v| {{
-            acc.extend(v.encode_bytes());
-            acc
-        }})),
+    if avps.is_empty() {{
+        return None;
     }}
+    let length = avps.iter().map(|v| v.raw_value().len()).sum::<usize>();
+    if length > {max_length} {{
+        return Some(Err(AVPError::InvalidAttributeLengthError(\"<= {max_length} bytes\".to_owned(), length)
+            .with_attribute_name(\"{attr_name}\")));
+    }}
+    Some(Ok(avps.into_iter().fold(Vec::with_capacity(length), |mut acc, v| {{
+        acc.extend_from_slice(v.raw_value());
+        acc
+    }})))
 }}
 "
     );
@@ -570,31 +1918,32 @@ pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Vec<u8>> {{
 }
 
 fn generate_fixed_length_octets_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
     method_identifier: &str,
     type_identifier: &str,
     fixed_octets_length: usize,
 ) {
     let code = format!(
         "/// Add `{method_identifier}` fixed-length octets value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {{
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
     if value.len() != {fixed_octets_length} {{
         return Err(AVPError::InvalidAttributeLengthError(\"{fixed_octets_length} bytes\".to_owned(), value.len()));
     }}
-    packet.add(AVP::from_bytes({type_identifier}, value));
+    packet.add(AVP::from_bytes({type_identifier}, value)?);
     Ok(())
 }}
 /// Lookup a `{method_identifier}` fixed-length octets value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Vec<u8>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_bytes())
+    packet.lookup({type_identifier}).map(|v| v.decode_bytes())
 }}
 /// Lookup all of the `{method_identifier}` fixed-length octets value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Vec<Vec<u8>> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_bytes())
     }}
     vec
 }}
@@ -604,7 +1953,8 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Vec<Vec<u8>> {{
 }
 
 fn generate_ipaddr_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
@@ -617,13 +1967,13 @@ pub fn add_{method_identifier}(packet: &mut Packet, value: &Ipv4Addr) {{
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_ipv4())
+    packet.lookup({type_identifier}).map(|v| v.decode_ipv4().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` ipaddr value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_ipv4()?)
+        vec.push(avp.decode_ipv4().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -632,14 +1982,46 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Ipv4Addr>,
     w.write_all(code.as_bytes()).unwrap();
 }
 
+fn generate_tagged_ipaddr_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` tagged ipaddr value to a packet.
+pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: &Ipv4Addr) {{
+    packet.add(AVP::from_tagged_ipv4({type_identifier}, tag, value));
+}}
+/// Lookup a `{method_identifier}` tagged ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<(Ipv4Addr, Tag), AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_tagged_ipv4().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
+}}
+/// Lookup all of the `{method_identifier}` tagged ipaddr value from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(Ipv4Addr, Tag)>, AVPError> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_tagged_ipv4().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
+    }}
+    Ok(vec)
+}}
+",
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
 fn generate_ipv4_prefix_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
     let code = format!(
         "/// Add `{method_identifier}` ipv4 prefix value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {{
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
     packet.add(AVP::from_ipv4_prefix({type_identifier}, value)?);
     Ok(())
 }}
@@ -647,13 +2029,13 @@ pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) -> Result<(),
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_ipv4_prefix())
+    packet.lookup({type_identifier}).map(|v| v.decode_ipv4_prefix().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` ipv4 prefix value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_ipv4_prefix()?)
+        vec.push(avp.decode_ipv4_prefix().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -663,7 +2045,8 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Vec<u8>>, A
 }
 
 fn generate_ipv6addr_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
@@ -676,13 +2059,13 @@ pub fn add_{method_identifier}(packet: &mut Packet, value: &Ipv6Addr) {{
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<Ipv6Addr, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_ipv6())
+    packet.lookup({type_identifier}).map(|v| v.decode_ipv6().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` ipv6addr value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Ipv6Addr>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_ipv6()?)
+        vec.push(avp.decode_ipv6().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -692,13 +2075,15 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Ipv6Addr>,
 }
 
 fn generate_ipv6_prefix_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
     let code = format!(
         "/// Add `{method_identifier}` ipv6 prefix value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {{
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
     packet.add(AVP::from_ipv6_prefix({type_identifier}, value)?);
     Ok(())
 }}
@@ -706,13 +2091,13 @@ pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8]) -> Result<(),
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_ipv6_prefix())
+    packet.lookup({type_identifier}).map(|v| v.decode_ipv6_prefix().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` ipv6 prefix value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_ipv6_prefix()?)
+        vec.push(avp.decode_ipv6_prefix().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -722,26 +2107,59 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<Vec<u8>>, A
 }
 
 fn generate_date_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
     let code = format!(
         "/// Add `{method_identifier}` date value to a packet.
-pub fn add_{method_identifier}(packet: &mut Packet, value: &DateTime<Utc>) {{
-    packet.add(AVP::from_date({type_identifier}, value));
+pub fn add_{method_identifier}(packet: &mut Packet, value: &DateTime<Utc>) -> Result<(), AVPError> {{
+    packet.add(AVP::from_date({type_identifier}, value)?);
+    Ok(())
 }}
 /// Lookup a `{method_identifier}` date value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<DateTime<Utc>, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_date())
+    packet.lookup({type_identifier}).map(|v| v.decode_date().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` date value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<DateTime<Utc>>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_date()?)
+        vec.push(avp.decode_date().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
+    }}
+    Ok(vec)
+}}
+",
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
+fn generate_tagged_date_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` tagged date value to a packet.
+pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: &DateTime<Utc>) -> Result<(), AVPError> {{
+    packet.add(AVP::from_tagged_date({type_identifier}, tag, value)?);
+    Ok(())
+}}
+/// Lookup a `{method_identifier}` tagged date value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<(DateTime<Utc>, Tag), AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_tagged_date().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
+}}
+/// Lookup all of the `{method_identifier}` tagged date value from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(DateTime<Utc>, Tag)>, AVPError> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_tagged_date().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -751,7 +2169,8 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<DateTime<Ut
 }
 
 fn generate_integer_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
@@ -764,13 +2183,13 @@ pub fn add_{method_identifier}(packet: &mut Packet, value: u32) {{
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<u32, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_u32())
+    packet.lookup({type_identifier}).map(|v| v.decode_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` integer value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<u32>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_u32()?)
+        vec.push(avp.decode_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -779,8 +2198,36 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<u32>, AVPEr
     w.write_all(code.as_bytes()).unwrap();
 }
 
+/// Generate the accessors of an integer attribute as a `Duration` (i.e. `duration` option),
+/// in addition to the accessors of the raw seconds.
+fn generate_duration_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` value to a packet as a duration.
+///
+/// The fractional part of a second is truncated. It returns an error if the duration exceeds `u32::MAX` seconds.
+pub fn add_{method_identifier}_duration(packet: &mut Packet, value: Duration) -> Result<(), AVPError> {{
+    packet.add(AVP::from_duration({type_identifier}, value)?);
+    Ok(())
+}}
+/// Lookup a `{method_identifier}` value from a packet as a duration.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}_duration(packet: &Packet) -> Option<Result<Duration, AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_duration().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
+}}
+",
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
 fn generate_tagged_integer_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
@@ -793,13 +2240,13 @@ pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: u3
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<(u32, Tag), AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_tagged_u32())
+    packet.lookup({type_identifier}).map(|v| v.decode_tagged_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` tagged integer value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(u32, Tag)>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_tagged_u32()?)
+        vec.push(avp.decode_tagged_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -809,7 +2256,8 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(u32, Tag)>
 }
 
 fn generate_value_defined_integer_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
     value_type: &str,
@@ -817,19 +2265,19 @@ fn generate_value_defined_integer_attribute_code(
     let code = format!(
         "/// Add `{method_identifier}` value-defined integer value to a packet.
 pub fn add_{method_identifier}(packet: &mut Packet, value: {value_type}) {{
-    packet.add(AVP::from_u32({type_identifier}, value));
+    packet.add(AVP::from_u32({type_identifier}, value.to_u32()));
 }}
 /// Lookup a `{method_identifier}` value-defined integer value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<{value_type}, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| Ok(v.encode_u32()? as {value_type}))
+    packet.lookup({type_identifier}).map(|v| Ok({value_type}::from_u32(v.decode_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)))
 }}
 /// Lookup all of the `{method_identifier}` value-defined integer value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<{value_type}>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_u32()? as {value_type})
+        vec.push({value_type}::from_u32(avp.decode_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?))
     }}
     Ok(vec)
 }}
@@ -839,7 +2287,8 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<{value_type
 }
 
 fn generate_tagged_value_defined_integer_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
     value_type: &str,
@@ -847,23 +2296,23 @@ fn generate_tagged_value_defined_integer_attribute_code(
     let code = format!(
         "/// Add `{method_identifier}` tagged value-defined integer value to a packet.
 pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: {value_type}) {{
-    packet.add(AVP::from_tagged_u32({type_identifier}, tag, value));
+    packet.add(AVP::from_tagged_u32({type_identifier}, tag, value.to_u32()));
 }}
 /// Lookup a `{method_identifier}` tagged value-defined integer value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<({value_type}, Tag), AVPError>> {{
     packet.lookup({type_identifier}).map(|v| {{
-        let (v, t) = v.encode_tagged_u32()?;
-        Ok((v as {value_type}, t))
+        let (v, t) = v.decode_tagged_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?;
+        Ok(({value_type}::from_u32(v), t))
     }})
 }}
 /// Lookup all of the `{method_identifier}` tagged value-defined integer value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<({value_type}, Tag)>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        let (v, t) = avp.encode_tagged_u32()?;
-        vec.push((v as {value_type}, t))
+        let (v, t) = avp.decode_tagged_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?;
+        vec.push(({value_type}::from_u32(v), t))
     }}
     Ok(vec)
 }}
@@ -872,8 +2321,90 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<({value_typ
     w.write_all(code.as_bytes()).unwrap();
 }
 
+fn generate_flags_integer_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
+    method_identifier: &str,
+    type_identifier: &str,
+    value_type: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` flags value to a packet.
+pub fn add_{method_identifier}(packet: &mut Packet, value: {value_type}) {{
+    packet.add(AVP::from_u32({type_identifier}, value.bits()));
+}}
+/// Lookup a `{method_identifier}` flags value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<{value_type}, AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| {{
+        let v = v.decode_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?;
+        Ok({value_type}::from_bits_retain(v))
+    }})
+}}
+/// Lookup all of the `{method_identifier}` flags value from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<{value_type}>, AVPError> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        let v = avp.decode_u32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?;
+        vec.push({value_type}::from_bits_retain(v))
+    }}
+    Ok(vec)
+}}
+/// Returns whether the first `{method_identifier}` value of a packet has all of the given flags.
+///
+/// If there is no associated value with `{method_identifier}`, it returns `false`.
+pub fn contains_{method_identifier}(packet: &Packet, flags: {value_type}) -> Result<bool, AVPError> {{
+    packet.contains_flags({type_identifier}, flags.bits()).map_err(|e| e.with_attribute_name(\"{attr_name}\"))
+}}
+/// Sets the given flags to the first `{method_identifier}` value of a packet.
+///
+/// If there is no associated value with `{method_identifier}`, it adds the value that has only the flags.
+pub fn insert_{method_identifier}(packet: &mut Packet, flags: {value_type}) -> Result<(), AVPError> {{
+    packet.insert_flags({type_identifier}, flags.bits()).map_err(|e| e.with_attribute_name(\"{attr_name}\"))
+}}
+/// Clears the given flags of the first `{method_identifier}` value of a packet.
+pub fn remove_{method_identifier}(packet: &mut Packet, flags: {value_type}) -> Result<(), AVPError> {{
+    packet.remove_flags({type_identifier}, flags.bits()).map_err(|e| e.with_attribute_name(\"{attr_name}\"))
+}}
+"
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
+fn generate_signed_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` signed integer value to a packet.
+pub fn add_{method_identifier}(packet: &mut Packet, value: i32) {{
+    packet.add(AVP::from_i32({type_identifier}, value));
+}}
+/// Lookup a `{method_identifier}` signed integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<i32, AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_i32().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
+}}
+/// Lookup all of the `{method_identifier}` signed integer value from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<i32>, AVPError> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_i32().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
+    }}
+    Ok(vec)
+}}
+",
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
 fn generate_short_attribute_code(
-    w: &mut BufWriter<File>,
+    w: &mut dyn Write,
+    attr_name: &str,
     method_identifier: &str,
     type_identifier: &str,
 ) {
@@ -886,13 +2417,13 @@ pub fn add_{method_identifier}(packet: &mut Packet, value: u16) {{
 ///
 /// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
 pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<u16, AVPError>> {{
-    packet.lookup({type_identifier}).map(|v| v.encode_u16())
+    packet.lookup({type_identifier}).map(|v| v.decode_u16().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
 }}
 /// Lookup all of the `{method_identifier}` short integer value from a packet.
 pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<u16>, AVPError> {{
     let mut vec = Vec::new();
     for avp in packet.lookup_all({type_identifier}) {{
-        vec.push(avp.encode_u16()?)
+        vec.push(avp.decode_u16().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
     }}
     Ok(vec)
 }}
@@ -901,12 +2432,166 @@ pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<u16>, AVPEr
     w.write_all(code.as_bytes()).unwrap();
 }
 
+fn generate_tagged_short_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` tagged short integer value to a packet.
+pub fn add_{method_identifier}(packet: &mut Packet, tag: Option<&Tag>, value: u16) {{
+    packet.add(AVP::from_tagged_u16({type_identifier}, tag, value));
+}}
+/// Lookup a `{method_identifier}` tagged short integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<(u16, Tag), AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_tagged_u16().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
+}}
+/// Lookup all of the `{method_identifier}` tagged short integer value from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<(u16, Tag)>, AVPError> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_tagged_u16().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
+    }}
+    Ok(vec)
+}}
+",
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
+fn generate_ether_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` ether value (i.e. a MAC address) to a packet.
+pub fn add_{method_identifier}(packet: &mut Packet, value: &[u8; 6]) {{
+    packet.add(AVP::from_ether({type_identifier}, value));
+}}
+/// Lookup a `{method_identifier}` ether value (i.e. a MAC address) from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<[u8; 6], AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_ether().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
+}}
+/// Lookup all of the `{method_identifier}` ether value (i.e. a MAC address) from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<[u8; 6]>, AVPError> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_ether().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
+    }}
+    Ok(vec)
+}}
+",
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
+fn generate_abinary_attribute_code(
+    w: &mut dyn Write,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` abinary value (i.e. an Ascend binary filter) to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_{method_identifier}(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {{
+    let value = value.as_ref();
+    packet.add(AVP::from_abinary({type_identifier}, value)?);
+    Ok(())
+}}
+/// Lookup a `{method_identifier}` abinary value (i.e. an Ascend binary filter) from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Vec<u8>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_abinary())
+}}
+/// Lookup all of the `{method_identifier}` abinary value (i.e. an Ascend binary filter) from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Vec<Vec<u8>> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_abinary())
+    }}
+    vec
+}}
+",
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
+fn generate_combo_ip_attribute_code(
+    w: &mut dyn Write,
+    attr_name: &str,
+    method_identifier: &str,
+    type_identifier: &str,
+) {
+    let code = format!(
+        "/// Add `{method_identifier}` combo-ip value (i.e. either of IPv4 or IPv6 address) to a packet.
+pub fn add_{method_identifier}(packet: &mut Packet, value: &IpAddr) {{
+    packet.add(AVP::from_combo_ip({type_identifier}, value));
+}}
+/// Lookup a `{method_identifier}` combo-ip value (i.e. either of IPv4 or IPv6 address) from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `{method_identifier}`, it returns `None`.
+pub fn lookup_{method_identifier}(packet: &Packet) -> Option<Result<IpAddr, AVPError>> {{
+    packet.lookup({type_identifier}).map(|v| v.decode_combo_ip().map_err(|e| e.with_attribute_name(\"{attr_name}\")))
+}}
+/// Lookup all of the `{method_identifier}` combo-ip value (i.e. either of IPv4 or IPv6 address) from a packet.
+pub fn lookup_all_{method_identifier}(packet: &Packet) -> Result<Vec<IpAddr>, AVPError> {{
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all({type_identifier}) {{
+        vec.push(avp.decode_combo_ip().map_err(|e| e.with_attribute_name(\"{attr_name}\"))?)
+    }}
+    Ok(vec)
+}}
+",
+    );
+    w.write_all(code.as_bytes()).unwrap();
+}
+
 fn generate_vsa_attribute_code() {
     // NOP
 }
 
 type DictParsed = (Vec<RadiusAttribute>, BTreeMap<String, Vec<RadiusValue>>);
 
+/// Parse `VENDOR name id [format=t,l[,c]]` line.
+fn parse_vendor(items: &[&str]) -> Result<RadiusVendor, String> {
+    let id = items[2]
+        .parse()
+        .map_err(|_| format!("invalid vendor id has come => {}", items[2]))?;
+    let (type_length, length_length, continuation) =
+        match items.get(3).filter(|item| !item.starts_with('#')) {
+            None => (1, 1, false),
+            Some(item) => {
+                let format = item
+                    .strip_prefix(VENDOR_FORMAT_OPT_PREFIX)
+                    .ok_or_else(|| format!("unexpected vendor option has come => {item}"))?;
+                match format.split(',').collect::<Vec<&str>>().as_slice() {
+                    [t @ ("1" | "2" | "4"), l @ ("0" | "1" | "2")] => {
+                        (t.parse().unwrap(), l.parse().unwrap(), false)
+                    }
+                    // the continuation octet is available only for 1-octet type and 1-octet length (e.g. WiMAX)
+                    ["1", "1", "c"] => (1, 1, true),
+                    _ => return Err(format!("unsupported vendor format has come => {format}")),
+                }
+            }
+        };
+    Ok(RadiusVendor {
+        name: items[1].to_owned(),
+        id,
+        type_length,
+        length_length,
+        continuation,
+    })
+}
+
 fn parse_dict_file(
     dict_file_path: &Path,
 ) -> Result<(DictParsed, io::Lines<io::BufReader<File>>), String> {
@@ -917,6 +2602,8 @@ fn parse_dict_file(
 
     let mut radius_attributes: Vec<RadiusAttribute> = Vec::new();
     let mut radius_attribute_to_values: BTreeMap<String, Vec<RadiusValue>> = BTreeMap::new();
+    let mut vendors: BTreeMap<String, RadiusVendor> = BTreeMap::new();
+    let mut current_vendor: Option<RadiusVendor> = None;
 
     let lines = read_lines(dict_file_path).unwrap();
     for line_result in lines {
@@ -928,16 +2615,39 @@ fn parse_dict_file(
 
         let items = ws_re.split(line.as_str()).collect::<Vec<&str>>();
 
-        if items.len() < 4 {
+        let kind = items[0];
+        let min_items = match kind {
+            VENDOR_KIND => 3,
+            BEGIN_VENDOR_KIND | END_VENDOR_KIND => 2,
+            _ => 4,
+        };
+        if items.len() < min_items {
             return Err("the number of items is lacked in a line".to_owned());
         }
 
-        let kind = items[0];
         match kind {
+            VENDOR_KIND => {
+                let vendor = parse_vendor(&items)?;
+                vendors.insert(vendor.name.clone(), vendor);
+            }
+            BEGIN_VENDOR_KIND => {
+                current_vendor = match vendors.get(items[1]) {
+                    Some(vendor) => Some(vendor.clone()),
+                    None => return Err(format!("undefined vendor has come => {}", items[1])),
+                };
+            }
+            END_VENDOR_KIND => {
+                current_vendor = None;
+            }
             ATTRIBUTE_KIND => {
                 let mut encryption_type: Option<EncryptionType> = None;
                 let mut has_tag = false;
                 let mut concat_octets = false;
+                let mut max_occurrences: Option<usize> = None;
+                let mut max_length: Option<usize> = None;
+                let mut group: Option<String> = None;
+                let mut flags = false;
+                let mut duration = false;
                 if items.len() >= 5 {
                     // TODO consider to extract to a method
                     for type_opt in items[4].split(',') {
@@ -957,9 +2667,46 @@ fn parse_dict_file(
                             concat_octets = true;
                             continue;
                         }
+                        if let Some(max) = type_opt.strip_prefix(MAX_OCCURRENCES_TYPE_OPT_PREFIX) {
+                            max_occurrences = match max.parse() {
+                                Ok(max) => Some(max),
+                                Err(_) => {
+                                    return Err(format!("invalid max_occurs has come => {max}"))
+                                }
+                            };
+                            continue;
+                        }
+                        if let Some(max) = type_opt.strip_prefix(MAX_LENGTH_TYPE_OPT_PREFIX) {
+                            max_length = match max.parse() {
+                                Ok(max) => Some(max),
+                                Err(_) => {
+                                    return Err(format!("invalid max_length has come => {max}"))
+                                }
+                            };
+                            continue;
+                        }
+                        if let Some(name) = type_opt.strip_prefix(GROUP_TYPE_OPT_PREFIX) {
+                            group = Some(name.to_owned());
+                            continue;
+                        }
+                        if type_opt == FLAGS_TYPE_OPT {
+                            flags = true;
+                            continue;
+                        }
+                        if type_opt == DURATION_TYPE_OPT {
+                            duration = true;
+                            continue;
+                        }
                     }
                 }
 
+                if duration && (items[3] != "integer" || has_tag) {
+                    return Err(format!(
+                        "the duration attribute {} must be an untagged integer",
+                        items[1]
+                    ));
+                }
+
                 let (typ, fixed_octets_length) = match RadiusAttributeValueType::from_str(items[3])
                 {
                     Ok(t) => {
@@ -986,18 +2733,46 @@ fn parse_dict_file(
                                 Some(cap.get(1).unwrap().as_str().parse::<usize>().unwrap()),
                             )
                         } else {
-                            return Err(format!("invalid type has come => {}", items[3]));
+                            eprintln!(
+                                "warning: the data type `{}` of {} is not supported; it is handled as the raw octets",
+                                items[3], items[1]
+                            );
+                            (
+                                RadiusAttributeValueType::Unsupported(items[3].to_owned()),
+                                None,
+                            )
                         }
                     }
                 };
 
+                let max_type = match &current_vendor {
+                    Some(vendor) if vendor.type_length == 4 => u32::MAX,
+                    Some(vendor) => (1 << (8 * vendor.type_length)) - 1,
+                    None => u8::MAX as u32,
+                };
+                let attr_type = match items[2].parse::<u32>() {
+                    Ok(attr_type) if attr_type <= max_type => attr_type,
+                    _ => {
+                        return Err(format!(
+                            "invalid type of {} has come => {}",
+                            items[1], items[2]
+                        ))
+                    }
+                };
+
                 radius_attributes.push(RadiusAttribute {
                     name: items[1].to_string(),
-                    typ: items[2].parse().unwrap(),
+                    typ: attr_type,
+                    vendor: current_vendor.clone(),
                     value_type: typ,
                     fixed_octets_length,
                     concat_octets,
                     has_tag,
+                    max_occurrences,
+                    max_length,
+                    group,
+                    flags,
+                    duration,
                 });
             }
             VALUE_KIND => {
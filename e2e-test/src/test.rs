@@ -88,6 +88,7 @@ mod tests {
         test_socket_timeout().await;
     }
 
+    #[allow(clippy::bool_assert_comparison, clippy::unnecessary_to_owned)]
     async fn test_access_request() {
         let (sender, receiver) = oneshot::channel::<()>();
 
@@ -125,6 +126,7 @@ mod tests {
         server_proc.await.unwrap();
     }
 
+    #[allow(clippy::unnecessary_to_owned)]
     async fn test_socket_timeout() {
         let (sender, receiver) = oneshot::channel::<()>();
 
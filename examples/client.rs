@@ -8,6 +8,7 @@ use std::net::SocketAddr;
 use tokio::time::Duration;
 
 #[tokio::main]
+#[allow(clippy::unnecessary_to_owned)]
 async fn main() {
     env_logger::init();
 
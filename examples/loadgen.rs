@@ -0,0 +1,36 @@
+#[macro_use]
+extern crate log;
+use radius::loadgen::{LoadGenConfig, LoadGenerator};
+use std::net::SocketAddr;
+use tokio::time::Duration;
+
+// usage: cargo run --example loadgen -- [auth_addr] [acct_addr] [secret] [rate] [seconds]
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let arg = |i: usize, default: &str| args.get(i).cloned().unwrap_or(default.to_owned());
+    let auth_addr: SocketAddr = arg(0, "127.0.0.1:1812").parse().unwrap();
+    let acct_addr: SocketAddr = arg(1, "127.0.0.1:1813").parse().unwrap();
+
+    let mut config = LoadGenConfig::new(auth_addr, acct_addr, arg(2, "secret").as_bytes());
+    config.rate = arg(3, "100").parse().unwrap();
+    config.duration = Duration::from_secs(arg(4, "10").parse().unwrap());
+
+    let generator = LoadGenerator::new(config).unwrap();
+    let report = generator.run().await.unwrap();
+    info!(
+        "sent: {}, received: {} (rejected: {}), lost: {} ({:.2}%), errors: {}, throughput: {:.1}/s",
+        report.get_sent(),
+        report.get_received(),
+        report.get_rejected(),
+        report.get_lost(),
+        report.get_loss_ratio() * 100.0,
+        report.get_errors(),
+        report.get_throughput(),
+    );
+    for percentile in [50.0, 90.0, 99.0, 99.9] {
+        info!("p{}: {:?}", percentile, report.percentile(percentile));
+    }
+}
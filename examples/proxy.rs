@@ -0,0 +1,40 @@
+#[macro_use]
+extern crate log;
+
+use std::process;
+
+use tokio::signal;
+
+use radius::proxy::ReverseProxy;
+use radius::server::Server;
+
+// forwards the requests of `user@example.com` to the `home` pool, and the others to the `default` pool
+const CONFIG: &str = "
+client 127.0.0.1 secret
+pool home home-secret 127.0.0.1:11812 127.0.0.1:21812
+pool default default-secret 127.0.0.1:31812
+realm example.com home
+default default
+strip-realm
+";
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let proxy = ReverseProxy::from_config(CONFIG).unwrap();
+    let secret_provider = proxy.get_secret_provider();
+    let mut server = Server::listen("0.0.0.0", 1812, proxy, secret_provider)
+        .await
+        .unwrap();
+    info!(
+        "proxy is now ready: {}",
+        server.get_listen_address().unwrap()
+    );
+
+    let result = server.run(signal::ctrl_c()).await;
+    info!("{:?}", result);
+    if result.is_err() {
+        process::exit(1);
+    }
+}
@@ -9,6 +9,7 @@ use tokio::net::UdpSocket;
 use tokio::signal;
 
 use radius::core::code::Code;
+use radius::core::packet::DuplicatePolicy;
 use radius::core::request::Request;
 use radius::core::rfc2865;
 use radius::server::{RequestHandler, SecretProvider, SecretProviderError, Server};
@@ -23,6 +24,7 @@ async fn main() {
         .unwrap();
     server.set_buffer_size(1500); // default value: 1500
     server.set_skip_authenticity_validation(false); // default value: false
+    server.set_duplicate_policy(DuplicatePolicy::Allow); // default value: DuplicatePolicy::Allow
 
     // once it has reached here, a RADIUS server is now ready
     info!(
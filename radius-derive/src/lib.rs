@@ -0,0 +1,321 @@
+//! Derive macros for the radius crate.
+//!
+//! - `FromPacket` implements `radius::core::convert::FromPacket`
+//! - `ToPacket` implements `radius::core::convert::ToPacket`
+//!
+//! Each field has to be annotated with either `#[radius(typ = <AVP type>)]` or `#[radius(attribute = "<attribute name>")]`
+//! (e.g. `#[radius(attribute = "User-Name")]`); the attribute name is resolved by the registry of the dictionaries.
+//! The field type decides how the attribute is mapped:
+//!
+//! - `Option<T>`: an optional attribute
+//! - `Vec<T>` (except `Vec<u8>`, that is an octets value): all of the attributes of the type
+//! - `T`: a mandatory attribute
+//!
+//! The generated code refers to `::radius::core`; `#[radius(crate = "radius_proto")]` on the struct changes that path
+//! for the crates that depend on radius-proto directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Expr, Fields, GenericArgument, LitStr, Path,
+    PathArguments, Type,
+};
+
+enum FieldKind<'a> {
+    Single,
+    Optional(&'a Type),
+    Multiple(&'a Type),
+}
+
+enum AttributeSpec {
+    Type(Expr),
+    Name(LitStr),
+}
+
+struct RadiusField<'a> {
+    ident: &'a syn::Ident,
+    ty: &'a Type,
+    kind: FieldKind<'a>,
+    spec: AttributeSpec,
+}
+
+impl RadiusField<'_> {
+    fn typ_ident(&self) -> syn::Ident {
+        format_ident!("__{}_typ", self.ident)
+    }
+
+    /// Returns the type of each value of the field (e.g. `T` of `Option<T>`).
+    fn value_ty(&self) -> &Type {
+        match self.kind {
+            FieldKind::Single => self.ty,
+            FieldKind::Optional(ty) | FieldKind::Multiple(ty) => ty,
+        }
+    }
+
+    /// Binds the AVP type of the field to a local variable.
+    ///
+    /// If the field is annotated by the name, the data type of the attribute must be supported
+    /// unless the field has the raw octets (i.e. `Vec<u8>`).
+    fn bind_typ(&self, core: &Path) -> TokenStream2 {
+        let typ_ident = self.typ_ident();
+        match &self.spec {
+            AttributeSpec::Type(typ) => quote! {
+                let #typ_ident: #core::avp::AVPType = #typ;
+            },
+            AttributeSpec::Name(name) => {
+                let ensure_supported = if is_octets(self.value_ty()) {
+                    quote! {}
+                } else {
+                    quote! {
+                        if let Some(metadata) = #core::registry::attribute_metadata(#typ_ident) {
+                            metadata.ensure_supported()?;
+                        }
+                    }
+                };
+                quote! {
+                    let #typ_ident = match #core::registry::attribute_type(#name) {
+                        Some(typ) => typ,
+                        None => return Err(#core::avp::AVPError::UnknownAttributeNameError(#name.to_owned())),
+                    };
+                    #ensure_supported
+                }
+            }
+        }
+    }
+
+    /// Attaches the attribute name to a decoding error if the field is annotated by the name.
+    fn map_err(&self) -> TokenStream2 {
+        match &self.spec {
+            AttributeSpec::Type(_) => quote! {},
+            AttributeSpec::Name(name) => quote! {
+                .map_err(|e| e.with_attribute_name(#name))
+            },
+        }
+    }
+}
+
+#[proc_macro_derive(FromPacket, attributes(radius))]
+pub fn derive_from_packet(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_from_packet(&input) {
+        Ok(expanded) => expanded.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(ToPacket, attributes(radius))]
+pub fn derive_to_packet(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_to_packet(&input) {
+        Ok(expanded) => expanded.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand_from_packet(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let core = parse_core_path(input)?;
+    let radius_fields = parse_fields(input)?;
+    let bindings = radius_fields.iter().map(|field| field.bind_typ(&core));
+    let fields = radius_fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident;
+            let typ = field.typ_ident();
+            let map_err = field.map_err();
+            match field.kind {
+                FieldKind::Single => {
+                    let ty = field.ty;
+                    quote! {
+                        #ident: match packet.get::<#ty>(#typ) {
+                            Some(value) => value #map_err?,
+                            None => return Err(#core::avp::AVPError::MissingAttributeError(#typ)),
+                        }
+                    }
+                }
+                FieldKind::Optional(ty) => quote! {
+                    #ident: packet.get::<#ty>(#typ).transpose() #map_err?
+                },
+                FieldKind::Multiple(ty) => quote! {
+                    #ident: packet.get_all::<#ty>(#typ) #map_err?
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics #core::convert::FromPacket for #name #ty_generics #where_clause {
+            fn from_packet(
+                packet: &#core::packet::Packet,
+            ) -> Result<Self, #core::avp::AVPError> {
+                #(#bindings)*
+                Ok(#name {
+                    #(#fields),*
+                })
+            }
+        }
+    })
+}
+
+fn expand_to_packet(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let core = parse_core_path(input)?;
+    let radius_fields = parse_fields(input)?;
+    let bindings = radius_fields.iter().map(|field| field.bind_typ(&core));
+    let fields = radius_fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident;
+            let typ = field.typ_ident();
+            match field.kind {
+                FieldKind::Single => quote! {
+                    packet.add(#core::convert::ToAvp::to_avp(&self.#ident, #typ)?);
+                },
+                FieldKind::Optional(_) => quote! {
+                    if let Some(value) = &self.#ident {
+                        packet.add(#core::convert::ToAvp::to_avp(value, #typ)?);
+                    }
+                },
+                FieldKind::Multiple(_) => quote! {
+                    for value in &self.#ident {
+                        packet.add(#core::convert::ToAvp::to_avp(value, #typ)?);
+                    }
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics #core::convert::ToPacket for #name #ty_generics #where_clause {
+            fn to_packet(
+                &self,
+                packet: &mut #core::packet::Packet,
+            ) -> Result<(), #core::avp::AVPError> {
+                #(#bindings)*
+                #(#fields)*
+                Ok(())
+            }
+        }
+    })
+}
+
+/// Returns the path of the `core` module of the radius crate; it is `::radius::core` unless `#[radius(crate = "...")]` is given.
+fn parse_core_path(input: &DeriveInput) -> Result<Path, Error> {
+    let mut core = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("radius"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                core = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported radius attribute"))
+            }
+        })?;
+    }
+    Ok(core.unwrap_or_else(|| syn::parse_quote!(::radius::core)))
+}
+
+fn parse_fields(input: &DeriveInput) -> Result<Vec<RadiusField<'_>>, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    input,
+                    "only a struct with named fields is supported",
+                ))
+            }
+        },
+        _ => return Err(Error::new_spanned(input, "only a struct is supported")),
+    };
+
+    fields
+        .iter()
+        .map(|field| {
+            let mut spec = None;
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("radius"))
+            {
+                attr.parse_nested_meta(|meta| {
+                    if spec.is_some() {
+                        return Err(meta.error("duplicated radius attribute"));
+                    }
+                    if meta.path.is_ident("typ") {
+                        spec = Some(AttributeSpec::Type(meta.value()?.parse::<Expr>()?));
+                        Ok(())
+                    } else if meta.path.is_ident("attribute") {
+                        spec = Some(AttributeSpec::Name(meta.value()?.parse::<LitStr>()?));
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported radius attribute"))
+                    }
+                })?;
+            }
+
+            let spec =
+                match spec {
+                    Some(spec) => spec,
+                    None => return Err(Error::new_spanned(
+                        field,
+                        "missing #[radius(typ = ...)] or #[radius(attribute = \"...\")] attribute",
+                    )),
+                };
+
+            Ok(RadiusField {
+                ident: field.ident.as_ref().unwrap(),
+                ty: &field.ty,
+                kind: field_kind(&field.ty),
+                spec,
+            })
+        })
+        .collect()
+}
+
+fn field_kind(ty: &Type) -> FieldKind<'_> {
+    if let Some(inner) = generic_argument(ty, "Option") {
+        return FieldKind::Optional(inner);
+    }
+    if let Some(inner) = generic_argument(ty, "Vec") {
+        if !is_octets(ty) {
+            return FieldKind::Multiple(inner);
+        }
+    }
+    FieldKind::Single
+}
+
+/// Returns whether the type is `Vec<u8>`, i.e. an octets value.
+fn is_octets(ty: &Type) -> bool {
+    matches!(
+        generic_argument(ty, "Vec"),
+        Some(Type::Path(path)) if path.path.is_ident("u8")
+    )
+}
+
+fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,68 @@
+/*
+ * Builds an Access-Request with the C bindings, and decodes it again.
+ *
+ *   $ cargo build -p radius-ffi
+ *   $ cc -I radius-ffi/include radius-ffi/examples/decode.c -L target/debug -lradius_ffi -o decode
+ *   $ LD_LIBRARY_PATH=target/debug ./decode
+ */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "radius.h"
+
+#define USER_NAME_TYPE 1
+#define USER_PASSWORD_TYPE 2
+
+int main(void) {
+    const uint8_t secret[] = "secret";
+    const size_t secret_len = sizeof(secret) - 1;
+    const char *user_name = "alice";
+    const char *password = "password";
+
+    RadiusPacket *request = radius_packet_new(1, secret, secret_len);
+    radius_packet_add_attribute(request, USER_NAME_TYPE, (const uint8_t *)user_name, strlen(user_name));
+
+    uint8_t encrypted[128];
+    size_t encrypted_len;
+    int32_t result = radius_user_password_encrypt(secret, secret_len, radius_packet_get_authenticator(request),
+                                                  (const uint8_t *)password, strlen(password), encrypted,
+                                                  sizeof(encrypted), &encrypted_len);
+    if (result != RADIUS_OK) {
+        fprintf(stderr, "%s\n", radius_strerror(result));
+        return 1;
+    }
+    radius_packet_add_attribute(request, USER_PASSWORD_TYPE, encrypted, encrypted_len);
+
+    uint8_t buf[4096];
+    size_t len;
+    result = radius_packet_encode(request, buf, sizeof(buf), &len);
+    radius_packet_free(request);
+    if (result != RADIUS_OK) {
+        fprintf(stderr, "%s\n", radius_strerror(result));
+        return 1;
+    }
+
+    RadiusPacket *decoded;
+    result = radius_packet_decode(buf, len, secret, secret_len, &decoded);
+    if (result != RADIUS_OK) {
+        fprintf(stderr, "%s\n", radius_strerror(result));
+        return 1;
+    }
+
+    const uint8_t *value;
+    size_t value_len;
+    if (radius_packet_get_attribute(decoded, USER_NAME_TYPE, 0, &value, &value_len) == RADIUS_OK) {
+        printf("User-Name: %.*s\n", (int)value_len, (const char *)value);
+    }
+    if (radius_packet_get_attribute(decoded, USER_PASSWORD_TYPE, 0, &value, &value_len) == RADIUS_OK) {
+        uint8_t decrypted[128];
+        size_t decrypted_len;
+        if (radius_user_password_decrypt(secret, secret_len, radius_packet_get_authenticator(decoded), value,
+                                         value_len, decrypted, sizeof(decrypted), &decrypted_len) == RADIUS_OK) {
+            printf("User-Password: %.*s\n", (int)decrypted_len, (const char *)decrypted);
+        }
+    }
+    radius_packet_free(decoded);
+    return 0;
+}
@@ -0,0 +1,86 @@
+/*
+ * C bindings of the RADIUS packet codec of radius-rs.
+ *
+ * The functions return RADIUS_OK (i.e. 0) on success and a negative RADIUS_ERROR_* code on failure;
+ * radius_strerror() describes the code. The functions that write into a caller's buffer store the required
+ * length into `written` and return RADIUS_ERROR_BUFFER_TOO_SMALL if the buffer is too small.
+ */
+
+#ifndef RADIUS_H
+#define RADIUS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define RADIUS_OK (0)
+#define RADIUS_ERROR_NULL_POINTER (-1)
+#define RADIUS_ERROR_DECODE (-2)
+#define RADIUS_ERROR_ENCODE (-3)
+#define RADIUS_ERROR_INVALID_ATTRIBUTE (-4)
+#define RADIUS_ERROR_BUFFER_TOO_SMALL (-5)
+#define RADIUS_ERROR_NOT_FOUND (-6)
+
+/* An opaque handle of a packet; it must be released by radius_packet_free(). */
+typedef struct RadiusPacket RadiusPacket;
+
+/* Returns the static description of the result code. */
+const char *radius_strerror(int32_t code);
+
+/* Makes a packet of the code with a random identifier and authenticator; returns NULL if the code is invalid. */
+RadiusPacket *radius_packet_new(uint8_t code, const uint8_t *secret, size_t secret_len);
+
+/* Decodes the bytes into a packet, and stores that into `out`. */
+int32_t radius_packet_decode(const uint8_t *buf, size_t len, const uint8_t *secret, size_t secret_len,
+                             RadiusPacket **out);
+
+/* Releases the packet; NULL is ignored. */
+void radius_packet_free(RadiusPacket *packet);
+
+/* Encodes the packet into the buffer. */
+int32_t radius_packet_encode(const RadiusPacket *packet, uint8_t *buf, size_t buf_len, size_t *written);
+
+uint8_t radius_packet_get_code(const RadiusPacket *packet);
+
+uint8_t radius_packet_get_identifier(const RadiusPacket *packet);
+
+void radius_packet_set_identifier(RadiusPacket *packet, uint8_t identifier);
+
+/* Returns the pointer to the 16 bytes of the authenticator; it is valid while the packet is alive. */
+const uint8_t *radius_packet_get_authenticator(const RadiusPacket *packet);
+
+/* Returns the number of the attributes of the type in the packet. */
+size_t radius_packet_count_attribute(const RadiusPacket *packet, uint8_t type);
+
+/*
+ * Stores the pointer to the raw value of the `index`-th attribute of the type into `value`, and the length into
+ * `value_len`; the pointer is valid until the packet is modified or released.
+ * Returns RADIUS_ERROR_NOT_FOUND if there is no such attribute.
+ */
+int32_t radius_packet_get_attribute(const RadiusPacket *packet, uint8_t type, size_t index, const uint8_t **value,
+                                    size_t *value_len);
+
+/* Adds an attribute of the type with the raw value; the value must not exceed 253 bytes. */
+int32_t radius_packet_add_attribute(RadiusPacket *packet, uint8_t type, const uint8_t *value, size_t value_len);
+
+/* Deletes all of the attributes of the type from the packet. */
+void radius_packet_delete_attribute(RadiusPacket *packet, uint8_t type);
+
+/* Encrypts the password as the value of User-Password with the secret and the 16 bytes Request Authenticator. */
+int32_t radius_user_password_encrypt(const uint8_t *secret, size_t secret_len, const uint8_t *authenticator,
+                                     const uint8_t *password, size_t password_len, uint8_t *buf, size_t buf_len,
+                                     size_t *written);
+
+/* Decrypts the value of User-Password with the secret and the 16 bytes Request Authenticator. */
+int32_t radius_user_password_decrypt(const uint8_t *secret, size_t secret_len, const uint8_t *authenticator,
+                                     const uint8_t *value, size_t value_len, uint8_t *buf, size_t buf_len,
+                                     size_t *written);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* RADIUS_H */
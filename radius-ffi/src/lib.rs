@@ -0,0 +1,561 @@
+//! C bindings of the RADIUS packet codec, so that the NAS software that is written in C can embed it.
+//!
+//! The declarations are in `include/radius.h`. The functions return `RADIUS_OK` (i.e. 0) on success and
+//! a negative `RADIUS_ERROR_*` code on failure; `radius_strerror()` describes the code.
+//!
+//! The functions that write into a caller's buffer (e.g. `radius_packet_encode()`) store the required length
+//! into `written` and return `RADIUS_ERROR_BUFFER_TOO_SMALL` if the buffer is too small, so the caller can retry
+//! with a buffer of that length.
+
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+use radius_proto::avp::{AVPType, AVP};
+use radius_proto::code::Code;
+use radius_proto::packet::Packet;
+
+pub const RADIUS_OK: i32 = 0;
+pub const RADIUS_ERROR_NULL_POINTER: i32 = -1;
+pub const RADIUS_ERROR_DECODE: i32 = -2;
+pub const RADIUS_ERROR_ENCODE: i32 = -3;
+pub const RADIUS_ERROR_INVALID_ATTRIBUTE: i32 = -4;
+pub const RADIUS_ERROR_BUFFER_TOO_SMALL: i32 = -5;
+pub const RADIUS_ERROR_NOT_FOUND: i32 = -6;
+
+/// This struct is an opaque handle of a packet for C; it is made by `radius_packet_new()` or `radius_packet_decode()`,
+/// and must be released by `radius_packet_free()`.
+pub struct RadiusPacket {
+    packet: Packet,
+}
+
+/// Returns the slice of the pointer and the length; a null pointer is accepted for an empty slice.
+unsafe fn as_slice<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    if len == 0 {
+        return Some(&[]);
+    }
+    if data.is_null() {
+        return None;
+    }
+    Some(slice::from_raw_parts(data, len))
+}
+
+/// Copy the bytes into the caller's buffer, and store the length of those into `written`.
+unsafe fn write_bytes(bs: &[u8], buf: *mut u8, buf_len: usize, written: *mut usize) -> i32 {
+    if written.is_null() {
+        return RADIUS_ERROR_NULL_POINTER;
+    }
+    *written = bs.len();
+    if bs.len() > buf_len {
+        return RADIUS_ERROR_BUFFER_TOO_SMALL;
+    }
+    if !bs.is_empty() {
+        if buf.is_null() {
+            return RADIUS_ERROR_NULL_POINTER;
+        }
+        ptr::copy_nonoverlapping(bs.as_ptr(), buf, bs.len());
+    }
+    RADIUS_OK
+}
+
+/// Returns the static description of the result code.
+#[no_mangle]
+pub extern "C" fn radius_strerror(code: i32) -> *const c_char {
+    let message: &'static [u8] = match code {
+        RADIUS_OK => b"success\0",
+        RADIUS_ERROR_NULL_POINTER => b"null pointer\0",
+        RADIUS_ERROR_DECODE => b"failed to decode the packet\0",
+        RADIUS_ERROR_ENCODE => b"failed to encode the packet\0",
+        RADIUS_ERROR_INVALID_ATTRIBUTE => b"invalid attribute\0",
+        RADIUS_ERROR_BUFFER_TOO_SMALL => b"buffer too small\0",
+        RADIUS_ERROR_NOT_FOUND => b"attribute not found\0",
+        _ => b"unknown error\0",
+    };
+    message.as_ptr() as *const c_char
+}
+
+/// Make a packet of the code with a random identifier and authenticator; it returns null if the code is invalid.
+///
+/// # Safety
+///
+/// `secret` must point to `secret_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_new(
+    code: u8,
+    secret: *const u8,
+    secret_len: usize,
+) -> *mut RadiusPacket {
+    let secret = match as_slice(secret, secret_len) {
+        Some(secret) => secret,
+        None => return ptr::null_mut(),
+    };
+    let code = Code::from(code);
+    if code == Code::Invalid {
+        return ptr::null_mut();
+    }
+    Box::into_raw(Box::new(RadiusPacket {
+        packet: Packet::new(code, secret),
+    }))
+}
+
+/// Decode the bytes into a packet, and store that into `out`.
+///
+/// # Safety
+///
+/// `buf` and `secret` must point to `len` and `secret_len` bytes, and `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_decode(
+    buf: *const u8,
+    len: usize,
+    secret: *const u8,
+    secret_len: usize,
+    out: *mut *mut RadiusPacket,
+) -> i32 {
+    let (bs, secret) = match (as_slice(buf, len), as_slice(secret, secret_len)) {
+        (Some(bs), Some(secret)) => (bs, secret),
+        _ => return RADIUS_ERROR_NULL_POINTER,
+    };
+    if out.is_null() {
+        return RADIUS_ERROR_NULL_POINTER;
+    }
+    match Packet::decode(bs, secret) {
+        Ok(packet) => {
+            *out = Box::into_raw(Box::new(RadiusPacket { packet }));
+            RADIUS_OK
+        }
+        Err(_) => RADIUS_ERROR_DECODE,
+    }
+}
+
+/// Release the packet; null is ignored.
+///
+/// # Safety
+///
+/// `packet` must be the one that is made by this library, and must not be used after this.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_free(packet: *mut RadiusPacket) {
+    if !packet.is_null() {
+        drop(Box::from_raw(packet));
+    }
+}
+
+/// Encode the packet into the buffer.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet, `buf` must point to `buf_len` writable bytes, and `written` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_encode(
+    packet: *const RadiusPacket,
+    buf: *mut u8,
+    buf_len: usize,
+    written: *mut usize,
+) -> i32 {
+    let packet = match packet.as_ref() {
+        Some(packet) => &packet.packet,
+        None => return RADIUS_ERROR_NULL_POINTER,
+    };
+    match packet.encode() {
+        Ok(bs) => write_bytes(&bs, buf, buf_len, written),
+        Err(_) => RADIUS_ERROR_ENCODE,
+    }
+}
+
+/// Returns the code of the packet, or 0 (i.e. invalid) for null.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet or null.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_get_code(packet: *const RadiusPacket) -> u8 {
+    packet
+        .as_ref()
+        .map_or(Code::Invalid as u8, |packet| packet.packet.get_code() as u8)
+}
+
+/// Returns the identifier of the packet, or 0 for null.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet or null.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_get_identifier(packet: *const RadiusPacket) -> u8 {
+    packet
+        .as_ref()
+        .map_or(0, |packet| packet.packet.get_identifier())
+}
+
+/// Set the identifier of the packet.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet or null.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_set_identifier(packet: *mut RadiusPacket, identifier: u8) {
+    if let Some(packet) = packet.as_mut() {
+        packet.packet.set_identifier(identifier);
+    }
+}
+
+/// Returns the pointer to the 16 bytes of the authenticator of the packet, or null for null.
+/// The pointer is valid while the packet is alive.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet or null.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_get_authenticator(packet: *const RadiusPacket) -> *const u8 {
+    packet.as_ref().map_or(ptr::null(), |packet| {
+        packet.packet.get_authenticator().as_ptr()
+    })
+}
+
+/// Returns the number of the attributes of the type in the packet.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet or null.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_count_attribute(
+    packet: *const RadiusPacket,
+    typ: AVPType,
+) -> usize {
+    packet.as_ref().map_or(0, |packet| packet.packet.count(typ))
+}
+
+/// Store the pointer to the raw value of the `index`-th attribute of the type into `value`, and the length into `value_len`.
+/// The pointer is valid until the packet is modified or released.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet, and `value` and `value_len` must be valid pointers.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_get_attribute(
+    packet: *const RadiusPacket,
+    typ: AVPType,
+    index: usize,
+    value: *mut *const u8,
+    value_len: *mut usize,
+) -> i32 {
+    let packet = match packet.as_ref() {
+        Some(packet) => &packet.packet,
+        None => return RADIUS_ERROR_NULL_POINTER,
+    };
+    if value.is_null() || value_len.is_null() {
+        return RADIUS_ERROR_NULL_POINTER;
+    }
+    match packet.lookup_all(typ).get(index) {
+        Some(avp) => {
+            *value = avp.raw_value().as_ptr();
+            *value_len = avp.raw_value().len();
+            RADIUS_OK
+        }
+        None => RADIUS_ERROR_NOT_FOUND,
+    }
+}
+
+/// Add an attribute of the type with the raw value to the packet; the value must not exceed 253 bytes.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet, and `value` must point to `value_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_add_attribute(
+    packet: *mut RadiusPacket,
+    typ: AVPType,
+    value: *const u8,
+    value_len: usize,
+) -> i32 {
+    let (packet, value) = match (packet.as_mut(), as_slice(value, value_len)) {
+        (Some(packet), Some(value)) => (&mut packet.packet, value),
+        _ => return RADIUS_ERROR_NULL_POINTER,
+    };
+    match AVP::from_raw(typ, value) {
+        Ok(avp) => {
+            packet.add(avp);
+            RADIUS_OK
+        }
+        Err(_) => RADIUS_ERROR_INVALID_ATTRIBUTE,
+    }
+}
+
+/// Delete all of the attributes of the type from the packet.
+///
+/// # Safety
+///
+/// `packet` must be a valid packet or null.
+#[no_mangle]
+pub unsafe extern "C" fn radius_packet_delete_attribute(packet: *mut RadiusPacket, typ: AVPType) {
+    if let Some(packet) = packet.as_mut() {
+        packet.packet.delete(typ);
+    }
+}
+
+/// Encrypt the password as the value of User-Password with the secret and the 16 bytes Request Authenticator.
+/// see also: https://tools.ietf.org/html/rfc2865#section-5.2
+///
+/// # Safety
+///
+/// `secret`, `authenticator` and `password` must point to `secret_len`, 16 and `password_len` bytes,
+/// `buf` must point to `buf_len` writable bytes, and `written` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn radius_user_password_encrypt(
+    secret: *const u8,
+    secret_len: usize,
+    authenticator: *const u8,
+    password: *const u8,
+    password_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written: *mut usize,
+) -> i32 {
+    let (secret, authenticator, password) = match (
+        as_slice(secret, secret_len),
+        as_slice(authenticator, 16),
+        as_slice(password, password_len),
+    ) {
+        (Some(secret), Some(authenticator), Some(password)) => (secret, authenticator, password),
+        _ => return RADIUS_ERROR_NULL_POINTER,
+    };
+    // the type of the attribute doesn't matter to the encryption
+    match AVP::from_user_password(0, password, secret, authenticator) {
+        Ok(avp) => write_bytes(avp.raw_value(), buf, buf_len, written),
+        Err(_) => RADIUS_ERROR_INVALID_ATTRIBUTE,
+    }
+}
+
+/// Decrypt the value of User-Password with the secret and the 16 bytes Request Authenticator;
+/// the padding of the password is removed.
+///
+/// # Safety
+///
+/// `secret`, `authenticator` and `value` must point to `secret_len`, 16 and `value_len` bytes,
+/// `buf` must point to `buf_len` writable bytes, and `written` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn radius_user_password_decrypt(
+    secret: *const u8,
+    secret_len: usize,
+    authenticator: *const u8,
+    value: *const u8,
+    value_len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written: *mut usize,
+) -> i32 {
+    let (secret, authenticator, value) = match (
+        as_slice(secret, secret_len),
+        as_slice(authenticator, 16),
+        as_slice(value, value_len),
+    ) {
+        (Some(secret), Some(authenticator), Some(value)) => (secret, authenticator, value),
+        _ => return RADIUS_ERROR_NULL_POINTER,
+    };
+    let avp = match AVP::from_raw(0, value) {
+        Ok(avp) => avp,
+        Err(_) => return RADIUS_ERROR_INVALID_ATTRIBUTE,
+    };
+    match avp.decode_user_password(secret, authenticator) {
+        Ok(password) => write_bytes(&password, buf, buf_len, written),
+        Err(_) => RADIUS_ERROR_INVALID_ATTRIBUTE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+    use std::ptr;
+
+    use radius_proto::code::Code;
+    use radius_proto::rfc2865;
+
+    use crate::*;
+
+    const SECRET: &[u8] = b"secret";
+
+    unsafe fn encode(packet: *const RadiusPacket) -> Vec<u8> {
+        let mut written = 0;
+        assert_eq!(
+            radius_packet_encode(packet, ptr::null_mut(), 0, &mut written),
+            RADIUS_ERROR_BUFFER_TOO_SMALL
+        );
+        let mut buf = vec![0; written];
+        assert_eq!(
+            radius_packet_encode(packet, buf.as_mut_ptr(), buf.len(), &mut written),
+            RADIUS_OK
+        );
+        buf
+    }
+
+    #[test]
+    fn test_round_trip() {
+        unsafe {
+            let packet =
+                radius_packet_new(Code::AccessRequest as u8, SECRET.as_ptr(), SECRET.len());
+            assert!(!packet.is_null());
+            radius_packet_set_identifier(packet, 42);
+            assert_eq!(
+                radius_packet_add_attribute(packet, rfc2865::USER_NAME_TYPE, b"alice".as_ptr(), 5),
+                RADIUS_OK
+            );
+            assert_eq!(
+                radius_packet_add_attribute(packet, rfc2865::CLASS_TYPE, [0; 254].as_ptr(), 254),
+                RADIUS_ERROR_INVALID_ATTRIBUTE
+            );
+            let bs = encode(packet);
+            radius_packet_free(packet);
+
+            let mut decoded = ptr::null_mut();
+            assert_eq!(
+                radius_packet_decode(
+                    bs.as_ptr(),
+                    bs.len(),
+                    SECRET.as_ptr(),
+                    SECRET.len(),
+                    &mut decoded
+                ),
+                RADIUS_OK
+            );
+            assert_eq!(radius_packet_get_code(decoded), Code::AccessRequest as u8);
+            assert_eq!(radius_packet_get_identifier(decoded), 42);
+            assert_eq!(
+                slice::from_raw_parts(radius_packet_get_authenticator(decoded), 16),
+                &bs[4..20]
+            );
+            assert_eq!(
+                radius_packet_count_attribute(decoded, rfc2865::USER_NAME_TYPE),
+                1
+            );
+
+            let mut value = ptr::null();
+            let mut value_len = 0;
+            assert_eq!(
+                radius_packet_get_attribute(
+                    decoded,
+                    rfc2865::USER_NAME_TYPE,
+                    0,
+                    &mut value,
+                    &mut value_len
+                ),
+                RADIUS_OK
+            );
+            assert_eq!(slice::from_raw_parts(value, value_len), b"alice");
+            assert_eq!(
+                radius_packet_get_attribute(
+                    decoded,
+                    rfc2865::USER_NAME_TYPE,
+                    1,
+                    &mut value,
+                    &mut value_len
+                ),
+                RADIUS_ERROR_NOT_FOUND
+            );
+
+            radius_packet_delete_attribute(decoded, rfc2865::USER_NAME_TYPE);
+            assert_eq!(
+                radius_packet_count_attribute(decoded, rfc2865::USER_NAME_TYPE),
+                0
+            );
+            radius_packet_free(decoded);
+
+            assert_eq!(
+                radius_packet_decode(bs.as_ptr(), 10, SECRET.as_ptr(), SECRET.len(), &mut decoded),
+                RADIUS_ERROR_DECODE
+            );
+            assert!(radius_packet_new(0, SECRET.as_ptr(), SECRET.len()).is_null());
+        }
+    }
+
+    #[test]
+    fn test_user_password() {
+        let authenticator = [7; 16];
+        let mut encrypted = [0; 128];
+        let mut decrypted = [0; 128];
+        let mut written = 0;
+        unsafe {
+            assert_eq!(
+                radius_user_password_encrypt(
+                    SECRET.as_ptr(),
+                    SECRET.len(),
+                    authenticator.as_ptr(),
+                    b"password".as_ptr(),
+                    8,
+                    encrypted.as_mut_ptr(),
+                    encrypted.len(),
+                    &mut written,
+                ),
+                RADIUS_OK
+            );
+            assert_eq!(written, 16);
+            assert_eq!(
+                radius_user_password_decrypt(
+                    SECRET.as_ptr(),
+                    SECRET.len(),
+                    authenticator.as_ptr(),
+                    encrypted.as_ptr(),
+                    16,
+                    decrypted.as_mut_ptr(),
+                    decrypted.len(),
+                    &mut written,
+                ),
+                RADIUS_OK
+            );
+            assert_eq!(&decrypted[..written], b"password");
+
+            assert_eq!(
+                radius_user_password_decrypt(
+                    SECRET.as_ptr(),
+                    SECRET.len(),
+                    authenticator.as_ptr(),
+                    encrypted.as_ptr(),
+                    15,
+                    decrypted.as_mut_ptr(),
+                    decrypted.len(),
+                    &mut written,
+                ),
+                RADIUS_ERROR_INVALID_ATTRIBUTE
+            );
+            assert_eq!(
+                CStr::from_ptr(radius_strerror(RADIUS_ERROR_INVALID_ATTRIBUTE)).to_str(),
+                Ok("invalid attribute")
+            );
+        }
+    }
+
+    #[test]
+    fn test_header_declares_all_functions() {
+        let header = include_str!("../include/radius.h");
+        let source = include_str!("lib.rs");
+        let functions = source
+            .lines()
+            .filter_map(|line| line.split("extern \"C\" fn ").nth(1))
+            .filter_map(|rest| rest.split('(').next())
+            .collect::<Vec<_>>();
+        assert!(functions.len() > 10);
+        for function in functions {
+            assert!(
+                header.contains(&format!("{function}(")),
+                "{function} is not declared in radius.h"
+            );
+        }
+        for (name, value) in [
+            ("RADIUS_OK", RADIUS_OK),
+            ("RADIUS_ERROR_NULL_POINTER", RADIUS_ERROR_NULL_POINTER),
+            ("RADIUS_ERROR_DECODE", RADIUS_ERROR_DECODE),
+            ("RADIUS_ERROR_ENCODE", RADIUS_ERROR_ENCODE),
+            (
+                "RADIUS_ERROR_INVALID_ATTRIBUTE",
+                RADIUS_ERROR_INVALID_ATTRIBUTE,
+            ),
+            (
+                "RADIUS_ERROR_BUFFER_TOO_SMALL",
+                RADIUS_ERROR_BUFFER_TOO_SMALL,
+            ),
+            ("RADIUS_ERROR_NOT_FOUND", RADIUS_ERROR_NOT_FOUND),
+        ] {
+            assert!(
+                header.contains(&format!("#define {name} ({value})")),
+                "{name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,519 @@
+//! Ascend binary filters, i.e. the `abinary` value of `Ascend-Data-Filter` and the like.
+//!
+//! `AscendIpFilter` builds and parses an IP filter rule, that is 32 octets on the wire,
+//! and converts that from/into the textual form of FreeRADIUS (e.g. `ip in forward dstip 192.0.2.0/24 tcp dstport = 80`).
+
+use std::fmt;
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::convert::{FromAvp, ToAvp};
+
+/// The length of an Ascend binary filter on the wire.
+pub const ASCEND_FILTER_LENGTH: usize = 32;
+
+const IP_FILTER_TYPE: u8 = 1;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AscendFilterError {
+    /// This error is raised when the length of the binary filter is not `ASCEND_FILTER_LENGTH`.
+    #[error(
+        "invalid ascend filter length: expected={ASCEND_FILTER_LENGTH} bytes, actual={0} bytes"
+    )]
+    InvalidLengthError(usize),
+
+    /// This error is raised when the binary filter is not an IP filter (e.g. a generic or IPX filter).
+    #[error("unsupported ascend filter type: {0}")]
+    UnsupportedFilterTypeError(u8),
+
+    /// This error is raised when a field of the binary filter has an invalid value.
+    #[error("invalid value of ascend filter field {0}: {1}")]
+    InvalidValueError(&'static str, u8),
+
+    /// This error is raised when the textual filter cannot be parsed.
+    #[error("invalid ascend filter syntax: {0}")]
+    SyntaxError(String),
+}
+
+/// This enum represents whether the filter applies to the inbound or the outbound packets.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AscendFilterDirection {
+    Out,
+    In,
+}
+
+/// This enum represents whether the packets that match the filter are forwarded or dropped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AscendFilterAction {
+    Drop,
+    Forward,
+}
+
+/// This enum represents how a port of the packet is compared with the port of the filter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PortComparison {
+    Less,
+    Equal,
+    Greater,
+    NotEqual,
+}
+
+impl PortComparison {
+    fn to_octet(self) -> u8 {
+        match self {
+            PortComparison::Less => 1,
+            PortComparison::Equal => 2,
+            PortComparison::Greater => 3,
+            PortComparison::NotEqual => 4,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            PortComparison::Less => "<",
+            PortComparison::Equal => "=",
+            PortComparison::Greater => ">",
+            PortComparison::NotEqual => "!=",
+        }
+    }
+}
+
+/// This struct represents an Ascend IP filter rule.
+///
+/// The filter matches all of the packets of the direction until the conditions are set by the setters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AscendIpFilter {
+    direction: AscendFilterDirection,
+    action: AscendFilterAction,
+    source: Option<(Ipv4Addr, u8)>,
+    destination: Option<(Ipv4Addr, u8)>,
+    protocol: u8,
+    source_port: Option<(PortComparison, u16)>,
+    destination_port: Option<(PortComparison, u16)>,
+    established: bool,
+}
+
+impl AscendIpFilter {
+    pub fn new(direction: AscendFilterDirection, action: AscendFilterAction) -> Self {
+        AscendIpFilter {
+            direction,
+            action,
+            source: None,
+            destination: None,
+            protocol: 0,
+            source_port: None,
+            destination_port: None,
+            established: false,
+        }
+    }
+
+    pub fn get_direction(&self) -> AscendFilterDirection {
+        self.direction
+    }
+
+    pub fn get_action(&self) -> AscendFilterAction {
+        self.action
+    }
+
+    /// Returns the source network as the address and the prefix length.
+    pub fn get_source(&self) -> Option<(Ipv4Addr, u8)> {
+        self.source
+    }
+
+    /// Set the source network; the prefix length is capped at 32.
+    pub fn set_source(&mut self, address: Ipv4Addr, prefix_length: u8) {
+        self.source = Some((address, prefix_length.min(32)));
+    }
+
+    /// Returns the destination network as the address and the prefix length.
+    pub fn get_destination(&self) -> Option<(Ipv4Addr, u8)> {
+        self.destination
+    }
+
+    /// Set the destination network; the prefix length is capped at 32.
+    pub fn set_destination(&mut self, address: Ipv4Addr, prefix_length: u8) {
+        self.destination = Some((address, prefix_length.min(32)));
+    }
+
+    /// Returns the IP protocol number; zero means any protocol.
+    pub fn get_protocol(&self) -> u8 {
+        self.protocol
+    }
+
+    /// Set the IP protocol number (e.g. 6 for TCP, 17 for UDP); zero means any protocol.
+    pub fn set_protocol(&mut self, protocol: u8) {
+        self.protocol = protocol;
+    }
+
+    pub fn get_source_port(&self) -> Option<(PortComparison, u16)> {
+        self.source_port
+    }
+
+    pub fn set_source_port(&mut self, comparison: PortComparison, port: u16) {
+        self.source_port = Some((comparison, port));
+    }
+
+    pub fn get_destination_port(&self) -> Option<(PortComparison, u16)> {
+        self.destination_port
+    }
+
+    pub fn set_destination_port(&mut self, comparison: PortComparison, port: u16) {
+        self.destination_port = Some((comparison, port));
+    }
+
+    /// Returns whether the filter matches only the established TCP connections.
+    pub fn is_established(&self) -> bool {
+        self.established
+    }
+
+    pub fn set_established(&mut self, established: bool) {
+        self.established = established;
+    }
+
+    /// Encode the filter into the binary form, that is `ASCEND_FILTER_LENGTH` octets.
+    pub fn encode(&self) -> Vec<u8> {
+        let (source, source_prefix_length) = self.source.unwrap_or((Ipv4Addr::UNSPECIFIED, 0));
+        let (destination, destination_prefix_length) =
+            self.destination.unwrap_or((Ipv4Addr::UNSPECIFIED, 0));
+        let (source_comparison, source_port) = split_port(self.source_port);
+        let (destination_comparison, destination_port) = split_port(self.destination_port);
+
+        let mut bs = Vec::with_capacity(ASCEND_FILTER_LENGTH);
+        bs.push(IP_FILTER_TYPE);
+        bs.push(match self.action {
+            AscendFilterAction::Drop => 0,
+            AscendFilterAction::Forward => 1,
+        });
+        bs.push(match self.direction {
+            AscendFilterDirection::Out => 0,
+            AscendFilterDirection::In => 1,
+        });
+        bs.push(0); // fill
+        bs.extend_from_slice(&source.octets());
+        bs.extend_from_slice(&destination.octets());
+        bs.push(source_prefix_length);
+        bs.push(destination_prefix_length);
+        bs.push(self.protocol);
+        bs.push(self.established as u8);
+        bs.extend_from_slice(&source_port.to_be_bytes());
+        bs.extend_from_slice(&destination_port.to_be_bytes());
+        bs.push(source_comparison);
+        bs.push(destination_comparison);
+        bs.resize(ASCEND_FILTER_LENGTH, 0); // fill
+        bs
+    }
+
+    /// Decode the binary form of an IP filter.
+    pub fn decode(bs: &[u8]) -> Result<Self, AscendFilterError> {
+        if bs.len() != ASCEND_FILTER_LENGTH {
+            return Err(AscendFilterError::InvalidLengthError(bs.len()));
+        }
+        if bs[0] != IP_FILTER_TYPE {
+            return Err(AscendFilterError::UnsupportedFilterTypeError(bs[0]));
+        }
+
+        let action = match bs[1] {
+            0 => AscendFilterAction::Drop,
+            1 => AscendFilterAction::Forward,
+            v => return Err(AscendFilterError::InvalidValueError("forward", v)),
+        };
+        let direction = match bs[2] {
+            0 => AscendFilterDirection::Out,
+            1 => AscendFilterDirection::In,
+            v => return Err(AscendFilterError::InvalidValueError("direction", v)),
+        };
+        let network = |address: &[u8], prefix_length: u8, field: &'static str| {
+            let address = Ipv4Addr::new(address[0], address[1], address[2], address[3]);
+            match prefix_length {
+                0 if address.is_unspecified() => Ok(None),
+                0..=32 => Ok(Some((address, prefix_length))),
+                _ => Err(AscendFilterError::InvalidValueError(field, prefix_length)),
+            }
+        };
+        let port = |comparison: u8, port: &[u8], field: &'static str| {
+            let port = u16::from_be_bytes([port[0], port[1]]);
+            Ok(Some(match comparison {
+                0 => return Ok(None),
+                1 => (PortComparison::Less, port),
+                2 => (PortComparison::Equal, port),
+                3 => (PortComparison::Greater, port),
+                4 => (PortComparison::NotEqual, port),
+                _ => return Err(AscendFilterError::InvalidValueError(field, comparison)),
+            }))
+        };
+
+        Ok(AscendIpFilter {
+            direction,
+            action,
+            source: network(&bs[4..8], bs[12], "srcmask")?,
+            destination: network(&bs[8..12], bs[13], "dstmask")?,
+            protocol: bs[14],
+            established: bs[15] != 0,
+            source_port: port(bs[20], &bs[16..18], "srcPortComp")?,
+            destination_port: port(bs[21], &bs[18..20], "dstPortComp")?,
+        })
+    }
+}
+
+fn split_port(port: Option<(PortComparison, u16)>) -> (u8, u16) {
+    match port {
+        Some((comparison, port)) => (comparison.to_octet(), port),
+        None => (0, 0),
+    }
+}
+
+/// The textual form of FreeRADIUS, e.g. `ip in forward srcip 10.0.0.0/8 dstip 192.0.2.1/32 tcp srcport > 1023 dstport = 80 est`.
+impl fmt::Display for AscendIpFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.direction {
+            AscendFilterDirection::In => "ip in",
+            AscendFilterDirection::Out => "ip out",
+        })?;
+        f.write_str(match self.action {
+            AscendFilterAction::Forward => " forward",
+            AscendFilterAction::Drop => " drop",
+        })?;
+        if let Some((address, prefix_length)) = self.source {
+            write!(f, " srcip {address}/{prefix_length}")?;
+        }
+        if let Some((address, prefix_length)) = self.destination {
+            write!(f, " dstip {address}/{prefix_length}")?;
+        }
+        match protocol_name(self.protocol) {
+            _ if self.protocol == 0 => {}
+            Some(name) => write!(f, " {name}")?,
+            None => write!(f, " {}", self.protocol)?,
+        }
+        if let Some((comparison, port)) = self.source_port {
+            write!(f, " srcport {} {port}", comparison.as_str())?;
+        }
+        if let Some((comparison, port)) = self.destination_port {
+            write!(f, " dstport {} {port}", comparison.as_str())?;
+        }
+        if self.established {
+            f.write_str(" est")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AscendIpFilter {
+    type Err = AscendFilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let syntax_error = |message: &str| AscendFilterError::SyntaxError(message.to_owned());
+
+        let mut tokens = s.split_whitespace();
+        if tokens.next() != Some("ip") {
+            return Err(syntax_error("only the ip filter is supported"));
+        }
+        let direction = match tokens.next() {
+            Some("in") => AscendFilterDirection::In,
+            Some("out") => AscendFilterDirection::Out,
+            _ => return Err(syntax_error("the direction must be in or out")),
+        };
+        let action = match tokens.next() {
+            Some("forward") => AscendFilterAction::Forward,
+            Some("drop") => AscendFilterAction::Drop,
+            _ => return Err(syntax_error("the action must be forward or drop")),
+        };
+
+        let mut filter = AscendIpFilter::new(direction, action);
+        while let Some(token) = tokens.next() {
+            match token {
+                "srcip" | "dstip" => {
+                    let network = tokens
+                        .next()
+                        .and_then(parse_network)
+                        .ok_or_else(|| syntax_error(&format!("invalid network of {token}")))?;
+                    match token {
+                        "srcip" => filter.source = Some(network),
+                        _ => filter.destination = Some(network),
+                    }
+                }
+                "srcport" | "dstport" => {
+                    let comparison = match tokens.next() {
+                        Some("<") => PortComparison::Less,
+                        Some("=") => PortComparison::Equal,
+                        Some(">") => PortComparison::Greater,
+                        Some("!=") => PortComparison::NotEqual,
+                        _ => return Err(syntax_error(&format!("invalid comparison of {token}"))),
+                    };
+                    let port = tokens
+                        .next()
+                        .and_then(|port| port.parse().ok())
+                        .ok_or_else(|| syntax_error(&format!("invalid port of {token}")))?;
+                    match token {
+                        "srcport" => filter.source_port = Some((comparison, port)),
+                        _ => filter.destination_port = Some((comparison, port)),
+                    }
+                }
+                "est" => filter.established = true,
+                protocol => {
+                    filter.protocol = protocol_number(protocol)
+                        .or_else(|| protocol.parse().ok())
+                        .ok_or_else(|| syntax_error(&format!("unknown token: {protocol}")))?;
+                }
+            }
+        }
+        Ok(filter)
+    }
+}
+
+fn parse_network(network: &str) -> Option<(Ipv4Addr, u8)> {
+    let (address, prefix_length) = match network.split_once('/') {
+        Some((address, prefix_length)) => (address, prefix_length.parse().ok()?),
+        None => (network, 32),
+    };
+    if prefix_length > 32 {
+        return None;
+    }
+    Some((address.parse().ok()?, prefix_length))
+}
+
+const PROTOCOLS: &[(&str, u8)] = &[("icmp", 1), ("tcp", 6), ("udp", 17), ("ospf", 89)];
+
+fn protocol_name(protocol: u8) -> Option<&'static str> {
+    PROTOCOLS
+        .iter()
+        .find(|(_, number)| *number == protocol)
+        .map(|(name, _)| *name)
+}
+
+fn protocol_number(name: &str) -> Option<u8> {
+    PROTOCOLS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, number)| *number)
+}
+
+impl FromAvp for AscendIpFilter {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        AscendIpFilter::decode(&avp.decode_abinary())
+            .map_err(|e| AVPError::DecodingError(e.to_string()))
+    }
+}
+
+impl ToAvp for AscendIpFilter {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        AVP::from_abinary(typ, &self.encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::ascend::{
+        AscendFilterAction, AscendFilterDirection, AscendFilterError, AscendIpFilter,
+        PortComparison, ASCEND_FILTER_LENGTH,
+    };
+    use crate::avp::AVP;
+    use crate::convert::{FromAvp, ToAvp};
+
+    #[test]
+    fn test_encode_and_decode() {
+        let mut filter =
+            AscendIpFilter::new(AscendFilterDirection::In, AscendFilterAction::Forward);
+        filter.set_source(Ipv4Addr::new(10, 0, 0, 0), 8);
+        filter.set_destination(Ipv4Addr::new(192, 0, 2, 1), 32);
+        filter.set_protocol(6);
+        filter.set_source_port(PortComparison::Greater, 1023);
+        filter.set_destination_port(PortComparison::Equal, 80);
+        filter.set_established(true);
+
+        let encoded = filter.encode();
+        assert_eq!(
+            encoded,
+            vec![
+                0x01, 0x01, 0x01, 0x00, // type, forward, direction, fill
+                10, 0, 0, 0, // srcip
+                192, 0, 2, 1, // dstip
+                8, 32, 6, 1, // srcmask, dstmask, proto, established
+                0x03, 0xff, 0x00, 0x50, // srcport, dstport
+                3, 2, // srcPortComp, dstPortComp
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // fill
+            ]
+        );
+        assert_eq!(AscendIpFilter::decode(&encoded), Ok(filter.clone()));
+
+        let avp = filter.to_avp(242).unwrap();
+        assert_eq!(avp.raw_value().len(), ASCEND_FILTER_LENGTH);
+        assert_eq!(AscendIpFilter::from_avp(&avp), Ok(filter));
+    }
+
+    #[test]
+    fn test_decode_invalid_filter() {
+        assert_eq!(
+            AscendIpFilter::decode(&[0x01; 16]),
+            Err(AscendFilterError::InvalidLengthError(16))
+        );
+
+        let mut generic = vec![0x00; ASCEND_FILTER_LENGTH];
+        assert_eq!(
+            AscendIpFilter::decode(&generic),
+            Err(AscendFilterError::UnsupportedFilterTypeError(0))
+        );
+
+        generic[0] = 0x01;
+        generic[2] = 0x02;
+        assert_eq!(
+            AscendIpFilter::decode(&generic),
+            Err(AscendFilterError::InvalidValueError("direction", 2))
+        );
+
+        let avp = AVP::from_abinary(242, &[0x01; 16]).unwrap();
+        assert!(AscendIpFilter::from_avp(&avp).is_err());
+    }
+
+    #[test]
+    fn test_textual_form() {
+        let text =
+            "ip in forward srcip 10.0.0.0/8 dstip 192.0.2.1/32 tcp srcport > 1023 dstport = 80 est";
+        let filter = text.parse::<AscendIpFilter>().unwrap();
+        assert_eq!(filter.get_source(), Some((Ipv4Addr::new(10, 0, 0, 0), 8)));
+        assert_eq!(filter.get_protocol(), 6);
+        assert_eq!(
+            filter.get_destination_port(),
+            Some((PortComparison::Equal, 80))
+        );
+        assert!(filter.is_established());
+        assert_eq!(filter.to_string(), text);
+
+        let filter = "ip out drop dstip 192.0.2.1 47"
+            .parse::<AscendIpFilter>()
+            .unwrap();
+        assert_eq!(filter.get_direction(), AscendFilterDirection::Out);
+        assert_eq!(filter.get_action(), AscendFilterAction::Drop);
+        assert_eq!(
+            filter.get_destination(),
+            Some((Ipv4Addr::new(192, 0, 2, 1), 32))
+        );
+        assert_eq!(filter.to_string(), "ip out drop dstip 192.0.2.1/32 47");
+
+        let filter = "ip in forward".parse::<AscendIpFilter>().unwrap();
+        assert_eq!(
+            AscendIpFilter::decode(&filter.encode())
+                .unwrap()
+                .to_string(),
+            "ip in forward"
+        );
+
+        for invalid in [
+            "ipx in forward",
+            "ip sideways forward",
+            "ip in forward srcip 10.0.0.0/33",
+            "ip in forward dstport ~ 80",
+            "ip in forward dstport = http",
+            "ip in forward sctp",
+        ] {
+            assert!(matches!(
+                invalid.parse::<AscendIpFilter>(),
+                Err(AscendFilterError::SyntaxError(_))
+            ));
+        }
+    }
+}
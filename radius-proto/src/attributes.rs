@@ -1,4 +1,4 @@
-use crate::core::avp::{AVPType, AVP};
+use crate::avp::{AVPType, AVP, MAX_VALUE_LENGTH};
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Attributes(pub(crate) Vec<AVP>);
@@ -6,7 +6,7 @@ pub(crate) struct Attributes(pub(crate) Vec<AVP>);
 impl Attributes {
     pub(crate) fn decode(bs: &[u8]) -> Result<Attributes, String> {
         let mut i = 0;
-        let mut attrs = Vec::new();
+        let mut attrs = Vec::with_capacity(Self::count_hint(bs));
 
         while bs.len() > i {
             if bs[i..].len() < 2 {
@@ -33,11 +33,23 @@ impl Attributes {
         Ok(Attributes(attrs))
     }
 
+    /// Returns the number of the attributes by walking the length octets, to allocate the list at once.
+    fn count_hint(bs: &[u8]) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i + 1 < bs.len() && bs[i + 1] >= 2 {
+            count += 1;
+            i += bs[i + 1] as usize;
+        }
+        count
+    }
+
     pub(crate) fn add(&mut self, avp: AVP) {
         self.0.push(avp)
     }
 
     pub(crate) fn extend(&mut self, avps: Vec<AVP>) {
+        self.0.reserve(avps.len());
         self.0.extend(avps)
     }
 
@@ -53,12 +65,16 @@ impl Attributes {
         self.0.iter().filter(|&avp| avp.typ == typ).collect()
     }
 
+    pub(crate) fn count(&self, typ: AVPType) -> usize {
+        self.0.iter().filter(|&avp| avp.typ == typ).count()
+    }
+
     pub(crate) fn encode(&self) -> Result<Vec<u8>, String> {
         let mut encoded: Vec<u8> = Vec::new();
 
         for avp in &self.0 {
             let attr_len = avp.value.len();
-            if attr_len > 253 {
+            if attr_len > MAX_VALUE_LENGTH {
                 return Err("attribute is too large".to_owned());
             }
             encoded.push(avp.typ);
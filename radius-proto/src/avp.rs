@@ -0,0 +1,1889 @@
+use std::convert::TryInto;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use thiserror::Error;
+
+use crate::random::{self, RandomSource, ThreadRandomSource};
+use crate::tag::{Tag, UNUSED_TAG_VALUE};
+use crate::vsa::{Vendor, VendorType, VENDOR_SPECIFIC_TYPE};
+
+#[derive(Error, PartialEq, Debug)]
+pub enum AVPError {
+    /// This error is raised on the length of given plain text for user-password exceeds the maximum limit.
+    #[error("the maximum length of the plain text for user-password is 128, but the given value has {0} bytes")]
+    UserPasswordPlainTextMaximumLengthExceededError(usize),
+
+    /// This error is raised when the given secret value for a password is empty.
+    #[error("secret for password mustn't be empty, but the given value is empty")]
+    PasswordSecretMissingError(),
+
+    /// This error is raised when the given request-authenticator for the password doesn't have 16 bytes length exactly.
+    #[error("request authenticator for password has to have 16-bytes payload, but the given value doesn't")]
+    InvalidRequestAuthenticatorLength(),
+
+    /// This error is raised when attribute length is conflicted with the expected.
+    #[error("invalid attribute length: expected={0}, actual={1} bytes")]
+    InvalidAttributeLengthError(String, usize),
+
+    /// This error is raised when the tagged-value doesn't have a tag byte.
+    #[error("tag value is missing")]
+    TagMissingError(),
+
+    /// This error represents AVP decoding error.
+    #[error("decoding error: {0}")]
+    DecodingError(String),
+
+    /// This error is raised when the MSB of salt is invalid.
+    #[error("invalid salt. the MSB has to be 1, but given value isn't: {0}")]
+    InvalidSaltMSBError(u8),
+
+    /// This error is raised when a tag is invalid for the tagged-staring value.
+    #[error("invalid tag for string value. this must not be zero")]
+    InvalidTagForStringValueError(),
+
+    /// This error is raised when a tag is invalid for the tagged-integer value.
+    #[error("invalid tag for integer value. this must be less than or equal 0x1f")]
+    InvalidTagForIntegerValueError(),
+
+    /// This error is raised when a tag is invalid for the tagged fixed-length value (e.g. tagged ipaddr, tagged date).
+    #[error("invalid tag for fixed-length value. this must be less than or equal 0x1f")]
+    InvalidTagForFixedLengthValueError(),
+
+    /// This error is raised when the given datetime cannot be represented as a 32-bit unsigned UNIX time.
+    #[error("datetime is out of range for date attribute; it must be between 1970-01-01T00:00:00Z and 2106-02-07T06:28:15Z, but the given timestamp is {0}")]
+    DateOutOfRangeError(i64),
+
+    /// This error is raised when the given duration cannot be represented as the 32-bit unsigned seconds.
+    #[error("duration is out of range for the integer attribute; it must be less than or equal {} seconds, but the given duration is {0} seconds", u32::MAX)]
+    DurationOutOfRangeError(u64),
+
+    /// This error is raised when a mandatory attribute is missing in a packet.
+    #[error("attribute is missing; type = {0}")]
+    MissingAttributeError(AVPType),
+
+    /// This error is raised when the value is interpreted by the data type that this crate doesn't support (e.g. `tlv`);
+    /// such a value can be handled only as the raw octets.
+    #[error("the data type of `{0}` is not supported; it can be handled only as the raw octets")]
+    UnsupportedDataTypeError(String),
+
+    /// This error is raised when the vendor type doesn't fit in the type field of the vendor format (e.g. 256 for `format=1,1`).
+    #[error("the vendor type {0} doesn't fit in the type field of the vendor format")]
+    InvalidVendorTypeError(u32),
+
+    /// This error is raised when the given attribute name is not defined in the dictionaries.
+    #[error("unknown attribute name: {0}")]
+    UnknownAttributeNameError(String),
+
+    /// This error is raised when a value of the named attribute cannot be decoded.
+    /// It wraps the underlying error with the attribute name to make it diagnosable.
+    #[error("failed to decode `{attribute}` attribute: {source}")]
+    AttributeDecodingError {
+        attribute: &'static str,
+        source: Box<AVPError>,
+    },
+}
+
+impl AVPError {
+    /// (This method is for dictionary developers) wrap an error with the name of the attribute that has caused it.
+    pub fn with_attribute_name(self, attribute: &'static str) -> Self {
+        AVPError::AttributeDecodingError {
+            attribute,
+            source: Box::new(self),
+        }
+    }
+}
+
+pub type AVPType = u8;
+
+/// This enum represents a policy to disambiguate the era of a date attribute value.
+///
+/// A date attribute value is a 32-bit unsigned UNIX time, so it wraps around at 2106-02-07T06:28:16Z.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DateEraPolicy {
+    /// Interprets the value as-is, i.e. between 1970-01-01T00:00:00Z and 2106-02-07T06:28:15Z.
+    Unsigned,
+    /// Picks the era that makes the value closest to the given datetime (e.g. the current time).
+    NearestTo(DateTime<Utc>),
+}
+
+pub const TYPE_INVALID: AVPType = 255;
+
+/// The maximum length of the value of an attribute; i.e. 255 bytes of the attribute minus the type and length octets.
+pub const MAX_VALUE_LENGTH: usize = 253;
+
+/// The type codes of the Long Extended Type attributes; see also: https://tools.ietf.org/html/rfc6929#section-2.2
+pub const LONG_EXTENDED_TYPES: [AVPType; 2] = [245, 246];
+
+/// The maximum length of the data of a Long Extended Type attribute fragment; i.e. `MAX_VALUE_LENGTH` minus
+/// the Extended-Type and the Flags octets.
+pub const MAX_LONG_EXTENDED_FRAGMENT_LENGTH: usize = MAX_VALUE_LENGTH - 2;
+
+const LONG_EXTENDED_MORE_FLAG: u8 = 0x80;
+
+/// This enum represents a policy for a value that exceeds `MAX_VALUE_LENGTH` (i.e. a jumbo attribute).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum JumboAttributePolicy {
+    /// Rejects the value with `AVPError::InvalidAttributeLengthError`.
+    #[default]
+    Reject,
+    /// Splits the value into the consecutive attributes of the same type, as `EAP-Message` does;
+    /// the values of those are concatenated on lookup.
+    Concat,
+    /// Splits the value into the Long Extended Type attributes (i.e. type 245 or 246) with the More flag.
+    /// The first byte of the value is the Extended-Type, and the rest is the data that is fragmented.
+    /// see also: https://tools.ietf.org/html/rfc6929#section-2.2
+    LongExtended,
+}
+
+impl JumboAttributePolicy {
+    /// Make the AVPs of the value according to this policy; a value that fits in an attribute makes a single AVP.
+    pub fn split(&self, typ: AVPType, value: &[u8]) -> Result<Vec<AVP>, AVPError> {
+        match self {
+            JumboAttributePolicy::Reject => Ok(vec![AVP::from_bytes(typ, value)?]),
+            JumboAttributePolicy::Concat => value
+                .chunks(MAX_VALUE_LENGTH)
+                .map(|chunk| AVP::from_bytes(typ, chunk))
+                .collect(),
+            JumboAttributePolicy::LongExtended => {
+                if !LONG_EXTENDED_TYPES.contains(&typ) {
+                    return Err(AVPError::DecodingError(format!(
+                        "type {} is not a Long Extended Type",
+                        typ
+                    )));
+                }
+                let (extended_type, data) = value.split_first().ok_or_else(|| {
+                    AVPError::InvalidAttributeLengthError(">= 1 byte".to_owned(), 0)
+                })?;
+                let mut chunks = data.chunks(MAX_LONG_EXTENDED_FRAGMENT_LENGTH).peekable();
+                let mut avps = Vec::new();
+                // an empty data is sent as a fragment without the data
+                if chunks.peek().is_none() {
+                    return Ok(vec![AVP::from_bytes(typ, &[*extended_type, 0])?]);
+                }
+                while let Some(chunk) = chunks.next() {
+                    let flags = match chunks.peek() {
+                        Some(_) => LONG_EXTENDED_MORE_FLAG,
+                        None => 0,
+                    };
+                    avps.push(AVP::from_bytes(
+                        typ,
+                        &[&[*extended_type, flags][..], chunk].concat(),
+                    )?);
+                }
+                Ok(avps)
+            }
+        }
+    }
+
+    /// Reassemble the value from the AVPs of a type (e.g. `Packet::lookup_all()`) according to this policy.
+    ///
+    /// `Reject` returns the value of the first AVP. `LongExtended` joins the fragments of the Extended-Type of the first AVP
+    /// up to the one without the More flag; it fails if the last fragment has the More flag.
+    pub fn join(&self, avps: &[&AVP]) -> Option<Result<Vec<u8>, AVPError>> {
+        let first = avps.first()?;
+        match self {
+            JumboAttributePolicy::Reject => Some(Ok(first.value.clone())),
+            JumboAttributePolicy::Concat => Some(Ok(avps
+                .iter()
+                .flat_map(|avp| avp.value.iter().copied())
+                .collect())),
+            JumboAttributePolicy::LongExtended => {
+                let extended_type = match first.value.first() {
+                    Some(extended_type) => *extended_type,
+                    None => {
+                        return Some(Err(AVPError::InvalidAttributeLengthError(
+                            ">= 2 bytes".to_owned(),
+                            0,
+                        )))
+                    }
+                };
+                let mut value = vec![extended_type];
+                for avp in avps
+                    .iter()
+                    .filter(|avp| avp.value.first() == Some(&extended_type))
+                {
+                    let flags = match avp.value.get(1) {
+                        Some(flags) => *flags,
+                        None => {
+                            return Some(Err(AVPError::InvalidAttributeLengthError(
+                                ">= 2 bytes".to_owned(),
+                                avp.value.len(),
+                            )))
+                        }
+                    };
+                    value.extend_from_slice(&avp.value[2..]);
+                    if flags & LONG_EXTENDED_MORE_FLAG == 0 {
+                        return Some(Ok(value));
+                    }
+                }
+                Some(Err(AVPError::DecodingError(
+                    "the last fragment of the Long Extended Type attribute has the More flag"
+                        .to_owned(),
+                )))
+            }
+        }
+    }
+}
+
+/// This struct represents a attribute-value pair.
+///
+/// `Debug` and `Display` of this mask the value of the sensitive attribute; see also `Redact`.
+#[derive(Clone, PartialEq)]
+pub struct AVP {
+    pub(crate) typ: AVPType,
+    pub(crate) value: Vec<u8>,
+}
+
+impl AVP {
+    /// Make an AVP from the raw parts; the type code and the value as it is on the wire
+    /// (i.e. without the type and length octets, and already encrypted if that is needed).
+    ///
+    /// This is for the attributes that the dictionary modules don't cover.
+    /// The value must be up to `MAX_VALUE_LENGTH` bytes.
+    pub fn from_raw(typ: AVPType, value: &[u8]) -> Result<Self, AVPError> {
+        check_value_length(value)?;
+        Ok(AVP {
+            typ,
+            value: value.to_vec(),
+        })
+    }
+
+    /// Returns the type code of the attribute.
+    pub fn type_code(&self) -> AVPType {
+        self.typ
+    }
+
+    /// Returns the value of the attribute as it is on the wire, without decoding (e.g. decrypting) that.
+    pub fn raw_value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a duration value as the seconds (e.g. `Session-Timeout`).
+    ///
+    /// The fractional part of a second is truncated, and this raises an error if the seconds exceed `u32::MAX`.
+    pub fn from_duration(typ: AVPType, duration: Duration) -> Result<Self, AVPError> {
+        let seconds = duration.as_secs();
+        match seconds.try_into() {
+            Ok(seconds) => Ok(AVP::from_u32(typ, seconds)),
+            Err(_) => Err(AVPError::DurationOutOfRangeError(seconds)),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a u32 value.
+    pub fn from_u32(typ: AVPType, value: u32) -> Self {
+        AVP {
+            typ,
+            value: u32_to_network_bytes(value),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a signed i32 value.
+    pub fn from_i32(typ: AVPType, value: i32) -> Self {
+        // the two's complement representation, that is the same bits as the u32
+        AVP::from_u32(typ, value as u32)
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a u16 value.
+    pub fn from_u16(typ: AVPType, value: u16) -> Self {
+        AVP {
+            typ,
+            value: u16_to_network_bytes(value),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a tagged u32 value.
+    pub fn from_tagged_u32(typ: AVPType, tag: Option<&Tag>, value: u32) -> Self {
+        let tag = match tag {
+            None => &Tag {
+                value: UNUSED_TAG_VALUE,
+            },
+            Some(tag) => tag,
+        };
+
+        AVP {
+            typ,
+            value: [vec![tag.value], u32_to_network_bytes(value)].concat(),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a tagged u16 value.
+    pub fn from_tagged_u16(typ: AVPType, tag: Option<&Tag>, value: u16) -> Self {
+        AVP::from_u16(typ, value).with_tag(tag)
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a string value.
+    pub fn from_string(typ: AVPType, value: &str) -> Self {
+        AVP {
+            typ,
+            value: value.as_bytes().to_vec(),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from an owned string value; this takes the buffer without copying.
+    pub fn from_string_owned(typ: AVPType, value: String) -> Self {
+        AVP {
+            typ,
+            value: value.into_bytes(),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a tagged string value.
+    pub fn from_tagged_string(typ: AVPType, tag: Option<&Tag>, value: &str) -> Self {
+        match tag {
+            None => AVP {
+                typ,
+                value: value.as_bytes().to_vec(),
+            },
+            Some(tag) => {
+                let mut tagged = Vec::with_capacity(1 + value.len());
+                tagged.push(tag.value);
+                tagged.extend_from_slice(value.as_bytes());
+                AVP { typ, value: tagged }
+            }
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from bytes.
+    ///
+    /// It returns an error if the value exceeds `MAX_VALUE_LENGTH`; see also `JumboAttributePolicy` for such a value.
+    pub fn from_bytes(typ: AVPType, value: &[u8]) -> Result<Self, AVPError> {
+        check_value_length(value)?;
+        Ok(AVP {
+            typ,
+            value: value.to_vec(),
+        })
+    }
+
+    /// (This method is for dictionary developers) make an AVP from tagged bytes.
+    ///
+    /// Like the tagged string, the tag octet is omitted if the tag is `None`.
+    /// It returns an error if the value (including the tag octet) exceeds `MAX_VALUE_LENGTH`.
+    pub fn from_tagged_bytes(
+        typ: AVPType,
+        tag: Option<&Tag>,
+        value: &[u8],
+    ) -> Result<Self, AVPError> {
+        match tag {
+            None => AVP::from_bytes(typ, value),
+            Some(tag) => {
+                let mut tagged = Vec::with_capacity(1 + value.len());
+                tagged.push(tag.value);
+                tagged.extend_from_slice(value);
+                AVP::from_bytes_owned(typ, tagged)
+            }
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from owned bytes; this takes the buffer without copying.
+    ///
+    /// It returns an error if the value exceeds `MAX_VALUE_LENGTH`.
+    pub fn from_bytes_owned(typ: AVPType, value: Vec<u8>) -> Result<Self, AVPError> {
+        check_value_length(&value)?;
+        Ok(AVP { typ, value })
+    }
+
+    /// (This method is for dictionary developers) make a `Vendor-Specific` AVP that has a sub-attribute of the vendor.
+    ///
+    /// It returns an error if the vendor type doesn't fit in the type field of the vendor format,
+    /// or the sub-attribute doesn't fit in an AVP; see `Packet::add_vsa()` to split a value into the fragments
+    /// for the format that has the continuation octet.
+    pub fn from_vsa(
+        vendor: &Vendor,
+        vendor_type: VendorType,
+        value: &[u8],
+    ) -> Result<Self, AVPError> {
+        let max_value_length = vendor.get_format().max_value_length();
+        if value.len() > max_value_length {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("<= {max_value_length} bytes"),
+                value.len(),
+            ));
+        }
+        let mut vsas = vendor.encode_checked(vendor_type, value)?;
+        AVP::from_bytes_owned(VENDOR_SPECIFIC_TYPE, vsas.remove(0))
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a IPv4 value.
+    pub fn from_ipv4(typ: AVPType, value: &Ipv4Addr) -> Self {
+        AVP {
+            typ,
+            value: value.octets().to_vec(),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a tagged IPv4 value.
+    pub fn from_tagged_ipv4(typ: AVPType, tag: Option<&Tag>, value: &Ipv4Addr) -> Self {
+        AVP::from_ipv4(typ, value).with_tag(tag)
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a IPv4-prefix value.
+    pub fn from_ipv4_prefix(typ: AVPType, prefix: &[u8]) -> Result<Self, AVPError> {
+        let prefix_len = prefix.len();
+        if prefix_len != 4 {
+            return Err(AVPError::InvalidAttributeLengthError(
+                "4 bytes".to_owned(),
+                prefix_len,
+            ));
+        }
+
+        Ok(AVP {
+            typ,
+            // the prefix length is in bits; see https://tools.ietf.org/html/rfc8044#section-3.11
+            value: [vec![0x00, (prefix_len * 8) as u8], prefix.to_vec()].concat::<u8>(),
+        })
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a IPv6 value.
+    pub fn from_ipv6(typ: AVPType, value: &Ipv6Addr) -> Self {
+        AVP {
+            typ,
+            value: value.octets().to_vec(),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a IPv6-prefix value.
+    pub fn from_ipv6_prefix(typ: AVPType, prefix: &[u8]) -> Result<Self, AVPError> {
+        let prefix_len = prefix.len();
+        if prefix_len > 16 {
+            return Err(AVPError::InvalidAttributeLengthError(
+                "16 bytes".to_owned(),
+                prefix_len,
+            ));
+        }
+
+        Ok(AVP {
+            typ,
+            value: [vec![0x00, (prefix_len * 8) as u8], prefix.to_vec()].concat::<u8>(),
+        })
+    }
+
+    /// (This method is for dictionary developers) make an AVP from an ether value (i.e. a 6-octet MAC address).
+    pub fn from_ether(typ: AVPType, value: &[u8; 6]) -> Self {
+        AVP {
+            typ,
+            value: value.to_vec(),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from an abinary value (i.e. an Ascend binary filter blob).
+    ///
+    /// It returns an error if the value exceeds `MAX_VALUE_LENGTH`.
+    pub fn from_abinary(typ: AVPType, value: &[u8]) -> Result<Self, AVPError> {
+        AVP::from_bytes(typ, value)
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a combo-ip value (i.e. either of IPv4 or IPv6 address).
+    pub fn from_combo_ip(typ: AVPType, value: &IpAddr) -> Self {
+        match value {
+            IpAddr::V4(v4) => AVP::from_ipv4(typ, v4),
+            IpAddr::V6(v6) => AVP::from_ipv6(typ, v6),
+        }
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a user-password value.
+    /// see also: https://tools.ietf.org/html/rfc2865#section-5.2
+    pub fn from_user_password(
+        typ: AVPType,
+        plain_text: &[u8],
+        secret: &[u8],
+        request_authenticator: &[u8],
+    ) -> Result<Self, AVPError> {
+        // Call the shared secret S and the pseudo-random 128-bit Request
+        // Authenticator RA.  Break the password into 16-octet chunks p1, p2,
+        // etc.  with the last one padded at the end with nulls to a 16-octet
+        // boundary.  Call the ciphertext blocks c(1), c(2), etc.  We'll need
+        // intermediate values b1, b2, etc.
+        //
+        //    b1 = MD5(S + RA)       c(1) = p1 xor b1
+        //    b2 = MD5(S + c(1))     c(2) = p2 xor b2
+        //           .                       .
+        //           .                       .
+        //           .                       .
+        //    bi = MD5(S + c(i-1))   c(i) = pi xor bi
+        //
+        // ref: https://tools.ietf.org/html/rfc2865#section-5.2
+
+        if plain_text.len() > 128 {
+            return Err(AVPError::UserPasswordPlainTextMaximumLengthExceededError(
+                plain_text.len(),
+            ));
+        }
+
+        if secret.is_empty() {
+            return Err(AVPError::PasswordSecretMissingError());
+        }
+
+        if request_authenticator.len() != 16 {
+            return Err(AVPError::InvalidRequestAuthenticatorLength());
+        }
+
+        let mut buff = request_authenticator.to_vec();
+
+        if plain_text.is_empty() {
+            let enc = md5::compute([secret, &buff[..]].concat()).to_vec();
+            return Ok(AVP {
+                typ,
+                value: enc.iter().zip(vec![0; 16]).map(|(d, p)| d ^ p).collect(),
+            });
+        }
+
+        let mut enc: Vec<u8> = Vec::new();
+        for chunk in plain_text.chunks(16) {
+            let mut chunk_vec = chunk.to_vec();
+            let l = chunk.len();
+            if l < 16 {
+                chunk_vec.extend(vec![0; 16 - l]); // zero padding
+            }
+
+            let enc_block = md5::compute([secret, &buff[..]].concat()).to_vec();
+            buff = enc_block
+                .iter()
+                .zip(chunk_vec)
+                .map(|(d, p)| d ^ p)
+                .collect();
+            enc.extend(&buff);
+        }
+
+        Ok(AVP { typ, value: enc })
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a date value.
+    ///
+    /// The date value is a 32-bit unsigned UNIX time, so this raises an error if the datetime is out of that range.
+    pub fn from_date(typ: AVPType, dt: &DateTime<Utc>) -> Result<Self, AVPError> {
+        let timestamp = dt.timestamp();
+        let timestamp: u32 = match timestamp.try_into() {
+            Ok(timestamp) => timestamp,
+            Err(_) => return Err(AVPError::DateOutOfRangeError(timestamp)),
+        };
+
+        Ok(AVP {
+            typ,
+            value: u32_to_network_bytes(timestamp),
+        })
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a tagged date value.
+    ///
+    /// The date value is a 32-bit unsigned UNIX time, so this raises an error if the datetime is out of that range.
+    pub fn from_tagged_date(
+        typ: AVPType,
+        tag: Option<&Tag>,
+        dt: &DateTime<Utc>,
+    ) -> Result<Self, AVPError> {
+        Ok(AVP::from_date(typ, dt)?.with_tag(tag))
+    }
+
+    /// Prepends the tag octet to the value of the fixed-length data type; the unused tag (i.e. zero) is used for `None`.
+    fn with_tag(self, tag: Option<&Tag>) -> Self {
+        AVP {
+            typ: self.typ,
+            value: [
+                vec![tag.map_or(UNUSED_TAG_VALUE, |tag| tag.value)],
+                self.value,
+            ]
+            .concat(),
+        }
+    }
+
+    /// Splits the tag octet from the value of the fixed-length data type, and returns the tag and the untagged AVP.
+    fn split_fixed_length_tag(&self) -> Result<(Tag, AVP), AVPError> {
+        if self.value.is_empty() {
+            return Err(AVPError::TagMissingError());
+        }
+
+        let tag = Tag {
+            value: self.value[0],
+        };
+        if !tag.is_valid_value() && !tag.is_zero() {
+            return Err(AVPError::InvalidTagForFixedLengthValueError());
+        }
+
+        Ok((
+            tag,
+            AVP {
+                typ: self.typ,
+                value: self.value[1..].to_vec(),
+            },
+        ))
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a tunne-password value.
+    /// see also: https://tools.ietf.org/html/rfc2868#section-3.5
+    pub fn from_tunnel_password(
+        typ: AVPType,
+        tag: Option<&Tag>,
+        plain_text: &[u8],
+        secret: &[u8],
+        request_authenticator: &[u8],
+    ) -> Result<Self, AVPError> {
+        Self::from_tunnel_password_with_random_source(
+            typ,
+            tag,
+            plain_text,
+            secret,
+            request_authenticator,
+            &ThreadRandomSource,
+        )
+    }
+
+    /// (This method is for dictionary developers) make an AVP from a tunne-password value
+    /// with the salt from the given source.
+    pub fn from_tunnel_password_with_random_source(
+        typ: AVPType,
+        tag: Option<&Tag>,
+        plain_text: &[u8],
+        secret: &[u8],
+        request_authenticator: &[u8],
+        random_source: &dyn RandomSource,
+    ) -> Result<Self, AVPError> {
+        /*
+         *   0                   1                   2                   3
+         *   0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+         *  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+         *  |     Type      |    Length     |     Tag       |   Salt
+         *  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+         *     Salt (cont)  |   String ...
+         *  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+         *
+         *    b(1) = MD5(S + R + A)    c(1) = p(1) xor b(1)   C = c(1)
+         *    b(2) = MD5(S + c(1))     c(2) = p(2) xor b(2)   C = C + c(2)
+         *                .                      .
+         *                .                      .
+         *                .                      .
+         *    b(i) = MD5(S + c(i-1))   c(i) = p(i) xor b(i)   C = C + c(i)
+         *
+         *  The resulting encrypted String field will contain
+         *  c(1)+c(2)+...+c(i).
+         *
+         *  https://tools.ietf.org/html/rfc2868#section-3.5
+         */
+
+        Self::from_tunnel_password_with_salt(
+            typ,
+            tag,
+            plain_text,
+            secret,
+            request_authenticator,
+            random::random_salt(random_source),
+        )
+    }
+
+    /// Make a tunnel-password AVP with the given salt; the MSB of the salt must be set.
+    pub(crate) fn from_tunnel_password_with_salt(
+        typ: AVPType,
+        tag: Option<&Tag>,
+        plain_text: &[u8],
+        secret: &[u8],
+        request_authenticator: &[u8],
+        salt: [u8; 2],
+    ) -> Result<Self, AVPError> {
+        // the encrypted string is 240 bytes at most (i.e. 253 - tag - salt, aligned to 16 bytes),
+        // and that includes a byte of the data length
+        if plain_text.len() > 239 {
+            return Err(AVPError::InvalidAttributeLengthError(
+                "239 bytes".to_owned(),
+                plain_text.len(),
+            ));
+        }
+
+        if secret.is_empty() {
+            return Err(AVPError::PasswordSecretMissingError());
+        }
+
+        if request_authenticator.len() != 16 {
+            return Err(AVPError::InvalidRequestAuthenticatorLength());
+        }
+
+        // NOTE: prepend one byte as a tag and two bytes as a salt
+        // TODO: should it separate them to private struct fields?
+        let mut enc: Vec<u8> = [
+            vec![tag.map_or(UNUSED_TAG_VALUE, |v| v.value)],
+            salt.to_vec(),
+        ]
+        .concat();
+
+        // the plain text is prefixed by its length (i.e. "Data-Length"); see https://tools.ietf.org/html/rfc2868#section-3.5
+        let data = [&[plain_text.len() as u8][..], plain_text].concat();
+        let mut buff = [request_authenticator, &salt].concat();
+        for chunk in data.chunks(16) {
+            let mut chunk_vec = chunk.to_vec();
+            let l = chunk.len();
+            if l < 16 {
+                chunk_vec.extend(vec![0; 16 - l]); // zero padding
+            }
+
+            let enc_block = md5::compute([secret, &buff[..]].concat()).to_vec();
+            buff = enc_block
+                .iter()
+                .zip(chunk_vec)
+                .map(|(d, p)| d ^ p)
+                .collect();
+            enc.extend(&buff);
+        }
+
+        Ok(AVP { typ, value: enc })
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a duration value from the seconds (e.g. `Session-Timeout`).
+    pub fn decode_duration(&self) -> Result<Duration, AVPError> {
+        Ok(Duration::from_secs(self.decode_u32()?.into()))
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a u32 value.
+    pub fn decode_u32(&self) -> Result<u32, AVPError> {
+        const U32_SIZE: usize = std::mem::size_of::<u32>();
+        if self.value.len() != U32_SIZE {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("{U32_SIZE} bytes"),
+                self.value.len(),
+            ));
+        }
+
+        u32_from_network_bytes(&self.value)
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a signed i32 value.
+    pub fn decode_i32(&self) -> Result<i32, AVPError> {
+        Ok(self.decode_u32()? as i32)
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a u16 value.
+    pub fn decode_u16(&self) -> Result<u16, AVPError> {
+        const U16_SIZE: usize = std::mem::size_of::<u16>();
+        if self.value.len() != U16_SIZE {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("{U16_SIZE} bytes"),
+                self.value.len(),
+            ));
+        }
+
+        u16_from_network_bytes(&self.value)
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a tag and u32 value.
+    pub fn decode_tagged_u32(&self) -> Result<(u32, Tag), AVPError> {
+        if self.value.is_empty() {
+            return Err(AVPError::TagMissingError());
+        }
+
+        let tag = Tag {
+            value: self.value[0],
+        };
+
+        // ref RFC2868:
+        //   Valid values for this field are 0x01 through 0x1F,
+        //   inclusive.  If the Tag field is unused, it MUST be zero (0x00)
+        if !tag.is_valid_value() && !tag.is_zero() {
+            return Err(AVPError::InvalidTagForIntegerValueError());
+        }
+
+        const U32_SIZE: usize = std::mem::size_of::<u32>();
+        if self.value[1..].len() != U32_SIZE {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("{} bytes", U32_SIZE + 1),
+                self.value.len(),
+            ));
+        }
+        Ok((u32_from_network_bytes(&self.value[1..])?, tag))
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a tag and u16 value.
+    pub fn decode_tagged_u16(&self) -> Result<(u16, Tag), AVPError> {
+        let (tag, avp) = self.split_fixed_length_tag()?;
+        Ok((avp.decode_u16()?, tag))
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a string value.
+    pub fn decode_string(&self) -> Result<String, AVPError> {
+        match String::from_utf8(self.value.to_vec()) {
+            Ok(str) => Ok(str),
+            Err(e) => Err(AVPError::DecodingError(e.to_string())),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a tag and string value.
+    pub fn decode_tagged_string(&self) -> Result<(String, Option<Tag>), AVPError> {
+        let string_vec = self.value.to_vec();
+        if string_vec.is_empty() {
+            return Err(AVPError::TagMissingError());
+        }
+
+        let tag = Tag {
+            value: string_vec[0],
+        };
+
+        // ref RFC2868:
+        //   If the value of the Tag field is greater than 0x00
+        //   and less than or equal to 0x1F, it SHOULD be interpreted as
+        //   indicating which tunnel (of several alternatives) this attribute
+        //   pertains.
+        if tag.is_valid_value() {
+            return match String::from_utf8(string_vec[1..].to_vec()) {
+                Ok(str) => Ok((str, Some(tag))),
+                Err(e) => Err(AVPError::DecodingError(e.to_string())),
+            };
+        }
+
+        if tag.is_zero() {
+            return Err(AVPError::InvalidTagForStringValueError());
+        }
+
+        // ref RFC2868:
+        //   If the Tag field is greater than 0x1F, it SHOULD be
+        //   interpreted as the first byte of the following String field.
+        match String::from_utf8(self.value.to_vec()) {
+            Ok(str) => Ok((str, None)),
+            Err(e) => Err(AVPError::DecodingError(e.to_string())),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into bytes.
+    pub fn decode_bytes(&self) -> Vec<u8> {
+        self.value.to_vec()
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into tagged bytes.
+    ///
+    /// Like the tagged string, the first octet is regarded as the tag only if that is in the range of the valid tags
+    /// (i.e. 0x01 through 0x1F), and the unused tag (i.e. zero) is stripped without the tag.
+    /// Otherwise the whole value is returned as the untagged bytes.
+    pub fn decode_tagged_bytes(&self) -> Result<(Vec<u8>, Option<Tag>), AVPError> {
+        if self.value.is_empty() {
+            return Err(AVPError::TagMissingError());
+        }
+
+        let tag = Tag {
+            value: self.value[0],
+        };
+        if tag.is_valid_value() {
+            return Ok((self.value[1..].to_vec(), Some(tag)));
+        }
+        if tag.is_zero() {
+            return Ok((self.value[1..].to_vec(), None));
+        }
+        Ok((self.value.to_vec(), None))
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into Ipv4 value.
+    pub fn decode_ipv4(&self) -> Result<Ipv4Addr, AVPError> {
+        const IPV4_SIZE: usize = std::mem::size_of::<Ipv4Addr>();
+        if self.value.len() != IPV4_SIZE {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("{IPV4_SIZE} bytes"),
+                self.value.len(),
+            ));
+        }
+
+        let (int_bytes, _) = self.value.split_at(IPV4_SIZE);
+        match int_bytes.try_into() {
+            Ok::<[u8; IPV4_SIZE], _>(boxed_array) => Ok(Ipv4Addr::from(boxed_array)),
+            Err(e) => Err(AVPError::DecodingError(e.to_string())),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a tag and Ipv4 value.
+    pub fn decode_tagged_ipv4(&self) -> Result<(Ipv4Addr, Tag), AVPError> {
+        let (tag, avp) = self.split_fixed_length_tag()?;
+        Ok((avp.decode_ipv4()?, tag))
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into Ipv4-prefix value.
+    pub fn decode_ipv4_prefix(&self) -> Result<Vec<u8>, AVPError> {
+        match self.value.len() == 6 {
+            true => Ok(self.value[2..].to_owned()),
+            false => Err(AVPError::InvalidAttributeLengthError(
+                "6 bytes".to_owned(),
+                self.value.len(),
+            )),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into Ipv6 value.
+    pub fn decode_ipv6(&self) -> Result<Ipv6Addr, AVPError> {
+        const IPV6_SIZE: usize = std::mem::size_of::<Ipv6Addr>();
+        if self.value.len() != IPV6_SIZE {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("{IPV6_SIZE} bytes"),
+                self.value.len(),
+            ));
+        }
+
+        let (int_bytes, _) = self.value.split_at(IPV6_SIZE);
+        match int_bytes.try_into() {
+            Ok::<[u8; IPV6_SIZE], _>(boxed_array) => Ok(Ipv6Addr::from(boxed_array)),
+            Err(e) => Err(AVPError::DecodingError(e.to_string())),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into Ipv6-prefix value.
+    pub fn decode_ipv6_prefix(&self) -> Result<Vec<u8>, AVPError> {
+        match self.value.len() >= 2 {
+            true => Ok(self.value[2..].to_owned()),
+            false => Err(AVPError::InvalidAttributeLengthError(
+                "2+ bytes".to_owned(),
+                self.value.len(),
+            )),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into ether value (i.e. a 6-octet MAC address).
+    pub fn decode_ether(&self) -> Result<[u8; 6], AVPError> {
+        match self.value.as_slice().try_into() {
+            Ok(mac) => Ok(mac),
+            Err(_) => Err(AVPError::InvalidAttributeLengthError(
+                "6 bytes".to_owned(),
+                self.value.len(),
+            )),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into abinary value (i.e. an Ascend binary filter blob).
+    pub fn decode_abinary(&self) -> Vec<u8> {
+        self.value.to_vec()
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into combo-ip value;
+    /// the 4-octet value is an IPv4 address, and the 16-octet value is an IPv6 address.
+    pub fn decode_combo_ip(&self) -> Result<IpAddr, AVPError> {
+        match self.value.len() {
+            4 => Ok(IpAddr::V4(self.decode_ipv4()?)),
+            16 => Ok(IpAddr::V6(self.decode_ipv6()?)),
+            len => Err(AVPError::InvalidAttributeLengthError(
+                "4 or 16 bytes".to_owned(),
+                len,
+            )),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into user-password value as bytes.
+    pub fn decode_user_password(
+        &self,
+        secret: &[u8],
+        request_authenticator: &[u8],
+    ) -> Result<Vec<u8>, AVPError> {
+        if self.value.len() < 16 || self.value.len() > 128 {
+            return Err(AVPError::InvalidAttributeLengthError(
+                "16 >= bytes && 128 <= bytes".to_owned(),
+                self.value.len(),
+            ));
+        }
+
+        if secret.is_empty() {
+            return Err(AVPError::PasswordSecretMissingError());
+        }
+
+        if request_authenticator.len() != 16 {
+            return Err(AVPError::InvalidRequestAuthenticatorLength());
+        }
+
+        let mut dec: Vec<u8> = Vec::new();
+        let mut buff: Vec<u8> = request_authenticator.to_vec();
+
+        // NOTE:
+        // It ensures attribute value has 16 bytes length at least because the value is encoded by md5.
+        // And this must be aligned by each 16 bytes length.
+        for chunk in self.value.chunks(16) {
+            let chunk_vec = chunk.to_vec();
+            let dec_block = md5::compute([secret, &buff[..]].concat()).to_vec();
+            dec.extend(
+                dec_block
+                    .iter()
+                    .zip(&chunk_vec)
+                    .map(|(d, p)| d ^ p)
+                    .collect::<Vec<u8>>(),
+            );
+            buff = chunk_vec.clone();
+        }
+
+        // remove trailing zero bytes
+        match dec.split(|b| *b == 0).next() {
+            Some(dec) => Ok(dec.to_vec()),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into date value.
+    pub fn decode_date(&self) -> Result<DateTime<Utc>, AVPError> {
+        const U32_SIZE: usize = std::mem::size_of::<u32>();
+        if self.value.len() != U32_SIZE {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("{U32_SIZE}"),
+                self.value.len(),
+            ));
+        }
+
+        let timestamp = u32_from_network_bytes(&self.value)?;
+        Ok(Utc.timestamp_opt(timestamp as i64, 0).unwrap())
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a tag and date value.
+    pub fn decode_tagged_date(&self) -> Result<(DateTime<Utc>, Tag), AVPError> {
+        let (tag, avp) = self.split_fixed_length_tag()?;
+        Ok((avp.decode_date()?, tag))
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into date value as seconds since the UNIX epoch,
+    /// with disambiguating the era of the 32-bit value according to the given policy.
+    pub fn decode_date_u64(&self, era_policy: DateEraPolicy) -> Result<u64, AVPError> {
+        const U32_SIZE: usize = std::mem::size_of::<u32>();
+        if self.value.len() != U32_SIZE {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("{U32_SIZE}"),
+                self.value.len(),
+            ));
+        }
+
+        let timestamp = u32_from_network_bytes(&self.value)? as u64;
+
+        match era_policy {
+            DateEraPolicy::Unsigned => Ok(timestamp),
+            DateEraPolicy::NearestTo(pivot) => {
+                const ERA_LENGTH: i128 = 1 << 32;
+                let pivot = pivot.timestamp().max(0) as i128;
+                let era = (pivot - timestamp as i128 + ERA_LENGTH / 2).div_euclid(ERA_LENGTH);
+                Ok((timestamp as i128 + era.max(0) * ERA_LENGTH) as u64)
+            }
+        }
+    }
+
+    /// (This method is for dictionary developers) decode an AVP into a tunnel-password value as bytes.
+    pub fn decode_tunnel_password(
+        &self,
+        secret: &[u8],
+        request_authenticator: &[u8],
+    ) -> Result<(Vec<u8>, Tag), AVPError> {
+        if self.value.len() < 19
+            || self.value.len() > 243
+            || !(self.value.len() - 3).is_multiple_of(16)
+        {
+            return Err(AVPError::InvalidAttributeLengthError(
+                "19 <= bytes && bytes <= 242 && (bytes - 3) % 16 == 0".to_owned(),
+                self.value.len(),
+            ));
+        }
+
+        if self.value[1] & 0x80 != 0x80 {
+            // salt
+            return Err(AVPError::InvalidSaltMSBError(self.value[1]));
+        }
+
+        if secret.is_empty() {
+            return Err(AVPError::PasswordSecretMissingError());
+        }
+
+        if request_authenticator.len() != 16 {
+            return Err(AVPError::InvalidRequestAuthenticatorLength());
+        }
+
+        let tag = Tag {
+            value: self.value[0],
+        };
+        let mut dec: Vec<u8> = Vec::new();
+        let mut buff: Vec<u8> =
+            [request_authenticator.to_vec(), self.value[1..3].to_vec()].concat();
+
+        for chunk in self.value[3..].chunks(16) {
+            let chunk_vec = chunk.to_vec();
+            let dec_block = md5::compute([secret, &buff[..]].concat()).to_vec();
+            dec.extend(
+                dec_block
+                    .iter()
+                    .zip(&chunk_vec)
+                    .map(|(d, p)| d ^ p)
+                    .collect::<Vec<u8>>(),
+            );
+            buff = chunk_vec.clone();
+        }
+
+        // the first byte is the length of the plain text, and the rest is the plain text and the padding
+        let data_length = dec[0] as usize;
+        if data_length > dec.len() - 1 {
+            return Err(AVPError::DecodingError(format!(
+                "the data length of tunnel-password is {} bytes, but the decrypted data has only {} bytes",
+                data_length,
+                dec.len() - 1
+            )));
+        }
+        Ok((dec[1..=data_length].to_vec(), tag))
+    }
+}
+
+/// The former names of the decoders, that are named `encode_*` though those decode the values.
+impl AVP {
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_u32()`")]
+    pub fn encode_u32(&self) -> Result<u32, AVPError> {
+        self.decode_u32()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_u16()`")]
+    pub fn encode_u16(&self) -> Result<u16, AVPError> {
+        self.decode_u16()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_tagged_u32()`")]
+    pub fn encode_tagged_u32(&self) -> Result<(u32, Tag), AVPError> {
+        self.decode_tagged_u32()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_string()`")]
+    pub fn encode_string(&self) -> Result<String, AVPError> {
+        self.decode_string()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_tagged_string()`")]
+    pub fn encode_tagged_string(&self) -> Result<(String, Option<Tag>), AVPError> {
+        self.decode_tagged_string()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_bytes()`")]
+    pub fn encode_bytes(&self) -> Vec<u8> {
+        self.decode_bytes()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_ipv4()`")]
+    pub fn encode_ipv4(&self) -> Result<Ipv4Addr, AVPError> {
+        self.decode_ipv4()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_ipv4_prefix()`")]
+    pub fn encode_ipv4_prefix(&self) -> Result<Vec<u8>, AVPError> {
+        self.decode_ipv4_prefix()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_ipv6()`")]
+    pub fn encode_ipv6(&self) -> Result<Ipv6Addr, AVPError> {
+        self.decode_ipv6()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_ipv6_prefix()`")]
+    pub fn encode_ipv6_prefix(&self) -> Result<Vec<u8>, AVPError> {
+        self.decode_ipv6_prefix()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_user_password()`")]
+    pub fn encode_user_password(
+        &self,
+        secret: &[u8],
+        request_authenticator: &[u8],
+    ) -> Result<Vec<u8>, AVPError> {
+        self.decode_user_password(secret, request_authenticator)
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_date()`")]
+    pub fn encode_date(&self) -> Result<DateTime<Utc>, AVPError> {
+        self.decode_date()
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_date_u64()`")]
+    pub fn encode_date_u64(&self, era_policy: DateEraPolicy) -> Result<u64, AVPError> {
+        self.decode_date_u64(era_policy)
+    }
+
+    #[deprecated(since = "0.4.0", note = "renamed to `decode_tunnel_password()`")]
+    pub fn encode_tunnel_password(
+        &self,
+        secret: &[u8],
+        request_authenticator: &[u8],
+    ) -> Result<(Vec<u8>, Tag), AVPError> {
+        self.decode_tunnel_password(secret, request_authenticator)
+    }
+}
+
+fn check_value_length(value: &[u8]) -> Result<(), AVPError> {
+    if value.len() > MAX_VALUE_LENGTH {
+        return Err(AVPError::InvalidAttributeLengthError(
+            format!("<= {} bytes", MAX_VALUE_LENGTH),
+            value.len(),
+        ));
+    }
+    Ok(())
+}
+
+// The integers are in the network byte order (i.e. big-endian) on the wire regardless of the byte order of the target,
+// so the codecs of the integer values must go through these helpers instead of the native-endian conversions.
+
+fn u32_to_network_bytes(value: u32) -> Vec<u8> {
+    value.to_be_bytes().to_vec()
+}
+
+fn u16_to_network_bytes(value: u16) -> Vec<u8> {
+    value.to_be_bytes().to_vec()
+}
+
+fn u32_from_network_bytes(bs: &[u8]) -> Result<u32, AVPError> {
+    match bs.try_into() {
+        Ok(array) => Ok(u32::from_be_bytes(array)),
+        Err(e) => Err(AVPError::DecodingError(e.to_string())),
+    }
+}
+
+fn u16_from_network_bytes(bs: &[u8]) -> Result<u16, AVPError> {
+    match bs.try_into() {
+        Ok(array) => Ok(u16::from_be_bytes(array)),
+        Err(e) => Err(AVPError::DecodingError(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::avp::{
+        AVPError, DateEraPolicy, JumboAttributePolicy, AVP, MAX_LONG_EXTENDED_FRAGMENT_LENGTH,
+        MAX_VALUE_LENGTH,
+    };
+    use crate::tag::Tag;
+
+    #[test]
+    fn it_should_convert_attribute_to_integer32() -> Result<(), AVPError> {
+        let given_u32 = 16909060;
+        let avp = AVP::from_u32(1, given_u32);
+        assert_eq!(avp.decode_u32()?, given_u32);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_attribute_to_signed_integer32() -> Result<(), AVPError> {
+        for given_i32 in [0, 1, -1, i32::MIN, i32::MAX] {
+            let avp = AVP::from_i32(1, given_i32);
+            assert_eq!(avp.decode_i32()?, given_i32);
+        }
+        assert_eq!(AVP::from_i32(1, -2).raw_value(), &[0xff, 0xff, 0xff, 0xfe]);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_attribute_to_integer16() -> Result<(), AVPError> {
+        let given_u16 = 65534;
+        let avp = AVP::from_u16(1, given_u16);
+        assert_eq!(avp.decode_u16()?, given_u16);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_attribute_to_tagged_integer32() -> Result<(), AVPError> {
+        let given_u32 = 16909060;
+        let avp = AVP::from_tagged_u32(1, None, given_u32);
+        assert_eq!(avp.decode_tagged_u32()?, (given_u32, Tag::new_unused()));
+
+        let tag = Tag::new(2);
+        let avp = AVP::from_tagged_u32(1, Some(&tag), given_u32);
+        assert_eq!(avp.decode_tagged_u32()?, (given_u32, tag));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_attribute_to_string() -> Result<(), AVPError> {
+        let given_str = "Hello, World";
+        let avp = AVP::from_string(1, given_str);
+        assert_eq!(avp.decode_string()?, given_str);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_tagged_attribute_to_string() -> Result<(), AVPError> {
+        let given_str = "Hello, World";
+        let avp = AVP::from_tagged_string(1, None, given_str);
+        assert_eq!(avp.decode_tagged_string()?, (given_str.to_owned(), None));
+
+        let tag = Tag::new(3);
+        let avp = AVP::from_tagged_string(1, Some(&tag), given_str);
+        assert_eq!(
+            avp.decode_tagged_string()?,
+            (given_str.to_owned(), Some(tag))
+        );
+
+        let avp = AVP::from_tagged_string(1, Some(&Tag::new_unused()), given_str);
+        assert_eq!(
+            avp.decode_tagged_string().unwrap_err(),
+            AVPError::InvalidTagForStringValueError()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_attribute_to_byte() {
+        let given_bytes = b"Hello, World";
+        let avp = AVP::from_bytes(1, given_bytes).unwrap();
+        assert_eq!(avp.decode_bytes(), given_bytes);
+    }
+
+    #[test]
+    fn it_should_convert_ipv4() -> Result<(), AVPError> {
+        let given_ipv4 = Ipv4Addr::new(192, 0, 2, 1);
+        let avp = AVP::from_ipv4(1, &given_ipv4);
+        assert_eq!(avp.decode_ipv4()?, given_ipv4);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_tagged_fixed_length_values() -> Result<(), AVPError> {
+        let given_ipv4 = Ipv4Addr::new(192, 0, 2, 1);
+        let avp = AVP::from_tagged_ipv4(1, Some(&Tag::new(2)), &given_ipv4);
+        assert_eq!(avp.raw_value(), &[0x02, 192, 0, 2, 1]);
+        assert_eq!(avp.decode_tagged_ipv4()?, (given_ipv4, Tag::new(2)));
+        let avp = AVP::from_tagged_ipv4(1, None, &given_ipv4);
+        assert_eq!(avp.decode_tagged_ipv4()?, (given_ipv4, Tag::new_unused()));
+
+        let avp = AVP::from_tagged_u16(1, Some(&Tag::new(3)), 0x0102);
+        assert_eq!(avp.raw_value(), &[0x03, 0x01, 0x02]);
+        assert_eq!(avp.decode_tagged_u16()?, (0x0102, Tag::new(3)));
+
+        let dt = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let avp = AVP::from_tagged_date(1, Some(&Tag::new(4)), &dt)?;
+        assert_eq!(avp.decode_tagged_date()?, (dt, Tag::new(4)));
+
+        assert_eq!(
+            AVP::from_bytes(1, &[])?.decode_tagged_ipv4().unwrap_err(),
+            AVPError::TagMissingError()
+        );
+        assert_eq!(
+            AVP::from_bytes(1, &[0x20, 192, 0, 2, 1])?
+                .decode_tagged_ipv4()
+                .unwrap_err(),
+            AVPError::InvalidTagForFixedLengthValueError()
+        );
+        assert_eq!(
+            AVP::from_bytes(1, &[0x01, 0x01, 0x02])?
+                .decode_tagged_date()
+                .unwrap_err(),
+            AVPError::InvalidAttributeLengthError("4".to_owned(), 2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_tagged_bytes() -> Result<(), AVPError> {
+        let avp = AVP::from_tagged_bytes(1, Some(&Tag::new(1)), &[0xde, 0xad])?;
+        assert_eq!(avp.raw_value(), &[0x01, 0xde, 0xad]);
+        assert_eq!(
+            avp.decode_tagged_bytes()?,
+            (vec![0xde, 0xad], Some(Tag::new(1)))
+        );
+
+        let avp = AVP::from_tagged_bytes(1, None, &[0xde, 0xad])?;
+        assert_eq!(avp.decode_tagged_bytes()?, (vec![0xde, 0xad], None));
+
+        // the unused tag is stripped
+        let avp = AVP::from_bytes(1, &[0x00, 0xde, 0xad])?;
+        assert_eq!(avp.decode_tagged_bytes()?, (vec![0xde, 0xad], None));
+
+        assert_eq!(
+            AVP::from_tagged_bytes(1, Some(&Tag::new(1)), &[0; MAX_VALUE_LENGTH]).unwrap_err(),
+            AVPError::InvalidAttributeLengthError(
+                format!("<= {MAX_VALUE_LENGTH} bytes"),
+                MAX_VALUE_LENGTH + 1
+            )
+        );
+        assert_eq!(
+            AVP::from_bytes(1, &[])?.decode_tagged_bytes().unwrap_err(),
+            AVPError::TagMissingError()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_ipv6() -> Result<(), AVPError> {
+        let given_ipv6 = Ipv6Addr::new(
+            0x2001, 0x0db8, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001,
+        );
+        let avp = AVP::from_ipv6(1, &given_ipv6);
+        assert_eq!(avp.decode_ipv6()?, given_ipv6);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_ether() -> Result<(), AVPError> {
+        let given_mac = [0x00, 0x00, 0x5e, 0x00, 0x53, 0x01];
+        let avp = AVP::from_ether(1, &given_mac);
+        assert_eq!(avp.decode_ether()?, given_mac);
+        assert_eq!(
+            AVP::from_bytes(1, &[0x00, 0x00, 0x5e])?
+                .decode_ether()
+                .unwrap_err(),
+            AVPError::InvalidAttributeLengthError("6 bytes".to_owned(), 3)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_abinary() -> Result<(), AVPError> {
+        let given_filter = [0x01; 32];
+        let avp = AVP::from_abinary(1, &given_filter)?;
+        assert_eq!(avp.decode_abinary(), given_filter.to_vec());
+        assert!(AVP::from_abinary(1, &[0x01; MAX_VALUE_LENGTH + 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_combo_ip() -> Result<(), AVPError> {
+        let given_ipv4 = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+        let avp = AVP::from_combo_ip(1, &given_ipv4);
+        assert_eq!(avp.raw_value().len(), 4);
+        assert_eq!(avp.decode_combo_ip()?, given_ipv4);
+
+        let given_ipv6 = IpAddr::V6(Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1));
+        let avp = AVP::from_combo_ip(1, &given_ipv6);
+        assert_eq!(avp.raw_value().len(), 16);
+        assert_eq!(avp.decode_combo_ip()?, given_ipv6);
+
+        assert_eq!(
+            AVP::from_bytes(1, &[192, 0, 2])?
+                .decode_combo_ip()
+                .unwrap_err(),
+            AVPError::InvalidAttributeLengthError("4 or 16 bytes".to_owned(), 3)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_user_password() {
+        let secret = b"12345".to_vec();
+        let request_authenticator = b"0123456789abcdef".to_vec();
+
+        struct TestCase<'a> {
+            plain_text: &'a str,
+            expected_encoded_len: usize,
+        }
+
+        let test_cases = &[
+            TestCase {
+                plain_text: "",
+                expected_encoded_len: 16,
+            },
+            TestCase {
+                plain_text: "abc",
+                expected_encoded_len: 16,
+            },
+            TestCase {
+                plain_text: "0123456789abcde",
+                expected_encoded_len: 16,
+            },
+            TestCase {
+                plain_text: "0123456789abcdef",
+                expected_encoded_len: 16,
+            },
+            TestCase {
+                plain_text: "0123456789abcdef0",
+                expected_encoded_len: 32,
+            },
+            TestCase {
+                plain_text: "0123456789abcdef0123456789abcdef0123456789abcdef",
+                expected_encoded_len: 48,
+            },
+        ];
+
+        for test_case in test_cases {
+            let user_password_avp_result = AVP::from_user_password(
+                1,
+                test_case.plain_text.as_bytes(),
+                &secret,
+                &request_authenticator,
+            );
+            let avp = user_password_avp_result.unwrap();
+            assert_eq!(avp.value.len(), test_case.expected_encoded_len);
+
+            let decoded_password = avp
+                .decode_user_password(&secret, &request_authenticator)
+                .unwrap();
+            assert_eq!(
+                String::from_utf8(decoded_password).unwrap(),
+                test_case.plain_text
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_convert_date() -> Result<(), AVPError> {
+        let now = Utc::now();
+        let avp = AVP::from_date(1, &now)?;
+        assert_eq!(avp.decode_date()?.timestamp(), now.timestamp(),);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_date_after_2038() -> Result<(), AVPError> {
+        // beyond the signed 32-bit range
+        let dt = Utc.timestamp_opt(i32::MAX as i64 + 1, 0).unwrap();
+        let avp = AVP::from_date(1, &dt)?;
+        assert_eq!(avp.decode_date()?, dt);
+        assert_eq!(
+            avp.decode_date_u64(DateEraPolicy::Unsigned)?,
+            i32::MAX as u64 + 1
+        );
+
+        let dt = Utc.timestamp_opt(u32::MAX as i64, 0).unwrap();
+        let avp = AVP::from_date(1, &dt)?;
+        assert_eq!(avp.decode_date()?, dt);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_out_of_range_date() {
+        let dt = Utc.timestamp_opt(u32::MAX as i64 + 1, 0).unwrap();
+        assert_eq!(
+            AVP::from_date(1, &dt).unwrap_err(),
+            AVPError::DateOutOfRangeError(u32::MAX as i64 + 1)
+        );
+
+        let dt = Utc.timestamp_opt(-1, 0).unwrap();
+        assert_eq!(
+            AVP::from_date(1, &dt).unwrap_err(),
+            AVPError::DateOutOfRangeError(-1)
+        );
+    }
+
+    #[test]
+    fn it_should_disambiguate_date_era() -> Result<(), AVPError> {
+        let avp = AVP::from_u32(1, 100);
+        let next_era_pivot = Utc.timestamp_opt(u32::MAX as i64 - 100, 0).unwrap();
+        assert_eq!(
+            avp.decode_date_u64(DateEraPolicy::NearestTo(next_era_pivot))?,
+            (1 << 32) + 100
+        );
+
+        let avp = AVP::from_u32(1, u32::MAX - 100);
+        let first_era_pivot = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        assert_eq!(
+            avp.decode_date_u64(DateEraPolicy::NearestTo(first_era_pivot))?,
+            u32::MAX as u64 - 100
+        );
+
+        let avp = AVP::from_u32(1, 1_600_000_000);
+        let pivot = Utc.timestamp_opt(0, 0).unwrap();
+        assert_eq!(
+            avp.decode_date_u64(DateEraPolicy::NearestTo(pivot))?,
+            1_600_000_000
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_tunnel_password() -> Result<(), AVPError> {
+        let tag = Tag { value: 0x1e };
+        let secret = b"12345".to_vec();
+        let request_authenticator = b"0123456789abcdef".to_vec();
+
+        struct TestCase<'a> {
+            plain_text: &'a str,
+            expected_encoded_len: usize,
+        }
+
+        let test_cases = &[
+            TestCase {
+                plain_text: "",
+                expected_encoded_len: 16 + 3,
+            },
+            TestCase {
+                plain_text: "abc",
+                expected_encoded_len: 16 + 3,
+            },
+            TestCase {
+                plain_text: "0123456789abcde",
+                expected_encoded_len: 16 + 3,
+            },
+            TestCase {
+                plain_text: "pass\0word",
+                expected_encoded_len: 16 + 3,
+            },
+            TestCase {
+                plain_text: "0123456789abcdef",
+                expected_encoded_len: 32 + 3,
+            },
+            TestCase {
+                plain_text: "0123456789abcdef0",
+                expected_encoded_len: 32 + 3,
+            },
+            TestCase {
+                plain_text: "0123456789abcdef0123456789abcdef0123456789abcdef",
+                expected_encoded_len: 64 + 3,
+            },
+        ];
+
+        for test_case in test_cases {
+            let user_password_avp_result = AVP::from_tunnel_password(
+                1,
+                Some(&tag),
+                test_case.plain_text.as_bytes(),
+                &secret,
+                &request_authenticator,
+            );
+            let avp = user_password_avp_result.unwrap();
+            assert_eq!(avp.value.len(), test_case.expected_encoded_len);
+
+            let (decoded_password, got_tag) = avp
+                .decode_tunnel_password(&secret, &request_authenticator)
+                .unwrap();
+            assert_eq!(got_tag, tag);
+            assert_eq!(
+                String::from_utf8(decoded_password).unwrap(),
+                test_case.plain_text
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_encode_integers_in_network_byte_order() -> Result<(), AVPError> {
+        // these are the exact bytes on the wire, so this holds on the big-endian targets (e.g. s390x) as well
+        assert_eq!(
+            AVP::from_u32(1, 0x01020304).value,
+            vec![0x01, 0x02, 0x03, 0x04]
+        );
+        assert_eq!(AVP::from_u16(1, 0x0102).value, vec![0x01, 0x02]);
+        assert_eq!(
+            AVP::from_tagged_u32(1, Some(&Tag::new(0x1f)), 0x01020304).value,
+            vec![0x1f, 0x01, 0x02, 0x03, 0x04]
+        );
+        assert_eq!(
+            AVP::from_date(1, &Utc.timestamp_opt(0x5f5e1000, 0).unwrap())?.value,
+            vec![0x5f, 0x5e, 0x10, 0x00]
+        );
+        assert_eq!(
+            AVP::from_ipv4_prefix(1, &[192, 0, 2, 0])?.value,
+            vec![0x00, 32, 192, 0, 2, 0]
+        );
+        assert_eq!(
+            AVP::from_ipv6_prefix(1, &[0x20, 0x01, 0x0d, 0xb8])?.value,
+            vec![0x00, 32, 0x20, 0x01, 0x0d, 0xb8]
+        );
+
+        let avp = AVP::from_bytes(1, &[0x01, 0x02, 0x03, 0x04]).unwrap();
+        assert_eq!(avp.decode_u32()?, 0x01020304);
+        assert_eq!(avp.decode_date()?.timestamp(), 0x01020304);
+        assert_eq!(avp.decode_date_u64(DateEraPolicy::Unsigned)?, 0x01020304);
+        assert_eq!(
+            AVP::from_bytes(1, &[0x01, 0x02]).unwrap().decode_u16()?,
+            0x0102
+        );
+        assert_eq!(
+            AVP::from_bytes(1, &[0x1f, 0x01, 0x02, 0x03, 0x04])
+                .unwrap()
+                .decode_tagged_u32()?,
+            (0x01020304, Tag::new(0x1f))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_make_avp_from_owned_values_without_copying() {
+        let value = vec![0x01; 253];
+        let ptr = value.as_ptr();
+        let avp = AVP::from_bytes_owned(79, value).unwrap();
+        assert_eq!(avp.raw_value().as_ptr(), ptr);
+        assert_eq!(avp, AVP::from_bytes(79, &[0x01; 253]).unwrap());
+
+        let value = String::from("alice");
+        let ptr = value.as_ptr();
+        let avp = AVP::from_string_owned(1, value);
+        assert_eq!(avp.raw_value().as_ptr(), ptr);
+        assert_eq!(avp, AVP::from_string(1, "alice"));
+    }
+
+    #[test]
+    fn it_should_make_avp_from_raw_parts() -> Result<(), AVPError> {
+        let avp = AVP::from_raw(241, &[0x01, 0xca, 0xfe])?;
+        assert_eq!(avp.type_code(), 241);
+        assert_eq!(avp.raw_value(), &[0x01, 0xca, 0xfe]);
+        assert_eq!(avp, AVP::from_bytes(241, &[0x01, 0xca, 0xfe]).unwrap());
+
+        // the raw value of the encrypted attribute is the cipher text
+        let avp = AVP::from_user_password(2, b"password", b"secret", &[0x01; 16])?;
+        assert_eq!(avp.raw_value().len(), 16);
+        assert_ne!(&avp.raw_value()[..8], b"password");
+
+        assert_eq!(
+            AVP::from_raw(1, &[0; MAX_VALUE_LENGTH])?.raw_value().len(),
+            253
+        );
+        assert_eq!(
+            AVP::from_raw(1, &[0; MAX_VALUE_LENGTH + 1]).unwrap_err(),
+            AVPError::InvalidAttributeLengthError("<= 253 bytes".to_owned(), 254)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_duration() -> Result<(), AVPError> {
+        let avp = AVP::from_duration(1, Duration::from_secs(3600))?;
+        assert_eq!(avp.decode_u32()?, 3600);
+        assert_eq!(avp.decode_duration()?, Duration::from_secs(3600));
+
+        // the fractional part of a second is truncated
+        let avp = AVP::from_duration(1, Duration::from_millis(1500))?;
+        assert_eq!(avp.decode_duration()?, Duration::from_secs(1));
+
+        let avp = AVP::from_duration(1, Duration::from_secs(u32::MAX as u64))?;
+        assert_eq!(avp.decode_u32()?, u32::MAX);
+        assert_eq!(
+            AVP::from_duration(1, Duration::from_secs(u32::MAX as u64 + 1)).unwrap_err(),
+            AVPError::DurationOutOfRangeError(u32::MAX as u64 + 1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_ipv4_prefix() -> Result<(), AVPError> {
+        let prefix = vec![0x01, 0x02, 0x03, 0x04];
+        let avp = AVP::from_ipv4_prefix(1, &prefix)?;
+        assert_eq!(avp.decode_ipv4_prefix()?, prefix);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_ipv4_prefix_fail_because_of_invalid_prefix_length() {
+        let avp = AVP::from_ipv4_prefix(1, &[0x01, 0x02, 0x03]);
+        assert_eq!(
+            avp.unwrap_err(),
+            AVPError::InvalidAttributeLengthError("4 bytes".to_owned(), 3)
+        );
+
+        let avp = AVP::from_ipv4_prefix(1, &[0x01, 0x02, 0x03, 0x04, 0x05]);
+        assert_eq!(
+            avp.unwrap_err(),
+            AVPError::InvalidAttributeLengthError("4 bytes".to_owned(), 5)
+        );
+
+        assert_eq!(
+            AVP {
+                typ: 1,
+                value: vec![]
+            }
+            .decode_ipv4_prefix()
+            .unwrap_err(),
+            AVPError::InvalidAttributeLengthError("6 bytes".to_owned(), 0)
+        );
+    }
+
+    #[test]
+    fn should_convert_ipv6_prefix() -> Result<(), AVPError> {
+        let prefix = vec![];
+        let avp = AVP::from_ipv6_prefix(1, &prefix)?;
+        assert_eq!(avp.decode_ipv6_prefix()?, prefix);
+
+        let prefix = vec![0x00, 0x01, 0x02, 0x03];
+        let avp = AVP::from_ipv6_prefix(1, &prefix)?;
+        assert_eq!(avp.decode_ipv6_prefix()?, prefix);
+
+        let prefix = vec![
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+            0x0e, 0x0f,
+        ];
+        let avp = AVP::from_ipv6_prefix(1, &prefix)?;
+        assert_eq!(avp.decode_ipv6_prefix()?, prefix);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_ipv6_prefix_fail_because_of_invalid_prefix_length() {
+        let avp = AVP::from_ipv6_prefix(
+            1,
+            &[
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+                0x0e, 0x0f, 0x10,
+            ],
+        );
+        assert_eq!(
+            avp.unwrap_err(),
+            AVPError::InvalidAttributeLengthError("16 bytes".to_owned(), 17)
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn should_keep_former_names_of_decoders() -> Result<(), AVPError> {
+        let avp = AVP::from_u32(1, 42);
+        assert_eq!(avp.encode_u32()?, avp.decode_u32()?);
+        let avp = AVP::from_string(1, "value");
+        assert_eq!(avp.encode_string()?, "value");
+        assert_eq!(avp.encode_bytes(), b"value");
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_jumbo_value() {
+        assert_eq!(
+            AVP::from_bytes(1, &[0; MAX_VALUE_LENGTH])
+                .unwrap()
+                .value
+                .len(),
+            MAX_VALUE_LENGTH
+        );
+        assert_eq!(
+            AVP::from_bytes(1, &[0; MAX_VALUE_LENGTH + 1]).unwrap_err(),
+            AVPError::InvalidAttributeLengthError("<= 253 bytes".to_owned(), 254)
+        );
+        assert_eq!(
+            JumboAttributePolicy::Reject
+                .split(1, &[0; MAX_VALUE_LENGTH + 1])
+                .unwrap_err(),
+            AVPError::InvalidAttributeLengthError("<= 253 bytes".to_owned(), 254)
+        );
+    }
+
+    #[test]
+    fn should_split_and_join_jumbo_value_by_concat() -> Result<(), AVPError> {
+        let value: Vec<u8> = (0..600).map(|i| i as u8).collect();
+        let avps = JumboAttributePolicy::Concat.split(79, &value)?;
+        assert_eq!(
+            avps.iter().map(|avp| avp.value.len()).collect::<Vec<_>>(),
+            vec![253, 253, 94]
+        );
+        let refs = avps.iter().collect::<Vec<_>>();
+        assert_eq!(JumboAttributePolicy::Concat.join(&refs).unwrap()?, value);
+        assert_eq!(JumboAttributePolicy::Concat.join(&[]), None);
+        Ok(())
+    }
+
+    #[test]
+    fn should_split_and_join_jumbo_value_by_long_extended() -> Result<(), AVPError> {
+        let mut value = vec![1];
+        value.extend((0..600).map(|i| i as u8));
+        let avps = JumboAttributePolicy::LongExtended.split(245, &value)?;
+        assert_eq!(avps.len(), 3);
+        for (avp, flags) in avps.iter().zip([0x80, 0x80, 0x00]) {
+            assert_eq!(avp.value[..2], [1, flags]);
+        }
+        assert_eq!(avps[0].value.len() - 2, MAX_LONG_EXTENDED_FRAGMENT_LENGTH);
+
+        // the fragments of another Extended-Type are skipped
+        let other = AVP::from_bytes(245, &[2, 0, 0xff])?;
+        let refs = [&avps[0], &other, &avps[1], &avps[2]];
+        assert_eq!(
+            JumboAttributePolicy::LongExtended.join(&refs).unwrap()?,
+            value
+        );
+
+        assert_eq!(
+            JumboAttributePolicy::LongExtended
+                .join(&[&avps[0], &avps[1]])
+                .unwrap()
+                .unwrap_err(),
+            AVPError::DecodingError(
+                "the last fragment of the Long Extended Type attribute has the More flag"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            JumboAttributePolicy::LongExtended
+                .split(26, &value)
+                .unwrap_err(),
+            AVPError::DecodingError("type 26 is not a Long Extended Type".to_owned())
+        );
+        Ok(())
+    }
+}
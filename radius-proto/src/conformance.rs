@@ -0,0 +1,169 @@
+//! Conformance suite of the decoder against the golden corpus of the wire-format packets in `testdata/corpus/`.
+//!
+//! Each fixture has the packet as hex and the expected decoded output (i.e. `Packet::display(Redact::Reveal)`,
+//! or `error: ...` for the malformed packets); see `testdata/corpus/README.md` for the format.
+//! Run the tests with `RADIUS_CORPUS_BLESS=1` to rewrite the expected outputs after an intended change of the output.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::fingerprint::{NasFingerprint, NasKind, NasVendor};
+use crate::packet::Packet;
+use crate::redact::Redact;
+
+const BLESS_ENV: &str = "RADIUS_CORPUS_BLESS";
+
+struct Fixture {
+    path: PathBuf,
+    /// The lines before `expected:`, that are kept as they are on blessing.
+    preamble: String,
+    secret: String,
+    round_trip: bool,
+    valid_message_authenticator: bool,
+    bytes: Vec<u8>,
+    expected: String,
+}
+
+impl Fixture {
+    fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let (preamble, expected) = content
+            .split_once("expected:\n")
+            .ok_or("`expected:` is missing")?;
+
+        let mut fixture = Fixture {
+            path: path.to_owned(),
+            preamble: preamble.to_owned(),
+            secret: String::new(),
+            round_trip: false,
+            valid_message_authenticator: false,
+            bytes: vec![],
+            expected: expected.to_owned(),
+        };
+        let mut in_hex = false;
+        for line in preamble.lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+            if in_hex && line.starts_with(' ') {
+                for hex in line.split_whitespace() {
+                    fixture
+                        .bytes
+                        .push(u8::from_str_radix(hex, 16).map_err(|e| format!("{hex}: {e}"))?);
+                }
+                continue;
+            }
+            in_hex = false;
+
+            match line.split_once(':').map(|(k, v)| (k, v.trim())) {
+                Some(("secret", secret)) => fixture.secret = secret.to_owned(),
+                Some(("round-trip", "exact")) => fixture.round_trip = true,
+                Some(("message-authenticator", "valid")) => {
+                    fixture.valid_message_authenticator = true
+                }
+                Some(("hex", "")) => in_hex = true,
+                _ => return Err(format!("unexpected line: {line}")),
+            }
+        }
+        Ok(fixture)
+    }
+
+    /// Decode the packet and verify the checks of the fixture, and returns the decoded output.
+    fn run(&self) -> Result<String, String> {
+        let packet = match Packet::decode(&self.bytes, self.secret.as_bytes()) {
+            Ok(packet) => packet,
+            Err(e) => return Ok(format!("error: {e}\n")),
+        };
+
+        if self.valid_message_authenticator && !packet.is_authentic_message_authenticator(None) {
+            return Err("Message-Authenticator is not authentic".to_owned());
+        }
+        if self.round_trip {
+            let encoded = packet.encode().map_err(|e| e.to_string())?;
+            if encoded != self.bytes {
+                return Err(format!(
+                    "the re-encoded packet differs from the original one: {encoded:02x?}"
+                ));
+            }
+        }
+        Ok(format!("{}\n", packet.display(Redact::Reveal)))
+    }
+}
+
+#[test]
+fn test_golden_corpus() {
+    let corpus_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/corpus");
+    let mut paths = fs::read_dir(&corpus_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect::<Vec<PathBuf>>();
+    paths.sort();
+    assert!(!paths.is_empty(), "no fixture in {corpus_dir:?}");
+
+    let bless = std::env::var_os(BLESS_ENV).is_some();
+    let mut failures = vec![];
+    for path in paths {
+        let result = Fixture::load(&path).and_then(|fixture| {
+            let actual = fixture.run()?;
+            if actual == fixture.expected {
+                return Ok(());
+            }
+            if bless {
+                fs::write(
+                    &fixture.path,
+                    format!("{}expected:\n{actual}", fixture.preamble),
+                )
+                .map_err(|e| e.to_string())?;
+                return Ok(());
+            }
+            Err(format!(
+                "the decoded output differs;\n--- expected\n{}--- actual\n{actual}",
+                fixture.expected
+            ))
+        });
+        if let Err(e) = result {
+            failures.push(format!("{}: {e}", path.display()));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} fixture(s) failed (set {BLESS_ENV}=1 to update the expected outputs):\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_corpus_fingerprints() {
+    let corpus_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/corpus");
+    for (name, vendor, kind) in [
+        (
+            "cisco-ios-access-request.txt",
+            Some(NasVendor::Cisco),
+            NasKind::Virtual,
+        ),
+        (
+            "juniper-mx-accounting-start.txt",
+            Some(NasVendor::Juniper),
+            NasKind::Ethernet,
+        ),
+        (
+            "mikrotik-accounting-interim.txt",
+            Some(NasVendor::MikroTik),
+            NasKind::Unknown,
+        ),
+        (
+            "aruba-8021x-eap-identity.txt",
+            Some(NasVendor::Aruba),
+            NasKind::Wireless,
+        ),
+        ("status-server.txt", None, NasKind::Unknown),
+    ] {
+        let fixture = Fixture::load(&corpus_dir.join(name)).unwrap();
+        let packet = Packet::decode(&fixture.bytes, fixture.secret.as_bytes()).unwrap();
+        let fingerprint = NasFingerprint::of(&packet);
+        assert_eq!(fingerprint.get_vendor(), vendor, "{name}");
+        assert_eq!(fingerprint.get_kind(), kind, "{name}");
+    }
+}
@@ -0,0 +1,337 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use chrono::{DateTime, Utc};
+
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::packet::Packet;
+use crate::tag::Tag;
+
+pub use radius_derive::{FromPacket, ToPacket};
+
+/// FromAvp is a conversion from an AVP into a value.
+///
+/// This is implemented for the types that correspond to the dictionary data types, so that
+/// `Packet::get()` and `Packet::get_all()` can decode a value by type inference.
+pub trait FromAvp: Sized {
+    /// Decode an AVP into a value.
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError>;
+}
+
+/// ToAvp is a conversion from a value into an AVP.
+///
+/// Note that the values that need the packet context to be encoded (e.g. `User-Password`) are not supported by this.
+pub trait ToAvp {
+    /// Encode a value into an AVP of the given type.
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError>;
+}
+
+/// FromPacket is a conversion from a whole packet into a value (e.g. a struct that has the fields for the attributes).
+///
+/// This can be derived by `#[derive(FromPacket)]` with the `#[radius(typ = ...)]` or `#[radius(attribute = "...")]` field attributes.
+pub trait FromPacket: Sized {
+    /// Decode the attributes of a packet into a value.
+    fn from_packet(packet: &Packet) -> Result<Self, AVPError>;
+}
+
+/// ToPacket is a conversion from a value into the attributes of a packet.
+///
+/// This can be derived by `#[derive(ToPacket)]` with the `#[radius(typ = ...)]` or `#[radius(attribute = "...")]` field attributes.
+pub trait ToPacket {
+    /// Encode a value into the attributes and add them to a packet.
+    fn to_packet(&self, packet: &mut Packet) -> Result<(), AVPError>;
+}
+
+impl FromAvp for String {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_string()
+    }
+}
+
+impl FromAvp for Vec<u8> {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        Ok(avp.decode_bytes())
+    }
+}
+
+impl FromAvp for u32 {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_u32()
+    }
+}
+
+impl FromAvp for i32 {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_i32()
+    }
+}
+
+impl FromAvp for u16 {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_u16()
+    }
+}
+
+impl FromAvp for Ipv4Addr {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_ipv4()
+    }
+}
+
+impl FromAvp for Ipv6Addr {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_ipv6()
+    }
+}
+
+impl FromAvp for IpAddr {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_combo_ip()
+    }
+}
+
+impl FromAvp for [u8; 6] {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_ether()
+    }
+}
+
+impl FromAvp for DateTime<Utc> {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_date()
+    }
+}
+
+impl FromAvp for (u32, Tag) {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_tagged_u32()
+    }
+}
+
+impl FromAvp for (String, Option<Tag>) {
+    fn from_avp(avp: &AVP) -> Result<Self, AVPError> {
+        avp.decode_tagged_string()
+    }
+}
+
+impl ToAvp for String {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_string(typ, self))
+    }
+}
+
+impl ToAvp for str {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_string(typ, self))
+    }
+}
+
+impl ToAvp for Vec<u8> {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        AVP::from_bytes(typ, self)
+    }
+}
+
+impl ToAvp for u32 {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_u32(typ, *self))
+    }
+}
+
+impl ToAvp for i32 {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_i32(typ, *self))
+    }
+}
+
+impl ToAvp for u16 {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_u16(typ, *self))
+    }
+}
+
+impl ToAvp for Ipv4Addr {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_ipv4(typ, self))
+    }
+}
+
+impl ToAvp for Ipv6Addr {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_ipv6(typ, self))
+    }
+}
+
+impl ToAvp for IpAddr {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_combo_ip(typ, self))
+    }
+}
+
+impl ToAvp for [u8; 6] {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_ether(typ, self))
+    }
+}
+
+impl ToAvp for DateTime<Utc> {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        AVP::from_date(typ, self)
+    }
+}
+
+impl ToAvp for (u32, Tag) {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_tagged_u32(typ, Some(&self.1), self.0))
+    }
+}
+
+impl ToAvp for (String, Option<Tag>) {
+    fn to_avp(&self, typ: AVPType) -> Result<AVP, AVPError> {
+        Ok(AVP::from_tagged_string(typ, self.1.as_ref(), &self.0))
+    }
+}
+
+#[cfg(all(test, feature = "rfc2866", feature = "rfc2868"))]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::avp::{AVPError, AVP};
+    use crate::code::Code;
+    use crate::convert::{FromPacket, ToAvp, ToPacket};
+    use crate::packet::Packet;
+    use crate::tag::Tag;
+    use crate::{rfc2865, rfc2866, rfc2868};
+
+    #[derive(Debug, PartialEq, FromPacket, ToPacket)]
+    #[radius(crate = "crate")]
+    struct AccessRequest {
+        #[radius(typ = rfc2865::USER_NAME_TYPE)]
+        user_name: String,
+        #[radius(typ = rfc2865::NAS_IP_ADDRESS_TYPE)]
+        nas_ip_address: Option<Ipv4Addr>,
+        #[radius(typ = rfc2865::STATE_TYPE)]
+        state: Option<Vec<u8>>,
+        #[radius(typ = rfc2865::REPLY_MESSAGE_TYPE)]
+        reply_messages: Vec<String>,
+        #[radius(typ = rfc2868::TUNNEL_TYPE_TYPE)]
+        tunnel_type: Option<(u32, Tag)>,
+    }
+
+    #[test]
+    fn test_get() {
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2865::add_nas_ip_address(&mut packet, &Ipv4Addr::new(192, 0, 2, 1));
+        rfc2865::add_reply_message(&mut packet, "first");
+        rfc2865::add_reply_message(&mut packet, "second");
+
+        let user_name: String = packet.get(rfc2865::USER_NAME_TYPE).unwrap().unwrap();
+        assert_eq!(user_name, "alice");
+        assert_eq!(
+            packet
+                .get::<Ipv4Addr>(rfc2865::NAS_IP_ADDRESS_TYPE)
+                .unwrap()
+                .unwrap(),
+            Ipv4Addr::new(192, 0, 2, 1)
+        );
+        assert!(packet.get::<u32>(rfc2865::NAS_PORT_TYPE).is_none());
+        // User-Name cannot be decoded as an integer
+        assert!(packet.get::<u32>(rfc2865::USER_NAME_TYPE).unwrap().is_err());
+
+        let reply_messages: Vec<String> = packet.get_all(rfc2865::REPLY_MESSAGE_TYPE).unwrap();
+        assert_eq!(reply_messages, vec!["first", "second"]);
+    }
+
+    #[derive(Debug, PartialEq, FromPacket, ToPacket)]
+    #[radius(crate = "crate")]
+    struct AccountingRequest {
+        #[radius(attribute = "Acct-Session-Id")]
+        session_id: String,
+        #[radius(attribute = "Acct-Input-Octets")]
+        input_octets: Option<u32>,
+    }
+
+    #[derive(Debug, FromPacket)]
+    #[radius(crate = "crate")]
+    struct UnknownAttribute {
+        #[radius(attribute = "No-Such-Attribute")]
+        _value: Option<String>,
+    }
+
+    #[test]
+    fn test_struct_mapping_by_attribute_name() {
+        let request = AccountingRequest {
+            session_id: "s1".to_owned(),
+            input_octets: Some(100),
+        };
+        let packet = Packet::from_struct(Code::AccountingRequest, b"12345", &request).unwrap();
+        assert_eq!(
+            rfc2866::lookup_acct_session_id(&packet).unwrap().unwrap(),
+            "s1"
+        );
+        assert_eq!(packet.to_struct::<AccountingRequest>().unwrap(), request);
+
+        let mut malformed = Packet::new(Code::AccountingRequest, b"12345");
+        rfc2866::add_acct_session_id(&mut malformed, "s1");
+        malformed.add(AVP::from_u16(rfc2866::ACCT_INPUT_OCTETS_TYPE, 1));
+        assert_eq!(
+            malformed
+                .to_struct::<AccountingRequest>()
+                .unwrap_err()
+                .to_string(),
+            "failed to decode `Acct-Input-Octets` attribute: invalid attribute length: expected=4 bytes, actual=2 bytes"
+        );
+
+        assert_eq!(
+            packet.to_struct::<UnknownAttribute>().unwrap_err(),
+            AVPError::UnknownAttributeNameError("No-Such-Attribute".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_to_avp() {
+        let avp = (rfc2868::TUNNEL_TYPE_L2TP.to_u32(), Tag::new(1))
+            .to_avp(rfc2868::TUNNEL_TYPE_TYPE)
+            .unwrap();
+        assert_eq!(
+            avp.decode_tagged_u32().unwrap(),
+            (rfc2868::TUNNEL_TYPE_L2TP.to_u32(), Tag::new(1))
+        );
+        assert_eq!(
+            "alice"
+                .to_avp(rfc2865::USER_NAME_TYPE)
+                .unwrap()
+                .decode_string()
+                .unwrap(),
+            "alice"
+        );
+    }
+
+    #[test]
+    fn test_derive() {
+        let request = AccessRequest {
+            user_name: "alice".to_owned(),
+            nas_ip_address: Some(Ipv4Addr::new(192, 0, 2, 1)),
+            state: None,
+            reply_messages: vec!["first".to_owned(), "second".to_owned()],
+            tunnel_type: Some((rfc2868::TUNNEL_TYPE_L2TP.to_u32(), Tag::new(1))),
+        };
+
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        request.to_packet(&mut packet).unwrap();
+        assert_eq!(
+            rfc2865::lookup_user_name(&packet).unwrap().unwrap(),
+            "alice"
+        );
+        assert!(rfc2865::lookup_state(&packet).is_none());
+
+        let decoded = AccessRequest::from_packet(&packet).unwrap();
+        assert_eq!(decoded, request);
+
+        let empty = Packet::new(Code::AccessRequest, b"12345");
+        assert_eq!(
+            AccessRequest::from_packet(&empty).unwrap_err(),
+            AVPError::MissingAttributeError(rfc2865::USER_NAME_TYPE)
+        );
+    }
+}
@@ -0,0 +1,970 @@
+//! Runtime lookup of the attributes by name, for the attribute manipulation that is driven by configurations.
+//!
+//! `Dictionary` maps the attribute names (e.g. `Framed-IP-Address`) and the named values (e.g. `Framed-User`)
+//! to the AVP types and the values, and converts the textual values according to the data types of the attributes.
+//! `Packet::lookup_by_name()` and `Packet::add_by_name()` use this, so that the attributes can be read and written
+//! without recompiling (e.g. by the rules that are written in a configuration file).
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use thiserror::Error;
+
+use crate::ascend::AscendIpFilter;
+use crate::avp::{AVPType, AVP};
+use crate::metadata::AttributeDataType;
+use crate::packet::Packet;
+use crate::registry;
+use crate::tag::Tag;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DictionaryError {
+    /// This error is raised when the attribute is not defined in the dictionary.
+    #[error("unknown attribute: {0}")]
+    UnknownAttributeError(String),
+
+    /// This error is raised when the textual value cannot be converted into the attribute, and vice versa.
+    #[error("invalid value of {0}: {1}")]
+    InvalidValueError(String, String),
+
+    /// This error is raised when the data type of the attribute doesn't have the textual representation.
+    #[error("the data type of {0} is not supported")]
+    UnsupportedDataTypeError(String),
+}
+
+/// This struct represents an attribute of the dictionary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictionaryAttribute {
+    name: String,
+    typ: AVPType,
+    data_type: AttributeDataType,
+    encrypted: bool,
+    has_tag: bool,
+}
+
+impl DictionaryAttribute {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_type(&self) -> AVPType {
+        self.typ
+    }
+
+    pub fn get_data_type(&self) -> AttributeDataType {
+        self.data_type
+    }
+
+    /// Returns whether the value is encrypted by the packet (e.g. `User-Password`, `Tunnel-Password`).
+    pub fn is_encrypted(&self) -> bool {
+        self.encrypted
+    }
+
+    pub fn has_tag(&self) -> bool {
+        self.has_tag
+    }
+}
+
+/// This enum represents the textual encoding of the octets values that `Dictionary::format_value()` makes.
+///
+/// `Dictionary::make_avp()` accepts both of those (i.e. `0x` and `base64:` prefixes) regardless of this.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum OctetsEncoding {
+    /// e.g. `0x0a0b0c`
+    #[default]
+    Hex,
+    /// e.g. `base64:CgsM`
+    Base64,
+}
+
+/// The attributes and the named values to look up by name at runtime.
+///
+/// The names are case-insensitive, and `-` and `_` are not distinguished in the names of the values
+/// (e.g. `Framed-User`, `FRAMED_USER`).
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    attributes: HashMap<String, DictionaryAttribute>,
+    names: HashMap<AVPType, String>,
+    values: HashMap<(AVPType, String), u32>,
+    value_names: HashMap<(AVPType, u32), String>,
+    octets_encoding: OctetsEncoding,
+}
+
+impl Dictionary {
+    /// Make an empty dictionary.
+    pub fn new() -> Self {
+        Dictionary::default()
+    }
+
+    /// Make a dictionary that has the attributes and the values of all of the enabled dictionary modules.
+    pub fn builtin() -> Self {
+        let mut dictionary = Dictionary::new();
+        for metadata in registry::ATTRIBUTE_TABLES
+            .iter()
+            .flat_map(|table| table.iter())
+        {
+            dictionary.insert_attribute(DictionaryAttribute {
+                name: metadata.name.to_owned(),
+                typ: metadata.typ,
+                data_type: metadata.data_type,
+                encrypted: metadata.encrypted,
+                has_tag: metadata.has_tag,
+            });
+        }
+        for metadata in registry::VALUE_TABLES.iter().flat_map(|table| table.iter()) {
+            dictionary.insert_value(metadata.typ, metadata.name, metadata.value);
+        }
+        dictionary
+    }
+
+    /// Add an attribute (e.g. a vendor specific one that isn't generated) to the dictionary.
+    ///
+    /// This replaces the attribute that has the same name.
+    pub fn add_attribute(
+        &mut self,
+        name: &str,
+        typ: AVPType,
+        data_type: AttributeDataType,
+        has_tag: bool,
+    ) {
+        self.insert_attribute(DictionaryAttribute {
+            name: name.to_owned(),
+            typ,
+            data_type,
+            encrypted: false,
+            has_tag,
+        });
+    }
+
+    /// Add a named value of the attribute to the dictionary.
+    pub fn add_value(
+        &mut self,
+        attribute: &str,
+        name: &str,
+        value: u32,
+    ) -> Result<(), DictionaryError> {
+        let typ = self.attribute(attribute)?.typ;
+        self.insert_value(typ, name, value);
+        Ok(())
+    }
+
+    pub fn get_octets_encoding(&self) -> OctetsEncoding {
+        self.octets_encoding
+    }
+
+    /// Set the encoding of the octets values that `format_value()` makes (default: `OctetsEncoding::Hex`).
+    pub fn set_octets_encoding(&mut self, octets_encoding: OctetsEncoding) {
+        self.octets_encoding = octets_encoding;
+    }
+
+    /// Returns the attribute of the name.
+    pub fn lookup_attribute(&self, name: &str) -> Option<&DictionaryAttribute> {
+        self.attributes.get(&name.to_ascii_lowercase())
+    }
+
+    /// Returns the name of the attribute of the AVP type.
+    pub fn attribute_name(&self, typ: AVPType) -> Option<&str> {
+        self.names.get(&typ).map(String::as_str)
+    }
+
+    /// Returns the value of the attribute for the name of the value (e.g. `Framed-User` of `Service-Type`).
+    pub fn value_by_name(&self, typ: AVPType, name: &str) -> Option<u32> {
+        self.values.get(&(typ, normalize_value_name(name))).copied()
+    }
+
+    /// Returns the name of the value of the attribute.
+    pub fn value_name(&self, typ: AVPType, value: u32) -> Option<&str> {
+        self.value_names.get(&(typ, value)).map(String::as_str)
+    }
+
+    /// Make an AVP from the textual value according to the data type of the attribute.
+    ///
+    /// The tagged attribute can be written like `Tunnel-Type:1`. The integer value can be either a number or a named value.
+    /// The octets value is the hex string that has `0x` prefix, the base64 string that has `base64:` prefix
+    /// (e.g. `base64:3q0=`), or the raw string without those.
+    ///
+    /// The encrypted attributes (e.g. `User-Password`) cannot be made without a packet; use `Packet::add_by_name()` for those.
+    pub fn make_avp(&self, attribute: &str, value: &str) -> Result<AVP, DictionaryError> {
+        let (attr, tag) = self.attribute_with_tag(attribute)?;
+        if attr.encrypted {
+            return Err(DictionaryError::InvalidValueError(
+                attr.name.clone(),
+                "needs to be encrypted by the packet".to_owned(),
+            ));
+        }
+        self.make_plain_avp(attr, tag.as_ref(), value)
+    }
+
+    /// Returns the textual value of the AVP according to the data type of the attribute;
+    /// this is the inverse of `make_avp()` except for the tag.
+    pub fn format_value(&self, avp: &AVP) -> Result<String, DictionaryError> {
+        let attr = self.attribute_of(avp.typ)?;
+        if attr.encrypted {
+            return Err(DictionaryError::InvalidValueError(
+                attr.name.clone(),
+                "needs to be decrypted by the packet".to_owned(),
+            ));
+        }
+        self.format_plain_value(attr, avp)
+    }
+
+    fn insert_attribute(&mut self, attr: DictionaryAttribute) {
+        self.names.insert(attr.typ, attr.name.clone());
+        self.attributes.insert(attr.name.to_ascii_lowercase(), attr);
+    }
+
+    fn insert_value(&mut self, typ: AVPType, name: &str, value: u32) {
+        self.values.insert((typ, normalize_value_name(name)), value);
+        self.value_names
+            .entry((typ, value))
+            .or_insert_with(|| name.to_owned());
+    }
+
+    fn attribute(&self, name: &str) -> Result<&DictionaryAttribute, DictionaryError> {
+        self.lookup_attribute(name)
+            .ok_or_else(|| DictionaryError::UnknownAttributeError(name.to_owned()))
+    }
+
+    fn attribute_of(&self, typ: AVPType) -> Result<&DictionaryAttribute, DictionaryError> {
+        self.attribute_name(typ)
+            .and_then(|name| self.lookup_attribute(name))
+            .ok_or_else(|| DictionaryError::UnknownAttributeError(format!("Attr-{typ}")))
+    }
+
+    /// Returns the attribute and the tag of `Name:tag`.
+    fn attribute_with_tag(
+        &self,
+        attribute: &str,
+    ) -> Result<(&DictionaryAttribute, Option<Tag>), DictionaryError> {
+        let Some((name, tag)) = attribute.rsplit_once(':') else {
+            return Ok((self.attribute(attribute)?, None));
+        };
+
+        let attr = self.attribute(name)?;
+        match tag.parse::<u8>() {
+            Ok(tag) if attr.has_tag => Ok((attr, Some(Tag::new(tag)))),
+            _ => Err(DictionaryError::InvalidValueError(
+                attr.name.clone(),
+                format!("invalid tag: {tag}"),
+            )),
+        }
+    }
+
+    fn make_plain_avp(
+        &self,
+        attr: &DictionaryAttribute,
+        tag: Option<&Tag>,
+        value: &str,
+    ) -> Result<AVP, DictionaryError> {
+        let typ = attr.typ;
+        let invalid = || DictionaryError::InvalidValueError(attr.name.clone(), value.to_owned());
+
+        Ok(match attr.data_type {
+            AttributeDataType::String => {
+                AVP::from_tagged_string(typ, tag.filter(|tag| !tag.is_zero()), value)
+            }
+            AttributeDataType::Octets => {
+                let bytes = decode_octets(value).ok_or_else(invalid)?;
+                if attr.has_tag {
+                    AVP::from_tagged_bytes(typ, tag.filter(|tag| !tag.is_zero()), &bytes)
+                } else {
+                    AVP::from_bytes_owned(typ, bytes)
+                }
+                .map_err(|_| invalid())?
+            }
+            AttributeDataType::IpAddr => {
+                let v = value.parse::<Ipv4Addr>().map_err(|_| invalid())?;
+                if attr.has_tag {
+                    AVP::from_tagged_ipv4(typ, tag, &v)
+                } else {
+                    AVP::from_ipv4(typ, &v)
+                }
+            }
+            AttributeDataType::Ipv6Addr => {
+                AVP::from_ipv6(typ, &value.parse::<Ipv6Addr>().map_err(|_| invalid())?)
+            }
+            AttributeDataType::IfId => {
+                let interface_id = decode_hex(&value.replace(':', "")).ok_or_else(invalid)?;
+                if interface_id.len() != 8 {
+                    return Err(invalid());
+                }
+                AVP::from_bytes_owned(typ, interface_id).map_err(|_| invalid())?
+            }
+            AttributeDataType::Integer => {
+                let v = match value.parse() {
+                    Ok(v) => v,
+                    Err(_) => self.value_by_name(typ, value).ok_or_else(invalid)?,
+                };
+                if attr.has_tag {
+                    AVP::from_tagged_u32(typ, tag, v)
+                } else {
+                    AVP::from_u32(typ, v)
+                }
+            }
+            AttributeDataType::Signed => AVP::from_i32(typ, value.parse().map_err(|_| invalid())?),
+            AttributeDataType::Short => {
+                let v = value.parse().map_err(|_| invalid())?;
+                if attr.has_tag {
+                    AVP::from_tagged_u16(typ, tag, v)
+                } else {
+                    AVP::from_u16(typ, v)
+                }
+            }
+            // the date value is a 32-bit unsigned UNIX time, so the tagged one is the same as the tagged integer on the wire
+            AttributeDataType::Date => {
+                let v = value.parse().map_err(|_| invalid())?;
+                if attr.has_tag {
+                    AVP::from_tagged_u32(typ, tag, v)
+                } else {
+                    AVP::from_u32(typ, v)
+                }
+            }
+            AttributeDataType::Ether => {
+                AVP::from_ether(typ, &decode_mac_address(value).ok_or_else(invalid)?)
+            }
+            AttributeDataType::ABinary => {
+                let filter = match value.strip_prefix("0x") {
+                    Some(hex) => decode_hex(hex).ok_or_else(invalid)?,
+                    None => value
+                        .parse::<AscendIpFilter>()
+                        .map_err(|_| invalid())?
+                        .encode(),
+                };
+                AVP::from_abinary(typ, &filter).map_err(|_| invalid())?
+            }
+            AttributeDataType::ComboIp => {
+                AVP::from_combo_ip(typ, &value.parse::<IpAddr>().map_err(|_| invalid())?)
+            }
+            AttributeDataType::Ipv4Prefix
+            | AttributeDataType::Ipv6Prefix
+            | AttributeDataType::VSA
+            | AttributeDataType::Unsupported => {
+                return Err(DictionaryError::UnsupportedDataTypeError(attr.name.clone()))
+            }
+        })
+    }
+
+    fn format_plain_value(
+        &self,
+        attr: &DictionaryAttribute,
+        avp: &AVP,
+    ) -> Result<String, DictionaryError> {
+        let invalid =
+            |e: &dyn ToString| DictionaryError::InvalidValueError(attr.name.clone(), e.to_string());
+
+        Ok(match (attr.data_type, attr.has_tag) {
+            (AttributeDataType::String, false) => avp.decode_string().map_err(|e| invalid(&e))?,
+            (AttributeDataType::String, true) => {
+                avp.decode_tagged_string().map_err(|e| invalid(&e))?.0
+            }
+            (AttributeDataType::Octets, false) => self.encode_octets(&avp.decode_bytes()),
+            (AttributeDataType::Octets, true) => {
+                let (v, _) = avp.decode_tagged_bytes().map_err(|e| invalid(&e))?;
+                self.encode_octets(&v)
+            }
+            (AttributeDataType::IpAddr, false) => {
+                avp.decode_ipv4().map_err(|e| invalid(&e))?.to_string()
+            }
+            (AttributeDataType::IpAddr, true) => avp
+                .decode_tagged_ipv4()
+                .map_err(|e| invalid(&e))?
+                .0
+                .to_string(),
+            (AttributeDataType::Ipv6Addr, _) => {
+                avp.decode_ipv6().map_err(|e| invalid(&e))?.to_string()
+            }
+            (AttributeDataType::IfId, _) => {
+                let interface_id = avp.decode_bytes();
+                if interface_id.len() != 8 {
+                    return Err(invalid(&"the interface id must be 8 bytes"));
+                }
+                interface_id
+                    .chunks(2)
+                    .map(encode_hex)
+                    .collect::<Vec<String>>()
+                    .join(":")
+            }
+            (AttributeDataType::Integer, has_tag) => {
+                let v = if has_tag {
+                    avp.decode_tagged_u32().map_err(|e| invalid(&e))?.0
+                } else {
+                    avp.decode_u32().map_err(|e| invalid(&e))?
+                };
+                match self.value_name(attr.typ, v) {
+                    Some(name) => name.to_owned(),
+                    None => v.to_string(),
+                }
+            }
+            (AttributeDataType::Signed, _) => {
+                avp.decode_i32().map_err(|e| invalid(&e))?.to_string()
+            }
+            (AttributeDataType::Short, false) => {
+                avp.decode_u16().map_err(|e| invalid(&e))?.to_string()
+            }
+            (AttributeDataType::Short, true) => avp
+                .decode_tagged_u16()
+                .map_err(|e| invalid(&e))?
+                .0
+                .to_string(),
+            (AttributeDataType::Date, false) => {
+                avp.decode_u32().map_err(|e| invalid(&e))?.to_string()
+            }
+            (AttributeDataType::Date, true) => avp
+                .decode_tagged_u32()
+                .map_err(|e| invalid(&e))?
+                .0
+                .to_string(),
+            (AttributeDataType::Ether, _) => {
+                encode_mac_address(&avp.decode_ether().map_err(|e| invalid(&e))?)
+            }
+            (AttributeDataType::ABinary, _) => {
+                let filter = avp.decode_abinary();
+                match AscendIpFilter::decode(&filter) {
+                    Ok(filter) => filter.to_string(),
+                    Err(_) => format!("0x{}", encode_hex(&filter)),
+                }
+            }
+            (AttributeDataType::ComboIp, _) => {
+                avp.decode_combo_ip().map_err(|e| invalid(&e))?.to_string()
+            }
+            (
+                AttributeDataType::Ipv4Prefix
+                | AttributeDataType::Ipv6Prefix
+                | AttributeDataType::VSA
+                | AttributeDataType::Unsupported,
+                _,
+            ) => return Err(DictionaryError::UnsupportedDataTypeError(attr.name.clone())),
+        })
+    }
+}
+
+impl Packet {
+    /// Returns the textual value of the first attribute of the name (e.g. `Framed-IP-Address`).
+    ///
+    /// The named value is returned for the integer attribute if the dictionary has that (e.g. `Framed-User`),
+    /// and the encrypted attributes (e.g. `User-Password`) are decrypted by the packet.
+    /// If the packet doesn't have the attribute, this returns `Ok(None)`.
+    pub fn lookup_by_name(
+        &self,
+        dictionary: &Dictionary,
+        name: &str,
+    ) -> Result<Option<String>, DictionaryError> {
+        let attr = dictionary.attribute(name)?;
+        self.lookup(attr.typ)
+            .map(|avp| self.format_value_by(dictionary, attr, avp))
+            .transpose()
+    }
+
+    /// Returns the textual values of all of the attributes of the name; see also `lookup_by_name()`.
+    pub fn lookup_all_by_name(
+        &self,
+        dictionary: &Dictionary,
+        name: &str,
+    ) -> Result<Vec<String>, DictionaryError> {
+        let attr = dictionary.attribute(name)?;
+        self.lookup_all(attr.typ)
+            .into_iter()
+            .map(|avp| self.format_value_by(dictionary, attr, avp))
+            .collect()
+    }
+
+    /// Add an attribute of the name (e.g. `Framed-IP-Address`, `Tunnel-Type:1`) with the textual value,
+    /// that is converted according to the data type of the attribute; see also `Dictionary::make_avp()`.
+    ///
+    /// The encrypted attributes (e.g. `User-Password`) are encrypted by the secret and the authenticator of the packet.
+    pub fn add_by_name(
+        &mut self,
+        dictionary: &Dictionary,
+        name: &str,
+        value: &str,
+    ) -> Result<(), DictionaryError> {
+        let (attr, tag) = dictionary.attribute_with_tag(name)?;
+        let avp = if attr.encrypted {
+            let encrypted = if attr.has_tag {
+                AVP::from_tunnel_password_with_random_source(
+                    attr.typ,
+                    tag.as_ref(),
+                    value.as_bytes(),
+                    self.get_secret(),
+                    self.get_authenticator(),
+                    self.get_random_source().as_ref(),
+                )
+            } else {
+                AVP::from_user_password(
+                    attr.typ,
+                    value.as_bytes(),
+                    self.get_secret(),
+                    self.get_authenticator(),
+                )
+            };
+            encrypted
+                .map_err(|e| DictionaryError::InvalidValueError(attr.name.clone(), e.to_string()))?
+        } else {
+            dictionary.make_plain_avp(attr, tag.as_ref(), value)?
+        };
+        self.add(avp);
+        Ok(())
+    }
+
+    /// Delete all of the attributes of the name.
+    pub fn delete_by_name(
+        &mut self,
+        dictionary: &Dictionary,
+        name: &str,
+    ) -> Result<(), DictionaryError> {
+        let attr = dictionary.attribute(name)?;
+        self.delete(attr.typ);
+        Ok(())
+    }
+
+    fn format_value_by(
+        &self,
+        dictionary: &Dictionary,
+        attr: &DictionaryAttribute,
+        avp: &AVP,
+    ) -> Result<String, DictionaryError> {
+        if !attr.encrypted {
+            return dictionary.format_plain_value(attr, avp);
+        }
+
+        let decrypted = if attr.has_tag {
+            avp.decode_tunnel_password(self.get_secret(), self.get_authenticator())
+                .map(|(plain_text, _)| plain_text)
+        } else {
+            avp.decode_user_password(self.get_secret(), self.get_authenticator())
+        };
+        decrypted
+            .map(|plain_text| String::from_utf8_lossy(&plain_text).into_owned())
+            .map_err(|e| DictionaryError::InvalidValueError(attr.name.clone(), e.to_string()))
+    }
+}
+
+impl Dictionary {
+    fn encode_octets(&self, bs: &[u8]) -> String {
+        match self.octets_encoding {
+            OctetsEncoding::Hex => format!("0x{}", encode_hex(bs)),
+            OctetsEncoding::Base64 => format!("base64:{}", encode_base64(bs)),
+        }
+    }
+}
+
+/// Decode the textual octets value, i.e. `0x` + hex, `base64:` + base64 or the raw string.
+fn decode_octets(value: &str) -> Option<Vec<u8>> {
+    if let Some(hex) = value.strip_prefix("0x") {
+        return decode_hex(hex);
+    }
+    if let Some(base64) = value.strip_prefix("base64:") {
+        return decode_base64(base64);
+    }
+    Some(value.as_bytes().to_vec())
+}
+
+fn normalize_value_name(name: &str) -> String {
+    name.to_ascii_lowercase().replace('_', "-")
+}
+
+/// Decode a hex string (e.g. `0a0b`) into the bytes; it returns `None` if the string is not a valid hex.
+pub fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Encode the bytes into a lowercase hex string.
+pub fn encode_hex(bs: &[u8]) -> String {
+    bs.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Decode the standard base64 (RFC 4648) text; the padding is optional.
+/// It returns `None` if the text is not a valid base64.
+pub fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=');
+    let mut decoded = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let v = BASE64_ALPHABET.iter().position(|a| *a == c)? as u32;
+        buffer = (buffer << 6) | v;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+        }
+    }
+    if bits >= 6 {
+        return None;
+    }
+    Some(decoded)
+}
+
+/// Encode the bytes into the standard base64 (RFC 4648) text with the padding.
+pub fn encode_base64(bs: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bs.len().div_ceil(3) * 4);
+    for chunk in bs.chunks(3) {
+        let buffer = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, b)| acc | (u32::from(*b) << (16 - 8 * i)));
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => encoded
+                    .push(BASE64_ALPHABET[((buffer >> (18 - 6 * i)) & 0x3f) as usize] as char),
+                false => encoded.push('='),
+            }
+        }
+    }
+    encoded
+}
+
+/// Decode the MAC address that is separated by `:` or `-` (e.g. `00:00:5e:00:53:01`).
+fn decode_mac_address(value: &str) -> Option<[u8; 6]> {
+    let octets = value
+        .split([':', '-'])
+        .map(|octet| match octet.len() {
+            2 => u8::from_str_radix(octet, 16).ok(),
+            _ => None,
+        })
+        .collect::<Option<Vec<u8>>>()?;
+    octets.try_into().ok()
+}
+
+/// Encode the MAC address into the lowercase colon separated form (e.g. `00:00:5e:00:53:01`).
+fn encode_mac_address(mac: &[u8; 6]) -> String {
+    mac.iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<Vec<String>>()
+        .join(":")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::avp::AVP;
+    use crate::code::Code;
+    use crate::dictionary::{
+        decode_base64, encode_base64, Dictionary, DictionaryError, OctetsEncoding,
+    };
+    use crate::metadata::AttributeDataType;
+    use crate::packet::Packet;
+    use crate::rfc2865;
+    use crate::tag::Tag;
+
+    #[test]
+    fn test_add_and_lookup_by_name() {
+        let dictionary = Dictionary::builtin();
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        packet
+            .add_by_name(&dictionary, "Framed-IP-Address", "192.0.2.10")
+            .unwrap();
+        packet
+            .add_by_name(&dictionary, "service-type", "FRAMED_USER")
+            .unwrap();
+        packet.add_by_name(&dictionary, "Class", "0x0102").unwrap();
+        packet.add_by_name(&dictionary, "Class", "group").unwrap();
+
+        assert_eq!(
+            rfc2865::lookup_framed_ip_address(&packet).unwrap().unwrap(),
+            Ipv4Addr::new(192, 0, 2, 10)
+        );
+        assert_eq!(
+            rfc2865::lookup_service_type(&packet).unwrap().unwrap(),
+            rfc2865::SERVICE_TYPE_FRAMED_USER
+        );
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "Framed-IP-Address"),
+            Ok(Some("192.0.2.10".to_owned()))
+        );
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "Service-Type"),
+            Ok(Some("Framed-User".to_owned()))
+        );
+        assert_eq!(
+            packet.lookup_all_by_name(&dictionary, "Class"),
+            Ok(vec!["0x0102".to_owned(), "0x67726f7570".to_owned()])
+        );
+        assert_eq!(packet.lookup_by_name(&dictionary, "Framed-MTU"), Ok(None));
+
+        packet.delete_by_name(&dictionary, "Class").unwrap();
+        assert!(!rfc2865::has_class(&packet));
+    }
+
+    #[test]
+    fn test_octets_encodings() {
+        let mut dictionary = Dictionary::builtin();
+        for value in ["0xdeadbeef", "base64:3q2+7w==", "base64:3q2+7w"] {
+            let avp = dictionary.make_avp("Class", value).unwrap();
+            assert_eq!(avp.decode_bytes(), vec![0xde, 0xad, 0xbe, 0xef]);
+        }
+        assert_eq!(
+            dictionary.make_avp("Class", "raw").unwrap().decode_bytes(),
+            b"raw".to_vec()
+        );
+        assert!(matches!(
+            dictionary.make_avp("Class", "base64:3q2+7w!"),
+            Err(DictionaryError::InvalidValueError(_, _))
+        ));
+
+        let avp = dictionary.make_avp("Class", "0xdeadbeef").unwrap();
+        assert_eq!(dictionary.format_value(&avp), Ok("0xdeadbeef".to_owned()));
+        dictionary.set_octets_encoding(OctetsEncoding::Base64);
+        assert_eq!(
+            dictionary.format_value(&avp),
+            Ok("base64:3q2+7w==".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_base64() {
+        for (bs, text) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(encode_base64(bs), text);
+            assert_eq!(decode_base64(text), Some(bs.to_vec()));
+        }
+        assert_eq!(decode_base64("Zm9vYg"), Some(b"foob".to_vec()));
+        assert_eq!(decode_base64("Zm9v!"), None);
+    }
+
+    #[test]
+    fn test_encrypted_attribute_by_name() {
+        let dictionary = Dictionary::builtin();
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        packet
+            .add_by_name(&dictionary, "User-Password", "p@ssw0rd")
+            .unwrap();
+        assert_eq!(
+            rfc2865::lookup_user_password(&packet).unwrap().unwrap(),
+            b"p@ssw0rd"
+        );
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "User-Password"),
+            Ok(Some("p@ssw0rd".to_owned()))
+        );
+
+        // the encrypted attribute cannot be made without the packet
+        assert!(matches!(
+            dictionary.make_avp("User-Password", "p@ssw0rd"),
+            Err(DictionaryError::InvalidValueError(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_name_and_value() {
+        let dictionary = Dictionary::builtin();
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        assert_eq!(
+            packet.add_by_name(&dictionary, "No-Such-Attribute", "1"),
+            Err(DictionaryError::UnknownAttributeError(
+                "No-Such-Attribute".to_owned()
+            ))
+        );
+        assert_eq!(
+            packet.add_by_name(&dictionary, "Framed-IP-Address", "192.0.2"),
+            Err(DictionaryError::InvalidValueError(
+                "Framed-IP-Address".to_owned(),
+                "192.0.2".to_owned()
+            ))
+        );
+        assert!(matches!(
+            packet.add_by_name(&dictionary, "Service-Type", "No-Such-Value"),
+            Err(DictionaryError::InvalidValueError(_, _))
+        ));
+        // `Service-Type` doesn't have a tag
+        assert!(matches!(
+            packet.add_by_name(&dictionary, "Service-Type:1", "Framed-User"),
+            Err(DictionaryError::InvalidValueError(_, _))
+        ));
+        assert_eq!(
+            packet.add_by_name(&dictionary, "Vendor-Specific", "0x00"),
+            Err(DictionaryError::UnsupportedDataTypeError(
+                "Vendor-Specific".to_owned()
+            ))
+        );
+        assert!(packet.get_avps().is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_data_type() {
+        let mut dictionary = Dictionary::new();
+        dictionary.add_attribute(
+            "Example-MAC-Address",
+            250,
+            AttributeDataType::Unsupported,
+            false,
+        );
+
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        assert_eq!(
+            packet.add_by_name(&dictionary, "Example-MAC-Address", "00:00:5e:00:53:01"),
+            Err(DictionaryError::UnsupportedDataTypeError(
+                "Example-MAC-Address".to_owned()
+            ))
+        );
+
+        // the raw octets are kept as they are, but they don't have the textual representation
+        packet.add(AVP::from_bytes(250, &[0x00, 0x00, 0x5e, 0x00, 0x53, 0x01]).unwrap());
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "Example-MAC-Address"),
+            Err(DictionaryError::UnsupportedDataTypeError(
+                "Example-MAC-Address".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_custom_attribute() {
+        let mut dictionary = Dictionary::new();
+        dictionary.add_attribute("Example-Level", 250, AttributeDataType::Integer, false);
+        dictionary.add_value("Example-Level", "High", 3).unwrap();
+        assert_eq!(
+            dictionary.add_value("No-Such-Attribute", "High", 3),
+            Err(DictionaryError::UnknownAttributeError(
+                "No-Such-Attribute".to_owned()
+            ))
+        );
+
+        let avp = dictionary.make_avp("Example-Level", "high").unwrap();
+        assert_eq!(avp.decode_u32(), Ok(3));
+        assert_eq!(dictionary.format_value(&avp), Ok("High".to_owned()));
+        assert_eq!(dictionary.attribute_name(250), Some("Example-Level"));
+
+        dictionary.add_attribute("Example-Offset", 251, AttributeDataType::Signed, false);
+        let avp = dictionary.make_avp("Example-Offset", "-3").unwrap();
+        assert_eq!(avp.decode_i32(), Ok(-3));
+        assert_eq!(dictionary.format_value(&avp), Ok("-3".to_owned()));
+
+        // the builtin attributes are not in the custom dictionary
+        assert!(dictionary.lookup_attribute("User-Name").is_none());
+    }
+
+    #[test]
+    fn test_ether_abinary_and_combo_ip() {
+        let mut dictionary = Dictionary::new();
+        dictionary.add_attribute("Example-MAC-Address", 250, AttributeDataType::Ether, false);
+        dictionary.add_attribute("Example-Filter", 251, AttributeDataType::ABinary, false);
+        dictionary.add_attribute("Example-Address", 252, AttributeDataType::ComboIp, false);
+
+        let avp = dictionary
+            .make_avp("Example-MAC-Address", "00-00-5E-00-53-01")
+            .unwrap();
+        assert_eq!(avp.decode_ether(), Ok([0x00, 0x00, 0x5e, 0x00, 0x53, 0x01]));
+        assert_eq!(
+            dictionary.format_value(&avp),
+            Ok("00:00:5e:00:53:01".to_owned())
+        );
+        assert!(matches!(
+            dictionary.make_avp("Example-MAC-Address", "00:00:5e:00:53"),
+            Err(DictionaryError::InvalidValueError(_, _))
+        ));
+
+        let avp = dictionary.make_avp("Example-Filter", "0x0101").unwrap();
+        assert_eq!(avp.decode_abinary(), vec![0x01, 0x01]);
+        assert_eq!(dictionary.format_value(&avp), Ok("0x0101".to_owned()));
+
+        let avp = dictionary
+            .make_avp(
+                "Example-Filter",
+                "ip in drop dstip 192.0.2.0/24 udp dstport = 53",
+            )
+            .unwrap();
+        assert_eq!(avp.decode_abinary().len(), 32);
+        assert_eq!(
+            dictionary.format_value(&avp),
+            Ok("ip in drop dstip 192.0.2.0/24 udp dstport = 53".to_owned())
+        );
+        assert!(matches!(
+            dictionary.make_avp("Example-Filter", "ip in drop dstip"),
+            Err(DictionaryError::InvalidValueError(_, _))
+        ));
+
+        for address in ["192.0.2.1", "2001:db8::1"] {
+            let avp = dictionary.make_avp("Example-Address", address).unwrap();
+            assert_eq!(dictionary.format_value(&avp), Ok(address.to_owned()));
+        }
+    }
+
+    #[test]
+    fn test_custom_tagged_attributes() {
+        let mut dictionary = Dictionary::new();
+        dictionary.add_attribute("Example-Address", 250, AttributeDataType::IpAddr, true);
+        dictionary.add_attribute("Example-Port", 251, AttributeDataType::Short, true);
+        dictionary.add_attribute("Example-Key", 252, AttributeDataType::Octets, true);
+
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        packet
+            .add_by_name(&dictionary, "Example-Address:1", "192.0.2.1")
+            .unwrap();
+        packet
+            .add_by_name(&dictionary, "Example-Port:2", "1812")
+            .unwrap();
+        packet
+            .add_by_name(&dictionary, "Example-Key:3", "0xdead")
+            .unwrap();
+
+        let address = packet.lookup(250).unwrap();
+        assert_eq!(
+            address.decode_tagged_ipv4(),
+            Ok((Ipv4Addr::new(192, 0, 2, 1), Tag::new(1)))
+        );
+        assert_eq!(
+            packet.lookup(252).unwrap().decode_tagged_bytes(),
+            Ok((vec![0xde, 0xad], Some(Tag::new(3))))
+        );
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "Example-Address"),
+            Ok(Some("192.0.2.1".to_owned()))
+        );
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "Example-Port"),
+            Ok(Some("1812".to_owned()))
+        );
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "Example-Key"),
+            Ok(Some("0xdead".to_owned()))
+        );
+    }
+
+    #[cfg(feature = "rfc2868")]
+    #[test]
+    fn test_tagged_attribute_by_name() {
+        use crate::rfc2868;
+
+        let dictionary = Dictionary::builtin();
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        packet
+            .add_by_name(&dictionary, "Tunnel-Type:1", "L2TP")
+            .unwrap();
+        packet
+            .add_by_name(&dictionary, "Tunnel-Password:2", "tunnel")
+            .unwrap();
+
+        let (tunnel_type, tag) = rfc2868::lookup_tunnel_type(&packet).unwrap().unwrap();
+        assert_eq!(tunnel_type, rfc2868::TUNNEL_TYPE_L2TP);
+        assert_eq!(tag.get_value(), 1);
+        let (tunnel_password, tag) = rfc2868::lookup_tunnel_password(&packet).unwrap().unwrap();
+        assert_eq!(tunnel_password, b"tunnel");
+        assert_eq!(tag.get_value(), 2);
+
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "Tunnel-Type"),
+            Ok(Some("L2TP".to_owned()))
+        );
+        assert_eq!(
+            packet.lookup_by_name(&dictionary, "Tunnel-Password"),
+            Ok(Some("tunnel".to_owned()))
+        );
+    }
+}
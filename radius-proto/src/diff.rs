@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use crate::avp::{AVPType, AVP};
+use crate::registry;
+
+/// This enum represents a difference of an attribute between two packets.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeDiff {
+    /// An attribute that exists only in the other (i.e. right-hand) packet.
+    Added(AVP),
+    /// An attribute that exists only in the base (i.e. left-hand) packet.
+    Removed(AVP),
+    /// An attribute that exists in both packets but has different values.
+    Changed { before: AVP, after: AVP },
+}
+
+impl AttributeDiff {
+    /// Returns the AVP type of the attribute that has the difference.
+    pub fn get_type(&self) -> AVPType {
+        match self {
+            AttributeDiff::Added(avp) => avp.typ,
+            AttributeDiff::Removed(avp) => avp.typ,
+            AttributeDiff::Changed { before, .. } => before.typ,
+        }
+    }
+
+    /// Returns the name of the attribute that has the difference if it is known.
+    pub fn get_name(&self) -> Option<&'static str> {
+        registry::attribute_name(self.get_type())
+    }
+}
+
+/// This struct represents the differences of attributes between two packets.
+///
+/// Attributes are compared per AVP type; when an attribute type appears multiple times,
+/// the occurrences are matched by their order in each packet.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PacketDiff {
+    diffs: Vec<AttributeDiff>,
+}
+
+impl PacketDiff {
+    pub(crate) fn new(base: &[AVP], other: &[AVP]) -> Self {
+        let mut grouped: BTreeMap<AVPType, (Vec<&AVP>, Vec<&AVP>)> = BTreeMap::new();
+        for avp in base {
+            grouped.entry(avp.typ).or_default().0.push(avp);
+        }
+        for avp in other {
+            grouped.entry(avp.typ).or_default().1.push(avp);
+        }
+
+        let mut diffs = Vec::new();
+        for (before_avps, after_avps) in grouped.values() {
+            for i in 0..before_avps.len().max(after_avps.len()) {
+                match (before_avps.get(i), after_avps.get(i)) {
+                    (Some(before), Some(after)) => {
+                        if before.value != after.value {
+                            diffs.push(AttributeDiff::Changed {
+                                before: (*before).clone(),
+                                after: (*after).clone(),
+                            });
+                        }
+                    }
+                    (Some(before), None) => diffs.push(AttributeDiff::Removed((*before).clone())),
+                    (None, Some(after)) => diffs.push(AttributeDiff::Added((*after).clone())),
+                    (None, None) => {}
+                }
+            }
+        }
+
+        PacketDiff { diffs }
+    }
+
+    /// Returns whether there is no difference or not.
+    pub fn is_empty(&self) -> bool {
+        self.diffs.is_empty()
+    }
+
+    /// Returns all of the differences, ordered by AVP type.
+    pub fn get_diffs(&self) -> &[AttributeDiff] {
+        &self.diffs
+    }
+
+    /// Returns the attributes that exist only in the other packet.
+    pub fn added(&self) -> Vec<&AVP> {
+        self.diffs
+            .iter()
+            .filter_map(|diff| match diff {
+                AttributeDiff::Added(avp) => Some(avp),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the attributes that exist only in the base packet.
+    pub fn removed(&self) -> Vec<&AVP> {
+        self.diffs
+            .iter()
+            .filter_map(|diff| match diff {
+                AttributeDiff::Removed(avp) => Some(avp),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the pairs of attributes that have different values between the packets.
+    pub fn changed(&self) -> Vec<(&AVP, &AVP)> {
+        self.diffs
+            .iter()
+            .filter_map(|diff| match diff {
+                AttributeDiff::Changed { before, after } => Some((before, after)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::avp::AVP;
+    use crate::code::Code;
+    use crate::diff::AttributeDiff;
+    use crate::packet::Packet;
+    use crate::rfc2865;
+
+    #[test]
+    fn test_diff() {
+        let mut base = Packet::new(Code::AccessRequest, b"12345");
+        rfc2865::add_user_name(&mut base, "alice");
+        rfc2865::add_nas_port(&mut base, 1);
+        rfc2865::add_reply_message(&mut base, "first");
+        rfc2865::add_reply_message(&mut base, "second");
+
+        let mut other = base.clone();
+        rfc2865::delete_nas_port(&mut other);
+        rfc2865::delete_user_name(&mut other);
+        rfc2865::add_user_name(&mut other, "bob");
+        rfc2865::add_reply_message(&mut other, "third");
+        rfc2865::add_session_timeout(&mut other, 3600);
+
+        let diff = base.diff(&other);
+        assert!(!diff.is_empty());
+
+        let types = diff
+            .get_diffs()
+            .iter()
+            .map(|d| d.get_type())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            vec![
+                rfc2865::USER_NAME_TYPE,
+                rfc2865::NAS_PORT_TYPE,
+                rfc2865::REPLY_MESSAGE_TYPE,
+                rfc2865::SESSION_TIMEOUT_TYPE,
+            ]
+        );
+
+        let changed = diff.changed();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].0.decode_string().unwrap(), "alice");
+        assert_eq!(changed[0].1.decode_string().unwrap(), "bob");
+
+        let removed = diff.removed();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].decode_u32().unwrap(), 1);
+
+        let added = diff.added();
+        assert_eq!(added.len(), 2);
+        assert_eq!(added[0].decode_string().unwrap(), "third");
+        assert_eq!(added[1].decode_u32().unwrap(), 3600);
+
+        match &diff.get_diffs()[0] {
+            AttributeDiff::Changed { .. } => {}
+            d => panic!("unexpected diff: {:?}", d),
+        }
+        assert_eq!(diff.get_diffs()[0].get_name(), Some("User-Name"));
+        assert_eq!(
+            AttributeDiff::Added(AVP::from_bytes(250, b"unknown").unwrap()).get_name(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let mut base = Packet::new(Code::AccessRequest, b"12345");
+        rfc2865::add_user_name(&mut base, "alice");
+        let other = base.clone();
+
+        assert!(base.diff(&other).is_empty());
+    }
+}
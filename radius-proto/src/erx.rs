@@ -0,0 +1,3302 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for erx packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Juniper's (was Unisphere's) broadband RAS (ERX/E-series) vendor-specific attributes.
+//! #
+//! #    The tunnel and the service attributes are tagged as well as those of RFC 2868;
+//! #    e.g. `ERX-Service-Activate` and `ERX-Service-Volume` of the same tag describe a service.
+//! #
+//! #    $Id$
+//! #
+//! VENDOR        ERX                4874
+//!
+//! BEGIN-VENDOR    ERX
+//!
+//! ATTRIBUTE    ERX-Virtual-Router-Name            1    string
+//! ATTRIBUTE    ERX-Address-Pool-Name            2    string
+//! ATTRIBUTE    ERX-Local-Loopback-Interface        3    string
+//! ATTRIBUTE    ERX-Primary-Dns                4    ipaddr
+//! ATTRIBUTE    ERX-Secondary-Dns            5    ipaddr
+//! ATTRIBUTE    ERX-Primary-Wins            6    ipaddr
+//! ATTRIBUTE    ERX-Secondary-Wins            7    ipaddr
+//! ATTRIBUTE    ERX-Tunnel-Virtual-Router        8    string    has_tag
+//! ATTRIBUTE    ERX-Tunnel-Password            9    string    has_tag
+//! ATTRIBUTE    ERX-Ingress-Policy-Name            10    string
+//! ATTRIBUTE    ERX-Egress-Policy-Name            11    string
+//! ATTRIBUTE    ERX-Ingress-Statistics            12    integer
+//! ATTRIBUTE    ERX-Egress-Statistics            13    integer
+//! ATTRIBUTE    ERX-Atm-Service-Category        14    integer
+//! ATTRIBUTE    ERX-Atm-PCR                15    integer
+//! ATTRIBUTE    ERX-Atm-SCR                16    integer
+//! ATTRIBUTE    ERX-Atm-MBS                17    integer
+//! ATTRIBUTE    ERX-Cli-Initial-Access-Level        18    string
+//! ATTRIBUTE    ERX-Cli-Allow-All-VR-Access        19    integer
+//! ATTRIBUTE    ERX-Alternate-Cli-Access-Level        20    string
+//! ATTRIBUTE    ERX-Alternate-Cli-Vrouter-Name        21    string
+//! ATTRIBUTE    ERX-Sa-Validate                22    integer
+//! ATTRIBUTE    ERX-Igmp-Enable                23    integer
+//! ATTRIBUTE    ERX-Pppoe-Description            24    string
+//! ATTRIBUTE    ERX-Redirect-VR-Name            25    string
+//! ATTRIBUTE    ERX-Qos-Profile-Name            26    string
+//! ATTRIBUTE    ERX-Pppoe-Max-Sessions            27    integer
+//! ATTRIBUTE    ERX-Pppoe-Url                28    string
+//! ATTRIBUTE    ERX-Qos-Profile-Interface-Type        29    integer
+//! ATTRIBUTE    ERX-Tunnel-Nas-Port-Method        30    integer    has_tag
+//! ATTRIBUTE    ERX-Service-Bundle            31    string
+//! ATTRIBUTE    ERX-Tunnel-Tos                32    integer    has_tag
+//! ATTRIBUTE    ERX-Tunnel-Maximum-Sessions        33    integer    has_tag
+//! ATTRIBUTE    ERX-Framed-Ip-Route-Tag            34    string
+//! ATTRIBUTE    ERX-Dial-Out-Number            35    string
+//! ATTRIBUTE    ERX-PPP-Username            36    string
+//! ATTRIBUTE    ERX-PPP-Password            37    string
+//! ATTRIBUTE    ERX-PPP-Auth-Protocol            38    integer
+//! ATTRIBUTE    ERX-Minimum-BPS                39    integer
+//! ATTRIBUTE    ERX-Maximum-BPS                40    integer
+//! ATTRIBUTE    ERX-Bearer-Type                41    integer
+//! ATTRIBUTE    ERX-Input-Gigapkts            42    integer
+//! ATTRIBUTE    ERX-Output-Gigapkts            43    integer
+//! ATTRIBUTE    ERX-Tunnel-Interface-Id            44    string    has_tag
+//! ATTRIBUTE    ERX-IpV6-Virtual-Router            45    string
+//! ATTRIBUTE    ERX-IpV6-Local-Interface        46    string
+//! ATTRIBUTE    ERX-Ipv6-Primary-Dns            47    ipv6addr
+//! ATTRIBUTE    ERX-Ipv6-Secondary-Dns            48    ipv6addr
+//!
+//! ATTRIBUTE    ERX-Service-Activate            65    string    has_tag,group=service
+//! ATTRIBUTE    ERX-Service-Deactivate            66    string
+//! ATTRIBUTE    ERX-Service-Volume            67    integer    has_tag,group=service
+//! ATTRIBUTE    ERX-Service-Timeout            68    integer    has_tag,group=service
+//! ATTRIBUTE    ERX-Service-Statistics            69    integer    has_tag,group=service
+//!
+//! VALUE    ERX-Ingress-Statistics        disable            0
+//! VALUE    ERX-Ingress-Statistics        enable            1
+//!
+//! VALUE    ERX-Egress-Statistics        disable            0
+//! VALUE    ERX-Egress-Statistics        enable            1
+//!
+//! VALUE    ERX-Atm-Service-Category    UBR            1
+//! VALUE    ERX-Atm-Service-Category    UBRPCR            2
+//! VALUE    ERX-Atm-Service-Category    nrtVBR            3
+//! VALUE    ERX-Atm-Service-Category    CBR            4
+//!
+//! VALUE    ERX-Cli-Allow-All-VR-Access    disable            0
+//! VALUE    ERX-Cli-Allow-All-VR-Access    enable            1
+//!
+//! VALUE    ERX-Sa-Validate            disable            0
+//! VALUE    ERX-Sa-Validate            enable            1
+//!
+//! VALUE    ERX-Igmp-Enable            disable            0
+//! VALUE    ERX-Igmp-Enable            enable            1
+//!
+//! VALUE    ERX-Qos-Profile-Interface-Type    IP            1
+//! VALUE    ERX-Qos-Profile-Interface-Type    ATM            2
+//! VALUE    ERX-Qos-Profile-Interface-Type    HDLC            3
+//! VALUE    ERX-Qos-Profile-Interface-Type    ETHERNET        4
+//! VALUE    ERX-Qos-Profile-Interface-Type    SERVER-PORT        5
+//! VALUE    ERX-Qos-Profile-Interface-Type    ATM-1483        6
+//! VALUE    ERX-Qos-Profile-Interface-Type    FRAME-RELAY        7
+//! VALUE    ERX-Qos-Profile-Interface-Type    MPLS-MINOR        8
+//! VALUE    ERX-Qos-Profile-Interface-Type    CBF            9
+//! VALUE    ERX-Qos-Profile-Interface-Type    IP-TUNNEL        10
+//! VALUE    ERX-Qos-Profile-Interface-Type    VLAN-SUB        11
+//! VALUE    ERX-Qos-Profile-Interface-Type    PPPOE-SUB        12
+//!
+//! VALUE    ERX-Tunnel-Nas-Port-Method    None            0
+//! VALUE    ERX-Tunnel-Nas-Port-Method    CISCO-CLID        1
+//!
+//! VALUE    ERX-PPP-Auth-Protocol        None            0
+//! VALUE    ERX-PPP-Auth-Protocol        PAP            1
+//! VALUE    ERX-PPP-Auth-Protocol        CHAP            2
+//! VALUE    ERX-PPP-Auth-Protocol        PAP-CHAP        3
+//! VALUE    ERX-PPP-Auth-Protocol        CHAP-PAP        4
+//!
+//! VALUE    ERX-Bearer-Type            None            0
+//! VALUE    ERX-Bearer-Type            Analog            1
+//! VALUE    ERX-Bearer-Type            Digital            2
+//!
+//! VALUE    ERX-Service-Statistics        disabled        0
+//! VALUE    ERX-Service-Statistics        time            1
+//! VALUE    ERX-Service-Statistics        time-volume        2
+//!
+//! END-VENDOR    ERX
+//! ```
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::avp::{AVPError, AVP};
+use crate::metadata::{AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+use crate::tag::Tag;
+use crate::vsa::{Vendor, VendorFormat, VendorType, VENDOR_SPECIFIC_TYPE};
+
+/// The vendor of the `ERX` attributes, that are the sub-attributes of `Vendor-Specific`.
+pub const ERX_VENDOR: Vendor = Vendor::new(4874, VendorFormat::DEFAULT);
+
+pub const ERX_VIRTUAL_ROUTER_NAME_TYPE: VendorType = 1;
+/// Delete all of `erx_virtual_router_name` values from a packet.
+pub fn delete_erx_virtual_router_name(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_VIRTUAL_ROUTER_NAME_TYPE);
+}
+/// Returns whether a packet has `erx_virtual_router_name` without decoding the value.
+pub fn has_erx_virtual_router_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_VIRTUAL_ROUTER_NAME_TYPE)
+}
+/// Returns the number of `erx_virtual_router_name` attributes in a packet.
+pub fn count_erx_virtual_router_name(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_VIRTUAL_ROUTER_NAME_TYPE)
+}
+/// Add `erx_virtual_router_name` string value to a packet.
+pub fn add_erx_virtual_router_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_VIRTUAL_ROUTER_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_virtual_router_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_virtual_router_name`, it returns `None`.
+pub fn lookup_erx_virtual_router_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_VIRTUAL_ROUTER_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Virtual-Router-Name"))
+        })
+}
+/// Lookup all of the `erx_virtual_router_name` string value from a packet.
+pub fn lookup_all_erx_virtual_router_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_VIRTUAL_ROUTER_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Virtual-Router-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_ADDRESS_POOL_NAME_TYPE: VendorType = 2;
+/// Delete all of `erx_address_pool_name` values from a packet.
+pub fn delete_erx_address_pool_name(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_ADDRESS_POOL_NAME_TYPE);
+}
+/// Returns whether a packet has `erx_address_pool_name` without decoding the value.
+pub fn has_erx_address_pool_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_ADDRESS_POOL_NAME_TYPE)
+}
+/// Returns the number of `erx_address_pool_name` attributes in a packet.
+pub fn count_erx_address_pool_name(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_ADDRESS_POOL_NAME_TYPE)
+}
+/// Add `erx_address_pool_name` string value to a packet.
+pub fn add_erx_address_pool_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_ADDRESS_POOL_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_address_pool_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_address_pool_name`, it returns `None`.
+pub fn lookup_erx_address_pool_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_ADDRESS_POOL_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Address-Pool-Name"))
+        })
+}
+/// Lookup all of the `erx_address_pool_name` string value from a packet.
+pub fn lookup_all_erx_address_pool_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_ADDRESS_POOL_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Address-Pool-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_LOCAL_LOOPBACK_INTERFACE_TYPE: VendorType = 3;
+/// Delete all of `erx_local_loopback_interface` values from a packet.
+pub fn delete_erx_local_loopback_interface(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_LOCAL_LOOPBACK_INTERFACE_TYPE);
+}
+/// Returns whether a packet has `erx_local_loopback_interface` without decoding the value.
+pub fn has_erx_local_loopback_interface(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_LOCAL_LOOPBACK_INTERFACE_TYPE)
+}
+/// Returns the number of `erx_local_loopback_interface` attributes in a packet.
+pub fn count_erx_local_loopback_interface(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_LOCAL_LOOPBACK_INTERFACE_TYPE)
+}
+/// Add `erx_local_loopback_interface` string value to a packet.
+pub fn add_erx_local_loopback_interface(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_LOCAL_LOOPBACK_INTERFACE_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_local_loopback_interface` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_local_loopback_interface`, it returns `None`.
+pub fn lookup_erx_local_loopback_interface(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_LOCAL_LOOPBACK_INTERFACE_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Local-Loopback-Interface"))
+        })
+}
+/// Lookup all of the `erx_local_loopback_interface` string value from a packet.
+pub fn lookup_all_erx_local_loopback_interface(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_LOCAL_LOOPBACK_INTERFACE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Local-Loopback-Interface"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_PRIMARY_DNS_TYPE: VendorType = 4;
+/// Delete all of `erx_primary_dns` values from a packet.
+pub fn delete_erx_primary_dns(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_PRIMARY_DNS_TYPE);
+}
+/// Returns whether a packet has `erx_primary_dns` without decoding the value.
+pub fn has_erx_primary_dns(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_PRIMARY_DNS_TYPE)
+}
+/// Returns the number of `erx_primary_dns` attributes in a packet.
+pub fn count_erx_primary_dns(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_PRIMARY_DNS_TYPE)
+}
+/// Add `erx_primary_dns` ipaddr value to a packet.
+pub fn add_erx_primary_dns(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_PRIMARY_DNS_TYPE,
+        AVP::from_ipv4(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_primary_dns` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_primary_dns`, it returns `None`.
+pub fn lookup_erx_primary_dns(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_PRIMARY_DNS_TYPE)
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("ERX-Primary-Dns"))
+        })
+}
+/// Lookup all of the `erx_primary_dns` ipaddr value from a packet.
+pub fn lookup_all_erx_primary_dns(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_PRIMARY_DNS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("ERX-Primary-Dns"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_SECONDARY_DNS_TYPE: VendorType = 5;
+/// Delete all of `erx_secondary_dns` values from a packet.
+pub fn delete_erx_secondary_dns(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SECONDARY_DNS_TYPE);
+}
+/// Returns whether a packet has `erx_secondary_dns` without decoding the value.
+pub fn has_erx_secondary_dns(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SECONDARY_DNS_TYPE)
+}
+/// Returns the number of `erx_secondary_dns` attributes in a packet.
+pub fn count_erx_secondary_dns(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SECONDARY_DNS_TYPE)
+}
+/// Add `erx_secondary_dns` ipaddr value to a packet.
+pub fn add_erx_secondary_dns(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SECONDARY_DNS_TYPE,
+        AVP::from_ipv4(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_secondary_dns` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_secondary_dns`, it returns `None`.
+pub fn lookup_erx_secondary_dns(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SECONDARY_DNS_TYPE)
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("ERX-Secondary-Dns"))
+        })
+}
+/// Lookup all of the `erx_secondary_dns` ipaddr value from a packet.
+pub fn lookup_all_erx_secondary_dns(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SECONDARY_DNS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("ERX-Secondary-Dns"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_PRIMARY_WINS_TYPE: VendorType = 6;
+/// Delete all of `erx_primary_wins` values from a packet.
+pub fn delete_erx_primary_wins(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_PRIMARY_WINS_TYPE);
+}
+/// Returns whether a packet has `erx_primary_wins` without decoding the value.
+pub fn has_erx_primary_wins(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_PRIMARY_WINS_TYPE)
+}
+/// Returns the number of `erx_primary_wins` attributes in a packet.
+pub fn count_erx_primary_wins(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_PRIMARY_WINS_TYPE)
+}
+/// Add `erx_primary_wins` ipaddr value to a packet.
+pub fn add_erx_primary_wins(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_PRIMARY_WINS_TYPE,
+        AVP::from_ipv4(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_primary_wins` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_primary_wins`, it returns `None`.
+pub fn lookup_erx_primary_wins(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_PRIMARY_WINS_TYPE)
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("ERX-Primary-Wins"))
+        })
+}
+/// Lookup all of the `erx_primary_wins` ipaddr value from a packet.
+pub fn lookup_all_erx_primary_wins(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_PRIMARY_WINS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("ERX-Primary-Wins"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_SECONDARY_WINS_TYPE: VendorType = 7;
+/// Delete all of `erx_secondary_wins` values from a packet.
+pub fn delete_erx_secondary_wins(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SECONDARY_WINS_TYPE);
+}
+/// Returns whether a packet has `erx_secondary_wins` without decoding the value.
+pub fn has_erx_secondary_wins(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SECONDARY_WINS_TYPE)
+}
+/// Returns the number of `erx_secondary_wins` attributes in a packet.
+pub fn count_erx_secondary_wins(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SECONDARY_WINS_TYPE)
+}
+/// Add `erx_secondary_wins` ipaddr value to a packet.
+pub fn add_erx_secondary_wins(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SECONDARY_WINS_TYPE,
+        AVP::from_ipv4(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_secondary_wins` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_secondary_wins`, it returns `None`.
+pub fn lookup_erx_secondary_wins(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SECONDARY_WINS_TYPE)
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("ERX-Secondary-Wins"))
+        })
+}
+/// Lookup all of the `erx_secondary_wins` ipaddr value from a packet.
+pub fn lookup_all_erx_secondary_wins(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SECONDARY_WINS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("ERX-Secondary-Wins"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_TUNNEL_VIRTUAL_ROUTER_TYPE: VendorType = 8;
+/// Delete all of `erx_tunnel_virtual_router` values from a packet.
+pub fn delete_erx_tunnel_virtual_router(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_TUNNEL_VIRTUAL_ROUTER_TYPE);
+}
+/// Returns whether a packet has `erx_tunnel_virtual_router` without decoding the value.
+pub fn has_erx_tunnel_virtual_router(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_TUNNEL_VIRTUAL_ROUTER_TYPE)
+}
+/// Returns the number of `erx_tunnel_virtual_router` attributes in a packet.
+pub fn count_erx_tunnel_virtual_router(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_TUNNEL_VIRTUAL_ROUTER_TYPE)
+}
+/// Add `erx_tunnel_virtual_router` tagged string value to a packet.
+pub fn add_erx_tunnel_virtual_router(
+    packet: &mut Packet,
+    tag: Option<&Tag>,
+    value: impl AsRef<str>,
+) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        ERX_VENDOR.max_value_length() - usize::from(tag.is_some()),
+    );
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_TUNNEL_VIRTUAL_ROUTER_TYPE,
+        AVP::from_tagged_string(VENDOR_SPECIFIC_TYPE, tag, value),
+    );
+}
+/// Lookup a `erx_tunnel_virtual_router` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_tunnel_virtual_router`, it returns `None`.
+pub fn lookup_erx_tunnel_virtual_router(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_TUNNEL_VIRTUAL_ROUTER_TYPE)
+        .map(|v| {
+            v.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Virtual-Router"))
+        })
+}
+/// Lookup all of the `erx_tunnel_virtual_router` tagged string value from a packet.
+pub fn lookup_all_erx_tunnel_virtual_router(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_TUNNEL_VIRTUAL_ROUTER_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Virtual-Router"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_TUNNEL_PASSWORD_TYPE: VendorType = 9;
+/// Delete all of `erx_tunnel_password` values from a packet.
+pub fn delete_erx_tunnel_password(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_TUNNEL_PASSWORD_TYPE);
+}
+/// Returns whether a packet has `erx_tunnel_password` without decoding the value.
+pub fn has_erx_tunnel_password(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_TUNNEL_PASSWORD_TYPE)
+}
+/// Returns the number of `erx_tunnel_password` attributes in a packet.
+pub fn count_erx_tunnel_password(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_TUNNEL_PASSWORD_TYPE)
+}
+/// Add `erx_tunnel_password` tagged string value to a packet.
+pub fn add_erx_tunnel_password(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        ERX_VENDOR.max_value_length() - usize::from(tag.is_some()),
+    );
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_TUNNEL_PASSWORD_TYPE,
+        AVP::from_tagged_string(VENDOR_SPECIFIC_TYPE, tag, value),
+    );
+}
+/// Lookup a `erx_tunnel_password` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_tunnel_password`, it returns `None`.
+pub fn lookup_erx_tunnel_password(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_TUNNEL_PASSWORD_TYPE)
+        .map(|v| {
+            v.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Password"))
+        })
+}
+/// Lookup all of the `erx_tunnel_password` tagged string value from a packet.
+pub fn lookup_all_erx_tunnel_password(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_TUNNEL_PASSWORD_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Password"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_INGRESS_POLICY_NAME_TYPE: VendorType = 10;
+/// Delete all of `erx_ingress_policy_name` values from a packet.
+pub fn delete_erx_ingress_policy_name(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_INGRESS_POLICY_NAME_TYPE);
+}
+/// Returns whether a packet has `erx_ingress_policy_name` without decoding the value.
+pub fn has_erx_ingress_policy_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_INGRESS_POLICY_NAME_TYPE)
+}
+/// Returns the number of `erx_ingress_policy_name` attributes in a packet.
+pub fn count_erx_ingress_policy_name(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_INGRESS_POLICY_NAME_TYPE)
+}
+/// Add `erx_ingress_policy_name` string value to a packet.
+pub fn add_erx_ingress_policy_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_INGRESS_POLICY_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_ingress_policy_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ingress_policy_name`, it returns `None`.
+pub fn lookup_erx_ingress_policy_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_INGRESS_POLICY_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Ingress-Policy-Name"))
+        })
+}
+/// Lookup all of the `erx_ingress_policy_name` string value from a packet.
+pub fn lookup_all_erx_ingress_policy_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_INGRESS_POLICY_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Ingress-Policy-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_EGRESS_POLICY_NAME_TYPE: VendorType = 11;
+/// Delete all of `erx_egress_policy_name` values from a packet.
+pub fn delete_erx_egress_policy_name(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_EGRESS_POLICY_NAME_TYPE);
+}
+/// Returns whether a packet has `erx_egress_policy_name` without decoding the value.
+pub fn has_erx_egress_policy_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_EGRESS_POLICY_NAME_TYPE)
+}
+/// Returns the number of `erx_egress_policy_name` attributes in a packet.
+pub fn count_erx_egress_policy_name(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_EGRESS_POLICY_NAME_TYPE)
+}
+/// Add `erx_egress_policy_name` string value to a packet.
+pub fn add_erx_egress_policy_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_EGRESS_POLICY_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_egress_policy_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_egress_policy_name`, it returns `None`.
+pub fn lookup_erx_egress_policy_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_EGRESS_POLICY_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Egress-Policy-Name"))
+        })
+}
+/// Lookup all of the `erx_egress_policy_name` string value from a packet.
+pub fn lookup_all_erx_egress_policy_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_EGRESS_POLICY_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Egress-Policy-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_INGRESS_STATISTICS_TYPE: VendorType = 12;
+/// Delete all of `erx_ingress_statistics` values from a packet.
+pub fn delete_erx_ingress_statistics(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_INGRESS_STATISTICS_TYPE);
+}
+/// Returns whether a packet has `erx_ingress_statistics` without decoding the value.
+pub fn has_erx_ingress_statistics(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_INGRESS_STATISTICS_TYPE)
+}
+/// Returns the number of `erx_ingress_statistics` attributes in a packet.
+pub fn count_erx_ingress_statistics(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_INGRESS_STATISTICS_TYPE)
+}
+/// Add `erx_ingress_statistics` value-defined integer value to a packet.
+pub fn add_erx_ingress_statistics(packet: &mut Packet, value: ErxIngressStatistics) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_INGRESS_STATISTICS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_ingress_statistics` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ingress_statistics`, it returns `None`.
+pub fn lookup_erx_ingress_statistics(
+    packet: &Packet,
+) -> Option<Result<ErxIngressStatistics, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_INGRESS_STATISTICS_TYPE)
+        .map(|v| {
+            Ok(ErxIngressStatistics::from_u32(v.decode_u32().map_err(
+                |e| e.with_attribute_name("ERX-Ingress-Statistics"),
+            )?))
+        })
+}
+/// Lookup all of the `erx_ingress_statistics` value-defined integer value from a packet.
+pub fn lookup_all_erx_ingress_statistics(
+    packet: &Packet,
+) -> Result<Vec<ErxIngressStatistics>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_INGRESS_STATISTICS_TYPE) {
+        vec.push(ErxIngressStatistics::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Ingress-Statistics"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ERX_EGRESS_STATISTICS_TYPE: VendorType = 13;
+/// Delete all of `erx_egress_statistics` values from a packet.
+pub fn delete_erx_egress_statistics(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_EGRESS_STATISTICS_TYPE);
+}
+/// Returns whether a packet has `erx_egress_statistics` without decoding the value.
+pub fn has_erx_egress_statistics(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_EGRESS_STATISTICS_TYPE)
+}
+/// Returns the number of `erx_egress_statistics` attributes in a packet.
+pub fn count_erx_egress_statistics(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_EGRESS_STATISTICS_TYPE)
+}
+/// Add `erx_egress_statistics` value-defined integer value to a packet.
+pub fn add_erx_egress_statistics(packet: &mut Packet, value: ErxEgressStatistics) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_EGRESS_STATISTICS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_egress_statistics` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_egress_statistics`, it returns `None`.
+pub fn lookup_erx_egress_statistics(
+    packet: &Packet,
+) -> Option<Result<ErxEgressStatistics, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_EGRESS_STATISTICS_TYPE)
+        .map(|v| {
+            Ok(ErxEgressStatistics::from_u32(v.decode_u32().map_err(
+                |e| e.with_attribute_name("ERX-Egress-Statistics"),
+            )?))
+        })
+}
+/// Lookup all of the `erx_egress_statistics` value-defined integer value from a packet.
+pub fn lookup_all_erx_egress_statistics(
+    packet: &Packet,
+) -> Result<Vec<ErxEgressStatistics>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_EGRESS_STATISTICS_TYPE) {
+        vec.push(ErxEgressStatistics::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Egress-Statistics"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ERX_ATM_SERVICE_CATEGORY_TYPE: VendorType = 14;
+/// Delete all of `erx_atm_service_category` values from a packet.
+pub fn delete_erx_atm_service_category(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_ATM_SERVICE_CATEGORY_TYPE);
+}
+/// Returns whether a packet has `erx_atm_service_category` without decoding the value.
+pub fn has_erx_atm_service_category(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_ATM_SERVICE_CATEGORY_TYPE)
+}
+/// Returns the number of `erx_atm_service_category` attributes in a packet.
+pub fn count_erx_atm_service_category(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_ATM_SERVICE_CATEGORY_TYPE)
+}
+/// Add `erx_atm_service_category` value-defined integer value to a packet.
+pub fn add_erx_atm_service_category(packet: &mut Packet, value: ErxAtmServiceCategory) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_ATM_SERVICE_CATEGORY_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_atm_service_category` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_atm_service_category`, it returns `None`.
+pub fn lookup_erx_atm_service_category(
+    packet: &Packet,
+) -> Option<Result<ErxAtmServiceCategory, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_ATM_SERVICE_CATEGORY_TYPE)
+        .map(|v| {
+            Ok(ErxAtmServiceCategory::from_u32(v.decode_u32().map_err(
+                |e| e.with_attribute_name("ERX-Atm-Service-Category"),
+            )?))
+        })
+}
+/// Lookup all of the `erx_atm_service_category` value-defined integer value from a packet.
+pub fn lookup_all_erx_atm_service_category(
+    packet: &Packet,
+) -> Result<Vec<ErxAtmServiceCategory>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_ATM_SERVICE_CATEGORY_TYPE) {
+        vec.push(ErxAtmServiceCategory::from_u32(avp.decode_u32().map_err(
+            |e| e.with_attribute_name("ERX-Atm-Service-Category"),
+        )?))
+    }
+    Ok(vec)
+}
+
+pub const ERX_ATM_PCR_TYPE: VendorType = 15;
+/// Delete all of `erx_atm_pcr` values from a packet.
+pub fn delete_erx_atm_pcr(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_ATM_PCR_TYPE);
+}
+/// Returns whether a packet has `erx_atm_pcr` without decoding the value.
+pub fn has_erx_atm_pcr(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_ATM_PCR_TYPE)
+}
+/// Returns the number of `erx_atm_pcr` attributes in a packet.
+pub fn count_erx_atm_pcr(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_ATM_PCR_TYPE)
+}
+/// Add `erx_atm_pcr` integer value to a packet.
+pub fn add_erx_atm_pcr(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_ATM_PCR_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_atm_pcr` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_atm_pcr`, it returns `None`.
+pub fn lookup_erx_atm_pcr(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup_vsa(&ERX_VENDOR, ERX_ATM_PCR_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("ERX-Atm-PCR"))
+    })
+}
+/// Lookup all of the `erx_atm_pcr` integer value from a packet.
+pub fn lookup_all_erx_atm_pcr(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_ATM_PCR_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Atm-PCR"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_ATM_SCR_TYPE: VendorType = 16;
+/// Delete all of `erx_atm_scr` values from a packet.
+pub fn delete_erx_atm_scr(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_ATM_SCR_TYPE);
+}
+/// Returns whether a packet has `erx_atm_scr` without decoding the value.
+pub fn has_erx_atm_scr(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_ATM_SCR_TYPE)
+}
+/// Returns the number of `erx_atm_scr` attributes in a packet.
+pub fn count_erx_atm_scr(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_ATM_SCR_TYPE)
+}
+/// Add `erx_atm_scr` integer value to a packet.
+pub fn add_erx_atm_scr(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_ATM_SCR_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_atm_scr` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_atm_scr`, it returns `None`.
+pub fn lookup_erx_atm_scr(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup_vsa(&ERX_VENDOR, ERX_ATM_SCR_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("ERX-Atm-SCR"))
+    })
+}
+/// Lookup all of the `erx_atm_scr` integer value from a packet.
+pub fn lookup_all_erx_atm_scr(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_ATM_SCR_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Atm-SCR"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_ATM_MBS_TYPE: VendorType = 17;
+/// Delete all of `erx_atm_mbs` values from a packet.
+pub fn delete_erx_atm_mbs(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_ATM_MBS_TYPE);
+}
+/// Returns whether a packet has `erx_atm_mbs` without decoding the value.
+pub fn has_erx_atm_mbs(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_ATM_MBS_TYPE)
+}
+/// Returns the number of `erx_atm_mbs` attributes in a packet.
+pub fn count_erx_atm_mbs(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_ATM_MBS_TYPE)
+}
+/// Add `erx_atm_mbs` integer value to a packet.
+pub fn add_erx_atm_mbs(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_ATM_MBS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_atm_mbs` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_atm_mbs`, it returns `None`.
+pub fn lookup_erx_atm_mbs(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup_vsa(&ERX_VENDOR, ERX_ATM_MBS_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("ERX-Atm-MBS"))
+    })
+}
+/// Lookup all of the `erx_atm_mbs` integer value from a packet.
+pub fn lookup_all_erx_atm_mbs(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_ATM_MBS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Atm-MBS"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_CLI_INITIAL_ACCESS_LEVEL_TYPE: VendorType = 18;
+/// Delete all of `erx_cli_initial_access_level` values from a packet.
+pub fn delete_erx_cli_initial_access_level(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_CLI_INITIAL_ACCESS_LEVEL_TYPE);
+}
+/// Returns whether a packet has `erx_cli_initial_access_level` without decoding the value.
+pub fn has_erx_cli_initial_access_level(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_CLI_INITIAL_ACCESS_LEVEL_TYPE)
+}
+/// Returns the number of `erx_cli_initial_access_level` attributes in a packet.
+pub fn count_erx_cli_initial_access_level(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_CLI_INITIAL_ACCESS_LEVEL_TYPE)
+}
+/// Add `erx_cli_initial_access_level` string value to a packet.
+pub fn add_erx_cli_initial_access_level(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_CLI_INITIAL_ACCESS_LEVEL_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_cli_initial_access_level` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_cli_initial_access_level`, it returns `None`.
+pub fn lookup_erx_cli_initial_access_level(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_CLI_INITIAL_ACCESS_LEVEL_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Cli-Initial-Access-Level"))
+        })
+}
+/// Lookup all of the `erx_cli_initial_access_level` string value from a packet.
+pub fn lookup_all_erx_cli_initial_access_level(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_CLI_INITIAL_ACCESS_LEVEL_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Cli-Initial-Access-Level"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_CLI_ALLOW_ALL_VR_ACCESS_TYPE: VendorType = 19;
+/// Delete all of `erx_cli_allow_all_vr_access` values from a packet.
+pub fn delete_erx_cli_allow_all_vr_access(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_CLI_ALLOW_ALL_VR_ACCESS_TYPE);
+}
+/// Returns whether a packet has `erx_cli_allow_all_vr_access` without decoding the value.
+pub fn has_erx_cli_allow_all_vr_access(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_CLI_ALLOW_ALL_VR_ACCESS_TYPE)
+}
+/// Returns the number of `erx_cli_allow_all_vr_access` attributes in a packet.
+pub fn count_erx_cli_allow_all_vr_access(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_CLI_ALLOW_ALL_VR_ACCESS_TYPE)
+}
+/// Add `erx_cli_allow_all_vr_access` value-defined integer value to a packet.
+pub fn add_erx_cli_allow_all_vr_access(packet: &mut Packet, value: ErxCliAllowAllVRAccess) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_CLI_ALLOW_ALL_VR_ACCESS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_cli_allow_all_vr_access` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_cli_allow_all_vr_access`, it returns `None`.
+pub fn lookup_erx_cli_allow_all_vr_access(
+    packet: &Packet,
+) -> Option<Result<ErxCliAllowAllVRAccess, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_CLI_ALLOW_ALL_VR_ACCESS_TYPE)
+        .map(|v| {
+            Ok(ErxCliAllowAllVRAccess::from_u32(v.decode_u32().map_err(
+                |e| e.with_attribute_name("ERX-Cli-Allow-All-VR-Access"),
+            )?))
+        })
+}
+/// Lookup all of the `erx_cli_allow_all_vr_access` value-defined integer value from a packet.
+pub fn lookup_all_erx_cli_allow_all_vr_access(
+    packet: &Packet,
+) -> Result<Vec<ErxCliAllowAllVRAccess>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_CLI_ALLOW_ALL_VR_ACCESS_TYPE) {
+        vec.push(ErxCliAllowAllVRAccess::from_u32(avp.decode_u32().map_err(
+            |e| e.with_attribute_name("ERX-Cli-Allow-All-VR-Access"),
+        )?))
+    }
+    Ok(vec)
+}
+
+pub const ERX_ALTERNATE_CLI_ACCESS_LEVEL_TYPE: VendorType = 20;
+/// Delete all of `erx_alternate_cli_access_level` values from a packet.
+pub fn delete_erx_alternate_cli_access_level(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_ACCESS_LEVEL_TYPE);
+}
+/// Returns whether a packet has `erx_alternate_cli_access_level` without decoding the value.
+pub fn has_erx_alternate_cli_access_level(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_ACCESS_LEVEL_TYPE)
+}
+/// Returns the number of `erx_alternate_cli_access_level` attributes in a packet.
+pub fn count_erx_alternate_cli_access_level(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_ACCESS_LEVEL_TYPE)
+}
+/// Add `erx_alternate_cli_access_level` string value to a packet.
+pub fn add_erx_alternate_cli_access_level(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_ALTERNATE_CLI_ACCESS_LEVEL_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_alternate_cli_access_level` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_alternate_cli_access_level`, it returns `None`.
+pub fn lookup_erx_alternate_cli_access_level(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_ACCESS_LEVEL_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Alternate-Cli-Access-Level"))
+        })
+}
+/// Lookup all of the `erx_alternate_cli_access_level` string value from a packet.
+pub fn lookup_all_erx_alternate_cli_access_level(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_ACCESS_LEVEL_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Alternate-Cli-Access-Level"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_ALTERNATE_CLI_VROUTER_NAME_TYPE: VendorType = 21;
+/// Delete all of `erx_alternate_cli_vrouter_name` values from a packet.
+pub fn delete_erx_alternate_cli_vrouter_name(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_VROUTER_NAME_TYPE);
+}
+/// Returns whether a packet has `erx_alternate_cli_vrouter_name` without decoding the value.
+pub fn has_erx_alternate_cli_vrouter_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_VROUTER_NAME_TYPE)
+}
+/// Returns the number of `erx_alternate_cli_vrouter_name` attributes in a packet.
+pub fn count_erx_alternate_cli_vrouter_name(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_VROUTER_NAME_TYPE)
+}
+/// Add `erx_alternate_cli_vrouter_name` string value to a packet.
+pub fn add_erx_alternate_cli_vrouter_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_ALTERNATE_CLI_VROUTER_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_alternate_cli_vrouter_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_alternate_cli_vrouter_name`, it returns `None`.
+pub fn lookup_erx_alternate_cli_vrouter_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_VROUTER_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Alternate-Cli-Vrouter-Name"))
+        })
+}
+/// Lookup all of the `erx_alternate_cli_vrouter_name` string value from a packet.
+pub fn lookup_all_erx_alternate_cli_vrouter_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_ALTERNATE_CLI_VROUTER_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Alternate-Cli-Vrouter-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_SA_VALIDATE_TYPE: VendorType = 22;
+/// Delete all of `erx_sa_validate` values from a packet.
+pub fn delete_erx_sa_validate(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SA_VALIDATE_TYPE);
+}
+/// Returns whether a packet has `erx_sa_validate` without decoding the value.
+pub fn has_erx_sa_validate(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SA_VALIDATE_TYPE)
+}
+/// Returns the number of `erx_sa_validate` attributes in a packet.
+pub fn count_erx_sa_validate(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SA_VALIDATE_TYPE)
+}
+/// Add `erx_sa_validate` value-defined integer value to a packet.
+pub fn add_erx_sa_validate(packet: &mut Packet, value: ErxSaValidate) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SA_VALIDATE_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_sa_validate` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_sa_validate`, it returns `None`.
+pub fn lookup_erx_sa_validate(packet: &Packet) -> Option<Result<ErxSaValidate, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SA_VALIDATE_TYPE)
+        .map(|v| {
+            Ok(ErxSaValidate::from_u32(
+                v.decode_u32()
+                    .map_err(|e| e.with_attribute_name("ERX-Sa-Validate"))?,
+            ))
+        })
+}
+/// Lookup all of the `erx_sa_validate` value-defined integer value from a packet.
+pub fn lookup_all_erx_sa_validate(packet: &Packet) -> Result<Vec<ErxSaValidate>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SA_VALIDATE_TYPE) {
+        vec.push(ErxSaValidate::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Sa-Validate"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ERX_IGMP_ENABLE_TYPE: VendorType = 23;
+/// Delete all of `erx_igmp_enable` values from a packet.
+pub fn delete_erx_igmp_enable(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_IGMP_ENABLE_TYPE);
+}
+/// Returns whether a packet has `erx_igmp_enable` without decoding the value.
+pub fn has_erx_igmp_enable(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_IGMP_ENABLE_TYPE)
+}
+/// Returns the number of `erx_igmp_enable` attributes in a packet.
+pub fn count_erx_igmp_enable(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_IGMP_ENABLE_TYPE)
+}
+/// Add `erx_igmp_enable` value-defined integer value to a packet.
+pub fn add_erx_igmp_enable(packet: &mut Packet, value: ErxIgmpEnable) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_IGMP_ENABLE_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_igmp_enable` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_igmp_enable`, it returns `None`.
+pub fn lookup_erx_igmp_enable(packet: &Packet) -> Option<Result<ErxIgmpEnable, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_IGMP_ENABLE_TYPE)
+        .map(|v| {
+            Ok(ErxIgmpEnable::from_u32(
+                v.decode_u32()
+                    .map_err(|e| e.with_attribute_name("ERX-Igmp-Enable"))?,
+            ))
+        })
+}
+/// Lookup all of the `erx_igmp_enable` value-defined integer value from a packet.
+pub fn lookup_all_erx_igmp_enable(packet: &Packet) -> Result<Vec<ErxIgmpEnable>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_IGMP_ENABLE_TYPE) {
+        vec.push(ErxIgmpEnable::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Igmp-Enable"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ERX_PPPOE_DESCRIPTION_TYPE: VendorType = 24;
+/// Delete all of `erx_pppoe_description` values from a packet.
+pub fn delete_erx_pppoe_description(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_PPPOE_DESCRIPTION_TYPE);
+}
+/// Returns whether a packet has `erx_pppoe_description` without decoding the value.
+pub fn has_erx_pppoe_description(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_PPPOE_DESCRIPTION_TYPE)
+}
+/// Returns the number of `erx_pppoe_description` attributes in a packet.
+pub fn count_erx_pppoe_description(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_PPPOE_DESCRIPTION_TYPE)
+}
+/// Add `erx_pppoe_description` string value to a packet.
+pub fn add_erx_pppoe_description(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_PPPOE_DESCRIPTION_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_pppoe_description` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_pppoe_description`, it returns `None`.
+pub fn lookup_erx_pppoe_description(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_PPPOE_DESCRIPTION_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Pppoe-Description"))
+        })
+}
+/// Lookup all of the `erx_pppoe_description` string value from a packet.
+pub fn lookup_all_erx_pppoe_description(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_PPPOE_DESCRIPTION_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Pppoe-Description"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_REDIRECT_VR_NAME_TYPE: VendorType = 25;
+/// Delete all of `erx_redirect_vr_name` values from a packet.
+pub fn delete_erx_redirect_vr_name(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_REDIRECT_VR_NAME_TYPE);
+}
+/// Returns whether a packet has `erx_redirect_vr_name` without decoding the value.
+pub fn has_erx_redirect_vr_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_REDIRECT_VR_NAME_TYPE)
+}
+/// Returns the number of `erx_redirect_vr_name` attributes in a packet.
+pub fn count_erx_redirect_vr_name(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_REDIRECT_VR_NAME_TYPE)
+}
+/// Add `erx_redirect_vr_name` string value to a packet.
+pub fn add_erx_redirect_vr_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_REDIRECT_VR_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_redirect_vr_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_redirect_vr_name`, it returns `None`.
+pub fn lookup_erx_redirect_vr_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_REDIRECT_VR_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Redirect-VR-Name"))
+        })
+}
+/// Lookup all of the `erx_redirect_vr_name` string value from a packet.
+pub fn lookup_all_erx_redirect_vr_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_REDIRECT_VR_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Redirect-VR-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_QOS_PROFILE_NAME_TYPE: VendorType = 26;
+/// Delete all of `erx_qos_profile_name` values from a packet.
+pub fn delete_erx_qos_profile_name(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_NAME_TYPE);
+}
+/// Returns whether a packet has `erx_qos_profile_name` without decoding the value.
+pub fn has_erx_qos_profile_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_NAME_TYPE)
+}
+/// Returns the number of `erx_qos_profile_name` attributes in a packet.
+pub fn count_erx_qos_profile_name(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_NAME_TYPE)
+}
+/// Add `erx_qos_profile_name` string value to a packet.
+pub fn add_erx_qos_profile_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_QOS_PROFILE_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_qos_profile_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_qos_profile_name`, it returns `None`.
+pub fn lookup_erx_qos_profile_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Qos-Profile-Name"))
+        })
+}
+/// Lookup all of the `erx_qos_profile_name` string value from a packet.
+pub fn lookup_all_erx_qos_profile_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Qos-Profile-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_PPPOE_MAX_SESSIONS_TYPE: VendorType = 27;
+/// Delete all of `erx_pppoe_max_sessions` values from a packet.
+pub fn delete_erx_pppoe_max_sessions(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_PPPOE_MAX_SESSIONS_TYPE);
+}
+/// Returns whether a packet has `erx_pppoe_max_sessions` without decoding the value.
+pub fn has_erx_pppoe_max_sessions(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_PPPOE_MAX_SESSIONS_TYPE)
+}
+/// Returns the number of `erx_pppoe_max_sessions` attributes in a packet.
+pub fn count_erx_pppoe_max_sessions(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_PPPOE_MAX_SESSIONS_TYPE)
+}
+/// Add `erx_pppoe_max_sessions` integer value to a packet.
+pub fn add_erx_pppoe_max_sessions(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_PPPOE_MAX_SESSIONS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_pppoe_max_sessions` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_pppoe_max_sessions`, it returns `None`.
+pub fn lookup_erx_pppoe_max_sessions(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_PPPOE_MAX_SESSIONS_TYPE)
+        .map(|v| {
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Pppoe-Max-Sessions"))
+        })
+}
+/// Lookup all of the `erx_pppoe_max_sessions` integer value from a packet.
+pub fn lookup_all_erx_pppoe_max_sessions(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_PPPOE_MAX_SESSIONS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Pppoe-Max-Sessions"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_PPPOE_URL_TYPE: VendorType = 28;
+/// Delete all of `erx_pppoe_url` values from a packet.
+pub fn delete_erx_pppoe_url(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_PPPOE_URL_TYPE);
+}
+/// Returns whether a packet has `erx_pppoe_url` without decoding the value.
+pub fn has_erx_pppoe_url(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_PPPOE_URL_TYPE)
+}
+/// Returns the number of `erx_pppoe_url` attributes in a packet.
+pub fn count_erx_pppoe_url(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_PPPOE_URL_TYPE)
+}
+/// Add `erx_pppoe_url` string value to a packet.
+pub fn add_erx_pppoe_url(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_PPPOE_URL_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_pppoe_url` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_pppoe_url`, it returns `None`.
+pub fn lookup_erx_pppoe_url(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup_vsa(&ERX_VENDOR, ERX_PPPOE_URL_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("ERX-Pppoe-Url"))
+    })
+}
+/// Lookup all of the `erx_pppoe_url` string value from a packet.
+pub fn lookup_all_erx_pppoe_url(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_PPPOE_URL_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Pppoe-Url"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_TYPE: VendorType = 29;
+/// Delete all of `erx_qos_profile_interface_type` values from a packet.
+pub fn delete_erx_qos_profile_interface_type(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_INTERFACE_TYPE_TYPE);
+}
+/// Returns whether a packet has `erx_qos_profile_interface_type` without decoding the value.
+pub fn has_erx_qos_profile_interface_type(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_INTERFACE_TYPE_TYPE)
+}
+/// Returns the number of `erx_qos_profile_interface_type` attributes in a packet.
+pub fn count_erx_qos_profile_interface_type(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_INTERFACE_TYPE_TYPE)
+}
+/// Add `erx_qos_profile_interface_type` value-defined integer value to a packet.
+pub fn add_erx_qos_profile_interface_type(packet: &mut Packet, value: ErxQosProfileInterfaceType) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_QOS_PROFILE_INTERFACE_TYPE_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_qos_profile_interface_type` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_qos_profile_interface_type`, it returns `None`.
+pub fn lookup_erx_qos_profile_interface_type(
+    packet: &Packet,
+) -> Option<Result<ErxQosProfileInterfaceType, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_INTERFACE_TYPE_TYPE)
+        .map(|v| {
+            Ok(ErxQosProfileInterfaceType::from_u32(
+                v.decode_u32()
+                    .map_err(|e| e.with_attribute_name("ERX-Qos-Profile-Interface-Type"))?,
+            ))
+        })
+}
+/// Lookup all of the `erx_qos_profile_interface_type` value-defined integer value from a packet.
+pub fn lookup_all_erx_qos_profile_interface_type(
+    packet: &Packet,
+) -> Result<Vec<ErxQosProfileInterfaceType>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_QOS_PROFILE_INTERFACE_TYPE_TYPE) {
+        vec.push(ErxQosProfileInterfaceType::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Qos-Profile-Interface-Type"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ERX_TUNNEL_NAS_PORT_METHOD_TYPE: VendorType = 30;
+/// Delete all of `erx_tunnel_nas_port_method` values from a packet.
+pub fn delete_erx_tunnel_nas_port_method(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_TUNNEL_NAS_PORT_METHOD_TYPE);
+}
+/// Returns whether a packet has `erx_tunnel_nas_port_method` without decoding the value.
+pub fn has_erx_tunnel_nas_port_method(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_TUNNEL_NAS_PORT_METHOD_TYPE)
+}
+/// Returns the number of `erx_tunnel_nas_port_method` attributes in a packet.
+pub fn count_erx_tunnel_nas_port_method(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_TUNNEL_NAS_PORT_METHOD_TYPE)
+}
+/// Add `erx_tunnel_nas_port_method` tagged value-defined integer value to a packet.
+pub fn add_erx_tunnel_nas_port_method(
+    packet: &mut Packet,
+    tag: Option<&Tag>,
+    value: ErxTunnelNasPortMethod,
+) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_TUNNEL_NAS_PORT_METHOD_TYPE,
+        AVP::from_tagged_u32(VENDOR_SPECIFIC_TYPE, tag, value.to_u32()),
+    );
+}
+/// Lookup a `erx_tunnel_nas_port_method` tagged value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_tunnel_nas_port_method`, it returns `None`.
+pub fn lookup_erx_tunnel_nas_port_method(
+    packet: &Packet,
+) -> Option<Result<(ErxTunnelNasPortMethod, Tag), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_TUNNEL_NAS_PORT_METHOD_TYPE)
+        .map(|v| {
+            let (v, t) = v
+                .decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Nas-Port-Method"))?;
+            Ok((ErxTunnelNasPortMethod::from_u32(v), t))
+        })
+}
+/// Lookup all of the `erx_tunnel_nas_port_method` tagged value-defined integer value from a packet.
+pub fn lookup_all_erx_tunnel_nas_port_method(
+    packet: &Packet,
+) -> Result<Vec<(ErxTunnelNasPortMethod, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_TUNNEL_NAS_PORT_METHOD_TYPE) {
+        let (v, t) = avp
+            .decode_tagged_u32()
+            .map_err(|e| e.with_attribute_name("ERX-Tunnel-Nas-Port-Method"))?;
+        vec.push((ErxTunnelNasPortMethod::from_u32(v), t))
+    }
+    Ok(vec)
+}
+
+pub const ERX_SERVICE_BUNDLE_TYPE: VendorType = 31;
+/// Delete all of `erx_service_bundle` values from a packet.
+pub fn delete_erx_service_bundle(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SERVICE_BUNDLE_TYPE);
+}
+/// Returns whether a packet has `erx_service_bundle` without decoding the value.
+pub fn has_erx_service_bundle(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SERVICE_BUNDLE_TYPE)
+}
+/// Returns the number of `erx_service_bundle` attributes in a packet.
+pub fn count_erx_service_bundle(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SERVICE_BUNDLE_TYPE)
+}
+/// Add `erx_service_bundle` string value to a packet.
+pub fn add_erx_service_bundle(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SERVICE_BUNDLE_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_service_bundle` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_service_bundle`, it returns `None`.
+pub fn lookup_erx_service_bundle(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SERVICE_BUNDLE_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Bundle"))
+        })
+}
+/// Lookup all of the `erx_service_bundle` string value from a packet.
+pub fn lookup_all_erx_service_bundle(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SERVICE_BUNDLE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Bundle"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_TUNNEL_TOS_TYPE: VendorType = 32;
+/// Delete all of `erx_tunnel_tos` values from a packet.
+pub fn delete_erx_tunnel_tos(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_TUNNEL_TOS_TYPE);
+}
+/// Returns whether a packet has `erx_tunnel_tos` without decoding the value.
+pub fn has_erx_tunnel_tos(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_TUNNEL_TOS_TYPE)
+}
+/// Returns the number of `erx_tunnel_tos` attributes in a packet.
+pub fn count_erx_tunnel_tos(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_TUNNEL_TOS_TYPE)
+}
+/// Add `erx_tunnel_tos` tagged integer value to a packet.
+pub fn add_erx_tunnel_tos(packet: &mut Packet, tag: Option<&Tag>, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_TUNNEL_TOS_TYPE,
+        AVP::from_tagged_u32(VENDOR_SPECIFIC_TYPE, tag, value),
+    );
+}
+/// Lookup a `erx_tunnel_tos` tagged integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_tunnel_tos`, it returns `None`.
+pub fn lookup_erx_tunnel_tos(packet: &Packet) -> Option<Result<(u32, Tag), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_TUNNEL_TOS_TYPE)
+        .map(|v| {
+            v.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Tos"))
+        })
+}
+/// Lookup all of the `erx_tunnel_tos` tagged integer value from a packet.
+pub fn lookup_all_erx_tunnel_tos(packet: &Packet) -> Result<Vec<(u32, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_TUNNEL_TOS_TYPE) {
+        vec.push(
+            avp.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Tos"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_TUNNEL_MAXIMUM_SESSIONS_TYPE: VendorType = 33;
+/// Delete all of `erx_tunnel_maximum_sessions` values from a packet.
+pub fn delete_erx_tunnel_maximum_sessions(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_TUNNEL_MAXIMUM_SESSIONS_TYPE);
+}
+/// Returns whether a packet has `erx_tunnel_maximum_sessions` without decoding the value.
+pub fn has_erx_tunnel_maximum_sessions(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_TUNNEL_MAXIMUM_SESSIONS_TYPE)
+}
+/// Returns the number of `erx_tunnel_maximum_sessions` attributes in a packet.
+pub fn count_erx_tunnel_maximum_sessions(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_TUNNEL_MAXIMUM_SESSIONS_TYPE)
+}
+/// Add `erx_tunnel_maximum_sessions` tagged integer value to a packet.
+pub fn add_erx_tunnel_maximum_sessions(packet: &mut Packet, tag: Option<&Tag>, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_TUNNEL_MAXIMUM_SESSIONS_TYPE,
+        AVP::from_tagged_u32(VENDOR_SPECIFIC_TYPE, tag, value),
+    );
+}
+/// Lookup a `erx_tunnel_maximum_sessions` tagged integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_tunnel_maximum_sessions`, it returns `None`.
+pub fn lookup_erx_tunnel_maximum_sessions(packet: &Packet) -> Option<Result<(u32, Tag), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_TUNNEL_MAXIMUM_SESSIONS_TYPE)
+        .map(|v| {
+            v.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Maximum-Sessions"))
+        })
+}
+/// Lookup all of the `erx_tunnel_maximum_sessions` tagged integer value from a packet.
+pub fn lookup_all_erx_tunnel_maximum_sessions(
+    packet: &Packet,
+) -> Result<Vec<(u32, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_TUNNEL_MAXIMUM_SESSIONS_TYPE) {
+        vec.push(
+            avp.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Maximum-Sessions"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_FRAMED_IP_ROUTE_TAG_TYPE: VendorType = 34;
+/// Delete all of `erx_framed_ip_route_tag` values from a packet.
+pub fn delete_erx_framed_ip_route_tag(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_FRAMED_IP_ROUTE_TAG_TYPE);
+}
+/// Returns whether a packet has `erx_framed_ip_route_tag` without decoding the value.
+pub fn has_erx_framed_ip_route_tag(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_FRAMED_IP_ROUTE_TAG_TYPE)
+}
+/// Returns the number of `erx_framed_ip_route_tag` attributes in a packet.
+pub fn count_erx_framed_ip_route_tag(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_FRAMED_IP_ROUTE_TAG_TYPE)
+}
+/// Add `erx_framed_ip_route_tag` string value to a packet.
+pub fn add_erx_framed_ip_route_tag(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_FRAMED_IP_ROUTE_TAG_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_framed_ip_route_tag` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_framed_ip_route_tag`, it returns `None`.
+pub fn lookup_erx_framed_ip_route_tag(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_FRAMED_IP_ROUTE_TAG_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Framed-Ip-Route-Tag"))
+        })
+}
+/// Lookup all of the `erx_framed_ip_route_tag` string value from a packet.
+pub fn lookup_all_erx_framed_ip_route_tag(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_FRAMED_IP_ROUTE_TAG_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Framed-Ip-Route-Tag"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_DIAL_OUT_NUMBER_TYPE: VendorType = 35;
+/// Delete all of `erx_dial_out_number` values from a packet.
+pub fn delete_erx_dial_out_number(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_DIAL_OUT_NUMBER_TYPE);
+}
+/// Returns whether a packet has `erx_dial_out_number` without decoding the value.
+pub fn has_erx_dial_out_number(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_DIAL_OUT_NUMBER_TYPE)
+}
+/// Returns the number of `erx_dial_out_number` attributes in a packet.
+pub fn count_erx_dial_out_number(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_DIAL_OUT_NUMBER_TYPE)
+}
+/// Add `erx_dial_out_number` string value to a packet.
+pub fn add_erx_dial_out_number(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_DIAL_OUT_NUMBER_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_dial_out_number` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_dial_out_number`, it returns `None`.
+pub fn lookup_erx_dial_out_number(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_DIAL_OUT_NUMBER_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Dial-Out-Number"))
+        })
+}
+/// Lookup all of the `erx_dial_out_number` string value from a packet.
+pub fn lookup_all_erx_dial_out_number(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_DIAL_OUT_NUMBER_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Dial-Out-Number"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_PPP_USERNAME_TYPE: VendorType = 36;
+/// Delete all of `erx_ppp_username` values from a packet.
+pub fn delete_erx_ppp_username(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_PPP_USERNAME_TYPE);
+}
+/// Returns whether a packet has `erx_ppp_username` without decoding the value.
+pub fn has_erx_ppp_username(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_PPP_USERNAME_TYPE)
+}
+/// Returns the number of `erx_ppp_username` attributes in a packet.
+pub fn count_erx_ppp_username(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_PPP_USERNAME_TYPE)
+}
+/// Add `erx_ppp_username` string value to a packet.
+pub fn add_erx_ppp_username(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_PPP_USERNAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_ppp_username` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ppp_username`, it returns `None`.
+pub fn lookup_erx_ppp_username(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_PPP_USERNAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-PPP-Username"))
+        })
+}
+/// Lookup all of the `erx_ppp_username` string value from a packet.
+pub fn lookup_all_erx_ppp_username(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_PPP_USERNAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-PPP-Username"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_PPP_PASSWORD_TYPE: VendorType = 37;
+/// Delete all of `erx_ppp_password` values from a packet.
+pub fn delete_erx_ppp_password(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_PPP_PASSWORD_TYPE);
+}
+/// Returns whether a packet has `erx_ppp_password` without decoding the value.
+pub fn has_erx_ppp_password(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_PPP_PASSWORD_TYPE)
+}
+/// Returns the number of `erx_ppp_password` attributes in a packet.
+pub fn count_erx_ppp_password(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_PPP_PASSWORD_TYPE)
+}
+/// Add `erx_ppp_password` string value to a packet.
+pub fn add_erx_ppp_password(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_PPP_PASSWORD_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_ppp_password` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ppp_password`, it returns `None`.
+pub fn lookup_erx_ppp_password(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_PPP_PASSWORD_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-PPP-Password"))
+        })
+}
+/// Lookup all of the `erx_ppp_password` string value from a packet.
+pub fn lookup_all_erx_ppp_password(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_PPP_PASSWORD_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-PPP-Password"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_PPP_AUTH_PROTOCOL_TYPE: VendorType = 38;
+/// Delete all of `erx_ppp_auth_protocol` values from a packet.
+pub fn delete_erx_ppp_auth_protocol(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_PPP_AUTH_PROTOCOL_TYPE);
+}
+/// Returns whether a packet has `erx_ppp_auth_protocol` without decoding the value.
+pub fn has_erx_ppp_auth_protocol(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_PPP_AUTH_PROTOCOL_TYPE)
+}
+/// Returns the number of `erx_ppp_auth_protocol` attributes in a packet.
+pub fn count_erx_ppp_auth_protocol(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_PPP_AUTH_PROTOCOL_TYPE)
+}
+/// Add `erx_ppp_auth_protocol` value-defined integer value to a packet.
+pub fn add_erx_ppp_auth_protocol(packet: &mut Packet, value: ErxPppAuthProtocol) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_PPP_AUTH_PROTOCOL_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_ppp_auth_protocol` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ppp_auth_protocol`, it returns `None`.
+pub fn lookup_erx_ppp_auth_protocol(
+    packet: &Packet,
+) -> Option<Result<ErxPppAuthProtocol, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_PPP_AUTH_PROTOCOL_TYPE)
+        .map(|v| {
+            Ok(ErxPppAuthProtocol::from_u32(v.decode_u32().map_err(
+                |e| e.with_attribute_name("ERX-PPP-Auth-Protocol"),
+            )?))
+        })
+}
+/// Lookup all of the `erx_ppp_auth_protocol` value-defined integer value from a packet.
+pub fn lookup_all_erx_ppp_auth_protocol(
+    packet: &Packet,
+) -> Result<Vec<ErxPppAuthProtocol>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_PPP_AUTH_PROTOCOL_TYPE) {
+        vec.push(ErxPppAuthProtocol::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-PPP-Auth-Protocol"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ERX_MINIMUM_BPS_TYPE: VendorType = 39;
+/// Delete all of `erx_minimum_bps` values from a packet.
+pub fn delete_erx_minimum_bps(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_MINIMUM_BPS_TYPE);
+}
+/// Returns whether a packet has `erx_minimum_bps` without decoding the value.
+pub fn has_erx_minimum_bps(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_MINIMUM_BPS_TYPE)
+}
+/// Returns the number of `erx_minimum_bps` attributes in a packet.
+pub fn count_erx_minimum_bps(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_MINIMUM_BPS_TYPE)
+}
+/// Add `erx_minimum_bps` integer value to a packet.
+pub fn add_erx_minimum_bps(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_MINIMUM_BPS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_minimum_bps` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_minimum_bps`, it returns `None`.
+pub fn lookup_erx_minimum_bps(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_MINIMUM_BPS_TYPE)
+        .map(|v| {
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Minimum-BPS"))
+        })
+}
+/// Lookup all of the `erx_minimum_bps` integer value from a packet.
+pub fn lookup_all_erx_minimum_bps(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_MINIMUM_BPS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Minimum-BPS"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_MAXIMUM_BPS_TYPE: VendorType = 40;
+/// Delete all of `erx_maximum_bps` values from a packet.
+pub fn delete_erx_maximum_bps(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_MAXIMUM_BPS_TYPE);
+}
+/// Returns whether a packet has `erx_maximum_bps` without decoding the value.
+pub fn has_erx_maximum_bps(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_MAXIMUM_BPS_TYPE)
+}
+/// Returns the number of `erx_maximum_bps` attributes in a packet.
+pub fn count_erx_maximum_bps(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_MAXIMUM_BPS_TYPE)
+}
+/// Add `erx_maximum_bps` integer value to a packet.
+pub fn add_erx_maximum_bps(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_MAXIMUM_BPS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_maximum_bps` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_maximum_bps`, it returns `None`.
+pub fn lookup_erx_maximum_bps(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_MAXIMUM_BPS_TYPE)
+        .map(|v| {
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Maximum-BPS"))
+        })
+}
+/// Lookup all of the `erx_maximum_bps` integer value from a packet.
+pub fn lookup_all_erx_maximum_bps(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_MAXIMUM_BPS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Maximum-BPS"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_BEARER_TYPE_TYPE: VendorType = 41;
+/// Delete all of `erx_bearer_type` values from a packet.
+pub fn delete_erx_bearer_type(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_BEARER_TYPE_TYPE);
+}
+/// Returns whether a packet has `erx_bearer_type` without decoding the value.
+pub fn has_erx_bearer_type(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_BEARER_TYPE_TYPE)
+}
+/// Returns the number of `erx_bearer_type` attributes in a packet.
+pub fn count_erx_bearer_type(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_BEARER_TYPE_TYPE)
+}
+/// Add `erx_bearer_type` value-defined integer value to a packet.
+pub fn add_erx_bearer_type(packet: &mut Packet, value: ErxBearerType) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_BEARER_TYPE_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `erx_bearer_type` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_bearer_type`, it returns `None`.
+pub fn lookup_erx_bearer_type(packet: &Packet) -> Option<Result<ErxBearerType, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_BEARER_TYPE_TYPE)
+        .map(|v| {
+            Ok(ErxBearerType::from_u32(
+                v.decode_u32()
+                    .map_err(|e| e.with_attribute_name("ERX-Bearer-Type"))?,
+            ))
+        })
+}
+/// Lookup all of the `erx_bearer_type` value-defined integer value from a packet.
+pub fn lookup_all_erx_bearer_type(packet: &Packet) -> Result<Vec<ErxBearerType>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_BEARER_TYPE_TYPE) {
+        vec.push(ErxBearerType::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Bearer-Type"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ERX_INPUT_GIGAPKTS_TYPE: VendorType = 42;
+/// Delete all of `erx_input_gigapkts` values from a packet.
+pub fn delete_erx_input_gigapkts(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_INPUT_GIGAPKTS_TYPE);
+}
+/// Returns whether a packet has `erx_input_gigapkts` without decoding the value.
+pub fn has_erx_input_gigapkts(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_INPUT_GIGAPKTS_TYPE)
+}
+/// Returns the number of `erx_input_gigapkts` attributes in a packet.
+pub fn count_erx_input_gigapkts(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_INPUT_GIGAPKTS_TYPE)
+}
+/// Add `erx_input_gigapkts` integer value to a packet.
+pub fn add_erx_input_gigapkts(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_INPUT_GIGAPKTS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_input_gigapkts` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_input_gigapkts`, it returns `None`.
+pub fn lookup_erx_input_gigapkts(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_INPUT_GIGAPKTS_TYPE)
+        .map(|v| {
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Input-Gigapkts"))
+        })
+}
+/// Lookup all of the `erx_input_gigapkts` integer value from a packet.
+pub fn lookup_all_erx_input_gigapkts(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_INPUT_GIGAPKTS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Input-Gigapkts"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_OUTPUT_GIGAPKTS_TYPE: VendorType = 43;
+/// Delete all of `erx_output_gigapkts` values from a packet.
+pub fn delete_erx_output_gigapkts(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_OUTPUT_GIGAPKTS_TYPE);
+}
+/// Returns whether a packet has `erx_output_gigapkts` without decoding the value.
+pub fn has_erx_output_gigapkts(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_OUTPUT_GIGAPKTS_TYPE)
+}
+/// Returns the number of `erx_output_gigapkts` attributes in a packet.
+pub fn count_erx_output_gigapkts(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_OUTPUT_GIGAPKTS_TYPE)
+}
+/// Add `erx_output_gigapkts` integer value to a packet.
+pub fn add_erx_output_gigapkts(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_OUTPUT_GIGAPKTS_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_output_gigapkts` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_output_gigapkts`, it returns `None`.
+pub fn lookup_erx_output_gigapkts(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_OUTPUT_GIGAPKTS_TYPE)
+        .map(|v| {
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Output-Gigapkts"))
+        })
+}
+/// Lookup all of the `erx_output_gigapkts` integer value from a packet.
+pub fn lookup_all_erx_output_gigapkts(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_OUTPUT_GIGAPKTS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Output-Gigapkts"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_TUNNEL_INTERFACE_ID_TYPE: VendorType = 44;
+/// Delete all of `erx_tunnel_interface_id` values from a packet.
+pub fn delete_erx_tunnel_interface_id(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_TUNNEL_INTERFACE_ID_TYPE);
+}
+/// Returns whether a packet has `erx_tunnel_interface_id` without decoding the value.
+pub fn has_erx_tunnel_interface_id(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_TUNNEL_INTERFACE_ID_TYPE)
+}
+/// Returns the number of `erx_tunnel_interface_id` attributes in a packet.
+pub fn count_erx_tunnel_interface_id(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_TUNNEL_INTERFACE_ID_TYPE)
+}
+/// Add `erx_tunnel_interface_id` tagged string value to a packet.
+pub fn add_erx_tunnel_interface_id(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        ERX_VENDOR.max_value_length() - usize::from(tag.is_some()),
+    );
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_TUNNEL_INTERFACE_ID_TYPE,
+        AVP::from_tagged_string(VENDOR_SPECIFIC_TYPE, tag, value),
+    );
+}
+/// Lookup a `erx_tunnel_interface_id` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_tunnel_interface_id`, it returns `None`.
+pub fn lookup_erx_tunnel_interface_id(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_TUNNEL_INTERFACE_ID_TYPE)
+        .map(|v| {
+            v.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Interface-Id"))
+        })
+}
+/// Lookup all of the `erx_tunnel_interface_id` tagged string value from a packet.
+pub fn lookup_all_erx_tunnel_interface_id(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_TUNNEL_INTERFACE_ID_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("ERX-Tunnel-Interface-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_IP_V6_VIRTUAL_ROUTER_TYPE: VendorType = 45;
+/// Delete all of `erx_ip_v6_virtual_router` values from a packet.
+pub fn delete_erx_ip_v6_virtual_router(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_IP_V6_VIRTUAL_ROUTER_TYPE);
+}
+/// Returns whether a packet has `erx_ip_v6_virtual_router` without decoding the value.
+pub fn has_erx_ip_v6_virtual_router(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_IP_V6_VIRTUAL_ROUTER_TYPE)
+}
+/// Returns the number of `erx_ip_v6_virtual_router` attributes in a packet.
+pub fn count_erx_ip_v6_virtual_router(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_IP_V6_VIRTUAL_ROUTER_TYPE)
+}
+/// Add `erx_ip_v6_virtual_router` string value to a packet.
+pub fn add_erx_ip_v6_virtual_router(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_IP_V6_VIRTUAL_ROUTER_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_ip_v6_virtual_router` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ip_v6_virtual_router`, it returns `None`.
+pub fn lookup_erx_ip_v6_virtual_router(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_IP_V6_VIRTUAL_ROUTER_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-IpV6-Virtual-Router"))
+        })
+}
+/// Lookup all of the `erx_ip_v6_virtual_router` string value from a packet.
+pub fn lookup_all_erx_ip_v6_virtual_router(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_IP_V6_VIRTUAL_ROUTER_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-IpV6-Virtual-Router"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_IP_V6_LOCAL_INTERFACE_TYPE: VendorType = 46;
+/// Delete all of `erx_ip_v6_local_interface` values from a packet.
+pub fn delete_erx_ip_v6_local_interface(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_IP_V6_LOCAL_INTERFACE_TYPE);
+}
+/// Returns whether a packet has `erx_ip_v6_local_interface` without decoding the value.
+pub fn has_erx_ip_v6_local_interface(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_IP_V6_LOCAL_INTERFACE_TYPE)
+}
+/// Returns the number of `erx_ip_v6_local_interface` attributes in a packet.
+pub fn count_erx_ip_v6_local_interface(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_IP_V6_LOCAL_INTERFACE_TYPE)
+}
+/// Add `erx_ip_v6_local_interface` string value to a packet.
+pub fn add_erx_ip_v6_local_interface(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_IP_V6_LOCAL_INTERFACE_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_ip_v6_local_interface` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ip_v6_local_interface`, it returns `None`.
+pub fn lookup_erx_ip_v6_local_interface(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_IP_V6_LOCAL_INTERFACE_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-IpV6-Local-Interface"))
+        })
+}
+/// Lookup all of the `erx_ip_v6_local_interface` string value from a packet.
+pub fn lookup_all_erx_ip_v6_local_interface(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_IP_V6_LOCAL_INTERFACE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-IpV6-Local-Interface"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_IPV_6_PRIMARY_DNS_TYPE: VendorType = 47;
+/// Delete all of `erx_ipv_6_primary_dns` values from a packet.
+pub fn delete_erx_ipv_6_primary_dns(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_IPV_6_PRIMARY_DNS_TYPE);
+}
+/// Returns whether a packet has `erx_ipv_6_primary_dns` without decoding the value.
+pub fn has_erx_ipv_6_primary_dns(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_IPV_6_PRIMARY_DNS_TYPE)
+}
+/// Returns the number of `erx_ipv_6_primary_dns` attributes in a packet.
+pub fn count_erx_ipv_6_primary_dns(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_IPV_6_PRIMARY_DNS_TYPE)
+}
+/// Add `erx_ipv_6_primary_dns` ipv6addr value to a packet.
+pub fn add_erx_ipv_6_primary_dns(packet: &mut Packet, value: &Ipv6Addr) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_IPV_6_PRIMARY_DNS_TYPE,
+        AVP::from_ipv6(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_ipv_6_primary_dns` ipv6addr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ipv_6_primary_dns`, it returns `None`.
+pub fn lookup_erx_ipv_6_primary_dns(packet: &Packet) -> Option<Result<Ipv6Addr, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_IPV_6_PRIMARY_DNS_TYPE)
+        .map(|v| {
+            v.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("ERX-Ipv6-Primary-Dns"))
+        })
+}
+/// Lookup all of the `erx_ipv_6_primary_dns` ipv6addr value from a packet.
+pub fn lookup_all_erx_ipv_6_primary_dns(packet: &Packet) -> Result<Vec<Ipv6Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_IPV_6_PRIMARY_DNS_TYPE) {
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("ERX-Ipv6-Primary-Dns"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_IPV_6_SECONDARY_DNS_TYPE: VendorType = 48;
+/// Delete all of `erx_ipv_6_secondary_dns` values from a packet.
+pub fn delete_erx_ipv_6_secondary_dns(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_IPV_6_SECONDARY_DNS_TYPE);
+}
+/// Returns whether a packet has `erx_ipv_6_secondary_dns` without decoding the value.
+pub fn has_erx_ipv_6_secondary_dns(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_IPV_6_SECONDARY_DNS_TYPE)
+}
+/// Returns the number of `erx_ipv_6_secondary_dns` attributes in a packet.
+pub fn count_erx_ipv_6_secondary_dns(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_IPV_6_SECONDARY_DNS_TYPE)
+}
+/// Add `erx_ipv_6_secondary_dns` ipv6addr value to a packet.
+pub fn add_erx_ipv_6_secondary_dns(packet: &mut Packet, value: &Ipv6Addr) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_IPV_6_SECONDARY_DNS_TYPE,
+        AVP::from_ipv6(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_ipv_6_secondary_dns` ipv6addr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_ipv_6_secondary_dns`, it returns `None`.
+pub fn lookup_erx_ipv_6_secondary_dns(packet: &Packet) -> Option<Result<Ipv6Addr, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_IPV_6_SECONDARY_DNS_TYPE)
+        .map(|v| {
+            v.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("ERX-Ipv6-Secondary-Dns"))
+        })
+}
+/// Lookup all of the `erx_ipv_6_secondary_dns` ipv6addr value from a packet.
+pub fn lookup_all_erx_ipv_6_secondary_dns(packet: &Packet) -> Result<Vec<Ipv6Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_IPV_6_SECONDARY_DNS_TYPE) {
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("ERX-Ipv6-Secondary-Dns"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_SERVICE_ACTIVATE_TYPE: VendorType = 65;
+/// Delete all of `erx_service_activate` values from a packet.
+pub fn delete_erx_service_activate(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SERVICE_ACTIVATE_TYPE);
+}
+/// Returns whether a packet has `erx_service_activate` without decoding the value.
+pub fn has_erx_service_activate(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SERVICE_ACTIVATE_TYPE)
+}
+/// Returns the number of `erx_service_activate` attributes in a packet.
+pub fn count_erx_service_activate(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SERVICE_ACTIVATE_TYPE)
+}
+/// Add `erx_service_activate` tagged string value to a packet.
+pub fn add_erx_service_activate(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        ERX_VENDOR.max_value_length() - usize::from(tag.is_some()),
+    );
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SERVICE_ACTIVATE_TYPE,
+        AVP::from_tagged_string(VENDOR_SPECIFIC_TYPE, tag, value),
+    );
+}
+/// Lookup a `erx_service_activate` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_service_activate`, it returns `None`.
+pub fn lookup_erx_service_activate(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SERVICE_ACTIVATE_TYPE)
+        .map(|v| {
+            v.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Activate"))
+        })
+}
+/// Lookup all of the `erx_service_activate` tagged string value from a packet.
+pub fn lookup_all_erx_service_activate(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SERVICE_ACTIVATE_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Activate"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_SERVICE_DEACTIVATE_TYPE: VendorType = 66;
+/// Delete all of `erx_service_deactivate` values from a packet.
+pub fn delete_erx_service_deactivate(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SERVICE_DEACTIVATE_TYPE);
+}
+/// Returns whether a packet has `erx_service_deactivate` without decoding the value.
+pub fn has_erx_service_deactivate(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SERVICE_DEACTIVATE_TYPE)
+}
+/// Returns the number of `erx_service_deactivate` attributes in a packet.
+pub fn count_erx_service_deactivate(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SERVICE_DEACTIVATE_TYPE)
+}
+/// Add `erx_service_deactivate` string value to a packet.
+pub fn add_erx_service_deactivate(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), ERX_VENDOR.max_value_length());
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SERVICE_DEACTIVATE_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `erx_service_deactivate` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_service_deactivate`, it returns `None`.
+pub fn lookup_erx_service_deactivate(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SERVICE_DEACTIVATE_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Deactivate"))
+        })
+}
+/// Lookup all of the `erx_service_deactivate` string value from a packet.
+pub fn lookup_all_erx_service_deactivate(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SERVICE_DEACTIVATE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Deactivate"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_SERVICE_VOLUME_TYPE: VendorType = 67;
+/// Delete all of `erx_service_volume` values from a packet.
+pub fn delete_erx_service_volume(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SERVICE_VOLUME_TYPE);
+}
+/// Returns whether a packet has `erx_service_volume` without decoding the value.
+pub fn has_erx_service_volume(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SERVICE_VOLUME_TYPE)
+}
+/// Returns the number of `erx_service_volume` attributes in a packet.
+pub fn count_erx_service_volume(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SERVICE_VOLUME_TYPE)
+}
+/// Add `erx_service_volume` tagged integer value to a packet.
+pub fn add_erx_service_volume(packet: &mut Packet, tag: Option<&Tag>, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SERVICE_VOLUME_TYPE,
+        AVP::from_tagged_u32(VENDOR_SPECIFIC_TYPE, tag, value),
+    );
+}
+/// Lookup a `erx_service_volume` tagged integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_service_volume`, it returns `None`.
+pub fn lookup_erx_service_volume(packet: &Packet) -> Option<Result<(u32, Tag), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SERVICE_VOLUME_TYPE)
+        .map(|v| {
+            v.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Volume"))
+        })
+}
+/// Lookup all of the `erx_service_volume` tagged integer value from a packet.
+pub fn lookup_all_erx_service_volume(packet: &Packet) -> Result<Vec<(u32, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SERVICE_VOLUME_TYPE) {
+        vec.push(
+            avp.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Volume"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_SERVICE_TIMEOUT_TYPE: VendorType = 68;
+/// Delete all of `erx_service_timeout` values from a packet.
+pub fn delete_erx_service_timeout(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SERVICE_TIMEOUT_TYPE);
+}
+/// Returns whether a packet has `erx_service_timeout` without decoding the value.
+pub fn has_erx_service_timeout(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SERVICE_TIMEOUT_TYPE)
+}
+/// Returns the number of `erx_service_timeout` attributes in a packet.
+pub fn count_erx_service_timeout(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SERVICE_TIMEOUT_TYPE)
+}
+/// Add `erx_service_timeout` tagged integer value to a packet.
+pub fn add_erx_service_timeout(packet: &mut Packet, tag: Option<&Tag>, value: u32) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SERVICE_TIMEOUT_TYPE,
+        AVP::from_tagged_u32(VENDOR_SPECIFIC_TYPE, tag, value),
+    );
+}
+/// Lookup a `erx_service_timeout` tagged integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_service_timeout`, it returns `None`.
+pub fn lookup_erx_service_timeout(packet: &Packet) -> Option<Result<(u32, Tag), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SERVICE_TIMEOUT_TYPE)
+        .map(|v| {
+            v.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Timeout"))
+        })
+}
+/// Lookup all of the `erx_service_timeout` tagged integer value from a packet.
+pub fn lookup_all_erx_service_timeout(packet: &Packet) -> Result<Vec<(u32, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SERVICE_TIMEOUT_TYPE) {
+        vec.push(
+            avp.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Timeout"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ERX_SERVICE_STATISTICS_TYPE: VendorType = 69;
+/// Delete all of `erx_service_statistics` values from a packet.
+pub fn delete_erx_service_statistics(packet: &mut Packet) {
+    packet.delete_vsa(&ERX_VENDOR, ERX_SERVICE_STATISTICS_TYPE);
+}
+/// Returns whether a packet has `erx_service_statistics` without decoding the value.
+pub fn has_erx_service_statistics(packet: &Packet) -> bool {
+    packet.contains_vsa(&ERX_VENDOR, ERX_SERVICE_STATISTICS_TYPE)
+}
+/// Returns the number of `erx_service_statistics` attributes in a packet.
+pub fn count_erx_service_statistics(packet: &Packet) -> usize {
+    packet.count_vsa(&ERX_VENDOR, ERX_SERVICE_STATISTICS_TYPE)
+}
+/// Add `erx_service_statistics` tagged value-defined integer value to a packet.
+pub fn add_erx_service_statistics(
+    packet: &mut Packet,
+    tag: Option<&Tag>,
+    value: ErxServiceStatistics,
+) {
+    packet.add_vsa(
+        &ERX_VENDOR,
+        ERX_SERVICE_STATISTICS_TYPE,
+        AVP::from_tagged_u32(VENDOR_SPECIFIC_TYPE, tag, value.to_u32()),
+    );
+}
+/// Lookup a `erx_service_statistics` tagged value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `erx_service_statistics`, it returns `None`.
+pub fn lookup_erx_service_statistics(
+    packet: &Packet,
+) -> Option<Result<(ErxServiceStatistics, Tag), AVPError>> {
+    packet
+        .lookup_vsa(&ERX_VENDOR, ERX_SERVICE_STATISTICS_TYPE)
+        .map(|v| {
+            let (v, t) = v
+                .decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("ERX-Service-Statistics"))?;
+            Ok((ErxServiceStatistics::from_u32(v), t))
+        })
+}
+/// Lookup all of the `erx_service_statistics` tagged value-defined integer value from a packet.
+pub fn lookup_all_erx_service_statistics(
+    packet: &Packet,
+) -> Result<Vec<(ErxServiceStatistics, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&ERX_VENDOR, ERX_SERVICE_STATISTICS_TYPE) {
+        let (v, t) = avp
+            .decode_tagged_u32()
+            .map_err(|e| e.with_attribute_name("ERX-Service-Statistics"))?;
+        vec.push((ErxServiceStatistics::from_u32(v), t))
+    }
+    Ok(vec)
+}
+/// This struct represents the `service` attributes that share the same tag.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ServiceGroup {
+    /// The value of `ERX-Service-Activate`.
+    pub erx_service_activate: Option<String>,
+    /// The value of `ERX-Service-Volume`.
+    pub erx_service_volume: Option<u32>,
+    /// The value of `ERX-Service-Timeout`.
+    pub erx_service_timeout: Option<u32>,
+    /// The value of `ERX-Service-Statistics`.
+    pub erx_service_statistics: Option<ErxServiceStatistics>,
+}
+/// Lookup the `service` attributes that have the given tag from a packet.
+///
+/// Each field has the first looked up value of the attribute with the tag; it is `None` if there is no such attribute.
+pub fn service_group(packet: &Packet, tag: &Tag) -> Result<ServiceGroup, AVPError> {
+    Ok(ServiceGroup {
+        erx_service_activate: lookup_all_erx_service_activate(packet)?
+            .into_iter()
+            .find(|(_, t)| t.as_ref().map_or(tag.is_zero(), |t| t == tag))
+            .map(|(v, _)| v),
+        erx_service_volume: lookup_all_erx_service_volume(packet)?
+            .into_iter()
+            .find(|(_, t)| t == tag)
+            .map(|(v, _)| v),
+        erx_service_timeout: lookup_all_erx_service_timeout(packet)?
+            .into_iter()
+            .find(|(_, t)| t == tag)
+            .map(|(v, _)| v),
+        erx_service_statistics: lookup_all_erx_service_statistics(packet)?
+            .into_iter()
+            .find(|(_, t)| t == tag)
+            .map(|(v, _)| v),
+    })
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[];
+
+/// The values of `ERX-Atm-Service-Category`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxAtmServiceCategory {
+    Ubr,
+    Ubrpcr,
+    NrtVBR,
+    Cbr,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxAtmServiceCategory {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => ErxAtmServiceCategory::Ubr,
+            2 => ErxAtmServiceCategory::Ubrpcr,
+            3 => ErxAtmServiceCategory::NrtVBR,
+            4 => ErxAtmServiceCategory::Cbr,
+            value => ErxAtmServiceCategory::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxAtmServiceCategory::Ubr => 1,
+            ErxAtmServiceCategory::Ubrpcr => 2,
+            ErxAtmServiceCategory::NrtVBR => 3,
+            ErxAtmServiceCategory::Cbr => 4,
+            ErxAtmServiceCategory::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxAtmServiceCategory {
+    fn from(value: u32) -> Self {
+        ErxAtmServiceCategory::from_u32(value)
+    }
+}
+impl From<ErxAtmServiceCategory> for u32 {
+    fn from(value: ErxAtmServiceCategory) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxAtmServiceCategory {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `UBR`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "UBR" => Ok(ErxAtmServiceCategory::Ubr),
+            "UBRPCR" => Ok(ErxAtmServiceCategory::Ubrpcr),
+            "nrtVBR" => Ok(ErxAtmServiceCategory::NrtVBR),
+            "CBR" => Ok(ErxAtmServiceCategory::Cbr),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Atm-Service-Category`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_ATM_SERVICE_CATEGORY_UBR: ErxAtmServiceCategory = ErxAtmServiceCategory::Ubr;
+pub const ERX_ATM_SERVICE_CATEGORY_UBRPCR: ErxAtmServiceCategory = ErxAtmServiceCategory::Ubrpcr;
+pub const ERX_ATM_SERVICE_CATEGORY_NRT_VBR: ErxAtmServiceCategory = ErxAtmServiceCategory::NrtVBR;
+pub const ERX_ATM_SERVICE_CATEGORY_CBR: ErxAtmServiceCategory = ErxAtmServiceCategory::Cbr;
+
+/// The values of `ERX-Bearer-Type`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxBearerType {
+    None,
+    Analog,
+    Digital,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxBearerType {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxBearerType::None,
+            1 => ErxBearerType::Analog,
+            2 => ErxBearerType::Digital,
+            value => ErxBearerType::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxBearerType::None => 0,
+            ErxBearerType::Analog => 1,
+            ErxBearerType::Digital => 2,
+            ErxBearerType::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxBearerType {
+    fn from(value: u32) -> Self {
+        ErxBearerType::from_u32(value)
+    }
+}
+impl From<ErxBearerType> for u32 {
+    fn from(value: ErxBearerType) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxBearerType {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `None`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "None" => Ok(ErxBearerType::None),
+            "Analog" => Ok(ErxBearerType::Analog),
+            "Digital" => Ok(ErxBearerType::Digital),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Bearer-Type`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_BEARER_TYPE_NONE: ErxBearerType = ErxBearerType::None;
+pub const ERX_BEARER_TYPE_ANALOG: ErxBearerType = ErxBearerType::Analog;
+pub const ERX_BEARER_TYPE_DIGITAL: ErxBearerType = ErxBearerType::Digital;
+
+/// The values of `ERX-Cli-Allow-All-VR-Access`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxCliAllowAllVRAccess {
+    Disable,
+    Enable,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxCliAllowAllVRAccess {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxCliAllowAllVRAccess::Disable,
+            1 => ErxCliAllowAllVRAccess::Enable,
+            value => ErxCliAllowAllVRAccess::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxCliAllowAllVRAccess::Disable => 0,
+            ErxCliAllowAllVRAccess::Enable => 1,
+            ErxCliAllowAllVRAccess::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxCliAllowAllVRAccess {
+    fn from(value: u32) -> Self {
+        ErxCliAllowAllVRAccess::from_u32(value)
+    }
+}
+impl From<ErxCliAllowAllVRAccess> for u32 {
+    fn from(value: ErxCliAllowAllVRAccess) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxCliAllowAllVRAccess {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `disable`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "disable" => Ok(ErxCliAllowAllVRAccess::Disable),
+            "enable" => Ok(ErxCliAllowAllVRAccess::Enable),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Cli-Allow-All-VR-Access`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_CLI_ALLOW_ALL_VR_ACCESS_DISABLE: ErxCliAllowAllVRAccess =
+    ErxCliAllowAllVRAccess::Disable;
+pub const ERX_CLI_ALLOW_ALL_VR_ACCESS_ENABLE: ErxCliAllowAllVRAccess =
+    ErxCliAllowAllVRAccess::Enable;
+
+/// The values of `ERX-Egress-Statistics`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxEgressStatistics {
+    Disable,
+    Enable,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxEgressStatistics {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxEgressStatistics::Disable,
+            1 => ErxEgressStatistics::Enable,
+            value => ErxEgressStatistics::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxEgressStatistics::Disable => 0,
+            ErxEgressStatistics::Enable => 1,
+            ErxEgressStatistics::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxEgressStatistics {
+    fn from(value: u32) -> Self {
+        ErxEgressStatistics::from_u32(value)
+    }
+}
+impl From<ErxEgressStatistics> for u32 {
+    fn from(value: ErxEgressStatistics) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxEgressStatistics {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `disable`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "disable" => Ok(ErxEgressStatistics::Disable),
+            "enable" => Ok(ErxEgressStatistics::Enable),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Egress-Statistics`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_EGRESS_STATISTICS_DISABLE: ErxEgressStatistics = ErxEgressStatistics::Disable;
+pub const ERX_EGRESS_STATISTICS_ENABLE: ErxEgressStatistics = ErxEgressStatistics::Enable;
+
+/// The values of `ERX-Igmp-Enable`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxIgmpEnable {
+    Disable,
+    Enable,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxIgmpEnable {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxIgmpEnable::Disable,
+            1 => ErxIgmpEnable::Enable,
+            value => ErxIgmpEnable::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxIgmpEnable::Disable => 0,
+            ErxIgmpEnable::Enable => 1,
+            ErxIgmpEnable::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxIgmpEnable {
+    fn from(value: u32) -> Self {
+        ErxIgmpEnable::from_u32(value)
+    }
+}
+impl From<ErxIgmpEnable> for u32 {
+    fn from(value: ErxIgmpEnable) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxIgmpEnable {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `disable`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "disable" => Ok(ErxIgmpEnable::Disable),
+            "enable" => Ok(ErxIgmpEnable::Enable),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Igmp-Enable`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_IGMP_ENABLE_DISABLE: ErxIgmpEnable = ErxIgmpEnable::Disable;
+pub const ERX_IGMP_ENABLE_ENABLE: ErxIgmpEnable = ErxIgmpEnable::Enable;
+
+/// The values of `ERX-Ingress-Statistics`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxIngressStatistics {
+    Disable,
+    Enable,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxIngressStatistics {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxIngressStatistics::Disable,
+            1 => ErxIngressStatistics::Enable,
+            value => ErxIngressStatistics::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxIngressStatistics::Disable => 0,
+            ErxIngressStatistics::Enable => 1,
+            ErxIngressStatistics::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxIngressStatistics {
+    fn from(value: u32) -> Self {
+        ErxIngressStatistics::from_u32(value)
+    }
+}
+impl From<ErxIngressStatistics> for u32 {
+    fn from(value: ErxIngressStatistics) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxIngressStatistics {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `disable`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "disable" => Ok(ErxIngressStatistics::Disable),
+            "enable" => Ok(ErxIngressStatistics::Enable),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Ingress-Statistics`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_INGRESS_STATISTICS_DISABLE: ErxIngressStatistics = ErxIngressStatistics::Disable;
+pub const ERX_INGRESS_STATISTICS_ENABLE: ErxIngressStatistics = ErxIngressStatistics::Enable;
+
+/// The values of `ERX-PPP-Auth-Protocol`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxPppAuthProtocol {
+    None,
+    Pap,
+    Chap,
+    PapChap,
+    ChapPap,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxPppAuthProtocol {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxPppAuthProtocol::None,
+            1 => ErxPppAuthProtocol::Pap,
+            2 => ErxPppAuthProtocol::Chap,
+            3 => ErxPppAuthProtocol::PapChap,
+            4 => ErxPppAuthProtocol::ChapPap,
+            value => ErxPppAuthProtocol::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxPppAuthProtocol::None => 0,
+            ErxPppAuthProtocol::Pap => 1,
+            ErxPppAuthProtocol::Chap => 2,
+            ErxPppAuthProtocol::PapChap => 3,
+            ErxPppAuthProtocol::ChapPap => 4,
+            ErxPppAuthProtocol::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxPppAuthProtocol {
+    fn from(value: u32) -> Self {
+        ErxPppAuthProtocol::from_u32(value)
+    }
+}
+impl From<ErxPppAuthProtocol> for u32 {
+    fn from(value: ErxPppAuthProtocol) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxPppAuthProtocol {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `None`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "None" => Ok(ErxPppAuthProtocol::None),
+            "PAP" => Ok(ErxPppAuthProtocol::Pap),
+            "CHAP" => Ok(ErxPppAuthProtocol::Chap),
+            "PAP-CHAP" => Ok(ErxPppAuthProtocol::PapChap),
+            "CHAP-PAP" => Ok(ErxPppAuthProtocol::ChapPap),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-PPP-Auth-Protocol`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_PPP_AUTH_PROTOCOL_NONE: ErxPppAuthProtocol = ErxPppAuthProtocol::None;
+pub const ERX_PPP_AUTH_PROTOCOL_PAP: ErxPppAuthProtocol = ErxPppAuthProtocol::Pap;
+pub const ERX_PPP_AUTH_PROTOCOL_CHAP: ErxPppAuthProtocol = ErxPppAuthProtocol::Chap;
+pub const ERX_PPP_AUTH_PROTOCOL_PAP_CHAP: ErxPppAuthProtocol = ErxPppAuthProtocol::PapChap;
+pub const ERX_PPP_AUTH_PROTOCOL_CHAP_PAP: ErxPppAuthProtocol = ErxPppAuthProtocol::ChapPap;
+
+/// The values of `ERX-Qos-Profile-Interface-Type`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxQosProfileInterfaceType {
+    Ip,
+    Atm,
+    Hdlc,
+    Ethernet,
+    ServerPort,
+    Atm1483,
+    FrameRelay,
+    MplsMinor,
+    Cbf,
+    IpTunnel,
+    VlanSub,
+    PppoeSub,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxQosProfileInterfaceType {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => ErxQosProfileInterfaceType::Ip,
+            2 => ErxQosProfileInterfaceType::Atm,
+            3 => ErxQosProfileInterfaceType::Hdlc,
+            4 => ErxQosProfileInterfaceType::Ethernet,
+            5 => ErxQosProfileInterfaceType::ServerPort,
+            6 => ErxQosProfileInterfaceType::Atm1483,
+            7 => ErxQosProfileInterfaceType::FrameRelay,
+            8 => ErxQosProfileInterfaceType::MplsMinor,
+            9 => ErxQosProfileInterfaceType::Cbf,
+            10 => ErxQosProfileInterfaceType::IpTunnel,
+            11 => ErxQosProfileInterfaceType::VlanSub,
+            12 => ErxQosProfileInterfaceType::PppoeSub,
+            value => ErxQosProfileInterfaceType::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxQosProfileInterfaceType::Ip => 1,
+            ErxQosProfileInterfaceType::Atm => 2,
+            ErxQosProfileInterfaceType::Hdlc => 3,
+            ErxQosProfileInterfaceType::Ethernet => 4,
+            ErxQosProfileInterfaceType::ServerPort => 5,
+            ErxQosProfileInterfaceType::Atm1483 => 6,
+            ErxQosProfileInterfaceType::FrameRelay => 7,
+            ErxQosProfileInterfaceType::MplsMinor => 8,
+            ErxQosProfileInterfaceType::Cbf => 9,
+            ErxQosProfileInterfaceType::IpTunnel => 10,
+            ErxQosProfileInterfaceType::VlanSub => 11,
+            ErxQosProfileInterfaceType::PppoeSub => 12,
+            ErxQosProfileInterfaceType::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxQosProfileInterfaceType {
+    fn from(value: u32) -> Self {
+        ErxQosProfileInterfaceType::from_u32(value)
+    }
+}
+impl From<ErxQosProfileInterfaceType> for u32 {
+    fn from(value: ErxQosProfileInterfaceType) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxQosProfileInterfaceType {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `IP`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "IP" => Ok(ErxQosProfileInterfaceType::Ip),
+            "ATM" => Ok(ErxQosProfileInterfaceType::Atm),
+            "HDLC" => Ok(ErxQosProfileInterfaceType::Hdlc),
+            "ETHERNET" => Ok(ErxQosProfileInterfaceType::Ethernet),
+            "SERVER-PORT" => Ok(ErxQosProfileInterfaceType::ServerPort),
+            "ATM-1483" => Ok(ErxQosProfileInterfaceType::Atm1483),
+            "FRAME-RELAY" => Ok(ErxQosProfileInterfaceType::FrameRelay),
+            "MPLS-MINOR" => Ok(ErxQosProfileInterfaceType::MplsMinor),
+            "CBF" => Ok(ErxQosProfileInterfaceType::Cbf),
+            "IP-TUNNEL" => Ok(ErxQosProfileInterfaceType::IpTunnel),
+            "VLAN-SUB" => Ok(ErxQosProfileInterfaceType::VlanSub),
+            "PPPOE-SUB" => Ok(ErxQosProfileInterfaceType::PppoeSub),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Qos-Profile-Interface-Type`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_IP: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::Ip;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_ATM: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::Atm;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_HDLC: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::Hdlc;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_ETHERNET: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::Ethernet;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_SERVER_PORT: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::ServerPort;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_ATM_1483: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::Atm1483;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_FRAME_RELAY: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::FrameRelay;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_MPLS_MINOR: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::MplsMinor;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_CBF: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::Cbf;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_IP_TUNNEL: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::IpTunnel;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_VLAN_SUB: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::VlanSub;
+pub const ERX_QOS_PROFILE_INTERFACE_TYPE_PPPOE_SUB: ErxQosProfileInterfaceType =
+    ErxQosProfileInterfaceType::PppoeSub;
+
+/// The values of `ERX-Sa-Validate`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxSaValidate {
+    Disable,
+    Enable,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxSaValidate {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxSaValidate::Disable,
+            1 => ErxSaValidate::Enable,
+            value => ErxSaValidate::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxSaValidate::Disable => 0,
+            ErxSaValidate::Enable => 1,
+            ErxSaValidate::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxSaValidate {
+    fn from(value: u32) -> Self {
+        ErxSaValidate::from_u32(value)
+    }
+}
+impl From<ErxSaValidate> for u32 {
+    fn from(value: ErxSaValidate) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxSaValidate {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `disable`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "disable" => Ok(ErxSaValidate::Disable),
+            "enable" => Ok(ErxSaValidate::Enable),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Sa-Validate`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_SA_VALIDATE_DISABLE: ErxSaValidate = ErxSaValidate::Disable;
+pub const ERX_SA_VALIDATE_ENABLE: ErxSaValidate = ErxSaValidate::Enable;
+
+/// The values of `ERX-Service-Statistics`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxServiceStatistics {
+    Disabled,
+    Time,
+    TimeVolume,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxServiceStatistics {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxServiceStatistics::Disabled,
+            1 => ErxServiceStatistics::Time,
+            2 => ErxServiceStatistics::TimeVolume,
+            value => ErxServiceStatistics::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxServiceStatistics::Disabled => 0,
+            ErxServiceStatistics::Time => 1,
+            ErxServiceStatistics::TimeVolume => 2,
+            ErxServiceStatistics::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxServiceStatistics {
+    fn from(value: u32) -> Self {
+        ErxServiceStatistics::from_u32(value)
+    }
+}
+impl From<ErxServiceStatistics> for u32 {
+    fn from(value: ErxServiceStatistics) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxServiceStatistics {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `disabled`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "disabled" => Ok(ErxServiceStatistics::Disabled),
+            "time" => Ok(ErxServiceStatistics::Time),
+            "time-volume" => Ok(ErxServiceStatistics::TimeVolume),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Service-Statistics`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_SERVICE_STATISTICS_DISABLED: ErxServiceStatistics = ErxServiceStatistics::Disabled;
+pub const ERX_SERVICE_STATISTICS_TIME: ErxServiceStatistics = ErxServiceStatistics::Time;
+pub const ERX_SERVICE_STATISTICS_TIME_VOLUME: ErxServiceStatistics =
+    ErxServiceStatistics::TimeVolume;
+
+/// The values of `ERX-Tunnel-Nas-Port-Method`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErxTunnelNasPortMethod {
+    None,
+    CiscoClid,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErxTunnelNasPortMethod {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => ErxTunnelNasPortMethod::None,
+            1 => ErxTunnelNasPortMethod::CiscoClid,
+            value => ErxTunnelNasPortMethod::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErxTunnelNasPortMethod::None => 0,
+            ErxTunnelNasPortMethod::CiscoClid => 1,
+            ErxTunnelNasPortMethod::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErxTunnelNasPortMethod {
+    fn from(value: u32) -> Self {
+        ErxTunnelNasPortMethod::from_u32(value)
+    }
+}
+impl From<ErxTunnelNasPortMethod> for u32 {
+    fn from(value: ErxTunnelNasPortMethod) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErxTunnelNasPortMethod {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `None`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "None" => Ok(ErxTunnelNasPortMethod::None),
+            "CISCO-CLID" => Ok(ErxTunnelNasPortMethod::CiscoClid),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ERX-Tunnel-Nas-Port-Method`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERX_TUNNEL_NAS_PORT_METHOD_NONE: ErxTunnelNasPortMethod = ErxTunnelNasPortMethod::None;
+pub const ERX_TUNNEL_NAS_PORT_METHOD_CISCO_CLID: ErxTunnelNasPortMethod =
+    ErxTunnelNasPortMethod::CiscoClid;
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -0,0 +1,309 @@
+//! Heuristics that guess the vendor and the kind of a NAS from the attributes of its requests.
+//!
+//! `NasFingerprint::of()` looks at the vendor IDs of the `Vendor-Specific` attributes, `NAS-Port-Type`,
+//! the format of `Connect-Info` and the format of the MAC address in `Calling-Station-Id`.
+//! The result is a guess; `NasProfileRegistry` uses the guessed vendor only when the NAS isn't registered by the address
+//! or the identifier.
+
+use std::fmt;
+
+use crate::avp::{AVPType, AVP};
+use crate::nas_profile::MacFormat;
+use crate::packet::Packet;
+use crate::rfc2865;
+use crate::vsa::VENDOR_SPECIFIC_TYPE;
+
+// the same as `rfc2869::CONNECT_INFO_TYPE`; that module is optional (i.e. `rfc2869` feature)
+const CONNECT_INFO_TYPE: AVPType = 77;
+
+/// The names of the well-known vendor IDs (i.e. the SMI Network Management Private Enterprise Codes).
+const VENDOR_NAMES: &[(u32, &str)] = &[
+    (9, "Cisco"),
+    (311, "Microsoft"),
+    (2011, "Huawei"),
+    (2352, "Ericsson"),
+    (2636, "Juniper"),
+    (3076, "Cisco"),
+    (3561, "Broadband-Forum"),
+    (4874, "Juniper"),
+    (6527, "Nokia"),
+    (10415, "3GPP"),
+    (12356, "Fortinet"),
+    (14122, "WISPr"),
+    (14823, "Aruba"),
+    (14988, "MikroTik"),
+    (25053, "Ruckus"),
+    (41112, "Ubiquiti"),
+];
+
+/// The vendor IDs of the VSAs that the NASes of the various vendors send, e.g. MS-CHAP (Microsoft) and the DSL line
+/// attributes (Broadband Forum); those don't tell the vendor of the NAS.
+const VENDOR_NEUTRAL_IDS: &[u32] = &[311, 3561, 10415, 14122];
+
+/// Returns the name of the vendor of the vendor ID if it is well-known (e.g. `Cisco` for `9`).
+pub fn vendor_name(vendor_id: u32) -> Option<&'static str> {
+    VENDOR_NAMES
+        .iter()
+        .find(|(id, _)| *id == vendor_id)
+        .map(|(_, name)| *name)
+}
+
+/// This enum represents the vendor of a NAS.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NasVendor {
+    Cisco,
+    Juniper,
+    Huawei,
+    Ericsson,
+    Nokia,
+    MikroTik,
+    Aruba,
+    Ruckus,
+    Fortinet,
+    Ubiquiti,
+    /// hostapd, i.e. the access points that are based on Linux (e.g. OpenWrt).
+    Hostapd,
+    /// A vendor that sends the VSAs of the vendor ID, that isn't well-known.
+    Other(u32),
+}
+
+impl NasVendor {
+    /// Returns the vendor of the NAS that sends the VSAs of the vendor ID, or `None` for the vendor-neutral IDs
+    /// (e.g. Microsoft for MS-CHAP).
+    pub fn from_vendor_id(vendor_id: u32) -> Option<Self> {
+        if VENDOR_NEUTRAL_IDS.contains(&vendor_id) {
+            return None;
+        }
+        Some(match vendor_id {
+            9 | 3076 => NasVendor::Cisco,
+            2636 | 4874 => NasVendor::Juniper,
+            2011 => NasVendor::Huawei,
+            2352 => NasVendor::Ericsson,
+            6527 => NasVendor::Nokia,
+            14988 => NasVendor::MikroTik,
+            14823 => NasVendor::Aruba,
+            25053 => NasVendor::Ruckus,
+            12356 => NasVendor::Fortinet,
+            41112 => NasVendor::Ubiquiti,
+            _ => NasVendor::Other(vendor_id),
+        })
+    }
+}
+
+impl fmt::Display for NasVendor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NasVendor::Cisco => f.write_str("Cisco"),
+            NasVendor::Juniper => f.write_str("Juniper"),
+            NasVendor::Huawei => f.write_str("Huawei"),
+            NasVendor::Ericsson => f.write_str("Ericsson"),
+            NasVendor::Nokia => f.write_str("Nokia"),
+            NasVendor::MikroTik => f.write_str("MikroTik"),
+            NasVendor::Aruba => f.write_str("Aruba"),
+            NasVendor::Ruckus => f.write_str("Ruckus"),
+            NasVendor::Fortinet => f.write_str("Fortinet"),
+            NasVendor::Ubiquiti => f.write_str("Ubiquiti"),
+            NasVendor::Hostapd => f.write_str("hostapd"),
+            NasVendor::Other(vendor_id) => write!(f, "Vendor-{vendor_id}"),
+        }
+    }
+}
+
+/// This enum represents the kind of a NAS, that is guessed by the access technology.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum NasKind {
+    /// The kind is unknown.
+    #[default]
+    Unknown,
+    /// A wireless access point or controller (e.g. `NAS-Port-Type = Wireless-802.11`).
+    Wireless,
+    /// A NAS of the Ethernet ports, e.g. a switch of 802.1X or a BNG of the Ethernet subscribers (i.e. `NAS-Port-Type = Ethernet`).
+    Ethernet,
+    /// A broadband remote access server (e.g. xDSL, cable).
+    Broadband,
+    /// A dial-up access server (e.g. `NAS-Port-Type = Async`, ISDN).
+    Dialup,
+    /// A NAS of the virtual ports, e.g. a VPN concentrator or the login on a VTY line (i.e. `NAS-Port-Type = Virtual`).
+    Virtual,
+}
+
+impl NasKind {
+    fn from_nas_port_type(nas_port_type: rfc2865::NasPortType) -> Self {
+        use rfc2865::NasPortType;
+        match nas_port_type {
+            NasPortType::Wireless80211 | NasPortType::WirelessOther => NasKind::Wireless,
+            NasPortType::Ethernet => NasKind::Ethernet,
+            NasPortType::Sdsl
+            | NasPortType::AdslCap
+            | NasPortType::AdslDmt
+            | NasPortType::Idsl
+            | NasPortType::Xdsl
+            | NasPortType::Cable => NasKind::Broadband,
+            // PPPoA, PPPoEoA, PPPoEoE, PPPoEoVLAN and PPPoEoQinQ of RFC 4603
+            NasPortType::Unknown(30..=34) => NasKind::Broadband,
+            NasPortType::Async
+            | NasPortType::Sync
+            | NasPortType::Isdn
+            | NasPortType::IsdnV120
+            | NasPortType::IsdnV110
+            | NasPortType::Piafs
+            | NasPortType::G3Fax => NasKind::Dialup,
+            NasPortType::Virtual => NasKind::Virtual,
+            _ => NasKind::Unknown,
+        }
+    }
+}
+
+/// This struct represents the guess of the vendor and the kind of the NAS that sent a request.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NasFingerprint {
+    vendor: Option<NasVendor>,
+    vendor_ids: Vec<u32>,
+    kind: NasKind,
+    mac_format: Option<MacFormat>,
+}
+
+impl NasFingerprint {
+    /// Guess the NAS that sent the request from its attributes.
+    pub fn of(packet: &Packet) -> Self {
+        let mut vendor_ids = Vec::new();
+        for avp in packet.lookup_all(VENDOR_SPECIFIC_TYPE) {
+            if let Some(vendor_id) = vsa_vendor_id(avp) {
+                if !vendor_ids.contains(&vendor_id) {
+                    vendor_ids.push(vendor_id);
+                }
+            }
+        }
+        let connect_info = packet
+            .lookup(CONNECT_INFO_TYPE)
+            .and_then(|avp| avp.decode_string().ok());
+        // e.g. `CONNECT 54Mbps 802.11g`
+        let is_hostapd_connect_info = connect_info.as_deref().is_some_and(|connect_info| {
+            let words = connect_info.split(' ').collect::<Vec<&str>>();
+            words.len() == 3
+                && words[0] == "CONNECT"
+                && words[1].ends_with("Mbps")
+                && words[2].starts_with("802.11")
+        });
+
+        let vendor = vendor_ids
+            .iter()
+            .find_map(|vendor_id| NasVendor::from_vendor_id(*vendor_id))
+            .or_else(|| is_hostapd_connect_info.then_some(NasVendor::Hostapd));
+
+        let kind = match rfc2865::lookup_nas_port_type(packet).and_then(|typ| typ.ok()) {
+            Some(nas_port_type) => NasKind::from_nas_port_type(nas_port_type),
+            None if connect_info.is_some_and(|connect_info| connect_info.contains("802.11")) => {
+                NasKind::Wireless
+            }
+            None => NasKind::Unknown,
+        };
+
+        let mac_format = rfc2865::lookup_calling_station_id(packet)
+            .and_then(|id| id.ok())
+            .and_then(|id| MacFormat::detect(&id));
+
+        NasFingerprint {
+            vendor,
+            vendor_ids,
+            kind,
+            mac_format,
+        }
+    }
+
+    /// Returns the guessed vendor of the NAS.
+    pub fn get_vendor(&self) -> Option<NasVendor> {
+        self.vendor
+    }
+
+    /// Returns the vendor IDs of the VSAs in the request, in order of the first occurrence.
+    pub fn get_vendor_ids(&self) -> &[u32] {
+        &self.vendor_ids
+    }
+
+    /// Returns the guessed kind of the NAS.
+    pub fn get_kind(&self) -> NasKind {
+        self.kind
+    }
+
+    /// Returns the format of the MAC address in `Calling-Station-Id`, if that is a MAC address.
+    pub fn get_mac_format(&self) -> Option<MacFormat> {
+        self.mac_format
+    }
+}
+
+pub(crate) fn vsa_vendor_id(avp: &AVP) -> Option<u32> {
+    avp.value
+        .get(..4)
+        .map(|id| u32::from_be_bytes(id.try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::avp::AVP;
+    use crate::code::Code;
+    use crate::fingerprint::{vendor_name, NasFingerprint, NasKind, NasVendor};
+    use crate::nas_profile::MacFormat;
+    use crate::packet::Packet;
+    use crate::rfc2865;
+    use crate::vsa::{Vendor, VendorFormat};
+
+    #[test]
+    fn test_fingerprint() {
+        let fingerprint = NasFingerprint::of(&Packet::new(Code::AccessRequest, b"secret"));
+        assert_eq!(fingerprint, NasFingerprint::default());
+
+        // MS-CHAP (Microsoft) doesn't tell the vendor
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        packet.add_vsa(
+            &Vendor::new(311, VendorFormat::DEFAULT),
+            11,
+            AVP::from_bytes(0, &[0; 16]).unwrap(),
+        );
+        packet.add_vsa(
+            &Vendor::new(9, VendorFormat::DEFAULT),
+            1,
+            AVP::from_string(0, "audit-session-id=0A000001"),
+        );
+        rfc2865::add_nas_port_type(&mut packet, rfc2865::NasPortType::Ethernet);
+        rfc2865::add_calling_station_id(&mut packet, "0000.5e00.5301");
+        let fingerprint = NasFingerprint::of(&packet);
+        assert_eq!(fingerprint.get_vendor(), Some(NasVendor::Cisco));
+        assert_eq!(fingerprint.get_vendor_ids(), &[311, 9]);
+        assert_eq!(fingerprint.get_kind(), NasKind::Ethernet);
+        assert_eq!(fingerprint.get_mac_format(), Some(MacFormat::DottedLower));
+
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        packet.add(AVP::from_string(77, "CONNECT 54Mbps 802.11g"));
+        rfc2865::add_calling_station_id(&mut packet, "00-00-5E-00-53-01");
+        let fingerprint = NasFingerprint::of(&packet);
+        assert_eq!(fingerprint.get_vendor(), Some(NasVendor::Hostapd));
+        assert_eq!(fingerprint.get_kind(), NasKind::Wireless);
+        assert_eq!(fingerprint.get_mac_format(), Some(MacFormat::HyphenUpper));
+
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        packet.add_vsa(
+            &Vendor::new(65535, VendorFormat::DEFAULT),
+            1,
+            AVP::from_u32(0, 1),
+        );
+        rfc2865::add_nas_port_type(&mut packet, rfc2865::NasPortType::from_u32(32));
+        rfc2865::add_calling_station_id(&mut packet, "+15550100");
+        let fingerprint = NasFingerprint::of(&packet);
+        assert_eq!(fingerprint.get_vendor(), Some(NasVendor::Other(65535)));
+        assert_eq!(
+            fingerprint.get_vendor().unwrap().to_string(),
+            "Vendor-65535"
+        );
+        assert_eq!(fingerprint.get_kind(), NasKind::Broadband);
+        assert_eq!(fingerprint.get_mac_format(), None);
+    }
+
+    #[test]
+    fn test_vendor_name() {
+        assert_eq!(vendor_name(9), Some("Cisco"));
+        assert_eq!(vendor_name(14122), Some("WISPr"));
+        assert_eq!(vendor_name(65535), None);
+    }
+}
@@ -0,0 +1,199 @@
+//! Immutable packet that is cheaply shareable among the tasks.
+//!
+//! `Packet` and `AVP` are `Send` and `Sync`, but cloning a `Packet` copies all of the attributes.
+//! `FrozenPacket` has the packet as the bytes on the wire behind `Arc`, so that a decoded request can be inspected by
+//! several async tasks (e.g. for the fan-out to the multiple handlers) with cloning only the reference counters.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::code::Code;
+use crate::convert::FromAvp;
+use crate::packet::{Packet, PacketError, RADIUS_PACKET_HEADER_LENGTH};
+
+/// `FrozenPacket` is an immutable packet; the clone of this shares the bytes of the original one.
+///
+/// `Debug` of this shows only the header, so it doesn't leak the secret and the sensitive attributes.
+#[derive(Clone)]
+pub struct FrozenPacket {
+    /// the header and the attributes; the authenticator is the one of the packet as it is
+    bytes: Arc<[u8]>,
+    secret: Arc<[u8]>,
+    /// the type and the range of the value in `bytes` of each attribute, in order of appearance
+    attributes: Arc<[(AVPType, usize, usize)]>,
+}
+
+impl Packet {
+    /// Make an immutable packet that is cheaply cloneable from this.
+    ///
+    /// The authenticator is kept as it is (i.e. it is not computed like `encode()`);
+    /// this fails if the packet cannot be marshaled (e.g. it exceeds the maximum packet length).
+    pub fn freeze(&self) -> Result<FrozenPacket, PacketError> {
+        let bytes = self.marshal_binary().map_err(PacketError::EncodingError)?;
+
+        let mut attributes = Vec::with_capacity(self.get_avps().len());
+        let mut i = RADIUS_PACKET_HEADER_LENGTH;
+        while i < bytes.len() {
+            let length = bytes[i + 1] as usize;
+            attributes.push((bytes[i], i + 2, i + length));
+            i += length;
+        }
+
+        Ok(FrozenPacket {
+            bytes: bytes.into(),
+            secret: self.get_secret().as_slice().into(),
+            attributes: attributes.into(),
+        })
+    }
+}
+
+impl FrozenPacket {
+    pub fn get_code(&self) -> Code {
+        Code::from(self.bytes[0])
+    }
+
+    pub fn get_identifier(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    pub fn get_authenticator(&self) -> &[u8] {
+        &self.bytes[4..RADIUS_PACKET_HEADER_LENGTH]
+    }
+
+    pub fn get_secret(&self) -> &[u8] {
+        &self.secret
+    }
+
+    /// Returns the bytes of the packet; note that the authenticator is not computed (see also `Packet::freeze()`).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the raw value of the first AVP that matches with the given AVP type.
+    pub fn lookup_raw(&self, typ: AVPType) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find(|(t, _, _)| *t == typ)
+            .map(|(_, start, end)| &self.bytes[*start..*end])
+    }
+
+    /// Returns the raw values of the AVPs that match with the given AVP type.
+    pub fn lookup_all_raw(&self, typ: AVPType) -> Vec<&[u8]> {
+        self.attributes
+            .iter()
+            .filter(|(t, _, _)| *t == typ)
+            .map(|(_, start, end)| &self.bytes[*start..*end])
+            .collect()
+    }
+
+    /// Returns whether the packet has an AVP of the given AVP type.
+    pub fn contains(&self, typ: AVPType) -> bool {
+        self.attributes.iter().any(|(t, _, _)| *t == typ)
+    }
+
+    /// Returns the number of the AVPs of the given AVP type.
+    pub fn count(&self, typ: AVPType) -> usize {
+        self.attributes.iter().filter(|(t, _, _)| *t == typ).count()
+    }
+
+    /// Returns the value of the first AVP that matches with the given AVP type, decoded as `T`.
+    pub fn get<T: FromAvp>(&self, typ: AVPType) -> Option<Result<T, AVPError>> {
+        self.lookup_raw(typ)
+            .map(|value| T::from_avp(&AVP::from_bytes(typ, value)?))
+    }
+
+    /// Make a mutable packet from this; e.g. to use the dictionary modules or to make a response.
+    pub fn to_packet(&self) -> Packet {
+        Packet::decode(&self.bytes, &self.secret)
+            .expect("the bytes of the frozen packet must be decodable")
+    }
+}
+
+impl fmt::Debug for FrozenPacket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrozenPacket")
+            .field("code", &self.get_code())
+            .field("identifier", &self.get_identifier())
+            .field("length", &self.bytes.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+    use std::sync::Arc;
+
+    use crate::avp::{AVPError, AVP};
+    use crate::code::Code;
+    use crate::frozen::FrozenPacket;
+    use crate::packet::{Packet, PacketError};
+    use crate::rfc2865;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_send_and_sync() {
+        assert_send_sync::<Packet>();
+        assert_send_sync::<AVP>();
+        assert_send_sync::<FrozenPacket>();
+        assert_send_sync::<PacketError>();
+        assert_send_sync::<AVPError>();
+    }
+
+    #[tokio::test]
+    async fn test_freeze() {
+        let mut packet = Packet::new_with_identifier(Code::AccessRequest, b"secret", 42);
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2865::add_nas_ip_address(&mut packet, &Ipv4Addr::new(192, 0, 2, 1));
+        rfc2865::add_class(&mut packet, b"first").unwrap();
+        rfc2865::add_class(&mut packet, b"second").unwrap();
+
+        let frozen = packet.freeze().unwrap();
+        assert_eq!(frozen.get_code(), Code::AccessRequest);
+        assert_eq!(frozen.get_identifier(), 42);
+        assert_eq!(
+            frozen.get_authenticator(),
+            packet.get_authenticator().as_slice()
+        );
+        assert_eq!(frozen.get_secret(), b"secret");
+        assert_eq!(frozen.as_bytes(), packet.encode().unwrap());
+        assert_eq!(
+            frozen.lookup_raw(rfc2865::USER_NAME_TYPE),
+            Some(b"alice".as_slice())
+        );
+        assert_eq!(
+            frozen.lookup_all_raw(rfc2865::CLASS_TYPE),
+            vec![b"first".as_slice(), b"second".as_slice()]
+        );
+        assert_eq!(frozen.count(rfc2865::CLASS_TYPE), 2);
+        assert!(!frozen.contains(rfc2865::STATE_TYPE));
+        assert_eq!(
+            frozen
+                .get::<Ipv4Addr>(rfc2865::NAS_IP_ADDRESS_TYPE)
+                .unwrap()
+                .unwrap(),
+            Ipv4Addr::new(192, 0, 2, 1)
+        );
+        assert_eq!(
+            format!("{:?}", frozen),
+            "FrozenPacket { code: AccessRequest, identifier: 42, length: 48, .. }"
+        );
+
+        // the clones share the bytes among the tasks
+        let handles = (0..4)
+            .map(|_| {
+                let frozen = frozen.clone();
+                tokio::spawn(async move { frozen.lookup_raw(rfc2865::USER_NAME_TYPE).is_some() })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert!(handle.await.unwrap());
+        }
+        assert!(Arc::ptr_eq(&frozen.bytes, &frozen.clone().bytes));
+        assert_eq!(Arc::strong_count(&frozen.bytes), 1);
+
+        assert_eq!(frozen.to_packet(), packet);
+    }
+}
@@ -0,0 +1,1113 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for juniper packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Juniper Networks' (JUNOS) vendor-specific attributes.
+//! #
+//! #    $Id$
+//! #
+//! VENDOR        Juniper                2636
+//!
+//! BEGIN-VENDOR    Juniper
+//!
+//! ATTRIBUTE    Juniper-Local-User-Name            1    string
+//! ATTRIBUTE    Juniper-Allow-Commands            2    string
+//! ATTRIBUTE    Juniper-Deny-Commands            3    string
+//! ATTRIBUTE    Juniper-Allow-Configuration        4    string
+//! ATTRIBUTE    Juniper-Deny-Configuration        5    string
+//! ATTRIBUTE    Juniper-Interactive-Command        8    string
+//! ATTRIBUTE    Juniper-Configuration-Change        9    string
+//! ATTRIBUTE    Juniper-User-Permissions        10    string
+//! ATTRIBUTE    Juniper-Junosspace-Profile        11    string
+//!
+//! ATTRIBUTE    Juniper-CTP-Group            21    integer
+//! ATTRIBUTE    Juniper-CTPView-APP-Group        22    integer
+//! ATTRIBUTE    Juniper-CTPView-OS-Group        23    integer
+//!
+//! ATTRIBUTE    Juniper-Primary-Dns            31    ipaddr
+//! ATTRIBUTE    Juniper-Primary-Wins            32    ipaddr
+//! ATTRIBUTE    Juniper-Secondary-Dns            33    ipaddr
+//! ATTRIBUTE    Juniper-Secondary-Wins            34    ipaddr
+//! ATTRIBUTE    Juniper-Interface-Id            35    string
+//! ATTRIBUTE    Juniper-Ip-Pool-Name            36    string
+//! ATTRIBUTE    Juniper-Keep-Alive            37    integer
+//!
+//! VALUE    Juniper-CTP-Group        Read_Only        1
+//! VALUE    Juniper-CTP-Group        Admin            2
+//! VALUE    Juniper-CTP-Group        Privileged_Admin    3
+//! VALUE    Juniper-CTP-Group        Auditor            4
+//!
+//! VALUE    Juniper-CTPView-APP-Group    Net_View        1
+//! VALUE    Juniper-CTPView-APP-Group    Net_Admin        2
+//! VALUE    Juniper-CTPView-APP-Group    Global_Admin        3
+//!
+//! VALUE    Juniper-CTPView-OS-Group    Web_Manager        1
+//! VALUE    Juniper-CTPView-OS-Group    System_Admin        2
+//! VALUE    Juniper-CTPView-OS-Group    Auditor            3
+//!
+//! END-VENDOR    Juniper
+//! ```
+
+use std::net::Ipv4Addr;
+
+use crate::avp::{AVPError, AVP};
+use crate::metadata::{AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+use crate::vsa::{Vendor, VendorFormat, VendorType, VENDOR_SPECIFIC_TYPE};
+
+/// The vendor of the `Juniper` attributes, that are the sub-attributes of `Vendor-Specific`.
+pub const JUNIPER_VENDOR: Vendor = Vendor::new(2636, VendorFormat::DEFAULT);
+
+pub const JUNIPER_LOCAL_USER_NAME_TYPE: VendorType = 1;
+/// Delete all of `juniper_local_user_name` values from a packet.
+pub fn delete_juniper_local_user_name(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_LOCAL_USER_NAME_TYPE);
+}
+/// Returns whether a packet has `juniper_local_user_name` without decoding the value.
+pub fn has_juniper_local_user_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_LOCAL_USER_NAME_TYPE)
+}
+/// Returns the number of `juniper_local_user_name` attributes in a packet.
+pub fn count_juniper_local_user_name(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_LOCAL_USER_NAME_TYPE)
+}
+/// Add `juniper_local_user_name` string value to a packet.
+pub fn add_juniper_local_user_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_LOCAL_USER_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_local_user_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_local_user_name`, it returns `None`.
+pub fn lookup_juniper_local_user_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_LOCAL_USER_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Local-User-Name"))
+        })
+}
+/// Lookup all of the `juniper_local_user_name` string value from a packet.
+pub fn lookup_all_juniper_local_user_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_LOCAL_USER_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Local-User-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_ALLOW_COMMANDS_TYPE: VendorType = 2;
+/// Delete all of `juniper_allow_commands` values from a packet.
+pub fn delete_juniper_allow_commands(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_COMMANDS_TYPE);
+}
+/// Returns whether a packet has `juniper_allow_commands` without decoding the value.
+pub fn has_juniper_allow_commands(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_COMMANDS_TYPE)
+}
+/// Returns the number of `juniper_allow_commands` attributes in a packet.
+pub fn count_juniper_allow_commands(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_COMMANDS_TYPE)
+}
+/// Add `juniper_allow_commands` string value to a packet.
+pub fn add_juniper_allow_commands(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_ALLOW_COMMANDS_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_allow_commands` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_allow_commands`, it returns `None`.
+pub fn lookup_juniper_allow_commands(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_COMMANDS_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Allow-Commands"))
+        })
+}
+/// Lookup all of the `juniper_allow_commands` string value from a packet.
+pub fn lookup_all_juniper_allow_commands(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_COMMANDS_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Allow-Commands"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_DENY_COMMANDS_TYPE: VendorType = 3;
+/// Delete all of `juniper_deny_commands` values from a packet.
+pub fn delete_juniper_deny_commands(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_COMMANDS_TYPE);
+}
+/// Returns whether a packet has `juniper_deny_commands` without decoding the value.
+pub fn has_juniper_deny_commands(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_COMMANDS_TYPE)
+}
+/// Returns the number of `juniper_deny_commands` attributes in a packet.
+pub fn count_juniper_deny_commands(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_COMMANDS_TYPE)
+}
+/// Add `juniper_deny_commands` string value to a packet.
+pub fn add_juniper_deny_commands(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_DENY_COMMANDS_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_deny_commands` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_deny_commands`, it returns `None`.
+pub fn lookup_juniper_deny_commands(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_COMMANDS_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Deny-Commands"))
+        })
+}
+/// Lookup all of the `juniper_deny_commands` string value from a packet.
+pub fn lookup_all_juniper_deny_commands(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_COMMANDS_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Deny-Commands"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_ALLOW_CONFIGURATION_TYPE: VendorType = 4;
+/// Delete all of `juniper_allow_configuration` values from a packet.
+pub fn delete_juniper_allow_configuration(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_CONFIGURATION_TYPE);
+}
+/// Returns whether a packet has `juniper_allow_configuration` without decoding the value.
+pub fn has_juniper_allow_configuration(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_CONFIGURATION_TYPE)
+}
+/// Returns the number of `juniper_allow_configuration` attributes in a packet.
+pub fn count_juniper_allow_configuration(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_CONFIGURATION_TYPE)
+}
+/// Add `juniper_allow_configuration` string value to a packet.
+pub fn add_juniper_allow_configuration(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_ALLOW_CONFIGURATION_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_allow_configuration` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_allow_configuration`, it returns `None`.
+pub fn lookup_juniper_allow_configuration(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_CONFIGURATION_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Allow-Configuration"))
+        })
+}
+/// Lookup all of the `juniper_allow_configuration` string value from a packet.
+pub fn lookup_all_juniper_allow_configuration(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_ALLOW_CONFIGURATION_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Allow-Configuration"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_DENY_CONFIGURATION_TYPE: VendorType = 5;
+/// Delete all of `juniper_deny_configuration` values from a packet.
+pub fn delete_juniper_deny_configuration(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_CONFIGURATION_TYPE);
+}
+/// Returns whether a packet has `juniper_deny_configuration` without decoding the value.
+pub fn has_juniper_deny_configuration(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_CONFIGURATION_TYPE)
+}
+/// Returns the number of `juniper_deny_configuration` attributes in a packet.
+pub fn count_juniper_deny_configuration(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_CONFIGURATION_TYPE)
+}
+/// Add `juniper_deny_configuration` string value to a packet.
+pub fn add_juniper_deny_configuration(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_DENY_CONFIGURATION_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_deny_configuration` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_deny_configuration`, it returns `None`.
+pub fn lookup_juniper_deny_configuration(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_CONFIGURATION_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Deny-Configuration"))
+        })
+}
+/// Lookup all of the `juniper_deny_configuration` string value from a packet.
+pub fn lookup_all_juniper_deny_configuration(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_DENY_CONFIGURATION_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Deny-Configuration"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_INTERACTIVE_COMMAND_TYPE: VendorType = 8;
+/// Delete all of `juniper_interactive_command` values from a packet.
+pub fn delete_juniper_interactive_command(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_INTERACTIVE_COMMAND_TYPE);
+}
+/// Returns whether a packet has `juniper_interactive_command` without decoding the value.
+pub fn has_juniper_interactive_command(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_INTERACTIVE_COMMAND_TYPE)
+}
+/// Returns the number of `juniper_interactive_command` attributes in a packet.
+pub fn count_juniper_interactive_command(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_INTERACTIVE_COMMAND_TYPE)
+}
+/// Add `juniper_interactive_command` string value to a packet.
+pub fn add_juniper_interactive_command(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_INTERACTIVE_COMMAND_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_interactive_command` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_interactive_command`, it returns `None`.
+pub fn lookup_juniper_interactive_command(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_INTERACTIVE_COMMAND_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Interactive-Command"))
+        })
+}
+/// Lookup all of the `juniper_interactive_command` string value from a packet.
+pub fn lookup_all_juniper_interactive_command(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_INTERACTIVE_COMMAND_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Interactive-Command"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_CONFIGURATION_CHANGE_TYPE: VendorType = 9;
+/// Delete all of `juniper_configuration_change` values from a packet.
+pub fn delete_juniper_configuration_change(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_CONFIGURATION_CHANGE_TYPE);
+}
+/// Returns whether a packet has `juniper_configuration_change` without decoding the value.
+pub fn has_juniper_configuration_change(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_CONFIGURATION_CHANGE_TYPE)
+}
+/// Returns the number of `juniper_configuration_change` attributes in a packet.
+pub fn count_juniper_configuration_change(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_CONFIGURATION_CHANGE_TYPE)
+}
+/// Add `juniper_configuration_change` string value to a packet.
+pub fn add_juniper_configuration_change(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_CONFIGURATION_CHANGE_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_configuration_change` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_configuration_change`, it returns `None`.
+pub fn lookup_juniper_configuration_change(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_CONFIGURATION_CHANGE_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Configuration-Change"))
+        })
+}
+/// Lookup all of the `juniper_configuration_change` string value from a packet.
+pub fn lookup_all_juniper_configuration_change(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_CONFIGURATION_CHANGE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Configuration-Change"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_USER_PERMISSIONS_TYPE: VendorType = 10;
+/// Delete all of `juniper_user_permissions` values from a packet.
+pub fn delete_juniper_user_permissions(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_USER_PERMISSIONS_TYPE);
+}
+/// Returns whether a packet has `juniper_user_permissions` without decoding the value.
+pub fn has_juniper_user_permissions(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_USER_PERMISSIONS_TYPE)
+}
+/// Returns the number of `juniper_user_permissions` attributes in a packet.
+pub fn count_juniper_user_permissions(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_USER_PERMISSIONS_TYPE)
+}
+/// Add `juniper_user_permissions` string value to a packet.
+pub fn add_juniper_user_permissions(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_USER_PERMISSIONS_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_user_permissions` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_user_permissions`, it returns `None`.
+pub fn lookup_juniper_user_permissions(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_USER_PERMISSIONS_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-User-Permissions"))
+        })
+}
+/// Lookup all of the `juniper_user_permissions` string value from a packet.
+pub fn lookup_all_juniper_user_permissions(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_USER_PERMISSIONS_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-User-Permissions"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_JUNOSSPACE_PROFILE_TYPE: VendorType = 11;
+/// Delete all of `juniper_junosspace_profile` values from a packet.
+pub fn delete_juniper_junosspace_profile(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_JUNOSSPACE_PROFILE_TYPE);
+}
+/// Returns whether a packet has `juniper_junosspace_profile` without decoding the value.
+pub fn has_juniper_junosspace_profile(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_JUNOSSPACE_PROFILE_TYPE)
+}
+/// Returns the number of `juniper_junosspace_profile` attributes in a packet.
+pub fn count_juniper_junosspace_profile(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_JUNOSSPACE_PROFILE_TYPE)
+}
+/// Add `juniper_junosspace_profile` string value to a packet.
+pub fn add_juniper_junosspace_profile(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_JUNOSSPACE_PROFILE_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_junosspace_profile` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_junosspace_profile`, it returns `None`.
+pub fn lookup_juniper_junosspace_profile(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_JUNOSSPACE_PROFILE_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Junosspace-Profile"))
+        })
+}
+/// Lookup all of the `juniper_junosspace_profile` string value from a packet.
+pub fn lookup_all_juniper_junosspace_profile(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_JUNOSSPACE_PROFILE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Junosspace-Profile"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_CTP_GROUP_TYPE: VendorType = 21;
+/// Delete all of `juniper_ctp_group` values from a packet.
+pub fn delete_juniper_ctp_group(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_GROUP_TYPE);
+}
+/// Returns whether a packet has `juniper_ctp_group` without decoding the value.
+pub fn has_juniper_ctp_group(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_GROUP_TYPE)
+}
+/// Returns the number of `juniper_ctp_group` attributes in a packet.
+pub fn count_juniper_ctp_group(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_GROUP_TYPE)
+}
+/// Add `juniper_ctp_group` value-defined integer value to a packet.
+pub fn add_juniper_ctp_group(packet: &mut Packet, value: JuniperCTPGroup) {
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_CTP_GROUP_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `juniper_ctp_group` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_ctp_group`, it returns `None`.
+pub fn lookup_juniper_ctp_group(packet: &Packet) -> Option<Result<JuniperCTPGroup, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_GROUP_TYPE)
+        .map(|v| {
+            Ok(JuniperCTPGroup::from_u32(
+                v.decode_u32()
+                    .map_err(|e| e.with_attribute_name("Juniper-CTP-Group"))?,
+            ))
+        })
+}
+/// Lookup all of the `juniper_ctp_group` value-defined integer value from a packet.
+pub fn lookup_all_juniper_ctp_group(packet: &Packet) -> Result<Vec<JuniperCTPGroup>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_GROUP_TYPE) {
+        vec.push(JuniperCTPGroup::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Juniper-CTP-Group"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_CTP_VIEW_APP_GROUP_TYPE: VendorType = 22;
+/// Delete all of `juniper_ctp_view_app_group` values from a packet.
+pub fn delete_juniper_ctp_view_app_group(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_APP_GROUP_TYPE);
+}
+/// Returns whether a packet has `juniper_ctp_view_app_group` without decoding the value.
+pub fn has_juniper_ctp_view_app_group(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_APP_GROUP_TYPE)
+}
+/// Returns the number of `juniper_ctp_view_app_group` attributes in a packet.
+pub fn count_juniper_ctp_view_app_group(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_APP_GROUP_TYPE)
+}
+/// Add `juniper_ctp_view_app_group` value-defined integer value to a packet.
+pub fn add_juniper_ctp_view_app_group(packet: &mut Packet, value: JuniperCTPViewAPPGroup) {
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_CTP_VIEW_APP_GROUP_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `juniper_ctp_view_app_group` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_ctp_view_app_group`, it returns `None`.
+pub fn lookup_juniper_ctp_view_app_group(
+    packet: &Packet,
+) -> Option<Result<JuniperCTPViewAPPGroup, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_APP_GROUP_TYPE)
+        .map(|v| {
+            Ok(JuniperCTPViewAPPGroup::from_u32(v.decode_u32().map_err(
+                |e| e.with_attribute_name("Juniper-CTPView-APP-Group"),
+            )?))
+        })
+}
+/// Lookup all of the `juniper_ctp_view_app_group` value-defined integer value from a packet.
+pub fn lookup_all_juniper_ctp_view_app_group(
+    packet: &Packet,
+) -> Result<Vec<JuniperCTPViewAPPGroup>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_APP_GROUP_TYPE) {
+        vec.push(JuniperCTPViewAPPGroup::from_u32(avp.decode_u32().map_err(
+            |e| e.with_attribute_name("Juniper-CTPView-APP-Group"),
+        )?))
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_CTP_VIEW_OS_GROUP_TYPE: VendorType = 23;
+/// Delete all of `juniper_ctp_view_os_group` values from a packet.
+pub fn delete_juniper_ctp_view_os_group(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_OS_GROUP_TYPE);
+}
+/// Returns whether a packet has `juniper_ctp_view_os_group` without decoding the value.
+pub fn has_juniper_ctp_view_os_group(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_OS_GROUP_TYPE)
+}
+/// Returns the number of `juniper_ctp_view_os_group` attributes in a packet.
+pub fn count_juniper_ctp_view_os_group(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_OS_GROUP_TYPE)
+}
+/// Add `juniper_ctp_view_os_group` value-defined integer value to a packet.
+pub fn add_juniper_ctp_view_os_group(packet: &mut Packet, value: JuniperCTPViewOSGroup) {
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_CTP_VIEW_OS_GROUP_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value.to_u32()),
+    );
+}
+/// Lookup a `juniper_ctp_view_os_group` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_ctp_view_os_group`, it returns `None`.
+pub fn lookup_juniper_ctp_view_os_group(
+    packet: &Packet,
+) -> Option<Result<JuniperCTPViewOSGroup, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_OS_GROUP_TYPE)
+        .map(|v| {
+            Ok(JuniperCTPViewOSGroup::from_u32(v.decode_u32().map_err(
+                |e| e.with_attribute_name("Juniper-CTPView-OS-Group"),
+            )?))
+        })
+}
+/// Lookup all of the `juniper_ctp_view_os_group` value-defined integer value from a packet.
+pub fn lookup_all_juniper_ctp_view_os_group(
+    packet: &Packet,
+) -> Result<Vec<JuniperCTPViewOSGroup>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_CTP_VIEW_OS_GROUP_TYPE) {
+        vec.push(JuniperCTPViewOSGroup::from_u32(avp.decode_u32().map_err(
+            |e| e.with_attribute_name("Juniper-CTPView-OS-Group"),
+        )?))
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_PRIMARY_DNS_TYPE: VendorType = 31;
+/// Delete all of `juniper_primary_dns` values from a packet.
+pub fn delete_juniper_primary_dns(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_DNS_TYPE);
+}
+/// Returns whether a packet has `juniper_primary_dns` without decoding the value.
+pub fn has_juniper_primary_dns(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_DNS_TYPE)
+}
+/// Returns the number of `juniper_primary_dns` attributes in a packet.
+pub fn count_juniper_primary_dns(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_DNS_TYPE)
+}
+/// Add `juniper_primary_dns` ipaddr value to a packet.
+pub fn add_juniper_primary_dns(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_PRIMARY_DNS_TYPE,
+        AVP::from_ipv4(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_primary_dns` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_primary_dns`, it returns `None`.
+pub fn lookup_juniper_primary_dns(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_DNS_TYPE)
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Juniper-Primary-Dns"))
+        })
+}
+/// Lookup all of the `juniper_primary_dns` ipaddr value from a packet.
+pub fn lookup_all_juniper_primary_dns(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_DNS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Juniper-Primary-Dns"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_PRIMARY_WINS_TYPE: VendorType = 32;
+/// Delete all of `juniper_primary_wins` values from a packet.
+pub fn delete_juniper_primary_wins(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_WINS_TYPE);
+}
+/// Returns whether a packet has `juniper_primary_wins` without decoding the value.
+pub fn has_juniper_primary_wins(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_WINS_TYPE)
+}
+/// Returns the number of `juniper_primary_wins` attributes in a packet.
+pub fn count_juniper_primary_wins(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_WINS_TYPE)
+}
+/// Add `juniper_primary_wins` ipaddr value to a packet.
+pub fn add_juniper_primary_wins(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_PRIMARY_WINS_TYPE,
+        AVP::from_ipv4(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_primary_wins` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_primary_wins`, it returns `None`.
+pub fn lookup_juniper_primary_wins(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_WINS_TYPE)
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Juniper-Primary-Wins"))
+        })
+}
+/// Lookup all of the `juniper_primary_wins` ipaddr value from a packet.
+pub fn lookup_all_juniper_primary_wins(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_PRIMARY_WINS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Juniper-Primary-Wins"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_SECONDARY_DNS_TYPE: VendorType = 33;
+/// Delete all of `juniper_secondary_dns` values from a packet.
+pub fn delete_juniper_secondary_dns(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_DNS_TYPE);
+}
+/// Returns whether a packet has `juniper_secondary_dns` without decoding the value.
+pub fn has_juniper_secondary_dns(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_DNS_TYPE)
+}
+/// Returns the number of `juniper_secondary_dns` attributes in a packet.
+pub fn count_juniper_secondary_dns(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_DNS_TYPE)
+}
+/// Add `juniper_secondary_dns` ipaddr value to a packet.
+pub fn add_juniper_secondary_dns(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_SECONDARY_DNS_TYPE,
+        AVP::from_ipv4(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_secondary_dns` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_secondary_dns`, it returns `None`.
+pub fn lookup_juniper_secondary_dns(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_DNS_TYPE)
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Juniper-Secondary-Dns"))
+        })
+}
+/// Lookup all of the `juniper_secondary_dns` ipaddr value from a packet.
+pub fn lookup_all_juniper_secondary_dns(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_DNS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Juniper-Secondary-Dns"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_SECONDARY_WINS_TYPE: VendorType = 34;
+/// Delete all of `juniper_secondary_wins` values from a packet.
+pub fn delete_juniper_secondary_wins(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_WINS_TYPE);
+}
+/// Returns whether a packet has `juniper_secondary_wins` without decoding the value.
+pub fn has_juniper_secondary_wins(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_WINS_TYPE)
+}
+/// Returns the number of `juniper_secondary_wins` attributes in a packet.
+pub fn count_juniper_secondary_wins(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_WINS_TYPE)
+}
+/// Add `juniper_secondary_wins` ipaddr value to a packet.
+pub fn add_juniper_secondary_wins(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_SECONDARY_WINS_TYPE,
+        AVP::from_ipv4(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_secondary_wins` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_secondary_wins`, it returns `None`.
+pub fn lookup_juniper_secondary_wins(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_WINS_TYPE)
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Juniper-Secondary-Wins"))
+        })
+}
+/// Lookup all of the `juniper_secondary_wins` ipaddr value from a packet.
+pub fn lookup_all_juniper_secondary_wins(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_SECONDARY_WINS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Juniper-Secondary-Wins"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_INTERFACE_ID_TYPE: VendorType = 35;
+/// Delete all of `juniper_interface_id` values from a packet.
+pub fn delete_juniper_interface_id(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_INTERFACE_ID_TYPE);
+}
+/// Returns whether a packet has `juniper_interface_id` without decoding the value.
+pub fn has_juniper_interface_id(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_INTERFACE_ID_TYPE)
+}
+/// Returns the number of `juniper_interface_id` attributes in a packet.
+pub fn count_juniper_interface_id(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_INTERFACE_ID_TYPE)
+}
+/// Add `juniper_interface_id` string value to a packet.
+pub fn add_juniper_interface_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_INTERFACE_ID_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_interface_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_interface_id`, it returns `None`.
+pub fn lookup_juniper_interface_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_INTERFACE_ID_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Interface-Id"))
+        })
+}
+/// Lookup all of the `juniper_interface_id` string value from a packet.
+pub fn lookup_all_juniper_interface_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_INTERFACE_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Interface-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_IP_POOL_NAME_TYPE: VendorType = 36;
+/// Delete all of `juniper_ip_pool_name` values from a packet.
+pub fn delete_juniper_ip_pool_name(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_IP_POOL_NAME_TYPE);
+}
+/// Returns whether a packet has `juniper_ip_pool_name` without decoding the value.
+pub fn has_juniper_ip_pool_name(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_IP_POOL_NAME_TYPE)
+}
+/// Returns the number of `juniper_ip_pool_name` attributes in a packet.
+pub fn count_juniper_ip_pool_name(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_IP_POOL_NAME_TYPE)
+}
+/// Add `juniper_ip_pool_name` string value to a packet.
+pub fn add_juniper_ip_pool_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), JUNIPER_VENDOR.max_value_length());
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_IP_POOL_NAME_TYPE,
+        AVP::from_string(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_ip_pool_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_ip_pool_name`, it returns `None`.
+pub fn lookup_juniper_ip_pool_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_IP_POOL_NAME_TYPE)
+        .map(|v| {
+            v.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Ip-Pool-Name"))
+        })
+}
+/// Lookup all of the `juniper_ip_pool_name` string value from a packet.
+pub fn lookup_all_juniper_ip_pool_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_IP_POOL_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Juniper-Ip-Pool-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const JUNIPER_KEEP_ALIVE_TYPE: VendorType = 37;
+/// Delete all of `juniper_keep_alive` values from a packet.
+pub fn delete_juniper_keep_alive(packet: &mut Packet) {
+    packet.delete_vsa(&JUNIPER_VENDOR, JUNIPER_KEEP_ALIVE_TYPE);
+}
+/// Returns whether a packet has `juniper_keep_alive` without decoding the value.
+pub fn has_juniper_keep_alive(packet: &Packet) -> bool {
+    packet.contains_vsa(&JUNIPER_VENDOR, JUNIPER_KEEP_ALIVE_TYPE)
+}
+/// Returns the number of `juniper_keep_alive` attributes in a packet.
+pub fn count_juniper_keep_alive(packet: &Packet) -> usize {
+    packet.count_vsa(&JUNIPER_VENDOR, JUNIPER_KEEP_ALIVE_TYPE)
+}
+/// Add `juniper_keep_alive` integer value to a packet.
+pub fn add_juniper_keep_alive(packet: &mut Packet, value: u32) {
+    packet.add_vsa(
+        &JUNIPER_VENDOR,
+        JUNIPER_KEEP_ALIVE_TYPE,
+        AVP::from_u32(VENDOR_SPECIFIC_TYPE, value),
+    );
+}
+/// Lookup a `juniper_keep_alive` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `juniper_keep_alive`, it returns `None`.
+pub fn lookup_juniper_keep_alive(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet
+        .lookup_vsa(&JUNIPER_VENDOR, JUNIPER_KEEP_ALIVE_TYPE)
+        .map(|v| {
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Juniper-Keep-Alive"))
+        })
+}
+/// Lookup all of the `juniper_keep_alive` integer value from a packet.
+pub fn lookup_all_juniper_keep_alive(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all_vsa(&JUNIPER_VENDOR, JUNIPER_KEEP_ALIVE_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Juniper-Keep-Alive"))?,
+        )
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[];
+
+/// The values of `Juniper-CTP-Group`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum JuniperCTPGroup {
+    ReadOnly,
+    Admin,
+    PrivilegedAdmin,
+    Auditor,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl JuniperCTPGroup {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => JuniperCTPGroup::ReadOnly,
+            2 => JuniperCTPGroup::Admin,
+            3 => JuniperCTPGroup::PrivilegedAdmin,
+            4 => JuniperCTPGroup::Auditor,
+            value => JuniperCTPGroup::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            JuniperCTPGroup::ReadOnly => 1,
+            JuniperCTPGroup::Admin => 2,
+            JuniperCTPGroup::PrivilegedAdmin => 3,
+            JuniperCTPGroup::Auditor => 4,
+            JuniperCTPGroup::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for JuniperCTPGroup {
+    fn from(value: u32) -> Self {
+        JuniperCTPGroup::from_u32(value)
+    }
+}
+impl From<JuniperCTPGroup> for u32 {
+    fn from(value: JuniperCTPGroup) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for JuniperCTPGroup {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Read_Only`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Read_Only" => Ok(JuniperCTPGroup::ReadOnly),
+            "Admin" => Ok(JuniperCTPGroup::Admin),
+            "Privileged_Admin" => Ok(JuniperCTPGroup::PrivilegedAdmin),
+            "Auditor" => Ok(JuniperCTPGroup::Auditor),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Juniper-CTP-Group`: {name}"
+            ))),
+        }
+    }
+}
+pub const JUNIPER_CTP_GROUP_READ_ONLY: JuniperCTPGroup = JuniperCTPGroup::ReadOnly;
+pub const JUNIPER_CTP_GROUP_ADMIN: JuniperCTPGroup = JuniperCTPGroup::Admin;
+pub const JUNIPER_CTP_GROUP_PRIVILEGED_ADMIN: JuniperCTPGroup = JuniperCTPGroup::PrivilegedAdmin;
+pub const JUNIPER_CTP_GROUP_AUDITOR: JuniperCTPGroup = JuniperCTPGroup::Auditor;
+
+/// The values of `Juniper-CTPView-APP-Group`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum JuniperCTPViewAPPGroup {
+    NetView,
+    NetAdmin,
+    GlobalAdmin,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl JuniperCTPViewAPPGroup {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => JuniperCTPViewAPPGroup::NetView,
+            2 => JuniperCTPViewAPPGroup::NetAdmin,
+            3 => JuniperCTPViewAPPGroup::GlobalAdmin,
+            value => JuniperCTPViewAPPGroup::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            JuniperCTPViewAPPGroup::NetView => 1,
+            JuniperCTPViewAPPGroup::NetAdmin => 2,
+            JuniperCTPViewAPPGroup::GlobalAdmin => 3,
+            JuniperCTPViewAPPGroup::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for JuniperCTPViewAPPGroup {
+    fn from(value: u32) -> Self {
+        JuniperCTPViewAPPGroup::from_u32(value)
+    }
+}
+impl From<JuniperCTPViewAPPGroup> for u32 {
+    fn from(value: JuniperCTPViewAPPGroup) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for JuniperCTPViewAPPGroup {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Net_View`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Net_View" => Ok(JuniperCTPViewAPPGroup::NetView),
+            "Net_Admin" => Ok(JuniperCTPViewAPPGroup::NetAdmin),
+            "Global_Admin" => Ok(JuniperCTPViewAPPGroup::GlobalAdmin),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Juniper-CTPView-APP-Group`: {name}"
+            ))),
+        }
+    }
+}
+pub const JUNIPER_CTP_VIEW_APP_GROUP_NET_VIEW: JuniperCTPViewAPPGroup =
+    JuniperCTPViewAPPGroup::NetView;
+pub const JUNIPER_CTP_VIEW_APP_GROUP_NET_ADMIN: JuniperCTPViewAPPGroup =
+    JuniperCTPViewAPPGroup::NetAdmin;
+pub const JUNIPER_CTP_VIEW_APP_GROUP_GLOBAL_ADMIN: JuniperCTPViewAPPGroup =
+    JuniperCTPViewAPPGroup::GlobalAdmin;
+
+/// The values of `Juniper-CTPView-OS-Group`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum JuniperCTPViewOSGroup {
+    WebManager,
+    SystemAdmin,
+    Auditor,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl JuniperCTPViewOSGroup {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => JuniperCTPViewOSGroup::WebManager,
+            2 => JuniperCTPViewOSGroup::SystemAdmin,
+            3 => JuniperCTPViewOSGroup::Auditor,
+            value => JuniperCTPViewOSGroup::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            JuniperCTPViewOSGroup::WebManager => 1,
+            JuniperCTPViewOSGroup::SystemAdmin => 2,
+            JuniperCTPViewOSGroup::Auditor => 3,
+            JuniperCTPViewOSGroup::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for JuniperCTPViewOSGroup {
+    fn from(value: u32) -> Self {
+        JuniperCTPViewOSGroup::from_u32(value)
+    }
+}
+impl From<JuniperCTPViewOSGroup> for u32 {
+    fn from(value: JuniperCTPViewOSGroup) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for JuniperCTPViewOSGroup {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Web_Manager`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Web_Manager" => Ok(JuniperCTPViewOSGroup::WebManager),
+            "System_Admin" => Ok(JuniperCTPViewOSGroup::SystemAdmin),
+            "Auditor" => Ok(JuniperCTPViewOSGroup::Auditor),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Juniper-CTPView-OS-Group`: {name}"
+            ))),
+        }
+    }
+}
+pub const JUNIPER_CTP_VIEW_OS_GROUP_WEB_MANAGER: JuniperCTPViewOSGroup =
+    JuniperCTPViewOSGroup::WebManager;
+pub const JUNIPER_CTP_VIEW_OS_GROUP_SYSTEM_ADMIN: JuniperCTPViewOSGroup =
+    JuniperCTPViewOSGroup::SystemAdmin;
+pub const JUNIPER_CTP_VIEW_OS_GROUP_AUDITOR: JuniperCTPViewOSGroup = JuniperCTPViewOSGroup::Auditor;
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -0,0 +1,86 @@
+//! RADIUS packet and attribute codec, i.e. the `core` module of the radius crate without the async client/server.
+//!
+//! This crate doesn't depend on the async runtime and the network, so the embedded and WASM applications that
+//! only encode and decode the packets can depend on this instead of the radius crate.
+
+#[macro_use]
+extern crate log;
+
+// the generated flags attributes (i.e. `flags` option of the dictionary) are defined by this
+pub use bitflags;
+
+pub mod ascend;
+pub(crate) mod attributes;
+pub mod avp;
+pub mod code;
+#[cfg(all(test, feature = "full"))]
+mod conformance;
+pub mod convert;
+pub mod dictionary;
+pub mod diff;
+#[cfg(feature = "erx")]
+pub mod erx;
+pub mod fingerprint;
+pub mod frozen;
+#[cfg(feature = "juniper")]
+pub mod juniper;
+pub mod metadata;
+pub mod nas_profile;
+pub mod packet;
+pub mod prune;
+pub mod random;
+pub mod redact;
+pub mod registry;
+pub mod reply_message;
+pub mod request_info;
+pub mod rfc2865;
+#[cfg(feature = "rfc2866")]
+pub mod rfc2866;
+#[cfg(feature = "rfc2867")]
+pub mod rfc2867;
+#[cfg(feature = "rfc2868")]
+pub mod rfc2868;
+#[cfg(feature = "rfc2869")]
+pub mod rfc2869;
+#[cfg(feature = "rfc3162")]
+pub mod rfc3162;
+#[cfg(feature = "rfc3576")]
+pub mod rfc3576;
+#[cfg(feature = "rfc3580")]
+pub mod rfc3580;
+#[cfg(feature = "rfc4072")]
+pub mod rfc4072;
+#[cfg(feature = "rfc4372")]
+pub mod rfc4372;
+#[cfg(feature = "rfc4603")]
+pub mod rfc4603;
+#[cfg(feature = "rfc4675")]
+pub mod rfc4675;
+#[cfg(feature = "rfc4818")]
+pub mod rfc4818;
+#[cfg(feature = "rfc4849")]
+pub mod rfc4849;
+#[cfg(feature = "rfc5090")]
+pub mod rfc5090;
+#[cfg(feature = "rfc5176")]
+pub mod rfc5176;
+#[cfg(feature = "rfc5607")]
+pub mod rfc5607;
+#[cfg(feature = "rfc5904")]
+pub mod rfc5904;
+#[cfg(feature = "rfc6519")]
+pub mod rfc6519;
+#[cfg(feature = "rfc6572")]
+pub mod rfc6572;
+#[cfg(feature = "rfc6677")]
+pub mod rfc6677;
+#[cfg(feature = "rfc6911")]
+pub mod rfc6911;
+#[cfg(feature = "rfc7055")]
+pub mod rfc7055;
+#[cfg(feature = "rfc7155")]
+pub mod rfc7155;
+pub mod tag;
+pub mod test_vectors;
+pub mod util;
+pub mod vsa;
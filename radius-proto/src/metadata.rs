@@ -0,0 +1,178 @@
+use crate::avp::{AVPError, AVPType};
+
+/// This enum represents a data type of an attribute value according to the dictionary.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AttributeDataType {
+    String,
+    Octets,
+    IpAddr,
+    Ipv4Prefix,
+    Ipv6Addr,
+    Ipv6Prefix,
+    IfId,
+    Date,
+    Integer,
+    /// A signed 32-bit integer.
+    Signed,
+    Short,
+    /// A 6-octet MAC address.
+    Ether,
+    /// An Ascend binary filter.
+    ABinary,
+    /// Either of IPv4 or IPv6 address, that is distinguished by the length.
+    ComboIp,
+    VSA,
+    /// The data type that this crate doesn't support (e.g. `tlv`, `struct`) or the unsupported combination
+    /// of the data type and the tag (e.g. tagged `ipv6addr`). The value of such an attribute is the raw octets.
+    Unsupported,
+}
+
+/// This struct represents the metadata of an attribute that is defined in a dictionary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeMetadata {
+    /// The name of the attribute (e.g. `User-Name`).
+    pub name: &'static str,
+    /// The AVP type of the attribute.
+    pub typ: AVPType,
+    /// The data type of the attribute value.
+    pub data_type: AttributeDataType,
+    /// Whether the attribute value is encrypted (e.g. `User-Password`, `Tunnel-Password`) or not.
+    pub encrypted: bool,
+    /// Whether the attribute value has a tag or not.
+    pub has_tag: bool,
+    /// The maximum length of the attribute value in bytes; for a value that is split into multiple attributes
+    /// (e.g. `EAP-Message`), this is the maximum total length. `None` means unlimited.
+    pub max_length: Option<usize>,
+    /// The maximum number of occurrences of the attribute in a packet. `None` means unlimited.
+    pub max_occurrences: Option<usize>,
+}
+
+impl AttributeMetadata {
+    /// Returns an error if the data type of the attribute is not supported, i.e. the value can be handled only as the raw octets.
+    pub fn ensure_supported(&self) -> Result<(), AVPError> {
+        match self.data_type {
+            AttributeDataType::Unsupported => {
+                Err(AVPError::UnsupportedDataTypeError(self.name.to_owned()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// This struct represents a named value of an attribute that is defined in a dictionary (i.e. `VALUE` line).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueMetadata {
+    /// The AVP type of the attribute that has the value.
+    pub typ: AVPType,
+    /// The name of the value (e.g. `Framed-User`).
+    pub name: &'static str,
+    /// The value.
+    pub value: u32,
+}
+
+#[cfg(all(test, feature = "rfc2868", feature = "rfc2869"))]
+mod tests {
+    use crate::avp::AVPError;
+    use crate::metadata::{AttributeDataType, AttributeMetadata};
+    use crate::{registry, rfc2865, rfc2868, rfc2869};
+
+    #[test]
+    fn test_generated_attribute_table() {
+        let user_password = rfc2865::ATTRIBUTE_TABLE
+            .iter()
+            .find(|metadata| metadata.typ == rfc2865::USER_PASSWORD_TYPE)
+            .unwrap();
+        assert_eq!(user_password.name, "User-Password");
+        assert_eq!(user_password.data_type, AttributeDataType::String);
+        assert!(user_password.encrypted);
+        assert!(!user_password.has_tag);
+        assert_eq!(user_password.max_length, Some(128));
+        assert_eq!(user_password.max_occurrences, Some(1));
+
+        let tunnel_type = rfc2868::ATTRIBUTE_TABLE
+            .iter()
+            .find(|metadata| metadata.typ == rfc2868::TUNNEL_TYPE_TYPE)
+            .unwrap();
+        assert_eq!(tunnel_type.name, "Tunnel-Type");
+        assert_eq!(tunnel_type.data_type, AttributeDataType::Integer);
+        assert!(!tunnel_type.encrypted);
+        assert!(tunnel_type.has_tag);
+        assert_eq!(tunnel_type.max_length, Some(5));
+        assert_eq!(tunnel_type.max_occurrences, None);
+
+        let eap_message = rfc2869::ATTRIBUTE_TABLE
+            .iter()
+            .find(|metadata| metadata.typ == rfc2869::EAP_MESSAGE_TYPE)
+            .unwrap();
+        assert_eq!(eap_message.data_type, AttributeDataType::Octets);
+        assert_eq!(eap_message.max_length, Some(4044));
+    }
+
+    #[test]
+    fn test_ensure_supported() {
+        let user_name = registry::attribute_metadata(rfc2865::USER_NAME_TYPE).unwrap();
+        assert_eq!(user_name.ensure_supported(), Ok(()));
+
+        let unsupported = AttributeMetadata {
+            name: "Example-MAC-Address",
+            typ: 250,
+            data_type: AttributeDataType::Unsupported,
+            encrypted: false,
+            has_tag: false,
+            max_length: Some(253),
+            max_occurrences: None,
+        };
+        assert_eq!(
+            unsupported.ensure_supported(),
+            Err(AVPError::UnsupportedDataTypeError(
+                "Example-MAC-Address".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_registry() {
+        assert_eq!(
+            registry::attribute_name(rfc2865::USER_NAME_TYPE),
+            Some("User-Name")
+        );
+        assert_eq!(
+            registry::attribute_name(rfc2869::EAP_MESSAGE_TYPE),
+            Some("EAP-Message")
+        );
+        assert_eq!(registry::attribute_name(250), None);
+
+        assert_eq!(
+            registry::attribute_type("Framed-IP-Address"),
+            Some(rfc2865::FRAMED_IP_ADDRESS_TYPE)
+        );
+        assert_eq!(registry::attribute_type("No-Such-Attribute"), None);
+
+        let metadata = registry::attribute_metadata(rfc2868::TUNNEL_PASSWORD_TYPE).unwrap();
+        assert_eq!(metadata.name, "Tunnel-Password");
+        assert!(metadata.encrypted);
+        assert!(registry::attribute_metadata(250).is_none());
+    }
+
+    #[test]
+    fn test_registry_in_const_context() {
+        const USER_NAME: Option<&str> = registry::attribute_name(rfc2865::USER_NAME_TYPE);
+        const EAP_MESSAGE_TYPE: Option<u8> = registry::attribute_type("EAP-Message");
+        const TUNNEL_PASSWORD: Option<&AttributeMetadata> =
+            registry::attribute_metadata(rfc2868::TUNNEL_PASSWORD_TYPE);
+        assert_eq!(USER_NAME, Some("User-Name"));
+        assert_eq!(EAP_MESSAGE_TYPE, Some(rfc2869::EAP_MESSAGE_TYPE));
+        assert!(TUNNEL_PASSWORD.unwrap().encrypted);
+
+        // every entry of the tables is reachable by the lookups
+        for metadata in registry::ATTRIBUTE_TABLES
+            .iter()
+            .flat_map(|table| table.iter())
+        {
+            assert_eq!(registry::attribute_metadata(metadata.typ), Some(metadata));
+            assert_eq!(registry::attribute_name(metadata.typ), Some(metadata.name));
+            assert_eq!(registry::attribute_type(metadata.name), Some(metadata.typ));
+        }
+    }
+}
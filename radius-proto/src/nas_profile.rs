@@ -0,0 +1,505 @@
+//! Per-NAS capability profiles, i.e. the quirks of the NASes that the outgoing packets should be adapted to.
+//!
+//! A `NasProfile` describes whether the NAS needs `Message-Authenticator`, the maximum size of the packets that it accepts,
+//! the vendors of the `Vendor-Specific` attributes that it understands and the format of the MAC addresses
+//! in `Calling-Station-Id`/`Called-Station-Id`; `NasProfile::apply()` adapts a packet to those.
+//! `NasProfileRegistry` looks up the profile of a NAS by its address, `NAS-IP-Address`, `NAS-Identifier`
+//! or the vendor that `fingerprint::NasFingerprint` guesses.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::avp::AVP;
+use crate::fingerprint::{self, NasFingerprint, NasVendor};
+use crate::packet::{Packet, PacketError, MAX_PACKET_LENGTH, MESSAGE_AUTHENTICATOR_TYPE};
+use crate::rfc2865;
+use crate::vsa::VENDOR_SPECIFIC_TYPE;
+
+#[derive(Error, Debug)]
+pub enum NasProfileError {
+    /// This error is raised when the adapted packet exceeds the maximum packet size of the NAS.
+    #[error("the packet of {0} bytes exceeds the maximum packet size {1} bytes of the NAS")]
+    PacketTooLargeError(usize, usize),
+
+    /// This error is raised when the adapted packet cannot be encoded.
+    #[error("failed to encode the packet; {0}")]
+    EncodingError(PacketError),
+}
+
+/// This enum represents the vendors of the `Vendor-Specific` attributes that a NAS understands.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum VsaDialect {
+    /// The NAS accepts the VSAs of any vendor.
+    #[default]
+    Any,
+    /// The NAS understands only the VSAs of these vendor IDs; the others are dropped.
+    Vendors(Vec<u32>),
+    /// The NAS doesn't accept any VSA; all of those are dropped.
+    Unsupported,
+}
+
+impl VsaDialect {
+    fn accepts(&self, avp: &AVP) -> bool {
+        match self {
+            VsaDialect::Any => true,
+            VsaDialect::Vendors(vendor_ids) => fingerprint::vsa_vendor_id(avp)
+                .is_some_and(|vendor_id| vendor_ids.contains(&vendor_id)),
+            VsaDialect::Unsupported => false,
+        }
+    }
+}
+
+/// This enum represents the format of the MAC addresses that a NAS expects in `Calling-Station-Id`/`Called-Station-Id`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum MacFormat {
+    /// The MAC addresses are left as they are.
+    #[default]
+    AsIs,
+    /// e.g. `00-00-5E-00-53-01` (RFC 3580)
+    HyphenUpper,
+    /// e.g. `00:00:5e:00:53:01`
+    ColonLower,
+    /// e.g. `00005e005301`
+    BareLower,
+    /// e.g. `0000.5e00.5301` (Cisco)
+    DottedLower,
+}
+
+impl MacFormat {
+    /// Format a MAC address; `MacFormat::AsIs` returns `None`.
+    pub fn format(&self, mac: &[u8; 6]) -> Option<String> {
+        let hex = |separator: &str, upper: bool| {
+            mac.iter()
+                .map(|b| match upper {
+                    true => format!("{b:02X}"),
+                    false => format!("{b:02x}"),
+                })
+                .collect::<Vec<String>>()
+                .join(separator)
+        };
+        match self {
+            MacFormat::AsIs => None,
+            MacFormat::HyphenUpper => Some(hex("-", true)),
+            MacFormat::ColonLower => Some(hex(":", false)),
+            MacFormat::BareLower => Some(hex("", false)),
+            MacFormat::DottedLower => {
+                let bare = hex("", false);
+                Some(format!("{}.{}.{}", &bare[..4], &bare[4..8], &bare[8..]))
+            }
+        }
+    }
+
+    /// Reformat the MAC address at the head of a `Calling-Station-Id`/`Called-Station-Id` value,
+    /// keeping the rest of that (e.g. `:SSID` of RFC 3580). If the value doesn't begin with a MAC address, this returns `None`.
+    pub fn reformat(&self, value: &str) -> Option<String> {
+        let (mac, rest) = parse_mac_address(value)?;
+        self.format(&mac).map(|formatted| formatted + rest)
+    }
+
+    /// Returns the format of the MAC address at the head of a `Calling-Station-Id`/`Called-Station-Id` value,
+    /// or `None` if the value doesn't begin with a MAC address in any of the formats.
+    pub fn detect(value: &str) -> Option<MacFormat> {
+        let (mac, _) = parse_mac_address(value)?;
+        [
+            MacFormat::HyphenUpper,
+            MacFormat::ColonLower,
+            MacFormat::BareLower,
+            MacFormat::DottedLower,
+        ]
+        .into_iter()
+        .find(|format| {
+            format
+                .format(&mac)
+                .is_some_and(|formatted| value.starts_with(&formatted))
+        })
+    }
+}
+
+/// Parse the MAC address at the head of the value in any of the forms of `MacFormat`, and returns that and the rest.
+/// The rest must be empty or begin with `:`.
+fn parse_mac_address(value: &str) -> Option<([u8; 6], &str)> {
+    let hex_digits = |s: &str| -> Option<Vec<u8>> {
+        if !s.len().is_multiple_of(2) || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+            .collect()
+    };
+    let separated = |len: usize, separator: char, group: usize| -> Option<([u8; 6], &str)> {
+        let head = value.get(..len)?;
+        let groups = head.split(separator).collect::<Vec<&str>>();
+        if groups.len() != 12 / group || groups.iter().any(|g| g.len() != group) {
+            return None;
+        }
+        let mac = hex_digits(&groups.concat())?.try_into().ok()?;
+        Some((mac, &value[len..]))
+    };
+
+    [
+        separated(17, ':', 2),
+        separated(17, '-', 2),
+        separated(14, '.', 4),
+        separated(12, ' ', 12),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|(_, rest)| rest.is_empty() || rest.starts_with(':'))
+}
+
+/// This struct represents the capabilities and the quirks of a NAS (or a family of the NASes).
+#[derive(Debug, Clone, PartialEq)]
+pub struct NasProfile {
+    name: String,
+    requires_message_authenticator: bool,
+    max_packet_size: usize,
+    vsa_dialect: VsaDialect,
+    mac_format: MacFormat,
+}
+
+impl NasProfile {
+    /// Make a profile of the name (e.g. a model name), that doesn't adapt the packets until the capabilities are set.
+    pub fn new(name: &str) -> Self {
+        NasProfile {
+            name: name.to_owned(),
+            requires_message_authenticator: false,
+            max_packet_size: MAX_PACKET_LENGTH,
+            vsa_dialect: VsaDialect::default(),
+            mac_format: MacFormat::default(),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_requires_message_authenticator(&self) -> bool {
+        self.requires_message_authenticator
+    }
+
+    /// Set whether the NAS needs `Message-Authenticator` in every packet (default: `false`).
+    pub fn set_requires_message_authenticator(&mut self, requires_message_authenticator: bool) {
+        self.requires_message_authenticator = requires_message_authenticator;
+    }
+
+    pub fn get_max_packet_size(&self) -> usize {
+        self.max_packet_size
+    }
+
+    /// Set the maximum size of the packets that the NAS accepts (default: `MAX_PACKET_LENGTH`, i.e. 4096 bytes).
+    /// Some NASes have the smaller receive buffers than the RFC maximum.
+    pub fn set_max_packet_size(&mut self, max_packet_size: usize) {
+        self.max_packet_size = max_packet_size.min(MAX_PACKET_LENGTH);
+    }
+
+    pub fn get_vsa_dialect(&self) -> &VsaDialect {
+        &self.vsa_dialect
+    }
+
+    /// Set the vendors of the VSAs that the NAS understands (default: `VsaDialect::Any`).
+    pub fn set_vsa_dialect(&mut self, vsa_dialect: VsaDialect) {
+        self.vsa_dialect = vsa_dialect;
+    }
+
+    pub fn get_mac_format(&self) -> MacFormat {
+        self.mac_format
+    }
+
+    /// Set the format of the MAC addresses in `Calling-Station-Id`/`Called-Station-Id` (default: `MacFormat::AsIs`).
+    pub fn set_mac_format(&mut self, mac_format: MacFormat) {
+        self.mac_format = mac_format;
+    }
+
+    /// Adapt a packet to the NAS: this drops the VSAs that the NAS doesn't understand, reformats the MAC addresses,
+    /// and adds `Message-Authenticator` if the NAS needs that (the value is computed on encoding).
+    ///
+    /// This fails with `NasProfileError::PacketTooLargeError` if the packet still exceeds the maximum packet size of the NAS.
+    pub fn apply(&self, packet: &mut Packet) -> Result<(), NasProfileError> {
+        packet
+            .get_avps_mut()
+            .retain(|avp| avp.typ != VENDOR_SPECIFIC_TYPE || self.vsa_dialect.accepts(avp));
+
+        if self.mac_format != MacFormat::AsIs {
+            for avp in packet.get_avps_mut().iter_mut().filter(|avp| {
+                avp.typ == rfc2865::CALLING_STATION_ID_TYPE
+                    || avp.typ == rfc2865::CALLED_STATION_ID_TYPE
+            }) {
+                let reformatted = avp
+                    .decode_string()
+                    .ok()
+                    .and_then(|value| self.mac_format.reformat(&value));
+                if let Some(reformatted) = reformatted {
+                    *avp = AVP::from_string(avp.typ, &reformatted);
+                }
+            }
+        }
+
+        if self.requires_message_authenticator && !packet.contains(MESSAGE_AUTHENTICATOR_TYPE) {
+            packet.add(AVP::from_bytes(MESSAGE_AUTHENTICATOR_TYPE, &[0; 16]).unwrap());
+        }
+
+        let size = packet.encoded_len();
+        if size > self.max_packet_size {
+            return Err(NasProfileError::PacketTooLargeError(
+                size,
+                self.max_packet_size,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Encode a packet that is adapted to the NAS by `apply()`; the given packet is left as it is.
+    pub fn encode(&self, packet: &Packet) -> Result<Vec<u8>, NasProfileError> {
+        let mut packet = packet.clone();
+        self.apply(&mut packet)?;
+        packet.encode().map_err(NasProfileError::EncodingError)
+    }
+}
+
+/// This struct holds the profiles of the NASes, and looks up that of a NAS.
+///
+/// The profile is looked up by the source address of the packet, `NAS-IP-Address`, `NAS-Identifier` and the vendor
+/// that `NasFingerprint` guesses in order, and the default profile is returned if none of those is registered.
+#[derive(Debug, Clone, Default)]
+pub struct NasProfileRegistry {
+    by_ip_address: HashMap<IpAddr, Arc<NasProfile>>,
+    by_identifier: HashMap<String, Arc<NasProfile>>,
+    by_vendor: HashMap<NasVendor, Arc<NasProfile>>,
+    default_profile: Option<Arc<NasProfile>>,
+}
+
+impl NasProfileRegistry {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Register the profile of the NAS of the IP address (i.e. the source address or `NAS-IP-Address`).
+    pub fn add_by_ip_address(&mut self, ip_address: IpAddr, profile: NasProfile) {
+        self.by_ip_address.insert(ip_address, Arc::new(profile));
+    }
+
+    /// Register the profile of the NAS of the `NAS-Identifier`.
+    pub fn add_by_identifier(&mut self, identifier: &str, profile: NasProfile) {
+        self.by_identifier
+            .insert(identifier.to_owned(), Arc::new(profile));
+    }
+
+    /// Register the profile of the NASes of the vendor, that is guessed by `NasFingerprint`.
+    pub fn add_by_vendor(&mut self, vendor: NasVendor, profile: NasProfile) {
+        self.by_vendor.insert(vendor, Arc::new(profile));
+    }
+
+    /// Set the profile of the NASes that aren't registered (default: none).
+    pub fn set_default_profile(&mut self, profile: NasProfile) {
+        self.default_profile = Some(Arc::new(profile));
+    }
+
+    /// Returns the profile of the NAS that sent the packet from the address.
+    pub fn lookup(&self, source: IpAddr, packet: &Packet) -> Option<Arc<NasProfile>> {
+        let nas_ip_address = rfc2865::lookup_nas_ip_address(packet)
+            .and_then(|ip| ip.ok())
+            .map(IpAddr::V4);
+        let nas_identifier = rfc2865::lookup_nas_identifier(packet).and_then(|id| id.ok());
+
+        [Some(source), nas_ip_address]
+            .into_iter()
+            .flatten()
+            .find_map(|ip| self.by_ip_address.get(&ip))
+            .or_else(|| nas_identifier.and_then(|id| self.by_identifier.get(&id)))
+            .or_else(|| {
+                NasFingerprint::of(packet)
+                    .get_vendor()
+                    .and_then(|vendor| self.by_vendor.get(&vendor))
+            })
+            .or(self.default_profile.as_ref())
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use crate::avp::AVP;
+    use crate::code::Code;
+    use crate::fingerprint::NasVendor;
+    use crate::nas_profile::{
+        MacFormat, NasProfile, NasProfileError, NasProfileRegistry, VsaDialect,
+    };
+    use crate::packet::{Packet, MESSAGE_AUTHENTICATOR_TYPE};
+    use crate::rfc2865;
+    use crate::vsa::{Vendor, VendorFormat, VENDOR_SPECIFIC_TYPE};
+
+    #[test]
+    fn test_mac_format() {
+        let mac = [0x00, 0x00, 0x5e, 0x00, 0x53, 0x01];
+        assert_eq!(MacFormat::AsIs.format(&mac), None);
+        assert_eq!(
+            MacFormat::HyphenUpper.format(&mac).unwrap(),
+            "00-00-5E-00-53-01"
+        );
+        assert_eq!(
+            MacFormat::ColonLower.format(&mac).unwrap(),
+            "00:00:5e:00:53:01"
+        );
+        assert_eq!(MacFormat::BareLower.format(&mac).unwrap(), "00005e005301");
+        assert_eq!(
+            MacFormat::DottedLower.format(&mac).unwrap(),
+            "0000.5e00.5301"
+        );
+
+        for value in [
+            "00-00-5E-00-53-01",
+            "00:00:5e:00:53:01",
+            "00005E005301",
+            "0000.5e00.5301",
+        ] {
+            assert_eq!(
+                MacFormat::HyphenUpper.reformat(value).unwrap(),
+                "00-00-5E-00-53-01"
+            );
+        }
+        assert_eq!(
+            MacFormat::ColonLower
+                .reformat("00-00-5E-00-53-01:example-ssid")
+                .unwrap(),
+            "00:00:5e:00:53:01:example-ssid"
+        );
+        assert_eq!(MacFormat::ColonLower.reformat("+15550100"), None);
+
+        assert_eq!(
+            MacFormat::detect("00:00:5e:00:53:01:example-ssid"),
+            Some(MacFormat::ColonLower)
+        );
+        assert_eq!(
+            MacFormat::detect("00005e005301"),
+            Some(MacFormat::BareLower)
+        );
+        // the parsable but unsupported format
+        assert_eq!(MacFormat::detect("00-00-5e-00-53-01"), None);
+        assert_eq!(MacFormat::ColonLower.reformat("00-00-5E-00-53-01x"), None);
+    }
+
+    #[test]
+    fn test_apply() -> Result<(), NasProfileError> {
+        let known = Vendor::new(2636, VendorFormat::DEFAULT);
+        let unknown = Vendor::new(4874, VendorFormat::DEFAULT);
+
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        rfc2865::add_calling_station_id(&mut packet, "00:00:5e:00:53:01");
+        rfc2865::add_called_station_id(&mut packet, "00-00-5E-00-53-02:example-ssid");
+        packet.add_vsa(&known, 1, AVP::from_string(0, "known"));
+        packet.add_vsa(&unknown, 1, AVP::from_string(0, "unknown"));
+
+        // the default profile leaves the packet as it is
+        let mut adapted = packet.clone();
+        NasProfile::new("default").apply(&mut adapted)?;
+        assert_eq!(adapted, packet);
+
+        let mut profile = NasProfile::new("example");
+        profile.set_requires_message_authenticator(true);
+        profile.set_vsa_dialect(VsaDialect::Vendors(vec![2636]));
+        profile.set_mac_format(MacFormat::HyphenUpper);
+
+        let mut adapted = packet.clone();
+        profile.apply(&mut adapted)?;
+        assert_eq!(
+            rfc2865::lookup_calling_station_id(&adapted)
+                .unwrap()
+                .unwrap(),
+            "00-00-5E-00-53-01"
+        );
+        assert_eq!(
+            rfc2865::lookup_called_station_id(&adapted)
+                .unwrap()
+                .unwrap(),
+            "00-00-5E-00-53-02:example-ssid"
+        );
+        assert!(adapted.contains_vsa(&known, 1));
+        assert!(!adapted.contains_vsa(&unknown, 1));
+        assert_eq!(adapted.count(MESSAGE_AUTHENTICATOR_TYPE), 1);
+
+        // applying again doesn't change the adapted packet
+        let mut again = adapted.clone();
+        profile.apply(&mut again)?;
+        assert_eq!(again, adapted);
+
+        let decoded = Packet::decode(&profile.encode(&packet)?, b"secret").unwrap();
+        assert!(decoded.is_authentic_message_authenticator(Some(packet.get_authenticator())));
+
+        profile.set_vsa_dialect(VsaDialect::Unsupported);
+        let mut adapted = packet.clone();
+        profile.apply(&mut adapted)?;
+        assert!(!adapted.contains(VENDOR_SPECIFIC_TYPE));
+
+        profile.set_max_packet_size(64);
+        match profile.apply(&mut packet.clone()) {
+            Err(NasProfileError::PacketTooLargeError(size, 64)) => assert!(size > 64),
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = NasProfileRegistry::new();
+        assert!(registry
+            .lookup(
+                IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+                &Packet::new(Code::AccessRequest, b"secret")
+            )
+            .is_none());
+
+        registry.add_by_ip_address(
+            IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+            NasProfile::new("by-source"),
+        );
+        registry.add_by_ip_address(
+            IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+            NasProfile::new("by-nas-ip-address"),
+        );
+        registry.add_by_identifier("nas3", NasProfile::new("by-identifier"));
+        registry.add_by_vendor(NasVendor::MikroTik, NasProfile::new("by-vendor"));
+        registry.set_default_profile(NasProfile::new("default"));
+
+        let lookup = |source: Ipv4Addr, f: &dyn Fn(&mut Packet)| {
+            let mut packet = Packet::new(Code::AccessRequest, b"secret");
+            f(&mut packet);
+            registry
+                .lookup(IpAddr::V4(source), &packet)
+                .unwrap()
+                .get_name()
+                .to_owned()
+        };
+
+        assert_eq!(lookup(Ipv4Addr::new(192, 0, 2, 1), &|_| {}), "by-source");
+        assert_eq!(
+            lookup(Ipv4Addr::new(198, 51, 100, 1), &|packet| {
+                rfc2865::add_nas_ip_address(packet, &Ipv4Addr::new(192, 0, 2, 2))
+            }),
+            "by-nas-ip-address"
+        );
+        assert_eq!(
+            lookup(Ipv4Addr::new(198, 51, 100, 1), &|packet| {
+                rfc2865::add_nas_identifier(packet, "nas3")
+            }),
+            "by-identifier"
+        );
+        assert_eq!(
+            lookup(Ipv4Addr::new(198, 51, 100, 1), &|packet| {
+                packet.add_vsa(
+                    &Vendor::new(14988, VendorFormat::DEFAULT),
+                    1,
+                    AVP::from_string(0, "example"),
+                )
+            }),
+            "by-vendor"
+        );
+        assert_eq!(lookup(Ipv4Addr::new(198, 51, 100, 1), &|_| {}), "default");
+    }
+}
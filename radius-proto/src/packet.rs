@@ -0,0 +1,1691 @@
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::attributes::Attributes;
+use crate::avp::{AVPError, AVPType, JumboAttributePolicy, AVP};
+use crate::code::Code;
+use crate::convert::{FromAvp, FromPacket, ToPacket};
+use crate::dictionary::encode_hex;
+use crate::diff::PacketDiff;
+use crate::prune::OversizeReport;
+use crate::random::{self, RandomSource};
+use crate::registry;
+use crate::util::truncate_utf8;
+use crate::vsa::{Vendor, VendorType, VENDOR_SPECIFIC_TYPE};
+
+/// The maximum length of a packet.
+pub const MAX_PACKET_LENGTH: usize = 4096;
+/// The length of the header of a packet, i.e. the minimum length of a packet.
+pub const RADIUS_PACKET_HEADER_LENGTH: usize = 20;
+// the same as `MESSAGE_AUTHENTICATOR_TYPE`; that module is optional (i.e. `rfc2869` feature)
+pub(crate) const MESSAGE_AUTHENTICATOR_TYPE: AVPType = 80;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PacketError {
+    /// An error indicates the entire length of the given packet has insufficient length.
+    #[error("RADIUS packet doesn't have enough length of bytes; it has to be at least {0} bytes, but actual length was {1}")]
+    InsufficientPacketPayloadLengthError(usize, usize),
+
+    /// An error indicates the length that is instructed by a header is insufficient.
+    #[error("RADIUS packet header indicates the length as {0} bytes, but this is insufficient; this must have {1} bytes at least")]
+    InsufficientHeaderDefinedPacketLengthError(usize, usize),
+
+    /// An error indicates the length that is instructed by a header exceeds the maximum length of the RADIUS packet.
+    #[error("RADIUS packet header indicates the length as {0} bytes, but this exceeds the maximum length {1} bytes")]
+    HeaderDefinedPacketLengthExceedsMaximumLimitError(usize, usize),
+
+    /// An error that is raised when an error has been occurred on decoding bytes for a packet.
+    #[error("failed to decode the packet: {0}")]
+    DecodingError(String),
+
+    /// An error that is raised when an error has been occurred on encoding a packet into bytes.
+    #[error("failed to encode the packet: {0}")]
+    EncodingError(String),
+
+    /// An error that is raised when the packet exceeds the maximum packet length on encoding;
+    /// the report tells the attributes that would need to be dropped.
+    #[error("packet is too large: {0}")]
+    PacketTooLargeError(OversizeReport),
+
+    /// An error that is raised when it received unknown packet type code of RADIUS.
+    #[error("Unknown RADIUS packet type code: {0}")]
+    UnknownCodeError(String),
+
+    /// An error that is raised when an attribute appears more than the permitted number of times.
+    #[error(
+        "attribute type {0} must appear at most {1} time(s) in a packet, but it appeared {2} times"
+    )]
+    DuplicateAttributeError(AVPType, usize, usize),
+}
+
+/// This enum represents a policy for the attributes that appear more than the permitted number of times in a packet
+/// (e.g. RFC 2865 says `User-Password` must appear at most once).
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum DuplicatePolicy {
+    /// Accepts all of the duplicated attributes as they are.
+    #[default]
+    Allow,
+    /// Keeps the attributes up to the permitted number of times in order of appearance, and drops the rest.
+    FirstWins,
+    /// Rejects the packet with `PacketError::DuplicateAttributeError`.
+    Reject,
+}
+
+/// This enum represents a policy for the order of the attributes on encoding a packet;
+/// some NAS implementations are sensitive to the order of the attributes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum OrderingPolicy {
+    /// Encodes the attributes in order of insertion (or appearance on decoding).
+    #[default]
+    Preserve,
+    /// Encodes the attributes in ascending order of the type; the attributes of the same type keep their order.
+    Canonical,
+    /// Encodes `Message-Authenticator` at first, and the others in order of insertion.
+    MessageAuthenticatorFirst,
+}
+
+/// This enum represents a policy for the string values that don't fit in an attribute (i.e. longer than 253 bytes),
+/// which is applied by the `add_*` functions of the string attributes of the dictionary modules.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum OversizeStringPolicy {
+    /// Adds the value as it is; the encoding of the packet fails.
+    #[default]
+    Keep,
+    /// Truncates the value to fit in the attribute at a UTF-8 character boundary (e.g. a long `User-Name` or `Called-Station-Id`).
+    Truncate,
+}
+
+fn max_occurrences(typ: AVPType) -> Option<usize> {
+    registry::attribute_metadata(typ).and_then(|metadata| metadata.max_occurrences)
+}
+
+/// This struct represents a packet of RADIUS for request and response.
+///
+/// `Debug` and `Display` of this mask the shared secret and the values of the sensitive attributes;
+/// see also `Redact`.
+#[derive(Clone)]
+pub struct Packet {
+    code: Code,
+    identifier: u8,
+    authenticator: Vec<u8>,
+    secret: Vec<u8>,
+    attributes: Attributes,
+    ordering_policy: OrderingPolicy,
+    oversize_string_policy: OversizeStringPolicy,
+    random_source: Arc<dyn RandomSource>,
+}
+
+// the random source doesn't take part in the equality; it affects only the values that are generated later
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+            && self.identifier == other.identifier
+            && self.authenticator == other.authenticator
+            && self.secret == other.secret
+            && self.attributes == other.attributes
+            && self.ordering_policy == other.ordering_policy
+            && self.oversize_string_policy == other.oversize_string_policy
+    }
+}
+
+impl Packet {
+    /// Constructor for a Packet.
+    ///
+    /// By default, this constructor makes an instance with a random identifier value.
+    /// If you'd like to set an arbitrary identifier, please use `new_with_identifier()` constructor instead or `set_identifier()` method for created instance.
+    pub fn new(code: Code, secret: &[u8]) -> Self {
+        Self::_new(code, secret, None, random::default_random_source())
+    }
+
+    /// Constructor for a Packet that generates the random values (i.e. the identifier, the authenticator and
+    /// the salts of the encrypted attributes) from the given source; see also `set_random_source()`.
+    ///
+    /// e.g. the packets that are made with `SeededRandomSource` of the same seed are byte-identical.
+    pub fn new_with_random_source(
+        code: Code,
+        secret: &[u8],
+        random_source: Arc<dyn RandomSource>,
+    ) -> Self {
+        Self::_new(code, secret, None, random_source)
+    }
+
+    /// Constructor for a Packet with arbitrary identifier value.
+    ///
+    /// If you want to make an instance with a random identifier value, please consider using `new()`.
+    pub fn new_with_identifier(code: Code, secret: &[u8], identifier: u8) -> Self {
+        Self::_new(
+            code,
+            secret,
+            Some(identifier),
+            random::default_random_source(),
+        )
+    }
+
+    /// Constructor for a Packet that has room for the given number of the attributes without reallocation;
+    /// e.g. for a proxy that copies the attributes of a request.
+    pub fn with_capacity(code: Code, secret: &[u8], capacity: usize) -> Self {
+        let mut packet = Self::_new(code, secret, None, random::default_random_source());
+        packet.reserve(capacity);
+        packet
+    }
+
+    fn _new(
+        code: Code,
+        secret: &[u8],
+        maybe_identifier: Option<u8>,
+        random_source: Arc<dyn RandomSource>,
+    ) -> Self {
+        let [identifier, authenticator @ ..] = random::random_bytes::<17>(random_source.as_ref());
+        Packet {
+            code: code.to_owned(),
+            identifier: maybe_identifier.unwrap_or(identifier),
+            authenticator: authenticator.to_vec(),
+            secret: secret.to_owned(),
+            attributes: Attributes(vec![]),
+            ordering_policy: OrderingPolicy::default(),
+            oversize_string_policy: OversizeStringPolicy::default(),
+            random_source,
+        }
+    }
+
+    pub fn get_code(&self) -> Code {
+        self.code
+    }
+
+    /// Returns the identifier that matches the response to the request.
+    pub fn get_identifier(&self) -> u8 {
+        self.identifier
+    }
+
+    pub fn get_secret(&self) -> &Vec<u8> {
+        &self.secret
+    }
+
+    /// Returns the authenticator (16 bytes); see `set_authenticator()` for the meaning of this value per packet code.
+    pub fn get_authenticator(&self) -> &Vec<u8> {
+        &self.authenticator
+    }
+
+    /// This sets an authenticator value to an instance.
+    ///
+    /// The meaning of this value depends on the code of the packet:
+    ///
+    /// * Access-Request and Status-Server: this is the Request Authenticator that is sent as it is, so this must be
+    ///   unpredictable and unique over the lifetime of the secret (see also: https://tools.ietf.org/html/rfc2865#section-3).
+    ///   The encrypted attributes (e.g. `User-Password`) are encrypted with this value when those are added,
+    ///   so this has to be set before adding those; a proxy that forwards a request as it is keeps the original one.
+    /// * Responses (e.g. Access-Accept): this is the Request Authenticator of the corresponding request,
+    ///   and `encode()` computes the Response Authenticator from this; `make_response_packet()` sets this.
+    /// * Accounting-Request, Disconnect-Request and CoA-Request: `encode()` computes the Request Authenticator,
+    ///   so this value is not sent.
+    pub fn set_authenticator(&mut self, authenticator: [u8; 16]) {
+        self.authenticator = authenticator.to_vec();
+    }
+
+    pub fn get_ordering_policy(&self) -> OrderingPolicy {
+        self.ordering_policy
+    }
+
+    /// Set a policy for the order of the attributes on encoding (default: `OrderingPolicy::Preserve`).
+    ///
+    /// This doesn't change the order of the attributes in this packet (e.g. `lookup_all()`);
+    /// it takes effect on `encode()`, and `Message-Authenticator` is computed over the reordered attributes.
+    pub fn set_ordering_policy(&mut self, ordering_policy: OrderingPolicy) {
+        self.ordering_policy = ordering_policy;
+    }
+
+    pub fn get_oversize_string_policy(&self) -> OversizeStringPolicy {
+        self.oversize_string_policy
+    }
+
+    /// Set a policy for the string values that don't fit in an attribute (default: `OversizeStringPolicy::Keep`).
+    pub fn set_oversize_string_policy(&mut self, oversize_string_policy: OversizeStringPolicy) {
+        self.oversize_string_policy = oversize_string_policy;
+    }
+
+    /// (This method is for dictionary developers) truncate a string value to `max_len` bytes by `util::truncate_utf8()`
+    /// if the oversize string policy is `OversizeStringPolicy::Truncate`; otherwise this returns the value as it is.
+    pub fn truncate_string<'a>(&self, value: &'a str, max_len: usize) -> &'a str {
+        match self.oversize_string_policy {
+            OversizeStringPolicy::Keep => value,
+            OversizeStringPolicy::Truncate => {
+                let truncated = truncate_utf8(value, max_len);
+                if truncated.len() < value.len() {
+                    debug!(
+                        "truncated a string value from {} bytes to {} bytes",
+                        value.len(),
+                        truncated.len()
+                    );
+                }
+                truncated
+            }
+        }
+    }
+
+    pub fn get_random_source(&self) -> &Arc<dyn RandomSource> {
+        &self.random_source
+    }
+
+    /// Set a source of the random values that are generated after this (e.g. the salt of `Tunnel-Password`);
+    /// the responses that are made by `make_response_packet()` inherit this.
+    pub fn set_random_source(&mut self, random_source: Arc<dyn RandomSource>) {
+        self.random_source = random_source;
+    }
+
+    /// Returns the AVPs of this packet in the order of those on the wire.
+    pub fn get_avps(&self) -> &[AVP] {
+        &self.attributes.0
+    }
+
+    pub(crate) fn remove_avp(&mut self, index: usize) -> AVP {
+        self.attributes.0.remove(index)
+    }
+
+    pub(crate) fn get_avps_mut(&mut self) -> &mut Vec<AVP> {
+        &mut self.attributes.0
+    }
+
+    /// This sets an identifier value to an instance.
+    ///
+    /// A response must have the same identifier as the request (`make_response_packet()` sets this),
+    /// and a retransmitted request must keep the identifier so that the server can detect the duplication.
+    /// The requests in flight to the same destination from the same source port must have distinct identifiers;
+    /// note that `Client` overwrites this with the allocated one when `SocketStrategy::PerDestination` is used.
+    pub fn set_identifier(&mut self, identifier: u8) {
+        self.identifier = identifier;
+    }
+
+    /// This decodes bytes into a Packet.
+    ///
+    /// This accepts duplicated attributes as they are; if you'd like to enforce the number of occurrences,
+    /// please use `decode_with_duplicate_policy()` instead.
+    ///
+    /// The attributes are kept as they are on the wire, including the unknown types, the undecodable values,
+    /// the duplicates, and the order; so `encode()` of the decoded packet reproduces the attributes byte-for-byte
+    /// (only the authenticators are recomputed as needed), and a proxy doesn't mangle what it doesn't understand.
+    pub fn decode(bs: &[u8], secret: &[u8]) -> Result<Self, PacketError> {
+        Self::decode_with_duplicate_policy(bs, secret, DuplicatePolicy::Allow)
+    }
+
+    /// This decodes bytes into a Packet with applying the given policy to the attributes
+    /// that appear more than the permitted number of times.
+    pub fn decode_with_duplicate_policy(
+        bs: &[u8],
+        secret: &[u8],
+        duplicate_policy: DuplicatePolicy,
+    ) -> Result<Self, PacketError> {
+        if bs.len() < RADIUS_PACKET_HEADER_LENGTH {
+            return Err(PacketError::InsufficientPacketPayloadLengthError(
+                RADIUS_PACKET_HEADER_LENGTH,
+                bs.len(),
+            ));
+        }
+
+        let len = match bs[2..4].try_into() {
+            Ok(v) => u16::from_be_bytes(v),
+            Err(e) => return Err(PacketError::DecodingError(e.to_string())),
+        } as usize;
+        if len < RADIUS_PACKET_HEADER_LENGTH {
+            return Err(PacketError::InsufficientHeaderDefinedPacketLengthError(
+                len,
+                RADIUS_PACKET_HEADER_LENGTH,
+            ));
+        }
+        if len > MAX_PACKET_LENGTH {
+            return Err(
+                PacketError::HeaderDefinedPacketLengthExceedsMaximumLimitError(
+                    len,
+                    MAX_PACKET_LENGTH,
+                ),
+            );
+        }
+        if bs.len() < len {
+            return Err(PacketError::InsufficientPacketPayloadLengthError(
+                len,
+                bs.len(),
+            ));
+        }
+
+        let mut attributes = match Attributes::decode(&bs[RADIUS_PACKET_HEADER_LENGTH..len]) {
+            Ok(attributes) => attributes,
+            Err(e) => return Err(PacketError::DecodingError(e)),
+        };
+        Self::apply_duplicate_policy(&mut attributes, duplicate_policy)?;
+
+        Ok(Packet {
+            code: Code::from(bs[0]),
+            identifier: bs[1],
+            authenticator: bs[4..RADIUS_PACKET_HEADER_LENGTH].to_owned(),
+            secret: secret.to_owned(),
+            attributes,
+            ordering_policy: OrderingPolicy::default(),
+            oversize_string_policy: OversizeStringPolicy::default(),
+            random_source: random::default_random_source(),
+        })
+    }
+
+    fn apply_duplicate_policy(
+        attributes: &mut Attributes,
+        duplicate_policy: DuplicatePolicy,
+    ) -> Result<(), PacketError> {
+        if duplicate_policy == DuplicatePolicy::Allow {
+            return Ok(());
+        }
+
+        let mut counts: HashMap<AVPType, usize> = HashMap::new();
+        for avp in &attributes.0 {
+            *counts.entry(avp.typ).or_insert(0) += 1;
+        }
+
+        if duplicate_policy == DuplicatePolicy::Reject {
+            for avp in &attributes.0 {
+                let count = counts[&avp.typ];
+                if let Some(max) = max_occurrences(avp.typ) {
+                    if count > max {
+                        return Err(PacketError::DuplicateAttributeError(avp.typ, max, count));
+                    }
+                }
+            }
+            return Ok(());
+        }
+
+        let mut seen: HashMap<AVPType, usize> = HashMap::new();
+        attributes.0.retain(|avp| {
+            let seen_count = seen.entry(avp.typ).or_insert(0);
+            *seen_count += 1;
+            match max_occurrences(avp.typ) {
+                Some(max) => *seen_count <= max,
+                None => true,
+            }
+        });
+
+        Ok(())
+    }
+
+    /// This method makes a response packet according to self (i.e. request packet).
+    pub fn make_response_packet(&self, code: Code) -> Self {
+        Packet {
+            code,
+            identifier: self.identifier,
+            authenticator: self.authenticator.clone(),
+            secret: self.secret.clone(),
+            attributes: Attributes(vec![]),
+            ordering_policy: OrderingPolicy::default(),
+            oversize_string_policy: OversizeStringPolicy::default(),
+            random_source: self.random_source.clone(),
+        }
+    }
+
+    /// This method encodes the Packet into bytes.
+    ///
+    /// If the packet has a `Message-Authenticator` attribute, the value is computed on encoding,
+    /// so a placeholder value (e.g. 16 zero bytes) is enough to add that.
+    ///
+    /// If the packet exceeds the maximum packet length, this fails with `PacketError::PacketTooLargeError`;
+    /// see also `encode_with_pruning()`.
+    pub fn encode(&self) -> Result<Vec<u8>, PacketError> {
+        if self.encoded_len() > MAX_PACKET_LENGTH {
+            if let Some(report) = self.oversize_report() {
+                return Err(PacketError::PacketTooLargeError(report));
+            }
+        }
+
+        let mut bs = match self.marshal_binary_with_message_authenticator() {
+            Ok(bs) => bs,
+            Err(e) => return Err(PacketError::EncodingError(e)),
+        };
+
+        match self.code {
+            Code::AccessRequest | Code::StatusServer => Ok(bs),
+            Code::AccessAccept
+            | Code::AccessReject
+            | Code::AccountingRequest
+            | Code::AccountingResponse
+            | Code::AccessChallenge
+            | Code::DisconnectRequest
+            | Code::DisconnectACK
+            | Code::DisconnectNAK
+            | Code::CoARequest
+            | Code::CoAACK
+            | Code::CoANAK => {
+                let mut buf: Vec<u8> = bs[..4].to_vec();
+                match self.code {
+                    Code::AccountingRequest // see "Request Authenticator" in https://tools.ietf.org/html/rfc2866#section-3
+                    | Code::DisconnectRequest // same as "RFC2866"; https://tools.ietf.org/html/rfc5176#section-2.3
+                    | Code::CoARequest // same as "RFC2866"; https://tools.ietf.org/html/rfc5176#section-2.3
+                    => {
+                        buf.extend(vec![
+                            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                            0x00, 0x00, 0x00, 0x00,
+                        ]);
+                    }
+                    _ => {
+                        buf.extend(self.authenticator.clone()); // TODO take from `bs`?
+                    }
+                }
+                buf.extend(bs[RADIUS_PACKET_HEADER_LENGTH..].to_vec());
+                buf.extend(&self.secret);
+                bs.splice(4..20, md5::compute(&buf).to_vec());
+
+                Ok(bs)
+            }
+            _ => Err(PacketError::UnknownCodeError(format!("{:?}", self.code))),
+        }
+    }
+
+    fn marshal_binary_with_message_authenticator(&self) -> Result<Vec<u8>, String> {
+        if self.lookup(MESSAGE_AUTHENTICATOR_TYPE).is_none() {
+            return self.marshal_binary();
+        }
+
+        let message_authenticator = self.compute_message_authenticator(&self.authenticator)?;
+        let mut packet = self.clone();
+        for avp in packet.attributes.0.iter_mut() {
+            if avp.typ == MESSAGE_AUTHENTICATOR_TYPE {
+                avp.value = message_authenticator.clone();
+            }
+        }
+        packet.marshal_binary()
+    }
+
+    /// Compute the value of `Message-Authenticator` with the given Request Authenticator.
+    /// see also: https://tools.ietf.org/html/rfc3579#section-3.2
+    fn compute_message_authenticator(&self, authenticator: &[u8]) -> Result<Vec<u8>, String> {
+        let mut packet = self.clone();
+        packet.authenticator = match self.code {
+            // these requests use zeros as same as the Request Authenticator; https://tools.ietf.org/html/rfc5176#section-3.3
+            Code::AccountingRequest | Code::DisconnectRequest | Code::CoARequest => vec![0; 16],
+            _ => authenticator.to_vec(),
+        };
+        for avp in packet.attributes.0.iter_mut() {
+            if avp.typ == MESSAGE_AUTHENTICATOR_TYPE {
+                avp.value = vec![0; 16];
+            }
+        }
+        Ok(hmac_md5(&self.secret, &packet.marshal_binary()?))
+    }
+
+    /// Returns whether the `Message-Authenticator` of this decoded packet is authentic or not.
+    ///
+    /// To verify a response, `request_authenticator` has to be the Request Authenticator of the corresponding request;
+    /// to verify a request, that should be `None`. This returns false when the packet doesn't have `Message-Authenticator`.
+    pub fn is_authentic_message_authenticator(&self, request_authenticator: Option<&[u8]>) -> bool {
+        let message_authenticator = match self.lookup(MESSAGE_AUTHENTICATOR_TYPE) {
+            Some(avp) => avp.decode_bytes(),
+            None => return false,
+        };
+        match self
+            .compute_message_authenticator(request_authenticator.unwrap_or(&self.authenticator))
+        {
+            Ok(expected) => expected == message_authenticator,
+            Err(_) => false,
+        }
+    }
+
+    /*
+     * Binary structure:
+     *   0                   1                   2                   3
+     *   0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+     *  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+     *  |     Code      |  Identifier   |            Length             |
+     *  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+     *  |                                                               |
+     *  |                         Authenticator                         |
+     *  |                                                               |
+     *  |                                                               |
+     *  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+     *  |  Attributes ...
+     *  +-+-+-+-+-+-+-+-+-+-+-+-+-
+     */
+    pub(crate) fn marshal_binary(&self) -> Result<Vec<u8>, String> {
+        let encoded_avp = match self.ordering_policy {
+            OrderingPolicy::Preserve => self.attributes.encode()?,
+            ordering_policy => {
+                let mut attributes = self.attributes.clone();
+                // the sort is stable, so the fragments of a value (e.g. `EAP-Message`) keep their order
+                match ordering_policy {
+                    OrderingPolicy::Preserve => {}
+                    OrderingPolicy::Canonical => attributes.0.sort_by_key(|avp| avp.typ),
+                    OrderingPolicy::MessageAuthenticatorFirst => attributes
+                        .0
+                        .sort_by_key(|avp| avp.typ != MESSAGE_AUTHENTICATOR_TYPE),
+                }
+                attributes.encode()?
+            }
+        };
+
+        let size = RADIUS_PACKET_HEADER_LENGTH as u16 + encoded_avp.len() as u16;
+        if size as usize > MAX_PACKET_LENGTH {
+            return Err("packet is too large".to_owned());
+        }
+
+        let mut bs: Vec<u8> = Vec::new();
+        bs.push(self.code as u8);
+        bs.push(self.identifier);
+        bs.extend(u16::to_be_bytes(size).to_vec());
+        bs.extend(self.authenticator.to_vec());
+        bs.extend(encoded_avp);
+        Ok(bs)
+    }
+
+    /// Returns whether the Packet is authentic response or not.
+    pub fn is_authentic_response(response: &[u8], request: &[u8], secret: &[u8]) -> bool {
+        if response.len() < RADIUS_PACKET_HEADER_LENGTH
+            || request.len() < RADIUS_PACKET_HEADER_LENGTH
+            || secret.is_empty()
+        {
+            return false;
+        }
+
+        md5::compute(
+            [
+                &response[..4],
+                &request[4..RADIUS_PACKET_HEADER_LENGTH],
+                &response[RADIUS_PACKET_HEADER_LENGTH..],
+                secret,
+            ]
+            .concat(),
+        )
+        .to_vec()
+        .eq(&response[4..RADIUS_PACKET_HEADER_LENGTH].to_vec())
+    }
+
+    /// Returns whether the Packet is authentic request or not.
+    pub fn is_authentic_request(request: &[u8], secret: &[u8]) -> bool {
+        if request.len() < RADIUS_PACKET_HEADER_LENGTH || secret.is_empty() {
+            return false;
+        }
+
+        match Code::from(request[0]) {
+            Code::AccessRequest | Code::StatusServer => true,
+            Code::AccountingRequest | Code::DisconnectRequest | Code::CoARequest => md5::compute(
+                [
+                    &request[..4],
+                    &[
+                        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                        0x00, 0x00, 0x00, 0x00,
+                    ],
+                    &request[RADIUS_PACKET_HEADER_LENGTH..],
+                    secret,
+                ]
+                .concat(),
+            )
+            .to_vec()
+            .eq(&request[4..RADIUS_PACKET_HEADER_LENGTH].to_vec()),
+            _ => false,
+        }
+    }
+
+    /// Add an AVP to the list of AVPs.
+    pub fn add(&mut self, avp: AVP) {
+        self.attributes.add(avp);
+    }
+
+    /// Add a value that may exceed `MAX_VALUE_LENGTH` as the AVPs according to the policy; see also `JumboAttributePolicy`.
+    pub fn add_with_policy(
+        &mut self,
+        typ: AVPType,
+        value: &[u8],
+        policy: JumboAttributePolicy,
+    ) -> Result<(), AVPError> {
+        self.extend(policy.split(typ, value)?);
+        Ok(())
+    }
+
+    /// Lookup a value that is added by `add_with_policy()` (or the peer); the AVPs are reassembled according to the policy.
+    pub fn lookup_with_policy(
+        &self,
+        typ: AVPType,
+        policy: JumboAttributePolicy,
+    ) -> Option<Result<Vec<u8>, AVPError>> {
+        policy.join(&self.lookup_all(typ))
+    }
+
+    /// Add AVPs to the list of AVPs; this reserves the room for those at once.
+    pub fn extend(&mut self, avps: Vec<AVP>) {
+        self.attributes.extend(avps)
+    }
+
+    /// Reserve the room for at least `additional` more AVPs.
+    pub fn reserve(&mut self, additional: usize) {
+        self.attributes.0.reserve(additional)
+    }
+
+    /// Delete all of AVPs from the list according to given AVP type.
+    pub fn delete(&mut self, typ: AVPType) {
+        self.attributes.del(typ);
+    }
+
+    /// Returns an AVP that matches at first with the given AVP type. If there are not any matched ones, this returns `None`.
+    pub fn lookup(&self, typ: AVPType) -> Option<&AVP> {
+        self.attributes.lookup(typ)
+    }
+
+    /// Returns the value of the AVP that matches at first with the given AVP type as a lowercase hex string
+    /// without the prefix (e.g. `0a0b0c`), for logging the octets values. If there is no matched AVP, this returns `None`.
+    pub fn lookup_hex(&self, typ: AVPType) -> Option<String> {
+        self.lookup(typ).map(|avp| encode_hex(&avp.value))
+    }
+
+    /// Returns AVPs that match with the given AVP type.
+    pub fn lookup_all(&self, typ: AVPType) -> Vec<&AVP> {
+        self.attributes.lookup_all(typ)
+    }
+
+    /// Returns whether the packet has an AVP of the given AVP type.
+    pub fn contains(&self, typ: AVPType) -> bool {
+        self.attributes.lookup(typ).is_some()
+    }
+
+    /// Returns the number of the AVPs of the given AVP type.
+    pub fn count(&self, typ: AVPType) -> usize {
+        self.attributes.count(typ)
+    }
+
+    /// Returns whether the first integer AVP of the given type has all of the given flags (i.e. the bits of the mask).
+    /// If the packet doesn't have the AVP, this returns `Ok(false)`.
+    pub fn contains_flags(&self, typ: AVPType, flags: u32) -> Result<bool, AVPError> {
+        match self.lookup(typ) {
+            Some(avp) => Ok(avp.decode_u32()? & flags == flags),
+            None => Ok(false),
+        }
+    }
+
+    /// Sets the given flags to the first integer AVP of the given type.
+    /// If the packet doesn't have the AVP, this adds the AVP that has only the flags.
+    pub fn insert_flags(&mut self, typ: AVPType, flags: u32) -> Result<(), AVPError> {
+        match self.attributes.0.iter_mut().find(|avp| avp.typ == typ) {
+            Some(avp) => *avp = AVP::from_u32(typ, avp.decode_u32()? | flags),
+            None => self.add(AVP::from_u32(typ, flags)),
+        }
+        Ok(())
+    }
+
+    /// Clears the given flags of the first integer AVP of the given type.
+    /// If the packet doesn't have the AVP, this does nothing.
+    pub fn remove_flags(&mut self, typ: AVPType, flags: u32) -> Result<(), AVPError> {
+        if let Some(avp) = self.attributes.0.iter_mut().find(|avp| avp.typ == typ) {
+            *avp = AVP::from_u32(typ, avp.decode_u32()? & !flags);
+        }
+        Ok(())
+    }
+
+    /// Add a sub-attribute of the vendor as a `Vendor-Specific` AVP; the value is that of an AVP
+    /// which is made by the constructors (e.g. `AVP::from_string()`), so that the sub-attribute is encoded like an attribute.
+    /// If the vendor format has the continuation octet, the value that doesn't fit in an AVP is split into the fragments.
+    ///
+    /// The vendor type is truncated to the width of the type field of the vendor format, and the sub-attribute that doesn't
+    /// fit in an AVP makes the encoding of the packet fail; see `AVP::from_vsa()` to check those on adding.
+    pub fn add_vsa(&mut self, vendor: &Vendor, vendor_type: VendorType, avp: AVP) {
+        for value in vendor.encode(vendor_type, &avp.value) {
+            self.add(AVP {
+                typ: VENDOR_SPECIFIC_TYPE,
+                value,
+            });
+        }
+    }
+
+    /// Returns the first sub-attribute of the vendor and the vendor type as an AVP (i.e. the type of that is `Vendor-Specific`),
+    /// so that the value can be decoded by the decoders of AVP (e.g. `AVP::decode_string()`).
+    /// The malformed `Vendor-Specific` AVPs are skipped.
+    pub fn lookup_vsa(&self, vendor: &Vendor, vendor_type: VendorType) -> Option<AVP> {
+        self.lookup_all_vsa(vendor, vendor_type).into_iter().next()
+    }
+
+    /// Returns all of the sub-attributes of the vendor and the vendor type; see also `lookup_vsa()`.
+    ///
+    /// The fragments of a value (i.e. those that are flagged by the continuation octet) are reassembled,
+    /// and the incomplete one that lacks the last fragment is skipped.
+    pub fn lookup_all_vsa(&self, vendor: &Vendor, vendor_type: VendorType) -> Vec<AVP> {
+        let mut avps = Vec::new();
+        let mut fragments: Option<Vec<u8>> = None;
+        for sub_attribute in self
+            .lookup_all(VENDOR_SPECIFIC_TYPE)
+            .into_iter()
+            .filter_map(|avp| vendor.decode(&avp.value))
+            .flatten()
+            .filter(|sub_attribute| sub_attribute.typ == vendor_type)
+        {
+            let mut value = fragments.take().unwrap_or_default();
+            value.extend_from_slice(sub_attribute.value);
+            match sub_attribute.more {
+                true => fragments = Some(value),
+                false => avps.push(AVP {
+                    typ: VENDOR_SPECIFIC_TYPE,
+                    value,
+                }),
+            }
+        }
+        avps
+    }
+
+    /// Delete all of the sub-attributes of the vendor and the vendor type; a `Vendor-Specific` AVP that has the other
+    /// sub-attributes too is re-encoded without those, and that which becomes empty is deleted.
+    pub fn delete_vsa(&mut self, vendor: &Vendor, vendor_type: VendorType) {
+        let mut attributes = Vec::with_capacity(self.attributes.0.len());
+        for avp in self.attributes.0.drain(..) {
+            let sub_attributes = match avp.typ {
+                VENDOR_SPECIFIC_TYPE => vendor.decode(&avp.value),
+                _ => None,
+            };
+            match sub_attributes {
+                Some(sub_attributes) if sub_attributes.iter().any(|sub| sub.typ == vendor_type) => {
+                    let remaining = sub_attributes
+                        .iter()
+                        .filter(|sub| sub.typ != vendor_type)
+                        .map(|sub| vendor.encode_sub_attribute(sub))
+                        .collect::<Vec<Vec<u8>>>();
+                    if !remaining.is_empty() {
+                        attributes.push(AVP {
+                            typ: VENDOR_SPECIFIC_TYPE,
+                            value: [avp.value[..4].to_vec(), remaining.concat()].concat(),
+                        });
+                    }
+                }
+                _ => attributes.push(avp),
+            }
+        }
+        self.attributes.0 = attributes;
+    }
+
+    /// Returns whether the packet has a sub-attribute of the vendor and the vendor type.
+    pub fn contains_vsa(&self, vendor: &Vendor, vendor_type: VendorType) -> bool {
+        self.count_vsa(vendor, vendor_type) > 0
+    }
+
+    /// Returns the number of the sub-attributes of the vendor and the vendor type.
+    pub fn count_vsa(&self, vendor: &Vendor, vendor_type: VendorType) -> usize {
+        self.lookup_all_vsa(vendor, vendor_type).len()
+    }
+
+    /// Returns a value of the AVP that matches at first with the given AVP type, decoded as `T`.
+    /// If there is no matched AVP, this returns `None`.
+    pub fn get<T: FromAvp>(&self, typ: AVPType) -> Option<Result<T, AVPError>> {
+        self.lookup(typ).map(T::from_avp)
+    }
+
+    /// Returns the values of the AVPs that match with the given AVP type, decoded as `T`.
+    pub fn get_all<T: FromAvp>(&self, typ: AVPType) -> Result<Vec<T>, AVPError> {
+        self.lookup_all(typ).into_iter().map(T::from_avp).collect()
+    }
+
+    /// Decode the attributes of this packet into a struct (e.g. the one that derives `FromPacket`).
+    pub fn to_struct<T: FromPacket>(&self) -> Result<T, AVPError> {
+        T::from_packet(self)
+    }
+
+    /// Make a packet that has the attributes that are encoded from a struct (e.g. the one that derives `ToPacket`).
+    pub fn from_struct<T: ToPacket>(
+        code: Code,
+        secret: &[u8],
+        value: &T,
+    ) -> Result<Self, AVPError> {
+        let mut packet = Packet::new(code, secret);
+        value.to_packet(&mut packet)?;
+        Ok(packet)
+    }
+
+    /// Returns the differences of the attributes between self (as the base) and the given packet.
+    ///
+    /// This is useful to see what a rewriting layer (e.g. a proxy) has changed in a packet.
+    pub fn diff(&self, other: &Packet) -> PacketDiff {
+        PacketDiff::new(&self.attributes.0, &other.attributes.0)
+    }
+}
+
+fn hmac_md5(key: &[u8], message: &[u8]) -> Vec<u8> {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..16].copy_from_slice(&md5::compute(key).0);
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = block.iter().map(|b| b ^ 0x36).collect::<Vec<u8>>();
+    inner.extend(message);
+    let mut outer = block.iter().map(|b| b ^ 0x5c).collect::<Vec<u8>>();
+    outer.extend(md5::compute(&inner).0);
+    md5::compute(&outer).to_vec()
+}
+
+#[cfg(all(test, feature = "rfc2869"))]
+mod tests {
+    use std::net::Ipv4Addr;
+    use std::time::Duration;
+
+    use crate::avp::{AVPError, JumboAttributePolicy, AVP};
+    use crate::code::Code;
+    use crate::packet::{
+        hmac_md5, DuplicatePolicy, OrderingPolicy, OversizeStringPolicy, Packet, PacketError,
+        MAX_PACKET_LENGTH, RADIUS_PACKET_HEADER_LENGTH,
+    };
+    use crate::tag::Tag;
+    use crate::vsa::{Vendor, VendorFormat};
+    use crate::{rfc2865, rfc2866, rfc2867, rfc2868, rfc2869};
+
+    #[test]
+    fn test_for_rfc2865_7_1() -> Result<(), PacketError> {
+        // ref: https://tools.ietf.org/html/rfc2865#section-7.1
+
+        let secret: Vec<u8> = "xyzzy5461".as_bytes().to_vec();
+        let request: Vec<u8> = vec![
+            0x01, 0x00, 0x00, 0x38, 0x0f, 0x40, 0x3f, 0x94, 0x73, 0x97, 0x80, 0x57, 0xbd, 0x83,
+            0xd5, 0xcb, 0x98, 0xf4, 0x22, 0x7a, 0x01, 0x06, 0x6e, 0x65, 0x6d, 0x6f, 0x02, 0x12,
+            0x0d, 0xbe, 0x70, 0x8d, 0x93, 0xd4, 0x13, 0xce, 0x31, 0x96, 0xe4, 0x3f, 0x78, 0x2a,
+            0x0a, 0xee, 0x04, 0x06, 0xc0, 0xa8, 0x01, 0x10, 0x05, 0x06, 0x00, 0x00, 0x00, 0x03,
+        ];
+
+        let request_packet = Packet::decode(&request, &secret)?;
+        assert_eq!(request_packet.code, Code::AccessRequest);
+        assert_eq!(request_packet.identifier, 0);
+        assert_eq!(
+            rfc2865::lookup_user_name(&request_packet).unwrap().unwrap(),
+            "nemo"
+        );
+        assert_eq!(
+            rfc2865::lookup_all_user_name(&request_packet).unwrap(),
+            vec!["nemo"],
+        );
+        assert_eq!(
+            rfc2865::lookup_user_password(&request_packet)
+                .unwrap()
+                .unwrap(),
+            b"arctangent"
+        );
+        assert_eq!(
+            rfc2865::lookup_nas_ip_address(&request_packet)
+                .unwrap()
+                .unwrap(),
+            Ipv4Addr::from([192, 168, 1, 16]),
+        );
+        assert_eq!(
+            rfc2865::lookup_nas_port(&request_packet).unwrap().unwrap(),
+            3
+        );
+        assert_eq!(request_packet.encode().unwrap(), request);
+        assert!(Packet::is_authentic_request(&request, &secret));
+
+        let response: Vec<u8> = vec![
+            0x02, 0x00, 0x00, 0x26, 0x86, 0xfe, 0x22, 0x0e, 0x76, 0x24, 0xba, 0x2a, 0x10, 0x05,
+            0xf6, 0xbf, 0x9b, 0x55, 0xe0, 0xb2, 0x06, 0x06, 0x00, 0x00, 0x00, 0x01, 0x0f, 0x06,
+            0x00, 0x00, 0x00, 0x00, 0x0e, 0x06, 0xc0, 0xa8, 0x01, 0x03,
+        ];
+        let mut response_packet = request_packet.make_response_packet(Code::AccessAccept);
+        rfc2865::add_service_type(&mut response_packet, rfc2865::SERVICE_TYPE_LOGIN_USER);
+        rfc2865::add_login_service(&mut response_packet, rfc2865::LOGIN_SERVICE_TELNET);
+        rfc2865::add_login_ip_host(&mut response_packet, &Ipv4Addr::from([192, 168, 1, 3]));
+        assert_eq!(response_packet.encode().unwrap(), response);
+        assert!(Packet::is_authentic_response(&response, &request, &secret));
+
+        // test removing a AVP
+        assert!(rfc2865::lookup_service_type(&response_packet).is_some());
+        rfc2865::delete_service_type(&mut response_packet);
+        assert!(rfc2865::lookup_service_type(&response_packet).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_for_rfc2865_7_2() -> Result<(), PacketError> {
+        let secret: Vec<u8> = "xyzzy5461".as_bytes().to_vec();
+        let request: Vec<u8> = vec![
+            0x01, 0x01, 0x00, 0x47, 0x2a, 0xee, 0x86, 0xf0, 0x8d, 0x0d, 0x55, 0x96, 0x9c, 0xa5,
+            0x97, 0x8e, 0x0d, 0x33, 0x67, 0xa2, 0x01, 0x08, 0x66, 0x6c, 0x6f, 0x70, 0x73, 0x79,
+            0x03, 0x13, 0x16, 0xe9, 0x75, 0x57, 0xc3, 0x16, 0x18, 0x58, 0x95, 0xf2, 0x93, 0xff,
+            0x63, 0x44, 0x07, 0x72, 0x75, 0x04, 0x06, 0xc0, 0xa8, 0x01, 0x10, 0x05, 0x06, 0x00,
+            0x00, 0x00, 0x14, 0x06, 0x06, 0x00, 0x00, 0x00, 0x02, 0x07, 0x06, 0x00, 0x00, 0x00,
+            0x01,
+        ];
+
+        let request_packet = Packet::decode(&request, &secret)?;
+        assert_eq!(request_packet.get_code(), Code::AccessRequest);
+        assert_eq!(request_packet.identifier, 1);
+        assert_eq!(
+            rfc2865::lookup_user_name(&request_packet).unwrap().unwrap(),
+            "flopsy"
+        );
+        assert_eq!(
+            rfc2865::lookup_nas_ip_address(&request_packet)
+                .unwrap()
+                .unwrap(),
+            Ipv4Addr::from([192, 168, 1, 16]),
+        );
+        assert_eq!(
+            rfc2865::lookup_nas_port(&request_packet).unwrap().unwrap(),
+            20
+        );
+        assert_eq!(
+            rfc2865::lookup_service_type(&request_packet)
+                .unwrap()
+                .unwrap(),
+            rfc2865::SERVICE_TYPE_FRAMED_USER,
+        );
+        assert_eq!(
+            rfc2865::lookup_framed_protocol(&request_packet)
+                .unwrap()
+                .unwrap(),
+            rfc2865::FRAMED_PROTOCOL_PPP,
+        );
+
+        let response: Vec<u8> = vec![
+            0x02, 0x01, 0x00, 0x38, 0x15, 0xef, 0xbc, 0x7d, 0xab, 0x26, 0xcf, 0xa3, 0xdc, 0x34,
+            0xd9, 0xc0, 0x3c, 0x86, 0x01, 0xa4, 0x06, 0x06, 0x00, 0x00, 0x00, 0x02, 0x07, 0x06,
+            0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0xff, 0xff, 0xff, 0xfe, 0x0a, 0x06, 0x00, 0x00,
+            0x00, 0x00, 0x0d, 0x06, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x06, 0x00, 0x00, 0x05,
+            //    ^ incorrectly a 2 in the document
+            0xdc,
+        ];
+        let response_packet = Packet::decode(&response, &secret).unwrap();
+
+        assert_eq!(response_packet.get_code(), Code::AccessAccept);
+        assert_eq!(response_packet.get_identifier(), 1);
+        assert_eq!(
+            rfc2865::lookup_service_type(&response_packet)
+                .unwrap()
+                .unwrap(),
+            rfc2865::SERVICE_TYPE_FRAMED_USER
+        );
+        assert_eq!(
+            rfc2865::lookup_framed_protocol(&response_packet)
+                .unwrap()
+                .unwrap(),
+            rfc2865::FRAMED_PROTOCOL_PPP,
+        );
+        assert_eq!(
+            rfc2865::lookup_framed_ip_address(&response_packet)
+                .unwrap()
+                .unwrap(),
+            Ipv4Addr::from([255, 255, 255, 254]),
+        );
+        assert_eq!(
+            rfc2865::lookup_framed_routing(&response_packet)
+                .unwrap()
+                .unwrap(),
+            rfc2865::FRAMED_ROUTING_NONE,
+        );
+        assert_eq!(
+            rfc2865::lookup_framed_compression(&response_packet)
+                .unwrap()
+                .unwrap(),
+            rfc2865::FRAMED_COMPRESSION_VAN_JACOBSON_TCP_IP,
+        );
+        assert_eq!(
+            rfc2865::lookup_framed_mtu(&response_packet)
+                .unwrap()
+                .unwrap(),
+            1500,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_passwords() {
+        let passwords = vec![
+            b"".to_vec(),
+            b"qwerty".to_vec(),
+            b"helloworld1231231231231233489hegufudhsgdsfygdf8g".to_vec(),
+        ];
+
+        let secret = b"xyzzy5461";
+
+        for password in passwords {
+            let mut request_packet = Packet::new(Code::AccessRequest, secret);
+            rfc2865::add_user_password(&mut request_packet, &password).unwrap();
+
+            let encoded = request_packet.encode().unwrap();
+
+            let decoded = Packet::decode(&encoded, secret).unwrap();
+            assert_eq!(
+                rfc2865::lookup_user_password(&decoded).unwrap().unwrap(),
+                password
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        struct TestCase<'a> {
+            plain_text: &'a str,
+            expected_error: PacketError,
+        }
+
+        let test_cases = &[
+            TestCase {
+                plain_text: "\x01",
+                expected_error: PacketError::InsufficientPacketPayloadLengthError(RADIUS_PACKET_HEADER_LENGTH, 1),
+            },
+            TestCase {
+                plain_text: "\x01\x7f\x00\x00\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01",
+                expected_error: PacketError::InsufficientHeaderDefinedPacketLengthError(0, RADIUS_PACKET_HEADER_LENGTH),
+            },
+            TestCase {
+                plain_text: "\x01\x7f\x7f\x7f\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01",
+                expected_error: PacketError::HeaderDefinedPacketLengthExceedsMaximumLimitError(32639, MAX_PACKET_LENGTH),
+            },
+            TestCase {
+                plain_text: "\x00\x7f\x00\x16\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x00",
+                expected_error: PacketError::InsufficientPacketPayloadLengthError(22, 21),
+            },
+            TestCase {
+                plain_text: "\x01\x01\x00\x16\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x00",
+                expected_error: PacketError::DecodingError("invalid attribute length".to_owned()),
+            }
+        ];
+
+        let secret = b"12345";
+        for test_case in test_cases {
+            let result = Packet::decode(test_case.plain_text.as_bytes(), secret);
+            assert!(result.is_err());
+            assert_eq!(result.err().unwrap(), test_case.expected_error);
+        }
+    }
+
+    #[test]
+    fn test_packet_attribute_length_boundary() {
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        packet.add(AVP {
+            typ: 1,
+            value: vec![1; 253],
+        });
+        let encoded = packet.encode();
+        assert!(encoded.is_ok());
+
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        packet.add(AVP {
+            typ: 1,
+            value: vec![1; 254],
+        });
+        let encoded = packet.encode();
+        assert!(encoded.is_err());
+        assert_eq!(
+            encoded.err().unwrap(),
+            PacketError::EncodingError("attribute is too large".to_owned()),
+        );
+    }
+
+    #[test]
+    fn test_flags() -> Result<(), AVPError> {
+        const FLAGS_TYPE: u8 = 250;
+        let mut packet = Packet::new(Code::AccessAccept, b"12345");
+        assert!(!packet.contains_flags(FLAGS_TYPE, 0b001)?);
+
+        packet.insert_flags(FLAGS_TYPE, 0b001)?;
+        packet.insert_flags(FLAGS_TYPE, 0b100)?;
+        assert_eq!(packet.count(FLAGS_TYPE), 1);
+        assert_eq!(packet.lookup(FLAGS_TYPE).unwrap().decode_u32()?, 0b101);
+        assert!(packet.contains_flags(FLAGS_TYPE, 0b101)?);
+        assert!(!packet.contains_flags(FLAGS_TYPE, 0b011)?);
+
+        packet.remove_flags(FLAGS_TYPE, 0b001)?;
+        assert_eq!(packet.lookup(FLAGS_TYPE).unwrap().decode_u32()?, 0b100);
+
+        packet.delete(FLAGS_TYPE);
+        packet.add(AVP::from_u16(FLAGS_TYPE, 1));
+        assert!(packet.contains_flags(FLAGS_TYPE, 0b001).is_err());
+        assert!(packet.insert_flags(FLAGS_TYPE, 0b001).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_vsa() -> Result<(), AVPError> {
+        const ERX: Vendor = Vendor::new(4874, VendorFormat::DEFAULT);
+        const USR: Vendor = Vendor::new(429, VendorFormat::new(4, 0));
+        let mut packet = Packet::new(Code::AccessAccept, b"12345");
+        packet.add_vsa(&ERX, 1, AVP::from_string(0, "vr1"));
+        packet.add_vsa(
+            &ERX,
+            8,
+            AVP::from_tagged_string(0, Some(&Tag::new(1)), "vr2"),
+        );
+        packet.add_vsa(&USR, 0x9000, AVP::from_u32(0, 42));
+        // the other sub-attribute of ERX in the same VSA
+        packet.add(AVP::from_bytes(
+            rfc2865::VENDOR_SPECIFIC_TYPE,
+            &[
+                0x00, 0x00, 0x13, 0x0a, 0x01, 0x05, b'v', b'r', b'3', 0x0b, 0x03, b'p',
+            ],
+        )?);
+
+        assert_eq!(
+            packet
+                .lookup(rfc2865::VENDOR_SPECIFIC_TYPE)
+                .unwrap()
+                .raw_value(),
+            &[0x00, 0x00, 0x13, 0x0a, 0x01, 0x05, b'v', b'r', b'1']
+        );
+        assert_eq!(packet.lookup_vsa(&ERX, 1).unwrap().decode_string()?, "vr1");
+        assert_eq!(packet.count_vsa(&ERX, 1), 2);
+        assert_eq!(
+            packet.lookup_vsa(&ERX, 8).unwrap().decode_tagged_string()?,
+            ("vr2".to_owned(), Some(Tag::new(1)))
+        );
+        assert_eq!(packet.lookup_vsa(&USR, 0x9000).unwrap().decode_u32()?, 42);
+        assert!(!packet.contains_vsa(&USR, 1));
+        assert!(!packet.contains_vsa(&ERX, 0x9000));
+
+        packet.delete_vsa(&ERX, 1);
+        assert!(!packet.contains_vsa(&ERX, 1));
+        assert_eq!(packet.lookup_vsa(&ERX, 11).unwrap().raw_value(), b"p");
+        assert_eq!(packet.count(rfc2865::VENDOR_SPECIFIC_TYPE), 3);
+
+        assert_eq!(
+            AVP::from_vsa(&ERX, 256, b"abc"),
+            Err(AVPError::InvalidVendorTypeError(256))
+        );
+        assert!(packet.encode().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_vsa_fragments() -> Result<(), AVPError> {
+        const WIMAX: Vendor = Vendor::new(24757, VendorFormat::new_with_continuation(1, 1));
+        let value = "a".repeat(300);
+        let mut packet = Packet::new(Code::AccessAccept, b"12345");
+        packet.add_vsa(&WIMAX, 1, AVP::from_string(0, &value));
+        packet.add_vsa(&WIMAX, 2, AVP::from_string(0, "short"));
+        assert_eq!(packet.count(rfc2865::VENDOR_SPECIFIC_TYPE), 3);
+        assert_eq!(packet.count_vsa(&WIMAX, 1), 1);
+        assert_eq!(
+            packet.lookup_vsa(&WIMAX, 1).unwrap().decode_string()?,
+            value
+        );
+        assert_eq!(
+            packet.lookup_vsa(&WIMAX, 2).unwrap().decode_string()?,
+            "short"
+        );
+
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"12345").unwrap();
+        assert_eq!(
+            decoded.lookup_vsa(&WIMAX, 1).unwrap().decode_string()?,
+            value
+        );
+
+        // the incomplete value that lacks the last fragment is skipped
+        packet.attributes.0.remove(1);
+        assert!(!packet.contains_vsa(&WIMAX, 1));
+
+        packet.delete_vsa(&WIMAX, 1);
+        assert_eq!(packet.count(rfc2865::VENDOR_SPECIFIC_TYPE), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_with_policy() -> Result<(), AVPError> {
+        let value = vec![1; 300];
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        assert!(packet
+            .add_with_policy(25, &value, JumboAttributePolicy::Reject)
+            .is_err());
+        assert_eq!(
+            packet.lookup_with_policy(25, JumboAttributePolicy::Reject),
+            None
+        );
+
+        packet.add_with_policy(25, &value, JumboAttributePolicy::Concat)?;
+        assert_eq!(packet.count(25), 2);
+        assert_eq!(
+            packet
+                .lookup_with_policy(25, JumboAttributePolicy::Concat)
+                .unwrap()?,
+            value
+        );
+
+        // the fragments go through the encoding and the decoding as is
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"12345").unwrap();
+        assert_eq!(
+            decoded
+                .lookup_with_policy(25, JumboAttributePolicy::Concat)
+                .unwrap()?,
+            value
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_length_in_network_byte_order() -> Result<(), PacketError> {
+        // the length is over 255 bytes, so both of the bytes of the Length field are significant
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        packet.add(AVP::from_bytes(rfc2865::CLASS_TYPE, &[1; 253]).unwrap());
+        packet.add(AVP::from_bytes(rfc2865::CLASS_TYPE, &[1; 25]).unwrap());
+        let encoded = packet.encode()?;
+        assert_eq!(encoded.len(), 0x012e);
+        assert_eq!(encoded[2..4], [0x01, 0x2e]);
+        assert_eq!(
+            Packet::decode(&encoded, b"12345")?.count(rfc2865::CLASS_TYPE),
+            2
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_presence_and_count() {
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        rfc2865::add_class(&mut packet, b"class-1").unwrap();
+        rfc2865::add_class(&mut packet, b"class-2").unwrap();
+        rfc2865::add_user_name(&mut packet, "alice");
+
+        assert!(packet.contains(rfc2865::CLASS_TYPE));
+        assert!(!packet.contains(rfc2865::REPLY_MESSAGE_TYPE));
+        assert_eq!(packet.count(rfc2865::CLASS_TYPE), 2);
+        assert_eq!(packet.count(rfc2865::REPLY_MESSAGE_TYPE), 0);
+
+        assert!(rfc2865::has_user_name(&packet));
+        assert!(!rfc2865::has_reply_message(&packet));
+        assert_eq!(rfc2865::count_class(&packet), 2);
+        assert_eq!(rfc2865::count_user_name(&packet), 1);
+    }
+
+    #[test]
+    fn test_lookup_hex() {
+        let mut packet = Packet::new(Code::AccessAccept, b"12345");
+        assert_eq!(packet.lookup_hex(rfc2865::CLASS_TYPE), None);
+        rfc2865::add_class(&mut packet, [0x0a, 0x0b, 0x0c]).unwrap();
+        rfc2865::add_class(&mut packet, [0xff]).unwrap();
+        assert_eq!(
+            packet.lookup_hex(rfc2865::CLASS_TYPE),
+            Some("0a0b0c".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_lookup_error_has_attribute_name() {
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        packet.add(AVP {
+            typ: rfc2865::FRAMED_IP_ADDRESS_TYPE,
+            value: vec![192, 0, 2],
+        });
+
+        let err = rfc2865::lookup_framed_ip_address(&packet)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            AVPError::AttributeDecodingError {
+                attribute: "Framed-IP-Address",
+                source: Box::new(AVPError::InvalidAttributeLengthError(
+                    "4 bytes".to_owned(),
+                    3
+                )),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "failed to decode `Framed-IP-Address` attribute: invalid attribute length: expected=4 bytes, actual=3 bytes"
+        );
+        assert_eq!(
+            rfc2865::lookup_all_framed_ip_address(&packet).unwrap_err(),
+            err
+        );
+    }
+
+    #[test]
+    fn test_decode_with_duplicate_policy() {
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2865::add_reply_message(&mut packet, "first");
+        rfc2865::add_user_name(&mut packet, "bob");
+        rfc2865::add_reply_message(&mut packet, "second");
+        let encoded = packet.encode().unwrap();
+
+        let decoded =
+            Packet::decode_with_duplicate_policy(&encoded, b"12345", DuplicatePolicy::Allow)
+                .unwrap();
+        assert_eq!(
+            rfc2865::lookup_all_user_name(&decoded).unwrap(),
+            vec!["alice", "bob"]
+        );
+
+        let decoded =
+            Packet::decode_with_duplicate_policy(&encoded, b"12345", DuplicatePolicy::FirstWins)
+                .unwrap();
+        assert_eq!(
+            rfc2865::lookup_all_user_name(&decoded).unwrap(),
+            vec!["alice"]
+        );
+        assert_eq!(
+            rfc2865::lookup_all_reply_message(&decoded).unwrap(),
+            vec!["first", "second"]
+        );
+
+        let err = Packet::decode_with_duplicate_policy(&encoded, b"12345", DuplicatePolicy::Reject)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PacketError::DuplicateAttributeError(rfc2865::USER_NAME_TYPE, 1, 2)
+        );
+    }
+
+    #[test]
+    fn test_round_trip_preserves_attributes() {
+        let attributes: Vec<u8> = [
+            vec![0x01, 0x07, b'a', b'l', b'i', b'c', b'e'], // User-Name
+            vec![0xfa, 0x04, 0xde, 0xad],                   // unknown type
+            vec![0x19, 0x03, 0x01],                         // Class
+            vec![0x1a, 0x05, 0x00, 0x00, 0x09],             // truncated Vendor-Specific
+            vec![0xfa, 0x02],                               // unknown type without value
+            vec![0x19, 0x03, 0x02],                         // duplicated Class
+            vec![0x04, 0x03, 0xc0],                         // NAS-IP-Address that cannot be decoded
+        ]
+        .concat();
+        let mut request = vec![0x01, 0x10, 0x00, 20 + attributes.len() as u8];
+        request.extend([0x5a; 16]);
+        request.extend(&attributes);
+
+        let decoded = Packet::decode(&request, b"secret").unwrap();
+        assert!(rfc2865::lookup_nas_ip_address(&decoded).unwrap().is_err());
+        assert_eq!(decoded.encode().unwrap(), request);
+
+        // a proxy appends an attribute, and the others are kept as they are
+        let mut proxied = decoded.clone();
+        proxied.add(AVP::from_bytes(33, b"state").unwrap());
+        let encoded = proxied.encode().unwrap();
+        assert_eq!(encoded[3] as usize, request.len() + 7);
+        assert_eq!(&encoded[4..request.len()], &request[4..]);
+        assert_eq!(
+            &encoded[request.len()..],
+            &[33, 7, b's', b't', b'a', b't', b'e']
+        );
+
+        // the Response Authenticator is recomputed, but the attributes are not changed
+        let mut response = request.clone();
+        response[0] = Code::AccessAccept as u8;
+        let encoded = Packet::decode(&response, b"secret")
+            .unwrap()
+            .encode()
+            .unwrap();
+        assert_eq!(&encoded[..4], &response[..4]);
+        assert_eq!(
+            &encoded[RADIUS_PACKET_HEADER_LENGTH..],
+            &response[RADIUS_PACKET_HEADER_LENGTH..]
+        );
+    }
+
+    #[test]
+    fn test_with_arbitrary_identifier() {
+        let mut packet = Packet::new(Code::AccessRequest, b"12345");
+        let random_ident = packet.get_identifier();
+        let expected_ident = random_ident + 1;
+        packet.set_identifier(expected_ident);
+        assert_eq!(packet.get_identifier(), expected_ident);
+
+        packet = Packet::new_with_identifier(Code::AccessRequest, b"12345", expected_ident);
+        assert_eq!(packet.get_identifier(), expected_ident);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut request = Packet::new(Code::AccessRequest, b"12345");
+        for i in 0..60 {
+            rfc2865::add_class(&mut request, [i]).unwrap();
+        }
+        let decoded = Packet::decode(&request.encode().unwrap(), b"12345").unwrap();
+        assert_eq!(decoded.attributes.0.capacity(), 60);
+
+        // a proxy copies the attributes without reallocation
+        let mut proxied = Packet::with_capacity(Code::AccessRequest, b"67890", 61);
+        let capacity = proxied.attributes.0.capacity();
+        assert!(capacity >= 61);
+        proxied.extend(decoded.get_avps().to_vec());
+        rfc2865::add_nas_identifier(&mut proxied, "proxy");
+        assert_eq!(proxied.attributes.0.capacity(), capacity);
+        assert_eq!(proxied.get_avps().len(), 61);
+
+        proxied.reserve(100);
+        assert!(proxied.attributes.0.capacity() >= 161);
+    }
+
+    #[test]
+    fn test_set_authenticator() {
+        let mut request = Packet::new(Code::AccessRequest, b"12345");
+        request.set_authenticator([0x11; 16]);
+        assert_eq!(request.get_authenticator(), &vec![0x11; 16]);
+        let encoded = request.encode().unwrap();
+        assert_eq!(&encoded[4..RADIUS_PACKET_HEADER_LENGTH], &[0x11; 16]);
+
+        // the Response Authenticator is computed from the Request Authenticator
+        let response = request.make_response_packet(Code::AccessAccept);
+        assert_eq!(response.get_authenticator(), &vec![0x11; 16]);
+        assert!(Packet::is_authentic_response(
+            &response.encode().unwrap(),
+            &encoded,
+            b"12345"
+        ));
+
+        // the authenticator of Accounting-Request is computed on encoding
+        let mut request = Packet::new(Code::AccountingRequest, b"12345");
+        request.set_authenticator([0x11; 16]);
+        let encoded = request.encode().unwrap();
+        assert_ne!(&encoded[4..RADIUS_PACKET_HEADER_LENGTH], &[0x11; 16]);
+        assert!(Packet::is_authentic_request(&encoded, b"12345"));
+    }
+
+    #[test]
+    fn test_ordering_policy() {
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_state(&mut packet, b"state").unwrap();
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2869::add_message_authenticator(&mut packet, [0; 16]).unwrap();
+        rfc2865::add_class(&mut packet, b"second").unwrap();
+        rfc2865::add_user_name(&mut packet, "bob");
+        let encoded_types = |packet: &Packet| {
+            let decoded = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+            assert!(decoded.is_authentic_message_authenticator(None));
+            decoded
+                .get_avps()
+                .iter()
+                .map(|avp| avp.type_code())
+                .collect::<Vec<u8>>()
+        };
+
+        assert_eq!(packet.get_ordering_policy(), OrderingPolicy::Preserve);
+        assert_eq!(encoded_types(&packet), vec![24, 1, 80, 25, 1]);
+
+        packet.set_ordering_policy(OrderingPolicy::Canonical);
+        assert_eq!(encoded_types(&packet), vec![1, 1, 24, 25, 80]);
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        assert_eq!(
+            rfc2865::lookup_all_user_name(&decoded).unwrap(),
+            vec!["alice", "bob"]
+        );
+
+        packet.set_ordering_policy(OrderingPolicy::MessageAuthenticatorFirst);
+        assert_eq!(encoded_types(&packet), vec![80, 24, 1, 25, 1]);
+
+        // the attributes of the packet itself are not reordered
+        assert_eq!(
+            rfc2865::lookup_user_name(&packet).unwrap().unwrap(),
+            "alice"
+        );
+        assert_eq!(packet.get_avps()[0].type_code(), 24);
+    }
+
+    #[test]
+    fn test_add_as_ref() -> Result<(), AVPError> {
+        let user_name = String::from("alice");
+        let class = vec![1, 2, 3];
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut packet, &user_name);
+        rfc2865::add_user_name(&mut packet, user_name);
+        rfc2865::add_class(&mut packet, &class)?;
+        rfc2865::add_class(&mut packet, class)?;
+        rfc2865::add_state(&mut packet, b"state")?;
+        rfc2865::add_user_password(&mut packet, "password")?;
+
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        assert_eq!(
+            rfc2865::lookup_all_user_name(&decoded)?,
+            vec!["alice", "alice"]
+        );
+        assert_eq!(
+            rfc2865::lookup_all_class(&decoded),
+            vec![vec![1, 2, 3], vec![1, 2, 3]]
+        );
+        assert_eq!(
+            rfc2865::lookup_user_password(&decoded).unwrap()?,
+            b"password"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_enums() -> Result<(), AVPError> {
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_status_type(&mut packet, rfc2866::AcctStatusType::Start);
+        rfc2866::add_acct_status_type(&mut packet, rfc2866::ACCT_STATUS_TYPE_INTERIM_UPDATE);
+        rfc2866::add_acct_status_type(&mut packet, rfc2867::ACCT_STATUS_TYPE_TUNNEL_START);
+        rfc2866::add_acct_status_type(&mut packet, rfc2866::AcctStatusType::from(100));
+        rfc2868::add_tunnel_type(&mut packet, Some(&Tag::new(1)), rfc2868::TunnelType::L2Tp);
+
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        let status_types = rfc2866::lookup_all_acct_status_type(&decoded)?;
+        assert_eq!(
+            status_types,
+            vec![
+                rfc2866::AcctStatusType::Start,
+                rfc2866::AcctStatusType::Alive,
+                rfc2866::AcctStatusType::Unknown(9),
+                rfc2866::AcctStatusType::Unknown(100),
+            ]
+        );
+        assert_eq!(status_types[2], rfc2867::ACCT_STATUS_TYPE_TUNNEL_START);
+        assert_eq!(
+            status_types
+                .iter()
+                .map(|t| u32::from(*t))
+                .collect::<Vec<u32>>(),
+            vec![1, 3, 9, 100]
+        );
+        assert_eq!(
+            rfc2868::lookup_tunnel_type(&decoded).unwrap()?,
+            (rfc2868::TunnelType::L2Tp, Tag::new(1))
+        );
+
+        // the aliases of a value are the same variant
+        assert_eq!(
+            rfc2866::AcctStatusType::try_from("Interim-Update")?,
+            rfc2866::AcctStatusType::Alive
+        );
+        assert_eq!(
+            rfc2866::AcctStatusType::try_from("Alive")?,
+            rfc2866::AcctStatusType::Alive
+        );
+        assert!(rfc2866::AcctStatusType::try_from("No-Such-Value").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_duration_attributes() -> Result<(), AVPError> {
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        rfc2865::add_session_timeout_duration(&mut packet, Duration::from_secs(3600))?;
+        rfc2865::add_idle_timeout_duration(&mut packet, Duration::from_secs(600))?;
+        rfc2869::add_acct_interim_interval_duration(&mut packet, Duration::from_secs(300))?;
+        assert!(
+            rfc2865::add_idle_timeout_duration(&mut packet, Duration::from_secs(1 << 32)).is_err()
+        );
+
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        assert_eq!(rfc2865::lookup_session_timeout(&decoded).unwrap()?, 3600);
+        assert_eq!(
+            rfc2865::lookup_session_timeout_duration(&decoded).unwrap()?,
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            rfc2865::lookup_idle_timeout_duration(&decoded).unwrap()?,
+            Duration::from_secs(600)
+        );
+        assert_eq!(
+            rfc2869::lookup_acct_interim_interval_duration(&decoded).unwrap()?,
+            Duration::from_secs(300)
+        );
+        assert!(rfc2865::lookup_termination_action(&decoded).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversize_string_policy() {
+        // "あ" is 3 bytes, so that 100 characters don't fit in an attribute
+        let user_name = "あ".repeat(100);
+
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        assert_eq!(
+            packet.get_oversize_string_policy(),
+            OversizeStringPolicy::Keep
+        );
+        rfc2865::add_user_name(&mut packet, &user_name);
+        assert!(packet.encode().is_err());
+
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        packet.set_oversize_string_policy(OversizeStringPolicy::Truncate);
+        rfc2865::add_user_name(&mut packet, &user_name);
+        rfc2865::add_called_station_id(&mut packet, "00-00-5E-00-53-00:guest");
+        let tag = Tag::new(1);
+        rfc2868::add_tunnel_private_group_id(&mut packet, Some(&tag), "v".repeat(300));
+
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        let truncated = rfc2865::lookup_user_name(&decoded).unwrap().unwrap();
+        assert_eq!(truncated, "あ".repeat(84));
+        assert!(user_name.starts_with(&truncated));
+        assert_eq!(
+            rfc2865::lookup_called_station_id(&decoded)
+                .unwrap()
+                .unwrap(),
+            "00-00-5E-00-53-00:guest"
+        );
+        let (group_id, _) = rfc2868::lookup_tunnel_private_group_id(&decoded)
+            .unwrap()
+            .unwrap();
+        assert_eq!(group_id, "v".repeat(252));
+    }
+
+    #[test]
+    fn test_message_authenticator() {
+        // ref: https://tools.ietf.org/html/rfc2104 (test case 1 of RFC 2202)
+        assert_eq!(
+            hmac_md5(&[0x0b; 16], b"Hi There"),
+            vec![
+                0x92, 0x94, 0x72, 0x7a, 0x36, 0x38, 0xbb, 0x1c, 0x13, 0xf4, 0x8e, 0xf8, 0x15, 0x8b,
+                0xfc, 0x9d
+            ]
+        );
+
+        let mut request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut request, "alice");
+        rfc2869::add_message_authenticator(&mut request, [0; 16]).unwrap();
+        let encoded_request = request.encode().unwrap();
+        let decoded_request = Packet::decode(&encoded_request, b"secret").unwrap();
+        assert!(decoded_request.is_authentic_message_authenticator(None));
+        assert!(!Packet::decode(&encoded_request, b"wrong")
+            .unwrap()
+            .is_authentic_message_authenticator(None));
+
+        let mut response = decoded_request.make_response_packet(Code::AccessAccept);
+        rfc2869::add_message_authenticator(&mut response, [0; 16]).unwrap();
+        let encoded_response = response.encode().unwrap();
+        assert!(Packet::is_authentic_response(
+            &encoded_response,
+            &encoded_request,
+            b"secret"
+        ));
+        let decoded_response = Packet::decode(&encoded_response, b"secret").unwrap();
+        assert!(
+            decoded_response.is_authentic_message_authenticator(Some(request.get_authenticator()))
+        );
+        assert!(!decoded_response.is_authentic_message_authenticator(None));
+
+        let mut accounting_request = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2869::add_message_authenticator(&mut accounting_request, [0; 16]).unwrap();
+        let decoded = Packet::decode(&accounting_request.encode().unwrap(), b"secret").unwrap();
+        assert!(decoded.is_authentic_message_authenticator(None));
+
+        assert!(
+            !Packet::new(Code::AccessRequest, b"secret").is_authentic_message_authenticator(None)
+        );
+    }
+}
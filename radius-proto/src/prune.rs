@@ -0,0 +1,461 @@
+//! Diagnostics and pruning of the packets that exceed the maximum packet length.
+//!
+//! `Packet::encode()` fails with `PacketError::PacketTooLargeError` that has an `OversizeReport`,
+//! i.e. the size of each attribute and the attributes that would need to be dropped.
+//! `Packet::encode_with_pruning()` drops the attributes by a `PruningStrategy` until the packet fits instead.
+//! `Packet::remaining_capacity()` tells the room of a packet in advance, e.g. to size the EAP fragments.
+
+use std::fmt;
+
+use crate::avp::{AVPType, AVP};
+use crate::packet::{
+    Packet, PacketError, MAX_PACKET_LENGTH, MESSAGE_AUTHENTICATOR_TYPE, RADIUS_PACKET_HEADER_LENGTH,
+};
+use crate::registry;
+use crate::rfc2865;
+
+const AVP_HEADER_LENGTH: usize = 2;
+const MAX_AVP_VALUE_LENGTH: usize = 253;
+const MESSAGE_AUTHENTICATOR_LENGTH: usize = AVP_HEADER_LENGTH + 16;
+
+/// This struct represents the encoded size of an attribute in a packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeSize {
+    index: usize,
+    typ: AVPType,
+    size: usize,
+}
+
+impl AttributeSize {
+    /// Returns the position of the attribute in the packet.
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get_type(&self) -> AVPType {
+        self.typ
+    }
+
+    /// Returns the encoded size of the attribute including the type and the length octets.
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the name of the attribute if it is known.
+    pub fn get_name(&self) -> Option<&'static str> {
+        registry::attribute_name(self.typ)
+    }
+}
+
+impl fmt::Display for AttributeSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get_name() {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "Attr-{}", self.typ),
+        }?;
+        write!(f, "[#{}] ({} bytes)", self.index, self.size)
+    }
+}
+
+/// This struct represents the report of a packet that exceeds the maximum packet length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OversizeReport {
+    size: usize,
+    max_size: usize,
+    attributes: Vec<AttributeSize>,
+    suggested_drops: Vec<AttributeSize>,
+}
+
+impl OversizeReport {
+    /// Returns the report of the packet if that exceeds the maximum packet length, otherwise `None`.
+    pub fn of(packet: &Packet) -> Option<Self> {
+        let attributes = packet
+            .get_avps()
+            .iter()
+            .enumerate()
+            .map(|(index, avp)| AttributeSize {
+                index,
+                typ: avp.typ,
+                size: AVP_HEADER_LENGTH + avp.value.len(),
+            })
+            .collect::<Vec<AttributeSize>>();
+        let size = RADIUS_PACKET_HEADER_LENGTH
+            + attributes
+                .iter()
+                .map(AttributeSize::get_size)
+                .sum::<usize>();
+        if size <= MAX_PACKET_LENGTH {
+            return None;
+        }
+
+        // the fewest attributes to drop, i.e. the largest ones first
+        let mut by_size = attributes.clone();
+        by_size.sort_by(|a, b| b.size.cmp(&a.size).then(a.index.cmp(&b.index)));
+        let mut suggested_drops = Vec::new();
+        let mut dropped_size = 0;
+        for attribute in by_size {
+            if size - dropped_size <= MAX_PACKET_LENGTH {
+                break;
+            }
+            dropped_size += attribute.size;
+            suggested_drops.push(attribute);
+        }
+
+        Some(OversizeReport {
+            size,
+            max_size: MAX_PACKET_LENGTH,
+            attributes,
+            suggested_drops,
+        })
+    }
+
+    /// Returns the encoded size of the packet.
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get_max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns the number of the bytes that exceed the maximum packet length.
+    pub fn get_excess(&self) -> usize {
+        self.size - self.max_size
+    }
+
+    /// Returns the sizes of all of the attributes in order of the packet.
+    pub fn get_attributes(&self) -> &[AttributeSize] {
+        &self.attributes
+    }
+
+    /// Returns the fewest attributes that would need to be dropped to fit the packet, i.e. the largest ones.
+    pub fn get_suggested_drops(&self) -> &[AttributeSize] {
+        &self.suggested_drops
+    }
+}
+
+impl fmt::Display for OversizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} bytes exceeds the maximum length {} bytes by {} bytes; dropping ",
+            self.size,
+            self.max_size,
+            self.get_excess()
+        )?;
+        for (i, attribute) in self.suggested_drops.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{attribute}")?;
+        }
+        f.write_str(" would fit")
+    }
+}
+
+/// PruningStrategy drops the attributes of a packet that exceeds the maximum packet length.
+pub trait PruningStrategy {
+    /// Drop (or rearrange) the attributes of the packet to reduce its size by `excess` bytes at least,
+    /// and returns the dropped attributes.
+    ///
+    /// This is called repeatedly while the packet is oversized; returning no attribute means giving up.
+    fn prune(&self, packet: &mut Packet, excess: usize) -> Vec<AVP>;
+}
+
+/// The strategy that drops the attributes of the given types in order, from the last occurrence of each type.
+///
+/// By default, this drops only `Reply-Message`s, that are informational.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropByPriority {
+    types: Vec<AVPType>,
+}
+
+impl DropByPriority {
+    /// Make a strategy that drops the attributes of the types; the earlier type is dropped first.
+    pub fn new(types: Vec<AVPType>) -> Self {
+        DropByPriority { types }
+    }
+}
+
+impl Default for DropByPriority {
+    fn default() -> Self {
+        DropByPriority::new(vec![rfc2865::REPLY_MESSAGE_TYPE])
+    }
+}
+
+impl PruningStrategy for DropByPriority {
+    fn prune(&self, packet: &mut Packet, excess: usize) -> Vec<AVP> {
+        let mut dropped = Vec::new();
+        let mut dropped_size = 0;
+        for typ in &self.types {
+            while dropped_size < excess {
+                let Some(index) = packet.get_avps().iter().rposition(|avp| avp.typ == *typ) else {
+                    break;
+                };
+                let avp = packet.remove_avp(index);
+                dropped_size += AVP_HEADER_LENGTH + avp.value.len();
+                dropped.push(avp);
+            }
+        }
+        dropped
+    }
+}
+
+/// This struct represents the result of `Packet::encode_with_pruning()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PruningReport {
+    encoded: Vec<u8>,
+    dropped: Vec<AVP>,
+    original_size: usize,
+}
+
+impl PruningReport {
+    /// Returns the encoded packet.
+    pub fn get_encoded(&self) -> &[u8] {
+        &self.encoded
+    }
+
+    /// Returns the dropped attributes; this is empty if the packet fits as it is.
+    pub fn get_dropped(&self) -> &[AVP] {
+        &self.dropped
+    }
+
+    /// Returns the encoded size of the packet before pruning.
+    pub fn get_original_size(&self) -> usize {
+        self.original_size
+    }
+}
+
+impl Packet {
+    /// Returns the report if this packet exceeds the maximum packet length, otherwise `None`.
+    pub fn oversize_report(&self) -> Option<OversizeReport> {
+        OversizeReport::of(self)
+    }
+
+    /// Encode the packet into bytes, dropping the attributes by the strategy if the packet exceeds the maximum length.
+    ///
+    /// If the strategy cannot make the packet fit, this fails with `PacketError::PacketTooLargeError`
+    /// that reports the pruned packet.
+    pub fn encode_with_pruning(
+        &self,
+        strategy: &dyn PruningStrategy,
+    ) -> Result<PruningReport, PacketError> {
+        let Some(report) = self.oversize_report() else {
+            return Ok(PruningReport {
+                encoded: self.encode()?,
+                dropped: vec![],
+                original_size: self.encoded_len(),
+            });
+        };
+
+        let mut packet = self.clone();
+        let mut dropped = Vec::new();
+        while let Some(report) = packet.oversize_report() {
+            let pruned = strategy.prune(&mut packet, report.get_excess());
+            if pruned.is_empty() {
+                return Err(PacketError::PacketTooLargeError(report));
+            }
+            dropped.extend(pruned);
+        }
+        if !dropped.is_empty() {
+            warn!(
+                "the packet has been pruned to fit the maximum length; {}",
+                report
+            );
+        }
+
+        Ok(PruningReport {
+            encoded: packet.encode()?,
+            dropped,
+            original_size: report.get_size(),
+        })
+    }
+
+    /// Returns the length of the encoded packet.
+    pub fn encoded_len(&self) -> usize {
+        RADIUS_PACKET_HEADER_LENGTH
+            + self
+                .get_avps()
+                .iter()
+                .map(|avp| AVP_HEADER_LENGTH + avp.value.len())
+                .sum::<usize>()
+    }
+
+    /// Returns the number of the bytes that can be added to the packet as the attributes (i.e. including the attribute headers)
+    /// without exceeding the maximum packet length.
+    ///
+    /// The room of `Message-Authenticator` (18 bytes) is reserved unless the packet already has that,
+    /// because that is added to the packets that carry EAP and is recommended for the others.
+    pub fn remaining_capacity(&self) -> usize {
+        let reserved = if self.contains(MESSAGE_AUTHENTICATOR_TYPE) {
+            0
+        } else {
+            MESSAGE_AUTHENTICATOR_LENGTH
+        };
+        MAX_PACKET_LENGTH.saturating_sub(self.encoded_len() + reserved)
+    }
+
+    /// Returns the number of the bytes of a value that can be added to the packet, when the value is split into
+    /// the attributes of up to 253 bytes (e.g. `EAP-Message`); see also `remaining_capacity()`.
+    ///
+    /// This is the maximum size of an EAP packet (i.e. the fragment) that fits in the packet.
+    pub fn remaining_value_capacity(&self) -> usize {
+        let remaining = self.remaining_capacity();
+        let max_avp_length = AVP_HEADER_LENGTH + MAX_AVP_VALUE_LENGTH;
+        (remaining / max_avp_length) * MAX_AVP_VALUE_LENGTH
+            + (remaining % max_avp_length).saturating_sub(AVP_HEADER_LENGTH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::avp::AVP;
+    use crate::code::Code;
+    use crate::packet::{Packet, PacketError};
+    use crate::prune::{DropByPriority, PruningStrategy};
+    use crate::rfc2865;
+
+    fn make_oversized_packet() -> Packet {
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        rfc2865::add_user_name(&mut packet, "alice");
+        for _ in 0..15 {
+            rfc2865::add_class(&mut packet, [0; 250]).unwrap();
+        }
+        for _ in 0..3 {
+            rfc2865::add_reply_message(&mut packet, "x".repeat(100));
+        }
+        packet // 20 + 7 + 15 * 252 + 3 * 102 = 4113 bytes
+    }
+
+    #[test]
+    fn test_remaining_capacity() {
+        let mut packet = Packet::new(Code::AccessChallenge, b"secret");
+        assert_eq!(packet.remaining_capacity(), 4096 - 20 - 18);
+        assert_eq!(
+            packet.remaining_value_capacity(),
+            15 * 253 + (4058 - 15 * 255 - 2)
+        );
+
+        rfc2865::add_user_name(&mut packet, "alice");
+        assert_eq!(packet.remaining_capacity(), 4096 - 20 - 18 - 7);
+
+        // the added Message-Authenticator is not reserved twice
+        packet.add(AVP::from_bytes(80, &[0; 16]).unwrap());
+        assert_eq!(packet.remaining_capacity(), 4096 - 20 - 18 - 7);
+
+        // the EAP-Messages of the value capacity fit in the packet exactly
+        let capacity = packet.remaining_value_capacity();
+        for chunk in vec![0; capacity].chunks(253) {
+            packet.add(AVP::from_bytes(79, chunk).unwrap());
+        }
+        assert_eq!(packet.encoded_len(), 4096);
+        assert_eq!(packet.remaining_capacity(), 0);
+        assert_eq!(packet.remaining_value_capacity(), 0);
+        assert!(packet.encode().is_ok());
+
+        // a remainder that is not larger than an attribute header has no room for a value
+        let mut packet = Packet::new(Code::AccessChallenge, b"secret");
+        packet.add(AVP::from_bytes(80, &[0; 16]).unwrap());
+        for _ in 0..15 {
+            rfc2865::add_class(&mut packet, [0; 253]).unwrap();
+        }
+        rfc2865::add_class(&mut packet, [0; 229]).unwrap();
+        assert_eq!(packet.remaining_capacity(), 2);
+        assert_eq!(packet.remaining_value_capacity(), 0);
+    }
+
+    #[test]
+    fn test_encode_reports_oversized_packet() {
+        let packet = make_oversized_packet();
+        assert_eq!(packet.encoded_len(), 4113);
+
+        let report = match packet.encode() {
+            Err(PacketError::PacketTooLargeError(report)) => report,
+            result => panic!("unexpected result: {result:?}"),
+        };
+        assert_eq!(report.get_size(), 4113);
+        assert_eq!(report.get_excess(), 17);
+        assert_eq!(report.get_attributes().len(), 19);
+        assert_eq!(report.get_suggested_drops().len(), 1);
+        assert_eq!(report.get_suggested_drops()[0].get_name(), Some("Class"));
+        assert_eq!(report.get_suggested_drops()[0].get_index(), 1);
+        assert_eq!(
+            report.to_string(),
+            "4113 bytes exceeds the maximum length 4096 bytes by 17 bytes; dropping Class[#1] (252 bytes) would fit"
+        );
+
+        let mut packet = packet;
+        rfc2865::delete_reply_message(&mut packet);
+        assert!(packet.oversize_report().is_none());
+        assert!(packet.encode().is_ok());
+    }
+
+    #[test]
+    fn test_encode_with_pruning() {
+        let packet = make_oversized_packet();
+        let report = packet
+            .encode_with_pruning(&DropByPriority::default())
+            .unwrap();
+        assert_eq!(report.get_original_size(), 4113);
+        assert_eq!(report.get_dropped().len(), 1);
+        assert_eq!(report.get_encoded().len(), 4113 - 102);
+
+        let decoded = Packet::decode(report.get_encoded(), b"secret").unwrap();
+        assert_eq!(
+            rfc2865::lookup_all_reply_message(&decoded).unwrap().len(),
+            2
+        );
+        assert_eq!(rfc2865::lookup_all_class(&decoded).len(), 15);
+
+        // nothing is dropped when the packet fits
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        rfc2865::add_reply_message(&mut packet, "hello");
+        let report = packet
+            .encode_with_pruning(&DropByPriority::default())
+            .unwrap();
+        assert!(report.get_dropped().is_empty());
+        assert_eq!(report.get_original_size(), 27);
+    }
+
+    #[test]
+    fn test_encode_with_pruning_gives_up() {
+        let mut packet = make_oversized_packet();
+        rfc2865::delete_reply_message(&mut packet);
+        rfc2865::add_reply_message(&mut packet, "x".repeat(10));
+        for _ in 0..2 {
+            rfc2865::add_class(&mut packet, [0; 250]).unwrap();
+        }
+
+        let result = packet.encode_with_pruning(&DropByPriority::default());
+        let report = match result {
+            Err(PacketError::PacketTooLargeError(report)) => report,
+            result => panic!("unexpected result: {result:?}"),
+        };
+        // the report is of the pruned packet, i.e. without Reply-Message
+        assert!(report
+            .get_attributes()
+            .iter()
+            .all(|attribute| attribute.get_type() != rfc2865::REPLY_MESSAGE_TYPE));
+    }
+
+    #[test]
+    fn test_custom_pruning_strategy() {
+        struct DropClasses;
+        impl PruningStrategy for DropClasses {
+            fn prune(&self, packet: &mut Packet, _excess: usize) -> Vec<AVP> {
+                let classes = packet
+                    .lookup_all(rfc2865::CLASS_TYPE)
+                    .into_iter()
+                    .cloned()
+                    .collect();
+                packet.delete(rfc2865::CLASS_TYPE);
+                classes
+            }
+        }
+
+        let report = make_oversized_packet()
+            .encode_with_pruning(&DropClasses)
+            .unwrap();
+        assert_eq!(report.get_dropped().len(), 15);
+    }
+}
@@ -0,0 +1,130 @@
+//! Source of the random values that go into the packets, i.e. the authenticators, the identifiers,
+//! the salts of the encrypted attributes and the State values.
+//!
+//! The default is `ThreadRandomSource`; `SeededRandomSource` makes byte-identical packets for the same seed,
+//! for the tests and the reproducible traffic (e.g. `loadgen`). It must not be used for the real traffic,
+//! because the Request Authenticator and the salts have to be unpredictable.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// This trait represents a source of the random bytes.
+pub trait RandomSource: Send + Sync {
+    /// Fill the buffer with the random bytes.
+    fn fill_bytes(&self, dest: &mut [u8]);
+}
+
+/// `ThreadRandomSource` is the default source that is backed by `rand::thread_rng()`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ThreadRandomSource;
+
+impl RandomSource for ThreadRandomSource {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        rand::thread_rng().fill_bytes(dest);
+    }
+}
+
+/// `SeededRandomSource` generates the same sequence of the bytes for the same seed.
+///
+/// The clones of this share the state, so the sequence continues over the clones.
+#[derive(Clone)]
+pub struct SeededRandomSource {
+    rng: Arc<Mutex<StdRng>>,
+}
+
+impl SeededRandomSource {
+    pub fn new(seed: u64) -> Self {
+        SeededRandomSource {
+            rng: Arc::new(Mutex::new(StdRng::seed_from_u64(seed))),
+        }
+    }
+}
+
+impl RandomSource for SeededRandomSource {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        self.rng.lock().unwrap().fill_bytes(dest);
+    }
+}
+
+impl fmt::Debug for SeededRandomSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SeededRandomSource").finish_non_exhaustive()
+    }
+}
+
+/// Returns the default source, i.e. `ThreadRandomSource`.
+pub fn default_random_source() -> Arc<dyn RandomSource> {
+    Arc::new(ThreadRandomSource)
+}
+
+/// Returns the random bytes of the given length from the source.
+pub fn random_bytes<const N: usize>(source: &dyn RandomSource) -> [u8; N] {
+    let mut bytes = [0; N];
+    source.fill_bytes(&mut bytes);
+    bytes
+}
+
+/// Returns the salt of the encrypted attributes (e.g. Tunnel-Password); its MSB is set.
+pub fn random_salt(source: &dyn RandomSource) -> [u8; 2] {
+    let [first, second] = random_bytes::<2>(source);
+    [first | 0x80, second]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::{random_salt, RandomSource, SeededRandomSource};
+
+    #[test]
+    fn test_seeded_random_source() {
+        let mut first = [0; 32];
+        let mut second = [0; 32];
+        SeededRandomSource::new(42).fill_bytes(&mut first);
+        SeededRandomSource::new(42).fill_bytes(&mut second);
+        assert_eq!(first, second);
+
+        // the clones continue the same sequence
+        let source = SeededRandomSource::new(42);
+        let mut head = [0; 16];
+        let mut tail = [0; 16];
+        source.fill_bytes(&mut head);
+        source.clone().fill_bytes(&mut tail);
+        assert_eq!([head, tail].concat(), first);
+
+        SeededRandomSource::new(43).fill_bytes(&mut second);
+        assert_ne!(first, second);
+
+        assert!(random_salt(&source)[0] & 0x80 != 0);
+    }
+
+    #[cfg(feature = "rfc2868")]
+    #[test]
+    fn test_reproducible_packets() {
+        use std::sync::Arc;
+
+        use crate::code::Code;
+        use crate::packet::Packet;
+        use crate::rfc2868;
+        use crate::tag::Tag;
+
+        let make_packets = |seed: u64| {
+            let request = Packet::new_with_random_source(
+                Code::AccessRequest,
+                b"secret",
+                Arc::new(SeededRandomSource::new(seed)),
+            );
+            let mut response = request.make_response_packet(Code::AccessAccept);
+            rfc2868::add_tunnel_password(&mut response, Some(&Tag::new(1)), b"password").unwrap();
+            (request.encode().unwrap(), response.encode().unwrap())
+        };
+        assert_eq!(make_packets(42), make_packets(42));
+
+        let (request, response) = make_packets(42);
+        let (other_request, other_response) = make_packets(43);
+        assert_ne!(request[1..20], other_request[1..20]);
+        // the salt of Tunnel-Password is taken from the source that the response inherits from the request
+        assert_ne!(response[20..], other_response[20..]);
+    }
+}
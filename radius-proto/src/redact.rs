@@ -0,0 +1,469 @@
+//! Redaction of the sensitive attributes on the textual output (i.e. `Debug`, `Display`) of packets and AVPs.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::slice;
+
+use crate::avp::AVP;
+use crate::code::Code;
+use crate::fingerprint;
+use crate::metadata::AttributeDataType;
+use crate::packet::Packet;
+use crate::registry;
+use crate::rfc2865;
+
+const MICROSOFT_VENDOR_ID: u32 = 311;
+const MS_MPPE_SEND_KEY_TYPE: u8 = 16;
+const MS_MPPE_RECV_KEY_TYPE: u8 = 17;
+
+const REDACTED: &str = "<redacted>";
+
+/// This enum represents a policy whether the textual output masks the values of the sensitive attributes,
+/// i.e. the encrypted ones (e.g. `User-Password`, `Tunnel-Password`) and MS-MPPE-Send-Key/MS-MPPE-Recv-Key,
+/// and the shared secret of the packet.
+///
+/// `Debug` and `Display` of `Packet` and `AVP` always mask them; use `Packet::display()` and `AVP::display()`
+/// with `Redact::Reveal` to reveal those on purpose (e.g. for debugging in a development environment).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Redact {
+    /// Masks the sensitive values.
+    #[default]
+    Mask,
+    /// Prints the sensitive values as they are.
+    Reveal,
+}
+
+/// Returns whether the value of the AVP is sensitive, i.e. that is masked by `Redact::Mask`.
+pub fn is_sensitive(avp: &AVP) -> bool {
+    if registry::attribute_metadata(avp.typ).is_some_and(|metadata| metadata.encrypted) {
+        return true;
+    }
+
+    // MS-MPPE-Send-Key and MS-MPPE-Recv-Key in a Vendor-Specific attribute; see RFC 2548
+    avp.typ == rfc2865::VENDOR_SPECIFIC_TYPE
+        && avp.value.len() >= 6
+        && u32::from_be_bytes([avp.value[0], avp.value[1], avp.value[2], avp.value[3]])
+            == MICROSOFT_VENDOR_ID
+        && [MS_MPPE_SEND_KEY_TYPE, MS_MPPE_RECV_KEY_TYPE].contains(&avp.value[4])
+}
+
+/// The textual representation of an AVP that follows the `Redact` policy; this is made by `AVP::display()`.
+///
+/// `Display` prints the AVP as `Name = value` (e.g. `User-Name = "alice"`), and `Debug` prints that as a struct.
+pub struct AVPDisplay<'a> {
+    avp: &'a AVP,
+    redact: Redact,
+}
+
+impl AVPDisplay<'_> {
+    fn is_masked(&self) -> bool {
+        self.redact == Redact::Mask && is_sensitive(self.avp)
+    }
+}
+
+impl fmt::Display for AVPDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match registry::attribute_name(self.avp.typ) {
+            Some(name) => write!(f, "{name} = ")?,
+            None => write!(f, "Attr-{} = ", self.avp.typ)?,
+        }
+        if self.is_masked() {
+            return f.write_str(REDACTED);
+        }
+        fmt_value_with_vendor(self.avp, f)
+    }
+}
+
+impl fmt::Debug for AVPDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("AVP");
+        debug_struct.field("typ", &self.avp.typ);
+        if self.is_masked() {
+            debug_struct.field("value", &format_args!("{REDACTED}"));
+        } else {
+            debug_struct.field("value", &self.avp.value);
+        }
+        debug_struct.finish()
+    }
+}
+
+/// Format the value of the AVP, and the name of the vendor for a `Vendor-Specific` attribute.
+fn fmt_value_with_vendor(avp: &AVP, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt_value(avp, f)?;
+    if avp.typ == rfc2865::VENDOR_SPECIFIC_TYPE {
+        if let Some(name) = fingerprint::vsa_vendor_id(avp).and_then(fingerprint::vendor_name) {
+            write!(f, " ({name})")?;
+        }
+    }
+    Ok(())
+}
+
+fn fmt_value(avp: &AVP, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let Some(metadata) = registry::attribute_metadata(avp.typ) else {
+        return fmt_hex(&avp.value, f);
+    };
+
+    let formatted = match (metadata.data_type, metadata.has_tag) {
+        (AttributeDataType::String, false) => {
+            avp.decode_string().ok().map(|value| format!("{value:?}"))
+        }
+        (AttributeDataType::String, true) => {
+            avp.decode_tagged_string()
+                .ok()
+                .map(|(value, tag)| match tag {
+                    Some(tag) => format!("{value:?} (tag: {})", tag.get_value()),
+                    None => format!("{value:?}"),
+                })
+        }
+        (AttributeDataType::Integer, false) => avp.decode_u32().ok().map(|value| value.to_string()),
+        (AttributeDataType::Integer, true) => avp
+            .decode_tagged_u32()
+            .ok()
+            .map(|(value, tag)| format!("{value} (tag: {})", tag.get_value())),
+        (AttributeDataType::Signed, _) => avp.decode_i32().ok().map(|value| value.to_string()),
+        (AttributeDataType::Short, false) => avp.decode_u16().ok().map(|value| value.to_string()),
+        (AttributeDataType::Short, true) => avp
+            .decode_tagged_u16()
+            .ok()
+            .map(|(value, tag)| format!("{value} (tag: {})", tag.get_value())),
+        (AttributeDataType::IpAddr, false) => avp.decode_ipv4().ok().map(|value| value.to_string()),
+        (AttributeDataType::IpAddr, true) => avp
+            .decode_tagged_ipv4()
+            .ok()
+            .map(|(value, tag)| format!("{value} (tag: {})", tag.get_value())),
+        (AttributeDataType::Ipv6Addr, _) => avp.decode_ipv6().ok().map(|value| value.to_string()),
+        (AttributeDataType::ComboIp, _) => {
+            avp.decode_combo_ip().ok().map(|value| value.to_string())
+        }
+        (AttributeDataType::Date, false) => avp.decode_date().ok().map(|value| value.to_rfc3339()),
+        (AttributeDataType::Date, true) => avp
+            .decode_tagged_date()
+            .ok()
+            .map(|(value, tag)| format!("{} (tag: {})", value.to_rfc3339(), tag.get_value())),
+        _ => None,
+    };
+    match formatted {
+        Some(formatted) => f.write_str(&formatted),
+        None => fmt_hex(&avp.value, f),
+    }
+}
+
+fn fmt_hex(bs: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("0x")?;
+    for b in bs {
+        write!(f, "{b:02x}")?;
+    }
+    Ok(())
+}
+
+/// The textual representation of a packet that follows the `Redact` policy; this is made by `Packet::display()`.
+///
+/// `Display` prints the header and an attribute per line like the following:
+///
+/// ```text
+/// Access-Request (identifier: 1, authenticator: 0x00112233445566778899aabbccddeeff)
+///   User-Name = "alice"
+///   User-Password = <redacted>
+/// ```
+pub struct PacketDisplay<'a> {
+    packet: &'a Packet,
+    redact: Redact,
+}
+
+impl fmt::Display for PacketDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (identifier: {}, authenticator: ",
+            self.packet.get_code().string(),
+            self.packet.get_identifier()
+        )?;
+        fmt_hex(self.packet.get_authenticator(), f)?;
+        f.write_str(")")?;
+        for avp in self.packet.get_avps() {
+            write!(f, "\n  {}", avp.display(self.redact))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PacketDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("Packet");
+        debug_struct
+            .field("code", &self.packet.get_code())
+            .field("identifier", &self.packet.get_identifier())
+            .field("authenticator", self.packet.get_authenticator());
+        match self.redact {
+            Redact::Mask => debug_struct.field("secret", &format_args!("{REDACTED}")),
+            Redact::Reveal => debug_struct.field("secret", self.packet.get_secret()),
+        };
+        debug_struct
+            .field(
+                "attributes",
+                &self
+                    .packet
+                    .get_avps()
+                    .iter()
+                    .map(|avp| avp.display(self.redact))
+                    .collect::<Vec<AVPDisplay>>(),
+            )
+            .finish()
+    }
+}
+
+/// The value of a field of `Packet::log_fields()`; this is formatted by `Display` (and `Debug`) only when a logger
+/// writes that, as `Display` of the packet does (e.g. `"alice"`, `12`, `<redacted>`).
+#[derive(Clone, Copy)]
+pub enum LogValue<'a> {
+    /// The code of the packet, e.g. `Access-Request`.
+    Code(Code),
+    /// The identifier of the packet.
+    Identifier(u8),
+    /// The value of an attribute; the sensitive value is masked.
+    Attribute(&'a AVP),
+}
+
+impl fmt::Display for LogValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogValue::Code(code) => f.write_str(code.string()),
+            LogValue::Identifier(identifier) => write!(f, "{identifier}"),
+            LogValue::Attribute(avp) if is_sensitive(avp) => f.write_str(REDACTED),
+            LogValue::Attribute(avp) => fmt_value_with_vendor(avp, f),
+        }
+    }
+}
+
+impl fmt::Debug for LogValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// The iterator of the fields of a packet for the structured loggers; this is made by `Packet::log_fields()`.
+///
+/// This yields `code` and `identifier` of the packet, and then the name and the value of each attribute in order
+/// (e.g. `("User-Name", "alice")`); the names of the known attributes are borrowed from the dictionaries,
+/// and the unknown attribute is named like `Attr-250`.
+#[derive(Clone)]
+pub struct LogFields<'a> {
+    packet: &'a Packet,
+    header_position: usize,
+    avps: slice::Iter<'a, AVP>,
+}
+
+impl<'a> Iterator for LogFields<'a> {
+    type Item = (Cow<'static, str>, LogValue<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.header_position += 1;
+        match self.header_position {
+            1 => Some((
+                Cow::Borrowed("code"),
+                LogValue::Code(self.packet.get_code()),
+            )),
+            2 => Some((
+                Cow::Borrowed("identifier"),
+                LogValue::Identifier(self.packet.get_identifier()),
+            )),
+            _ => self.avps.next().map(|avp| {
+                let name = match registry::attribute_name(avp.typ) {
+                    Some(name) => Cow::Borrowed(name),
+                    None => Cow::Owned(format!("Attr-{}", avp.typ)),
+                };
+                (name, LogValue::Attribute(avp))
+            }),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = 2usize.saturating_sub(self.header_position) + self.avps.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for LogFields<'_> {}
+
+impl AVP {
+    /// Returns the textual representation of this AVP that follows the given `Redact` policy.
+    pub fn display(&self, redact: Redact) -> AVPDisplay<'_> {
+        AVPDisplay { avp: self, redact }
+    }
+}
+
+impl fmt::Display for AVP {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display(Redact::Mask), f)
+    }
+}
+
+impl fmt::Debug for AVP {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.display(Redact::Mask), f)
+    }
+}
+
+impl Packet {
+    /// Returns the fields of this packet as the pairs of the name and the value for the structured loggers
+    /// (e.g. the `key=value` summaries), with the sensitive values masked as `Redact::Mask` does.
+    ///
+    /// The values are formatted only when those are written, e.g.
+    /// `packet.log_fields().for_each(|(name, value)| info!("{name}={value}"))`.
+    pub fn log_fields(&self) -> LogFields<'_> {
+        LogFields {
+            packet: self,
+            header_position: 0,
+            avps: self.get_avps().iter(),
+        }
+    }
+
+    /// Returns the textual representation of this packet that follows the given `Redact` policy.
+    pub fn display(&self, redact: Redact) -> PacketDisplay<'_> {
+        PacketDisplay {
+            packet: self,
+            redact,
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display(Redact::Mask), f)
+    }
+}
+
+impl fmt::Debug for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.display(Redact::Mask), f)
+    }
+}
+
+#[cfg(all(test, feature = "rfc2868"))]
+mod tests {
+    use crate::avp::AVP;
+    use crate::code::Code;
+    use crate::packet::Packet;
+    use crate::redact::{is_sensitive, Redact};
+    use crate::tag::Tag;
+    use crate::vsa::{Vendor, VendorFormat};
+    use crate::{rfc2865, rfc2868};
+
+    fn make_packet() -> Packet {
+        let header = [&[1, 1, 0, 20][..], &[0; 16]].concat(); // Access-Request without attributes
+        let mut packet = Packet::decode(&header, b"secret").unwrap();
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2865::add_user_password(&mut packet, b"password").unwrap();
+        rfc2865::add_nas_port(&mut packet, 12);
+        rfc2868::add_tunnel_type(
+            &mut packet,
+            Some(&Tag::new(1)),
+            rfc2868::TunnelType::from(13),
+        );
+        packet
+    }
+
+    #[test]
+    fn test_packet_display_masks_sensitive_values() {
+        let packet = make_packet();
+        assert_eq!(
+            format!("{}", packet),
+            "Access-Request (identifier: 1, authenticator: 0x00000000000000000000000000000000)
+  User-Name = \"alice\"
+  User-Password = <redacted>
+  NAS-Port = 12
+  Tunnel-Type = 13 (tag: 1)"
+        );
+
+        let debug = format!("{:?}", packet);
+        assert!(debug.contains("secret: <redacted>"));
+        assert!(debug.contains("value: <redacted>"));
+        assert!(!debug.contains("115, 101, 99, 114, 101, 116")); // b"secret"
+    }
+
+    #[test]
+    fn test_log_fields() {
+        let mut packet = make_packet();
+        packet.add(AVP::from_u32(250, 1));
+        packet.add_vsa(
+            &Vendor::new(9, VendorFormat::DEFAULT),
+            1,
+            AVP::from_string(0, "a"),
+        );
+
+        let fields = packet.log_fields();
+        assert_eq!(fields.len(), 8);
+        let fields = fields
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<String>>();
+        assert_eq!(
+            fields,
+            vec![
+                "code=Access-Request",
+                "identifier=1",
+                "User-Name=\"alice\"",
+                "User-Password=<redacted>",
+                "NAS-Port=12",
+                "Tunnel-Type=13 (tag: 1)",
+                "Attr-250=0x00000001",
+                "Vendor-Specific=0x00000009010361 (Cisco)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_packet_display_reveals_sensitive_values_on_opt_in() {
+        let packet = make_packet();
+        let user_password = packet.lookup(rfc2865::USER_PASSWORD_TYPE).unwrap();
+        assert_eq!(
+            format!("{}", user_password.display(Redact::Reveal)),
+            format!(
+                "User-Password = 0x{}",
+                user_password
+                    .decode_bytes()
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>()
+            )
+        );
+
+        let debug = format!("{:?}", packet.display(Redact::Reveal));
+        assert!(debug.contains("secret: [115, 101, 99, 114, 101, 116]"));
+        assert!(!debug.contains("<redacted>"));
+    }
+
+    #[test]
+    fn test_is_sensitive() {
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        rfc2868::add_tunnel_password(&mut packet, None, b"password").unwrap();
+        assert!(is_sensitive(
+            packet.lookup(rfc2868::TUNNEL_PASSWORD_TYPE).unwrap()
+        ));
+
+        let ms_mppe_recv_key = AVP::from_bytes(
+            rfc2865::VENDOR_SPECIFIC_TYPE,
+            &[0, 0, 1, 55, 17, 4, 0xab, 0xcd],
+        )
+        .unwrap();
+        assert!(is_sensitive(&ms_mppe_recv_key));
+        assert_eq!(
+            format!("{}", ms_mppe_recv_key),
+            "Vendor-Specific = <redacted>"
+        );
+
+        let other_vendor_attribute = AVP::from_bytes(
+            rfc2865::VENDOR_SPECIFIC_TYPE,
+            &[0, 0, 0, 9, 1, 4, 0xab, 0xcd],
+        )
+        .unwrap();
+        assert!(!is_sensitive(&other_vendor_attribute));
+        assert_eq!(
+            format!("{}", other_vendor_attribute),
+            "Vendor-Specific = 0x000000090104abcd (Cisco)"
+        );
+        assert_eq!(
+            format!("{}", AVP::from_u32(250, 1)),
+            "Attr-250 = 0x00000001"
+        );
+    }
+}
@@ -0,0 +1,1056 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Registry of the attributes across all of the dictionary modules.
+
+use crate::avp::AVPType;
+use crate::metadata::{AttributeMetadata, ValueMetadata};
+
+#[cfg(feature = "erx")]
+use crate::erx;
+#[cfg(feature = "juniper")]
+use crate::juniper;
+use crate::rfc2865;
+#[cfg(feature = "rfc2866")]
+use crate::rfc2866;
+#[cfg(feature = "rfc2867")]
+use crate::rfc2867;
+#[cfg(feature = "rfc2868")]
+use crate::rfc2868;
+#[cfg(feature = "rfc2869")]
+use crate::rfc2869;
+#[cfg(feature = "rfc3162")]
+use crate::rfc3162;
+#[cfg(feature = "rfc3576")]
+use crate::rfc3576;
+#[cfg(feature = "rfc3580")]
+use crate::rfc3580;
+#[cfg(feature = "rfc4072")]
+use crate::rfc4072;
+#[cfg(feature = "rfc4372")]
+use crate::rfc4372;
+#[cfg(feature = "rfc4603")]
+use crate::rfc4603;
+#[cfg(feature = "rfc4675")]
+use crate::rfc4675;
+#[cfg(feature = "rfc4818")]
+use crate::rfc4818;
+#[cfg(feature = "rfc4849")]
+use crate::rfc4849;
+#[cfg(feature = "rfc5090")]
+use crate::rfc5090;
+#[cfg(feature = "rfc5176")]
+use crate::rfc5176;
+#[cfg(feature = "rfc5607")]
+use crate::rfc5607;
+#[cfg(feature = "rfc5904")]
+use crate::rfc5904;
+#[cfg(feature = "rfc6519")]
+use crate::rfc6519;
+#[cfg(feature = "rfc6572")]
+use crate::rfc6572;
+#[cfg(feature = "rfc6677")]
+use crate::rfc6677;
+#[cfg(feature = "rfc6911")]
+use crate::rfc6911;
+#[cfg(feature = "rfc7055")]
+use crate::rfc7055;
+#[cfg(feature = "rfc7155")]
+use crate::rfc7155;
+
+/// The metadata tables of all of the dictionary modules.
+pub const ATTRIBUTE_TABLES: &[&[AttributeMetadata]] = &[
+    rfc2865::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc2866")]
+    rfc2866::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc2867")]
+    rfc2867::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc2868")]
+    rfc2868::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc2869")]
+    rfc2869::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc3162")]
+    rfc3162::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc3576")]
+    rfc3576::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc3580")]
+    rfc3580::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc4072")]
+    rfc4072::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc4372")]
+    rfc4372::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc4603")]
+    rfc4603::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc4675")]
+    rfc4675::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc4818")]
+    rfc4818::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc4849")]
+    rfc4849::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc5090")]
+    rfc5090::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc5176")]
+    rfc5176::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc5607")]
+    rfc5607::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc5904")]
+    rfc5904::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc6519")]
+    rfc6519::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc6572")]
+    rfc6572::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc6677")]
+    rfc6677::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc6911")]
+    rfc6911::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc7055")]
+    rfc7055::ATTRIBUTE_TABLE,
+    #[cfg(feature = "rfc7155")]
+    rfc7155::ATTRIBUTE_TABLE,
+    #[cfg(feature = "erx")]
+    erx::ATTRIBUTE_TABLE,
+    #[cfg(feature = "juniper")]
+    juniper::ATTRIBUTE_TABLE,
+];
+
+/// The named value tables of all of the dictionary modules.
+pub const VALUE_TABLES: &[&[ValueMetadata]] = &[
+    rfc2865::VALUE_TABLE,
+    #[cfg(feature = "rfc2866")]
+    rfc2866::VALUE_TABLE,
+    #[cfg(feature = "rfc2867")]
+    rfc2867::VALUE_TABLE,
+    #[cfg(feature = "rfc2868")]
+    rfc2868::VALUE_TABLE,
+    #[cfg(feature = "rfc2869")]
+    rfc2869::VALUE_TABLE,
+    #[cfg(feature = "rfc3162")]
+    rfc3162::VALUE_TABLE,
+    #[cfg(feature = "rfc3576")]
+    rfc3576::VALUE_TABLE,
+    #[cfg(feature = "rfc3580")]
+    rfc3580::VALUE_TABLE,
+    #[cfg(feature = "rfc4072")]
+    rfc4072::VALUE_TABLE,
+    #[cfg(feature = "rfc4372")]
+    rfc4372::VALUE_TABLE,
+    #[cfg(feature = "rfc4603")]
+    rfc4603::VALUE_TABLE,
+    #[cfg(feature = "rfc4675")]
+    rfc4675::VALUE_TABLE,
+    #[cfg(feature = "rfc4818")]
+    rfc4818::VALUE_TABLE,
+    #[cfg(feature = "rfc4849")]
+    rfc4849::VALUE_TABLE,
+    #[cfg(feature = "rfc5090")]
+    rfc5090::VALUE_TABLE,
+    #[cfg(feature = "rfc5176")]
+    rfc5176::VALUE_TABLE,
+    #[cfg(feature = "rfc5607")]
+    rfc5607::VALUE_TABLE,
+    #[cfg(feature = "rfc5904")]
+    rfc5904::VALUE_TABLE,
+    #[cfg(feature = "rfc6519")]
+    rfc6519::VALUE_TABLE,
+    #[cfg(feature = "rfc6572")]
+    rfc6572::VALUE_TABLE,
+    #[cfg(feature = "rfc6677")]
+    rfc6677::VALUE_TABLE,
+    #[cfg(feature = "rfc6911")]
+    rfc6911::VALUE_TABLE,
+    #[cfg(feature = "rfc7055")]
+    rfc7055::VALUE_TABLE,
+    #[cfg(feature = "rfc7155")]
+    rfc7155::VALUE_TABLE,
+    #[cfg(feature = "erx")]
+    erx::VALUE_TABLE,
+    #[cfg(feature = "juniper")]
+    juniper::VALUE_TABLE,
+];
+
+/// Returns the name of the attribute (e.g. `User-Name`) for the given AVP type.
+///
+/// If the AVP type is not defined in any dictionary, it returns `None`.
+pub const fn attribute_name(typ: AVPType) -> Option<&'static str> {
+    match typ {
+        rfc2865::USER_NAME_TYPE => Some("User-Name"),
+        rfc2865::USER_PASSWORD_TYPE => Some("User-Password"),
+        rfc2865::CHAP_PASSWORD_TYPE => Some("CHAP-Password"),
+        rfc2865::NAS_IP_ADDRESS_TYPE => Some("NAS-IP-Address"),
+        rfc2865::NAS_PORT_TYPE => Some("NAS-Port"),
+        rfc2865::SERVICE_TYPE_TYPE => Some("Service-Type"),
+        rfc2865::FRAMED_PROTOCOL_TYPE => Some("Framed-Protocol"),
+        rfc2865::FRAMED_IP_ADDRESS_TYPE => Some("Framed-IP-Address"),
+        rfc2865::FRAMED_IP_NETMASK_TYPE => Some("Framed-IP-Netmask"),
+        rfc2865::FRAMED_ROUTING_TYPE => Some("Framed-Routing"),
+        rfc2865::FILTER_ID_TYPE => Some("Filter-Id"),
+        rfc2865::FRAMED_MTU_TYPE => Some("Framed-MTU"),
+        rfc2865::FRAMED_COMPRESSION_TYPE => Some("Framed-Compression"),
+        rfc2865::LOGIN_IP_HOST_TYPE => Some("Login-IP-Host"),
+        rfc2865::LOGIN_SERVICE_TYPE => Some("Login-Service"),
+        rfc2865::LOGIN_TCP_PORT_TYPE => Some("Login-TCP-Port"),
+        rfc2865::REPLY_MESSAGE_TYPE => Some("Reply-Message"),
+        rfc2865::CALLBACK_NUMBER_TYPE => Some("Callback-Number"),
+        rfc2865::CALLBACK_ID_TYPE => Some("Callback-Id"),
+        rfc2865::FRAMED_ROUTE_TYPE => Some("Framed-Route"),
+        rfc2865::FRAMED_IPX_NETWORK_TYPE => Some("Framed-IPX-Network"),
+        rfc2865::STATE_TYPE => Some("State"),
+        rfc2865::CLASS_TYPE => Some("Class"),
+        rfc2865::VENDOR_SPECIFIC_TYPE => Some("Vendor-Specific"),
+        rfc2865::SESSION_TIMEOUT_TYPE => Some("Session-Timeout"),
+        rfc2865::IDLE_TIMEOUT_TYPE => Some("Idle-Timeout"),
+        rfc2865::TERMINATION_ACTION_TYPE => Some("Termination-Action"),
+        rfc2865::CALLED_STATION_ID_TYPE => Some("Called-Station-Id"),
+        rfc2865::CALLING_STATION_ID_TYPE => Some("Calling-Station-Id"),
+        rfc2865::NAS_IDENTIFIER_TYPE => Some("NAS-Identifier"),
+        rfc2865::PROXY_STATE_TYPE => Some("Proxy-State"),
+        rfc2865::LOGIN_LAT_SERVICE_TYPE => Some("Login-LAT-Service"),
+        rfc2865::LOGIN_LAT_NODE_TYPE => Some("Login-LAT-Node"),
+        rfc2865::LOGIN_LAT_GROUP_TYPE => Some("Login-LAT-Group"),
+        rfc2865::FRAMED_APPLE_TALK_LINK_TYPE => Some("Framed-AppleTalk-Link"),
+        rfc2865::FRAMED_APPLE_TALK_NETWORK_TYPE => Some("Framed-AppleTalk-Network"),
+        rfc2865::FRAMED_APPLE_TALK_ZONE_TYPE => Some("Framed-AppleTalk-Zone"),
+        rfc2865::CHAP_CHALLENGE_TYPE => Some("CHAP-Challenge"),
+        rfc2865::NAS_PORT_TYPE_TYPE => Some("NAS-Port-Type"),
+        rfc2865::PORT_LIMIT_TYPE => Some("Port-Limit"),
+        rfc2865::LOGIN_LAT_PORT_TYPE => Some("Login-LAT-Port"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_STATUS_TYPE_TYPE => Some("Acct-Status-Type"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_DELAY_TIME_TYPE => Some("Acct-Delay-Time"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_INPUT_OCTETS_TYPE => Some("Acct-Input-Octets"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_OUTPUT_OCTETS_TYPE => Some("Acct-Output-Octets"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_SESSION_ID_TYPE => Some("Acct-Session-Id"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_AUTHENTIC_TYPE => Some("Acct-Authentic"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_SESSION_TIME_TYPE => Some("Acct-Session-Time"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_INPUT_PACKETS_TYPE => Some("Acct-Input-Packets"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_OUTPUT_PACKETS_TYPE => Some("Acct-Output-Packets"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_TERMINATE_CAUSE_TYPE => Some("Acct-Terminate-Cause"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_MULTI_SESSION_ID_TYPE => Some("Acct-Multi-Session-Id"),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_LINK_COUNT_TYPE => Some("Acct-Link-Count"),
+        #[cfg(feature = "rfc2867")]
+        rfc2867::ACCT_TUNNEL_CONNECTION_TYPE => Some("Acct-Tunnel-Connection"),
+        #[cfg(feature = "rfc2867")]
+        rfc2867::ACCT_TUNNEL_PACKETS_LOST_TYPE => Some("Acct-Tunnel-Packets-Lost"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_TYPE_TYPE => Some("Tunnel-Type"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_MEDIUM_TYPE_TYPE => Some("Tunnel-Medium-Type"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_CLIENT_ENDPOINT_TYPE => Some("Tunnel-Client-Endpoint"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_SERVER_ENDPOINT_TYPE => Some("Tunnel-Server-Endpoint"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_PASSWORD_TYPE => Some("Tunnel-Password"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_PRIVATE_GROUP_ID_TYPE => Some("Tunnel-Private-Group-Id"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_ASSIGNMENT_ID_TYPE => Some("Tunnel-Assignment-Id"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_PREFERENCE_TYPE => Some("Tunnel-Preference"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_CLIENT_AUTH_ID_TYPE => Some("Tunnel-Client-Auth-Id"),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_SERVER_AUTH_ID_TYPE => Some("Tunnel-Server-Auth-Id"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ACCT_INPUT_GIGAWORDS_TYPE => Some("Acct-Input-Gigawords"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ACCT_OUTPUT_GIGAWORDS_TYPE => Some("Acct-Output-Gigawords"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::EVENT_TIMESTAMP_TYPE => Some("Event-Timestamp"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_PASSWORD_TYPE => Some("ARAP-Password"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_FEATURES_TYPE => Some("ARAP-Features"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_ZONE_ACCESS_TYPE => Some("ARAP-Zone-Access"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_SECURITY_TYPE => Some("ARAP-Security"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_SECURITY_DATA_TYPE => Some("ARAP-Security-Data"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::PASSWORD_RETRY_TYPE => Some("Password-Retry"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::PROMPT_TYPE => Some("Prompt"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::CONNECT_INFO_TYPE => Some("Connect-Info"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::CONFIGURATION_TOKEN_TYPE => Some("Configuration-Token"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::EAP_MESSAGE_TYPE => Some("EAP-Message"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::MESSAGE_AUTHENTICATOR_TYPE => Some("Message-Authenticator"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_CHALLENGE_RESPONSE_TYPE => Some("ARAP-Challenge-Response"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ACCT_INTERIM_INTERVAL_TYPE => Some("Acct-Interim-Interval"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::NAS_PORT_ID_TYPE => Some("NAS-Port-Id"),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::FRAMED_POOL_TYPE => Some("Framed-Pool"),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::NAS_IPV6_ADDRESS_TYPE => Some("NAS-IPV6-Address"),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::FRAMED_INTERFACE_ID_TYPE => Some("Framed-Interface-Id"),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::FRAMED_IPV6_PREFIX_TYPE => Some("Framed-IPV6-Prefix"),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::LOGIN_IPV6_HOST_TYPE => Some("Login-IPV6-Host"),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::FRAMED_IPV6_ROUTE_TYPE => Some("Framed-IPV6-Route"),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::FRAMED_IPV6_POOL_TYPE => Some("Framed-IPV6-Pool"),
+        #[cfg(feature = "rfc3576")]
+        rfc3576::ERROR_CAUSE_TYPE => Some("Error-Cause"),
+        #[cfg(feature = "rfc4072")]
+        rfc4072::EAP_KEY_NAME_TYPE => Some("EAP-Key-Name"),
+        #[cfg(feature = "rfc4372")]
+        rfc4372::CHARGEABLE_USER_IDENTITY_TYPE => Some("Chargeable-User-Identity"),
+        #[cfg(feature = "rfc4675")]
+        rfc4675::EGRESS_VLANID_TYPE => Some("Egress-VLANID"),
+        #[cfg(feature = "rfc4675")]
+        rfc4675::INGRESS_FILTERS_TYPE => Some("Ingress-Filters"),
+        #[cfg(feature = "rfc4675")]
+        rfc4675::EGRESS_VLAN_NAME_TYPE => Some("Egress-VLAN-Name"),
+        #[cfg(feature = "rfc4675")]
+        rfc4675::USER_PRIORITY_TABLE_TYPE => Some("User-Priority-Table"),
+        #[cfg(feature = "rfc4818")]
+        rfc4818::DELEGATED_IPV6_PREFIX_TYPE => Some("Delegated-IPV6-Prefix"),
+        #[cfg(feature = "rfc4849")]
+        rfc4849::NAS_FILTER_RULE_TYPE => Some("NAS-Filter-Rule"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_RESPONSE_TYPE => Some("Digest-Response"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_REALM_TYPE => Some("Digest-Realm"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_NONCE_TYPE => Some("Digest-Nonce"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_RESPONSE_AUTH_TYPE => Some("Digest-Response-Auth"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_NEXTNONCE_TYPE => Some("Digest-Nextnonce"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_METHOD_TYPE => Some("Digest-Method"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_URI_TYPE => Some("Digest-URI"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_QOP_TYPE => Some("Digest-Qop"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_ALGORITHM_TYPE => Some("Digest-Algorithm"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_ENTITY_BODY_HASH_TYPE => Some("Digest-Entity-Body-Hash"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_C_NONCE_TYPE => Some("Digest-CNonce"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_NONCE_COUNT_TYPE => Some("Digest-Nonce-Count"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_USERNAME_TYPE => Some("Digest-Username"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_OPAQUE_TYPE => Some("Digest-Opaque"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_AUTH_PARAM_TYPE => Some("Digest-Auth-Param"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_AKA_AUTS_TYPE => Some("Digest-AKA-Auts"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_DOMAIN_TYPE => Some("Digest-Domain"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_STALE_TYPE => Some("Digest-Stale"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_HA1_TYPE => Some("Digest-HA1"),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::SIP_AOR_TYPE => Some("SIP-AOR"),
+        #[cfg(feature = "rfc5607")]
+        rfc5607::FRAMED_MANAGEMENT_TYPE => Some("Framed-Management"),
+        #[cfg(feature = "rfc5607")]
+        rfc5607::MANAGEMENT_TRANSPORT_PROTECTION_TYPE => Some("Management-Transport-Protection"),
+        #[cfg(feature = "rfc5607")]
+        rfc5607::MANAGEMENT_POLICY_ID_TYPE => Some("Management-Policy-Id"),
+        #[cfg(feature = "rfc5607")]
+        rfc5607::MANAGEMENT_PRIVILEGE_LEVEL_TYPE => Some("Management-Privilege-Level"),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_SS_CERT_TYPE => Some("PKM-SS-Cert"),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_CA_CERT_TYPE => Some("PKM-CA-Cert"),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_CONFIG_SETTINGS_TYPE => Some("PKM-Config-Settings"),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_CRYPTOSUITE_LIST_TYPE => Some("PKM-Cryptosuite-List"),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_SAID_TYPE => Some("PKM-SAID"),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_SA_DESCRIPTOR_TYPE => Some("PKM-SA-Descriptor"),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_AUTH_KEY_TYPE => Some("PKM-Auth-Key"),
+        #[cfg(feature = "rfc6519")]
+        rfc6519::DS_LITE_TUNNEL_NAME_TYPE => Some("DS-Lite-Tunnel-Name"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::MOBILE_NODE_IDENTIFIER_TYPE => Some("Mobile-Node-Identifier"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::SERVICE_SELECTION_TYPE => Some("Service-Selection"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE => Some("PMIP6-Home-LMA-IPV6-Address"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE => Some("PMIP6-Visited-LMA-IPV6-Address"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE => Some("PMIP6-Home-LMA-IPV4-Address"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE => Some("PMIP6-Visited-LMA-IPV4-Address"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_HN_PREFIX_TYPE => Some("PMIP6-Home-HN-Prefix"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_HN_PREFIX_TYPE => Some("PMIP6-Visited-HN-Prefix"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_INTERFACE_ID_TYPE => Some("PMIP6-Home-Interface-ID"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_INTERFACE_ID_TYPE => Some("PMIP6-Visited-Interface-ID"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_IPV4_HO_A_TYPE => Some("PMIP6-Home-IPV4-HoA"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_IPV4_HO_A_TYPE => Some("PMIP6-Visited-IPV4-HoA"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE => Some("PMIP6-Home-DHCP4-Server-Address"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE => {
+            Some("PMIP6-Visited-DHCP4-Server-Address")
+        }
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE => Some("PMIP6-Home-DHCP6-Server-Address"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE => {
+            Some("PMIP6-Visited-DHCP6-Server-Address")
+        }
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_IPV4_GATEWAY_TYPE => Some("PMIP6-Home-IPV4-Gateway"),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_IPV4_GATEWAY_TYPE => Some("PMIP6-Visited-IPV4-Gateway"),
+        #[cfg(feature = "rfc6677")]
+        rfc6677::EAP_LOWER_LAYER_TYPE => Some("EAP-Lower-Layer"),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::FRAMED_IPV6_ADDRESS_TYPE => Some("Framed-IPV6-Address"),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::DNS_SERVER_IPV6_ADDRESS_TYPE => Some("DNS-Server-IPV6-Address"),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::ROUTE_IPV6_INFORMATION_TYPE => Some("Route-IPV6-Information"),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::DELEGATED_IPV6_PREFIX_POOL_TYPE => Some("Delegated-IPV6-Prefix-Pool"),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::STATEFUL_IPV6_ADDRESS_POOL_TYPE => Some("Stateful-IPV6-Address-Pool"),
+        #[cfg(feature = "rfc7055")]
+        rfc7055::GSS_ACCEPTOR_SERVICE_NAME_TYPE => Some("GSS-Acceptor-Service-Name"),
+        #[cfg(feature = "rfc7055")]
+        rfc7055::GSS_ACCEPTOR_HOST_NAME_TYPE => Some("GSS-Acceptor-Host-Name"),
+        #[cfg(feature = "rfc7055")]
+        rfc7055::GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE => Some("GSS-Acceptor-Service-Specifics"),
+        #[cfg(feature = "rfc7055")]
+        rfc7055::GSS_ACCEPTOR_REALM_NAME_TYPE => Some("GSS-Acceptor-Realm-Name"),
+        #[cfg(feature = "rfc7155")]
+        rfc7155::ORIGINATING_LINE_INFO_TYPE => Some("Originating-Line-Info"),
+        _ => None,
+    }
+}
+
+/// Returns the AVP type for the given attribute name (e.g. `User-Name`).
+///
+/// If the name is not defined in any dictionary, it returns `None`.
+pub const fn attribute_type(name: &str) -> Option<AVPType> {
+    match name.as_bytes() {
+        b"User-Name" => Some(rfc2865::USER_NAME_TYPE),
+        b"User-Password" => Some(rfc2865::USER_PASSWORD_TYPE),
+        b"CHAP-Password" => Some(rfc2865::CHAP_PASSWORD_TYPE),
+        b"NAS-IP-Address" => Some(rfc2865::NAS_IP_ADDRESS_TYPE),
+        b"NAS-Port" => Some(rfc2865::NAS_PORT_TYPE),
+        b"Service-Type" => Some(rfc2865::SERVICE_TYPE_TYPE),
+        b"Framed-Protocol" => Some(rfc2865::FRAMED_PROTOCOL_TYPE),
+        b"Framed-IP-Address" => Some(rfc2865::FRAMED_IP_ADDRESS_TYPE),
+        b"Framed-IP-Netmask" => Some(rfc2865::FRAMED_IP_NETMASK_TYPE),
+        b"Framed-Routing" => Some(rfc2865::FRAMED_ROUTING_TYPE),
+        b"Filter-Id" => Some(rfc2865::FILTER_ID_TYPE),
+        b"Framed-MTU" => Some(rfc2865::FRAMED_MTU_TYPE),
+        b"Framed-Compression" => Some(rfc2865::FRAMED_COMPRESSION_TYPE),
+        b"Login-IP-Host" => Some(rfc2865::LOGIN_IP_HOST_TYPE),
+        b"Login-Service" => Some(rfc2865::LOGIN_SERVICE_TYPE),
+        b"Login-TCP-Port" => Some(rfc2865::LOGIN_TCP_PORT_TYPE),
+        b"Reply-Message" => Some(rfc2865::REPLY_MESSAGE_TYPE),
+        b"Callback-Number" => Some(rfc2865::CALLBACK_NUMBER_TYPE),
+        b"Callback-Id" => Some(rfc2865::CALLBACK_ID_TYPE),
+        b"Framed-Route" => Some(rfc2865::FRAMED_ROUTE_TYPE),
+        b"Framed-IPX-Network" => Some(rfc2865::FRAMED_IPX_NETWORK_TYPE),
+        b"State" => Some(rfc2865::STATE_TYPE),
+        b"Class" => Some(rfc2865::CLASS_TYPE),
+        b"Vendor-Specific" => Some(rfc2865::VENDOR_SPECIFIC_TYPE),
+        b"Session-Timeout" => Some(rfc2865::SESSION_TIMEOUT_TYPE),
+        b"Idle-Timeout" => Some(rfc2865::IDLE_TIMEOUT_TYPE),
+        b"Termination-Action" => Some(rfc2865::TERMINATION_ACTION_TYPE),
+        b"Called-Station-Id" => Some(rfc2865::CALLED_STATION_ID_TYPE),
+        b"Calling-Station-Id" => Some(rfc2865::CALLING_STATION_ID_TYPE),
+        b"NAS-Identifier" => Some(rfc2865::NAS_IDENTIFIER_TYPE),
+        b"Proxy-State" => Some(rfc2865::PROXY_STATE_TYPE),
+        b"Login-LAT-Service" => Some(rfc2865::LOGIN_LAT_SERVICE_TYPE),
+        b"Login-LAT-Node" => Some(rfc2865::LOGIN_LAT_NODE_TYPE),
+        b"Login-LAT-Group" => Some(rfc2865::LOGIN_LAT_GROUP_TYPE),
+        b"Framed-AppleTalk-Link" => Some(rfc2865::FRAMED_APPLE_TALK_LINK_TYPE),
+        b"Framed-AppleTalk-Network" => Some(rfc2865::FRAMED_APPLE_TALK_NETWORK_TYPE),
+        b"Framed-AppleTalk-Zone" => Some(rfc2865::FRAMED_APPLE_TALK_ZONE_TYPE),
+        b"CHAP-Challenge" => Some(rfc2865::CHAP_CHALLENGE_TYPE),
+        b"NAS-Port-Type" => Some(rfc2865::NAS_PORT_TYPE_TYPE),
+        b"Port-Limit" => Some(rfc2865::PORT_LIMIT_TYPE),
+        b"Login-LAT-Port" => Some(rfc2865::LOGIN_LAT_PORT_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Status-Type" => Some(rfc2866::ACCT_STATUS_TYPE_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Delay-Time" => Some(rfc2866::ACCT_DELAY_TIME_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Input-Octets" => Some(rfc2866::ACCT_INPUT_OCTETS_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Output-Octets" => Some(rfc2866::ACCT_OUTPUT_OCTETS_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Session-Id" => Some(rfc2866::ACCT_SESSION_ID_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Authentic" => Some(rfc2866::ACCT_AUTHENTIC_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Session-Time" => Some(rfc2866::ACCT_SESSION_TIME_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Input-Packets" => Some(rfc2866::ACCT_INPUT_PACKETS_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Output-Packets" => Some(rfc2866::ACCT_OUTPUT_PACKETS_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Terminate-Cause" => Some(rfc2866::ACCT_TERMINATE_CAUSE_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Multi-Session-Id" => Some(rfc2866::ACCT_MULTI_SESSION_ID_TYPE),
+        #[cfg(feature = "rfc2866")]
+        b"Acct-Link-Count" => Some(rfc2866::ACCT_LINK_COUNT_TYPE),
+        #[cfg(feature = "rfc2867")]
+        b"Acct-Tunnel-Connection" => Some(rfc2867::ACCT_TUNNEL_CONNECTION_TYPE),
+        #[cfg(feature = "rfc2867")]
+        b"Acct-Tunnel-Packets-Lost" => Some(rfc2867::ACCT_TUNNEL_PACKETS_LOST_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Type" => Some(rfc2868::TUNNEL_TYPE_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Medium-Type" => Some(rfc2868::TUNNEL_MEDIUM_TYPE_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Client-Endpoint" => Some(rfc2868::TUNNEL_CLIENT_ENDPOINT_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Server-Endpoint" => Some(rfc2868::TUNNEL_SERVER_ENDPOINT_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Password" => Some(rfc2868::TUNNEL_PASSWORD_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Private-Group-Id" => Some(rfc2868::TUNNEL_PRIVATE_GROUP_ID_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Assignment-Id" => Some(rfc2868::TUNNEL_ASSIGNMENT_ID_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Preference" => Some(rfc2868::TUNNEL_PREFERENCE_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Client-Auth-Id" => Some(rfc2868::TUNNEL_CLIENT_AUTH_ID_TYPE),
+        #[cfg(feature = "rfc2868")]
+        b"Tunnel-Server-Auth-Id" => Some(rfc2868::TUNNEL_SERVER_AUTH_ID_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Acct-Input-Gigawords" => Some(rfc2869::ACCT_INPUT_GIGAWORDS_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Acct-Output-Gigawords" => Some(rfc2869::ACCT_OUTPUT_GIGAWORDS_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Event-Timestamp" => Some(rfc2869::EVENT_TIMESTAMP_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"ARAP-Password" => Some(rfc2869::ARAP_PASSWORD_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"ARAP-Features" => Some(rfc2869::ARAP_FEATURES_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"ARAP-Zone-Access" => Some(rfc2869::ARAP_ZONE_ACCESS_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"ARAP-Security" => Some(rfc2869::ARAP_SECURITY_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"ARAP-Security-Data" => Some(rfc2869::ARAP_SECURITY_DATA_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Password-Retry" => Some(rfc2869::PASSWORD_RETRY_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Prompt" => Some(rfc2869::PROMPT_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Connect-Info" => Some(rfc2869::CONNECT_INFO_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Configuration-Token" => Some(rfc2869::CONFIGURATION_TOKEN_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"EAP-Message" => Some(rfc2869::EAP_MESSAGE_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Message-Authenticator" => Some(rfc2869::MESSAGE_AUTHENTICATOR_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"ARAP-Challenge-Response" => Some(rfc2869::ARAP_CHALLENGE_RESPONSE_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Acct-Interim-Interval" => Some(rfc2869::ACCT_INTERIM_INTERVAL_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"NAS-Port-Id" => Some(rfc2869::NAS_PORT_ID_TYPE),
+        #[cfg(feature = "rfc2869")]
+        b"Framed-Pool" => Some(rfc2869::FRAMED_POOL_TYPE),
+        #[cfg(feature = "rfc3162")]
+        b"NAS-IPV6-Address" => Some(rfc3162::NAS_IPV6_ADDRESS_TYPE),
+        #[cfg(feature = "rfc3162")]
+        b"Framed-Interface-Id" => Some(rfc3162::FRAMED_INTERFACE_ID_TYPE),
+        #[cfg(feature = "rfc3162")]
+        b"Framed-IPV6-Prefix" => Some(rfc3162::FRAMED_IPV6_PREFIX_TYPE),
+        #[cfg(feature = "rfc3162")]
+        b"Login-IPV6-Host" => Some(rfc3162::LOGIN_IPV6_HOST_TYPE),
+        #[cfg(feature = "rfc3162")]
+        b"Framed-IPV6-Route" => Some(rfc3162::FRAMED_IPV6_ROUTE_TYPE),
+        #[cfg(feature = "rfc3162")]
+        b"Framed-IPV6-Pool" => Some(rfc3162::FRAMED_IPV6_POOL_TYPE),
+        #[cfg(feature = "rfc3576")]
+        b"Error-Cause" => Some(rfc3576::ERROR_CAUSE_TYPE),
+        #[cfg(feature = "rfc4072")]
+        b"EAP-Key-Name" => Some(rfc4072::EAP_KEY_NAME_TYPE),
+        #[cfg(feature = "rfc4372")]
+        b"Chargeable-User-Identity" => Some(rfc4372::CHARGEABLE_USER_IDENTITY_TYPE),
+        #[cfg(feature = "rfc4675")]
+        b"Egress-VLANID" => Some(rfc4675::EGRESS_VLANID_TYPE),
+        #[cfg(feature = "rfc4675")]
+        b"Ingress-Filters" => Some(rfc4675::INGRESS_FILTERS_TYPE),
+        #[cfg(feature = "rfc4675")]
+        b"Egress-VLAN-Name" => Some(rfc4675::EGRESS_VLAN_NAME_TYPE),
+        #[cfg(feature = "rfc4675")]
+        b"User-Priority-Table" => Some(rfc4675::USER_PRIORITY_TABLE_TYPE),
+        #[cfg(feature = "rfc4818")]
+        b"Delegated-IPV6-Prefix" => Some(rfc4818::DELEGATED_IPV6_PREFIX_TYPE),
+        #[cfg(feature = "rfc4849")]
+        b"NAS-Filter-Rule" => Some(rfc4849::NAS_FILTER_RULE_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Response" => Some(rfc5090::DIGEST_RESPONSE_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Realm" => Some(rfc5090::DIGEST_REALM_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Nonce" => Some(rfc5090::DIGEST_NONCE_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Response-Auth" => Some(rfc5090::DIGEST_RESPONSE_AUTH_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Nextnonce" => Some(rfc5090::DIGEST_NEXTNONCE_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Method" => Some(rfc5090::DIGEST_METHOD_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-URI" => Some(rfc5090::DIGEST_URI_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Qop" => Some(rfc5090::DIGEST_QOP_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Algorithm" => Some(rfc5090::DIGEST_ALGORITHM_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Entity-Body-Hash" => Some(rfc5090::DIGEST_ENTITY_BODY_HASH_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-CNonce" => Some(rfc5090::DIGEST_C_NONCE_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Nonce-Count" => Some(rfc5090::DIGEST_NONCE_COUNT_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Username" => Some(rfc5090::DIGEST_USERNAME_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Opaque" => Some(rfc5090::DIGEST_OPAQUE_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Auth-Param" => Some(rfc5090::DIGEST_AUTH_PARAM_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-AKA-Auts" => Some(rfc5090::DIGEST_AKA_AUTS_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Domain" => Some(rfc5090::DIGEST_DOMAIN_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-Stale" => Some(rfc5090::DIGEST_STALE_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"Digest-HA1" => Some(rfc5090::DIGEST_HA1_TYPE),
+        #[cfg(feature = "rfc5090")]
+        b"SIP-AOR" => Some(rfc5090::SIP_AOR_TYPE),
+        #[cfg(feature = "rfc5607")]
+        b"Framed-Management" => Some(rfc5607::FRAMED_MANAGEMENT_TYPE),
+        #[cfg(feature = "rfc5607")]
+        b"Management-Transport-Protection" => Some(rfc5607::MANAGEMENT_TRANSPORT_PROTECTION_TYPE),
+        #[cfg(feature = "rfc5607")]
+        b"Management-Policy-Id" => Some(rfc5607::MANAGEMENT_POLICY_ID_TYPE),
+        #[cfg(feature = "rfc5607")]
+        b"Management-Privilege-Level" => Some(rfc5607::MANAGEMENT_PRIVILEGE_LEVEL_TYPE),
+        #[cfg(feature = "rfc5904")]
+        b"PKM-SS-Cert" => Some(rfc5904::PKM_SS_CERT_TYPE),
+        #[cfg(feature = "rfc5904")]
+        b"PKM-CA-Cert" => Some(rfc5904::PKM_CA_CERT_TYPE),
+        #[cfg(feature = "rfc5904")]
+        b"PKM-Config-Settings" => Some(rfc5904::PKM_CONFIG_SETTINGS_TYPE),
+        #[cfg(feature = "rfc5904")]
+        b"PKM-Cryptosuite-List" => Some(rfc5904::PKM_CRYPTOSUITE_LIST_TYPE),
+        #[cfg(feature = "rfc5904")]
+        b"PKM-SAID" => Some(rfc5904::PKM_SAID_TYPE),
+        #[cfg(feature = "rfc5904")]
+        b"PKM-SA-Descriptor" => Some(rfc5904::PKM_SA_DESCRIPTOR_TYPE),
+        #[cfg(feature = "rfc5904")]
+        b"PKM-Auth-Key" => Some(rfc5904::PKM_AUTH_KEY_TYPE),
+        #[cfg(feature = "rfc6519")]
+        b"DS-Lite-Tunnel-Name" => Some(rfc6519::DS_LITE_TUNNEL_NAME_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"Mobile-Node-Identifier" => Some(rfc6572::MOBILE_NODE_IDENTIFIER_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"Service-Selection" => Some(rfc6572::SERVICE_SELECTION_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Home-LMA-IPV6-Address" => Some(rfc6572::PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Visited-LMA-IPV6-Address" => Some(rfc6572::PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Home-LMA-IPV4-Address" => Some(rfc6572::PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Visited-LMA-IPV4-Address" => Some(rfc6572::PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Home-HN-Prefix" => Some(rfc6572::PMIP6_HOME_HN_PREFIX_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Visited-HN-Prefix" => Some(rfc6572::PMIP6_VISITED_HN_PREFIX_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Home-Interface-ID" => Some(rfc6572::PMIP6_HOME_INTERFACE_ID_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Visited-Interface-ID" => Some(rfc6572::PMIP6_VISITED_INTERFACE_ID_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Home-IPV4-HoA" => Some(rfc6572::PMIP6_HOME_IPV4_HO_A_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Visited-IPV4-HoA" => Some(rfc6572::PMIP6_VISITED_IPV4_HO_A_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Home-DHCP4-Server-Address" => Some(rfc6572::PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Visited-DHCP4-Server-Address" => {
+            Some(rfc6572::PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE)
+        }
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Home-DHCP6-Server-Address" => Some(rfc6572::PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Visited-DHCP6-Server-Address" => {
+            Some(rfc6572::PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE)
+        }
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Home-IPV4-Gateway" => Some(rfc6572::PMIP6_HOME_IPV4_GATEWAY_TYPE),
+        #[cfg(feature = "rfc6572")]
+        b"PMIP6-Visited-IPV4-Gateway" => Some(rfc6572::PMIP6_VISITED_IPV4_GATEWAY_TYPE),
+        #[cfg(feature = "rfc6677")]
+        b"EAP-Lower-Layer" => Some(rfc6677::EAP_LOWER_LAYER_TYPE),
+        #[cfg(feature = "rfc6911")]
+        b"Framed-IPV6-Address" => Some(rfc6911::FRAMED_IPV6_ADDRESS_TYPE),
+        #[cfg(feature = "rfc6911")]
+        b"DNS-Server-IPV6-Address" => Some(rfc6911::DNS_SERVER_IPV6_ADDRESS_TYPE),
+        #[cfg(feature = "rfc6911")]
+        b"Route-IPV6-Information" => Some(rfc6911::ROUTE_IPV6_INFORMATION_TYPE),
+        #[cfg(feature = "rfc6911")]
+        b"Delegated-IPV6-Prefix-Pool" => Some(rfc6911::DELEGATED_IPV6_PREFIX_POOL_TYPE),
+        #[cfg(feature = "rfc6911")]
+        b"Stateful-IPV6-Address-Pool" => Some(rfc6911::STATEFUL_IPV6_ADDRESS_POOL_TYPE),
+        #[cfg(feature = "rfc7055")]
+        b"GSS-Acceptor-Service-Name" => Some(rfc7055::GSS_ACCEPTOR_SERVICE_NAME_TYPE),
+        #[cfg(feature = "rfc7055")]
+        b"GSS-Acceptor-Host-Name" => Some(rfc7055::GSS_ACCEPTOR_HOST_NAME_TYPE),
+        #[cfg(feature = "rfc7055")]
+        b"GSS-Acceptor-Service-Specifics" => Some(rfc7055::GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE),
+        #[cfg(feature = "rfc7055")]
+        b"GSS-Acceptor-Realm-Name" => Some(rfc7055::GSS_ACCEPTOR_REALM_NAME_TYPE),
+        #[cfg(feature = "rfc7155")]
+        b"Originating-Line-Info" => Some(rfc7155::ORIGINATING_LINE_INFO_TYPE),
+        _ => None,
+    }
+}
+
+/// Returns the metadata of the attribute for the given AVP type.
+///
+/// If the AVP type is not defined in any dictionary, it returns `None`.
+pub const fn attribute_metadata(typ: AVPType) -> Option<&'static AttributeMetadata> {
+    match typ {
+        rfc2865::USER_NAME_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[0]),
+        rfc2865::USER_PASSWORD_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[1]),
+        rfc2865::CHAP_PASSWORD_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[2]),
+        rfc2865::NAS_IP_ADDRESS_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[3]),
+        rfc2865::NAS_PORT_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[4]),
+        rfc2865::SERVICE_TYPE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[5]),
+        rfc2865::FRAMED_PROTOCOL_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[6]),
+        rfc2865::FRAMED_IP_ADDRESS_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[7]),
+        rfc2865::FRAMED_IP_NETMASK_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[8]),
+        rfc2865::FRAMED_ROUTING_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[9]),
+        rfc2865::FILTER_ID_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[10]),
+        rfc2865::FRAMED_MTU_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[11]),
+        rfc2865::FRAMED_COMPRESSION_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[12]),
+        rfc2865::LOGIN_IP_HOST_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[13]),
+        rfc2865::LOGIN_SERVICE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[14]),
+        rfc2865::LOGIN_TCP_PORT_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[15]),
+        rfc2865::REPLY_MESSAGE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[16]),
+        rfc2865::CALLBACK_NUMBER_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[17]),
+        rfc2865::CALLBACK_ID_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[18]),
+        rfc2865::FRAMED_ROUTE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[19]),
+        rfc2865::FRAMED_IPX_NETWORK_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[20]),
+        rfc2865::STATE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[21]),
+        rfc2865::CLASS_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[22]),
+        rfc2865::VENDOR_SPECIFIC_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[23]),
+        rfc2865::SESSION_TIMEOUT_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[24]),
+        rfc2865::IDLE_TIMEOUT_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[25]),
+        rfc2865::TERMINATION_ACTION_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[26]),
+        rfc2865::CALLED_STATION_ID_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[27]),
+        rfc2865::CALLING_STATION_ID_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[28]),
+        rfc2865::NAS_IDENTIFIER_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[29]),
+        rfc2865::PROXY_STATE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[30]),
+        rfc2865::LOGIN_LAT_SERVICE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[31]),
+        rfc2865::LOGIN_LAT_NODE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[32]),
+        rfc2865::LOGIN_LAT_GROUP_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[33]),
+        rfc2865::FRAMED_APPLE_TALK_LINK_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[34]),
+        rfc2865::FRAMED_APPLE_TALK_NETWORK_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[35]),
+        rfc2865::FRAMED_APPLE_TALK_ZONE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[36]),
+        rfc2865::CHAP_CHALLENGE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[37]),
+        rfc2865::NAS_PORT_TYPE_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[38]),
+        rfc2865::PORT_LIMIT_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[39]),
+        rfc2865::LOGIN_LAT_PORT_TYPE => Some(&rfc2865::ATTRIBUTE_TABLE[40]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_STATUS_TYPE_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_DELAY_TIME_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_INPUT_OCTETS_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_OUTPUT_OCTETS_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_SESSION_ID_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[4]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_AUTHENTIC_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[5]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_SESSION_TIME_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[6]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_INPUT_PACKETS_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[7]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_OUTPUT_PACKETS_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[8]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_TERMINATE_CAUSE_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[9]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_MULTI_SESSION_ID_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[10]),
+        #[cfg(feature = "rfc2866")]
+        rfc2866::ACCT_LINK_COUNT_TYPE => Some(&rfc2866::ATTRIBUTE_TABLE[11]),
+        #[cfg(feature = "rfc2867")]
+        rfc2867::ACCT_TUNNEL_CONNECTION_TYPE => Some(&rfc2867::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc2867")]
+        rfc2867::ACCT_TUNNEL_PACKETS_LOST_TYPE => Some(&rfc2867::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_TYPE_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_MEDIUM_TYPE_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_CLIENT_ENDPOINT_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_SERVER_ENDPOINT_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_PASSWORD_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[4]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_PRIVATE_GROUP_ID_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[5]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_ASSIGNMENT_ID_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[6]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_PREFERENCE_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[7]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_CLIENT_AUTH_ID_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[8]),
+        #[cfg(feature = "rfc2868")]
+        rfc2868::TUNNEL_SERVER_AUTH_ID_TYPE => Some(&rfc2868::ATTRIBUTE_TABLE[9]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ACCT_INPUT_GIGAWORDS_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ACCT_OUTPUT_GIGAWORDS_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::EVENT_TIMESTAMP_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_PASSWORD_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_FEATURES_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[4]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_ZONE_ACCESS_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[5]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_SECURITY_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[6]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_SECURITY_DATA_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[7]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::PASSWORD_RETRY_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[8]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::PROMPT_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[9]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::CONNECT_INFO_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[10]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::CONFIGURATION_TOKEN_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[11]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::EAP_MESSAGE_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[12]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::MESSAGE_AUTHENTICATOR_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[13]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ARAP_CHALLENGE_RESPONSE_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[14]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::ACCT_INTERIM_INTERVAL_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[15]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::NAS_PORT_ID_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[16]),
+        #[cfg(feature = "rfc2869")]
+        rfc2869::FRAMED_POOL_TYPE => Some(&rfc2869::ATTRIBUTE_TABLE[17]),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::NAS_IPV6_ADDRESS_TYPE => Some(&rfc3162::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::FRAMED_INTERFACE_ID_TYPE => Some(&rfc3162::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::FRAMED_IPV6_PREFIX_TYPE => Some(&rfc3162::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::LOGIN_IPV6_HOST_TYPE => Some(&rfc3162::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::FRAMED_IPV6_ROUTE_TYPE => Some(&rfc3162::ATTRIBUTE_TABLE[4]),
+        #[cfg(feature = "rfc3162")]
+        rfc3162::FRAMED_IPV6_POOL_TYPE => Some(&rfc3162::ATTRIBUTE_TABLE[5]),
+        #[cfg(feature = "rfc3576")]
+        rfc3576::ERROR_CAUSE_TYPE => Some(&rfc3576::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc4072")]
+        rfc4072::EAP_KEY_NAME_TYPE => Some(&rfc4072::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc4372")]
+        rfc4372::CHARGEABLE_USER_IDENTITY_TYPE => Some(&rfc4372::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc4675")]
+        rfc4675::EGRESS_VLANID_TYPE => Some(&rfc4675::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc4675")]
+        rfc4675::INGRESS_FILTERS_TYPE => Some(&rfc4675::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc4675")]
+        rfc4675::EGRESS_VLAN_NAME_TYPE => Some(&rfc4675::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc4675")]
+        rfc4675::USER_PRIORITY_TABLE_TYPE => Some(&rfc4675::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc4818")]
+        rfc4818::DELEGATED_IPV6_PREFIX_TYPE => Some(&rfc4818::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc4849")]
+        rfc4849::NAS_FILTER_RULE_TYPE => Some(&rfc4849::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_RESPONSE_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_REALM_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_NONCE_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_RESPONSE_AUTH_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_NEXTNONCE_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[4]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_METHOD_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[5]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_URI_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[6]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_QOP_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[7]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_ALGORITHM_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[8]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_ENTITY_BODY_HASH_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[9]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_C_NONCE_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[10]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_NONCE_COUNT_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[11]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_USERNAME_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[12]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_OPAQUE_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[13]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_AUTH_PARAM_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[14]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_AKA_AUTS_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[15]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_DOMAIN_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[16]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_STALE_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[17]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::DIGEST_HA1_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[18]),
+        #[cfg(feature = "rfc5090")]
+        rfc5090::SIP_AOR_TYPE => Some(&rfc5090::ATTRIBUTE_TABLE[19]),
+        #[cfg(feature = "rfc5607")]
+        rfc5607::FRAMED_MANAGEMENT_TYPE => Some(&rfc5607::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc5607")]
+        rfc5607::MANAGEMENT_TRANSPORT_PROTECTION_TYPE => Some(&rfc5607::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc5607")]
+        rfc5607::MANAGEMENT_POLICY_ID_TYPE => Some(&rfc5607::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc5607")]
+        rfc5607::MANAGEMENT_PRIVILEGE_LEVEL_TYPE => Some(&rfc5607::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_SS_CERT_TYPE => Some(&rfc5904::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_CA_CERT_TYPE => Some(&rfc5904::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_CONFIG_SETTINGS_TYPE => Some(&rfc5904::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_CRYPTOSUITE_LIST_TYPE => Some(&rfc5904::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_SAID_TYPE => Some(&rfc5904::ATTRIBUTE_TABLE[4]),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_SA_DESCRIPTOR_TYPE => Some(&rfc5904::ATTRIBUTE_TABLE[5]),
+        #[cfg(feature = "rfc5904")]
+        rfc5904::PKM_AUTH_KEY_TYPE => Some(&rfc5904::ATTRIBUTE_TABLE[6]),
+        #[cfg(feature = "rfc6519")]
+        rfc6519::DS_LITE_TUNNEL_NAME_TYPE => Some(&rfc6519::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::MOBILE_NODE_IDENTIFIER_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::SERVICE_SELECTION_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[4]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[5]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_HN_PREFIX_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[6]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_HN_PREFIX_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[7]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_INTERFACE_ID_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[8]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_INTERFACE_ID_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[9]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_IPV4_HO_A_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[10]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_IPV4_HO_A_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[11]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[12]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[13]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[14]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[15]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_HOME_IPV4_GATEWAY_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[16]),
+        #[cfg(feature = "rfc6572")]
+        rfc6572::PMIP6_VISITED_IPV4_GATEWAY_TYPE => Some(&rfc6572::ATTRIBUTE_TABLE[17]),
+        #[cfg(feature = "rfc6677")]
+        rfc6677::EAP_LOWER_LAYER_TYPE => Some(&rfc6677::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::FRAMED_IPV6_ADDRESS_TYPE => Some(&rfc6911::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::DNS_SERVER_IPV6_ADDRESS_TYPE => Some(&rfc6911::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::ROUTE_IPV6_INFORMATION_TYPE => Some(&rfc6911::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::DELEGATED_IPV6_PREFIX_POOL_TYPE => Some(&rfc6911::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc6911")]
+        rfc6911::STATEFUL_IPV6_ADDRESS_POOL_TYPE => Some(&rfc6911::ATTRIBUTE_TABLE[4]),
+        #[cfg(feature = "rfc7055")]
+        rfc7055::GSS_ACCEPTOR_SERVICE_NAME_TYPE => Some(&rfc7055::ATTRIBUTE_TABLE[0]),
+        #[cfg(feature = "rfc7055")]
+        rfc7055::GSS_ACCEPTOR_HOST_NAME_TYPE => Some(&rfc7055::ATTRIBUTE_TABLE[1]),
+        #[cfg(feature = "rfc7055")]
+        rfc7055::GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE => Some(&rfc7055::ATTRIBUTE_TABLE[2]),
+        #[cfg(feature = "rfc7055")]
+        rfc7055::GSS_ACCEPTOR_REALM_NAME_TYPE => Some(&rfc7055::ATTRIBUTE_TABLE[3]),
+        #[cfg(feature = "rfc7155")]
+        rfc7155::ORIGINATING_LINE_INFO_TYPE => Some(&rfc7155::ATTRIBUTE_TABLE[0]),
+        _ => None,
+    }
+}
+
+/// Returns the name of the value (e.g. `Framed-User` of `Service-Type`) for the given AVP type and value.
+///
+/// If the value is not named in any dictionary, it returns `None`.
+pub fn value_name(typ: AVPType, value: u32) -> Option<&'static str> {
+    VALUE_TABLES
+        .iter()
+        .flat_map(|table| table.iter())
+        .find(|metadata| metadata.typ == typ && metadata.value == value)
+        .map(|metadata| metadata.name)
+}
+
+/// Returns the value for the given AVP type and the name of the value (e.g. `Framed-User` of `Service-Type`).
+///
+/// If the value is not named in any dictionary, it returns `None`.
+pub fn value_by_name(typ: AVPType, name: &str) -> Option<u32> {
+    VALUE_TABLES
+        .iter()
+        .flat_map(|table| table.iter())
+        .find(|metadata| metadata.typ == typ && metadata.name == name)
+        .map(|metadata| metadata.value)
+}
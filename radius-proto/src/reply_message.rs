@@ -0,0 +1,127 @@
+//! Helpers of `Reply-Message` as a multi-line text.
+//!
+//! A text that is longer than an attribute (i.e. 253 bytes) is split into the multiple `Reply-Message` attributes,
+//! and those are displayed in order by the NAS (see also: https://tools.ietf.org/html/rfc2865#section-5.18).
+//! The text is split at the line breaks where possible, and never in a UTF-8 character,
+//! so that each attribute is a valid UTF-8 string on its own.
+
+use crate::avp::{AVPError, AVP};
+use crate::packet::Packet;
+use crate::rfc2865::REPLY_MESSAGE_TYPE;
+use crate::util::truncate_utf8;
+
+const MAX_REPLY_MESSAGE_LENGTH: usize = 253;
+
+/// Set a text as `Reply-Message` of a packet, replacing the existing `Reply-Message` attributes.
+///
+/// The text is split into the attributes of up to 253 bytes; the empty text deletes `Reply-Message`.
+pub fn set_reply_message_text(packet: &mut Packet, text: &str) {
+    packet.delete(REPLY_MESSAGE_TYPE);
+    for chunk in split_text(text, MAX_REPLY_MESSAGE_LENGTH) {
+        packet.add(AVP::from_string(REPLY_MESSAGE_TYPE, chunk));
+    }
+}
+
+/// Lookup the text of the `Reply-Message` attributes of a packet, which are joined in order.
+///
+/// If there is no `Reply-Message`, it returns `None`. The attributes are joined before decoding,
+/// so the text that the sender has split in a UTF-8 character is also decoded.
+pub fn lookup_reply_message_text(packet: &Packet) -> Option<Result<String, AVPError>> {
+    let avps = packet.lookup_all(REPLY_MESSAGE_TYPE);
+    if avps.is_empty() {
+        return None;
+    }
+    let bs: Vec<u8> = avps
+        .iter()
+        .flat_map(|avp| avp.raw_value().iter().copied())
+        .collect();
+    Some(
+        String::from_utf8(bs).map_err(|e| {
+            AVPError::DecodingError(e.to_string()).with_attribute_name("Reply-Message")
+        }),
+    )
+}
+
+/// Lookup the lines of the text of the `Reply-Message` attributes of a packet; see also `lookup_reply_message_text()`.
+pub fn lookup_reply_message_lines(packet: &Packet) -> Option<Result<Vec<String>, AVPError>> {
+    lookup_reply_message_text(packet)
+        .map(|text| text.map(|text| text.lines().map(|line| line.to_owned()).collect()))
+}
+
+/// Splits a text into the chunks of up to `max_len` bytes at the char boundaries,
+/// preferring the end of a line for each chunk.
+fn split_text(text: &str, max_len: usize) -> Vec<&str> {
+    let mut chunks = vec![];
+    let mut rest = text;
+    while rest.len() > max_len {
+        let mut end = truncate_utf8(rest, max_len).len();
+        if let Some(newline) = rest[..end].rfind('\n') {
+            end = newline + 1;
+        }
+        chunks.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::avp::AVP;
+    use crate::code::Code;
+    use crate::packet::Packet;
+    use crate::reply_message::{
+        lookup_reply_message_lines, lookup_reply_message_text, set_reply_message_text, split_text,
+    };
+    use crate::rfc2865;
+
+    #[test]
+    fn test_split_text() {
+        assert_eq!(split_text("", 4), Vec::<&str>::new());
+        assert_eq!(split_text("abcd", 4), vec!["abcd"]);
+        assert_eq!(split_text("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        // splits at the end of a line
+        assert_eq!(split_text("ab\ncdef\ng", 4), vec!["ab\n", "cdef", "\ng"]);
+        // never splits a character; "あ" is 3 bytes
+        assert_eq!(split_text("aあいう", 4), vec!["aあ", "い", "う"]);
+    }
+
+    #[test]
+    fn test_reply_message_text() {
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        assert!(lookup_reply_message_text(&packet).is_none());
+
+        rfc2865::add_reply_message(&mut packet, "old");
+        let text = format!(
+            "Welcome to the guest network.\n{}\n{}",
+            "Please read the terms of use. ".repeat(10),
+            "ご利用ありがとうございます。".repeat(10)
+        );
+        set_reply_message_text(&mut packet, &text);
+
+        let messages = rfc2865::lookup_all_reply_message(&packet).unwrap();
+        assert_eq!(messages.len(), 5);
+        assert_eq!(messages[0], "Welcome to the guest network.\n");
+        assert!(messages.iter().all(|message| message.len() <= 253));
+        assert_eq!(lookup_reply_message_text(&packet).unwrap().unwrap(), text);
+        assert_eq!(
+            lookup_reply_message_lines(&packet).unwrap().unwrap(),
+            text.lines().collect::<Vec<&str>>()
+        );
+
+        // the text that is split in a character by the sender
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        let bs = "あ".as_bytes();
+        packet.add(AVP::from_bytes(rfc2865::REPLY_MESSAGE_TYPE, &bs[..1]).unwrap());
+        packet.add(AVP::from_bytes(rfc2865::REPLY_MESSAGE_TYPE, &bs[1..]).unwrap());
+        assert_eq!(lookup_reply_message_text(&packet).unwrap().unwrap(), "あ");
+
+        packet.add(AVP::from_bytes(rfc2865::REPLY_MESSAGE_TYPE, &[0xff]).unwrap());
+        assert!(lookup_reply_message_text(&packet).unwrap().is_err());
+
+        set_reply_message_text(&mut packet, "");
+        assert!(!rfc2865::has_reply_message(&packet));
+    }
+}
@@ -0,0 +1,124 @@
+//! The standard attributes of a request that identify the NAS, the port and the user.
+
+use std::net::IpAddr;
+
+use crate::avp::AVPError;
+use crate::packet::Packet;
+use crate::rfc2865;
+#[cfg(feature = "rfc2866")]
+use crate::rfc2866;
+#[cfg(feature = "rfc3162")]
+use crate::rfc3162;
+
+/// This struct represents the standard attributes of a request; each field is `None` if the request doesn't have that.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RequestInfo {
+    pub nas_identifier: Option<String>,
+    /// The value of `NAS-IP-Address`, or `NAS-IPv6-Address` if the request doesn't have that.
+    pub nas_ip_address: Option<IpAddr>,
+    pub calling_station_id: Option<String>,
+    pub called_station_id: Option<String>,
+    pub nas_port: Option<u32>,
+    pub nas_port_type: Option<rfc2865::NasPortType>,
+    pub user_name: Option<String>,
+    /// The value of `Acct-Session-Id`; that is always `None` without `rfc2866` feature.
+    pub acct_session_id: Option<String>,
+}
+
+impl Packet {
+    /// Returns the standard attributes of the request (e.g. `NAS-Identifier`, `Calling-Station-Id`, `User-Name`).
+    ///
+    /// It returns an error if any of those attributes cannot be decoded.
+    pub fn request_info(&self) -> Result<RequestInfo, AVPError> {
+        let nas_ip_address = match rfc2865::lookup_nas_ip_address(self).transpose()? {
+            Some(nas_ip_address) => Some(IpAddr::V4(nas_ip_address)),
+            None => self.lookup_nas_ipv6_address()?,
+        };
+
+        Ok(RequestInfo {
+            nas_identifier: rfc2865::lookup_nas_identifier(self).transpose()?,
+            nas_ip_address,
+            calling_station_id: rfc2865::lookup_calling_station_id(self).transpose()?,
+            called_station_id: rfc2865::lookup_called_station_id(self).transpose()?,
+            nas_port: rfc2865::lookup_nas_port(self).transpose()?,
+            nas_port_type: rfc2865::lookup_nas_port_type(self).transpose()?,
+            user_name: rfc2865::lookup_user_name(self).transpose()?,
+            acct_session_id: self.lookup_acct_session_id()?,
+        })
+    }
+
+    #[cfg(feature = "rfc3162")]
+    fn lookup_nas_ipv6_address(&self) -> Result<Option<IpAddr>, AVPError> {
+        Ok(rfc3162::lookup_nas_ipv6_address(self)
+            .transpose()?
+            .map(IpAddr::V6))
+    }
+
+    #[cfg(not(feature = "rfc3162"))]
+    fn lookup_nas_ipv6_address(&self) -> Result<Option<IpAddr>, AVPError> {
+        Ok(None)
+    }
+
+    #[cfg(feature = "rfc2866")]
+    fn lookup_acct_session_id(&self) -> Result<Option<String>, AVPError> {
+        rfc2866::lookup_acct_session_id(self).transpose()
+    }
+
+    #[cfg(not(feature = "rfc2866"))]
+    fn lookup_acct_session_id(&self) -> Result<Option<String>, AVPError> {
+        Ok(None)
+    }
+}
+
+#[cfg(all(test, feature = "rfc2866", feature = "rfc3162"))]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::avp::AVP;
+    use crate::code::Code;
+    use crate::packet::Packet;
+    use crate::request_info::RequestInfo;
+    use crate::{rfc2865, rfc2866, rfc3162};
+
+    #[test]
+    fn test_request_info() {
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        assert_eq!(packet.request_info().unwrap(), RequestInfo::default());
+
+        rfc2865::add_nas_identifier(&mut packet, "nas1");
+        rfc2865::add_nas_ip_address(&mut packet, &Ipv4Addr::new(192, 0, 2, 1));
+        rfc3162::add_nas_ipv6_address(&mut packet, &Ipv6Addr::LOCALHOST);
+        rfc2865::add_calling_station_id(&mut packet, "00-00-5E-00-53-01");
+        rfc2865::add_called_station_id(&mut packet, "00-00-5E-00-53-00:guest");
+        rfc2865::add_nas_port(&mut packet, 10);
+        rfc2865::add_nas_port_type(&mut packet, rfc2865::NasPortType::Wireless80211);
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2866::add_acct_session_id(&mut packet, "session-1");
+
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        assert_eq!(
+            decoded.request_info().unwrap(),
+            RequestInfo {
+                nas_identifier: Some("nas1".to_owned()),
+                nas_ip_address: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+                calling_station_id: Some("00-00-5E-00-53-01".to_owned()),
+                called_station_id: Some("00-00-5E-00-53-00:guest".to_owned()),
+                nas_port: Some(10),
+                nas_port_type: Some(rfc2865::NasPortType::Wireless80211),
+                user_name: Some("alice".to_owned()),
+                acct_session_id: Some("session-1".to_owned()),
+            }
+        );
+
+        // NAS-IPv6-Address is the NAS IP address of the request without NAS-IP-Address
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc3162::add_nas_ipv6_address(&mut packet, &Ipv6Addr::LOCALHOST);
+        assert_eq!(
+            packet.request_info().unwrap().nas_ip_address,
+            Some(IpAddr::V6(Ipv6Addr::LOCALHOST))
+        );
+
+        packet.add(AVP::from_bytes(rfc2865::NAS_PORT_TYPE, &[0, 1]).unwrap());
+        assert!(packet.request_info().is_err());
+    }
+}
@@ -0,0 +1,3050 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc2865 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 2865.
+//! #    http://www.ietf.org/rfc/rfc2865.txt
+//! #
+//! #    `max_occurs` is a radius-rs specific option that is derived from
+//! #    "5.44. Table of Attributes" of the RFC.
+//! #
+//! #    $Id$
+//! #
+//! ATTRIBUTE    User-Name                1    string    max_occurs=1
+//! ATTRIBUTE    User-Password                2    string    encrypt=1,max_occurs=1
+//! ATTRIBUTE    CHAP-Password                3    octets    max_occurs=1
+//! ATTRIBUTE    NAS-IP-Address                4    ipaddr    max_occurs=1
+//! ATTRIBUTE    NAS-Port                5    integer    max_occurs=1
+//! ATTRIBUTE    Service-Type                6    integer    max_occurs=1
+//! ATTRIBUTE    Framed-Protocol                7    integer    max_occurs=1
+//! ATTRIBUTE    Framed-IP-Address            8    ipaddr    max_occurs=1
+//! ATTRIBUTE    Framed-IP-Netmask            9    ipaddr    max_occurs=1
+//! ATTRIBUTE    Framed-Routing                10    integer    max_occurs=1
+//! ATTRIBUTE    Filter-Id                11    string
+//! ATTRIBUTE    Framed-MTU                12    integer    max_occurs=1
+//! ATTRIBUTE    Framed-Compression            13    integer
+//! ATTRIBUTE    Login-IP-Host                14    ipaddr
+//! ATTRIBUTE    Login-Service                15    integer    max_occurs=1
+//! ATTRIBUTE    Login-TCP-Port                16    integer    max_occurs=1
+//! # Attribute 17 is undefined
+//! ATTRIBUTE    Reply-Message                18    string
+//! ATTRIBUTE    Callback-Number                19    string    max_occurs=1
+//! ATTRIBUTE    Callback-Id                20    string    max_occurs=1
+//! # Attribute 21 is undefined
+//! ATTRIBUTE    Framed-Route                22    string
+//! ATTRIBUTE    Framed-IPX-Network            23    ipaddr    max_occurs=1
+//! ATTRIBUTE    State                    24    octets    max_occurs=1
+//! ATTRIBUTE    Class                    25    octets
+//! ATTRIBUTE    Vendor-Specific                26    vsa
+//! ATTRIBUTE    Session-Timeout                27    integer    max_occurs=1,duration
+//! ATTRIBUTE    Idle-Timeout                28    integer    max_occurs=1,duration
+//! ATTRIBUTE    Termination-Action            29    integer    max_occurs=1
+//! ATTRIBUTE    Called-Station-Id            30    string    max_occurs=1
+//! ATTRIBUTE    Calling-Station-Id            31    string    max_occurs=1
+//! ATTRIBUTE    NAS-Identifier                32    string    max_occurs=1
+//! ATTRIBUTE    Proxy-State                33    octets
+//! ATTRIBUTE    Login-LAT-Service            34    string    max_occurs=1
+//! ATTRIBUTE    Login-LAT-Node                35    string    max_occurs=1
+//! ATTRIBUTE    Login-LAT-Group                36    octets    max_occurs=1
+//! ATTRIBUTE    Framed-AppleTalk-Link            37    integer    max_occurs=1
+//! ATTRIBUTE    Framed-AppleTalk-Network        38    integer
+//! ATTRIBUTE    Framed-AppleTalk-Zone            39    string    max_occurs=1
+//!
+//! ATTRIBUTE    CHAP-Challenge                60    octets    max_occurs=1
+//! ATTRIBUTE    NAS-Port-Type                61    integer    max_occurs=1
+//! ATTRIBUTE    Port-Limit                62    integer    max_occurs=1
+//! ATTRIBUTE    Login-LAT-Port                63    string    max_occurs=1
+//!
+//! #
+//! #    Integer Translations
+//! #
+//!
+//! #    Service types
+//!
+//! VALUE    Service-Type            Login-User        1
+//! VALUE    Service-Type            Framed-User        2
+//! VALUE    Service-Type            Callback-Login-User    3
+//! VALUE    Service-Type            Callback-Framed-User    4
+//! VALUE    Service-Type            Outbound-User        5
+//! VALUE    Service-Type            Administrative-User    6
+//! VALUE    Service-Type            NAS-Prompt-User        7
+//! VALUE    Service-Type            Authenticate-Only    8
+//! VALUE    Service-Type            Callback-NAS-Prompt    9
+//! VALUE    Service-Type            Call-Check        10
+//! VALUE    Service-Type            Callback-Administrative    11
+//!
+//! #    Framed Protocols
+//!
+//! VALUE    Framed-Protocol            PPP            1
+//! VALUE    Framed-Protocol            SLIP            2
+//! VALUE    Framed-Protocol            ARAP            3
+//! VALUE    Framed-Protocol            Gandalf-SLML        4
+//! VALUE    Framed-Protocol            Xylogics-IPX-SLIP    5
+//! VALUE    Framed-Protocol            X.75-Synchronous    6
+//!
+//! #    Framed Routing Values
+//!
+//! VALUE    Framed-Routing            None            0
+//! VALUE    Framed-Routing            Broadcast        1
+//! VALUE    Framed-Routing            Listen            2
+//! VALUE    Framed-Routing            Broadcast-Listen    3
+//!
+//! #    Framed Compression Types
+//!
+//! VALUE    Framed-Compression        None            0
+//! VALUE    Framed-Compression        Van-Jacobson-TCP-IP    1
+//! VALUE    Framed-Compression        IPX-Header-Compression    2
+//! VALUE    Framed-Compression        Stac-LZS        3
+//!
+//! #    Login Services
+//!
+//! VALUE    Login-Service            Telnet            0
+//! VALUE    Login-Service            Rlogin            1
+//! VALUE    Login-Service            TCP-Clear        2
+//! VALUE    Login-Service            PortMaster        3
+//! VALUE    Login-Service            LAT            4
+//! VALUE    Login-Service            X25-PAD            5
+//! VALUE    Login-Service            X25-T3POS        6
+//! VALUE    Login-Service            TCP-Clear-Quiet        8
+//!
+//! #    Login-TCP-Port        (see /etc/services for more examples)
+//!
+//! VALUE    Login-TCP-Port            Telnet            23
+//! VALUE    Login-TCP-Port            Rlogin            513
+//! VALUE    Login-TCP-Port            Rsh            514
+//!
+//! #    Termination Options
+//!
+//! VALUE    Termination-Action        Default            0
+//! VALUE    Termination-Action        RADIUS-Request        1
+//!
+//! #    NAS Port Types
+//!
+//! VALUE    NAS-Port-Type            Async            0
+//! VALUE    NAS-Port-Type            Sync            1
+//! VALUE    NAS-Port-Type            ISDN            2
+//! VALUE    NAS-Port-Type            ISDN-V120        3
+//! VALUE    NAS-Port-Type            ISDN-V110        4
+//! VALUE    NAS-Port-Type            Virtual            5
+//! VALUE    NAS-Port-Type            PIAFS            6
+//! VALUE    NAS-Port-Type            HDLC-Clear-Channel    7
+//! VALUE    NAS-Port-Type            X.25            8
+//! VALUE    NAS-Port-Type            X.75            9
+//! VALUE    NAS-Port-Type            G.3-Fax            10
+//! VALUE    NAS-Port-Type            SDSL            11
+//! VALUE    NAS-Port-Type            ADSL-CAP        12
+//! VALUE    NAS-Port-Type            ADSL-DMT        13
+//! VALUE    NAS-Port-Type            IDSL            14
+//! VALUE    NAS-Port-Type            Ethernet        15
+//! VALUE    NAS-Port-Type            xDSL            16
+//! VALUE    NAS-Port-Type            Cable            17
+//! VALUE    NAS-Port-Type            Wireless-Other        18
+//! VALUE    NAS-Port-Type            Wireless-802.11        19
+//! ```
+
+use std::net::Ipv4Addr;
+use std::time::Duration;
+
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+pub const USER_NAME_TYPE: AVPType = 1;
+/// Delete all of `user_name` values from a packet.
+pub fn delete_user_name(packet: &mut Packet) {
+    packet.delete(USER_NAME_TYPE);
+}
+/// Returns whether a packet has `user_name` without decoding the value.
+pub fn has_user_name(packet: &Packet) -> bool {
+    packet.contains(USER_NAME_TYPE)
+}
+/// Returns the number of `user_name` attributes in a packet.
+pub fn count_user_name(packet: &Packet) -> usize {
+    packet.count(USER_NAME_TYPE)
+}
+/// Add `user_name` string value to a packet.
+pub fn add_user_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(USER_NAME_TYPE, value));
+}
+/// Lookup a `user_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `user_name`, it returns `None`.
+pub fn lookup_user_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(USER_NAME_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("User-Name"))
+    })
+}
+/// Lookup all of the `user_name` string value from a packet.
+pub fn lookup_all_user_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(USER_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("User-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const USER_PASSWORD_TYPE: AVPType = 2;
+/// Delete all of `user_password` values from a packet.
+pub fn delete_user_password(packet: &mut Packet) {
+    packet.delete(USER_PASSWORD_TYPE);
+}
+/// Returns whether a packet has `user_password` without decoding the value.
+pub fn has_user_password(packet: &Packet) -> bool {
+    packet.contains(USER_PASSWORD_TYPE)
+}
+/// Returns the number of `user_password` attributes in a packet.
+pub fn count_user_password(packet: &Packet) -> usize {
+    packet.count(USER_PASSWORD_TYPE)
+}
+/// Add `user_password` user-password value to a packet.
+pub fn add_user_password(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_user_password(
+        USER_PASSWORD_TYPE,
+        value,
+        packet.get_secret(),
+        packet.get_authenticator(),
+    )?);
+    Ok(())
+}
+/// Lookup a `user_password` user-password value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `user_password`, it returns `None`.
+pub fn lookup_user_password(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
+    packet.lookup(USER_PASSWORD_TYPE).map(|v| {
+        v.decode_user_password(packet.get_secret(), packet.get_authenticator())
+            .map_err(|e| e.with_attribute_name("User-Password"))
+    })
+}
+/// Lookup all of the `user_password` user-password value from a packet.
+pub fn lookup_all_user_password(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(USER_PASSWORD_TYPE) {
+        vec.push(
+            avp.decode_user_password(packet.get_secret(), packet.get_authenticator())
+                .map_err(|e| e.with_attribute_name("User-Password"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const CHAP_PASSWORD_TYPE: AVPType = 3;
+/// Delete all of `chap_password` values from a packet.
+pub fn delete_chap_password(packet: &mut Packet) {
+    packet.delete(CHAP_PASSWORD_TYPE);
+}
+/// Returns whether a packet has `chap_password` without decoding the value.
+pub fn has_chap_password(packet: &Packet) -> bool {
+    packet.contains(CHAP_PASSWORD_TYPE)
+}
+/// Returns the number of `chap_password` attributes in a packet.
+pub fn count_chap_password(packet: &Packet) -> usize {
+    packet.count(CHAP_PASSWORD_TYPE)
+}
+/// Add `chap_password` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_chap_password(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(CHAP_PASSWORD_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `chap_password` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `chap_password`, it returns `None`.
+pub fn lookup_chap_password(packet: &Packet) -> Option<Vec<u8>> {
+    packet.lookup(CHAP_PASSWORD_TYPE).map(|v| v.decode_bytes())
+}
+/// Lookup all of the `chap_password` octets value from a packet.
+pub fn lookup_all_chap_password(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CHAP_PASSWORD_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const NAS_IP_ADDRESS_TYPE: AVPType = 4;
+/// Delete all of `nas_ip_address` values from a packet.
+pub fn delete_nas_ip_address(packet: &mut Packet) {
+    packet.delete(NAS_IP_ADDRESS_TYPE);
+}
+/// Returns whether a packet has `nas_ip_address` without decoding the value.
+pub fn has_nas_ip_address(packet: &Packet) -> bool {
+    packet.contains(NAS_IP_ADDRESS_TYPE)
+}
+/// Returns the number of `nas_ip_address` attributes in a packet.
+pub fn count_nas_ip_address(packet: &Packet) -> usize {
+    packet.count(NAS_IP_ADDRESS_TYPE)
+}
+/// Add `nas_ip_address` ipaddr value to a packet.
+pub fn add_nas_ip_address(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add(AVP::from_ipv4(NAS_IP_ADDRESS_TYPE, value));
+}
+/// Lookup a `nas_ip_address` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `nas_ip_address`, it returns `None`.
+pub fn lookup_nas_ip_address(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet.lookup(NAS_IP_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("NAS-IP-Address"))
+    })
+}
+/// Lookup all of the `nas_ip_address` ipaddr value from a packet.
+pub fn lookup_all_nas_ip_address(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(NAS_IP_ADDRESS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("NAS-IP-Address"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const NAS_PORT_TYPE: AVPType = 5;
+/// Delete all of `nas_port` values from a packet.
+pub fn delete_nas_port(packet: &mut Packet) {
+    packet.delete(NAS_PORT_TYPE);
+}
+/// Returns whether a packet has `nas_port` without decoding the value.
+pub fn has_nas_port(packet: &Packet) -> bool {
+    packet.contains(NAS_PORT_TYPE)
+}
+/// Returns the number of `nas_port` attributes in a packet.
+pub fn count_nas_port(packet: &Packet) -> usize {
+    packet.count(NAS_PORT_TYPE)
+}
+/// Add `nas_port` integer value to a packet.
+pub fn add_nas_port(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(NAS_PORT_TYPE, value));
+}
+/// Lookup a `nas_port` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `nas_port`, it returns `None`.
+pub fn lookup_nas_port(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(NAS_PORT_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("NAS-Port"))
+    })
+}
+/// Lookup all of the `nas_port` integer value from a packet.
+pub fn lookup_all_nas_port(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(NAS_PORT_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("NAS-Port"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const SERVICE_TYPE_TYPE: AVPType = 6;
+/// Delete all of `service_type` values from a packet.
+pub fn delete_service_type(packet: &mut Packet) {
+    packet.delete(SERVICE_TYPE_TYPE);
+}
+/// Returns whether a packet has `service_type` without decoding the value.
+pub fn has_service_type(packet: &Packet) -> bool {
+    packet.contains(SERVICE_TYPE_TYPE)
+}
+/// Returns the number of `service_type` attributes in a packet.
+pub fn count_service_type(packet: &Packet) -> usize {
+    packet.count(SERVICE_TYPE_TYPE)
+}
+/// Add `service_type` value-defined integer value to a packet.
+pub fn add_service_type(packet: &mut Packet, value: ServiceType) {
+    packet.add(AVP::from_u32(SERVICE_TYPE_TYPE, value.to_u32()));
+}
+/// Lookup a `service_type` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `service_type`, it returns `None`.
+pub fn lookup_service_type(packet: &Packet) -> Option<Result<ServiceType, AVPError>> {
+    packet.lookup(SERVICE_TYPE_TYPE).map(|v| {
+        Ok(ServiceType::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Service-Type"))?,
+        ))
+    })
+}
+/// Lookup all of the `service_type` value-defined integer value from a packet.
+pub fn lookup_all_service_type(packet: &Packet) -> Result<Vec<ServiceType>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(SERVICE_TYPE_TYPE) {
+        vec.push(ServiceType::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Service-Type"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_PROTOCOL_TYPE: AVPType = 7;
+/// Delete all of `framed_protocol` values from a packet.
+pub fn delete_framed_protocol(packet: &mut Packet) {
+    packet.delete(FRAMED_PROTOCOL_TYPE);
+}
+/// Returns whether a packet has `framed_protocol` without decoding the value.
+pub fn has_framed_protocol(packet: &Packet) -> bool {
+    packet.contains(FRAMED_PROTOCOL_TYPE)
+}
+/// Returns the number of `framed_protocol` attributes in a packet.
+pub fn count_framed_protocol(packet: &Packet) -> usize {
+    packet.count(FRAMED_PROTOCOL_TYPE)
+}
+/// Add `framed_protocol` value-defined integer value to a packet.
+pub fn add_framed_protocol(packet: &mut Packet, value: FramedProtocol) {
+    packet.add(AVP::from_u32(FRAMED_PROTOCOL_TYPE, value.to_u32()));
+}
+/// Lookup a `framed_protocol` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_protocol`, it returns `None`.
+pub fn lookup_framed_protocol(packet: &Packet) -> Option<Result<FramedProtocol, AVPError>> {
+    packet.lookup(FRAMED_PROTOCOL_TYPE).map(|v| {
+        Ok(FramedProtocol::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-Protocol"))?,
+        ))
+    })
+}
+/// Lookup all of the `framed_protocol` value-defined integer value from a packet.
+pub fn lookup_all_framed_protocol(packet: &Packet) -> Result<Vec<FramedProtocol>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_PROTOCOL_TYPE) {
+        vec.push(FramedProtocol::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-Protocol"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_IP_ADDRESS_TYPE: AVPType = 8;
+/// Delete all of `framed_ip_address` values from a packet.
+pub fn delete_framed_ip_address(packet: &mut Packet) {
+    packet.delete(FRAMED_IP_ADDRESS_TYPE);
+}
+/// Returns whether a packet has `framed_ip_address` without decoding the value.
+pub fn has_framed_ip_address(packet: &Packet) -> bool {
+    packet.contains(FRAMED_IP_ADDRESS_TYPE)
+}
+/// Returns the number of `framed_ip_address` attributes in a packet.
+pub fn count_framed_ip_address(packet: &Packet) -> usize {
+    packet.count(FRAMED_IP_ADDRESS_TYPE)
+}
+/// Add `framed_ip_address` ipaddr value to a packet.
+pub fn add_framed_ip_address(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add(AVP::from_ipv4(FRAMED_IP_ADDRESS_TYPE, value));
+}
+/// Lookup a `framed_ip_address` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_ip_address`, it returns `None`.
+pub fn lookup_framed_ip_address(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet.lookup(FRAMED_IP_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("Framed-IP-Address"))
+    })
+}
+/// Lookup all of the `framed_ip_address` ipaddr value from a packet.
+pub fn lookup_all_framed_ip_address(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_IP_ADDRESS_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Framed-IP-Address"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_IP_NETMASK_TYPE: AVPType = 9;
+/// Delete all of `framed_ip_netmask` values from a packet.
+pub fn delete_framed_ip_netmask(packet: &mut Packet) {
+    packet.delete(FRAMED_IP_NETMASK_TYPE);
+}
+/// Returns whether a packet has `framed_ip_netmask` without decoding the value.
+pub fn has_framed_ip_netmask(packet: &Packet) -> bool {
+    packet.contains(FRAMED_IP_NETMASK_TYPE)
+}
+/// Returns the number of `framed_ip_netmask` attributes in a packet.
+pub fn count_framed_ip_netmask(packet: &Packet) -> usize {
+    packet.count(FRAMED_IP_NETMASK_TYPE)
+}
+/// Add `framed_ip_netmask` ipaddr value to a packet.
+pub fn add_framed_ip_netmask(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add(AVP::from_ipv4(FRAMED_IP_NETMASK_TYPE, value));
+}
+/// Lookup a `framed_ip_netmask` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_ip_netmask`, it returns `None`.
+pub fn lookup_framed_ip_netmask(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet.lookup(FRAMED_IP_NETMASK_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("Framed-IP-Netmask"))
+    })
+}
+/// Lookup all of the `framed_ip_netmask` ipaddr value from a packet.
+pub fn lookup_all_framed_ip_netmask(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_IP_NETMASK_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Framed-IP-Netmask"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_ROUTING_TYPE: AVPType = 10;
+/// Delete all of `framed_routing` values from a packet.
+pub fn delete_framed_routing(packet: &mut Packet) {
+    packet.delete(FRAMED_ROUTING_TYPE);
+}
+/// Returns whether a packet has `framed_routing` without decoding the value.
+pub fn has_framed_routing(packet: &Packet) -> bool {
+    packet.contains(FRAMED_ROUTING_TYPE)
+}
+/// Returns the number of `framed_routing` attributes in a packet.
+pub fn count_framed_routing(packet: &Packet) -> usize {
+    packet.count(FRAMED_ROUTING_TYPE)
+}
+/// Add `framed_routing` value-defined integer value to a packet.
+pub fn add_framed_routing(packet: &mut Packet, value: FramedRouting) {
+    packet.add(AVP::from_u32(FRAMED_ROUTING_TYPE, value.to_u32()));
+}
+/// Lookup a `framed_routing` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_routing`, it returns `None`.
+pub fn lookup_framed_routing(packet: &Packet) -> Option<Result<FramedRouting, AVPError>> {
+    packet.lookup(FRAMED_ROUTING_TYPE).map(|v| {
+        Ok(FramedRouting::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-Routing"))?,
+        ))
+    })
+}
+/// Lookup all of the `framed_routing` value-defined integer value from a packet.
+pub fn lookup_all_framed_routing(packet: &Packet) -> Result<Vec<FramedRouting>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_ROUTING_TYPE) {
+        vec.push(FramedRouting::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-Routing"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const FILTER_ID_TYPE: AVPType = 11;
+/// Delete all of `filter_id` values from a packet.
+pub fn delete_filter_id(packet: &mut Packet) {
+    packet.delete(FILTER_ID_TYPE);
+}
+/// Returns whether a packet has `filter_id` without decoding the value.
+pub fn has_filter_id(packet: &Packet) -> bool {
+    packet.contains(FILTER_ID_TYPE)
+}
+/// Returns the number of `filter_id` attributes in a packet.
+pub fn count_filter_id(packet: &Packet) -> usize {
+    packet.count(FILTER_ID_TYPE)
+}
+/// Add `filter_id` string value to a packet.
+pub fn add_filter_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(FILTER_ID_TYPE, value));
+}
+/// Lookup a `filter_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `filter_id`, it returns `None`.
+pub fn lookup_filter_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(FILTER_ID_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Filter-Id"))
+    })
+}
+/// Lookup all of the `filter_id` string value from a packet.
+pub fn lookup_all_filter_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FILTER_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Filter-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_MTU_TYPE: AVPType = 12;
+/// Delete all of `framed_mtu` values from a packet.
+pub fn delete_framed_mtu(packet: &mut Packet) {
+    packet.delete(FRAMED_MTU_TYPE);
+}
+/// Returns whether a packet has `framed_mtu` without decoding the value.
+pub fn has_framed_mtu(packet: &Packet) -> bool {
+    packet.contains(FRAMED_MTU_TYPE)
+}
+/// Returns the number of `framed_mtu` attributes in a packet.
+pub fn count_framed_mtu(packet: &Packet) -> usize {
+    packet.count(FRAMED_MTU_TYPE)
+}
+/// Add `framed_mtu` integer value to a packet.
+pub fn add_framed_mtu(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(FRAMED_MTU_TYPE, value));
+}
+/// Lookup a `framed_mtu` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_mtu`, it returns `None`.
+pub fn lookup_framed_mtu(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(FRAMED_MTU_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Framed-MTU"))
+    })
+}
+/// Lookup all of the `framed_mtu` integer value from a packet.
+pub fn lookup_all_framed_mtu(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_MTU_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-MTU"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_COMPRESSION_TYPE: AVPType = 13;
+/// Delete all of `framed_compression` values from a packet.
+pub fn delete_framed_compression(packet: &mut Packet) {
+    packet.delete(FRAMED_COMPRESSION_TYPE);
+}
+/// Returns whether a packet has `framed_compression` without decoding the value.
+pub fn has_framed_compression(packet: &Packet) -> bool {
+    packet.contains(FRAMED_COMPRESSION_TYPE)
+}
+/// Returns the number of `framed_compression` attributes in a packet.
+pub fn count_framed_compression(packet: &Packet) -> usize {
+    packet.count(FRAMED_COMPRESSION_TYPE)
+}
+/// Add `framed_compression` value-defined integer value to a packet.
+pub fn add_framed_compression(packet: &mut Packet, value: FramedCompression) {
+    packet.add(AVP::from_u32(FRAMED_COMPRESSION_TYPE, value.to_u32()));
+}
+/// Lookup a `framed_compression` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_compression`, it returns `None`.
+pub fn lookup_framed_compression(packet: &Packet) -> Option<Result<FramedCompression, AVPError>> {
+    packet.lookup(FRAMED_COMPRESSION_TYPE).map(|v| {
+        Ok(FramedCompression::from_u32(v.decode_u32().map_err(
+            |e| e.with_attribute_name("Framed-Compression"),
+        )?))
+    })
+}
+/// Lookup all of the `framed_compression` value-defined integer value from a packet.
+pub fn lookup_all_framed_compression(packet: &Packet) -> Result<Vec<FramedCompression>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_COMPRESSION_TYPE) {
+        vec.push(FramedCompression::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-Compression"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const LOGIN_IP_HOST_TYPE: AVPType = 14;
+/// Delete all of `login_ip_host` values from a packet.
+pub fn delete_login_ip_host(packet: &mut Packet) {
+    packet.delete(LOGIN_IP_HOST_TYPE);
+}
+/// Returns whether a packet has `login_ip_host` without decoding the value.
+pub fn has_login_ip_host(packet: &Packet) -> bool {
+    packet.contains(LOGIN_IP_HOST_TYPE)
+}
+/// Returns the number of `login_ip_host` attributes in a packet.
+pub fn count_login_ip_host(packet: &Packet) -> usize {
+    packet.count(LOGIN_IP_HOST_TYPE)
+}
+/// Add `login_ip_host` ipaddr value to a packet.
+pub fn add_login_ip_host(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add(AVP::from_ipv4(LOGIN_IP_HOST_TYPE, value));
+}
+/// Lookup a `login_ip_host` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `login_ip_host`, it returns `None`.
+pub fn lookup_login_ip_host(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet.lookup(LOGIN_IP_HOST_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("Login-IP-Host"))
+    })
+}
+/// Lookup all of the `login_ip_host` ipaddr value from a packet.
+pub fn lookup_all_login_ip_host(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(LOGIN_IP_HOST_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Login-IP-Host"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const LOGIN_SERVICE_TYPE: AVPType = 15;
+/// Delete all of `login_service` values from a packet.
+pub fn delete_login_service(packet: &mut Packet) {
+    packet.delete(LOGIN_SERVICE_TYPE);
+}
+/// Returns whether a packet has `login_service` without decoding the value.
+pub fn has_login_service(packet: &Packet) -> bool {
+    packet.contains(LOGIN_SERVICE_TYPE)
+}
+/// Returns the number of `login_service` attributes in a packet.
+pub fn count_login_service(packet: &Packet) -> usize {
+    packet.count(LOGIN_SERVICE_TYPE)
+}
+/// Add `login_service` value-defined integer value to a packet.
+pub fn add_login_service(packet: &mut Packet, value: LoginService) {
+    packet.add(AVP::from_u32(LOGIN_SERVICE_TYPE, value.to_u32()));
+}
+/// Lookup a `login_service` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `login_service`, it returns `None`.
+pub fn lookup_login_service(packet: &Packet) -> Option<Result<LoginService, AVPError>> {
+    packet.lookup(LOGIN_SERVICE_TYPE).map(|v| {
+        Ok(LoginService::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Login-Service"))?,
+        ))
+    })
+}
+/// Lookup all of the `login_service` value-defined integer value from a packet.
+pub fn lookup_all_login_service(packet: &Packet) -> Result<Vec<LoginService>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(LOGIN_SERVICE_TYPE) {
+        vec.push(LoginService::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Login-Service"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const LOGIN_TCP_PORT_TYPE: AVPType = 16;
+/// Delete all of `login_tcp_port` values from a packet.
+pub fn delete_login_tcp_port(packet: &mut Packet) {
+    packet.delete(LOGIN_TCP_PORT_TYPE);
+}
+/// Returns whether a packet has `login_tcp_port` without decoding the value.
+pub fn has_login_tcp_port(packet: &Packet) -> bool {
+    packet.contains(LOGIN_TCP_PORT_TYPE)
+}
+/// Returns the number of `login_tcp_port` attributes in a packet.
+pub fn count_login_tcp_port(packet: &Packet) -> usize {
+    packet.count(LOGIN_TCP_PORT_TYPE)
+}
+/// Add `login_tcp_port` value-defined integer value to a packet.
+pub fn add_login_tcp_port(packet: &mut Packet, value: LoginTCPPort) {
+    packet.add(AVP::from_u32(LOGIN_TCP_PORT_TYPE, value.to_u32()));
+}
+/// Lookup a `login_tcp_port` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `login_tcp_port`, it returns `None`.
+pub fn lookup_login_tcp_port(packet: &Packet) -> Option<Result<LoginTCPPort, AVPError>> {
+    packet.lookup(LOGIN_TCP_PORT_TYPE).map(|v| {
+        Ok(LoginTCPPort::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Login-TCP-Port"))?,
+        ))
+    })
+}
+/// Lookup all of the `login_tcp_port` value-defined integer value from a packet.
+pub fn lookup_all_login_tcp_port(packet: &Packet) -> Result<Vec<LoginTCPPort>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(LOGIN_TCP_PORT_TYPE) {
+        vec.push(LoginTCPPort::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Login-TCP-Port"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const REPLY_MESSAGE_TYPE: AVPType = 18;
+/// Delete all of `reply_message` values from a packet.
+pub fn delete_reply_message(packet: &mut Packet) {
+    packet.delete(REPLY_MESSAGE_TYPE);
+}
+/// Returns whether a packet has `reply_message` without decoding the value.
+pub fn has_reply_message(packet: &Packet) -> bool {
+    packet.contains(REPLY_MESSAGE_TYPE)
+}
+/// Returns the number of `reply_message` attributes in a packet.
+pub fn count_reply_message(packet: &Packet) -> usize {
+    packet.count(REPLY_MESSAGE_TYPE)
+}
+/// Add `reply_message` string value to a packet.
+pub fn add_reply_message(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(REPLY_MESSAGE_TYPE, value));
+}
+/// Lookup a `reply_message` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `reply_message`, it returns `None`.
+pub fn lookup_reply_message(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(REPLY_MESSAGE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Reply-Message"))
+    })
+}
+/// Lookup all of the `reply_message` string value from a packet.
+pub fn lookup_all_reply_message(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(REPLY_MESSAGE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Reply-Message"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const CALLBACK_NUMBER_TYPE: AVPType = 19;
+/// Delete all of `callback_number` values from a packet.
+pub fn delete_callback_number(packet: &mut Packet) {
+    packet.delete(CALLBACK_NUMBER_TYPE);
+}
+/// Returns whether a packet has `callback_number` without decoding the value.
+pub fn has_callback_number(packet: &Packet) -> bool {
+    packet.contains(CALLBACK_NUMBER_TYPE)
+}
+/// Returns the number of `callback_number` attributes in a packet.
+pub fn count_callback_number(packet: &Packet) -> usize {
+    packet.count(CALLBACK_NUMBER_TYPE)
+}
+/// Add `callback_number` string value to a packet.
+pub fn add_callback_number(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(CALLBACK_NUMBER_TYPE, value));
+}
+/// Lookup a `callback_number` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `callback_number`, it returns `None`.
+pub fn lookup_callback_number(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(CALLBACK_NUMBER_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Callback-Number"))
+    })
+}
+/// Lookup all of the `callback_number` string value from a packet.
+pub fn lookup_all_callback_number(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CALLBACK_NUMBER_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Callback-Number"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const CALLBACK_ID_TYPE: AVPType = 20;
+/// Delete all of `callback_id` values from a packet.
+pub fn delete_callback_id(packet: &mut Packet) {
+    packet.delete(CALLBACK_ID_TYPE);
+}
+/// Returns whether a packet has `callback_id` without decoding the value.
+pub fn has_callback_id(packet: &Packet) -> bool {
+    packet.contains(CALLBACK_ID_TYPE)
+}
+/// Returns the number of `callback_id` attributes in a packet.
+pub fn count_callback_id(packet: &Packet) -> usize {
+    packet.count(CALLBACK_ID_TYPE)
+}
+/// Add `callback_id` string value to a packet.
+pub fn add_callback_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(CALLBACK_ID_TYPE, value));
+}
+/// Lookup a `callback_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `callback_id`, it returns `None`.
+pub fn lookup_callback_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(CALLBACK_ID_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Callback-Id"))
+    })
+}
+/// Lookup all of the `callback_id` string value from a packet.
+pub fn lookup_all_callback_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CALLBACK_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Callback-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_ROUTE_TYPE: AVPType = 22;
+/// Delete all of `framed_route` values from a packet.
+pub fn delete_framed_route(packet: &mut Packet) {
+    packet.delete(FRAMED_ROUTE_TYPE);
+}
+/// Returns whether a packet has `framed_route` without decoding the value.
+pub fn has_framed_route(packet: &Packet) -> bool {
+    packet.contains(FRAMED_ROUTE_TYPE)
+}
+/// Returns the number of `framed_route` attributes in a packet.
+pub fn count_framed_route(packet: &Packet) -> usize {
+    packet.count(FRAMED_ROUTE_TYPE)
+}
+/// Add `framed_route` string value to a packet.
+pub fn add_framed_route(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(FRAMED_ROUTE_TYPE, value));
+}
+/// Lookup a `framed_route` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_route`, it returns `None`.
+pub fn lookup_framed_route(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(FRAMED_ROUTE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Framed-Route"))
+    })
+}
+/// Lookup all of the `framed_route` string value from a packet.
+pub fn lookup_all_framed_route(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_ROUTE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Framed-Route"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_IPX_NETWORK_TYPE: AVPType = 23;
+/// Delete all of `framed_ipx_network` values from a packet.
+pub fn delete_framed_ipx_network(packet: &mut Packet) {
+    packet.delete(FRAMED_IPX_NETWORK_TYPE);
+}
+/// Returns whether a packet has `framed_ipx_network` without decoding the value.
+pub fn has_framed_ipx_network(packet: &Packet) -> bool {
+    packet.contains(FRAMED_IPX_NETWORK_TYPE)
+}
+/// Returns the number of `framed_ipx_network` attributes in a packet.
+pub fn count_framed_ipx_network(packet: &Packet) -> usize {
+    packet.count(FRAMED_IPX_NETWORK_TYPE)
+}
+/// Add `framed_ipx_network` ipaddr value to a packet.
+pub fn add_framed_ipx_network(packet: &mut Packet, value: &Ipv4Addr) {
+    packet.add(AVP::from_ipv4(FRAMED_IPX_NETWORK_TYPE, value));
+}
+/// Lookup a `framed_ipx_network` ipaddr value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_ipx_network`, it returns `None`.
+pub fn lookup_framed_ipx_network(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
+    packet.lookup(FRAMED_IPX_NETWORK_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("Framed-IPX-Network"))
+    })
+}
+/// Lookup all of the `framed_ipx_network` ipaddr value from a packet.
+pub fn lookup_all_framed_ipx_network(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_IPX_NETWORK_TYPE) {
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("Framed-IPX-Network"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const STATE_TYPE: AVPType = 24;
+/// Delete all of `state` values from a packet.
+pub fn delete_state(packet: &mut Packet) {
+    packet.delete(STATE_TYPE);
+}
+/// Returns whether a packet has `state` without decoding the value.
+pub fn has_state(packet: &Packet) -> bool {
+    packet.contains(STATE_TYPE)
+}
+/// Returns the number of `state` attributes in a packet.
+pub fn count_state(packet: &Packet) -> usize {
+    packet.count(STATE_TYPE)
+}
+/// Add `state` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_state(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(STATE_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `state` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `state`, it returns `None`.
+pub fn lookup_state(packet: &Packet) -> Option<Vec<u8>> {
+    packet.lookup(STATE_TYPE).map(|v| v.decode_bytes())
+}
+/// Lookup all of the `state` octets value from a packet.
+pub fn lookup_all_state(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(STATE_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const CLASS_TYPE: AVPType = 25;
+/// Delete all of `class` values from a packet.
+pub fn delete_class(packet: &mut Packet) {
+    packet.delete(CLASS_TYPE);
+}
+/// Returns whether a packet has `class` without decoding the value.
+pub fn has_class(packet: &Packet) -> bool {
+    packet.contains(CLASS_TYPE)
+}
+/// Returns the number of `class` attributes in a packet.
+pub fn count_class(packet: &Packet) -> usize {
+    packet.count(CLASS_TYPE)
+}
+/// Add `class` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_class(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(CLASS_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `class` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `class`, it returns `None`.
+pub fn lookup_class(packet: &Packet) -> Option<Vec<u8>> {
+    packet.lookup(CLASS_TYPE).map(|v| v.decode_bytes())
+}
+/// Lookup all of the `class` octets value from a packet.
+pub fn lookup_all_class(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CLASS_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const VENDOR_SPECIFIC_TYPE: AVPType = 26;
+/// Delete all of `vendor_specific` values from a packet.
+pub fn delete_vendor_specific(packet: &mut Packet) {
+    packet.delete(VENDOR_SPECIFIC_TYPE);
+}
+/// Returns whether a packet has `vendor_specific` without decoding the value.
+pub fn has_vendor_specific(packet: &Packet) -> bool {
+    packet.contains(VENDOR_SPECIFIC_TYPE)
+}
+/// Returns the number of `vendor_specific` attributes in a packet.
+pub fn count_vendor_specific(packet: &Packet) -> usize {
+    packet.count(VENDOR_SPECIFIC_TYPE)
+}
+
+pub const SESSION_TIMEOUT_TYPE: AVPType = 27;
+/// Delete all of `session_timeout` values from a packet.
+pub fn delete_session_timeout(packet: &mut Packet) {
+    packet.delete(SESSION_TIMEOUT_TYPE);
+}
+/// Returns whether a packet has `session_timeout` without decoding the value.
+pub fn has_session_timeout(packet: &Packet) -> bool {
+    packet.contains(SESSION_TIMEOUT_TYPE)
+}
+/// Returns the number of `session_timeout` attributes in a packet.
+pub fn count_session_timeout(packet: &Packet) -> usize {
+    packet.count(SESSION_TIMEOUT_TYPE)
+}
+/// Add `session_timeout` integer value to a packet.
+pub fn add_session_timeout(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(SESSION_TIMEOUT_TYPE, value));
+}
+/// Lookup a `session_timeout` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `session_timeout`, it returns `None`.
+pub fn lookup_session_timeout(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(SESSION_TIMEOUT_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Session-Timeout"))
+    })
+}
+/// Lookup all of the `session_timeout` integer value from a packet.
+pub fn lookup_all_session_timeout(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(SESSION_TIMEOUT_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Session-Timeout"))?,
+        )
+    }
+    Ok(vec)
+}
+/// Add `session_timeout` value to a packet as a duration.
+///
+/// The fractional part of a second is truncated. It returns an error if the duration exceeds `u32::MAX` seconds.
+pub fn add_session_timeout_duration(packet: &mut Packet, value: Duration) -> Result<(), AVPError> {
+    packet.add(AVP::from_duration(SESSION_TIMEOUT_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `session_timeout` value from a packet as a duration.
+///
+/// It returns the first looked up value. If there is no associated value with `session_timeout`, it returns `None`.
+pub fn lookup_session_timeout_duration(packet: &Packet) -> Option<Result<Duration, AVPError>> {
+    packet.lookup(SESSION_TIMEOUT_TYPE).map(|v| {
+        v.decode_duration()
+            .map_err(|e| e.with_attribute_name("Session-Timeout"))
+    })
+}
+
+pub const IDLE_TIMEOUT_TYPE: AVPType = 28;
+/// Delete all of `idle_timeout` values from a packet.
+pub fn delete_idle_timeout(packet: &mut Packet) {
+    packet.delete(IDLE_TIMEOUT_TYPE);
+}
+/// Returns whether a packet has `idle_timeout` without decoding the value.
+pub fn has_idle_timeout(packet: &Packet) -> bool {
+    packet.contains(IDLE_TIMEOUT_TYPE)
+}
+/// Returns the number of `idle_timeout` attributes in a packet.
+pub fn count_idle_timeout(packet: &Packet) -> usize {
+    packet.count(IDLE_TIMEOUT_TYPE)
+}
+/// Add `idle_timeout` integer value to a packet.
+pub fn add_idle_timeout(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(IDLE_TIMEOUT_TYPE, value));
+}
+/// Lookup a `idle_timeout` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `idle_timeout`, it returns `None`.
+pub fn lookup_idle_timeout(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(IDLE_TIMEOUT_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Idle-Timeout"))
+    })
+}
+/// Lookup all of the `idle_timeout` integer value from a packet.
+pub fn lookup_all_idle_timeout(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(IDLE_TIMEOUT_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Idle-Timeout"))?,
+        )
+    }
+    Ok(vec)
+}
+/// Add `idle_timeout` value to a packet as a duration.
+///
+/// The fractional part of a second is truncated. It returns an error if the duration exceeds `u32::MAX` seconds.
+pub fn add_idle_timeout_duration(packet: &mut Packet, value: Duration) -> Result<(), AVPError> {
+    packet.add(AVP::from_duration(IDLE_TIMEOUT_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `idle_timeout` value from a packet as a duration.
+///
+/// It returns the first looked up value. If there is no associated value with `idle_timeout`, it returns `None`.
+pub fn lookup_idle_timeout_duration(packet: &Packet) -> Option<Result<Duration, AVPError>> {
+    packet.lookup(IDLE_TIMEOUT_TYPE).map(|v| {
+        v.decode_duration()
+            .map_err(|e| e.with_attribute_name("Idle-Timeout"))
+    })
+}
+
+pub const TERMINATION_ACTION_TYPE: AVPType = 29;
+/// Delete all of `termination_action` values from a packet.
+pub fn delete_termination_action(packet: &mut Packet) {
+    packet.delete(TERMINATION_ACTION_TYPE);
+}
+/// Returns whether a packet has `termination_action` without decoding the value.
+pub fn has_termination_action(packet: &Packet) -> bool {
+    packet.contains(TERMINATION_ACTION_TYPE)
+}
+/// Returns the number of `termination_action` attributes in a packet.
+pub fn count_termination_action(packet: &Packet) -> usize {
+    packet.count(TERMINATION_ACTION_TYPE)
+}
+/// Add `termination_action` value-defined integer value to a packet.
+pub fn add_termination_action(packet: &mut Packet, value: TerminationAction) {
+    packet.add(AVP::from_u32(TERMINATION_ACTION_TYPE, value.to_u32()));
+}
+/// Lookup a `termination_action` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `termination_action`, it returns `None`.
+pub fn lookup_termination_action(packet: &Packet) -> Option<Result<TerminationAction, AVPError>> {
+    packet.lookup(TERMINATION_ACTION_TYPE).map(|v| {
+        Ok(TerminationAction::from_u32(v.decode_u32().map_err(
+            |e| e.with_attribute_name("Termination-Action"),
+        )?))
+    })
+}
+/// Lookup all of the `termination_action` value-defined integer value from a packet.
+pub fn lookup_all_termination_action(packet: &Packet) -> Result<Vec<TerminationAction>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TERMINATION_ACTION_TYPE) {
+        vec.push(TerminationAction::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Termination-Action"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const CALLED_STATION_ID_TYPE: AVPType = 30;
+/// Delete all of `called_station_id` values from a packet.
+pub fn delete_called_station_id(packet: &mut Packet) {
+    packet.delete(CALLED_STATION_ID_TYPE);
+}
+/// Returns whether a packet has `called_station_id` without decoding the value.
+pub fn has_called_station_id(packet: &Packet) -> bool {
+    packet.contains(CALLED_STATION_ID_TYPE)
+}
+/// Returns the number of `called_station_id` attributes in a packet.
+pub fn count_called_station_id(packet: &Packet) -> usize {
+    packet.count(CALLED_STATION_ID_TYPE)
+}
+/// Add `called_station_id` string value to a packet.
+pub fn add_called_station_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(CALLED_STATION_ID_TYPE, value));
+}
+/// Lookup a `called_station_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `called_station_id`, it returns `None`.
+pub fn lookup_called_station_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(CALLED_STATION_ID_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Called-Station-Id"))
+    })
+}
+/// Lookup all of the `called_station_id` string value from a packet.
+pub fn lookup_all_called_station_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CALLED_STATION_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Called-Station-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const CALLING_STATION_ID_TYPE: AVPType = 31;
+/// Delete all of `calling_station_id` values from a packet.
+pub fn delete_calling_station_id(packet: &mut Packet) {
+    packet.delete(CALLING_STATION_ID_TYPE);
+}
+/// Returns whether a packet has `calling_station_id` without decoding the value.
+pub fn has_calling_station_id(packet: &Packet) -> bool {
+    packet.contains(CALLING_STATION_ID_TYPE)
+}
+/// Returns the number of `calling_station_id` attributes in a packet.
+pub fn count_calling_station_id(packet: &Packet) -> usize {
+    packet.count(CALLING_STATION_ID_TYPE)
+}
+/// Add `calling_station_id` string value to a packet.
+pub fn add_calling_station_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(CALLING_STATION_ID_TYPE, value));
+}
+/// Lookup a `calling_station_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `calling_station_id`, it returns `None`.
+pub fn lookup_calling_station_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(CALLING_STATION_ID_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Calling-Station-Id"))
+    })
+}
+/// Lookup all of the `calling_station_id` string value from a packet.
+pub fn lookup_all_calling_station_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CALLING_STATION_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Calling-Station-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const NAS_IDENTIFIER_TYPE: AVPType = 32;
+/// Delete all of `nas_identifier` values from a packet.
+pub fn delete_nas_identifier(packet: &mut Packet) {
+    packet.delete(NAS_IDENTIFIER_TYPE);
+}
+/// Returns whether a packet has `nas_identifier` without decoding the value.
+pub fn has_nas_identifier(packet: &Packet) -> bool {
+    packet.contains(NAS_IDENTIFIER_TYPE)
+}
+/// Returns the number of `nas_identifier` attributes in a packet.
+pub fn count_nas_identifier(packet: &Packet) -> usize {
+    packet.count(NAS_IDENTIFIER_TYPE)
+}
+/// Add `nas_identifier` string value to a packet.
+pub fn add_nas_identifier(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(NAS_IDENTIFIER_TYPE, value));
+}
+/// Lookup a `nas_identifier` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `nas_identifier`, it returns `None`.
+pub fn lookup_nas_identifier(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(NAS_IDENTIFIER_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("NAS-Identifier"))
+    })
+}
+/// Lookup all of the `nas_identifier` string value from a packet.
+pub fn lookup_all_nas_identifier(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(NAS_IDENTIFIER_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("NAS-Identifier"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const PROXY_STATE_TYPE: AVPType = 33;
+/// Delete all of `proxy_state` values from a packet.
+pub fn delete_proxy_state(packet: &mut Packet) {
+    packet.delete(PROXY_STATE_TYPE);
+}
+/// Returns whether a packet has `proxy_state` without decoding the value.
+pub fn has_proxy_state(packet: &Packet) -> bool {
+    packet.contains(PROXY_STATE_TYPE)
+}
+/// Returns the number of `proxy_state` attributes in a packet.
+pub fn count_proxy_state(packet: &Packet) -> usize {
+    packet.count(PROXY_STATE_TYPE)
+}
+/// Add `proxy_state` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_proxy_state(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(PROXY_STATE_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `proxy_state` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `proxy_state`, it returns `None`.
+pub fn lookup_proxy_state(packet: &Packet) -> Option<Vec<u8>> {
+    packet.lookup(PROXY_STATE_TYPE).map(|v| v.decode_bytes())
+}
+/// Lookup all of the `proxy_state` octets value from a packet.
+pub fn lookup_all_proxy_state(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PROXY_STATE_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const LOGIN_LAT_SERVICE_TYPE: AVPType = 34;
+/// Delete all of `login_lat_service` values from a packet.
+pub fn delete_login_lat_service(packet: &mut Packet) {
+    packet.delete(LOGIN_LAT_SERVICE_TYPE);
+}
+/// Returns whether a packet has `login_lat_service` without decoding the value.
+pub fn has_login_lat_service(packet: &Packet) -> bool {
+    packet.contains(LOGIN_LAT_SERVICE_TYPE)
+}
+/// Returns the number of `login_lat_service` attributes in a packet.
+pub fn count_login_lat_service(packet: &Packet) -> usize {
+    packet.count(LOGIN_LAT_SERVICE_TYPE)
+}
+/// Add `login_lat_service` string value to a packet.
+pub fn add_login_lat_service(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(LOGIN_LAT_SERVICE_TYPE, value));
+}
+/// Lookup a `login_lat_service` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `login_lat_service`, it returns `None`.
+pub fn lookup_login_lat_service(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(LOGIN_LAT_SERVICE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Login-LAT-Service"))
+    })
+}
+/// Lookup all of the `login_lat_service` string value from a packet.
+pub fn lookup_all_login_lat_service(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(LOGIN_LAT_SERVICE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Login-LAT-Service"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const LOGIN_LAT_NODE_TYPE: AVPType = 35;
+/// Delete all of `login_lat_node` values from a packet.
+pub fn delete_login_lat_node(packet: &mut Packet) {
+    packet.delete(LOGIN_LAT_NODE_TYPE);
+}
+/// Returns whether a packet has `login_lat_node` without decoding the value.
+pub fn has_login_lat_node(packet: &Packet) -> bool {
+    packet.contains(LOGIN_LAT_NODE_TYPE)
+}
+/// Returns the number of `login_lat_node` attributes in a packet.
+pub fn count_login_lat_node(packet: &Packet) -> usize {
+    packet.count(LOGIN_LAT_NODE_TYPE)
+}
+/// Add `login_lat_node` string value to a packet.
+pub fn add_login_lat_node(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(LOGIN_LAT_NODE_TYPE, value));
+}
+/// Lookup a `login_lat_node` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `login_lat_node`, it returns `None`.
+pub fn lookup_login_lat_node(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(LOGIN_LAT_NODE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Login-LAT-Node"))
+    })
+}
+/// Lookup all of the `login_lat_node` string value from a packet.
+pub fn lookup_all_login_lat_node(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(LOGIN_LAT_NODE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Login-LAT-Node"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const LOGIN_LAT_GROUP_TYPE: AVPType = 36;
+/// Delete all of `login_lat_group` values from a packet.
+pub fn delete_login_lat_group(packet: &mut Packet) {
+    packet.delete(LOGIN_LAT_GROUP_TYPE);
+}
+/// Returns whether a packet has `login_lat_group` without decoding the value.
+pub fn has_login_lat_group(packet: &Packet) -> bool {
+    packet.contains(LOGIN_LAT_GROUP_TYPE)
+}
+/// Returns the number of `login_lat_group` attributes in a packet.
+pub fn count_login_lat_group(packet: &Packet) -> usize {
+    packet.count(LOGIN_LAT_GROUP_TYPE)
+}
+/// Add `login_lat_group` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_login_lat_group(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(LOGIN_LAT_GROUP_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `login_lat_group` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `login_lat_group`, it returns `None`.
+pub fn lookup_login_lat_group(packet: &Packet) -> Option<Vec<u8>> {
+    packet
+        .lookup(LOGIN_LAT_GROUP_TYPE)
+        .map(|v| v.decode_bytes())
+}
+/// Lookup all of the `login_lat_group` octets value from a packet.
+pub fn lookup_all_login_lat_group(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(LOGIN_LAT_GROUP_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const FRAMED_APPLE_TALK_LINK_TYPE: AVPType = 37;
+/// Delete all of `framed_apple_talk_link` values from a packet.
+pub fn delete_framed_apple_talk_link(packet: &mut Packet) {
+    packet.delete(FRAMED_APPLE_TALK_LINK_TYPE);
+}
+/// Returns whether a packet has `framed_apple_talk_link` without decoding the value.
+pub fn has_framed_apple_talk_link(packet: &Packet) -> bool {
+    packet.contains(FRAMED_APPLE_TALK_LINK_TYPE)
+}
+/// Returns the number of `framed_apple_talk_link` attributes in a packet.
+pub fn count_framed_apple_talk_link(packet: &Packet) -> usize {
+    packet.count(FRAMED_APPLE_TALK_LINK_TYPE)
+}
+/// Add `framed_apple_talk_link` integer value to a packet.
+pub fn add_framed_apple_talk_link(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(FRAMED_APPLE_TALK_LINK_TYPE, value));
+}
+/// Lookup a `framed_apple_talk_link` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_apple_talk_link`, it returns `None`.
+pub fn lookup_framed_apple_talk_link(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(FRAMED_APPLE_TALK_LINK_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Framed-AppleTalk-Link"))
+    })
+}
+/// Lookup all of the `framed_apple_talk_link` integer value from a packet.
+pub fn lookup_all_framed_apple_talk_link(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_APPLE_TALK_LINK_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-AppleTalk-Link"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_APPLE_TALK_NETWORK_TYPE: AVPType = 38;
+/// Delete all of `framed_apple_talk_network` values from a packet.
+pub fn delete_framed_apple_talk_network(packet: &mut Packet) {
+    packet.delete(FRAMED_APPLE_TALK_NETWORK_TYPE);
+}
+/// Returns whether a packet has `framed_apple_talk_network` without decoding the value.
+pub fn has_framed_apple_talk_network(packet: &Packet) -> bool {
+    packet.contains(FRAMED_APPLE_TALK_NETWORK_TYPE)
+}
+/// Returns the number of `framed_apple_talk_network` attributes in a packet.
+pub fn count_framed_apple_talk_network(packet: &Packet) -> usize {
+    packet.count(FRAMED_APPLE_TALK_NETWORK_TYPE)
+}
+/// Add `framed_apple_talk_network` integer value to a packet.
+pub fn add_framed_apple_talk_network(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(FRAMED_APPLE_TALK_NETWORK_TYPE, value));
+}
+/// Lookup a `framed_apple_talk_network` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_apple_talk_network`, it returns `None`.
+pub fn lookup_framed_apple_talk_network(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(FRAMED_APPLE_TALK_NETWORK_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Framed-AppleTalk-Network"))
+    })
+}
+/// Lookup all of the `framed_apple_talk_network` integer value from a packet.
+pub fn lookup_all_framed_apple_talk_network(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_APPLE_TALK_NETWORK_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-AppleTalk-Network"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_APPLE_TALK_ZONE_TYPE: AVPType = 39;
+/// Delete all of `framed_apple_talk_zone` values from a packet.
+pub fn delete_framed_apple_talk_zone(packet: &mut Packet) {
+    packet.delete(FRAMED_APPLE_TALK_ZONE_TYPE);
+}
+/// Returns whether a packet has `framed_apple_talk_zone` without decoding the value.
+pub fn has_framed_apple_talk_zone(packet: &Packet) -> bool {
+    packet.contains(FRAMED_APPLE_TALK_ZONE_TYPE)
+}
+/// Returns the number of `framed_apple_talk_zone` attributes in a packet.
+pub fn count_framed_apple_talk_zone(packet: &Packet) -> usize {
+    packet.count(FRAMED_APPLE_TALK_ZONE_TYPE)
+}
+/// Add `framed_apple_talk_zone` string value to a packet.
+pub fn add_framed_apple_talk_zone(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(FRAMED_APPLE_TALK_ZONE_TYPE, value));
+}
+/// Lookup a `framed_apple_talk_zone` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_apple_talk_zone`, it returns `None`.
+pub fn lookup_framed_apple_talk_zone(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(FRAMED_APPLE_TALK_ZONE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Framed-AppleTalk-Zone"))
+    })
+}
+/// Lookup all of the `framed_apple_talk_zone` string value from a packet.
+pub fn lookup_all_framed_apple_talk_zone(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_APPLE_TALK_ZONE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Framed-AppleTalk-Zone"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const CHAP_CHALLENGE_TYPE: AVPType = 60;
+/// Delete all of `chap_challenge` values from a packet.
+pub fn delete_chap_challenge(packet: &mut Packet) {
+    packet.delete(CHAP_CHALLENGE_TYPE);
+}
+/// Returns whether a packet has `chap_challenge` without decoding the value.
+pub fn has_chap_challenge(packet: &Packet) -> bool {
+    packet.contains(CHAP_CHALLENGE_TYPE)
+}
+/// Returns the number of `chap_challenge` attributes in a packet.
+pub fn count_chap_challenge(packet: &Packet) -> usize {
+    packet.count(CHAP_CHALLENGE_TYPE)
+}
+/// Add `chap_challenge` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_chap_challenge(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(CHAP_CHALLENGE_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `chap_challenge` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `chap_challenge`, it returns `None`.
+pub fn lookup_chap_challenge(packet: &Packet) -> Option<Vec<u8>> {
+    packet.lookup(CHAP_CHALLENGE_TYPE).map(|v| v.decode_bytes())
+}
+/// Lookup all of the `chap_challenge` octets value from a packet.
+pub fn lookup_all_chap_challenge(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CHAP_CHALLENGE_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const NAS_PORT_TYPE_TYPE: AVPType = 61;
+/// Delete all of `nas_port_type` values from a packet.
+pub fn delete_nas_port_type(packet: &mut Packet) {
+    packet.delete(NAS_PORT_TYPE_TYPE);
+}
+/// Returns whether a packet has `nas_port_type` without decoding the value.
+pub fn has_nas_port_type(packet: &Packet) -> bool {
+    packet.contains(NAS_PORT_TYPE_TYPE)
+}
+/// Returns the number of `nas_port_type` attributes in a packet.
+pub fn count_nas_port_type(packet: &Packet) -> usize {
+    packet.count(NAS_PORT_TYPE_TYPE)
+}
+/// Add `nas_port_type` value-defined integer value to a packet.
+pub fn add_nas_port_type(packet: &mut Packet, value: NasPortType) {
+    packet.add(AVP::from_u32(NAS_PORT_TYPE_TYPE, value.to_u32()));
+}
+/// Lookup a `nas_port_type` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `nas_port_type`, it returns `None`.
+pub fn lookup_nas_port_type(packet: &Packet) -> Option<Result<NasPortType, AVPError>> {
+    packet.lookup(NAS_PORT_TYPE_TYPE).map(|v| {
+        Ok(NasPortType::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("NAS-Port-Type"))?,
+        ))
+    })
+}
+/// Lookup all of the `nas_port_type` value-defined integer value from a packet.
+pub fn lookup_all_nas_port_type(packet: &Packet) -> Result<Vec<NasPortType>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(NAS_PORT_TYPE_TYPE) {
+        vec.push(NasPortType::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("NAS-Port-Type"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const PORT_LIMIT_TYPE: AVPType = 62;
+/// Delete all of `port_limit` values from a packet.
+pub fn delete_port_limit(packet: &mut Packet) {
+    packet.delete(PORT_LIMIT_TYPE);
+}
+/// Returns whether a packet has `port_limit` without decoding the value.
+pub fn has_port_limit(packet: &Packet) -> bool {
+    packet.contains(PORT_LIMIT_TYPE)
+}
+/// Returns the number of `port_limit` attributes in a packet.
+pub fn count_port_limit(packet: &Packet) -> usize {
+    packet.count(PORT_LIMIT_TYPE)
+}
+/// Add `port_limit` integer value to a packet.
+pub fn add_port_limit(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(PORT_LIMIT_TYPE, value));
+}
+/// Lookup a `port_limit` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `port_limit`, it returns `None`.
+pub fn lookup_port_limit(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(PORT_LIMIT_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Port-Limit"))
+    })
+}
+/// Lookup all of the `port_limit` integer value from a packet.
+pub fn lookup_all_port_limit(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PORT_LIMIT_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Port-Limit"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const LOGIN_LAT_PORT_TYPE: AVPType = 63;
+/// Delete all of `login_lat_port` values from a packet.
+pub fn delete_login_lat_port(packet: &mut Packet) {
+    packet.delete(LOGIN_LAT_PORT_TYPE);
+}
+/// Returns whether a packet has `login_lat_port` without decoding the value.
+pub fn has_login_lat_port(packet: &Packet) -> bool {
+    packet.contains(LOGIN_LAT_PORT_TYPE)
+}
+/// Returns the number of `login_lat_port` attributes in a packet.
+pub fn count_login_lat_port(packet: &Packet) -> usize {
+    packet.count(LOGIN_LAT_PORT_TYPE)
+}
+/// Add `login_lat_port` string value to a packet.
+pub fn add_login_lat_port(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(LOGIN_LAT_PORT_TYPE, value));
+}
+/// Lookup a `login_lat_port` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `login_lat_port`, it returns `None`.
+pub fn lookup_login_lat_port(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(LOGIN_LAT_PORT_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Login-LAT-Port"))
+    })
+}
+/// Lookup all of the `login_lat_port` string value from a packet.
+pub fn lookup_all_login_lat_port(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(LOGIN_LAT_PORT_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Login-LAT-Port"))?,
+        )
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "User-Name",
+        typ: USER_NAME_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "User-Password",
+        typ: USER_PASSWORD_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: true,
+        has_tag: false,
+        max_length: Some(128),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "CHAP-Password",
+        typ: CHAP_PASSWORD_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "NAS-IP-Address",
+        typ: NAS_IP_ADDRESS_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "NAS-Port",
+        typ: NAS_PORT_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Service-Type",
+        typ: SERVICE_TYPE_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Framed-Protocol",
+        typ: FRAMED_PROTOCOL_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Framed-IP-Address",
+        typ: FRAMED_IP_ADDRESS_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Framed-IP-Netmask",
+        typ: FRAMED_IP_NETMASK_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Framed-Routing",
+        typ: FRAMED_ROUTING_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Filter-Id",
+        typ: FILTER_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Framed-MTU",
+        typ: FRAMED_MTU_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Framed-Compression",
+        typ: FRAMED_COMPRESSION_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Login-IP-Host",
+        typ: LOGIN_IP_HOST_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Login-Service",
+        typ: LOGIN_SERVICE_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Login-TCP-Port",
+        typ: LOGIN_TCP_PORT_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Reply-Message",
+        typ: REPLY_MESSAGE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Callback-Number",
+        typ: CALLBACK_NUMBER_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Callback-Id",
+        typ: CALLBACK_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Framed-Route",
+        typ: FRAMED_ROUTE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Framed-IPX-Network",
+        typ: FRAMED_IPX_NETWORK_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "State",
+        typ: STATE_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Class",
+        typ: CLASS_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Vendor-Specific",
+        typ: VENDOR_SPECIFIC_TYPE,
+        data_type: AttributeDataType::VSA,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Session-Timeout",
+        typ: SESSION_TIMEOUT_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Idle-Timeout",
+        typ: IDLE_TIMEOUT_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Termination-Action",
+        typ: TERMINATION_ACTION_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Called-Station-Id",
+        typ: CALLED_STATION_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Calling-Station-Id",
+        typ: CALLING_STATION_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "NAS-Identifier",
+        typ: NAS_IDENTIFIER_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Proxy-State",
+        typ: PROXY_STATE_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Login-LAT-Service",
+        typ: LOGIN_LAT_SERVICE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Login-LAT-Node",
+        typ: LOGIN_LAT_NODE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Login-LAT-Group",
+        typ: LOGIN_LAT_GROUP_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Framed-AppleTalk-Link",
+        typ: FRAMED_APPLE_TALK_LINK_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Framed-AppleTalk-Network",
+        typ: FRAMED_APPLE_TALK_NETWORK_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Framed-AppleTalk-Zone",
+        typ: FRAMED_APPLE_TALK_ZONE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "CHAP-Challenge",
+        typ: CHAP_CHALLENGE_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "NAS-Port-Type",
+        typ: NAS_PORT_TYPE_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Port-Limit",
+        typ: PORT_LIMIT_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: Some(1),
+    },
+    AttributeMetadata {
+        name: "Login-LAT-Port",
+        typ: LOGIN_LAT_PORT_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: Some(1),
+    },
+];
+
+/// The values of `Framed-Compression`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FramedCompression {
+    None,
+    VanJacobsonTCPIP,
+    IpxHeaderCompression,
+    StacLZS,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl FramedCompression {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => FramedCompression::None,
+            1 => FramedCompression::VanJacobsonTCPIP,
+            2 => FramedCompression::IpxHeaderCompression,
+            3 => FramedCompression::StacLZS,
+            value => FramedCompression::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            FramedCompression::None => 0,
+            FramedCompression::VanJacobsonTCPIP => 1,
+            FramedCompression::IpxHeaderCompression => 2,
+            FramedCompression::StacLZS => 3,
+            FramedCompression::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for FramedCompression {
+    fn from(value: u32) -> Self {
+        FramedCompression::from_u32(value)
+    }
+}
+impl From<FramedCompression> for u32 {
+    fn from(value: FramedCompression) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for FramedCompression {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `None`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "None" => Ok(FramedCompression::None),
+            "Van-Jacobson-TCP-IP" => Ok(FramedCompression::VanJacobsonTCPIP),
+            "IPX-Header-Compression" => Ok(FramedCompression::IpxHeaderCompression),
+            "Stac-LZS" => Ok(FramedCompression::StacLZS),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Framed-Compression`: {name}"
+            ))),
+        }
+    }
+}
+pub const FRAMED_COMPRESSION_NONE: FramedCompression = FramedCompression::None;
+pub const FRAMED_COMPRESSION_VAN_JACOBSON_TCP_IP: FramedCompression =
+    FramedCompression::VanJacobsonTCPIP;
+pub const FRAMED_COMPRESSION_IPX_HEADER_COMPRESSION: FramedCompression =
+    FramedCompression::IpxHeaderCompression;
+pub const FRAMED_COMPRESSION_STAC_LZS: FramedCompression = FramedCompression::StacLZS;
+
+/// The values of `Framed-Protocol`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FramedProtocol {
+    Ppp,
+    Slip,
+    Arap,
+    GandalfSLML,
+    XylogicsIPXSLIP,
+    X75Synchronous,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl FramedProtocol {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => FramedProtocol::Ppp,
+            2 => FramedProtocol::Slip,
+            3 => FramedProtocol::Arap,
+            4 => FramedProtocol::GandalfSLML,
+            5 => FramedProtocol::XylogicsIPXSLIP,
+            6 => FramedProtocol::X75Synchronous,
+            value => FramedProtocol::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            FramedProtocol::Ppp => 1,
+            FramedProtocol::Slip => 2,
+            FramedProtocol::Arap => 3,
+            FramedProtocol::GandalfSLML => 4,
+            FramedProtocol::XylogicsIPXSLIP => 5,
+            FramedProtocol::X75Synchronous => 6,
+            FramedProtocol::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for FramedProtocol {
+    fn from(value: u32) -> Self {
+        FramedProtocol::from_u32(value)
+    }
+}
+impl From<FramedProtocol> for u32 {
+    fn from(value: FramedProtocol) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for FramedProtocol {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `PPP`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "PPP" => Ok(FramedProtocol::Ppp),
+            "SLIP" => Ok(FramedProtocol::Slip),
+            "ARAP" => Ok(FramedProtocol::Arap),
+            "Gandalf-SLML" => Ok(FramedProtocol::GandalfSLML),
+            "Xylogics-IPX-SLIP" => Ok(FramedProtocol::XylogicsIPXSLIP),
+            "X.75-Synchronous" => Ok(FramedProtocol::X75Synchronous),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Framed-Protocol`: {name}"
+            ))),
+        }
+    }
+}
+pub const FRAMED_PROTOCOL_PPP: FramedProtocol = FramedProtocol::Ppp;
+pub const FRAMED_PROTOCOL_SLIP: FramedProtocol = FramedProtocol::Slip;
+pub const FRAMED_PROTOCOL_ARAP: FramedProtocol = FramedProtocol::Arap;
+pub const FRAMED_PROTOCOL_GANDALF_SLML: FramedProtocol = FramedProtocol::GandalfSLML;
+pub const FRAMED_PROTOCOL_XYLOGICS_IPX_SLIP: FramedProtocol = FramedProtocol::XylogicsIPXSLIP;
+pub const FRAMED_PROTOCOL_X_75_SYNCHRONOUS: FramedProtocol = FramedProtocol::X75Synchronous;
+
+/// The values of `Framed-Routing`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FramedRouting {
+    None,
+    Broadcast,
+    Listen,
+    BroadcastListen,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl FramedRouting {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => FramedRouting::None,
+            1 => FramedRouting::Broadcast,
+            2 => FramedRouting::Listen,
+            3 => FramedRouting::BroadcastListen,
+            value => FramedRouting::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            FramedRouting::None => 0,
+            FramedRouting::Broadcast => 1,
+            FramedRouting::Listen => 2,
+            FramedRouting::BroadcastListen => 3,
+            FramedRouting::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for FramedRouting {
+    fn from(value: u32) -> Self {
+        FramedRouting::from_u32(value)
+    }
+}
+impl From<FramedRouting> for u32 {
+    fn from(value: FramedRouting) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for FramedRouting {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `None`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "None" => Ok(FramedRouting::None),
+            "Broadcast" => Ok(FramedRouting::Broadcast),
+            "Listen" => Ok(FramedRouting::Listen),
+            "Broadcast-Listen" => Ok(FramedRouting::BroadcastListen),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Framed-Routing`: {name}"
+            ))),
+        }
+    }
+}
+pub const FRAMED_ROUTING_NONE: FramedRouting = FramedRouting::None;
+pub const FRAMED_ROUTING_BROADCAST: FramedRouting = FramedRouting::Broadcast;
+pub const FRAMED_ROUTING_LISTEN: FramedRouting = FramedRouting::Listen;
+pub const FRAMED_ROUTING_BROADCAST_LISTEN: FramedRouting = FramedRouting::BroadcastListen;
+
+/// The values of `Login-Service`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LoginService {
+    Telnet,
+    Rlogin,
+    TcpClear,
+    PortMaster,
+    Lat,
+    X25Pad,
+    X25T3Pos,
+    TcpClearQuiet,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl LoginService {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => LoginService::Telnet,
+            1 => LoginService::Rlogin,
+            2 => LoginService::TcpClear,
+            3 => LoginService::PortMaster,
+            4 => LoginService::Lat,
+            5 => LoginService::X25Pad,
+            6 => LoginService::X25T3Pos,
+            8 => LoginService::TcpClearQuiet,
+            value => LoginService::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            LoginService::Telnet => 0,
+            LoginService::Rlogin => 1,
+            LoginService::TcpClear => 2,
+            LoginService::PortMaster => 3,
+            LoginService::Lat => 4,
+            LoginService::X25Pad => 5,
+            LoginService::X25T3Pos => 6,
+            LoginService::TcpClearQuiet => 8,
+            LoginService::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for LoginService {
+    fn from(value: u32) -> Self {
+        LoginService::from_u32(value)
+    }
+}
+impl From<LoginService> for u32 {
+    fn from(value: LoginService) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for LoginService {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Telnet`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Telnet" => Ok(LoginService::Telnet),
+            "Rlogin" => Ok(LoginService::Rlogin),
+            "TCP-Clear" => Ok(LoginService::TcpClear),
+            "PortMaster" => Ok(LoginService::PortMaster),
+            "LAT" => Ok(LoginService::Lat),
+            "X25-PAD" => Ok(LoginService::X25Pad),
+            "X25-T3POS" => Ok(LoginService::X25T3Pos),
+            "TCP-Clear-Quiet" => Ok(LoginService::TcpClearQuiet),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Login-Service`: {name}"
+            ))),
+        }
+    }
+}
+pub const LOGIN_SERVICE_TELNET: LoginService = LoginService::Telnet;
+pub const LOGIN_SERVICE_RLOGIN: LoginService = LoginService::Rlogin;
+pub const LOGIN_SERVICE_TCP_CLEAR: LoginService = LoginService::TcpClear;
+pub const LOGIN_SERVICE_PORT_MASTER: LoginService = LoginService::PortMaster;
+pub const LOGIN_SERVICE_LAT: LoginService = LoginService::Lat;
+pub const LOGIN_SERVICE_X25_PAD: LoginService = LoginService::X25Pad;
+pub const LOGIN_SERVICE_X25_T3POS: LoginService = LoginService::X25T3Pos;
+pub const LOGIN_SERVICE_TCP_CLEAR_QUIET: LoginService = LoginService::TcpClearQuiet;
+
+/// The values of `Login-TCP-Port`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LoginTCPPort {
+    Telnet,
+    Rlogin,
+    Rsh,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl LoginTCPPort {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            23 => LoginTCPPort::Telnet,
+            513 => LoginTCPPort::Rlogin,
+            514 => LoginTCPPort::Rsh,
+            value => LoginTCPPort::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            LoginTCPPort::Telnet => 23,
+            LoginTCPPort::Rlogin => 513,
+            LoginTCPPort::Rsh => 514,
+            LoginTCPPort::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for LoginTCPPort {
+    fn from(value: u32) -> Self {
+        LoginTCPPort::from_u32(value)
+    }
+}
+impl From<LoginTCPPort> for u32 {
+    fn from(value: LoginTCPPort) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for LoginTCPPort {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Telnet`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Telnet" => Ok(LoginTCPPort::Telnet),
+            "Rlogin" => Ok(LoginTCPPort::Rlogin),
+            "Rsh" => Ok(LoginTCPPort::Rsh),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Login-TCP-Port`: {name}"
+            ))),
+        }
+    }
+}
+pub const LOGIN_TCP_PORT_TELNET: LoginTCPPort = LoginTCPPort::Telnet;
+pub const LOGIN_TCP_PORT_RLOGIN: LoginTCPPort = LoginTCPPort::Rlogin;
+pub const LOGIN_TCP_PORT_RSH: LoginTCPPort = LoginTCPPort::Rsh;
+
+/// The values of `NAS-Port-Type`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NasPortType {
+    Async,
+    Sync,
+    Isdn,
+    IsdnV120,
+    IsdnV110,
+    Virtual,
+    Piafs,
+    HdlcClearChannel,
+    X25,
+    X75,
+    G3Fax,
+    Sdsl,
+    AdslCap,
+    AdslDmt,
+    Idsl,
+    Ethernet,
+    Xdsl,
+    Cable,
+    WirelessOther,
+    Wireless80211,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl NasPortType {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => NasPortType::Async,
+            1 => NasPortType::Sync,
+            2 => NasPortType::Isdn,
+            3 => NasPortType::IsdnV120,
+            4 => NasPortType::IsdnV110,
+            5 => NasPortType::Virtual,
+            6 => NasPortType::Piafs,
+            7 => NasPortType::HdlcClearChannel,
+            8 => NasPortType::X25,
+            9 => NasPortType::X75,
+            10 => NasPortType::G3Fax,
+            11 => NasPortType::Sdsl,
+            12 => NasPortType::AdslCap,
+            13 => NasPortType::AdslDmt,
+            14 => NasPortType::Idsl,
+            15 => NasPortType::Ethernet,
+            16 => NasPortType::Xdsl,
+            17 => NasPortType::Cable,
+            18 => NasPortType::WirelessOther,
+            19 => NasPortType::Wireless80211,
+            value => NasPortType::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            NasPortType::Async => 0,
+            NasPortType::Sync => 1,
+            NasPortType::Isdn => 2,
+            NasPortType::IsdnV120 => 3,
+            NasPortType::IsdnV110 => 4,
+            NasPortType::Virtual => 5,
+            NasPortType::Piafs => 6,
+            NasPortType::HdlcClearChannel => 7,
+            NasPortType::X25 => 8,
+            NasPortType::X75 => 9,
+            NasPortType::G3Fax => 10,
+            NasPortType::Sdsl => 11,
+            NasPortType::AdslCap => 12,
+            NasPortType::AdslDmt => 13,
+            NasPortType::Idsl => 14,
+            NasPortType::Ethernet => 15,
+            NasPortType::Xdsl => 16,
+            NasPortType::Cable => 17,
+            NasPortType::WirelessOther => 18,
+            NasPortType::Wireless80211 => 19,
+            NasPortType::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for NasPortType {
+    fn from(value: u32) -> Self {
+        NasPortType::from_u32(value)
+    }
+}
+impl From<NasPortType> for u32 {
+    fn from(value: NasPortType) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for NasPortType {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Async`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Async" => Ok(NasPortType::Async),
+            "Sync" => Ok(NasPortType::Sync),
+            "ISDN" => Ok(NasPortType::Isdn),
+            "ISDN-V120" => Ok(NasPortType::IsdnV120),
+            "ISDN-V110" => Ok(NasPortType::IsdnV110),
+            "Virtual" => Ok(NasPortType::Virtual),
+            "PIAFS" => Ok(NasPortType::Piafs),
+            "HDLC-Clear-Channel" => Ok(NasPortType::HdlcClearChannel),
+            "X.25" => Ok(NasPortType::X25),
+            "X.75" => Ok(NasPortType::X75),
+            "G.3-Fax" => Ok(NasPortType::G3Fax),
+            "SDSL" => Ok(NasPortType::Sdsl),
+            "ADSL-CAP" => Ok(NasPortType::AdslCap),
+            "ADSL-DMT" => Ok(NasPortType::AdslDmt),
+            "IDSL" => Ok(NasPortType::Idsl),
+            "Ethernet" => Ok(NasPortType::Ethernet),
+            "xDSL" => Ok(NasPortType::Xdsl),
+            "Cable" => Ok(NasPortType::Cable),
+            "Wireless-Other" => Ok(NasPortType::WirelessOther),
+            "Wireless-802.11" => Ok(NasPortType::Wireless80211),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `NAS-Port-Type`: {name}"
+            ))),
+        }
+    }
+}
+pub const NAS_PORT_TYPE_ASYNC: NasPortType = NasPortType::Async;
+pub const NAS_PORT_TYPE_SYNC: NasPortType = NasPortType::Sync;
+pub const NAS_PORT_TYPE_ISDN: NasPortType = NasPortType::Isdn;
+pub const NAS_PORT_TYPE_ISDN_V120: NasPortType = NasPortType::IsdnV120;
+pub const NAS_PORT_TYPE_ISDN_V110: NasPortType = NasPortType::IsdnV110;
+pub const NAS_PORT_TYPE_VIRTUAL: NasPortType = NasPortType::Virtual;
+pub const NAS_PORT_TYPE_PIAFS: NasPortType = NasPortType::Piafs;
+pub const NAS_PORT_TYPE_HDLC_CLEAR_CHANNEL: NasPortType = NasPortType::HdlcClearChannel;
+pub const NAS_PORT_TYPE_X_25: NasPortType = NasPortType::X25;
+pub const NAS_PORT_TYPE_X_75: NasPortType = NasPortType::X75;
+pub const NAS_PORT_TYPE_G_3_FAX: NasPortType = NasPortType::G3Fax;
+pub const NAS_PORT_TYPE_SDSL: NasPortType = NasPortType::Sdsl;
+pub const NAS_PORT_TYPE_ADSL_CAP: NasPortType = NasPortType::AdslCap;
+pub const NAS_PORT_TYPE_ADSL_DMT: NasPortType = NasPortType::AdslDmt;
+pub const NAS_PORT_TYPE_IDSL: NasPortType = NasPortType::Idsl;
+pub const NAS_PORT_TYPE_ETHERNET: NasPortType = NasPortType::Ethernet;
+pub const NAS_PORT_TYPE_X_DSL: NasPortType = NasPortType::Xdsl;
+pub const NAS_PORT_TYPE_CABLE: NasPortType = NasPortType::Cable;
+pub const NAS_PORT_TYPE_WIRELESS_OTHER: NasPortType = NasPortType::WirelessOther;
+pub const NAS_PORT_TYPE_WIRELESS_802_11: NasPortType = NasPortType::Wireless80211;
+
+/// The values of `Service-Type`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceType {
+    LoginUser,
+    FramedUser,
+    CallbackLoginUser,
+    CallbackFramedUser,
+    OutboundUser,
+    AdministrativeUser,
+    NasPromptUser,
+    AuthenticateOnly,
+    CallbackNASPrompt,
+    CallCheck,
+    CallbackAdministrative,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ServiceType {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => ServiceType::LoginUser,
+            2 => ServiceType::FramedUser,
+            3 => ServiceType::CallbackLoginUser,
+            4 => ServiceType::CallbackFramedUser,
+            5 => ServiceType::OutboundUser,
+            6 => ServiceType::AdministrativeUser,
+            7 => ServiceType::NasPromptUser,
+            8 => ServiceType::AuthenticateOnly,
+            9 => ServiceType::CallbackNASPrompt,
+            10 => ServiceType::CallCheck,
+            11 => ServiceType::CallbackAdministrative,
+            value => ServiceType::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ServiceType::LoginUser => 1,
+            ServiceType::FramedUser => 2,
+            ServiceType::CallbackLoginUser => 3,
+            ServiceType::CallbackFramedUser => 4,
+            ServiceType::OutboundUser => 5,
+            ServiceType::AdministrativeUser => 6,
+            ServiceType::NasPromptUser => 7,
+            ServiceType::AuthenticateOnly => 8,
+            ServiceType::CallbackNASPrompt => 9,
+            ServiceType::CallCheck => 10,
+            ServiceType::CallbackAdministrative => 11,
+            ServiceType::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ServiceType {
+    fn from(value: u32) -> Self {
+        ServiceType::from_u32(value)
+    }
+}
+impl From<ServiceType> for u32 {
+    fn from(value: ServiceType) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ServiceType {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Login-User`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Login-User" => Ok(ServiceType::LoginUser),
+            "Framed-User" => Ok(ServiceType::FramedUser),
+            "Callback-Login-User" => Ok(ServiceType::CallbackLoginUser),
+            "Callback-Framed-User" => Ok(ServiceType::CallbackFramedUser),
+            "Outbound-User" => Ok(ServiceType::OutboundUser),
+            "Administrative-User" => Ok(ServiceType::AdministrativeUser),
+            "NAS-Prompt-User" => Ok(ServiceType::NasPromptUser),
+            "Authenticate-Only" => Ok(ServiceType::AuthenticateOnly),
+            "Callback-NAS-Prompt" => Ok(ServiceType::CallbackNASPrompt),
+            "Call-Check" => Ok(ServiceType::CallCheck),
+            "Callback-Administrative" => Ok(ServiceType::CallbackAdministrative),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Service-Type`: {name}"
+            ))),
+        }
+    }
+}
+pub const SERVICE_TYPE_LOGIN_USER: ServiceType = ServiceType::LoginUser;
+pub const SERVICE_TYPE_FRAMED_USER: ServiceType = ServiceType::FramedUser;
+pub const SERVICE_TYPE_CALLBACK_LOGIN_USER: ServiceType = ServiceType::CallbackLoginUser;
+pub const SERVICE_TYPE_CALLBACK_FRAMED_USER: ServiceType = ServiceType::CallbackFramedUser;
+pub const SERVICE_TYPE_OUTBOUND_USER: ServiceType = ServiceType::OutboundUser;
+pub const SERVICE_TYPE_ADMINISTRATIVE_USER: ServiceType = ServiceType::AdministrativeUser;
+pub const SERVICE_TYPE_NAS_PROMPT_USER: ServiceType = ServiceType::NasPromptUser;
+pub const SERVICE_TYPE_AUTHENTICATE_ONLY: ServiceType = ServiceType::AuthenticateOnly;
+pub const SERVICE_TYPE_CALLBACK_NAS_PROMPT: ServiceType = ServiceType::CallbackNASPrompt;
+pub const SERVICE_TYPE_CALL_CHECK: ServiceType = ServiceType::CallCheck;
+pub const SERVICE_TYPE_CALLBACK_ADMINISTRATIVE: ServiceType = ServiceType::CallbackAdministrative;
+
+/// The values of `Termination-Action`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TerminationAction {
+    Default,
+    RadiusRequest,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl TerminationAction {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => TerminationAction::Default,
+            1 => TerminationAction::RadiusRequest,
+            value => TerminationAction::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            TerminationAction::Default => 0,
+            TerminationAction::RadiusRequest => 1,
+            TerminationAction::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for TerminationAction {
+    fn from(value: u32) -> Self {
+        TerminationAction::from_u32(value)
+    }
+}
+impl From<TerminationAction> for u32 {
+    fn from(value: TerminationAction) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for TerminationAction {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Default`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Default" => Ok(TerminationAction::Default),
+            "RADIUS-Request" => Ok(TerminationAction::RadiusRequest),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Termination-Action`: {name}"
+            ))),
+        }
+    }
+}
+pub const TERMINATION_ACTION_DEFAULT: TerminationAction = TerminationAction::Default;
+pub const TERMINATION_ACTION_RADIUS_REQUEST: TerminationAction = TerminationAction::RadiusRequest;
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: FRAMED_COMPRESSION_TYPE,
+        name: "None",
+        value: 0,
+    },
+    ValueMetadata {
+        typ: FRAMED_COMPRESSION_TYPE,
+        name: "Van-Jacobson-TCP-IP",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: FRAMED_COMPRESSION_TYPE,
+        name: "IPX-Header-Compression",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: FRAMED_COMPRESSION_TYPE,
+        name: "Stac-LZS",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: FRAMED_PROTOCOL_TYPE,
+        name: "PPP",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: FRAMED_PROTOCOL_TYPE,
+        name: "SLIP",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: FRAMED_PROTOCOL_TYPE,
+        name: "ARAP",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: FRAMED_PROTOCOL_TYPE,
+        name: "Gandalf-SLML",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: FRAMED_PROTOCOL_TYPE,
+        name: "Xylogics-IPX-SLIP",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: FRAMED_PROTOCOL_TYPE,
+        name: "X.75-Synchronous",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: FRAMED_ROUTING_TYPE,
+        name: "None",
+        value: 0,
+    },
+    ValueMetadata {
+        typ: FRAMED_ROUTING_TYPE,
+        name: "Broadcast",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: FRAMED_ROUTING_TYPE,
+        name: "Listen",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: FRAMED_ROUTING_TYPE,
+        name: "Broadcast-Listen",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: LOGIN_SERVICE_TYPE,
+        name: "Telnet",
+        value: 0,
+    },
+    ValueMetadata {
+        typ: LOGIN_SERVICE_TYPE,
+        name: "Rlogin",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: LOGIN_SERVICE_TYPE,
+        name: "TCP-Clear",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: LOGIN_SERVICE_TYPE,
+        name: "PortMaster",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: LOGIN_SERVICE_TYPE,
+        name: "LAT",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: LOGIN_SERVICE_TYPE,
+        name: "X25-PAD",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: LOGIN_SERVICE_TYPE,
+        name: "X25-T3POS",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: LOGIN_SERVICE_TYPE,
+        name: "TCP-Clear-Quiet",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: LOGIN_TCP_PORT_TYPE,
+        name: "Telnet",
+        value: 23,
+    },
+    ValueMetadata {
+        typ: LOGIN_TCP_PORT_TYPE,
+        name: "Rlogin",
+        value: 513,
+    },
+    ValueMetadata {
+        typ: LOGIN_TCP_PORT_TYPE,
+        name: "Rsh",
+        value: 514,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "Async",
+        value: 0,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "Sync",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "ISDN",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "ISDN-V120",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "ISDN-V110",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "Virtual",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "PIAFS",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "HDLC-Clear-Channel",
+        value: 7,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "X.25",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "X.75",
+        value: 9,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "G.3-Fax",
+        value: 10,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "SDSL",
+        value: 11,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "ADSL-CAP",
+        value: 12,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "ADSL-DMT",
+        value: 13,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "IDSL",
+        value: 14,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "Ethernet",
+        value: 15,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "xDSL",
+        value: 16,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "Cable",
+        value: 17,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "Wireless-Other",
+        value: 18,
+    },
+    ValueMetadata {
+        typ: NAS_PORT_TYPE_TYPE,
+        name: "Wireless-802.11",
+        value: 19,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Login-User",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Framed-User",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Callback-Login-User",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Callback-Framed-User",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Outbound-User",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Administrative-User",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "NAS-Prompt-User",
+        value: 7,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Authenticate-Only",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Callback-NAS-Prompt",
+        value: 9,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Call-Check",
+        value: 10,
+    },
+    ValueMetadata {
+        typ: SERVICE_TYPE_TYPE,
+        name: "Callback-Administrative",
+        value: 11,
+    },
+    ValueMetadata {
+        typ: TERMINATION_ACTION_TYPE,
+        name: "Default",
+        value: 0,
+    },
+    ValueMetadata {
+        typ: TERMINATION_ACTION_TYPE,
+        name: "RADIUS-Request",
+        value: 1,
+    },
+];
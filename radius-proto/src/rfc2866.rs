@@ -0,0 +1,1074 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc2866 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 2866.
+//! #    http://www.ietf.org/rfc/rfc2866.txt
+//! #
+//! #    $Id$
+//! #
+//! ATTRIBUTE    Acct-Status-Type            40    integer
+//! ATTRIBUTE    Acct-Delay-Time                41    integer
+//! ATTRIBUTE    Acct-Input-Octets            42    integer
+//! ATTRIBUTE    Acct-Output-Octets            43    integer
+//! ATTRIBUTE    Acct-Session-Id                44    string
+//! ATTRIBUTE    Acct-Authentic                45    integer
+//! ATTRIBUTE    Acct-Session-Time            46    integer
+//! ATTRIBUTE    Acct-Input-Packets            47    integer
+//! ATTRIBUTE    Acct-Output-Packets            48    integer
+//! ATTRIBUTE    Acct-Terminate-Cause            49    integer
+//! ATTRIBUTE    Acct-Multi-Session-Id            50    string
+//! ATTRIBUTE    Acct-Link-Count                51    integer
+//!
+//! #    Accounting Status Types
+//!
+//! VALUE    Acct-Status-Type        Start            1
+//! VALUE    Acct-Status-Type        Stop            2
+//! VALUE    Acct-Status-Type        Alive            3   # dup
+//! VALUE    Acct-Status-Type        Interim-Update        3
+//! VALUE    Acct-Status-Type        Accounting-On        7
+//! VALUE    Acct-Status-Type        Accounting-Off        8
+//! VALUE    Acct-Status-Type        Failed            15
+//!
+//! #    Authentication Types
+//!
+//! VALUE    Acct-Authentic            RADIUS            1
+//! VALUE    Acct-Authentic            Local            2
+//! VALUE    Acct-Authentic            Remote            3
+//! VALUE    Acct-Authentic            Diameter        4
+//!
+//! #    Acct Terminate Causes
+//!
+//! VALUE    Acct-Terminate-Cause        User-Request        1
+//! VALUE    Acct-Terminate-Cause        Lost-Carrier        2
+//! VALUE    Acct-Terminate-Cause        Lost-Service        3
+//! VALUE    Acct-Terminate-Cause        Idle-Timeout        4
+//! VALUE    Acct-Terminate-Cause        Session-Timeout        5
+//! VALUE    Acct-Terminate-Cause        Admin-Reset        6
+//! VALUE    Acct-Terminate-Cause        Admin-Reboot        7
+//! VALUE    Acct-Terminate-Cause        Port-Error        8
+//! VALUE    Acct-Terminate-Cause        NAS-Error        9
+//! VALUE    Acct-Terminate-Cause        NAS-Request        10
+//! VALUE    Acct-Terminate-Cause        NAS-Reboot        11
+//! VALUE    Acct-Terminate-Cause        Port-Unneeded        12
+//! VALUE    Acct-Terminate-Cause        Port-Preempted        13
+//! VALUE    Acct-Terminate-Cause        Port-Suspended        14
+//! VALUE    Acct-Terminate-Cause        Service-Unavailable    15
+//! VALUE    Acct-Terminate-Cause        Callback        16
+//! VALUE    Acct-Terminate-Cause        User-Error        17
+//! VALUE    Acct-Terminate-Cause        Host-Request        18
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+pub const ACCT_STATUS_TYPE_TYPE: AVPType = 40;
+/// Delete all of `acct_status_type` values from a packet.
+pub fn delete_acct_status_type(packet: &mut Packet) {
+    packet.delete(ACCT_STATUS_TYPE_TYPE);
+}
+/// Returns whether a packet has `acct_status_type` without decoding the value.
+pub fn has_acct_status_type(packet: &Packet) -> bool {
+    packet.contains(ACCT_STATUS_TYPE_TYPE)
+}
+/// Returns the number of `acct_status_type` attributes in a packet.
+pub fn count_acct_status_type(packet: &Packet) -> usize {
+    packet.count(ACCT_STATUS_TYPE_TYPE)
+}
+/// Add `acct_status_type` value-defined integer value to a packet.
+pub fn add_acct_status_type(packet: &mut Packet, value: AcctStatusType) {
+    packet.add(AVP::from_u32(ACCT_STATUS_TYPE_TYPE, value.to_u32()));
+}
+/// Lookup a `acct_status_type` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_status_type`, it returns `None`.
+pub fn lookup_acct_status_type(packet: &Packet) -> Option<Result<AcctStatusType, AVPError>> {
+    packet.lookup(ACCT_STATUS_TYPE_TYPE).map(|v| {
+        Ok(AcctStatusType::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Status-Type"))?,
+        ))
+    })
+}
+/// Lookup all of the `acct_status_type` value-defined integer value from a packet.
+pub fn lookup_all_acct_status_type(packet: &Packet) -> Result<Vec<AcctStatusType>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_STATUS_TYPE_TYPE) {
+        vec.push(AcctStatusType::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Status-Type"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ACCT_DELAY_TIME_TYPE: AVPType = 41;
+/// Delete all of `acct_delay_time` values from a packet.
+pub fn delete_acct_delay_time(packet: &mut Packet) {
+    packet.delete(ACCT_DELAY_TIME_TYPE);
+}
+/// Returns whether a packet has `acct_delay_time` without decoding the value.
+pub fn has_acct_delay_time(packet: &Packet) -> bool {
+    packet.contains(ACCT_DELAY_TIME_TYPE)
+}
+/// Returns the number of `acct_delay_time` attributes in a packet.
+pub fn count_acct_delay_time(packet: &Packet) -> usize {
+    packet.count(ACCT_DELAY_TIME_TYPE)
+}
+/// Add `acct_delay_time` integer value to a packet.
+pub fn add_acct_delay_time(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_DELAY_TIME_TYPE, value));
+}
+/// Lookup a `acct_delay_time` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_delay_time`, it returns `None`.
+pub fn lookup_acct_delay_time(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_DELAY_TIME_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Delay-Time"))
+    })
+}
+/// Lookup all of the `acct_delay_time` integer value from a packet.
+pub fn lookup_all_acct_delay_time(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_DELAY_TIME_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Delay-Time"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_INPUT_OCTETS_TYPE: AVPType = 42;
+/// Delete all of `acct_input_octets` values from a packet.
+pub fn delete_acct_input_octets(packet: &mut Packet) {
+    packet.delete(ACCT_INPUT_OCTETS_TYPE);
+}
+/// Returns whether a packet has `acct_input_octets` without decoding the value.
+pub fn has_acct_input_octets(packet: &Packet) -> bool {
+    packet.contains(ACCT_INPUT_OCTETS_TYPE)
+}
+/// Returns the number of `acct_input_octets` attributes in a packet.
+pub fn count_acct_input_octets(packet: &Packet) -> usize {
+    packet.count(ACCT_INPUT_OCTETS_TYPE)
+}
+/// Add `acct_input_octets` integer value to a packet.
+pub fn add_acct_input_octets(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_INPUT_OCTETS_TYPE, value));
+}
+/// Lookup a `acct_input_octets` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_input_octets`, it returns `None`.
+pub fn lookup_acct_input_octets(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_INPUT_OCTETS_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Input-Octets"))
+    })
+}
+/// Lookup all of the `acct_input_octets` integer value from a packet.
+pub fn lookup_all_acct_input_octets(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_INPUT_OCTETS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Input-Octets"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_OUTPUT_OCTETS_TYPE: AVPType = 43;
+/// Delete all of `acct_output_octets` values from a packet.
+pub fn delete_acct_output_octets(packet: &mut Packet) {
+    packet.delete(ACCT_OUTPUT_OCTETS_TYPE);
+}
+/// Returns whether a packet has `acct_output_octets` without decoding the value.
+pub fn has_acct_output_octets(packet: &Packet) -> bool {
+    packet.contains(ACCT_OUTPUT_OCTETS_TYPE)
+}
+/// Returns the number of `acct_output_octets` attributes in a packet.
+pub fn count_acct_output_octets(packet: &Packet) -> usize {
+    packet.count(ACCT_OUTPUT_OCTETS_TYPE)
+}
+/// Add `acct_output_octets` integer value to a packet.
+pub fn add_acct_output_octets(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_OUTPUT_OCTETS_TYPE, value));
+}
+/// Lookup a `acct_output_octets` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_output_octets`, it returns `None`.
+pub fn lookup_acct_output_octets(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_OUTPUT_OCTETS_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Output-Octets"))
+    })
+}
+/// Lookup all of the `acct_output_octets` integer value from a packet.
+pub fn lookup_all_acct_output_octets(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_OUTPUT_OCTETS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Output-Octets"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_SESSION_ID_TYPE: AVPType = 44;
+/// Delete all of `acct_session_id` values from a packet.
+pub fn delete_acct_session_id(packet: &mut Packet) {
+    packet.delete(ACCT_SESSION_ID_TYPE);
+}
+/// Returns whether a packet has `acct_session_id` without decoding the value.
+pub fn has_acct_session_id(packet: &Packet) -> bool {
+    packet.contains(ACCT_SESSION_ID_TYPE)
+}
+/// Returns the number of `acct_session_id` attributes in a packet.
+pub fn count_acct_session_id(packet: &Packet) -> usize {
+    packet.count(ACCT_SESSION_ID_TYPE)
+}
+/// Add `acct_session_id` string value to a packet.
+pub fn add_acct_session_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(ACCT_SESSION_ID_TYPE, value));
+}
+/// Lookup a `acct_session_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_session_id`, it returns `None`.
+pub fn lookup_acct_session_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(ACCT_SESSION_ID_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Acct-Session-Id"))
+    })
+}
+/// Lookup all of the `acct_session_id` string value from a packet.
+pub fn lookup_all_acct_session_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_SESSION_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Acct-Session-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_AUTHENTIC_TYPE: AVPType = 45;
+/// Delete all of `acct_authentic` values from a packet.
+pub fn delete_acct_authentic(packet: &mut Packet) {
+    packet.delete(ACCT_AUTHENTIC_TYPE);
+}
+/// Returns whether a packet has `acct_authentic` without decoding the value.
+pub fn has_acct_authentic(packet: &Packet) -> bool {
+    packet.contains(ACCT_AUTHENTIC_TYPE)
+}
+/// Returns the number of `acct_authentic` attributes in a packet.
+pub fn count_acct_authentic(packet: &Packet) -> usize {
+    packet.count(ACCT_AUTHENTIC_TYPE)
+}
+/// Add `acct_authentic` value-defined integer value to a packet.
+pub fn add_acct_authentic(packet: &mut Packet, value: AcctAuthentic) {
+    packet.add(AVP::from_u32(ACCT_AUTHENTIC_TYPE, value.to_u32()));
+}
+/// Lookup a `acct_authentic` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_authentic`, it returns `None`.
+pub fn lookup_acct_authentic(packet: &Packet) -> Option<Result<AcctAuthentic, AVPError>> {
+    packet.lookup(ACCT_AUTHENTIC_TYPE).map(|v| {
+        Ok(AcctAuthentic::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Authentic"))?,
+        ))
+    })
+}
+/// Lookup all of the `acct_authentic` value-defined integer value from a packet.
+pub fn lookup_all_acct_authentic(packet: &Packet) -> Result<Vec<AcctAuthentic>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_AUTHENTIC_TYPE) {
+        vec.push(AcctAuthentic::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Authentic"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ACCT_SESSION_TIME_TYPE: AVPType = 46;
+/// Delete all of `acct_session_time` values from a packet.
+pub fn delete_acct_session_time(packet: &mut Packet) {
+    packet.delete(ACCT_SESSION_TIME_TYPE);
+}
+/// Returns whether a packet has `acct_session_time` without decoding the value.
+pub fn has_acct_session_time(packet: &Packet) -> bool {
+    packet.contains(ACCT_SESSION_TIME_TYPE)
+}
+/// Returns the number of `acct_session_time` attributes in a packet.
+pub fn count_acct_session_time(packet: &Packet) -> usize {
+    packet.count(ACCT_SESSION_TIME_TYPE)
+}
+/// Add `acct_session_time` integer value to a packet.
+pub fn add_acct_session_time(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_SESSION_TIME_TYPE, value));
+}
+/// Lookup a `acct_session_time` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_session_time`, it returns `None`.
+pub fn lookup_acct_session_time(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_SESSION_TIME_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Session-Time"))
+    })
+}
+/// Lookup all of the `acct_session_time` integer value from a packet.
+pub fn lookup_all_acct_session_time(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_SESSION_TIME_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Session-Time"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_INPUT_PACKETS_TYPE: AVPType = 47;
+/// Delete all of `acct_input_packets` values from a packet.
+pub fn delete_acct_input_packets(packet: &mut Packet) {
+    packet.delete(ACCT_INPUT_PACKETS_TYPE);
+}
+/// Returns whether a packet has `acct_input_packets` without decoding the value.
+pub fn has_acct_input_packets(packet: &Packet) -> bool {
+    packet.contains(ACCT_INPUT_PACKETS_TYPE)
+}
+/// Returns the number of `acct_input_packets` attributes in a packet.
+pub fn count_acct_input_packets(packet: &Packet) -> usize {
+    packet.count(ACCT_INPUT_PACKETS_TYPE)
+}
+/// Add `acct_input_packets` integer value to a packet.
+pub fn add_acct_input_packets(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_INPUT_PACKETS_TYPE, value));
+}
+/// Lookup a `acct_input_packets` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_input_packets`, it returns `None`.
+pub fn lookup_acct_input_packets(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_INPUT_PACKETS_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Input-Packets"))
+    })
+}
+/// Lookup all of the `acct_input_packets` integer value from a packet.
+pub fn lookup_all_acct_input_packets(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_INPUT_PACKETS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Input-Packets"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_OUTPUT_PACKETS_TYPE: AVPType = 48;
+/// Delete all of `acct_output_packets` values from a packet.
+pub fn delete_acct_output_packets(packet: &mut Packet) {
+    packet.delete(ACCT_OUTPUT_PACKETS_TYPE);
+}
+/// Returns whether a packet has `acct_output_packets` without decoding the value.
+pub fn has_acct_output_packets(packet: &Packet) -> bool {
+    packet.contains(ACCT_OUTPUT_PACKETS_TYPE)
+}
+/// Returns the number of `acct_output_packets` attributes in a packet.
+pub fn count_acct_output_packets(packet: &Packet) -> usize {
+    packet.count(ACCT_OUTPUT_PACKETS_TYPE)
+}
+/// Add `acct_output_packets` integer value to a packet.
+pub fn add_acct_output_packets(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_OUTPUT_PACKETS_TYPE, value));
+}
+/// Lookup a `acct_output_packets` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_output_packets`, it returns `None`.
+pub fn lookup_acct_output_packets(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_OUTPUT_PACKETS_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Output-Packets"))
+    })
+}
+/// Lookup all of the `acct_output_packets` integer value from a packet.
+pub fn lookup_all_acct_output_packets(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_OUTPUT_PACKETS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Output-Packets"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_TERMINATE_CAUSE_TYPE: AVPType = 49;
+/// Delete all of `acct_terminate_cause` values from a packet.
+pub fn delete_acct_terminate_cause(packet: &mut Packet) {
+    packet.delete(ACCT_TERMINATE_CAUSE_TYPE);
+}
+/// Returns whether a packet has `acct_terminate_cause` without decoding the value.
+pub fn has_acct_terminate_cause(packet: &Packet) -> bool {
+    packet.contains(ACCT_TERMINATE_CAUSE_TYPE)
+}
+/// Returns the number of `acct_terminate_cause` attributes in a packet.
+pub fn count_acct_terminate_cause(packet: &Packet) -> usize {
+    packet.count(ACCT_TERMINATE_CAUSE_TYPE)
+}
+/// Add `acct_terminate_cause` value-defined integer value to a packet.
+pub fn add_acct_terminate_cause(packet: &mut Packet, value: AcctTerminateCause) {
+    packet.add(AVP::from_u32(ACCT_TERMINATE_CAUSE_TYPE, value.to_u32()));
+}
+/// Lookup a `acct_terminate_cause` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_terminate_cause`, it returns `None`.
+pub fn lookup_acct_terminate_cause(
+    packet: &Packet,
+) -> Option<Result<AcctTerminateCause, AVPError>> {
+    packet.lookup(ACCT_TERMINATE_CAUSE_TYPE).map(|v| {
+        Ok(AcctTerminateCause::from_u32(v.decode_u32().map_err(
+            |e| e.with_attribute_name("Acct-Terminate-Cause"),
+        )?))
+    })
+}
+/// Lookup all of the `acct_terminate_cause` value-defined integer value from a packet.
+pub fn lookup_all_acct_terminate_cause(
+    packet: &Packet,
+) -> Result<Vec<AcctTerminateCause>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_TERMINATE_CAUSE_TYPE) {
+        vec.push(AcctTerminateCause::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Terminate-Cause"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ACCT_MULTI_SESSION_ID_TYPE: AVPType = 50;
+/// Delete all of `acct_multi_session_id` values from a packet.
+pub fn delete_acct_multi_session_id(packet: &mut Packet) {
+    packet.delete(ACCT_MULTI_SESSION_ID_TYPE);
+}
+/// Returns whether a packet has `acct_multi_session_id` without decoding the value.
+pub fn has_acct_multi_session_id(packet: &Packet) -> bool {
+    packet.contains(ACCT_MULTI_SESSION_ID_TYPE)
+}
+/// Returns the number of `acct_multi_session_id` attributes in a packet.
+pub fn count_acct_multi_session_id(packet: &Packet) -> usize {
+    packet.count(ACCT_MULTI_SESSION_ID_TYPE)
+}
+/// Add `acct_multi_session_id` string value to a packet.
+pub fn add_acct_multi_session_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(ACCT_MULTI_SESSION_ID_TYPE, value));
+}
+/// Lookup a `acct_multi_session_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_multi_session_id`, it returns `None`.
+pub fn lookup_acct_multi_session_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(ACCT_MULTI_SESSION_ID_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Acct-Multi-Session-Id"))
+    })
+}
+/// Lookup all of the `acct_multi_session_id` string value from a packet.
+pub fn lookup_all_acct_multi_session_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_MULTI_SESSION_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Acct-Multi-Session-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_LINK_COUNT_TYPE: AVPType = 51;
+/// Delete all of `acct_link_count` values from a packet.
+pub fn delete_acct_link_count(packet: &mut Packet) {
+    packet.delete(ACCT_LINK_COUNT_TYPE);
+}
+/// Returns whether a packet has `acct_link_count` without decoding the value.
+pub fn has_acct_link_count(packet: &Packet) -> bool {
+    packet.contains(ACCT_LINK_COUNT_TYPE)
+}
+/// Returns the number of `acct_link_count` attributes in a packet.
+pub fn count_acct_link_count(packet: &Packet) -> usize {
+    packet.count(ACCT_LINK_COUNT_TYPE)
+}
+/// Add `acct_link_count` integer value to a packet.
+pub fn add_acct_link_count(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_LINK_COUNT_TYPE, value));
+}
+/// Lookup a `acct_link_count` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_link_count`, it returns `None`.
+pub fn lookup_acct_link_count(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_LINK_COUNT_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Link-Count"))
+    })
+}
+/// Lookup all of the `acct_link_count` integer value from a packet.
+pub fn lookup_all_acct_link_count(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_LINK_COUNT_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Link-Count"))?,
+        )
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Acct-Status-Type",
+        typ: ACCT_STATUS_TYPE_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Delay-Time",
+        typ: ACCT_DELAY_TIME_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Input-Octets",
+        typ: ACCT_INPUT_OCTETS_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Output-Octets",
+        typ: ACCT_OUTPUT_OCTETS_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Session-Id",
+        typ: ACCT_SESSION_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Authentic",
+        typ: ACCT_AUTHENTIC_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Session-Time",
+        typ: ACCT_SESSION_TIME_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Input-Packets",
+        typ: ACCT_INPUT_PACKETS_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Output-Packets",
+        typ: ACCT_OUTPUT_PACKETS_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Terminate-Cause",
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Multi-Session-Id",
+        typ: ACCT_MULTI_SESSION_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Link-Count",
+        typ: ACCT_LINK_COUNT_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+];
+
+/// The values of `Acct-Authentic`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AcctAuthentic {
+    Radius,
+    Local,
+    Remote,
+    Diameter,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl AcctAuthentic {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => AcctAuthentic::Radius,
+            2 => AcctAuthentic::Local,
+            3 => AcctAuthentic::Remote,
+            4 => AcctAuthentic::Diameter,
+            value => AcctAuthentic::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            AcctAuthentic::Radius => 1,
+            AcctAuthentic::Local => 2,
+            AcctAuthentic::Remote => 3,
+            AcctAuthentic::Diameter => 4,
+            AcctAuthentic::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for AcctAuthentic {
+    fn from(value: u32) -> Self {
+        AcctAuthentic::from_u32(value)
+    }
+}
+impl From<AcctAuthentic> for u32 {
+    fn from(value: AcctAuthentic) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for AcctAuthentic {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `RADIUS`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "RADIUS" => Ok(AcctAuthentic::Radius),
+            "Local" => Ok(AcctAuthentic::Local),
+            "Remote" => Ok(AcctAuthentic::Remote),
+            "Diameter" => Ok(AcctAuthentic::Diameter),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Acct-Authentic`: {name}"
+            ))),
+        }
+    }
+}
+pub const ACCT_AUTHENTIC_RADIUS: AcctAuthentic = AcctAuthentic::Radius;
+pub const ACCT_AUTHENTIC_LOCAL: AcctAuthentic = AcctAuthentic::Local;
+pub const ACCT_AUTHENTIC_REMOTE: AcctAuthentic = AcctAuthentic::Remote;
+pub const ACCT_AUTHENTIC_DIAMETER: AcctAuthentic = AcctAuthentic::Diameter;
+
+/// The values of `Acct-Status-Type`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AcctStatusType {
+    Start,
+    Stop,
+    Alive,
+    AccountingOn,
+    AccountingOff,
+    Failed,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl AcctStatusType {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => AcctStatusType::Start,
+            2 => AcctStatusType::Stop,
+            3 => AcctStatusType::Alive,
+            7 => AcctStatusType::AccountingOn,
+            8 => AcctStatusType::AccountingOff,
+            15 => AcctStatusType::Failed,
+            value => AcctStatusType::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            AcctStatusType::Start => 1,
+            AcctStatusType::Stop => 2,
+            AcctStatusType::Alive => 3,
+            AcctStatusType::AccountingOn => 7,
+            AcctStatusType::AccountingOff => 8,
+            AcctStatusType::Failed => 15,
+            AcctStatusType::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for AcctStatusType {
+    fn from(value: u32) -> Self {
+        AcctStatusType::from_u32(value)
+    }
+}
+impl From<AcctStatusType> for u32 {
+    fn from(value: AcctStatusType) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for AcctStatusType {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Start`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Start" => Ok(AcctStatusType::Start),
+            "Stop" => Ok(AcctStatusType::Stop),
+            "Alive" => Ok(AcctStatusType::Alive),
+            "Interim-Update" => Ok(AcctStatusType::Alive),
+            "Accounting-On" => Ok(AcctStatusType::AccountingOn),
+            "Accounting-Off" => Ok(AcctStatusType::AccountingOff),
+            "Failed" => Ok(AcctStatusType::Failed),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Acct-Status-Type`: {name}"
+            ))),
+        }
+    }
+}
+pub const ACCT_STATUS_TYPE_START: AcctStatusType = AcctStatusType::Start;
+pub const ACCT_STATUS_TYPE_STOP: AcctStatusType = AcctStatusType::Stop;
+pub const ACCT_STATUS_TYPE_ALIVE: AcctStatusType = AcctStatusType::Alive;
+pub const ACCT_STATUS_TYPE_INTERIM_UPDATE: AcctStatusType = AcctStatusType::Alive;
+pub const ACCT_STATUS_TYPE_ACCOUNTING_ON: AcctStatusType = AcctStatusType::AccountingOn;
+pub const ACCT_STATUS_TYPE_ACCOUNTING_OFF: AcctStatusType = AcctStatusType::AccountingOff;
+pub const ACCT_STATUS_TYPE_FAILED: AcctStatusType = AcctStatusType::Failed;
+
+/// The values of `Acct-Terminate-Cause`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AcctTerminateCause {
+    UserRequest,
+    LostCarrier,
+    LostService,
+    IdleTimeout,
+    SessionTimeout,
+    AdminReset,
+    AdminReboot,
+    PortError,
+    NasError,
+    NasRequest,
+    NasReboot,
+    PortUnneeded,
+    PortPreempted,
+    PortSuspended,
+    ServiceUnavailable,
+    Callback,
+    UserError,
+    HostRequest,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl AcctTerminateCause {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => AcctTerminateCause::UserRequest,
+            2 => AcctTerminateCause::LostCarrier,
+            3 => AcctTerminateCause::LostService,
+            4 => AcctTerminateCause::IdleTimeout,
+            5 => AcctTerminateCause::SessionTimeout,
+            6 => AcctTerminateCause::AdminReset,
+            7 => AcctTerminateCause::AdminReboot,
+            8 => AcctTerminateCause::PortError,
+            9 => AcctTerminateCause::NasError,
+            10 => AcctTerminateCause::NasRequest,
+            11 => AcctTerminateCause::NasReboot,
+            12 => AcctTerminateCause::PortUnneeded,
+            13 => AcctTerminateCause::PortPreempted,
+            14 => AcctTerminateCause::PortSuspended,
+            15 => AcctTerminateCause::ServiceUnavailable,
+            16 => AcctTerminateCause::Callback,
+            17 => AcctTerminateCause::UserError,
+            18 => AcctTerminateCause::HostRequest,
+            value => AcctTerminateCause::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            AcctTerminateCause::UserRequest => 1,
+            AcctTerminateCause::LostCarrier => 2,
+            AcctTerminateCause::LostService => 3,
+            AcctTerminateCause::IdleTimeout => 4,
+            AcctTerminateCause::SessionTimeout => 5,
+            AcctTerminateCause::AdminReset => 6,
+            AcctTerminateCause::AdminReboot => 7,
+            AcctTerminateCause::PortError => 8,
+            AcctTerminateCause::NasError => 9,
+            AcctTerminateCause::NasRequest => 10,
+            AcctTerminateCause::NasReboot => 11,
+            AcctTerminateCause::PortUnneeded => 12,
+            AcctTerminateCause::PortPreempted => 13,
+            AcctTerminateCause::PortSuspended => 14,
+            AcctTerminateCause::ServiceUnavailable => 15,
+            AcctTerminateCause::Callback => 16,
+            AcctTerminateCause::UserError => 17,
+            AcctTerminateCause::HostRequest => 18,
+            AcctTerminateCause::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for AcctTerminateCause {
+    fn from(value: u32) -> Self {
+        AcctTerminateCause::from_u32(value)
+    }
+}
+impl From<AcctTerminateCause> for u32 {
+    fn from(value: AcctTerminateCause) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for AcctTerminateCause {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `User-Request`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "User-Request" => Ok(AcctTerminateCause::UserRequest),
+            "Lost-Carrier" => Ok(AcctTerminateCause::LostCarrier),
+            "Lost-Service" => Ok(AcctTerminateCause::LostService),
+            "Idle-Timeout" => Ok(AcctTerminateCause::IdleTimeout),
+            "Session-Timeout" => Ok(AcctTerminateCause::SessionTimeout),
+            "Admin-Reset" => Ok(AcctTerminateCause::AdminReset),
+            "Admin-Reboot" => Ok(AcctTerminateCause::AdminReboot),
+            "Port-Error" => Ok(AcctTerminateCause::PortError),
+            "NAS-Error" => Ok(AcctTerminateCause::NasError),
+            "NAS-Request" => Ok(AcctTerminateCause::NasRequest),
+            "NAS-Reboot" => Ok(AcctTerminateCause::NasReboot),
+            "Port-Unneeded" => Ok(AcctTerminateCause::PortUnneeded),
+            "Port-Preempted" => Ok(AcctTerminateCause::PortPreempted),
+            "Port-Suspended" => Ok(AcctTerminateCause::PortSuspended),
+            "Service-Unavailable" => Ok(AcctTerminateCause::ServiceUnavailable),
+            "Callback" => Ok(AcctTerminateCause::Callback),
+            "User-Error" => Ok(AcctTerminateCause::UserError),
+            "Host-Request" => Ok(AcctTerminateCause::HostRequest),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Acct-Terminate-Cause`: {name}"
+            ))),
+        }
+    }
+}
+pub const ACCT_TERMINATE_CAUSE_USER_REQUEST: AcctTerminateCause = AcctTerminateCause::UserRequest;
+pub const ACCT_TERMINATE_CAUSE_LOST_CARRIER: AcctTerminateCause = AcctTerminateCause::LostCarrier;
+pub const ACCT_TERMINATE_CAUSE_LOST_SERVICE: AcctTerminateCause = AcctTerminateCause::LostService;
+pub const ACCT_TERMINATE_CAUSE_IDLE_TIMEOUT: AcctTerminateCause = AcctTerminateCause::IdleTimeout;
+pub const ACCT_TERMINATE_CAUSE_SESSION_TIMEOUT: AcctTerminateCause =
+    AcctTerminateCause::SessionTimeout;
+pub const ACCT_TERMINATE_CAUSE_ADMIN_RESET: AcctTerminateCause = AcctTerminateCause::AdminReset;
+pub const ACCT_TERMINATE_CAUSE_ADMIN_REBOOT: AcctTerminateCause = AcctTerminateCause::AdminReboot;
+pub const ACCT_TERMINATE_CAUSE_PORT_ERROR: AcctTerminateCause = AcctTerminateCause::PortError;
+pub const ACCT_TERMINATE_CAUSE_NAS_ERROR: AcctTerminateCause = AcctTerminateCause::NasError;
+pub const ACCT_TERMINATE_CAUSE_NAS_REQUEST: AcctTerminateCause = AcctTerminateCause::NasRequest;
+pub const ACCT_TERMINATE_CAUSE_NAS_REBOOT: AcctTerminateCause = AcctTerminateCause::NasReboot;
+pub const ACCT_TERMINATE_CAUSE_PORT_UNNEEDED: AcctTerminateCause = AcctTerminateCause::PortUnneeded;
+pub const ACCT_TERMINATE_CAUSE_PORT_PREEMPTED: AcctTerminateCause =
+    AcctTerminateCause::PortPreempted;
+pub const ACCT_TERMINATE_CAUSE_PORT_SUSPENDED: AcctTerminateCause =
+    AcctTerminateCause::PortSuspended;
+pub const ACCT_TERMINATE_CAUSE_SERVICE_UNAVAILABLE: AcctTerminateCause =
+    AcctTerminateCause::ServiceUnavailable;
+pub const ACCT_TERMINATE_CAUSE_CALLBACK: AcctTerminateCause = AcctTerminateCause::Callback;
+pub const ACCT_TERMINATE_CAUSE_USER_ERROR: AcctTerminateCause = AcctTerminateCause::UserError;
+pub const ACCT_TERMINATE_CAUSE_HOST_REQUEST: AcctTerminateCause = AcctTerminateCause::HostRequest;
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: ACCT_AUTHENTIC_TYPE,
+        name: "RADIUS",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: ACCT_AUTHENTIC_TYPE,
+        name: "Local",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: ACCT_AUTHENTIC_TYPE,
+        name: "Remote",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: ACCT_AUTHENTIC_TYPE,
+        name: "Diameter",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: ACCT_STATUS_TYPE_TYPE,
+        name: "Start",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: ACCT_STATUS_TYPE_TYPE,
+        name: "Stop",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: ACCT_STATUS_TYPE_TYPE,
+        name: "Alive",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: ACCT_STATUS_TYPE_TYPE,
+        name: "Interim-Update",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: ACCT_STATUS_TYPE_TYPE,
+        name: "Accounting-On",
+        value: 7,
+    },
+    ValueMetadata {
+        typ: ACCT_STATUS_TYPE_TYPE,
+        name: "Accounting-Off",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: ACCT_STATUS_TYPE_TYPE,
+        name: "Failed",
+        value: 15,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "User-Request",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Lost-Carrier",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Lost-Service",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Idle-Timeout",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Session-Timeout",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Admin-Reset",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Admin-Reboot",
+        value: 7,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Port-Error",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "NAS-Error",
+        value: 9,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "NAS-Request",
+        value: 10,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "NAS-Reboot",
+        value: 11,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Port-Unneeded",
+        value: 12,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Port-Preempted",
+        value: 13,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Port-Suspended",
+        value: 14,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Service-Unavailable",
+        value: 15,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Callback",
+        value: 16,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "User-Error",
+        value: 17,
+    },
+    ValueMetadata {
+        typ: ACCT_TERMINATE_CAUSE_TYPE,
+        name: "Host-Request",
+        value: 18,
+    },
+];
@@ -0,0 +1,178 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc2867 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 2867.
+//! #    http://www.ietf.org/rfc/rfc2867.txt
+//! #
+//! #    $Id$
+//! #
+//! ATTRIBUTE    Acct-Tunnel-Connection            68    string
+//! ATTRIBUTE    Acct-Tunnel-Packets-Lost        86    integer
+//!
+//! VALUE    Acct-Status-Type        Tunnel-Start        9
+//! VALUE    Acct-Status-Type        Tunnel-Stop        10
+//! VALUE    Acct-Status-Type        Tunnel-Reject        11
+//! VALUE    Acct-Status-Type        Tunnel-Link-Start    12
+//! VALUE    Acct-Status-Type        Tunnel-Link-Stop    13
+//! VALUE    Acct-Status-Type        Tunnel-Link-Reject    14
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+use crate::rfc2866;
+
+pub const ACCT_TUNNEL_CONNECTION_TYPE: AVPType = 68;
+/// Delete all of `acct_tunnel_connection` values from a packet.
+pub fn delete_acct_tunnel_connection(packet: &mut Packet) {
+    packet.delete(ACCT_TUNNEL_CONNECTION_TYPE);
+}
+/// Returns whether a packet has `acct_tunnel_connection` without decoding the value.
+pub fn has_acct_tunnel_connection(packet: &Packet) -> bool {
+    packet.contains(ACCT_TUNNEL_CONNECTION_TYPE)
+}
+/// Returns the number of `acct_tunnel_connection` attributes in a packet.
+pub fn count_acct_tunnel_connection(packet: &Packet) -> usize {
+    packet.count(ACCT_TUNNEL_CONNECTION_TYPE)
+}
+/// Add `acct_tunnel_connection` string value to a packet.
+pub fn add_acct_tunnel_connection(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(ACCT_TUNNEL_CONNECTION_TYPE, value));
+}
+/// Lookup a `acct_tunnel_connection` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_tunnel_connection`, it returns `None`.
+pub fn lookup_acct_tunnel_connection(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(ACCT_TUNNEL_CONNECTION_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Acct-Tunnel-Connection"))
+    })
+}
+/// Lookup all of the `acct_tunnel_connection` string value from a packet.
+pub fn lookup_all_acct_tunnel_connection(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_TUNNEL_CONNECTION_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Acct-Tunnel-Connection"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_TUNNEL_PACKETS_LOST_TYPE: AVPType = 86;
+/// Delete all of `acct_tunnel_packets_lost` values from a packet.
+pub fn delete_acct_tunnel_packets_lost(packet: &mut Packet) {
+    packet.delete(ACCT_TUNNEL_PACKETS_LOST_TYPE);
+}
+/// Returns whether a packet has `acct_tunnel_packets_lost` without decoding the value.
+pub fn has_acct_tunnel_packets_lost(packet: &Packet) -> bool {
+    packet.contains(ACCT_TUNNEL_PACKETS_LOST_TYPE)
+}
+/// Returns the number of `acct_tunnel_packets_lost` attributes in a packet.
+pub fn count_acct_tunnel_packets_lost(packet: &Packet) -> usize {
+    packet.count(ACCT_TUNNEL_PACKETS_LOST_TYPE)
+}
+/// Add `acct_tunnel_packets_lost` integer value to a packet.
+pub fn add_acct_tunnel_packets_lost(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_TUNNEL_PACKETS_LOST_TYPE, value));
+}
+/// Lookup a `acct_tunnel_packets_lost` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_tunnel_packets_lost`, it returns `None`.
+pub fn lookup_acct_tunnel_packets_lost(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_TUNNEL_PACKETS_LOST_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Tunnel-Packets-Lost"))
+    })
+}
+/// Lookup all of the `acct_tunnel_packets_lost` integer value from a packet.
+pub fn lookup_all_acct_tunnel_packets_lost(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_TUNNEL_PACKETS_LOST_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Tunnel-Packets-Lost"))?,
+        )
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Acct-Tunnel-Connection",
+        typ: ACCT_TUNNEL_CONNECTION_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Tunnel-Packets-Lost",
+        typ: ACCT_TUNNEL_PACKETS_LOST_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+];
+
+pub const ACCT_STATUS_TYPE_TUNNEL_START: rfc2866::AcctStatusType =
+    rfc2866::AcctStatusType::from_u32(9);
+pub const ACCT_STATUS_TYPE_TUNNEL_STOP: rfc2866::AcctStatusType =
+    rfc2866::AcctStatusType::from_u32(10);
+pub const ACCT_STATUS_TYPE_TUNNEL_REJECT: rfc2866::AcctStatusType =
+    rfc2866::AcctStatusType::from_u32(11);
+pub const ACCT_STATUS_TYPE_TUNNEL_LINK_START: rfc2866::AcctStatusType =
+    rfc2866::AcctStatusType::from_u32(12);
+pub const ACCT_STATUS_TYPE_TUNNEL_LINK_STOP: rfc2866::AcctStatusType =
+    rfc2866::AcctStatusType::from_u32(13);
+pub const ACCT_STATUS_TYPE_TUNNEL_LINK_REJECT: rfc2866::AcctStatusType =
+    rfc2866::AcctStatusType::from_u32(14);
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: rfc2866::ACCT_STATUS_TYPE_TYPE,
+        name: "Tunnel-Start",
+        value: 9,
+    },
+    ValueMetadata {
+        typ: rfc2866::ACCT_STATUS_TYPE_TYPE,
+        name: "Tunnel-Stop",
+        value: 10,
+    },
+    ValueMetadata {
+        typ: rfc2866::ACCT_STATUS_TYPE_TYPE,
+        name: "Tunnel-Reject",
+        value: 11,
+    },
+    ValueMetadata {
+        typ: rfc2866::ACCT_STATUS_TYPE_TYPE,
+        name: "Tunnel-Link-Start",
+        value: 12,
+    },
+    ValueMetadata {
+        typ: rfc2866::ACCT_STATUS_TYPE_TYPE,
+        name: "Tunnel-Link-Stop",
+        value: 13,
+    },
+    ValueMetadata {
+        typ: rfc2866::ACCT_STATUS_TYPE_TYPE,
+        name: "Tunnel-Link-Reject",
+        value: 14,
+    },
+];
@@ -0,0 +1,1081 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc2868 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 2868.
+//! #    http://www.ietf.org/rfc/rfc2868.txt
+//! #
+//! #    `group` is a radius-rs specific option that bundles the tagged attributes
+//! #    which describe a tunnel, so that the attributes that share a tag can be looked up at once.
+//! #
+//! #    $Id$
+//! #
+//! ATTRIBUTE    Tunnel-Type                64    integer    has_tag,group=tunnel
+//! ATTRIBUTE    Tunnel-Medium-Type            65    integer    has_tag,group=tunnel
+//! ATTRIBUTE    Tunnel-Client-Endpoint            66    string    has_tag,group=tunnel
+//! ATTRIBUTE    Tunnel-Server-Endpoint            67    string    has_tag,group=tunnel
+//!
+//! ATTRIBUTE    Tunnel-Password                69    string    has_tag,encrypt=2,group=tunnel
+//!
+//! ATTRIBUTE    Tunnel-Private-Group-Id            81    string    has_tag,group=tunnel
+//! ATTRIBUTE    Tunnel-Assignment-Id            82    string    has_tag,group=tunnel
+//! ATTRIBUTE    Tunnel-Preference            83    integer    has_tag,group=tunnel
+//!
+//! ATTRIBUTE    Tunnel-Client-Auth-Id            90    string    has_tag,group=tunnel
+//! ATTRIBUTE    Tunnel-Server-Auth-Id            91    string    has_tag,group=tunnel
+//!
+//! #    Tunnel Type
+//!
+//! VALUE    Tunnel-Type            PPTP            1
+//! VALUE    Tunnel-Type            L2F            2
+//! VALUE    Tunnel-Type            L2TP            3
+//! VALUE    Tunnel-Type            ATMP            4
+//! VALUE    Tunnel-Type            VTP            5
+//! VALUE    Tunnel-Type            AH            6
+//! VALUE    Tunnel-Type            IP            7
+//! VALUE    Tunnel-Type            MIN-IP            8
+//! VALUE    Tunnel-Type            ESP            9
+//! VALUE    Tunnel-Type            GRE            10
+//! VALUE    Tunnel-Type            DVS            11
+//! VALUE    Tunnel-Type            IP-in-IP        12
+//!
+//! #    Tunnel Medium Type
+//!
+//! VALUE    Tunnel-Medium-Type        IP            1
+//! VALUE    Tunnel-Medium-Type        IPv4            1
+//! VALUE    Tunnel-Medium-Type        IPv6            2
+//! VALUE    Tunnel-Medium-Type        NSAP            3
+//! VALUE    Tunnel-Medium-Type        HDLC            4
+//! VALUE    Tunnel-Medium-Type        BBN-1822        5
+//! VALUE    Tunnel-Medium-Type        IEEE-802        6
+//! VALUE    Tunnel-Medium-Type        E.163            7
+//! VALUE    Tunnel-Medium-Type        E.164            8
+//! VALUE    Tunnel-Medium-Type        F.69            9
+//! VALUE    Tunnel-Medium-Type        X.121            10
+//! VALUE    Tunnel-Medium-Type        IPX            11
+//! VALUE    Tunnel-Medium-Type        Appletalk        12
+//! VALUE    Tunnel-Medium-Type        DecNet-IV        13
+//! VALUE    Tunnel-Medium-Type        Banyan-Vines        14
+//! VALUE    Tunnel-Medium-Type        E.164-NSAP        15
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+use crate::tag::Tag;
+
+pub const TUNNEL_TYPE_TYPE: AVPType = 64;
+/// Delete all of `tunnel_type` values from a packet.
+pub fn delete_tunnel_type(packet: &mut Packet) {
+    packet.delete(TUNNEL_TYPE_TYPE);
+}
+/// Returns whether a packet has `tunnel_type` without decoding the value.
+pub fn has_tunnel_type(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_TYPE_TYPE)
+}
+/// Returns the number of `tunnel_type` attributes in a packet.
+pub fn count_tunnel_type(packet: &Packet) -> usize {
+    packet.count(TUNNEL_TYPE_TYPE)
+}
+/// Add `tunnel_type` tagged value-defined integer value to a packet.
+pub fn add_tunnel_type(packet: &mut Packet, tag: Option<&Tag>, value: TunnelType) {
+    packet.add(AVP::from_tagged_u32(TUNNEL_TYPE_TYPE, tag, value.to_u32()));
+}
+/// Lookup a `tunnel_type` tagged value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_type`, it returns `None`.
+pub fn lookup_tunnel_type(packet: &Packet) -> Option<Result<(TunnelType, Tag), AVPError>> {
+    packet.lookup(TUNNEL_TYPE_TYPE).map(|v| {
+        let (v, t) = v
+            .decode_tagged_u32()
+            .map_err(|e| e.with_attribute_name("Tunnel-Type"))?;
+        Ok((TunnelType::from_u32(v), t))
+    })
+}
+/// Lookup all of the `tunnel_type` tagged value-defined integer value from a packet.
+pub fn lookup_all_tunnel_type(packet: &Packet) -> Result<Vec<(TunnelType, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_TYPE_TYPE) {
+        let (v, t) = avp
+            .decode_tagged_u32()
+            .map_err(|e| e.with_attribute_name("Tunnel-Type"))?;
+        vec.push((TunnelType::from_u32(v), t))
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_MEDIUM_TYPE_TYPE: AVPType = 65;
+/// Delete all of `tunnel_medium_type` values from a packet.
+pub fn delete_tunnel_medium_type(packet: &mut Packet) {
+    packet.delete(TUNNEL_MEDIUM_TYPE_TYPE);
+}
+/// Returns whether a packet has `tunnel_medium_type` without decoding the value.
+pub fn has_tunnel_medium_type(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_MEDIUM_TYPE_TYPE)
+}
+/// Returns the number of `tunnel_medium_type` attributes in a packet.
+pub fn count_tunnel_medium_type(packet: &Packet) -> usize {
+    packet.count(TUNNEL_MEDIUM_TYPE_TYPE)
+}
+/// Add `tunnel_medium_type` tagged value-defined integer value to a packet.
+pub fn add_tunnel_medium_type(packet: &mut Packet, tag: Option<&Tag>, value: TunnelMediumType) {
+    packet.add(AVP::from_tagged_u32(
+        TUNNEL_MEDIUM_TYPE_TYPE,
+        tag,
+        value.to_u32(),
+    ));
+}
+/// Lookup a `tunnel_medium_type` tagged value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_medium_type`, it returns `None`.
+pub fn lookup_tunnel_medium_type(
+    packet: &Packet,
+) -> Option<Result<(TunnelMediumType, Tag), AVPError>> {
+    packet.lookup(TUNNEL_MEDIUM_TYPE_TYPE).map(|v| {
+        let (v, t) = v
+            .decode_tagged_u32()
+            .map_err(|e| e.with_attribute_name("Tunnel-Medium-Type"))?;
+        Ok((TunnelMediumType::from_u32(v), t))
+    })
+}
+/// Lookup all of the `tunnel_medium_type` tagged value-defined integer value from a packet.
+pub fn lookup_all_tunnel_medium_type(
+    packet: &Packet,
+) -> Result<Vec<(TunnelMediumType, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_MEDIUM_TYPE_TYPE) {
+        let (v, t) = avp
+            .decode_tagged_u32()
+            .map_err(|e| e.with_attribute_name("Tunnel-Medium-Type"))?;
+        vec.push((TunnelMediumType::from_u32(v), t))
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_CLIENT_ENDPOINT_TYPE: AVPType = 66;
+/// Delete all of `tunnel_client_endpoint` values from a packet.
+pub fn delete_tunnel_client_endpoint(packet: &mut Packet) {
+    packet.delete(TUNNEL_CLIENT_ENDPOINT_TYPE);
+}
+/// Returns whether a packet has `tunnel_client_endpoint` without decoding the value.
+pub fn has_tunnel_client_endpoint(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_CLIENT_ENDPOINT_TYPE)
+}
+/// Returns the number of `tunnel_client_endpoint` attributes in a packet.
+pub fn count_tunnel_client_endpoint(packet: &Packet) -> usize {
+    packet.count(TUNNEL_CLIENT_ENDPOINT_TYPE)
+}
+/// Add `tunnel_client_endpoint` tagged string value to a packet.
+pub fn add_tunnel_client_endpoint(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        MAX_VALUE_LENGTH - usize::from(tag.is_some()),
+    );
+    packet.add(AVP::from_tagged_string(
+        TUNNEL_CLIENT_ENDPOINT_TYPE,
+        tag,
+        value,
+    ));
+}
+/// Lookup a `tunnel_client_endpoint` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_client_endpoint`, it returns `None`.
+pub fn lookup_tunnel_client_endpoint(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet.lookup(TUNNEL_CLIENT_ENDPOINT_TYPE).map(|v| {
+        v.decode_tagged_string()
+            .map_err(|e| e.with_attribute_name("Tunnel-Client-Endpoint"))
+    })
+}
+/// Lookup all of the `tunnel_client_endpoint` tagged string value from a packet.
+pub fn lookup_all_tunnel_client_endpoint(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_CLIENT_ENDPOINT_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("Tunnel-Client-Endpoint"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_SERVER_ENDPOINT_TYPE: AVPType = 67;
+/// Delete all of `tunnel_server_endpoint` values from a packet.
+pub fn delete_tunnel_server_endpoint(packet: &mut Packet) {
+    packet.delete(TUNNEL_SERVER_ENDPOINT_TYPE);
+}
+/// Returns whether a packet has `tunnel_server_endpoint` without decoding the value.
+pub fn has_tunnel_server_endpoint(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_SERVER_ENDPOINT_TYPE)
+}
+/// Returns the number of `tunnel_server_endpoint` attributes in a packet.
+pub fn count_tunnel_server_endpoint(packet: &Packet) -> usize {
+    packet.count(TUNNEL_SERVER_ENDPOINT_TYPE)
+}
+/// Add `tunnel_server_endpoint` tagged string value to a packet.
+pub fn add_tunnel_server_endpoint(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        MAX_VALUE_LENGTH - usize::from(tag.is_some()),
+    );
+    packet.add(AVP::from_tagged_string(
+        TUNNEL_SERVER_ENDPOINT_TYPE,
+        tag,
+        value,
+    ));
+}
+/// Lookup a `tunnel_server_endpoint` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_server_endpoint`, it returns `None`.
+pub fn lookup_tunnel_server_endpoint(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet.lookup(TUNNEL_SERVER_ENDPOINT_TYPE).map(|v| {
+        v.decode_tagged_string()
+            .map_err(|e| e.with_attribute_name("Tunnel-Server-Endpoint"))
+    })
+}
+/// Lookup all of the `tunnel_server_endpoint` tagged string value from a packet.
+pub fn lookup_all_tunnel_server_endpoint(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_SERVER_ENDPOINT_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("Tunnel-Server-Endpoint"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_PASSWORD_TYPE: AVPType = 69;
+/// Delete all of `tunnel_password` values from a packet.
+pub fn delete_tunnel_password(packet: &mut Packet) {
+    packet.delete(TUNNEL_PASSWORD_TYPE);
+}
+/// Returns whether a packet has `tunnel_password` without decoding the value.
+pub fn has_tunnel_password(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_PASSWORD_TYPE)
+}
+/// Returns the number of `tunnel_password` attributes in a packet.
+pub fn count_tunnel_password(packet: &Packet) -> usize {
+    packet.count(TUNNEL_PASSWORD_TYPE)
+}
+/// Add `tunnel_password` tunnel-password value to a packet.
+pub fn add_tunnel_password(
+    packet: &mut Packet,
+    tag: Option<&Tag>,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_tunnel_password_with_random_source(
+        TUNNEL_PASSWORD_TYPE,
+        tag,
+        value,
+        packet.get_secret(),
+        packet.get_authenticator(),
+        packet.get_random_source().as_ref(),
+    )?);
+    Ok(())
+}
+/// Lookup a `tunnel_password` tunnel-password value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_password`, it returns `None`.
+pub fn lookup_tunnel_password(packet: &Packet) -> Option<Result<(Vec<u8>, Tag), AVPError>> {
+    packet.lookup(TUNNEL_PASSWORD_TYPE).map(|v| {
+        v.decode_tunnel_password(packet.get_secret(), packet.get_authenticator())
+            .map_err(|e| e.with_attribute_name("Tunnel-Password"))
+    })
+}
+/// Lookup all of the `tunnel_password` tunnel-password value from a packet.
+pub fn lookup_all_tunnel_password(packet: &Packet) -> Result<Vec<(Vec<u8>, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_PASSWORD_TYPE) {
+        vec.push(
+            avp.decode_tunnel_password(packet.get_secret(), packet.get_authenticator())
+                .map_err(|e| e.with_attribute_name("Tunnel-Password"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_PRIVATE_GROUP_ID_TYPE: AVPType = 81;
+/// Delete all of `tunnel_private_group_id` values from a packet.
+pub fn delete_tunnel_private_group_id(packet: &mut Packet) {
+    packet.delete(TUNNEL_PRIVATE_GROUP_ID_TYPE);
+}
+/// Returns whether a packet has `tunnel_private_group_id` without decoding the value.
+pub fn has_tunnel_private_group_id(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_PRIVATE_GROUP_ID_TYPE)
+}
+/// Returns the number of `tunnel_private_group_id` attributes in a packet.
+pub fn count_tunnel_private_group_id(packet: &Packet) -> usize {
+    packet.count(TUNNEL_PRIVATE_GROUP_ID_TYPE)
+}
+/// Add `tunnel_private_group_id` tagged string value to a packet.
+pub fn add_tunnel_private_group_id(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        MAX_VALUE_LENGTH - usize::from(tag.is_some()),
+    );
+    packet.add(AVP::from_tagged_string(
+        TUNNEL_PRIVATE_GROUP_ID_TYPE,
+        tag,
+        value,
+    ));
+}
+/// Lookup a `tunnel_private_group_id` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_private_group_id`, it returns `None`.
+pub fn lookup_tunnel_private_group_id(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet.lookup(TUNNEL_PRIVATE_GROUP_ID_TYPE).map(|v| {
+        v.decode_tagged_string()
+            .map_err(|e| e.with_attribute_name("Tunnel-Private-Group-Id"))
+    })
+}
+/// Lookup all of the `tunnel_private_group_id` tagged string value from a packet.
+pub fn lookup_all_tunnel_private_group_id(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_PRIVATE_GROUP_ID_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("Tunnel-Private-Group-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_ASSIGNMENT_ID_TYPE: AVPType = 82;
+/// Delete all of `tunnel_assignment_id` values from a packet.
+pub fn delete_tunnel_assignment_id(packet: &mut Packet) {
+    packet.delete(TUNNEL_ASSIGNMENT_ID_TYPE);
+}
+/// Returns whether a packet has `tunnel_assignment_id` without decoding the value.
+pub fn has_tunnel_assignment_id(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_ASSIGNMENT_ID_TYPE)
+}
+/// Returns the number of `tunnel_assignment_id` attributes in a packet.
+pub fn count_tunnel_assignment_id(packet: &Packet) -> usize {
+    packet.count(TUNNEL_ASSIGNMENT_ID_TYPE)
+}
+/// Add `tunnel_assignment_id` tagged string value to a packet.
+pub fn add_tunnel_assignment_id(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        MAX_VALUE_LENGTH - usize::from(tag.is_some()),
+    );
+    packet.add(AVP::from_tagged_string(
+        TUNNEL_ASSIGNMENT_ID_TYPE,
+        tag,
+        value,
+    ));
+}
+/// Lookup a `tunnel_assignment_id` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_assignment_id`, it returns `None`.
+pub fn lookup_tunnel_assignment_id(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet.lookup(TUNNEL_ASSIGNMENT_ID_TYPE).map(|v| {
+        v.decode_tagged_string()
+            .map_err(|e| e.with_attribute_name("Tunnel-Assignment-Id"))
+    })
+}
+/// Lookup all of the `tunnel_assignment_id` tagged string value from a packet.
+pub fn lookup_all_tunnel_assignment_id(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_ASSIGNMENT_ID_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("Tunnel-Assignment-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_PREFERENCE_TYPE: AVPType = 83;
+/// Delete all of `tunnel_preference` values from a packet.
+pub fn delete_tunnel_preference(packet: &mut Packet) {
+    packet.delete(TUNNEL_PREFERENCE_TYPE);
+}
+/// Returns whether a packet has `tunnel_preference` without decoding the value.
+pub fn has_tunnel_preference(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_PREFERENCE_TYPE)
+}
+/// Returns the number of `tunnel_preference` attributes in a packet.
+pub fn count_tunnel_preference(packet: &Packet) -> usize {
+    packet.count(TUNNEL_PREFERENCE_TYPE)
+}
+/// Add `tunnel_preference` tagged integer value to a packet.
+pub fn add_tunnel_preference(packet: &mut Packet, tag: Option<&Tag>, value: u32) {
+    packet.add(AVP::from_tagged_u32(TUNNEL_PREFERENCE_TYPE, tag, value));
+}
+/// Lookup a `tunnel_preference` tagged integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_preference`, it returns `None`.
+pub fn lookup_tunnel_preference(packet: &Packet) -> Option<Result<(u32, Tag), AVPError>> {
+    packet.lookup(TUNNEL_PREFERENCE_TYPE).map(|v| {
+        v.decode_tagged_u32()
+            .map_err(|e| e.with_attribute_name("Tunnel-Preference"))
+    })
+}
+/// Lookup all of the `tunnel_preference` tagged integer value from a packet.
+pub fn lookup_all_tunnel_preference(packet: &Packet) -> Result<Vec<(u32, Tag)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_PREFERENCE_TYPE) {
+        vec.push(
+            avp.decode_tagged_u32()
+                .map_err(|e| e.with_attribute_name("Tunnel-Preference"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_CLIENT_AUTH_ID_TYPE: AVPType = 90;
+/// Delete all of `tunnel_client_auth_id` values from a packet.
+pub fn delete_tunnel_client_auth_id(packet: &mut Packet) {
+    packet.delete(TUNNEL_CLIENT_AUTH_ID_TYPE);
+}
+/// Returns whether a packet has `tunnel_client_auth_id` without decoding the value.
+pub fn has_tunnel_client_auth_id(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_CLIENT_AUTH_ID_TYPE)
+}
+/// Returns the number of `tunnel_client_auth_id` attributes in a packet.
+pub fn count_tunnel_client_auth_id(packet: &Packet) -> usize {
+    packet.count(TUNNEL_CLIENT_AUTH_ID_TYPE)
+}
+/// Add `tunnel_client_auth_id` tagged string value to a packet.
+pub fn add_tunnel_client_auth_id(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        MAX_VALUE_LENGTH - usize::from(tag.is_some()),
+    );
+    packet.add(AVP::from_tagged_string(
+        TUNNEL_CLIENT_AUTH_ID_TYPE,
+        tag,
+        value,
+    ));
+}
+/// Lookup a `tunnel_client_auth_id` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_client_auth_id`, it returns `None`.
+pub fn lookup_tunnel_client_auth_id(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet.lookup(TUNNEL_CLIENT_AUTH_ID_TYPE).map(|v| {
+        v.decode_tagged_string()
+            .map_err(|e| e.with_attribute_name("Tunnel-Client-Auth-Id"))
+    })
+}
+/// Lookup all of the `tunnel_client_auth_id` tagged string value from a packet.
+pub fn lookup_all_tunnel_client_auth_id(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_CLIENT_AUTH_ID_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("Tunnel-Client-Auth-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const TUNNEL_SERVER_AUTH_ID_TYPE: AVPType = 91;
+/// Delete all of `tunnel_server_auth_id` values from a packet.
+pub fn delete_tunnel_server_auth_id(packet: &mut Packet) {
+    packet.delete(TUNNEL_SERVER_AUTH_ID_TYPE);
+}
+/// Returns whether a packet has `tunnel_server_auth_id` without decoding the value.
+pub fn has_tunnel_server_auth_id(packet: &Packet) -> bool {
+    packet.contains(TUNNEL_SERVER_AUTH_ID_TYPE)
+}
+/// Returns the number of `tunnel_server_auth_id` attributes in a packet.
+pub fn count_tunnel_server_auth_id(packet: &Packet) -> usize {
+    packet.count(TUNNEL_SERVER_AUTH_ID_TYPE)
+}
+/// Add `tunnel_server_auth_id` tagged string value to a packet.
+pub fn add_tunnel_server_auth_id(packet: &mut Packet, tag: Option<&Tag>, value: impl AsRef<str>) {
+    let value = packet.truncate_string(
+        value.as_ref(),
+        MAX_VALUE_LENGTH - usize::from(tag.is_some()),
+    );
+    packet.add(AVP::from_tagged_string(
+        TUNNEL_SERVER_AUTH_ID_TYPE,
+        tag,
+        value,
+    ));
+}
+/// Lookup a `tunnel_server_auth_id` tagged string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `tunnel_server_auth_id`, it returns `None`.
+pub fn lookup_tunnel_server_auth_id(
+    packet: &Packet,
+) -> Option<Result<(String, Option<Tag>), AVPError>> {
+    packet.lookup(TUNNEL_SERVER_AUTH_ID_TYPE).map(|v| {
+        v.decode_tagged_string()
+            .map_err(|e| e.with_attribute_name("Tunnel-Server-Auth-Id"))
+    })
+}
+/// Lookup all of the `tunnel_server_auth_id` tagged string value from a packet.
+pub fn lookup_all_tunnel_server_auth_id(
+    packet: &Packet,
+) -> Result<Vec<(String, Option<Tag>)>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(TUNNEL_SERVER_AUTH_ID_TYPE) {
+        vec.push(
+            avp.decode_tagged_string()
+                .map_err(|e| e.with_attribute_name("Tunnel-Server-Auth-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+/// This struct represents the `tunnel` attributes that share the same tag.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TunnelGroup {
+    /// The value of `Tunnel-Type`.
+    pub tunnel_type: Option<TunnelType>,
+    /// The value of `Tunnel-Medium-Type`.
+    pub tunnel_medium_type: Option<TunnelMediumType>,
+    /// The value of `Tunnel-Client-Endpoint`.
+    pub tunnel_client_endpoint: Option<String>,
+    /// The value of `Tunnel-Server-Endpoint`.
+    pub tunnel_server_endpoint: Option<String>,
+    /// The value of `Tunnel-Password`.
+    pub tunnel_password: Option<Vec<u8>>,
+    /// The value of `Tunnel-Private-Group-Id`.
+    pub tunnel_private_group_id: Option<String>,
+    /// The value of `Tunnel-Assignment-Id`.
+    pub tunnel_assignment_id: Option<String>,
+    /// The value of `Tunnel-Preference`.
+    pub tunnel_preference: Option<u32>,
+    /// The value of `Tunnel-Client-Auth-Id`.
+    pub tunnel_client_auth_id: Option<String>,
+    /// The value of `Tunnel-Server-Auth-Id`.
+    pub tunnel_server_auth_id: Option<String>,
+}
+/// Lookup the `tunnel` attributes that have the given tag from a packet.
+///
+/// Each field has the first looked up value of the attribute with the tag; it is `None` if there is no such attribute.
+pub fn tunnel_group(packet: &Packet, tag: &Tag) -> Result<TunnelGroup, AVPError> {
+    Ok(TunnelGroup {
+        tunnel_type: lookup_all_tunnel_type(packet)?
+            .into_iter()
+            .find(|(_, t)| t == tag)
+            .map(|(v, _)| v),
+        tunnel_medium_type: lookup_all_tunnel_medium_type(packet)?
+            .into_iter()
+            .find(|(_, t)| t == tag)
+            .map(|(v, _)| v),
+        tunnel_client_endpoint: lookup_all_tunnel_client_endpoint(packet)?
+            .into_iter()
+            .find(|(_, t)| t.as_ref().map_or(tag.is_zero(), |t| t == tag))
+            .map(|(v, _)| v),
+        tunnel_server_endpoint: lookup_all_tunnel_server_endpoint(packet)?
+            .into_iter()
+            .find(|(_, t)| t.as_ref().map_or(tag.is_zero(), |t| t == tag))
+            .map(|(v, _)| v),
+        tunnel_password: lookup_all_tunnel_password(packet)?
+            .into_iter()
+            .find(|(_, t)| t == tag)
+            .map(|(v, _)| v),
+        tunnel_private_group_id: lookup_all_tunnel_private_group_id(packet)?
+            .into_iter()
+            .find(|(_, t)| t.as_ref().map_or(tag.is_zero(), |t| t == tag))
+            .map(|(v, _)| v),
+        tunnel_assignment_id: lookup_all_tunnel_assignment_id(packet)?
+            .into_iter()
+            .find(|(_, t)| t.as_ref().map_or(tag.is_zero(), |t| t == tag))
+            .map(|(v, _)| v),
+        tunnel_preference: lookup_all_tunnel_preference(packet)?
+            .into_iter()
+            .find(|(_, t)| t == tag)
+            .map(|(v, _)| v),
+        tunnel_client_auth_id: lookup_all_tunnel_client_auth_id(packet)?
+            .into_iter()
+            .find(|(_, t)| t.as_ref().map_or(tag.is_zero(), |t| t == tag))
+            .map(|(v, _)| v),
+        tunnel_server_auth_id: lookup_all_tunnel_server_auth_id(packet)?
+            .into_iter()
+            .find(|(_, t)| t.as_ref().map_or(tag.is_zero(), |t| t == tag))
+            .map(|(v, _)| v),
+    })
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Tunnel-Type",
+        typ: TUNNEL_TYPE_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(5),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Medium-Type",
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(5),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Client-Endpoint",
+        typ: TUNNEL_CLIENT_ENDPOINT_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Server-Endpoint",
+        typ: TUNNEL_SERVER_ENDPOINT_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Password",
+        typ: TUNNEL_PASSWORD_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: true,
+        has_tag: true,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Private-Group-Id",
+        typ: TUNNEL_PRIVATE_GROUP_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Assignment-Id",
+        typ: TUNNEL_ASSIGNMENT_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Preference",
+        typ: TUNNEL_PREFERENCE_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(5),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Client-Auth-Id",
+        typ: TUNNEL_CLIENT_AUTH_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Tunnel-Server-Auth-Id",
+        typ: TUNNEL_SERVER_AUTH_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: true,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+];
+
+/// The values of `Tunnel-Medium-Type`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TunnelMediumType {
+    Ip,
+    Ipv6,
+    Nsap,
+    Hdlc,
+    Bbn1822,
+    Ieee802,
+    E163,
+    E164,
+    F69,
+    X121,
+    Ipx,
+    Appletalk,
+    DecNetIV,
+    BanyanVines,
+    E164Nsap,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl TunnelMediumType {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => TunnelMediumType::Ip,
+            2 => TunnelMediumType::Ipv6,
+            3 => TunnelMediumType::Nsap,
+            4 => TunnelMediumType::Hdlc,
+            5 => TunnelMediumType::Bbn1822,
+            6 => TunnelMediumType::Ieee802,
+            7 => TunnelMediumType::E163,
+            8 => TunnelMediumType::E164,
+            9 => TunnelMediumType::F69,
+            10 => TunnelMediumType::X121,
+            11 => TunnelMediumType::Ipx,
+            12 => TunnelMediumType::Appletalk,
+            13 => TunnelMediumType::DecNetIV,
+            14 => TunnelMediumType::BanyanVines,
+            15 => TunnelMediumType::E164Nsap,
+            value => TunnelMediumType::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            TunnelMediumType::Ip => 1,
+            TunnelMediumType::Ipv6 => 2,
+            TunnelMediumType::Nsap => 3,
+            TunnelMediumType::Hdlc => 4,
+            TunnelMediumType::Bbn1822 => 5,
+            TunnelMediumType::Ieee802 => 6,
+            TunnelMediumType::E163 => 7,
+            TunnelMediumType::E164 => 8,
+            TunnelMediumType::F69 => 9,
+            TunnelMediumType::X121 => 10,
+            TunnelMediumType::Ipx => 11,
+            TunnelMediumType::Appletalk => 12,
+            TunnelMediumType::DecNetIV => 13,
+            TunnelMediumType::BanyanVines => 14,
+            TunnelMediumType::E164Nsap => 15,
+            TunnelMediumType::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for TunnelMediumType {
+    fn from(value: u32) -> Self {
+        TunnelMediumType::from_u32(value)
+    }
+}
+impl From<TunnelMediumType> for u32 {
+    fn from(value: TunnelMediumType) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for TunnelMediumType {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `IP`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "IP" => Ok(TunnelMediumType::Ip),
+            "IPv4" => Ok(TunnelMediumType::Ip),
+            "IPv6" => Ok(TunnelMediumType::Ipv6),
+            "NSAP" => Ok(TunnelMediumType::Nsap),
+            "HDLC" => Ok(TunnelMediumType::Hdlc),
+            "BBN-1822" => Ok(TunnelMediumType::Bbn1822),
+            "IEEE-802" => Ok(TunnelMediumType::Ieee802),
+            "E.163" => Ok(TunnelMediumType::E163),
+            "E.164" => Ok(TunnelMediumType::E164),
+            "F.69" => Ok(TunnelMediumType::F69),
+            "X.121" => Ok(TunnelMediumType::X121),
+            "IPX" => Ok(TunnelMediumType::Ipx),
+            "Appletalk" => Ok(TunnelMediumType::Appletalk),
+            "DecNet-IV" => Ok(TunnelMediumType::DecNetIV),
+            "Banyan-Vines" => Ok(TunnelMediumType::BanyanVines),
+            "E.164-NSAP" => Ok(TunnelMediumType::E164Nsap),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Tunnel-Medium-Type`: {name}"
+            ))),
+        }
+    }
+}
+pub const TUNNEL_MEDIUM_TYPE_IP: TunnelMediumType = TunnelMediumType::Ip;
+pub const TUNNEL_MEDIUM_TYPE_I_PV_4: TunnelMediumType = TunnelMediumType::Ip;
+pub const TUNNEL_MEDIUM_TYPE_I_PV_6: TunnelMediumType = TunnelMediumType::Ipv6;
+pub const TUNNEL_MEDIUM_TYPE_NSAP: TunnelMediumType = TunnelMediumType::Nsap;
+pub const TUNNEL_MEDIUM_TYPE_HDLC: TunnelMediumType = TunnelMediumType::Hdlc;
+pub const TUNNEL_MEDIUM_TYPE_BBN_1822: TunnelMediumType = TunnelMediumType::Bbn1822;
+pub const TUNNEL_MEDIUM_TYPE_IEEE_802: TunnelMediumType = TunnelMediumType::Ieee802;
+pub const TUNNEL_MEDIUM_TYPE_E_163: TunnelMediumType = TunnelMediumType::E163;
+pub const TUNNEL_MEDIUM_TYPE_E_164: TunnelMediumType = TunnelMediumType::E164;
+pub const TUNNEL_MEDIUM_TYPE_F_69: TunnelMediumType = TunnelMediumType::F69;
+pub const TUNNEL_MEDIUM_TYPE_X_121: TunnelMediumType = TunnelMediumType::X121;
+pub const TUNNEL_MEDIUM_TYPE_IPX: TunnelMediumType = TunnelMediumType::Ipx;
+pub const TUNNEL_MEDIUM_TYPE_APPLETALK: TunnelMediumType = TunnelMediumType::Appletalk;
+pub const TUNNEL_MEDIUM_TYPE_DEC_NET_IV: TunnelMediumType = TunnelMediumType::DecNetIV;
+pub const TUNNEL_MEDIUM_TYPE_BANYAN_VINES: TunnelMediumType = TunnelMediumType::BanyanVines;
+pub const TUNNEL_MEDIUM_TYPE_E_164_NSAP: TunnelMediumType = TunnelMediumType::E164Nsap;
+
+/// The values of `Tunnel-Type`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TunnelType {
+    Pptp,
+    L2F,
+    L2Tp,
+    Atmp,
+    Vtp,
+    Ah,
+    Ip,
+    MinIp,
+    Esp,
+    Gre,
+    Dvs,
+    IpInIP,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl TunnelType {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => TunnelType::Pptp,
+            2 => TunnelType::L2F,
+            3 => TunnelType::L2Tp,
+            4 => TunnelType::Atmp,
+            5 => TunnelType::Vtp,
+            6 => TunnelType::Ah,
+            7 => TunnelType::Ip,
+            8 => TunnelType::MinIp,
+            9 => TunnelType::Esp,
+            10 => TunnelType::Gre,
+            11 => TunnelType::Dvs,
+            12 => TunnelType::IpInIP,
+            value => TunnelType::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            TunnelType::Pptp => 1,
+            TunnelType::L2F => 2,
+            TunnelType::L2Tp => 3,
+            TunnelType::Atmp => 4,
+            TunnelType::Vtp => 5,
+            TunnelType::Ah => 6,
+            TunnelType::Ip => 7,
+            TunnelType::MinIp => 8,
+            TunnelType::Esp => 9,
+            TunnelType::Gre => 10,
+            TunnelType::Dvs => 11,
+            TunnelType::IpInIP => 12,
+            TunnelType::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for TunnelType {
+    fn from(value: u32) -> Self {
+        TunnelType::from_u32(value)
+    }
+}
+impl From<TunnelType> for u32 {
+    fn from(value: TunnelType) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for TunnelType {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `PPTP`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "PPTP" => Ok(TunnelType::Pptp),
+            "L2F" => Ok(TunnelType::L2F),
+            "L2TP" => Ok(TunnelType::L2Tp),
+            "ATMP" => Ok(TunnelType::Atmp),
+            "VTP" => Ok(TunnelType::Vtp),
+            "AH" => Ok(TunnelType::Ah),
+            "IP" => Ok(TunnelType::Ip),
+            "MIN-IP" => Ok(TunnelType::MinIp),
+            "ESP" => Ok(TunnelType::Esp),
+            "GRE" => Ok(TunnelType::Gre),
+            "DVS" => Ok(TunnelType::Dvs),
+            "IP-in-IP" => Ok(TunnelType::IpInIP),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Tunnel-Type`: {name}"
+            ))),
+        }
+    }
+}
+pub const TUNNEL_TYPE_PPTP: TunnelType = TunnelType::Pptp;
+pub const TUNNEL_TYPE_L2F: TunnelType = TunnelType::L2F;
+pub const TUNNEL_TYPE_L2TP: TunnelType = TunnelType::L2Tp;
+pub const TUNNEL_TYPE_ATMP: TunnelType = TunnelType::Atmp;
+pub const TUNNEL_TYPE_VTP: TunnelType = TunnelType::Vtp;
+pub const TUNNEL_TYPE_AH: TunnelType = TunnelType::Ah;
+pub const TUNNEL_TYPE_IP: TunnelType = TunnelType::Ip;
+pub const TUNNEL_TYPE_MIN_IP: TunnelType = TunnelType::MinIp;
+pub const TUNNEL_TYPE_ESP: TunnelType = TunnelType::Esp;
+pub const TUNNEL_TYPE_GRE: TunnelType = TunnelType::Gre;
+pub const TUNNEL_TYPE_DVS: TunnelType = TunnelType::Dvs;
+pub const TUNNEL_TYPE_IP_IN_IP: TunnelType = TunnelType::IpInIP;
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "IP",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "IPv4",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "IPv6",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "NSAP",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "HDLC",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "BBN-1822",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "IEEE-802",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "E.163",
+        value: 7,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "E.164",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "F.69",
+        value: 9,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "X.121",
+        value: 10,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "IPX",
+        value: 11,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "Appletalk",
+        value: 12,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "DecNet-IV",
+        value: 13,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "Banyan-Vines",
+        value: 14,
+    },
+    ValueMetadata {
+        typ: TUNNEL_MEDIUM_TYPE_TYPE,
+        name: "E.164-NSAP",
+        value: 15,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "PPTP",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "L2F",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "L2TP",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "ATMP",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "VTP",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "AH",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "IP",
+        value: 7,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "MIN-IP",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "ESP",
+        value: 9,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "GRE",
+        value: 10,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "DVS",
+        value: 11,
+    },
+    ValueMetadata {
+        typ: TUNNEL_TYPE_TYPE,
+        name: "IP-in-IP",
+        value: 12,
+    },
+];
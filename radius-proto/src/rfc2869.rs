@@ -0,0 +1,1111 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc2869 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 2869.
+//! #    http://www.ietf.org/rfc/rfc2869.txt
+//! #
+//! #    `max_length` is a radius-rs specific option for the `concat` attributes;
+//! #    4044 bytes is the most that fits in a 4096-byte packet as 253-byte fragments.
+//! #
+//! #    $Id$
+//! #
+//! ATTRIBUTE    Acct-Input-Gigawords            52    integer
+//! ATTRIBUTE    Acct-Output-Gigawords            53    integer
+//!
+//! ATTRIBUTE    Event-Timestamp                55    date
+//!
+//! ATTRIBUTE    ARAP-Password                70    octets[16]
+//! ATTRIBUTE    ARAP-Features                71    octets[14]
+//! ATTRIBUTE    ARAP-Zone-Access            72    integer
+//! ATTRIBUTE    ARAP-Security                73    integer
+//! ATTRIBUTE    ARAP-Security-Data            74    string
+//! ATTRIBUTE    Password-Retry                75    integer
+//! ATTRIBUTE    Prompt                    76    integer
+//! ATTRIBUTE    Connect-Info                77    string
+//! ATTRIBUTE    Configuration-Token            78    string
+//! ATTRIBUTE    EAP-Message                79    octets    concat,max_length=4044
+//! ATTRIBUTE    Message-Authenticator            80    octets
+//!
+//! ATTRIBUTE    ARAP-Challenge-Response            84    octets[8]
+//! ATTRIBUTE    Acct-Interim-Interval            85    integer    duration
+//! # 86: RFC 2867
+//! ATTRIBUTE    NAS-Port-Id                87    string
+//! ATTRIBUTE    Framed-Pool                88    string
+//!
+//! #    ARAP Zone Access
+//!
+//! VALUE    ARAP-Zone-Access        Default-Zone        1
+//! VALUE    ARAP-Zone-Access        Zone-Filter-Inclusive    2
+//! VALUE    ARAP-Zone-Access        Zone-Filter-Exclusive    4
+//!
+//! #    Prompt
+//! VALUE    Prompt                No-Echo            0
+//! VALUE    Prompt                Echo            1
+//! ```
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::avp::{AVPError, AVPType, JumboAttributePolicy, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+pub const ACCT_INPUT_GIGAWORDS_TYPE: AVPType = 52;
+/// Delete all of `acct_input_gigawords` values from a packet.
+pub fn delete_acct_input_gigawords(packet: &mut Packet) {
+    packet.delete(ACCT_INPUT_GIGAWORDS_TYPE);
+}
+/// Returns whether a packet has `acct_input_gigawords` without decoding the value.
+pub fn has_acct_input_gigawords(packet: &Packet) -> bool {
+    packet.contains(ACCT_INPUT_GIGAWORDS_TYPE)
+}
+/// Returns the number of `acct_input_gigawords` attributes in a packet.
+pub fn count_acct_input_gigawords(packet: &Packet) -> usize {
+    packet.count(ACCT_INPUT_GIGAWORDS_TYPE)
+}
+/// Add `acct_input_gigawords` integer value to a packet.
+pub fn add_acct_input_gigawords(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_INPUT_GIGAWORDS_TYPE, value));
+}
+/// Lookup a `acct_input_gigawords` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_input_gigawords`, it returns `None`.
+pub fn lookup_acct_input_gigawords(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_INPUT_GIGAWORDS_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Input-Gigawords"))
+    })
+}
+/// Lookup all of the `acct_input_gigawords` integer value from a packet.
+pub fn lookup_all_acct_input_gigawords(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_INPUT_GIGAWORDS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Input-Gigawords"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ACCT_OUTPUT_GIGAWORDS_TYPE: AVPType = 53;
+/// Delete all of `acct_output_gigawords` values from a packet.
+pub fn delete_acct_output_gigawords(packet: &mut Packet) {
+    packet.delete(ACCT_OUTPUT_GIGAWORDS_TYPE);
+}
+/// Returns whether a packet has `acct_output_gigawords` without decoding the value.
+pub fn has_acct_output_gigawords(packet: &Packet) -> bool {
+    packet.contains(ACCT_OUTPUT_GIGAWORDS_TYPE)
+}
+/// Returns the number of `acct_output_gigawords` attributes in a packet.
+pub fn count_acct_output_gigawords(packet: &Packet) -> usize {
+    packet.count(ACCT_OUTPUT_GIGAWORDS_TYPE)
+}
+/// Add `acct_output_gigawords` integer value to a packet.
+pub fn add_acct_output_gigawords(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_OUTPUT_GIGAWORDS_TYPE, value));
+}
+/// Lookup a `acct_output_gigawords` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_output_gigawords`, it returns `None`.
+pub fn lookup_acct_output_gigawords(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_OUTPUT_GIGAWORDS_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Output-Gigawords"))
+    })
+}
+/// Lookup all of the `acct_output_gigawords` integer value from a packet.
+pub fn lookup_all_acct_output_gigawords(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_OUTPUT_GIGAWORDS_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Output-Gigawords"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const EVENT_TIMESTAMP_TYPE: AVPType = 55;
+/// Delete all of `event_timestamp` values from a packet.
+pub fn delete_event_timestamp(packet: &mut Packet) {
+    packet.delete(EVENT_TIMESTAMP_TYPE);
+}
+/// Returns whether a packet has `event_timestamp` without decoding the value.
+pub fn has_event_timestamp(packet: &Packet) -> bool {
+    packet.contains(EVENT_TIMESTAMP_TYPE)
+}
+/// Returns the number of `event_timestamp` attributes in a packet.
+pub fn count_event_timestamp(packet: &Packet) -> usize {
+    packet.count(EVENT_TIMESTAMP_TYPE)
+}
+/// Add `event_timestamp` date value to a packet.
+pub fn add_event_timestamp(packet: &mut Packet, value: &DateTime<Utc>) -> Result<(), AVPError> {
+    packet.add(AVP::from_date(EVENT_TIMESTAMP_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `event_timestamp` date value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `event_timestamp`, it returns `None`.
+pub fn lookup_event_timestamp(packet: &Packet) -> Option<Result<DateTime<Utc>, AVPError>> {
+    packet.lookup(EVENT_TIMESTAMP_TYPE).map(|v| {
+        v.decode_date()
+            .map_err(|e| e.with_attribute_name("Event-Timestamp"))
+    })
+}
+/// Lookup all of the `event_timestamp` date value from a packet.
+pub fn lookup_all_event_timestamp(packet: &Packet) -> Result<Vec<DateTime<Utc>>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(EVENT_TIMESTAMP_TYPE) {
+        vec.push(
+            avp.decode_date()
+                .map_err(|e| e.with_attribute_name("Event-Timestamp"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ARAP_PASSWORD_TYPE: AVPType = 70;
+/// Delete all of `arap_password` values from a packet.
+pub fn delete_arap_password(packet: &mut Packet) {
+    packet.delete(ARAP_PASSWORD_TYPE);
+}
+/// Returns whether a packet has `arap_password` without decoding the value.
+pub fn has_arap_password(packet: &Packet) -> bool {
+    packet.contains(ARAP_PASSWORD_TYPE)
+}
+/// Returns the number of `arap_password` attributes in a packet.
+pub fn count_arap_password(packet: &Packet) -> usize {
+    packet.count(ARAP_PASSWORD_TYPE)
+}
+/// Add `arap_password` fixed-length octets value to a packet.
+pub fn add_arap_password(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    if value.len() != 16 {
+        return Err(AVPError::InvalidAttributeLengthError(
+            "16 bytes".to_owned(),
+            value.len(),
+        ));
+    }
+    packet.add(AVP::from_bytes(ARAP_PASSWORD_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `arap_password` fixed-length octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `arap_password`, it returns `None`.
+pub fn lookup_arap_password(packet: &Packet) -> Option<Vec<u8>> {
+    packet.lookup(ARAP_PASSWORD_TYPE).map(|v| v.decode_bytes())
+}
+/// Lookup all of the `arap_password` fixed-length octets value from a packet.
+pub fn lookup_all_arap_password(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ARAP_PASSWORD_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const ARAP_FEATURES_TYPE: AVPType = 71;
+/// Delete all of `arap_features` values from a packet.
+pub fn delete_arap_features(packet: &mut Packet) {
+    packet.delete(ARAP_FEATURES_TYPE);
+}
+/// Returns whether a packet has `arap_features` without decoding the value.
+pub fn has_arap_features(packet: &Packet) -> bool {
+    packet.contains(ARAP_FEATURES_TYPE)
+}
+/// Returns the number of `arap_features` attributes in a packet.
+pub fn count_arap_features(packet: &Packet) -> usize {
+    packet.count(ARAP_FEATURES_TYPE)
+}
+/// Add `arap_features` fixed-length octets value to a packet.
+pub fn add_arap_features(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    if value.len() != 14 {
+        return Err(AVPError::InvalidAttributeLengthError(
+            "14 bytes".to_owned(),
+            value.len(),
+        ));
+    }
+    packet.add(AVP::from_bytes(ARAP_FEATURES_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `arap_features` fixed-length octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `arap_features`, it returns `None`.
+pub fn lookup_arap_features(packet: &Packet) -> Option<Vec<u8>> {
+    packet.lookup(ARAP_FEATURES_TYPE).map(|v| v.decode_bytes())
+}
+/// Lookup all of the `arap_features` fixed-length octets value from a packet.
+pub fn lookup_all_arap_features(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ARAP_FEATURES_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const ARAP_ZONE_ACCESS_TYPE: AVPType = 72;
+/// Delete all of `arap_zone_access` values from a packet.
+pub fn delete_arap_zone_access(packet: &mut Packet) {
+    packet.delete(ARAP_ZONE_ACCESS_TYPE);
+}
+/// Returns whether a packet has `arap_zone_access` without decoding the value.
+pub fn has_arap_zone_access(packet: &Packet) -> bool {
+    packet.contains(ARAP_ZONE_ACCESS_TYPE)
+}
+/// Returns the number of `arap_zone_access` attributes in a packet.
+pub fn count_arap_zone_access(packet: &Packet) -> usize {
+    packet.count(ARAP_ZONE_ACCESS_TYPE)
+}
+/// Add `arap_zone_access` value-defined integer value to a packet.
+pub fn add_arap_zone_access(packet: &mut Packet, value: ArapZoneAccess) {
+    packet.add(AVP::from_u32(ARAP_ZONE_ACCESS_TYPE, value.to_u32()));
+}
+/// Lookup a `arap_zone_access` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `arap_zone_access`, it returns `None`.
+pub fn lookup_arap_zone_access(packet: &Packet) -> Option<Result<ArapZoneAccess, AVPError>> {
+    packet.lookup(ARAP_ZONE_ACCESS_TYPE).map(|v| {
+        Ok(ArapZoneAccess::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("ARAP-Zone-Access"))?,
+        ))
+    })
+}
+/// Lookup all of the `arap_zone_access` value-defined integer value from a packet.
+pub fn lookup_all_arap_zone_access(packet: &Packet) -> Result<Vec<ArapZoneAccess>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ARAP_ZONE_ACCESS_TYPE) {
+        vec.push(ArapZoneAccess::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ARAP-Zone-Access"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const ARAP_SECURITY_TYPE: AVPType = 73;
+/// Delete all of `arap_security` values from a packet.
+pub fn delete_arap_security(packet: &mut Packet) {
+    packet.delete(ARAP_SECURITY_TYPE);
+}
+/// Returns whether a packet has `arap_security` without decoding the value.
+pub fn has_arap_security(packet: &Packet) -> bool {
+    packet.contains(ARAP_SECURITY_TYPE)
+}
+/// Returns the number of `arap_security` attributes in a packet.
+pub fn count_arap_security(packet: &Packet) -> usize {
+    packet.count(ARAP_SECURITY_TYPE)
+}
+/// Add `arap_security` integer value to a packet.
+pub fn add_arap_security(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ARAP_SECURITY_TYPE, value));
+}
+/// Lookup a `arap_security` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `arap_security`, it returns `None`.
+pub fn lookup_arap_security(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ARAP_SECURITY_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("ARAP-Security"))
+    })
+}
+/// Lookup all of the `arap_security` integer value from a packet.
+pub fn lookup_all_arap_security(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ARAP_SECURITY_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("ARAP-Security"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const ARAP_SECURITY_DATA_TYPE: AVPType = 74;
+/// Delete all of `arap_security_data` values from a packet.
+pub fn delete_arap_security_data(packet: &mut Packet) {
+    packet.delete(ARAP_SECURITY_DATA_TYPE);
+}
+/// Returns whether a packet has `arap_security_data` without decoding the value.
+pub fn has_arap_security_data(packet: &Packet) -> bool {
+    packet.contains(ARAP_SECURITY_DATA_TYPE)
+}
+/// Returns the number of `arap_security_data` attributes in a packet.
+pub fn count_arap_security_data(packet: &Packet) -> usize {
+    packet.count(ARAP_SECURITY_DATA_TYPE)
+}
+/// Add `arap_security_data` string value to a packet.
+pub fn add_arap_security_data(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(ARAP_SECURITY_DATA_TYPE, value));
+}
+/// Lookup a `arap_security_data` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `arap_security_data`, it returns `None`.
+pub fn lookup_arap_security_data(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(ARAP_SECURITY_DATA_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("ARAP-Security-Data"))
+    })
+}
+/// Lookup all of the `arap_security_data` string value from a packet.
+pub fn lookup_all_arap_security_data(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ARAP_SECURITY_DATA_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("ARAP-Security-Data"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const PASSWORD_RETRY_TYPE: AVPType = 75;
+/// Delete all of `password_retry` values from a packet.
+pub fn delete_password_retry(packet: &mut Packet) {
+    packet.delete(PASSWORD_RETRY_TYPE);
+}
+/// Returns whether a packet has `password_retry` without decoding the value.
+pub fn has_password_retry(packet: &Packet) -> bool {
+    packet.contains(PASSWORD_RETRY_TYPE)
+}
+/// Returns the number of `password_retry` attributes in a packet.
+pub fn count_password_retry(packet: &Packet) -> usize {
+    packet.count(PASSWORD_RETRY_TYPE)
+}
+/// Add `password_retry` integer value to a packet.
+pub fn add_password_retry(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(PASSWORD_RETRY_TYPE, value));
+}
+/// Lookup a `password_retry` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `password_retry`, it returns `None`.
+pub fn lookup_password_retry(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(PASSWORD_RETRY_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Password-Retry"))
+    })
+}
+/// Lookup all of the `password_retry` integer value from a packet.
+pub fn lookup_all_password_retry(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PASSWORD_RETRY_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Password-Retry"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const PROMPT_TYPE: AVPType = 76;
+/// Delete all of `prompt` values from a packet.
+pub fn delete_prompt(packet: &mut Packet) {
+    packet.delete(PROMPT_TYPE);
+}
+/// Returns whether a packet has `prompt` without decoding the value.
+pub fn has_prompt(packet: &Packet) -> bool {
+    packet.contains(PROMPT_TYPE)
+}
+/// Returns the number of `prompt` attributes in a packet.
+pub fn count_prompt(packet: &Packet) -> usize {
+    packet.count(PROMPT_TYPE)
+}
+/// Add `prompt` value-defined integer value to a packet.
+pub fn add_prompt(packet: &mut Packet, value: Prompt) {
+    packet.add(AVP::from_u32(PROMPT_TYPE, value.to_u32()));
+}
+/// Lookup a `prompt` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `prompt`, it returns `None`.
+pub fn lookup_prompt(packet: &Packet) -> Option<Result<Prompt, AVPError>> {
+    packet.lookup(PROMPT_TYPE).map(|v| {
+        Ok(Prompt::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Prompt"))?,
+        ))
+    })
+}
+/// Lookup all of the `prompt` value-defined integer value from a packet.
+pub fn lookup_all_prompt(packet: &Packet) -> Result<Vec<Prompt>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PROMPT_TYPE) {
+        vec.push(Prompt::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Prompt"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const CONNECT_INFO_TYPE: AVPType = 77;
+/// Delete all of `connect_info` values from a packet.
+pub fn delete_connect_info(packet: &mut Packet) {
+    packet.delete(CONNECT_INFO_TYPE);
+}
+/// Returns whether a packet has `connect_info` without decoding the value.
+pub fn has_connect_info(packet: &Packet) -> bool {
+    packet.contains(CONNECT_INFO_TYPE)
+}
+/// Returns the number of `connect_info` attributes in a packet.
+pub fn count_connect_info(packet: &Packet) -> usize {
+    packet.count(CONNECT_INFO_TYPE)
+}
+/// Add `connect_info` string value to a packet.
+pub fn add_connect_info(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(CONNECT_INFO_TYPE, value));
+}
+/// Lookup a `connect_info` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `connect_info`, it returns `None`.
+pub fn lookup_connect_info(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(CONNECT_INFO_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Connect-Info"))
+    })
+}
+/// Lookup all of the `connect_info` string value from a packet.
+pub fn lookup_all_connect_info(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CONNECT_INFO_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Connect-Info"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const CONFIGURATION_TOKEN_TYPE: AVPType = 78;
+/// Delete all of `configuration_token` values from a packet.
+pub fn delete_configuration_token(packet: &mut Packet) {
+    packet.delete(CONFIGURATION_TOKEN_TYPE);
+}
+/// Returns whether a packet has `configuration_token` without decoding the value.
+pub fn has_configuration_token(packet: &Packet) -> bool {
+    packet.contains(CONFIGURATION_TOKEN_TYPE)
+}
+/// Returns the number of `configuration_token` attributes in a packet.
+pub fn count_configuration_token(packet: &Packet) -> usize {
+    packet.count(CONFIGURATION_TOKEN_TYPE)
+}
+/// Add `configuration_token` string value to a packet.
+pub fn add_configuration_token(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(CONFIGURATION_TOKEN_TYPE, value));
+}
+/// Lookup a `configuration_token` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `configuration_token`, it returns `None`.
+pub fn lookup_configuration_token(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(CONFIGURATION_TOKEN_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Configuration-Token"))
+    })
+}
+/// Lookup all of the `configuration_token` string value from a packet.
+pub fn lookup_all_configuration_token(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(CONFIGURATION_TOKEN_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Configuration-Token"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const EAP_MESSAGE_TYPE: AVPType = 79;
+/// Delete all of `eap_message` values from a packet.
+pub fn delete_eap_message(packet: &mut Packet) {
+    packet.delete(EAP_MESSAGE_TYPE);
+}
+/// Returns whether a packet has `eap_message` without decoding the value.
+pub fn has_eap_message(packet: &Packet) -> bool {
+    packet.contains(EAP_MESSAGE_TYPE)
+}
+/// Returns the number of `eap_message` attributes in a packet.
+pub fn count_eap_message(packet: &Packet) -> usize {
+    packet.count(EAP_MESSAGE_TYPE)
+}
+/// Add `eap_message` octets value to a packet; the value is split into the multiple attributes.
+///
+/// It returns an error if the value exceeds 4044 bytes.
+pub fn add_eap_message(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    if value.len() > 4044 {
+        return Err(AVPError::InvalidAttributeLengthError(
+            "<= 4044 bytes".to_owned(),
+            value.len(),
+        ));
+    }
+    packet.add_with_policy(EAP_MESSAGE_TYPE, value, JumboAttributePolicy::Concat)
+}
+/// Lookup a `eap_message` octets value from a packet; the values of the attributes are concatenated.
+///
+/// If there is no associated value with `eap_message`, it returns `None`.
+/// It returns an error if the concatenated value exceeds 4044 bytes.
+pub fn lookup_eap_message(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
+    let avps = packet.lookup_all(EAP_MESSAGE_TYPE);
+    if avps.is_empty() {
+        return None;
+    }
+    let length = avps.iter().map(|v| v.raw_value().len()).sum::<usize>();
+    if length > 4044 {
+        return Some(Err(AVPError::InvalidAttributeLengthError(
+            "<= 4044 bytes".to_owned(),
+            length,
+        )
+        .with_attribute_name("EAP-Message")));
+    }
+    Some(Ok(avps.into_iter().fold(
+        Vec::with_capacity(length),
+        |mut acc, v| {
+            acc.extend_from_slice(v.raw_value());
+            acc
+        },
+    )))
+}
+
+pub const MESSAGE_AUTHENTICATOR_TYPE: AVPType = 80;
+/// Delete all of `message_authenticator` values from a packet.
+pub fn delete_message_authenticator(packet: &mut Packet) {
+    packet.delete(MESSAGE_AUTHENTICATOR_TYPE);
+}
+/// Returns whether a packet has `message_authenticator` without decoding the value.
+pub fn has_message_authenticator(packet: &Packet) -> bool {
+    packet.contains(MESSAGE_AUTHENTICATOR_TYPE)
+}
+/// Returns the number of `message_authenticator` attributes in a packet.
+pub fn count_message_authenticator(packet: &Packet) -> usize {
+    packet.count(MESSAGE_AUTHENTICATOR_TYPE)
+}
+/// Add `message_authenticator` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_message_authenticator(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(MESSAGE_AUTHENTICATOR_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `message_authenticator` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `message_authenticator`, it returns `None`.
+pub fn lookup_message_authenticator(packet: &Packet) -> Option<Vec<u8>> {
+    packet
+        .lookup(MESSAGE_AUTHENTICATOR_TYPE)
+        .map(|v| v.decode_bytes())
+}
+/// Lookup all of the `message_authenticator` octets value from a packet.
+pub fn lookup_all_message_authenticator(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(MESSAGE_AUTHENTICATOR_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const ARAP_CHALLENGE_RESPONSE_TYPE: AVPType = 84;
+/// Delete all of `arap_challenge_response` values from a packet.
+pub fn delete_arap_challenge_response(packet: &mut Packet) {
+    packet.delete(ARAP_CHALLENGE_RESPONSE_TYPE);
+}
+/// Returns whether a packet has `arap_challenge_response` without decoding the value.
+pub fn has_arap_challenge_response(packet: &Packet) -> bool {
+    packet.contains(ARAP_CHALLENGE_RESPONSE_TYPE)
+}
+/// Returns the number of `arap_challenge_response` attributes in a packet.
+pub fn count_arap_challenge_response(packet: &Packet) -> usize {
+    packet.count(ARAP_CHALLENGE_RESPONSE_TYPE)
+}
+/// Add `arap_challenge_response` fixed-length octets value to a packet.
+pub fn add_arap_challenge_response(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    if value.len() != 8 {
+        return Err(AVPError::InvalidAttributeLengthError(
+            "8 bytes".to_owned(),
+            value.len(),
+        ));
+    }
+    packet.add(AVP::from_bytes(ARAP_CHALLENGE_RESPONSE_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `arap_challenge_response` fixed-length octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `arap_challenge_response`, it returns `None`.
+pub fn lookup_arap_challenge_response(packet: &Packet) -> Option<Vec<u8>> {
+    packet
+        .lookup(ARAP_CHALLENGE_RESPONSE_TYPE)
+        .map(|v| v.decode_bytes())
+}
+/// Lookup all of the `arap_challenge_response` fixed-length octets value from a packet.
+pub fn lookup_all_arap_challenge_response(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ARAP_CHALLENGE_RESPONSE_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const ACCT_INTERIM_INTERVAL_TYPE: AVPType = 85;
+/// Delete all of `acct_interim_interval` values from a packet.
+pub fn delete_acct_interim_interval(packet: &mut Packet) {
+    packet.delete(ACCT_INTERIM_INTERVAL_TYPE);
+}
+/// Returns whether a packet has `acct_interim_interval` without decoding the value.
+pub fn has_acct_interim_interval(packet: &Packet) -> bool {
+    packet.contains(ACCT_INTERIM_INTERVAL_TYPE)
+}
+/// Returns the number of `acct_interim_interval` attributes in a packet.
+pub fn count_acct_interim_interval(packet: &Packet) -> usize {
+    packet.count(ACCT_INTERIM_INTERVAL_TYPE)
+}
+/// Add `acct_interim_interval` integer value to a packet.
+pub fn add_acct_interim_interval(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(ACCT_INTERIM_INTERVAL_TYPE, value));
+}
+/// Lookup a `acct_interim_interval` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_interim_interval`, it returns `None`.
+pub fn lookup_acct_interim_interval(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(ACCT_INTERIM_INTERVAL_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Acct-Interim-Interval"))
+    })
+}
+/// Lookup all of the `acct_interim_interval` integer value from a packet.
+pub fn lookup_all_acct_interim_interval(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ACCT_INTERIM_INTERVAL_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Acct-Interim-Interval"))?,
+        )
+    }
+    Ok(vec)
+}
+/// Add `acct_interim_interval` value to a packet as a duration.
+///
+/// The fractional part of a second is truncated. It returns an error if the duration exceeds `u32::MAX` seconds.
+pub fn add_acct_interim_interval_duration(
+    packet: &mut Packet,
+    value: Duration,
+) -> Result<(), AVPError> {
+    packet.add(AVP::from_duration(ACCT_INTERIM_INTERVAL_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `acct_interim_interval` value from a packet as a duration.
+///
+/// It returns the first looked up value. If there is no associated value with `acct_interim_interval`, it returns `None`.
+pub fn lookup_acct_interim_interval_duration(
+    packet: &Packet,
+) -> Option<Result<Duration, AVPError>> {
+    packet.lookup(ACCT_INTERIM_INTERVAL_TYPE).map(|v| {
+        v.decode_duration()
+            .map_err(|e| e.with_attribute_name("Acct-Interim-Interval"))
+    })
+}
+
+pub const NAS_PORT_ID_TYPE: AVPType = 87;
+/// Delete all of `nas_port_id` values from a packet.
+pub fn delete_nas_port_id(packet: &mut Packet) {
+    packet.delete(NAS_PORT_ID_TYPE);
+}
+/// Returns whether a packet has `nas_port_id` without decoding the value.
+pub fn has_nas_port_id(packet: &Packet) -> bool {
+    packet.contains(NAS_PORT_ID_TYPE)
+}
+/// Returns the number of `nas_port_id` attributes in a packet.
+pub fn count_nas_port_id(packet: &Packet) -> usize {
+    packet.count(NAS_PORT_ID_TYPE)
+}
+/// Add `nas_port_id` string value to a packet.
+pub fn add_nas_port_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(NAS_PORT_ID_TYPE, value));
+}
+/// Lookup a `nas_port_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `nas_port_id`, it returns `None`.
+pub fn lookup_nas_port_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(NAS_PORT_ID_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("NAS-Port-Id"))
+    })
+}
+/// Lookup all of the `nas_port_id` string value from a packet.
+pub fn lookup_all_nas_port_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(NAS_PORT_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("NAS-Port-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const FRAMED_POOL_TYPE: AVPType = 88;
+/// Delete all of `framed_pool` values from a packet.
+pub fn delete_framed_pool(packet: &mut Packet) {
+    packet.delete(FRAMED_POOL_TYPE);
+}
+/// Returns whether a packet has `framed_pool` without decoding the value.
+pub fn has_framed_pool(packet: &Packet) -> bool {
+    packet.contains(FRAMED_POOL_TYPE)
+}
+/// Returns the number of `framed_pool` attributes in a packet.
+pub fn count_framed_pool(packet: &Packet) -> usize {
+    packet.count(FRAMED_POOL_TYPE)
+}
+/// Add `framed_pool` string value to a packet.
+pub fn add_framed_pool(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(FRAMED_POOL_TYPE, value));
+}
+/// Lookup a `framed_pool` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_pool`, it returns `None`.
+pub fn lookup_framed_pool(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(FRAMED_POOL_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Framed-Pool"))
+    })
+}
+/// Lookup all of the `framed_pool` string value from a packet.
+pub fn lookup_all_framed_pool(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_POOL_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Framed-Pool"))?,
+        )
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Acct-Input-Gigawords",
+        typ: ACCT_INPUT_GIGAWORDS_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Output-Gigawords",
+        typ: ACCT_OUTPUT_GIGAWORDS_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Event-Timestamp",
+        typ: EVENT_TIMESTAMP_TYPE,
+        data_type: AttributeDataType::Date,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "ARAP-Password",
+        typ: ARAP_PASSWORD_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "ARAP-Features",
+        typ: ARAP_FEATURES_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(14),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "ARAP-Zone-Access",
+        typ: ARAP_ZONE_ACCESS_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "ARAP-Security",
+        typ: ARAP_SECURITY_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "ARAP-Security-Data",
+        typ: ARAP_SECURITY_DATA_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Password-Retry",
+        typ: PASSWORD_RETRY_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Prompt",
+        typ: PROMPT_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Connect-Info",
+        typ: CONNECT_INFO_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Configuration-Token",
+        typ: CONFIGURATION_TOKEN_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "EAP-Message",
+        typ: EAP_MESSAGE_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4044),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Message-Authenticator",
+        typ: MESSAGE_AUTHENTICATOR_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "ARAP-Challenge-Response",
+        typ: ARAP_CHALLENGE_RESPONSE_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(8),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Acct-Interim-Interval",
+        typ: ACCT_INTERIM_INTERVAL_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "NAS-Port-Id",
+        typ: NAS_PORT_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Framed-Pool",
+        typ: FRAMED_POOL_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+];
+
+/// The values of `ARAP-Zone-Access`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ArapZoneAccess {
+    DefaultZone,
+    ZoneFilterInclusive,
+    ZoneFilterExclusive,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ArapZoneAccess {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => ArapZoneAccess::DefaultZone,
+            2 => ArapZoneAccess::ZoneFilterInclusive,
+            4 => ArapZoneAccess::ZoneFilterExclusive,
+            value => ArapZoneAccess::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ArapZoneAccess::DefaultZone => 1,
+            ArapZoneAccess::ZoneFilterInclusive => 2,
+            ArapZoneAccess::ZoneFilterExclusive => 4,
+            ArapZoneAccess::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ArapZoneAccess {
+    fn from(value: u32) -> Self {
+        ArapZoneAccess::from_u32(value)
+    }
+}
+impl From<ArapZoneAccess> for u32 {
+    fn from(value: ArapZoneAccess) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ArapZoneAccess {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Default-Zone`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Default-Zone" => Ok(ArapZoneAccess::DefaultZone),
+            "Zone-Filter-Inclusive" => Ok(ArapZoneAccess::ZoneFilterInclusive),
+            "Zone-Filter-Exclusive" => Ok(ArapZoneAccess::ZoneFilterExclusive),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `ARAP-Zone-Access`: {name}"
+            ))),
+        }
+    }
+}
+pub const ARAP_ZONE_ACCESS_DEFAULT_ZONE: ArapZoneAccess = ArapZoneAccess::DefaultZone;
+pub const ARAP_ZONE_ACCESS_ZONE_FILTER_INCLUSIVE: ArapZoneAccess =
+    ArapZoneAccess::ZoneFilterInclusive;
+pub const ARAP_ZONE_ACCESS_ZONE_FILTER_EXCLUSIVE: ArapZoneAccess =
+    ArapZoneAccess::ZoneFilterExclusive;
+
+/// The values of `Prompt`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Prompt {
+    NoEcho,
+    Echo,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl Prompt {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => Prompt::NoEcho,
+            1 => Prompt::Echo,
+            value => Prompt::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            Prompt::NoEcho => 0,
+            Prompt::Echo => 1,
+            Prompt::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for Prompt {
+    fn from(value: u32) -> Self {
+        Prompt::from_u32(value)
+    }
+}
+impl From<Prompt> for u32 {
+    fn from(value: Prompt) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for Prompt {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `No-Echo`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "No-Echo" => Ok(Prompt::NoEcho),
+            "Echo" => Ok(Prompt::Echo),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Prompt`: {name}"
+            ))),
+        }
+    }
+}
+pub const PROMPT_NO_ECHO: Prompt = Prompt::NoEcho;
+pub const PROMPT_ECHO: Prompt = Prompt::Echo;
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: ARAP_ZONE_ACCESS_TYPE,
+        name: "Default-Zone",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: ARAP_ZONE_ACCESS_TYPE,
+        name: "Zone-Filter-Inclusive",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: ARAP_ZONE_ACCESS_TYPE,
+        name: "Zone-Filter-Exclusive",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: PROMPT_TYPE,
+        name: "No-Echo",
+        value: 0,
+    },
+    ValueMetadata {
+        typ: PROMPT_TYPE,
+        name: "Echo",
+        value: 1,
+    },
+];
@@ -24,14 +24,23 @@
 
 use std::net::Ipv6Addr;
 
-use crate::core::avp::{AVPError, AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const NAS_IPV6_ADDRESS_TYPE: AVPType = 95;
 /// Delete all of `nas_ipv6_address` values from a packet.
 pub fn delete_nas_ipv6_address(packet: &mut Packet) {
     packet.delete(NAS_IPV6_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `nas_ipv6_address` without decoding the value.
+pub fn has_nas_ipv6_address(packet: &Packet) -> bool {
+    packet.contains(NAS_IPV6_ADDRESS_TYPE)
+}
+/// Returns the number of `nas_ipv6_address` attributes in a packet.
+pub fn count_nas_ipv6_address(packet: &Packet) -> usize {
+    packet.count(NAS_IPV6_ADDRESS_TYPE)
+}
 /// Add `nas_ipv6_address` ipv6addr value to a packet.
 pub fn add_nas_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
     packet.add(AVP::from_ipv6(NAS_IPV6_ADDRESS_TYPE, value));
@@ -40,15 +49,19 @@ pub fn add_nas_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
 ///
 /// It returns the first looked up value. If there is no associated value with `nas_ipv6_address`, it returns `None`.
 pub fn lookup_nas_ipv6_address(packet: &Packet) -> Option<Result<Ipv6Addr, AVPError>> {
-    packet
-        .lookup(NAS_IPV6_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv6())
+    packet.lookup(NAS_IPV6_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv6()
+            .map_err(|e| e.with_attribute_name("NAS-IPV6-Address"))
+    })
 }
 /// Lookup all of the `nas_ipv6_address` ipv6addr value from a packet.
 pub fn lookup_all_nas_ipv6_address(packet: &Packet) -> Result<Vec<Ipv6Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(NAS_IPV6_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv6()?)
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("NAS-IPV6-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -58,15 +71,27 @@ pub const FRAMED_INTERFACE_ID_TYPE: AVPType = 96;
 pub fn delete_framed_interface_id(packet: &mut Packet) {
     packet.delete(FRAMED_INTERFACE_ID_TYPE);
 }
+/// Returns whether a packet has `framed_interface_id` without decoding the value.
+pub fn has_framed_interface_id(packet: &Packet) -> bool {
+    packet.contains(FRAMED_INTERFACE_ID_TYPE)
+}
+/// Returns the number of `framed_interface_id` attributes in a packet.
+pub fn count_framed_interface_id(packet: &Packet) -> usize {
+    packet.count(FRAMED_INTERFACE_ID_TYPE)
+}
 /// Add `framed_interface_id` fixed-length octets value to a packet.
-pub fn add_framed_interface_id(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_framed_interface_id(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     if value.len() != 8 {
         return Err(AVPError::InvalidAttributeLengthError(
             "8 bytes".to_owned(),
             value.len(),
         ));
     }
-    packet.add(AVP::from_bytes(FRAMED_INTERFACE_ID_TYPE, value));
+    packet.add(AVP::from_bytes(FRAMED_INTERFACE_ID_TYPE, value)?);
     Ok(())
 }
 /// Lookup a `framed_interface_id` fixed-length octets value from a packet.
@@ -75,13 +100,13 @@ pub fn add_framed_interface_id(packet: &mut Packet, value: &[u8]) -> Result<(),
 pub fn lookup_framed_interface_id(packet: &Packet) -> Option<Vec<u8>> {
     packet
         .lookup(FRAMED_INTERFACE_ID_TYPE)
-        .map(|v| v.encode_bytes())
+        .map(|v| v.decode_bytes())
 }
 /// Lookup all of the `framed_interface_id` fixed-length octets value from a packet.
 pub fn lookup_all_framed_interface_id(packet: &Packet) -> Vec<Vec<u8>> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(FRAMED_INTERFACE_ID_TYPE) {
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_bytes())
     }
     vec
 }
@@ -91,8 +116,20 @@ pub const FRAMED_IPV6_PREFIX_TYPE: AVPType = 97;
 pub fn delete_framed_ipv6_prefix(packet: &mut Packet) {
     packet.delete(FRAMED_IPV6_PREFIX_TYPE);
 }
+/// Returns whether a packet has `framed_ipv6_prefix` without decoding the value.
+pub fn has_framed_ipv6_prefix(packet: &Packet) -> bool {
+    packet.contains(FRAMED_IPV6_PREFIX_TYPE)
+}
+/// Returns the number of `framed_ipv6_prefix` attributes in a packet.
+pub fn count_framed_ipv6_prefix(packet: &Packet) -> usize {
+    packet.count(FRAMED_IPV6_PREFIX_TYPE)
+}
 /// Add `framed_ipv6_prefix` ipv6 prefix value to a packet.
-pub fn add_framed_ipv6_prefix(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_framed_ipv6_prefix(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     packet.add(AVP::from_ipv6_prefix(FRAMED_IPV6_PREFIX_TYPE, value)?);
     Ok(())
 }
@@ -100,15 +137,19 @@ pub fn add_framed_ipv6_prefix(packet: &mut Packet, value: &[u8]) -> Result<(), A
 ///
 /// It returns the first looked up value. If there is no associated value with `framed_ipv6_prefix`, it returns `None`.
 pub fn lookup_framed_ipv6_prefix(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
-    packet
-        .lookup(FRAMED_IPV6_PREFIX_TYPE)
-        .map(|v| v.encode_ipv6_prefix())
+    packet.lookup(FRAMED_IPV6_PREFIX_TYPE).map(|v| {
+        v.decode_ipv6_prefix()
+            .map_err(|e| e.with_attribute_name("Framed-IPV6-Prefix"))
+    })
 }
 /// Lookup all of the `framed_ipv6_prefix` ipv6 prefix value from a packet.
 pub fn lookup_all_framed_ipv6_prefix(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(FRAMED_IPV6_PREFIX_TYPE) {
-        vec.push(avp.encode_ipv6_prefix()?)
+        vec.push(
+            avp.decode_ipv6_prefix()
+                .map_err(|e| e.with_attribute_name("Framed-IPV6-Prefix"))?,
+        )
     }
     Ok(vec)
 }
@@ -118,6 +159,14 @@ pub const LOGIN_IPV6_HOST_TYPE: AVPType = 98;
 pub fn delete_login_ipv6_host(packet: &mut Packet) {
     packet.delete(LOGIN_IPV6_HOST_TYPE);
 }
+/// Returns whether a packet has `login_ipv6_host` without decoding the value.
+pub fn has_login_ipv6_host(packet: &Packet) -> bool {
+    packet.contains(LOGIN_IPV6_HOST_TYPE)
+}
+/// Returns the number of `login_ipv6_host` attributes in a packet.
+pub fn count_login_ipv6_host(packet: &Packet) -> usize {
+    packet.count(LOGIN_IPV6_HOST_TYPE)
+}
 /// Add `login_ipv6_host` ipv6addr value to a packet.
 pub fn add_login_ipv6_host(packet: &mut Packet, value: &Ipv6Addr) {
     packet.add(AVP::from_ipv6(LOGIN_IPV6_HOST_TYPE, value));
@@ -126,13 +175,19 @@ pub fn add_login_ipv6_host(packet: &mut Packet, value: &Ipv6Addr) {
 ///
 /// It returns the first looked up value. If there is no associated value with `login_ipv6_host`, it returns `None`.
 pub fn lookup_login_ipv6_host(packet: &Packet) -> Option<Result<Ipv6Addr, AVPError>> {
-    packet.lookup(LOGIN_IPV6_HOST_TYPE).map(|v| v.encode_ipv6())
+    packet.lookup(LOGIN_IPV6_HOST_TYPE).map(|v| {
+        v.decode_ipv6()
+            .map_err(|e| e.with_attribute_name("Login-IPV6-Host"))
+    })
 }
 /// Lookup all of the `login_ipv6_host` ipv6addr value from a packet.
 pub fn lookup_all_login_ipv6_host(packet: &Packet) -> Result<Vec<Ipv6Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(LOGIN_IPV6_HOST_TYPE) {
-        vec.push(avp.encode_ipv6()?)
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("Login-IPV6-Host"))?,
+        )
     }
     Ok(vec)
 }
@@ -142,23 +197,36 @@ pub const FRAMED_IPV6_ROUTE_TYPE: AVPType = 99;
 pub fn delete_framed_ipv6_route(packet: &mut Packet) {
     packet.delete(FRAMED_IPV6_ROUTE_TYPE);
 }
+/// Returns whether a packet has `framed_ipv6_route` without decoding the value.
+pub fn has_framed_ipv6_route(packet: &Packet) -> bool {
+    packet.contains(FRAMED_IPV6_ROUTE_TYPE)
+}
+/// Returns the number of `framed_ipv6_route` attributes in a packet.
+pub fn count_framed_ipv6_route(packet: &Packet) -> usize {
+    packet.count(FRAMED_IPV6_ROUTE_TYPE)
+}
 /// Add `framed_ipv6_route` string value to a packet.
-pub fn add_framed_ipv6_route(packet: &mut Packet, value: &str) {
+pub fn add_framed_ipv6_route(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
     packet.add(AVP::from_string(FRAMED_IPV6_ROUTE_TYPE, value));
 }
 /// Lookup a `framed_ipv6_route` string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `framed_ipv6_route`, it returns `None`.
 pub fn lookup_framed_ipv6_route(packet: &Packet) -> Option<Result<String, AVPError>> {
-    packet
-        .lookup(FRAMED_IPV6_ROUTE_TYPE)
-        .map(|v| v.encode_string())
+    packet.lookup(FRAMED_IPV6_ROUTE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Framed-IPV6-Route"))
+    })
 }
 /// Lookup all of the `framed_ipv6_route` string value from a packet.
 pub fn lookup_all_framed_ipv6_route(packet: &Packet) -> Result<Vec<String>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(FRAMED_IPV6_ROUTE_TYPE) {
-        vec.push(avp.encode_string()?)
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Framed-IPV6-Route"))?,
+        )
     }
     Ok(vec)
 }
@@ -168,23 +236,97 @@ pub const FRAMED_IPV6_POOL_TYPE: AVPType = 100;
 pub fn delete_framed_ipv6_pool(packet: &mut Packet) {
     packet.delete(FRAMED_IPV6_POOL_TYPE);
 }
+/// Returns whether a packet has `framed_ipv6_pool` without decoding the value.
+pub fn has_framed_ipv6_pool(packet: &Packet) -> bool {
+    packet.contains(FRAMED_IPV6_POOL_TYPE)
+}
+/// Returns the number of `framed_ipv6_pool` attributes in a packet.
+pub fn count_framed_ipv6_pool(packet: &Packet) -> usize {
+    packet.count(FRAMED_IPV6_POOL_TYPE)
+}
 /// Add `framed_ipv6_pool` string value to a packet.
-pub fn add_framed_ipv6_pool(packet: &mut Packet, value: &str) {
+pub fn add_framed_ipv6_pool(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
     packet.add(AVP::from_string(FRAMED_IPV6_POOL_TYPE, value));
 }
 /// Lookup a `framed_ipv6_pool` string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `framed_ipv6_pool`, it returns `None`.
 pub fn lookup_framed_ipv6_pool(packet: &Packet) -> Option<Result<String, AVPError>> {
-    packet
-        .lookup(FRAMED_IPV6_POOL_TYPE)
-        .map(|v| v.encode_string())
+    packet.lookup(FRAMED_IPV6_POOL_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Framed-IPV6-Pool"))
+    })
 }
 /// Lookup all of the `framed_ipv6_pool` string value from a packet.
 pub fn lookup_all_framed_ipv6_pool(packet: &Packet) -> Result<Vec<String>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(FRAMED_IPV6_POOL_TYPE) {
-        vec.push(avp.encode_string()?)
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Framed-IPV6-Pool"))?,
+        )
     }
     Ok(vec)
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "NAS-IPV6-Address",
+        typ: NAS_IPV6_ADDRESS_TYPE,
+        data_type: AttributeDataType::Ipv6Addr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Framed-Interface-Id",
+        typ: FRAMED_INTERFACE_ID_TYPE,
+        data_type: AttributeDataType::IfId,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(8),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Framed-IPV6-Prefix",
+        typ: FRAMED_IPV6_PREFIX_TYPE,
+        data_type: AttributeDataType::Ipv6Prefix,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(18),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Login-IPV6-Host",
+        typ: LOGIN_IPV6_HOST_TYPE,
+        data_type: AttributeDataType::Ipv6Addr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Framed-IPV6-Route",
+        typ: FRAMED_IPV6_ROUTE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Framed-IPV6-Pool",
+        typ: FRAMED_IPV6_POOL_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
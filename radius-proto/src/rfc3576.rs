@@ -0,0 +1,304 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc3576 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 3576.
+//! #    http://www.ietf.org/rfc/rfc3576.txt
+//! #
+//! #    $Id$
+//! #
+//! ATTRIBUTE    Error-Cause                101    integer
+//!
+//! #    Service Types
+//!
+//! VALUE    Service-Type            Authorize-Only        17
+//!
+//! #    Error causes
+//!
+//! VALUE    Error-Cause            Residual-Context-Removed    201
+//! VALUE    Error-Cause            Invalid-EAP-Packet    202
+//! VALUE    Error-Cause            Unsupported-Attribute    401
+//! VALUE    Error-Cause            Missing-Attribute    402
+//! VALUE    Error-Cause            NAS-Identification-Mismatch    403
+//! VALUE    Error-Cause            Invalid-Request        404
+//! VALUE    Error-Cause            Unsupported-Service    405
+//! VALUE    Error-Cause            Unsupported-Extension    406
+//! VALUE    Error-Cause            Administratively-Prohibited    501
+//! VALUE    Error-Cause            Proxy-Request-Not-Routable    502
+//! VALUE    Error-Cause            Session-Context-Not-Found    503
+//! VALUE    Error-Cause            Session-Context-Not-Removable    504
+//! VALUE    Error-Cause            Proxy-Processing-Error    505
+//! VALUE    Error-Cause            Resources-Unavailable    506
+//! VALUE    Error-Cause            Request-Initiated    507
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+use crate::rfc2865;
+
+pub const ERROR_CAUSE_TYPE: AVPType = 101;
+/// Delete all of `error_cause` values from a packet.
+pub fn delete_error_cause(packet: &mut Packet) {
+    packet.delete(ERROR_CAUSE_TYPE);
+}
+/// Returns whether a packet has `error_cause` without decoding the value.
+pub fn has_error_cause(packet: &Packet) -> bool {
+    packet.contains(ERROR_CAUSE_TYPE)
+}
+/// Returns the number of `error_cause` attributes in a packet.
+pub fn count_error_cause(packet: &Packet) -> usize {
+    packet.count(ERROR_CAUSE_TYPE)
+}
+/// Add `error_cause` value-defined integer value to a packet.
+pub fn add_error_cause(packet: &mut Packet, value: ErrorCause) {
+    packet.add(AVP::from_u32(ERROR_CAUSE_TYPE, value.to_u32()));
+}
+/// Lookup a `error_cause` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `error_cause`, it returns `None`.
+pub fn lookup_error_cause(packet: &Packet) -> Option<Result<ErrorCause, AVPError>> {
+    packet.lookup(ERROR_CAUSE_TYPE).map(|v| {
+        Ok(ErrorCause::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Error-Cause"))?,
+        ))
+    })
+}
+/// Lookup all of the `error_cause` value-defined integer value from a packet.
+pub fn lookup_all_error_cause(packet: &Packet) -> Result<Vec<ErrorCause>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(ERROR_CAUSE_TYPE) {
+        vec.push(ErrorCause::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Error-Cause"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[AttributeMetadata {
+    name: "Error-Cause",
+    typ: ERROR_CAUSE_TYPE,
+    data_type: AttributeDataType::Integer,
+    encrypted: false,
+    has_tag: false,
+    max_length: Some(4),
+    max_occurrences: None,
+}];
+
+/// The values of `Error-Cause`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorCause {
+    ResidualContextRemoved,
+    InvalidEAPPacket,
+    UnsupportedAttribute,
+    MissingAttribute,
+    NasIdentificationMismatch,
+    InvalidRequest,
+    UnsupportedService,
+    UnsupportedExtension,
+    AdministrativelyProhibited,
+    ProxyRequestNotRoutable,
+    SessionContextNotFound,
+    SessionContextNotRemovable,
+    ProxyProcessingError,
+    ResourcesUnavailable,
+    RequestInitiated,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ErrorCause {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            201 => ErrorCause::ResidualContextRemoved,
+            202 => ErrorCause::InvalidEAPPacket,
+            401 => ErrorCause::UnsupportedAttribute,
+            402 => ErrorCause::MissingAttribute,
+            403 => ErrorCause::NasIdentificationMismatch,
+            404 => ErrorCause::InvalidRequest,
+            405 => ErrorCause::UnsupportedService,
+            406 => ErrorCause::UnsupportedExtension,
+            501 => ErrorCause::AdministrativelyProhibited,
+            502 => ErrorCause::ProxyRequestNotRoutable,
+            503 => ErrorCause::SessionContextNotFound,
+            504 => ErrorCause::SessionContextNotRemovable,
+            505 => ErrorCause::ProxyProcessingError,
+            506 => ErrorCause::ResourcesUnavailable,
+            507 => ErrorCause::RequestInitiated,
+            value => ErrorCause::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ErrorCause::ResidualContextRemoved => 201,
+            ErrorCause::InvalidEAPPacket => 202,
+            ErrorCause::UnsupportedAttribute => 401,
+            ErrorCause::MissingAttribute => 402,
+            ErrorCause::NasIdentificationMismatch => 403,
+            ErrorCause::InvalidRequest => 404,
+            ErrorCause::UnsupportedService => 405,
+            ErrorCause::UnsupportedExtension => 406,
+            ErrorCause::AdministrativelyProhibited => 501,
+            ErrorCause::ProxyRequestNotRoutable => 502,
+            ErrorCause::SessionContextNotFound => 503,
+            ErrorCause::SessionContextNotRemovable => 504,
+            ErrorCause::ProxyProcessingError => 505,
+            ErrorCause::ResourcesUnavailable => 506,
+            ErrorCause::RequestInitiated => 507,
+            ErrorCause::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ErrorCause {
+    fn from(value: u32) -> Self {
+        ErrorCause::from_u32(value)
+    }
+}
+impl From<ErrorCause> for u32 {
+    fn from(value: ErrorCause) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ErrorCause {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Residual-Context-Removed`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Residual-Context-Removed" => Ok(ErrorCause::ResidualContextRemoved),
+            "Invalid-EAP-Packet" => Ok(ErrorCause::InvalidEAPPacket),
+            "Unsupported-Attribute" => Ok(ErrorCause::UnsupportedAttribute),
+            "Missing-Attribute" => Ok(ErrorCause::MissingAttribute),
+            "NAS-Identification-Mismatch" => Ok(ErrorCause::NasIdentificationMismatch),
+            "Invalid-Request" => Ok(ErrorCause::InvalidRequest),
+            "Unsupported-Service" => Ok(ErrorCause::UnsupportedService),
+            "Unsupported-Extension" => Ok(ErrorCause::UnsupportedExtension),
+            "Administratively-Prohibited" => Ok(ErrorCause::AdministrativelyProhibited),
+            "Proxy-Request-Not-Routable" => Ok(ErrorCause::ProxyRequestNotRoutable),
+            "Session-Context-Not-Found" => Ok(ErrorCause::SessionContextNotFound),
+            "Session-Context-Not-Removable" => Ok(ErrorCause::SessionContextNotRemovable),
+            "Proxy-Processing-Error" => Ok(ErrorCause::ProxyProcessingError),
+            "Resources-Unavailable" => Ok(ErrorCause::ResourcesUnavailable),
+            "Request-Initiated" => Ok(ErrorCause::RequestInitiated),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Error-Cause`: {name}"
+            ))),
+        }
+    }
+}
+pub const ERROR_CAUSE_RESIDUAL_CONTEXT_REMOVED: ErrorCause = ErrorCause::ResidualContextRemoved;
+pub const ERROR_CAUSE_INVALID_EAP_PACKET: ErrorCause = ErrorCause::InvalidEAPPacket;
+pub const ERROR_CAUSE_UNSUPPORTED_ATTRIBUTE: ErrorCause = ErrorCause::UnsupportedAttribute;
+pub const ERROR_CAUSE_MISSING_ATTRIBUTE: ErrorCause = ErrorCause::MissingAttribute;
+pub const ERROR_CAUSE_NAS_IDENTIFICATION_MISMATCH: ErrorCause =
+    ErrorCause::NasIdentificationMismatch;
+pub const ERROR_CAUSE_INVALID_REQUEST: ErrorCause = ErrorCause::InvalidRequest;
+pub const ERROR_CAUSE_UNSUPPORTED_SERVICE: ErrorCause = ErrorCause::UnsupportedService;
+pub const ERROR_CAUSE_UNSUPPORTED_EXTENSION: ErrorCause = ErrorCause::UnsupportedExtension;
+pub const ERROR_CAUSE_ADMINISTRATIVELY_PROHIBITED: ErrorCause =
+    ErrorCause::AdministrativelyProhibited;
+pub const ERROR_CAUSE_PROXY_REQUEST_NOT_ROUTABLE: ErrorCause = ErrorCause::ProxyRequestNotRoutable;
+pub const ERROR_CAUSE_SESSION_CONTEXT_NOT_FOUND: ErrorCause = ErrorCause::SessionContextNotFound;
+pub const ERROR_CAUSE_SESSION_CONTEXT_NOT_REMOVABLE: ErrorCause =
+    ErrorCause::SessionContextNotRemovable;
+pub const ERROR_CAUSE_PROXY_PROCESSING_ERROR: ErrorCause = ErrorCause::ProxyProcessingError;
+pub const ERROR_CAUSE_RESOURCES_UNAVAILABLE: ErrorCause = ErrorCause::ResourcesUnavailable;
+pub const ERROR_CAUSE_REQUEST_INITIATED: ErrorCause = ErrorCause::RequestInitiated;
+
+pub const SERVICE_TYPE_AUTHORIZE_ONLY: rfc2865::ServiceType = rfc2865::ServiceType::from_u32(17);
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Residual-Context-Removed",
+        value: 201,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Invalid-EAP-Packet",
+        value: 202,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Unsupported-Attribute",
+        value: 401,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Missing-Attribute",
+        value: 402,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "NAS-Identification-Mismatch",
+        value: 403,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Invalid-Request",
+        value: 404,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Unsupported-Service",
+        value: 405,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Unsupported-Extension",
+        value: 406,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Administratively-Prohibited",
+        value: 501,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Proxy-Request-Not-Routable",
+        value: 502,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Session-Context-Not-Found",
+        value: 503,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Session-Context-Not-Removable",
+        value: 504,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Proxy-Processing-Error",
+        value: 505,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Resources-Unavailable",
+        value: 506,
+    },
+    ValueMetadata {
+        typ: ERROR_CAUSE_TYPE,
+        name: "Request-Initiated",
+        value: 507,
+    },
+    ValueMetadata {
+        typ: rfc2865::SERVICE_TYPE_TYPE,
+        name: "Authorize-Only",
+        value: 17,
+    },
+];
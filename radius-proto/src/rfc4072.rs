@@ -18,29 +18,56 @@
 //! ATTRIBUTE    EAP-Key-Name                102    octets
 //! ```
 
-use crate::core::avp::{AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const EAP_KEY_NAME_TYPE: AVPType = 102;
 /// Delete all of `eap_key_name` values from a packet.
 pub fn delete_eap_key_name(packet: &mut Packet) {
     packet.delete(EAP_KEY_NAME_TYPE);
 }
+/// Returns whether a packet has `eap_key_name` without decoding the value.
+pub fn has_eap_key_name(packet: &Packet) -> bool {
+    packet.contains(EAP_KEY_NAME_TYPE)
+}
+/// Returns the number of `eap_key_name` attributes in a packet.
+pub fn count_eap_key_name(packet: &Packet) -> usize {
+    packet.count(EAP_KEY_NAME_TYPE)
+}
 /// Add `eap_key_name` octets value to a packet.
-pub fn add_eap_key_name(packet: &mut Packet, value: &[u8]) {
-    packet.add(AVP::from_bytes(EAP_KEY_NAME_TYPE, value));
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_eap_key_name(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(EAP_KEY_NAME_TYPE, value)?);
+    Ok(())
 }
 /// Lookup a `eap_key_name` octets value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `eap_key_name`, it returns `None`.
 pub fn lookup_eap_key_name(packet: &Packet) -> Option<Vec<u8>> {
-    packet.lookup(EAP_KEY_NAME_TYPE).map(|v| v.encode_bytes())
+    packet.lookup(EAP_KEY_NAME_TYPE).map(|v| v.decode_bytes())
 }
 /// Lookup all of the `eap_key_name` octets value from a packet.
 pub fn lookup_all_eap_key_name(packet: &Packet) -> Vec<Vec<u8>> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(EAP_KEY_NAME_TYPE) {
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_bytes())
     }
     vec
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[AttributeMetadata {
+    name: "EAP-Key-Name",
+    typ: EAP_KEY_NAME_TYPE,
+    data_type: AttributeDataType::Octets,
+    encrypted: false,
+    has_tag: false,
+    max_length: Some(253),
+    max_occurrences: None,
+}];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
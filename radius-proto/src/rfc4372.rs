@@ -17,17 +17,33 @@
 //! ATTRIBUTE    Chargeable-User-Identity        89    octets
 //! ```
 
-use crate::core::avp::{AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const CHARGEABLE_USER_IDENTITY_TYPE: AVPType = 89;
 /// Delete all of `chargeable_user_identity` values from a packet.
 pub fn delete_chargeable_user_identity(packet: &mut Packet) {
     packet.delete(CHARGEABLE_USER_IDENTITY_TYPE);
 }
+/// Returns whether a packet has `chargeable_user_identity` without decoding the value.
+pub fn has_chargeable_user_identity(packet: &Packet) -> bool {
+    packet.contains(CHARGEABLE_USER_IDENTITY_TYPE)
+}
+/// Returns the number of `chargeable_user_identity` attributes in a packet.
+pub fn count_chargeable_user_identity(packet: &Packet) -> usize {
+    packet.count(CHARGEABLE_USER_IDENTITY_TYPE)
+}
 /// Add `chargeable_user_identity` octets value to a packet.
-pub fn add_chargeable_user_identity(packet: &mut Packet, value: &[u8]) {
-    packet.add(AVP::from_bytes(CHARGEABLE_USER_IDENTITY_TYPE, value));
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_chargeable_user_identity(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(CHARGEABLE_USER_IDENTITY_TYPE, value)?);
+    Ok(())
 }
 /// Lookup a `chargeable_user_identity` octets value from a packet.
 ///
@@ -35,13 +51,27 @@ pub fn add_chargeable_user_identity(packet: &mut Packet, value: &[u8]) {
 pub fn lookup_chargeable_user_identity(packet: &Packet) -> Option<Vec<u8>> {
     packet
         .lookup(CHARGEABLE_USER_IDENTITY_TYPE)
-        .map(|v| v.encode_bytes())
+        .map(|v| v.decode_bytes())
 }
 /// Lookup all of the `chargeable_user_identity` octets value from a packet.
 pub fn lookup_all_chargeable_user_identity(packet: &Packet) -> Vec<Vec<u8>> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(CHARGEABLE_USER_IDENTITY_TYPE) {
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_bytes())
     }
     vec
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[AttributeMetadata {
+    name: "Chargeable-User-Identity",
+    typ: CHARGEABLE_USER_IDENTITY_TYPE,
+    data_type: AttributeDataType::Octets,
+    encrypted: false,
+    has_tag: false,
+    max_length: Some(253),
+    max_occurrences: None,
+}];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -0,0 +1,307 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc4675 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 4675.
+//! #    http://www.ietf.org/rfc/rfc4675.txt
+//! #
+//! #    $Id$
+//! #
+//!
+//! #
+//! #  High byte = '1' (0x31) means the frames are tagged.
+//! #  High byte = '2' (0x32) means the frames are untagged.
+//! #
+//! #  Next 12 bits MUST be zero.
+//! #
+//! #  Lower 12 bits is the IEEE-802.1Q VLAN VID.
+//! #
+//! ATTRIBUTE    Egress-VLANID                56    integer
+//! ATTRIBUTE    Ingress-Filters                57    integer
+//!
+//! #
+//! #  First byte == '1' (0x31) means that the frames are tagged.
+//! #  First byte == '2' (0x32) means that the frames are untagged.
+//! #
+//! ATTRIBUTE    Egress-VLAN-Name            58    string
+//! ATTRIBUTE    User-Priority-Table            59    octets
+//!
+//! VALUE    Ingress-Filters            Enabled            1
+//! VALUE    Ingress-Filters            Disabled        2
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+pub const EGRESS_VLANID_TYPE: AVPType = 56;
+/// Delete all of `egress_vlanid` values from a packet.
+pub fn delete_egress_vlanid(packet: &mut Packet) {
+    packet.delete(EGRESS_VLANID_TYPE);
+}
+/// Returns whether a packet has `egress_vlanid` without decoding the value.
+pub fn has_egress_vlanid(packet: &Packet) -> bool {
+    packet.contains(EGRESS_VLANID_TYPE)
+}
+/// Returns the number of `egress_vlanid` attributes in a packet.
+pub fn count_egress_vlanid(packet: &Packet) -> usize {
+    packet.count(EGRESS_VLANID_TYPE)
+}
+/// Add `egress_vlanid` integer value to a packet.
+pub fn add_egress_vlanid(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(EGRESS_VLANID_TYPE, value));
+}
+/// Lookup a `egress_vlanid` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `egress_vlanid`, it returns `None`.
+pub fn lookup_egress_vlanid(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(EGRESS_VLANID_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Egress-VLANID"))
+    })
+}
+/// Lookup all of the `egress_vlanid` integer value from a packet.
+pub fn lookup_all_egress_vlanid(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(EGRESS_VLANID_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Egress-VLANID"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const INGRESS_FILTERS_TYPE: AVPType = 57;
+/// Delete all of `ingress_filters` values from a packet.
+pub fn delete_ingress_filters(packet: &mut Packet) {
+    packet.delete(INGRESS_FILTERS_TYPE);
+}
+/// Returns whether a packet has `ingress_filters` without decoding the value.
+pub fn has_ingress_filters(packet: &Packet) -> bool {
+    packet.contains(INGRESS_FILTERS_TYPE)
+}
+/// Returns the number of `ingress_filters` attributes in a packet.
+pub fn count_ingress_filters(packet: &Packet) -> usize {
+    packet.count(INGRESS_FILTERS_TYPE)
+}
+/// Add `ingress_filters` value-defined integer value to a packet.
+pub fn add_ingress_filters(packet: &mut Packet, value: IngressFilters) {
+    packet.add(AVP::from_u32(INGRESS_FILTERS_TYPE, value.to_u32()));
+}
+/// Lookup a `ingress_filters` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `ingress_filters`, it returns `None`.
+pub fn lookup_ingress_filters(packet: &Packet) -> Option<Result<IngressFilters, AVPError>> {
+    packet.lookup(INGRESS_FILTERS_TYPE).map(|v| {
+        Ok(IngressFilters::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Ingress-Filters"))?,
+        ))
+    })
+}
+/// Lookup all of the `ingress_filters` value-defined integer value from a packet.
+pub fn lookup_all_ingress_filters(packet: &Packet) -> Result<Vec<IngressFilters>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(INGRESS_FILTERS_TYPE) {
+        vec.push(IngressFilters::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Ingress-Filters"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const EGRESS_VLAN_NAME_TYPE: AVPType = 58;
+/// Delete all of `egress_vlan_name` values from a packet.
+pub fn delete_egress_vlan_name(packet: &mut Packet) {
+    packet.delete(EGRESS_VLAN_NAME_TYPE);
+}
+/// Returns whether a packet has `egress_vlan_name` without decoding the value.
+pub fn has_egress_vlan_name(packet: &Packet) -> bool {
+    packet.contains(EGRESS_VLAN_NAME_TYPE)
+}
+/// Returns the number of `egress_vlan_name` attributes in a packet.
+pub fn count_egress_vlan_name(packet: &Packet) -> usize {
+    packet.count(EGRESS_VLAN_NAME_TYPE)
+}
+/// Add `egress_vlan_name` string value to a packet.
+pub fn add_egress_vlan_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(EGRESS_VLAN_NAME_TYPE, value));
+}
+/// Lookup a `egress_vlan_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `egress_vlan_name`, it returns `None`.
+pub fn lookup_egress_vlan_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(EGRESS_VLAN_NAME_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Egress-VLAN-Name"))
+    })
+}
+/// Lookup all of the `egress_vlan_name` string value from a packet.
+pub fn lookup_all_egress_vlan_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(EGRESS_VLAN_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Egress-VLAN-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const USER_PRIORITY_TABLE_TYPE: AVPType = 59;
+/// Delete all of `user_priority_table` values from a packet.
+pub fn delete_user_priority_table(packet: &mut Packet) {
+    packet.delete(USER_PRIORITY_TABLE_TYPE);
+}
+/// Returns whether a packet has `user_priority_table` without decoding the value.
+pub fn has_user_priority_table(packet: &Packet) -> bool {
+    packet.contains(USER_PRIORITY_TABLE_TYPE)
+}
+/// Returns the number of `user_priority_table` attributes in a packet.
+pub fn count_user_priority_table(packet: &Packet) -> usize {
+    packet.count(USER_PRIORITY_TABLE_TYPE)
+}
+/// Add `user_priority_table` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_user_priority_table(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(USER_PRIORITY_TABLE_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `user_priority_table` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `user_priority_table`, it returns `None`.
+pub fn lookup_user_priority_table(packet: &Packet) -> Option<Vec<u8>> {
+    packet
+        .lookup(USER_PRIORITY_TABLE_TYPE)
+        .map(|v| v.decode_bytes())
+}
+/// Lookup all of the `user_priority_table` octets value from a packet.
+pub fn lookup_all_user_priority_table(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(USER_PRIORITY_TABLE_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Egress-VLANID",
+        typ: EGRESS_VLANID_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Ingress-Filters",
+        typ: INGRESS_FILTERS_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Egress-VLAN-Name",
+        typ: EGRESS_VLAN_NAME_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "User-Priority-Table",
+        typ: USER_PRIORITY_TABLE_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+];
+
+/// The values of `Ingress-Filters`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IngressFilters {
+    Enabled,
+    Disabled,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl IngressFilters {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => IngressFilters::Enabled,
+            2 => IngressFilters::Disabled,
+            value => IngressFilters::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            IngressFilters::Enabled => 1,
+            IngressFilters::Disabled => 2,
+            IngressFilters::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for IngressFilters {
+    fn from(value: u32) -> Self {
+        IngressFilters::from_u32(value)
+    }
+}
+impl From<IngressFilters> for u32 {
+    fn from(value: IngressFilters) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for IngressFilters {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Enabled`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Enabled" => Ok(IngressFilters::Enabled),
+            "Disabled" => Ok(IngressFilters::Disabled),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Ingress-Filters`: {name}"
+            ))),
+        }
+    }
+}
+pub const INGRESS_FILTERS_ENABLED: IngressFilters = IngressFilters::Enabled;
+pub const INGRESS_FILTERS_DISABLED: IngressFilters = IngressFilters::Disabled;
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: INGRESS_FILTERS_TYPE,
+        name: "Enabled",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: INGRESS_FILTERS_TYPE,
+        name: "Disabled",
+        value: 2,
+    },
+];
@@ -20,16 +20,29 @@
 //! ATTRIBUTE    Delegated-IPV6-Prefix            123    ipv6prefix
 //! ```
 
-use crate::core::avp::{AVPError, AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const DELEGATED_IPV6_PREFIX_TYPE: AVPType = 123;
 /// Delete all of `delegated_ipv6_prefix` values from a packet.
 pub fn delete_delegated_ipv6_prefix(packet: &mut Packet) {
     packet.delete(DELEGATED_IPV6_PREFIX_TYPE);
 }
+/// Returns whether a packet has `delegated_ipv6_prefix` without decoding the value.
+pub fn has_delegated_ipv6_prefix(packet: &Packet) -> bool {
+    packet.contains(DELEGATED_IPV6_PREFIX_TYPE)
+}
+/// Returns the number of `delegated_ipv6_prefix` attributes in a packet.
+pub fn count_delegated_ipv6_prefix(packet: &Packet) -> usize {
+    packet.count(DELEGATED_IPV6_PREFIX_TYPE)
+}
 /// Add `delegated_ipv6_prefix` ipv6 prefix value to a packet.
-pub fn add_delegated_ipv6_prefix(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_delegated_ipv6_prefix(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     packet.add(AVP::from_ipv6_prefix(DELEGATED_IPV6_PREFIX_TYPE, value)?);
     Ok(())
 }
@@ -37,15 +50,33 @@ pub fn add_delegated_ipv6_prefix(packet: &mut Packet, value: &[u8]) -> Result<()
 ///
 /// It returns the first looked up value. If there is no associated value with `delegated_ipv6_prefix`, it returns `None`.
 pub fn lookup_delegated_ipv6_prefix(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
-    packet
-        .lookup(DELEGATED_IPV6_PREFIX_TYPE)
-        .map(|v| v.encode_ipv6_prefix())
+    packet.lookup(DELEGATED_IPV6_PREFIX_TYPE).map(|v| {
+        v.decode_ipv6_prefix()
+            .map_err(|e| e.with_attribute_name("Delegated-IPV6-Prefix"))
+    })
 }
 /// Lookup all of the `delegated_ipv6_prefix` ipv6 prefix value from a packet.
 pub fn lookup_all_delegated_ipv6_prefix(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(DELEGATED_IPV6_PREFIX_TYPE) {
-        vec.push(avp.encode_ipv6_prefix()?)
+        vec.push(
+            avp.decode_ipv6_prefix()
+                .map_err(|e| e.with_attribute_name("Delegated-IPV6-Prefix"))?,
+        )
     }
     Ok(vec)
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[AttributeMetadata {
+    name: "Delegated-IPV6-Prefix",
+    typ: DELEGATED_IPV6_PREFIX_TYPE,
+    data_type: AttributeDataType::Ipv6Prefix,
+    encrypted: false,
+    has_tag: false,
+    max_length: Some(18),
+    max_occurrences: None,
+}];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -17,31 +17,59 @@
 //! ATTRIBUTE    NAS-Filter-Rule                92    string
 //! ```
 
-use crate::core::avp::{AVPError, AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const NAS_FILTER_RULE_TYPE: AVPType = 92;
 /// Delete all of `nas_filter_rule` values from a packet.
 pub fn delete_nas_filter_rule(packet: &mut Packet) {
     packet.delete(NAS_FILTER_RULE_TYPE);
 }
+/// Returns whether a packet has `nas_filter_rule` without decoding the value.
+pub fn has_nas_filter_rule(packet: &Packet) -> bool {
+    packet.contains(NAS_FILTER_RULE_TYPE)
+}
+/// Returns the number of `nas_filter_rule` attributes in a packet.
+pub fn count_nas_filter_rule(packet: &Packet) -> usize {
+    packet.count(NAS_FILTER_RULE_TYPE)
+}
 /// Add `nas_filter_rule` string value to a packet.
-pub fn add_nas_filter_rule(packet: &mut Packet, value: &str) {
+pub fn add_nas_filter_rule(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
     packet.add(AVP::from_string(NAS_FILTER_RULE_TYPE, value));
 }
 /// Lookup a `nas_filter_rule` string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `nas_filter_rule`, it returns `None`.
 pub fn lookup_nas_filter_rule(packet: &Packet) -> Option<Result<String, AVPError>> {
-    packet
-        .lookup(NAS_FILTER_RULE_TYPE)
-        .map(|v| v.encode_string())
+    packet.lookup(NAS_FILTER_RULE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("NAS-Filter-Rule"))
+    })
 }
 /// Lookup all of the `nas_filter_rule` string value from a packet.
 pub fn lookup_all_nas_filter_rule(packet: &Packet) -> Result<Vec<String>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(NAS_FILTER_RULE_TYPE) {
-        vec.push(avp.encode_string()?)
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("NAS-Filter-Rule"))?,
+        )
     }
     Ok(vec)
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[AttributeMetadata {
+    name: "NAS-Filter-Rule",
+    typ: NAS_FILTER_RULE_TYPE,
+    data_type: AttributeDataType::String,
+    encrypted: false,
+    has_tag: false,
+    max_length: Some(253),
+    max_occurrences: None,
+}];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
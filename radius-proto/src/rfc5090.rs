@@ -0,0 +1,1008 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc5090 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 5090.
+//! #    http://www.ietf.org/rfc/rfc5090.txt
+//! #
+//! #    $Id$
+//! #
+//! ATTRIBUTE    Digest-Response                103    string
+//! ATTRIBUTE    Digest-Realm                104    string
+//! ATTRIBUTE    Digest-Nonce                105    string
+//! ATTRIBUTE    Digest-Response-Auth            106    string
+//! ATTRIBUTE    Digest-Nextnonce            107    string
+//! ATTRIBUTE    Digest-Method                108    string
+//! ATTRIBUTE    Digest-URI                109    string
+//! ATTRIBUTE    Digest-Qop                110    string
+//! ATTRIBUTE    Digest-Algorithm            111    string
+//! ATTRIBUTE    Digest-Entity-Body-Hash            112    string
+//! ATTRIBUTE    Digest-CNonce                113    string
+//! ATTRIBUTE    Digest-Nonce-Count            114    string
+//! ATTRIBUTE    Digest-Username                115    string
+//! ATTRIBUTE    Digest-Opaque                116    string
+//! ATTRIBUTE    Digest-Auth-Param            117    string
+//! ATTRIBUTE    Digest-AKA-Auts                118    string
+//! ATTRIBUTE    Digest-Domain                119    string
+//! ATTRIBUTE    Digest-Stale                120    string
+//! ATTRIBUTE    Digest-HA1                121    string
+//! ATTRIBUTE    SIP-AOR                    122    string
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+pub const DIGEST_RESPONSE_TYPE: AVPType = 103;
+/// Delete all of `digest_response` values from a packet.
+pub fn delete_digest_response(packet: &mut Packet) {
+    packet.delete(DIGEST_RESPONSE_TYPE);
+}
+/// Returns whether a packet has `digest_response` without decoding the value.
+pub fn has_digest_response(packet: &Packet) -> bool {
+    packet.contains(DIGEST_RESPONSE_TYPE)
+}
+/// Returns the number of `digest_response` attributes in a packet.
+pub fn count_digest_response(packet: &Packet) -> usize {
+    packet.count(DIGEST_RESPONSE_TYPE)
+}
+/// Add `digest_response` string value to a packet.
+pub fn add_digest_response(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_RESPONSE_TYPE, value));
+}
+/// Lookup a `digest_response` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_response`, it returns `None`.
+pub fn lookup_digest_response(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_RESPONSE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Response"))
+    })
+}
+/// Lookup all of the `digest_response` string value from a packet.
+pub fn lookup_all_digest_response(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_RESPONSE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Response"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_REALM_TYPE: AVPType = 104;
+/// Delete all of `digest_realm` values from a packet.
+pub fn delete_digest_realm(packet: &mut Packet) {
+    packet.delete(DIGEST_REALM_TYPE);
+}
+/// Returns whether a packet has `digest_realm` without decoding the value.
+pub fn has_digest_realm(packet: &Packet) -> bool {
+    packet.contains(DIGEST_REALM_TYPE)
+}
+/// Returns the number of `digest_realm` attributes in a packet.
+pub fn count_digest_realm(packet: &Packet) -> usize {
+    packet.count(DIGEST_REALM_TYPE)
+}
+/// Add `digest_realm` string value to a packet.
+pub fn add_digest_realm(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_REALM_TYPE, value));
+}
+/// Lookup a `digest_realm` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_realm`, it returns `None`.
+pub fn lookup_digest_realm(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_REALM_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Realm"))
+    })
+}
+/// Lookup all of the `digest_realm` string value from a packet.
+pub fn lookup_all_digest_realm(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_REALM_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Realm"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_NONCE_TYPE: AVPType = 105;
+/// Delete all of `digest_nonce` values from a packet.
+pub fn delete_digest_nonce(packet: &mut Packet) {
+    packet.delete(DIGEST_NONCE_TYPE);
+}
+/// Returns whether a packet has `digest_nonce` without decoding the value.
+pub fn has_digest_nonce(packet: &Packet) -> bool {
+    packet.contains(DIGEST_NONCE_TYPE)
+}
+/// Returns the number of `digest_nonce` attributes in a packet.
+pub fn count_digest_nonce(packet: &Packet) -> usize {
+    packet.count(DIGEST_NONCE_TYPE)
+}
+/// Add `digest_nonce` string value to a packet.
+pub fn add_digest_nonce(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_NONCE_TYPE, value));
+}
+/// Lookup a `digest_nonce` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_nonce`, it returns `None`.
+pub fn lookup_digest_nonce(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_NONCE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Nonce"))
+    })
+}
+/// Lookup all of the `digest_nonce` string value from a packet.
+pub fn lookup_all_digest_nonce(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_NONCE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Nonce"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_RESPONSE_AUTH_TYPE: AVPType = 106;
+/// Delete all of `digest_response_auth` values from a packet.
+pub fn delete_digest_response_auth(packet: &mut Packet) {
+    packet.delete(DIGEST_RESPONSE_AUTH_TYPE);
+}
+/// Returns whether a packet has `digest_response_auth` without decoding the value.
+pub fn has_digest_response_auth(packet: &Packet) -> bool {
+    packet.contains(DIGEST_RESPONSE_AUTH_TYPE)
+}
+/// Returns the number of `digest_response_auth` attributes in a packet.
+pub fn count_digest_response_auth(packet: &Packet) -> usize {
+    packet.count(DIGEST_RESPONSE_AUTH_TYPE)
+}
+/// Add `digest_response_auth` string value to a packet.
+pub fn add_digest_response_auth(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_RESPONSE_AUTH_TYPE, value));
+}
+/// Lookup a `digest_response_auth` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_response_auth`, it returns `None`.
+pub fn lookup_digest_response_auth(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_RESPONSE_AUTH_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Response-Auth"))
+    })
+}
+/// Lookup all of the `digest_response_auth` string value from a packet.
+pub fn lookup_all_digest_response_auth(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_RESPONSE_AUTH_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Response-Auth"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_NEXTNONCE_TYPE: AVPType = 107;
+/// Delete all of `digest_nextnonce` values from a packet.
+pub fn delete_digest_nextnonce(packet: &mut Packet) {
+    packet.delete(DIGEST_NEXTNONCE_TYPE);
+}
+/// Returns whether a packet has `digest_nextnonce` without decoding the value.
+pub fn has_digest_nextnonce(packet: &Packet) -> bool {
+    packet.contains(DIGEST_NEXTNONCE_TYPE)
+}
+/// Returns the number of `digest_nextnonce` attributes in a packet.
+pub fn count_digest_nextnonce(packet: &Packet) -> usize {
+    packet.count(DIGEST_NEXTNONCE_TYPE)
+}
+/// Add `digest_nextnonce` string value to a packet.
+pub fn add_digest_nextnonce(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_NEXTNONCE_TYPE, value));
+}
+/// Lookup a `digest_nextnonce` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_nextnonce`, it returns `None`.
+pub fn lookup_digest_nextnonce(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_NEXTNONCE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Nextnonce"))
+    })
+}
+/// Lookup all of the `digest_nextnonce` string value from a packet.
+pub fn lookup_all_digest_nextnonce(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_NEXTNONCE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Nextnonce"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_METHOD_TYPE: AVPType = 108;
+/// Delete all of `digest_method` values from a packet.
+pub fn delete_digest_method(packet: &mut Packet) {
+    packet.delete(DIGEST_METHOD_TYPE);
+}
+/// Returns whether a packet has `digest_method` without decoding the value.
+pub fn has_digest_method(packet: &Packet) -> bool {
+    packet.contains(DIGEST_METHOD_TYPE)
+}
+/// Returns the number of `digest_method` attributes in a packet.
+pub fn count_digest_method(packet: &Packet) -> usize {
+    packet.count(DIGEST_METHOD_TYPE)
+}
+/// Add `digest_method` string value to a packet.
+pub fn add_digest_method(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_METHOD_TYPE, value));
+}
+/// Lookup a `digest_method` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_method`, it returns `None`.
+pub fn lookup_digest_method(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_METHOD_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Method"))
+    })
+}
+/// Lookup all of the `digest_method` string value from a packet.
+pub fn lookup_all_digest_method(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_METHOD_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Method"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_URI_TYPE: AVPType = 109;
+/// Delete all of `digest_uri` values from a packet.
+pub fn delete_digest_uri(packet: &mut Packet) {
+    packet.delete(DIGEST_URI_TYPE);
+}
+/// Returns whether a packet has `digest_uri` without decoding the value.
+pub fn has_digest_uri(packet: &Packet) -> bool {
+    packet.contains(DIGEST_URI_TYPE)
+}
+/// Returns the number of `digest_uri` attributes in a packet.
+pub fn count_digest_uri(packet: &Packet) -> usize {
+    packet.count(DIGEST_URI_TYPE)
+}
+/// Add `digest_uri` string value to a packet.
+pub fn add_digest_uri(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_URI_TYPE, value));
+}
+/// Lookup a `digest_uri` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_uri`, it returns `None`.
+pub fn lookup_digest_uri(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_URI_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-URI"))
+    })
+}
+/// Lookup all of the `digest_uri` string value from a packet.
+pub fn lookup_all_digest_uri(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_URI_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-URI"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_QOP_TYPE: AVPType = 110;
+/// Delete all of `digest_qop` values from a packet.
+pub fn delete_digest_qop(packet: &mut Packet) {
+    packet.delete(DIGEST_QOP_TYPE);
+}
+/// Returns whether a packet has `digest_qop` without decoding the value.
+pub fn has_digest_qop(packet: &Packet) -> bool {
+    packet.contains(DIGEST_QOP_TYPE)
+}
+/// Returns the number of `digest_qop` attributes in a packet.
+pub fn count_digest_qop(packet: &Packet) -> usize {
+    packet.count(DIGEST_QOP_TYPE)
+}
+/// Add `digest_qop` string value to a packet.
+pub fn add_digest_qop(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_QOP_TYPE, value));
+}
+/// Lookup a `digest_qop` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_qop`, it returns `None`.
+pub fn lookup_digest_qop(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_QOP_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Qop"))
+    })
+}
+/// Lookup all of the `digest_qop` string value from a packet.
+pub fn lookup_all_digest_qop(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_QOP_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Qop"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_ALGORITHM_TYPE: AVPType = 111;
+/// Delete all of `digest_algorithm` values from a packet.
+pub fn delete_digest_algorithm(packet: &mut Packet) {
+    packet.delete(DIGEST_ALGORITHM_TYPE);
+}
+/// Returns whether a packet has `digest_algorithm` without decoding the value.
+pub fn has_digest_algorithm(packet: &Packet) -> bool {
+    packet.contains(DIGEST_ALGORITHM_TYPE)
+}
+/// Returns the number of `digest_algorithm` attributes in a packet.
+pub fn count_digest_algorithm(packet: &Packet) -> usize {
+    packet.count(DIGEST_ALGORITHM_TYPE)
+}
+/// Add `digest_algorithm` string value to a packet.
+pub fn add_digest_algorithm(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_ALGORITHM_TYPE, value));
+}
+/// Lookup a `digest_algorithm` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_algorithm`, it returns `None`.
+pub fn lookup_digest_algorithm(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_ALGORITHM_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Algorithm"))
+    })
+}
+/// Lookup all of the `digest_algorithm` string value from a packet.
+pub fn lookup_all_digest_algorithm(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_ALGORITHM_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Algorithm"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_ENTITY_BODY_HASH_TYPE: AVPType = 112;
+/// Delete all of `digest_entity_body_hash` values from a packet.
+pub fn delete_digest_entity_body_hash(packet: &mut Packet) {
+    packet.delete(DIGEST_ENTITY_BODY_HASH_TYPE);
+}
+/// Returns whether a packet has `digest_entity_body_hash` without decoding the value.
+pub fn has_digest_entity_body_hash(packet: &Packet) -> bool {
+    packet.contains(DIGEST_ENTITY_BODY_HASH_TYPE)
+}
+/// Returns the number of `digest_entity_body_hash` attributes in a packet.
+pub fn count_digest_entity_body_hash(packet: &Packet) -> usize {
+    packet.count(DIGEST_ENTITY_BODY_HASH_TYPE)
+}
+/// Add `digest_entity_body_hash` string value to a packet.
+pub fn add_digest_entity_body_hash(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_ENTITY_BODY_HASH_TYPE, value));
+}
+/// Lookup a `digest_entity_body_hash` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_entity_body_hash`, it returns `None`.
+pub fn lookup_digest_entity_body_hash(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_ENTITY_BODY_HASH_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Entity-Body-Hash"))
+    })
+}
+/// Lookup all of the `digest_entity_body_hash` string value from a packet.
+pub fn lookup_all_digest_entity_body_hash(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_ENTITY_BODY_HASH_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Entity-Body-Hash"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_C_NONCE_TYPE: AVPType = 113;
+/// Delete all of `digest_c_nonce` values from a packet.
+pub fn delete_digest_c_nonce(packet: &mut Packet) {
+    packet.delete(DIGEST_C_NONCE_TYPE);
+}
+/// Returns whether a packet has `digest_c_nonce` without decoding the value.
+pub fn has_digest_c_nonce(packet: &Packet) -> bool {
+    packet.contains(DIGEST_C_NONCE_TYPE)
+}
+/// Returns the number of `digest_c_nonce` attributes in a packet.
+pub fn count_digest_c_nonce(packet: &Packet) -> usize {
+    packet.count(DIGEST_C_NONCE_TYPE)
+}
+/// Add `digest_c_nonce` string value to a packet.
+pub fn add_digest_c_nonce(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_C_NONCE_TYPE, value));
+}
+/// Lookup a `digest_c_nonce` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_c_nonce`, it returns `None`.
+pub fn lookup_digest_c_nonce(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_C_NONCE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-CNonce"))
+    })
+}
+/// Lookup all of the `digest_c_nonce` string value from a packet.
+pub fn lookup_all_digest_c_nonce(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_C_NONCE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-CNonce"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_NONCE_COUNT_TYPE: AVPType = 114;
+/// Delete all of `digest_nonce_count` values from a packet.
+pub fn delete_digest_nonce_count(packet: &mut Packet) {
+    packet.delete(DIGEST_NONCE_COUNT_TYPE);
+}
+/// Returns whether a packet has `digest_nonce_count` without decoding the value.
+pub fn has_digest_nonce_count(packet: &Packet) -> bool {
+    packet.contains(DIGEST_NONCE_COUNT_TYPE)
+}
+/// Returns the number of `digest_nonce_count` attributes in a packet.
+pub fn count_digest_nonce_count(packet: &Packet) -> usize {
+    packet.count(DIGEST_NONCE_COUNT_TYPE)
+}
+/// Add `digest_nonce_count` string value to a packet.
+pub fn add_digest_nonce_count(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_NONCE_COUNT_TYPE, value));
+}
+/// Lookup a `digest_nonce_count` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_nonce_count`, it returns `None`.
+pub fn lookup_digest_nonce_count(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_NONCE_COUNT_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Nonce-Count"))
+    })
+}
+/// Lookup all of the `digest_nonce_count` string value from a packet.
+pub fn lookup_all_digest_nonce_count(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_NONCE_COUNT_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Nonce-Count"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_USERNAME_TYPE: AVPType = 115;
+/// Delete all of `digest_username` values from a packet.
+pub fn delete_digest_username(packet: &mut Packet) {
+    packet.delete(DIGEST_USERNAME_TYPE);
+}
+/// Returns whether a packet has `digest_username` without decoding the value.
+pub fn has_digest_username(packet: &Packet) -> bool {
+    packet.contains(DIGEST_USERNAME_TYPE)
+}
+/// Returns the number of `digest_username` attributes in a packet.
+pub fn count_digest_username(packet: &Packet) -> usize {
+    packet.count(DIGEST_USERNAME_TYPE)
+}
+/// Add `digest_username` string value to a packet.
+pub fn add_digest_username(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_USERNAME_TYPE, value));
+}
+/// Lookup a `digest_username` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_username`, it returns `None`.
+pub fn lookup_digest_username(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_USERNAME_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Username"))
+    })
+}
+/// Lookup all of the `digest_username` string value from a packet.
+pub fn lookup_all_digest_username(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_USERNAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Username"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_OPAQUE_TYPE: AVPType = 116;
+/// Delete all of `digest_opaque` values from a packet.
+pub fn delete_digest_opaque(packet: &mut Packet) {
+    packet.delete(DIGEST_OPAQUE_TYPE);
+}
+/// Returns whether a packet has `digest_opaque` without decoding the value.
+pub fn has_digest_opaque(packet: &Packet) -> bool {
+    packet.contains(DIGEST_OPAQUE_TYPE)
+}
+/// Returns the number of `digest_opaque` attributes in a packet.
+pub fn count_digest_opaque(packet: &Packet) -> usize {
+    packet.count(DIGEST_OPAQUE_TYPE)
+}
+/// Add `digest_opaque` string value to a packet.
+pub fn add_digest_opaque(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_OPAQUE_TYPE, value));
+}
+/// Lookup a `digest_opaque` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_opaque`, it returns `None`.
+pub fn lookup_digest_opaque(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_OPAQUE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Opaque"))
+    })
+}
+/// Lookup all of the `digest_opaque` string value from a packet.
+pub fn lookup_all_digest_opaque(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_OPAQUE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Opaque"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_AUTH_PARAM_TYPE: AVPType = 117;
+/// Delete all of `digest_auth_param` values from a packet.
+pub fn delete_digest_auth_param(packet: &mut Packet) {
+    packet.delete(DIGEST_AUTH_PARAM_TYPE);
+}
+/// Returns whether a packet has `digest_auth_param` without decoding the value.
+pub fn has_digest_auth_param(packet: &Packet) -> bool {
+    packet.contains(DIGEST_AUTH_PARAM_TYPE)
+}
+/// Returns the number of `digest_auth_param` attributes in a packet.
+pub fn count_digest_auth_param(packet: &Packet) -> usize {
+    packet.count(DIGEST_AUTH_PARAM_TYPE)
+}
+/// Add `digest_auth_param` string value to a packet.
+pub fn add_digest_auth_param(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_AUTH_PARAM_TYPE, value));
+}
+/// Lookup a `digest_auth_param` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_auth_param`, it returns `None`.
+pub fn lookup_digest_auth_param(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_AUTH_PARAM_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Auth-Param"))
+    })
+}
+/// Lookup all of the `digest_auth_param` string value from a packet.
+pub fn lookup_all_digest_auth_param(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_AUTH_PARAM_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Auth-Param"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_AKA_AUTS_TYPE: AVPType = 118;
+/// Delete all of `digest_aka_auts` values from a packet.
+pub fn delete_digest_aka_auts(packet: &mut Packet) {
+    packet.delete(DIGEST_AKA_AUTS_TYPE);
+}
+/// Returns whether a packet has `digest_aka_auts` without decoding the value.
+pub fn has_digest_aka_auts(packet: &Packet) -> bool {
+    packet.contains(DIGEST_AKA_AUTS_TYPE)
+}
+/// Returns the number of `digest_aka_auts` attributes in a packet.
+pub fn count_digest_aka_auts(packet: &Packet) -> usize {
+    packet.count(DIGEST_AKA_AUTS_TYPE)
+}
+/// Add `digest_aka_auts` string value to a packet.
+pub fn add_digest_aka_auts(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_AKA_AUTS_TYPE, value));
+}
+/// Lookup a `digest_aka_auts` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_aka_auts`, it returns `None`.
+pub fn lookup_digest_aka_auts(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_AKA_AUTS_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-AKA-Auts"))
+    })
+}
+/// Lookup all of the `digest_aka_auts` string value from a packet.
+pub fn lookup_all_digest_aka_auts(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_AKA_AUTS_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-AKA-Auts"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_DOMAIN_TYPE: AVPType = 119;
+/// Delete all of `digest_domain` values from a packet.
+pub fn delete_digest_domain(packet: &mut Packet) {
+    packet.delete(DIGEST_DOMAIN_TYPE);
+}
+/// Returns whether a packet has `digest_domain` without decoding the value.
+pub fn has_digest_domain(packet: &Packet) -> bool {
+    packet.contains(DIGEST_DOMAIN_TYPE)
+}
+/// Returns the number of `digest_domain` attributes in a packet.
+pub fn count_digest_domain(packet: &Packet) -> usize {
+    packet.count(DIGEST_DOMAIN_TYPE)
+}
+/// Add `digest_domain` string value to a packet.
+pub fn add_digest_domain(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_DOMAIN_TYPE, value));
+}
+/// Lookup a `digest_domain` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_domain`, it returns `None`.
+pub fn lookup_digest_domain(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_DOMAIN_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Domain"))
+    })
+}
+/// Lookup all of the `digest_domain` string value from a packet.
+pub fn lookup_all_digest_domain(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_DOMAIN_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Domain"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_STALE_TYPE: AVPType = 120;
+/// Delete all of `digest_stale` values from a packet.
+pub fn delete_digest_stale(packet: &mut Packet) {
+    packet.delete(DIGEST_STALE_TYPE);
+}
+/// Returns whether a packet has `digest_stale` without decoding the value.
+pub fn has_digest_stale(packet: &Packet) -> bool {
+    packet.contains(DIGEST_STALE_TYPE)
+}
+/// Returns the number of `digest_stale` attributes in a packet.
+pub fn count_digest_stale(packet: &Packet) -> usize {
+    packet.count(DIGEST_STALE_TYPE)
+}
+/// Add `digest_stale` string value to a packet.
+pub fn add_digest_stale(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_STALE_TYPE, value));
+}
+/// Lookup a `digest_stale` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_stale`, it returns `None`.
+pub fn lookup_digest_stale(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_STALE_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-Stale"))
+    })
+}
+/// Lookup all of the `digest_stale` string value from a packet.
+pub fn lookup_all_digest_stale(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_STALE_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-Stale"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const DIGEST_HA1_TYPE: AVPType = 121;
+/// Delete all of `digest_ha1` values from a packet.
+pub fn delete_digest_ha1(packet: &mut Packet) {
+    packet.delete(DIGEST_HA1_TYPE);
+}
+/// Returns whether a packet has `digest_ha1` without decoding the value.
+pub fn has_digest_ha1(packet: &Packet) -> bool {
+    packet.contains(DIGEST_HA1_TYPE)
+}
+/// Returns the number of `digest_ha1` attributes in a packet.
+pub fn count_digest_ha1(packet: &Packet) -> usize {
+    packet.count(DIGEST_HA1_TYPE)
+}
+/// Add `digest_ha1` string value to a packet.
+pub fn add_digest_ha1(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(DIGEST_HA1_TYPE, value));
+}
+/// Lookup a `digest_ha1` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `digest_ha1`, it returns `None`.
+pub fn lookup_digest_ha1(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(DIGEST_HA1_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Digest-HA1"))
+    })
+}
+/// Lookup all of the `digest_ha1` string value from a packet.
+pub fn lookup_all_digest_ha1(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(DIGEST_HA1_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Digest-HA1"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const SIP_AOR_TYPE: AVPType = 122;
+/// Delete all of `sip_aor` values from a packet.
+pub fn delete_sip_aor(packet: &mut Packet) {
+    packet.delete(SIP_AOR_TYPE);
+}
+/// Returns whether a packet has `sip_aor` without decoding the value.
+pub fn has_sip_aor(packet: &Packet) -> bool {
+    packet.contains(SIP_AOR_TYPE)
+}
+/// Returns the number of `sip_aor` attributes in a packet.
+pub fn count_sip_aor(packet: &Packet) -> usize {
+    packet.count(SIP_AOR_TYPE)
+}
+/// Add `sip_aor` string value to a packet.
+pub fn add_sip_aor(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(SIP_AOR_TYPE, value));
+}
+/// Lookup a `sip_aor` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `sip_aor`, it returns `None`.
+pub fn lookup_sip_aor(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(SIP_AOR_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("SIP-AOR"))
+    })
+}
+/// Lookup all of the `sip_aor` string value from a packet.
+pub fn lookup_all_sip_aor(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(SIP_AOR_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("SIP-AOR"))?,
+        )
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Digest-Response",
+        typ: DIGEST_RESPONSE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Realm",
+        typ: DIGEST_REALM_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Nonce",
+        typ: DIGEST_NONCE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Response-Auth",
+        typ: DIGEST_RESPONSE_AUTH_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Nextnonce",
+        typ: DIGEST_NEXTNONCE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Method",
+        typ: DIGEST_METHOD_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-URI",
+        typ: DIGEST_URI_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Qop",
+        typ: DIGEST_QOP_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Algorithm",
+        typ: DIGEST_ALGORITHM_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Entity-Body-Hash",
+        typ: DIGEST_ENTITY_BODY_HASH_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-CNonce",
+        typ: DIGEST_C_NONCE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Nonce-Count",
+        typ: DIGEST_NONCE_COUNT_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Username",
+        typ: DIGEST_USERNAME_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Opaque",
+        typ: DIGEST_OPAQUE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Auth-Param",
+        typ: DIGEST_AUTH_PARAM_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-AKA-Auts",
+        typ: DIGEST_AKA_AUTS_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Domain",
+        typ: DIGEST_DOMAIN_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-Stale",
+        typ: DIGEST_STALE_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Digest-HA1",
+        typ: DIGEST_HA1_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "SIP-AOR",
+        typ: SIP_AOR_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -18,7 +18,28 @@
 //! VALUE    Error-Cause            Multiple-Session-Selection-Unsupported    508
 //! ```
 
-use crate::core::rfc3576;
+use crate::metadata::{AttributeMetadata, ValueMetadata};
 
-pub const ERROR_CAUSE_INVALID_ATTRIBUTE_VALUE: rfc3576::ErrorCause = 407;
-pub const ERROR_CAUSE_MULTIPLE_SESSION_SELECTION_UNSUPPORTED: rfc3576::ErrorCause = 508;
+use crate::rfc3576;
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[];
+
+pub const ERROR_CAUSE_INVALID_ATTRIBUTE_VALUE: rfc3576::ErrorCause =
+    rfc3576::ErrorCause::from_u32(407);
+pub const ERROR_CAUSE_MULTIPLE_SESSION_SELECTION_UNSUPPORTED: rfc3576::ErrorCause =
+    rfc3576::ErrorCause::from_u32(508);
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: rfc3576::ERROR_CAUSE_TYPE,
+        name: "Invalid-Attribute-Value",
+        value: 407,
+    },
+    ValueMetadata {
+        typ: rfc3576::ERROR_CAUSE_TYPE,
+        name: "Multiple-Session-Selection-Unsupported",
+        value: 508,
+    },
+];
@@ -0,0 +1,468 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc5607 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 5607.
+//! #    http://www.ietf.org/rfc/rfc5607.txt
+//! #
+//! #    $Id$
+//! #
+//!
+//! VALUE    Service-Type            Framed-Management    18
+//!
+//! ATTRIBUTE    Framed-Management            133    integer
+//!
+//! VALUE    Framed-Management        SNMP            1
+//! VALUE    Framed-Management        Web-Based        2
+//! VALUE    Framed-Management        Netconf            3
+//! VALUE    Framed-Management        FTP            4
+//! VALUE    Framed-Management        TFTP            5
+//! VALUE    Framed-Management        SFTP            6
+//! VALUE    Framed-Management        RCP            7
+//! VALUE    Framed-Management        SCP            8
+//!
+//! ATTRIBUTE    Management-Transport-Protection        134    integer
+//!
+//! VALUE    Management-Transport-Protection    No-Protection        1
+//! VALUE    Management-Transport-Protection    Integrity-Protection    2
+//! VALUE    Management-Transport-Protection    Integrity-Confidentiality-Protection    3
+//!
+//! ATTRIBUTE    Management-Policy-Id            135    string
+//!
+//! ATTRIBUTE    Management-Privilege-Level        136    integer
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+use crate::rfc2865;
+
+pub const FRAMED_MANAGEMENT_TYPE: AVPType = 133;
+/// Delete all of `framed_management` values from a packet.
+pub fn delete_framed_management(packet: &mut Packet) {
+    packet.delete(FRAMED_MANAGEMENT_TYPE);
+}
+/// Returns whether a packet has `framed_management` without decoding the value.
+pub fn has_framed_management(packet: &Packet) -> bool {
+    packet.contains(FRAMED_MANAGEMENT_TYPE)
+}
+/// Returns the number of `framed_management` attributes in a packet.
+pub fn count_framed_management(packet: &Packet) -> usize {
+    packet.count(FRAMED_MANAGEMENT_TYPE)
+}
+/// Add `framed_management` value-defined integer value to a packet.
+pub fn add_framed_management(packet: &mut Packet, value: FramedManagement) {
+    packet.add(AVP::from_u32(FRAMED_MANAGEMENT_TYPE, value.to_u32()));
+}
+/// Lookup a `framed_management` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `framed_management`, it returns `None`.
+pub fn lookup_framed_management(packet: &Packet) -> Option<Result<FramedManagement, AVPError>> {
+    packet.lookup(FRAMED_MANAGEMENT_TYPE).map(|v| {
+        Ok(FramedManagement::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-Management"))?,
+        ))
+    })
+}
+/// Lookup all of the `framed_management` value-defined integer value from a packet.
+pub fn lookup_all_framed_management(packet: &Packet) -> Result<Vec<FramedManagement>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(FRAMED_MANAGEMENT_TYPE) {
+        vec.push(FramedManagement::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Framed-Management"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const MANAGEMENT_TRANSPORT_PROTECTION_TYPE: AVPType = 134;
+/// Delete all of `management_transport_protection` values from a packet.
+pub fn delete_management_transport_protection(packet: &mut Packet) {
+    packet.delete(MANAGEMENT_TRANSPORT_PROTECTION_TYPE);
+}
+/// Returns whether a packet has `management_transport_protection` without decoding the value.
+pub fn has_management_transport_protection(packet: &Packet) -> bool {
+    packet.contains(MANAGEMENT_TRANSPORT_PROTECTION_TYPE)
+}
+/// Returns the number of `management_transport_protection` attributes in a packet.
+pub fn count_management_transport_protection(packet: &Packet) -> usize {
+    packet.count(MANAGEMENT_TRANSPORT_PROTECTION_TYPE)
+}
+/// Add `management_transport_protection` value-defined integer value to a packet.
+pub fn add_management_transport_protection(
+    packet: &mut Packet,
+    value: ManagementTransportProtection,
+) {
+    packet.add(AVP::from_u32(
+        MANAGEMENT_TRANSPORT_PROTECTION_TYPE,
+        value.to_u32(),
+    ));
+}
+/// Lookup a `management_transport_protection` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `management_transport_protection`, it returns `None`.
+pub fn lookup_management_transport_protection(
+    packet: &Packet,
+) -> Option<Result<ManagementTransportProtection, AVPError>> {
+    packet
+        .lookup(MANAGEMENT_TRANSPORT_PROTECTION_TYPE)
+        .map(|v| {
+            Ok(ManagementTransportProtection::from_u32(
+                v.decode_u32()
+                    .map_err(|e| e.with_attribute_name("Management-Transport-Protection"))?,
+            ))
+        })
+}
+/// Lookup all of the `management_transport_protection` value-defined integer value from a packet.
+pub fn lookup_all_management_transport_protection(
+    packet: &Packet,
+) -> Result<Vec<ManagementTransportProtection>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(MANAGEMENT_TRANSPORT_PROTECTION_TYPE) {
+        vec.push(ManagementTransportProtection::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Management-Transport-Protection"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+pub const MANAGEMENT_POLICY_ID_TYPE: AVPType = 135;
+/// Delete all of `management_policy_id` values from a packet.
+pub fn delete_management_policy_id(packet: &mut Packet) {
+    packet.delete(MANAGEMENT_POLICY_ID_TYPE);
+}
+/// Returns whether a packet has `management_policy_id` without decoding the value.
+pub fn has_management_policy_id(packet: &Packet) -> bool {
+    packet.contains(MANAGEMENT_POLICY_ID_TYPE)
+}
+/// Returns the number of `management_policy_id` attributes in a packet.
+pub fn count_management_policy_id(packet: &Packet) -> usize {
+    packet.count(MANAGEMENT_POLICY_ID_TYPE)
+}
+/// Add `management_policy_id` string value to a packet.
+pub fn add_management_policy_id(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(MANAGEMENT_POLICY_ID_TYPE, value));
+}
+/// Lookup a `management_policy_id` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `management_policy_id`, it returns `None`.
+pub fn lookup_management_policy_id(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(MANAGEMENT_POLICY_ID_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Management-Policy-Id"))
+    })
+}
+/// Lookup all of the `management_policy_id` string value from a packet.
+pub fn lookup_all_management_policy_id(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(MANAGEMENT_POLICY_ID_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Management-Policy-Id"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const MANAGEMENT_PRIVILEGE_LEVEL_TYPE: AVPType = 136;
+/// Delete all of `management_privilege_level` values from a packet.
+pub fn delete_management_privilege_level(packet: &mut Packet) {
+    packet.delete(MANAGEMENT_PRIVILEGE_LEVEL_TYPE);
+}
+/// Returns whether a packet has `management_privilege_level` without decoding the value.
+pub fn has_management_privilege_level(packet: &Packet) -> bool {
+    packet.contains(MANAGEMENT_PRIVILEGE_LEVEL_TYPE)
+}
+/// Returns the number of `management_privilege_level` attributes in a packet.
+pub fn count_management_privilege_level(packet: &Packet) -> usize {
+    packet.count(MANAGEMENT_PRIVILEGE_LEVEL_TYPE)
+}
+/// Add `management_privilege_level` integer value to a packet.
+pub fn add_management_privilege_level(packet: &mut Packet, value: u32) {
+    packet.add(AVP::from_u32(MANAGEMENT_PRIVILEGE_LEVEL_TYPE, value));
+}
+/// Lookup a `management_privilege_level` integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `management_privilege_level`, it returns `None`.
+pub fn lookup_management_privilege_level(packet: &Packet) -> Option<Result<u32, AVPError>> {
+    packet.lookup(MANAGEMENT_PRIVILEGE_LEVEL_TYPE).map(|v| {
+        v.decode_u32()
+            .map_err(|e| e.with_attribute_name("Management-Privilege-Level"))
+    })
+}
+/// Lookup all of the `management_privilege_level` integer value from a packet.
+pub fn lookup_all_management_privilege_level(packet: &Packet) -> Result<Vec<u32>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(MANAGEMENT_PRIVILEGE_LEVEL_TYPE) {
+        vec.push(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("Management-Privilege-Level"))?,
+        )
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Framed-Management",
+        typ: FRAMED_MANAGEMENT_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Management-Transport-Protection",
+        typ: MANAGEMENT_TRANSPORT_PROTECTION_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Management-Policy-Id",
+        typ: MANAGEMENT_POLICY_ID_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Management-Privilege-Level",
+        typ: MANAGEMENT_PRIVILEGE_LEVEL_TYPE,
+        data_type: AttributeDataType::Integer,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+];
+
+/// The values of `Framed-Management`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FramedManagement {
+    Snmp,
+    WebBased,
+    Netconf,
+    Ftp,
+    Tftp,
+    Sftp,
+    Rcp,
+    Scp,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl FramedManagement {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => FramedManagement::Snmp,
+            2 => FramedManagement::WebBased,
+            3 => FramedManagement::Netconf,
+            4 => FramedManagement::Ftp,
+            5 => FramedManagement::Tftp,
+            6 => FramedManagement::Sftp,
+            7 => FramedManagement::Rcp,
+            8 => FramedManagement::Scp,
+            value => FramedManagement::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            FramedManagement::Snmp => 1,
+            FramedManagement::WebBased => 2,
+            FramedManagement::Netconf => 3,
+            FramedManagement::Ftp => 4,
+            FramedManagement::Tftp => 5,
+            FramedManagement::Sftp => 6,
+            FramedManagement::Rcp => 7,
+            FramedManagement::Scp => 8,
+            FramedManagement::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for FramedManagement {
+    fn from(value: u32) -> Self {
+        FramedManagement::from_u32(value)
+    }
+}
+impl From<FramedManagement> for u32 {
+    fn from(value: FramedManagement) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for FramedManagement {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `SNMP`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "SNMP" => Ok(FramedManagement::Snmp),
+            "Web-Based" => Ok(FramedManagement::WebBased),
+            "Netconf" => Ok(FramedManagement::Netconf),
+            "FTP" => Ok(FramedManagement::Ftp),
+            "TFTP" => Ok(FramedManagement::Tftp),
+            "SFTP" => Ok(FramedManagement::Sftp),
+            "RCP" => Ok(FramedManagement::Rcp),
+            "SCP" => Ok(FramedManagement::Scp),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Framed-Management`: {name}"
+            ))),
+        }
+    }
+}
+pub const FRAMED_MANAGEMENT_SNMP: FramedManagement = FramedManagement::Snmp;
+pub const FRAMED_MANAGEMENT_WEB_BASED: FramedManagement = FramedManagement::WebBased;
+pub const FRAMED_MANAGEMENT_NETCONF: FramedManagement = FramedManagement::Netconf;
+pub const FRAMED_MANAGEMENT_FTP: FramedManagement = FramedManagement::Ftp;
+pub const FRAMED_MANAGEMENT_TFTP: FramedManagement = FramedManagement::Tftp;
+pub const FRAMED_MANAGEMENT_SFTP: FramedManagement = FramedManagement::Sftp;
+pub const FRAMED_MANAGEMENT_RCP: FramedManagement = FramedManagement::Rcp;
+pub const FRAMED_MANAGEMENT_SCP: FramedManagement = FramedManagement::Scp;
+
+/// The values of `Management-Transport-Protection`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ManagementTransportProtection {
+    NoProtection,
+    IntegrityProtection,
+    IntegrityConfidentialityProtection,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl ManagementTransportProtection {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => ManagementTransportProtection::NoProtection,
+            2 => ManagementTransportProtection::IntegrityProtection,
+            3 => ManagementTransportProtection::IntegrityConfidentialityProtection,
+            value => ManagementTransportProtection::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            ManagementTransportProtection::NoProtection => 1,
+            ManagementTransportProtection::IntegrityProtection => 2,
+            ManagementTransportProtection::IntegrityConfidentialityProtection => 3,
+            ManagementTransportProtection::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for ManagementTransportProtection {
+    fn from(value: u32) -> Self {
+        ManagementTransportProtection::from_u32(value)
+    }
+}
+impl From<ManagementTransportProtection> for u32 {
+    fn from(value: ManagementTransportProtection) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for ManagementTransportProtection {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `No-Protection`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "No-Protection" => Ok(ManagementTransportProtection::NoProtection),
+            "Integrity-Protection" => Ok(ManagementTransportProtection::IntegrityProtection),
+            "Integrity-Confidentiality-Protection" => {
+                Ok(ManagementTransportProtection::IntegrityConfidentialityProtection)
+            }
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `Management-Transport-Protection`: {name}"
+            ))),
+        }
+    }
+}
+pub const MANAGEMENT_TRANSPORT_PROTECTION_NO_PROTECTION: ManagementTransportProtection =
+    ManagementTransportProtection::NoProtection;
+pub const MANAGEMENT_TRANSPORT_PROTECTION_INTEGRITY_PROTECTION: ManagementTransportProtection =
+    ManagementTransportProtection::IntegrityProtection;
+pub const MANAGEMENT_TRANSPORT_PROTECTION_INTEGRITY_CONFIDENTIALITY_PROTECTION:
+    ManagementTransportProtection =
+    ManagementTransportProtection::IntegrityConfidentialityProtection;
+
+pub const SERVICE_TYPE_FRAMED_MANAGEMENT: rfc2865::ServiceType = rfc2865::ServiceType::from_u32(18);
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: FRAMED_MANAGEMENT_TYPE,
+        name: "SNMP",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: FRAMED_MANAGEMENT_TYPE,
+        name: "Web-Based",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: FRAMED_MANAGEMENT_TYPE,
+        name: "Netconf",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: FRAMED_MANAGEMENT_TYPE,
+        name: "FTP",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: FRAMED_MANAGEMENT_TYPE,
+        name: "TFTP",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: FRAMED_MANAGEMENT_TYPE,
+        name: "SFTP",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: FRAMED_MANAGEMENT_TYPE,
+        name: "RCP",
+        value: 7,
+    },
+    ValueMetadata {
+        typ: FRAMED_MANAGEMENT_TYPE,
+        name: "SCP",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: MANAGEMENT_TRANSPORT_PROTECTION_TYPE,
+        name: "No-Protection",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: MANAGEMENT_TRANSPORT_PROTECTION_TYPE,
+        name: "Integrity-Protection",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: MANAGEMENT_TRANSPORT_PROTECTION_TYPE,
+        name: "Integrity-Confidentiality-Protection",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: rfc2865::SERVICE_TYPE_TYPE,
+        name: "Framed-Management",
+        value: 18,
+    },
+];
@@ -0,0 +1,407 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc5904 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 5904.
+//! #    http://www.ietf.org/rfc/rfc5904.txt
+//! #
+//! #    `max_length` is a radius-rs specific option for the `concat` attributes;
+//! #    4044 bytes is the most that fits in a 4096-byte packet as 253-byte fragments.
+//! #
+//! #    $Id$
+//! #
+//!
+//! # The next two attributes are continued, like EAP-Message
+//! ATTRIBUTE    PKM-SS-Cert                137    octets    concat,max_length=4044
+//! ATTRIBUTE    PKM-CA-Cert                138    octets    concat,max_length=4044
+//!
+//! # 28 bytes of data, 7 integers
+//! ATTRIBUTE    PKM-Config-Settings            139    octets
+//! ATTRIBUTE    PKM-Cryptosuite-List            140    octets
+//! ATTRIBUTE    PKM-SAID                141    short
+//!
+//! # 6 bytes of data: SAID, 1 byte of type, 3 of cryptosuite
+//! ATTRIBUTE    PKM-SA-Descriptor            142    octets
+//!
+//! # 133 bytes of data: integer lifetime, 1 byte sequence, 128 bytes of key
+//! ATTRIBUTE    PKM-Auth-Key                143    octets
+//! ```
+
+use crate::avp::{AVPError, AVPType, JumboAttributePolicy, AVP};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+pub const PKM_SS_CERT_TYPE: AVPType = 137;
+/// Delete all of `pkm_ss_cert` values from a packet.
+pub fn delete_pkm_ss_cert(packet: &mut Packet) {
+    packet.delete(PKM_SS_CERT_TYPE);
+}
+/// Returns whether a packet has `pkm_ss_cert` without decoding the value.
+pub fn has_pkm_ss_cert(packet: &Packet) -> bool {
+    packet.contains(PKM_SS_CERT_TYPE)
+}
+/// Returns the number of `pkm_ss_cert` attributes in a packet.
+pub fn count_pkm_ss_cert(packet: &Packet) -> usize {
+    packet.count(PKM_SS_CERT_TYPE)
+}
+/// Add `pkm_ss_cert` octets value to a packet; the value is split into the multiple attributes.
+///
+/// It returns an error if the value exceeds 4044 bytes.
+pub fn add_pkm_ss_cert(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    if value.len() > 4044 {
+        return Err(AVPError::InvalidAttributeLengthError(
+            "<= 4044 bytes".to_owned(),
+            value.len(),
+        ));
+    }
+    packet.add_with_policy(PKM_SS_CERT_TYPE, value, JumboAttributePolicy::Concat)
+}
+/// Lookup a `pkm_ss_cert` octets value from a packet; the values of the attributes are concatenated.
+///
+/// If there is no associated value with `pkm_ss_cert`, it returns `None`.
+/// It returns an error if the concatenated value exceeds 4044 bytes.
+pub fn lookup_pkm_ss_cert(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
+    let avps = packet.lookup_all(PKM_SS_CERT_TYPE);
+    if avps.is_empty() {
+        return None;
+    }
+    let length = avps.iter().map(|v| v.raw_value().len()).sum::<usize>();
+    if length > 4044 {
+        return Some(Err(AVPError::InvalidAttributeLengthError(
+            "<= 4044 bytes".to_owned(),
+            length,
+        )
+        .with_attribute_name("PKM-SS-Cert")));
+    }
+    Some(Ok(avps.into_iter().fold(
+        Vec::with_capacity(length),
+        |mut acc, v| {
+            acc.extend_from_slice(v.raw_value());
+            acc
+        },
+    )))
+}
+
+pub const PKM_CA_CERT_TYPE: AVPType = 138;
+/// Delete all of `pkm_ca_cert` values from a packet.
+pub fn delete_pkm_ca_cert(packet: &mut Packet) {
+    packet.delete(PKM_CA_CERT_TYPE);
+}
+/// Returns whether a packet has `pkm_ca_cert` without decoding the value.
+pub fn has_pkm_ca_cert(packet: &Packet) -> bool {
+    packet.contains(PKM_CA_CERT_TYPE)
+}
+/// Returns the number of `pkm_ca_cert` attributes in a packet.
+pub fn count_pkm_ca_cert(packet: &Packet) -> usize {
+    packet.count(PKM_CA_CERT_TYPE)
+}
+/// Add `pkm_ca_cert` octets value to a packet; the value is split into the multiple attributes.
+///
+/// It returns an error if the value exceeds 4044 bytes.
+pub fn add_pkm_ca_cert(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    if value.len() > 4044 {
+        return Err(AVPError::InvalidAttributeLengthError(
+            "<= 4044 bytes".to_owned(),
+            value.len(),
+        ));
+    }
+    packet.add_with_policy(PKM_CA_CERT_TYPE, value, JumboAttributePolicy::Concat)
+}
+/// Lookup a `pkm_ca_cert` octets value from a packet; the values of the attributes are concatenated.
+///
+/// If there is no associated value with `pkm_ca_cert`, it returns `None`.
+/// It returns an error if the concatenated value exceeds 4044 bytes.
+pub fn lookup_pkm_ca_cert(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
+    let avps = packet.lookup_all(PKM_CA_CERT_TYPE);
+    if avps.is_empty() {
+        return None;
+    }
+    let length = avps.iter().map(|v| v.raw_value().len()).sum::<usize>();
+    if length > 4044 {
+        return Some(Err(AVPError::InvalidAttributeLengthError(
+            "<= 4044 bytes".to_owned(),
+            length,
+        )
+        .with_attribute_name("PKM-CA-Cert")));
+    }
+    Some(Ok(avps.into_iter().fold(
+        Vec::with_capacity(length),
+        |mut acc, v| {
+            acc.extend_from_slice(v.raw_value());
+            acc
+        },
+    )))
+}
+
+pub const PKM_CONFIG_SETTINGS_TYPE: AVPType = 139;
+/// Delete all of `pkm_config_settings` values from a packet.
+pub fn delete_pkm_config_settings(packet: &mut Packet) {
+    packet.delete(PKM_CONFIG_SETTINGS_TYPE);
+}
+/// Returns whether a packet has `pkm_config_settings` without decoding the value.
+pub fn has_pkm_config_settings(packet: &Packet) -> bool {
+    packet.contains(PKM_CONFIG_SETTINGS_TYPE)
+}
+/// Returns the number of `pkm_config_settings` attributes in a packet.
+pub fn count_pkm_config_settings(packet: &Packet) -> usize {
+    packet.count(PKM_CONFIG_SETTINGS_TYPE)
+}
+/// Add `pkm_config_settings` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_pkm_config_settings(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(PKM_CONFIG_SETTINGS_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `pkm_config_settings` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `pkm_config_settings`, it returns `None`.
+pub fn lookup_pkm_config_settings(packet: &Packet) -> Option<Vec<u8>> {
+    packet
+        .lookup(PKM_CONFIG_SETTINGS_TYPE)
+        .map(|v| v.decode_bytes())
+}
+/// Lookup all of the `pkm_config_settings` octets value from a packet.
+pub fn lookup_all_pkm_config_settings(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PKM_CONFIG_SETTINGS_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const PKM_CRYPTOSUITE_LIST_TYPE: AVPType = 140;
+/// Delete all of `pkm_cryptosuite_list` values from a packet.
+pub fn delete_pkm_cryptosuite_list(packet: &mut Packet) {
+    packet.delete(PKM_CRYPTOSUITE_LIST_TYPE);
+}
+/// Returns whether a packet has `pkm_cryptosuite_list` without decoding the value.
+pub fn has_pkm_cryptosuite_list(packet: &Packet) -> bool {
+    packet.contains(PKM_CRYPTOSUITE_LIST_TYPE)
+}
+/// Returns the number of `pkm_cryptosuite_list` attributes in a packet.
+pub fn count_pkm_cryptosuite_list(packet: &Packet) -> usize {
+    packet.count(PKM_CRYPTOSUITE_LIST_TYPE)
+}
+/// Add `pkm_cryptosuite_list` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_pkm_cryptosuite_list(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(PKM_CRYPTOSUITE_LIST_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `pkm_cryptosuite_list` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `pkm_cryptosuite_list`, it returns `None`.
+pub fn lookup_pkm_cryptosuite_list(packet: &Packet) -> Option<Vec<u8>> {
+    packet
+        .lookup(PKM_CRYPTOSUITE_LIST_TYPE)
+        .map(|v| v.decode_bytes())
+}
+/// Lookup all of the `pkm_cryptosuite_list` octets value from a packet.
+pub fn lookup_all_pkm_cryptosuite_list(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PKM_CRYPTOSUITE_LIST_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const PKM_SAID_TYPE: AVPType = 141;
+/// Delete all of `pkm_said` values from a packet.
+pub fn delete_pkm_said(packet: &mut Packet) {
+    packet.delete(PKM_SAID_TYPE);
+}
+/// Returns whether a packet has `pkm_said` without decoding the value.
+pub fn has_pkm_said(packet: &Packet) -> bool {
+    packet.contains(PKM_SAID_TYPE)
+}
+/// Returns the number of `pkm_said` attributes in a packet.
+pub fn count_pkm_said(packet: &Packet) -> usize {
+    packet.count(PKM_SAID_TYPE)
+}
+/// Add `pkm_said` short integer value to a packet.
+pub fn add_pkm_said(packet: &mut Packet, value: u16) {
+    packet.add(AVP::from_u16(PKM_SAID_TYPE, value));
+}
+/// Lookup a `pkm_said` short integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `pkm_said`, it returns `None`.
+pub fn lookup_pkm_said(packet: &Packet) -> Option<Result<u16, AVPError>> {
+    packet.lookup(PKM_SAID_TYPE).map(|v| {
+        v.decode_u16()
+            .map_err(|e| e.with_attribute_name("PKM-SAID"))
+    })
+}
+/// Lookup all of the `pkm_said` short integer value from a packet.
+pub fn lookup_all_pkm_said(packet: &Packet) -> Result<Vec<u16>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PKM_SAID_TYPE) {
+        vec.push(
+            avp.decode_u16()
+                .map_err(|e| e.with_attribute_name("PKM-SAID"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const PKM_SA_DESCRIPTOR_TYPE: AVPType = 142;
+/// Delete all of `pkm_sa_descriptor` values from a packet.
+pub fn delete_pkm_sa_descriptor(packet: &mut Packet) {
+    packet.delete(PKM_SA_DESCRIPTOR_TYPE);
+}
+/// Returns whether a packet has `pkm_sa_descriptor` without decoding the value.
+pub fn has_pkm_sa_descriptor(packet: &Packet) -> bool {
+    packet.contains(PKM_SA_DESCRIPTOR_TYPE)
+}
+/// Returns the number of `pkm_sa_descriptor` attributes in a packet.
+pub fn count_pkm_sa_descriptor(packet: &Packet) -> usize {
+    packet.count(PKM_SA_DESCRIPTOR_TYPE)
+}
+/// Add `pkm_sa_descriptor` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_pkm_sa_descriptor(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(PKM_SA_DESCRIPTOR_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `pkm_sa_descriptor` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `pkm_sa_descriptor`, it returns `None`.
+pub fn lookup_pkm_sa_descriptor(packet: &Packet) -> Option<Vec<u8>> {
+    packet
+        .lookup(PKM_SA_DESCRIPTOR_TYPE)
+        .map(|v| v.decode_bytes())
+}
+/// Lookup all of the `pkm_sa_descriptor` octets value from a packet.
+pub fn lookup_all_pkm_sa_descriptor(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PKM_SA_DESCRIPTOR_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+pub const PKM_AUTH_KEY_TYPE: AVPType = 143;
+/// Delete all of `pkm_auth_key` values from a packet.
+pub fn delete_pkm_auth_key(packet: &mut Packet) {
+    packet.delete(PKM_AUTH_KEY_TYPE);
+}
+/// Returns whether a packet has `pkm_auth_key` without decoding the value.
+pub fn has_pkm_auth_key(packet: &Packet) -> bool {
+    packet.contains(PKM_AUTH_KEY_TYPE)
+}
+/// Returns the number of `pkm_auth_key` attributes in a packet.
+pub fn count_pkm_auth_key(packet: &Packet) -> usize {
+    packet.count(PKM_AUTH_KEY_TYPE)
+}
+/// Add `pkm_auth_key` octets value to a packet.
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_pkm_auth_key(packet: &mut Packet, value: impl AsRef<[u8]>) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(PKM_AUTH_KEY_TYPE, value)?);
+    Ok(())
+}
+/// Lookup a `pkm_auth_key` octets value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `pkm_auth_key`, it returns `None`.
+pub fn lookup_pkm_auth_key(packet: &Packet) -> Option<Vec<u8>> {
+    packet.lookup(PKM_AUTH_KEY_TYPE).map(|v| v.decode_bytes())
+}
+/// Lookup all of the `pkm_auth_key` octets value from a packet.
+pub fn lookup_all_pkm_auth_key(packet: &Packet) -> Vec<Vec<u8>> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(PKM_AUTH_KEY_TYPE) {
+        vec.push(avp.decode_bytes())
+    }
+    vec
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "PKM-SS-Cert",
+        typ: PKM_SS_CERT_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4044),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PKM-CA-Cert",
+        typ: PKM_CA_CERT_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4044),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PKM-Config-Settings",
+        typ: PKM_CONFIG_SETTINGS_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PKM-Cryptosuite-List",
+        typ: PKM_CRYPTOSUITE_LIST_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PKM-SAID",
+        typ: PKM_SAID_TYPE,
+        data_type: AttributeDataType::Short,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(2),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PKM-SA-Descriptor",
+        typ: PKM_SA_DESCRIPTOR_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PKM-Auth-Key",
+        typ: PKM_AUTH_KEY_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -18,31 +18,59 @@
 //! ATTRIBUTE    DS-Lite-Tunnel-Name            144    string
 //! ```
 
-use crate::core::avp::{AVPError, AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const DS_LITE_TUNNEL_NAME_TYPE: AVPType = 144;
 /// Delete all of `ds_lite_tunnel_name` values from a packet.
 pub fn delete_ds_lite_tunnel_name(packet: &mut Packet) {
     packet.delete(DS_LITE_TUNNEL_NAME_TYPE);
 }
+/// Returns whether a packet has `ds_lite_tunnel_name` without decoding the value.
+pub fn has_ds_lite_tunnel_name(packet: &Packet) -> bool {
+    packet.contains(DS_LITE_TUNNEL_NAME_TYPE)
+}
+/// Returns the number of `ds_lite_tunnel_name` attributes in a packet.
+pub fn count_ds_lite_tunnel_name(packet: &Packet) -> usize {
+    packet.count(DS_LITE_TUNNEL_NAME_TYPE)
+}
 /// Add `ds_lite_tunnel_name` string value to a packet.
-pub fn add_ds_lite_tunnel_name(packet: &mut Packet, value: &str) {
+pub fn add_ds_lite_tunnel_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
     packet.add(AVP::from_string(DS_LITE_TUNNEL_NAME_TYPE, value));
 }
 /// Lookup a `ds_lite_tunnel_name` string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `ds_lite_tunnel_name`, it returns `None`.
 pub fn lookup_ds_lite_tunnel_name(packet: &Packet) -> Option<Result<String, AVPError>> {
-    packet
-        .lookup(DS_LITE_TUNNEL_NAME_TYPE)
-        .map(|v| v.encode_string())
+    packet.lookup(DS_LITE_TUNNEL_NAME_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("DS-Lite-Tunnel-Name"))
+    })
 }
 /// Lookup all of the `ds_lite_tunnel_name` string value from a packet.
 pub fn lookup_all_ds_lite_tunnel_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(DS_LITE_TUNNEL_NAME_TYPE) {
-        vec.push(avp.encode_string()?)
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("DS-Lite-Tunnel-Name"))?,
+        )
     }
     Ok(vec)
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[AttributeMetadata {
+    name: "DS-Lite-Tunnel-Name",
+    typ: DS_LITE_TUNNEL_NAME_TYPE,
+    data_type: AttributeDataType::String,
+    encrypted: false,
+    has_tag: false,
+    max_length: Some(253),
+    max_occurrences: None,
+}];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
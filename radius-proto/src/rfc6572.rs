@@ -37,17 +37,33 @@
 
 use std::net::{Ipv4Addr, Ipv6Addr};
 
-use crate::core::avp::{AVPError, AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const MOBILE_NODE_IDENTIFIER_TYPE: AVPType = 145;
 /// Delete all of `mobile_node_identifier` values from a packet.
 pub fn delete_mobile_node_identifier(packet: &mut Packet) {
     packet.delete(MOBILE_NODE_IDENTIFIER_TYPE);
 }
+/// Returns whether a packet has `mobile_node_identifier` without decoding the value.
+pub fn has_mobile_node_identifier(packet: &Packet) -> bool {
+    packet.contains(MOBILE_NODE_IDENTIFIER_TYPE)
+}
+/// Returns the number of `mobile_node_identifier` attributes in a packet.
+pub fn count_mobile_node_identifier(packet: &Packet) -> usize {
+    packet.count(MOBILE_NODE_IDENTIFIER_TYPE)
+}
 /// Add `mobile_node_identifier` octets value to a packet.
-pub fn add_mobile_node_identifier(packet: &mut Packet, value: &[u8]) {
-    packet.add(AVP::from_bytes(MOBILE_NODE_IDENTIFIER_TYPE, value));
+///
+/// It returns an error if the value exceeds 253 bytes.
+pub fn add_mobile_node_identifier(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
+    packet.add(AVP::from_bytes(MOBILE_NODE_IDENTIFIER_TYPE, value)?);
+    Ok(())
 }
 /// Lookup a `mobile_node_identifier` octets value from a packet.
 ///
@@ -55,13 +71,13 @@ pub fn add_mobile_node_identifier(packet: &mut Packet, value: &[u8]) {
 pub fn lookup_mobile_node_identifier(packet: &Packet) -> Option<Vec<u8>> {
     packet
         .lookup(MOBILE_NODE_IDENTIFIER_TYPE)
-        .map(|v| v.encode_bytes())
+        .map(|v| v.decode_bytes())
 }
 /// Lookup all of the `mobile_node_identifier` octets value from a packet.
 pub fn lookup_all_mobile_node_identifier(packet: &Packet) -> Vec<Vec<u8>> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(MOBILE_NODE_IDENTIFIER_TYPE) {
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_bytes())
     }
     vec
 }
@@ -71,23 +87,36 @@ pub const SERVICE_SELECTION_TYPE: AVPType = 146;
 pub fn delete_service_selection(packet: &mut Packet) {
     packet.delete(SERVICE_SELECTION_TYPE);
 }
+/// Returns whether a packet has `service_selection` without decoding the value.
+pub fn has_service_selection(packet: &Packet) -> bool {
+    packet.contains(SERVICE_SELECTION_TYPE)
+}
+/// Returns the number of `service_selection` attributes in a packet.
+pub fn count_service_selection(packet: &Packet) -> usize {
+    packet.count(SERVICE_SELECTION_TYPE)
+}
 /// Add `service_selection` string value to a packet.
-pub fn add_service_selection(packet: &mut Packet, value: &str) {
+pub fn add_service_selection(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
     packet.add(AVP::from_string(SERVICE_SELECTION_TYPE, value));
 }
 /// Lookup a `service_selection` string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `service_selection`, it returns `None`.
 pub fn lookup_service_selection(packet: &Packet) -> Option<Result<String, AVPError>> {
-    packet
-        .lookup(SERVICE_SELECTION_TYPE)
-        .map(|v| v.encode_string())
+    packet.lookup(SERVICE_SELECTION_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Service-Selection"))
+    })
 }
 /// Lookup all of the `service_selection` string value from a packet.
 pub fn lookup_all_service_selection(packet: &Packet) -> Result<Vec<String>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(SERVICE_SELECTION_TYPE) {
-        vec.push(avp.encode_string()?)
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Service-Selection"))?,
+        )
     }
     Ok(vec)
 }
@@ -97,6 +126,14 @@ pub const PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE: AVPType = 147;
 pub fn delete_pmip6_home_lma_ipv6_address(packet: &mut Packet) {
     packet.delete(PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `pmip6_home_lma_ipv6_address` without decoding the value.
+pub fn has_pmip6_home_lma_ipv6_address(packet: &Packet) -> bool {
+    packet.contains(PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE)
+}
+/// Returns the number of `pmip6_home_lma_ipv6_address` attributes in a packet.
+pub fn count_pmip6_home_lma_ipv6_address(packet: &Packet) -> usize {
+    packet.count(PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE)
+}
 /// Add `pmip6_home_lma_ipv6_address` ipv6addr value to a packet.
 pub fn add_pmip6_home_lma_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
     packet.add(AVP::from_ipv6(PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE, value));
@@ -105,15 +142,19 @@ pub fn add_pmip6_home_lma_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
 ///
 /// It returns the first looked up value. If there is no associated value with `pmip6_home_lma_ipv6_address`, it returns `None`.
 pub fn lookup_pmip6_home_lma_ipv6_address(packet: &Packet) -> Option<Result<Ipv6Addr, AVPError>> {
-    packet
-        .lookup(PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv6())
+    packet.lookup(PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv6()
+            .map_err(|e| e.with_attribute_name("PMIP6-Home-LMA-IPV6-Address"))
+    })
 }
 /// Lookup all of the `pmip6_home_lma_ipv6_address` ipv6addr value from a packet.
 pub fn lookup_all_pmip6_home_lma_ipv6_address(packet: &Packet) -> Result<Vec<Ipv6Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv6()?)
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-LMA-IPV6-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -123,6 +164,14 @@ pub const PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE: AVPType = 148;
 pub fn delete_pmip6_visited_lma_ipv6_address(packet: &mut Packet) {
     packet.delete(PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `pmip6_visited_lma_ipv6_address` without decoding the value.
+pub fn has_pmip6_visited_lma_ipv6_address(packet: &Packet) -> bool {
+    packet.contains(PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE)
+}
+/// Returns the number of `pmip6_visited_lma_ipv6_address` attributes in a packet.
+pub fn count_pmip6_visited_lma_ipv6_address(packet: &Packet) -> usize {
+    packet.count(PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE)
+}
 /// Add `pmip6_visited_lma_ipv6_address` ipv6addr value to a packet.
 pub fn add_pmip6_visited_lma_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
     packet.add(AVP::from_ipv6(PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE, value));
@@ -133,9 +182,10 @@ pub fn add_pmip6_visited_lma_ipv6_address(packet: &mut Packet, value: &Ipv6Addr)
 pub fn lookup_pmip6_visited_lma_ipv6_address(
     packet: &Packet,
 ) -> Option<Result<Ipv6Addr, AVPError>> {
-    packet
-        .lookup(PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv6())
+    packet.lookup(PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv6()
+            .map_err(|e| e.with_attribute_name("PMIP6-Visited-LMA-IPV6-Address"))
+    })
 }
 /// Lookup all of the `pmip6_visited_lma_ipv6_address` ipv6addr value from a packet.
 pub fn lookup_all_pmip6_visited_lma_ipv6_address(
@@ -143,7 +193,10 @@ pub fn lookup_all_pmip6_visited_lma_ipv6_address(
 ) -> Result<Vec<Ipv6Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv6()?)
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-LMA-IPV6-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -153,6 +206,14 @@ pub const PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE: AVPType = 149;
 pub fn delete_pmip6_home_lma_ipv4_address(packet: &mut Packet) {
     packet.delete(PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `pmip6_home_lma_ipv4_address` without decoding the value.
+pub fn has_pmip6_home_lma_ipv4_address(packet: &Packet) -> bool {
+    packet.contains(PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE)
+}
+/// Returns the number of `pmip6_home_lma_ipv4_address` attributes in a packet.
+pub fn count_pmip6_home_lma_ipv4_address(packet: &Packet) -> usize {
+    packet.count(PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE)
+}
 /// Add `pmip6_home_lma_ipv4_address` ipaddr value to a packet.
 pub fn add_pmip6_home_lma_ipv4_address(packet: &mut Packet, value: &Ipv4Addr) {
     packet.add(AVP::from_ipv4(PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE, value));
@@ -161,15 +222,19 @@ pub fn add_pmip6_home_lma_ipv4_address(packet: &mut Packet, value: &Ipv4Addr) {
 ///
 /// It returns the first looked up value. If there is no associated value with `pmip6_home_lma_ipv4_address`, it returns `None`.
 pub fn lookup_pmip6_home_lma_ipv4_address(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
-    packet
-        .lookup(PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv4())
+    packet.lookup(PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("PMIP6-Home-LMA-IPV4-Address"))
+    })
 }
 /// Lookup all of the `pmip6_home_lma_ipv4_address` ipaddr value from a packet.
 pub fn lookup_all_pmip6_home_lma_ipv4_address(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv4()?)
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-LMA-IPV4-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -179,6 +244,14 @@ pub const PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE: AVPType = 150;
 pub fn delete_pmip6_visited_lma_ipv4_address(packet: &mut Packet) {
     packet.delete(PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `pmip6_visited_lma_ipv4_address` without decoding the value.
+pub fn has_pmip6_visited_lma_ipv4_address(packet: &Packet) -> bool {
+    packet.contains(PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE)
+}
+/// Returns the number of `pmip6_visited_lma_ipv4_address` attributes in a packet.
+pub fn count_pmip6_visited_lma_ipv4_address(packet: &Packet) -> usize {
+    packet.count(PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE)
+}
 /// Add `pmip6_visited_lma_ipv4_address` ipaddr value to a packet.
 pub fn add_pmip6_visited_lma_ipv4_address(packet: &mut Packet, value: &Ipv4Addr) {
     packet.add(AVP::from_ipv4(PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE, value));
@@ -189,9 +262,10 @@ pub fn add_pmip6_visited_lma_ipv4_address(packet: &mut Packet, value: &Ipv4Addr)
 pub fn lookup_pmip6_visited_lma_ipv4_address(
     packet: &Packet,
 ) -> Option<Result<Ipv4Addr, AVPError>> {
-    packet
-        .lookup(PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv4())
+    packet.lookup(PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("PMIP6-Visited-LMA-IPV4-Address"))
+    })
 }
 /// Lookup all of the `pmip6_visited_lma_ipv4_address` ipaddr value from a packet.
 pub fn lookup_all_pmip6_visited_lma_ipv4_address(
@@ -199,7 +273,10 @@ pub fn lookup_all_pmip6_visited_lma_ipv4_address(
 ) -> Result<Vec<Ipv4Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv4()?)
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-LMA-IPV4-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -209,8 +286,20 @@ pub const PMIP6_HOME_HN_PREFIX_TYPE: AVPType = 151;
 pub fn delete_pmip6_home_hn_prefix(packet: &mut Packet) {
     packet.delete(PMIP6_HOME_HN_PREFIX_TYPE);
 }
+/// Returns whether a packet has `pmip6_home_hn_prefix` without decoding the value.
+pub fn has_pmip6_home_hn_prefix(packet: &Packet) -> bool {
+    packet.contains(PMIP6_HOME_HN_PREFIX_TYPE)
+}
+/// Returns the number of `pmip6_home_hn_prefix` attributes in a packet.
+pub fn count_pmip6_home_hn_prefix(packet: &Packet) -> usize {
+    packet.count(PMIP6_HOME_HN_PREFIX_TYPE)
+}
 /// Add `pmip6_home_hn_prefix` ipv6 prefix value to a packet.
-pub fn add_pmip6_home_hn_prefix(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_pmip6_home_hn_prefix(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     packet.add(AVP::from_ipv6_prefix(PMIP6_HOME_HN_PREFIX_TYPE, value)?);
     Ok(())
 }
@@ -218,15 +307,19 @@ pub fn add_pmip6_home_hn_prefix(packet: &mut Packet, value: &[u8]) -> Result<(),
 ///
 /// It returns the first looked up value. If there is no associated value with `pmip6_home_hn_prefix`, it returns `None`.
 pub fn lookup_pmip6_home_hn_prefix(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
-    packet
-        .lookup(PMIP6_HOME_HN_PREFIX_TYPE)
-        .map(|v| v.encode_ipv6_prefix())
+    packet.lookup(PMIP6_HOME_HN_PREFIX_TYPE).map(|v| {
+        v.decode_ipv6_prefix()
+            .map_err(|e| e.with_attribute_name("PMIP6-Home-HN-Prefix"))
+    })
 }
 /// Lookup all of the `pmip6_home_hn_prefix` ipv6 prefix value from a packet.
 pub fn lookup_all_pmip6_home_hn_prefix(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_HOME_HN_PREFIX_TYPE) {
-        vec.push(avp.encode_ipv6_prefix()?)
+        vec.push(
+            avp.decode_ipv6_prefix()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-HN-Prefix"))?,
+        )
     }
     Ok(vec)
 }
@@ -236,8 +329,20 @@ pub const PMIP6_VISITED_HN_PREFIX_TYPE: AVPType = 152;
 pub fn delete_pmip6_visited_hn_prefix(packet: &mut Packet) {
     packet.delete(PMIP6_VISITED_HN_PREFIX_TYPE);
 }
+/// Returns whether a packet has `pmip6_visited_hn_prefix` without decoding the value.
+pub fn has_pmip6_visited_hn_prefix(packet: &Packet) -> bool {
+    packet.contains(PMIP6_VISITED_HN_PREFIX_TYPE)
+}
+/// Returns the number of `pmip6_visited_hn_prefix` attributes in a packet.
+pub fn count_pmip6_visited_hn_prefix(packet: &Packet) -> usize {
+    packet.count(PMIP6_VISITED_HN_PREFIX_TYPE)
+}
 /// Add `pmip6_visited_hn_prefix` ipv6 prefix value to a packet.
-pub fn add_pmip6_visited_hn_prefix(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_pmip6_visited_hn_prefix(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     packet.add(AVP::from_ipv6_prefix(PMIP6_VISITED_HN_PREFIX_TYPE, value)?);
     Ok(())
 }
@@ -245,15 +350,19 @@ pub fn add_pmip6_visited_hn_prefix(packet: &mut Packet, value: &[u8]) -> Result<
 ///
 /// It returns the first looked up value. If there is no associated value with `pmip6_visited_hn_prefix`, it returns `None`.
 pub fn lookup_pmip6_visited_hn_prefix(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
-    packet
-        .lookup(PMIP6_VISITED_HN_PREFIX_TYPE)
-        .map(|v| v.encode_ipv6_prefix())
+    packet.lookup(PMIP6_VISITED_HN_PREFIX_TYPE).map(|v| {
+        v.decode_ipv6_prefix()
+            .map_err(|e| e.with_attribute_name("PMIP6-Visited-HN-Prefix"))
+    })
 }
 /// Lookup all of the `pmip6_visited_hn_prefix` ipv6 prefix value from a packet.
 pub fn lookup_all_pmip6_visited_hn_prefix(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_VISITED_HN_PREFIX_TYPE) {
-        vec.push(avp.encode_ipv6_prefix()?)
+        vec.push(
+            avp.decode_ipv6_prefix()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-HN-Prefix"))?,
+        )
     }
     Ok(vec)
 }
@@ -263,15 +372,27 @@ pub const PMIP6_HOME_INTERFACE_ID_TYPE: AVPType = 153;
 pub fn delete_pmip6_home_interface_id(packet: &mut Packet) {
     packet.delete(PMIP6_HOME_INTERFACE_ID_TYPE);
 }
+/// Returns whether a packet has `pmip6_home_interface_id` without decoding the value.
+pub fn has_pmip6_home_interface_id(packet: &Packet) -> bool {
+    packet.contains(PMIP6_HOME_INTERFACE_ID_TYPE)
+}
+/// Returns the number of `pmip6_home_interface_id` attributes in a packet.
+pub fn count_pmip6_home_interface_id(packet: &Packet) -> usize {
+    packet.count(PMIP6_HOME_INTERFACE_ID_TYPE)
+}
 /// Add `pmip6_home_interface_id` fixed-length octets value to a packet.
-pub fn add_pmip6_home_interface_id(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_pmip6_home_interface_id(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     if value.len() != 8 {
         return Err(AVPError::InvalidAttributeLengthError(
             "8 bytes".to_owned(),
             value.len(),
         ));
     }
-    packet.add(AVP::from_bytes(PMIP6_HOME_INTERFACE_ID_TYPE, value));
+    packet.add(AVP::from_bytes(PMIP6_HOME_INTERFACE_ID_TYPE, value)?);
     Ok(())
 }
 /// Lookup a `pmip6_home_interface_id` fixed-length octets value from a packet.
@@ -280,13 +401,13 @@ pub fn add_pmip6_home_interface_id(packet: &mut Packet, value: &[u8]) -> Result<
 pub fn lookup_pmip6_home_interface_id(packet: &Packet) -> Option<Vec<u8>> {
     packet
         .lookup(PMIP6_HOME_INTERFACE_ID_TYPE)
-        .map(|v| v.encode_bytes())
+        .map(|v| v.decode_bytes())
 }
 /// Lookup all of the `pmip6_home_interface_id` fixed-length octets value from a packet.
 pub fn lookup_all_pmip6_home_interface_id(packet: &Packet) -> Vec<Vec<u8>> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_HOME_INTERFACE_ID_TYPE) {
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_bytes())
     }
     vec
 }
@@ -296,15 +417,27 @@ pub const PMIP6_VISITED_INTERFACE_ID_TYPE: AVPType = 154;
 pub fn delete_pmip6_visited_interface_id(packet: &mut Packet) {
     packet.delete(PMIP6_VISITED_INTERFACE_ID_TYPE);
 }
+/// Returns whether a packet has `pmip6_visited_interface_id` without decoding the value.
+pub fn has_pmip6_visited_interface_id(packet: &Packet) -> bool {
+    packet.contains(PMIP6_VISITED_INTERFACE_ID_TYPE)
+}
+/// Returns the number of `pmip6_visited_interface_id` attributes in a packet.
+pub fn count_pmip6_visited_interface_id(packet: &Packet) -> usize {
+    packet.count(PMIP6_VISITED_INTERFACE_ID_TYPE)
+}
 /// Add `pmip6_visited_interface_id` fixed-length octets value to a packet.
-pub fn add_pmip6_visited_interface_id(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_pmip6_visited_interface_id(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     if value.len() != 8 {
         return Err(AVPError::InvalidAttributeLengthError(
             "8 bytes".to_owned(),
             value.len(),
         ));
     }
-    packet.add(AVP::from_bytes(PMIP6_VISITED_INTERFACE_ID_TYPE, value));
+    packet.add(AVP::from_bytes(PMIP6_VISITED_INTERFACE_ID_TYPE, value)?);
     Ok(())
 }
 /// Lookup a `pmip6_visited_interface_id` fixed-length octets value from a packet.
@@ -313,13 +446,13 @@ pub fn add_pmip6_visited_interface_id(packet: &mut Packet, value: &[u8]) -> Resu
 pub fn lookup_pmip6_visited_interface_id(packet: &Packet) -> Option<Vec<u8>> {
     packet
         .lookup(PMIP6_VISITED_INTERFACE_ID_TYPE)
-        .map(|v| v.encode_bytes())
+        .map(|v| v.decode_bytes())
 }
 /// Lookup all of the `pmip6_visited_interface_id` fixed-length octets value from a packet.
 pub fn lookup_all_pmip6_visited_interface_id(packet: &Packet) -> Vec<Vec<u8>> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_VISITED_INTERFACE_ID_TYPE) {
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_bytes())
     }
     vec
 }
@@ -329,8 +462,20 @@ pub const PMIP6_HOME_IPV4_HO_A_TYPE: AVPType = 155;
 pub fn delete_pmip6_home_ipv4_ho_a(packet: &mut Packet) {
     packet.delete(PMIP6_HOME_IPV4_HO_A_TYPE);
 }
+/// Returns whether a packet has `pmip6_home_ipv4_ho_a` without decoding the value.
+pub fn has_pmip6_home_ipv4_ho_a(packet: &Packet) -> bool {
+    packet.contains(PMIP6_HOME_IPV4_HO_A_TYPE)
+}
+/// Returns the number of `pmip6_home_ipv4_ho_a` attributes in a packet.
+pub fn count_pmip6_home_ipv4_ho_a(packet: &Packet) -> usize {
+    packet.count(PMIP6_HOME_IPV4_HO_A_TYPE)
+}
 /// Add `pmip6_home_ipv4_ho_a` ipv4 prefix value to a packet.
-pub fn add_pmip6_home_ipv4_ho_a(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_pmip6_home_ipv4_ho_a(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     packet.add(AVP::from_ipv4_prefix(PMIP6_HOME_IPV4_HO_A_TYPE, value)?);
     Ok(())
 }
@@ -338,15 +483,19 @@ pub fn add_pmip6_home_ipv4_ho_a(packet: &mut Packet, value: &[u8]) -> Result<(),
 ///
 /// It returns the first looked up value. If there is no associated value with `pmip6_home_ipv4_ho_a`, it returns `None`.
 pub fn lookup_pmip6_home_ipv4_ho_a(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
-    packet
-        .lookup(PMIP6_HOME_IPV4_HO_A_TYPE)
-        .map(|v| v.encode_ipv4_prefix())
+    packet.lookup(PMIP6_HOME_IPV4_HO_A_TYPE).map(|v| {
+        v.decode_ipv4_prefix()
+            .map_err(|e| e.with_attribute_name("PMIP6-Home-IPV4-HoA"))
+    })
 }
 /// Lookup all of the `pmip6_home_ipv4_ho_a` ipv4 prefix value from a packet.
 pub fn lookup_all_pmip6_home_ipv4_ho_a(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_HOME_IPV4_HO_A_TYPE) {
-        vec.push(avp.encode_ipv4_prefix()?)
+        vec.push(
+            avp.decode_ipv4_prefix()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-IPV4-HoA"))?,
+        )
     }
     Ok(vec)
 }
@@ -356,8 +505,20 @@ pub const PMIP6_VISITED_IPV4_HO_A_TYPE: AVPType = 156;
 pub fn delete_pmip6_visited_ipv4_ho_a(packet: &mut Packet) {
     packet.delete(PMIP6_VISITED_IPV4_HO_A_TYPE);
 }
+/// Returns whether a packet has `pmip6_visited_ipv4_ho_a` without decoding the value.
+pub fn has_pmip6_visited_ipv4_ho_a(packet: &Packet) -> bool {
+    packet.contains(PMIP6_VISITED_IPV4_HO_A_TYPE)
+}
+/// Returns the number of `pmip6_visited_ipv4_ho_a` attributes in a packet.
+pub fn count_pmip6_visited_ipv4_ho_a(packet: &Packet) -> usize {
+    packet.count(PMIP6_VISITED_IPV4_HO_A_TYPE)
+}
 /// Add `pmip6_visited_ipv4_ho_a` ipv4 prefix value to a packet.
-pub fn add_pmip6_visited_ipv4_ho_a(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_pmip6_visited_ipv4_ho_a(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     packet.add(AVP::from_ipv4_prefix(PMIP6_VISITED_IPV4_HO_A_TYPE, value)?);
     Ok(())
 }
@@ -365,15 +526,19 @@ pub fn add_pmip6_visited_ipv4_ho_a(packet: &mut Packet, value: &[u8]) -> Result<
 ///
 /// It returns the first looked up value. If there is no associated value with `pmip6_visited_ipv4_ho_a`, it returns `None`.
 pub fn lookup_pmip6_visited_ipv4_ho_a(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
-    packet
-        .lookup(PMIP6_VISITED_IPV4_HO_A_TYPE)
-        .map(|v| v.encode_ipv4_prefix())
+    packet.lookup(PMIP6_VISITED_IPV4_HO_A_TYPE).map(|v| {
+        v.decode_ipv4_prefix()
+            .map_err(|e| e.with_attribute_name("PMIP6-Visited-IPV4-HoA"))
+    })
 }
 /// Lookup all of the `pmip6_visited_ipv4_ho_a` ipv4 prefix value from a packet.
 pub fn lookup_all_pmip6_visited_ipv4_ho_a(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_VISITED_IPV4_HO_A_TYPE) {
-        vec.push(avp.encode_ipv4_prefix()?)
+        vec.push(
+            avp.decode_ipv4_prefix()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-IPV4-HoA"))?,
+        )
     }
     Ok(vec)
 }
@@ -383,6 +548,14 @@ pub const PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE: AVPType = 157;
 pub fn delete_pmip6_home_dhcp4_server_address(packet: &mut Packet) {
     packet.delete(PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `pmip6_home_dhcp4_server_address` without decoding the value.
+pub fn has_pmip6_home_dhcp4_server_address(packet: &Packet) -> bool {
+    packet.contains(PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE)
+}
+/// Returns the number of `pmip6_home_dhcp4_server_address` attributes in a packet.
+pub fn count_pmip6_home_dhcp4_server_address(packet: &Packet) -> usize {
+    packet.count(PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE)
+}
 /// Add `pmip6_home_dhcp4_server_address` ipaddr value to a packet.
 pub fn add_pmip6_home_dhcp4_server_address(packet: &mut Packet, value: &Ipv4Addr) {
     packet.add(AVP::from_ipv4(PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE, value));
@@ -395,7 +568,10 @@ pub fn lookup_pmip6_home_dhcp4_server_address(
 ) -> Option<Result<Ipv4Addr, AVPError>> {
     packet
         .lookup(PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv4())
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-DHCP4-Server-Address"))
+        })
 }
 /// Lookup all of the `pmip6_home_dhcp4_server_address` ipaddr value from a packet.
 pub fn lookup_all_pmip6_home_dhcp4_server_address(
@@ -403,7 +579,10 @@ pub fn lookup_all_pmip6_home_dhcp4_server_address(
 ) -> Result<Vec<Ipv4Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv4()?)
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-DHCP4-Server-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -413,6 +592,14 @@ pub const PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE: AVPType = 158;
 pub fn delete_pmip6_visited_dhcp4_server_address(packet: &mut Packet) {
     packet.delete(PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `pmip6_visited_dhcp4_server_address` without decoding the value.
+pub fn has_pmip6_visited_dhcp4_server_address(packet: &Packet) -> bool {
+    packet.contains(PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE)
+}
+/// Returns the number of `pmip6_visited_dhcp4_server_address` attributes in a packet.
+pub fn count_pmip6_visited_dhcp4_server_address(packet: &Packet) -> usize {
+    packet.count(PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE)
+}
 /// Add `pmip6_visited_dhcp4_server_address` ipaddr value to a packet.
 pub fn add_pmip6_visited_dhcp4_server_address(packet: &mut Packet, value: &Ipv4Addr) {
     packet.add(AVP::from_ipv4(
@@ -428,7 +615,10 @@ pub fn lookup_pmip6_visited_dhcp4_server_address(
 ) -> Option<Result<Ipv4Addr, AVPError>> {
     packet
         .lookup(PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv4())
+        .map(|v| {
+            v.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-DHCP4-Server-Address"))
+        })
 }
 /// Lookup all of the `pmip6_visited_dhcp4_server_address` ipaddr value from a packet.
 pub fn lookup_all_pmip6_visited_dhcp4_server_address(
@@ -436,7 +626,10 @@ pub fn lookup_all_pmip6_visited_dhcp4_server_address(
 ) -> Result<Vec<Ipv4Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv4()?)
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-DHCP4-Server-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -446,6 +639,14 @@ pub const PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE: AVPType = 159;
 pub fn delete_pmip6_home_dhcp6_server_address(packet: &mut Packet) {
     packet.delete(PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `pmip6_home_dhcp6_server_address` without decoding the value.
+pub fn has_pmip6_home_dhcp6_server_address(packet: &Packet) -> bool {
+    packet.contains(PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE)
+}
+/// Returns the number of `pmip6_home_dhcp6_server_address` attributes in a packet.
+pub fn count_pmip6_home_dhcp6_server_address(packet: &Packet) -> usize {
+    packet.count(PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE)
+}
 /// Add `pmip6_home_dhcp6_server_address` ipv6addr value to a packet.
 pub fn add_pmip6_home_dhcp6_server_address(packet: &mut Packet, value: &Ipv6Addr) {
     packet.add(AVP::from_ipv6(PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE, value));
@@ -458,7 +659,10 @@ pub fn lookup_pmip6_home_dhcp6_server_address(
 ) -> Option<Result<Ipv6Addr, AVPError>> {
     packet
         .lookup(PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv6())
+        .map(|v| {
+            v.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-DHCP6-Server-Address"))
+        })
 }
 /// Lookup all of the `pmip6_home_dhcp6_server_address` ipv6addr value from a packet.
 pub fn lookup_all_pmip6_home_dhcp6_server_address(
@@ -466,7 +670,10 @@ pub fn lookup_all_pmip6_home_dhcp6_server_address(
 ) -> Result<Vec<Ipv6Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv6()?)
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-DHCP6-Server-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -476,6 +683,14 @@ pub const PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE: AVPType = 160;
 pub fn delete_pmip6_visited_dhcp6_server_address(packet: &mut Packet) {
     packet.delete(PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `pmip6_visited_dhcp6_server_address` without decoding the value.
+pub fn has_pmip6_visited_dhcp6_server_address(packet: &Packet) -> bool {
+    packet.contains(PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE)
+}
+/// Returns the number of `pmip6_visited_dhcp6_server_address` attributes in a packet.
+pub fn count_pmip6_visited_dhcp6_server_address(packet: &Packet) -> usize {
+    packet.count(PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE)
+}
 /// Add `pmip6_visited_dhcp6_server_address` ipv6addr value to a packet.
 pub fn add_pmip6_visited_dhcp6_server_address(packet: &mut Packet, value: &Ipv6Addr) {
     packet.add(AVP::from_ipv6(
@@ -491,7 +706,10 @@ pub fn lookup_pmip6_visited_dhcp6_server_address(
 ) -> Option<Result<Ipv6Addr, AVPError>> {
     packet
         .lookup(PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv6())
+        .map(|v| {
+            v.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-DHCP6-Server-Address"))
+        })
 }
 /// Lookup all of the `pmip6_visited_dhcp6_server_address` ipv6addr value from a packet.
 pub fn lookup_all_pmip6_visited_dhcp6_server_address(
@@ -499,7 +717,10 @@ pub fn lookup_all_pmip6_visited_dhcp6_server_address(
 ) -> Result<Vec<Ipv6Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv6()?)
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-DHCP6-Server-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -509,6 +730,14 @@ pub const PMIP6_HOME_IPV4_GATEWAY_TYPE: AVPType = 161;
 pub fn delete_pmip6_home_ipv4_gateway(packet: &mut Packet) {
     packet.delete(PMIP6_HOME_IPV4_GATEWAY_TYPE);
 }
+/// Returns whether a packet has `pmip6_home_ipv4_gateway` without decoding the value.
+pub fn has_pmip6_home_ipv4_gateway(packet: &Packet) -> bool {
+    packet.contains(PMIP6_HOME_IPV4_GATEWAY_TYPE)
+}
+/// Returns the number of `pmip6_home_ipv4_gateway` attributes in a packet.
+pub fn count_pmip6_home_ipv4_gateway(packet: &Packet) -> usize {
+    packet.count(PMIP6_HOME_IPV4_GATEWAY_TYPE)
+}
 /// Add `pmip6_home_ipv4_gateway` ipaddr value to a packet.
 pub fn add_pmip6_home_ipv4_gateway(packet: &mut Packet, value: &Ipv4Addr) {
     packet.add(AVP::from_ipv4(PMIP6_HOME_IPV4_GATEWAY_TYPE, value));
@@ -517,15 +746,19 @@ pub fn add_pmip6_home_ipv4_gateway(packet: &mut Packet, value: &Ipv4Addr) {
 ///
 /// It returns the first looked up value. If there is no associated value with `pmip6_home_ipv4_gateway`, it returns `None`.
 pub fn lookup_pmip6_home_ipv4_gateway(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
-    packet
-        .lookup(PMIP6_HOME_IPV4_GATEWAY_TYPE)
-        .map(|v| v.encode_ipv4())
+    packet.lookup(PMIP6_HOME_IPV4_GATEWAY_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("PMIP6-Home-IPV4-Gateway"))
+    })
 }
 /// Lookup all of the `pmip6_home_ipv4_gateway` ipaddr value from a packet.
 pub fn lookup_all_pmip6_home_ipv4_gateway(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_HOME_IPV4_GATEWAY_TYPE) {
-        vec.push(avp.encode_ipv4()?)
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("PMIP6-Home-IPV4-Gateway"))?,
+        )
     }
     Ok(vec)
 }
@@ -535,6 +768,14 @@ pub const PMIP6_VISITED_IPV4_GATEWAY_TYPE: AVPType = 162;
 pub fn delete_pmip6_visited_ipv4_gateway(packet: &mut Packet) {
     packet.delete(PMIP6_VISITED_IPV4_GATEWAY_TYPE);
 }
+/// Returns whether a packet has `pmip6_visited_ipv4_gateway` without decoding the value.
+pub fn has_pmip6_visited_ipv4_gateway(packet: &Packet) -> bool {
+    packet.contains(PMIP6_VISITED_IPV4_GATEWAY_TYPE)
+}
+/// Returns the number of `pmip6_visited_ipv4_gateway` attributes in a packet.
+pub fn count_pmip6_visited_ipv4_gateway(packet: &Packet) -> usize {
+    packet.count(PMIP6_VISITED_IPV4_GATEWAY_TYPE)
+}
 /// Add `pmip6_visited_ipv4_gateway` ipaddr value to a packet.
 pub fn add_pmip6_visited_ipv4_gateway(packet: &mut Packet, value: &Ipv4Addr) {
     packet.add(AVP::from_ipv4(PMIP6_VISITED_IPV4_GATEWAY_TYPE, value));
@@ -543,15 +784,188 @@ pub fn add_pmip6_visited_ipv4_gateway(packet: &mut Packet, value: &Ipv4Addr) {
 ///
 /// It returns the first looked up value. If there is no associated value with `pmip6_visited_ipv4_gateway`, it returns `None`.
 pub fn lookup_pmip6_visited_ipv4_gateway(packet: &Packet) -> Option<Result<Ipv4Addr, AVPError>> {
-    packet
-        .lookup(PMIP6_VISITED_IPV4_GATEWAY_TYPE)
-        .map(|v| v.encode_ipv4())
+    packet.lookup(PMIP6_VISITED_IPV4_GATEWAY_TYPE).map(|v| {
+        v.decode_ipv4()
+            .map_err(|e| e.with_attribute_name("PMIP6-Visited-IPV4-Gateway"))
+    })
 }
 /// Lookup all of the `pmip6_visited_ipv4_gateway` ipaddr value from a packet.
 pub fn lookup_all_pmip6_visited_ipv4_gateway(packet: &Packet) -> Result<Vec<Ipv4Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(PMIP6_VISITED_IPV4_GATEWAY_TYPE) {
-        vec.push(avp.encode_ipv4()?)
+        vec.push(
+            avp.decode_ipv4()
+                .map_err(|e| e.with_attribute_name("PMIP6-Visited-IPV4-Gateway"))?,
+        )
     }
     Ok(vec)
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Mobile-Node-Identifier",
+        typ: MOBILE_NODE_IDENTIFIER_TYPE,
+        data_type: AttributeDataType::Octets,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Service-Selection",
+        typ: SERVICE_SELECTION_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Home-LMA-IPV6-Address",
+        typ: PMIP6_HOME_LMA_IPV6_ADDRESS_TYPE,
+        data_type: AttributeDataType::Ipv6Addr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Visited-LMA-IPV6-Address",
+        typ: PMIP6_VISITED_LMA_IPV6_ADDRESS_TYPE,
+        data_type: AttributeDataType::Ipv6Addr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Home-LMA-IPV4-Address",
+        typ: PMIP6_HOME_LMA_IPV4_ADDRESS_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Visited-LMA-IPV4-Address",
+        typ: PMIP6_VISITED_LMA_IPV4_ADDRESS_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Home-HN-Prefix",
+        typ: PMIP6_HOME_HN_PREFIX_TYPE,
+        data_type: AttributeDataType::Ipv6Prefix,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(18),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Visited-HN-Prefix",
+        typ: PMIP6_VISITED_HN_PREFIX_TYPE,
+        data_type: AttributeDataType::Ipv6Prefix,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(18),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Home-Interface-ID",
+        typ: PMIP6_HOME_INTERFACE_ID_TYPE,
+        data_type: AttributeDataType::IfId,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(8),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Visited-Interface-ID",
+        typ: PMIP6_VISITED_INTERFACE_ID_TYPE,
+        data_type: AttributeDataType::IfId,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(8),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Home-IPV4-HoA",
+        typ: PMIP6_HOME_IPV4_HO_A_TYPE,
+        data_type: AttributeDataType::Ipv4Prefix,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(6),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Visited-IPV4-HoA",
+        typ: PMIP6_VISITED_IPV4_HO_A_TYPE,
+        data_type: AttributeDataType::Ipv4Prefix,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(6),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Home-DHCP4-Server-Address",
+        typ: PMIP6_HOME_DHCP4_SERVER_ADDRESS_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Visited-DHCP4-Server-Address",
+        typ: PMIP6_VISITED_DHCP4_SERVER_ADDRESS_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Home-DHCP6-Server-Address",
+        typ: PMIP6_HOME_DHCP6_SERVER_ADDRESS_TYPE,
+        data_type: AttributeDataType::Ipv6Addr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Visited-DHCP6-Server-Address",
+        typ: PMIP6_VISITED_DHCP6_SERVER_ADDRESS_TYPE,
+        data_type: AttributeDataType::Ipv6Addr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Home-IPV4-Gateway",
+        typ: PMIP6_HOME_IPV4_GATEWAY_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "PMIP6-Visited-IPV4-Gateway",
+        typ: PMIP6_VISITED_IPV4_GATEWAY_TYPE,
+        data_type: AttributeDataType::IpAddr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(4),
+        max_occurrences: None,
+    },
+];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -0,0 +1,219 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc6677 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #    Attributes and values defined in RFC 6677
+//! #    http://www.ietf.org/rfc/rfc6677.txt
+//! #
+//!
+//! ATTRIBUTE    EAP-Lower-Layer                163    integer
+//!
+//! VALUE    EAP-Lower-Layer            Wired-IEEE-802.1X    1
+//! VALUE    EAP-Lower-Layer            IEEE-802.1X-No-Preauth    2
+//! VALUE    EAP-Lower-Layer            IEEE-802.1X-Preauth    3
+//! VALUE    EAP-Lower-Layer            IEEE-802.16e        4
+//! VALUE    EAP-Lower-Layer            IKEv2            5
+//! VALUE    EAP-Lower-Layer            PPP            6
+//! VALUE    EAP-Lower-Layer            PANA-No-Preauth        7
+//! VALUE    EAP-Lower-Layer            GSS-API            8
+//! VALUE    EAP-Lower-Layer            PANA-Preauth        9
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+pub const EAP_LOWER_LAYER_TYPE: AVPType = 163;
+/// Delete all of `eap_lower_layer` values from a packet.
+pub fn delete_eap_lower_layer(packet: &mut Packet) {
+    packet.delete(EAP_LOWER_LAYER_TYPE);
+}
+/// Returns whether a packet has `eap_lower_layer` without decoding the value.
+pub fn has_eap_lower_layer(packet: &Packet) -> bool {
+    packet.contains(EAP_LOWER_LAYER_TYPE)
+}
+/// Returns the number of `eap_lower_layer` attributes in a packet.
+pub fn count_eap_lower_layer(packet: &Packet) -> usize {
+    packet.count(EAP_LOWER_LAYER_TYPE)
+}
+/// Add `eap_lower_layer` value-defined integer value to a packet.
+pub fn add_eap_lower_layer(packet: &mut Packet, value: EapLowerLayer) {
+    packet.add(AVP::from_u32(EAP_LOWER_LAYER_TYPE, value.to_u32()));
+}
+/// Lookup a `eap_lower_layer` value-defined integer value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `eap_lower_layer`, it returns `None`.
+pub fn lookup_eap_lower_layer(packet: &Packet) -> Option<Result<EapLowerLayer, AVPError>> {
+    packet.lookup(EAP_LOWER_LAYER_TYPE).map(|v| {
+        Ok(EapLowerLayer::from_u32(
+            v.decode_u32()
+                .map_err(|e| e.with_attribute_name("EAP-Lower-Layer"))?,
+        ))
+    })
+}
+/// Lookup all of the `eap_lower_layer` value-defined integer value from a packet.
+pub fn lookup_all_eap_lower_layer(packet: &Packet) -> Result<Vec<EapLowerLayer>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(EAP_LOWER_LAYER_TYPE) {
+        vec.push(EapLowerLayer::from_u32(
+            avp.decode_u32()
+                .map_err(|e| e.with_attribute_name("EAP-Lower-Layer"))?,
+        ))
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[AttributeMetadata {
+    name: "EAP-Lower-Layer",
+    typ: EAP_LOWER_LAYER_TYPE,
+    data_type: AttributeDataType::Integer,
+    encrypted: false,
+    has_tag: false,
+    max_length: Some(4),
+    max_occurrences: None,
+}];
+
+/// The values of `EAP-Lower-Layer`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EapLowerLayer {
+    WiredIEEE8021X,
+    Ieee8021XNoPreauth,
+    Ieee8021XPreauth,
+    Ieee80216E,
+    Ikev2,
+    Ppp,
+    PanaNoPreauth,
+    GssApi,
+    PanaPreauth,
+    /// A value that isn't defined in this module (e.g. a value that another module adds).
+    Unknown(u32),
+}
+impl EapLowerLayer {
+    /// Returns the variant of a value; the value that isn't defined in this module is `Unknown`.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            1 => EapLowerLayer::WiredIEEE8021X,
+            2 => EapLowerLayer::Ieee8021XNoPreauth,
+            3 => EapLowerLayer::Ieee8021XPreauth,
+            4 => EapLowerLayer::Ieee80216E,
+            5 => EapLowerLayer::Ikev2,
+            6 => EapLowerLayer::Ppp,
+            7 => EapLowerLayer::PanaNoPreauth,
+            8 => EapLowerLayer::GssApi,
+            9 => EapLowerLayer::PanaPreauth,
+            value => EapLowerLayer::Unknown(value),
+        }
+    }
+    /// Returns the value of the variant.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            EapLowerLayer::WiredIEEE8021X => 1,
+            EapLowerLayer::Ieee8021XNoPreauth => 2,
+            EapLowerLayer::Ieee8021XPreauth => 3,
+            EapLowerLayer::Ieee80216E => 4,
+            EapLowerLayer::Ikev2 => 5,
+            EapLowerLayer::Ppp => 6,
+            EapLowerLayer::PanaNoPreauth => 7,
+            EapLowerLayer::GssApi => 8,
+            EapLowerLayer::PanaPreauth => 9,
+            EapLowerLayer::Unknown(value) => value,
+        }
+    }
+}
+impl From<u32> for EapLowerLayer {
+    fn from(value: u32) -> Self {
+        EapLowerLayer::from_u32(value)
+    }
+}
+impl From<EapLowerLayer> for u32 {
+    fn from(value: EapLowerLayer) -> Self {
+        value.to_u32()
+    }
+}
+impl TryFrom<&str> for EapLowerLayer {
+    type Error = AVPError;
+    /// Returns the variant of a value name in the dictionary (e.g. `Wired-IEEE-802.1X`).
+    fn try_from(name: &str) -> Result<Self, AVPError> {
+        match name {
+            "Wired-IEEE-802.1X" => Ok(EapLowerLayer::WiredIEEE8021X),
+            "IEEE-802.1X-No-Preauth" => Ok(EapLowerLayer::Ieee8021XNoPreauth),
+            "IEEE-802.1X-Preauth" => Ok(EapLowerLayer::Ieee8021XPreauth),
+            "IEEE-802.16e" => Ok(EapLowerLayer::Ieee80216E),
+            "IKEv2" => Ok(EapLowerLayer::Ikev2),
+            "PPP" => Ok(EapLowerLayer::Ppp),
+            "PANA-No-Preauth" => Ok(EapLowerLayer::PanaNoPreauth),
+            "GSS-API" => Ok(EapLowerLayer::GssApi),
+            "PANA-Preauth" => Ok(EapLowerLayer::PanaPreauth),
+            _ => Err(AVPError::DecodingError(format!(
+                "unknown value name of `EAP-Lower-Layer`: {name}"
+            ))),
+        }
+    }
+}
+pub const EAP_LOWER_LAYER_WIRED_IEEE_802_1X: EapLowerLayer = EapLowerLayer::WiredIEEE8021X;
+pub const EAP_LOWER_LAYER_IEEE_802_1X_NO_PREAUTH: EapLowerLayer = EapLowerLayer::Ieee8021XNoPreauth;
+pub const EAP_LOWER_LAYER_IEEE_802_1X_PREAUTH: EapLowerLayer = EapLowerLayer::Ieee8021XPreauth;
+pub const EAP_LOWER_LAYER_IEEE_802_1_6E: EapLowerLayer = EapLowerLayer::Ieee80216E;
+pub const EAP_LOWER_LAYER_IK_EV_2: EapLowerLayer = EapLowerLayer::Ikev2;
+pub const EAP_LOWER_LAYER_PPP: EapLowerLayer = EapLowerLayer::Ppp;
+pub const EAP_LOWER_LAYER_PANA_NO_PREAUTH: EapLowerLayer = EapLowerLayer::PanaNoPreauth;
+pub const EAP_LOWER_LAYER_GSS_API: EapLowerLayer = EapLowerLayer::GssApi;
+pub const EAP_LOWER_LAYER_PANA_PREAUTH: EapLowerLayer = EapLowerLayer::PanaPreauth;
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "Wired-IEEE-802.1X",
+        value: 1,
+    },
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "IEEE-802.1X-No-Preauth",
+        value: 2,
+    },
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "IEEE-802.1X-Preauth",
+        value: 3,
+    },
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "IEEE-802.16e",
+        value: 4,
+    },
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "IKEv2",
+        value: 5,
+    },
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "PPP",
+        value: 6,
+    },
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "PANA-No-Preauth",
+        value: 7,
+    },
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "GSS-API",
+        value: 8,
+    },
+    ValueMetadata {
+        typ: EAP_LOWER_LAYER_TYPE,
+        name: "PANA-Preauth",
+        value: 9,
+    },
+];
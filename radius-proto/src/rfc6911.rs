@@ -22,14 +22,23 @@
 
 use std::net::Ipv6Addr;
 
-use crate::core::avp::{AVPError, AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const FRAMED_IPV6_ADDRESS_TYPE: AVPType = 168;
 /// Delete all of `framed_ipv6_address` values from a packet.
 pub fn delete_framed_ipv6_address(packet: &mut Packet) {
     packet.delete(FRAMED_IPV6_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `framed_ipv6_address` without decoding the value.
+pub fn has_framed_ipv6_address(packet: &Packet) -> bool {
+    packet.contains(FRAMED_IPV6_ADDRESS_TYPE)
+}
+/// Returns the number of `framed_ipv6_address` attributes in a packet.
+pub fn count_framed_ipv6_address(packet: &Packet) -> usize {
+    packet.count(FRAMED_IPV6_ADDRESS_TYPE)
+}
 /// Add `framed_ipv6_address` ipv6addr value to a packet.
 pub fn add_framed_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
     packet.add(AVP::from_ipv6(FRAMED_IPV6_ADDRESS_TYPE, value));
@@ -38,15 +47,19 @@ pub fn add_framed_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
 ///
 /// It returns the first looked up value. If there is no associated value with `framed_ipv6_address`, it returns `None`.
 pub fn lookup_framed_ipv6_address(packet: &Packet) -> Option<Result<Ipv6Addr, AVPError>> {
-    packet
-        .lookup(FRAMED_IPV6_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv6())
+    packet.lookup(FRAMED_IPV6_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv6()
+            .map_err(|e| e.with_attribute_name("Framed-IPV6-Address"))
+    })
 }
 /// Lookup all of the `framed_ipv6_address` ipv6addr value from a packet.
 pub fn lookup_all_framed_ipv6_address(packet: &Packet) -> Result<Vec<Ipv6Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(FRAMED_IPV6_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv6()?)
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("Framed-IPV6-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -56,6 +69,14 @@ pub const DNS_SERVER_IPV6_ADDRESS_TYPE: AVPType = 169;
 pub fn delete_dns_server_ipv6_address(packet: &mut Packet) {
     packet.delete(DNS_SERVER_IPV6_ADDRESS_TYPE);
 }
+/// Returns whether a packet has `dns_server_ipv6_address` without decoding the value.
+pub fn has_dns_server_ipv6_address(packet: &Packet) -> bool {
+    packet.contains(DNS_SERVER_IPV6_ADDRESS_TYPE)
+}
+/// Returns the number of `dns_server_ipv6_address` attributes in a packet.
+pub fn count_dns_server_ipv6_address(packet: &Packet) -> usize {
+    packet.count(DNS_SERVER_IPV6_ADDRESS_TYPE)
+}
 /// Add `dns_server_ipv6_address` ipv6addr value to a packet.
 pub fn add_dns_server_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
     packet.add(AVP::from_ipv6(DNS_SERVER_IPV6_ADDRESS_TYPE, value));
@@ -64,15 +85,19 @@ pub fn add_dns_server_ipv6_address(packet: &mut Packet, value: &Ipv6Addr) {
 ///
 /// It returns the first looked up value. If there is no associated value with `dns_server_ipv6_address`, it returns `None`.
 pub fn lookup_dns_server_ipv6_address(packet: &Packet) -> Option<Result<Ipv6Addr, AVPError>> {
-    packet
-        .lookup(DNS_SERVER_IPV6_ADDRESS_TYPE)
-        .map(|v| v.encode_ipv6())
+    packet.lookup(DNS_SERVER_IPV6_ADDRESS_TYPE).map(|v| {
+        v.decode_ipv6()
+            .map_err(|e| e.with_attribute_name("DNS-Server-IPV6-Address"))
+    })
 }
 /// Lookup all of the `dns_server_ipv6_address` ipv6addr value from a packet.
 pub fn lookup_all_dns_server_ipv6_address(packet: &Packet) -> Result<Vec<Ipv6Addr>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(DNS_SERVER_IPV6_ADDRESS_TYPE) {
-        vec.push(avp.encode_ipv6()?)
+        vec.push(
+            avp.decode_ipv6()
+                .map_err(|e| e.with_attribute_name("DNS-Server-IPV6-Address"))?,
+        )
     }
     Ok(vec)
 }
@@ -82,8 +107,20 @@ pub const ROUTE_IPV6_INFORMATION_TYPE: AVPType = 170;
 pub fn delete_route_ipv6_information(packet: &mut Packet) {
     packet.delete(ROUTE_IPV6_INFORMATION_TYPE);
 }
+/// Returns whether a packet has `route_ipv6_information` without decoding the value.
+pub fn has_route_ipv6_information(packet: &Packet) -> bool {
+    packet.contains(ROUTE_IPV6_INFORMATION_TYPE)
+}
+/// Returns the number of `route_ipv6_information` attributes in a packet.
+pub fn count_route_ipv6_information(packet: &Packet) -> usize {
+    packet.count(ROUTE_IPV6_INFORMATION_TYPE)
+}
 /// Add `route_ipv6_information` ipv6 prefix value to a packet.
-pub fn add_route_ipv6_information(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_route_ipv6_information(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     packet.add(AVP::from_ipv6_prefix(ROUTE_IPV6_INFORMATION_TYPE, value)?);
     Ok(())
 }
@@ -91,15 +128,19 @@ pub fn add_route_ipv6_information(packet: &mut Packet, value: &[u8]) -> Result<(
 ///
 /// It returns the first looked up value. If there is no associated value with `route_ipv6_information`, it returns `None`.
 pub fn lookup_route_ipv6_information(packet: &Packet) -> Option<Result<Vec<u8>, AVPError>> {
-    packet
-        .lookup(ROUTE_IPV6_INFORMATION_TYPE)
-        .map(|v| v.encode_ipv6_prefix())
+    packet.lookup(ROUTE_IPV6_INFORMATION_TYPE).map(|v| {
+        v.decode_ipv6_prefix()
+            .map_err(|e| e.with_attribute_name("Route-IPV6-Information"))
+    })
 }
 /// Lookup all of the `route_ipv6_information` ipv6 prefix value from a packet.
 pub fn lookup_all_route_ipv6_information(packet: &Packet) -> Result<Vec<Vec<u8>>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(ROUTE_IPV6_INFORMATION_TYPE) {
-        vec.push(avp.encode_ipv6_prefix()?)
+        vec.push(
+            avp.decode_ipv6_prefix()
+                .map_err(|e| e.with_attribute_name("Route-IPV6-Information"))?,
+        )
     }
     Ok(vec)
 }
@@ -109,23 +150,36 @@ pub const DELEGATED_IPV6_PREFIX_POOL_TYPE: AVPType = 171;
 pub fn delete_delegated_ipv6_prefix_pool(packet: &mut Packet) {
     packet.delete(DELEGATED_IPV6_PREFIX_POOL_TYPE);
 }
+/// Returns whether a packet has `delegated_ipv6_prefix_pool` without decoding the value.
+pub fn has_delegated_ipv6_prefix_pool(packet: &Packet) -> bool {
+    packet.contains(DELEGATED_IPV6_PREFIX_POOL_TYPE)
+}
+/// Returns the number of `delegated_ipv6_prefix_pool` attributes in a packet.
+pub fn count_delegated_ipv6_prefix_pool(packet: &Packet) -> usize {
+    packet.count(DELEGATED_IPV6_PREFIX_POOL_TYPE)
+}
 /// Add `delegated_ipv6_prefix_pool` string value to a packet.
-pub fn add_delegated_ipv6_prefix_pool(packet: &mut Packet, value: &str) {
+pub fn add_delegated_ipv6_prefix_pool(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
     packet.add(AVP::from_string(DELEGATED_IPV6_PREFIX_POOL_TYPE, value));
 }
 /// Lookup a `delegated_ipv6_prefix_pool` string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `delegated_ipv6_prefix_pool`, it returns `None`.
 pub fn lookup_delegated_ipv6_prefix_pool(packet: &Packet) -> Option<Result<String, AVPError>> {
-    packet
-        .lookup(DELEGATED_IPV6_PREFIX_POOL_TYPE)
-        .map(|v| v.encode_string())
+    packet.lookup(DELEGATED_IPV6_PREFIX_POOL_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Delegated-IPV6-Prefix-Pool"))
+    })
 }
 /// Lookup all of the `delegated_ipv6_prefix_pool` string value from a packet.
 pub fn lookup_all_delegated_ipv6_prefix_pool(packet: &Packet) -> Result<Vec<String>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(DELEGATED_IPV6_PREFIX_POOL_TYPE) {
-        vec.push(avp.encode_string()?)
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Delegated-IPV6-Prefix-Pool"))?,
+        )
     }
     Ok(vec)
 }
@@ -135,23 +189,88 @@ pub const STATEFUL_IPV6_ADDRESS_POOL_TYPE: AVPType = 172;
 pub fn delete_stateful_ipv6_address_pool(packet: &mut Packet) {
     packet.delete(STATEFUL_IPV6_ADDRESS_POOL_TYPE);
 }
+/// Returns whether a packet has `stateful_ipv6_address_pool` without decoding the value.
+pub fn has_stateful_ipv6_address_pool(packet: &Packet) -> bool {
+    packet.contains(STATEFUL_IPV6_ADDRESS_POOL_TYPE)
+}
+/// Returns the number of `stateful_ipv6_address_pool` attributes in a packet.
+pub fn count_stateful_ipv6_address_pool(packet: &Packet) -> usize {
+    packet.count(STATEFUL_IPV6_ADDRESS_POOL_TYPE)
+}
 /// Add `stateful_ipv6_address_pool` string value to a packet.
-pub fn add_stateful_ipv6_address_pool(packet: &mut Packet, value: &str) {
+pub fn add_stateful_ipv6_address_pool(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
     packet.add(AVP::from_string(STATEFUL_IPV6_ADDRESS_POOL_TYPE, value));
 }
 /// Lookup a `stateful_ipv6_address_pool` string value from a packet.
 ///
 /// It returns the first looked up value. If there is no associated value with `stateful_ipv6_address_pool`, it returns `None`.
 pub fn lookup_stateful_ipv6_address_pool(packet: &Packet) -> Option<Result<String, AVPError>> {
-    packet
-        .lookup(STATEFUL_IPV6_ADDRESS_POOL_TYPE)
-        .map(|v| v.encode_string())
+    packet.lookup(STATEFUL_IPV6_ADDRESS_POOL_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("Stateful-IPV6-Address-Pool"))
+    })
 }
 /// Lookup all of the `stateful_ipv6_address_pool` string value from a packet.
 pub fn lookup_all_stateful_ipv6_address_pool(packet: &Packet) -> Result<Vec<String>, AVPError> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(STATEFUL_IPV6_ADDRESS_POOL_TYPE) {
-        vec.push(avp.encode_string()?)
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("Stateful-IPV6-Address-Pool"))?,
+        )
     }
     Ok(vec)
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "Framed-IPV6-Address",
+        typ: FRAMED_IPV6_ADDRESS_TYPE,
+        data_type: AttributeDataType::Ipv6Addr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "DNS-Server-IPV6-Address",
+        typ: DNS_SERVER_IPV6_ADDRESS_TYPE,
+        data_type: AttributeDataType::Ipv6Addr,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(16),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Route-IPV6-Information",
+        typ: ROUTE_IPV6_INFORMATION_TYPE,
+        data_type: AttributeDataType::Ipv6Prefix,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(18),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Delegated-IPV6-Prefix-Pool",
+        typ: DELEGATED_IPV6_PREFIX_POOL_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "Stateful-IPV6-Address-Pool",
+        typ: STATEFUL_IPV6_ADDRESS_POOL_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
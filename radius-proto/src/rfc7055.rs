@@ -0,0 +1,223 @@
+// Code generated by machine generator; DO NOT EDIT.
+
+//! Utility for rfc7055 packet.
+//!
+//! This module handles the packet according to the following definition:
+//! ```text
+//! //! # -*- text -*-
+//! # Copyright (C) 2020 The FreeRADIUS Server project and contributors
+//! # This work is licensed under CC-BY version 4.0 https://creativecommons.org/licenses/by/4.0
+//! # Version $Id$
+//! #
+//! #        Attributes and values defined in RFC 7055
+//! #        http://www.ietf.org/rfc/rfc7055.txt
+//! #
+//!
+//! ATTRIBUTE    GSS-Acceptor-Service-Name        164    string
+//! ATTRIBUTE    GSS-Acceptor-Host-Name            165    string
+//! ATTRIBUTE    GSS-Acceptor-Service-Specifics        166    string
+//! ATTRIBUTE    GSS-Acceptor-Realm-Name            167    string
+//! ```
+
+use crate::avp::{AVPError, AVPType, AVP, MAX_VALUE_LENGTH};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
+
+pub const GSS_ACCEPTOR_SERVICE_NAME_TYPE: AVPType = 164;
+/// Delete all of `gss_acceptor_service_name` values from a packet.
+pub fn delete_gss_acceptor_service_name(packet: &mut Packet) {
+    packet.delete(GSS_ACCEPTOR_SERVICE_NAME_TYPE);
+}
+/// Returns whether a packet has `gss_acceptor_service_name` without decoding the value.
+pub fn has_gss_acceptor_service_name(packet: &Packet) -> bool {
+    packet.contains(GSS_ACCEPTOR_SERVICE_NAME_TYPE)
+}
+/// Returns the number of `gss_acceptor_service_name` attributes in a packet.
+pub fn count_gss_acceptor_service_name(packet: &Packet) -> usize {
+    packet.count(GSS_ACCEPTOR_SERVICE_NAME_TYPE)
+}
+/// Add `gss_acceptor_service_name` string value to a packet.
+pub fn add_gss_acceptor_service_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(GSS_ACCEPTOR_SERVICE_NAME_TYPE, value));
+}
+/// Lookup a `gss_acceptor_service_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `gss_acceptor_service_name`, it returns `None`.
+pub fn lookup_gss_acceptor_service_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(GSS_ACCEPTOR_SERVICE_NAME_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("GSS-Acceptor-Service-Name"))
+    })
+}
+/// Lookup all of the `gss_acceptor_service_name` string value from a packet.
+pub fn lookup_all_gss_acceptor_service_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(GSS_ACCEPTOR_SERVICE_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("GSS-Acceptor-Service-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const GSS_ACCEPTOR_HOST_NAME_TYPE: AVPType = 165;
+/// Delete all of `gss_acceptor_host_name` values from a packet.
+pub fn delete_gss_acceptor_host_name(packet: &mut Packet) {
+    packet.delete(GSS_ACCEPTOR_HOST_NAME_TYPE);
+}
+/// Returns whether a packet has `gss_acceptor_host_name` without decoding the value.
+pub fn has_gss_acceptor_host_name(packet: &Packet) -> bool {
+    packet.contains(GSS_ACCEPTOR_HOST_NAME_TYPE)
+}
+/// Returns the number of `gss_acceptor_host_name` attributes in a packet.
+pub fn count_gss_acceptor_host_name(packet: &Packet) -> usize {
+    packet.count(GSS_ACCEPTOR_HOST_NAME_TYPE)
+}
+/// Add `gss_acceptor_host_name` string value to a packet.
+pub fn add_gss_acceptor_host_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(GSS_ACCEPTOR_HOST_NAME_TYPE, value));
+}
+/// Lookup a `gss_acceptor_host_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `gss_acceptor_host_name`, it returns `None`.
+pub fn lookup_gss_acceptor_host_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(GSS_ACCEPTOR_HOST_NAME_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("GSS-Acceptor-Host-Name"))
+    })
+}
+/// Lookup all of the `gss_acceptor_host_name` string value from a packet.
+pub fn lookup_all_gss_acceptor_host_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(GSS_ACCEPTOR_HOST_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("GSS-Acceptor-Host-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE: AVPType = 166;
+/// Delete all of `gss_acceptor_service_specifics` values from a packet.
+pub fn delete_gss_acceptor_service_specifics(packet: &mut Packet) {
+    packet.delete(GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE);
+}
+/// Returns whether a packet has `gss_acceptor_service_specifics` without decoding the value.
+pub fn has_gss_acceptor_service_specifics(packet: &Packet) -> bool {
+    packet.contains(GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE)
+}
+/// Returns the number of `gss_acceptor_service_specifics` attributes in a packet.
+pub fn count_gss_acceptor_service_specifics(packet: &Packet) -> usize {
+    packet.count(GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE)
+}
+/// Add `gss_acceptor_service_specifics` string value to a packet.
+pub fn add_gss_acceptor_service_specifics(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE, value));
+}
+/// Lookup a `gss_acceptor_service_specifics` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `gss_acceptor_service_specifics`, it returns `None`.
+pub fn lookup_gss_acceptor_service_specifics(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("GSS-Acceptor-Service-Specifics"))
+    })
+}
+/// Lookup all of the `gss_acceptor_service_specifics` string value from a packet.
+pub fn lookup_all_gss_acceptor_service_specifics(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("GSS-Acceptor-Service-Specifics"))?,
+        )
+    }
+    Ok(vec)
+}
+
+pub const GSS_ACCEPTOR_REALM_NAME_TYPE: AVPType = 167;
+/// Delete all of `gss_acceptor_realm_name` values from a packet.
+pub fn delete_gss_acceptor_realm_name(packet: &mut Packet) {
+    packet.delete(GSS_ACCEPTOR_REALM_NAME_TYPE);
+}
+/// Returns whether a packet has `gss_acceptor_realm_name` without decoding the value.
+pub fn has_gss_acceptor_realm_name(packet: &Packet) -> bool {
+    packet.contains(GSS_ACCEPTOR_REALM_NAME_TYPE)
+}
+/// Returns the number of `gss_acceptor_realm_name` attributes in a packet.
+pub fn count_gss_acceptor_realm_name(packet: &Packet) -> usize {
+    packet.count(GSS_ACCEPTOR_REALM_NAME_TYPE)
+}
+/// Add `gss_acceptor_realm_name` string value to a packet.
+pub fn add_gss_acceptor_realm_name(packet: &mut Packet, value: impl AsRef<str>) {
+    let value = packet.truncate_string(value.as_ref(), MAX_VALUE_LENGTH);
+    packet.add(AVP::from_string(GSS_ACCEPTOR_REALM_NAME_TYPE, value));
+}
+/// Lookup a `gss_acceptor_realm_name` string value from a packet.
+///
+/// It returns the first looked up value. If there is no associated value with `gss_acceptor_realm_name`, it returns `None`.
+pub fn lookup_gss_acceptor_realm_name(packet: &Packet) -> Option<Result<String, AVPError>> {
+    packet.lookup(GSS_ACCEPTOR_REALM_NAME_TYPE).map(|v| {
+        v.decode_string()
+            .map_err(|e| e.with_attribute_name("GSS-Acceptor-Realm-Name"))
+    })
+}
+/// Lookup all of the `gss_acceptor_realm_name` string value from a packet.
+pub fn lookup_all_gss_acceptor_realm_name(packet: &Packet) -> Result<Vec<String>, AVPError> {
+    let mut vec = Vec::new();
+    for avp in packet.lookup_all(GSS_ACCEPTOR_REALM_NAME_TYPE) {
+        vec.push(
+            avp.decode_string()
+                .map_err(|e| e.with_attribute_name("GSS-Acceptor-Realm-Name"))?,
+        )
+    }
+    Ok(vec)
+}
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[
+    AttributeMetadata {
+        name: "GSS-Acceptor-Service-Name",
+        typ: GSS_ACCEPTOR_SERVICE_NAME_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "GSS-Acceptor-Host-Name",
+        typ: GSS_ACCEPTOR_HOST_NAME_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "GSS-Acceptor-Service-Specifics",
+        typ: GSS_ACCEPTOR_SERVICE_SPECIFICS_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+    AttributeMetadata {
+        name: "GSS-Acceptor-Realm-Name",
+        typ: GSS_ACCEPTOR_REALM_NAME_TYPE,
+        data_type: AttributeDataType::String,
+        encrypted: false,
+        has_tag: false,
+        max_length: Some(253),
+        max_occurrences: None,
+    },
+];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -19,23 +19,36 @@
 //! ATTRIBUTE    Originating-Line-Info            94    octets[2]
 //! ```
 
-use crate::core::avp::{AVPError, AVPType, AVP};
-use crate::core::packet::Packet;
+use crate::avp::{AVPError, AVPType, AVP};
+use crate::metadata::{AttributeDataType, AttributeMetadata, ValueMetadata};
+use crate::packet::Packet;
 
 pub const ORIGINATING_LINE_INFO_TYPE: AVPType = 94;
 /// Delete all of `originating_line_info` values from a packet.
 pub fn delete_originating_line_info(packet: &mut Packet) {
     packet.delete(ORIGINATING_LINE_INFO_TYPE);
 }
+/// Returns whether a packet has `originating_line_info` without decoding the value.
+pub fn has_originating_line_info(packet: &Packet) -> bool {
+    packet.contains(ORIGINATING_LINE_INFO_TYPE)
+}
+/// Returns the number of `originating_line_info` attributes in a packet.
+pub fn count_originating_line_info(packet: &Packet) -> usize {
+    packet.count(ORIGINATING_LINE_INFO_TYPE)
+}
 /// Add `originating_line_info` fixed-length octets value to a packet.
-pub fn add_originating_line_info(packet: &mut Packet, value: &[u8]) -> Result<(), AVPError> {
+pub fn add_originating_line_info(
+    packet: &mut Packet,
+    value: impl AsRef<[u8]>,
+) -> Result<(), AVPError> {
+    let value = value.as_ref();
     if value.len() != 2 {
         return Err(AVPError::InvalidAttributeLengthError(
             "2 bytes".to_owned(),
             value.len(),
         ));
     }
-    packet.add(AVP::from_bytes(ORIGINATING_LINE_INFO_TYPE, value));
+    packet.add(AVP::from_bytes(ORIGINATING_LINE_INFO_TYPE, value)?);
     Ok(())
 }
 /// Lookup a `originating_line_info` fixed-length octets value from a packet.
@@ -44,13 +57,27 @@ pub fn add_originating_line_info(packet: &mut Packet, value: &[u8]) -> Result<()
 pub fn lookup_originating_line_info(packet: &Packet) -> Option<Vec<u8>> {
     packet
         .lookup(ORIGINATING_LINE_INFO_TYPE)
-        .map(|v| v.encode_bytes())
+        .map(|v| v.decode_bytes())
 }
 /// Lookup all of the `originating_line_info` fixed-length octets value from a packet.
 pub fn lookup_all_originating_line_info(packet: &Packet) -> Vec<Vec<u8>> {
     let mut vec = Vec::new();
     for avp in packet.lookup_all(ORIGINATING_LINE_INFO_TYPE) {
-        vec.push(avp.encode_bytes())
+        vec.push(avp.decode_bytes())
     }
     vec
 }
+
+/// The metadata of the attributes that are defined in this module.
+pub const ATTRIBUTE_TABLE: &[AttributeMetadata] = &[AttributeMetadata {
+    name: "Originating-Line-Info",
+    typ: ORIGINATING_LINE_INFO_TYPE,
+    data_type: AttributeDataType::Octets,
+    encrypted: false,
+    has_tag: false,
+    max_length: Some(2),
+    max_occurrences: None,
+}];
+
+/// The named values of the attributes that are defined in this module.
+pub const VALUE_TABLE: &[ValueMetadata] = &[];
@@ -0,0 +1,103 @@
+pub(crate) const UNUSED_TAG_VALUE: u8 = 0x00;
+
+/// Tag represents a tag of a RADIUS value.
+/// see also: http://www.ietf.org/rfc/rfc2868.html
+#[derive(Debug, PartialEq)]
+pub struct Tag {
+    pub(crate) value: u8,
+}
+
+impl Tag {
+    pub fn new(value: u8) -> Self {
+        Tag { value }
+    }
+
+    pub fn new_unused() -> Self {
+        Tag {
+            value: UNUSED_TAG_VALUE,
+        }
+    }
+
+    pub fn get_value(&self) -> u8 {
+        self.value
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value == UNUSED_TAG_VALUE
+    }
+
+    pub fn is_valid_value(&self) -> bool {
+        1 <= self.value && self.value <= 0x1f
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tag::Tag;
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn test_is_zero() {
+        let tag = Tag { value: 0 };
+        assert_eq!(tag.is_zero(), true);
+        let tag = Tag { value: 1 };
+        assert_eq!(tag.is_zero(), false);
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn test_is_valid_value() {
+        let tag = Tag { value: 1 };
+        assert_eq!(tag.is_valid_value(), true);
+        let tag = Tag { value: 0 };
+        assert_eq!(tag.is_valid_value(), false);
+        let tag = Tag { value: 0x20 };
+        assert_eq!(tag.is_valid_value(), false);
+    }
+
+    #[cfg(feature = "rfc2868")]
+    #[test]
+    fn test_tunnel_group() {
+        use crate::code::Code;
+        use crate::packet::Packet;
+        use crate::rfc2868;
+
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        for (tag, tunnel_type, endpoint) in [
+            (1, rfc2868::TUNNEL_TYPE_L2TP, "192.0.2.1"),
+            (2, rfc2868::TUNNEL_TYPE_PPTP, "192.0.2.2"),
+        ] {
+            let tag = Tag::new(tag);
+            rfc2868::add_tunnel_type(&mut packet, Some(&tag), tunnel_type);
+            rfc2868::add_tunnel_medium_type(
+                &mut packet,
+                Some(&tag),
+                rfc2868::TUNNEL_MEDIUM_TYPE_I_PV_4,
+            );
+            rfc2868::add_tunnel_server_endpoint(&mut packet, Some(&tag), endpoint);
+        }
+        rfc2868::add_tunnel_password(&mut packet, Some(&Tag::new(2)), b"password").unwrap();
+        rfc2868::add_tunnel_private_group_id(&mut packet, None, "vlan-10");
+
+        let group = rfc2868::tunnel_group(&packet, &Tag::new(2)).unwrap();
+        assert_eq!(
+            group,
+            rfc2868::TunnelGroup {
+                tunnel_type: Some(rfc2868::TUNNEL_TYPE_PPTP),
+                tunnel_medium_type: Some(rfc2868::TUNNEL_MEDIUM_TYPE_I_PV_4),
+                tunnel_server_endpoint: Some("192.0.2.2".to_owned()),
+                tunnel_password: Some(b"password".to_vec()),
+                ..Default::default()
+            }
+        );
+
+        let group = rfc2868::tunnel_group(&packet, &Tag::new(1)).unwrap();
+        assert_eq!(group.tunnel_type, Some(rfc2868::TUNNEL_TYPE_L2TP));
+        assert_eq!(group.tunnel_password, None);
+
+        // an untagged value belongs to the zero tag
+        let group = rfc2868::tunnel_group(&packet, &Tag::new_unused()).unwrap();
+        assert_eq!(group.tunnel_private_group_id, Some("vlan-10".to_owned()));
+        assert_eq!(group.tunnel_type, None);
+    }
+}
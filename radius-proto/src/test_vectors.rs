@@ -0,0 +1,294 @@
+//! Interoperable test vectors of the RADIUS cryptographic operations,
+//! i.e. the authenticators, `User-Password`, `Tunnel-Password` and `Message-Authenticator`.
+//!
+//! The exchanges of RFC 2865 are the examples in section 7 of that RFC as they are.
+//! RFC 2866, RFC 2868 and RFC 3579 don't have worked examples, so those vectors are computed by the algorithms
+//! of the RFCs with an independent implementation (Python's `hashlib` and `hmac`) and the same shared secret.
+//!
+//! These are public so that an alternative implementation of the primitives (e.g. a swapped MD5 backend)
+//! can be verified against them; the tests of this module verify the implementation of this crate.
+
+/// The shared secret of all of the vectors; this is the one of the examples of RFC 2865.
+pub const SECRET: &[u8] = b"xyzzy5461";
+
+/// This struct represents a pair of a request and its response on the wire.
+#[derive(Debug, Clone, Copy)]
+pub struct ExchangeVector {
+    /// Where the vector comes from (e.g. `RFC 2865 section 7.1`).
+    pub source: &'static str,
+    pub request: &'static [u8],
+    pub response: &'static [u8],
+}
+
+/// This struct represents an encrypted `User-Password` value; see RFC 2865 section 5.2.
+#[derive(Debug, Clone, Copy)]
+pub struct UserPasswordVector {
+    pub source: &'static str,
+    pub request_authenticator: [u8; 16],
+    pub plain_text: &'static [u8],
+    /// The value of the attribute.
+    pub cipher_text: &'static [u8],
+}
+
+/// This struct represents an encrypted `Tunnel-Password` value; see RFC 2868 section 3.5.
+#[derive(Debug, Clone, Copy)]
+pub struct TunnelPasswordVector {
+    pub source: &'static str,
+    pub request_authenticator: [u8; 16],
+    pub tag: u8,
+    pub salt: [u8; 2],
+    pub plain_text: &'static [u8],
+    /// The value of the attribute, i.e. the tag, the salt and the encrypted string.
+    pub value: &'static [u8],
+}
+
+/// The Request Authenticator of the Access-Request of RFC 2865 section 7.1.
+pub const RFC2865_REQUEST_AUTHENTICATOR: [u8; 16] = [
+    0x0f, 0x40, 0x3f, 0x94, 0x73, 0x97, 0x80, 0x57, 0xbd, 0x83, 0xd5, 0xcb, 0x98, 0xf4, 0x22, 0x7a,
+];
+
+/// The exchanges of the Request Authenticator and the Response Authenticator (RFC 2865 section 3, RFC 2866 section 3).
+pub const EXCHANGE_VECTORS: &[ExchangeVector] = &[
+    // User-Name = "nemo", User-Password = "arctangent", NAS-IP-Address = 192.168.1.16, NAS-Port = 3
+    // => Service-Type = Login-User, Login-Service = Telnet, Login-IP-Host = 192.168.1.3
+    ExchangeVector {
+        source: "RFC 2865 section 7.1",
+        request: &[
+            0x01, 0x00, 0x00, 0x38, 0x0f, 0x40, 0x3f, 0x94, 0x73, 0x97, 0x80, 0x57, 0xbd, 0x83,
+            0xd5, 0xcb, 0x98, 0xf4, 0x22, 0x7a, 0x01, 0x06, 0x6e, 0x65, 0x6d, 0x6f, 0x02, 0x12,
+            0x0d, 0xbe, 0x70, 0x8d, 0x93, 0xd4, 0x13, 0xce, 0x31, 0x96, 0xe4, 0x3f, 0x78, 0x2a,
+            0x0a, 0xee, 0x04, 0x06, 0xc0, 0xa8, 0x01, 0x10, 0x05, 0x06, 0x00, 0x00, 0x00, 0x03,
+        ],
+        response: &[
+            0x02, 0x00, 0x00, 0x26, 0x86, 0xfe, 0x22, 0x0e, 0x76, 0x24, 0xba, 0x2a, 0x10, 0x05,
+            0xf6, 0xbf, 0x9b, 0x55, 0xe0, 0xb2, 0x06, 0x06, 0x00, 0x00, 0x00, 0x01, 0x0f, 0x06,
+            0x00, 0x00, 0x00, 0x00, 0x0e, 0x06, 0xc0, 0xa8, 0x01, 0x03,
+        ],
+    },
+    // User-Name = "flopsy", CHAP-Password, NAS-IP-Address = 192.168.1.16, NAS-Port = 20, Service-Type = Framed-User, Framed-Protocol = PPP
+    // => Service-Type = Framed-User, Framed-Protocol = PPP, Framed-IP-Address = 255.255.255.254, Framed-Routing = Listen,
+    //    Framed-Compression = Van-Jacobson-TCP-IP, Framed-MTU = 1500
+    ExchangeVector {
+        source: "RFC 2865 section 7.2",
+        request: &[
+            0x01, 0x01, 0x00, 0x47, 0x2a, 0xee, 0x86, 0xf0, 0x8d, 0x0d, 0x55, 0x96, 0x9c, 0xa5,
+            0x97, 0x8e, 0x0d, 0x33, 0x67, 0xa2, 0x01, 0x08, 0x66, 0x6c, 0x6f, 0x70, 0x73, 0x79,
+            0x03, 0x13, 0x16, 0xe9, 0x75, 0x57, 0xc3, 0x16, 0x18, 0x58, 0x95, 0xf2, 0x93, 0xff,
+            0x63, 0x44, 0x07, 0x72, 0x75, 0x04, 0x06, 0xc0, 0xa8, 0x01, 0x10, 0x05, 0x06, 0x00,
+            0x00, 0x00, 0x14, 0x06, 0x06, 0x00, 0x00, 0x00, 0x02, 0x07, 0x06, 0x00, 0x00, 0x00,
+            0x01,
+        ],
+        response: &[
+            0x02, 0x01, 0x00, 0x38, 0x15, 0xef, 0xbc, 0x7d, 0xab, 0x26, 0xcf, 0xa3, 0xdc, 0x34,
+            0xd9, 0xc0, 0x3c, 0x86, 0x01, 0xa4, 0x06, 0x06, 0x00, 0x00, 0x00, 0x02, 0x07, 0x06,
+            0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0xff, 0xff, 0xff, 0xfe, 0x0a, 0x06, 0x00, 0x00,
+            0x00, 0x02, 0x0d, 0x06, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x06, 0x00, 0x00, 0x05, 0xdc,
+        ],
+    },
+    // Acct-Status-Type = Start, User-Name = "nemo", Acct-Session-Id = "00000001", NAS-IP-Address = 192.168.1.16,
+    // NAS-Port = 3, Acct-Authentic = RADIUS => Accounting-Response
+    ExchangeVector {
+        source: "RFC 2866 section 3",
+        request: &[
+            0x04, 0x02, 0x00, 0x3c, 0xc4, 0xda, 0xfc, 0x3b, 0x8a, 0x9b, 0x31, 0x69, 0xe8, 0x7f,
+            0xd3, 0x07, 0xa2, 0xd8, 0x85, 0x15, 0x28, 0x06, 0x00, 0x00, 0x00, 0x01, 0x01, 0x06,
+            0x6e, 0x65, 0x6d, 0x6f, 0x2c, 0x0a, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x31,
+            0x04, 0x06, 0xc0, 0xa8, 0x01, 0x10, 0x05, 0x06, 0x00, 0x00, 0x00, 0x03, 0x2d, 0x06,
+            0x00, 0x00, 0x00, 0x01,
+        ],
+        response: &[
+            0x05, 0x02, 0x00, 0x14, 0x81, 0x26, 0x6e, 0x4b, 0x57, 0x69, 0x5f, 0x99, 0x05, 0xb3,
+            0x12, 0xdd, 0x47, 0x93, 0x29, 0x55,
+        ],
+    },
+];
+
+/// The exchanges that have `Message-Authenticator` (RFC 3579 section 3.2).
+pub const MESSAGE_AUTHENTICATOR_VECTORS: &[ExchangeVector] = &[
+    // User-Name = "nemo", NAS-IP-Address = 192.168.1.16, NAS-Port = 3, EAP-Message = EAP-Response/Identity
+    // => EAP-Message = EAP-Request/MD5-Challenge, State = "state-0001" in Access-Challenge
+    ExchangeVector {
+        source: "RFC 3579 section 3.2",
+        request: &[
+            0x01, 0x03, 0x00, 0x43, 0x2b, 0xa1, 0xd4, 0x8c, 0x1f, 0x6e, 0x39, 0x07, 0x55, 0xc2,
+            0x9a, 0xe0, 0x73, 0x4b, 0x16, 0xfd, 0x01, 0x06, 0x6e, 0x65, 0x6d, 0x6f, 0x04, 0x06,
+            0xc0, 0xa8, 0x01, 0x10, 0x05, 0x06, 0x00, 0x00, 0x00, 0x03, 0x4f, 0x0b, 0x02, 0x00,
+            0x00, 0x09, 0x01, 0x6e, 0x65, 0x6d, 0x6f, 0x50, 0x12, 0x01, 0xce, 0x98, 0xc6, 0x9c,
+            0xbc, 0xe1, 0x61, 0x0e, 0x78, 0xa5, 0xde, 0x57, 0x6c, 0xf3, 0x66,
+        ],
+        response: &[
+            0x0b, 0x03, 0x00, 0x4a, 0x1c, 0x43, 0xe4, 0xd2, 0xc8, 0xe8, 0x4d, 0x56, 0xe3, 0x25,
+            0xbe, 0xcb, 0xd2, 0x44, 0x4a, 0xa2, 0x4f, 0x18, 0x01, 0x01, 0x00, 0x16, 0x04, 0x10,
+            0x5f, 0x8a, 0x3e, 0x92, 0xc4, 0x07, 0x1b, 0x6d, 0x2e, 0xa9, 0x40, 0xf3, 0x88, 0x11,
+            0xd7, 0x6c, 0x18, 0x0c, 0x73, 0x74, 0x61, 0x74, 0x65, 0x2d, 0x30, 0x30, 0x30, 0x31,
+            0x50, 0x12, 0x34, 0x21, 0x9b, 0xc6, 0x8e, 0x57, 0x58, 0x89, 0x39, 0x98, 0x19, 0x61,
+            0xa1, 0x6a, 0xd4, 0x51,
+        ],
+    },
+];
+
+/// The `User-Password` values (RFC 2865 section 5.2).
+pub const USER_PASSWORD_VECTORS: &[UserPasswordVector] = &[
+    UserPasswordVector {
+        source: "RFC 2865 section 7.1",
+        request_authenticator: RFC2865_REQUEST_AUTHENTICATOR,
+        plain_text: b"arctangent",
+        cipher_text: &[
+            0x0d, 0xbe, 0x70, 0x8d, 0x93, 0xd4, 0x13, 0xce, 0x31, 0x96, 0xe4, 0x3f, 0x78, 0x2a,
+            0x0a, 0xee,
+        ],
+    },
+    UserPasswordVector {
+        source: "RFC 2865 section 5.2",
+        request_authenticator: RFC2865_REQUEST_AUTHENTICATOR,
+        plain_text: b"correct horse battery staple",
+        cipher_text: &[
+            0x0f, 0xa3, 0x61, 0x8b, 0x97, 0xd9, 0x00, 0x8b, 0x37, 0x8d, 0x96, 0x4c, 0x1d, 0x0a,
+            0x68, 0x8f, 0xf8, 0x1c, 0xf1, 0xb3, 0x3b, 0x8f, 0xeb, 0xbd, 0x4e, 0xf4, 0xb9, 0x36,
+            0x20, 0xa8, 0x6e, 0x24,
+        ],
+    },
+];
+
+/// The `Tunnel-Password` values (RFC 2868 section 3.5).
+pub const TUNNEL_PASSWORD_VECTORS: &[TunnelPasswordVector] = &[
+    TunnelPasswordVector {
+        source: "RFC 2868 section 3.5",
+        request_authenticator: RFC2865_REQUEST_AUTHENTICATOR,
+        tag: 1,
+        salt: [0x85, 0x9a],
+        plain_text: b"tunnel-secret",
+        value: &[
+            0x01, 0x85, 0x9a, 0xc8, 0x3b, 0x59, 0x4e, 0x73, 0x3c, 0x8a, 0x89, 0x59, 0x7e, 0xbe,
+            0x24, 0x9c, 0x89, 0xea, 0x62,
+        ],
+    },
+    TunnelPasswordVector {
+        source: "RFC 2868 section 3.5",
+        request_authenticator: RFC2865_REQUEST_AUTHENTICATOR,
+        tag: 2,
+        salt: [0xc3, 0x17],
+        plain_text: b"a tunnel password over 16 bytes",
+        value: &[
+            0x02, 0xc3, 0x17, 0xc3, 0x8c, 0x81, 0x7f, 0xbf, 0xb5, 0xde, 0xa5, 0x3a, 0x0a, 0x3b,
+            0x9d, 0x83, 0x3a, 0x63, 0xc8, 0xfd, 0x7f, 0xa4, 0x69, 0x26, 0xec, 0x84, 0xb7, 0xe7,
+            0x9e, 0x09, 0x11, 0x16, 0x91, 0x54, 0xfb,
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::avp::AVP;
+    use crate::packet::Packet;
+    use crate::rfc2865;
+    use crate::tag::Tag;
+    use crate::test_vectors::{
+        EXCHANGE_VECTORS, MESSAGE_AUTHENTICATOR_VECTORS, SECRET, TUNNEL_PASSWORD_VECTORS,
+        USER_PASSWORD_VECTORS,
+    };
+
+    /// Verify the authenticators, and that the packets are encoded into the same bytes.
+    fn verify_exchange(request: &[u8], response: &[u8], source: &str) {
+        assert!(Packet::is_authentic_request(request, SECRET), "{source}");
+        assert!(
+            Packet::is_authentic_response(response, request, SECRET),
+            "{source}"
+        );
+
+        let request_packet = Packet::decode(request, SECRET).unwrap();
+        assert_eq!(request_packet.encode().unwrap(), request, "{source}");
+
+        let decoded_response = Packet::decode(response, SECRET).unwrap();
+        let mut response_packet = request_packet.make_response_packet(decoded_response.get_code());
+        response_packet.extend(decoded_response.get_avps().to_vec());
+        assert_eq!(response_packet.encode().unwrap(), response, "{source}");
+    }
+
+    #[test]
+    fn test_exchange_vectors() {
+        for vector in EXCHANGE_VECTORS {
+            verify_exchange(vector.request, vector.response, vector.source);
+        }
+
+        // the values of RFC 2865 section 7.1
+        let request = Packet::decode(EXCHANGE_VECTORS[0].request, SECRET).unwrap();
+        assert_eq!(
+            rfc2865::lookup_user_name(&request).unwrap().unwrap(),
+            "nemo"
+        );
+        assert_eq!(
+            rfc2865::lookup_user_password(&request).unwrap().unwrap(),
+            b"arctangent"
+        );
+    }
+
+    #[test]
+    fn test_message_authenticator_vectors() {
+        for vector in MESSAGE_AUTHENTICATOR_VECTORS {
+            verify_exchange(vector.request, vector.response, vector.source);
+
+            let request = Packet::decode(vector.request, SECRET).unwrap();
+            assert!(
+                request.is_authentic_message_authenticator(None),
+                "{}",
+                vector.source
+            );
+            let response = Packet::decode(vector.response, SECRET).unwrap();
+            assert!(
+                response.is_authentic_message_authenticator(Some(request.get_authenticator())),
+                "{}",
+                vector.source
+            );
+        }
+    }
+
+    #[test]
+    fn test_user_password_vectors() {
+        for vector in USER_PASSWORD_VECTORS {
+            let avp = AVP::from_user_password(
+                rfc2865::USER_PASSWORD_TYPE,
+                vector.plain_text,
+                SECRET,
+                &vector.request_authenticator,
+            )
+            .unwrap();
+            assert_eq!(avp.decode_bytes(), vector.cipher_text, "{}", vector.source);
+
+            let avp = AVP::from_bytes(rfc2865::USER_PASSWORD_TYPE, vector.cipher_text).unwrap();
+            assert_eq!(
+                avp.decode_user_password(SECRET, &vector.request_authenticator)
+                    .unwrap(),
+                vector.plain_text,
+                "{}",
+                vector.source
+            );
+        }
+    }
+
+    #[test]
+    fn test_tunnel_password_vectors() {
+        // the same as `rfc2868::TUNNEL_PASSWORD_TYPE`; that module is optional
+        const TUNNEL_PASSWORD_TYPE: u8 = 69;
+
+        for vector in TUNNEL_PASSWORD_VECTORS {
+            let avp = AVP::from_tunnel_password_with_salt(
+                TUNNEL_PASSWORD_TYPE,
+                Some(&Tag::new(vector.tag)),
+                vector.plain_text,
+                SECRET,
+                &vector.request_authenticator,
+                vector.salt,
+            )
+            .unwrap();
+            assert_eq!(avp.decode_bytes(), vector.value, "{}", vector.source);
+
+            let avp = AVP::from_bytes(TUNNEL_PASSWORD_TYPE, vector.value).unwrap();
+            let (plain_text, tag) = avp
+                .decode_tunnel_password(SECRET, &vector.request_authenticator)
+                .unwrap();
+            assert_eq!(plain_text, vector.plain_text, "{}", vector.source);
+            assert_eq!(tag, Tag::new(vector.tag), "{}", vector.source);
+        }
+    }
+}
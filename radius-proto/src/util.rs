@@ -0,0 +1,35 @@
+//! Utilities for the attribute values.
+
+/// Truncates a string to at most `max_bytes` bytes without splitting a UTF-8 character,
+/// so that the truncated string is still valid.
+///
+/// e.g. `truncate_utf8("あいう", 7)` returns `"あい"` because "あ" and "い" are 3 bytes each.
+pub fn truncate_utf8(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut end = max_bytes;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::truncate_utf8;
+
+    #[test]
+    fn test_truncate_utf8() {
+        assert_eq!(truncate_utf8("alice", 10), "alice");
+        assert_eq!(truncate_utf8("alice", 5), "alice");
+        assert_eq!(truncate_utf8("alice", 3), "ali");
+        assert_eq!(truncate_utf8("alice", 0), "");
+        assert_eq!(truncate_utf8("", 0), "");
+        // "あ" and "い" are 3 bytes, and "🙂" is 4 bytes
+        assert_eq!(truncate_utf8("あいう", 7), "あい");
+        assert_eq!(truncate_utf8("あいう", 6), "あい");
+        assert_eq!(truncate_utf8("あいう", 2), "");
+        assert_eq!(truncate_utf8("a🙂", 4), "a");
+    }
+}
@@ -0,0 +1,365 @@
+//! Vendor-Specific attributes (VSA), i.e. the sub-attributes in `Vendor-Specific` attribute; see RFC 2865 section 5.26.
+//!
+//! The most of the vendors follow the format that is suggested by RFC 2865 (i.e. 1-octet type and 1-octet length),
+//! but some of those have the wider type field and/or the other length field (e.g. `format=2,1` of Lucent, `format=4,0` of USR,
+//! `format=2,2` of Starent), or the continuation octet to split a long value into the fragments (`format=1,1,c` of WiMAX).
+//! `VendorFormat` represents that, and the VSA accessors of `Packet` (e.g. `Packet::add_vsa()`) honor that.
+
+use crate::avp::{AVPError, MAX_VALUE_LENGTH};
+
+pub use crate::rfc2865::VENDOR_SPECIFIC_TYPE;
+
+/// The type of a vendor-specific attribute; this is wider than `AVPType` since some vendors have 2-octet or 4-octet types.
+pub type VendorType = u32;
+
+const VENDOR_ID_LENGTH: usize = 4;
+const CONTINUATION_FLAGS_LENGTH: usize = 1;
+const MORE_FRAGMENTS_FLAG: u8 = 0x80;
+
+/// This struct represents the widths of the type field and the length field of the vendor-specific attributes,
+/// i.e. `format=t,l[,c]` option of `VENDOR` line in a dictionary.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VendorFormat {
+    type_length: usize,
+    length_length: usize,
+    continuation: bool,
+}
+
+impl VendorFormat {
+    /// The format that is suggested by RFC 2865, i.e. `format=1,1`.
+    pub const DEFAULT: VendorFormat = VendorFormat::new(1, 1);
+
+    /// Make a format from the width of the type field (1, 2 or 4 octets) and the length field (0, 1 or 2 octets).
+    ///
+    /// This panics for the other widths; since this is a `const fn`, that is a compile error in a const context.
+    pub const fn new(type_length: usize, length_length: usize) -> Self {
+        assert!(
+            matches!(type_length, 1 | 2 | 4),
+            "the type field of a vendor format must be 1, 2 or 4 octets"
+        );
+        assert!(
+            length_length <= 2,
+            "the length field of a vendor format must be 0, 1 or 2 octets"
+        );
+        VendorFormat {
+            type_length,
+            length_length,
+            continuation: false,
+        }
+    }
+
+    /// Make a format that has the continuation octet after the length field (i.e. `format=1,1,c` of WiMAX);
+    /// a value that doesn't fit in a `Vendor-Specific` attribute is split into the fragments that are flagged by that.
+    ///
+    /// Only `format=1,1` can have the continuation octet; this panics for the other widths as well as `new()`.
+    pub const fn new_with_continuation(type_length: usize, length_length: usize) -> Self {
+        assert!(
+            type_length == 1 && length_length == 1,
+            "only the vendor format of 1-octet type and 1-octet length can have the continuation octet"
+        );
+        VendorFormat {
+            type_length,
+            length_length,
+            continuation: true,
+        }
+    }
+
+    /// Returns the width of the type field in octets.
+    pub fn get_type_length(&self) -> usize {
+        self.type_length
+    }
+
+    /// Returns the width of the length field in octets; zero means that a VSA has only a sub-attribute.
+    pub fn get_length_length(&self) -> usize {
+        self.length_length
+    }
+
+    /// Returns whether the sub-attributes have the continuation octet.
+    pub fn has_continuation(&self) -> bool {
+        self.continuation
+    }
+
+    fn header_length(&self) -> usize {
+        self.type_length
+            + self.length_length
+            + if self.continuation {
+                CONTINUATION_FLAGS_LENGTH
+            } else {
+                0
+            }
+    }
+
+    /// Returns the maximum length of the value of a sub-attribute that fits in a `Vendor-Specific` attribute.
+    pub(crate) fn max_value_length(&self) -> usize {
+        MAX_VALUE_LENGTH - VENDOR_ID_LENGTH - self.header_length()
+    }
+
+    fn max_vendor_type(&self) -> VendorType {
+        match self.type_length {
+            4 => VendorType::MAX,
+            n => (1 << (8 * n)) - 1,
+        }
+    }
+}
+
+impl Default for VendorFormat {
+    fn default() -> Self {
+        VendorFormat::DEFAULT
+    }
+}
+
+/// This struct represents a sub-attribute in a `Vendor-Specific` attribute.
+#[derive(Debug, PartialEq)]
+pub(crate) struct SubAttribute<'a> {
+    pub(crate) typ: VendorType,
+    /// Whether the value continues to the next sub-attribute of the same type (i.e. the continuation octet has the M flag).
+    pub(crate) more: bool,
+    pub(crate) value: &'a [u8],
+}
+
+/// This struct represents a vendor, i.e. `VENDOR` line in a dictionary.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Vendor {
+    id: u32,
+    format: VendorFormat,
+}
+
+impl Vendor {
+    /// Make a vendor from the SMI Network Management Private Enterprise Code and the format of the VSAs.
+    pub const fn new(id: u32, format: VendorFormat) -> Self {
+        Vendor { id, format }
+    }
+
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn get_format(&self) -> VendorFormat {
+        self.format
+    }
+
+    /// Returns the maximum length of the value of a sub-attribute; that is unlimited (i.e. `usize::MAX`)
+    /// if the format has the continuation octet, since such a value is split into the fragments.
+    pub fn max_value_length(&self) -> usize {
+        if self.format.continuation {
+            usize::MAX
+        } else {
+            self.format.max_value_length()
+        }
+    }
+
+    /// Encode a sub-attribute into the values of `Vendor-Specific` attributes; that is split into the fragments
+    /// if the format has the continuation octet and the value doesn't fit in an attribute, otherwise that is a single one.
+    ///
+    /// The vendor type is truncated to the width of the type field; see `encode_checked()` to reject such a type.
+    pub(crate) fn encode(&self, vendor_type: VendorType, value: &[u8]) -> Vec<Vec<u8>> {
+        if !self.format.continuation || value.len() <= self.format.max_value_length() {
+            return vec![self.encode_fragment(vendor_type, false, value)];
+        }
+
+        let fragments = value
+            .chunks(self.format.max_value_length())
+            .collect::<Vec<&[u8]>>();
+        fragments
+            .iter()
+            .enumerate()
+            .map(|(i, fragment)| {
+                self.encode_fragment(vendor_type, i + 1 < fragments.len(), fragment)
+            })
+            .collect()
+    }
+
+    /// Encode a sub-attribute like `encode()`, but this returns an error if the vendor type doesn't fit in the type field,
+    /// or the sub-attribute doesn't fit in a `Vendor-Specific` attribute and the format has no continuation octet.
+    pub(crate) fn encode_checked(
+        &self,
+        vendor_type: VendorType,
+        value: &[u8],
+    ) -> Result<Vec<Vec<u8>>, AVPError> {
+        if vendor_type > self.format.max_vendor_type() {
+            return Err(AVPError::InvalidVendorTypeError(vendor_type));
+        }
+        let max_value_length = self.format.max_value_length();
+        if !self.format.continuation && value.len() > max_value_length {
+            return Err(AVPError::InvalidAttributeLengthError(
+                format!("<= {max_value_length} bytes"),
+                value.len(),
+            ));
+        }
+        Ok(self.encode(vendor_type, value))
+    }
+
+    fn encode_fragment(&self, vendor_type: VendorType, more: bool, value: &[u8]) -> Vec<u8> {
+        let mut bs = self.id.to_be_bytes().to_vec();
+        bs.extend(self.encode_sub_attribute(&SubAttribute {
+            typ: vendor_type,
+            more,
+            value,
+        }));
+        bs
+    }
+
+    /// Encode a sub-attribute without the vendor ID.
+    pub(crate) fn encode_sub_attribute(&self, sub_attribute: &SubAttribute) -> Vec<u8> {
+        let format = &self.format;
+        let length = format.header_length() + sub_attribute.value.len();
+        let mut bs = Vec::with_capacity(length);
+        bs.extend_from_slice(&sub_attribute.typ.to_be_bytes()[4 - format.type_length..]);
+        bs.extend_from_slice(&(length as u16).to_be_bytes()[2 - format.length_length..]);
+        if format.continuation {
+            bs.push(if sub_attribute.more {
+                MORE_FRAGMENTS_FLAG
+            } else {
+                0
+            });
+        }
+        bs.extend_from_slice(sub_attribute.value);
+        bs
+    }
+
+    /// Decode the value of `Vendor-Specific` attribute into the sub-attributes.
+    ///
+    /// It returns `None` if the value is of the other vendor or that is malformed.
+    pub(crate) fn decode<'a>(&self, vsa: &'a [u8]) -> Option<Vec<SubAttribute<'a>>> {
+        if vsa.len() < VENDOR_ID_LENGTH || vsa[..VENDOR_ID_LENGTH] != self.id.to_be_bytes() {
+            return None;
+        }
+
+        let format = &self.format;
+        let mut sub_attributes = Vec::new();
+        let mut rest = &vsa[VENDOR_ID_LENGTH..];
+        while !rest.is_empty() {
+            if rest.len() < format.header_length() {
+                return None;
+            }
+            let to_number = |bs: &[u8]| bs.iter().fold(0, |acc, b| (acc << 8) | *b as u32);
+            let vendor_type = to_number(&rest[..format.type_length]);
+            let length = match format.length_length {
+                0 => rest.len(),
+                n => to_number(&rest[format.type_length..format.type_length + n]) as usize,
+            };
+            if length < format.header_length() || length > rest.len() {
+                return None;
+            }
+            sub_attributes.push(SubAttribute {
+                typ: vendor_type,
+                more: format.continuation
+                    && rest[format.type_length + format.length_length] & MORE_FRAGMENTS_FLAG != 0,
+                value: &rest[format.header_length()..length],
+            });
+            rest = &rest[length..];
+        }
+        Some(sub_attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::avp::AVPError;
+    use crate::vsa::{SubAttribute, Vendor, VendorFormat};
+
+    fn sub_attribute(typ: u32, more: bool, value: &[u8]) -> SubAttribute<'_> {
+        SubAttribute { typ, more, value }
+    }
+
+    #[test]
+    fn test_encode_and_decode() {
+        let testcases = [
+            (VendorFormat::DEFAULT, vec![0x01, 0x05, b'a', b'b', b'c']),
+            (
+                VendorFormat::new(2, 1),
+                vec![0x00, 0x01, 0x06, b'a', b'b', b'c'],
+            ),
+            (
+                VendorFormat::new(4, 0),
+                vec![0x00, 0x00, 0x00, 0x01, b'a', b'b', b'c'],
+            ),
+            (
+                VendorFormat::new(2, 2),
+                vec![0x00, 0x01, 0x00, 0x07, b'a', b'b', b'c'],
+            ),
+            (
+                VendorFormat::new_with_continuation(1, 1),
+                vec![0x01, 0x06, 0x00, b'a', b'b', b'c'],
+            ),
+        ];
+        for (format, encoded_sub_attribute) in testcases {
+            let vendor = Vendor::new(4874, format);
+            let encoded = vendor.encode(1, b"abc");
+            assert_eq!(encoded.len(), 1);
+            assert_eq!(encoded[0][..4], [0x00, 0x00, 0x13, 0x0a]);
+            assert_eq!(encoded[0][4..], encoded_sub_attribute);
+            assert_eq!(
+                vendor.decode(&encoded[0]),
+                Some(vec![sub_attribute(1, false, b"abc")])
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_fragments() {
+        let vendor = Vendor::new(24757, VendorFormat::new_with_continuation(1, 1));
+        let value = (0..=255).collect::<Vec<u8>>();
+        let encoded = vendor.encode(1, &value);
+        assert_eq!(encoded.len(), 2);
+        assert_eq!(encoded[0].len(), 253);
+        assert_eq!(encoded[0][4..7], [0x01, 249, 0x80]);
+        assert_eq!(encoded[1][4..7], [0x01, 3 + 10, 0x00]);
+        assert_eq!(
+            vendor.decode(&encoded[0]),
+            Some(vec![sub_attribute(1, true, &value[..246])])
+        );
+        assert_eq!(
+            vendor.decode(&encoded[1]),
+            Some(vec![sub_attribute(1, false, &value[246..])])
+        );
+
+        // the format without the continuation octet doesn't split the value
+        assert_eq!(
+            Vendor::new(9, VendorFormat::DEFAULT)
+                .encode(1, &value)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_decode_multiple_sub_attributes() {
+        let vendor = Vendor::new(9, VendorFormat::DEFAULT);
+        let vsa = [0x00, 0x00, 0x00, 0x09, 0x01, 0x03, b'a', 0x02, 0x02];
+        assert_eq!(
+            vendor.decode(&vsa),
+            Some(vec![
+                sub_attribute(1, false, b"a"),
+                sub_attribute(2, false, b"")
+            ])
+        );
+
+        // other vendor
+        assert_eq!(Vendor::new(311, VendorFormat::DEFAULT).decode(&vsa), None);
+        // the length of the sub-attribute exceeds the VSA
+        assert_eq!(vendor.decode(&vsa[..8]), None);
+        // the length of the sub-attribute is shorter than the header
+        assert_eq!(vendor.decode(&[0x00, 0x00, 0x00, 0x09, 0x01, 0x01]), None);
+    }
+
+    #[test]
+    fn test_encode_checked() {
+        let vendor = Vendor::new(529, VendorFormat::new(2, 1));
+        assert!(vendor.encode_checked(0xffff, b"abc").is_ok());
+        assert_eq!(
+            vendor.encode_checked(0x10000, b"abc"),
+            Err(AVPError::InvalidVendorTypeError(0x10000))
+        );
+        assert_eq!(
+            vendor.encode_checked(1, &[0x00; 247]),
+            Err(AVPError::InvalidAttributeLengthError(
+                "<= 246 bytes".to_owned(),
+                247
+            ))
+        );
+
+        let vendor = Vendor::new(24757, VendorFormat::new_with_continuation(1, 1));
+        assert_eq!(vendor.encode_checked(1, &[0x00; 300]).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,402 @@
+//! Python bindings of the radius crate, i.e. the `radius_rs` module that provides `Packet`, `AVP` and `Client`.
+//!
+//! The attributes can be accessed by name like pyrad (e.g. `packet["User-Name"] = "alice"`),
+//! according to the dictionary of all of the dictionary modules; the encrypted values (e.g. `User-Password`)
+//! are encrypted and decrypted transparently.
+//!
+//! ```python
+//! import radius_rs
+//!
+//! client = radius_rs.Client(timeout=5.0, retries=3)
+//! request = radius_rs.Packet(radius_rs.ACCESS_REQUEST, b"secret")
+//! request["User-Name"] = "alice"
+//! request["User-Password"] = "password"
+//! response = client.send(request, "127.0.0.1:1812")
+//! assert response.code == radius_rs.ACCESS_ACCEPT
+//! ```
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use radius::client::Client;
+use radius::core::avp::{AVPType, AVP};
+use radius::core::code::Code;
+use radius::core::dictionary::Dictionary;
+use radius::core::packet::Packet;
+use radius::retry::ExponentialBackoffRetryPolicy;
+use tokio::runtime::Runtime;
+
+create_exception!(
+    radius_rs,
+    RadiusError,
+    PyException,
+    "Raised when a packet or an attribute can't be encoded/decoded, or a request fails."
+);
+
+fn to_py_err<E: ToString>(e: E) -> PyErr {
+    RadiusError::new_err(e.to_string())
+}
+
+fn dictionary() -> &'static Dictionary {
+    static DICTIONARY: OnceLock<Dictionary> = OnceLock::new();
+    DICTIONARY.get_or_init(Dictionary::builtin)
+}
+
+/// An attribute-value pair; the value is the raw bytes on the wire.
+#[pyclass(name = "AVP", module = "radius_rs")]
+#[derive(Clone)]
+struct PyAVP {
+    avp: AVP,
+}
+
+#[pymethods]
+impl PyAVP {
+    #[new]
+    fn new(typ: AVPType, value: &[u8]) -> PyResult<Self> {
+        let avp = AVP::from_raw(typ, value).map_err(to_py_err)?;
+        Ok(PyAVP { avp })
+    }
+
+    #[getter(r#type)]
+    fn get_type(&self) -> AVPType {
+        self.avp.type_code()
+    }
+
+    #[getter]
+    fn get_value<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self.avp.raw_value())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.avp)
+    }
+}
+
+/// A request or response packet.
+#[pyclass(name = "Packet", module = "radius_rs")]
+#[derive(Clone)]
+struct PyPacket {
+    packet: Packet,
+}
+
+#[pymethods]
+impl PyPacket {
+    /// Make a packet of the code with a random identifier and authenticator.
+    #[new]
+    fn new(code: u8, secret: &[u8]) -> PyResult<Self> {
+        match Code::from(code) {
+            Code::Invalid => Err(PyValueError::new_err(format!("invalid code: {code}"))),
+            code => Ok(PyPacket {
+                packet: Packet::new(code, secret),
+            }),
+        }
+    }
+
+    #[staticmethod]
+    fn decode(data: &[u8], secret: &[u8]) -> PyResult<Self> {
+        let packet = Packet::decode(data, secret).map_err(to_py_err)?;
+        Ok(PyPacket { packet })
+    }
+
+    fn encode<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bs = self.packet.encode().map_err(to_py_err)?;
+        Ok(PyBytes::new(py, &bs))
+    }
+
+    /// Make a response packet of the code to this request (e.g. pyrad's `CreateReply()`).
+    fn create_reply(&self, code: u8) -> PyResult<Self> {
+        match Code::from(code) {
+            Code::Invalid => Err(PyValueError::new_err(format!("invalid code: {code}"))),
+            code => Ok(PyPacket {
+                packet: self.packet.make_response_packet(code),
+            }),
+        }
+    }
+
+    #[getter]
+    fn get_code(&self) -> u8 {
+        self.packet.get_code() as u8
+    }
+
+    #[getter]
+    fn get_identifier(&self) -> u8 {
+        self.packet.get_identifier()
+    }
+
+    #[setter]
+    fn set_identifier(&mut self, identifier: u8) {
+        self.packet.set_identifier(identifier);
+    }
+
+    #[getter]
+    fn get_authenticator<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self.packet.get_authenticator())
+    }
+
+    /// Add an attribute of the type with the raw value.
+    fn add(&mut self, typ: AVPType, value: &[u8]) -> PyResult<()> {
+        let avp = AVP::from_raw(typ, value).map_err(to_py_err)?;
+        self.packet.add(avp);
+        Ok(())
+    }
+
+    /// Returns the raw value of the first attribute of the type, or `None`.
+    fn get<'py>(&self, py: Python<'py>, typ: AVPType) -> Option<Bound<'py, PyBytes>> {
+        self.packet
+            .lookup(typ)
+            .map(|avp| PyBytes::new(py, avp.raw_value()))
+    }
+
+    /// Returns the raw values of all of the attributes of the type.
+    fn get_all<'py>(&self, py: Python<'py>, typ: AVPType) -> Vec<Bound<'py, PyBytes>> {
+        self.packet
+            .lookup_all(typ)
+            .into_iter()
+            .map(|avp| PyBytes::new(py, avp.raw_value()))
+            .collect()
+    }
+
+    fn delete(&mut self, typ: AVPType) {
+        self.packet.delete(typ);
+    }
+
+    fn avps(&self) -> Vec<PyAVP> {
+        self.packet
+            .get_avps()
+            .iter()
+            .map(|avp| PyAVP { avp: avp.clone() })
+            .collect()
+    }
+
+    /// Returns the textual values of the attributes of the name; it raises `KeyError` if there is no such attribute.
+    fn __getitem__(&self, name: &str) -> PyResult<Vec<String>> {
+        let values = self
+            .packet
+            .lookup_all_by_name(dictionary(), name)
+            .map_err(to_py_err)?;
+        if values.is_empty() {
+            return Err(PyKeyError::new_err(name.to_owned()));
+        }
+        Ok(values)
+    }
+
+    /// Add an attribute of the name with the textual value (e.g. `packet["Service-Type"] = "Framed-User"`).
+    fn __setitem__(&mut self, name: &str, value: &str) -> PyResult<()> {
+        self.packet
+            .add_by_name(dictionary(), name, value)
+            .map_err(to_py_err)
+    }
+
+    fn __delitem__(&mut self, name: &str) -> PyResult<()> {
+        self.packet
+            .delete_by_name(dictionary(), name)
+            .map_err(to_py_err)
+    }
+
+    fn __contains__(&self, name: &str) -> bool {
+        dictionary()
+            .lookup_attribute(name)
+            .is_some_and(|attribute| self.packet.contains(attribute.get_type()))
+    }
+
+    fn __len__(&self) -> usize {
+        self.packet.get_avps().len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}", self.packet)
+    }
+}
+
+/// A blocking client; each request is retransmitted up to `retries` times with the `timeout` seconds for each.
+#[pyclass(name = "Client", module = "radius_rs")]
+struct PyClient {
+    client: Client,
+    retry_policy: ExponentialBackoffRetryPolicy,
+    runtime: Runtime,
+}
+
+#[pymethods]
+impl PyClient {
+    #[new]
+    #[pyo3(signature = (timeout = 5.0, retries = 3))]
+    fn new(timeout: f64, retries: usize) -> PyResult<Self> {
+        let timeout = Duration::try_from_secs_f64(timeout)
+            .map_err(|e| PyValueError::new_err(format!("invalid timeout: {e}")))?;
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(to_py_err)?;
+        Ok(PyClient {
+            client: Client::new(Some(timeout), Some(timeout)),
+            retry_policy: ExponentialBackoffRetryPolicy {
+                initial_timeout: timeout,
+                multiplier: 1.0,
+                max_timeout: timeout,
+                max_attempts: retries.max(1),
+                max_duration: None,
+                jitter: 0.0,
+            },
+            runtime,
+        })
+    }
+
+    /// Send the packet to the host (e.g. `radius.example.com:1812`) and returns the response;
+    /// the GIL is released while waiting for that.
+    fn send(&self, py: Python<'_>, packet: &PyPacket, host: &str) -> PyResult<PyPacket> {
+        let response = py.allow_threads(|| {
+            self.runtime
+                .block_on(self.client.send_packet_to_host_with_retry(
+                    host,
+                    &packet.packet,
+                    &self.retry_policy,
+                ))
+        });
+        let packet = response.map_err(to_py_err)?;
+        Ok(PyPacket { packet })
+    }
+}
+
+#[pymodule]
+fn radius_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAVP>()?;
+    m.add_class::<PyPacket>()?;
+    m.add_class::<PyClient>()?;
+    m.add("RadiusError", m.py().get_type::<RadiusError>())?;
+    for code in [
+        Code::AccessRequest,
+        Code::AccessAccept,
+        Code::AccessReject,
+        Code::AccountingRequest,
+        Code::AccountingResponse,
+        Code::AccessChallenge,
+        Code::StatusServer,
+        Code::StatusClient,
+        Code::DisconnectRequest,
+        Code::DisconnectACK,
+        Code::DisconnectNAK,
+        Code::CoARequest,
+        Code::CoAACK,
+        Code::CoANAK,
+    ] {
+        // e.g. `Access-Request` is `ACCESS_REQUEST`
+        let name = code.string().replace('-', "_").to_ascii_uppercase();
+        m.add(name.as_str(), code as u8)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::net::UdpSocket;
+    use std::thread;
+
+    use pyo3::prelude::*;
+    use pyo3::types::{PyDict, PyModule};
+    use radius::core::code::Code;
+    use radius::core::packet::Packet;
+    use radius::core::rfc2865;
+
+    use crate::radius_rs;
+
+    fn run(script: &str, locals: &[(&str, &str)]) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "radius_rs").unwrap();
+            radius_rs(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("radius_rs", module).unwrap();
+            for (key, value) in locals {
+                globals.set_item(key, value).unwrap();
+            }
+            let script = CString::new(script).unwrap();
+            if let Err(e) = py.run(&script, Some(&globals), None) {
+                panic!("{}", e.value(py));
+            }
+        });
+    }
+
+    #[test]
+    fn test_packet() {
+        run(
+            r#"
+request = radius_rs.Packet(radius_rs.ACCESS_REQUEST, b"secret")
+request.identifier = 42
+request["User-Name"] = "alice"
+request["User-Password"] = "password"
+request["Service-Type"] = "Framed-User"
+request.add(25, b"\x01\x02")
+assert "User-Name" in request and "Reply-Message" not in request
+assert len(request) == 4
+
+decoded = radius_rs.Packet.decode(request.encode(), b"secret")
+assert decoded.code == radius_rs.ACCESS_REQUEST
+assert decoded.identifier == 42
+assert decoded.authenticator == request.authenticator
+assert decoded["User-Name"] == ["alice"]
+assert decoded["User-Password"] == ["password"]
+assert decoded["Service-Type"] == ["Framed-User"]
+assert decoded.get(25) == b"\x01\x02"
+assert decoded.get_all(26) == []
+assert [(avp.type, avp.value) for avp in decoded.avps()][0] == (1, b"alice")
+
+del decoded["User-Name"]
+try:
+    decoded["User-Name"]
+    raise AssertionError("KeyError is expected")
+except KeyError:
+    pass
+
+try:
+    decoded["No-Such-Attribute"] = "value"
+    raise AssertionError("RadiusError is expected")
+except radius_rs.RadiusError:
+    pass
+
+try:
+    radius_rs.Packet.decode(b"\x01\x02", b"secret")
+    raise AssertionError("RadiusError is expected")
+except radius_rs.RadiusError:
+    pass
+
+reply = request.create_reply(radius_rs.ACCESS_ACCEPT)
+assert reply.identifier == 42
+"#,
+            &[],
+        );
+    }
+
+    #[test]
+    fn test_client() {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap().to_string();
+        let server = thread::spawn(move || {
+            let mut buf = [0; 4096];
+            let (len, peer) = socket.recv_from(&mut buf).unwrap();
+            let request = Packet::decode(&buf[..len], b"secret").unwrap();
+            let mut response = request.make_response_packet(Code::AccessAccept);
+            let user_name = rfc2865::lookup_user_name(&request).unwrap().unwrap();
+            rfc2865::add_reply_message(&mut response, format!("hello, {user_name}"));
+            socket.send_to(&response.encode().unwrap(), peer).unwrap();
+        });
+
+        run(
+            r#"
+client = radius_rs.Client(timeout=5.0, retries=1)
+request = radius_rs.Packet(radius_rs.ACCESS_REQUEST, b"secret")
+request["User-Name"] = "alice"
+response = client.send(request, addr)
+assert response.code == radius_rs.ACCESS_ACCEPT
+assert response["Reply-Message"] == ["hello, alice"]
+"#,
+            &[("addr", &addr)],
+        );
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,365 @@
+//! Rate-based detection of the abusive clients.
+//!
+//! `AbuseDetector` counts the suspicious events (e.g. authentication failures, malformed packets) per client
+//! in a sliding window, and calls the `AbuseHandler` when the count reaches the threshold.
+//! The server drops the requests from the clients that the handler bans, so `TemporaryBan` makes
+//! fail2ban-style reactions.
+
+use std::collections::{HashMap, VecDeque};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::clock::{self, Clock};
+
+/// The number of the tracked (client, kind) pairs to sweep the stale counters.
+const SWEEP_THRESHOLD: usize = 10000;
+
+/// This enum represents the kind of a suspicious event of a client.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AbuseKind {
+    /// The server responds Access-Reject to the client.
+    AuthFailure,
+    /// The request cannot be decoded.
+    MalformedPacket,
+    /// The request has an invalid authenticator (e.g. the shared secret is wrong).
+    InvalidAuthenticator,
+}
+
+/// This struct represents a threshold of the number of the events in a sliding window.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AbuseThreshold {
+    count: usize,
+    window: Duration,
+}
+
+impl AbuseThreshold {
+    pub fn new(count: usize, window: Duration) -> Self {
+        AbuseThreshold { count, window }
+    }
+
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+
+    pub fn get_window(&self) -> Duration {
+        self.window
+    }
+}
+
+/// AbuseHandler reacts to the clients that exceed the thresholds.
+pub trait AbuseHandler: 'static + Sync + Send {
+    /// Called when the number of the events of the client reaches the threshold in the window.
+    ///
+    /// This is called in the request processing, so this must not block.
+    fn on_threshold_exceeded(&self, client: IpAddr, kind: AbuseKind, threshold: &AbuseThreshold);
+
+    /// Returns whether the requests from the client are dropped (e.g. the client is banned).
+    fn is_banned(&self, _client: IpAddr) -> bool {
+        false
+    }
+}
+
+impl<F: Fn(IpAddr, AbuseKind, &AbuseThreshold) + 'static + Sync + Send> AbuseHandler for F {
+    fn on_threshold_exceeded(&self, client: IpAddr, kind: AbuseKind, threshold: &AbuseThreshold) {
+        self(client, kind, threshold)
+    }
+}
+
+/// The handler that bans the client for a while when it exceeds a threshold.
+#[derive(Debug)]
+pub struct TemporaryBan {
+    duration: Duration,
+    banned_until: Mutex<HashMap<IpAddr, Instant>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl TemporaryBan {
+    pub fn new(duration: Duration) -> Self {
+        TemporaryBan {
+            duration,
+            banned_until: Mutex::new(HashMap::new()),
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set a clock of the expiry of the bans (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Lift the ban of the client.
+    pub fn unban(&self, client: IpAddr) {
+        self.banned_until.lock().unwrap().remove(&client);
+    }
+
+    fn is_banned_at(&self, client: IpAddr, now: Instant) -> bool {
+        let mut banned_until = self.banned_until.lock().unwrap();
+        match banned_until.get(&client) {
+            Some(until) if *until > now => true,
+            Some(_) => {
+                banned_until.remove(&client);
+                false
+            }
+            None => false,
+        }
+    }
+}
+
+impl AbuseHandler for TemporaryBan {
+    fn on_threshold_exceeded(&self, client: IpAddr, kind: AbuseKind, threshold: &AbuseThreshold) {
+        warn!(
+            "client {} is banned for {:?}; {:?} reached {} times in {:?}",
+            client,
+            self.duration,
+            kind,
+            threshold.get_count(),
+            threshold.get_window()
+        );
+        self.banned_until
+            .lock()
+            .unwrap()
+            .insert(client, self.clock.now() + self.duration);
+    }
+
+    fn is_banned(&self, client: IpAddr) -> bool {
+        self.is_banned_at(client, self.clock.now())
+    }
+}
+
+/// The per-client counters of the suspicious events with the thresholds.
+///
+/// By default, the thresholds are 10 authentication failures, 20 malformed packets and 20 invalid authenticators
+/// per minute. The counter of the client is reset when it reaches the threshold, so the handler is called once per burst.
+pub struct AbuseDetector {
+    thresholds: HashMap<AbuseKind, AbuseThreshold>,
+    counters: Mutex<HashMap<(IpAddr, AbuseKind), VecDeque<Instant>>>,
+    handler: Arc<dyn AbuseHandler>,
+    clock: Arc<dyn Clock>,
+}
+
+impl AbuseDetector {
+    pub fn new<H: AbuseHandler>(handler: H) -> Self {
+        let minute = Duration::from_secs(60);
+        AbuseDetector {
+            thresholds: HashMap::from([
+                (AbuseKind::AuthFailure, AbuseThreshold::new(10, minute)),
+                (AbuseKind::MalformedPacket, AbuseThreshold::new(20, minute)),
+                (
+                    AbuseKind::InvalidAuthenticator,
+                    AbuseThreshold::new(20, minute),
+                ),
+            ]),
+            counters: Mutex::new(HashMap::new()),
+            handler: Arc::new(handler),
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set a clock of the sliding windows (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Set the threshold of the kind of the events.
+    pub fn set_threshold(&mut self, kind: AbuseKind, threshold: AbuseThreshold) {
+        self.thresholds.insert(kind, threshold);
+    }
+
+    /// Stop counting the kind of the events.
+    pub fn remove_threshold(&mut self, kind: AbuseKind) {
+        self.thresholds.remove(&kind);
+    }
+
+    pub fn get_handler(&self) -> &Arc<dyn AbuseHandler> {
+        &self.handler
+    }
+
+    /// Returns whether the requests from the client are dropped; this delegates to the handler.
+    pub fn is_banned(&self, client: IpAddr) -> bool {
+        self.handler.is_banned(client)
+    }
+
+    /// Count an event of the client, and call the handler if the count reaches the threshold.
+    pub fn record(&self, client: IpAddr, kind: AbuseKind) {
+        self.record_at(client, kind, self.clock.now());
+    }
+
+    fn record_at(&self, client: IpAddr, kind: AbuseKind, now: Instant) {
+        let Some(threshold) = self.thresholds.get(&kind) else {
+            return;
+        };
+
+        let exceeded = {
+            let mut counters = self.counters.lock().unwrap();
+            if counters.len() >= SWEEP_THRESHOLD {
+                let max_window = self
+                    .thresholds
+                    .values()
+                    .map(AbuseThreshold::get_window)
+                    .max()
+                    .unwrap_or_default();
+                counters.retain(|_, occurrences| {
+                    occurrences
+                        .back()
+                        .is_some_and(|last| now.duration_since(*last) < max_window)
+                });
+            }
+
+            let occurrences = counters.entry((client, kind)).or_default();
+            while occurrences
+                .front()
+                .is_some_and(|first| now.duration_since(*first) >= threshold.window)
+            {
+                occurrences.pop_front();
+            }
+            occurrences.push_back(now);
+            if occurrences.len() >= threshold.count {
+                counters.remove(&(client, kind));
+                true
+            } else {
+                false
+            }
+        };
+
+        if exceeded {
+            self.handler.on_threshold_exceeded(client, kind, threshold);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use crate::abuse::{AbuseDetector, AbuseHandler, AbuseKind, AbuseThreshold, TemporaryBan};
+    use crate::clock::MockClock;
+
+    const CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+    const OTHER_CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2));
+
+    #[test]
+    fn test_threshold_in_sliding_window() {
+        let exceeded = Arc::new(Mutex::new(vec![]));
+        let exceeded_for_handler = exceeded.clone();
+        let mut detector = AbuseDetector::new(move |client, kind, threshold: &AbuseThreshold| {
+            exceeded_for_handler
+                .lock()
+                .unwrap()
+                .push((client, kind, threshold.get_count()));
+        });
+        detector.set_threshold(
+            AbuseKind::AuthFailure,
+            AbuseThreshold::new(3, Duration::from_secs(10)),
+        );
+
+        let now = Instant::now();
+        detector.record_at(CLIENT, AbuseKind::AuthFailure, now);
+        detector.record_at(CLIENT, AbuseKind::AuthFailure, now + Duration::from_secs(1));
+        detector.record_at(
+            OTHER_CLIENT,
+            AbuseKind::AuthFailure,
+            now + Duration::from_secs(2),
+        );
+        detector.record_at(
+            CLIENT,
+            AbuseKind::MalformedPacket,
+            now + Duration::from_secs(3),
+        );
+        // the first failure is out of the window
+        detector.record_at(
+            CLIENT,
+            AbuseKind::AuthFailure,
+            now + Duration::from_secs(10),
+        );
+        assert!(exceeded.lock().unwrap().is_empty());
+
+        detector.record_at(
+            CLIENT,
+            AbuseKind::AuthFailure,
+            now + Duration::from_millis(10500),
+        );
+        assert_eq!(
+            *exceeded.lock().unwrap(),
+            vec![(CLIENT, AbuseKind::AuthFailure, 3)]
+        );
+
+        // the counter has been reset
+        detector.record_at(
+            CLIENT,
+            AbuseKind::AuthFailure,
+            now + Duration::from_millis(10600),
+        );
+        assert_eq!(exceeded.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_removed_threshold() {
+        let mut detector =
+            AbuseDetector::new(|_, _, _: &AbuseThreshold| panic!("the handler must not be called"));
+        detector.remove_threshold(AbuseKind::MalformedPacket);
+        for _ in 0..100 {
+            detector.record(CLIENT, AbuseKind::MalformedPacket);
+        }
+    }
+
+    #[test]
+    fn test_temporary_ban() {
+        let mut detector = AbuseDetector::new(TemporaryBan::new(Duration::from_secs(60)));
+        detector.set_threshold(
+            AbuseKind::InvalidAuthenticator,
+            AbuseThreshold::new(2, Duration::from_secs(10)),
+        );
+
+        detector.record(CLIENT, AbuseKind::InvalidAuthenticator);
+        assert!(!detector.is_banned(CLIENT));
+        detector.record(CLIENT, AbuseKind::InvalidAuthenticator);
+        assert!(detector.is_banned(CLIENT));
+        assert!(!detector.is_banned(OTHER_CLIENT));
+
+        let ban = TemporaryBan::new(Duration::from_secs(60));
+        ban.banned_until
+            .lock()
+            .unwrap()
+            .insert(CLIENT, Instant::now() + Duration::from_secs(60));
+        assert!(ban.is_banned_at(CLIENT, Instant::now()));
+        assert!(!ban.is_banned_at(CLIENT, Instant::now() + Duration::from_secs(61)));
+        // the expired ban is removed
+        assert!(ban.banned_until.lock().unwrap().is_empty());
+
+        ban.banned_until
+            .lock()
+            .unwrap()
+            .insert(CLIENT, Instant::now() + Duration::from_secs(60));
+        ban.unban(CLIENT);
+        assert!(!ban.is_banned(CLIENT));
+    }
+
+    #[test]
+    fn test_temporary_ban_on_mock_clock() {
+        let clock = MockClock::default();
+        let mut ban = TemporaryBan::new(Duration::from_secs(60));
+        ban.set_clock(Arc::new(clock.clone()));
+        let mut detector = AbuseDetector::new(ban);
+        detector.set_clock(Arc::new(clock.clone()));
+        detector.set_threshold(
+            AbuseKind::AuthFailure,
+            AbuseThreshold::new(2, Duration::from_secs(10)),
+        );
+
+        detector.record(CLIENT, AbuseKind::AuthFailure);
+        clock.advance(Duration::from_secs(10));
+        // the first failure is out of the window
+        detector.record(CLIENT, AbuseKind::AuthFailure);
+        assert!(!detector.is_banned(CLIENT));
+        detector.record(CLIENT, AbuseKind::AuthFailure);
+        assert!(detector.is_banned(CLIENT));
+
+        clock.advance(Duration::from_secs(59));
+        assert!(detector.is_banned(CLIENT));
+        clock.advance(Duration::from_secs(1));
+        assert!(!detector.is_banned(CLIENT));
+    }
+}
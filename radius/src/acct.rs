@@ -0,0 +1,669 @@
+//! Utilities for RADIUS Accounting servers.
+//!
+//! see also: https://tools.ietf.org/html/rfc2866
+
+pub mod detail;
+pub mod export;
+pub mod fanout;
+#[cfg(feature = "acct-sink-kafka")]
+pub mod kafka;
+#[cfg(feature = "acct-sink")]
+pub mod sink;
+pub mod spool;
+#[cfg(feature = "acct-sql")]
+pub mod sql;
+#[cfg(feature = "acct-sqlite")]
+pub mod sqlite;
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use tokio::net::UdpSocket;
+
+use crate::clock::{self, Clock};
+use crate::core::avp::AVPError;
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::request::Request;
+use crate::core::{rfc2865, rfc2866, rfc2869, rfc3162};
+use crate::server::RequestHandler;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AcctError {
+    /// This error is raised when the request is not an Accounting-Request.
+    #[error("unexpected request code: {0}")]
+    UnexpectedRequestCodeError(String),
+
+    /// This error is raised when the request doesn't have an `Acct-Status-Type` attribute.
+    #[error("Acct-Status-Type attribute is missing")]
+    AcctStatusTypeMissingError(),
+
+    /// This error is raised when the request doesn't have an `Acct-Session-Id` attribute.
+    #[error("Acct-Session-Id attribute is missing")]
+    AcctSessionIdMissingError(),
+
+    /// This error is raised when an attribute of the request cannot be decoded.
+    #[error("failed to decode an accounting attribute; {0}")]
+    AttributeDecodingError(AVPError),
+
+    /// This error is raised when the `Acct-Status-Type` is not supported by the handler.
+    #[error("unsupported Acct-Status-Type: {}", .0.to_u32())]
+    UnsupportedAcctStatusTypeError(rfc2866::AcctStatusType),
+
+    /// This error is raised when the session store fails an operation.
+    #[error("session store error: {0}")]
+    SessionStoreError(String),
+
+    /// This error is raised when an accounting record cannot be exported.
+    #[error("failed to export an accounting record: {0}")]
+    RecordExportingError(export::ExportError),
+
+    /// This error is raised when the Accounting-Response cannot be encoded.
+    #[error("failed to encode Accounting-Response: {0}")]
+    ResponseEncodingError(String),
+
+    /// This error is raised when the Accounting-Response cannot be sent.
+    #[error("failed to send Accounting-Response: {0}")]
+    ResponseSendingError(String),
+}
+
+/// This struct represents a key to identify an accounting session.
+///
+/// `Acct-Session-Id` is unique only per NAS, so the key consists of that and the NAS identity.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SessionKey {
+    /// The value of the `Acct-Session-Id` attribute.
+    pub session_id: String,
+    /// The identity of the NAS; `NAS-Identifier`, `NAS-IP-Address`, `NAS-IPv6-Address` or the source address of the request, in that order of precedence.
+    pub nas: String,
+}
+
+impl SessionKey {
+    /// Make a session key from an accounting request packet.
+    ///
+    /// `remote_ip` is used as the NAS identity when the packet doesn't have any NAS identifying attribute.
+    pub fn from_packet(packet: &Packet, remote_ip: IpAddr) -> Result<Self, AcctError> {
+        let session_id = match rfc2866::lookup_acct_session_id(packet) {
+            Some(session_id) => session_id.map_err(AcctError::AttributeDecodingError)?,
+            None => return Err(AcctError::AcctSessionIdMissingError()),
+        };
+
+        Ok(SessionKey {
+            session_id,
+            nas: nas_identity(packet, remote_ip)?,
+        })
+    }
+}
+
+fn nas_identity(packet: &Packet, remote_ip: IpAddr) -> Result<String, AcctError> {
+    if let Some(nas_identifier) = rfc2865::lookup_nas_identifier(packet) {
+        return nas_identifier.map_err(AcctError::AttributeDecodingError);
+    }
+    if let Some(nas_ip_address) = rfc2865::lookup_nas_ip_address(packet) {
+        return Ok(nas_ip_address
+            .map_err(AcctError::AttributeDecodingError)?
+            .to_string());
+    }
+    if let Some(nas_ipv6_address) = rfc3162::lookup_nas_ipv6_address(packet) {
+        return Ok(nas_ipv6_address
+            .map_err(AcctError::AttributeDecodingError)?
+            .to_string());
+    }
+    Ok(remote_ip.to_string())
+}
+
+/// This struct represents an accounting session that is maintained by a `SessionStore`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub key: SessionKey,
+    pub user_name: Option<String>,
+    pub nas_port: Option<u32>,
+    pub framed_ip_address: Option<Ipv4Addr>,
+    pub calling_station_id: Option<String>,
+    /// The value of `Acct-Session-Time` in seconds.
+    pub session_time: u32,
+    /// The number of input octets, including `Acct-Input-Gigawords`.
+    pub input_octets: u64,
+    /// The number of output octets, including `Acct-Output-Gigawords`.
+    pub output_octets: u64,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Session {
+    /// Make a session from an accounting request that is received at the given time.
+    pub fn from_request_at(request: &Request, now: DateTime<Utc>) -> Result<Self, AcctError> {
+        let packet = request.get_packet();
+        let counters = Counters::from_packet(packet)?;
+
+        Ok(Session {
+            key: SessionKey::from_packet(packet, request.get_remote_addr().ip())?,
+            user_name: lookup_optional(rfc2865::lookup_user_name(packet))?,
+            nas_port: lookup_optional(rfc2865::lookup_nas_port(packet))?,
+            framed_ip_address: lookup_optional(rfc2865::lookup_framed_ip_address(packet))?,
+            calling_station_id: lookup_optional(rfc2865::lookup_calling_station_id(packet))?,
+            session_time: counters.session_time,
+            input_octets: counters.input_octets,
+            output_octets: counters.output_octets,
+            started_at: now,
+            updated_at: now,
+        })
+    }
+}
+
+pub(crate) fn lookup_optional<T>(
+    maybe_value: Option<Result<T, AVPError>>,
+) -> Result<Option<T>, AcctError> {
+    maybe_value
+        .transpose()
+        .map_err(AcctError::AttributeDecodingError)
+}
+
+fn combine_octets(octets: Option<u32>, gigawords: Option<u32>) -> u64 {
+    ((gigawords.unwrap_or(0) as u64) << 32) | octets.unwrap_or(0) as u64
+}
+
+/// This struct represents the usage counters of an accounting request; the missing attributes are zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Counters {
+    /// The number of input octets, including `Acct-Input-Gigawords`.
+    pub input_octets: u64,
+    /// The number of output octets, including `Acct-Output-Gigawords`.
+    pub output_octets: u64,
+    pub input_packets: u32,
+    pub output_packets: u32,
+    /// The value of `Acct-Session-Time` in seconds.
+    pub session_time: u32,
+}
+
+impl Counters {
+    /// Make the counters from an accounting request.
+    pub fn from_packet(packet: &Packet) -> Result<Self, AcctError> {
+        Ok(Counters {
+            input_octets: combine_octets(
+                lookup_optional(rfc2866::lookup_acct_input_octets(packet))?,
+                lookup_optional(rfc2869::lookup_acct_input_gigawords(packet))?,
+            ),
+            output_octets: combine_octets(
+                lookup_optional(rfc2866::lookup_acct_output_octets(packet))?,
+                lookup_optional(rfc2869::lookup_acct_output_gigawords(packet))?,
+            ),
+            input_packets: lookup_optional(rfc2866::lookup_acct_input_packets(packet))?
+                .unwrap_or(0),
+            output_packets: lookup_optional(rfc2866::lookup_acct_output_packets(packet))?
+                .unwrap_or(0),
+            session_time: lookup_optional(rfc2866::lookup_acct_session_time(packet))?.unwrap_or(0),
+        })
+    }
+
+    /// Returns the usage since the previous counters of the same session (e.g. the previous `Interim-Update`).
+    ///
+    /// A counter that is less than the previous one is regarded as wrapped around; the octets wrap at 2^32
+    /// unless either of those has the gigawords, and at 2^64 otherwise.
+    pub fn delta(&self, previous: &Counters) -> Counters {
+        Counters {
+            input_octets: octets_delta(self.input_octets, previous.input_octets),
+            output_octets: octets_delta(self.output_octets, previous.output_octets),
+            input_packets: self.input_packets.wrapping_sub(previous.input_packets),
+            output_packets: self.output_packets.wrapping_sub(previous.output_packets),
+            session_time: self.session_time.wrapping_sub(previous.session_time),
+        }
+    }
+}
+
+fn octets_delta(current: u64, previous: u64) -> u64 {
+    if current <= u32::MAX as u64 && previous <= u32::MAX as u64 {
+        (current as u32).wrapping_sub(previous as u32) as u64
+    } else {
+        current.wrapping_sub(previous)
+    }
+}
+
+/// SessionStore is a storage of the active accounting sessions.
+///
+/// The sessions are upserted by `Start` and `Interim-Update`, and removed by `Stop`.
+#[async_trait]
+pub trait SessionStore: 'static + Sync + Send {
+    /// Upsert a session on `Acct-Status-Type = Start`.
+    async fn start(&self, session: Session) -> Result<(), AcctError>;
+
+    /// Upsert a session on `Acct-Status-Type = Interim-Update`.
+    /// If the session already exists, the implementation should keep the original `started_at`.
+    async fn interim(&self, session: Session) -> Result<(), AcctError>;
+
+    /// Remove a session on `Acct-Status-Type = Stop`; this returns the removed session if it exists.
+    async fn stop(&self, key: &SessionKey) -> Result<Option<Session>, AcctError>;
+
+    /// Remove all of the sessions of the NAS on `Acct-Status-Type = Accounting-On` and `Accounting-Off`;
+    /// this returns the number of the removed sessions.
+    async fn clear_nas(&self, nas: &str) -> Result<usize, AcctError>;
+
+    /// Get a session by the key.
+    async fn get(&self, key: &SessionKey) -> Result<Option<Session>, AcctError>;
+
+    /// Find the active sessions of the user.
+    async fn find_by_user_name(&self, user_name: &str) -> Result<Vec<Session>, AcctError>;
+}
+
+/// An in-memory implementation of the `SessionStore`.
+#[derive(Debug, Default)]
+pub struct InMemorySessionStore {
+    sessions: RwLock<HashMap<SessionKey, Session>>,
+}
+
+impl InMemorySessionStore {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the number of the active sessions.
+    pub fn len(&self) -> usize {
+        self.sessions.read().unwrap().len()
+    }
+
+    /// Returns whether there is no active session or not.
+    pub fn is_empty(&self) -> bool {
+        self.sessions.read().unwrap().is_empty()
+    }
+}
+
+#[async_trait]
+impl SessionStore for InMemorySessionStore {
+    async fn start(&self, session: Session) -> Result<(), AcctError> {
+        let mut sessions = self.sessions.write().unwrap();
+        sessions.insert(session.key.clone(), session);
+        Ok(())
+    }
+
+    async fn interim(&self, mut session: Session) -> Result<(), AcctError> {
+        let mut sessions = self.sessions.write().unwrap();
+        if let Some(existing) = sessions.get(&session.key) {
+            session.started_at = existing.started_at;
+        }
+        sessions.insert(session.key.clone(), session);
+        Ok(())
+    }
+
+    async fn stop(&self, key: &SessionKey) -> Result<Option<Session>, AcctError> {
+        let mut sessions = self.sessions.write().unwrap();
+        Ok(sessions.remove(key))
+    }
+
+    async fn clear_nas(&self, nas: &str) -> Result<usize, AcctError> {
+        let mut sessions = self.sessions.write().unwrap();
+        let before = sessions.len();
+        sessions.retain(|key, _| key.nas != nas);
+        Ok(before - sessions.len())
+    }
+
+    async fn get(&self, key: &SessionKey) -> Result<Option<Session>, AcctError> {
+        let sessions = self.sessions.read().unwrap();
+        Ok(sessions.get(key).cloned())
+    }
+
+    async fn find_by_user_name(&self, user_name: &str) -> Result<Vec<Session>, AcctError> {
+        let sessions = self.sessions.read().unwrap();
+        Ok(sessions
+            .values()
+            .filter(|session| session.user_name.as_deref() == Some(user_name))
+            .cloned()
+            .collect())
+    }
+}
+
+/// A prebuilt `RequestHandler` for the accounting server that maintains the sessions in a `SessionStore`.
+///
+/// This sends an Accounting-Response only when the request has been recorded successfully,
+/// so that the NAS retransmits the request on failure.
+/// see also: https://tools.ietf.org/html/rfc2866#section-4.1
+pub struct AccountingHandler<S: SessionStore> {
+    store: Arc<S>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<S: SessionStore> AccountingHandler<S> {
+    pub fn new(store: Arc<S>) -> Self {
+        AccountingHandler {
+            store,
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set a clock of the timestamps of the sessions (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Returns the session store that is maintained by this handler.
+    pub fn get_store(&self) -> &Arc<S> {
+        &self.store
+    }
+
+    /// Record an accounting request to the session store.
+    pub async fn record(&self, request: &Request) -> Result<(), AcctError> {
+        let packet = request.get_packet();
+        if packet.get_code() != Code::AccountingRequest {
+            return Err(AcctError::UnexpectedRequestCodeError(format!(
+                "{:?}",
+                packet.get_code()
+            )));
+        }
+
+        let status_type = match rfc2866::lookup_acct_status_type(packet) {
+            Some(status_type) => status_type.map_err(AcctError::AttributeDecodingError)?,
+            None => return Err(AcctError::AcctStatusTypeMissingError()),
+        };
+
+        match status_type {
+            rfc2866::ACCT_STATUS_TYPE_START => {
+                self.store
+                    .start(Session::from_request_at(request, self.clock.now_utc())?)
+                    .await
+            }
+            rfc2866::ACCT_STATUS_TYPE_INTERIM_UPDATE => {
+                self.store
+                    .interim(Session::from_request_at(request, self.clock.now_utc())?)
+                    .await
+            }
+            rfc2866::ACCT_STATUS_TYPE_STOP => {
+                let key = SessionKey::from_packet(packet, request.get_remote_addr().ip())?;
+                self.store.stop(&key).await.map(|_| ())
+            }
+            rfc2866::ACCT_STATUS_TYPE_ACCOUNTING_ON | rfc2866::ACCT_STATUS_TYPE_ACCOUNTING_OFF => {
+                let nas = nas_identity(packet, request.get_remote_addr().ip())?;
+                self.store.clear_nas(&nas).await.map(|_| ())
+            }
+            _ => Err(AcctError::UnsupportedAcctStatusTypeError(status_type)),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: SessionStore> RequestHandler<(), AcctError> for AccountingHandler<S> {
+    async fn handle_radius_request(
+        &self,
+        conn: &UdpSocket,
+        request: &Request,
+    ) -> Result<(), AcctError> {
+        self.record(request).await?;
+        send_accounting_response(conn, request).await
+    }
+}
+
+pub(crate) async fn send_accounting_response(
+    conn: &UdpSocket,
+    request: &Request,
+) -> Result<(), AcctError> {
+    let response = match request
+        .get_packet()
+        .make_response_packet(Code::AccountingResponse)
+        .encode()
+    {
+        Ok(response) => response,
+        Err(e) => return Err(AcctError::ResponseEncodingError(e.to_string())),
+    };
+    match conn.send_to(&response, request.get_remote_addr()).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(AcctError::ResponseSendingError(e.to_string())),
+    }
+}
+
+/// The default `Reply-Message` of the Access-Reject that is made by `SimultaneousUseChecker`.
+pub const DEFAULT_SIMULTANEOUS_USE_REPLY_MESSAGE: &str =
+    "You are already logged in - access denied";
+
+/// A policy hook that limits the number of the active sessions per user,
+/// like `Simultaneous-Use` of FreeRADIUS.
+///
+/// The active sessions are counted by the `SessionStore`.
+pub struct SimultaneousUseChecker<S: SessionStore> {
+    store: Arc<S>,
+    limit: usize,
+    reply_message: String,
+}
+
+impl<S: SessionStore> SimultaneousUseChecker<S> {
+    /// Make a checker that allows up to `limit` active sessions per user.
+    pub fn new(store: Arc<S>, limit: usize) -> Self {
+        SimultaneousUseChecker {
+            store,
+            limit,
+            reply_message: DEFAULT_SIMULTANEOUS_USE_REPLY_MESSAGE.to_owned(),
+        }
+    }
+
+    /// Set a `Reply-Message` of the Access-Reject (default: `DEFAULT_SIMULTANEOUS_USE_REPLY_MESSAGE`).
+    pub fn set_reply_message(&mut self, reply_message: &str) {
+        self.reply_message = reply_message.to_owned();
+    }
+
+    /// Returns whether the user has reached the limit of the active sessions or not.
+    pub async fn is_limit_exceeded(&self, user_name: &str) -> Result<bool, AcctError> {
+        let sessions = self.store.find_by_user_name(user_name).await?;
+        Ok(sessions.len() >= self.limit)
+    }
+
+    /// Check an Access-Request.
+    ///
+    /// This returns an Access-Reject packet with `Reply-Message` when the user of the request
+    /// already has the permitted number of active sessions; otherwise `None`.
+    /// A request that doesn't have `User-Name` is not subject to this check.
+    pub async fn check(&self, request: &Request) -> Result<Option<Packet>, AcctError> {
+        let packet = request.get_packet();
+        let user_name = match lookup_optional(rfc2865::lookup_user_name(packet))? {
+            Some(user_name) => user_name,
+            None => return Ok(None),
+        };
+
+        if !self.is_limit_exceeded(&user_name).await? {
+            return Ok(None);
+        }
+
+        info!(
+            "simultaneous use limit exceeded; user={}, limit={}",
+            user_name, self.limit
+        );
+        let mut response = packet.make_response_packet(Code::AccessReject);
+        rfc2865::add_reply_message(&mut response, &self.reply_message);
+        Ok(Some(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::Arc;
+
+    use crate::acct::{
+        AccountingHandler, AcctError, Counters, InMemorySessionStore, SessionKey, SessionStore,
+        SimultaneousUseChecker, DEFAULT_SIMULTANEOUS_USE_REPLY_MESSAGE,
+    };
+    use crate::core::avp::AVP;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::{rfc2865, rfc2866, rfc2869};
+
+    fn make_request(status_type: rfc2866::AcctStatusType, session_id: &str) -> Request {
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_status_type(&mut packet, status_type);
+        rfc2866::add_acct_session_id(&mut packet, session_id);
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2865::add_nas_ip_address(&mut packet, &Ipv4Addr::new(192, 0, 2, 1));
+
+        let addr: SocketAddr = "127.0.0.1:1813".parse().unwrap();
+        Request::new(addr, addr, packet)
+    }
+
+    #[test]
+    fn test_counters() {
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        assert_eq!(Counters::from_packet(&packet).unwrap(), Counters::default());
+
+        rfc2866::add_acct_input_octets(&mut packet, 10);
+        rfc2869::add_acct_input_gigawords(&mut packet, 1);
+        rfc2866::add_acct_output_octets(&mut packet, 20);
+        rfc2866::add_acct_input_packets(&mut packet, 3);
+        rfc2866::add_acct_output_packets(&mut packet, 4);
+        rfc2866::add_acct_session_time(&mut packet, 60);
+        let previous = Counters::from_packet(&packet).unwrap();
+        assert_eq!(
+            previous,
+            Counters {
+                input_octets: (1 << 32) + 10,
+                output_octets: 20,
+                input_packets: 3,
+                output_packets: 4,
+                session_time: 60,
+            }
+        );
+
+        let current = Counters {
+            input_octets: (2 << 32) + 5,
+            output_octets: 120,
+            input_packets: 13,
+            output_packets: 14,
+            session_time: 120,
+        };
+        assert_eq!(
+            current.delta(&previous),
+            Counters {
+                input_octets: (1 << 32) - 5,
+                output_octets: 100,
+                input_packets: 10,
+                output_packets: 10,
+                session_time: 60,
+            }
+        );
+
+        // the counters without the gigawords wrap at 2^32
+        let previous = Counters {
+            output_octets: u32::MAX as u64 - 9,
+            input_packets: u32::MAX,
+            ..Counters::default()
+        };
+        let current = Counters {
+            output_octets: 10,
+            input_packets: 1,
+            ..Counters::default()
+        };
+        let delta = current.delta(&previous);
+        assert_eq!(delta.output_octets, 20);
+        assert_eq!(delta.input_packets, 2);
+
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        packet.add(AVP::from_bytes(rfc2866::ACCT_INPUT_PACKETS_TYPE, &[1]).unwrap());
+        assert!(matches!(
+            Counters::from_packet(&packet),
+            Err(AcctError::AttributeDecodingError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_accounting_handler() {
+        let store = Arc::new(InMemorySessionStore::new());
+        let handler = AccountingHandler::new(store.clone());
+        let key = SessionKey {
+            session_id: "s1".to_owned(),
+            nas: "192.0.2.1".to_owned(),
+        };
+
+        handler
+            .record(&make_request(rfc2866::ACCT_STATUS_TYPE_START, "s1"))
+            .await
+            .unwrap();
+        let started = store.get(&key).await.unwrap().unwrap();
+        assert_eq!(started.user_name.as_deref(), Some("alice"));
+        assert_eq!(started.input_octets, 0);
+
+        let mut interim = make_request(rfc2866::ACCT_STATUS_TYPE_INTERIM_UPDATE, "s1");
+        let mut packet = interim.get_packet().clone();
+        rfc2866::add_acct_input_octets(&mut packet, 10);
+        rfc2869::add_acct_input_gigawords(&mut packet, 1);
+        interim = Request::new(interim.get_local_addr(), interim.get_remote_addr(), packet);
+        handler.record(&interim).await.unwrap();
+        let updated = store.get(&key).await.unwrap().unwrap();
+        assert_eq!(updated.input_octets, (1 << 32) + 10);
+        assert_eq!(updated.started_at, started.started_at);
+
+        handler
+            .record(&make_request(rfc2866::ACCT_STATUS_TYPE_START, "s2"))
+            .await
+            .unwrap();
+        assert_eq!(store.find_by_user_name("alice").await.unwrap().len(), 2);
+
+        handler
+            .record(&make_request(rfc2866::ACCT_STATUS_TYPE_STOP, "s1"))
+            .await
+            .unwrap();
+        assert!(store.get(&key).await.unwrap().is_none());
+        assert_eq!(store.len(), 1);
+
+        handler
+            .record(&make_request(rfc2866::ACCT_STATUS_TYPE_ACCOUNTING_ON, "-"))
+            .await
+            .unwrap();
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_accounting_handler_rejects_invalid_request() {
+        let handler = AccountingHandler::new(Arc::new(InMemorySessionStore::new()));
+        let addr: SocketAddr = "127.0.0.1:1813".parse().unwrap();
+
+        let packet = Packet::new(Code::AccessRequest, b"secret");
+        assert_eq!(
+            handler
+                .record(&Request::new(addr, addr, packet))
+                .await
+                .unwrap_err(),
+            AcctError::UnexpectedRequestCodeError("AccessRequest".to_owned())
+        );
+
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_status_type(&mut packet, rfc2866::ACCT_STATUS_TYPE_START);
+        assert_eq!(
+            handler
+                .record(&Request::new(addr, addr, packet))
+                .await
+                .unwrap_err(),
+            AcctError::AcctSessionIdMissingError()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simultaneous_use_checker() {
+        let store = Arc::new(InMemorySessionStore::new());
+        let handler = AccountingHandler::new(store.clone());
+        let checker = SimultaneousUseChecker::new(store.clone(), 1);
+
+        let addr: SocketAddr = "127.0.0.1:1812".parse().unwrap();
+        let mut access_request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut access_request, "alice");
+        let access_request = Request::new(addr, addr, access_request);
+
+        assert!(checker.check(&access_request).await.unwrap().is_none());
+
+        handler
+            .record(&make_request(rfc2866::ACCT_STATUS_TYPE_START, "s1"))
+            .await
+            .unwrap();
+        let response = checker.check(&access_request).await.unwrap().unwrap();
+        assert_eq!(response.get_code(), Code::AccessReject);
+        assert_eq!(
+            rfc2865::lookup_reply_message(&response).unwrap().unwrap(),
+            DEFAULT_SIMULTANEOUS_USE_REPLY_MESSAGE
+        );
+
+        let without_user_name = Packet::new(Code::AccessRequest, b"secret");
+        assert!(checker
+            .check(&Request::new(addr, addr, without_user_name))
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,566 @@
+//! Reader and writer of the FreeRADIUS "detail" files, for replaying the accounting backlogs and spooling
+//! the accounting records in the compatible format.
+//!
+//! A detail file consists of the records that are separated by a blank line; each record begins with
+//! a timestamp line (e.g. `Mon Jan  1 00:00:00 2024`) and is followed by the (tab-)indented `Attr = value` lines:
+//!
+//! ```text
+//! Mon Jan  1 00:00:00 2024
+//!     Acct-Status-Type = Start
+//!     User-Name = "alice"
+//!     Timestamp = 1704067200
+//!
+//! ```
+//!
+//! The timestamp line has no time zone; the writer writes it in UTC and also appends a `Timestamp` attribute
+//! (the UNIX time), which takes precedence over the timestamp line on reading.
+
+use std::io::{BufRead, Write};
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use thiserror::Error;
+
+use crate::core::avp::{AVPType, AVP};
+use crate::core::code::Code;
+use crate::core::dictionary::{decode_hex, encode_hex, Dictionary, DictionaryError};
+use crate::core::metadata::AttributeDataType;
+use crate::core::packet::Packet;
+
+const TIMESTAMP_FORMAT: &str = "%a %b %e %H:%M:%S %Y";
+const TIMESTAMP_ATTRIBUTE: &str = "Timestamp";
+
+/// The attributes that FreeRADIUS adds to the records on the server side; those are not put into the packets.
+const SERVER_ATTRIBUTES: &[&str] = &[
+    "Packet-Src-IP-Address",
+    "Packet-Src-IPv6-Address",
+    "Packet-Dst-IP-Address",
+    "Packet-Dst-IPv6-Address",
+    "Packet-Src-Port",
+    "Packet-Dst-Port",
+    "Packet-Original-Timestamp",
+    "Client-IP-Address",
+    "Client-IPv6-Address",
+    "Acct-Unique-Session-Id",
+    TIMESTAMP_ATTRIBUTE,
+];
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DetailError {
+    /// This error is raised when it failed to read the detail file.
+    #[error("failed to read the detail file; {0}")]
+    ReadingError(String),
+
+    /// This error is raised when it failed to write the detail file.
+    #[error("failed to write the detail file; {0}")]
+    WritingError(String),
+
+    /// This error is raised when a line of the detail file cannot be parsed.
+    #[error("malformed record at line {0}; {1}")]
+    MalformedRecordError(usize, String),
+
+    /// This error is raised when an attribute of the record cannot be converted to/from an AVP.
+    #[error("failed to convert an attribute; {0}")]
+    AttributeConversionError(DictionaryError),
+
+    /// This error is raised when the packet to write is not an Accounting-Request.
+    #[error("unexpected packet code: {0}")]
+    UnexpectedPacketCodeError(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DetailAttribute {
+    name: String,
+    value: String,
+    /// whether the value is written as a quoted string
+    quoted: bool,
+}
+
+/// This struct represents a record of the detail file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailRecord {
+    timestamp: DateTime<Utc>,
+    attributes: Vec<DetailAttribute>,
+}
+
+impl DetailRecord {
+    pub fn new(timestamp: DateTime<Utc>) -> Self {
+        DetailRecord {
+            timestamp,
+            attributes: vec![],
+        }
+    }
+
+    /// Make a record from an Accounting-Request packet with the attribute names and the values of the dictionary.
+    ///
+    /// The attributes that the dictionary cannot format (e.g. unknown ones, Vendor-Specific) are written as
+    /// `Attr-<type> = 0x<hex>`, and the encrypted attributes are never written.
+    pub fn from_packet(
+        packet: &Packet,
+        dictionary: &Dictionary,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Self, DetailError> {
+        if packet.get_code() != Code::AccountingRequest {
+            return Err(DetailError::UnexpectedPacketCodeError(
+                packet.get_code().string().to_owned(),
+            ));
+        }
+
+        let mut record = DetailRecord::new(timestamp);
+        for avp in packet.get_avps() {
+            let attr = dictionary
+                .attribute_name(avp.type_code())
+                .and_then(|name| dictionary.lookup_attribute(name));
+            let Some(attr) = attr else {
+                record.push_raw(avp);
+                continue;
+            };
+            if attr.is_encrypted() {
+                continue;
+            }
+            match dictionary.format_value(avp) {
+                Ok(value) => record.attributes.push(DetailAttribute {
+                    name: match tag_of(avp, attr.get_data_type(), attr.has_tag()) {
+                        Some(tag) => format!("{}:{}", attr.get_name(), tag),
+                        None => attr.get_name().to_owned(),
+                    },
+                    value,
+                    quoted: attr.get_data_type() == AttributeDataType::String,
+                }),
+                Err(_) => record.push_raw(avp),
+            }
+        }
+        record.push(TIMESTAMP_ATTRIBUTE, &timestamp.timestamp().to_string());
+        Ok(record)
+    }
+
+    pub fn get_timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Returns the attributes of the record as the pairs of the name and the (unquoted) value, in order of appearance.
+    pub fn get_attributes(&self) -> Vec<(&str, &str)> {
+        self.attributes
+            .iter()
+            .map(|attr| (attr.name.as_str(), attr.value.as_str()))
+            .collect()
+    }
+
+    /// Returns the value of the first attribute of the name; the name is case-insensitive.
+    pub fn lookup(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attr| attr.name.eq_ignore_ascii_case(name))
+            .map(|attr| attr.value.as_str())
+    }
+
+    /// Add an attribute to the record; the value is quoted on writing unless it is a bare word (e.g. `Start`, `192.0.2.1`).
+    pub fn push(&mut self, name: &str, value: &str) {
+        self.attributes.push(DetailAttribute {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            quoted: !is_bare_word(value),
+        });
+    }
+
+    /// Make an Accounting-Request packet from the record with the dictionary.
+    ///
+    /// The attributes that FreeRADIUS adds on the server side (e.g. `Timestamp`, `Client-IP-Address`) are skipped,
+    /// and `Attr-<type> = 0x<hex>` is put into the packet as it is. The other attributes must be known by the dictionary.
+    pub fn to_packet(&self, dictionary: &Dictionary, secret: &[u8]) -> Result<Packet, DetailError> {
+        let mut packet = Packet::new(Code::AccountingRequest, secret);
+        for attr in &self.attributes {
+            if let Some(avp) = raw_avp(&attr.name, &attr.value) {
+                packet.add(avp);
+                continue;
+            }
+            if SERVER_ATTRIBUTES
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&attr.name))
+                && dictionary.lookup_attribute(&attr.name).is_none()
+            {
+                continue;
+            }
+            packet.add(
+                dictionary
+                    .make_avp(&attr.name, &attr.value)
+                    .map_err(DetailError::AttributeConversionError)?,
+            );
+        }
+        Ok(packet)
+    }
+
+    fn push_raw(&mut self, avp: &AVP) {
+        self.attributes.push(DetailAttribute {
+            name: format!("Attr-{}", avp.type_code()),
+            value: format!("0x{}", encode_hex(avp.raw_value())),
+            quoted: false,
+        });
+    }
+}
+
+/// `DetailReader` reads the records of a detail file one by one, as an iterator.
+///
+/// The blank lines between the records can be omitted; a timestamp line also begins a new record.
+pub struct DetailReader<R> {
+    reader: R,
+    line_number: usize,
+    /// the timestamp line that begins the next record
+    pending_header: Option<String>,
+    is_eof: bool,
+}
+
+impl<R: BufRead> DetailReader<R> {
+    pub fn new(reader: R) -> Self {
+        DetailReader {
+            reader,
+            line_number: 0,
+            pending_header: None,
+            is_eof: false,
+        }
+    }
+
+    /// Returns the next record, or `None` when the file ends.
+    pub fn read_record(&mut self) -> Result<Option<DetailRecord>, DetailError> {
+        let mut record: Option<DetailRecord> = None;
+        loop {
+            let line = match self.pending_header.take() {
+                Some(header) => header,
+                None => match self.read_line()? {
+                    Some(line) => line,
+                    None => return Ok(record),
+                },
+            };
+
+            if line.trim().is_empty() {
+                if record.is_some() {
+                    return Ok(record);
+                }
+                continue;
+            }
+
+            if !line.starts_with(['\t', ' ']) {
+                if record.is_some() {
+                    self.pending_header = Some(line);
+                    return Ok(record);
+                }
+                record = Some(DetailRecord::new(self.parse_timestamp(&line)?));
+                continue;
+            }
+
+            let Some(record) = record.as_mut() else {
+                return Err(self.malformed("an attribute line before the timestamp line"));
+            };
+            let attr = self.parse_attribute(line.trim())?;
+            if attr.name.eq_ignore_ascii_case(TIMESTAMP_ATTRIBUTE) {
+                if let Some(timestamp) = attr
+                    .value
+                    .parse()
+                    .ok()
+                    .and_then(|secs| Utc.timestamp_opt(secs, 0).single())
+                {
+                    record.timestamp = timestamp;
+                }
+            }
+            record.attributes.push(attr);
+        }
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, DetailError> {
+        if self.is_eof {
+            return Ok(None);
+        }
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => {
+                self.is_eof = true;
+                Ok(None)
+            }
+            Ok(_) => {
+                self.line_number += 1;
+                Ok(Some(line.trim_end_matches(['\r', '\n']).to_owned()))
+            }
+            Err(e) => Err(DetailError::ReadingError(e.to_string())),
+        }
+    }
+
+    fn parse_timestamp(&self, line: &str) -> Result<DateTime<Utc>, DetailError> {
+        NaiveDateTime::parse_from_str(line.trim(), TIMESTAMP_FORMAT)
+            .map(|timestamp| timestamp.and_utc())
+            .map_err(|e| self.malformed(&format!("invalid timestamp line {line:?}: {e}")))
+    }
+
+    fn parse_attribute(&self, line: &str) -> Result<DetailAttribute, DetailError> {
+        let Some((name, value)) = line.split_once('=') else {
+            return Err(self.malformed(&format!("`=` is missing: {line:?}")));
+        };
+        let (name, value) = (name.trim(), value.trim());
+        if name.is_empty() {
+            return Err(self.malformed(&format!("attribute name is missing: {line:?}")));
+        }
+
+        let (value, quoted) = match value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+        {
+            Some(quoted) => (
+                unescape(quoted)
+                    .ok_or_else(|| self.malformed(&format!("invalid escape: {line:?}")))?,
+                true,
+            ),
+            None => (value.to_owned(), false),
+        };
+        Ok(DetailAttribute {
+            name: name.to_owned(),
+            value,
+            quoted,
+        })
+    }
+
+    fn malformed(&self, message: &str) -> DetailError {
+        DetailError::MalformedRecordError(self.line_number, message.to_owned())
+    }
+}
+
+impl<R: BufRead> Iterator for DetailReader<R> {
+    type Item = Result<DetailRecord, DetailError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+/// `DetailWriter` writes the records in the detail file format.
+///
+/// Each record is written at once and flushed, so that a record is not interleaved with the others.
+pub struct DetailWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> DetailWriter<W> {
+    pub fn new(writer: W) -> Self {
+        DetailWriter { writer }
+    }
+
+    pub fn write_record(&mut self, record: &DetailRecord) -> Result<(), DetailError> {
+        let mut buf = format!("{}\n", record.timestamp.format(TIMESTAMP_FORMAT));
+        for attr in &record.attributes {
+            if attr.quoted {
+                buf.push_str(&format!("\t{} = \"{}\"\n", attr.name, escape(&attr.value)));
+            } else {
+                buf.push_str(&format!("\t{} = {}\n", attr.name, attr.value));
+            }
+        }
+        buf.push('\n');
+
+        self.writer
+            .write_all(buf.as_bytes())
+            .and_then(|_| self.writer.flush())
+            .map_err(|e| DetailError::WritingError(e.to_string()))
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Returns the tag of the tagged attribute, if that is in use.
+fn tag_of(avp: &AVP, data_type: AttributeDataType, has_tag: bool) -> Option<u8> {
+    if !has_tag {
+        return None;
+    }
+    let tag = match data_type {
+        AttributeDataType::Integer | AttributeDataType::Date => {
+            avp.decode_tagged_u32().ok().map(|(_, tag)| tag)
+        }
+        AttributeDataType::Short => avp.decode_tagged_u16().ok().map(|(_, tag)| tag),
+        AttributeDataType::IpAddr => avp.decode_tagged_ipv4().ok().map(|(_, tag)| tag),
+        AttributeDataType::String => avp.decode_tagged_string().ok().and_then(|(_, tag)| tag),
+        AttributeDataType::Octets => avp.decode_tagged_bytes().ok().and_then(|(_, tag)| tag),
+        _ => None,
+    };
+    tag.filter(|tag| !tag.is_zero()).map(|tag| tag.get_value())
+}
+
+/// Make an AVP of `Attr-<type> = 0x<hex>`.
+fn raw_avp(name: &str, value: &str) -> Option<AVP> {
+    let typ = name.strip_prefix("Attr-")?.parse::<AVPType>().ok()?;
+    let value = decode_hex(value.strip_prefix("0x")?)?;
+    AVP::from_bytes_owned(typ, value).ok()
+}
+
+fn is_bare_word(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | ':' | '-' | '_' | '/'))
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_ascii_control() => escaped.push_str(&format!("\\{:03o}", c as u8)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(value: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => unescaped.push('\n'),
+            'r' => unescaped.push('\r'),
+            't' => unescaped.push('\t'),
+            c @ '0'..='7' => {
+                let octal: String = [Some(c), chars.next(), chars.next()]
+                    .into_iter()
+                    .collect::<Option<String>>()?;
+                unescaped.push(u8::from_str_radix(&octal, 8).ok()? as char);
+            }
+            c => unescaped.push(c),
+        }
+    }
+    Some(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::acct::detail::{DetailError, DetailReader, DetailRecord, DetailWriter};
+    use crate::core::avp::AVP;
+    use crate::core::code::Code;
+    use crate::core::dictionary::Dictionary;
+    use crate::core::packet::Packet;
+    use crate::core::{rfc2865, rfc2866};
+
+    const DETAIL: &str = "Mon Jan  1 00:00:00 2024
+\tAcct-Status-Type = Start
+\tUser-Name = \"alice \\\"the admin\\\"\"
+\tFramed-IP-Address = 192.0.2.10
+\tAcct-Session-Id = \"0001\"
+\tClient-IP-Address = 192.0.2.1
+\tTimestamp = 1704067230
+
+Mon Jan  1 00:10:00 2024
+\tAcct-Status-Type = Stop
+\tUser-Name = \"alice \\\"the admin\\\"\"
+\tAcct-Session-Id = \"0001\"
+\tAcct-Session-Time = 600
+";
+
+    #[test]
+    fn test_read_records() {
+        let records = DetailReader::new(DETAIL.as_bytes())
+            .collect::<Result<Vec<DetailRecord>, DetailError>>()
+            .unwrap();
+        assert_eq!(records.len(), 2);
+
+        // `Timestamp` takes precedence over the timestamp line
+        assert_eq!(
+            records[0].get_timestamp(),
+            Utc.timestamp_opt(1704067230, 0).unwrap()
+        );
+        assert_eq!(records[0].lookup("user-name"), Some("alice \"the admin\""));
+        assert_eq!(
+            records[1].get_timestamp(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 10, 0).unwrap()
+        );
+        assert_eq!(
+            records[1].get_attributes(),
+            vec![
+                ("Acct-Status-Type", "Stop"),
+                ("User-Name", "alice \"the admin\""),
+                ("Acct-Session-Id", "0001"),
+                ("Acct-Session-Time", "600"),
+            ]
+        );
+
+        let packet = records[0]
+            .to_packet(&Dictionary::builtin(), b"secret")
+            .unwrap();
+        assert_eq!(packet.get_code(), Code::AccountingRequest);
+        assert_eq!(
+            rfc2866::lookup_acct_status_type(&packet).unwrap().unwrap(),
+            rfc2866::ACCT_STATUS_TYPE_START
+        );
+        assert_eq!(
+            rfc2865::lookup_framed_ip_address(&packet).unwrap().unwrap(),
+            Ipv4Addr::new(192, 0, 2, 10)
+        );
+        // the attributes on the server side are not put into the packet
+        assert_eq!(packet.get_avps().len(), 4);
+    }
+
+    #[test]
+    fn test_write_and_read_packet() {
+        let dictionary = Dictionary::builtin();
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_status_type(&mut packet, rfc2866::ACCT_STATUS_TYPE_STOP);
+        rfc2865::add_user_name(&mut packet, "bob\tsmith");
+        rfc2866::add_acct_input_octets(&mut packet, 1234);
+        packet.add(AVP::from_bytes(250, &[0xde, 0xad]).unwrap());
+        let timestamp = Utc.with_ymd_and_hms(2024, 3, 9, 12, 34, 56).unwrap();
+
+        let record = DetailRecord::from_packet(&packet, &dictionary, timestamp).unwrap();
+        let mut writer = DetailWriter::new(vec![]);
+        writer.write_record(&record).unwrap();
+        writer.write_record(&record).unwrap();
+        let written = String::from_utf8(writer.into_inner()).unwrap();
+        let expected = "Sat Mar  9 12:34:56 2024
+\tAcct-Status-Type = Stop
+\tUser-Name = \"bob\\tsmith\"
+\tAcct-Input-Octets = 1234
+\tAttr-250 = 0xdead
+\tTimestamp = 1709987696
+
+";
+        assert_eq!(written, expected.repeat(2));
+
+        let records = DetailReader::new(written.as_bytes())
+            .collect::<Result<Vec<DetailRecord>, DetailError>>()
+            .unwrap();
+        assert_eq!(records, vec![record.clone(), record]);
+        let decoded = records[0].to_packet(&dictionary, b"secret").unwrap();
+        assert_eq!(decoded.get_avps(), packet.get_avps());
+    }
+
+    #[test]
+    fn test_malformed_records() {
+        let errors = [
+            ("\tUser-Name = \"alice\"\n", 1),
+            ("Mon Jan  1 00:00:00 2024\n\tUser-Name \"alice\"\n", 2),
+            ("not a timestamp\n", 1),
+        ];
+        for (detail, line_number) in errors {
+            match DetailReader::new(detail.as_bytes()).read_record() {
+                Err(DetailError::MalformedRecordError(n, _)) => assert_eq!(n, line_number),
+                other => panic!("unexpected result for {detail:?}: {other:?}"),
+            }
+        }
+
+        let record = DetailReader::new("Mon Jan  1 00:00:00 2024\n\tUnknown-Attr = 1\n".as_bytes())
+            .read_record()
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            record.to_packet(&Dictionary::builtin(), b"secret"),
+            Err(DetailError::AttributeConversionError(_))
+        ));
+    }
+}
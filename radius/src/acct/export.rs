@@ -0,0 +1,452 @@
+//! Export of Accounting-Request packets as flattened records (e.g. JSON Lines, CSV) for data pipelines.
+//!
+//! Each attribute becomes a field whose name is the attribute name in the dictionaries (e.g. `Acct-Session-Id`).
+//! Vendor-Specific attributes are namespaced by the vendor ID (e.g. `Vendor-9-Attr-1`),
+//! and unknown attributes are named by the AVP type (e.g. `Attr-250`).
+//! Encrypted attributes (e.g. `User-Password`) are never exported.
+
+use std::io::Write;
+
+use thiserror::Error;
+
+use crate::core::avp::AVP;
+use crate::core::code::Code;
+use crate::core::metadata::AttributeDataType;
+use crate::core::packet::Packet;
+use crate::core::registry;
+use crate::core::rfc2865;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ExportError {
+    /// This error is raised when the packet to export is not an Accounting-Request.
+    #[error("unexpected packet code: {0}")]
+    UnexpectedPacketCodeError(String),
+
+    /// This error is raised when a sink fails to write a record.
+    #[error("failed to write a record: {0}")]
+    WritingError(String),
+
+    /// This error is raised when a sink is unavailable (e.g. the connection to the message queue is lost).
+    #[error("sink is unavailable: {0}")]
+    SinkUnavailableError(String),
+
+    /// This error is raised when a buffering sink cannot keep any more records.
+    #[error("buffer of the sink is full; capacity = {0}")]
+    BufferOverflowError(usize),
+}
+
+/// This struct represents a flattened accounting record.
+///
+/// The fields keep the order of appearance in the packet, and the values of the repeated attributes
+/// are gathered into the same field.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExportRecord {
+    fields: Vec<(String, Vec<String>)>,
+}
+
+impl ExportRecord {
+    /// Make a record from an Accounting-Request packet.
+    pub fn from_packet(packet: &Packet) -> Result<Self, ExportError> {
+        if packet.get_code() != Code::AccountingRequest {
+            return Err(ExportError::UnexpectedPacketCodeError(
+                packet.get_code().string().to_owned(),
+            ));
+        }
+
+        let mut record = ExportRecord::default();
+        for avp in packet.get_avps() {
+            if avp.type_code() == rfc2865::VENDOR_SPECIFIC_TYPE {
+                record.push_vendor_specific(avp);
+                continue;
+            }
+
+            match registry::attribute_metadata(avp.type_code()) {
+                Some(metadata) if metadata.encrypted => {}
+                Some(metadata) => record.push(
+                    metadata.name,
+                    format_value(avp, metadata.data_type, metadata.has_tag),
+                ),
+                None => record.push(
+                    &format!("Attr-{}", avp.type_code()),
+                    to_hex(avp.raw_value()),
+                ),
+            }
+        }
+        Ok(record)
+    }
+
+    /// Add a value to the field; this is useful to enrich a record (e.g. with the address of the NAS).
+    pub fn push(&mut self, name: &str, value: String) {
+        match self.fields.iter_mut().find(|(field, _)| field == name) {
+            Some((_, values)) => values.push(value),
+            None => self.fields.push((name.to_owned(), vec![value])),
+        }
+    }
+
+    /// Returns the values of the field.
+    pub fn get(&self, name: &str) -> Option<&[String]> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, values)| values.as_slice())
+    }
+
+    /// Returns the fields of the record in order of appearance.
+    pub fn get_fields(&self) -> &[(String, Vec<String>)] {
+        &self.fields
+    }
+
+    /// Returns the record as a JSON object.
+    ///
+    /// A field that has a single value is a string, and a field that has multiple values is an array of strings.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        for (i, (name, values)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str(&json_string(name));
+            json.push(':');
+            if values.len() == 1 {
+                json.push_str(&json_string(&values[0]));
+            } else {
+                let values = values
+                    .iter()
+                    .map(|value| json_string(value))
+                    .collect::<Vec<_>>();
+                json.push_str(&format!("[{}]", values.join(",")));
+            }
+        }
+        json.push('}');
+        json
+    }
+
+    fn push_vendor_specific(&mut self, avp: &AVP) {
+        let value = avp.raw_value();
+        if value.len() < 4 {
+            self.push("Vendor-Specific", to_hex(value));
+            return;
+        }
+        let vendor_id = u32::from_be_bytes([value[0], value[1], value[2], value[3]]);
+
+        // vendor attributes are expected to be the TLV format of RFC 2865 section 5.26;
+        // otherwise the whole value is exported as is
+        let mut sub_attrs = Vec::new();
+        let mut i = 4;
+        while i < value.len() {
+            let rest = &value[i..];
+            if rest.len() < 2 || rest[1] < 2 || rest[1] as usize > rest.len() {
+                self.push(&format!("Vendor-{}", vendor_id), to_hex(&value[4..]));
+                return;
+            }
+            let length = rest[1] as usize;
+            sub_attrs.push((rest[0], &rest[2..length]));
+            i += length;
+        }
+
+        for (vendor_type, value) in sub_attrs {
+            self.push(
+                &format!("Vendor-{}-Attr-{}", vendor_id, vendor_type),
+                to_hex(value),
+            );
+        }
+    }
+}
+
+fn format_value(avp: &AVP, data_type: AttributeDataType, has_tag: bool) -> String {
+    let formatted = match data_type {
+        AttributeDataType::String if has_tag => {
+            avp.decode_tagged_string().map(|(value, _)| value).ok()
+        }
+        AttributeDataType::String => avp.decode_string().ok(),
+        AttributeDataType::Integer if has_tag => avp
+            .decode_tagged_u32()
+            .map(|(value, _)| value.to_string())
+            .ok(),
+        AttributeDataType::Integer => avp.decode_u32().map(|value| value.to_string()).ok(),
+        AttributeDataType::Signed => avp.decode_i32().map(|value| value.to_string()).ok(),
+        AttributeDataType::Short if has_tag => avp
+            .decode_tagged_u16()
+            .map(|(value, _)| value.to_string())
+            .ok(),
+        AttributeDataType::Short => avp.decode_u16().map(|value| value.to_string()).ok(),
+        AttributeDataType::IpAddr if has_tag => avp
+            .decode_tagged_ipv4()
+            .map(|(value, _)| value.to_string())
+            .ok(),
+        AttributeDataType::IpAddr => avp.decode_ipv4().map(|value| value.to_string()).ok(),
+        AttributeDataType::Ipv6Addr => avp.decode_ipv6().map(|value| value.to_string()).ok(),
+        AttributeDataType::ComboIp => avp.decode_combo_ip().map(|value| value.to_string()).ok(),
+        AttributeDataType::Date if has_tag => avp
+            .decode_tagged_date()
+            .map(|(value, _)| value.to_rfc3339())
+            .ok(),
+        AttributeDataType::Date => avp.decode_date().map(|value| value.to_rfc3339()).ok(),
+        _ => None,
+    };
+    formatted.unwrap_or_else(|| to_hex(avp.raw_value()))
+}
+
+fn to_hex(bs: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + bs.len() * 2);
+    hex.push_str("0x");
+    for b in bs {
+        hex.push_str(&format!("{:02x}", b));
+    }
+    hex
+}
+
+/// ExportSink is a destination of the exported records.
+///
+/// This is the extension point to pipe the records into arbitrary storages (e.g. files, message queues).
+pub trait ExportSink {
+    /// Write a record to the sink.
+    fn write_record(&mut self, record: &ExportRecord) -> Result<(), ExportError>;
+
+    /// Flush the buffered records if the sink has them.
+    fn flush(&mut self) -> Result<(), ExportError> {
+        Ok(())
+    }
+
+    /// Convert an Accounting-Request packet into a record and write that to the sink.
+    fn export(&mut self, packet: &Packet) -> Result<(), ExportError> {
+        self.write_record(&ExportRecord::from_packet(packet)?)
+    }
+}
+
+/// The sink that writes a record as a JSON object per line (a.k.a. JSON Lines); see `ExportRecord::to_json()`.
+pub struct JsonLinesSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesSink<W> {
+    pub fn new(writer: W) -> Self {
+        JsonLinesSink { writer }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> ExportSink for JsonLinesSink<W> {
+    fn write_record(&mut self, record: &ExportRecord) -> Result<(), ExportError> {
+        let mut line = record.to_json();
+        line.push('\n');
+
+        self.writer
+            .write_all(line.as_bytes())
+            .map_err(|e| ExportError::WritingError(e.to_string()))
+    }
+
+    fn flush(&mut self) -> Result<(), ExportError> {
+        self.writer
+            .flush()
+            .map_err(|e| ExportError::WritingError(e.to_string()))
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// The sink that writes records as CSV with the given columns.
+///
+/// The header line is written before the first record. The fields that are not in the columns are dropped,
+/// and the multiple values of a field are joined by `separator` (default: `;`).
+pub struct CsvSink<W: Write> {
+    writer: W,
+    columns: Vec<String>,
+    separator: String,
+    header_written: bool,
+}
+
+impl<W: Write> CsvSink<W> {
+    pub fn new(writer: W, columns: Vec<String>) -> Self {
+        CsvSink {
+            writer,
+            columns,
+            separator: ";".to_owned(),
+            header_written: false,
+        }
+    }
+
+    /// Set the separator to join the multiple values of a field.
+    pub fn set_separator(&mut self, separator: &str) {
+        self.separator = separator.to_owned();
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_line(&mut self, cells: Vec<String>) -> Result<(), ExportError> {
+        let line = cells
+            .iter()
+            .map(|cell| csv_cell(cell))
+            .collect::<Vec<_>>()
+            .join(",");
+        self.writer
+            .write_all(format!("{}\n", line).as_bytes())
+            .map_err(|e| ExportError::WritingError(e.to_string()))
+    }
+}
+
+impl<W: Write> ExportSink for CsvSink<W> {
+    fn write_record(&mut self, record: &ExportRecord) -> Result<(), ExportError> {
+        if !self.header_written {
+            self.write_line(self.columns.clone())?;
+            self.header_written = true;
+        }
+
+        let cells = self
+            .columns
+            .iter()
+            .map(|column| {
+                record
+                    .get(column)
+                    .map(|values| values.join(&self.separator))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        self.write_line(cells)
+    }
+
+    fn flush(&mut self) -> Result<(), ExportError> {
+        self.writer
+            .flush()
+            .map_err(|e| ExportError::WritingError(e.to_string()))
+    }
+}
+
+fn csv_cell(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::acct::export::{CsvSink, ExportError, ExportRecord, ExportSink, JsonLinesSink};
+    use crate::core::avp::AVP;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::{rfc2865, rfc2866};
+
+    fn make_accounting_request() -> Packet {
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_status_type(&mut packet, rfc2866::ACCT_STATUS_TYPE_START);
+        rfc2866::add_acct_session_id(&mut packet, "session, \"1\"");
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2865::add_user_password(&mut packet, b"password").unwrap();
+        rfc2865::add_nas_ip_address(&mut packet, &Ipv4Addr::new(192, 0, 2, 1));
+        rfc2865::add_class(&mut packet, b"\x01\x02").unwrap();
+        rfc2865::add_class(&mut packet, b"\x03").unwrap();
+        packet.add(
+            AVP::from_bytes(
+                rfc2865::VENDOR_SPECIFIC_TYPE,
+                &[0x00, 0x00, 0x00, 0x09, 0x01, 0x05, b'a', b'b', b'c'],
+            )
+            .unwrap(),
+        );
+        packet.add(AVP::from_bytes(250, &[0xff]).unwrap());
+        packet
+    }
+
+    #[test]
+    fn test_record_from_packet() {
+        let record = ExportRecord::from_packet(&make_accounting_request()).unwrap();
+
+        let names = record
+            .get_fields()
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "Acct-Status-Type",
+                "Acct-Session-Id",
+                "User-Name",
+                "NAS-IP-Address",
+                "Class",
+                "Vendor-9-Attr-1",
+                "Attr-250",
+            ]
+        );
+        assert_eq!(record.get("Acct-Status-Type"), Some(&["1".to_owned()][..]));
+        assert_eq!(
+            record.get("NAS-IP-Address"),
+            Some(&["192.0.2.1".to_owned()][..])
+        );
+        assert_eq!(
+            record.get("Class"),
+            Some(&["0x0102".to_owned(), "0x03".to_owned()][..])
+        );
+        assert_eq!(
+            record.get("Vendor-9-Attr-1"),
+            Some(&["0x616263".to_owned()][..])
+        );
+        assert_eq!(record.get("User-Password"), None);
+
+        assert_eq!(
+            ExportRecord::from_packet(&Packet::new(Code::AccessRequest, b"secret")),
+            Err(ExportError::UnexpectedPacketCodeError(
+                "Access-Request".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_json_lines_sink() {
+        let mut sink = JsonLinesSink::new(vec![]);
+        sink.export(&make_accounting_request()).unwrap();
+        sink.export(&make_accounting_request()).unwrap();
+
+        let written = String::from_utf8(sink.into_inner()).unwrap();
+        let lines = written.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            r#"{"Acct-Status-Type":"1","Acct-Session-Id":"session, \"1\"","User-Name":"alice","NAS-IP-Address":"192.0.2.1","Class":["0x0102","0x03"],"Vendor-9-Attr-1":"0x616263","Attr-250":"0xff"}"#
+        );
+    }
+
+    #[test]
+    fn test_csv_sink() {
+        let mut sink = CsvSink::new(
+            vec![],
+            vec![
+                "Acct-Session-Id".to_owned(),
+                "User-Name".to_owned(),
+                "Class".to_owned(),
+                "Acct-Input-Octets".to_owned(),
+            ],
+        );
+        sink.export(&make_accounting_request()).unwrap();
+
+        let written = String::from_utf8(sink.into_inner()).unwrap();
+        assert_eq!(
+            written,
+            "Acct-Session-Id,User-Name,Class,Acct-Input-Octets\n\"session, \"\"1\"\"\",alice,0x0102;0x03,\n"
+        );
+    }
+}
@@ -0,0 +1,265 @@
+//! Fan-out of the accounting requests, that mirrors each request to the multiple upstreams
+//! (e.g. the primary accounting server and an analytics collector) simultaneously.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use thiserror::Error;
+use tokio::task::JoinSet;
+
+use crate::client::{Client, ClientError};
+use crate::core::packet::Packet;
+use crate::retry::RetryPolicy;
+
+#[derive(Error, Debug)]
+pub enum FanOutError {
+    /// This error is raised when the responses don't satisfy the success policy; this has the failures of the targets.
+    #[error("failed to deliver the request to {}", format_failures(.0))]
+    DeliveryFailedError(Vec<(SocketAddr, ClientError)>),
+
+    /// This error is raised when no target is configured.
+    #[error("no fan-out target")]
+    NoTargetError(),
+}
+
+/// This enum represents the policy of whether the fan-out is successful.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SuccessPolicy {
+    /// All of the targets must respond.
+    #[default]
+    All,
+    /// At least one of the targets must respond.
+    Any,
+}
+
+/// This struct represents an upstream of the fan-out with its own retransmission policy.
+#[derive(Clone)]
+pub struct FanOutTarget {
+    remote_addr: SocketAddr,
+    retry_policy: Arc<dyn RetryPolicy>,
+}
+
+impl FanOutTarget {
+    pub fn new(remote_addr: SocketAddr, retry_policy: Arc<dyn RetryPolicy>) -> Self {
+        FanOutTarget {
+            remote_addr,
+            retry_policy,
+        }
+    }
+
+    pub fn get_remote_addr(&self) -> SocketAddr {
+        self.remote_addr
+    }
+}
+
+/// This struct represents the responses of the fan-out.
+///
+/// With `SuccessPolicy::Any`, this can have the failures of some targets.
+#[derive(Debug)]
+pub struct FanOutResponses {
+    responses: Vec<(SocketAddr, Packet)>,
+    failures: Vec<(SocketAddr, ClientError)>,
+}
+
+impl FanOutResponses {
+    /// Returns the responses in order of the targets.
+    pub fn get_responses(&self) -> &[(SocketAddr, Packet)] {
+        &self.responses
+    }
+
+    /// Returns the failures in order of the targets.
+    pub fn get_failures(&self) -> &[(SocketAddr, ClientError)] {
+        &self.failures
+    }
+}
+
+/// `FanOutClient` sends a request to all of the targets simultaneously.
+///
+/// Each target is retransmitted independently according to its own retry policy,
+/// so a slow or unreachable target doesn't delay the retransmissions to the others.
+pub struct FanOutClient {
+    client: Arc<Client>,
+    targets: Vec<FanOutTarget>,
+    success_policy: SuccessPolicy,
+}
+
+impl FanOutClient {
+    pub fn new(client: Client, targets: Vec<FanOutTarget>, success_policy: SuccessPolicy) -> Self {
+        FanOutClient {
+            client: Arc::new(client),
+            targets,
+            success_policy,
+        }
+    }
+
+    pub fn get_targets(&self) -> &[FanOutTarget] {
+        &self.targets
+    }
+
+    /// This method sends the request to all of the targets, and waits for all of them to respond or give up.
+    ///
+    /// If the responses don't satisfy the success policy, the `DeliveryFailedError` occurs.
+    pub async fn send_packet(
+        &self,
+        request_packet: &Packet,
+    ) -> Result<FanOutResponses, FanOutError> {
+        if self.targets.is_empty() {
+            return Err(FanOutError::NoTargetError());
+        }
+
+        let mut tasks = JoinSet::new();
+        for (i, target) in self.targets.iter().enumerate() {
+            let client = self.client.clone();
+            let target = target.clone();
+            let request_packet = request_packet.clone();
+            tasks.spawn(async move {
+                let result = client
+                    .send_packet_with_retry(
+                        &target.remote_addr,
+                        &request_packet,
+                        target.retry_policy.as_ref(),
+                    )
+                    .await;
+                (i, result)
+            });
+        }
+
+        let mut results = Vec::with_capacity(self.targets.len());
+        while let Some(joined) = tasks.join_next().await {
+            // the task panics only when the client does, so propagates that as it is
+            results.push(joined.unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic())));
+        }
+        results.sort_by_key(|(i, _)| *i);
+
+        let mut responses = FanOutResponses {
+            responses: vec![],
+            failures: vec![],
+        };
+        for (i, result) in results {
+            let remote_addr = self.targets[i].remote_addr;
+            match result {
+                Ok(response) => responses.responses.push((remote_addr, response)),
+                Err(e) => {
+                    warn!("failed to send a request to {}; {}", remote_addr, e);
+                    responses.failures.push((remote_addr, e));
+                }
+            }
+        }
+
+        let is_successful = match self.success_policy {
+            SuccessPolicy::All => responses.failures.is_empty(),
+            SuccessPolicy::Any => !responses.responses.is_empty(),
+        };
+        if !is_successful {
+            return Err(FanOutError::DeliveryFailedError(responses.failures));
+        }
+        Ok(responses)
+    }
+}
+
+fn format_failures(failures: &[(SocketAddr, ClientError)]) -> String {
+    failures
+        .iter()
+        .map(|(remote_addr, e)| format!("{remote_addr} ({e})"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::net::UdpSocket;
+
+    use crate::acct::fanout::{FanOutClient, FanOutError, FanOutTarget, SuccessPolicy};
+    use crate::client::{Client, ClientError};
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::retry::ExponentialBackoffRetryPolicy;
+
+    /// Spawn a server that responds to the requests; when `drop_first` is true, it drops the first transmission.
+    async fn spawn_server(respond: bool, drop_first: bool) -> SocketAddr {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            let mut received = 0;
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                received += 1;
+                if !respond || (drop_first && received == 1) {
+                    continue;
+                }
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                let response = request.make_response_packet(Code::AccountingResponse);
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+        server_addr
+    }
+
+    fn target(remote_addr: SocketAddr, max_attempts: usize) -> FanOutTarget {
+        FanOutTarget::new(
+            remote_addr,
+            Arc::new(ExponentialBackoffRetryPolicy {
+                initial_timeout: Duration::from_millis(100),
+                multiplier: 1.0,
+                max_timeout: Duration::from_millis(100),
+                max_attempts,
+                max_duration: None,
+                jitter: 0.0,
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fan_out() {
+        let primary = spawn_server(true, false).await;
+        // the collector needs the retransmission, which doesn't affect the primary
+        let collector = spawn_server(true, true).await;
+        let unreachable = spawn_server(false, false).await;
+        let request = Packet::new(Code::AccountingRequest, b"secret");
+
+        let client = FanOutClient::new(
+            Client::new(None, None),
+            vec![target(primary, 1), target(collector, 2)],
+            SuccessPolicy::All,
+        );
+        let responses = client.send_packet(&request).await.unwrap();
+        assert_eq!(
+            responses
+                .get_responses()
+                .iter()
+                .map(|(addr, response)| (*addr, response.get_code()))
+                .collect::<Vec<(SocketAddr, Code)>>(),
+            vec![
+                (primary, Code::AccountingResponse),
+                (collector, Code::AccountingResponse)
+            ]
+        );
+
+        let targets = vec![target(primary, 1), target(unreachable, 1)];
+        let client =
+            FanOutClient::new(Client::new(None, None), targets.clone(), SuccessPolicy::Any);
+        let responses = client.send_packet(&request).await.unwrap();
+        assert_eq!(responses.get_responses().len(), 1);
+        assert!(matches!(
+            responses.get_failures(),
+            [(addr, ClientError::SocketTimeoutError())] if *addr == unreachable
+        ));
+
+        let client = FanOutClient::new(Client::new(None, None), targets, SuccessPolicy::All);
+        match client.send_packet(&request).await {
+            Err(FanOutError::DeliveryFailedError(failures)) => {
+                assert_eq!(failures.len(), 1);
+                assert_eq!(failures[0].0, unreachable);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
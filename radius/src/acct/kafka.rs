@@ -0,0 +1,112 @@
+//! `Sink` of a Kafka producer, so that the accounting records are streamed into a Kafka topic.
+//!
+//! This module is available with the `acct-sink-kafka` feature.
+//!
+//! A record is produced to a partition of the topic as a JSON object (see `ExportRecord::to_json()`),
+//! keyed by `Acct-Session-Id` if the record has that. Wrap the sink in `acct::sink::BufferedSink` to keep the records
+//! while the brokers are unreachable.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rskafka::client::partition::{Compression, PartitionClient, UnknownTopicHandling};
+use rskafka::client::ClientBuilder;
+use rskafka::record::Record;
+
+use crate::acct::export::{ExportError, ExportRecord};
+use crate::acct::sink::Sink;
+
+/// The field of the record that is the key of the Kafka record.
+pub const KEY_FIELD: &str = "Acct-Session-Id";
+
+/// A `Sink` that produces the records to a partition of a Kafka topic.
+pub struct KafkaSink {
+    partition_client: PartitionClient,
+    compression: Compression,
+}
+
+impl KafkaSink {
+    /// A constructor of the sink on the client of a topic partition.
+    pub fn new(partition_client: PartitionClient) -> Self {
+        KafkaSink {
+            partition_client,
+            compression: Compression::default(),
+        }
+    }
+
+    /// Connect to the Kafka cluster by the bootstrap brokers (e.g. `kafka.example.com:9092`), and make a sink of the topic partition.
+    pub async fn connect(
+        bootstrap_brokers: Vec<String>,
+        topic: &str,
+        partition: i32,
+    ) -> Result<Self, ExportError> {
+        let client = ClientBuilder::new(bootstrap_brokers)
+            .build()
+            .await
+            .map_err(|e| ExportError::SinkUnavailableError(e.to_string()))?;
+        let partition_client = client
+            .partition_client(topic, partition, UnknownTopicHandling::Retry)
+            .await
+            .map_err(|e| ExportError::SinkUnavailableError(e.to_string()))?;
+        Ok(Self::new(partition_client))
+    }
+
+    /// Set a compression of the produced records (default: `Compression::NoCompression`).
+    pub fn set_compression(&mut self, compression: Compression) {
+        self.compression = compression;
+    }
+
+    pub fn get_partition_client(&self) -> &PartitionClient {
+        &self.partition_client
+    }
+}
+
+#[async_trait]
+impl Sink for KafkaSink {
+    async fn send(&self, record: ExportRecord) -> Result<(), ExportError> {
+        self.partition_client
+            .produce(vec![to_kafka_record(&record, Utc::now())], self.compression)
+            .await
+            .map(|_| ())
+            .map_err(|e| ExportError::SinkUnavailableError(e.to_string()))
+    }
+}
+
+fn to_kafka_record(record: &ExportRecord, timestamp: DateTime<Utc>) -> Record {
+    Record {
+        key: record
+            .get(KEY_FIELD)
+            .and_then(|values| values.first())
+            .map(|key| key.as_bytes().to_vec()),
+        value: Some(record.to_json().into_bytes()),
+        headers: Default::default(),
+        timestamp,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::acct::export::ExportRecord;
+    use crate::acct::kafka::to_kafka_record;
+
+    #[test]
+    fn test_to_kafka_record() {
+        let now = Utc::now();
+        let mut record = ExportRecord::default();
+        record.push("User-Name", "alice".to_owned());
+        record.push("Acct-Session-Id", "session-1".to_owned());
+
+        let kafka_record = to_kafka_record(&record, now);
+        assert_eq!(kafka_record.key, Some(b"session-1".to_vec()));
+        assert_eq!(
+            kafka_record.value,
+            Some(br#"{"User-Name":"alice","Acct-Session-Id":"session-1"}"#.to_vec())
+        );
+        assert_eq!(kafka_record.timestamp, now);
+
+        let kafka_record = to_kafka_record(&ExportRecord::default(), now);
+        assert_eq!(kafka_record.key, None);
+        assert_eq!(kafka_record.value, Some(b"{}".to_vec()));
+    }
+}
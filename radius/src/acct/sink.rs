@@ -0,0 +1,342 @@
+//! Accounting handler that pushes the accounting records to an asynchronous sink (e.g. a message queue).
+//!
+//! This module is available with the `acct-sink` feature.
+//!
+//! An Accounting-Response is sent only after a record is accepted by the sink, so the NAS retransmits the request
+//! while the sink is down. `BufferedSink` keeps the records that cannot be delivered and retries them in order,
+//! so that the records are delivered at least once while the process is running.
+//!
+//! With the `acct-sink-kafka` feature, `acct::kafka::KafkaSink` produces the records to a Kafka topic.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::net::UdpSocket;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::acct::export::{ExportError, ExportRecord};
+use crate::acct::{send_accounting_response, AcctError};
+use crate::clock::{self, Clock};
+use crate::core::request::Request;
+use crate::server::RequestHandler;
+
+/// The name of the field that has the source address of the accounting request.
+pub const PACKET_SRC_IP_ADDRESS_FIELD: &str = "Packet-Src-IP-Address";
+
+/// Sink is an asynchronous destination of the accounting records.
+///
+/// Implement this for a message queue producer (e.g. Kafka, NATS) to stream the accounting records into that.
+/// `send` must return `Ok` only when the record has been accepted by the destination.
+#[async_trait]
+pub trait Sink: 'static + Sync + Send {
+    async fn send(&self, record: ExportRecord) -> Result<(), ExportError>;
+}
+
+/// The sink that sends the records to a `tokio::sync::mpsc` channel.
+pub struct MpscSink {
+    sender: mpsc::Sender<ExportRecord>,
+}
+
+impl MpscSink {
+    pub fn new(sender: mpsc::Sender<ExportRecord>) -> Self {
+        MpscSink { sender }
+    }
+}
+
+#[async_trait]
+impl Sink for MpscSink {
+    async fn send(&self, record: ExportRecord) -> Result<(), ExportError> {
+        self.sender
+            .send(record)
+            .await
+            .map_err(|_| ExportError::SinkUnavailableError("channel is closed".to_owned()))
+    }
+}
+
+/// The sink that buffers the records while the inner sink is down.
+///
+/// A record is buffered when the inner sink fails, and the buffered records are sent in order before the next record,
+/// or by `flush()`; `start_flush_task()` flushes those periodically while no record comes.
+/// When the buffer is full, the new record is rejected with `BufferOverflowError`.
+///
+/// The buffer is on memory and the buffered records have been acknowledged to the NAS,
+/// so those are lost if the process stops before they are flushed. Use `acct::spool::SpoolingClient` to forward
+/// the Accounting-Requests through a disk spool instead if that window is not acceptable.
+pub struct BufferedSink<S: Sink> {
+    inner: S,
+    capacity: usize,
+    buffer: Mutex<VecDeque<ExportRecord>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<S: Sink> BufferedSink<S> {
+    /// Make a sink that buffers up to `capacity` records.
+    pub fn new(inner: S, capacity: usize) -> Self {
+        BufferedSink {
+            inner,
+            capacity,
+            buffer: Mutex::new(VecDeque::new()),
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set a clock of the flush task (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Returns the number of the buffered records.
+    pub async fn get_buffered_len(&self) -> usize {
+        self.buffer.lock().await.len()
+    }
+
+    /// Send the buffered records to the inner sink in order.
+    ///
+    /// This stops at the first failure and keeps the rest of the records.
+    pub async fn flush(&self) -> Result<(), ExportError> {
+        let mut buffer = self.buffer.lock().await;
+        Self::drain(&self.inner, &mut buffer).await
+    }
+
+    /// Start a task that flushes the buffered records at the interval.
+    ///
+    /// The task refers to the sink weakly, so it ends when the sink is dropped.
+    pub fn start_flush_task(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let sink = Arc::downgrade(self);
+        let clock = self.clock.clone();
+        tokio::spawn(async move {
+            loop {
+                clock.sleep(interval).await;
+                let sink = match sink.upgrade() {
+                    Some(sink) => sink,
+                    None => return,
+                };
+                if sink.get_buffered_len().await == 0 {
+                    continue;
+                }
+                if let Err(e) = sink.flush().await {
+                    warn!(
+                        "sink is still unavailable; {} records are buffered: {}",
+                        sink.get_buffered_len().await,
+                        e
+                    );
+                }
+            }
+        })
+    }
+
+    async fn drain(inner: &S, buffer: &mut VecDeque<ExportRecord>) -> Result<(), ExportError> {
+        while let Some(record) = buffer.front() {
+            inner.send(record.clone()).await?;
+            buffer.pop_front();
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: Sink> Sink for BufferedSink<S> {
+    async fn send(&self, record: ExportRecord) -> Result<(), ExportError> {
+        let mut buffer = self.buffer.lock().await;
+        if buffer.len() >= self.capacity {
+            // retry the buffered records to make room for the new one
+            if Self::drain(&self.inner, &mut buffer).await.is_err() {
+                return Err(ExportError::BufferOverflowError(self.capacity));
+            }
+        }
+        buffer.push_back(record);
+
+        if let Err(e) = Self::drain(&self.inner, &mut buffer).await {
+            warn!(
+                "sink is unavailable; buffered {} records: {}",
+                buffer.len(),
+                e
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The accounting handler that converts an Accounting-Request into an `ExportRecord` and pushes that to the sink.
+///
+/// The record has `Packet-Src-IP-Address` field in addition to the attributes of the request.
+pub struct SinkAccountingHandler<S: Sink> {
+    sink: Arc<S>,
+}
+
+impl<S: Sink> SinkAccountingHandler<S> {
+    pub fn new(sink: Arc<S>) -> Self {
+        SinkAccountingHandler { sink }
+    }
+
+    pub fn get_sink(&self) -> &Arc<S> {
+        &self.sink
+    }
+
+    /// Push the accounting request to the sink.
+    pub async fn record(&self, request: &Request) -> Result<(), AcctError> {
+        let mut record = ExportRecord::from_packet(request.get_packet())
+            .map_err(AcctError::RecordExportingError)?;
+        record.push(
+            PACKET_SRC_IP_ADDRESS_FIELD,
+            request.get_remote_addr().ip().to_string(),
+        );
+        self.sink
+            .send(record)
+            .await
+            .map_err(AcctError::RecordExportingError)
+    }
+}
+
+#[async_trait]
+impl<S: Sink> RequestHandler<(), AcctError> for SinkAccountingHandler<S> {
+    async fn handle_radius_request(
+        &self,
+        conn: &UdpSocket,
+        request: &Request,
+    ) -> Result<(), AcctError> {
+        self.record(request).await?;
+        send_accounting_response(conn, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use tokio::sync::{mpsc, Mutex};
+
+    use crate::acct::export::{ExportError, ExportRecord};
+    use crate::acct::sink::{
+        BufferedSink, MpscSink, Sink, SinkAccountingHandler, PACKET_SRC_IP_ADDRESS_FIELD,
+    };
+    use crate::acct::AcctError;
+    use crate::clock::MockClock;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::rfc2866;
+
+    struct FlakySink {
+        is_down: AtomicBool,
+        received: Mutex<Vec<ExportRecord>>,
+    }
+
+    #[async_trait]
+    impl Sink for FlakySink {
+        async fn send(&self, record: ExportRecord) -> Result<(), ExportError> {
+            if self.is_down.load(Ordering::SeqCst) {
+                return Err(ExportError::SinkUnavailableError("down".to_owned()));
+            }
+            self.received.lock().await.push(record);
+            Ok(())
+        }
+    }
+
+    fn make_record(session_id: &str) -> ExportRecord {
+        let mut record = ExportRecord::default();
+        record.push("Acct-Session-Id", session_id.to_owned());
+        record
+    }
+
+    #[tokio::test]
+    async fn test_buffered_sink() {
+        let sink = BufferedSink::new(
+            FlakySink {
+                is_down: AtomicBool::new(true),
+                received: Mutex::new(vec![]),
+            },
+            2,
+        );
+
+        sink.send(make_record("1")).await.unwrap();
+        sink.send(make_record("2")).await.unwrap();
+        assert_eq!(sink.get_buffered_len().await, 2);
+        assert_eq!(
+            sink.send(make_record("3")).await,
+            Err(ExportError::BufferOverflowError(2))
+        );
+
+        sink.inner.is_down.store(false, Ordering::SeqCst);
+        sink.send(make_record("4")).await.unwrap();
+        assert_eq!(sink.get_buffered_len().await, 0);
+        assert_eq!(
+            *sink.inner.received.lock().await,
+            vec![make_record("1"), make_record("2"), make_record("4")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_buffered_sink_flush_task() {
+        let clock = MockClock::default();
+        let mut sink = BufferedSink::new(
+            FlakySink {
+                is_down: AtomicBool::new(true),
+                received: Mutex::new(vec![]),
+            },
+            2,
+        );
+        sink.set_clock(Arc::new(clock.clone()));
+        let sink = Arc::new(sink);
+        let flush_task = sink.start_flush_task(Duration::from_secs(10));
+
+        sink.send(make_record("1")).await.unwrap();
+        assert_eq!(sink.get_buffered_len().await, 1);
+
+        // the buffered record is flushed without the next record
+        sink.inner.is_down.store(false, Ordering::SeqCst);
+        while clock.get_pending_sleeps_len() == 0 {
+            tokio::task::yield_now().await;
+        }
+        clock.advance(Duration::from_secs(10));
+        while sink.get_buffered_len().await > 0 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(*sink.inner.received.lock().await, vec![make_record("1")]);
+
+        // the task ends when the sink is dropped
+        while clock.get_pending_sleeps_len() == 0 {
+            tokio::task::yield_now().await;
+        }
+        drop(sink);
+        clock.advance(Duration::from_secs(10));
+        flush_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sink_accounting_handler() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let handler = SinkAccountingHandler::new(Arc::new(MpscSink::new(sender)));
+
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_session_id(&mut packet, "session-1");
+        let remote_addr: SocketAddr = "192.0.2.1:1813".parse().unwrap();
+        let request = Request::new("127.0.0.1:1813".parse().unwrap(), remote_addr, packet);
+
+        handler.record(&request).await.unwrap();
+        let record = receiver.recv().await.unwrap();
+        assert_eq!(
+            record.get("Acct-Session-Id"),
+            Some(&["session-1".to_owned()][..])
+        );
+        assert_eq!(
+            record.get(PACKET_SRC_IP_ADDRESS_FIELD),
+            Some(&["192.0.2.1".to_owned()][..])
+        );
+
+        drop(receiver);
+        assert_eq!(
+            handler.record(&request).await,
+            Err(AcctError::RecordExportingError(
+                ExportError::SinkUnavailableError("channel is closed".to_owned())
+            ))
+        );
+    }
+}
@@ -0,0 +1,767 @@
+//! Disk spool of the Accounting-Request packets for the accounting client, that keeps the packets while the upstream
+//! is unreachable and replays them in order when the connectivity returns.
+//!
+//! The spool file is a sequence of the entries; each entry is the spooled time (the UNIX time as 8 bytes big-endian)
+//! followed by the encoded packet, which is delimited by the Length field of the header.
+//! On replaying, `Acct-Delay-Time` of the packet is increased by the time that the packet has been spooled
+//! (see also: https://tools.ietf.org/html/rfc2866#section-5.2).
+//!
+//! The replaying is at-least-once; if the process dies while replaying, the packets that have been sent since the last
+//! compaction of the spool file are sent again.
+//! A packet that the upstream keeps failing (e.g. with a malformed response) is given up after the max replay attempts,
+//! and moved to the dead-letter spool if that is set, so that it doesn't block the following packets.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use crate::client::{Client, ClientError};
+use crate::core::code::Code;
+use crate::core::packet::{Packet, RADIUS_PACKET_HEADER_LENGTH};
+use crate::core::rfc2866;
+
+const TIMESTAMP_LENGTH: usize = 8;
+const DEFAULT_MAX_REPLAY_ATTEMPTS: usize = 3;
+
+#[derive(Error, Debug)]
+pub enum SpoolError {
+    /// This error is raised when it failed to read or write the spool file.
+    #[error("failed to access the spool file {0}; {1}")]
+    SpoolFileError(String, String),
+
+    /// This error is raised when the spool doesn't have room for the packet.
+    #[error("spool is full; max size = {0} bytes")]
+    SpoolFullError(u64),
+
+    /// This error is raised when the packet to spool is not an Accounting-Request or cannot be encoded.
+    #[error("failed to spool the packet; {0}")]
+    InvalidPacketError(String),
+
+    /// This error is raised when it failed to send a packet for the reason other than the unreachable upstream
+    /// (e.g. the response cannot be decoded).
+    #[error("failed to send an accounting request; {0}")]
+    SendingError(ClientError),
+}
+
+/// This enum represents the policy of `fsync` on spooling the packets.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum FsyncPolicy {
+    /// Calls `fsync` for each packet; no packet is lost on the power loss, but it is the slowest.
+    #[default]
+    Always,
+    /// Calls `fsync` once per the given number of the packets.
+    Batched(usize),
+    /// Leaves the flushing to the OS.
+    Never,
+}
+
+struct SpooledPacket {
+    spooled_at: u64,
+    encoded: Vec<u8>,
+    // the number of the failed replays since the spool is opened
+    attempts: usize,
+}
+
+impl SpooledPacket {
+    fn size(&self) -> u64 {
+        (TIMESTAMP_LENGTH + self.encoded.len()) as u64
+    }
+}
+
+/// `DiskSpool` is a bounded FIFO of the encoded Accounting-Request packets on a file.
+pub struct DiskSpool {
+    path: PathBuf,
+    file: File,
+    max_size: u64,
+    fsync_policy: FsyncPolicy,
+    entries: VecDeque<SpooledPacket>,
+    size: u64,
+    unsynced: usize,
+}
+
+impl DiskSpool {
+    /// Open the spool file, or create it if it doesn't exist.
+    ///
+    /// The packets that are left in the file are loaded to be replayed; a partially written entry at the end of the file
+    /// (e.g. by a crash) is discarded.
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        max_size: u64,
+        fsync_policy: FsyncPolicy,
+    ) -> Result<Self, SpoolError> {
+        let path = path.as_ref().to_path_buf();
+        let file_error = |e: std::io::Error| {
+            SpoolError::SpoolFileError(path.display().to_string(), e.to_string())
+        };
+
+        let mut bs = vec![];
+        match File::open(&path) {
+            Ok(mut file) => {
+                file.read_to_end(&mut bs).map_err(file_error)?;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(file_error(e)),
+        }
+
+        let entries = parse_entries(&bs);
+        let size = entries.iter().map(SpooledPacket::size).sum::<u64>();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(file_error)?;
+        if size < bs.len() as u64 {
+            warn!(
+                "discarded a partially written entry at the end of the spool file {}",
+                path.display()
+            );
+            file.set_len(size).map_err(file_error)?;
+        }
+
+        Ok(DiskSpool {
+            path,
+            file,
+            max_size,
+            fsync_policy,
+            entries,
+            size,
+            unsynced: 0,
+        })
+    }
+
+    /// Returns the number of the spooled packets.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the size of the spool file in bytes.
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+
+    /// Append an Accounting-Request packet to the spool.
+    ///
+    /// If the spool file would exceed the max size, this fails with `SpoolError::SpoolFullError`.
+    pub fn push(&mut self, packet: &Packet) -> Result<(), SpoolError> {
+        self.push_at(packet, unix_time_now())
+    }
+
+    fn push_at(&mut self, packet: &Packet, spooled_at: u64) -> Result<(), SpoolError> {
+        if packet.get_code() != Code::AccountingRequest {
+            return Err(SpoolError::InvalidPacketError(format!(
+                "unexpected packet code: {}",
+                packet.get_code().string()
+            )));
+        }
+        let entry = SpooledPacket {
+            spooled_at,
+            encoded: packet
+                .encode()
+                .map_err(|e| SpoolError::InvalidPacketError(e.to_string()))?,
+            attempts: 0,
+        };
+        self.push_entry(entry)
+    }
+
+    fn push_entry(&mut self, entry: SpooledPacket) -> Result<(), SpoolError> {
+        if self.size + entry.size() > self.max_size {
+            return Err(SpoolError::SpoolFullError(self.max_size));
+        }
+
+        let mut bs = entry.spooled_at.to_be_bytes().to_vec();
+        bs.extend(&entry.encoded);
+        self.file.write_all(&bs).map_err(|e| self.file_error(e))?;
+        self.unsynced += 1;
+        let needs_sync = match self.fsync_policy {
+            FsyncPolicy::Always => true,
+            FsyncPolicy::Batched(n) => self.unsynced >= n,
+            FsyncPolicy::Never => false,
+        };
+        if needs_sync {
+            self.file.sync_data().map_err(|e| self.file_error(e))?;
+            self.unsynced = 0;
+        }
+
+        self.size += entry.size();
+        self.entries.push_back(entry);
+        Ok(())
+    }
+
+    fn pop_front(&mut self) -> Option<SpooledPacket> {
+        let entry = self.entries.pop_front()?;
+        self.size -= entry.size();
+        Some(entry)
+    }
+
+    /// Rewrite the spool file with the remaining entries.
+    fn compact(&mut self) -> Result<(), SpoolError> {
+        let tmp_path = PathBuf::from(format!("{}.tmp", self.path.display()));
+        let mut bs = Vec::with_capacity(self.size as usize);
+        for entry in &self.entries {
+            bs.extend(entry.spooled_at.to_be_bytes());
+            bs.extend(&entry.encoded);
+        }
+
+        let mut tmp = File::create(&tmp_path).map_err(|e| self.file_error(e))?;
+        tmp.write_all(&bs).map_err(|e| self.file_error(e))?;
+        if self.fsync_policy != FsyncPolicy::Never {
+            tmp.sync_data().map_err(|e| self.file_error(e))?;
+        }
+        fs::rename(&tmp_path, &self.path).map_err(|e| self.file_error(e))?;
+
+        self.file = OpenOptions::new()
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| self.file_error(e))?;
+        self.unsynced = 0;
+        Ok(())
+    }
+
+    fn file_error(&self, e: std::io::Error) -> SpoolError {
+        SpoolError::SpoolFileError(self.path.display().to_string(), e.to_string())
+    }
+}
+
+/// This enum represents the outcome of `SpoolingClient::send_packet()`.
+#[derive(Debug)]
+pub enum SendOutcome {
+    /// The packet has been sent and this is the response.
+    Sent(Packet),
+    /// The packet has been spooled to be replayed later.
+    Spooled,
+}
+
+/// `SpoolingClient` is an accounting client that spools the packets to the disk when the upstream is unreachable.
+///
+/// While the spool has packets, the new packets are also spooled behind those so that the upstream receives
+/// the packets in order; the spooled packets are replayed on the next `send_packet()` or by `replay()`.
+pub struct SpoolingClient {
+    client: Client,
+    spool: Mutex<DiskSpool>,
+    secret: Vec<u8>,
+    max_replay_attempts: usize,
+    dead_letter: Option<Mutex<DiskSpool>>,
+    given_up_count: AtomicU64,
+}
+
+impl SpoolingClient {
+    /// A constructor for a spooling client; `secret` is the shared secret to decode the spooled packets for replaying.
+    pub fn new(client: Client, spool: DiskSpool, secret: &[u8]) -> Self {
+        SpoolingClient {
+            client,
+            spool: Mutex::new(spool),
+            secret: secret.to_vec(),
+            max_replay_attempts: DEFAULT_MAX_REPLAY_ATTEMPTS,
+            dead_letter: None,
+            given_up_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Set the max number of the attempts to replay a spooled packet that fails for the reason other than the unreachable
+    /// upstream (default: `3`); the packet is given up after that, so that it doesn't block the following packets.
+    pub fn set_max_replay_attempts(&mut self, max_replay_attempts: usize) {
+        self.max_replay_attempts = max_replay_attempts.max(1);
+    }
+
+    pub fn get_max_replay_attempts(&self) -> usize {
+        self.max_replay_attempts
+    }
+
+    /// Set a spool to keep the packets that have been given up; without that, those are dropped.
+    pub fn set_dead_letter(&mut self, dead_letter: DiskSpool) {
+        self.dead_letter = Some(Mutex::new(dead_letter));
+    }
+
+    /// Returns the number of the packets that have been given up.
+    pub fn get_given_up_count(&self) -> u64 {
+        self.given_up_count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of the spooled packets.
+    pub async fn get_spooled_count(&self) -> usize {
+        self.spool.lock().await.len()
+    }
+
+    /// This method sends an Accounting-Request packet to the destination, or spools that if the destination is unreachable
+    /// (i.e. timed-out, or failed to send or receive).
+    ///
+    /// If the spooled packets cannot be replayed, the packet is spooled behind those.
+    pub async fn send_packet(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+    ) -> Result<SendOutcome, SpoolError> {
+        let (outcome, _) = self
+            .send_packet_with_failover(std::slice::from_ref(remote_addr), request_packet)
+            .await?;
+        Ok(outcome)
+    }
+
+    /// This method is the same as `send_packet()`, but tries the destinations in order, and spools the packet
+    /// only when all of them are unreachable.
+    ///
+    /// This returns the outcome and the number of the leading destinations that were unreachable,
+    /// i.e. the packet has been sent to the next one of those if it is `SendOutcome::Sent`.
+    pub async fn send_packet_with_failover(
+        &self,
+        remote_addrs: &[SocketAddr],
+        request_packet: &Packet,
+    ) -> Result<(SendOutcome, usize), SpoolError> {
+        for (i, remote_addr) in remote_addrs.iter().enumerate() {
+            if let Some(response) = self.try_send_packet(remote_addr, request_packet).await? {
+                return Ok((SendOutcome::Sent(response), i));
+            }
+        }
+
+        self.spool.lock().await.push(request_packet)?;
+        Ok((SendOutcome::Spooled, remote_addrs.len()))
+    }
+
+    /// Sends the packet after the spooled packets, and returns `None` if those or the packet cannot be delivered
+    /// because the destination is unreachable.
+    async fn try_send_packet(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+    ) -> Result<Option<Packet>, SpoolError> {
+        if !self.spool.lock().await.is_empty() {
+            if let Err(e) = self.replay(remote_addr).await {
+                warn!(
+                    "failed to replay the spooled accounting requests to {}; {}",
+                    remote_addr, e
+                );
+            }
+            if !self.spool.lock().await.is_empty() {
+                return Ok(None);
+            }
+        }
+
+        match self.client.send_packet(remote_addr, request_packet).await {
+            Ok(response) => Ok(Some(response)),
+            Err(e) if is_unreachable(&e) => {
+                warn!(
+                    "{} is unreachable for an accounting request; {}",
+                    remote_addr, e
+                );
+                Ok(None)
+            }
+            Err(e) => Err(SpoolError::SendingError(e)),
+        }
+    }
+
+    /// Replay the spooled packets in order, and returns the number of the replayed packets.
+    ///
+    /// This stops at the first packet that cannot be delivered; that and the following packets stay in the spool.
+    /// A packet that has failed for the max replay attempts for the reason other than the unreachable destination
+    /// is given up, and the replaying goes on to the following packets.
+    pub async fn replay(&self, remote_addr: &SocketAddr) -> Result<usize, SpoolError> {
+        let mut spool = self.spool.lock().await;
+        let mut replayed = 0;
+        let mut removed = false;
+        let mut result = Ok(());
+        while let Some(entry) = spool.entries.front_mut() {
+            let mut packet = match Packet::decode(&entry.encoded, &self.secret) {
+                Ok(packet) => packet,
+                Err(e) => {
+                    warn!("dropped a spooled packet that cannot be decoded; {}", e);
+                    spool.pop_front();
+                    removed = true;
+                    continue;
+                }
+            };
+            let delay = match rfc2866::lookup_acct_delay_time(&packet) {
+                Some(Ok(delay)) => delay,
+                _ => 0,
+            };
+            let spooled_for = unix_time_now().saturating_sub(entry.spooled_at);
+            rfc2866::delete_acct_delay_time(&mut packet);
+            rfc2866::add_acct_delay_time(
+                &mut packet,
+                delay.saturating_add(u32::try_from(spooled_for).unwrap_or(u32::MAX)),
+            );
+
+            match self.client.send_packet(remote_addr, &packet).await {
+                Ok(_) => {
+                    spool.pop_front();
+                    removed = true;
+                    replayed += 1;
+                }
+                Err(e) if is_unreachable(&e) => {
+                    debug!("{} is still unreachable; {}", remote_addr, e);
+                    break;
+                }
+                Err(e) => {
+                    entry.attempts += 1;
+                    if entry.attempts < self.max_replay_attempts {
+                        result = Err(SpoolError::SendingError(e));
+                        break;
+                    }
+                    if let Some(entry) = spool.pop_front() {
+                        removed = true;
+                        self.give_up(entry, &e).await;
+                    }
+                }
+            }
+        }
+
+        if removed {
+            spool.compact()?;
+        }
+        result.map(|_| replayed)
+    }
+
+    async fn give_up(&self, entry: SpooledPacket, e: &ClientError) {
+        self.given_up_count.fetch_add(1, Ordering::Relaxed);
+        match &self.dead_letter {
+            Some(dead_letter) => match dead_letter.lock().await.push_entry(entry) {
+                Ok(_) => warn!(
+                    "moved a spooled packet to the dead-letter spool after {} attempts; {}",
+                    self.max_replay_attempts, e
+                ),
+                Err(dead_letter_err) => warn!(
+                    "dropped a spooled packet after {} attempts; {}; the dead-letter spool failed; {}",
+                    self.max_replay_attempts, e, dead_letter_err
+                ),
+            },
+            None => warn!(
+                "dropped a spooled packet after {} attempts; {}",
+                self.max_replay_attempts, e
+            ),
+        }
+    }
+}
+
+fn is_unreachable(e: &ClientError) -> bool {
+    matches!(
+        e,
+        ClientError::FailedEstablishingUdpConnectionError(_, _)
+            | ClientError::FailedSendingRadiusPacketError(_, _)
+            | ClientError::FailedReceivingResponseError(_, _)
+            | ClientError::ConnectionTimeoutError()
+            | ClientError::SocketTimeoutError()
+            | ClientError::DeadlineExceededError()
+    )
+}
+
+fn parse_entries(bs: &[u8]) -> VecDeque<SpooledPacket> {
+    let mut entries = VecDeque::new();
+    let mut rest = bs;
+    while rest.len() >= TIMESTAMP_LENGTH + RADIUS_PACKET_HEADER_LENGTH {
+        let len =
+            u16::from_be_bytes([rest[TIMESTAMP_LENGTH + 2], rest[TIMESTAMP_LENGTH + 3]]) as usize;
+        if len < RADIUS_PACKET_HEADER_LENGTH || rest.len() < TIMESTAMP_LENGTH + len {
+            break;
+        }
+        let mut spooled_at = [0; TIMESTAMP_LENGTH];
+        spooled_at.copy_from_slice(&rest[..TIMESTAMP_LENGTH]);
+        entries.push_back(SpooledPacket {
+            spooled_at: u64::from_be_bytes(spooled_at),
+            encoded: rest[TIMESTAMP_LENGTH..TIMESTAMP_LENGTH + len].to_vec(),
+            attempts: 0,
+        });
+        rest = &rest[TIMESTAMP_LENGTH + len..];
+    }
+    entries
+}
+
+fn unix_time_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use tokio::net::UdpSocket;
+
+    use crate::acct::spool::{
+        unix_time_now, DiskSpool, FsyncPolicy, SendOutcome, SpoolError, SpoolingClient,
+    };
+    use crate::client::Client;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::{rfc2865, rfc2866};
+
+    fn spool_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("radius-spool-{}-{}", std::process::id(), name));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn accounting_request(user_name: &str) -> Packet {
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_status_type(&mut packet, rfc2866::ACCT_STATUS_TYPE_START);
+        rfc2865::add_user_name(&mut packet, user_name);
+        packet
+    }
+
+    #[test]
+    fn test_disk_spool() {
+        let path = spool_path("disk");
+        let mut spool = DiskSpool::open(&path, 1024, FsyncPolicy::Batched(2)).unwrap();
+        spool.push(&accounting_request("alice")).unwrap();
+        spool.push(&accounting_request("bob")).unwrap();
+        let size = spool.get_size();
+        assert_eq!(size, fs::metadata(&path).unwrap().len());
+        assert!(matches!(
+            spool.push(&Packet::new(Code::AccessRequest, b"secret")),
+            Err(SpoolError::InvalidPacketError(_))
+        ));
+        drop(spool);
+
+        // a partially written entry is discarded on opening
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(&[0, 0, 0, 0, 0, 0, 0, 1, 4, 1, 0])
+            .unwrap();
+        let mut spool = DiskSpool::open(&path, size + 40, FsyncPolicy::Always).unwrap();
+        assert_eq!(spool.len(), 2);
+        assert_eq!(spool.get_size(), size);
+        assert_eq!(fs::metadata(&path).unwrap().len(), size);
+
+        assert!(matches!(
+            spool.push(&accounting_request("carol")),
+            Err(SpoolError::SpoolFullError(_))
+        ));
+        spool.pop_front();
+        spool.compact().unwrap();
+        assert_eq!(
+            DiskSpool::open(&path, 1024, FsyncPolicy::Never)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spool_and_replay() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        let online = Arc::new(AtomicBool::new(false));
+        let received = Arc::new(Mutex::new(vec![]));
+        {
+            let online = online.clone();
+            let received = received.clone();
+            tokio::spawn(async move {
+                let mut buf = vec![0; 4096];
+                loop {
+                    let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                    if !online.load(Ordering::SeqCst) {
+                        continue;
+                    }
+                    let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                    let response = request.make_response_packet(Code::AccountingResponse);
+                    received.lock().unwrap().push(request);
+                    server
+                        .send_to(&response.encode().unwrap(), addr)
+                        .await
+                        .unwrap();
+                }
+            });
+        }
+
+        let path = spool_path("replay");
+        let mut spool = DiskSpool::open(&path, 4096, FsyncPolicy::Always).unwrap();
+        let mut delayed = accounting_request("alice");
+        rfc2866::add_acct_delay_time(&mut delayed, 5);
+        spool.push_at(&delayed, unix_time_now() - 30).unwrap();
+        let client = SpoolingClient::new(
+            Client::new(None, Some(Duration::from_millis(100))),
+            spool,
+            b"secret",
+        );
+
+        // the upstream is unreachable
+        let outcome = client
+            .send_packet(&server_addr, &accounting_request("bob"))
+            .await
+            .unwrap();
+        assert!(matches!(outcome, SendOutcome::Spooled));
+        assert_eq!(client.get_spooled_count().await, 2);
+        assert_eq!(client.replay(&server_addr).await.unwrap(), 0);
+
+        // the spooled packets are replayed before the new one
+        online.store(true, Ordering::SeqCst);
+        let outcome = client
+            .send_packet(&server_addr, &accounting_request("carol"))
+            .await
+            .unwrap();
+        assert!(matches!(outcome, SendOutcome::Sent(_)));
+        assert_eq!(client.get_spooled_count().await, 0);
+
+        let received = received.lock().unwrap();
+        let user_names = received
+            .iter()
+            .map(|packet| rfc2865::lookup_user_name(packet).unwrap().unwrap())
+            .collect::<Vec<String>>();
+        assert_eq!(user_names, vec!["alice", "bob", "carol"]);
+        let delay = rfc2866::lookup_acct_delay_time(&received[0])
+            .unwrap()
+            .unwrap();
+        assert!((35..40).contains(&delay), "unexpected delay: {delay}");
+        assert!(!rfc2866::has_acct_delay_time(&received[2]));
+        assert_eq!(fs::metadata(&path).unwrap().len(), 0);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_packet_with_failover() {
+        // the port that is not listened, and the upstream that responds
+        let unreachable_addr = UdpSocket::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                let response = request.make_response_packet(Code::AccountingResponse);
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let path = spool_path("failover");
+        let client = SpoolingClient::new(
+            Client::new(None, Some(Duration::from_millis(100))),
+            DiskSpool::open(&path, 4096, FsyncPolicy::Always).unwrap(),
+            b"secret",
+        );
+
+        // the packet is not spooled while a destination responds
+        let (outcome, unreachable) = client
+            .send_packet_with_failover(
+                &[unreachable_addr, server_addr],
+                &accounting_request("alice"),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(outcome, SendOutcome::Sent(_)));
+        assert_eq!(unreachable, 1);
+        assert_eq!(client.get_spooled_count().await, 0);
+
+        let (outcome, unreachable) = client
+            .send_packet_with_failover(&[unreachable_addr], &accounting_request("bob"))
+            .await
+            .unwrap();
+        assert!(matches!(outcome, SendOutcome::Spooled));
+        assert_eq!(unreachable, 1);
+        assert_eq!(client.get_spooled_count().await, 1);
+
+        // the spooled packet is replayed to the destination that responds, before the new one
+        let (outcome, unreachable) = client
+            .send_packet_with_failover(
+                &[unreachable_addr, server_addr],
+                &accounting_request("carol"),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(outcome, SendOutcome::Sent(_)));
+        assert_eq!(unreachable, 1);
+        assert_eq!(client.get_spooled_count().await, 0);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_with_malformed_response() {
+        // the upstream returns a malformed response to the packets of `alice`
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        let received = Arc::new(Mutex::new(vec![]));
+        {
+            let received = received.clone();
+            tokio::spawn(async move {
+                let mut buf = vec![0; 4096];
+                loop {
+                    let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                    let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                    let user_name = rfc2865::lookup_user_name(&request).unwrap().unwrap();
+                    let response = if user_name == "alice" {
+                        vec![5, 0, 0]
+                    } else {
+                        received.lock().unwrap().push(user_name);
+                        request
+                            .make_response_packet(Code::AccountingResponse)
+                            .encode()
+                            .unwrap()
+                    };
+                    server.send_to(&response, addr).await.unwrap();
+                }
+            });
+        }
+
+        let path = spool_path("malformed");
+        let dead_letter_path = spool_path("malformed-dead-letter");
+        let mut spool = DiskSpool::open(&path, 4096, FsyncPolicy::Always).unwrap();
+        spool.push(&accounting_request("alice")).unwrap();
+        let mut client = SpoolingClient::new(
+            Client::new(None, Some(Duration::from_millis(100))),
+            spool,
+            b"secret",
+        );
+        client.set_max_replay_attempts(2);
+        client.set_dead_letter(
+            DiskSpool::open(&dead_letter_path, 4096, FsyncPolicy::Always).unwrap(),
+        );
+
+        // the new packet is spooled behind the packet that failed to be replayed
+        let outcome = client
+            .send_packet(&server_addr, &accounting_request("bob"))
+            .await
+            .unwrap();
+        assert!(matches!(outcome, SendOutcome::Spooled));
+        assert_eq!(client.get_spooled_count().await, 2);
+        assert_eq!(client.get_given_up_count(), 0);
+
+        // the failing packet is given up at the max attempts, and the following packets are sent
+        let outcome = client
+            .send_packet(&server_addr, &accounting_request("carol"))
+            .await
+            .unwrap();
+        assert!(matches!(outcome, SendOutcome::Sent(_)));
+        assert_eq!(client.get_spooled_count().await, 0);
+        assert_eq!(client.get_given_up_count(), 1);
+        assert_eq!(*received.lock().unwrap(), vec!["bob", "carol"]);
+        assert_eq!(
+            DiskSpool::open(&dead_letter_path, 4096, FsyncPolicy::Never)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(&dead_letter_path).unwrap();
+    }
+}
@@ -0,0 +1,544 @@
+//! Accounting writer into a SQL table that is compatible with the `radacct` schema of FreeRADIUS.
+//!
+//! This module is available with the `acct-sql` feature.
+//!
+//! The writer makes the SQL statements for PostgreSQL or MySQL, and executes them through `SqlExecutor`,
+//! so it doesn't depend on any specific database driver; implement `SqlExecutor` for a driver.
+//! With the `sqlx` feature, `SqlExecutor` is implemented for `sqlx::PgPool` and `sqlx::MySqlPool`.
+//!
+//! - `Start`: inserts a row (a retransmitted request is ignored)
+//! - `Interim-Update`: upserts the row with the latest counters
+//! - `Stop`: upserts the row with the stop time and the terminate cause, i.e. closes the session
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::net::UdpSocket;
+
+use crate::acct::{lookup_optional, send_accounting_response, AcctError, Session, SessionKey};
+use crate::core::request::Request;
+use crate::core::rfc2866;
+use crate::server::RequestHandler;
+
+/// The default name of the accounting table.
+pub const DEFAULT_TABLE_NAME: &str = "radacct";
+
+/// This enum represents a SQL dialect of the statements.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SqlDialect {
+    Postgres,
+    MySql,
+}
+
+impl SqlDialect {
+    /// Returns the `CREATE TABLE` statement of the accounting table.
+    pub fn schema(&self, table_name: &str) -> String {
+        match self {
+            SqlDialect::Postgres => format!(
+                "CREATE TABLE IF NOT EXISTS {} (
+    radacctid BIGSERIAL PRIMARY KEY,
+    acctsessionid TEXT NOT NULL,
+    acctuniqueid TEXT NOT NULL UNIQUE,
+    username TEXT,
+    nasipaddress TEXT NOT NULL,
+    nasportid TEXT,
+    acctstarttime TIMESTAMP WITH TIME ZONE,
+    acctupdatetime TIMESTAMP WITH TIME ZONE,
+    acctstoptime TIMESTAMP WITH TIME ZONE,
+    acctsessiontime BIGINT,
+    acctinputoctets BIGINT,
+    acctoutputoctets BIGINT,
+    callingstationid TEXT,
+    framedipaddress TEXT,
+    acctterminatecause TEXT
+)",
+                table_name
+            ),
+            SqlDialect::MySql => format!(
+                "CREATE TABLE IF NOT EXISTS {} (
+    radacctid BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    acctsessionid VARCHAR(64) NOT NULL,
+    acctuniqueid VARCHAR(32) NOT NULL UNIQUE,
+    username VARCHAR(64),
+    nasipaddress VARCHAR(253) NOT NULL,
+    nasportid VARCHAR(32),
+    acctstarttime DATETIME,
+    acctupdatetime DATETIME,
+    acctstoptime DATETIME,
+    acctsessiontime BIGINT UNSIGNED,
+    acctinputoctets BIGINT UNSIGNED,
+    acctoutputoctets BIGINT UNSIGNED,
+    callingstationid VARCHAR(50),
+    framedipaddress VARCHAR(15),
+    acctterminatecause VARCHAR(32)
+)",
+                table_name
+            ),
+        }
+    }
+
+    fn placeholder(&self, n: usize) -> String {
+        match self {
+            SqlDialect::Postgres => format!("${}", n),
+            SqlDialect::MySql => "?".to_owned(),
+        }
+    }
+}
+
+/// This enum represents a parameter value of a SQL statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    Null,
+    Text(String),
+    Integer(i64),
+    Timestamp(DateTime<Utc>),
+}
+
+impl From<Option<String>> for SqlValue {
+    fn from(value: Option<String>) -> Self {
+        value.map_or(SqlValue::Null, SqlValue::Text)
+    }
+}
+
+/// SqlExecutor executes a SQL statement with the positional parameters.
+///
+/// The placeholders of the statement are the ones of the `SqlDialect` (i.e. `$1` for PostgreSQL and `?` for MySQL).
+#[async_trait]
+pub trait SqlExecutor: 'static + Sync + Send {
+    /// Execute the statement; this returns the number of the affected rows.
+    async fn execute(&self, statement: &str, params: Vec<SqlValue>) -> Result<u64, String>;
+}
+
+/// This enum represents a kind of the accounting update.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Update {
+    Start,
+    Interim,
+    Stop,
+}
+
+const COLUMNS: [&str; 14] = [
+    "acctsessionid",
+    "acctuniqueid",
+    "username",
+    "nasipaddress",
+    "nasportid",
+    "acctstarttime",
+    "acctupdatetime",
+    "acctstoptime",
+    "acctsessiontime",
+    "acctinputoctets",
+    "acctoutputoctets",
+    "callingstationid",
+    "framedipaddress",
+    "acctterminatecause",
+];
+
+/// The accounting handler that writes the accounting requests into a `radacct` compatible table.
+///
+/// This sends an Accounting-Response only when the statement has been executed successfully.
+/// `Accounting-On` and `Accounting-Off` are acknowledged without any statement.
+pub struct SqlAccountingWriter<E: SqlExecutor> {
+    executor: Arc<E>,
+    dialect: SqlDialect,
+    table_name: String,
+}
+
+impl<E: SqlExecutor> SqlAccountingWriter<E> {
+    pub fn new(executor: Arc<E>, dialect: SqlDialect) -> Self {
+        SqlAccountingWriter {
+            executor,
+            dialect,
+            table_name: DEFAULT_TABLE_NAME.to_owned(),
+        }
+    }
+
+    /// Set the name of the accounting table (default: `DEFAULT_TABLE_NAME`).
+    pub fn set_table_name(&mut self, table_name: &str) {
+        self.table_name = table_name.to_owned();
+    }
+
+    pub fn get_executor(&self) -> &Arc<E> {
+        &self.executor
+    }
+
+    /// Write the accounting request into the table.
+    pub async fn write(&self, request: &Request) -> Result<(), AcctError> {
+        let packet = request.get_packet();
+        let status_type = match rfc2866::lookup_acct_status_type(packet) {
+            Some(status_type) => status_type.map_err(AcctError::AttributeDecodingError)?,
+            None => return Err(AcctError::AcctStatusTypeMissingError()),
+        };
+
+        let update = match status_type {
+            rfc2866::ACCT_STATUS_TYPE_START => Update::Start,
+            rfc2866::ACCT_STATUS_TYPE_INTERIM_UPDATE => Update::Interim,
+            rfc2866::ACCT_STATUS_TYPE_STOP => Update::Stop,
+            rfc2866::ACCT_STATUS_TYPE_ACCOUNTING_ON | rfc2866::ACCT_STATUS_TYPE_ACCOUNTING_OFF => {
+                return Ok(())
+            }
+            _ => return Err(AcctError::UnsupportedAcctStatusTypeError(status_type)),
+        };
+
+        let session = Session::from_request_at(request, Utc::now())?;
+        let terminate_cause = lookup_optional(rfc2866::lookup_acct_terminate_cause(packet))?;
+        let (statement, params) = self.make_statement(update, &session, terminate_cause);
+        self.executor
+            .execute(&statement, params)
+            .await
+            .map(|_| ())
+            .map_err(AcctError::SessionStoreError)
+    }
+
+    fn make_statement(
+        &self,
+        update: Update,
+        session: &Session,
+        terminate_cause: Option<rfc2866::AcctTerminateCause>,
+    ) -> (String, Vec<SqlValue>) {
+        let params = vec![
+            SqlValue::Text(session.key.session_id.clone()),
+            SqlValue::Text(unique_id(&session.key)),
+            session.user_name.clone().into(),
+            SqlValue::Text(session.key.nas.clone()),
+            session.nas_port.map(|port| port.to_string()).into(),
+            if update == Update::Start {
+                SqlValue::Timestamp(session.started_at)
+            } else {
+                SqlValue::Null
+            },
+            SqlValue::Timestamp(session.updated_at),
+            if update == Update::Stop {
+                SqlValue::Timestamp(session.updated_at)
+            } else {
+                SqlValue::Null
+            },
+            SqlValue::Integer(session.session_time as i64),
+            SqlValue::Integer(session.input_octets as i64),
+            SqlValue::Integer(session.output_octets as i64),
+            session.calling_station_id.clone().into(),
+            session.framed_ip_address.map(|ip| ip.to_string()).into(),
+            terminate_cause
+                .map(|cause| cause.to_u32().to_string())
+                .into(),
+        ];
+
+        let placeholders = (1..=COLUMNS.len())
+            .map(|n| self.dialect.placeholder(n))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let updated_columns: &[&str] = match update {
+            Update::Start => &[],
+            Update::Interim => &[
+                "acctupdatetime",
+                "acctsessiontime",
+                "acctinputoctets",
+                "acctoutputoctets",
+                "framedipaddress",
+            ],
+            Update::Stop => &[
+                "acctupdatetime",
+                "acctstoptime",
+                "acctsessiontime",
+                "acctinputoctets",
+                "acctoutputoctets",
+                "acctterminatecause",
+            ],
+        };
+
+        let on_conflict = match (self.dialect, updated_columns.is_empty()) {
+            (SqlDialect::Postgres, true) => "ON CONFLICT (acctuniqueid) DO NOTHING".to_owned(),
+            (SqlDialect::Postgres, false) => format!(
+                "ON CONFLICT (acctuniqueid) DO UPDATE SET {}",
+                updated_columns
+                    .iter()
+                    .map(|column| format!("{} = EXCLUDED.{}", column, column))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            (SqlDialect::MySql, true) => {
+                "ON DUPLICATE KEY UPDATE acctuniqueid = acctuniqueid".to_owned()
+            }
+            (SqlDialect::MySql, false) => format!(
+                "ON DUPLICATE KEY UPDATE {}",
+                updated_columns
+                    .iter()
+                    .map(|column| format!("{} = VALUES({})", column, column))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+
+        (
+            format!(
+                "INSERT INTO {} ({}) VALUES ({}) {}",
+                self.table_name,
+                COLUMNS.join(", "),
+                placeholders,
+                on_conflict
+            ),
+            params,
+        )
+    }
+}
+
+/// Returns the unique ID of the session, that is the MD5 hex digest of the session key (like `acctuniqueid` of FreeRADIUS).
+pub fn unique_id(key: &SessionKey) -> String {
+    format!(
+        "{:x}",
+        md5::compute(format!("{}\0{}", key.nas, key.session_id))
+    )
+}
+
+#[async_trait]
+impl<E: SqlExecutor> RequestHandler<(), AcctError> for SqlAccountingWriter<E> {
+    async fn handle_radius_request(
+        &self,
+        conn: &UdpSocket,
+        request: &Request,
+    ) -> Result<(), AcctError> {
+        self.write(request).await?;
+        send_accounting_response(conn, request).await
+    }
+}
+
+/// The NULL parameter without a type, so that PostgreSQL infers the type from the column (e.g. `TIMESTAMP` or `TEXT`).
+#[cfg(feature = "sqlx")]
+struct UntypedNull;
+
+#[cfg(feature = "sqlx")]
+impl sqlx::Type<sqlx::Postgres> for UntypedNull {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        // the OID 0 is "unspecified" in the protocol
+        sqlx::postgres::PgTypeInfo::with_oid(sqlx::postgres::types::Oid(0))
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl sqlx::Encode<'_, sqlx::Postgres> for UntypedNull {
+    fn encode_by_ref(
+        &self,
+        _buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        Ok(sqlx::encode::IsNull::Yes)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+#[async_trait]
+impl SqlExecutor for sqlx::PgPool {
+    async fn execute(&self, statement: &str, params: Vec<SqlValue>) -> Result<u64, String> {
+        let mut query = sqlx::query(statement);
+        for param in params {
+            query = match param {
+                SqlValue::Null => query.bind(UntypedNull),
+                SqlValue::Text(text) => query.bind(text),
+                SqlValue::Integer(integer) => query.bind(integer),
+                SqlValue::Timestamp(timestamp) => query.bind(timestamp),
+            };
+        }
+        query
+            .execute(self)
+            .await
+            .map(|result| result.rows_affected())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "sqlx")]
+#[async_trait]
+impl SqlExecutor for sqlx::MySqlPool {
+    async fn execute(&self, statement: &str, params: Vec<SqlValue>) -> Result<u64, String> {
+        let mut query = sqlx::query(statement);
+        for param in params {
+            query = match param {
+                SqlValue::Null => query.bind(None::<String>),
+                SqlValue::Text(text) => query.bind(text),
+                SqlValue::Integer(integer) => query.bind(integer),
+                SqlValue::Timestamp(timestamp) => query.bind(timestamp),
+            };
+        }
+        query
+            .execute(self)
+            .await
+            .map(|result| result.rows_affected())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+
+    use crate::acct::sql::{SqlAccountingWriter, SqlDialect, SqlExecutor, SqlValue};
+    use crate::acct::AcctError;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::{rfc2865, rfc2866};
+
+    #[derive(Default)]
+    struct RecordingExecutor {
+        statements: Mutex<Vec<(String, Vec<SqlValue>)>>,
+    }
+
+    #[async_trait]
+    impl SqlExecutor for RecordingExecutor {
+        async fn execute(&self, statement: &str, params: Vec<SqlValue>) -> Result<u64, String> {
+            self.statements
+                .lock()
+                .unwrap()
+                .push((statement.to_owned(), params));
+            Ok(1)
+        }
+    }
+
+    fn make_request(status_type: rfc2866::AcctStatusType) -> Request {
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_status_type(&mut packet, status_type);
+        rfc2866::add_acct_session_id(&mut packet, "session-1");
+        rfc2865::add_user_name(&mut packet, "alice");
+        rfc2865::add_nas_identifier(&mut packet, "nas-1");
+        rfc2866::add_acct_input_octets(&mut packet, 100);
+        if status_type == rfc2866::ACCT_STATUS_TYPE_STOP {
+            rfc2866::add_acct_terminate_cause(
+                &mut packet,
+                rfc2866::ACCT_TERMINATE_CAUSE_USER_REQUEST,
+            );
+        }
+        Request::new(
+            "127.0.0.1:1813".parse().unwrap(),
+            "192.0.2.1:1813".parse().unwrap(),
+            packet,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sql_accounting_writer() {
+        let executor = Arc::new(RecordingExecutor::default());
+        let writer = SqlAccountingWriter::new(executor.clone(), SqlDialect::Postgres);
+
+        writer
+            .write(&make_request(rfc2866::ACCT_STATUS_TYPE_START))
+            .await
+            .unwrap();
+        writer
+            .write(&make_request(rfc2866::ACCT_STATUS_TYPE_STOP))
+            .await
+            .unwrap();
+        writer
+            .write(&make_request(rfc2866::ACCT_STATUS_TYPE_ACCOUNTING_ON))
+            .await
+            .unwrap();
+
+        let statements = executor.statements.lock().unwrap();
+        assert_eq!(statements.len(), 2);
+
+        let (start, start_params) = &statements[0];
+        assert!(start.starts_with("INSERT INTO radacct (acctsessionid, acctuniqueid, "));
+        assert!(start.contains("VALUES ($1, $2, $3, "));
+        assert!(start.ends_with("ON CONFLICT (acctuniqueid) DO NOTHING"));
+        assert_eq!(start_params[0], SqlValue::Text("session-1".to_owned()));
+        assert_eq!(start_params[2], SqlValue::Text("alice".to_owned()));
+        assert_eq!(start_params[3], SqlValue::Text("nas-1".to_owned()));
+        assert_eq!(start_params[7], SqlValue::Null);
+        assert_eq!(start_params[9], SqlValue::Integer(100));
+
+        let (stop, stop_params) = &statements[1];
+        assert!(stop.ends_with(
+            "ON CONFLICT (acctuniqueid) DO UPDATE SET acctupdatetime = EXCLUDED.acctupdatetime, acctstoptime = EXCLUDED.acctstoptime, acctsessiontime = EXCLUDED.acctsessiontime, acctinputoctets = EXCLUDED.acctinputoctets, acctoutputoctets = EXCLUDED.acctoutputoctets, acctterminatecause = EXCLUDED.acctterminatecause"
+        ));
+        assert_eq!(stop_params[1], start_params[1]);
+        assert!(matches!(stop_params[7], SqlValue::Timestamp(_)));
+        assert_eq!(stop_params[13], SqlValue::Text("1".to_owned()));
+    }
+
+    // this runs against the PostgreSQL of `RADIUS_TEST_POSTGRES_URL` (e.g. `postgres://localhost/radius`), and is skipped without that
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn test_sql_accounting_writer_with_pg_pool() {
+        let Ok(url) = std::env::var("RADIUS_TEST_POSTGRES_URL") else {
+            return;
+        };
+        let pool = Arc::new(sqlx::PgPool::connect(&url).await.unwrap());
+        let table_name = format!("radacct_test_{}", std::process::id());
+        sqlx::query(&SqlDialect::Postgres.schema(&table_name))
+            .execute(pool.as_ref())
+            .await
+            .unwrap();
+        let mut writer = SqlAccountingWriter::new(pool.clone(), SqlDialect::Postgres);
+        writer.set_table_name(&table_name);
+
+        writer
+            .write(&make_request(rfc2866::ACCT_STATUS_TYPE_START))
+            .await
+            .unwrap();
+        // the NULL of the start time of the interim update is bound to the timestamp column
+        writer
+            .write(&make_request(rfc2866::ACCT_STATUS_TYPE_INTERIM_UPDATE))
+            .await
+            .unwrap();
+        writer
+            .write(&make_request(rfc2866::ACCT_STATUS_TYPE_STOP))
+            .await
+            .unwrap();
+
+        let (user_name, input_octets, started, stopped, terminate_cause): (
+            String,
+            i64,
+            bool,
+            bool,
+            String,
+        ) = sqlx::query_as(&format!(
+            "SELECT username, acctinputoctets, acctstarttime IS NOT NULL, acctstoptime IS NOT NULL, acctterminatecause FROM {}",
+            table_name
+        ))
+        .fetch_one(pool.as_ref())
+        .await
+        .unwrap();
+        sqlx::query(&format!("DROP TABLE {}", table_name))
+            .execute(pool.as_ref())
+            .await
+            .unwrap();
+
+        assert_eq!(user_name, "alice");
+        assert_eq!(input_octets, 100);
+        assert!(started);
+        assert!(stopped);
+        assert_eq!(terminate_cause, "1");
+    }
+
+    #[tokio::test]
+    async fn test_sql_accounting_writer_for_mysql() {
+        let executor = Arc::new(RecordingExecutor::default());
+        let mut writer = SqlAccountingWriter::new(executor.clone(), SqlDialect::MySql);
+        writer.set_table_name("acct");
+
+        writer
+            .write(&make_request(rfc2866::ACCT_STATUS_TYPE_INTERIM_UPDATE))
+            .await
+            .unwrap();
+
+        let (interim, _) = executor.statements.lock().unwrap().remove(0);
+        assert!(interim.starts_with("INSERT INTO acct ("));
+        assert!(interim.contains("VALUES (?, ?, ?, "));
+        assert!(
+            interim.contains("ON DUPLICATE KEY UPDATE acctupdatetime = VALUES(acctupdatetime), ")
+        );
+
+        let mut packet = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2866::add_acct_session_id(&mut packet, "session-1");
+        let request = Request::new(
+            "127.0.0.1:1813".parse().unwrap(),
+            "192.0.2.1:1813".parse().unwrap(),
+            packet,
+        );
+        assert_eq!(
+            writer.write(&request).await,
+            Err(AcctError::AcctStatusTypeMissingError())
+        );
+    }
+}
@@ -0,0 +1,344 @@
+//! `SessionStore` on a SQLite database, so that the active sessions survive a restart of the accounting server.
+//!
+//! This module is available with the `acct-sqlite` feature.
+//!
+//! The sessions are kept in a table (default: `radius_sessions`) that is created on opening if it doesn't exist;
+//! the timestamps are RFC 3339 texts, and the octet counters are stored as the bit patterns of `INTEGER` (i.e. `i64`).
+
+use std::net::Ipv4Addr;
+use std::path::Path;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+
+use crate::acct::{AcctError, Session, SessionKey, SessionStore};
+
+/// The default name of the sessions table.
+pub const DEFAULT_TABLE_NAME: &str = "radius_sessions";
+
+const COLUMNS: &str =
+    "session_id, nas, user_name, nas_port, framed_ip_address, calling_station_id, \
+    session_time, input_octets, output_octets, started_at, updated_at";
+
+/// A `SessionStore` on a SQLite database.
+///
+/// The operations run on the connection in place; those are short, but they block the executor for that while.
+pub struct SqliteSessionStore {
+    conn: Mutex<Connection>,
+    table_name: String,
+}
+
+impl SqliteSessionStore {
+    /// Open the database file, or create it if it doesn't exist.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, AcctError> {
+        Self::from_connection(Connection::open(path).map_err(store_error)?)
+    }
+
+    /// Open a database on memory; the sessions are lost when the store is dropped.
+    pub fn open_in_memory() -> Result<Self, AcctError> {
+        Self::from_connection(Connection::open_in_memory().map_err(store_error)?)
+    }
+
+    /// A constructor of the store on the connection with the default table name.
+    pub fn from_connection(conn: Connection) -> Result<Self, AcctError> {
+        Self::with_table_name(conn, DEFAULT_TABLE_NAME)
+    }
+
+    /// A constructor of the store on the connection with the given table name; the table is created if it doesn't exist.
+    pub fn with_table_name(conn: Connection, table_name: &str) -> Result<Self, AcctError> {
+        conn.execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS {table_name} (
+    session_id TEXT NOT NULL,
+    nas TEXT NOT NULL,
+    user_name TEXT,
+    nas_port INTEGER,
+    framed_ip_address TEXT,
+    calling_station_id TEXT,
+    session_time INTEGER NOT NULL,
+    input_octets INTEGER NOT NULL,
+    output_octets INTEGER NOT NULL,
+    started_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (session_id, nas)
+);
+CREATE INDEX IF NOT EXISTS {table_name}_user_name ON {table_name} (user_name);
+CREATE INDEX IF NOT EXISTS {table_name}_nas ON {table_name} (nas);"
+        ))
+        .map_err(store_error)?;
+
+        Ok(SqliteSessionStore {
+            conn: Mutex::new(conn),
+            table_name: table_name.to_owned(),
+        })
+    }
+
+    pub fn get_table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Returns the number of the active sessions.
+    pub fn len(&self) -> Result<usize, AcctError> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            &format!("SELECT COUNT(*) FROM {}", self.table_name),
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .map(|count| count as usize)
+        .map_err(store_error)
+    }
+
+    /// Returns whether there is no active session or not.
+    pub fn is_empty(&self) -> Result<bool, AcctError> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// Upsert the session; `update_started_at` is whether to overwrite `started_at` of the existing session.
+    fn upsert(&self, session: &Session, update_started_at: bool) -> Result<(), AcctError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            &format!(
+                "INSERT INTO {} ({COLUMNS}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+ON CONFLICT (session_id, nas) DO UPDATE SET user_name = excluded.user_name, nas_port = excluded.nas_port,
+framed_ip_address = excluded.framed_ip_address, calling_station_id = excluded.calling_station_id,
+session_time = excluded.session_time, input_octets = excluded.input_octets, output_octets = excluded.output_octets,
+{}updated_at = excluded.updated_at",
+                self.table_name,
+                if update_started_at {
+                    "started_at = excluded.started_at, "
+                } else {
+                    ""
+                },
+            ),
+            params![
+                session.key.session_id,
+                session.key.nas,
+                session.user_name,
+                session.nas_port,
+                session
+                    .framed_ip_address
+                    .map(|framed_ip_address| framed_ip_address.to_string()),
+                session.calling_station_id,
+                session.session_time,
+                session.input_octets as i64,
+                session.output_octets as i64,
+                format_time(&session.started_at),
+                format_time(&session.updated_at),
+            ],
+        )
+        .map_err(store_error)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SessionStore for SqliteSessionStore {
+    async fn start(&self, session: Session) -> Result<(), AcctError> {
+        self.upsert(&session, true)
+    }
+
+    async fn interim(&self, session: Session) -> Result<(), AcctError> {
+        self.upsert(&session, false)
+    }
+
+    async fn stop(&self, key: &SessionKey) -> Result<Option<Session>, AcctError> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            &format!(
+                "DELETE FROM {} WHERE session_id = ?1 AND nas = ?2 RETURNING {COLUMNS}",
+                self.table_name
+            ),
+            params![key.session_id, key.nas],
+            read_session,
+        )
+        .optional()
+        .map_err(store_error)
+    }
+
+    async fn clear_nas(&self, nas: &str) -> Result<usize, AcctError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            &format!("DELETE FROM {} WHERE nas = ?1", self.table_name),
+            params![nas],
+        )
+        .map_err(store_error)
+    }
+
+    async fn get(&self, key: &SessionKey) -> Result<Option<Session>, AcctError> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            &format!(
+                "SELECT {COLUMNS} FROM {} WHERE session_id = ?1 AND nas = ?2",
+                self.table_name
+            ),
+            params![key.session_id, key.nas],
+            read_session,
+        )
+        .optional()
+        .map_err(store_error)
+    }
+
+    async fn find_by_user_name(&self, user_name: &str) -> Result<Vec<Session>, AcctError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn
+            .prepare(&format!(
+                "SELECT {COLUMNS} FROM {} WHERE user_name = ?1",
+                self.table_name
+            ))
+            .map_err(store_error)?;
+        let sessions = statement
+            .query_map(params![user_name], read_session)
+            .map_err(store_error)?
+            .collect::<Result<Vec<Session>, rusqlite::Error>>()
+            .map_err(store_error)?;
+        Ok(sessions)
+    }
+}
+
+fn read_session(row: &Row) -> Result<Session, rusqlite::Error> {
+    let framed_ip_address = row
+        .get::<_, Option<String>>(4)?
+        .map(|framed_ip_address| {
+            framed_ip_address
+                .parse::<Ipv4Addr>()
+                .map_err(|e| conversion_error(4, e))
+        })
+        .transpose()?;
+
+    Ok(Session {
+        key: SessionKey {
+            session_id: row.get(0)?,
+            nas: row.get(1)?,
+        },
+        user_name: row.get(2)?,
+        nas_port: row.get(3)?,
+        framed_ip_address,
+        calling_station_id: row.get(5)?,
+        session_time: row.get(6)?,
+        input_octets: row.get::<_, i64>(7)? as u64,
+        output_octets: row.get::<_, i64>(8)? as u64,
+        started_at: parse_time(row, 9)?,
+        updated_at: parse_time(row, 10)?,
+    })
+}
+
+fn format_time(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+fn parse_time(row: &Row, idx: usize) -> Result<DateTime<Utc>, rusqlite::Error> {
+    DateTime::parse_from_rfc3339(&row.get::<_, String>(idx)?)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(|e| conversion_error(idx, e))
+}
+
+fn conversion_error<E: std::error::Error + Send + Sync + 'static>(
+    idx: usize,
+    e: E,
+) -> rusqlite::Error {
+    rusqlite::Error::FromSqlConversionFailure(idx, rusqlite::types::Type::Text, Box::new(e))
+}
+
+fn store_error(e: rusqlite::Error) -> AcctError {
+    AcctError::SessionStoreError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::net::Ipv4Addr;
+
+    use chrono::{Duration, Utc};
+
+    use crate::acct::sqlite::SqliteSessionStore;
+    use crate::acct::{Session, SessionKey, SessionStore};
+
+    fn session(session_id: &str, nas: &str, user_name: &str) -> Session {
+        let now = Utc::now();
+        Session {
+            key: SessionKey {
+                session_id: session_id.to_owned(),
+                nas: nas.to_owned(),
+            },
+            user_name: Some(user_name.to_owned()),
+            nas_port: Some(1),
+            framed_ip_address: Some(Ipv4Addr::new(192, 0, 2, 10)),
+            calling_station_id: None,
+            session_time: 0,
+            input_octets: 0,
+            output_octets: 0,
+            started_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_session_store() {
+        let store = SqliteSessionStore::open_in_memory().unwrap();
+        let started = session("s1", "nas1", "alice");
+        store.start(started.clone()).await.unwrap();
+        store.start(session("s2", "nas2", "alice")).await.unwrap();
+        store.start(session("s1", "nas2", "bob")).await.unwrap();
+        assert_eq!(store.len().unwrap(), 3);
+        assert_eq!(
+            store.get(&started.key).await.unwrap(),
+            Some(started.clone())
+        );
+
+        // the interim update keeps the original start time, and the octets beyond `i64` are kept
+        let mut updated = session("s1", "nas1", "alice");
+        updated.started_at = started.started_at + Duration::seconds(60);
+        updated.updated_at = updated.started_at;
+        updated.session_time = 60;
+        updated.input_octets = u64::MAX;
+        updated.output_octets = 1 << 40;
+        updated.framed_ip_address = None;
+        store.interim(updated.clone()).await.unwrap();
+        let expected = Session {
+            started_at: started.started_at,
+            ..updated
+        };
+        assert_eq!(
+            store.get(&started.key).await.unwrap(),
+            Some(expected.clone())
+        );
+
+        // the interim update of an unknown session inserts it
+        let unknown = session("s3", "nas1", "carol");
+        store.interim(unknown.clone()).await.unwrap();
+        assert_eq!(store.get(&unknown.key).await.unwrap(), Some(unknown));
+
+        let mut sessions = store.find_by_user_name("alice").await.unwrap();
+        sessions.sort_by(|a, b| a.key.session_id.cmp(&b.key.session_id));
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0], expected);
+
+        assert_eq!(store.stop(&started.key).await.unwrap(), Some(expected));
+        assert_eq!(store.stop(&started.key).await.unwrap(), None);
+        assert_eq!(store.clear_nas("nas2").await.unwrap(), 2);
+        assert_eq!(store.len().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_session_store_persistence() {
+        let path =
+            std::env::temp_dir().join(format!("radius-sessions-{}.sqlite", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let started = session("s1", "nas1", "alice");
+        SqliteSessionStore::open(&path)
+            .unwrap()
+            .start(started.clone())
+            .await
+            .unwrap();
+
+        // the sessions survive reopening
+        let store = SqliteSessionStore::open(&path).unwrap();
+        assert_eq!(store.get(&started.key).await.unwrap(), Some(started));
+
+        drop(store);
+        fs::remove_file(&path).unwrap();
+    }
+}
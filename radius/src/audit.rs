@@ -0,0 +1,285 @@
+//! Audit events of the RADIUS server.
+//!
+//! `Server` emits the structured `AuditEvent`s to an `AuditSink` (e.g. a closure or `ChannelAuditSink`),
+//! so that the events can be forwarded to a SIEM without parsing the logs.
+//!
+//! The events of the accounting requests and the dropped requests are emitted by the server itself.
+//! The results of the authentication are emitted when the handler sends the response through
+//! `Request::audit_response()`; the prebuilt handlers (e.g. `PapAuthenticationHandler`, `EapHandler`) do that.
+
+use std::net::{IpAddr, SocketAddr};
+
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+
+use crate::core::avp::AVPType;
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::rfc2865;
+
+// the same as `rfc2866::ACCT_STATUS_TYPE_TYPE` and `rfc2866::ACCT_SESSION_ID_TYPE`; that module is optional
+const ACCT_STATUS_TYPE_TYPE: AVPType = 40;
+const ACCT_SESSION_ID_TYPE: AVPType = 44;
+const ACCT_STATUS_TYPE_START: u32 = 1;
+const ACCT_STATUS_TYPE_STOP: u32 = 2;
+const ACCT_STATUS_TYPE_INTERIM_UPDATE: u32 = 3;
+const ACCT_STATUS_TYPE_ACCOUNTING_ON: u32 = 7;
+const ACCT_STATUS_TYPE_ACCOUNTING_OFF: u32 = 8;
+
+/// This enum represents the kind of an audit event.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AuditEventKind {
+    /// The user is authenticated (i.e. Access-Accept is sent).
+    AuthAccept,
+    /// The user is rejected (i.e. Access-Reject is sent).
+    AuthReject,
+    /// The user is challenged (i.e. Access-Challenge is sent).
+    AuthChallenge,
+    /// An Accounting-Request of `Acct-Status-Type = Start` is received.
+    AcctStart,
+    /// An Accounting-Request of `Acct-Status-Type = Stop` is received.
+    AcctStop,
+    /// An Accounting-Request of `Acct-Status-Type = Interim-Update` is received.
+    AcctInterimUpdate,
+    /// An Accounting-Request of `Acct-Status-Type = Accounting-On` is received.
+    AcctOn,
+    /// An Accounting-Request of `Acct-Status-Type = Accounting-Off` is received.
+    AcctOff,
+    /// The request is dropped by the server (e.g. invalid authenticator, malformed packet).
+    RequestDropped,
+    /// The request handler returns an error.
+    HandlerError,
+    /// The request handler doesn't finish by the deadline of the server.
+    HandlerTimeout,
+}
+
+/// This struct represents an audit event of the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEvent {
+    kind: AuditEventKind,
+    timestamp: DateTime<Utc>,
+    remote_addr: SocketAddr,
+    user_name: Option<String>,
+    nas_identifier: Option<String>,
+    nas_ip_address: Option<IpAddr>,
+    session_id: Option<String>,
+    reason: Option<String>,
+}
+
+impl AuditEvent {
+    /// Make an event that has the user and the NAS of the request packet.
+    pub fn new(kind: AuditEventKind, remote_addr: SocketAddr, request: Option<&Packet>) -> Self {
+        let lookup_string = |typ: AVPType| {
+            request
+                .and_then(|packet| packet.lookup(typ))
+                .and_then(|avp| avp.decode_string().ok())
+        };
+        AuditEvent {
+            kind,
+            timestamp: Utc::now(),
+            remote_addr,
+            user_name: lookup_string(rfc2865::USER_NAME_TYPE),
+            nas_identifier: lookup_string(rfc2865::NAS_IDENTIFIER_TYPE),
+            nas_ip_address: request
+                .and_then(rfc2865::lookup_nas_ip_address)
+                .and_then(|nas_ip_address| nas_ip_address.ok())
+                .map(IpAddr::V4),
+            session_id: lookup_string(ACCT_SESSION_ID_TYPE),
+            reason: None,
+        }
+    }
+
+    /// Set the reason of the event (e.g. `Reply-Message` of the Access-Reject).
+    pub fn set_reason(&mut self, reason: &str) {
+        self.reason = Some(reason.to_owned());
+    }
+
+    /// Make the event for the Accounting-Request according to its `Acct-Status-Type`,
+    /// or returns `None` for the other requests.
+    pub(crate) fn for_accounting_request(
+        remote_addr: SocketAddr,
+        request: &Packet,
+    ) -> Option<Self> {
+        if request.get_code() != Code::AccountingRequest {
+            return None;
+        }
+        let kind = match request.lookup(ACCT_STATUS_TYPE_TYPE)?.decode_u32().ok()? {
+            ACCT_STATUS_TYPE_START => AuditEventKind::AcctStart,
+            ACCT_STATUS_TYPE_STOP => AuditEventKind::AcctStop,
+            ACCT_STATUS_TYPE_INTERIM_UPDATE => AuditEventKind::AcctInterimUpdate,
+            ACCT_STATUS_TYPE_ACCOUNTING_ON => AuditEventKind::AcctOn,
+            ACCT_STATUS_TYPE_ACCOUNTING_OFF => AuditEventKind::AcctOff,
+            _ => return None,
+        };
+        Some(Self::new(kind, remote_addr, Some(request)))
+    }
+
+    /// Make the event for the response to the Access-Request, or returns `None` for the other responses.
+    pub(crate) fn for_response(
+        remote_addr: SocketAddr,
+        request: &Packet,
+        response: &Packet,
+    ) -> Option<Self> {
+        let kind = match response.get_code() {
+            Code::AccessAccept => AuditEventKind::AuthAccept,
+            Code::AccessReject => AuditEventKind::AuthReject,
+            Code::AccessChallenge => AuditEventKind::AuthChallenge,
+            _ => return None,
+        };
+        let mut event = Self::new(kind, remote_addr, Some(request));
+        if kind == AuditEventKind::AuthReject {
+            event.reason = rfc2865::lookup_reply_message(response).and_then(|message| message.ok());
+        }
+        Some(event)
+    }
+
+    pub fn get_kind(&self) -> AuditEventKind {
+        self.kind
+    }
+
+    pub fn get_timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Returns the address of the NAS (or the proxy) that sent the request.
+    pub fn get_remote_addr(&self) -> SocketAddr {
+        self.remote_addr
+    }
+
+    pub fn get_user_name(&self) -> Option<&str> {
+        self.user_name.as_deref()
+    }
+
+    pub fn get_nas_identifier(&self) -> Option<&str> {
+        self.nas_identifier.as_deref()
+    }
+
+    pub fn get_nas_ip_address(&self) -> Option<IpAddr> {
+        self.nas_ip_address
+    }
+
+    /// Returns `Acct-Session-Id` of the request.
+    pub fn get_session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
+    /// Returns the reason of the event; e.g. `Reply-Message` of the Access-Reject, the cause of the dropped request.
+    pub fn get_reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
+
+/// AuditSink receives the audit events.
+///
+/// `emit` is called in the request processing, so this must not block; hand the event off to a queue if the delivery is slow.
+pub trait AuditSink: 'static + Sync + Send {
+    fn emit(&self, event: AuditEvent);
+}
+
+impl<F: Fn(AuditEvent) + 'static + Sync + Send> AuditSink for F {
+    fn emit(&self, event: AuditEvent) {
+        self(event)
+    }
+}
+
+/// The sink that sends the events to a `tokio::sync::mpsc` channel.
+///
+/// The event is dropped with a warning when the channel is full or closed, so that a slow consumer doesn't stall the server.
+pub struct ChannelAuditSink {
+    sender: mpsc::Sender<AuditEvent>,
+}
+
+impl ChannelAuditSink {
+    pub fn new(sender: mpsc::Sender<AuditEvent>) -> Self {
+        ChannelAuditSink { sender }
+    }
+}
+
+impl AuditSink for ChannelAuditSink {
+    fn emit(&self, event: AuditEvent) {
+        if let Err(e) = self.sender.try_send(event) {
+            warn!("failed to send an audit event to the channel; {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use tokio::sync::mpsc;
+
+    use crate::audit::{AuditEvent, AuditEventKind, AuditSink, ChannelAuditSink};
+    use crate::core::avp::AVP;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2865;
+
+    fn remote_addr() -> SocketAddr {
+        "192.0.2.1:1812".parse().unwrap()
+    }
+
+    #[test]
+    fn test_event_for_response() {
+        let mut request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut request, "alice");
+        rfc2865::add_nas_identifier(&mut request, "nas01");
+        rfc2865::add_nas_ip_address(&mut request, &Ipv4Addr::new(192, 0, 2, 1));
+
+        let mut response = request.make_response_packet(Code::AccessReject);
+        rfc2865::add_reply_message(&mut response, "account is locked");
+        let event = AuditEvent::for_response(remote_addr(), &request, &response).unwrap();
+        assert_eq!(event.get_kind(), AuditEventKind::AuthReject);
+        assert_eq!(event.get_user_name(), Some("alice"));
+        assert_eq!(event.get_nas_identifier(), Some("nas01"));
+        assert_eq!(
+            event.get_nas_ip_address(),
+            Some(Ipv4Addr::new(192, 0, 2, 1).into())
+        );
+        assert_eq!(event.get_reason(), Some("account is locked"));
+
+        let response = request.make_response_packet(Code::AccessAccept);
+        let event = AuditEvent::for_response(remote_addr(), &request, &response).unwrap();
+        assert_eq!(event.get_kind(), AuditEventKind::AuthAccept);
+        assert_eq!(event.get_reason(), None);
+
+        let response = request.make_response_packet(Code::AccountingResponse);
+        assert!(AuditEvent::for_response(remote_addr(), &request, &response).is_none());
+    }
+
+    #[test]
+    fn test_event_for_accounting_request() {
+        let mut request = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2865::add_user_name(&mut request, "alice");
+        request.add(AVP::from_u32(40, 2)); // Acct-Status-Type = Stop
+        request.add(AVP::from_string(44, "session-1")); // Acct-Session-Id
+        let event = AuditEvent::for_accounting_request(remote_addr(), &request).unwrap();
+        assert_eq!(event.get_kind(), AuditEventKind::AcctStop);
+        assert_eq!(event.get_user_name(), Some("alice"));
+        assert_eq!(event.get_session_id(), Some("session-1"));
+
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        assert!(AuditEvent::for_accounting_request(remote_addr(), &request).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_channel_audit_sink() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let sink = ChannelAuditSink::new(sender);
+        sink.emit(AuditEvent::new(
+            AuditEventKind::RequestDropped,
+            remote_addr(),
+            None,
+        ));
+        // the channel is full, so this event is dropped instead of blocking
+        sink.emit(AuditEvent::new(
+            AuditEventKind::HandlerError,
+            remote_addr(),
+            None,
+        ));
+
+        let event = receiver.recv().await.unwrap();
+        assert_eq!(event.get_kind(), AuditEventKind::RequestDropped);
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -0,0 +1,312 @@
+//! Authentication backends for RADIUS servers.
+//!
+//! An `Authenticator` validates the credentials of a user against a backend (e.g. LDAP),
+//! and `PapAuthenticationHandler` serves Access-Requests with that.
+//! `otp::OtpChallengeHandler` adds a one-time password challenge as the second factor.
+//! `users::UsersFile` authenticates users by the FreeRADIUS `users` file.
+//! `verify_password` validates a password against the hashed one in a legacy user store (e.g. crypt(3) or `{SSHA}`).
+
+mod bcrypt;
+pub(crate) mod hash;
+#[cfg(feature = "auth-ldap")]
+pub mod ldap;
+pub mod otp;
+mod password;
+pub mod users;
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use thiserror::Error;
+use tokio::net::UdpSocket;
+
+use crate::core::avp::{AVPError, AVP};
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::request::Request;
+use crate::core::rfc2865;
+use crate::server::RequestHandler;
+
+pub use password::verify_password;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AuthError {
+    /// This error is raised when the request is not an Access-Request.
+    #[error("unexpected request code: {0}")]
+    UnexpectedRequestCodeError(String),
+
+    /// This error is raised when the request doesn't have the credentials (e.g. `User-Name` and `User-Password`).
+    #[error("credentials are missing: {0}")]
+    CredentialsMissingError(String),
+
+    /// This error is raised when an attribute of the request cannot be decoded.
+    #[error("failed to decode an attribute; {0}")]
+    AttributeDecodingError(AVPError),
+
+    /// This error is raised when the authentication backend fails (e.g. it is unreachable).
+    #[error("authentication backend error: {0}")]
+    BackendError(String),
+
+    /// This error is raised when the response cannot be encoded.
+    #[error("failed to encode the response: {0}")]
+    ResponseEncodingError(String),
+
+    /// This error is raised when the response cannot be sent.
+    #[error("failed to send the response: {0}")]
+    ResponseSendingError(String),
+
+    /// This error is raised when the format of the stored password hash is unknown or malformed.
+    #[error("unsupported password hash: {0}")]
+    UnsupportedPasswordHashError(String),
+}
+
+/// This struct represents the attributes to put into an Access-Accept of the authenticated user.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReplyTemplate {
+    attributes: Vec<AVP>,
+}
+
+impl ReplyTemplate {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add an attribute to the template.
+    pub fn add(&mut self, avp: AVP) {
+        self.attributes.push(avp);
+    }
+
+    /// Add all of the attributes of the other template.
+    pub fn extend(&mut self, other: &ReplyTemplate) {
+        self.attributes.extend(other.attributes.iter().cloned());
+    }
+
+    pub fn get_attributes(&self) -> &[AVP] {
+        &self.attributes
+    }
+
+    /// Add the attributes of the template to the response packet.
+    pub fn apply(&self, response: &mut Packet) {
+        response.extend(self.attributes.clone());
+    }
+}
+
+/// This enum represents the result of an authentication.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthResult {
+    /// The user is authenticated; the template is applied to the Access-Accept.
+    Accept(ReplyTemplate),
+    /// The credentials are rejected.
+    Reject,
+}
+
+/// Authenticator validates the credentials of a user against a backend.
+#[async_trait]
+pub trait Authenticator: 'static + Sync + Send {
+    /// Authenticate the user by the plain text password.
+    ///
+    /// This returns `AuthResult::Reject` for the invalid credentials, and an error only when the backend fails.
+    async fn authenticate(&self, user_name: &str, password: &[u8])
+        -> Result<AuthResult, AuthError>;
+}
+
+/// PasswordStore provides the cleartext passwords of the users,
+/// that are required by the challenge-response methods (e.g. EAP-MD5).
+#[async_trait]
+pub trait PasswordStore: 'static + Sync + Send {
+    /// Returns the password of the user, or `None` if the user doesn't exist.
+    async fn get_password(&self, user_name: &str) -> Result<Option<Vec<u8>>, AuthError>;
+}
+
+/// An in-memory implementation of the `PasswordStore`.
+#[derive(Debug, Default)]
+pub struct InMemoryPasswordStore {
+    passwords: RwLock<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryPasswordStore {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the password of the user.
+    pub fn set_password(&self, user_name: &str, password: &[u8]) {
+        self.passwords
+            .write()
+            .unwrap()
+            .insert(user_name.to_owned(), password.to_vec());
+    }
+}
+
+#[async_trait]
+impl PasswordStore for InMemoryPasswordStore {
+    async fn get_password(&self, user_name: &str) -> Result<Option<Vec<u8>>, AuthError> {
+        Ok(self.passwords.read().unwrap().get(user_name).cloned())
+    }
+}
+
+/// A prebuilt `RequestHandler` that authenticates PAP (i.e. `User-Name` and `User-Password`) Access-Requests
+/// by an `Authenticator`.
+///
+/// This responds Access-Accept or Access-Reject according to the result of the authentication.
+/// When the backend fails, this doesn't respond so that the NAS retries or fails over.
+pub struct PapAuthenticationHandler<A: Authenticator> {
+    authenticator: Arc<A>,
+}
+
+impl<A: Authenticator> PapAuthenticationHandler<A> {
+    pub fn new(authenticator: Arc<A>) -> Self {
+        PapAuthenticationHandler { authenticator }
+    }
+
+    pub fn get_authenticator(&self) -> &Arc<A> {
+        &self.authenticator
+    }
+
+    /// Authenticate the Access-Request and make the response packet.
+    pub async fn make_response(&self, request: &Request) -> Result<Packet, AuthError> {
+        let packet = request.get_packet();
+        let (user_name, password) = lookup_pap_credentials(packet)?;
+
+        match self
+            .authenticator
+            .authenticate(&user_name, &password)
+            .await?
+        {
+            AuthResult::Accept(template) => {
+                let mut response = packet.make_response_packet(Code::AccessAccept);
+                template.apply(&mut response);
+                Ok(response)
+            }
+            AuthResult::Reject => Ok(packet.make_response_packet(Code::AccessReject)),
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Authenticator> RequestHandler<(), AuthError> for PapAuthenticationHandler<A> {
+    async fn handle_radius_request(
+        &self,
+        conn: &UdpSocket,
+        request: &Request,
+    ) -> Result<(), AuthError> {
+        let response = self.make_response(request).await?;
+        send_response(conn, request, &response).await
+    }
+}
+
+/// Returns `User-Name` and `User-Password` of the Access-Request.
+pub(crate) fn lookup_pap_credentials(packet: &Packet) -> Result<(String, Vec<u8>), AuthError> {
+    if packet.get_code() != Code::AccessRequest {
+        return Err(AuthError::UnexpectedRequestCodeError(
+            packet.get_code().string().to_owned(),
+        ));
+    }
+
+    let user_name = match rfc2865::lookup_user_name(packet) {
+        Some(user_name) => user_name.map_err(AuthError::AttributeDecodingError)?,
+        None => return Err(AuthError::CredentialsMissingError("User-Name".to_owned())),
+    };
+    let password = match rfc2865::lookup_user_password(packet) {
+        Some(password) => password.map_err(AuthError::AttributeDecodingError)?,
+        None => {
+            return Err(AuthError::CredentialsMissingError(
+                "User-Password".to_owned(),
+            ))
+        }
+    };
+    Ok((user_name, password))
+}
+
+pub(crate) async fn send_response(
+    conn: &UdpSocket,
+    request: &Request,
+    response: &Packet,
+) -> Result<(), AuthError> {
+    let encoded = response
+        .encode()
+        .map_err(|e| AuthError::ResponseEncodingError(e.to_string()))?;
+    match conn.send_to(&encoded, request.get_remote_addr()).await {
+        Ok(_) => {
+            request.audit_response(response);
+            Ok(())
+        }
+        Err(e) => Err(AuthError::ResponseSendingError(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use crate::auth::{
+        AuthError, AuthResult, Authenticator, PapAuthenticationHandler, ReplyTemplate,
+    };
+    use crate::core::avp::AVP;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::rfc2865;
+
+    struct StaticAuthenticator;
+
+    #[async_trait]
+    impl Authenticator for StaticAuthenticator {
+        async fn authenticate(
+            &self,
+            user_name: &str,
+            password: &[u8],
+        ) -> Result<AuthResult, AuthError> {
+            if user_name == "alice" && password == b"password" {
+                let mut template = ReplyTemplate::new();
+                template.add(AVP::from_u32(rfc2865::SESSION_TIMEOUT_TYPE, 3600));
+                return Ok(AuthResult::Accept(template));
+            }
+            Ok(AuthResult::Reject)
+        }
+    }
+
+    fn make_request(user_name: &str, password: Option<&[u8]>) -> Request {
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut packet, user_name);
+        if let Some(password) = password {
+            rfc2865::add_user_password(&mut packet, password).unwrap();
+        }
+        Request::new(
+            "127.0.0.1:1812".parse().unwrap(),
+            "192.0.2.1:1812".parse().unwrap(),
+            packet,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_pap_authentication_handler() {
+        let handler = PapAuthenticationHandler::new(Arc::new(StaticAuthenticator));
+
+        let accept = handler
+            .make_response(&make_request("alice", Some(b"password")))
+            .await
+            .unwrap();
+        assert_eq!(accept.get_code(), Code::AccessAccept);
+        assert_eq!(
+            rfc2865::lookup_session_timeout(&accept).unwrap().unwrap(),
+            3600
+        );
+
+        let reject = handler
+            .make_response(&make_request("alice", Some(b"wrong")))
+            .await
+            .unwrap();
+        assert_eq!(reject.get_code(), Code::AccessReject);
+
+        assert_eq!(
+            handler.make_response(&make_request("alice", None)).await,
+            Err(AuthError::CredentialsMissingError(
+                "User-Password".to_owned()
+            ))
+        );
+    }
+}
@@ -0,0 +1,466 @@
+//! `Authenticator` implementation by LDAP simple bind.
+//!
+//! This module is available with the `auth-ldap` feature.
+//!
+//! The authenticator binds to the LDAP server as the user with the given password,
+//! and optionally checks the group membership of the user by the LDAP Compare operation
+//! to choose the reply templates. Only the plain LDAP (i.e. not LDAPS nor StartTLS) is supported,
+//! so the connection to the LDAP server should be protected by the network.
+//! see also: https://tools.ietf.org/html/rfc4511
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use crate::auth::{AuthError, AuthResult, Authenticator, ReplyTemplate};
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_ENUMERATED: u8 = 0x0a;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_BIND_REQUEST: u8 = 0x60;
+const TAG_BIND_RESPONSE: u8 = 0x61;
+const TAG_UNBIND_REQUEST: u8 = 0x42;
+const TAG_COMPARE_REQUEST: u8 = 0x6e;
+const TAG_COMPARE_RESPONSE: u8 = 0x6f;
+const TAG_SIMPLE_AUTHENTICATION: u8 = 0x80;
+
+const RESULT_SUCCESS: u8 = 0;
+const RESULT_COMPARE_FALSE: u8 = 5;
+const RESULT_COMPARE_TRUE: u8 = 6;
+const RESULT_NO_SUCH_OBJECT: u8 = 32;
+const RESULT_INVALID_CREDENTIALS: u8 = 49;
+
+const MAX_MESSAGE_LENGTH: usize = 65536;
+
+/// The placeholder of the user name in the bind DN template.
+pub const USER_PLACEHOLDER: &str = "{user}";
+
+/// The default attribute of a group entry that has the DNs of the members.
+pub const DEFAULT_GROUP_MEMBER_ATTRIBUTE: &str = "member";
+
+/// An `Authenticator` that authenticates a user by LDAP simple bind.
+pub struct LdapAuthenticator {
+    server_addr: String,
+    bind_dn_template: String,
+    timeout: Duration,
+    group_member_attribute: String,
+    groups: Vec<(String, ReplyTemplate)>,
+    default_template: ReplyTemplate,
+    is_group_required: bool,
+}
+
+impl LdapAuthenticator {
+    /// Make an authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `server_addr` - The address of the LDAP server with the port (e.g. `ldap.example.com:389`).
+    /// * `bind_dn_template` - The DN to bind as; `{user}` is replaced with the escaped user name (e.g. `uid={user},ou=people,dc=example,dc=com`).
+    pub fn new(server_addr: &str, bind_dn_template: &str) -> Self {
+        LdapAuthenticator {
+            server_addr: server_addr.to_owned(),
+            bind_dn_template: bind_dn_template.to_owned(),
+            timeout: Duration::from_secs(5),
+            group_member_attribute: DEFAULT_GROUP_MEMBER_ATTRIBUTE.to_owned(),
+            groups: vec![],
+            default_template: ReplyTemplate::new(),
+            is_group_required: false,
+        }
+    }
+
+    /// Set the timeout of the whole LDAP operations per authentication (default: 5 seconds).
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Set the attribute of a group entry that has the DNs of the members (default: `member`).
+    pub fn set_group_member_attribute(&mut self, attribute: &str) {
+        self.group_member_attribute = attribute.to_owned();
+    }
+
+    /// Add a group; the template is applied to the Access-Accept when the user is a member of the group.
+    pub fn add_group(&mut self, group_dn: &str, template: ReplyTemplate) {
+        self.groups.push((group_dn.to_owned(), template));
+    }
+
+    /// Set the template that is applied to the Access-Accept of every authenticated user.
+    pub fn set_default_template(&mut self, template: ReplyTemplate) {
+        self.default_template = template;
+    }
+
+    /// Set whether the user has to be a member of any group or not (default: false).
+    pub fn set_group_required(&mut self, is_group_required: bool) {
+        self.is_group_required = is_group_required;
+    }
+
+    /// Returns the DN to bind as for the user.
+    pub fn make_bind_dn(&self, user_name: &str) -> String {
+        self.bind_dn_template
+            .replace(USER_PLACEHOLDER, &escape_dn_value(user_name))
+    }
+
+    async fn authenticate_with_connection(
+        &self,
+        user_name: &str,
+        password: &[u8],
+    ) -> Result<AuthResult, AuthError> {
+        let mut conn = TcpStream::connect(&self.server_addr)
+            .await
+            .map_err(|e| AuthError::BackendError(format!("failed to connect: {}", e)))?;
+
+        let bind_dn = self.make_bind_dn(user_name);
+        let mut message_id = 1;
+        write_message(&mut conn, &bind_request(message_id, &bind_dn, password)).await?;
+        match read_result_code(&mut conn, message_id, TAG_BIND_RESPONSE).await? {
+            RESULT_SUCCESS => {}
+            RESULT_INVALID_CREDENTIALS => return Ok(AuthResult::Reject),
+            code => {
+                return Err(AuthError::BackendError(format!(
+                    "bind failed; resultCode={}",
+                    code
+                )))
+            }
+        }
+
+        let mut template = self.default_template.clone();
+        let mut is_member_of_any = false;
+        for (group_dn, group_template) in &self.groups {
+            message_id += 1;
+            let request = compare_request(
+                message_id,
+                group_dn,
+                &self.group_member_attribute,
+                bind_dn.as_bytes(),
+            );
+            write_message(&mut conn, &request).await?;
+            match read_result_code(&mut conn, message_id, TAG_COMPARE_RESPONSE).await? {
+                RESULT_COMPARE_TRUE => {
+                    is_member_of_any = true;
+                    template.extend(group_template);
+                }
+                RESULT_COMPARE_FALSE | RESULT_NO_SUCH_OBJECT => {}
+                code => {
+                    return Err(AuthError::BackendError(format!(
+                        "compare failed; resultCode={}",
+                        code
+                    )))
+                }
+            }
+        }
+
+        message_id += 1;
+        // unbind is a courtesy, so the failure doesn't matter
+        let _ = write_message(&mut conn, &unbind_request(message_id)).await;
+
+        if self.is_group_required && !is_member_of_any {
+            return Ok(AuthResult::Reject);
+        }
+        Ok(AuthResult::Accept(template))
+    }
+}
+
+#[async_trait]
+impl Authenticator for LdapAuthenticator {
+    async fn authenticate(
+        &self,
+        user_name: &str,
+        password: &[u8],
+    ) -> Result<AuthResult, AuthError> {
+        // an empty password makes an unauthenticated bind, that always succeeds (RFC 4513 section 5.1.2)
+        if user_name.is_empty() || password.is_empty() {
+            return Ok(AuthResult::Reject);
+        }
+
+        match timeout(
+            self.timeout,
+            self.authenticate_with_connection(user_name, password),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => Err(AuthError::BackendError("timed out".to_owned())),
+        }
+    }
+}
+
+/// Escape a value of a DN according to RFC 4514 section 2.4.
+fn escape_dn_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let last = value.chars().count().saturating_sub(1);
+    for (i, c) in value.chars().enumerate() {
+        match c {
+            '"' | '+' | ',' | ';' | '<' | '>' | '\\' | '=' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '#' | ' ' if i == 0 => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' ' if i == last => escaped.push_str("\\ "),
+            '\0' => escaped.push_str("\\00"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn encode_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        encoded.push(len as u8);
+    } else if len <= 0xff {
+        encoded.extend([0x81, len as u8]);
+    } else {
+        encoded.extend([0x82, (len >> 8) as u8, len as u8]);
+    }
+    encoded.extend(content);
+    encoded
+}
+
+fn encode_integer(tag: u8, value: u32) -> Vec<u8> {
+    let bs = value.to_be_bytes();
+    let mut start = bs.iter().position(|b| *b != 0).unwrap_or(3);
+    // keep the value positive in two's complement
+    if bs[start] & 0x80 != 0 {
+        start -= 1;
+    }
+    encode_tlv(tag, &bs[start..])
+}
+
+fn encode_message(message_id: u32, protocol_op: Vec<u8>) -> Vec<u8> {
+    let mut content = encode_integer(TAG_INTEGER, message_id);
+    content.extend(protocol_op);
+    encode_tlv(TAG_SEQUENCE, &content)
+}
+
+fn bind_request(message_id: u32, dn: &str, password: &[u8]) -> Vec<u8> {
+    let mut content = encode_integer(TAG_INTEGER, 3);
+    content.extend(encode_tlv(TAG_OCTET_STRING, dn.as_bytes()));
+    content.extend(encode_tlv(TAG_SIMPLE_AUTHENTICATION, password));
+    encode_message(message_id, encode_tlv(TAG_BIND_REQUEST, &content))
+}
+
+fn compare_request(message_id: u32, dn: &str, attribute: &str, value: &[u8]) -> Vec<u8> {
+    let mut assertion = encode_tlv(TAG_OCTET_STRING, attribute.as_bytes());
+    assertion.extend(encode_tlv(TAG_OCTET_STRING, value));
+    let mut content = encode_tlv(TAG_OCTET_STRING, dn.as_bytes());
+    content.extend(encode_tlv(TAG_SEQUENCE, &assertion));
+    encode_message(message_id, encode_tlv(TAG_COMPARE_REQUEST, &content))
+}
+
+fn unbind_request(message_id: u32) -> Vec<u8> {
+    encode_message(message_id, encode_tlv(TAG_UNBIND_REQUEST, &[]))
+}
+
+async fn write_message(conn: &mut TcpStream, message: &[u8]) -> Result<(), AuthError> {
+    conn.write_all(message)
+        .await
+        .map_err(|e| AuthError::BackendError(format!("failed to send a request: {}", e)))
+}
+
+/// Read a BER element from the stream; this returns the tag and the content.
+async fn read_element(conn: &mut TcpStream) -> Result<(u8, Vec<u8>), AuthError> {
+    let io_error =
+        |e: std::io::Error| AuthError::BackendError(format!("failed to receive a response: {}", e));
+
+    let mut header = [0u8; 2];
+    conn.read_exact(&mut header).await.map_err(io_error)?;
+    let len = if header[1] & 0x80 == 0 {
+        header[1] as usize
+    } else {
+        let num_octets = (header[1] & 0x7f) as usize;
+        if num_octets == 0 || num_octets > 4 {
+            return Err(AuthError::BackendError("unsupported BER length".to_owned()));
+        }
+        let mut octets = [0u8; 4];
+        conn.read_exact(&mut octets[4 - num_octets..])
+            .await
+            .map_err(io_error)?;
+        u32::from_be_bytes(octets) as usize
+    };
+    if len > MAX_MESSAGE_LENGTH {
+        return Err(AuthError::BackendError(format!(
+            "response is too large: {} bytes",
+            len
+        )));
+    }
+
+    let mut content = vec![0u8; len];
+    conn.read_exact(&mut content).await.map_err(io_error)?;
+    Ok((header[0], content))
+}
+
+/// Split a BER element from the head of the bytes; this returns the tag, the content and the rest.
+fn split_element(bs: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    if bs.len() < 2 {
+        return None;
+    }
+    let (len, header_len) = if bs[1] & 0x80 == 0 {
+        (bs[1] as usize, 2)
+    } else {
+        let num_octets = (bs[1] & 0x7f) as usize;
+        if num_octets == 0 || num_octets > 4 || bs.len() < 2 + num_octets {
+            return None;
+        }
+        let len = bs[2..2 + num_octets]
+            .iter()
+            .fold(0usize, |acc, b| (acc << 8) | *b as usize);
+        (len, 2 + num_octets)
+    };
+    if bs.len() < header_len + len {
+        return None;
+    }
+    Some((
+        bs[0],
+        &bs[header_len..header_len + len],
+        &bs[header_len + len..],
+    ))
+}
+
+/// Read an LDAPResult response of the message and returns the resultCode.
+async fn read_result_code(
+    conn: &mut TcpStream,
+    message_id: u32,
+    expected_tag: u8,
+) -> Result<u8, AuthError> {
+    let malformed = || AuthError::BackendError("malformed response".to_owned());
+
+    let (tag, message) = read_element(conn).await?;
+    if tag != TAG_SEQUENCE {
+        return Err(malformed());
+    }
+    let (tag, id, rest) = split_element(&message).ok_or_else(malformed)?;
+    if tag != TAG_INTEGER || id.iter().fold(0u32, |acc, b| (acc << 8) | *b as u32) != message_id {
+        return Err(malformed());
+    }
+    let (tag, protocol_op, _) = split_element(rest).ok_or_else(malformed)?;
+    if tag != expected_tag {
+        return Err(malformed());
+    }
+    match split_element(protocol_op).ok_or_else(malformed)? {
+        (TAG_ENUMERATED, [code], _) => Ok(*code),
+        _ => Err(malformed()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+
+    use crate::auth::ldap::{
+        escape_dn_value, read_element, split_element, LdapAuthenticator, TAG_BIND_REQUEST,
+        TAG_COMPARE_REQUEST, TAG_ENUMERATED, TAG_OCTET_STRING, TAG_SEQUENCE,
+    };
+    use crate::auth::{AuthResult, Authenticator, ReplyTemplate};
+    use crate::core::avp::AVP;
+    use crate::core::rfc2865;
+
+    /// A tiny LDAP server that accepts the bind of `uid=alice` with `password`,
+    /// and has `uid=alice` as a member of `cn=admins`.
+    async fn serve_ldap() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut conn, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    while let Ok((_, message)) = read_element(&mut conn).await {
+                        let (_, id, rest) = split_element(&message).unwrap();
+                        let (tag, op, _) = split_element(rest).unwrap();
+                        let result_code = match tag {
+                            TAG_BIND_REQUEST => {
+                                let (_, _, rest) = split_element(op).unwrap();
+                                let (_, dn, rest) = split_element(rest).unwrap();
+                                let (_, password, _) = split_element(rest).unwrap();
+                                if dn == b"uid=alice,dc=example" && password == b"password" {
+                                    0
+                                } else {
+                                    49
+                                }
+                            }
+                            TAG_COMPARE_REQUEST => {
+                                let (_, dn, rest) = split_element(op).unwrap();
+                                let (_, assertion, _) = split_element(rest).unwrap();
+                                let (_, _, rest) = split_element(assertion).unwrap();
+                                let (_, value, _) = split_element(rest).unwrap();
+                                if dn == b"cn=admins,dc=example" && value == b"uid=alice,dc=example"
+                                {
+                                    6
+                                } else {
+                                    5
+                                }
+                            }
+                            _ => return,
+                        };
+
+                        let mut result = vec![TAG_ENUMERATED, 1, result_code];
+                        result.extend([TAG_OCTET_STRING, 0, TAG_OCTET_STRING, 0]);
+                        let mut response = vec![0x02, id.len() as u8];
+                        response.extend(id);
+                        response.extend([tag + 1, result.len() as u8]);
+                        response.extend(result);
+                        let mut message = vec![TAG_SEQUENCE, response.len() as u8];
+                        message.extend(response);
+                        conn.write_all(&message).await.unwrap();
+                    }
+                });
+            }
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn test_ldap_authenticator() {
+        let addr = serve_ldap().await;
+        let mut authenticator = LdapAuthenticator::new(&addr.to_string(), "uid={user},dc=example");
+        let mut admin_template = ReplyTemplate::new();
+        admin_template.add(AVP::from_string(rfc2865::FILTER_ID_TYPE, "admin"));
+        authenticator.add_group("cn=admins,dc=example", admin_template.clone());
+        authenticator.add_group("cn=guests,dc=example", ReplyTemplate::new());
+
+        assert_eq!(
+            authenticator.authenticate("alice", b"password").await,
+            Ok(AuthResult::Accept(admin_template))
+        );
+        assert_eq!(
+            authenticator.authenticate("alice", b"wrong").await,
+            Ok(AuthResult::Reject)
+        );
+        assert_eq!(
+            authenticator.authenticate("alice", b"").await,
+            Ok(AuthResult::Reject)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ldap_authenticator_requires_group() {
+        let addr = serve_ldap().await;
+        let mut authenticator = LdapAuthenticator::new(&addr.to_string(), "uid={user},dc=example");
+        authenticator.add_group("cn=guests,dc=example", ReplyTemplate::new());
+        authenticator.set_group_required(true);
+
+        assert_eq!(
+            authenticator.authenticate("alice", b"password").await,
+            Ok(AuthResult::Reject)
+        );
+    }
+
+    #[test]
+    fn test_escape_dn_value() {
+        assert_eq!(escape_dn_value("alice"), "alice");
+        assert_eq!(
+            escape_dn_value("a,b=c+d\\e"),
+            "a\\,b\\=c\\+d\\\\e".to_owned()
+        );
+        assert_eq!(escape_dn_value("#x "), "\\#x\\ ");
+    }
+}
@@ -0,0 +1,401 @@
+//! Two-factor authentication by one-time passwords over Access-Challenge.
+//!
+//! 1. The first Access-Request has the password; that is validated by an `Authenticator`,
+//!    and the server responds an Access-Challenge with `State` and `Reply-Message` (the prompt).
+//! 2. The second Access-Request has the `State` and the one-time password in `User-Password`;
+//!    that is validated by an `OtpVerifier`, and the server responds Access-Accept or Access-Reject.
+//!
+//! see also: https://tools.ietf.org/html/rfc2865#section-4.4
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio::net::UdpSocket;
+
+use crate::auth::hash::{constant_time_eq, hmac_sha1};
+use crate::auth::{
+    lookup_pap_credentials, send_response, AuthError, AuthResult, Authenticator, ReplyTemplate,
+};
+use crate::clock::{self, Clock};
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::random::{self, RandomSource};
+use crate::core::request::Request;
+use crate::core::rfc2865;
+use crate::server::RequestHandler;
+
+/// The default `Reply-Message` of the Access-Challenge that prompts the one-time password.
+pub const DEFAULT_OTP_PROMPT: &str = "Enter your one-time password";
+
+/// OtpVerifier validates a one-time password of a user.
+#[async_trait]
+pub trait OtpVerifier: 'static + Sync + Send {
+    async fn verify(&self, user_name: &str, otp: &str) -> Result<bool, AuthError>;
+}
+
+/// Generate a TOTP value (HMAC-SHA1) at the given UNIX time.
+/// see also: https://tools.ietf.org/html/rfc6238
+pub fn totp(secret: &[u8], unix_time: u64, step: Duration, digits: u32) -> String {
+    let counter = unix_time / step.as_secs().max(1);
+    hotp(secret, counter, digits)
+}
+
+/// Generate a HOTP value of the counter.
+/// see also: https://tools.ietf.org/html/rfc4226
+pub fn hotp(secret: &[u8], counter: u64, digits: u32) -> String {
+    let hash = hmac_sha1(secret, &counter.to_be_bytes());
+    let offset = (hash[19] & 0x0f) as usize;
+    let code = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        code as u64 % 10u64.pow(digits),
+        width = digits as usize
+    )
+}
+
+/// An `OtpVerifier` by TOTP with the per-user secrets.
+pub struct TotpVerifier {
+    secrets: RwLock<HashMap<String, Vec<u8>>>,
+    step: Duration,
+    digits: u32,
+    skew: u64,
+    clock: Arc<dyn Clock>,
+}
+
+impl TotpVerifier {
+    /// Make a verifier with the parameters of the common authenticator apps (30 seconds step, 6 digits),
+    /// that accepts the values of the adjacent time steps for the clock skew.
+    pub fn new() -> Self {
+        TotpVerifier {
+            secrets: RwLock::new(HashMap::new()),
+            step: Duration::from_secs(30),
+            digits: 6,
+            skew: 1,
+            clock: clock::default_clock(),
+        }
+    }
+
+    pub fn set_step(&mut self, step: Duration) {
+        self.step = step;
+    }
+
+    pub fn set_digits(&mut self, digits: u32) {
+        self.digits = digits;
+    }
+
+    /// Set the number of the time steps to accept before and after the current one (default: 1).
+    pub fn set_skew(&mut self, skew: u64) {
+        self.skew = skew;
+    }
+
+    /// Set a clock of the current time step (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Register the shared secret of the user.
+    pub fn add_secret(&self, user_name: &str, secret: &[u8]) {
+        self.secrets
+            .write()
+            .unwrap()
+            .insert(user_name.to_owned(), secret.to_vec());
+    }
+
+    /// Verify the one-time password at the given UNIX time.
+    pub fn verify_at(&self, user_name: &str, otp: &str, unix_time: u64) -> bool {
+        let secrets = self.secrets.read().unwrap();
+        let secret = match secrets.get(user_name) {
+            Some(secret) => secret,
+            None => return false,
+        };
+
+        let step = self.step.as_secs().max(1);
+        let counter = unix_time / step;
+        let from = counter.saturating_sub(self.skew);
+        (from..=counter + self.skew).any(|counter| {
+            constant_time_eq(
+                hotp(secret, counter, self.digits).as_bytes(),
+                otp.as_bytes(),
+            )
+        })
+    }
+}
+
+impl Default for TotpVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OtpVerifier for TotpVerifier {
+    async fn verify(&self, user_name: &str, otp: &str) -> Result<bool, AuthError> {
+        Ok(self.verify_at(
+            user_name,
+            otp,
+            self.clock.now_utc().timestamp().max(0) as u64,
+        ))
+    }
+}
+
+struct PendingChallenge {
+    user_name: String,
+    template: ReplyTemplate,
+    expires_at: Instant,
+}
+
+/// A prebuilt `RequestHandler` that authenticates a user by the password and a one-time password.
+///
+/// The `State` of the challenge is a random value, that can be used only once within the timeout.
+pub struct OtpChallengeHandler<A: Authenticator, V: OtpVerifier> {
+    authenticator: Arc<A>,
+    verifier: Arc<V>,
+    prompt: String,
+    challenge_timeout: Duration,
+    challenges: Mutex<HashMap<Vec<u8>, PendingChallenge>>,
+    random_source: Arc<dyn RandomSource>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<A: Authenticator, V: OtpVerifier> OtpChallengeHandler<A, V> {
+    pub fn new(authenticator: Arc<A>, verifier: Arc<V>) -> Self {
+        OtpChallengeHandler {
+            authenticator,
+            verifier,
+            prompt: DEFAULT_OTP_PROMPT.to_owned(),
+            challenge_timeout: Duration::from_secs(60),
+            challenges: Mutex::new(HashMap::new()),
+            random_source: random::default_random_source(),
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set the `Reply-Message` of the Access-Challenge (default: `DEFAULT_OTP_PROMPT`).
+    pub fn set_prompt(&mut self, prompt: &str) {
+        self.prompt = prompt.to_owned();
+    }
+
+    /// Set the time to wait for the response of a challenge (default: 60 seconds).
+    pub fn set_challenge_timeout(&mut self, challenge_timeout: Duration) {
+        self.challenge_timeout = challenge_timeout;
+    }
+
+    /// Set a source of the `State` values (default: `ThreadRandomSource`).
+    pub fn set_random_source(&mut self, random_source: Arc<dyn RandomSource>) {
+        self.random_source = random_source;
+    }
+
+    /// Set a clock of the expiry of the challenges (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Returns the number of the challenges that wait for the response.
+    pub fn get_pending_challenges_len(&self) -> usize {
+        self.challenges.lock().unwrap().len()
+    }
+
+    /// Authenticate the Access-Request and make the response packet.
+    pub async fn make_response(&self, request: &Request) -> Result<Packet, AuthError> {
+        let packet = request.get_packet();
+        let (user_name, password) = lookup_pap_credentials(packet)?;
+
+        match rfc2865::lookup_state(packet) {
+            None => self.challenge(packet, &user_name, &password).await,
+            Some(state) => self.respond(packet, &user_name, &password, &state).await,
+        }
+    }
+
+    async fn challenge(
+        &self,
+        packet: &Packet,
+        user_name: &str,
+        password: &[u8],
+    ) -> Result<Packet, AuthError> {
+        let template = match self.authenticator.authenticate(user_name, password).await? {
+            AuthResult::Accept(template) => template,
+            AuthResult::Reject => return Ok(packet.make_response_packet(Code::AccessReject)),
+        };
+
+        let state = random::random_bytes::<16>(self.random_source.as_ref()).to_vec();
+        let now = self.clock.now();
+        {
+            let mut challenges = self.challenges.lock().unwrap();
+            challenges.retain(|_, challenge| challenge.expires_at > now);
+            challenges.insert(
+                state.clone(),
+                PendingChallenge {
+                    user_name: user_name.to_owned(),
+                    template,
+                    expires_at: now + self.challenge_timeout,
+                },
+            );
+        }
+
+        let mut response = packet.make_response_packet(Code::AccessChallenge);
+        rfc2865::add_state(&mut response, &state)
+            .map_err(|e| AuthError::ResponseEncodingError(e.to_string()))?;
+        rfc2865::add_reply_message(&mut response, &self.prompt);
+        Ok(response)
+    }
+
+    async fn respond(
+        &self,
+        packet: &Packet,
+        user_name: &str,
+        otp: &[u8],
+        state: &[u8],
+    ) -> Result<Packet, AuthError> {
+        let challenge = match self.challenges.lock().unwrap().remove(state) {
+            Some(challenge) if challenge.expires_at > self.clock.now() => challenge,
+            _ => return Ok(packet.make_response_packet(Code::AccessReject)),
+        };
+        if challenge.user_name != user_name {
+            return Ok(packet.make_response_packet(Code::AccessReject));
+        }
+
+        let otp = String::from_utf8_lossy(otp);
+        if !self.verifier.verify(user_name, &otp).await? {
+            return Ok(packet.make_response_packet(Code::AccessReject));
+        }
+
+        let mut response = packet.make_response_packet(Code::AccessAccept);
+        challenge.template.apply(&mut response);
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl<A: Authenticator, V: OtpVerifier> RequestHandler<(), AuthError> for OtpChallengeHandler<A, V> {
+    async fn handle_radius_request(
+        &self,
+        conn: &UdpSocket,
+        request: &Request,
+    ) -> Result<(), AuthError> {
+        let response = self.make_response(request).await?;
+        send_response(conn, request, &response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+
+    use crate::auth::otp::{totp, OtpChallengeHandler, TotpVerifier, DEFAULT_OTP_PROMPT};
+    use crate::auth::{AuthError, AuthResult, Authenticator, ReplyTemplate};
+    use crate::core::avp::AVP;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::rfc2865;
+
+    const SECRET: &[u8] = b"12345678901234567890";
+
+    struct StaticAuthenticator;
+
+    #[async_trait]
+    impl Authenticator for StaticAuthenticator {
+        async fn authenticate(
+            &self,
+            user_name: &str,
+            password: &[u8],
+        ) -> Result<AuthResult, AuthError> {
+            if user_name == "alice" && password == b"password" {
+                let mut template = ReplyTemplate::new();
+                template.add(AVP::from_u32(rfc2865::SESSION_TIMEOUT_TYPE, 3600));
+                return Ok(AuthResult::Accept(template));
+            }
+            Ok(AuthResult::Reject)
+        }
+    }
+
+    fn make_request(user_name: &str, password: &[u8], state: Option<&[u8]>) -> Request {
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut packet, user_name);
+        rfc2865::add_user_password(&mut packet, password).unwrap();
+        if let Some(state) = state {
+            rfc2865::add_state(&mut packet, state).unwrap();
+        }
+        Request::new(
+            "127.0.0.1:1812".parse().unwrap(),
+            "192.0.2.1:1812".parse().unwrap(),
+            packet,
+        )
+    }
+
+    #[test]
+    fn test_totp() {
+        // ref: https://tools.ietf.org/html/rfc6238#appendix-B
+        let step = Duration::from_secs(30);
+        assert_eq!(totp(SECRET, 59, step, 8), "94287082");
+        assert_eq!(totp(SECRET, 1111111109, step, 8), "07081804");
+        assert_eq!(totp(SECRET, 20000000000, step, 8), "65353130");
+
+        let verifier = TotpVerifier::new();
+        verifier.add_secret("alice", SECRET);
+        assert!(verifier.verify_at("alice", "081804", 1111111109));
+        assert!(verifier.verify_at("alice", "081804", 1111111109 + 30));
+        assert!(!verifier.verify_at("alice", "081804", 1111111109 + 60));
+        assert!(!verifier.verify_at("bob", "081804", 1111111109));
+    }
+
+    #[tokio::test]
+    async fn test_otp_challenge_handler() {
+        let verifier = TotpVerifier::new();
+        verifier.add_secret("alice", SECRET);
+        let handler = OtpChallengeHandler::new(Arc::new(StaticAuthenticator), Arc::new(verifier));
+
+        let reject = handler
+            .make_response(&make_request("alice", b"wrong", None))
+            .await
+            .unwrap();
+        assert_eq!(reject.get_code(), Code::AccessReject);
+
+        let challenge = handler
+            .make_response(&make_request("alice", b"password", None))
+            .await
+            .unwrap();
+        assert_eq!(challenge.get_code(), Code::AccessChallenge);
+        assert_eq!(
+            rfc2865::lookup_reply_message(&challenge).unwrap().unwrap(),
+            DEFAULT_OTP_PROMPT
+        );
+        let state = rfc2865::lookup_state(&challenge).unwrap();
+        assert_eq!(handler.get_pending_challenges_len(), 1);
+
+        let otp = totp(
+            SECRET,
+            Utc::now().timestamp() as u64,
+            Duration::from_secs(30),
+            6,
+        );
+        let accept = handler
+            .make_response(&make_request("alice", otp.as_bytes(), Some(&state)))
+            .await
+            .unwrap();
+        assert_eq!(accept.get_code(), Code::AccessAccept);
+        assert_eq!(
+            rfc2865::lookup_session_timeout(&accept).unwrap().unwrap(),
+            3600
+        );
+
+        // the state can be used only once
+        let replayed = handler
+            .make_response(&make_request("alice", otp.as_bytes(), Some(&state)))
+            .await
+            .unwrap();
+        assert_eq!(replayed.get_code(), Code::AccessReject);
+        assert_eq!(handler.get_pending_challenges_len(), 0);
+    }
+}
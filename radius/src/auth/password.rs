@@ -0,0 +1,367 @@
+//! Verification of the hashed passwords that are common in the legacy user stores.
+
+use crate::auth::hash::{base64_decode, constant_time_eq, md4, sha1, sha256, sha512};
+use crate::auth::{bcrypt, AuthError};
+use crate::core::dictionary::decode_hex;
+
+const CRYPT_ALPHABET: &[u8] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Verify the supplied password against the stored password.
+///
+/// The stored password is one of the following formats:
+///
+/// - crypt(3): MD5-crypt (`$1$`), SHA-256-crypt (`$5$`) and SHA-512-crypt (`$6$`), optionally with `{CRYPT}` prefix
+/// - bcrypt: `$2a$`, `$2b$` and `$2y$`
+/// - LDAP style salted digests: `{SSHA}`, `{SSHA256}`, `{SSHA512}` and `{SMD5}`, and the unsalted `{SHA}` and `{MD5}`
+/// - NT hash: `{NT}` or `$NT$` prefixed hex string
+/// - `{CLEARTEXT}` prefixed plain text
+///
+/// This returns `UnsupportedPasswordHashError` when the stored password is not any of them (e.g. the traditional DES crypt).
+pub fn verify_password(stored: &str, supplied: &[u8]) -> Result<bool, AuthError> {
+    let unsupported = || AuthError::UnsupportedPasswordHashError(hash_scheme(stored));
+
+    if let Some(cleartext) = strip_prefix_ignore_case(stored, "{CLEARTEXT}") {
+        return Ok(constant_time_eq(cleartext.as_bytes(), supplied));
+    }
+    if let Some(crypted) = strip_prefix_ignore_case(stored, "{CRYPT}") {
+        return verify_crypt(crypted, supplied).ok_or_else(unsupported);
+    }
+    if let Some(hex) =
+        strip_prefix_ignore_case(stored, "{NT}").or_else(|| stored.strip_prefix("$NT$"))
+    {
+        let expected = decode_hex(hex).ok_or_else(unsupported)?;
+        return Ok(constant_time_eq(&expected, &nt_hash(supplied)));
+    }
+    if stored.starts_with('{') {
+        return verify_digest(stored, supplied).ok_or_else(unsupported);
+    }
+    verify_crypt(stored, supplied).ok_or_else(unsupported)
+}
+
+/// Compute the NT hash, i.e. MD4 of the UTF-16LE password.
+pub(crate) fn nt_hash(password: &[u8]) -> [u8; 16] {
+    let utf16 = String::from_utf8_lossy(password)
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect::<Vec<u8>>();
+    md4(&utf16)
+}
+
+fn hash_scheme(stored: &str) -> String {
+    let end = match stored.as_bytes().first() {
+        Some(b'{') => stored.find('}').map_or(0, |i| i + 1),
+        Some(b'$') => stored[1..].find('$').map_or(0, |i| i + 2),
+        _ => 0,
+    };
+    if end == 0 {
+        "unknown".to_owned()
+    } else {
+        stored[..end].to_owned()
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(head) if head.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
+
+fn verify_digest(stored: &str, supplied: &[u8]) -> Option<bool> {
+    let end = stored.find('}')?;
+    let scheme = stored[1..end].to_ascii_uppercase();
+    let decoded = base64_decode(&stored[end + 1..])?;
+
+    let digest = |data: &[u8]| -> Option<Vec<u8>> {
+        Some(match scheme.as_str() {
+            "SHA" | "SSHA" => sha1(data).to_vec(),
+            "SHA256" | "SSHA256" => sha256(data).to_vec(),
+            "SHA512" | "SSHA512" => sha512(data).to_vec(),
+            "MD5" | "SMD5" => md5::compute(data).to_vec(),
+            _ => return None,
+        })
+    };
+    let digest_length = digest(b"")?.len();
+    let is_salted = scheme.starts_with("SS") || scheme == "SMD5";
+    if decoded.len() < digest_length || (!is_salted && decoded.len() != digest_length) {
+        return None;
+    }
+
+    // the salt follows the digest
+    let (expected, salt) = decoded.split_at(digest_length);
+    let computed = digest(&[supplied, salt].concat())?;
+    Some(constant_time_eq(expected, &computed))
+}
+
+fn verify_crypt(stored: &str, supplied: &[u8]) -> Option<bool> {
+    let mut fields = stored.split('$');
+    if !fields.next()?.is_empty() {
+        return None;
+    }
+    let scheme = fields.next()?;
+    let fields = fields.collect::<Vec<&str>>();
+
+    let (expected, computed) = match (scheme, fields.as_slice()) {
+        ("1", [salt, hash]) => (*hash, md5_crypt(supplied, salt)),
+        ("5", [salt, hash]) => (*hash, sha_crypt(supplied, salt, None, false)?),
+        ("6", [salt, hash]) => (*hash, sha_crypt(supplied, salt, None, true)?),
+        ("5", [rounds, salt, hash]) => (*hash, sha_crypt(supplied, salt, Some(rounds), false)?),
+        ("6", [rounds, salt, hash]) => (*hash, sha_crypt(supplied, salt, Some(rounds), true)?),
+        ("2a" | "2b" | "2y", [cost, salt_and_hash]) => {
+            if salt_and_hash.len() <= bcrypt::SALT_LENGTH {
+                return None;
+            }
+            let (salt, hash) = salt_and_hash.split_at(bcrypt::SALT_LENGTH);
+            (hash, bcrypt::bcrypt(supplied, cost.parse().ok()?, salt)?)
+        }
+        _ => return None,
+    };
+    Some(constant_time_eq(expected.as_bytes(), computed.as_bytes()))
+}
+
+/// Encode the bytes by the crypt(3) base64; `groups` are the indices of the 3 bytes that make 4 characters,
+/// and the last group can be shorter.
+fn encode_crypt_base64(digest: &[u8], groups: &[&[usize]]) -> String {
+    let mut encoded = String::new();
+    for group in groups {
+        let mut v: u32 = 0;
+        for i in group.iter() {
+            v = (v << 8) | digest[*i] as u32;
+        }
+        for _ in 0..=group.len() {
+            encoded.push(CRYPT_ALPHABET[(v & 0x3f) as usize] as char);
+            v >>= 6;
+        }
+    }
+    encoded
+}
+
+/// Compute the MD5-crypt hash by the salt; see also FreeBSD's `crypt-md5.c`.
+fn md5_crypt(password: &[u8], salt: &str) -> String {
+    let salt = &salt.as_bytes()[..salt.len().min(8)];
+
+    let alternate = md5::compute([password, salt, password].concat());
+    let mut context = [password, b"$1$", salt].concat();
+    let mut remaining = password.len();
+    while remaining > 0 {
+        context.extend(&alternate[..remaining.min(16)]);
+        remaining = remaining.saturating_sub(16);
+    }
+    let mut i = password.len();
+    while i > 0 {
+        if i & 1 == 1 {
+            context.push(0);
+        } else {
+            context.push(password[0]);
+        }
+        i >>= 1;
+    }
+
+    let mut digest = md5::compute(&context).to_vec();
+    for i in 0..1000 {
+        let mut context = vec![];
+        if i & 1 == 1 {
+            context.extend(password);
+        } else {
+            context.extend(&digest);
+        }
+        if i % 3 != 0 {
+            context.extend(salt);
+        }
+        if i % 7 != 0 {
+            context.extend(password);
+        }
+        if i & 1 == 1 {
+            context.extend(&digest);
+        } else {
+            context.extend(password);
+        }
+        digest = md5::compute(&context).to_vec();
+    }
+
+    encode_crypt_base64(
+        &digest,
+        &[
+            &[0, 6, 12],
+            &[1, 7, 13],
+            &[2, 8, 14],
+            &[3, 9, 15],
+            &[4, 10, 5],
+            &[11],
+        ],
+    )
+}
+
+/// Compute the SHA-crypt hash by the salt and the `rounds=N` parameter.
+/// see also: https://www.akkadia.org/drepper/SHA-crypt.txt
+fn sha_crypt(password: &[u8], salt: &str, rounds: Option<&str>, is_sha512: bool) -> Option<String> {
+    let rounds = match rounds {
+        Some(rounds) => rounds
+            .strip_prefix("rounds=")?
+            .parse::<u64>()
+            .ok()?
+            .clamp(1000, 999_999_999),
+        None => 5000,
+    };
+    let salt = &salt.as_bytes()[..salt.len().min(16)];
+    let hash = |data: &[u8]| -> Vec<u8> {
+        if is_sha512 {
+            sha512(data).to_vec()
+        } else {
+            sha256(data).to_vec()
+        }
+    };
+    let repeat = |digest: &[u8], length: usize| -> Vec<u8> {
+        digest.iter().cycle().take(length).cloned().collect()
+    };
+
+    let b = hash(&[password, salt, password].concat());
+    let mut context = [password, salt].concat();
+    context.extend(repeat(&b, password.len()));
+    let mut i = password.len();
+    while i > 0 {
+        if i & 1 == 1 {
+            context.extend(&b);
+        } else {
+            context.extend(password);
+        }
+        i >>= 1;
+    }
+    let a = hash(&context);
+
+    let p = repeat(&hash(&password.repeat(password.len())), password.len());
+    let s = repeat(&hash(&salt.repeat(16 + a[0] as usize)), salt.len());
+
+    let mut digest = a;
+    for i in 0..rounds {
+        let mut context = vec![];
+        if i & 1 == 1 {
+            context.extend(&p);
+        } else {
+            context.extend(&digest);
+        }
+        if i % 3 != 0 {
+            context.extend(&s);
+        }
+        if i % 7 != 0 {
+            context.extend(&p);
+        }
+        if i & 1 == 1 {
+            context.extend(&digest);
+        } else {
+            context.extend(&p);
+        }
+        digest = hash(&context);
+    }
+
+    Some(if is_sha512 {
+        encode_crypt_base64(
+            &digest,
+            &[
+                &[0, 21, 42],
+                &[22, 43, 1],
+                &[44, 2, 23],
+                &[3, 24, 45],
+                &[25, 46, 4],
+                &[47, 5, 26],
+                &[6, 27, 48],
+                &[28, 49, 7],
+                &[50, 8, 29],
+                &[9, 30, 51],
+                &[31, 52, 10],
+                &[53, 11, 32],
+                &[12, 33, 54],
+                &[34, 55, 13],
+                &[56, 14, 35],
+                &[15, 36, 57],
+                &[37, 58, 16],
+                &[59, 17, 38],
+                &[18, 39, 60],
+                &[40, 61, 19],
+                &[62, 20, 41],
+                &[63],
+            ],
+        )
+    } else {
+        encode_crypt_base64(
+            &digest,
+            &[
+                &[0, 10, 20],
+                &[21, 1, 11],
+                &[12, 22, 2],
+                &[3, 13, 23],
+                &[24, 4, 14],
+                &[15, 25, 5],
+                &[6, 16, 26],
+                &[27, 7, 17],
+                &[18, 28, 8],
+                &[9, 19, 29],
+                &[31, 30],
+            ],
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::password::verify_password;
+    use crate::auth::AuthError;
+
+    #[test]
+    fn test_verify_crypt() {
+        let cases = [
+            "$1$saltsalt$qjXMvbEw8oaL.CzflDtaK/",
+            "{CRYPT}$1$saltsalt$qjXMvbEw8oaL.CzflDtaK/",
+            "$2b$04$abcdefghijklmnopqrstuughE8Ev8uGFaUgY2cNEySvxngrb/Jzdm",
+        ];
+        for stored in cases {
+            assert_eq!(verify_password(stored, b"password"), Ok(true), "{}", stored);
+            assert_eq!(verify_password(stored, b"wrong"), Ok(false), "{}", stored);
+        }
+
+        // ref: https://www.akkadia.org/drepper/SHA-crypt.txt
+        let cases = [
+            "$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5",
+            "$6$rounds=5000$saltstring$svn8UoSVapNtMuq1ukKS4tPQd8iKwSMHWjl/O817G3uBnIFNjnQJuesI68u4OTLiBFdcbYEdFCoEOfaS35inz1",
+            "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW",
+        ];
+        assert_eq!(verify_password(cases[0], b"Hello world!"), Ok(true));
+        assert_eq!(verify_password(cases[1], b"Hello world!"), Ok(true));
+        assert_eq!(verify_password(cases[2], b"U*U"), Ok(true));
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        // SHA-1("password" + "salt") + "salt"
+        let cases = [
+            "{SSHA}yI6cZwQadOA1e+/f+T+H3eCQQhRzYWx0",
+            "{SHA}W6ph5Mm5Pz8GgiULbPgzG37mj9g=",
+            "{MD5}X03MO1qnZdYdgyfeuILPmQ==",
+            "{NT}8846F7EAEE8FB117AD06BDD830B7586C",
+            "$NT$8846f7eaee8fb117ad06bdd830b7586c",
+            "{cleartext}password",
+        ];
+        for stored in cases {
+            assert_eq!(verify_password(stored, b"password"), Ok(true), "{}", stored);
+            assert_eq!(verify_password(stored, b"wrong"), Ok(false), "{}", stored);
+        }
+
+        assert_eq!(
+            verify_password("abJnggxhB/yWI", b"password"),
+            Err(AuthError::UnsupportedPasswordHashError(
+                "unknown".to_owned()
+            ))
+        );
+        assert_eq!(
+            verify_password("{SSHA}!!", b"password"),
+            Err(AuthError::UnsupportedPasswordHashError("{SSHA}".to_owned()))
+        );
+        assert_eq!(
+            verify_password(
+                "$2x$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW",
+                b"U*U"
+            ),
+            Err(AuthError::UnsupportedPasswordHashError("$2x$".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,891 @@
+//! `Authenticator` implementation by the classic FreeRADIUS `users` file.
+//!
+//! The file consists of the entries that have the user name (or `DEFAULT`) and the check items on the first line,
+//! and the reply items on the following indented lines:
+//!
+//! ```text
+//! # comment
+//! bob     Cleartext-Password := "hello"
+//!         Service-Type = Framed-User,
+//!         Framed-IP-Address = 192.0.2.10,
+//!         Fall-Through = Yes
+//!
+//! DEFAULT NAS-IP-Address == 192.0.2.1
+//!         Reply-Message = "welcome"
+//! ```
+//!
+//! The entries are evaluated from the top; an entry matches when the name is the user name or `DEFAULT`
+//! and all of the comparison check items (e.g. `==`, `!=`, `>`, `=*`) match the request.
+//! The evaluation stops at the first matched entry unless that has `Fall-Through = Yes`.
+//!
+//! The check items with the assignment operators (`=`, `:=` and `+=`) are the control items; the password is given by
+//! `Cleartext-Password`, `User-Password`, `Crypt-Password`, `NT-Password`, `SSHA-Password` or `Password-With-Header`
+//! (see also `verify_password`), and `Auth-Type := Accept` or `Auth-Type := Reject` decides the result without the password.
+//!
+//! The reply items must be defined in the dictionaries of this crate, and the integer values can be written by the names
+//! of the RFC 2865/2868/3580 enumerations (e.g. `Framed-User`, `VLAN`) or by the numbers.
+//! The regular expression operators (`=~` and `!~`) and the encrypted reply attributes (e.g. `Tunnel-Password`) are not supported.
+
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::auth::password::verify_password;
+use crate::auth::{
+    lookup_pap_credentials, AuthError, AuthResult, Authenticator, PasswordStore, ReplyTemplate,
+};
+use crate::core::avp::{AVPType, AVP};
+use crate::core::dictionary::{Dictionary, DictionaryError};
+use crate::core::metadata::AttributeDataType;
+use crate::core::packet::Packet;
+use crate::core::registry;
+
+/// The name of the entry that matches any user.
+pub const DEFAULT_ENTRY_NAME: &str = "DEFAULT";
+
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// The control items that have the password, and the prefixes of the format for `verify_password`.
+const PASSWORD_ATTRIBUTES: [(&str, &str); 6] = [
+    ("Cleartext-Password", "{CLEARTEXT}"),
+    ("User-Password", "{CLEARTEXT}"),
+    ("Password-With-Header", ""),
+    ("Crypt-Password", "{CRYPT}"),
+    ("NT-Password", "{NT}"),
+    ("SSHA-Password", "{SSHA}"),
+];
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UsersFileError {
+    /// This error is raised when a line of the users file is malformed.
+    #[error("syntax error at line {0}: {1}")]
+    SyntaxError(usize, String),
+
+    /// This error is raised when an attribute of the users file is not defined in the dictionaries.
+    #[error("unknown attribute at line {0}: {1}")]
+    UnknownAttributeError(usize, String),
+
+    /// This error is raised when a value of the users file cannot be converted into the attribute.
+    #[error("invalid value at line {0}: {1}")]
+    InvalidValueError(usize, String),
+
+    /// This error is raised when the users file cannot be read.
+    #[error("failed to read the users file: {0}")]
+    FileReadingError(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Set,
+    Assign,
+    Append,
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    Present,
+    NotPresent,
+}
+
+impl Operator {
+    /// The operators in the order of the longest match.
+    const TOKENS: [(&'static str, Option<Operator>); 13] = [
+        (":=", Some(Operator::Assign)),
+        ("+=", Some(Operator::Append)),
+        ("==", Some(Operator::Equal)),
+        ("!=", Some(Operator::NotEqual)),
+        (">=", Some(Operator::GreaterThanOrEqual)),
+        ("<=", Some(Operator::LessThanOrEqual)),
+        ("=~", None),
+        ("!~", None),
+        ("=*", Some(Operator::Present)),
+        ("!*", Some(Operator::NotPresent)),
+        ("=", Some(Operator::Set)),
+        (">", Some(Operator::GreaterThan)),
+        ("<", Some(Operator::LessThan)),
+    ];
+
+    fn is_assignment(&self) -> bool {
+        matches!(self, Operator::Set | Operator::Assign | Operator::Append)
+    }
+
+    fn is_ordering(&self) -> bool {
+        matches!(
+            self,
+            Operator::GreaterThan
+                | Operator::GreaterThanOrEqual
+                | Operator::LessThan
+                | Operator::LessThanOrEqual
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum CheckItem {
+    /// A control item, that is kept as the text.
+    Control(String, Operator, String),
+    /// A comparison with the attribute of the request.
+    Request(AVPType, Operator, AVP),
+}
+
+/// The attribute names and the values of the control items.
+type ControlItems = Vec<(String, String)>;
+
+#[derive(Debug, Clone, PartialEq)]
+struct UsersEntry {
+    name: String,
+    check_items: Vec<CheckItem>,
+    reply_items: Vec<(Operator, AVP)>,
+    is_fall_through: bool,
+}
+
+/// An `Authenticator` that authenticates a user by the FreeRADIUS `users` file.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UsersFile {
+    entries: Vec<UsersEntry>,
+}
+
+impl UsersFile {
+    /// Parse the content of the users file.
+    ///
+    /// `$INCLUDE` is not supported by this; use `load()` instead.
+    pub fn parse(content: &str) -> Result<Self, UsersFileError> {
+        let mut users_file = UsersFile::default();
+        users_file.parse_content(content, None, 0)?;
+        Ok(users_file)
+    }
+
+    /// Read and parse the users file, including the files of `$INCLUDE` that are relative to the including file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, UsersFileError> {
+        let mut users_file = UsersFile::default();
+        users_file.load_file(path.as_ref(), 0)?;
+        Ok(users_file)
+    }
+
+    pub fn get_entries_len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Authenticate the PAP Access-Request, that the comparison check items are evaluated against.
+    pub async fn authenticate_request(&self, request: &Packet) -> Result<AuthResult, AuthError> {
+        let (user_name, password) = lookup_pap_credentials(request)?;
+        self.evaluate(&user_name, &password, Some(request))
+    }
+
+    /// Evaluate the entries for the user and validate the password.
+    ///
+    /// When the request is `None`, the entries that have the comparison check items never match.
+    pub fn evaluate(
+        &self,
+        user_name: &str,
+        password: &[u8],
+        request: Option<&Packet>,
+    ) -> Result<AuthResult, AuthError> {
+        let (control, reply) = match self.authorize(user_name, request) {
+            Some(authorized) => authorized,
+            None => return Ok(AuthResult::Reject),
+        };
+
+        let mut template = ReplyTemplate::new();
+        for avp in reply {
+            template.add(avp);
+        }
+
+        match lookup_control(&control, "Auth-Type") {
+            Some(auth_type) if auth_type.eq_ignore_ascii_case("Accept") => {
+                return Ok(AuthResult::Accept(template))
+            }
+            Some(auth_type) if auth_type.eq_ignore_ascii_case("Reject") => {
+                return Ok(AuthResult::Reject)
+            }
+            _ => {}
+        }
+
+        for (attribute, prefix) in PASSWORD_ATTRIBUTES.iter() {
+            if let Some(value) = lookup_control(&control, attribute) {
+                let value = match *attribute {
+                    "NT-Password" => value.trim_start_matches("0x"),
+                    _ => value,
+                };
+                return match verify_password(&format!("{}{}", prefix, value), password)? {
+                    true => Ok(AuthResult::Accept(template)),
+                    false => Ok(AuthResult::Reject),
+                };
+            }
+        }
+        Ok(AuthResult::Reject)
+    }
+
+    /// Returns the control items and the reply attributes of the matched entries, or `None` if no entry matches.
+    fn authorize(
+        &self,
+        user_name: &str,
+        request: Option<&Packet>,
+    ) -> Option<(ControlItems, Vec<AVP>)> {
+        let mut is_matched = false;
+        let mut control: ControlItems = vec![];
+        let mut reply: Vec<AVP> = vec![];
+
+        for entry in self.entries.iter() {
+            if entry.name != user_name && entry.name != DEFAULT_ENTRY_NAME {
+                continue;
+            }
+            if !entry
+                .check_items
+                .iter()
+                .all(|item| matches_request(item, request))
+            {
+                continue;
+            }
+            is_matched = true;
+
+            for item in entry.check_items.iter() {
+                if let CheckItem::Control(attribute, operator, value) = item {
+                    let exists = lookup_control(&control, attribute).is_some();
+                    match operator {
+                        Operator::Set if exists => continue,
+                        Operator::Assign => {
+                            control.retain(|(name, _)| !name.eq_ignore_ascii_case(attribute))
+                        }
+                        _ => {}
+                    }
+                    control.push((attribute.clone(), value.clone()));
+                }
+            }
+
+            for (operator, avp) in entry.reply_items.iter() {
+                let exists = reply.iter().any(|v| v.type_code() == avp.type_code());
+                match operator {
+                    Operator::Set if exists => continue,
+                    Operator::Assign => reply.retain(|v| v.type_code() != avp.type_code()),
+                    _ => {}
+                }
+                reply.push(avp.clone());
+            }
+
+            if !entry.is_fall_through {
+                break;
+            }
+        }
+
+        if is_matched {
+            Some((control, reply))
+        } else {
+            None
+        }
+    }
+
+    fn load_file(&mut self, path: &Path, depth: usize) -> Result<(), UsersFileError> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| UsersFileError::FileReadingError(format!("{}: {}", path.display(), e)))?;
+        self.parse_content(&content, Some(path), depth)
+    }
+
+    fn parse_content(
+        &mut self,
+        content: &str,
+        path: Option<&Path>,
+        depth: usize,
+    ) -> Result<(), UsersFileError> {
+        // whether the next line can have the reply items, i.e. the previous line is the first line of the entry
+        // or the reply items that end with a comma
+        let mut is_reply_allowed = false;
+        // whether the previous line ends with a comma, i.e. the next line must continue the reply items
+        let mut is_continued = false;
+
+        for (i, line) in content.lines().enumerate() {
+            let line_number = i + 1;
+            let mut cursor = Cursor::new(line, line_number);
+            cursor.skip_whitespaces();
+            if cursor.is_end() {
+                continue;
+            }
+
+            if line.starts_with(|c: char| c.is_whitespace()) {
+                let entry = match self.entries.last_mut() {
+                    Some(entry) if is_reply_allowed => entry,
+                    _ => {
+                        return Err(UsersFileError::SyntaxError(
+                            line_number,
+                            "unexpected reply items".to_owned(),
+                        ))
+                    }
+                };
+                let (items, has_trailing_comma) = cursor.read_items()?;
+                for (attribute, operator, value) in items {
+                    if !operator.is_assignment() {
+                        return Err(UsersFileError::SyntaxError(
+                            line_number,
+                            format!("invalid operator for the reply item {}", attribute),
+                        ));
+                    }
+                    if attribute.eq_ignore_ascii_case("Fall-Through") {
+                        entry.is_fall_through = parse_bool(&value).ok_or_else(|| {
+                            UsersFileError::InvalidValueError(line_number, value.clone())
+                        })?;
+                        continue;
+                    }
+                    let avp = make_avp(line_number, &attribute, &value)?;
+                    entry.reply_items.push((operator, avp));
+                }
+                is_reply_allowed = has_trailing_comma;
+                is_continued = has_trailing_comma;
+                continue;
+            }
+
+            if is_continued {
+                return Err(UsersFileError::SyntaxError(
+                    line_number,
+                    "the reply items end with a comma".to_owned(),
+                ));
+            }
+
+            let name = cursor.read_value()?;
+            if name == "$INCLUDE" {
+                cursor.skip_whitespaces();
+                let included = cursor.read_value()?;
+                let path = match path {
+                    Some(path) if depth < MAX_INCLUDE_DEPTH => path
+                        .parent()
+                        .unwrap_or_else(|| Path::new(""))
+                        .join(included),
+                    Some(_) => {
+                        return Err(UsersFileError::SyntaxError(
+                            line_number,
+                            "$INCLUDE is nested too deeply".to_owned(),
+                        ))
+                    }
+                    None => {
+                        return Err(UsersFileError::SyntaxError(
+                            line_number,
+                            "$INCLUDE is only supported by UsersFile::load()".to_owned(),
+                        ))
+                    }
+                };
+                self.load_file(&path, depth + 1)?;
+                continue;
+            }
+
+            let (items, has_trailing_comma) = cursor.read_items()?;
+            if has_trailing_comma {
+                return Err(UsersFileError::SyntaxError(
+                    line_number,
+                    "the check items end with a comma".to_owned(),
+                ));
+            }
+            let check_items = items
+                .into_iter()
+                .map(|(attribute, operator, value)| {
+                    make_check_item(line_number, attribute, operator, value)
+                })
+                .collect::<Result<Vec<CheckItem>, UsersFileError>>()?;
+
+            self.entries.push(UsersEntry {
+                name,
+                check_items,
+                reply_items: vec![],
+                is_fall_through: false,
+            });
+            is_reply_allowed = true;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Authenticator for UsersFile {
+    /// Authenticate the user by the entries; the entries that have the comparison check items never match,
+    /// so use `authenticate_request()` to evaluate them.
+    async fn authenticate(
+        &self,
+        user_name: &str,
+        password: &[u8],
+    ) -> Result<AuthResult, AuthError> {
+        self.evaluate(user_name, password, None)
+    }
+}
+
+#[async_trait]
+impl PasswordStore for UsersFile {
+    /// Returns the `Cleartext-Password` (or `User-Password`) of the matched entries.
+    async fn get_password(&self, user_name: &str) -> Result<Option<Vec<u8>>, AuthError> {
+        Ok(self.authorize(user_name, None).and_then(|(control, _)| {
+            lookup_control(&control, "Cleartext-Password")
+                .or_else(|| lookup_control(&control, "User-Password"))
+                .map(|password| password.as_bytes().to_vec())
+        }))
+    }
+}
+
+fn lookup_control<'a>(control: &'a [(String, String)], attribute: &str) -> Option<&'a str> {
+    control
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(attribute))
+        .map(|(_, value)| value.as_str())
+}
+
+fn matches_request(item: &CheckItem, request: Option<&Packet>) -> bool {
+    let (typ, operator, expected) = match item {
+        CheckItem::Control(..) => return true,
+        CheckItem::Request(typ, operator, expected) => (*typ, operator, expected),
+    };
+    let request = match request {
+        Some(request) => request,
+        None => return false,
+    };
+
+    let actual = request.lookup_all(typ);
+    match operator {
+        Operator::Present => !actual.is_empty(),
+        Operator::NotPresent => actual.is_empty(),
+        Operator::Equal => actual
+            .iter()
+            .any(|avp| avp.raw_value() == expected.raw_value()),
+        Operator::NotEqual => {
+            !actual.is_empty()
+                && actual
+                    .iter()
+                    .all(|avp| avp.raw_value() != expected.raw_value())
+        }
+        _ => {
+            let expected = match decode_number(expected) {
+                Some(v) => v,
+                None => return false,
+            };
+            actual
+                .iter()
+                .filter_map(|avp| decode_number(avp))
+                .any(|actual| match operator {
+                    Operator::GreaterThan => actual > expected,
+                    Operator::GreaterThanOrEqual => actual >= expected,
+                    Operator::LessThan => actual < expected,
+                    _ => actual <= expected,
+                })
+        }
+    }
+}
+
+fn decode_number(avp: &AVP) -> Option<u32> {
+    match avp.raw_value().len() {
+        2 => avp.decode_u16().ok().map(u32::from),
+        _ => avp.decode_u32().ok(),
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "yes" | "true" | "1" => Some(true),
+        "no" | "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn make_check_item(
+    line_number: usize,
+    attribute: String,
+    operator: Operator,
+    value: String,
+) -> Result<CheckItem, UsersFileError> {
+    if operator.is_assignment() {
+        return Ok(CheckItem::Control(attribute, operator, value));
+    }
+
+    let avp = match operator {
+        // the value of the presence operators is meaningless
+        Operator::Present | Operator::NotPresent => AVP::from_bytes(
+            registry::attribute_type(&attribute).ok_or_else(|| {
+                UsersFileError::UnknownAttributeError(line_number, attribute.clone())
+            })?,
+            &[],
+        )
+        .map_err(|e| UsersFileError::InvalidValueError(line_number, e.to_string()))?,
+        _ => make_avp(line_number, &attribute, &value)?,
+    };
+    if operator.is_ordering() {
+        let data_type =
+            registry::attribute_metadata(avp.type_code()).map(|metadata| metadata.data_type);
+        if !matches!(
+            data_type,
+            Some(AttributeDataType::Integer | AttributeDataType::Short | AttributeDataType::Date)
+        ) {
+            return Err(UsersFileError::SyntaxError(
+                line_number,
+                format!("{} cannot be compared by the ordering operator", attribute),
+            ));
+        }
+    }
+    Ok(CheckItem::Request(avp.type_code(), operator, avp))
+}
+
+/// Make an AVP from the textual value according to the data type of the attribute.
+fn make_avp(line_number: usize, attribute: &str, value: &str) -> Result<AVP, UsersFileError> {
+    static DICTIONARY: OnceLock<Dictionary> = OnceLock::new();
+    DICTIONARY
+        .get_or_init(Dictionary::builtin)
+        .make_avp(attribute, value)
+        .map_err(|e| match e {
+            DictionaryError::UnknownAttributeError(name) => {
+                UsersFileError::UnknownAttributeError(line_number, name)
+            }
+            DictionaryError::InvalidValueError(name, value) => {
+                UsersFileError::InvalidValueError(line_number, format!("{} {}", name, value))
+            }
+            e => UsersFileError::InvalidValueError(line_number, e.to_string()),
+        })
+}
+
+/// The cursor to read a line of the users file.
+struct Cursor<'a> {
+    line: &'a str,
+    position: usize,
+    line_number: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(line: &'a str, line_number: usize) -> Self {
+        Cursor {
+            line,
+            position: 0,
+            line_number,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    fn skip_whitespaces(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Whether the rest of the line is empty or a comment.
+    fn is_end(&self) -> bool {
+        let rest = self.rest().trim_start();
+        rest.is_empty() || rest.starts_with('#')
+    }
+
+    fn syntax_error(&self, message: &str) -> UsersFileError {
+        UsersFileError::SyntaxError(self.line_number, message.to_owned())
+    }
+
+    /// Read the comma separated `attribute operator value` items, and whether the items end with a comma.
+    #[allow(clippy::type_complexity)]
+    fn read_items(&mut self) -> Result<(Vec<(String, Operator, String)>, bool), UsersFileError> {
+        let mut items = vec![];
+        loop {
+            self.skip_whitespaces();
+            if self.is_end() {
+                // the items end with a comma unless there is no item
+                let has_trailing_comma = !items.is_empty();
+                return Ok((items, has_trailing_comma));
+            }
+
+            let attribute = self.read_while(|c| c.is_ascii_alphanumeric() || "-_.:".contains(c));
+            if attribute.is_empty() {
+                return Err(self.syntax_error("an attribute name is expected"));
+            }
+            self.skip_whitespaces();
+            let operator = self.read_operator(attribute)?;
+            self.skip_whitespaces();
+            let value = self.read_value()?;
+            items.push((attribute.to_owned(), operator, value));
+
+            self.skip_whitespaces();
+            if self.is_end() {
+                return Ok((items, false));
+            }
+            if !self.rest().starts_with(',') {
+                return Err(self.syntax_error("a comma is expected"));
+            }
+            self.position += 1;
+        }
+    }
+
+    fn read_while<F: Fn(char) -> bool>(&mut self, predicate: F) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    fn read_operator(&mut self, attribute: &str) -> Result<Operator, UsersFileError> {
+        for (token, operator) in Operator::TOKENS.iter() {
+            if self.rest().starts_with(token) {
+                self.position += token.len();
+                return operator.ok_or_else(|| {
+                    self.syntax_error(&format!("the operator {} is not supported", token))
+                });
+            }
+        }
+        Err(self.syntax_error(&format!("an operator is expected after {}", attribute)))
+    }
+
+    /// Read the quoted or bare value.
+    fn read_value(&mut self) -> Result<String, UsersFileError> {
+        let quote = match self.rest().chars().next() {
+            Some(c @ ('"' | '\'')) => c,
+            _ => {
+                let value = self.read_while(|c| !c.is_whitespace() && c != ',' && c != '#');
+                if value.is_empty() {
+                    return Err(self.syntax_error("a value is expected"));
+                }
+                return Ok(value.to_owned());
+            }
+        };
+
+        let mut value = String::new();
+        let mut chars = self.rest().char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, 'r')) => value.push('\r'),
+                    Some((_, 't')) => value.push('\t'),
+                    Some((_, c)) => value.push(c),
+                    None => break,
+                },
+                c if c == quote => {
+                    self.position += i + 1;
+                    return Ok(value);
+                }
+                c => value.push(c),
+            }
+        }
+        Err(self.syntax_error("the quoted value is not terminated"))
+    }
+}
+
+#[cfg(all(test, feature = "rfc2868", feature = "rfc3580"))]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::auth::users::{UsersFile, UsersFileError};
+    use crate::auth::{AuthResult, Authenticator, PasswordStore, ReplyTemplate};
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::{rfc2865, rfc2868, rfc3580};
+
+    const USERS: &str = r#"
+# the users file for the tests
+bob     Cleartext-Password := "hello"
+        Service-Type = Framed-User,
+        Framed-IP-Address = 192.0.2.10,
+        Fall-Through = Yes
+
+alice   Crypt-Password := "$1$saltsalt$qjXMvbEw8oaL.CzflDtaK/"
+        Reply-Message = "hi, \"alice\"",
+        Tunnel-Type:1 = VLAN,
+        Tunnel-Private-Group-Id:1 = "100"
+
+mallory Auth-Type := Reject
+
+DEFAULT NAS-IP-Address == 192.0.2.1, NAS-Port >= 10
+        Reply-Message := "via the NAS", Session-Timeout = 3600
+
+DEFAULT
+        Framed-MTU = 1500
+"#;
+
+    fn get_template(result: AuthResult) -> ReplyTemplate {
+        match result {
+            AuthResult::Accept(template) => template,
+            AuthResult::Reject => panic!("unexpected reject"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authenticate() {
+        let users_file = UsersFile::parse(USERS).unwrap();
+        assert_eq!(users_file.get_entries_len(), 5);
+
+        let template = get_template(users_file.authenticate("bob", b"hello").await.unwrap());
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        template.apply(&mut packet);
+        assert_eq!(
+            rfc2865::lookup_service_type(&packet).unwrap().unwrap(),
+            rfc2865::SERVICE_TYPE_FRAMED_USER
+        );
+        assert_eq!(
+            rfc2865::lookup_framed_ip_address(&packet).unwrap().unwrap(),
+            Ipv4Addr::new(192, 0, 2, 10)
+        );
+        // falls through to the last DEFAULT
+        assert_eq!(rfc2865::lookup_framed_mtu(&packet).unwrap().unwrap(), 1500);
+        assert_eq!(
+            users_file.authenticate("bob", b"wrong").await.unwrap(),
+            AuthResult::Reject
+        );
+
+        let template = get_template(users_file.authenticate("alice", b"password").await.unwrap());
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        template.apply(&mut packet);
+        assert_eq!(
+            rfc2865::lookup_reply_message(&packet).unwrap().unwrap(),
+            "hi, \"alice\""
+        );
+        let (tunnel_type, tag) = rfc2868::lookup_tunnel_type(&packet).unwrap().unwrap();
+        assert_eq!(tunnel_type, rfc3580::TUNNEL_TYPE_VLAN);
+        assert_eq!(tag.get_value(), 1);
+        // doesn't fall through
+        assert!(rfc2865::lookup_framed_mtu(&packet).is_none());
+
+        assert_eq!(
+            users_file.authenticate("mallory", b"").await.unwrap(),
+            AuthResult::Reject
+        );
+        // DEFAULT doesn't have the password
+        assert_eq!(
+            users_file.authenticate("carol", b"hello").await.unwrap(),
+            AuthResult::Reject
+        );
+
+        assert_eq!(
+            users_file.get_password("bob").await.unwrap(),
+            Some(b"hello".to_vec())
+        );
+        assert_eq!(users_file.get_password("alice").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_request() {
+        let users_file = UsersFile::parse(USERS).unwrap();
+
+        let make_request = |nas_port: u32| {
+            let mut packet = Packet::new(Code::AccessRequest, b"secret");
+            rfc2865::add_user_name(&mut packet, "bob");
+            rfc2865::add_user_password(&mut packet, b"hello").unwrap();
+            rfc2865::add_nas_ip_address(&mut packet, &Ipv4Addr::new(192, 0, 2, 1));
+            rfc2865::add_nas_port(&mut packet, nas_port);
+            packet
+        };
+
+        let template = get_template(
+            users_file
+                .authenticate_request(&make_request(10))
+                .await
+                .unwrap(),
+        );
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        template.apply(&mut packet);
+        assert_eq!(
+            rfc2865::lookup_reply_message(&packet).unwrap().unwrap(),
+            "via the NAS"
+        );
+        assert_eq!(
+            rfc2865::lookup_session_timeout(&packet).unwrap().unwrap(),
+            3600
+        );
+        // the DEFAULT that has the comparisons doesn't fall through
+        assert!(rfc2865::lookup_framed_mtu(&packet).is_none());
+
+        let template = get_template(
+            users_file
+                .authenticate_request(&make_request(9))
+                .await
+                .unwrap(),
+        );
+        let mut packet = Packet::new(Code::AccessAccept, b"secret");
+        template.apply(&mut packet);
+        assert!(rfc2865::lookup_reply_message(&packet).is_none());
+        assert_eq!(rfc2865::lookup_framed_mtu(&packet).unwrap().unwrap(), 1500);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        struct TestCase<'a> {
+            content: &'a str,
+            expected: UsersFileError,
+        }
+
+        let test_cases = [
+            TestCase {
+                content: "bob Cleartext-Password := \"hello",
+                expected: UsersFileError::SyntaxError(
+                    1,
+                    "the quoted value is not terminated".to_owned(),
+                ),
+            },
+            TestCase {
+                content: "bob\n\tNo-Such-Attribute = 1",
+                expected: UsersFileError::UnknownAttributeError(2, "No-Such-Attribute".to_owned()),
+            },
+            TestCase {
+                content: "bob\n\tFramed-IP-Address = 192.0.2",
+                expected: UsersFileError::InvalidValueError(
+                    2,
+                    "Framed-IP-Address 192.0.2".to_owned(),
+                ),
+            },
+            TestCase {
+                content: "bob\n\tReply-Message = \"a\"\n\tSession-Timeout = 10",
+                expected: UsersFileError::SyntaxError(3, "unexpected reply items".to_owned()),
+            },
+            TestCase {
+                content: "bob User-Name =~ \"^b\"",
+                expected: UsersFileError::SyntaxError(
+                    1,
+                    "the operator =~ is not supported".to_owned(),
+                ),
+            },
+            TestCase {
+                content: "$INCLUDE users.local",
+                expected: UsersFileError::SyntaxError(
+                    1,
+                    "$INCLUDE is only supported by UsersFile::load()".to_owned(),
+                ),
+            },
+        ];
+
+        for test_case in test_cases {
+            assert_eq!(
+                UsersFile::parse(test_case.content),
+                Err(test_case.expected),
+                "{}",
+                test_case.content
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load() {
+        let dir = std::env::temp_dir().join(format!("radius-users-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("users"),
+            "$INCLUDE users.local\nDEFAULT Auth-Type := Accept\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("users.local"),
+            "bob NT-Password := \"0x8846F7EAEE8FB117AD06BDD830B7586C\"\n",
+        )
+        .unwrap();
+
+        let users_file = UsersFile::load(dir.join("users")).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(users_file.get_entries_len(), 2);
+        assert!(matches!(
+            users_file.authenticate("bob", b"password").await.unwrap(),
+            AuthResult::Accept(_)
+        ));
+        assert_eq!(
+            users_file.authenticate("bob", b"wrong").await.unwrap(),
+            AuthResult::Reject
+        );
+        assert!(matches!(
+            users_file.authenticate("carol", b"anything").await.unwrap(),
+            AuthResult::Accept(_)
+        ));
+        assert!(matches!(
+            UsersFile::load(dir.join("users")),
+            Err(UsersFileError::FileReadingError(_))
+        ));
+    }
+}
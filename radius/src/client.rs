@@ -1,13 +1,37 @@
 //! RADIUS client implementation.
 
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::future::{poll_fn, Future};
 use std::net::SocketAddr;
-use std::time::Duration;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+use std::time::{Duration, Instant};
 
 use thiserror::Error;
-use tokio::net::UdpSocket;
-use tokio::time::timeout;
+use tokio::net::{lookup_host, UdpSocket};
+use tokio::sync::{oneshot, Notify};
+use tokio::task::JoinHandle;
+use tokio::time::{timeout, timeout_at};
 
-use crate::core::packet::Packet;
+use crate::clock::{self, Clock};
+use crate::conversation::{ConversationRecorder, Direction};
+use crate::core::avp::AVPType;
+use crate::core::code::Code;
+use crate::core::packet::{Packet, RADIUS_PACKET_HEADER_LENGTH};
+use crate::core::{reply_message, rfc2865};
+use crate::negative_cache::NegativeCache;
+use crate::retry::{NoRetryPolicy, RetryPolicy};
+
+/// The default delay before the request to the next address of a host is started (see also: https://tools.ietf.org/html/rfc8305#section-8).
+pub const DEFAULT_FALLBACK_DELAY: Duration = Duration::from_millis(250);
+/// The default duration for which the resolved addresses of a host are cached.
+pub const DEFAULT_RESOLUTION_TTL: Duration = Duration::from_secs(60);
+
+// the same as `rfc2869::MESSAGE_AUTHENTICATOR_TYPE`; that module is optional (i.e. `rfc2869` feature)
+const MESSAGE_AUTHENTICATOR_TYPE: AVPType = 80;
 
 #[derive(Error, Debug)]
 pub enum ClientError {
@@ -44,12 +68,211 @@ pub enum ClientError {
     /// Socket timeout means it fails to receive a response from the request target in time.
     #[error("socket timeout")]
     SocketTimeoutError(),
+
+    /// This error is raised when it fails to resolve the host name.
+    #[error("failed to resolve the host {0}; {1}")]
+    FailedResolvingHostError(String, String),
+
+    /// This error is raised when the host name is resolved to no address that is acceptable for the address family preference.
+    #[error("no address is resolved for the host {0}")]
+    NoAddressResolvedError(String),
+
+    /// This error is raised when the client that is not constructed by `Client::for_host()` sends a packet to the default host.
+    #[error("the client has no default host")]
+    NoDefaultHostError(),
+
+    /// This error is raised when all of the identifiers are in flight for the destination
+    /// and the identifier exhaustion policy is `IdentifierExhaustionPolicy::Fail`.
+    #[error("no identifier is available for {0}")]
+    IdentifierExhaustedError(String),
+
+    /// This error is raised when the response is not returned by the deadline.
+    #[error("deadline exceeded")]
+    DeadlineExceededError(),
+}
+
+/// This enum represents a strategy of the socket management of the client.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SocketStrategy {
+    /// Binds a socket with an ephemeral source port per request.
+    /// Each request has the whole identifier space, so the identifier of the packet is used as it is.
+    #[default]
+    Ephemeral,
+    /// Reuses a socket per destination, which is friendly to NAT.
+    /// The client allocates the identifier per request so that the requests in flight don't collide;
+    /// so up to 256 requests can be in flight for a destination.
+    PerDestination,
+}
+
+/// This enum represents the behavior of `SocketStrategy::PerDestination` when all of the identifiers are in flight for a destination.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum IdentifierExhaustionPolicy {
+    /// Fails the request with `ClientError::IdentifierExhaustedError` immediately,
+    /// so that the caller can shed the load or try another destination.
+    #[default]
+    Fail,
+    /// Waits until an identifier is released by a finished request; i.e. the requests are queued
+    /// as the backpressure. The wait is not bounded by the retry policy, so use `send_packet_with_deadline()`
+    /// or a timeout of the caller to bound that.
+    Wait,
+}
+
+/// This enum represents the preference of the address family for the host name resolution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum AddressFamilyPreference {
+    /// Tries IPv6 and IPv4 addresses alternately, starting from IPv6 (see also: https://tools.ietf.org/html/rfc8305#section-4).
+    #[default]
+    PreferIpv6,
+    /// Tries IPv4 and IPv6 addresses alternately, starting from IPv4.
+    PreferIpv4,
+    /// Uses only IPv6 addresses.
+    Ipv6Only,
+    /// Uses only IPv4 addresses.
+    Ipv4Only,
+}
+
+impl AddressFamilyPreference {
+    fn sort(&self, addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+        let (v6, v4): (Vec<SocketAddr>, Vec<SocketAddr>) =
+            addrs.into_iter().partition(|addr| addr.is_ipv6());
+        let (preferred, others) = match self {
+            AddressFamilyPreference::PreferIpv6 => (v6, v4),
+            AddressFamilyPreference::PreferIpv4 => (v4, v6),
+            AddressFamilyPreference::Ipv6Only => return v6,
+            AddressFamilyPreference::Ipv4Only => return v4,
+        };
+
+        let mut sorted = Vec::with_capacity(preferred.len() + others.len());
+        let mut preferred = preferred.into_iter();
+        let mut others = others.into_iter();
+        loop {
+            match (preferred.next(), others.next()) {
+                (None, None) => break,
+                (a, b) => sorted.extend(a.into_iter().chain(b)),
+            }
+        }
+        sorted
+    }
+}
+
+/// This enum represents the outcome of an Access-Request, that is mapped from the response by `Client::authenticate()`.
+///
+/// Each outcome that has been responded keeps the response packet for the advanced use (e.g. the vendor-specific attributes).
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthOutcome {
+    /// The server accepted the request; the response has the authorization attributes (e.g. `Framed-IP-Address`).
+    Accept { response: Packet },
+    /// The server rejected the request, with the `Reply-Message` text if it is given.
+    Reject {
+        reply_message: Option<String>,
+        response: Packet,
+    },
+    /// The server challenged the request; the `State` has to be sent back in the next request,
+    /// and the `Reply-Message` text is the prompt for the user if it is given.
+    Challenge {
+        state: Option<Vec<u8>>,
+        reply_message: Option<String>,
+        response: Packet,
+    },
+    /// The server didn't respond in time.
+    Timeout,
+    /// The response was invalid, e.g. it couldn't be decoded, its Response Authenticator wasn't authentic, or its code wasn't of an Access-Request.
+    Invalid(String),
+}
+
+impl AuthOutcome {
+    /// Maps the response to the outcome of the request.
+    ///
+    /// The Response Authenticator (and the Message-Authenticator if it is given) of the response are verified with the request,
+    /// so both have to be the datagrams as sent and as received, e.g. the ones returned by `Client::exchange_with_retry()`.
+    pub fn from_response(request_data: &[u8], response_data: &[u8], secret: &[u8]) -> Self {
+        if !Packet::is_authentic_response(response_data, request_data, secret) {
+            return AuthOutcome::Invalid("the response is not authentic".to_owned());
+        }
+        let response = match Packet::decode(response_data, secret) {
+            Ok(response) => response,
+            Err(e) => return AuthOutcome::Invalid(format!("{e}")),
+        };
+        if response.contains(MESSAGE_AUTHENTICATOR_TYPE)
+            && !response.is_authentic_message_authenticator(Some(
+                &request_data[4..RADIUS_PACKET_HEADER_LENGTH],
+            ))
+        {
+            return AuthOutcome::Invalid(
+                "the Message-Authenticator of the response is not authentic".to_owned(),
+            );
+        }
+
+        let reply_message = match reply_message::lookup_reply_message_text(&response) {
+            Some(Ok(text)) => Some(text),
+            Some(Err(e)) => return AuthOutcome::Invalid(format!("{e}")),
+            None => None,
+        };
+        match response.get_code() {
+            Code::AccessAccept => AuthOutcome::Accept { response },
+            Code::AccessReject => AuthOutcome::Reject {
+                reply_message,
+                response,
+            },
+            Code::AccessChallenge => AuthOutcome::Challenge {
+                state: rfc2865::lookup_state(&response),
+                reply_message,
+                response,
+            },
+            code => AuthOutcome::Invalid(format!(
+                "unexpected response code for an Access-Request: {code:?}"
+            )),
+        }
+    }
+
+    /// Returns the response packet if the server responded to the request.
+    pub fn get_response(&self) -> Option<&Packet> {
+        match self {
+            AuthOutcome::Accept { response }
+            | AuthOutcome::Reject { response, .. }
+            | AuthOutcome::Challenge { response, .. } => Some(response),
+            AuthOutcome::Timeout | AuthOutcome::Invalid(_) => None,
+        }
+    }
+
+    fn from_result(
+        result: Result<(Vec<u8>, Vec<u8>), ClientError>,
+        secret: &[u8],
+    ) -> Result<Self, ClientError> {
+        match result {
+            Ok((request_data, response_data)) => Ok(AuthOutcome::from_response(
+                &request_data,
+                &response_data,
+                secret,
+            )),
+            Err(
+                ClientError::SocketTimeoutError()
+                | ClientError::ConnectionTimeoutError()
+                | ClientError::DeadlineExceededError(),
+            ) => Ok(AuthOutcome::Timeout),
+            Err(ClientError::FailedDecodingRadiusResponseError(e)) => Ok(AuthOutcome::Invalid(e)),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 /// A basic implementation of the RADIUS client.
 pub struct Client {
     connection_timeout: Option<Duration>,
     socket_timeout: Option<Duration>,
+    host: Option<String>,
+    address_family_preference: AddressFamilyPreference,
+    fallback_delay: Option<Duration>,
+    resolution_ttl: Duration,
+    /// the resolved addresses per host, with the time when they were resolved
+    resolved_addrs: Mutex<HashMap<String, (Vec<SocketAddr>, Instant)>>,
+    socket_strategy: SocketStrategy,
+    identifier_exhaustion_policy: IdentifierExhaustionPolicy,
+    identifier_exhaustion_count: AtomicU64,
+    destination_sockets: Mutex<HashMap<SocketAddr, Arc<DestinationSocket>>>,
+    conversation_recorder: Option<ConversationRecorder>,
+    negative_cache: Option<NegativeCache>,
+    clock: Arc<dyn Clock>,
 }
 
 impl Client {
@@ -60,24 +283,664 @@ impl Client {
     /// # Arguments
     ///
     /// * `connection_timeout` - A duration of connection timeout. If the connection is not established in time, the `ConnectionTimeoutError` occurs.
-    ///                          If this value is `None`, it never timed-out.
+    ///   If this value is `None`, it never timed-out.
     /// * `socket_timeout` - A duration of socket timeout. If the response is not returned in time, the `SocketTimeoutError` occurs.
-    ///                      If this value is `None`, it never timed-out.
+    ///   If this value is `None`, it never timed-out.
     pub fn new(connection_timeout: Option<Duration>, socket_timeout: Option<Duration>) -> Self {
         Client {
             connection_timeout,
             socket_timeout,
+            host: None,
+            address_family_preference: AddressFamilyPreference::default(),
+            fallback_delay: Some(DEFAULT_FALLBACK_DELAY),
+            resolution_ttl: DEFAULT_RESOLUTION_TTL,
+            resolved_addrs: Mutex::new(HashMap::new()),
+            socket_strategy: SocketStrategy::default(),
+            identifier_exhaustion_policy: IdentifierExhaustionPolicy::default(),
+            identifier_exhaustion_count: AtomicU64::new(0),
+            destination_sockets: Mutex::new(HashMap::new()),
+            conversation_recorder: None,
+            negative_cache: None,
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// A constructor for a client of the host that is specified by a host name and a port (e.g. `radius.example.com:1812`).
+    ///
+    /// The host is resolved on demand by `send_packet_to_default_host()`; see also `send_packet_to_host()`.
+    /// The timeouts are the same as the ones of `new()`.
+    pub fn for_host(
+        host: &str,
+        connection_timeout: Option<Duration>,
+        socket_timeout: Option<Duration>,
+    ) -> Self {
+        Client {
+            host: Some(host.to_owned()),
+            ..Self::new(connection_timeout, socket_timeout)
+        }
+    }
+
+    /// Returns the default host of the client that is given by `for_host()`.
+    pub fn get_host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// Set a clock of the timeouts of the responses, i.e. the socket timeout and the retransmission timers
+    /// (default: `SystemClock`); e.g. `MockClock` for the tests of the retransmissions.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Set a preference of the address family for `send_packet_to_host()` (default: `AddressFamilyPreference::PreferIpv6`).
+    pub fn set_address_family_preference(
+        &mut self,
+        address_family_preference: AddressFamilyPreference,
+    ) {
+        self.address_family_preference = address_family_preference;
+    }
+
+    /// Set a delay before the request to the next address of a host is started while the previous ones are in flight
+    /// (default: `Some(DEFAULT_FALLBACK_DELAY)`); `None` tries the addresses one by one until each of them fails.
+    pub fn set_fallback_delay(&mut self, fallback_delay: Option<Duration>) {
+        self.fallback_delay = fallback_delay;
+    }
+
+    /// Set a duration for which the resolved addresses of a host are cached (default: `DEFAULT_RESOLUTION_TTL`).
+    pub fn set_resolution_ttl(&mut self, resolution_ttl: Duration) {
+        self.resolution_ttl = resolution_ttl;
+    }
+
+    /// This method sends a packet to the default host that is given by `for_host()`.
+    ///
+    /// If the client has no default host, the `NoDefaultHostError` occurs.
+    pub async fn send_packet_to_default_host(
+        &self,
+        request_packet: &Packet,
+    ) -> Result<Packet, ClientError> {
+        match &self.host {
+            Some(host) => self.send_packet_to_host(host, request_packet).await,
+            None => Err(ClientError::NoDefaultHostError()),
+        }
+    }
+
+    /// This method is the same as `send_packet_to_default_host()`, but retransmits the packet to each address according to the retry policy.
+    pub async fn send_packet_to_default_host_with_retry(
+        &self,
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<Packet, ClientError> {
+        match &self.host {
+            Some(host) => {
+                self.send_packet_to_host_with_retry(host, request_packet, retry_policy)
+                    .await
+            }
+            None => Err(ClientError::NoDefaultHostError()),
+        }
+    }
+
+    /// This method sends a packet to the destination that is specified by a host name and a port (e.g. `radius.example.com:1812`).
+    ///
+    /// The resolved addresses are tried in the order of the address family preference, like Happy Eyeballs (see also: RFC 8305):
+    /// the request to the next address is started when the previous one fails or doesn't respond within the fallback delay,
+    /// while the previous ones are kept in flight, and the first response wins.
+    /// So the server can receive the same request on the multiple addresses; `set_fallback_delay(None)` disables that.
+    /// The resolved addresses are cached per host for the resolution TTL, and resolved again when all of them failed.
+    pub async fn send_packet_to_host(
+        &self,
+        host: &str,
+        request_packet: &Packet,
+    ) -> Result<Packet, ClientError> {
+        let retry_policy = NoRetryPolicy {
+            timeout: self.socket_timeout,
+        };
+        self.send_packet_to_host_with_retry(host, request_packet, &retry_policy)
+            .await
+    }
+
+    /// This method is the same as `send_packet_to_host()`, but retransmits the packet to each address according to the retry policy.
+    /// See also `send_packet_with_retry()`.
+    pub async fn send_packet_to_host_with_retry(
+        &self,
+        host: &str,
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<Packet, ClientError> {
+        let (_, response) = self
+            .exchange_with_host_with_retry(host, request_packet, retry_policy)
+            .await?;
+        Self::decode_response(&response, request_packet)
+    }
+
+    /// This method is the same as `send_packet_to_host_with_retry()`, but returns the request as sent and the response as received
+    /// without decoding, like `exchange_with_retry()`.
+    pub async fn exchange_with_host_with_retry(
+        &self,
+        host: &str,
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<(Vec<u8>, Vec<u8>), ClientError> {
+        let addrs = match self.get_cached_addrs(host) {
+            Some(addrs) => addrs,
+            None => self.resolve(host).await?,
+        };
+
+        match self
+            .exchange_with_addrs(host, &addrs, request_packet, retry_policy)
+            .await
+        {
+            Ok(exchanged) => Ok(exchanged),
+            Err(e) => {
+                self.resolved_addrs.lock().unwrap().remove(host);
+                Err(e)
+            }
+        }
+    }
+
+    /// Sends the request to the addresses with the staggered starts by the fallback delay, and returns the first response;
+    /// if all of them failed, the last error is returned.
+    async fn exchange_with_addrs(
+        &self,
+        host: &str,
+        addrs: &[SocketAddr],
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<(Vec<u8>, Vec<u8>), ClientError> {
+        type Attempt<'a> = Pin<
+            Box<
+                dyn Future<Output = (SocketAddr, Result<(Vec<u8>, Vec<u8>), ClientError>)>
+                    + Send
+                    + 'a,
+            >,
+        >;
+
+        enum Event {
+            Finished(usize, SocketAddr, Result<(Vec<u8>, Vec<u8>), ClientError>),
+            FallbackElapsed,
+        }
+
+        let mut last_err = ClientError::NoAddressResolvedError(host.to_owned());
+        let mut remaining = addrs.iter();
+        let mut attempts: Vec<Attempt> = Vec::new();
+        let mut fallback = None;
+        loop {
+            // the next address is started at first, and whenever an attempt fails or the fallback delay elapses
+            if let Some(addr) = remaining.next() {
+                attempts.push(Box::pin(async move {
+                    (
+                        *addr,
+                        self.exchange_with_retry(addr, request_packet, retry_policy)
+                            .await,
+                    )
+                }));
+                fallback = self
+                    .fallback_delay
+                    .map(|fallback_delay| self.clock.sleep(fallback_delay));
+            }
+            if attempts.is_empty() {
+                return Err(last_err);
+            }
+
+            let event = poll_fn(|cx| {
+                for (i, attempt) in attempts.iter_mut().enumerate() {
+                    if let Poll::Ready((addr, result)) = attempt.as_mut().poll(cx) {
+                        return Poll::Ready(Event::Finished(i, addr, result));
+                    }
+                }
+                if let Some(sleep) = fallback.as_mut() {
+                    if sleep.as_mut().poll(cx).is_ready() {
+                        return Poll::Ready(Event::FallbackElapsed);
+                    }
+                }
+                Poll::Pending
+            })
+            .await;
+
+            match event {
+                Event::Finished(_, _, Ok(exchanged)) => return Ok(exchanged),
+                Event::Finished(i, addr, Err(e)) => {
+                    debug!("failed to send a packet to {} ({}); {}", host, addr, e);
+                    drop(attempts.swap_remove(i));
+                    last_err = e;
+                }
+                Event::FallbackElapsed => {
+                    debug!(
+                        "no response from {} in the fallback delay; trying the next address",
+                        host
+                    );
+                    fallback = None;
+                }
+            }
+        }
+    }
+
+    fn get_cached_addrs(&self, host: &str) -> Option<Vec<SocketAddr>> {
+        let now = self.clock.now();
+        let mut resolved_addrs = self.resolved_addrs.lock().unwrap();
+        match resolved_addrs.get(host) {
+            Some((addrs, resolved_at))
+                if now.duration_since(*resolved_at) < self.resolution_ttl =>
+            {
+                Some(addrs.clone())
+            }
+            Some(_) => {
+                resolved_addrs.remove(host);
+                None
+            }
+            None => None,
+        }
+    }
+
+    async fn resolve(&self, host: &str) -> Result<Vec<SocketAddr>, ClientError> {
+        let addrs = match lookup_host(host).await {
+            Ok(addrs) => addrs.collect::<Vec<_>>(),
+            Err(e) => {
+                return Err(ClientError::FailedResolvingHostError(
+                    host.to_owned(),
+                    e.to_string(),
+                ))
+            }
+        };
+
+        let addrs = self.address_family_preference.sort(addrs);
+        if addrs.is_empty() {
+            return Err(ClientError::NoAddressResolvedError(host.to_owned()));
+        }
+
+        self.resolved_addrs
+            .lock()
+            .unwrap()
+            .insert(host.to_owned(), (addrs.clone(), self.clock.now()));
+        Ok(addrs)
+    }
+
+    /// Set a strategy of the socket management for `send_packet()` (default: `SocketStrategy::Ephemeral`).
+    pub fn set_socket_strategy(&mut self, socket_strategy: SocketStrategy) {
+        self.socket_strategy = socket_strategy;
+    }
+
+    /// Set a behavior of `SocketStrategy::PerDestination` when all of the identifiers are in flight for a destination
+    /// (default: `IdentifierExhaustionPolicy::Fail`).
+    pub fn set_identifier_exhaustion_policy(
+        &mut self,
+        identifier_exhaustion_policy: IdentifierExhaustionPolicy,
+    ) {
+        self.identifier_exhaustion_policy = identifier_exhaustion_policy;
+    }
+
+    /// Returns the number of the requests that found all of the identifiers in flight for the destination,
+    /// i.e. the requests that failed or waited by the identifier exhaustion policy.
+    pub fn get_identifier_exhaustion_count(&self) -> u64 {
+        self.identifier_exhaustion_count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of the requests in flight on the shared socket for the destination of `SocketStrategy::PerDestination`.
+    pub fn get_in_flight_count(&self, remote_addr: &SocketAddr) -> usize {
+        match self.destination_sockets.lock().unwrap().get(remote_addr) {
+            Some(destination_socket) => destination_socket.pending.lock().unwrap().senders.len(),
+            None => 0,
+        }
+    }
+
+    /// Waits until all of the requests in flight on the shared sockets of `SocketStrategy::PerDestination` are finished,
+    /// and then closes those sockets; e.g. for the graceful shutdown.
+    ///
+    /// The requests that are started while draining are also waited for; the sockets are opened again on demand.
+    pub async fn drain(&self) {
+        loop {
+            let destination_sockets: Vec<Arc<DestinationSocket>> = self
+                .destination_sockets
+                .lock()
+                .unwrap()
+                .values()
+                .cloned()
+                .collect();
+            for destination_socket in &destination_sockets {
+                destination_socket.wait_drained().await;
+            }
+
+            let mut sockets = self.destination_sockets.lock().unwrap();
+            sockets.retain(|_, destination_socket| {
+                !destination_socket
+                    .pending
+                    .lock()
+                    .unwrap()
+                    .senders
+                    .is_empty()
+            });
+            if sockets.is_empty() {
+                return;
+            }
+        }
+    }
+
+    /// Set a recorder of the packets that this client sends and receives, including the retransmissions (default: none).
+    pub fn set_conversation_recorder(&mut self, conversation_recorder: ConversationRecorder) {
+        self.conversation_recorder = Some(conversation_recorder);
+    }
+
+    /// Set a negative cache to answer the repeated requests after Access-Reject by `authenticate()` without sending those (default: none).
+    pub fn set_negative_cache(&mut self, negative_cache: NegativeCache) {
+        self.negative_cache = Some(negative_cache);
+    }
+
+    pub fn get_negative_cache(&self) -> Option<&NegativeCache> {
+        self.negative_cache.as_ref()
+    }
+
+    fn record(&self, direction: Direction, conn: &UdpSocket, remote_addr: &SocketAddr, bs: &[u8]) {
+        if let Some(conversation_recorder) = &self.conversation_recorder {
+            let local_addr = match conn.local_addr() {
+                Ok(local_addr) => local_addr,
+                Err(e) => {
+                    debug!("failed to get the local address to record a packet; {}", e);
+                    return;
+                }
+            };
+            match direction {
+                Direction::Outgoing => {
+                    conversation_recorder.record(direction, local_addr, *remote_addr, bs)
+                }
+                Direction::Incoming => {
+                    conversation_recorder.record(direction, *remote_addr, local_addr, bs)
+                }
+            }
         }
     }
 
     /// This method sends a packet to the destination.
     ///
-    /// This method doesn't support auto retransmission when something failed, so if you need such a feature you have to implement that.
+    /// This method doesn't support auto retransmission when something failed, so if you need such a feature you have to use `send_packet_with_retry()`.
+    ///
+    /// The returned future is cancel-safe; when it is dropped before completion, the allocated identifier is reclaimed
+    /// and the response that arrives later is dropped.
+    ///
+    /// When the socket strategy is `SocketStrategy::PerDestination`, the identifier of the packet is overwritten by the allocated one.
     pub async fn send_packet(
         &self,
         remote_addr: &SocketAddr,
         request_packet: &Packet,
     ) -> Result<Packet, ClientError> {
+        let retry_policy = NoRetryPolicy {
+            timeout: self.socket_timeout,
+        };
+        self.send_packet_with_retry(remote_addr, request_packet, &retry_policy)
+            .await
+    }
+
+    /// This method sends an Access-Request to the destination, and maps the response to an `AuthOutcome`.
+    ///
+    /// The timeouts are mapped to `AuthOutcome::Timeout`, and the undecodable or the non-authentic responses are mapped to `AuthOutcome::Invalid`;
+    /// the other failures (e.g. the socket errors) remain as the errors.
+    ///
+    /// If the client has a negative cache, the request in the cooldown after Access-Reject is not sent
+    /// and the cached `AuthOutcome::Reject` is returned.
+    pub async fn authenticate(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+    ) -> Result<AuthOutcome, ClientError> {
+        let retry_policy = NoRetryPolicy {
+            timeout: self.socket_timeout,
+        };
+        self.authenticate_with_retry(remote_addr, request_packet, &retry_policy)
+            .await
+    }
+
+    /// This method is the same as `authenticate()`, but retransmits the request according to the retry policy like `send_packet_with_retry()`.
+    pub async fn authenticate_with_retry(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<AuthOutcome, ClientError> {
+        if let Some(outcome) = self
+            .negative_cache
+            .as_ref()
+            .and_then(|negative_cache| negative_cache.lookup(request_packet))
+        {
+            debug!(
+                "the request to {} is suppressed by the negative cache",
+                remote_addr
+            );
+            return Ok(outcome);
+        }
+
+        let result = self
+            .exchange_with_retry(remote_addr, request_packet, retry_policy)
+            .await;
+        let outcome = AuthOutcome::from_result(result, request_packet.get_secret())?;
+        if let Some(negative_cache) = &self.negative_cache {
+            negative_cache.record(request_packet, &outcome);
+        }
+        Ok(outcome)
+    }
+
+    /// This method sends a packet to the destination, and gives up when the response is not returned by the deadline.
+    ///
+    /// The deadline covers the whole of the request, including the connection establishment.
+    /// If it exceeds the deadline, the `DeadlineExceededError` occurs.
+    pub async fn send_packet_with_deadline(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+        deadline: Instant,
+    ) -> Result<Packet, ClientError> {
+        match timeout_at(
+            deadline.into(),
+            self.send_packet(remote_addr, request_packet),
+        )
+        .await
+        {
+            Ok(response) => response,
+            Err(_) => Err(ClientError::DeadlineExceededError()),
+        }
+    }
+
+    /// This method sends a packet to the destination, and retransmits it according to the retry policy when its response is not returned in time.
+    ///
+    /// The retransmitted packet is identical to the original one (i.e. the same identifier and authenticator) and sent from the same socket,
+    /// so that the server can detect the duplication. The socket timeout of the client is not used; the retry policy decides the timeouts.
+    /// If all of the transmissions are timed-out, the `SocketTimeoutError` occurs.
+    pub async fn send_packet_with_retry(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<Packet, ClientError> {
+        let (_, response) = self
+            .exchange_with_retry(remote_addr, request_packet, retry_policy)
+            .await?;
+        Self::decode_response(&response, request_packet)
+    }
+
+    /// This method is the same as `send_packet_with_retry()`, but returns the request as sent and the response as received
+    /// without decoding, e.g. to verify the Response Authenticator with `Packet::is_authentic_response()`.
+    ///
+    /// The sent request can differ from the encoded `request_packet` when the socket strategy assigns the identifier.
+    pub async fn exchange_with_retry(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<(Vec<u8>, Vec<u8>), ClientError> {
+        match self.socket_strategy {
+            SocketStrategy::Ephemeral => {
+                self.send_packet_ephemeral(remote_addr, request_packet, retry_policy)
+                    .await
+            }
+            SocketStrategy::PerDestination => {
+                self.send_packet_per_destination(remote_addr, request_packet, retry_policy)
+                    .await
+            }
+        }
+    }
+
+    async fn send_packet_ephemeral(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<(Vec<u8>, Vec<u8>), ClientError> {
+        let conn = self.bind_and_connect(remote_addr).await?;
+
+        let request_data = match request_packet.encode() {
+            Ok(encoded) => encoded,
+            Err(e) => return Err(ClientError::FailedRadiusPacketEncodingError(format!("{e}"))),
+        };
+
+        let started_at = self.clock.now();
+        let mut attempt = 1;
+        let mut previous_timeout = None;
+        while let Some(socket_timeout) = retry_policy.next_timeout(
+            attempt,
+            previous_timeout,
+            self.clock.now().duration_since(started_at),
+        ) {
+            if let Some(response) = clock::timeout(
+                self.clock.as_ref(),
+                socket_timeout,
+                self.request(&conn, &request_data, remote_addr),
+            )
+            .await
+            {
+                return Ok((request_data, response?));
+            }
+            attempt += 1;
+            previous_timeout = Some(socket_timeout);
+        }
+
+        Err(ClientError::SocketTimeoutError())
+    }
+
+    async fn send_packet_per_destination(
+        &self,
+        remote_addr: &SocketAddr,
+        request_packet: &Packet,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<(Vec<u8>, Vec<u8>), ClientError> {
+        let destination_socket = self.get_destination_socket(remote_addr).await?;
+
+        let mut exhausted = false;
+        let (identifier, mut receiver) = loop {
+            // take a permit of the release before trying, so that a release in between is not missed
+            let released = destination_socket.released.notified();
+            let (sender, receiver) = oneshot::channel();
+            if let Some(identifier) = destination_socket.register(sender) {
+                break (identifier, receiver);
+            }
+
+            if !exhausted {
+                exhausted = true;
+                self.identifier_exhaustion_count
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+            match self.identifier_exhaustion_policy {
+                IdentifierExhaustionPolicy::Fail => {
+                    return Err(ClientError::IdentifierExhaustedError(
+                        remote_addr.to_string(),
+                    ))
+                }
+                IdentifierExhaustionPolicy::Wait => {
+                    debug!(
+                        "waiting for an identifier to be released for {}",
+                        remote_addr
+                    );
+                    released.await;
+                }
+            }
+        };
+        // the identifier is released when this guard is dropped, even if this future is cancelled
+        let _pending_guard = PendingGuard {
+            destination_socket: &destination_socket,
+            identifier,
+        };
+
+        let mut request_packet = request_packet.clone();
+        request_packet.set_identifier(identifier);
+        let request_data = match request_packet.encode() {
+            Ok(encoded) => encoded,
+            Err(e) => return Err(ClientError::FailedRadiusPacketEncodingError(format!("{e}"))),
+        };
+        destination_socket.set_request(identifier, &request_data, request_packet.get_secret());
+
+        let started_at = self.clock.now();
+        let mut attempt = 1;
+        let mut previous_timeout = None;
+        while let Some(socket_timeout) = retry_policy.next_timeout(
+            attempt,
+            previous_timeout,
+            self.clock.now().duration_since(started_at),
+        ) {
+            self.record(
+                Direction::Outgoing,
+                &destination_socket.conn,
+                remote_addr,
+                &request_data,
+            );
+            if let Err(e) = destination_socket.conn.send(&request_data).await {
+                return Err(ClientError::FailedSendingRadiusPacketError(
+                    remote_addr.to_string(),
+                    e.to_string(),
+                ));
+            }
+            match clock::timeout(self.clock.as_ref(), socket_timeout, &mut receiver).await {
+                Some(Ok(response)) => {
+                    self.record(
+                        Direction::Incoming,
+                        &destination_socket.conn,
+                        remote_addr,
+                        &response,
+                    );
+                    return Ok((request_data, response));
+                }
+                Some(Err(e)) => {
+                    return Err(ClientError::FailedReceivingResponseError(
+                        remote_addr.to_string(),
+                        e.to_string(),
+                    ))
+                }
+                None => {}
+            }
+            attempt += 1;
+            previous_timeout = Some(socket_timeout);
+        }
+
+        Err(ClientError::SocketTimeoutError())
+    }
+
+    async fn get_destination_socket(
+        &self,
+        remote_addr: &SocketAddr,
+    ) -> Result<Arc<DestinationSocket>, ClientError> {
+        if let Some(destination_socket) = self.destination_sockets.lock().unwrap().get(remote_addr)
+        {
+            return Ok(destination_socket.clone());
+        }
+
+        let conn = Arc::new(self.bind_and_connect(remote_addr).await?);
+        let pending = Arc::new(Mutex::new(PendingRequests::default()));
+        let reader = tokio::spawn(DestinationSocket::read_responses(
+            conn.clone(),
+            pending.clone(),
+        ));
+        let destination_socket = Arc::new(DestinationSocket {
+            conn,
+            pending,
+            released: Notify::new(),
+            drained: Notify::new(),
+            reader,
+        });
+
+        Ok(self
+            .destination_sockets
+            .lock()
+            .unwrap()
+            .entry(*remote_addr)
+            .or_insert(destination_socket)
+            .clone())
+    }
+
+    async fn bind_and_connect(&self, remote_addr: &SocketAddr) -> Result<UdpSocket, ClientError> {
         let local_addr: SocketAddr = if remote_addr.is_ipv4() {
             "0.0.0.0:0"
         } else {
@@ -101,27 +964,11 @@ impl Client {
             None => self.connect(&conn, remote_addr).await,
         }?;
 
-        let request_data = match request_packet.encode() {
-            Ok(encoded) => encoded,
-            Err(e) => return Err(ClientError::FailedRadiusPacketEncodingError(format!("{e}"))),
-        };
-
-        let response = match self.socket_timeout {
-            Some(socket_timeout) => {
-                match timeout(
-                    socket_timeout,
-                    self.request(&conn, &request_data, remote_addr),
-                )
-                .await
-                {
-                    Ok(response) => response,
-                    Err(_) => Err(ClientError::SocketTimeoutError()),
-                }
-            }
-            None => self.request(&conn, &request_data, remote_addr).await,
-        }?;
+        Ok(conn)
+    }
 
-        match Packet::decode(&response.to_vec(), request_packet.get_secret()) {
+    fn decode_response(response: &[u8], request_packet: &Packet) -> Result<Packet, ClientError> {
+        match Packet::decode(response, request_packet.get_secret()) {
             Ok(response_packet) => Ok(response_packet),
             Err(e) => Err(ClientError::FailedDecodingRadiusResponseError(format!(
                 "{e}"
@@ -145,6 +992,7 @@ impl Client {
         request_data: &[u8],
         remote_addr: &SocketAddr,
     ) -> Result<Vec<u8>, ClientError> {
+        self.record(Direction::Outgoing, conn, remote_addr, request_data);
         match conn.send(request_data).await {
             Ok(_) => {}
             Err(e) => {
@@ -157,7 +1005,10 @@ impl Client {
 
         let mut buf = vec![0; Self::MAX_DATAGRAM_SIZE];
         match conn.recv(&mut buf).await {
-            Ok(len) => Ok(buf[..len].to_vec()),
+            Ok(len) => {
+                self.record(Direction::Incoming, conn, remote_addr, &buf[..len]);
+                Ok(buf[..len].to_vec())
+            }
             Err(e) => Err(ClientError::FailedReceivingResponseError(
                 remote_addr.to_string(),
                 e.to_string(),
@@ -165,3 +1016,757 @@ impl Client {
         }
     }
 }
+
+/// A request in flight on the shared socket.
+struct PendingRequest {
+    /// the sender is taken when the response is dispatched
+    sender: Option<oneshot::Sender<Vec<u8>>>,
+    /// the header of the encoded request and the secret to verify the Response Authenticator;
+    /// these are set before the request is sent, and the datagrams that arrive before that are dropped
+    request_header: Vec<u8>,
+    secret: Vec<u8>,
+}
+
+#[derive(Default)]
+struct PendingRequests {
+    next_identifier: u8,
+    /// the identifiers in flight; the identifier is released when the request is finished
+    senders: HashMap<u8, PendingRequest>,
+}
+
+/// A socket that is shared by the requests to the same destination.
+/// The responses are dispatched to the requests by the identifier, and only the authentic ones are dispatched
+/// so that a late or spoofed datagram doesn't take the place of the response of the request that reuses the identifier.
+struct DestinationSocket {
+    conn: Arc<UdpSocket>,
+    pending: Arc<Mutex<PendingRequests>>,
+    /// notified per released identifier, for the requests that wait for an identifier
+    released: Notify,
+    /// notified when no request is in flight, for `Client::drain()`
+    drained: Notify,
+    reader: JoinHandle<()>,
+}
+
+impl DestinationSocket {
+    /// Allocates an identifier that is not in flight and registers the sender for the response.
+    fn register(&self, sender: oneshot::Sender<Vec<u8>>) -> Option<u8> {
+        let mut pending = self.pending.lock().unwrap();
+        for _ in 0..=u8::MAX as usize {
+            let identifier = pending.next_identifier;
+            pending.next_identifier = pending.next_identifier.wrapping_add(1);
+            if let Entry::Vacant(entry) = pending.senders.entry(identifier) {
+                entry.insert(PendingRequest {
+                    sender: Some(sender),
+                    request_header: vec![],
+                    secret: vec![],
+                });
+                return Some(identifier);
+            }
+        }
+        None
+    }
+
+    /// Sets the encoded request of the identifier to verify its response.
+    fn set_request(&self, identifier: u8, request_data: &[u8], secret: &[u8]) {
+        if let Some(pending_request) = self.pending.lock().unwrap().senders.get_mut(&identifier) {
+            pending_request.request_header = request_data[..RADIUS_PACKET_HEADER_LENGTH].to_vec();
+            pending_request.secret = secret.to_vec();
+        }
+    }
+
+    fn release(&self, identifier: u8) {
+        let drained = {
+            let mut pending = self.pending.lock().unwrap();
+            pending.senders.remove(&identifier);
+            pending.senders.is_empty()
+        };
+        self.released.notify_one();
+        if drained {
+            self.drained.notify_waiters();
+        }
+    }
+
+    async fn wait_drained(&self) {
+        loop {
+            let drained = self.drained.notified();
+            if self.pending.lock().unwrap().senders.is_empty() {
+                return;
+            }
+            drained.await;
+        }
+    }
+
+    async fn read_responses(conn: Arc<UdpSocket>, pending: Arc<Mutex<PendingRequests>>) {
+        let mut buf = vec![0; Client::MAX_DATAGRAM_SIZE];
+        loop {
+            let len = match conn.recv(&mut buf).await {
+                Ok(len) => len,
+                Err(e) => {
+                    debug!("failed to receive a response on the shared socket; {}", e);
+                    continue;
+                }
+            };
+            if len < 2 {
+                continue;
+            }
+
+            // a late response (i.e. its request has been finished) is dropped here,
+            // and so is the datagram that isn't authentic for the request in flight, keeping the slot for the genuine response
+            let identifier = buf[1];
+            let sender = match pending.lock().unwrap().senders.get_mut(&identifier) {
+                Some(pending_request)
+                    if Packet::is_authentic_response(
+                        &buf[..len],
+                        &pending_request.request_header,
+                        &pending_request.secret,
+                    ) =>
+                {
+                    pending_request.sender.take()
+                }
+                Some(_) => {
+                    debug!(
+                        "dropped a response that is not authentic for identifier {}",
+                        identifier
+                    );
+                    None
+                }
+                None => {
+                    debug!("dropped a response for unknown identifier {}", identifier);
+                    None
+                }
+            };
+            if let Some(sender) = sender {
+                let _ = sender.send(buf[..len].to_vec());
+            }
+        }
+    }
+}
+
+impl Drop for DestinationSocket {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+struct PendingGuard<'a> {
+    destination_socket: &'a DestinationSocket,
+    identifier: u8,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.destination_socket.release(self.identifier);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use tokio::net::UdpSocket;
+    use tokio::sync::mpsc;
+    use tokio::time::timeout;
+
+    use crate::client::{
+        AddressFamilyPreference, AuthOutcome, Client, ClientError, IdentifierExhaustionPolicy,
+        SocketStrategy,
+    };
+    use crate::clock::MockClock;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2865;
+    use crate::negative_cache::NegativeCache;
+    use crate::retry::{ExponentialBackoffRetryPolicy, NoRetryPolicy};
+
+    #[test]
+    fn test_address_family_preference() {
+        let addrs: Vec<SocketAddr> = vec![
+            "192.0.2.1:1812".parse().unwrap(),
+            "192.0.2.2:1812".parse().unwrap(),
+            "[2001:db8::1]:1812".parse().unwrap(),
+        ];
+
+        assert_eq!(
+            AddressFamilyPreference::PreferIpv6.sort(addrs.clone()),
+            vec![addrs[2], addrs[0], addrs[1]]
+        );
+        assert_eq!(
+            AddressFamilyPreference::PreferIpv4.sort(addrs.clone()),
+            vec![addrs[0], addrs[2], addrs[1]]
+        );
+        assert_eq!(
+            AddressFamilyPreference::Ipv6Only.sort(addrs.clone()),
+            vec![addrs[2]]
+        );
+        assert_eq!(
+            AddressFamilyPreference::Ipv4Only.sort(addrs.clone()),
+            vec![addrs[0], addrs[1]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_per_destination_socket() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            let mut source_addrs = HashSet::new();
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                source_addrs.insert(addr);
+                assert_eq!(source_addrs.len(), 1);
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                let response = request.make_response_packet(Code::AccessAccept);
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let mut client = Client::new(None, Some(Duration::from_secs(1)));
+        client.set_socket_strategy(SocketStrategy::PerDestination);
+
+        let request = Packet::new_with_identifier(Code::AccessRequest, b"secret", 100);
+        let (first, second) = tokio::join!(
+            client.send_packet(&server_addr, &request),
+            client.send_packet(&server_addr, &request),
+        );
+        let mut identifiers = vec![
+            first.unwrap().get_identifier(),
+            second.unwrap().get_identifier(),
+        ];
+        identifiers.sort();
+        assert_eq!(identifiers, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_per_destination_socket_drops_stale_response() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+            let request = Packet::decode(&buf[..len], b"secret").unwrap();
+
+            // a late response of the former request that had the same identifier, and then the genuine one
+            let former_request = Packet::new_with_identifier(
+                Code::AccessRequest,
+                b"secret",
+                request.get_identifier(),
+            );
+            for response in [
+                former_request.make_response_packet(Code::AccessReject),
+                request.make_response_packet(Code::AccessAccept),
+            ] {
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let mut client = Client::new(None, Some(Duration::from_secs(1)));
+        client.set_socket_strategy(SocketStrategy::PerDestination);
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        let response = client.send_packet(&server_addr, &request).await.unwrap();
+        assert_eq!(response.get_code(), Code::AccessAccept);
+    }
+
+    /// Starts a server that holds the responses until the gate is opened.
+    async fn gated_server() -> (SocketAddr, mpsc::Sender<()>) {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        let (gate, mut opened) = mpsc::channel(1);
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            let mut held = vec![];
+            let mut open = false;
+            loop {
+                tokio::select! {
+                    received = server.recv_from(&mut buf) => {
+                        let (len, addr) = received.unwrap();
+                        let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                        let response = request.make_response_packet(Code::AccessAccept).encode().unwrap();
+                        if open {
+                            server.send_to(&response, addr).await.unwrap();
+                        } else {
+                            held.push((response, addr));
+                        }
+                    }
+                    Some(_) = opened.recv(), if !open => {
+                        open = true;
+                        for (response, addr) in held.drain(..) {
+                            server.send_to(&response, addr).await.unwrap();
+                        }
+                    }
+                }
+            }
+        });
+        (server_addr, gate)
+    }
+
+    async fn wait_until<F: Fn() -> bool>(condition: F) {
+        for _ in 0..500 {
+            if condition() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("timed out");
+    }
+
+    #[tokio::test]
+    async fn test_identifier_exhaustion() {
+        let (server_addr, gate) = gated_server().await;
+        let mut client = Client::new(None, Some(Duration::from_secs(10)));
+        client.set_socket_strategy(SocketStrategy::PerDestination);
+        let client = Arc::new(client);
+
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        let mut handles = vec![];
+        for _ in 0..256 {
+            let (client, request) = (client.clone(), request.clone());
+            handles.push(tokio::spawn(async move {
+                client.send_packet(&server_addr, &request).await
+            }));
+        }
+        wait_until(|| client.get_in_flight_count(&server_addr) == 256).await;
+
+        match client.send_packet(&server_addr, &request).await {
+            Err(ClientError::IdentifierExhaustedError(_)) => {}
+            result => panic!("unexpected result: {result:?}"),
+        }
+        assert_eq!(client.get_identifier_exhaustion_count(), 1);
+
+        gate.send(()).await.unwrap();
+        for handle in handles {
+            assert!(handle.await.unwrap().is_ok());
+        }
+        assert_eq!(client.get_in_flight_count(&server_addr), 0);
+    }
+
+    #[tokio::test]
+    async fn test_identifier_exhaustion_wait_and_drain() {
+        let (server_addr, gate) = gated_server().await;
+        let mut client = Client::new(None, Some(Duration::from_secs(10)));
+        client.set_socket_strategy(SocketStrategy::PerDestination);
+        client.set_identifier_exhaustion_policy(IdentifierExhaustionPolicy::Wait);
+        let client = Arc::new(client);
+
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        let mut handles = vec![];
+        for _ in 0..256 {
+            let (client, request) = (client.clone(), request.clone());
+            handles.push(tokio::spawn(async move {
+                client.send_packet(&server_addr, &request).await
+            }));
+        }
+        wait_until(|| client.get_in_flight_count(&server_addr) == 256).await;
+
+        // the request beyond the identifier space waits for a released identifier
+        let waiting = {
+            let (client, request) = (client.clone(), request.clone());
+            tokio::spawn(async move { client.send_packet(&server_addr, &request).await })
+        };
+        wait_until(|| client.get_identifier_exhaustion_count() == 1).await;
+        assert!(!waiting.is_finished());
+
+        let drained = {
+            let client = client.clone();
+            tokio::spawn(async move { client.drain().await })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!drained.is_finished());
+
+        gate.send(()).await.unwrap();
+        for handle in handles {
+            assert!(handle.await.unwrap().is_ok());
+        }
+        assert!(waiting.await.unwrap().is_ok());
+        drained.await.unwrap();
+        assert_eq!(client.get_in_flight_count(&server_addr), 0);
+        assert_eq!(client.get_identifier_exhaustion_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_packet_with_retry() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            let mut received = 0;
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                received += 1;
+                // drops the first transmission of each request
+                if received % 2 == 1 {
+                    continue;
+                }
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                let response = request.make_response_packet(Code::AccessAccept);
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let retry_policy = ExponentialBackoffRetryPolicy {
+            initial_timeout: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_timeout: Duration::from_millis(400),
+            max_attempts: 3,
+            max_duration: None,
+            jitter: 0.0,
+        };
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        for socket_strategy in [SocketStrategy::Ephemeral, SocketStrategy::PerDestination] {
+            let mut client = Client::new(None, None);
+            client.set_socket_strategy(socket_strategy);
+            let response = client
+                .send_packet_with_retry(&server_addr, &request, &retry_policy)
+                .await
+                .unwrap();
+            assert_eq!(response.get_code(), Code::AccessAccept);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_packet_with_retry_on_mock_clock() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        let (received_sender, mut received_receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                // never responds
+                let (len, _) = server.recv_from(&mut buf).await.unwrap();
+                received_sender.send(buf[..len].to_vec()).unwrap();
+            }
+        });
+
+        let retry_policy = ExponentialBackoffRetryPolicy {
+            initial_timeout: Duration::from_secs(10),
+            multiplier: 2.0,
+            max_timeout: Duration::from_secs(60),
+            max_attempts: 3,
+            max_duration: None,
+            jitter: 0.0,
+        };
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        for socket_strategy in [SocketStrategy::Ephemeral, SocketStrategy::PerDestination] {
+            let clock = MockClock::default();
+            let mut client = Client::new(None, None);
+            client.set_socket_strategy(socket_strategy);
+            client.set_clock(Arc::new(clock.clone()));
+            let request = request.clone();
+            let retry_policy = retry_policy.clone();
+            let sending = tokio::spawn(async move {
+                client
+                    .send_packet_with_retry(&server_addr, &request, &retry_policy)
+                    .await
+            });
+
+            // the timeouts are 10, 20 and 40 seconds, which elapse without the real sleeps
+            for timeout in [10, 20, 40] {
+                received_receiver.recv().await.unwrap();
+                while clock.get_pending_sleeps_len() == 0 {
+                    tokio::task::yield_now().await;
+                }
+                clock.advance(Duration::from_secs(timeout - 1));
+                tokio::task::yield_now().await;
+                assert!(!sending.is_finished());
+                clock.advance(Duration::from_secs(1));
+            }
+            assert!(matches!(
+                sending.await.unwrap(),
+                Err(ClientError::SocketTimeoutError())
+            ));
+            assert!(received_receiver.try_recv().is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_packet_to_host() {
+        let silent = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let silent_addr = silent.local_addr().unwrap();
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                let response = request.make_response_packet(Code::AccessAccept);
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        let retry_policy = NoRetryPolicy { timeout: None };
+
+        // the address that never responds doesn't block the next one even without the socket timeout
+        let mut client = Client::new(None, None);
+        client.set_fallback_delay(Some(Duration::from_millis(50)));
+        let (_, response) = timeout(
+            Duration::from_secs(5),
+            client.exchange_with_addrs(
+                "host",
+                &[silent_addr, server_addr],
+                &request,
+                &retry_policy,
+            ),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            Packet::decode(&response, b"secret").unwrap().get_code(),
+            Code::AccessAccept
+        );
+        let mut buf = vec![0; 4096];
+        assert!(silent.try_recv_from(&mut buf).is_ok());
+
+        // the resolved addresses are cached for the resolution TTL
+        let clock = MockClock::default();
+        let mut client = Client::for_host(&server_addr.to_string(), None, None);
+        client.set_clock(Arc::new(clock.clone()));
+        client.set_resolution_ttl(Duration::from_secs(10));
+        let response = client.send_packet_to_default_host(&request).await.unwrap();
+        assert_eq!(response.get_code(), Code::AccessAccept);
+        let host = server_addr.to_string();
+        assert_eq!(client.get_cached_addrs(&host), Some(vec![server_addr]));
+        clock.advance(Duration::from_secs(9));
+        assert_eq!(client.get_cached_addrs(&host), Some(vec![server_addr]));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(client.get_cached_addrs(&host), None);
+
+        assert!(matches!(
+            Client::new(None, None)
+                .send_packet_to_default_host(&request)
+                .await,
+            Err(ClientError::NoDefaultHostError())
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_packet_with_deadline() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            // doesn't respond to the first request until the second one arrives
+            let (len, _) = server.recv_from(&mut buf).await.unwrap();
+            let cancelled_request = Packet::decode(&buf[..len], b"secret").unwrap();
+            let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+            let request = Packet::decode(&buf[..len], b"secret").unwrap();
+
+            for response in [
+                cancelled_request.make_response_packet(Code::AccessReject),
+                request.make_response_packet(Code::AccessAccept),
+            ] {
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let mut client = Client::new(None, Some(Duration::from_secs(1)));
+        client.set_socket_strategy(SocketStrategy::PerDestination);
+        let request = Packet::new(Code::AccessRequest, b"secret");
+
+        let deadline = Instant::now() + Duration::from_millis(100);
+        assert!(matches!(
+            client
+                .send_packet_with_deadline(&server_addr, &request, deadline)
+                .await,
+            Err(ClientError::DeadlineExceededError())
+        ));
+
+        // the identifier of the cancelled request has been reclaimed
+        let destination_socket = client.get_destination_socket(&server_addr).await.unwrap();
+        assert!(destination_socket
+            .pending
+            .lock()
+            .unwrap()
+            .senders
+            .is_empty());
+
+        // the late response is dropped without affecting the next request
+        let response = client.send_packet(&server_addr, &request).await.unwrap();
+        assert_eq!(response.get_code(), Code::AccessAccept);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                let mut response = match rfc2865::lookup_user_name(&request)
+                    .unwrap()
+                    .unwrap()
+                    .as_str()
+                {
+                    "accept" => request.make_response_packet(Code::AccessAccept),
+                    "reject" => {
+                        let mut response = request.make_response_packet(Code::AccessReject);
+                        rfc2865::add_reply_message(&mut response, "Invalid ");
+                        rfc2865::add_reply_message(&mut response, "password");
+                        response
+                    }
+                    "challenge" => {
+                        let mut response = request.make_response_packet(Code::AccessChallenge);
+                        rfc2865::add_state(&mut response, b"state").unwrap();
+                        rfc2865::add_reply_message(&mut response, "Enter the OTP");
+                        response
+                    }
+                    "forged" => {
+                        let mut response = Packet::new_with_identifier(
+                            Code::AccessAccept,
+                            b"forged",
+                            request.get_identifier(),
+                        );
+                        let mut authenticator = [0; 16];
+                        authenticator.copy_from_slice(request.get_authenticator());
+                        response.set_authenticator(authenticator);
+                        response
+                    }
+                    "accounting" => request.make_response_packet(Code::AccountingResponse),
+                    _ => continue,
+                };
+                rfc2865::add_user_name(&mut response, "response");
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let client = Client::new(None, Some(Duration::from_millis(100)));
+        let authenticate = |user_name: &str| {
+            let mut request = Packet::new(Code::AccessRequest, b"secret");
+            rfc2865::add_user_name(&mut request, user_name);
+            let client = &client;
+            async move { client.authenticate(&server_addr, &request).await.unwrap() }
+        };
+
+        let outcome = authenticate("accept").await;
+        assert!(matches!(outcome, AuthOutcome::Accept { .. }));
+        assert_eq!(
+            rfc2865::lookup_user_name(outcome.get_response().unwrap())
+                .unwrap()
+                .unwrap(),
+            "response"
+        );
+
+        let outcome = authenticate("reject").await;
+        assert!(matches!(
+            outcome,
+            AuthOutcome::Reject { reply_message: Some(ref reply_message), .. } if reply_message == "Invalid password"
+        ));
+
+        let outcome = authenticate("challenge").await;
+        assert!(matches!(
+            outcome,
+            AuthOutcome::Challenge { state: Some(ref state), reply_message: Some(ref reply_message), .. }
+                if state == b"state" && reply_message == "Enter the OTP"
+        ));
+
+        assert!(matches!(
+            authenticate("forged").await,
+            AuthOutcome::Invalid(_)
+        ));
+        assert!(matches!(
+            authenticate("accounting").await,
+            AuthOutcome::Invalid(_)
+        ));
+
+        let outcome = authenticate("silent").await;
+        assert_eq!(outcome, AuthOutcome::Timeout);
+        assert!(outcome.get_response().is_none());
+    }
+
+    #[test]
+    fn test_auth_outcome_from_response() {
+        let mut request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut request, "user");
+        let request_data = request.encode().unwrap();
+        let mut response = request.make_response_packet(Code::AccessAccept);
+        rfc2865::add_user_name(&mut response, "response");
+        let response_data = response.encode().unwrap();
+
+        assert!(matches!(
+            AuthOutcome::from_response(&request_data, &response_data, b"secret"),
+            AuthOutcome::Accept { .. }
+        ));
+        // the response signed with the other secret
+        assert!(matches!(
+            AuthOutcome::from_response(&request_data, &response_data, b"wrong"),
+            AuthOutcome::Invalid(_)
+        ));
+        // the response to the other request
+        let other_request_data = Packet::new(Code::AccessRequest, b"secret")
+            .encode()
+            .unwrap();
+        assert!(matches!(
+            AuthOutcome::from_response(&other_request_data, &response_data, b"secret"),
+            AuthOutcome::Invalid(_)
+        ));
+        // the tampered response
+        let mut tampered = response_data.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            AuthOutcome::from_response(&request_data, &tampered, b"secret"),
+            AuthOutcome::Invalid(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_with_negative_cache() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        let (received_sender, mut received_receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                received_sender.send(()).unwrap();
+                let response = request.make_response_packet(Code::AccessReject);
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let mut client = Client::new(None, Some(Duration::from_secs(1)));
+        client.set_negative_cache(NegativeCache::new(Duration::from_secs(60)));
+        let mut request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut request, "alice");
+
+        for _ in 0..3 {
+            let outcome = client.authenticate(&server_addr, &request).await.unwrap();
+            assert!(matches!(outcome, AuthOutcome::Reject { .. }));
+        }
+        // only the first request reaches the server
+        received_receiver.recv().await.unwrap();
+        assert!(received_receiver.try_recv().is_err());
+        assert_eq!(
+            client.get_negative_cache().unwrap().get_suppressed_count(),
+            2
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Clock abstraction for the time-dependent logic, i.e. the retransmission timers, the expiry of the conversations
+//! and the caches, and `Event-Timestamp`.
+//!
+//! The default is `SystemClock`; `MockClock` is a manually advanced clock, so the tests can go through
+//! the timeouts and the expiry without the real sleeps.
+
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::oneshot;
+
+/// This trait represents a source of the current time and the timers.
+#[async_trait]
+pub trait Clock: 'static + Send + Sync + fmt::Debug {
+    /// Returns the current monotonic time, which is for the timeouts and the expiry.
+    fn now(&self) -> Instant;
+
+    /// Returns the current wall-clock time, which is for the timestamps (e.g. `Event-Timestamp`).
+    fn now_utc(&self) -> DateTime<Utc>;
+
+    /// Waits until the duration has elapsed on this clock.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// `SystemClock` is the default clock that is backed by the system time and the timers of tokio.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn now_utc(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+struct MockClockState {
+    now: Instant,
+    now_utc: DateTime<Utc>,
+    /// the sleeps that wait for the deadline; `None` is the deadline that never comes
+    sleeps: Vec<(Option<Instant>, oneshot::Sender<()>)>,
+}
+
+/// `MockClock` is a clock that stands still until `advance()` is called;
+/// `sleep()` completes when the clock is advanced past its deadline.
+///
+/// The clones of this share the time, so the same clock can be given to the client and be advanced by the test.
+#[derive(Clone)]
+pub struct MockClock {
+    state: Arc<Mutex<MockClockState>>,
+}
+
+impl MockClock {
+    /// A constructor for a clock that starts at the given wall-clock time.
+    pub fn new(now_utc: DateTime<Utc>) -> Self {
+        MockClock {
+            state: Arc::new(Mutex::new(MockClockState {
+                now: Instant::now(),
+                now_utc,
+                sleeps: Vec::new(),
+            })),
+        }
+    }
+
+    /// Advance the clock by the duration, and complete the sleeps of which deadline has come.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.now += duration;
+        state.now_utc += chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::MAX);
+        let now = state.now;
+        let (elapsed, pending) = std::mem::take(&mut state.sleeps)
+            .into_iter()
+            .filter(|(_, sender)| !sender.is_closed())
+            .partition::<Vec<_>, _>(|(deadline, _)| deadline.is_some_and(|d| d <= now));
+        state.sleeps = pending;
+        for (_, sender) in elapsed {
+            let _ = sender.send(());
+        }
+    }
+
+    /// Returns the number of the sleeps that wait for the clock to be advanced;
+    /// e.g. a test can wait for the client to start waiting for a response.
+    pub fn get_pending_sleeps_len(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        state.sleeps.retain(|(_, sender)| !sender.is_closed());
+        state.sleeps.len()
+    }
+}
+
+impl fmt::Debug for MockClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockClock")
+            .field("now_utc", &self.now_utc())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(Utc::now())
+    }
+}
+
+#[async_trait]
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.state.lock().unwrap().now
+    }
+
+    fn now_utc(&self) -> DateTime<Utc> {
+        self.state.lock().unwrap().now_utc
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        let receiver = {
+            let mut state = self.state.lock().unwrap();
+            if duration.is_zero() {
+                return;
+            }
+            let (sender, receiver) = oneshot::channel();
+            let deadline = state.now.checked_add(duration);
+            state.sleeps.push((deadline, sender));
+            receiver
+        };
+        let _ = receiver.await;
+    }
+}
+
+/// Returns the default clock, i.e. `SystemClock`.
+pub fn default_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// Waits for the future up to the duration on the clock; it returns `None` if the duration has elapsed.
+pub(crate) async fn timeout<F: Future>(
+    clock: &dyn Clock,
+    duration: Duration,
+    future: F,
+) -> Option<F::Output> {
+    tokio::select! {
+        output = future => Some(output),
+        _ = clock.sleep(duration) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::clock::{timeout, Clock, MockClock};
+
+    #[tokio::test]
+    async fn test_mock_clock() {
+        let clock = MockClock::new(Utc.timestamp_opt(1_600_000_000, 0).unwrap());
+        let started_at = clock.now();
+
+        let sleeping_clock = clock.clone();
+        let sleep = tokio::spawn(async move {
+            timeout(
+                &sleeping_clock,
+                Duration::from_secs(10),
+                std::future::pending::<()>(),
+            )
+            .await
+        });
+        while clock.get_pending_sleeps_len() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        clock.advance(Duration::from_secs(9));
+        tokio::task::yield_now().await;
+        assert!(!sleep.is_finished());
+        assert_eq!(clock.get_pending_sleeps_len(), 1);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(sleep.await.unwrap(), None);
+        assert_eq!(clock.get_pending_sleeps_len(), 0);
+        assert_eq!(clock.now() - started_at, Duration::from_secs(10));
+        assert_eq!(clock.now_utc().timestamp(), 1_600_000_010);
+
+        // the future that completes in time is not affected by the clock
+        assert_eq!(
+            timeout(&clock, Duration::from_secs(1), async { 42 }).await,
+            Some(42)
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Recorder of the packets that are exchanged by the client or the server, to debug the interoperability problems
+//! (e.g. a challenge-response conversation that a vendor's NAS doesn't follow).
+//!
+//! `Client::set_conversation_recorder()` records the requests and the responses (including the retransmissions),
+//! and `Server::set_conversation_recorder()` records the requests and the responses that the handlers send
+//! through `Request::audit_response()`. The recorded packets can be dumped as a text transcript or a pcap file.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::core::packet::Packet;
+use crate::core::redact::Redact;
+use crate::pcap::{self, PcapError};
+
+/// This enum represents the direction of a recorded packet from the viewpoint of the recorder's owner.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    /// The packet is sent by the client or the server.
+    Outgoing,
+    /// The packet is received by the client or the server.
+    Incoming,
+}
+
+/// This struct represents a packet that is recorded by `ConversationRecorder`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedPacket {
+    timestamp: DateTime<Utc>,
+    direction: Direction,
+    src_addr: SocketAddr,
+    dst_addr: SocketAddr,
+    bytes: Vec<u8>,
+}
+
+impl RecordedPacket {
+    pub fn get_timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    pub fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn get_src_addr(&self) -> SocketAddr {
+        self.src_addr
+    }
+
+    pub fn get_dst_addr(&self) -> SocketAddr {
+        self.dst_addr
+    }
+
+    /// Returns the bytes of the packet as they are on the wire.
+    pub fn get_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// `ConversationRecorder` keeps the packets that are exchanged, up to the given number of the packets;
+/// the oldest packet is dropped when it is full.
+///
+/// The clones of this share the recorded packets, so the same recorder can be given to the client and be dumped later.
+#[derive(Debug, Clone)]
+pub struct ConversationRecorder {
+    packets: Arc<Mutex<VecDeque<RecordedPacket>>>,
+    max_packets: usize,
+}
+
+impl ConversationRecorder {
+    pub fn new(max_packets: usize) -> Self {
+        ConversationRecorder {
+            packets: Arc::new(Mutex::new(VecDeque::new())),
+            max_packets,
+        }
+    }
+
+    /// Record a packet that is exchanged between the addresses.
+    pub fn record(
+        &self,
+        direction: Direction,
+        src_addr: SocketAddr,
+        dst_addr: SocketAddr,
+        bytes: &[u8],
+    ) {
+        if self.max_packets == 0 {
+            return;
+        }
+        let mut packets = self.packets.lock().unwrap();
+        while packets.len() >= self.max_packets {
+            packets.pop_front();
+        }
+        packets.push_back(RecordedPacket {
+            timestamp: Utc::now(),
+            direction,
+            src_addr,
+            dst_addr,
+            bytes: bytes.to_vec(),
+        });
+    }
+
+    /// Returns the recorded packets in order of recording.
+    pub fn get_packets(&self) -> Vec<RecordedPacket> {
+        self.packets.lock().unwrap().iter().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.packets.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Discard all of the recorded packets; e.g. to record the next session.
+    pub fn clear(&self) {
+        self.packets.lock().unwrap().clear();
+    }
+
+    /// Write the recorded packets as a text transcript; each packet has the decoded attributes and the hex dump.
+    ///
+    /// The sensitive attribute values are masked as `Packet::display(Redact::Mask)` does, but the hex dump has
+    /// the bytes as they are (the encrypted values stay encrypted).
+    pub fn write_transcript<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for packet in self.get_packets() {
+            w.write_all(format_transcript_entry(&packet).as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Write the recorded packets as a pcap file; see also `pcap::Writer`.
+    pub fn write_pcap<W: Write>(&self, w: W) -> Result<(), PcapError> {
+        let mut writer = pcap::Writer::new(w)?;
+        for packet in self.get_packets() {
+            writer.write_datagram(
+                packet.timestamp,
+                packet.src_addr,
+                packet.dst_addr,
+                &packet.bytes,
+            )?;
+        }
+        writer.flush()
+    }
+}
+
+fn format_transcript_entry(packet: &RecordedPacket) -> String {
+    let mut entry = format!(
+        "{} {} -> {} ({}, {} bytes)\n",
+        packet
+            .timestamp
+            .to_rfc3339_opts(SecondsFormat::Micros, true),
+        packet.src_addr,
+        packet.dst_addr,
+        match packet.direction {
+            Direction::Outgoing => "outgoing",
+            Direction::Incoming => "incoming",
+        },
+        packet.bytes.len(),
+    );
+
+    // the secret is not necessary to display the masked values
+    match Packet::decode(&packet.bytes, b"") {
+        Ok(decoded) => {
+            for line in decoded.display(Redact::Mask).to_string().lines() {
+                let _ = writeln!(entry, "  {line}");
+            }
+        }
+        Err(e) => {
+            let _ = writeln!(entry, "  <undecodable: {e}>");
+        }
+    }
+
+    for (i, chunk) in packet.bytes.chunks(16).enumerate() {
+        let _ = write!(entry, "    {:04x} ", i * 16);
+        for b in chunk {
+            let _ = write!(entry, " {b:02x}");
+        }
+        entry.push('\n');
+    }
+    entry.push('\n');
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use tokio::net::UdpSocket;
+
+    use crate::client::Client;
+    use crate::conversation::{ConversationRecorder, Direction};
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2865;
+
+    #[tokio::test]
+    async fn test_record_client_conversation() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+            let request = Packet::decode(&buf[..len], b"secret").unwrap();
+            let mut response = request.make_response_packet(Code::AccessChallenge);
+            rfc2865::add_state(&mut response, b"state").unwrap();
+            server
+                .send_to(&response.encode().unwrap(), addr)
+                .await
+                .unwrap();
+        });
+
+        let recorder = ConversationRecorder::new(16);
+        let mut client = Client::new(None, None);
+        client.set_conversation_recorder(recorder.clone());
+        let mut request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut request, "alice");
+        rfc2865::add_user_password(&mut request, b"password").unwrap();
+        client.send_packet(&server_addr, &request).await.unwrap();
+
+        let packets = recorder.get_packets();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].get_direction(), Direction::Outgoing);
+        assert_eq!(packets[0].get_dst_addr(), server_addr);
+        assert_eq!(packets[0].get_bytes(), request.encode().unwrap());
+        assert_eq!(packets[1].get_direction(), Direction::Incoming);
+        assert_eq!(packets[1].get_src_addr(), server_addr);
+        assert_eq!(packets[1].get_dst_addr(), packets[0].get_src_addr());
+
+        let mut transcript = Vec::new();
+        recorder.write_transcript(&mut transcript).unwrap();
+        let transcript = String::from_utf8(transcript).unwrap();
+        assert!(transcript.contains(&format!("-> {server_addr} (outgoing, ")));
+        assert!(transcript.contains("  Access-Request (identifier: "));
+        assert!(transcript.contains("    User-Name = \"alice\"\n"));
+        assert!(transcript.contains("    User-Password = <redacted>\n"));
+        assert!(transcript.contains("  Access-Challenge (identifier: "));
+        assert!(transcript.contains(&format!("    0000  01 {:02x}", request.get_identifier())));
+
+        let mut pcap = Vec::new();
+        recorder.write_pcap(&mut pcap).unwrap();
+        assert_eq!(&pcap[..4], &[0xd4, 0xc3, 0xb2, 0xa1]);
+        let request_length = packets[0].get_bytes().len();
+        let incl_len = u32::from_le_bytes(pcap[24 + 8..24 + 12].try_into().unwrap()) as usize;
+        assert_eq!(incl_len, 14 + 20 + 8 + request_length);
+        let frame = &pcap[24 + 16..24 + 16 + incl_len];
+        assert_eq!(&frame[14 + 28..], packets[0].get_bytes());
+        assert_eq!(
+            pcap.len(),
+            24 + (16 + 14 + 28) * 2 + request_length + packets[1].get_bytes().len()
+        );
+
+        recorder.clear();
+        assert!(recorder.is_empty());
+    }
+
+    #[test]
+    fn test_max_packets() {
+        let recorder = ConversationRecorder::new(2);
+        let src_addr: SocketAddr = "[2001:db8::1]:50000".parse().unwrap();
+        let dst_addr: SocketAddr = "192.0.2.1:1812".parse().unwrap();
+        for i in 0..3 {
+            recorder.record(Direction::Outgoing, src_addr, dst_addr, &[i]);
+        }
+        let packets = recorder.get_packets();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].get_bytes(), &[1]);
+
+        // a packet that cannot be decoded is dumped as it is
+        let mut transcript = Vec::new();
+        recorder.write_transcript(&mut transcript).unwrap();
+        assert!(String::from_utf8(transcript)
+            .unwrap()
+            .contains("  <undecodable: "));
+
+        // the addresses of the different families are written as IPv6
+        let mut pcap = Vec::new();
+        recorder.write_pcap(&mut pcap).unwrap();
+        assert_eq!(pcap[24 + 16 + 14] >> 4, 6);
+    }
+}
@@ -1,32 +1,7 @@
 //! RADIUS core implementation for server, client and application.
+//!
+//! The codec (e.g. `packet`, `avp` and the dictionary modules) is re-exported from the radius-proto crate.
+
+pub use radius_proto::*;
 
-pub(crate) mod attributes;
-pub mod avp;
-pub mod code;
-pub mod packet;
 pub mod request;
-pub mod rfc2865;
-pub mod rfc2866;
-pub mod rfc2867;
-pub mod rfc2868;
-pub mod rfc2869;
-pub mod rfc3162;
-pub mod rfc3576;
-pub mod rfc3580;
-pub mod rfc4072;
-pub mod rfc4372;
-pub mod rfc4603;
-pub mod rfc4675;
-pub mod rfc4818;
-pub mod rfc4849;
-pub mod rfc5090;
-pub mod rfc5176;
-pub mod rfc5607;
-pub mod rfc5904;
-pub mod rfc6519;
-pub mod rfc6572;
-pub mod rfc6677;
-pub mod rfc6911;
-pub mod rfc7055;
-pub mod rfc7155;
-pub mod tag;
@@ -1,5 +1,11 @@
 use std::net::SocketAddr;
+use std::sync::Arc;
 
+use crate::abuse::{AbuseDetector, AbuseKind};
+use crate::audit::{AuditEvent, AuditSink};
+use crate::conversation::{ConversationRecorder, Direction};
+use crate::core::code::Code;
+use crate::core::nas_profile::NasProfile;
 use crate::core::packet::Packet;
 
 /// RADIUS request object.
@@ -7,6 +13,10 @@ pub struct Request {
     local_addr: SocketAddr,
     remote_addr: SocketAddr,
     packet: Packet,
+    audit_sink: Option<Arc<dyn AuditSink>>,
+    abuse_detector: Option<Arc<AbuseDetector>>,
+    conversation_recorder: Option<ConversationRecorder>,
+    nas_profile: Option<Arc<NasProfile>>,
 }
 
 impl Request {
@@ -15,9 +25,32 @@ impl Request {
             local_addr,
             remote_addr,
             packet,
+            audit_sink: None,
+            abuse_detector: None,
+            conversation_recorder: None,
+            nas_profile: None,
         }
     }
 
+    pub(crate) fn set_audit_sink(&mut self, audit_sink: Option<Arc<dyn AuditSink>>) {
+        self.audit_sink = audit_sink;
+    }
+
+    pub(crate) fn set_abuse_detector(&mut self, abuse_detector: Option<Arc<AbuseDetector>>) {
+        self.abuse_detector = abuse_detector;
+    }
+
+    pub(crate) fn set_conversation_recorder(
+        &mut self,
+        conversation_recorder: Option<ConversationRecorder>,
+    ) {
+        self.conversation_recorder = conversation_recorder;
+    }
+
+    pub(crate) fn set_nas_profile(&mut self, nas_profile: Option<Arc<NasProfile>>) {
+        self.nas_profile = nas_profile;
+    }
+
     pub fn get_local_addr(&self) -> SocketAddr {
         self.local_addr
     }
@@ -29,4 +62,41 @@ impl Request {
     pub fn get_packet(&self) -> &Packet {
         &self.packet
     }
+
+    /// Returns the profile of the NAS that sent this request, if the server has a `NasProfileRegistry` that has that.
+    pub fn get_nas_profile(&self) -> Option<&NasProfile> {
+        self.nas_profile.as_deref()
+    }
+
+    /// Emit the audit event of the response (i.e. Access-Accept, Access-Reject or Access-Challenge)
+    /// to the `AuditSink` of the server, and count the Access-Reject as an authentication failure of the client
+    /// for the `AbuseDetector` of the server; call this when a handler sends the response.
+    ///
+    /// This does nothing for the other responses, or when the server doesn't have those.
+    ///
+    /// The response is also recorded by the `ConversationRecorder` of the server, if any.
+    pub fn audit_response(&self, response: &Packet) {
+        if let Some(conversation_recorder) = &self.conversation_recorder {
+            // the encoding of a response is deterministic, so this is the same bytes as the sent one
+            if let Ok(encoded) = response.encode() {
+                conversation_recorder.record(
+                    Direction::Outgoing,
+                    self.local_addr,
+                    self.remote_addr,
+                    &encoded,
+                );
+            }
+        }
+        if let Some(abuse_detector) = &self.abuse_detector {
+            if response.get_code() == Code::AccessReject {
+                abuse_detector.record(self.remote_addr.ip(), AbuseKind::AuthFailure);
+            }
+        }
+        if let Some(audit_sink) = &self.audit_sink {
+            if let Some(event) = AuditEvent::for_response(self.remote_addr, &self.packet, response)
+            {
+                audit_sink.emit(event);
+            }
+        }
+    }
 }
@@ -0,0 +1,880 @@
+//! Utilities for Dynamic Authorization Extensions to RADIUS (i.e. CoA and Disconnect).
+//!
+//! see also: https://tools.ietf.org/html/rfc5176
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::acct::{Session, SessionKey};
+use crate::client::{Client as RadiusClient, ClientError};
+use crate::clock::{self, Clock};
+use crate::core::avp::{AVPError, AVPType};
+use crate::core::code::Code;
+use crate::core::packet::{Packet, RADIUS_PACKET_HEADER_LENGTH};
+use crate::core::{rfc2865, rfc2866, rfc2869, rfc3162, rfc3576};
+use crate::retry::NoRetryPolicy;
+
+/// The default time window for `Event-Timestamp` verification.
+/// see also: https://tools.ietf.org/html/rfc5176#section-6.4
+pub const DEFAULT_EVENT_TIMESTAMP_WINDOW: Duration = Duration::from_secs(300);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DynAuthError {
+    /// This error is raised when the packet doesn't have an `Event-Timestamp` attribute.
+    #[error("Event-Timestamp attribute is missing")]
+    EventTimestampMissingError(),
+
+    /// This error is raised when the `Event-Timestamp` attribute cannot be decoded.
+    #[error("failed to decode Event-Timestamp attribute; {0}")]
+    EventTimestampDecodingError(AVPError),
+
+    /// This error is raised when the `Event-Timestamp` is not within the acceptable time window.
+    #[error("Event-Timestamp is out of the acceptable time window; skew={0}ms, max={1}ms")]
+    EventTimestampSkewExceededError(i64, u128),
+
+    /// This error is raised when the `Event-Timestamp` attribute cannot be encoded.
+    #[error("failed to encode Event-Timestamp attribute; {0}")]
+    EventTimestampEncodingError(AVPError),
+
+    /// This error is raised when the packet is neither a CoA-Request nor a Disconnect-Request.
+    #[error("unexpected request code: {0}")]
+    UnexpectedRequestCodeError(String),
+
+    /// This error is raised when the request doesn't have any NAS identification attribute
+    /// (i.e. `NAS-IP-Address`, `NAS-IPv6-Address` or `NAS-Identifier`).
+    #[error("NAS identification attribute is missing")]
+    NasIdentificationMissingError(),
+
+    /// This error is raised when the request doesn't have any session identification attribute (e.g. `Acct-Session-Id`).
+    #[error("session identification attribute is missing")]
+    SessionIdentificationMissingError(),
+
+    /// This error is raised when the request has an attribute that must not be in the request (e.g. `User-Password`).
+    #[error("the request must not have {0} attribute")]
+    ForbiddenAttributeError(String),
+
+    /// This error is raised when the CoA-Request of `Service-Type = Authorize-Only` doesn't have a `State` attribute.
+    #[error("State attribute is missing for Service-Type = Authorize-Only")]
+    StateMissingError(),
+}
+
+/// Add an `Event-Timestamp` attribute that indicates the current time to a packet.
+pub fn add_event_timestamp_now(packet: &mut Packet) -> Result<(), AVPError> {
+    rfc2869::add_event_timestamp(packet, &Utc::now())
+}
+
+/// Verify the `Event-Timestamp` attribute of a packet against the current time.
+///
+/// This is for the replay protection of CoA-Request and Disconnect-Request;
+/// the packet is acceptable when the difference between the timestamp and the current time is within `max_skew`.
+/// see also: https://tools.ietf.org/html/rfc5176#section-6.4
+pub fn verify_event_timestamp(packet: &Packet, max_skew: Duration) -> Result<(), DynAuthError> {
+    verify_event_timestamp_at(packet, max_skew, &Utc::now())
+}
+
+/// Verify the `Event-Timestamp` attribute of a packet against the given time.
+///
+/// `Event-Timestamp` has only one second resolution, so this treats the timestamp as a one-second interval
+/// so that the truncated sub-second part of the sender's clock doesn't count as a skew.
+pub fn verify_event_timestamp_at(
+    packet: &Packet,
+    max_skew: Duration,
+    now: &DateTime<Utc>,
+) -> Result<(), DynAuthError> {
+    let event_timestamp = match rfc2869::lookup_event_timestamp(packet) {
+        Some(Ok(event_timestamp)) => event_timestamp,
+        Some(Err(e)) => return Err(DynAuthError::EventTimestampDecodingError(e)),
+        None => return Err(DynAuthError::EventTimestampMissingError()),
+    };
+
+    let elapsed_millis = now
+        .signed_duration_since(event_timestamp)
+        .num_milliseconds();
+    let skew_millis = if elapsed_millis < 0 {
+        elapsed_millis
+    } else {
+        (elapsed_millis - 999).max(0)
+    };
+
+    let max_skew_millis = max_skew.as_millis();
+    if skew_millis.unsigned_abs() as u128 > max_skew_millis {
+        return Err(DynAuthError::EventTimestampSkewExceededError(
+            skew_millis,
+            max_skew_millis,
+        ));
+    }
+
+    Ok(())
+}
+
+/// The attributes that identify the NAS in a CoA/Disconnect-Request.
+/// see also: https://tools.ietf.org/html/rfc5176#section-3
+const NAS_IDENTIFICATION_TYPES: [AVPType; 3] = [
+    rfc2865::NAS_IP_ADDRESS_TYPE,
+    rfc3162::NAS_IPV6_ADDRESS_TYPE,
+    rfc2865::NAS_IDENTIFIER_TYPE,
+];
+
+/// `Chargeable-User-Identity` (RFC 4372); that is a session identification attribute, but `rfc4372` feature is optional.
+const CHARGEABLE_USER_IDENTITY_TYPE: AVPType = 89;
+
+/// The attributes that identify the session in a CoA/Disconnect-Request.
+/// see also: https://tools.ietf.org/html/rfc5176#section-3
+const SESSION_IDENTIFICATION_TYPES: [AVPType; 11] = [
+    rfc2865::USER_NAME_TYPE,
+    rfc2865::NAS_PORT_TYPE,
+    rfc2865::FRAMED_IP_ADDRESS_TYPE,
+    rfc2865::CALLED_STATION_ID_TYPE,
+    rfc2865::CALLING_STATION_ID_TYPE,
+    rfc2866::ACCT_SESSION_ID_TYPE,
+    rfc2866::ACCT_MULTI_SESSION_ID_TYPE,
+    rfc2869::NAS_PORT_ID_TYPE,
+    CHARGEABLE_USER_IDENTITY_TYPE,
+    rfc3162::FRAMED_INTERFACE_ID_TYPE,
+    rfc3162::FRAMED_IPV6_PREFIX_TYPE,
+];
+
+/// The attributes that must not be in a CoA/Disconnect-Request.
+/// see also: https://tools.ietf.org/html/rfc5176#section-3.5
+const FORBIDDEN_TYPES: [(AVPType, &str); 3] = [
+    (rfc2865::USER_PASSWORD_TYPE, "User-Password"),
+    (rfc2865::CHAP_PASSWORD_TYPE, "CHAP-Password"),
+    (rfc3576::ERROR_CAUSE_TYPE, "Error-Cause"),
+];
+
+/// This enum represents the attribute that identifies the NAS in a CoA/Disconnect-Request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NasIdentification {
+    IpAddress(Ipv4Addr),
+    Ipv6Address(Ipv6Addr),
+    Identifier(String),
+}
+
+/// This enum represents an attribute that identifies the session in a CoA/Disconnect-Request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionIdentification {
+    AcctSessionId(String),
+    AcctMultiSessionId(String),
+    UserName(String),
+    CallingStationId(String),
+    CalledStationId(String),
+    NasPort(u32),
+    NasPortId(String),
+    FramedIpAddress(Ipv4Addr),
+}
+
+/// Make a CoA-Request or a Disconnect-Request that has the NAS identification and the session identification attributes.
+///
+/// The other attributes (e.g. the other session identification attributes, the authorization changes) can be added to
+/// the packet afterward; `validate_request()` verifies the whole request.
+pub fn make_request(
+    code: Code,
+    secret: &[u8],
+    nas: &NasIdentification,
+    session: &SessionIdentification,
+) -> Result<Packet, DynAuthError> {
+    if code != Code::CoARequest && code != Code::DisconnectRequest {
+        return Err(DynAuthError::UnexpectedRequestCodeError(format!(
+            "{code:?}"
+        )));
+    }
+
+    let mut packet = Packet::new(code, secret);
+    match nas {
+        NasIdentification::IpAddress(ip) => rfc2865::add_nas_ip_address(&mut packet, ip),
+        NasIdentification::Ipv6Address(ip) => rfc3162::add_nas_ipv6_address(&mut packet, ip),
+        NasIdentification::Identifier(id) => rfc2865::add_nas_identifier(&mut packet, id),
+    }
+    match session {
+        SessionIdentification::AcctSessionId(id) => rfc2866::add_acct_session_id(&mut packet, id),
+        SessionIdentification::AcctMultiSessionId(id) => {
+            rfc2866::add_acct_multi_session_id(&mut packet, id)
+        }
+        SessionIdentification::UserName(name) => rfc2865::add_user_name(&mut packet, name),
+        SessionIdentification::CallingStationId(id) => {
+            rfc2865::add_calling_station_id(&mut packet, id)
+        }
+        SessionIdentification::CalledStationId(id) => {
+            rfc2865::add_called_station_id(&mut packet, id)
+        }
+        SessionIdentification::NasPort(port) => rfc2865::add_nas_port(&mut packet, *port),
+        SessionIdentification::NasPortId(id) => rfc2869::add_nas_port_id(&mut packet, id),
+        SessionIdentification::FramedIpAddress(ip) => {
+            rfc2865::add_framed_ip_address(&mut packet, ip)
+        }
+    }
+    Ok(packet)
+}
+
+/// Verify that a CoA-Request or a Disconnect-Request has the attributes that RFC 5176 requires:
+/// a NAS identification attribute, a session identification attribute, no attributes that are only for the
+/// Access-Request or the responses (e.g. `User-Password`, `Error-Cause`),
+/// and `State` for the CoA-Request of `Service-Type = Authorize-Only`.
+///
+/// see also: https://tools.ietf.org/html/rfc5176#section-3
+pub fn validate_request(packet: &Packet) -> Result<(), DynAuthError> {
+    let code = packet.get_code();
+    if code != Code::CoARequest && code != Code::DisconnectRequest {
+        return Err(DynAuthError::UnexpectedRequestCodeError(format!(
+            "{code:?}"
+        )));
+    }
+
+    if let Some((_, name)) = FORBIDDEN_TYPES
+        .iter()
+        .find(|(typ, _)| packet.contains(*typ))
+    {
+        return Err(DynAuthError::ForbiddenAttributeError(name.to_string()));
+    }
+    if !NAS_IDENTIFICATION_TYPES
+        .iter()
+        .any(|typ| packet.contains(*typ))
+    {
+        return Err(DynAuthError::NasIdentificationMissingError());
+    }
+    if !SESSION_IDENTIFICATION_TYPES
+        .iter()
+        .any(|typ| packet.contains(*typ))
+    {
+        return Err(DynAuthError::SessionIdentificationMissingError());
+    }
+
+    let authorize_only = matches!(
+        rfc2865::lookup_service_type(packet),
+        Some(Ok(service_type)) if service_type == rfc3576::SERVICE_TYPE_AUTHORIZE_ONLY
+    );
+    if authorize_only && !rfc2865::has_state(packet) {
+        return Err(DynAuthError::StateMissingError());
+    }
+
+    Ok(())
+}
+
+/// This enum represents the class of an `Error-Cause` value.
+/// see also: https://tools.ietf.org/html/rfc5176#section-3.6
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ErrorCauseClass {
+    /// 200-299: the request has been completed successfully (e.g. `Residual-Context-Removed`).
+    Success,
+    /// 400-499: the request is erroneous on the requester side (e.g. `Missing-Attribute`).
+    RequestError,
+    /// 500-599: the request has failed on the NAS or the proxy (e.g. `Session-Context-Not-Found`).
+    NasError,
+    /// The value is out of the ranges that are defined by RFC 5176.
+    Unknown,
+}
+
+/// Classify an `Error-Cause` value by its range.
+pub fn classify_error_cause(cause: rfc3576::ErrorCause) -> ErrorCauseClass {
+    match cause.to_u32() {
+        200..=299 => ErrorCauseClass::Success,
+        400..=499 => ErrorCauseClass::RequestError,
+        500..=599 => ErrorCauseClass::NasError,
+        _ => ErrorCauseClass::Unknown,
+    }
+}
+
+fn response_codes(request: &Packet) -> Result<(Code, Code), DynAuthError> {
+    match request.get_code() {
+        Code::CoARequest => Ok((Code::CoAACK, Code::CoANAK)),
+        Code::DisconnectRequest => Ok((Code::DisconnectACK, Code::DisconnectNAK)),
+        code => Err(DynAuthError::UnexpectedRequestCodeError(format!(
+            "{code:?}"
+        ))),
+    }
+}
+
+/// Make a CoA-ACK or a Disconnect-ACK for a CoA-Request or a Disconnect-Request.
+pub fn ack(request: &Packet) -> Result<Packet, DynAuthError> {
+    let (ack_code, _) = response_codes(request)?;
+    Ok(request.make_response_packet(ack_code))
+}
+
+/// Make a CoA-NAK or a Disconnect-NAK for a CoA-Request or a Disconnect-Request, that has the `Error-Cause`.
+///
+/// e.g. `dynauth::nak_with_cause(&request, rfc3576::ErrorCause::SessionContextNotFound)`
+pub fn nak_with_cause(
+    request: &Packet,
+    cause: rfc3576::ErrorCause,
+) -> Result<Packet, DynAuthError> {
+    let (_, nak_code) = response_codes(request)?;
+    let mut response = request.make_response_packet(nak_code);
+    rfc3576::add_error_cause(&mut response, cause);
+    Ok(response)
+}
+
+const DEFAULT_MAX_CONCURRENCY: usize = 16;
+const DEFAULT_MAX_ATTEMPTS: usize = 3;
+
+/// This enum represents the result of a Disconnect-Request for a session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DisconnectOutcome {
+    /// The NAS responded with Disconnect-ACK.
+    Ack,
+    /// The NAS responded with Disconnect-NAK, with the `Error-Cause` if it is given.
+    Nak(Option<rfc3576::ErrorCause>),
+    /// The NAS didn't respond within all of the attempts.
+    Timeout,
+    /// The request couldn't be completed for the other reason.
+    Failed(String),
+}
+
+/// This struct represents a summary of the Disconnect-Requests for a batch of sessions.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DisconnectReport {
+    results: Vec<(SessionKey, DisconnectOutcome)>,
+}
+
+impl DisconnectReport {
+    /// Returns the outcome per session, in the order of the given sessions.
+    pub fn get_results(&self) -> &[(SessionKey, DisconnectOutcome)] {
+        &self.results
+    }
+
+    /// Returns the number of the sessions that are acknowledged.
+    pub fn count_acked(&self) -> usize {
+        self.count(|outcome| matches!(outcome, DisconnectOutcome::Ack))
+    }
+
+    /// Returns the number of the sessions that are not acknowledged.
+    pub fn count_naked(&self) -> usize {
+        self.count(|outcome| matches!(outcome, DisconnectOutcome::Nak(_)))
+    }
+
+    /// Returns the number of the sessions that are timed-out.
+    pub fn count_timed_out(&self) -> usize {
+        self.count(|outcome| matches!(outcome, DisconnectOutcome::Timeout))
+    }
+
+    /// Returns the number of the sessions that are failed for the other reason.
+    pub fn count_failed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, DisconnectOutcome::Failed(_)))
+    }
+
+    fn count(&self, predicate: impl Fn(&DisconnectOutcome) -> bool) -> usize {
+        self.results
+            .iter()
+            .filter(|(_, outcome)| predicate(outcome))
+            .count()
+    }
+}
+
+/// A client for the Dynamic Authorization requests to a NAS.
+pub struct Client {
+    secret: Vec<u8>,
+    connection_timeout: Option<Duration>,
+    socket_timeout: Option<Duration>,
+    max_concurrency: usize,
+    max_attempts: usize,
+    clock: Arc<dyn Clock>,
+}
+
+impl Client {
+    /// A constructor for a client.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - A shared-secret between the client and the NAS.
+    /// * `connection_timeout` - A duration of connection timeout for each request. See also `client::Client::new()`.
+    /// * `socket_timeout` - A duration of socket timeout for each attempt. See also `client::Client::new()`.
+    pub fn new(
+        secret: &[u8],
+        connection_timeout: Option<Duration>,
+        socket_timeout: Option<Duration>,
+    ) -> Self {
+        Client {
+            secret: secret.to_vec(),
+            connection_timeout,
+            socket_timeout,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set the maximum number of the requests in flight (default: `16`).
+    pub fn set_max_concurrency(&mut self, max_concurrency: usize) {
+        self.max_concurrency = max_concurrency;
+    }
+
+    /// Set the maximum number of the attempts per request; a request is retransmitted on timeout (default: `3`).
+    pub fn set_max_attempts(&mut self, max_attempts: usize) {
+        self.max_attempts = max_attempts;
+    }
+
+    /// Set a clock of `Event-Timestamp` and the timeouts of the responses (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Make a Disconnect-Request packet that identifies the session.
+    ///
+    /// The packet contains `Acct-Session-Id`, the NAS identification attribute, `User-Name` and `Framed-IP-Address`
+    /// (if the session has them) and `Event-Timestamp`.
+    pub fn make_disconnect_request(&self, session: &Session) -> Result<Packet, DynAuthError> {
+        let nas = match session.key.nas.parse::<IpAddr>() {
+            Ok(IpAddr::V4(nas_ip_address)) => NasIdentification::IpAddress(nas_ip_address),
+            Ok(IpAddr::V6(nas_ipv6_address)) => NasIdentification::Ipv6Address(nas_ipv6_address),
+            Err(_) => NasIdentification::Identifier(session.key.nas.clone()),
+        };
+        let mut packet = make_request(
+            Code::DisconnectRequest,
+            &self.secret,
+            &nas,
+            &SessionIdentification::AcctSessionId(session.key.session_id.clone()),
+        )?;
+        if let Some(user_name) = &session.user_name {
+            rfc2865::add_user_name(&mut packet, user_name);
+        }
+        if let Some(framed_ip_address) = &session.framed_ip_address {
+            rfc2865::add_framed_ip_address(&mut packet, framed_ip_address);
+        }
+        rfc2869::add_event_timestamp(&mut packet, &self.clock.now_utc())
+            .map_err(DynAuthError::EventTimestampEncodingError)?;
+        Ok(packet)
+    }
+
+    /// Send Disconnect-Requests for a batch of sessions to the NAS, with bounded concurrency.
+    ///
+    /// This never fails as a whole; the outcome of each session is reported in the `DisconnectReport`.
+    pub async fn disconnect_sessions<I: IntoIterator<Item = Session>>(
+        &self,
+        nas_addr: SocketAddr,
+        sessions: I,
+    ) -> DisconnectReport {
+        let semaphore = Arc::new(Semaphore::new(self.max_concurrency.max(1)));
+        let mut join_set = JoinSet::new();
+        let mut results = Vec::new();
+
+        for (i, session) in sessions.into_iter().enumerate() {
+            let key = session.key.clone();
+            let packet = match self.make_disconnect_request(&session) {
+                Ok(packet) => packet,
+                Err(e) => {
+                    results.push((i, key, DisconnectOutcome::Failed(e.to_string())));
+                    continue;
+                }
+            };
+
+            let permit = semaphore.clone().acquire_owned().await.unwrap();
+            let mut client = RadiusClient::new(self.connection_timeout, self.socket_timeout);
+            client.set_clock(self.clock.clone());
+            let retry_policy = NoRetryPolicy {
+                timeout: self.socket_timeout,
+            };
+            let max_attempts = self.max_attempts.max(1);
+            join_set.spawn(async move {
+                let outcome =
+                    Self::send_with_retry(&client, &nas_addr, &packet, &retry_policy, max_attempts)
+                        .await;
+                drop(permit);
+                (i, key, outcome)
+            });
+        }
+
+        while let Some(joined) = join_set.join_next().await {
+            match joined {
+                Ok(result) => results.push(result),
+                Err(e) => error!("failed to join a Disconnect-Request task; {}", e),
+            }
+        }
+
+        results.sort_by_key(|(i, _, _)| *i);
+        DisconnectReport {
+            results: results
+                .into_iter()
+                .map(|(_, key, outcome)| (key, outcome))
+                .collect(),
+        }
+    }
+
+    async fn send_with_retry(
+        client: &RadiusClient,
+        nas_addr: &SocketAddr,
+        packet: &Packet,
+        retry_policy: &NoRetryPolicy,
+        max_attempts: usize,
+    ) -> DisconnectOutcome {
+        for _ in 0..max_attempts {
+            match client
+                .exchange_with_retry(nas_addr, packet, retry_policy)
+                .await
+            {
+                Ok((request_data, response_data)) => {
+                    let response = match Self::verify_response(
+                        &request_data,
+                        &response_data,
+                        packet.get_secret(),
+                    ) {
+                        Ok(response) => response,
+                        Err(reason) => return DisconnectOutcome::Failed(reason),
+                    };
+                    return match response.get_code() {
+                        Code::DisconnectACK => DisconnectOutcome::Ack,
+                        Code::DisconnectNAK => DisconnectOutcome::Nak(
+                            rfc3576::lookup_error_cause(&response).and_then(|cause| cause.ok()),
+                        ),
+                        code => {
+                            DisconnectOutcome::Failed(format!("unexpected response code: {code:?}"))
+                        }
+                    };
+                }
+                Err(ClientError::SocketTimeoutError())
+                | Err(ClientError::ConnectionTimeoutError()) => continue,
+                Err(e) => return DisconnectOutcome::Failed(e.to_string()),
+            }
+        }
+        DisconnectOutcome::Timeout
+    }
+
+    /// Verifies the Response Authenticator (and the Message-Authenticator if it is given) of the response with the request as sent.
+    fn verify_response(
+        request_data: &[u8],
+        response_data: &[u8],
+        secret: &[u8],
+    ) -> Result<Packet, String> {
+        if !Packet::is_authentic_response(response_data, request_data, secret) {
+            return Err("the response is not authentic".to_owned());
+        }
+        let response = Packet::decode(response_data, secret).map_err(|e| e.to_string())?;
+        if rfc2869::has_message_authenticator(&response)
+            && !response.is_authentic_message_authenticator(Some(
+                &request_data[4..RADIUS_PACKET_HEADER_LENGTH],
+            ))
+        {
+            return Err("the Message-Authenticator of the response is not authentic".to_owned());
+        }
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+    use tokio::net::UdpSocket;
+
+    use crate::acct::{Session, SessionKey};
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::{rfc2865, rfc2866, rfc2869, rfc3162, rfc3576};
+    use crate::dynauth::{
+        ack, add_event_timestamp_now, classify_error_cause, make_request, nak_with_cause,
+        validate_request, verify_event_timestamp, verify_event_timestamp_at, Client,
+        DisconnectOutcome, DynAuthError, ErrorCauseClass, NasIdentification, SessionIdentification,
+        DEFAULT_EVENT_TIMESTAMP_WINDOW,
+    };
+
+    #[test]
+    fn test_add_event_timestamp_now() {
+        let mut packet = Packet::new(Code::CoARequest, b"secret");
+        add_event_timestamp_now(&mut packet).unwrap();
+
+        assert!(rfc2869::lookup_event_timestamp(&packet).is_some());
+        assert!(verify_event_timestamp(&packet, DEFAULT_EVENT_TIMESTAMP_WINDOW).is_ok());
+    }
+
+    #[test]
+    fn test_verify_event_timestamp_at() {
+        let mut packet = Packet::new(Code::DisconnectRequest, b"secret");
+        let now = Utc.timestamp_opt(1_600_000_000, 500_000_000).unwrap();
+        let max_skew = Duration::from_secs(10);
+
+        assert_eq!(
+            verify_event_timestamp_at(&packet, max_skew, &now),
+            Err(DynAuthError::EventTimestampMissingError())
+        );
+
+        // sub-second part of the current time shouldn't be counted as a skew
+        rfc2869::add_event_timestamp(&mut packet, &Utc.timestamp_opt(1_600_000_000, 0).unwrap())
+            .unwrap();
+        assert!(verify_event_timestamp_at(&packet, Duration::from_secs(0), &now).is_ok());
+
+        rfc2869::delete_event_timestamp(&mut packet);
+        rfc2869::add_event_timestamp(&mut packet, &Utc.timestamp_opt(1_599_999_990, 0).unwrap())
+            .unwrap();
+        assert!(verify_event_timestamp_at(&packet, max_skew, &now).is_ok());
+
+        rfc2869::delete_event_timestamp(&mut packet);
+        rfc2869::add_event_timestamp(&mut packet, &Utc.timestamp_opt(1_599_999_989, 0).unwrap())
+            .unwrap();
+        assert_eq!(
+            verify_event_timestamp_at(&packet, max_skew, &now),
+            Err(DynAuthError::EventTimestampSkewExceededError(
+                10_501, 10_000
+            ))
+        );
+
+        rfc2869::delete_event_timestamp(&mut packet);
+        rfc2869::add_event_timestamp(&mut packet, &Utc.timestamp_opt(1_600_000_011, 0).unwrap())
+            .unwrap();
+        assert_eq!(
+            verify_event_timestamp_at(&packet, max_skew, &now),
+            Err(DynAuthError::EventTimestampSkewExceededError(
+                -10_500, 10_000
+            ))
+        );
+    }
+
+    #[test]
+    fn test_make_request() {
+        let packet = make_request(
+            Code::CoARequest,
+            b"secret",
+            &NasIdentification::Identifier("nas1".to_owned()),
+            &SessionIdentification::UserName("alice".to_owned()),
+        )
+        .unwrap();
+        assert_eq!(packet.get_code(), Code::CoARequest);
+        assert_eq!(
+            rfc2865::lookup_nas_identifier(&packet).unwrap().unwrap(),
+            "nas1"
+        );
+        assert_eq!(
+            rfc2865::lookup_user_name(&packet).unwrap().unwrap(),
+            "alice"
+        );
+        assert!(validate_request(&packet).is_ok());
+
+        let err = make_request(
+            Code::AccessRequest,
+            b"secret",
+            &NasIdentification::IpAddress(Ipv4Addr::new(192, 0, 2, 1)),
+            &SessionIdentification::AcctSessionId("s1".to_owned()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, DynAuthError::UnexpectedRequestCodeError(_)));
+    }
+
+    #[test]
+    fn test_validate_request() {
+        let mut packet = Packet::new(Code::DisconnectRequest, b"secret");
+        assert!(matches!(
+            validate_request(&packet),
+            Err(DynAuthError::NasIdentificationMissingError())
+        ));
+
+        rfc2865::add_nas_ip_address(&mut packet, &Ipv4Addr::new(192, 0, 2, 1));
+        assert!(matches!(
+            validate_request(&packet),
+            Err(DynAuthError::SessionIdentificationMissingError())
+        ));
+
+        rfc2866::add_acct_session_id(&mut packet, "s1");
+        assert!(validate_request(&packet).is_ok());
+
+        let mut forbidden = packet.clone();
+        rfc2865::add_user_password(&mut forbidden, b"password").unwrap();
+        match validate_request(&forbidden) {
+            Err(DynAuthError::ForbiddenAttributeError(name)) => assert_eq!(name, "User-Password"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        let mut forbidden = packet.clone();
+        rfc3576::add_error_cause(
+            &mut forbidden,
+            rfc3576::ERROR_CAUSE_SESSION_CONTEXT_NOT_FOUND,
+        );
+        assert!(matches!(
+            validate_request(&forbidden),
+            Err(DynAuthError::ForbiddenAttributeError(_))
+        ));
+
+        let mut authorize_only = Packet::new(Code::CoARequest, b"secret");
+        rfc2865::add_nas_identifier(&mut authorize_only, "nas1");
+        rfc2866::add_acct_session_id(&mut authorize_only, "s1");
+        rfc2865::add_service_type(&mut authorize_only, rfc3576::SERVICE_TYPE_AUTHORIZE_ONLY);
+        assert!(matches!(
+            validate_request(&authorize_only),
+            Err(DynAuthError::StateMissingError())
+        ));
+        rfc2865::add_state(&mut authorize_only, b"state").unwrap();
+        assert!(validate_request(&authorize_only).is_ok());
+    }
+
+    #[test]
+    fn test_ack_and_nak_with_cause() {
+        let request = Packet::new(Code::CoARequest, b"secret");
+        let response = ack(&request).unwrap();
+        assert_eq!(response.get_code(), Code::CoAACK);
+        assert_eq!(response.get_identifier(), request.get_identifier());
+
+        let response = nak_with_cause(&request, rfc3576::ErrorCause::UnsupportedAttribute).unwrap();
+        assert_eq!(response.get_code(), Code::CoANAK);
+        assert_eq!(
+            rfc3576::lookup_error_cause(&response).unwrap().unwrap(),
+            rfc3576::ErrorCause::UnsupportedAttribute
+        );
+
+        let request = Packet::new(Code::DisconnectRequest, b"secret");
+        assert_eq!(ack(&request).unwrap().get_code(), Code::DisconnectACK);
+        let response =
+            nak_with_cause(&request, rfc3576::ErrorCause::SessionContextNotFound).unwrap();
+        assert_eq!(response.get_code(), Code::DisconnectNAK);
+
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        assert!(matches!(
+            ack(&request),
+            Err(DynAuthError::UnexpectedRequestCodeError(_))
+        ));
+        assert!(matches!(
+            nak_with_cause(&request, rfc3576::ErrorCause::InvalidRequest),
+            Err(DynAuthError::UnexpectedRequestCodeError(_))
+        ));
+    }
+
+    #[test]
+    fn test_classify_error_cause() {
+        assert_eq!(
+            classify_error_cause(rfc3576::ErrorCause::ResidualContextRemoved),
+            ErrorCauseClass::Success
+        );
+        assert_eq!(
+            classify_error_cause(rfc3576::ErrorCause::MissingAttribute),
+            ErrorCauseClass::RequestError
+        );
+        assert_eq!(
+            classify_error_cause(rfc3576::ErrorCause::SessionContextNotFound),
+            ErrorCauseClass::NasError
+        );
+        assert_eq!(
+            classify_error_cause(rfc3576::ErrorCause::Unknown(999)),
+            ErrorCauseClass::Unknown
+        );
+    }
+
+    fn make_session(session_id: &str) -> Session {
+        let now = Utc::now();
+        Session {
+            key: SessionKey {
+                session_id: session_id.to_owned(),
+                nas: "192.0.2.1".to_owned(),
+            },
+            user_name: Some("alice".to_owned()),
+            nas_port: None,
+            framed_ip_address: None,
+            calling_station_id: None,
+            session_time: 0,
+            input_octets: 0,
+            output_octets: 0,
+            started_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_sessions() {
+        let nas = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let nas_addr = nas.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = nas.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                assert_eq!(
+                    rfc2865::lookup_nas_ip_address(&request).unwrap().unwrap(),
+                    Ipv4Addr::new(192, 0, 2, 1)
+                );
+                let response = match rfc2866::lookup_acct_session_id(&request)
+                    .unwrap()
+                    .unwrap()
+                    .as_str()
+                {
+                    "ack" => ack(&request).unwrap(),
+                    "forged" => {
+                        // tamper the Response Authenticator
+                        let mut response_data = ack(&request).unwrap().encode().unwrap();
+                        response_data[4] ^= 1;
+                        nas.send_to(&response_data, addr).await.unwrap();
+                        continue;
+                    }
+                    "nak" => {
+                        nak_with_cause(&request, rfc3576::ERROR_CAUSE_SESSION_CONTEXT_NOT_FOUND)
+                            .unwrap()
+                    }
+                    _ => continue,
+                };
+                nas.send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let mut client = Client::new(b"secret", None, Some(Duration::from_millis(100)));
+        client.set_max_concurrency(2);
+        client.set_max_attempts(2);
+        let report = client
+            .disconnect_sessions(
+                nas_addr,
+                vec![
+                    make_session("ack"),
+                    make_session("nak"),
+                    make_session("timeout"),
+                    make_session("forged"),
+                    make_session("ack"),
+                ],
+            )
+            .await;
+
+        let outcomes = report
+            .get_results()
+            .iter()
+            .map(|(key, outcome)| (key.session_id.as_str(), outcome.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("ack", DisconnectOutcome::Ack),
+                (
+                    "nak",
+                    DisconnectOutcome::Nak(Some(rfc3576::ERROR_CAUSE_SESSION_CONTEXT_NOT_FOUND))
+                ),
+                ("timeout", DisconnectOutcome::Timeout),
+                (
+                    "forged",
+                    DisconnectOutcome::Failed("the response is not authentic".to_owned())
+                ),
+                ("ack", DisconnectOutcome::Ack),
+            ]
+        );
+        assert_eq!(report.count_acked(), 2);
+        assert_eq!(report.count_naked(), 1);
+        assert_eq!(report.count_timed_out(), 1);
+        assert_eq!(report.count_failed(), 1);
+    }
+
+    #[test]
+    fn test_make_disconnect_request_nas_identification() {
+        let client = Client::new(b"secret", None, None);
+
+        let packet = client.make_disconnect_request(&make_session("s1")).unwrap();
+        assert_eq!(
+            rfc2865::lookup_nas_ip_address(&packet).unwrap().unwrap(),
+            Ipv4Addr::new(192, 0, 2, 1)
+        );
+
+        let mut session = make_session("s1");
+        session.key.nas = "2001:db8::1".to_owned();
+        let packet = client.make_disconnect_request(&session).unwrap();
+        assert_eq!(
+            rfc3162::lookup_nas_ipv6_address(&packet).unwrap().unwrap(),
+            "2001:db8::1".parse::<Ipv6Addr>().unwrap()
+        );
+        assert!(rfc2865::lookup_nas_identifier(&packet).is_none());
+
+        session.key.nas = "nas1".to_owned();
+        let packet = client.make_disconnect_request(&session).unwrap();
+        assert_eq!(
+            rfc2865::lookup_nas_identifier(&packet).unwrap().unwrap(),
+            "nas1"
+        );
+    }
+}
@@ -0,0 +1,525 @@
+//! EAP (Extensible Authentication Protocol) over RADIUS.
+//!
+//! `EapHandler` serves the EAP conversations that are carried by `EAP-Message` attributes;
+//! it takes care of `State`, EAP identifiers and `Message-Authenticator`, and delegates the method specific part to an `EapMethod`.
+//! see also: https://tools.ietf.org/html/rfc3579 and https://tools.ietf.org/html/rfc3748
+
+pub mod md5;
+pub mod mppe;
+pub mod peap;
+pub mod peer;
+pub mod tls;
+pub mod ttls;
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use thiserror::Error;
+use tokio::net::UdpSocket;
+
+use crate::auth::{AuthError, ReplyTemplate};
+use crate::clock::{self, Clock};
+use crate::core::avp::AVPError;
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::random::{self, RandomSource};
+use crate::core::request::Request;
+use crate::core::{rfc2865, rfc2869};
+use crate::server::RequestHandler;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum EapError {
+    /// This error is raised when the bytes cannot be decoded as an EAP packet.
+    #[error("failed to decode the EAP packet: {0}")]
+    DecodingError(String),
+
+    /// This error is raised when the request is not an Access-Request.
+    #[error("unexpected request code: {0}")]
+    UnexpectedRequestCodeError(String),
+
+    /// This error is raised when the request doesn't have an `EAP-Message` attribute.
+    #[error("EAP-Message attribute is missing")]
+    EapMessageMissingError(),
+
+    /// This error is raised when the `Message-Authenticator` of the request is missing or invalid.
+    #[error("Message-Authenticator attribute is missing or invalid")]
+    InvalidMessageAuthenticatorError(),
+
+    /// This error is raised when the EAP packet is not an EAP-Response.
+    #[error("unexpected EAP code: {0}")]
+    UnexpectedEapCodeError(u8),
+
+    /// This error is raised when an attribute of the request cannot be decoded.
+    #[error("failed to decode an attribute; {0}")]
+    AttributeDecodingError(AVPError),
+
+    /// This error is raised when an attribute cannot be encoded (e.g. the EAP packet is too large).
+    #[error("failed to encode an attribute; {0}")]
+    AttributeEncodingError(AVPError),
+
+    /// This error is raised when the authentication backend fails.
+    #[error("authentication backend error; {0}")]
+    BackendError(AuthError),
+
+    /// This error is raised when the EAP method fails to process a message.
+    #[error("EAP method error: {0}")]
+    MethodError(String),
+
+    /// This error is raised when the response cannot be encoded.
+    #[error("failed to encode the response: {0}")]
+    ResponseEncodingError(String),
+
+    /// This error is raised when the response cannot be sent.
+    #[error("failed to send the response: {0}")]
+    ResponseSendingError(String),
+
+    /// This error is raised when the peer fails to send a request or receive the response.
+    #[error("failed to send the request: {0}")]
+    RequestSendingError(String),
+
+    /// This error is raised when the server responds with an unexpected code.
+    #[error("unexpected response code: {0}")]
+    UnexpectedResponseCodeError(String),
+
+    /// This error is raised when the conversation doesn't finish within the maximum number of the rounds.
+    #[error("the conversation exceeds {0} rounds")]
+    TooManyRoundsError(usize),
+}
+
+/// This enum represents a code of EAP packet.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EapCode {
+    Request = 1,
+    Response = 2,
+    Success = 3,
+    Failure = 4,
+}
+
+impl TryFrom<u8> for EapCode {
+    type Error = EapError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(EapCode::Request),
+            2 => Ok(EapCode::Response),
+            3 => Ok(EapCode::Success),
+            4 => Ok(EapCode::Failure),
+            _ => Err(EapError::DecodingError(format!(
+                "unknown EAP code: {}",
+                value
+            ))),
+        }
+    }
+}
+
+pub type EapType = u8;
+
+pub const EAP_TYPE_IDENTITY: EapType = 1;
+pub const EAP_TYPE_NOTIFICATION: EapType = 2;
+pub const EAP_TYPE_NAK: EapType = 3;
+pub const EAP_TYPE_MD5_CHALLENGE: EapType = 4;
+pub const EAP_TYPE_TLS: EapType = 13;
+pub const EAP_TYPE_TTLS: EapType = 21;
+pub const EAP_TYPE_PEAP: EapType = 25;
+pub const EAP_TYPE_MSCHAPV2: EapType = 26;
+
+const EAP_HEADER_LENGTH: usize = 4;
+
+/// This struct represents an EAP packet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EapPacket {
+    code: EapCode,
+    identifier: u8,
+    typ: Option<EapType>,
+    data: Vec<u8>,
+}
+
+impl EapPacket {
+    /// Make an EAP-Request of the method type.
+    pub fn new_request(identifier: u8, typ: EapType, data: &[u8]) -> Self {
+        EapPacket {
+            code: EapCode::Request,
+            identifier,
+            typ: Some(typ),
+            data: data.to_vec(),
+        }
+    }
+
+    /// Make an EAP-Response of the method type.
+    pub fn new_response(identifier: u8, typ: EapType, data: &[u8]) -> Self {
+        EapPacket {
+            code: EapCode::Response,
+            identifier,
+            typ: Some(typ),
+            data: data.to_vec(),
+        }
+    }
+
+    /// Make an EAP-Success.
+    pub fn new_success(identifier: u8) -> Self {
+        EapPacket {
+            code: EapCode::Success,
+            identifier,
+            typ: None,
+            data: vec![],
+        }
+    }
+
+    /// Make an EAP-Failure.
+    pub fn new_failure(identifier: u8) -> Self {
+        EapPacket {
+            code: EapCode::Failure,
+            identifier,
+            typ: None,
+            data: vec![],
+        }
+    }
+
+    pub fn get_code(&self) -> EapCode {
+        self.code
+    }
+
+    pub fn get_identifier(&self) -> u8 {
+        self.identifier
+    }
+
+    /// Returns the method type; EAP-Success and EAP-Failure don't have that.
+    pub fn get_type(&self) -> Option<EapType> {
+        self.typ
+    }
+
+    /// Returns the type-data of the packet.
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn decode(bs: &[u8]) -> Result<Self, EapError> {
+        if bs.len() < EAP_HEADER_LENGTH {
+            return Err(EapError::DecodingError("short buffer".to_owned()));
+        }
+        let code = EapCode::try_from(bs[0])?;
+        let length = u16::from_be_bytes([bs[2], bs[3]]) as usize;
+        if length < EAP_HEADER_LENGTH || length > bs.len() {
+            return Err(EapError::DecodingError(format!(
+                "invalid length: {}",
+                length
+            )));
+        }
+
+        let (typ, data) = match code {
+            EapCode::Request | EapCode::Response => {
+                if length < EAP_HEADER_LENGTH + 1 {
+                    return Err(EapError::DecodingError("type is missing".to_owned()));
+                }
+                (
+                    Some(bs[EAP_HEADER_LENGTH]),
+                    bs[EAP_HEADER_LENGTH + 1..length].to_vec(),
+                )
+            }
+            EapCode::Success | EapCode::Failure => (None, vec![]),
+        };
+
+        Ok(EapPacket {
+            code,
+            identifier: bs[1],
+            typ,
+            data,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let length = EAP_HEADER_LENGTH + self.typ.map_or(0, |_| 1) + self.data.len();
+        let mut bs = vec![self.code as u8, self.identifier];
+        bs.extend((length as u16).to_be_bytes());
+        if let Some(typ) = self.typ {
+            bs.push(typ);
+        }
+        bs.extend(&self.data);
+        bs
+    }
+}
+
+/// Lookup the EAP packet of `EAP-Message` attributes from a RADIUS packet.
+pub fn lookup_eap_packet(packet: &Packet) -> Option<Result<EapPacket, EapError>> {
+    rfc2869::lookup_eap_message(packet).map(|bs| {
+        bs.map_err(EapError::AttributeDecodingError)
+            .and_then(|bs| EapPacket::decode(&bs))
+    })
+}
+
+/// Add the EAP packet as `EAP-Message` attributes to a RADIUS packet, with `Message-Authenticator`
+/// that is required by RFC 3579.
+///
+/// It returns an error if the EAP packet exceeds the maximum length of `EAP-Message`.
+pub fn add_eap_packet(packet: &mut Packet, eap: &EapPacket) -> Result<(), EapError> {
+    rfc2869::delete_eap_message(packet);
+    rfc2869::add_eap_message(packet, eap.encode()).map_err(EapError::AttributeEncodingError)?;
+    rfc2869::delete_message_authenticator(packet);
+    rfc2869::add_message_authenticator(packet, [0; 16]).map_err(EapError::AttributeEncodingError)
+}
+
+/// This enum represents the next step of an EAP method.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EapStep<S> {
+    /// Send an EAP-Request with the type-data, and wait for the response with the state.
+    Continue(Vec<u8>, S),
+    /// The authentication succeeds; the template is applied to the Access-Accept.
+    ///
+    /// The key-deriving methods (e.g. EAP-TLS) give the MSK, that is sent as the MPPE keys.
+    Success(ReplyTemplate, Option<Vec<u8>>),
+    /// The authentication fails.
+    Failure,
+}
+
+/// EapMethod implements the method specific part of an EAP conversation.
+#[async_trait]
+pub trait EapMethod: 'static + Sync + Send {
+    /// The state of a conversation between the rounds.
+    type State: 'static + Send;
+
+    /// Returns the method type.
+    fn get_type(&self) -> EapType;
+
+    /// Start a conversation for the identity of EAP-Response/Identity.
+    async fn start(&self, identity: &str) -> Result<EapStep<Self::State>, EapError>;
+
+    /// Process an EAP-Response of the method type.
+    async fn process(
+        &self,
+        state: Self::State,
+        response: &EapPacket,
+    ) -> Result<EapStep<Self::State>, EapError>;
+}
+
+struct Conversation<S> {
+    identifier: u8,
+    state: S,
+    expires_at: Instant,
+}
+
+/// A prebuilt `RequestHandler` that serves the EAP conversations with an `EapMethod`.
+///
+/// The request has to have a valid `Message-Authenticator`; otherwise that is discarded silently.
+/// An Access-Challenge has a random `State` that identifies the conversation, and the conversation
+/// is expired when the NAS doesn't respond within the timeout.
+pub struct EapHandler<M: EapMethod> {
+    method: Arc<M>,
+    conversation_timeout: Duration,
+    conversations: Mutex<HashMap<Vec<u8>, Conversation<M::State>>>,
+    random_source: Arc<dyn RandomSource>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<M: EapMethod> EapHandler<M> {
+    pub fn new(method: Arc<M>) -> Self {
+        EapHandler {
+            method,
+            conversation_timeout: Duration::from_secs(30),
+            conversations: Mutex::new(HashMap::new()),
+            random_source: random::default_random_source(),
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set the time to wait for the next round of a conversation (default: 30 seconds).
+    pub fn set_conversation_timeout(&mut self, conversation_timeout: Duration) {
+        self.conversation_timeout = conversation_timeout;
+    }
+
+    /// Set a source of the `State` values (default: `ThreadRandomSource`).
+    pub fn set_random_source(&mut self, random_source: Arc<dyn RandomSource>) {
+        self.random_source = random_source;
+    }
+
+    /// Set a clock of the expiry of the conversations (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    pub fn get_method(&self) -> &Arc<M> {
+        &self.method
+    }
+
+    /// Returns the number of the conversations that wait for the next round.
+    pub fn get_pending_conversations_len(&self) -> usize {
+        self.conversations.lock().unwrap().len()
+    }
+
+    /// Process the Access-Request and make the response packet.
+    pub async fn make_response(&self, request: &Request) -> Result<Packet, EapError> {
+        let packet = request.get_packet();
+        if packet.get_code() != Code::AccessRequest {
+            return Err(EapError::UnexpectedRequestCodeError(
+                packet.get_code().string().to_owned(),
+            ));
+        }
+        let eap = match lookup_eap_packet(packet) {
+            Some(eap) => eap?,
+            None => return Err(EapError::EapMessageMissingError()),
+        };
+        if !packet.is_authentic_message_authenticator(None) {
+            return Err(EapError::InvalidMessageAuthenticatorError());
+        }
+        if eap.get_code() != EapCode::Response {
+            return Err(EapError::UnexpectedEapCodeError(eap.get_code() as u8));
+        }
+
+        let step = match eap.get_type() {
+            Some(EAP_TYPE_IDENTITY) => {
+                let identity = String::from_utf8_lossy(eap.get_data()).into_owned();
+                self.method.start(&identity).await?
+            }
+            typ => {
+                let conversation = match rfc2865::lookup_state(packet)
+                    .and_then(|state| self.conversations.lock().unwrap().remove(&state))
+                {
+                    Some(conversation) => conversation,
+                    None => return self.make_failure(packet, &eap),
+                };
+                if conversation.expires_at <= self.clock.now()
+                    || conversation.identifier != eap.get_identifier()
+                    || typ != Some(self.method.get_type())
+                {
+                    // includes Legacy-Nak; no other method is available
+                    return self.make_failure(packet, &eap);
+                }
+                self.method.process(conversation.state, &eap).await?
+            }
+        };
+
+        match step {
+            EapStep::Continue(data, state) => {
+                let identifier = eap.get_identifier().wrapping_add(1);
+                let state_value = random::random_bytes::<16>(self.random_source.as_ref()).to_vec();
+                let now = self.clock.now();
+                {
+                    let mut conversations = self.conversations.lock().unwrap();
+                    conversations.retain(|_, conversation| conversation.expires_at > now);
+                    conversations.insert(
+                        state_value.clone(),
+                        Conversation {
+                            identifier,
+                            state,
+                            expires_at: now + self.conversation_timeout,
+                        },
+                    );
+                }
+
+                let mut response = packet.make_response_packet(Code::AccessChallenge);
+                rfc2865::add_state(&mut response, &state_value)
+                    .map_err(EapError::AttributeEncodingError)?;
+                add_eap_packet(
+                    &mut response,
+                    &EapPacket::new_request(identifier, self.method.get_type(), &data),
+                )?;
+                Ok(response)
+            }
+            EapStep::Success(template, msk) => {
+                let mut response = packet.make_response_packet(Code::AccessAccept);
+                template.apply(&mut response);
+                if let Some(msk) = msk {
+                    mppe::add_mppe_keys(&mut response, &msk)
+                        .map_err(|e| EapError::ResponseEncodingError(e.to_string()))?;
+                }
+                add_eap_packet(&mut response, &EapPacket::new_success(eap.get_identifier()))?;
+                Ok(response)
+            }
+            EapStep::Failure => self.make_failure(packet, &eap),
+        }
+    }
+
+    fn make_failure(&self, packet: &Packet, eap: &EapPacket) -> Result<Packet, EapError> {
+        let mut response = packet.make_response_packet(Code::AccessReject);
+        add_eap_packet(&mut response, &EapPacket::new_failure(eap.get_identifier()))?;
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl<M: EapMethod> RequestHandler<(), EapError> for EapHandler<M> {
+    async fn handle_radius_request(
+        &self,
+        conn: &UdpSocket,
+        request: &Request,
+    ) -> Result<(), EapError> {
+        let response = self.make_response(request).await?;
+        let encoded = response
+            .encode()
+            .map_err(|e| EapError::ResponseEncodingError(e.to_string()))?;
+        match conn.send_to(&encoded, request.get_remote_addr()).await {
+            Ok(_) => {
+                request.audit_response(&response);
+                Ok(())
+            }
+            Err(e) => Err(EapError::ResponseSendingError(e.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::avp::{AVPError, AVP};
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2869;
+    use crate::eap::{
+        add_eap_packet, lookup_eap_packet, EapCode, EapError, EapPacket, EAP_TYPE_IDENTITY,
+    };
+
+    #[test]
+    fn test_eap_packet() {
+        let identity = EapPacket::new_response(1, EAP_TYPE_IDENTITY, b"alice");
+        assert_eq!(
+            identity.encode(),
+            vec![0x02, 0x01, 0x00, 0x0a, 0x01, b'a', b'l', b'i', b'c', b'e']
+        );
+        assert_eq!(EapPacket::decode(&identity.encode()), Ok(identity.clone()));
+
+        let success = EapPacket::new_success(2);
+        assert_eq!(success.encode(), vec![0x03, 0x02, 0x00, 0x04]);
+        assert_eq!(EapPacket::decode(&success.encode()), Ok(success));
+
+        assert_eq!(
+            EapPacket::decode(&[0x05, 0x01, 0x00, 0x04]),
+            Err(EapError::DecodingError("unknown EAP code: 5".to_owned()))
+        );
+        assert_eq!(
+            EapPacket::decode(&[0x02, 0x01, 0x00, 0x04]),
+            Err(EapError::DecodingError("type is missing".to_owned()))
+        );
+
+        // a large EAP packet is fragmented into multiple EAP-Message attributes
+        let large = EapPacket::new_request(3, 13, &[0xab; 600]);
+        let mut packet = Packet::new(Code::AccessChallenge, b"secret");
+        add_eap_packet(&mut packet, &large).unwrap();
+        let decoded = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        assert_eq!(lookup_eap_packet(&decoded), Some(Ok(large)));
+        assert_eq!(
+            lookup_eap_packet(&decoded).unwrap().unwrap().get_code(),
+            EapCode::Request
+        );
+
+        // the total length of EAP-Message attributes is limited
+        let too_large = EapPacket::new_request(3, 13, &[0xab; 4040]);
+        assert_eq!(
+            add_eap_packet(&mut packet, &too_large),
+            Err(EapError::AttributeEncodingError(
+                AVPError::InvalidAttributeLengthError("<= 4044 bytes".to_owned(), 4045)
+            ))
+        );
+        let mut packet = Packet::new(Code::AccessChallenge, b"secret");
+        packet.extend(
+            [0xab; 4045]
+                .chunks(253)
+                .map(|chunk| AVP::from_bytes(rfc2869::EAP_MESSAGE_TYPE, chunk).unwrap())
+                .collect(),
+        );
+        assert!(matches!(
+            lookup_eap_packet(&packet),
+            Some(Err(EapError::AttributeDecodingError(_)))
+        ));
+    }
+}
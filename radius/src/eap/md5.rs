@@ -0,0 +1,280 @@
+//! EAP-MD5 method, that is the reference EAP method.
+//!
+//! The response value is `MD5(identifier || password || challenge)`, like CHAP.
+//! see also: https://tools.ietf.org/html/rfc3748#section-5.4
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::auth::hash::constant_time_eq;
+use crate::auth::{PasswordStore, ReplyTemplate};
+use crate::core::random::{self, RandomSource};
+use crate::eap::peer::EapPeerMethod;
+use crate::eap::{EapError, EapMethod, EapPacket, EapStep, EapType, EAP_TYPE_MD5_CHALLENGE};
+
+const CHALLENGE_LENGTH: usize = 16;
+
+/// The state of an EAP-MD5 conversation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Md5State {
+    identity: String,
+    challenge: Vec<u8>,
+}
+
+/// An `EapMethod` of EAP-MD5 that validates the response by the passwords of a `PasswordStore`.
+pub struct Md5Method<P: PasswordStore> {
+    password_store: Arc<P>,
+    template: ReplyTemplate,
+    random_source: Arc<dyn RandomSource>,
+}
+
+impl<P: PasswordStore> Md5Method<P> {
+    pub fn new(password_store: Arc<P>) -> Self {
+        Md5Method {
+            password_store,
+            template: ReplyTemplate::new(),
+            random_source: random::default_random_source(),
+        }
+    }
+
+    /// Set the template that is applied to the Access-Accept.
+    pub fn set_template(&mut self, template: ReplyTemplate) {
+        self.template = template;
+    }
+
+    /// Set a source of the challenges (default: `ThreadRandomSource`).
+    pub fn set_random_source(&mut self, random_source: Arc<dyn RandomSource>) {
+        self.random_source = random_source;
+    }
+}
+
+/// Compute the response value of EAP-MD5.
+pub fn compute_md5_response(identifier: u8, password: &[u8], challenge: &[u8]) -> Vec<u8> {
+    md5::compute([&[identifier], password, challenge].concat()).to_vec()
+}
+
+/// Encode the type-data of MD5-Challenge, i.e. Value-Size, Value and Name.
+pub fn encode_md5_data(value: &[u8], name: &[u8]) -> Vec<u8> {
+    let mut data = vec![value.len() as u8];
+    data.extend(value);
+    data.extend(name);
+    data
+}
+
+/// Decode the type-data of MD5-Challenge into the value and the name.
+pub fn decode_md5_data(data: &[u8]) -> Result<(&[u8], &[u8]), EapError> {
+    match data.split_first() {
+        Some((&size, rest)) if rest.len() >= size as usize => Ok(rest.split_at(size as usize)),
+        _ => Err(EapError::DecodingError(
+            "invalid MD5-Challenge data".to_owned(),
+        )),
+    }
+}
+
+#[async_trait]
+impl<P: PasswordStore> EapMethod for Md5Method<P> {
+    type State = Md5State;
+
+    fn get_type(&self) -> EapType {
+        EAP_TYPE_MD5_CHALLENGE
+    }
+
+    async fn start(&self, identity: &str) -> Result<EapStep<Self::State>, EapError> {
+        let challenge =
+            random::random_bytes::<CHALLENGE_LENGTH>(self.random_source.as_ref()).to_vec();
+        Ok(EapStep::Continue(
+            encode_md5_data(&challenge, b""),
+            Md5State {
+                identity: identity.to_owned(),
+                challenge,
+            },
+        ))
+    }
+
+    async fn process(
+        &self,
+        state: Self::State,
+        response: &EapPacket,
+    ) -> Result<EapStep<Self::State>, EapError> {
+        let (value, _) = decode_md5_data(response.get_data())?;
+        let password = match self
+            .password_store
+            .get_password(&state.identity)
+            .await
+            .map_err(EapError::BackendError)?
+        {
+            Some(password) => password,
+            None => return Ok(EapStep::Failure),
+        };
+
+        let expected = compute_md5_response(response.get_identifier(), &password, &state.challenge);
+        if constant_time_eq(&expected, value) {
+            Ok(EapStep::Success(self.template.clone(), None))
+        } else {
+            Ok(EapStep::Failure)
+        }
+    }
+}
+
+/// The peer side of EAP-MD5.
+pub struct Md5PeerMethod {
+    password: Vec<u8>,
+}
+
+impl Md5PeerMethod {
+    pub fn new(password: &[u8]) -> Self {
+        Md5PeerMethod {
+            password: password.to_vec(),
+        }
+    }
+}
+
+#[async_trait]
+impl EapPeerMethod for Md5PeerMethod {
+    fn get_type(&self) -> EapType {
+        EAP_TYPE_MD5_CHALLENGE
+    }
+
+    async fn process(&mut self, request: &EapPacket) -> Result<Vec<u8>, EapError> {
+        let (challenge, _) = decode_md5_data(request.get_data())?;
+        Ok(encode_md5_data(
+            &compute_md5_response(request.get_identifier(), &self.password, challenge),
+            b"",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::auth::InMemoryPasswordStore;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::{rfc2865, rfc2869};
+    use crate::eap::md5::{compute_md5_response, decode_md5_data, encode_md5_data, Md5Method};
+    use crate::eap::{
+        add_eap_packet, lookup_eap_packet, EapCode, EapError, EapHandler, EapPacket,
+        EAP_TYPE_IDENTITY, EAP_TYPE_MD5_CHALLENGE, EAP_TYPE_NAK,
+    };
+
+    fn make_request(eap: &EapPacket, state: Option<&[u8]>) -> Request {
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut packet, "alice");
+        if let Some(state) = state {
+            rfc2865::add_state(&mut packet, state).unwrap();
+        }
+        add_eap_packet(&mut packet, eap).unwrap();
+        // goes through the wire to compute the Message-Authenticator
+        let packet = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        Request::new(
+            "127.0.0.1:1812".parse().unwrap(),
+            "192.0.2.1:1812".parse().unwrap(),
+            packet,
+        )
+    }
+
+    fn make_handler() -> EapHandler<Md5Method<InMemoryPasswordStore>> {
+        let password_store = InMemoryPasswordStore::new();
+        password_store.set_password("alice", b"password");
+        EapHandler::new(Arc::new(Md5Method::new(Arc::new(password_store))))
+    }
+
+    async fn start(handler: &EapHandler<Md5Method<InMemoryPasswordStore>>) -> (Packet, EapPacket) {
+        let challenge = handler
+            .make_response(&make_request(
+                &EapPacket::new_response(1, EAP_TYPE_IDENTITY, b"alice"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(challenge.get_code(), Code::AccessChallenge);
+        let eap = lookup_eap_packet(&challenge).unwrap().unwrap();
+        assert_eq!(eap.get_code(), EapCode::Request);
+        assert_eq!(eap.get_identifier(), 2);
+        assert_eq!(eap.get_type(), Some(EAP_TYPE_MD5_CHALLENGE));
+        (challenge, eap)
+    }
+
+    #[tokio::test]
+    async fn test_eap_md5() {
+        let handler = make_handler();
+
+        let (challenge, eap) = start(&handler).await;
+        let state = rfc2865::lookup_state(&challenge).unwrap();
+        let (value, _) = decode_md5_data(eap.get_data()).unwrap();
+        let response = EapPacket::new_response(
+            eap.get_identifier(),
+            EAP_TYPE_MD5_CHALLENGE,
+            &encode_md5_data(
+                &compute_md5_response(eap.get_identifier(), b"password", value),
+                b"alice",
+            ),
+        );
+        let accept = handler
+            .make_response(&make_request(&response, Some(&state)))
+            .await
+            .unwrap();
+        assert_eq!(accept.get_code(), Code::AccessAccept);
+        assert_eq!(
+            lookup_eap_packet(&accept).unwrap().unwrap(),
+            EapPacket::new_success(2)
+        );
+        assert!(rfc2869::lookup_message_authenticator(&accept).is_some());
+        assert_eq!(handler.get_pending_conversations_len(), 0);
+
+        let (challenge, eap) = start(&handler).await;
+        let state = rfc2865::lookup_state(&challenge).unwrap();
+        let (value, _) = decode_md5_data(eap.get_data()).unwrap();
+        let response = EapPacket::new_response(
+            eap.get_identifier(),
+            EAP_TYPE_MD5_CHALLENGE,
+            &encode_md5_data(
+                &compute_md5_response(eap.get_identifier(), b"wrong", value),
+                b"",
+            ),
+        );
+        let reject = handler
+            .make_response(&make_request(&response, Some(&state)))
+            .await
+            .unwrap();
+        assert_eq!(reject.get_code(), Code::AccessReject);
+        assert_eq!(
+            lookup_eap_packet(&reject).unwrap().unwrap(),
+            EapPacket::new_failure(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_eap_md5_nak_and_invalid_message_authenticator() {
+        let handler = make_handler();
+
+        let (challenge, eap) = start(&handler).await;
+        let state = rfc2865::lookup_state(&challenge).unwrap();
+        let nak = EapPacket::new_response(eap.get_identifier(), EAP_TYPE_NAK, &[13]);
+        let reject = handler
+            .make_response(&make_request(&nak, Some(&state)))
+            .await
+            .unwrap();
+        assert_eq!(reject.get_code(), Code::AccessReject);
+
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc2869::add_eap_message(
+            &mut packet,
+            EapPacket::new_response(1, EAP_TYPE_IDENTITY, b"alice").encode(),
+        )
+        .unwrap();
+        rfc2869::add_message_authenticator(&mut packet, [0; 16]).unwrap();
+        let request = Request::new(
+            "127.0.0.1:1812".parse().unwrap(),
+            "192.0.2.1:1812".parse().unwrap(),
+            packet,
+        );
+        assert_eq!(
+            handler.make_response(&request).await,
+            Err(EapError::InvalidMessageAuthenticatorError())
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! MS-MPPE-Send-Key and MS-MPPE-Recv-Key of the Microsoft vendor attributes,
+//! that deliver the keys of the key-deriving EAP methods to the NAS.
+//! see also: https://tools.ietf.org/html/rfc2548#section-2.4.2 and https://tools.ietf.org/html/rfc5216#section-2.3
+
+use crate::core::avp::{AVPError, AVP};
+use crate::core::packet::Packet;
+use crate::core::random::{self, RandomSource, ThreadRandomSource};
+use crate::core::rfc2865;
+
+pub const MICROSOFT_VENDOR_ID: u32 = 311;
+pub const MS_MPPE_SEND_KEY_TYPE: u8 = 16;
+pub const MS_MPPE_RECV_KEY_TYPE: u8 = 17;
+
+const MSK_LENGTH: usize = 64;
+
+/// Add MS-MPPE-Recv-Key (the first half of the MSK) and MS-MPPE-Send-Key (the second half of the MSK)
+/// to the Access-Accept.
+///
+/// The keys are encrypted by the secret and the authenticator of the packet,
+/// so the packet has to be made by `Packet::make_response_packet()`; the salts are from the random source of the packet.
+pub fn add_mppe_keys(packet: &mut Packet, msk: &[u8]) -> Result<(), AVPError> {
+    if msk.len() != MSK_LENGTH {
+        return Err(AVPError::InvalidAttributeLengthError(
+            format!("{} bytes", MSK_LENGTH),
+            msk.len(),
+        ));
+    }
+
+    let random_source = packet.get_random_source().as_ref();
+    let recv_key = encrypt_mppe_key_with_random_source(
+        &msk[..32],
+        packet.get_secret(),
+        packet.get_authenticator(),
+        random_source,
+    )?;
+    let send_key = encrypt_mppe_key_with_random_source(
+        &msk[32..],
+        packet.get_secret(),
+        packet.get_authenticator(),
+        random_source,
+    )?;
+    packet.add(make_vendor_attribute(MS_MPPE_RECV_KEY_TYPE, &recv_key)?);
+    packet.add(make_vendor_attribute(MS_MPPE_SEND_KEY_TYPE, &send_key)?);
+    Ok(())
+}
+
+/// Lookup and decrypt MS-MPPE-Send-Key from the Access-Accept.
+///
+/// `request_authenticator` is the authenticator of the Access-Request.
+pub fn lookup_mppe_send_key(
+    packet: &Packet,
+    request_authenticator: &[u8],
+) -> Option<Result<Vec<u8>, AVPError>> {
+    lookup_vendor_attribute(packet, MS_MPPE_SEND_KEY_TYPE)
+        .map(|value| decrypt_mppe_key(&value, packet.get_secret(), request_authenticator))
+}
+
+/// Lookup and decrypt MS-MPPE-Recv-Key from the Access-Accept.
+///
+/// `request_authenticator` is the authenticator of the Access-Request.
+pub fn lookup_mppe_recv_key(
+    packet: &Packet,
+    request_authenticator: &[u8],
+) -> Option<Result<Vec<u8>, AVPError>> {
+    lookup_vendor_attribute(packet, MS_MPPE_RECV_KEY_TYPE)
+        .map(|value| decrypt_mppe_key(&value, packet.get_secret(), request_authenticator))
+}
+
+/// Encrypt the key into the value of MS-MPPE-Send-Key or MS-MPPE-Recv-Key, i.e. Salt and the encrypted String.
+pub fn encrypt_mppe_key(
+    key: &[u8],
+    secret: &[u8],
+    request_authenticator: &[u8],
+) -> Result<Vec<u8>, AVPError> {
+    encrypt_mppe_key_with_random_source(key, secret, request_authenticator, &ThreadRandomSource)
+}
+
+/// Encrypt the key as `encrypt_mppe_key()` does, with the salt from the given source.
+pub fn encrypt_mppe_key_with_random_source(
+    key: &[u8],
+    secret: &[u8],
+    request_authenticator: &[u8],
+    random_source: &dyn RandomSource,
+) -> Result<Vec<u8>, AVPError> {
+    /*
+     *  P = Key-Length + Key + Padding
+     *
+     *    b(1) = MD5(S + R + A)    c(1) = p(1) xor b(1)   C = c(1)
+     *    b(2) = MD5(S + c(1))     c(2) = p(2) xor b(2)   C = C + c(2)
+     *                .                      .
+     *    b(i) = MD5(S + c(i-1))   c(i) = p(i) xor b(i)   C = C + c(i)
+     *
+     *  https://tools.ietf.org/html/rfc2548#section-2.4.2
+     */
+
+    if key.len() > 240 {
+        return Err(AVPError::InvalidAttributeLengthError(
+            "240 bytes".to_owned(),
+            key.len(),
+        ));
+    }
+    if secret.is_empty() {
+        return Err(AVPError::PasswordSecretMissingError());
+    }
+    if request_authenticator.len() != 16 {
+        return Err(AVPError::InvalidRequestAuthenticatorLength());
+    }
+
+    let salt = random::random_salt(random_source);
+
+    let mut plain_text = vec![key.len() as u8];
+    plain_text.extend(key);
+    if !plain_text.len().is_multiple_of(16) {
+        plain_text.resize(plain_text.len() + 16 - plain_text.len() % 16, 0);
+    }
+
+    let mut enc = salt.to_vec();
+    let mut buff = [request_authenticator, &salt].concat();
+    for chunk in plain_text.chunks(16) {
+        buff = md5::compute([secret, &buff[..]].concat())
+            .iter()
+            .zip(chunk)
+            .map(|(d, p)| d ^ p)
+            .collect();
+        enc.extend(&buff);
+    }
+    Ok(enc)
+}
+
+/// Decrypt the value of MS-MPPE-Send-Key or MS-MPPE-Recv-Key into the key.
+pub fn decrypt_mppe_key(
+    value: &[u8],
+    secret: &[u8],
+    request_authenticator: &[u8],
+) -> Result<Vec<u8>, AVPError> {
+    if value.len() < 18 || !(value.len() - 2).is_multiple_of(16) {
+        return Err(AVPError::InvalidAttributeLengthError(
+            "18 <= bytes && (bytes - 2) % 16 == 0".to_owned(),
+            value.len(),
+        ));
+    }
+    if value[0] & 0x80 != 0x80 {
+        return Err(AVPError::InvalidSaltMSBError(value[0]));
+    }
+    if secret.is_empty() {
+        return Err(AVPError::PasswordSecretMissingError());
+    }
+    if request_authenticator.len() != 16 {
+        return Err(AVPError::InvalidRequestAuthenticatorLength());
+    }
+
+    let mut dec: Vec<u8> = Vec::new();
+    let mut buff = [request_authenticator, &value[..2]].concat();
+    for chunk in value[2..].chunks(16) {
+        dec.extend(
+            md5::compute([secret, &buff[..]].concat())
+                .iter()
+                .zip(chunk)
+                .map(|(d, c)| d ^ c),
+        );
+        buff = chunk.to_vec();
+    }
+
+    let key_length = dec[0] as usize;
+    if key_length > dec.len() - 1 {
+        return Err(AVPError::InvalidAttributeLengthError(
+            format!("{} bytes", dec.len() - 1),
+            key_length,
+        ));
+    }
+    Ok(dec[1..=key_length].to_vec())
+}
+
+fn make_vendor_attribute(vendor_type: u8, value: &[u8]) -> Result<AVP, AVPError> {
+    let mut vsa = MICROSOFT_VENDOR_ID.to_be_bytes().to_vec();
+    vsa.push(vendor_type);
+    vsa.push((value.len() + 2) as u8);
+    vsa.extend(value);
+    AVP::from_bytes_owned(rfc2865::VENDOR_SPECIFIC_TYPE, vsa)
+}
+
+fn lookup_vendor_attribute(packet: &Packet, vendor_type: u8) -> Option<Vec<u8>> {
+    packet
+        .lookup_all(rfc2865::VENDOR_SPECIFIC_TYPE)
+        .into_iter()
+        .map(|avp| avp.decode_bytes())
+        .find(|vsa| {
+            vsa.len() >= 6
+                && vsa[..4] == MICROSOFT_VENDOR_ID.to_be_bytes()
+                && vsa[4] == vendor_type
+                && vsa[5] as usize == vsa.len() - 4
+        })
+        .map(|vsa| vsa[6..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::avp::AVPError;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::eap::mppe::{
+        add_mppe_keys, decrypt_mppe_key, encrypt_mppe_key, lookup_mppe_recv_key,
+        lookup_mppe_send_key,
+    };
+
+    #[test]
+    fn test_mppe_keys() {
+        let request_authenticator = [0x5a; 16];
+        let enc = encrypt_mppe_key(&[0xab; 32], b"secret", &request_authenticator).unwrap();
+        // salt + (key length + 32 bytes key + padding)
+        assert_eq!(enc.len(), 2 + 48);
+        assert_eq!(
+            decrypt_mppe_key(&enc, b"secret", &request_authenticator),
+            Ok(vec![0xab; 32])
+        );
+        assert_ne!(
+            decrypt_mppe_key(&enc, b"wrong", &request_authenticator),
+            Ok(vec![0xab; 32])
+        );
+
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        let mut response = request.make_response_packet(Code::AccessAccept);
+        let msk = (0..64).collect::<Vec<u8>>();
+        add_mppe_keys(&mut response, &msk).unwrap();
+
+        let decoded = Packet::decode(&response.encode().unwrap(), b"secret").unwrap();
+        assert_eq!(
+            lookup_mppe_recv_key(&decoded, request.get_authenticator()),
+            Some(Ok(msk[..32].to_vec()))
+        );
+        assert_eq!(
+            lookup_mppe_send_key(&decoded, request.get_authenticator()),
+            Some(Ok(msk[32..].to_vec()))
+        );
+
+        assert_eq!(
+            add_mppe_keys(&mut response, &msk[..32]),
+            Err(AVPError::InvalidAttributeLengthError(
+                "64 bytes".to_owned(),
+                32
+            ))
+        );
+    }
+}
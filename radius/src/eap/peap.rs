@@ -0,0 +1,425 @@
+//! PEAP (version 0) method, that tunnels an inner EAP method (e.g. EAP-MSCHAPv2) in TLS.
+//!
+//! The outer TLS is driven by the EAP-TLS machinery. The inner EAP packets are compressed, i.e. without
+//! Code, Identifier and Length; those are derived from the outer EAP packet. The result of the inner method
+//! is acknowledged by the Result TLV of an EAP-TLV (Extensions) packet, that is not compressed.
+//! see also: https://tools.ietf.org/html/draft-kamath-pppext-peapv0-00
+//!
+//! Cryptographic binding is not implemented; the MSK is derived from the outer TLS session.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::auth::ReplyTemplate;
+use crate::eap::tls::{
+    TlsAcceptor, TlsProgress, TlsSession, TlsState, TlsTunnel, KEYING_MATERIAL_LABEL,
+};
+use crate::eap::{
+    EapCode, EapError, EapMethod, EapPacket, EapStep, EapType, EAP_TYPE_IDENTITY, EAP_TYPE_PEAP,
+};
+
+pub const EAP_TYPE_PEAP_EXTENSIONS: EapType = 33;
+
+const RESULT_TLV_TYPE: u16 = 3;
+const MANDATORY_TLV_FLAG: u16 = 0x8000;
+const RESULT_SUCCESS: u16 = 1;
+const RESULT_FAILURE: u16 = 2;
+
+/// Encode an inner EAP packet into the compressed form, i.e. Type and Type-Data.
+pub fn encode_compressed(eap: &EapPacket) -> Vec<u8> {
+    let mut bs = eap.get_type().map_or(vec![], |typ| vec![typ]);
+    bs.extend(eap.get_data());
+    bs
+}
+
+/// Decode the compressed form of an inner EAP-Response with the identifier of the outer packet.
+pub fn decode_compressed_response(identifier: u8, bs: &[u8]) -> Result<EapPacket, EapError> {
+    match bs.split_first() {
+        Some((&typ, data)) => Ok(EapPacket::new_response(identifier, typ, data)),
+        None => Err(EapError::DecodingError(
+            "inner EAP packet is empty".to_owned(),
+        )),
+    }
+}
+
+/// Make an EAP-TLV packet with the Result TLV.
+pub fn make_result_tlv(code: EapCode, identifier: u8, success: bool) -> EapPacket {
+    let result = if success {
+        RESULT_SUCCESS
+    } else {
+        RESULT_FAILURE
+    };
+    let mut data = (MANDATORY_TLV_FLAG | RESULT_TLV_TYPE)
+        .to_be_bytes()
+        .to_vec();
+    data.extend(2u16.to_be_bytes());
+    data.extend(result.to_be_bytes());
+    match code {
+        EapCode::Request => EapPacket::new_request(identifier, EAP_TYPE_PEAP_EXTENSIONS, &data),
+        _ => EapPacket::new_response(identifier, EAP_TYPE_PEAP_EXTENSIONS, &data),
+    }
+}
+
+/// Returns the value of the Result TLV of an EAP-TLV packet; `true` means success.
+pub fn lookup_result_tlv(eap: &EapPacket) -> Option<bool> {
+    if eap.get_type() != Some(EAP_TYPE_PEAP_EXTENSIONS) {
+        return None;
+    }
+    let mut data = eap.get_data();
+    while data.len() >= 4 {
+        let typ = u16::from_be_bytes([data[0], data[1]]) & !MANDATORY_TLV_FLAG;
+        let length = u16::from_be_bytes([data[2], data[3]]) as usize;
+        if data.len() < 4 + length {
+            return None;
+        }
+        if typ == RESULT_TLV_TYPE && length == 2 {
+            return Some(u16::from_be_bytes([data[4], data[5]]) == RESULT_SUCCESS);
+        }
+        data = &data[4 + length..];
+    }
+    None
+}
+
+enum PeapPhase<I> {
+    Handshake,
+    Identity,
+    Inner(I),
+    Result(Option<ReplyTemplate>),
+}
+
+/// The state of a PEAP conversation.
+pub struct PeapState<S: TlsSession, I> {
+    tls: TlsState<S>,
+    phase: PeapPhase<I>,
+}
+
+/// An `EapMethod` of PEAPv0, that authenticates the peer by the inner `EapMethod` in the tunnel.
+pub struct PeapMethod<A: TlsAcceptor, M: EapMethod> {
+    acceptor: Arc<A>,
+    inner: Arc<M>,
+    tunnel: TlsTunnel,
+    template: ReplyTemplate,
+}
+
+impl<A: TlsAcceptor, M: EapMethod> PeapMethod<A, M> {
+    pub fn new(acceptor: Arc<A>, inner: Arc<M>) -> Self {
+        PeapMethod {
+            acceptor,
+            inner,
+            tunnel: TlsTunnel {
+                fragment_size: 1000,
+                max_message_length: 65536,
+            },
+            template: ReplyTemplate::new(),
+        }
+    }
+
+    /// Set the maximum size of TLS data in an EAP-Request (default: 1000 bytes).
+    pub fn set_fragment_size(&mut self, fragment_size: usize) {
+        self.tunnel.fragment_size = fragment_size.max(1);
+    }
+
+    /// Set the template that is applied to the Access-Accept, in addition to that of the inner method.
+    pub fn set_template(&mut self, template: ReplyTemplate) {
+        self.template = template;
+    }
+
+    pub fn get_inner(&self) -> &Arc<M> {
+        &self.inner
+    }
+
+    fn send(
+        &self,
+        mut state: PeapState<A::Session, M::State>,
+        plaintext: &[u8],
+    ) -> EapStep<PeapState<A::Session, M::State>> {
+        match self.tunnel.send_plaintext(&mut state.tls, plaintext, 0) {
+            TlsProgress::Send(data) => EapStep::Continue(data, state),
+            _ => EapStep::Failure,
+        }
+    }
+
+    fn send_inner_step(
+        &self,
+        mut state: PeapState<A::Session, M::State>,
+        identifier: u8,
+        step: EapStep<M::State>,
+    ) -> EapStep<PeapState<A::Session, M::State>> {
+        // the next EAP-Request has the identifier of the response + 1; see `EapHandler`
+        let identifier = identifier.wrapping_add(1);
+        match step {
+            EapStep::Continue(data, inner_state) => {
+                state.phase = PeapPhase::Inner(inner_state);
+                let inner = EapPacket::new_request(identifier, self.inner.get_type(), &data);
+                self.send(state, &encode_compressed(&inner))
+            }
+            EapStep::Success(inner_template, _) => {
+                let mut template = self.template.clone();
+                template.extend(&inner_template);
+                state.phase = PeapPhase::Result(Some(template));
+                let result = make_result_tlv(EapCode::Request, identifier, true);
+                self.send(state, &result.encode())
+            }
+            EapStep::Failure => {
+                state.phase = PeapPhase::Result(None);
+                let result = make_result_tlv(EapCode::Request, identifier, false);
+                self.send(state, &result.encode())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<A: TlsAcceptor, M: EapMethod> EapMethod for PeapMethod<A, M> {
+    type State = PeapState<A::Session, M::State>;
+
+    fn get_type(&self) -> EapType {
+        EAP_TYPE_PEAP
+    }
+
+    async fn start(&self, identity: &str) -> Result<EapStep<Self::State>, EapError> {
+        let (data, tls) = self.tunnel.start(self.acceptor.as_ref(), identity, 0)?;
+        Ok(EapStep::Continue(
+            data,
+            PeapState {
+                tls,
+                phase: PeapPhase::Handshake,
+            },
+        ))
+    }
+
+    async fn process(
+        &self,
+        mut state: Self::State,
+        response: &EapPacket,
+    ) -> Result<EapStep<Self::State>, EapError> {
+        match self.tunnel.receive(&mut state.tls, response, 0)? {
+            TlsProgress::Send(data) => return Ok(EapStep::Continue(data, state)),
+            TlsProgress::Failure => return Ok(EapStep::Failure),
+            TlsProgress::Idle => {}
+        }
+        if state.tls.get_session().is_handshaking() {
+            return Ok(EapStep::Failure);
+        }
+
+        let plaintext = state.tls.get_session_mut().read_plaintext();
+        let identifier = response.get_identifier();
+        match std::mem::replace(&mut state.phase, PeapPhase::Handshake) {
+            PeapPhase::Handshake => {
+                // the tunnel is established; start the inner conversation
+                state.phase = PeapPhase::Identity;
+                let request =
+                    EapPacket::new_request(identifier.wrapping_add(1), EAP_TYPE_IDENTITY, &[]);
+                Ok(self.send(state, &encode_compressed(&request)))
+            }
+            PeapPhase::Identity => {
+                let inner = decode_compressed_response(identifier, &plaintext)?;
+                if inner.get_type() != Some(EAP_TYPE_IDENTITY) {
+                    return Ok(EapStep::Failure);
+                }
+                let identity = String::from_utf8_lossy(inner.get_data()).into_owned();
+                let step = self.inner.start(&identity).await?;
+                Ok(self.send_inner_step(state, identifier, step))
+            }
+            PeapPhase::Inner(inner_state) => {
+                let inner = decode_compressed_response(identifier, &plaintext)?;
+                let step = if inner.get_type() == Some(self.inner.get_type()) {
+                    self.inner.process(inner_state, &inner).await?
+                } else {
+                    // includes Legacy-Nak; no other inner method is available
+                    EapStep::Failure
+                };
+                Ok(self.send_inner_step(state, identifier, step))
+            }
+            PeapPhase::Result(template) => {
+                let result = EapPacket::decode(&plaintext)?;
+                match (template, lookup_result_tlv(&result)) {
+                    (Some(template), Some(true)) => {
+                        let msk = self.tunnel.derive_msk(&state.tls, KEYING_MATERIAL_LABEL)?;
+                        Ok(EapStep::Success(template, Some(msk)))
+                    }
+                    _ => Ok(EapStep::Failure),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Arc;
+
+    use crate::auth::InMemoryPasswordStore;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::rfc2865;
+    use crate::eap::md5::{compute_md5_response, decode_md5_data, encode_md5_data, Md5Method};
+    use crate::eap::peap::{
+        encode_compressed, lookup_result_tlv, make_result_tlv, PeapMethod, EAP_TYPE_PEAP_EXTENSIONS,
+    };
+    use crate::eap::tls::tests::{FakeTlsAcceptor, SERVER_FLIGHT_LENGTH};
+    use crate::eap::tls::TlsFragment;
+    use crate::eap::{
+        add_eap_packet, lookup_eap_packet, EapCode, EapHandler, EapMethod, EapPacket,
+        EAP_TYPE_IDENTITY, EAP_TYPE_MD5_CHALLENGE, EAP_TYPE_PEAP,
+    };
+
+    /// Send the TLS records to the handler, and returns the response and the records of the next EAP-Request.
+    pub(crate) async fn exchange<M: EapMethod>(
+        handler: &EapHandler<M>,
+        typ: u8,
+        previous: &Packet,
+        records: &[u8],
+    ) -> (Packet, Vec<u8>) {
+        let eap = lookup_eap_packet(previous).unwrap().unwrap();
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_state(&mut packet, rfc2865::lookup_state(previous).unwrap()).unwrap();
+        add_eap_packet(
+            &mut packet,
+            &EapPacket::new_response(
+                eap.get_identifier(),
+                typ,
+                &TlsFragment::new(0, None, records).encode(),
+            ),
+        )
+        .unwrap();
+        let packet = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        let response = handler
+            .make_response(&Request::new(
+                "127.0.0.1:1812".parse().unwrap(),
+                "192.0.2.1:1812".parse().unwrap(),
+                packet,
+            ))
+            .await
+            .unwrap();
+        let records = match lookup_eap_packet(&response).unwrap().unwrap() {
+            eap if eap.get_code() == EapCode::Request => TlsFragment::decode(eap.get_data())
+                .unwrap()
+                .get_data()
+                .to_vec(),
+            _ => vec![],
+        };
+        (response, records)
+    }
+
+    /// Establish the fake tunnel, and returns the Access-Challenge that has the first application data.
+    pub(crate) async fn establish<M: EapMethod>(
+        handler: &EapHandler<M>,
+        typ: u8,
+    ) -> (Packet, Vec<u8>) {
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        add_eap_packet(
+            &mut packet,
+            &EapPacket::new_response(1, EAP_TYPE_IDENTITY, b"anonymous"),
+        )
+        .unwrap();
+        let packet = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        let challenge = handler
+            .make_response(&Request::new(
+                "127.0.0.1:1812".parse().unwrap(),
+                "192.0.2.1:1812".parse().unwrap(),
+                packet,
+            ))
+            .await
+            .unwrap();
+
+        let (challenge, records) = exchange(handler, typ, &challenge, b"hello").await;
+        assert_eq!(records, vec![0x16; SERVER_FLIGHT_LENGTH]);
+        let (challenge, records) = exchange(handler, typ, &challenge, b"finished").await;
+        assert_eq!(records, b"server finished");
+        exchange(handler, typ, &challenge, &[]).await
+    }
+
+    fn make_handler() -> EapHandler<PeapMethod<FakeTlsAcceptor, Md5Method<InMemoryPasswordStore>>> {
+        let password_store = InMemoryPasswordStore::new();
+        password_store.set_password("alice", b"password");
+        let mut method = PeapMethod::new(
+            Arc::new(FakeTlsAcceptor),
+            Arc::new(Md5Method::new(Arc::new(password_store))),
+        );
+        method.set_fragment_size(4096);
+        EapHandler::new(Arc::new(method))
+    }
+
+    #[test]
+    fn test_result_tlv() {
+        let result = make_result_tlv(EapCode::Request, 5, true);
+        assert_eq!(result.get_type(), Some(EAP_TYPE_PEAP_EXTENSIONS));
+        assert_eq!(result.get_data(), &[0x80, 0x03, 0x00, 0x02, 0x00, 0x01]);
+        assert_eq!(lookup_result_tlv(&result), Some(true));
+        assert_eq!(
+            lookup_result_tlv(&make_result_tlv(EapCode::Response, 5, false)),
+            Some(false)
+        );
+        assert_eq!(
+            lookup_result_tlv(&EapPacket::new_response(5, EAP_TYPE_PEAP, &[])),
+            None
+        );
+    }
+
+    async fn authenticate(password: &[u8]) -> Packet {
+        let handler = make_handler();
+
+        // inner EAP-Request/Identity
+        let (challenge, records) = establish(&handler, EAP_TYPE_PEAP).await;
+        assert_eq!(records, vec![0x17, EAP_TYPE_IDENTITY]);
+
+        let (challenge, records) = exchange(
+            &handler,
+            EAP_TYPE_PEAP,
+            &challenge,
+            &[&[0x17, EAP_TYPE_IDENTITY][..], b"alice"].concat(),
+        )
+        .await;
+        assert_eq!(records[1], EAP_TYPE_MD5_CHALLENGE);
+
+        // the inner identifier is the same as the outer one
+        let identifier = lookup_eap_packet(&challenge)
+            .unwrap()
+            .unwrap()
+            .get_identifier();
+        let (value, _) = decode_md5_data(&records[2..]).unwrap();
+        let inner = EapPacket::new_response(
+            identifier,
+            EAP_TYPE_MD5_CHALLENGE,
+            &encode_md5_data(&compute_md5_response(identifier, password, value), b""),
+        );
+        let (challenge, records) = exchange(
+            &handler,
+            EAP_TYPE_PEAP,
+            &challenge,
+            &[&[0x17][..], &encode_compressed(&inner)].concat(),
+        )
+        .await;
+        let result = EapPacket::decode(&records[1..]).unwrap();
+
+        let ack = make_result_tlv(
+            EapCode::Response,
+            result.get_identifier(),
+            lookup_result_tlv(&result).unwrap(),
+        );
+        let (response, _) = exchange(
+            &handler,
+            EAP_TYPE_PEAP,
+            &challenge,
+            &[&[0x17][..], &ack.encode()].concat(),
+        )
+        .await;
+        response
+    }
+
+    #[tokio::test]
+    async fn test_peap() {
+        let accept = authenticate(b"password").await;
+        assert_eq!(accept.get_code(), Code::AccessAccept);
+        assert_eq!(
+            lookup_eap_packet(&accept).unwrap().unwrap().get_code(),
+            EapCode::Success
+        );
+
+        let reject = authenticate(b"wrong").await;
+        assert_eq!(reject.get_code(), Code::AccessReject);
+    }
+}
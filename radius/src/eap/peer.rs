@@ -0,0 +1,445 @@
+//! EAP peer (i.e. supplicant side) state machine.
+//!
+//! `EapPeer` responds to the EAP-Requests by the registered `EapPeerMethod`s, and `EapPeer::authenticate()`
+//! completes a multi-round EAP conversation against a RADIUS server by the async `Client`.
+//! That is intended for the test tools of 802.1X deployments.
+
+use std::net::SocketAddr;
+
+use async_trait::async_trait;
+
+use crate::client::Client;
+use crate::core::avp::AVP;
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::{rfc2865, rfc2869};
+use crate::eap::tls::{TlsProgress, TlsSession, TlsState, TlsTunnel, KEYING_MATERIAL_LABEL};
+use crate::eap::{
+    add_eap_packet, lookup_eap_packet, EapCode, EapError, EapPacket, EapType, EAP_TYPE_IDENTITY,
+    EAP_TYPE_NAK, EAP_TYPE_NOTIFICATION, EAP_TYPE_TLS,
+};
+
+/// EapPeerMethod implements the peer side of an EAP method.
+#[async_trait]
+pub trait EapPeerMethod: Send + Sync {
+    /// Returns the method type.
+    fn get_type(&self) -> EapType;
+
+    /// Process an EAP-Request of the method type, and returns the type-data of the EAP-Response.
+    async fn process(&mut self, request: &EapPacket) -> Result<Vec<u8>, EapError>;
+
+    /// Returns the MSK when the method derives that.
+    fn get_msk(&self) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+/// This enum represents the result of an EAP conversation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EapOutcome {
+    /// The server accepts the peer; this has the Access-Accept.
+    Accept(Packet),
+    /// The server rejects the peer; this has the Access-Reject.
+    Reject(Packet),
+}
+
+/// The state machine of an EAP peer.
+pub struct EapPeer {
+    identity: String,
+    methods: Vec<Box<dyn EapPeerMethod>>,
+    selected: Option<usize>,
+    last_response: Option<EapPacket>,
+    attributes: Vec<AVP>,
+    max_rounds: usize,
+    request_authenticator: Vec<u8>,
+}
+
+impl EapPeer {
+    pub fn new(identity: &str) -> Self {
+        EapPeer {
+            identity: identity.to_owned(),
+            methods: vec![],
+            selected: None,
+            last_response: None,
+            attributes: vec![],
+            max_rounds: 50,
+            request_authenticator: vec![],
+        }
+    }
+
+    /// Add an acceptable method; the methods that are added earlier are preferred on the negotiation.
+    pub fn add_method(&mut self, method: Box<dyn EapPeerMethod>) {
+        self.methods.push(method);
+    }
+
+    /// Add an attribute that is put into each Access-Request (e.g. `NAS-IP-Address`).
+    pub fn add_attribute(&mut self, avp: AVP) {
+        self.attributes.push(avp);
+    }
+
+    /// Set the maximum number of the round trips of a conversation (default: 50).
+    pub fn set_max_rounds(&mut self, max_rounds: usize) {
+        self.max_rounds = max_rounds;
+    }
+
+    pub fn get_identity(&self) -> &str {
+        &self.identity
+    }
+
+    /// Returns the type of the method that the server has started.
+    pub fn get_selected_type(&self) -> Option<EapType> {
+        self.selected.map(|i| self.methods[i].get_type())
+    }
+
+    /// Returns the MSK of the selected method.
+    pub fn get_msk(&self) -> Option<Vec<u8>> {
+        self.selected.and_then(|i| self.methods[i].get_msk())
+    }
+
+    /// Returns the Request Authenticator of the last Access-Request,
+    /// that is required to decrypt the MPPE keys of the Access-Accept.
+    pub fn get_request_authenticator(&self) -> &[u8] {
+        &self.request_authenticator
+    }
+
+    /// Make the EAP-Response/Identity that starts a conversation.
+    pub fn start(&mut self) -> EapPacket {
+        self.selected = None;
+        let response = EapPacket::new_response(0, EAP_TYPE_IDENTITY, self.identity.as_bytes());
+        self.last_response = Some(response.clone());
+        response
+    }
+
+    /// Process an EAP packet from the server.
+    ///
+    /// This returns the EAP-Response to send, or `None` for EAP-Success and EAP-Failure.
+    pub async fn process(&mut self, request: &EapPacket) -> Result<Option<EapPacket>, EapError> {
+        let typ = match (request.get_code(), request.get_type()) {
+            (EapCode::Request, Some(typ)) => typ,
+            (EapCode::Success, _) | (EapCode::Failure, _) => return Ok(None),
+            (code, _) => return Err(EapError::UnexpectedEapCodeError(code as u8)),
+        };
+
+        // a retransmitted request is answered by the same response
+        if let Some(last_response) = &self.last_response {
+            if last_response.get_identifier() == request.get_identifier()
+                && typ != EAP_TYPE_IDENTITY
+            {
+                return Ok(Some(last_response.clone()));
+            }
+        }
+
+        let identifier = request.get_identifier();
+        let response = match typ {
+            EAP_TYPE_IDENTITY => {
+                EapPacket::new_response(identifier, EAP_TYPE_IDENTITY, self.identity.as_bytes())
+            }
+            EAP_TYPE_NOTIFICATION => {
+                EapPacket::new_response(identifier, EAP_TYPE_NOTIFICATION, &[])
+            }
+            _ => match self.methods.iter().position(|m| m.get_type() == typ) {
+                Some(i) if self.selected.is_none_or(|selected| selected == i) => {
+                    self.selected = Some(i);
+                    let data = self.methods[i].process(request).await?;
+                    EapPacket::new_response(identifier, typ, &data)
+                }
+                _ => {
+                    // Legacy-Nak with the acceptable methods
+                    let desired = self
+                        .methods
+                        .iter()
+                        .map(|m| m.get_type())
+                        .collect::<Vec<EapType>>();
+                    EapPacket::new_response(identifier, EAP_TYPE_NAK, &desired)
+                }
+            },
+        };
+        self.last_response = Some(response.clone());
+        Ok(Some(response))
+    }
+
+    /// Complete an EAP conversation against the RADIUS server by the client.
+    ///
+    /// Each Access-Challenge has to have a valid `Message-Authenticator`; otherwise this fails with
+    /// `InvalidMessageAuthenticatorError`.
+    pub async fn authenticate(
+        &mut self,
+        client: &Client,
+        remote_addr: &SocketAddr,
+        secret: &[u8],
+    ) -> Result<EapOutcome, EapError> {
+        let mut eap = self.start();
+        let mut state: Option<Vec<u8>> = None;
+
+        for _ in 0..self.max_rounds {
+            let mut request = Packet::new(Code::AccessRequest, secret);
+            rfc2865::add_user_name(&mut request, &self.identity);
+            request.extend(self.attributes.clone());
+            if let Some(state) = &state {
+                rfc2865::add_state(&mut request, state)
+                    .map_err(EapError::AttributeEncodingError)?;
+            }
+            add_eap_packet(&mut request, &eap)?;
+            self.request_authenticator = request.get_authenticator().to_vec();
+
+            let response = client
+                .send_packet(remote_addr, &request)
+                .await
+                .map_err(|e| EapError::RequestSendingError(e.to_string()))?;
+            let authentic =
+                response.is_authentic_message_authenticator(Some(&self.request_authenticator));
+            if !authentic && rfc2869::lookup_message_authenticator(&response).is_some() {
+                return Err(EapError::InvalidMessageAuthenticatorError());
+            }
+
+            match response.get_code() {
+                Code::AccessAccept => return Ok(EapOutcome::Accept(response)),
+                Code::AccessReject => return Ok(EapOutcome::Reject(response)),
+                Code::AccessChallenge => {}
+                code => {
+                    return Err(EapError::UnexpectedResponseCodeError(
+                        code.string().to_owned(),
+                    ))
+                }
+            }
+
+            // Access-Challenge with EAP-Message requires Message-Authenticator
+            if !authentic {
+                return Err(EapError::InvalidMessageAuthenticatorError());
+            }
+            let request_eap = match lookup_eap_packet(&response) {
+                Some(eap) => eap?,
+                None => return Err(EapError::EapMessageMissingError()),
+            };
+            eap = match self.process(&request_eap).await? {
+                Some(eap) => eap,
+                None => {
+                    return Err(EapError::UnexpectedEapCodeError(
+                        request_eap.get_code() as u8
+                    ))
+                }
+            };
+            state = rfc2865::lookup_state(&response);
+        }
+
+        Err(EapError::TooManyRoundsError(self.max_rounds))
+    }
+}
+
+/// The peer side of EAP-TLS, that drives a client `TlsSession`.
+pub struct TlsPeerMethod<S: TlsSession> {
+    state: TlsState<S>,
+    tunnel: TlsTunnel,
+}
+
+impl<S: TlsSession> TlsPeerMethod<S> {
+    pub fn new(session: S) -> Self {
+        TlsPeerMethod {
+            state: TlsState::new(session, ""),
+            tunnel: TlsTunnel {
+                fragment_size: 1000,
+                max_message_length: 65536,
+            },
+        }
+    }
+
+    /// Set the maximum size of TLS data in an EAP-Response (default: 1000 bytes).
+    pub fn set_fragment_size(&mut self, fragment_size: usize) {
+        self.tunnel.fragment_size = fragment_size.max(1);
+    }
+
+    pub fn get_session(&self) -> &S {
+        self.state.get_session()
+    }
+}
+
+#[async_trait]
+impl<S: TlsSession + Sync> EapPeerMethod for TlsPeerMethod<S> {
+    fn get_type(&self) -> EapType {
+        EAP_TYPE_TLS
+    }
+
+    async fn process(&mut self, request: &EapPacket) -> Result<Vec<u8>, EapError> {
+        match self.tunnel.receive(&mut self.state, request, 0)? {
+            TlsProgress::Send(data) => Ok(data),
+            // acknowledge the last flight of the server
+            TlsProgress::Idle => Ok(vec![0]),
+            TlsProgress::Failure => Err(EapError::MethodError("TLS handshake failed".to_owned())),
+        }
+    }
+
+    fn get_msk(&self) -> Option<Vec<u8>> {
+        if self.state.get_session().is_handshaking() {
+            return None;
+        }
+        self.tunnel
+            .derive_msk(&self.state, KEYING_MATERIAL_LABEL)
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+
+    use tokio::net::UdpSocket;
+
+    use crate::auth::InMemoryPasswordStore;
+    use crate::client::Client;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::eap::md5::{Md5Method, Md5PeerMethod};
+    use crate::eap::mppe::lookup_mppe_recv_key;
+    use crate::eap::peer::{EapOutcome, EapPeer, TlsPeerMethod};
+    use crate::eap::tls::tests::{FakeTlsAcceptor, SERVER_FLIGHT_LENGTH};
+    use crate::eap::tls::{TlsMethod, TlsSession};
+    use crate::eap::{
+        EapHandler, EapMethod, EapPacket, EAP_TYPE_IDENTITY, EAP_TYPE_MD5_CHALLENGE, EAP_TYPE_NAK,
+        EAP_TYPE_TLS,
+    };
+    use crate::server::RequestHandler;
+
+    async fn serve<M: EapMethod>(handler: EapHandler<M>) -> SocketAddr {
+        let conn = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = conn.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, remote_addr) = conn.recv_from(&mut buf).await.unwrap();
+                let packet = Packet::decode(&buf[..len], b"secret").unwrap();
+                let request = Request::new(addr, remote_addr, packet);
+                handler
+                    .handle_radius_request(&conn, &request)
+                    .await
+                    .unwrap();
+            }
+        });
+        addr
+    }
+
+    struct FakeTlsClientSession {
+        round: usize,
+        pending: Vec<u8>,
+    }
+
+    impl TlsSession for FakeTlsClientSession {
+        fn read_tls(&mut self, records: &[u8]) -> Result<(), String> {
+            match self.round {
+                1 if records == vec![0x16; SERVER_FLIGHT_LENGTH] => {
+                    self.pending = b"finished".to_vec()
+                }
+                2 if records == b"server finished" => {}
+                _ => return Err("unexpected records".to_owned()),
+            }
+            self.round += 1;
+            Ok(())
+        }
+
+        fn write_tls(&mut self) -> Vec<u8> {
+            if self.round == 0 {
+                self.round += 1;
+                return b"hello".to_vec();
+            }
+            std::mem::take(&mut self.pending)
+        }
+
+        fn is_handshaking(&self) -> bool {
+            self.round < 3
+        }
+
+        fn export_keying_material(&self, label: &[u8], length: usize) -> Result<Vec<u8>, String> {
+            Ok((0..length).map(|v| v as u8 ^ label[0]).collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_peer_state_machine() {
+        let mut peer = EapPeer::new("alice");
+        peer.add_method(Box::new(Md5PeerMethod::new(b"password")));
+
+        assert_eq!(
+            peer.start(),
+            EapPacket::new_response(0, EAP_TYPE_IDENTITY, b"alice")
+        );
+
+        // an unsupported method is refused with Legacy-Nak
+        let nak = peer
+            .process(&EapPacket::new_request(1, EAP_TYPE_TLS, &[0x20]))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            nak,
+            EapPacket::new_response(1, EAP_TYPE_NAK, &[EAP_TYPE_MD5_CHALLENGE])
+        );
+
+        let request = EapPacket::new_request(2, EAP_TYPE_MD5_CHALLENGE, &[1, 0xaa]);
+        let response = peer.process(&request).await.unwrap().unwrap();
+        assert_eq!(response.get_type(), Some(EAP_TYPE_MD5_CHALLENGE));
+        assert_eq!(peer.get_selected_type(), Some(EAP_TYPE_MD5_CHALLENGE));
+        // the retransmission is answered by the same response
+        assert_eq!(peer.process(&request).await.unwrap(), Some(response));
+
+        assert_eq!(
+            peer.process(&EapPacket::new_success(2)).await.unwrap(),
+            None
+        );
+        assert!(peer
+            .process(&EapPacket::new_response(3, EAP_TYPE_IDENTITY, b""))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_eap_md5() {
+        let password_store = InMemoryPasswordStore::new();
+        password_store.set_password("alice", b"password");
+        let addr = serve(EapHandler::new(Arc::new(Md5Method::new(Arc::new(
+            password_store,
+        )))))
+        .await;
+        let client = Client::new(None, None);
+
+        let mut peer = EapPeer::new("alice");
+        peer.add_method(Box::new(Md5PeerMethod::new(b"password")));
+        match peer.authenticate(&client, &addr, b"secret").await.unwrap() {
+            EapOutcome::Accept(response) => assert_eq!(response.get_code(), Code::AccessAccept),
+            outcome => panic!("unexpected outcome: {:?}", outcome),
+        }
+
+        let mut peer = EapPeer::new("alice");
+        peer.add_method(Box::new(Md5PeerMethod::new(b"wrong")));
+        match peer.authenticate(&client, &addr, b"secret").await.unwrap() {
+            EapOutcome::Reject(response) => assert_eq!(response.get_code(), Code::AccessReject),
+            outcome => panic!("unexpected outcome: {:?}", outcome),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_eap_tls() {
+        let mut method = TlsMethod::new(Arc::new(FakeTlsAcceptor));
+        method.set_fragment_size(700);
+        let addr = serve(EapHandler::new(Arc::new(method))).await;
+        let client = Client::new(None, None);
+
+        let mut peer = EapPeer::new("alice");
+        let mut tls = TlsPeerMethod::new(FakeTlsClientSession {
+            round: 0,
+            pending: vec![],
+        });
+        tls.set_fragment_size(3);
+        peer.add_method(Box::new(tls));
+
+        let response = match peer.authenticate(&client, &addr, b"secret").await.unwrap() {
+            EapOutcome::Accept(response) => response,
+            outcome => panic!("unexpected outcome: {:?}", outcome),
+        };
+        let msk = peer.get_msk().unwrap();
+        assert_eq!(
+            lookup_mppe_recv_key(&response, peer.get_request_authenticator()),
+            Some(Ok(msk[..32].to_vec()))
+        );
+    }
+}
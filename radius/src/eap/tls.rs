@@ -0,0 +1,795 @@
+//! EAP-TLS method.
+//!
+//! This fragments and reassembles the TLS records over EAP-Request/EAP-Response,
+//! and the TLS handshake itself is driven by a `TlsAcceptor`; an adapter of a TLS library
+//! (e.g. a rustls `ServerConnection`) implements `TlsSession`.
+//! With the `eap-tls-rustls` feature, `TlsSession` is implemented for `rustls::ServerConnection`,
+//! and `RustlsAcceptor` makes those from a `rustls::ServerConfig`.
+//! see also: https://tools.ietf.org/html/rfc5216
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::auth::ReplyTemplate;
+use crate::eap::{EapError, EapMethod, EapPacket, EapStep, EapType, EAP_TYPE_TLS};
+
+pub const FLAG_LENGTH_INCLUDED: u8 = 0x80;
+pub const FLAG_MORE_FRAGMENTS: u8 = 0x40;
+pub const FLAG_START: u8 = 0x20;
+
+/// The label of the keying material to derive the MSK.
+/// see also: https://tools.ietf.org/html/rfc5216#section-2.3
+pub const KEYING_MATERIAL_LABEL: &[u8] = b"client EAP encryption";
+
+const KEYING_MATERIAL_LENGTH: usize = 128;
+const MSK_LENGTH: usize = 64;
+
+/// TlsSession is a TLS connection without I/O, like a rustls `ServerConnection` (or `ClientConnection` for the peer).
+pub trait TlsSession: 'static + Send {
+    /// Process the TLS records that are received from the peer.
+    fn read_tls(&mut self, records: &[u8]) -> Result<(), String>;
+
+    /// Take the TLS records to send to the peer.
+    fn write_tls(&mut self) -> Vec<u8>;
+
+    /// Returns whether the handshake is in progress.
+    fn is_handshaking(&self) -> bool;
+
+    /// Export the keying material of the established session (RFC 5705).
+    fn export_keying_material(&self, label: &[u8], length: usize) -> Result<Vec<u8>, String>;
+
+    /// Take the application data that is decrypted from the received records.
+    ///
+    /// This is required only by the tunneled methods (i.e. PEAP and EAP-TTLS).
+    fn read_plaintext(&mut self) -> Vec<u8> {
+        vec![]
+    }
+
+    /// Encrypt the application data into the records that are taken by `write_tls()`.
+    ///
+    /// This is required only by the tunneled methods (i.e. PEAP and EAP-TTLS).
+    fn write_plaintext(&mut self, _plaintext: &[u8]) -> Result<(), String> {
+        Err("application data is not supported".to_owned())
+    }
+}
+
+/// TlsAcceptor makes a `TlsSession` for each EAP-TLS conversation.
+pub trait TlsAcceptor: 'static + Sync + Send {
+    type Session: TlsSession;
+
+    fn accept(&self) -> Result<Self::Session, String>;
+}
+
+#[cfg(feature = "eap-tls-rustls")]
+impl TlsSession for rustls::ServerConnection {
+    fn read_tls(&mut self, records: &[u8]) -> Result<(), String> {
+        // the methods of the connection itself, not the ones of this trait that have the same names
+        let conn: &mut rustls::ConnectionCommon<_> = self;
+        let mut records = records;
+        while !records.is_empty() {
+            conn.read_tls(&mut records).map_err(|e| e.to_string())?;
+            conn.process_new_packets().map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn write_tls(&mut self) -> Vec<u8> {
+        let conn: &mut rustls::ConnectionCommon<_> = self;
+        let mut records = vec![];
+        while conn.wants_write() {
+            if conn.write_tls(&mut records).is_err() {
+                break;
+            }
+        }
+        records
+    }
+
+    fn is_handshaking(&self) -> bool {
+        let conn: &rustls::ConnectionCommon<_> = self;
+        conn.is_handshaking()
+    }
+
+    fn export_keying_material(&self, label: &[u8], length: usize) -> Result<Vec<u8>, String> {
+        let conn: &rustls::ConnectionCommon<_> = self;
+        conn.export_keying_material(vec![0; length], label, None)
+            .map_err(|e| e.to_string())
+    }
+
+    fn read_plaintext(&mut self) -> Vec<u8> {
+        let mut plaintext = vec![];
+        // this stops with `WouldBlock` at the end of the received data, and the data until that is kept
+        let _ = std::io::Read::read_to_end(&mut self.reader(), &mut plaintext);
+        plaintext
+    }
+
+    fn write_plaintext(&mut self, plaintext: &[u8]) -> Result<(), String> {
+        std::io::Write::write_all(&mut self.writer(), plaintext).map_err(|e| e.to_string())
+    }
+}
+
+/// A `TlsAcceptor` that makes a `rustls::ServerConnection` from the config for each conversation.
+///
+/// The MSK is derived as RFC 5216, i.e. for TLS 1.2; EAP-TLS over TLS 1.3 (RFC 9190) derives that differently,
+/// so make the config with TLS 1.2 only (e.g. `ServerConfig::builder_with_protocol_versions(&[&rustls::version::TLS12])`).
+#[cfg(feature = "eap-tls-rustls")]
+pub struct RustlsAcceptor {
+    config: Arc<rustls::ServerConfig>,
+}
+
+#[cfg(feature = "eap-tls-rustls")]
+impl RustlsAcceptor {
+    pub fn new(config: Arc<rustls::ServerConfig>) -> Self {
+        RustlsAcceptor { config }
+    }
+
+    pub fn get_config(&self) -> &Arc<rustls::ServerConfig> {
+        &self.config
+    }
+}
+
+#[cfg(feature = "eap-tls-rustls")]
+impl TlsAcceptor for RustlsAcceptor {
+    type Session = rustls::ServerConnection;
+
+    fn accept(&self) -> Result<Self::Session, String> {
+        rustls::ServerConnection::new(self.config.clone()).map_err(|e| e.to_string())
+    }
+}
+
+/// This struct represents the flags and the TLS data of an EAP-TLS packet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsFragment {
+    flags: u8,
+    message_length: Option<u32>,
+    data: Vec<u8>,
+}
+
+impl TlsFragment {
+    pub fn new(flags: u8, message_length: Option<u32>, data: &[u8]) -> Self {
+        let flags = match message_length {
+            Some(_) => flags | FLAG_LENGTH_INCLUDED,
+            None => flags & !FLAG_LENGTH_INCLUDED,
+        };
+        TlsFragment {
+            flags,
+            message_length,
+            data: data.to_vec(),
+        }
+    }
+
+    /// Make an acknowledgement of a fragment, that is an empty message.
+    pub fn new_ack() -> Self {
+        TlsFragment::new(0, None, &[])
+    }
+
+    pub fn get_flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Returns the total length of the TLS message; that is only in the first fragment.
+    pub fn get_message_length(&self) -> Option<u32> {
+        self.message_length
+    }
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn has_more_fragments(&self) -> bool {
+        self.flags & FLAG_MORE_FRAGMENTS != 0
+    }
+
+    pub fn is_ack(&self) -> bool {
+        self.flags & !FLAG_LENGTH_INCLUDED == 0 && self.data.is_empty()
+    }
+
+    /// Decode the type-data of an EAP-TLS packet.
+    pub fn decode(type_data: &[u8]) -> Result<Self, EapError> {
+        let (&flags, rest) = match type_data.split_first() {
+            Some(v) => v,
+            None => {
+                return Err(EapError::DecodingError(
+                    "EAP-TLS flags are missing".to_owned(),
+                ))
+            }
+        };
+        if flags & FLAG_LENGTH_INCLUDED == 0 {
+            return Ok(TlsFragment::new(flags, None, rest));
+        }
+        if rest.len() < 4 {
+            return Err(EapError::DecodingError(
+                "EAP-TLS message length is missing".to_owned(),
+            ));
+        }
+        let message_length = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]);
+        Ok(TlsFragment::new(flags, Some(message_length), &rest[4..]))
+    }
+
+    /// Encode into the type-data of an EAP-TLS packet.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bs = vec![self.flags];
+        if let Some(message_length) = self.message_length {
+            bs.extend(message_length.to_be_bytes());
+        }
+        bs.extend(&self.data);
+        bs
+    }
+}
+
+/// The state of an EAP-TLS conversation.
+pub struct TlsState<S: TlsSession> {
+    session: S,
+    identity: String,
+    received: Vec<u8>,
+    sending: Vec<u8>,
+    sending_offset: usize,
+}
+
+impl<S: TlsSession> TlsState<S> {
+    pub(crate) fn new(session: S, identity: &str) -> Self {
+        TlsState {
+            session,
+            identity: identity.to_owned(),
+            received: vec![],
+            sending: vec![],
+            sending_offset: 0,
+        }
+    }
+
+    pub fn get_session(&self) -> &S {
+        &self.session
+    }
+
+    pub fn get_session_mut(&mut self) -> &mut S {
+        &mut self.session
+    }
+
+    pub fn get_identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+/// The result of `TlsTunnel::receive()`.
+pub(crate) enum TlsProgress {
+    /// Send the type-data to the peer.
+    Send(Vec<u8>),
+    /// All the records from the peer are processed and there is nothing to send.
+    Idle,
+    /// The peer or the TLS session fails.
+    Failure,
+}
+
+/// TlsTunnel handles the fragmentation and the TLS session, that is shared by EAP-TLS and the tunneled methods.
+pub(crate) struct TlsTunnel {
+    pub(crate) fragment_size: usize,
+    pub(crate) max_message_length: usize,
+}
+
+impl TlsTunnel {
+    pub(crate) fn start<A: TlsAcceptor>(
+        &self,
+        acceptor: &A,
+        identity: &str,
+        flags: u8,
+    ) -> Result<(Vec<u8>, TlsState<A::Session>), EapError> {
+        let session = acceptor.accept().map_err(EapError::MethodError)?;
+        Ok((
+            TlsFragment::new(FLAG_START | flags, None, &[]).encode(),
+            TlsState::new(session, identity),
+        ))
+    }
+
+    pub(crate) fn receive<S: TlsSession>(
+        &self,
+        state: &mut TlsState<S>,
+        response: &EapPacket,
+        flags: u8,
+    ) -> Result<TlsProgress, EapError> {
+        let fragment = TlsFragment::decode(response.get_data())?;
+
+        if state.sending_offset < state.sending.len() {
+            // the peer acknowledges the previous fragment
+            if !fragment.is_ack() {
+                return Ok(TlsProgress::Failure);
+            }
+            return Ok(TlsProgress::Send(self.next_fragment(state, flags)));
+        }
+
+        if state.received.len() + fragment.get_data().len() > self.max_message_length {
+            return Ok(TlsProgress::Failure);
+        }
+        state.received.extend(fragment.get_data());
+        if fragment.has_more_fragments() {
+            return Ok(TlsProgress::Send(
+                TlsFragment::new(flags, None, &[]).encode(),
+            ));
+        }
+
+        let received = std::mem::take(&mut state.received);
+        if !received.is_empty() && state.session.read_tls(&received).is_err() {
+            return Ok(TlsProgress::Failure);
+        }
+
+        let records = state.session.write_tls();
+        if records.is_empty() {
+            return Ok(TlsProgress::Idle);
+        }
+        state.sending = records;
+        state.sending_offset = 0;
+        Ok(TlsProgress::Send(self.next_fragment(state, flags)))
+    }
+
+    pub(crate) fn is_sending<S: TlsSession>(&self, state: &TlsState<S>) -> bool {
+        state.sending_offset < state.sending.len()
+    }
+
+    /// Send the application data through the tunnel.
+    pub(crate) fn send_plaintext<S: TlsSession>(
+        &self,
+        state: &mut TlsState<S>,
+        plaintext: &[u8],
+        flags: u8,
+    ) -> TlsProgress {
+        if state.session.write_plaintext(plaintext).is_err() {
+            return TlsProgress::Failure;
+        }
+        let records = state.session.write_tls();
+        if records.is_empty() {
+            return TlsProgress::Failure;
+        }
+        state.sending = records;
+        state.sending_offset = 0;
+        TlsProgress::Send(self.next_fragment(state, flags))
+    }
+
+    pub(crate) fn derive_msk<S: TlsSession>(
+        &self,
+        state: &TlsState<S>,
+        label: &[u8],
+    ) -> Result<Vec<u8>, EapError> {
+        let keying_material = state
+            .session
+            .export_keying_material(label, KEYING_MATERIAL_LENGTH)
+            .map_err(EapError::MethodError)?;
+        if keying_material.len() < MSK_LENGTH {
+            return Err(EapError::MethodError(format!(
+                "too short keying material: {} bytes",
+                keying_material.len()
+            )));
+        }
+        Ok(keying_material[..MSK_LENGTH].to_vec())
+    }
+
+    fn next_fragment<S: TlsSession>(&self, state: &mut TlsState<S>, flags: u8) -> Vec<u8> {
+        let total = state.sending.len();
+        let first = state.sending_offset == 0;
+        let end = total.min(state.sending_offset + self.fragment_size);
+        let data = &state.sending[state.sending_offset..end];
+
+        let more = if end < total { FLAG_MORE_FRAGMENTS } else { 0 };
+        // the length is included in the first fragment of the fragmented message
+        let message_length = if first && end < total {
+            Some(total as u32)
+        } else {
+            None
+        };
+        let encoded = TlsFragment::new(flags | more, message_length, data).encode();
+
+        state.sending_offset = end;
+        if end == total {
+            state.sending = vec![];
+            state.sending_offset = 0;
+        }
+        encoded
+    }
+}
+
+/// An `EapMethod` of EAP-TLS.
+///
+/// The Access-Accept has the MPPE keys that are derived from the TLS session.
+pub struct TlsMethod<A: TlsAcceptor> {
+    acceptor: Arc<A>,
+    tunnel: TlsTunnel,
+    template: ReplyTemplate,
+}
+
+impl<A: TlsAcceptor> TlsMethod<A> {
+    pub fn new(acceptor: Arc<A>) -> Self {
+        TlsMethod {
+            acceptor,
+            tunnel: TlsTunnel {
+                fragment_size: 1000,
+                max_message_length: 65536,
+            },
+            template: ReplyTemplate::new(),
+        }
+    }
+
+    /// Set the maximum size of TLS data in an EAP-Request (default: 1000 bytes).
+    pub fn set_fragment_size(&mut self, fragment_size: usize) {
+        self.tunnel.fragment_size = fragment_size.max(1);
+    }
+
+    /// Set the maximum size of a reassembled TLS message from the peer (default: 65536 bytes).
+    pub fn set_max_message_length(&mut self, max_message_length: usize) {
+        self.tunnel.max_message_length = max_message_length;
+    }
+
+    /// Set the template that is applied to the Access-Accept.
+    pub fn set_template(&mut self, template: ReplyTemplate) {
+        self.template = template;
+    }
+
+    pub fn get_acceptor(&self) -> &Arc<A> {
+        &self.acceptor
+    }
+}
+
+#[async_trait]
+impl<A: TlsAcceptor> EapMethod for TlsMethod<A> {
+    type State = TlsState<A::Session>;
+
+    fn get_type(&self) -> EapType {
+        EAP_TYPE_TLS
+    }
+
+    async fn start(&self, identity: &str) -> Result<EapStep<Self::State>, EapError> {
+        let (data, state) = self.tunnel.start(self.acceptor.as_ref(), identity, 0)?;
+        Ok(EapStep::Continue(data, state))
+    }
+
+    async fn process(
+        &self,
+        mut state: Self::State,
+        response: &EapPacket,
+    ) -> Result<EapStep<Self::State>, EapError> {
+        match self.tunnel.receive(&mut state, response, 0)? {
+            TlsProgress::Send(data) => Ok(EapStep::Continue(data, state)),
+            TlsProgress::Failure => Ok(EapStep::Failure),
+            TlsProgress::Idle => {
+                if state.session.is_handshaking() || self.tunnel.is_sending(&state) {
+                    return Ok(EapStep::Failure);
+                }
+                let msk = self.tunnel.derive_msk(&state, KEYING_MATERIAL_LABEL)?;
+                Ok(EapStep::Success(self.template.clone(), Some(msk)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Arc;
+
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::{rfc2865, rfc2869};
+    use crate::eap::mppe::{lookup_mppe_recv_key, lookup_mppe_send_key};
+    use crate::eap::tls::{
+        TlsAcceptor, TlsFragment, TlsMethod, TlsSession, FLAG_LENGTH_INCLUDED, FLAG_MORE_FRAGMENTS,
+        FLAG_START,
+    };
+    use crate::eap::{
+        add_eap_packet, lookup_eap_packet, EapCode, EapHandler, EapPacket, EAP_TYPE_IDENTITY,
+        EAP_TYPE_TLS,
+    };
+
+    pub(crate) const SERVER_FLIGHT_LENGTH: usize = 2500;
+
+    /// A fake TLS session: the peer sends "hello" and "finished", and the server responds to each of them.
+    pub(crate) struct FakeTlsSession {
+        round: usize,
+        pending: Vec<u8>,
+        plaintext: Vec<u8>,
+    }
+
+    impl TlsSession for FakeTlsSession {
+        fn read_tls(&mut self, records: &[u8]) -> Result<(), String> {
+            match (self.round, records) {
+                (0, b"hello") => self.pending = vec![0x16; SERVER_FLIGHT_LENGTH],
+                (1, b"finished") => self.pending = b"server finished".to_vec(),
+                // the application data records after the handshake
+                (2, [0x17, plaintext @ ..]) => {
+                    self.plaintext.extend(plaintext);
+                    return Ok(());
+                }
+                _ => return Err("unexpected records".to_owned()),
+            }
+            self.round += 1;
+            Ok(())
+        }
+
+        fn read_plaintext(&mut self) -> Vec<u8> {
+            std::mem::take(&mut self.plaintext)
+        }
+
+        fn write_plaintext(&mut self, plaintext: &[u8]) -> Result<(), String> {
+            self.pending = [&[0x17], plaintext].concat();
+            Ok(())
+        }
+
+        fn write_tls(&mut self) -> Vec<u8> {
+            std::mem::take(&mut self.pending)
+        }
+
+        fn is_handshaking(&self) -> bool {
+            self.round < 2
+        }
+
+        fn export_keying_material(&self, label: &[u8], length: usize) -> Result<Vec<u8>, String> {
+            // the keying material depends on the label
+            Ok((0..length).map(|v| v as u8 ^ label[0]).collect())
+        }
+    }
+
+    pub(crate) struct FakeTlsAcceptor;
+
+    impl TlsAcceptor for FakeTlsAcceptor {
+        type Session = FakeTlsSession;
+
+        fn accept(&self) -> Result<Self::Session, String> {
+            Ok(FakeTlsSession {
+                round: 0,
+                pending: vec![],
+                plaintext: vec![],
+            })
+        }
+    }
+
+    fn make_request(eap: &EapPacket, state: Option<&[u8]>) -> Request {
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        if let Some(state) = state {
+            rfc2865::add_state(&mut packet, state).unwrap();
+        }
+        add_eap_packet(&mut packet, eap).unwrap();
+        let packet = Packet::decode(&packet.encode().unwrap(), b"secret").unwrap();
+        Request::new(
+            "127.0.0.1:1812".parse().unwrap(),
+            "192.0.2.1:1812".parse().unwrap(),
+            packet,
+        )
+    }
+
+    #[test]
+    fn test_tls_fragment() {
+        let fragment = TlsFragment::new(FLAG_MORE_FRAGMENTS, Some(3000), b"abc");
+        assert_eq!(
+            fragment.encode(),
+            vec![0xc0, 0x00, 0x00, 0x0b, 0xb8, b'a', b'b', b'c']
+        );
+        assert_eq!(TlsFragment::decode(&fragment.encode()), Ok(fragment));
+        assert!(TlsFragment::decode(&[0x00]).unwrap().is_ack());
+        assert!(TlsFragment::decode(&[FLAG_LENGTH_INCLUDED, 0x00]).is_err());
+        assert!(TlsFragment::decode(&[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_eap_tls() {
+        let mut method = TlsMethod::new(Arc::new(FakeTlsAcceptor));
+        method.set_fragment_size(1000);
+        let handler = EapHandler::new(Arc::new(method));
+
+        // EAP-TLS/Start
+        let mut challenge = handler
+            .make_response(&make_request(
+                &EapPacket::new_response(1, EAP_TYPE_IDENTITY, b"alice"),
+                None,
+            ))
+            .await
+            .unwrap();
+        let eap = lookup_eap_packet(&challenge).unwrap().unwrap();
+        assert_eq!(eap.get_type(), Some(EAP_TYPE_TLS));
+        assert_eq!(eap.get_data(), &[FLAG_START]);
+
+        // the client hello is fragmented into two responses
+        let mut fragments = vec![
+            TlsFragment::new(FLAG_MORE_FRAGMENTS, Some(5), b"hel"),
+            TlsFragment::new(0, None, b"lo"),
+        ];
+        fragments.reverse();
+        let mut identifier = eap.get_identifier();
+        let mut received: Vec<u8> = vec![];
+        let mut acks = 0;
+        loop {
+            let fragment = fragments.pop().unwrap_or_else(TlsFragment::new_ack);
+            let state = rfc2865::lookup_state(&challenge).unwrap();
+            challenge = handler
+                .make_response(&make_request(
+                    &EapPacket::new_response(identifier, EAP_TYPE_TLS, &fragment.encode()),
+                    Some(&state),
+                ))
+                .await
+                .unwrap();
+            assert_eq!(challenge.get_code(), Code::AccessChallenge);
+            let eap = lookup_eap_packet(&challenge).unwrap().unwrap();
+            identifier = eap.get_identifier();
+            let fragment = TlsFragment::decode(eap.get_data()).unwrap();
+            if fragment.is_ack() {
+                acks += 1;
+                continue;
+            }
+            received.extend(fragment.get_data());
+            if !fragment.has_more_fragments() {
+                break;
+            }
+        }
+        assert_eq!(acks, 1);
+        assert_eq!(received, vec![0x16; 2500]);
+
+        let state = rfc2865::lookup_state(&challenge).unwrap();
+        challenge = handler
+            .make_response(&make_request(
+                &EapPacket::new_response(
+                    identifier,
+                    EAP_TYPE_TLS,
+                    &TlsFragment::new(0, None, b"finished").encode(),
+                ),
+                Some(&state),
+            ))
+            .await
+            .unwrap();
+        let eap = lookup_eap_packet(&challenge).unwrap().unwrap();
+        assert_eq!(
+            TlsFragment::decode(eap.get_data()).unwrap().get_data(),
+            b"server finished"
+        );
+
+        // the client acknowledges the last flight
+        let state = rfc2865::lookup_state(&challenge).unwrap();
+        let request = make_request(
+            &EapPacket::new_response(
+                eap.get_identifier(),
+                EAP_TYPE_TLS,
+                &TlsFragment::new_ack().encode(),
+            ),
+            Some(&state),
+        );
+        let accept = handler.make_response(&request).await.unwrap();
+        assert_eq!(accept.get_code(), Code::AccessAccept);
+        assert_eq!(
+            lookup_eap_packet(&accept).unwrap().unwrap().get_code(),
+            EapCode::Success
+        );
+        assert!(rfc2869::lookup_message_authenticator(&accept).is_some());
+
+        let accept = Packet::decode(&accept.encode().unwrap(), b"secret").unwrap();
+        let authenticator = request.get_packet().get_authenticator();
+        assert_eq!(
+            lookup_mppe_recv_key(&accept, authenticator),
+            Some(Ok((0..32).map(|v| v ^ b'c').collect()))
+        );
+        assert_eq!(
+            lookup_mppe_send_key(&accept, authenticator),
+            Some(Ok((32..64).map(|v| v ^ b'c').collect()))
+        );
+    }
+
+    #[cfg(feature = "eap-tls-rustls")]
+    #[tokio::test]
+    async fn test_eap_tls_with_rustls() {
+        use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer, ServerName};
+
+        use crate::eap::tls::RustlsAcceptor;
+
+        let cert = CertificateDer::from(
+            include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/testdata/eap-tls/server.crt.der"
+            ))
+            .to_vec(),
+        );
+        let key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(
+            include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/testdata/eap-tls/server.key.der"
+            ))
+            .to_vec(),
+        ));
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let server_config = rustls::ServerConfig::builder_with_provider(provider.clone())
+            .with_protocol_versions(&[&rustls::version::TLS12])
+            .unwrap()
+            .with_no_client_auth()
+            .with_single_cert(vec![cert.clone()], key)
+            .unwrap();
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(cert).unwrap();
+        let client_config = rustls::ClientConfig::builder_with_provider(provider)
+            .with_protocol_versions(&[&rustls::version::TLS12])
+            .unwrap()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let mut client = rustls::ClientConnection::new(
+            Arc::new(client_config),
+            ServerName::try_from("radius.example.com").unwrap(),
+        )
+        .unwrap();
+
+        let mut method = TlsMethod::new(Arc::new(RustlsAcceptor::new(Arc::new(server_config))));
+        method.set_fragment_size(200);
+        let handler = EapHandler::new(Arc::new(method));
+        let mut challenge = handler
+            .make_response(&make_request(
+                &EapPacket::new_response(1, EAP_TYPE_IDENTITY, b"alice"),
+                None,
+            ))
+            .await
+            .unwrap();
+
+        // the client sends its flights, and acknowledges the fragments of the server until the Access-Accept
+        let mut received: Vec<u8> = vec![];
+        let (request, accept) = loop {
+            let eap = lookup_eap_packet(&challenge).unwrap().unwrap();
+            let fragment = TlsFragment::decode(eap.get_data()).unwrap();
+            received.extend(fragment.get_data());
+            if !fragment.has_more_fragments() && !received.is_empty() {
+                client.read_tls(&mut received.as_slice()).unwrap();
+                client.process_new_packets().unwrap();
+                received.clear();
+            }
+            let mut records = vec![];
+            while client.wants_write() {
+                client.write_tls(&mut records).unwrap();
+            }
+
+            let state = rfc2865::lookup_state(&challenge).unwrap();
+            let request = make_request(
+                &EapPacket::new_response(
+                    eap.get_identifier(),
+                    EAP_TYPE_TLS,
+                    &TlsFragment::new(0, None, &records).encode(),
+                ),
+                Some(&state),
+            );
+            let response = handler.make_response(&request).await.unwrap();
+            if response.get_code() != Code::AccessChallenge {
+                break (request, response);
+            }
+            challenge = response;
+        };
+        assert!(!client.is_handshaking());
+        assert_eq!(accept.get_code(), Code::AccessAccept);
+
+        // the MPPE keys are of the keying material that is exported with "client EAP encryption"
+        let msk = client
+            .export_keying_material(vec![0; 64], b"client EAP encryption", None)
+            .unwrap();
+        let accept = Packet::decode(&accept.encode().unwrap(), b"secret").unwrap();
+        let authenticator = request.get_packet().get_authenticator();
+        assert_eq!(
+            lookup_mppe_recv_key(&accept, authenticator),
+            Some(Ok(msk[..32].to_vec()))
+        );
+        assert_eq!(
+            lookup_mppe_send_key(&accept, authenticator),
+            Some(Ok(msk[32..].to_vec()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_eap_tls_handshake_failure() {
+        let handler = EapHandler::new(Arc::new(TlsMethod::new(Arc::new(FakeTlsAcceptor))));
+        let challenge = handler
+            .make_response(&make_request(
+                &EapPacket::new_response(1, EAP_TYPE_IDENTITY, b"alice"),
+                None,
+            ))
+            .await
+            .unwrap();
+        let state = rfc2865::lookup_state(&challenge).unwrap();
+        let reject = handler
+            .make_response(&make_request(
+                &EapPacket::new_response(
+                    2,
+                    EAP_TYPE_TLS,
+                    &TlsFragment::new(0, None, b"bye").encode(),
+                ),
+                Some(&state),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(reject.get_code(), Code::AccessReject);
+    }
+}
@@ -0,0 +1,399 @@
+//! EAP-TTLS (version 0) method, that tunnels the attributes of the inner authentication in TLS.
+//!
+//! The outer TLS is driven by the EAP-TLS machinery, and the inner attributes are Diameter AVPs;
+//! the RADIUS attributes are carried as the AVPs that have the same codes.
+//! The inner authentication is pluggable by `TtlsInnerAuthenticator`.
+//! see also: https://tools.ietf.org/html/rfc5281
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::auth::{AuthResult, Authenticator, ReplyTemplate};
+use crate::core::rfc2865;
+use crate::eap::tls::{TlsAcceptor, TlsProgress, TlsSession, TlsState, TlsTunnel};
+use crate::eap::{EapError, EapMethod, EapPacket, EapStep, EapType, EAP_TYPE_TTLS};
+
+/// The label of the keying material to derive the MSK.
+/// see also: https://tools.ietf.org/html/rfc5281#section-8
+pub const TTLS_KEYING_MATERIAL_LABEL: &[u8] = b"ttls keying material";
+
+const FLAG_VENDOR_SPECIFIC: u8 = 0x80;
+const FLAG_MANDATORY: u8 = 0x40;
+
+/// This struct represents a Diameter AVP in the tunnel.
+/// see also: https://tools.ietf.org/html/rfc5281#section-10.1
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiameterAvp {
+    code: u32,
+    vendor_id: Option<u32>,
+    mandatory: bool,
+    data: Vec<u8>,
+}
+
+impl DiameterAvp {
+    /// Make a mandatory AVP, e.g. the AVP of a RADIUS attribute.
+    pub fn new(code: u32, data: &[u8]) -> Self {
+        DiameterAvp {
+            code,
+            vendor_id: None,
+            mandatory: true,
+            data: data.to_vec(),
+        }
+    }
+
+    /// Make a vendor specific AVP.
+    pub fn new_vendor_specific(vendor_id: u32, code: u32, mandatory: bool, data: &[u8]) -> Self {
+        DiameterAvp {
+            code,
+            vendor_id: Some(vendor_id),
+            mandatory,
+            data: data.to_vec(),
+        }
+    }
+
+    pub fn get_code(&self) -> u32 {
+        self.code
+    }
+
+    pub fn get_vendor_id(&self) -> Option<u32> {
+        self.vendor_id
+    }
+
+    pub fn is_mandatory(&self) -> bool {
+        self.mandatory
+    }
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Encode the AVPs into the application data; each AVP is padded to a multiple of 4 bytes.
+pub fn encode_avps(avps: &[DiameterAvp]) -> Vec<u8> {
+    let mut bs = vec![];
+    for avp in avps {
+        let header_length = if avp.vendor_id.is_some() { 12 } else { 8 };
+        let length = header_length + avp.data.len();
+
+        let mut flags = 0;
+        if avp.vendor_id.is_some() {
+            flags |= FLAG_VENDOR_SPECIFIC;
+        }
+        if avp.mandatory {
+            flags |= FLAG_MANDATORY;
+        }
+
+        bs.extend(avp.code.to_be_bytes());
+        bs.push(flags);
+        bs.extend(&(length as u32).to_be_bytes()[1..]);
+        if let Some(vendor_id) = avp.vendor_id {
+            bs.extend(vendor_id.to_be_bytes());
+        }
+        bs.extend(&avp.data);
+        bs.resize(bs.len() + (4 - length % 4) % 4, 0);
+    }
+    bs
+}
+
+/// Decode the application data into the AVPs.
+pub fn decode_avps(bs: &[u8]) -> Result<Vec<DiameterAvp>, EapError> {
+    let mut avps = vec![];
+    let mut bs = bs;
+    while !bs.is_empty() {
+        if bs.len() < 8 {
+            return Err(EapError::DecodingError("short Diameter AVP".to_owned()));
+        }
+        let code = u32::from_be_bytes([bs[0], bs[1], bs[2], bs[3]]);
+        let flags = bs[4];
+        let length = u32::from_be_bytes([0, bs[5], bs[6], bs[7]]) as usize;
+        let header_length = if flags & FLAG_VENDOR_SPECIFIC != 0 {
+            12
+        } else {
+            8
+        };
+        if length < header_length || length > bs.len() {
+            return Err(EapError::DecodingError(format!(
+                "invalid Diameter AVP length: {}",
+                length
+            )));
+        }
+
+        avps.push(DiameterAvp {
+            code,
+            vendor_id: if header_length == 12 {
+                Some(u32::from_be_bytes([bs[8], bs[9], bs[10], bs[11]]))
+            } else {
+                None
+            },
+            mandatory: flags & FLAG_MANDATORY != 0,
+            data: bs[header_length..length].to_vec(),
+        });
+
+        let padded_length = length.div_ceil(4) * 4;
+        bs = &bs[padded_length.min(bs.len())..];
+    }
+    Ok(avps)
+}
+
+/// This enum represents the result of an inner authentication round.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TtlsInnerResult {
+    /// The user is authenticated; the template is applied to the Access-Accept.
+    Accept(ReplyTemplate),
+    /// The user is rejected.
+    Reject,
+    /// Send the AVPs to the peer and wait for the next round (e.g. the inner EAP or a challenge).
+    Challenge(Vec<DiameterAvp>),
+}
+
+/// TtlsInnerAuthenticator authenticates the user by the AVPs in the tunnel.
+#[async_trait]
+pub trait TtlsInnerAuthenticator: 'static + Sync + Send {
+    /// Authenticate the AVPs from the peer; `outer_identity` is the identity of the outer EAP-Response/Identity.
+    async fn authenticate(
+        &self,
+        outer_identity: &str,
+        avps: &[DiameterAvp],
+    ) -> Result<TtlsInnerResult, EapError>;
+}
+
+/// A `TtlsInnerAuthenticator` for the inner PAP (i.e. `User-Name` and `User-Password` AVPs) by an `Authenticator`.
+pub struct PapInnerAuthenticator<A: Authenticator> {
+    authenticator: Arc<A>,
+}
+
+impl<A: Authenticator> PapInnerAuthenticator<A> {
+    pub fn new(authenticator: Arc<A>) -> Self {
+        PapInnerAuthenticator { authenticator }
+    }
+}
+
+#[async_trait]
+impl<A: Authenticator> TtlsInnerAuthenticator for PapInnerAuthenticator<A> {
+    async fn authenticate(
+        &self,
+        _outer_identity: &str,
+        avps: &[DiameterAvp],
+    ) -> Result<TtlsInnerResult, EapError> {
+        let lookup = |typ: u8| {
+            avps.iter()
+                .find(|avp| avp.vendor_id.is_none() && avp.code == typ as u32)
+                .map(|avp| avp.data.clone())
+        };
+        let (user_name, password) = match (
+            lookup(rfc2865::USER_NAME_TYPE),
+            lookup(rfc2865::USER_PASSWORD_TYPE),
+        ) {
+            (Some(user_name), Some(password)) => (user_name, password),
+            _ => return Ok(TtlsInnerResult::Reject),
+        };
+
+        // the password may be padded with null characters; see RFC 5281 section 11.2.5
+        let password_length = password.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        match self
+            .authenticator
+            .authenticate(
+                &String::from_utf8_lossy(&user_name),
+                &password[..password_length],
+            )
+            .await
+            .map_err(EapError::BackendError)?
+        {
+            AuthResult::Accept(template) => Ok(TtlsInnerResult::Accept(template)),
+            AuthResult::Reject => Ok(TtlsInnerResult::Reject),
+        }
+    }
+}
+
+/// The state of an EAP-TTLS conversation.
+pub struct TtlsState<S: TlsSession> {
+    tls: TlsState<S>,
+}
+
+/// An `EapMethod` of EAP-TTLSv0.
+pub struct TtlsMethod<A: TlsAcceptor, I: TtlsInnerAuthenticator> {
+    acceptor: Arc<A>,
+    inner: Arc<I>,
+    tunnel: TlsTunnel,
+    template: ReplyTemplate,
+}
+
+impl<A: TlsAcceptor, I: TtlsInnerAuthenticator> TtlsMethod<A, I> {
+    pub fn new(acceptor: Arc<A>, inner: Arc<I>) -> Self {
+        TtlsMethod {
+            acceptor,
+            inner,
+            tunnel: TlsTunnel {
+                fragment_size: 1000,
+                max_message_length: 65536,
+            },
+            template: ReplyTemplate::new(),
+        }
+    }
+
+    /// Set the maximum size of TLS data in an EAP-Request (default: 1000 bytes).
+    pub fn set_fragment_size(&mut self, fragment_size: usize) {
+        self.tunnel.fragment_size = fragment_size.max(1);
+    }
+
+    /// Set the template that is applied to the Access-Accept, in addition to that of the inner authentication.
+    pub fn set_template(&mut self, template: ReplyTemplate) {
+        self.template = template;
+    }
+
+    pub fn get_inner(&self) -> &Arc<I> {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<A: TlsAcceptor, I: TtlsInnerAuthenticator> EapMethod for TtlsMethod<A, I> {
+    type State = TtlsState<A::Session>;
+
+    fn get_type(&self) -> EapType {
+        EAP_TYPE_TTLS
+    }
+
+    async fn start(&self, identity: &str) -> Result<EapStep<Self::State>, EapError> {
+        let (data, tls) = self.tunnel.start(self.acceptor.as_ref(), identity, 0)?;
+        Ok(EapStep::Continue(data, TtlsState { tls }))
+    }
+
+    async fn process(
+        &self,
+        mut state: Self::State,
+        response: &EapPacket,
+    ) -> Result<EapStep<Self::State>, EapError> {
+        match self.tunnel.receive(&mut state.tls, response, 0)? {
+            TlsProgress::Send(data) => return Ok(EapStep::Continue(data, state)),
+            TlsProgress::Failure => return Ok(EapStep::Failure),
+            TlsProgress::Idle => {}
+        }
+        if state.tls.get_session().is_handshaking() {
+            return Ok(EapStep::Failure);
+        }
+
+        let plaintext = state.tls.get_session_mut().read_plaintext();
+        if plaintext.is_empty() {
+            // the peer acknowledges the last flight of the handshake; wait for the AVPs
+            return Ok(EapStep::Continue(vec![0], state));
+        }
+
+        let avps = decode_avps(&plaintext)?;
+        let identity = state.tls.get_identity().to_owned();
+        match self.inner.authenticate(&identity, &avps).await? {
+            TtlsInnerResult::Accept(inner_template) => {
+                let mut template = self.template.clone();
+                template.extend(&inner_template);
+                let msk = self
+                    .tunnel
+                    .derive_msk(&state.tls, TTLS_KEYING_MATERIAL_LABEL)?;
+                Ok(EapStep::Success(template, Some(msk)))
+            }
+            TtlsInnerResult::Reject => Ok(EapStep::Failure),
+            TtlsInnerResult::Challenge(avps) => {
+                match self
+                    .tunnel
+                    .send_plaintext(&mut state.tls, &encode_avps(&avps), 0)
+                {
+                    TlsProgress::Send(data) => Ok(EapStep::Continue(data, state)),
+                    _ => Ok(EapStep::Failure),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use crate::auth::{AuthError, AuthResult, Authenticator, ReplyTemplate};
+    use crate::core::code::Code;
+    use crate::core::rfc2865;
+    use crate::eap::mppe::lookup_mppe_recv_key;
+    use crate::eap::peap::tests::{establish, exchange};
+    use crate::eap::tls::tests::FakeTlsAcceptor;
+    use crate::eap::ttls::{
+        decode_avps, encode_avps, DiameterAvp, PapInnerAuthenticator, TtlsMethod,
+    };
+    use crate::eap::{EapHandler, EAP_TYPE_TTLS};
+
+    struct StaticAuthenticator;
+
+    #[async_trait]
+    impl Authenticator for StaticAuthenticator {
+        async fn authenticate(
+            &self,
+            user_name: &str,
+            password: &[u8],
+        ) -> Result<AuthResult, AuthError> {
+            if user_name == "alice" && password == b"password" {
+                return Ok(AuthResult::Accept(ReplyTemplate::new()));
+            }
+            Ok(AuthResult::Reject)
+        }
+    }
+
+    #[test]
+    fn test_diameter_avps() {
+        let avps = vec![
+            DiameterAvp::new(rfc2865::USER_NAME_TYPE as u32, b"alice"),
+            DiameterAvp::new_vendor_specific(311, 26, false, b"ab"),
+        ];
+        let encoded = encode_avps(&avps);
+        assert_eq!(
+            encoded,
+            vec![
+                0x00, 0x00, 0x00, 0x01, 0x40, 0x00, 0x00, 0x0d, b'a', b'l', b'i', b'c', b'e', 0x00,
+                0x00, 0x00, // padding
+                0x00, 0x00, 0x00, 0x1a, 0x80, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x01, 0x37, b'a', b'b',
+                0x00, 0x00, // padding
+            ]
+        );
+        assert_eq!(decode_avps(&encoded), Ok(avps));
+        assert!(decode_avps(&[0x00, 0x00, 0x00, 0x01, 0x40, 0x00, 0x00, 0x20]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ttls_pap() {
+        for (password, expected) in [
+            (&b"password\0\0\0\0\0\0\0\0"[..], Code::AccessAccept),
+            (&b"wrong"[..], Code::AccessReject),
+        ] {
+            let mut method = TtlsMethod::new(
+                Arc::new(FakeTlsAcceptor),
+                Arc::new(PapInnerAuthenticator::new(Arc::new(StaticAuthenticator))),
+            );
+            method.set_fragment_size(4096);
+            let handler = EapHandler::new(Arc::new(method));
+
+            let (challenge, records) = establish(&handler, EAP_TYPE_TTLS).await;
+            assert!(records.is_empty());
+
+            let avps = encode_avps(&[
+                DiameterAvp::new(rfc2865::USER_NAME_TYPE as u32, b"alice"),
+                DiameterAvp::new(rfc2865::USER_PASSWORD_TYPE as u32, password),
+            ]);
+            let (response, _) = exchange(
+                &handler,
+                EAP_TYPE_TTLS,
+                &challenge,
+                &[&[0x17][..], &avps].concat(),
+            )
+            .await;
+            assert_eq!(response.get_code(), expected);
+            if expected == Code::AccessAccept {
+                // the MSK is derived by the label of EAP-TTLS
+                let recv_key = (0..32).map(|v| v ^ b't').collect::<Vec<u8>>();
+                assert!(
+                    lookup_mppe_recv_key(&response, response.get_authenticator())
+                        .is_some_and(|key| key == Ok(recv_key))
+                );
+            }
+        }
+    }
+}
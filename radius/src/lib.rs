@@ -1,6 +1,35 @@
 #[macro_use]
 extern crate log;
 
+// the modules that depend on the optional dictionary modules are available only with those features
+pub mod abuse;
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+pub mod acct;
+pub mod audit;
+pub mod auth;
 pub mod client;
+pub mod clock;
+pub mod conversation;
 pub mod core;
+#[cfg(all(
+    feature = "rfc2866",
+    feature = "rfc2869",
+    feature = "rfc3162",
+    feature = "rfc3576"
+))]
+pub mod dynauth;
+#[cfg(feature = "rfc2869")]
+pub mod eap;
+#[cfg(feature = "rfc2866")]
+pub mod loadgen;
+pub mod negative_cache;
+pub mod pcap;
+#[cfg(all(feature = "rfc2866", feature = "rfc3162"))]
+pub mod pool;
+pub mod proxy;
+pub mod retry;
 pub mod server;
+pub mod simple;
+#[cfg(feature = "socket-filter")]
+pub mod socket_filter;
+pub mod stream;
@@ -0,0 +1,461 @@
+//! Traffic generator for the capacity testing of the RADIUS servers.
+//!
+//! `LoadGenerator` sends a mix of Access-Requests and Accounting-Requests at a target rate,
+//! with a unique user (and a unique session for accounting) per request, and reports the latency percentiles and the loss.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use thiserror::Error;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::client::{Client, ClientError};
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::random::{self, RandomSource, SeededRandomSource};
+use crate::core::{rfc2865, rfc2866};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LoadGenError {
+    /// This error is raised when the configuration is invalid (e.g. zero rate).
+    #[error("invalid load generator configuration; {0}")]
+    InvalidConfigError(String),
+}
+
+/// This struct represents the configuration of `LoadGenerator`.
+#[derive(Debug, Clone)]
+pub struct LoadGenConfig {
+    /// The address of the authentication server.
+    pub auth_addr: SocketAddr,
+    /// The address of the accounting server.
+    pub acct_addr: SocketAddr,
+    pub secret: Vec<u8>,
+    /// The target rate in requests per second.
+    pub rate: f64,
+    /// The duration to generate the requests; the responses of the last requests are awaited after this.
+    pub duration: Duration,
+    /// The weight of Access-Requests in the mix.
+    pub auth_weight: u32,
+    /// The weight of Accounting-Requests (`Acct-Status-Type = Start`) in the mix.
+    pub acct_weight: u32,
+    /// The duration to wait for a response; a request that is not responded in time is counted as lost.
+    pub timeout: Duration,
+    /// The maximum number of the in-flight requests; the generation slows down when it is reached.
+    pub max_in_flight: usize,
+    /// The prefix of the user names and the session IDs, which are followed by the sequence number.
+    pub user_prefix: String,
+    pub password: Vec<u8>,
+    /// The seed of the random values in the requests (i.e. the identifiers, the authenticators and the salts);
+    /// the requests are byte-identical over the runs of the same seed. The random values are unpredictable if `None`.
+    pub seed: Option<u64>,
+}
+
+impl LoadGenConfig {
+    pub fn new(auth_addr: SocketAddr, acct_addr: SocketAddr, secret: &[u8]) -> Self {
+        LoadGenConfig {
+            auth_addr,
+            acct_addr,
+            secret: secret.to_vec(),
+            rate: 100.0,
+            duration: Duration::from_secs(10),
+            auth_weight: 1,
+            acct_weight: 1,
+            timeout: Duration::from_secs(3),
+            max_in_flight: 1024,
+            user_prefix: "loadgen-".to_owned(),
+            password: b"password".to_vec(),
+            seed: None,
+        }
+    }
+}
+
+/// This enum represents the kind of a generated request.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RequestKind {
+    Auth,
+    Acct,
+}
+
+/// This struct represents the result of a load test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadReport {
+    sent: usize,
+    received: usize,
+    rejected: usize,
+    lost: usize,
+    errors: usize,
+    elapsed: Duration,
+    /// sorted in ascending order
+    latencies: Vec<Duration>,
+}
+
+impl LoadReport {
+    /// Returns the number of the sent requests.
+    pub fn get_sent(&self) -> usize {
+        self.sent
+    }
+
+    /// Returns the number of the received responses, including the rejects.
+    pub fn get_received(&self) -> usize {
+        self.received
+    }
+
+    /// Returns the number of the received Access-Rejects.
+    pub fn get_rejected(&self) -> usize {
+        self.rejected
+    }
+
+    /// Returns the number of the requests that are not responded in time.
+    pub fn get_lost(&self) -> usize {
+        self.lost
+    }
+
+    /// Returns the number of the requests that failed except the timeouts (e.g. an undecodable response).
+    pub fn get_errors(&self) -> usize {
+        self.errors
+    }
+
+    /// Returns the duration from the first request to the last response.
+    pub fn get_elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the ratio of the lost requests to the sent ones.
+    pub fn get_loss_ratio(&self) -> f64 {
+        if self.sent == 0 {
+            return 0.0;
+        }
+        self.lost as f64 / self.sent as f64
+    }
+
+    /// Returns the number of the received responses per second.
+    pub fn get_throughput(&self) -> f64 {
+        if self.elapsed.is_zero() {
+            return 0.0;
+        }
+        self.received as f64 / self.elapsed.as_secs_f64()
+    }
+
+    /// Returns the latency of the given percentile (e.g. `99.0`) by the nearest-rank method;
+    /// it returns `None` if no response is received.
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * self.latencies.len() as f64).ceil();
+        let index = (rank as usize).max(1) - 1;
+        Some(self.latencies[index])
+    }
+}
+
+enum Outcome {
+    Received(Duration, Code),
+    Lost,
+    Error,
+}
+
+/// `LoadGenerator` sends the requests according to `LoadGenConfig`.
+pub struct LoadGenerator {
+    config: LoadGenConfig,
+    client: Arc<Client>,
+    random_source: Arc<dyn RandomSource>,
+}
+
+impl LoadGenerator {
+    pub fn new(config: LoadGenConfig) -> Result<Self, LoadGenError> {
+        if !(config.rate.is_finite() && config.rate > 0.0) {
+            return Err(LoadGenError::InvalidConfigError(format!(
+                "rate must be positive, but it was {}",
+                config.rate
+            )));
+        }
+        if config.auth_weight == 0 && config.acct_weight == 0 {
+            return Err(LoadGenError::InvalidConfigError(
+                "either auth_weight or acct_weight must be positive".to_owned(),
+            ));
+        }
+        if config.max_in_flight == 0 {
+            return Err(LoadGenError::InvalidConfigError(
+                "max_in_flight must be positive".to_owned(),
+            ));
+        }
+
+        let client = Client::new(None, Some(config.timeout));
+        let random_source: Arc<dyn RandomSource> = match config.seed {
+            Some(seed) => Arc::new(SeededRandomSource::new(seed)),
+            None => random::default_random_source(),
+        };
+        Ok(LoadGenerator {
+            config,
+            client: Arc::new(client),
+            random_source,
+        })
+    }
+
+    pub fn get_config(&self) -> &LoadGenConfig {
+        &self.config
+    }
+
+    /// Returns the kind of the n-th request; the kinds are interleaved according to the weights.
+    pub fn request_kind(&self, sequence: u64) -> RequestKind {
+        let total = (self.config.auth_weight + self.config.acct_weight) as u64;
+        match sequence % total < self.config.auth_weight as u64 {
+            true => RequestKind::Auth,
+            false => RequestKind::Acct,
+        }
+    }
+
+    /// Make the n-th request, that has a unique user name (and a unique session ID for accounting).
+    ///
+    /// The random values are taken from the generator's source in order of the calls,
+    /// so the requests are reproducible when they are made in the same order with the same seed.
+    pub fn make_request(&self, sequence: u64) -> Result<Packet, LoadGenError> {
+        let user_name = format!("{}{}", self.config.user_prefix, sequence);
+        let packet = match self.request_kind(sequence) {
+            RequestKind::Auth => {
+                let mut packet = Packet::new_with_random_source(
+                    Code::AccessRequest,
+                    &self.config.secret,
+                    self.random_source.clone(),
+                );
+                rfc2865::add_user_name(&mut packet, &user_name);
+                rfc2865::add_user_password(&mut packet, &self.config.password)
+                    .map_err(|e| LoadGenError::InvalidConfigError(e.to_string()))?;
+                packet
+            }
+            RequestKind::Acct => {
+                let mut packet = Packet::new_with_random_source(
+                    Code::AccountingRequest,
+                    &self.config.secret,
+                    self.random_source.clone(),
+                );
+                rfc2865::add_user_name(&mut packet, &user_name);
+                rfc2866::add_acct_status_type(&mut packet, rfc2866::ACCT_STATUS_TYPE_START);
+                rfc2866::add_acct_session_id(&mut packet, &user_name);
+                packet
+            }
+        };
+        Ok(packet)
+    }
+
+    /// Generate the requests at the target rate for the duration, and wait for their responses.
+    pub async fn run(&self) -> Result<LoadReport, LoadGenError> {
+        let in_flight = Arc::new(Semaphore::new(self.config.max_in_flight));
+        let mut ticker = interval(Duration::from_secs_f64(1.0 / self.config.rate));
+        // a tick that is late because of the in-flight limit is not caught up, so that it doesn't burst
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut tasks = JoinSet::new();
+        let started_at = Instant::now();
+        let mut sequence = 0;
+        loop {
+            ticker.tick().await;
+            if started_at.elapsed() >= self.config.duration {
+                break;
+            }
+
+            let request = self.make_request(sequence)?;
+            let remote_addr = match self.request_kind(sequence) {
+                RequestKind::Auth => self.config.auth_addr,
+                RequestKind::Acct => self.config.acct_addr,
+            };
+            sequence += 1;
+
+            let permit = in_flight.clone().acquire_owned().await.unwrap();
+            let client = self.client.clone();
+            tasks.spawn(async move {
+                let sent_at = Instant::now();
+                let outcome = match client.send_packet(&remote_addr, &request).await {
+                    Ok(response) => Outcome::Received(sent_at.elapsed(), response.get_code()),
+                    Err(ClientError::SocketTimeoutError()) => Outcome::Lost,
+                    Err(e) => {
+                        debug!("failed to send a request to {}; {}", remote_addr, e);
+                        Outcome::Error
+                    }
+                };
+                drop(permit);
+                outcome
+            });
+        }
+
+        let mut report = LoadReport {
+            sent: sequence as usize,
+            received: 0,
+            rejected: 0,
+            lost: 0,
+            errors: 0,
+            elapsed: Duration::ZERO,
+            latencies: Vec::with_capacity(sequence as usize),
+        };
+        while let Some(joined) = tasks.join_next().await {
+            // the task panics only when the client does, so propagates that as it is
+            match joined.unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic())) {
+                Outcome::Received(latency, code) => {
+                    report.received += 1;
+                    if code == Code::AccessReject {
+                        report.rejected += 1;
+                    }
+                    report.latencies.push(latency);
+                }
+                Outcome::Lost => report.lost += 1,
+                Outcome::Error => report.errors += 1,
+            }
+        }
+        report.elapsed = started_at.elapsed();
+        report.latencies.sort();
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::time::Duration;
+
+    use tokio::net::UdpSocket;
+
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::{rfc2865, rfc2866};
+    use crate::loadgen::{LoadGenConfig, LoadGenError, LoadGenerator, LoadReport, RequestKind};
+
+    /// Spawn a server that responds to the requests; it drops every `drop_every`-th request if that is positive.
+    async fn spawn_server(drop_every: usize) -> SocketAddr {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            let mut received = 0;
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                received += 1;
+                if drop_every > 0 && received % drop_every == 0 {
+                    continue;
+                }
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                let code = match request.get_code() {
+                    Code::AccessRequest => Code::AccessReject,
+                    _ => Code::AccountingResponse,
+                };
+                let response = request.make_response_packet(code);
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+        server_addr
+    }
+
+    fn config(server_addr: SocketAddr) -> LoadGenConfig {
+        let mut config = LoadGenConfig::new(server_addr, server_addr, b"secret");
+        config.rate = 200.0;
+        config.duration = Duration::from_millis(200);
+        config.auth_weight = 1;
+        config.acct_weight = 3;
+        config.timeout = Duration::from_millis(200);
+        config
+    }
+
+    #[test]
+    fn test_make_request() {
+        let generator = LoadGenerator::new(config("127.0.0.1:1812".parse().unwrap())).unwrap();
+        assert_eq!(
+            (0..8)
+                .map(|sequence| generator.request_kind(sequence))
+                .collect::<Vec<RequestKind>>(),
+            vec![
+                RequestKind::Auth,
+                RequestKind::Acct,
+                RequestKind::Acct,
+                RequestKind::Acct,
+                RequestKind::Auth,
+                RequestKind::Acct,
+                RequestKind::Acct,
+                RequestKind::Acct,
+            ]
+        );
+
+        let request = generator.make_request(4).unwrap();
+        assert_eq!(request.get_code(), Code::AccessRequest);
+        assert_eq!(
+            rfc2865::lookup_user_name(&request).unwrap().unwrap(),
+            "loadgen-4"
+        );
+        let request = generator.make_request(5).unwrap();
+        assert_eq!(request.get_code(), Code::AccountingRequest);
+        assert_eq!(
+            rfc2866::lookup_acct_session_id(&request).unwrap().unwrap(),
+            "loadgen-5"
+        );
+
+        // the same seed makes the byte-identical requests
+        let mut seeded = config("127.0.0.1:1812".parse().unwrap());
+        seeded.seed = Some(42);
+        let encode = |config: &LoadGenConfig| {
+            let generator = LoadGenerator::new(config.clone()).unwrap();
+            (0..4)
+                .map(|sequence| generator.make_request(sequence).unwrap().encode().unwrap())
+                .collect::<Vec<Vec<u8>>>()
+        };
+        assert_eq!(encode(&seeded), encode(&seeded));
+        let mut reseeded = seeded.clone();
+        reseeded.seed = Some(43);
+        assert_ne!(encode(&seeded), encode(&reseeded));
+
+        let mut invalid = config("127.0.0.1:1812".parse().unwrap());
+        invalid.rate = 0.0;
+        assert!(matches!(
+            LoadGenerator::new(invalid),
+            Err(LoadGenError::InvalidConfigError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_run() {
+        let generator = LoadGenerator::new(config(spawn_server(0).await)).unwrap();
+        let report = generator.run().await.unwrap();
+        assert!(report.get_sent() > 0);
+        assert_eq!(report.get_received(), report.get_sent());
+        assert_eq!(report.get_lost(), 0);
+        assert_eq!(report.get_errors(), 0);
+        assert_eq!(
+            report.get_rejected(),
+            report.get_sent().div_ceil(4) // every 4th request (from the first) is an Access-Request
+        );
+        assert!(report.percentile(50.0).unwrap() <= report.percentile(99.0).unwrap());
+
+        let generator = LoadGenerator::new(config(spawn_server(2).await)).unwrap();
+        let report = generator.run().await.unwrap();
+        assert_eq!(report.get_lost(), report.get_sent() / 2);
+        assert_eq!(report.get_received() + report.get_lost(), report.get_sent());
+        assert!(report.get_loss_ratio() > 0.0);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let report = LoadReport {
+            sent: 10,
+            received: 10,
+            rejected: 0,
+            lost: 0,
+            errors: 0,
+            elapsed: Duration::from_secs(2),
+            latencies: (1..=10).map(Duration::from_millis).collect(),
+        };
+        assert_eq!(report.percentile(50.0), Some(Duration::from_millis(5)));
+        assert_eq!(report.percentile(90.0), Some(Duration::from_millis(9)));
+        assert_eq!(report.percentile(99.0), Some(Duration::from_millis(10)));
+        assert_eq!(report.percentile(0.0), Some(Duration::from_millis(1)));
+        assert_eq!(report.get_throughput(), 5.0);
+
+        let report = LoadReport {
+            latencies: vec![],
+            ..report
+        };
+        assert_eq!(report.percentile(50.0), None);
+    }
+}
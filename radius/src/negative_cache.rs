@@ -0,0 +1,207 @@
+//! Client-side suppression of the retries after Access-Reject.
+//!
+//! `NegativeCache` remembers the Access-Reject per (user, NAS) for a cooldown, and `Client::authenticate()`
+//! answers the repeated attempts in the cooldown with the remembered outcome instead of sending those,
+//! so that a storm of the immediate re-authentications (e.g. a supplicant with a wrong password) doesn't reach the servers.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::client::AuthOutcome;
+use crate::clock::{self, Clock};
+use crate::core::packet::Packet;
+use crate::core::rfc2865;
+
+/// The number of the cached entries to sweep the expired ones.
+const SWEEP_THRESHOLD: usize = 10000;
+
+/// This struct represents the key of a cached Access-Reject; the `User-Name` and the NAS of the request.
+///
+/// The NAS is the `NAS-Identifier`, or the `NAS-IP-Address` if the former is not given.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NegativeCacheKey {
+    user_name: String,
+    nas: String,
+}
+
+impl NegativeCacheKey {
+    pub fn new(user_name: &str, nas: &str) -> Self {
+        NegativeCacheKey {
+            user_name: user_name.to_owned(),
+            nas: nas.to_owned(),
+        }
+    }
+
+    /// Returns the key of the request, or `None` if the request doesn't have a `User-Name`.
+    pub fn of(request: &Packet) -> Option<Self> {
+        let user_name = rfc2865::lookup_user_name(request)?.ok()?;
+        let nas = match rfc2865::lookup_nas_identifier(request) {
+            Some(Ok(nas_identifier)) => nas_identifier,
+            _ => match rfc2865::lookup_nas_ip_address(request) {
+                Some(Ok(nas_ip_address)) => nas_ip_address.to_string(),
+                _ => String::new(),
+            },
+        };
+        Some(NegativeCacheKey { user_name, nas })
+    }
+
+    pub fn get_user_name(&self) -> &str {
+        &self.user_name
+    }
+
+    pub fn get_nas(&self) -> &str {
+        &self.nas
+    }
+}
+
+/// The cache of the Access-Rejects per (user, NAS) for a cooldown.
+#[derive(Debug)]
+pub struct NegativeCache {
+    cooldown: Duration,
+    entries: Mutex<HashMap<NegativeCacheKey, (Instant, AuthOutcome)>>,
+    suppressed_count: AtomicU64,
+    clock: Arc<dyn Clock>,
+}
+
+impl NegativeCache {
+    pub fn new(cooldown: Duration) -> Self {
+        NegativeCache {
+            cooldown,
+            entries: Mutex::new(HashMap::new()),
+            suppressed_count: AtomicU64::new(0),
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set a clock of the expiry of the entries (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    pub fn get_cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    /// Returns the number of the requests that have been answered from the cache.
+    pub fn get_suppressed_count(&self) -> u64 {
+        self.suppressed_count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the cached Access-Reject of the request if it is in the cooldown.
+    pub fn lookup(&self, request: &Packet) -> Option<AuthOutcome> {
+        let key = NegativeCacheKey::of(request)?;
+        let now = self.clock.now();
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(&key) {
+            Some((until, outcome)) if *until > now => {
+                self.suppressed_count.fetch_add(1, Ordering::Relaxed);
+                Some(outcome.clone())
+            }
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Record the outcome of the request; an Access-Reject starts the cooldown, and an Access-Accept ends it.
+    pub fn record(&self, request: &Packet, outcome: &AuthOutcome) {
+        let Some(key) = NegativeCacheKey::of(request) else {
+            return;
+        };
+        let now = self.clock.now();
+        let mut entries = self.entries.lock().unwrap();
+        match outcome {
+            AuthOutcome::Reject { .. } => {
+                if entries.len() >= SWEEP_THRESHOLD {
+                    entries.retain(|_, (until, _)| *until > now);
+                }
+                entries.insert(key, (now + self.cooldown, outcome.clone()));
+            }
+            AuthOutcome::Accept { .. } => {
+                entries.remove(&key);
+            }
+            AuthOutcome::Challenge { .. } | AuthOutcome::Timeout | AuthOutcome::Invalid(_) => {}
+        }
+    }
+
+    /// Remove the cached Access-Reject of the key, e.g. when the user has changed the password.
+    pub fn invalidate(&self, key: &NegativeCacheKey) {
+        self.entries.lock().unwrap().remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::client::AuthOutcome;
+    use crate::clock::MockClock;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2865;
+    use crate::negative_cache::{NegativeCache, NegativeCacheKey};
+
+    fn make_request(user_name: &str, nas_ip_address: Ipv4Addr) -> Packet {
+        let mut request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut request, user_name);
+        rfc2865::add_nas_ip_address(&mut request, &nas_ip_address);
+        request
+    }
+
+    #[test]
+    fn test_negative_cache() {
+        let clock = MockClock::default();
+        let mut cache = NegativeCache::new(Duration::from_secs(30));
+        cache.set_clock(Arc::new(clock.clone()));
+
+        let request = make_request("alice", Ipv4Addr::new(192, 0, 2, 1));
+        let reject = AuthOutcome::Reject {
+            reply_message: Some("Invalid password".to_owned()),
+            response: request.make_response_packet(Code::AccessReject),
+        };
+        assert_eq!(cache.lookup(&request), None);
+        cache.record(&request, &reject);
+
+        // the other user and the other NAS are not suppressed
+        assert_eq!(
+            cache.lookup(&make_request("bob", Ipv4Addr::new(192, 0, 2, 1))),
+            None
+        );
+        assert_eq!(
+            cache.lookup(&make_request("alice", Ipv4Addr::new(192, 0, 2, 2))),
+            None
+        );
+
+        let retry = make_request("alice", Ipv4Addr::new(192, 0, 2, 1));
+        assert_eq!(cache.lookup(&retry), Some(reject.clone()));
+        assert_eq!(cache.get_suppressed_count(), 1);
+
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(cache.lookup(&retry), None);
+
+        // an Access-Accept ends the cooldown, and so does the invalidation
+        cache.record(&request, &reject);
+        cache.record(
+            &request,
+            &AuthOutcome::Accept {
+                response: request.make_response_packet(Code::AccessAccept),
+            },
+        );
+        assert_eq!(cache.lookup(&retry), None);
+        cache.record(&request, &reject);
+        cache.invalidate(&NegativeCacheKey::new("alice", "192.0.2.1"));
+        assert_eq!(cache.lookup(&retry), None);
+
+        // the requests without User-Name are never cached
+        let anonymous = Packet::new(Code::AccessRequest, b"secret");
+        cache.record(&anonymous, &reject);
+        assert_eq!(cache.lookup(&anonymous), None);
+        assert_eq!(cache.get_suppressed_count(), 1);
+    }
+}
@@ -0,0 +1,294 @@
+//! Writer of the pcap files, to inspect the traffic that is made with this crate (e.g. the test traffic) in Wireshark.
+//!
+//! Each RADIUS packet is wrapped in the Ethernet, IP and UDP headers; the checksums are computed,
+//! and the MAC addresses are made from the IP addresses.
+
+use std::io::Write;
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::core::packet::Packet;
+
+const PCAP_MAGIC_NUMBER: u32 = 0xa1b2c3d4;
+const PCAP_SNAPLEN: u32 = 65535;
+const PCAP_LINKTYPE_ETHERNET: u32 = 1;
+const ETHER_TYPE_IPV4: u16 = 0x0800;
+const ETHER_TYPE_IPV6: u16 = 0x86dd;
+const IP_PROTOCOL_UDP: u8 = 17;
+// the Ethernet, IPv6 and UDP headers
+const MAX_PAYLOAD_LENGTH: usize = PCAP_SNAPLEN as usize - 14 - 40 - 8;
+
+#[derive(Error, Debug)]
+pub enum PcapError {
+    /// This error is raised when it failed to write the pcap file.
+    #[error("failed to write the pcap file; {0}")]
+    WritingError(String),
+
+    /// This error is raised when the payload doesn't fit in a UDP datagram of the pcap file.
+    #[error("payload is too large; it must be at most {MAX_PAYLOAD_LENGTH} bytes, but actual length was {0}")]
+    PayloadTooLargeError(usize),
+
+    /// This error is raised when the RADIUS packet cannot be encoded.
+    #[error("failed to encode the packet; {0}")]
+    EncodingError(String),
+}
+
+/// `Writer` writes the UDP datagrams into a pcap file; the header of the file is written on construction.
+pub struct Writer<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(mut writer: W) -> Result<Self, PcapError> {
+        let mut header = Vec::with_capacity(24);
+        header.extend(PCAP_MAGIC_NUMBER.to_le_bytes());
+        header.extend(2u16.to_le_bytes()); // major version
+        header.extend(4u16.to_le_bytes()); // minor version
+        header.extend(0i32.to_le_bytes()); // time zone
+        header.extend(0u32.to_le_bytes()); // accuracy of the timestamps
+        header.extend(PCAP_SNAPLEN.to_le_bytes());
+        header.extend(PCAP_LINKTYPE_ETHERNET.to_le_bytes());
+        writer
+            .write_all(&header)
+            .map_err(|e| PcapError::WritingError(e.to_string()))?;
+        Ok(Writer { writer })
+    }
+
+    /// Write a UDP datagram that has the payload.
+    pub fn write_datagram(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        src_addr: SocketAddr,
+        dst_addr: SocketAddr,
+        payload: &[u8],
+    ) -> Result<(), PcapError> {
+        if payload.len() > MAX_PAYLOAD_LENGTH {
+            return Err(PcapError::PayloadTooLargeError(payload.len()));
+        }
+
+        let frame = ethernet_frame(src_addr, dst_addr, payload);
+        let mut record = Vec::with_capacity(16 + frame.len());
+        record.extend((timestamp.timestamp() as u32).to_le_bytes());
+        record.extend(timestamp.timestamp_subsec_micros().to_le_bytes());
+        record.extend((frame.len() as u32).to_le_bytes()); // captured length
+        record.extend((frame.len() as u32).to_le_bytes()); // original length
+        record.extend(frame);
+        self.writer
+            .write_all(&record)
+            .map_err(|e| PcapError::WritingError(e.to_string()))
+    }
+
+    /// Write a UDP datagram of the encoded RADIUS packet; see also `Packet::encode()`.
+    pub fn write_packet(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        src_addr: SocketAddr,
+        dst_addr: SocketAddr,
+        packet: &Packet,
+    ) -> Result<(), PcapError> {
+        let encoded = packet
+            .encode()
+            .map_err(|e| PcapError::EncodingError(e.to_string()))?;
+        self.write_datagram(timestamp, src_addr, dst_addr, &encoded)
+    }
+
+    pub fn flush(&mut self) -> Result<(), PcapError> {
+        self.writer
+            .flush()
+            .map_err(|e| PcapError::WritingError(e.to_string()))
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+fn ethernet_frame(src_addr: SocketAddr, dst_addr: SocketAddr, payload: &[u8]) -> Vec<u8> {
+    let ip = ip_udp_datagram(src_addr, dst_addr, payload);
+    let mut frame = Vec::with_capacity(14 + ip.len());
+    frame.extend(mac_address(dst_addr.ip()));
+    frame.extend(mac_address(src_addr.ip()));
+    frame.extend(
+        match ip[0] >> 4 {
+            4 => ETHER_TYPE_IPV4,
+            _ => ETHER_TYPE_IPV6,
+        }
+        .to_be_bytes(),
+    );
+    frame.extend(ip);
+    frame
+}
+
+/// Make a locally administered MAC address from the last 4 bytes of the IP address, so that the hosts are distinguishable.
+fn mac_address(ip: IpAddr) -> [u8; 6] {
+    let octets = to_ipv6(ip).octets();
+    [0x02, 0x00, octets[12], octets[13], octets[14], octets[15]]
+}
+
+/// Make the IP datagram that has the UDP datagram of the payload; IPv4 is used only if both addresses are IPv4
+/// (an IPv4 address is mapped into IPv6 otherwise).
+fn ip_udp_datagram(src_addr: SocketAddr, dst_addr: SocketAddr, payload: &[u8]) -> Vec<u8> {
+    let udp_length = 8 + payload.len();
+    let (src_ip, dst_ip) = match (src_addr.ip(), dst_addr.ip()) {
+        (IpAddr::V4(src_ip), IpAddr::V4(dst_ip)) => {
+            let mut pseudo_header = Vec::with_capacity(12);
+            pseudo_header.extend(src_ip.octets());
+            pseudo_header.extend(dst_ip.octets());
+            pseudo_header.extend([0, IP_PROTOCOL_UDP]);
+            pseudo_header.extend((udp_length as u16).to_be_bytes());
+            let udp = udp_datagram(src_addr.port(), dst_addr.port(), payload, &pseudo_header);
+
+            let mut ip = Vec::with_capacity(20 + udp.len());
+            ip.extend([0x45, 0x00]); // version, IHL and DSCP
+            ip.extend(((20 + udp.len()) as u16).to_be_bytes());
+            ip.extend([0x00, 0x00, 0x40, 0x00]); // identification, and "don't fragment"
+            ip.extend([64, IP_PROTOCOL_UDP]);
+            ip.extend([0x00, 0x00]); // the checksum is filled below
+            ip.extend(src_ip.octets());
+            ip.extend(dst_ip.octets());
+            let checksum = internet_checksum(&[&ip]);
+            ip[10..12].copy_from_slice(&checksum.to_be_bytes());
+            ip.extend(udp);
+            return ip;
+        }
+        (src_ip, dst_ip) => (to_ipv6(src_ip), to_ipv6(dst_ip)),
+    };
+
+    let mut pseudo_header = Vec::with_capacity(40);
+    pseudo_header.extend(src_ip.octets());
+    pseudo_header.extend(dst_ip.octets());
+    pseudo_header.extend((udp_length as u32).to_be_bytes());
+    pseudo_header.extend([0, 0, 0, IP_PROTOCOL_UDP]);
+    let udp = udp_datagram(src_addr.port(), dst_addr.port(), payload, &pseudo_header);
+
+    let mut ip = Vec::with_capacity(40 + udp.len());
+    ip.extend([0x60, 0x00, 0x00, 0x00]); // version, traffic class and flow label
+    ip.extend((udp.len() as u16).to_be_bytes());
+    ip.extend([IP_PROTOCOL_UDP, 64]);
+    ip.extend(src_ip.octets());
+    ip.extend(dst_ip.octets());
+    ip.extend(udp);
+    ip
+}
+
+fn udp_datagram(src_port: u16, dst_port: u16, payload: &[u8], pseudo_header: &[u8]) -> Vec<u8> {
+    let mut udp = Vec::with_capacity(8 + payload.len());
+    udp.extend(src_port.to_be_bytes());
+    udp.extend(dst_port.to_be_bytes());
+    udp.extend(((8 + payload.len()) as u16).to_be_bytes());
+    udp.extend([0x00, 0x00]); // the checksum is filled below
+    udp.extend(payload);
+    let checksum = match internet_checksum(&[pseudo_header, &udp]) {
+        0 => 0xffff, // zero means "no checksum"
+        checksum => checksum,
+    };
+    udp[6..8].copy_from_slice(&checksum.to_be_bytes());
+    udp
+}
+
+/// Compute the checksum of RFC 1071 over the concatenation of the given bytes; each of them must have even length except the last.
+fn internet_checksum(parts: &[&[u8]]) -> u16 {
+    let mut sum = 0u32;
+    for part in parts {
+        for chunk in part.chunks(2) {
+            let word = match chunk {
+                [hi, lo] => u16::from_be_bytes([*hi, *lo]),
+                [hi] => u16::from_be_bytes([*hi, 0]),
+                _ => unreachable!(),
+            };
+            sum += word as u32;
+        }
+    }
+    while sum > 0xffff {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+fn to_ipv6(ip: IpAddr) -> Ipv6Addr {
+    match ip {
+        IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+        IpAddr::V6(ip) => ip,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2865;
+    use crate::pcap::{internet_checksum, PcapError, Writer, MAX_PAYLOAD_LENGTH};
+
+    #[test]
+    fn test_write_packet() {
+        let timestamp = Utc.timestamp_opt(1709987696, 123_456_000).unwrap();
+        let client_addr: SocketAddr = "192.0.2.1:50000".parse().unwrap();
+        let server_addr: SocketAddr = "192.0.2.2:1812".parse().unwrap();
+        let mut packet = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut packet, "alice");
+        let encoded = packet.encode().unwrap();
+
+        let mut writer = Writer::new(Vec::new()).unwrap();
+        writer
+            .write_packet(timestamp, client_addr, server_addr, &packet)
+            .unwrap();
+        writer
+            .write_datagram(
+                timestamp,
+                "[2001:db8::2]:1812".parse().unwrap(),
+                client_addr,
+                &encoded,
+            )
+            .unwrap();
+        let pcap = writer.into_inner();
+
+        assert_eq!(&pcap[..4], &[0xd4, 0xc3, 0xb2, 0xa1]);
+        assert_eq!(&pcap[20..24], &[1, 0, 0, 0]); // Ethernet
+
+        let record = &pcap[24..];
+        assert_eq!(&record[..4], &1709987696u32.to_le_bytes());
+        assert_eq!(&record[4..8], &123_456u32.to_le_bytes());
+        let length = u32::from_le_bytes(record[8..12].try_into().unwrap()) as usize;
+        assert_eq!(length, 14 + 20 + 8 + encoded.len());
+        let frame = &record[16..16 + length];
+        assert_eq!(&frame[..6], &[0x02, 0x00, 192, 0, 2, 2]);
+        assert_eq!(&frame[6..12], &[0x02, 0x00, 192, 0, 2, 1]);
+        assert_eq!(&frame[12..14], &[0x08, 0x00]);
+        let ip = &frame[14..];
+        assert_eq!(internet_checksum(&[&ip[..20]]), 0);
+        assert_eq!(&ip[12..20], &[192, 0, 2, 1, 192, 0, 2, 2]);
+        let udp = &ip[20..];
+        assert_eq!(&udp[..4], &[0xc3, 0x50, 0x07, 0x14]);
+        assert_eq!(
+            internet_checksum(&[&ip[12..20], &[0, 17], &udp[4..6], udp]),
+            0
+        );
+        assert_eq!(&udp[8..], encoded);
+
+        // the IPv4 address is mapped into IPv6 when the other one is IPv6
+        let record = &record[16 + length..];
+        let length = u32::from_le_bytes(record[8..12].try_into().unwrap()) as usize;
+        assert_eq!(length, 14 + 40 + 8 + encoded.len());
+        let frame = &record[16..16 + length];
+        assert_eq!(&frame[12..14], &[0x86, 0xdd]);
+        assert_eq!(frame[14] >> 4, 6);
+        assert_eq!(record.len(), 16 + length);
+
+        let mut writer = Writer::new(Vec::new()).unwrap();
+        assert!(matches!(
+            writer.write_datagram(
+                timestamp,
+                client_addr,
+                server_addr,
+                &vec![0; MAX_PAYLOAD_LENGTH + 1]
+            ),
+            Err(PcapError::PayloadTooLargeError(_))
+        ));
+    }
+}
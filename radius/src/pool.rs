@@ -0,0 +1,649 @@
+//! Framed IP address pool management.
+
+use std::collections::{BTreeSet, HashMap};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::clock::{self, Clock};
+use crate::core::avp::AVPError;
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::request::Request;
+use crate::core::{rfc2865, rfc2866, rfc3162};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PoolError {
+    /// This error is raised when the given range is invalid (i.e. the start is greater than the end).
+    #[error("invalid address range: {0} - {1}")]
+    InvalidRangeError(IpAddr, IpAddr),
+
+    /// This error is raised when the given IPv6 prefix length cannot be delegated.
+    /// The prefix length must be a multiple of 8 and up to 128.
+    #[error("invalid IPv6 prefix length: {0}")]
+    InvalidPrefixLengthError(u8),
+
+    /// This error is raised when there is no available address in the pool.
+    #[error("address pool is exhausted")]
+    PoolExhaustedError(),
+
+    /// This error is raised when the request doesn't have the attribute to identify the lease owner.
+    #[error("lease owner identifying attribute is missing")]
+    OwnerMissingError(),
+
+    /// This error is raised when an attribute cannot be decoded or encoded.
+    #[error("failed to handle an attribute; {0}")]
+    AttributeError(AVPError),
+}
+
+/// This enum represents which attribute identifies the owner of a lease.
+///
+/// The same owner gets the same address as long as the address is not leased to another owner.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StickyBy {
+    /// Identifies the owner by `User-Name`.
+    #[default]
+    UserName,
+    /// Identifies the owner by `Calling-Station-Id` (i.e. the MAC address in the most situations).
+    CallingStationId,
+}
+
+/// This struct represents a lease of an address (or an IPv6 prefix).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lease {
+    pub address: IpAddr,
+    /// The prefix length of the address; this is `32` for an IPv4 address.
+    pub prefix_len: u8,
+    pub owner: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+struct Range {
+    start: u128,
+    end: u128,
+    step: u128,
+    prefix_len: u8,
+    is_ipv4: bool,
+}
+
+impl Range {
+    fn to_addr(&self, n: u128) -> IpAddr {
+        if self.is_ipv4 {
+            IpAddr::V4(Ipv4Addr::from(n as u32))
+        } else {
+            IpAddr::V6(Ipv6Addr::from(n))
+        }
+    }
+
+    fn contains(&self, n: u128) -> bool {
+        self.start <= n && n <= self.end
+    }
+}
+
+/// The addresses of a range that are available without scanning the range.
+#[derive(Debug)]
+struct RangeState {
+    /// the released or reclaimed addresses; those are lower than `next`
+    free: BTreeSet<u128>,
+    /// the lowest address that has never been leased, or `None` if all of them have been leased once
+    next: Option<u128>,
+}
+
+#[derive(Debug, Default)]
+struct PoolState {
+    leases: HashMap<IpAddr, Lease>,
+    // the most recent address per owner, to make the allocation sticky
+    owners: HashMap<(String, bool), IpAddr>,
+    // the leases in the order of the expiry, to reclaim the expired ones
+    expiries: BTreeSet<(DateTime<Utc>, IpAddr)>,
+    ipv4_ranges: Vec<RangeState>,
+    ipv6_ranges: Vec<RangeState>,
+}
+
+impl PoolState {
+    fn range_state(&mut self, is_ipv4: bool, i: usize) -> &mut RangeState {
+        if is_ipv4 {
+            &mut self.ipv4_ranges[i]
+        } else {
+            &mut self.ipv6_ranges[i]
+        }
+    }
+}
+
+/// An address pool that leases IPv4 addresses and IPv6 prefixes for `Framed-IP-Address` and `Framed-IPv6-Prefix`.
+///
+/// An expired lease is reclaimed lazily when the pool needs an address. The pool keeps the free addresses and the lowest
+/// address that has never been leased per range, so an allocation doesn't scan the ranges.
+#[derive(Debug)]
+pub struct IpPool {
+    ipv4_ranges: Vec<Range>,
+    ipv6_ranges: Vec<Range>,
+    lease_ttl: Duration,
+    sticky_by: StickyBy,
+    state: Mutex<PoolState>,
+    clock: Arc<dyn Clock>,
+}
+
+impl IpPool {
+    /// A constructor for an empty pool; the lease is valid for `lease_ttl` since it is allocated or renewed.
+    ///
+    /// A `lease_ttl` beyond the representable time (e.g. `Duration::MAX`) makes the leases that never expire.
+    pub fn new(lease_ttl: Duration) -> Self {
+        IpPool {
+            ipv4_ranges: Vec::new(),
+            ipv6_ranges: Vec::new(),
+            lease_ttl,
+            sticky_by: StickyBy::default(),
+            state: Mutex::new(PoolState::default()),
+            clock: clock::default_clock(),
+        }
+    }
+
+    /// Set a clock of the expiry of the leases (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Set an attribute to identify the owner of a lease (default: `StickyBy::UserName`).
+    pub fn set_sticky_by(&mut self, sticky_by: StickyBy) {
+        self.sticky_by = sticky_by;
+    }
+
+    /// Add an IPv4 address range (inclusive) to the pool.
+    pub fn add_ipv4_range(&mut self, start: Ipv4Addr, end: Ipv4Addr) -> Result<(), PoolError> {
+        if start > end {
+            return Err(PoolError::InvalidRangeError(start.into(), end.into()));
+        }
+        self.state.get_mut().unwrap().ipv4_ranges.push(RangeState {
+            free: BTreeSet::new(),
+            next: Some(u32::from(start) as u128),
+        });
+        self.ipv4_ranges.push(Range {
+            start: u32::from(start) as u128,
+            end: u32::from(end) as u128,
+            step: 1,
+            prefix_len: 32,
+            is_ipv4: true,
+        });
+        Ok(())
+    }
+
+    /// Add an IPv6 range (inclusive) to the pool, which is delegated per `prefix_len` prefix.
+    ///
+    /// e.g. `2001:db8::` - `2001:db8:0:ff::` with `prefix_len = 64` makes 256 of `/64` prefixes.
+    pub fn add_ipv6_prefix_range(
+        &mut self,
+        start: Ipv6Addr,
+        end: Ipv6Addr,
+        prefix_len: u8,
+    ) -> Result<(), PoolError> {
+        if prefix_len == 0 || prefix_len > 128 || !prefix_len.is_multiple_of(8) {
+            return Err(PoolError::InvalidPrefixLengthError(prefix_len));
+        }
+        if start > end {
+            return Err(PoolError::InvalidRangeError(start.into(), end.into()));
+        }
+        let host_mask = u128::MAX.checked_shr(prefix_len as u32).unwrap_or(0);
+        self.state.get_mut().unwrap().ipv6_ranges.push(RangeState {
+            free: BTreeSet::new(),
+            next: Some(u128::from(start) & !host_mask),
+        });
+        self.ipv6_ranges.push(Range {
+            start: u128::from(start) & !host_mask,
+            end: u128::from(end) & !host_mask,
+            step: host_mask + 1,
+            prefix_len,
+            is_ipv4: false,
+        });
+        Ok(())
+    }
+
+    /// Allocate an IPv4 address for the owner.
+    pub fn allocate_ipv4(&self, owner: &str) -> Result<Lease, PoolError> {
+        self.allocate_at(owner, true, self.clock.now_utc())
+    }
+
+    /// Allocate an IPv6 prefix for the owner.
+    pub fn allocate_ipv6_prefix(&self, owner: &str) -> Result<Lease, PoolError> {
+        self.allocate_at(owner, false, self.clock.now_utc())
+    }
+
+    /// Allocate an address at the given time; `is_ipv4` chooses the IPv4 ranges or the IPv6 ranges.
+    ///
+    /// If the owner already has a lease, that is renewed and returned.
+    pub fn allocate_at(
+        &self,
+        owner: &str,
+        is_ipv4: bool,
+        now: DateTime<Utc>,
+    ) -> Result<Lease, PoolError> {
+        let ranges = self.ranges(is_ipv4);
+        let expires_at = self.expires_at(now);
+
+        let mut state = self.state.lock().unwrap();
+
+        let owner_key = (owner.to_owned(), is_ipv4);
+        if let Some(address) = state.owners.get(&owner_key).copied() {
+            let is_available = match state.leases.get(&address) {
+                Some(lease) => lease.owner == owner || lease.expires_at <= now,
+                None => true,
+            };
+            if is_available && find_range(ranges, &address).is_some() {
+                return Ok(self.lease(&mut state, address, owner, expires_at));
+            }
+        }
+
+        self.reclaim(&mut state, now);
+        for (i, range) in ranges.iter().enumerate() {
+            let range_state = state.range_state(is_ipv4, i);
+            let n = match range_state.free.pop_first() {
+                Some(n) => n,
+                None => match range_state.next {
+                    Some(n) => {
+                        range_state.next =
+                            n.checked_add(range.step).filter(|next| *next <= range.end);
+                        n
+                    }
+                    None => continue,
+                },
+            };
+            return Ok(self.lease(&mut state, range.to_addr(n), owner, expires_at));
+        }
+
+        Err(PoolError::PoolExhaustedError())
+    }
+
+    fn ranges(&self, is_ipv4: bool) -> &[Range] {
+        if is_ipv4 {
+            &self.ipv4_ranges
+        } else {
+            &self.ipv6_ranges
+        }
+    }
+
+    /// Returns the expiry of the lease that is allocated or renewed at the time; this saturates at the max time.
+    fn expires_at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        chrono::Duration::from_std(self.lease_ttl)
+            .ok()
+            .and_then(|lease_ttl| now.checked_add_signed(lease_ttl))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Lease the address to the owner; the address is taken from the free addresses if it is there.
+    fn lease(
+        &self,
+        state: &mut PoolState,
+        address: IpAddr,
+        owner: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Lease {
+        let (i, range) = match find_range(self.ranges(address.is_ipv4()), &address) {
+            Some(found) => found,
+            None => unreachable!("the address is out of the ranges: {address}"),
+        };
+        state
+            .range_state(address.is_ipv4(), i)
+            .free
+            .remove(&ip_to_u128(&address));
+
+        let lease = Lease {
+            address,
+            prefix_len: range.prefix_len,
+            owner: owner.to_owned(),
+            expires_at,
+        };
+        if let Some(previous) = state.leases.insert(address, lease.clone()) {
+            state.expiries.remove(&(previous.expires_at, address));
+        }
+        state.expiries.insert((expires_at, address));
+        state
+            .owners
+            .insert((owner.to_owned(), address.is_ipv4()), address);
+        lease
+    }
+
+    /// Return the address of a removed lease to the free addresses of its range.
+    fn free(&self, state: &mut PoolState, address: &IpAddr) {
+        if let Some((i, _)) = find_range(self.ranges(address.is_ipv4()), address) {
+            state
+                .range_state(address.is_ipv4(), i)
+                .free
+                .insert(ip_to_u128(address));
+        }
+    }
+
+    /// Reclaim the leases that have expired by the time.
+    fn reclaim(&self, state: &mut PoolState, now: DateTime<Utc>) {
+        while let Some((expires_at, address)) = state.expiries.first().copied() {
+            if expires_at > now {
+                break;
+            }
+            state.expiries.pop_first();
+            state.leases.remove(&address);
+            self.free(state, &address);
+        }
+    }
+
+    /// Renew the leases of the owner; this returns the renewed leases.
+    pub fn renew_at(&self, owner: &str, now: DateTime<Utc>) -> Vec<Lease> {
+        let expires_at = self.expires_at(now);
+        let mut state = self.state.lock().unwrap();
+        let PoolState {
+            leases, expiries, ..
+        } = &mut *state;
+        leases
+            .values_mut()
+            .filter(|lease| lease.owner == owner)
+            .map(|lease| {
+                expiries.remove(&(lease.expires_at, lease.address));
+                expiries.insert((expires_at, lease.address));
+                lease.expires_at = expires_at;
+                lease.clone()
+            })
+            .collect()
+    }
+
+    /// Release the leases of the owner; this returns the released leases.
+    ///
+    /// The released address is still preferred for the owner on the next allocation unless it is leased to another.
+    pub fn release(&self, owner: &str) -> Vec<Lease> {
+        let mut state = self.state.lock().unwrap();
+        let addresses = state
+            .leases
+            .values()
+            .filter(|lease| lease.owner == owner)
+            .map(|lease| lease.address)
+            .collect::<Vec<_>>();
+        let mut released = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            if let Some(lease) = state.leases.remove(&address) {
+                state.expiries.remove(&(lease.expires_at, address));
+                self.free(&mut state, &address);
+                released.push(lease);
+            }
+        }
+        released
+    }
+
+    /// Returns the active lease of the address if it exists.
+    pub fn get_lease(&self, address: &IpAddr) -> Option<Lease> {
+        let state = self.state.lock().unwrap();
+        state
+            .leases
+            .get(address)
+            .filter(|lease| lease.expires_at > self.clock.now_utc())
+            .cloned()
+    }
+
+    fn lookup_owner(&self, packet: &Packet) -> Result<String, PoolError> {
+        let owner = match self.sticky_by {
+            StickyBy::UserName => rfc2865::lookup_user_name(packet),
+            StickyBy::CallingStationId => rfc2865::lookup_calling_station_id(packet),
+        };
+        match owner {
+            Some(owner) => owner.map_err(PoolError::AttributeError),
+            None => Err(PoolError::OwnerMissingError()),
+        }
+    }
+
+    /// Assign the leased addresses to an Access-Accept response for the request.
+    ///
+    /// This sets `Framed-IP-Address` when the pool has IPv4 ranges and `Framed-IPv6-Prefix` when the pool has IPv6 ranges.
+    /// The response that is not Access-Accept is left as it is.
+    pub fn assign(&self, request: &Request, response: &mut Packet) -> Result<(), PoolError> {
+        if response.get_code() != Code::AccessAccept {
+            return Ok(());
+        }
+
+        let owner = self.lookup_owner(request.get_packet())?;
+        if !self.ipv4_ranges.is_empty() {
+            let lease = self.allocate_ipv4(&owner)?;
+            if let IpAddr::V4(address) = lease.address {
+                rfc2865::add_framed_ip_address(response, &address);
+            }
+        }
+        if !self.ipv6_ranges.is_empty() {
+            let lease = self.allocate_ipv6_prefix(&owner)?;
+            if let IpAddr::V6(address) = lease.address {
+                let prefix = &address.octets()[..(lease.prefix_len / 8) as usize];
+                rfc3162::add_framed_ipv6_prefix(response, prefix)
+                    .map_err(PoolError::AttributeError)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Update the leases according to an Accounting-Request.
+    ///
+    /// `Interim-Update` renews the leases of the owner and `Stop` releases them. The other requests are ignored.
+    pub fn account(&self, request: &Request) -> Result<(), PoolError> {
+        let packet = request.get_packet();
+        if packet.get_code() != Code::AccountingRequest {
+            return Ok(());
+        }
+
+        let status_type = match rfc2866::lookup_acct_status_type(packet) {
+            Some(status_type) => status_type.map_err(PoolError::AttributeError)?,
+            None => return Ok(()),
+        };
+        match status_type {
+            rfc2866::ACCT_STATUS_TYPE_INTERIM_UPDATE => {
+                self.renew_at(&self.lookup_owner(packet)?, self.clock.now_utc());
+            }
+            rfc2866::ACCT_STATUS_TYPE_STOP => {
+                self.release(&self.lookup_owner(packet)?);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Returns the index and the range that contains the address.
+fn find_range<'a>(ranges: &'a [Range], address: &IpAddr) -> Option<(usize, &'a Range)> {
+    let n = ip_to_u128(address);
+    ranges
+        .iter()
+        .enumerate()
+        .find(|(_, range)| range.contains(n))
+}
+
+fn ip_to_u128(address: &IpAddr) -> u128 {
+    match address {
+        IpAddr::V4(v4) => u32::from(*v4) as u128,
+        IpAddr::V6(v6) => u128::from(*v6),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::time::Duration;
+
+    use chrono::{DateTime, Utc};
+
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::core::{rfc2865, rfc2866, rfc3162};
+    use crate::pool::{IpPool, PoolError};
+
+    #[test]
+    fn test_allocate_ipv4() {
+        let mut pool = IpPool::new(Duration::from_secs(60));
+        pool.add_ipv4_range(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 2))
+            .unwrap();
+        let now = Utc::now();
+
+        let alice = pool.allocate_at("alice", true, now).unwrap();
+        assert_eq!(alice.address, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+        let bob = pool.allocate_at("bob", true, now).unwrap();
+        assert_eq!(bob.address, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)));
+        assert_eq!(
+            pool.allocate_at("carol", true, now),
+            Err(PoolError::PoolExhaustedError())
+        );
+
+        // sticky
+        assert_eq!(
+            pool.allocate_at("alice", true, now).unwrap().address,
+            alice.address
+        );
+        pool.release("alice");
+        assert_eq!(
+            pool.allocate_at("alice", true, now).unwrap().address,
+            alice.address
+        );
+
+        // expired lease is reclaimed
+        let later = now + chrono::Duration::seconds(61);
+        assert_eq!(
+            pool.allocate_at("carol", true, later).unwrap().address,
+            alice.address
+        );
+        assert_eq!(
+            pool.allocate_at("alice", true, later).unwrap().address,
+            bob.address
+        );
+    }
+
+    #[test]
+    fn test_allocate_from_free_addresses() {
+        let mut pool = IpPool::new(Duration::from_secs(60));
+        pool.add_ipv4_range(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 3))
+            .unwrap();
+        pool.add_ipv4_range(Ipv4Addr::new(10, 0, 1, 1), Ipv4Addr::new(10, 0, 1, 1))
+            .unwrap();
+        let now = Utc::now();
+
+        for (owner, last_octet) in [("alice", 1), ("bob", 2), ("carol", 3)] {
+            assert_eq!(
+                pool.allocate_at(owner, true, now).unwrap().address,
+                IpAddr::V4(Ipv4Addr::new(10, 0, 0, last_octet))
+            );
+        }
+        assert_eq!(
+            pool.allocate_at("dave", true, now).unwrap().address,
+            IpAddr::V4(Ipv4Addr::new(10, 0, 1, 1))
+        );
+
+        // the released address is leased to another owner
+        pool.release("bob");
+        assert_eq!(
+            pool.allocate_at("erin", true, now).unwrap().address,
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2))
+        );
+        assert_eq!(
+            pool.allocate_at("bob", true, now),
+            Err(PoolError::PoolExhaustedError())
+        );
+
+        // the renewed lease is not reclaimed on its former expiry
+        let later = now + chrono::Duration::seconds(30);
+        assert_eq!(pool.renew_at("alice", later).len(), 1);
+        let expired = now + chrono::Duration::seconds(61);
+        assert_eq!(
+            pool.allocate_at("bob", true, expired).unwrap().address,
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2))
+        );
+        assert_eq!(
+            pool.allocate_at("frank", true, expired).unwrap().address,
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3))
+        );
+        assert_eq!(
+            pool.allocate_at("alice", true, expired).unwrap().address,
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))
+        );
+    }
+
+    #[test]
+    fn test_allocate_with_max_lease_ttl() {
+        let mut pool = IpPool::new(Duration::MAX);
+        pool.add_ipv4_range(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 1))
+            .unwrap();
+
+        let lease = pool.allocate_ipv4("alice").unwrap();
+        assert_eq!(lease.expires_at, DateTime::<Utc>::MAX_UTC);
+        assert_eq!(
+            pool.renew_at("alice", Utc::now())[0].expires_at,
+            DateTime::<Utc>::MAX_UTC
+        );
+        assert!(pool.get_lease(&lease.address).is_some());
+    }
+
+    #[test]
+    fn test_allocate_ipv6_prefix() {
+        let mut pool = IpPool::new(Duration::from_secs(60));
+        assert_eq!(
+            pool.add_ipv6_prefix_range(Ipv6Addr::LOCALHOST, Ipv6Addr::LOCALHOST, 60),
+            Err(PoolError::InvalidPrefixLengthError(60))
+        );
+        pool.add_ipv6_prefix_range(
+            "2001:db8::".parse().unwrap(),
+            "2001:db8:0:1::".parse().unwrap(),
+            64,
+        )
+        .unwrap();
+
+        let now = Utc::now();
+        let alice = pool.allocate_at("alice", false, now).unwrap();
+        assert_eq!(alice.address, "2001:db8::".parse::<IpAddr>().unwrap());
+        assert_eq!(alice.prefix_len, 64);
+        let bob = pool.allocate_at("bob", false, now).unwrap();
+        assert_eq!(bob.address, "2001:db8:0:1::".parse::<IpAddr>().unwrap());
+        assert!(pool.allocate_at("carol", false, now).is_err());
+    }
+
+    #[test]
+    fn test_assign_and_release() {
+        let mut pool = IpPool::new(Duration::from_secs(60));
+        pool.add_ipv4_range(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 1))
+            .unwrap();
+        pool.add_ipv6_prefix_range(
+            "2001:db8::".parse().unwrap(),
+            "2001:db8::".parse().unwrap(),
+            64,
+        )
+        .unwrap();
+
+        let addr: SocketAddr = "127.0.0.1:1812".parse().unwrap();
+        let mut access_request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut access_request, "alice");
+        let access_request = Request::new(addr, addr, access_request);
+
+        let mut response = access_request
+            .get_packet()
+            .make_response_packet(Code::AccessAccept);
+        pool.assign(&access_request, &mut response).unwrap();
+        assert_eq!(
+            rfc2865::lookup_framed_ip_address(&response)
+                .unwrap()
+                .unwrap(),
+            Ipv4Addr::new(10, 0, 0, 1)
+        );
+        assert_eq!(
+            rfc3162::lookup_framed_ipv6_prefix(&response)
+                .unwrap()
+                .unwrap(),
+            vec![0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0]
+        );
+
+        let mut stop = Packet::new(Code::AccountingRequest, b"secret");
+        rfc2865::add_user_name(&mut stop, "alice");
+        rfc2866::add_acct_status_type(&mut stop, rfc2866::ACCT_STATUS_TYPE_STOP);
+        pool.account(&Request::new(addr, addr, stop)).unwrap();
+        assert!(pool
+            .get_lease(&IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))
+            .is_none());
+
+        let mut reject = access_request
+            .get_packet()
+            .make_response_packet(Code::AccessReject);
+        pool.assign(&access_request, &mut reject).unwrap();
+        assert!(rfc2865::lookup_framed_ip_address(&reject).is_none());
+    }
+}
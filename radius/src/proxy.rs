@@ -0,0 +1,1473 @@
+//! Reverse proxy that forwards the requests to the upstream servers.
+//!
+//! `ReverseProxy` is a `RequestHandler` that routes a request by the realm of `User-Name` (i.e. `user@realm`)
+//! to an `UpstreamPool`, re-signs it with the secret of the pool, and relays the response to the client.
+//! The pools, realms and client secrets are given by the setters or by a configuration text;
+//! see `ReverseProxy::from_config()`.
+//!
+//! The Accounting-Requests to a pool can be acknowledged by the proxy itself and forwarded in the background
+//! through a disk spool, so that a slow or unreachable accounting server doesn't block the NAS;
+//! see `ReverseProxy::add_accounting_buffer()`.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+use std::sync::Weak;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use thiserror::Error;
+use tokio::net::UdpSocket;
+
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+use crate::acct::spool::{DiskSpool, FsyncPolicy, SendOutcome, SpoolingClient};
+use crate::client::{Client, ClientError};
+use crate::clock::{self, Clock};
+use crate::core::avp::{AVPError, AVPType};
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+use crate::core::code::Code;
+use crate::core::packet::{Packet, RADIUS_PACKET_HEADER_LENGTH};
+use crate::core::request::Request;
+use crate::core::rfc2865;
+#[cfg(feature = "rfc2868")]
+use crate::core::rfc2868;
+use crate::retry::{RetryPolicy, Rfc5080RetryPolicy};
+use crate::server::{RequestHandler, SecretProvider, SecretProviderError};
+
+const DEFAULT_CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_DOWN_DURATION: Duration = Duration::from_secs(30);
+// the same as `rfc2869::MESSAGE_AUTHENTICATOR_TYPE`; that module is optional (i.e. `rfc2869` feature)
+const MESSAGE_AUTHENTICATOR_TYPE: AVPType = 80;
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+const DEFAULT_ACCOUNTING_TIMEOUT: Duration = Duration::from_secs(5);
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+const DEFAULT_ACCOUNTING_REPLAY_INTERVAL: Duration = Duration::from_secs(30);
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+const DEFAULT_ACCOUNTING_SPOOL_SIZE: u64 = 64 * 1024 * 1024;
+
+#[derive(Error, Debug)]
+pub enum ProxyError {
+    /// This error is raised when the configuration text is invalid.
+    #[error("invalid proxy configuration at line {0}; {1}")]
+    InvalidConfigError(usize, String),
+
+    /// This error is raised when the pool of the given name is not added.
+    #[error("unknown upstream pool {0}")]
+    UnknownPoolError(String),
+
+    /// This error is raised when no upstream pool serves the realm of the request.
+    #[error("no upstream pool for the realm {0:?}")]
+    NoRouteError(Option<String>),
+
+    /// This error is raised when an encrypted attribute cannot be re-encrypted for the destination.
+    #[error("failed to re-encrypt an attribute; {0}")]
+    AttributeError(AVPError),
+
+    /// This error is raised when all of the upstream servers of the pool failed to respond.
+    #[error("no upstream server of the pool {0} responded; {1}")]
+    UpstreamError(String, ClientError),
+
+    /// This error is raised when the response of the upstream server doesn't answer the request of the proxy,
+    /// e.g. a forged response; the response is dropped.
+    #[error("invalid response from the upstream server {0}; {1}")]
+    InvalidUpstreamResponseError(SocketAddr, String),
+
+    /// This error is raised when it fails to relay the response to the client.
+    #[error("failed to relay the response to {0}; {1}")]
+    RelayingResponseError(SocketAddr, String),
+}
+
+/// This enum represents a strategy to select the upstream server of a request.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum LoadBalancing {
+    /// Distributes the requests over the servers in turn.
+    #[default]
+    RoundRobin,
+    /// Sends the requests of the same `User-Name` to the same server, so that the rounds of
+    /// a multi-round conversation (e.g. EAP) reach the server that holds the state of that.
+    StickyByUserName,
+    /// Sends the requests of the same `Calling-Station-Id` (i.e. the same device) to the same server.
+    StickyByCallingStationId,
+}
+
+impl FromStr for LoadBalancing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "round-robin" => Ok(LoadBalancing::RoundRobin),
+            "user-name" => Ok(LoadBalancing::StickyByUserName),
+            "calling-station-id" => Ok(LoadBalancing::StickyByCallingStationId),
+            _ => Err(format!("unknown load balancing strategy {s:?}")),
+        }
+    }
+}
+
+/// This struct represents a pool of the upstream servers that share a secret.
+///
+/// The servers are selected according to the load balancing strategy (default: `LoadBalancing::RoundRobin`),
+/// and a request fails over to the next server when a server doesn't respond according to the retry policy
+/// (default: `Rfc5080RetryPolicy`).
+///
+/// A server that failed to respond is down for a while (default: 30 seconds); the down servers are tried
+/// after the others, and the server is up again when the duration has elapsed or when it responds.
+/// The sticky strategies order the servers by the rendezvous hashing, so the requests of a key go to the same server
+/// while that is up, and move to the same fallback server while that is down.
+pub struct UpstreamPool {
+    servers: Vec<SocketAddr>,
+    secret: Vec<u8>,
+    retry_policy: Arc<dyn RetryPolicy>,
+    load_balancing: LoadBalancing,
+    down_duration: Duration,
+    clock: Arc<dyn Clock>,
+    next: AtomicUsize,
+    down_until: Mutex<HashMap<SocketAddr, Instant>>,
+}
+
+impl UpstreamPool {
+    pub fn new(servers: Vec<SocketAddr>, secret: &[u8]) -> Self {
+        UpstreamPool {
+            servers,
+            secret: secret.to_vec(),
+            retry_policy: Arc::new(Rfc5080RetryPolicy::default()),
+            load_balancing: LoadBalancing::default(),
+            down_duration: DEFAULT_DOWN_DURATION,
+            clock: clock::default_clock(),
+            next: AtomicUsize::new(0),
+            down_until: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn get_servers(&self) -> &[SocketAddr] {
+        &self.servers
+    }
+
+    pub fn get_secret(&self) -> &[u8] {
+        &self.secret
+    }
+
+    /// Set a retry policy of the transmissions to each server (default: `Rfc5080RetryPolicy`).
+    pub fn set_retry_policy(&mut self, retry_policy: Arc<dyn RetryPolicy>) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Set a strategy to select the server of a request (default: `LoadBalancing::RoundRobin`).
+    pub fn set_load_balancing(&mut self, load_balancing: LoadBalancing) {
+        self.load_balancing = load_balancing;
+    }
+
+    pub fn get_load_balancing(&self) -> LoadBalancing {
+        self.load_balancing
+    }
+
+    /// Set a duration that a server is down after it failed to respond (default: 30 seconds).
+    pub fn set_down_duration(&mut self, down_duration: Duration) {
+        self.down_duration = down_duration;
+    }
+
+    /// Set a clock of the down durations (default: `SystemClock`).
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Returns whether the server is up, i.e. it has not failed to respond in the down duration.
+    pub fn is_up(&self, server: &SocketAddr) -> bool {
+        match self.down_until.lock().unwrap().get(server) {
+            Some(down_until) => *down_until <= self.clock.now(),
+            None => true,
+        }
+    }
+
+    fn mark_down(&self, server: SocketAddr) {
+        let down_until = self.clock.now() + self.down_duration;
+        self.down_until.lock().unwrap().insert(server, down_until);
+    }
+
+    fn mark_up(&self, server: &SocketAddr) {
+        self.down_until.lock().unwrap().remove(server);
+    }
+
+    /// Returns the key of the stickiness of the request, if the strategy is sticky and the request has that.
+    fn sticky_key(&self, request: &Packet) -> Option<Vec<u8>> {
+        let typ = match self.load_balancing {
+            LoadBalancing::RoundRobin => return None,
+            LoadBalancing::StickyByUserName => rfc2865::USER_NAME_TYPE,
+            LoadBalancing::StickyByCallingStationId => rfc2865::CALLING_STATION_ID_TYPE,
+        };
+        request.lookup(typ).map(|avp| avp.raw_value().to_vec())
+    }
+
+    /// Returns the servers in the order to try for a request; the down servers come last.
+    ///
+    /// Without the sticky key, the order is rotated per request.
+    fn rotation(&self, sticky_key: Option<&[u8]>) -> Vec<SocketAddr> {
+        if self.servers.is_empty() {
+            return vec![];
+        }
+        let servers: Vec<SocketAddr> = match sticky_key {
+            Some(key) => {
+                let mut servers = self.servers.clone();
+                servers.sort_by_key(|server| {
+                    let mut hasher = DefaultHasher::new();
+                    key.hash(&mut hasher);
+                    server.hash(&mut hasher);
+                    std::cmp::Reverse(hasher.finish())
+                });
+                servers
+            }
+            None => {
+                let start = self.next.fetch_add(1, Ordering::Relaxed) % self.servers.len();
+                self.servers[start..]
+                    .iter()
+                    .chain(self.servers[..start].iter())
+                    .copied()
+                    .collect()
+            }
+        };
+        let (up, down): (Vec<SocketAddr>, Vec<SocketAddr>) =
+            servers.into_iter().partition(|server| self.is_up(server));
+        up.into_iter().chain(down).collect()
+    }
+}
+
+/// ClientSecrets is a `SecretProvider` of the clients of a `ReverseProxy`; see `ReverseProxy::get_secret_provider()`.
+#[derive(Debug, Clone, Default)]
+pub struct ClientSecrets {
+    secrets: HashMap<IpAddr, Vec<u8>>,
+}
+
+impl SecretProvider for ClientSecrets {
+    fn fetch_secret(&self, remote_addr: SocketAddr) -> Result<Vec<u8>, SecretProviderError> {
+        match self.secrets.get(&remote_addr.ip()) {
+            Some(secret) => Ok(secret.clone()),
+            None => Err(SecretProviderError::FailedFetchingError(format!(
+                "unknown client {}",
+                remote_addr.ip()
+            ))),
+        }
+    }
+}
+
+type Rewrite = Box<dyn Fn(&mut Packet) + Sync + Send>;
+
+/// The spooling clients of the pools that the Accounting-Requests are buffered for.
+#[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+struct AccountingBuffers {
+    buffers: HashMap<String, Arc<SpoolingClient>>,
+    replay_interval: Duration,
+    replayer_started: AtomicBool,
+    clock: Arc<dyn Clock>,
+}
+
+/// A RADIUS reverse proxy.
+///
+/// A request is routed to the pool of its realm, or to the default pool when the realm is absent or unknown;
+/// the request that has no route is dropped. The proxy adds its own `Proxy-State` to the forwarded request
+/// and removes that from the response, so the `Proxy-State`s of the downstream proxies are kept as they are.
+/// `User-Password` of the request and `Tunnel-Password` of the response are re-encrypted for the destination.
+///
+/// ## Example Usage
+/// - https://github.com/moznion/radius-rs/blob/HEAD/examples/proxy.rs
+pub struct ReverseProxy {
+    client: Client,
+    clients: ClientSecrets,
+    pools: HashMap<String, Arc<UpstreamPool>>,
+    realms: HashMap<String, String>,
+    default_pool: Option<String>,
+    strip_realm: bool,
+    request_rewrite: Option<Rewrite>,
+    response_rewrite: Option<Rewrite>,
+    proxy_state_counter: AtomicU32,
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    accounting_buffers: AccountingBuffers,
+}
+
+impl Default for ReverseProxy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReverseProxy {
+    pub fn new() -> Self {
+        ReverseProxy {
+            client: Client::new(Some(DEFAULT_CONNECTION_TIMEOUT), None),
+            clients: ClientSecrets::default(),
+            pools: HashMap::new(),
+            realms: HashMap::new(),
+            default_pool: None,
+            strip_realm: false,
+            request_rewrite: None,
+            response_rewrite: None,
+            proxy_state_counter: AtomicU32::new(rand::random()),
+            #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+            accounting_buffers: AccountingBuffers {
+                buffers: HashMap::new(),
+                replay_interval: DEFAULT_ACCOUNTING_REPLAY_INTERVAL,
+                replayer_started: AtomicBool::new(false),
+                clock: clock::default_clock(),
+            },
+        }
+    }
+
+    /// Makes a proxy from a configuration text.
+    ///
+    /// Each line is one of the following directives; the empty lines and the lines that start with `#` are ignored.
+    ///
+    /// ```text
+    /// client <ip> <secret>                      # a client and its shared secret
+    /// pool <name> <secret> [lb=<strategy>] <ip:port> [ip:port]…
+    ///                                           # an upstream pool, its shared secret, its load balancing strategy
+    ///                                           # (`round-robin`, `user-name` or `calling-station-id`) and its servers
+    /// realm <realm> <pool>                      # routes the realm to the pool
+    /// default <pool>                            # routes the other requests to the pool
+    /// strip-realm                               # removes the realm from User-Name of the forwarded requests
+    /// accounting-buffer <pool> <path> [max_size]
+    ///                                           # acknowledges the Accounting-Requests to the pool and forwards those
+    ///                                           # through the spool file (default max size: 64 MiB)
+    /// ```
+    ///
+    /// `accounting-buffer` is available only with the features of the `acct` module.
+    pub fn from_config(config: &str) -> Result<Self, ProxyError> {
+        let mut proxy = ReverseProxy::new();
+        for (i, line) in config.lines().enumerate() {
+            let lineno = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: String| ProxyError::InvalidConfigError(lineno, reason);
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["client", ip, secret] => {
+                    let ip = IpAddr::from_str(ip)
+                        .map_err(|e| invalid(format!("invalid client address {ip:?}; {e}")))?;
+                    proxy.add_client(ip, secret.as_bytes());
+                }
+                ["pool", name, secret, args @ ..] => {
+                    let mut servers = vec![];
+                    let mut load_balancing = LoadBalancing::default();
+                    for arg in args {
+                        match arg.strip_prefix("lb=") {
+                            Some(lb) => load_balancing = lb.parse().map_err(invalid)?,
+                            None => servers.push(SocketAddr::from_str(arg).map_err(|e| {
+                                invalid(format!("invalid server address {arg:?}; {e}"))
+                            })?),
+                        }
+                    }
+                    if servers.is_empty() {
+                        return Err(invalid(format!("no server for the pool {name:?}")));
+                    }
+                    let mut pool = UpstreamPool::new(servers, secret.as_bytes());
+                    pool.set_load_balancing(load_balancing);
+                    proxy.add_pool(name, pool);
+                }
+                ["realm", realm, pool] => {
+                    if !proxy.pools.contains_key(*pool) {
+                        return Err(invalid(format!("unknown pool {pool:?}")));
+                    }
+                    proxy.add_realm(realm, pool);
+                }
+                ["default", pool] => {
+                    if !proxy.pools.contains_key(*pool) {
+                        return Err(invalid(format!("unknown pool {pool:?}")));
+                    }
+                    proxy.set_default_pool(pool);
+                }
+                ["strip-realm"] => proxy.set_strip_realm(true),
+                #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+                ["accounting-buffer", pool, path, args @ ..] if args.len() <= 1 => {
+                    if !proxy.pools.contains_key(*pool) {
+                        return Err(invalid(format!("unknown pool {pool:?}")));
+                    }
+                    let max_size = match args.first() {
+                        Some(max_size) => max_size
+                            .parse()
+                            .map_err(|e| invalid(format!("invalid max size {max_size:?}; {e}")))?,
+                        None => DEFAULT_ACCOUNTING_SPOOL_SIZE,
+                    };
+                    let spool = DiskSpool::open(path, max_size, FsyncPolicy::Always)
+                        .map_err(|e| invalid(e.to_string()))?;
+                    proxy
+                        .add_accounting_buffer(pool, spool)
+                        .map_err(|e| invalid(e.to_string()))?;
+                }
+                _ => return Err(invalid(format!("unknown directive {line:?}"))),
+            }
+        }
+        Ok(proxy)
+    }
+
+    /// Set a client to send the requests to the upstream servers (default: `Client` with the connection timeout of 5 seconds);
+    /// the retransmissions are decided by the retry policy of each pool.
+    pub fn set_client(&mut self, client: Client) {
+        self.client = client;
+    }
+
+    /// Add a client of the proxy and its shared secret.
+    pub fn add_client(&mut self, ip: IpAddr, secret: &[u8]) {
+        self.clients.secrets.insert(ip, secret.to_vec());
+    }
+
+    /// Returns a `SecretProvider` of the clients for `Server::listen()`.
+    pub fn get_secret_provider(&self) -> ClientSecrets {
+        self.clients.clone()
+    }
+
+    /// Add an upstream pool with the name; the pool of the same name is replaced.
+    pub fn add_pool(&mut self, name: &str, pool: UpstreamPool) {
+        self.pools.insert(name.to_owned(), Arc::new(pool));
+    }
+
+    /// Route the requests of the realm (case-insensitive) to the pool of the name.
+    pub fn add_realm(&mut self, realm: &str, pool: &str) {
+        self.realms
+            .insert(realm.to_ascii_lowercase(), pool.to_owned());
+    }
+
+    /// Set a pool for the requests that have no realm or an unknown realm (default: none, i.e. those are dropped).
+    pub fn set_default_pool(&mut self, pool: &str) {
+        self.default_pool = Some(pool.to_owned());
+    }
+
+    /// Set whether the realm is removed from `User-Name` of the forwarded requests (default: false).
+    pub fn set_strip_realm(&mut self, strip_realm: bool) {
+        self.strip_realm = strip_realm;
+    }
+
+    /// Set a function that rewrites the request after the routing, just before it is forwarded.
+    pub fn set_request_rewrite<F: Fn(&mut Packet) + Sync + Send + 'static>(&mut self, rewrite: F) {
+        self.request_rewrite = Some(Box::new(rewrite));
+    }
+
+    /// Set a function that rewrites the response of the upstream server, just before it is relayed to the client.
+    pub fn set_response_rewrite<F: Fn(&mut Packet) + Sync + Send + 'static>(&mut self, rewrite: F) {
+        self.response_rewrite = Some(Box::new(rewrite));
+    }
+
+    /// Acknowledge the Accounting-Requests to the pool by the proxy itself, and forward those to the pool in the background.
+    ///
+    /// The requests are spooled while the servers of the pool are unreachable, and replayed in order on the next request
+    /// or periodically (see `set_accounting_replay_interval()`). The pool has to be added before this, because the spooled
+    /// requests are signed with the secret of the pool.
+    ///
+    /// An acknowledged request is lost when the spool is full, so the max size of the spool should cover the expected outage.
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    pub fn add_accounting_buffer(
+        &mut self,
+        pool: &str,
+        spool: DiskSpool,
+    ) -> Result<(), ProxyError> {
+        let secret = match self.pools.get(pool) {
+            Some(upstream_pool) => upstream_pool.get_secret().to_vec(),
+            None => return Err(ProxyError::UnknownPoolError(pool.to_owned())),
+        };
+        let client = Client::new(
+            Some(DEFAULT_CONNECTION_TIMEOUT),
+            Some(DEFAULT_ACCOUNTING_TIMEOUT),
+        );
+        self.accounting_buffers.buffers.insert(
+            pool.to_owned(),
+            Arc::new(SpoolingClient::new(client, spool, &secret)),
+        );
+        Ok(())
+    }
+
+    /// Set an interval to replay the spooled Accounting-Requests while no request comes (default: 30 seconds).
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    pub fn set_accounting_replay_interval(&mut self, replay_interval: Duration) {
+        self.accounting_buffers.replay_interval = replay_interval;
+    }
+
+    /// Set a clock of the periodic replay of the spooled Accounting-Requests (default: `SystemClock`).
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    pub fn set_accounting_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.accounting_buffers.clock = clock;
+    }
+
+    /// Acknowledge the Accounting-Request to the client, and forward that to the pool in the background.
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    async fn buffer_accounting_request(
+        &self,
+        conn: &UdpSocket,
+        req: &Request,
+        pool_name: &str,
+        pool: &Arc<UpstreamPool>,
+        buffer: &Arc<SpoolingClient>,
+        upstream_request: Packet,
+    ) -> Result<(), ProxyError> {
+        let request = req.get_packet();
+        let servers = pool.rotation(pool.sticky_key(request).as_deref());
+        if servers.is_empty() {
+            return Err(ProxyError::UpstreamError(
+                pool_name.to_owned(),
+                ClientError::NoAddressResolvedError(pool_name.to_owned()),
+            ));
+        }
+        self.start_accounting_replayer();
+
+        // the request fails over to the next server like the other requests, and is spooled when all of them are unreachable
+        let (pool, buffer) = (pool.clone(), buffer.clone());
+        tokio::spawn(async move {
+            match buffer
+                .send_packet_with_failover(&servers, &upstream_request)
+                .await
+            {
+                Ok((outcome, unreachable)) => {
+                    for server in &servers[..unreachable] {
+                        pool.mark_down(*server);
+                    }
+                    if let SendOutcome::Sent(_) = outcome {
+                        pool.mark_up(&servers[unreachable]);
+                    }
+                }
+                Err(e) => error!(
+                    "failed to forward an acknowledged accounting request to {:?}; {}",
+                    servers, e
+                ),
+            }
+        });
+
+        let mut response = request.make_response_packet(Code::AccountingResponse);
+        for avp in request.lookup_all(rfc2865::PROXY_STATE_TYPE) {
+            response.add(avp.clone());
+        }
+        if let Some(rewrite) = &self.response_rewrite {
+            rewrite(&mut response);
+        }
+        self.relay_response(conn, req, &response).await
+    }
+
+    /// Start the task that replays the spooled Accounting-Requests periodically, unless it has been started.
+    ///
+    /// The task refers to the pools and the buffers weakly, so it ends when the proxy is dropped.
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    fn start_accounting_replayer(&self) {
+        if self
+            .accounting_buffers
+            .replayer_started
+            .swap(true, Ordering::Relaxed)
+        {
+            return;
+        }
+        let buffers: Vec<(Weak<UpstreamPool>, Weak<SpoolingClient>)> = self
+            .accounting_buffers
+            .buffers
+            .iter()
+            .filter_map(|(name, buffer)| {
+                self.pools
+                    .get(name)
+                    .map(|pool| (Arc::downgrade(pool), Arc::downgrade(buffer)))
+            })
+            .collect();
+        let replay_interval = self.accounting_buffers.replay_interval;
+        let clock = self.accounting_buffers.clock.clone();
+        tokio::spawn(async move {
+            loop {
+                clock.sleep(replay_interval).await;
+                let mut alive = false;
+                for (pool, buffer) in &buffers {
+                    if let (Some(pool), Some(buffer)) = (pool.upgrade(), buffer.upgrade()) {
+                        alive = true;
+                        Self::replay_accounting_requests(&pool, &buffer).await;
+                    }
+                }
+                if !alive {
+                    debug!("the accounting replayer is stopped because the proxy has been dropped");
+                    return;
+                }
+            }
+        });
+    }
+
+    /// Replay the spooled Accounting-Requests to the servers of the pool in the rotation, until the spool is drained.
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    async fn replay_accounting_requests(pool: &UpstreamPool, buffer: &SpoolingClient) {
+        if buffer.get_spooled_count().await == 0 {
+            return;
+        }
+        for server in pool.rotation(None) {
+            match buffer.replay(&server).await {
+                Ok(_) if buffer.get_spooled_count().await == 0 => {
+                    pool.mark_up(&server);
+                    return;
+                }
+                Ok(_) => pool.mark_down(server),
+                Err(e) => {
+                    error!(
+                        "failed to replay the spooled accounting requests to {}; {}",
+                        server, e
+                    );
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn relay_response(
+        &self,
+        conn: &UdpSocket,
+        req: &Request,
+        response: &Packet,
+    ) -> Result<(), ProxyError> {
+        let encoded = response
+            .encode()
+            .map_err(|e| ProxyError::RelayingResponseError(req.get_remote_addr(), e.to_string()))?;
+        conn.send_to(&encoded, req.get_remote_addr())
+            .await
+            .map_err(|e| ProxyError::RelayingResponseError(req.get_remote_addr(), e.to_string()))?;
+        req.audit_response(response);
+        Ok(())
+    }
+
+    /// Returns the name of the pool for the request.
+    fn route(&self, realm: Option<&str>) -> Option<&str> {
+        realm
+            .and_then(|realm| self.realms.get(&realm.to_ascii_lowercase()))
+            .or(self.default_pool.as_ref())
+            .map(|pool| pool.as_str())
+    }
+
+    /// Makes the request to forward to the pool.
+    fn make_upstream_request(
+        &self,
+        request: &Packet,
+        pool: &UpstreamPool,
+        stripped_user_name: Option<&str>,
+        proxy_state: &[u8],
+    ) -> Result<Packet, ProxyError> {
+        let mut upstream_request = Packet::with_capacity(
+            request.get_code(),
+            pool.get_secret(),
+            request.get_avps().len() + 1,
+        );
+        for avp in request.get_avps() {
+            match avp.type_code() {
+                rfc2865::USER_NAME_TYPE => match stripped_user_name {
+                    Some(user) => rfc2865::add_user_name(&mut upstream_request, user),
+                    None => upstream_request.add(avp.clone()),
+                },
+                rfc2865::USER_PASSWORD_TYPE => {
+                    let password = avp
+                        .decode_user_password(request.get_secret(), request.get_authenticator())
+                        .map_err(ProxyError::AttributeError)?;
+                    rfc2865::add_user_password(&mut upstream_request, &password)
+                        .map_err(ProxyError::AttributeError)?;
+                }
+                _ => upstream_request.add(avp.clone()),
+            }
+        }
+        // the CHAP-Password is of the Request Authenticator without CHAP-Challenge, and that changes over the hop
+        if request.contains(rfc2865::CHAP_PASSWORD_TYPE)
+            && !request.contains(rfc2865::CHAP_CHALLENGE_TYPE)
+        {
+            rfc2865::add_chap_challenge(&mut upstream_request, request.get_authenticator())
+                .map_err(ProxyError::AttributeError)?;
+        }
+        rfc2865::add_proxy_state(&mut upstream_request, proxy_state)
+            .map_err(ProxyError::AttributeError)?;
+
+        if let Some(rewrite) = &self.request_rewrite {
+            rewrite(&mut upstream_request);
+        }
+        Ok(upstream_request)
+    }
+
+    /// Makes the response to relay to the client.
+    // `upstream_request` is used only to re-encrypt `Tunnel-Password`
+    #[cfg_attr(not(feature = "rfc2868"), allow(unused_variables))]
+    fn make_downstream_response(
+        &self,
+        request: &Packet,
+        upstream_request: &Packet,
+        upstream_response: &Packet,
+        proxy_state: &[u8],
+    ) -> Result<Packet, ProxyError> {
+        let mut response = request.make_response_packet(upstream_response.get_code());
+        let mut proxy_state_removed = false;
+        for avp in upstream_response.get_avps() {
+            match avp.type_code() {
+                rfc2865::PROXY_STATE_TYPE
+                    if !proxy_state_removed && avp.raw_value() == proxy_state =>
+                {
+                    proxy_state_removed = true;
+                }
+                #[cfg(feature = "rfc2868")]
+                rfc2868::TUNNEL_PASSWORD_TYPE => {
+                    let (password, tag) = avp
+                        .decode_tunnel_password(
+                            upstream_request.get_secret(),
+                            upstream_request.get_authenticator(),
+                        )
+                        .map_err(ProxyError::AttributeError)?;
+                    rfc2868::add_tunnel_password(&mut response, Some(&tag), &password)
+                        .map_err(ProxyError::AttributeError)?;
+                }
+                _ => response.add(avp.clone()),
+            }
+        }
+        if let Some(rewrite) = &self.response_rewrite {
+            rewrite(&mut response);
+        }
+        Ok(response)
+    }
+
+    fn next_proxy_state(&self) -> Vec<u8> {
+        self.proxy_state_counter
+            .fetch_add(1, Ordering::Relaxed)
+            .to_be_bytes()
+            .to_vec()
+    }
+}
+
+/// Decodes the response of the upstream server, and verifies that it answers the request as sent;
+/// i.e. the Response Authenticator, the Message-Authenticator, and the Proxy-State of the proxy.
+fn verify_upstream_response(
+    server: &SocketAddr,
+    upstream_request: &Packet,
+    request_data: &[u8],
+    response_data: &[u8],
+    proxy_state: &[u8],
+) -> Result<Packet, ProxyError> {
+    let invalid = |reason: String| ProxyError::InvalidUpstreamResponseError(*server, reason);
+    let secret = upstream_request.get_secret();
+
+    if !Packet::is_authentic_response(response_data, request_data, secret) {
+        return Err(invalid(
+            "the Response Authenticator is not authentic".to_owned(),
+        ));
+    }
+    let response = Packet::decode(response_data, secret).map_err(|e| invalid(e.to_string()))?;
+
+    // the Message-Authenticator is required if the request has that
+    if (response.contains(MESSAGE_AUTHENTICATOR_TYPE)
+        || upstream_request.contains(MESSAGE_AUTHENTICATOR_TYPE))
+        && !response
+            .is_authentic_message_authenticator(Some(&request_data[4..RADIUS_PACKET_HEADER_LENGTH]))
+    {
+        return Err(invalid(
+            "the Message-Authenticator is missing or not authentic".to_owned(),
+        ));
+    }
+
+    if !response
+        .lookup_all(rfc2865::PROXY_STATE_TYPE)
+        .iter()
+        .any(|avp| avp.raw_value() == proxy_state)
+    {
+        return Err(invalid(
+            "the Proxy-State of the proxy is not returned".to_owned(),
+        ));
+    }
+    Ok(response)
+}
+
+/// Splits `user@realm` into the user and the realm.
+fn split_realm(user_name: &str) -> (&str, Option<&str>) {
+    match user_name.rsplit_once('@') {
+        Some((user, realm)) if !realm.is_empty() => (user, Some(realm)),
+        _ => (user_name, None),
+    }
+}
+
+#[async_trait]
+impl RequestHandler<(), ProxyError> for ReverseProxy {
+    async fn handle_radius_request(
+        &self,
+        conn: &UdpSocket,
+        req: &Request,
+    ) -> Result<(), ProxyError> {
+        let request = req.get_packet();
+
+        let user_name = rfc2865::lookup_user_name(request).and_then(|user_name| user_name.ok());
+        let (user, realm) = match &user_name {
+            Some(user_name) => split_realm(user_name),
+            None => ("", None),
+        };
+        let pool_name = self
+            .route(realm)
+            .ok_or_else(|| ProxyError::NoRouteError(realm.map(|realm| realm.to_owned())))?;
+        let pool = match self.pools.get(pool_name) {
+            Some(pool) => pool,
+            None => {
+                return Err(ProxyError::NoRouteError(
+                    realm.map(|realm| realm.to_owned()),
+                ))
+            }
+        };
+
+        let stripped_user_name = match realm {
+            Some(_) if self.strip_realm => Some(user),
+            _ => None,
+        };
+        let proxy_state = self.next_proxy_state();
+        let upstream_request =
+            self.make_upstream_request(request, pool, stripped_user_name, &proxy_state)?;
+
+        #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+        if request.get_code() == Code::AccountingRequest {
+            if let Some(buffer) = self.accounting_buffers.buffers.get(pool_name) {
+                return self
+                    .buffer_accounting_request(conn, req, pool_name, pool, buffer, upstream_request)
+                    .await;
+            }
+        }
+
+        let mut last_err = ClientError::NoAddressResolvedError(pool_name.to_owned());
+        let mut upstream_response = None;
+        for server in pool.rotation(pool.sticky_key(request).as_deref()) {
+            match self
+                .client
+                .exchange_with_retry(&server, &upstream_request, pool.retry_policy.as_ref())
+                .await
+            {
+                Ok((request_data, response_data)) => {
+                    pool.mark_up(&server);
+                    upstream_response = Some((server, request_data, response_data));
+                    break;
+                }
+                Err(e) => {
+                    pool.mark_down(server);
+                    info!(
+                        "upstream server {} of the pool {} failed; {}",
+                        server, pool_name, e
+                    );
+                    last_err = e;
+                }
+            }
+        }
+        let upstream_response = match upstream_response {
+            Some((server, request_data, response_data)) => verify_upstream_response(
+                &server,
+                &upstream_request,
+                &request_data,
+                &response_data,
+                &proxy_state,
+            )?,
+            None => return Err(ProxyError::UpstreamError(pool_name.to_owned(), last_err)),
+        };
+
+        let response = self.make_downstream_response(
+            request,
+            &upstream_request,
+            &upstream_response,
+            &proxy_state,
+        )?;
+        self.relay_response(conn, req, &response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use chrono::Utc;
+    use tokio::net::UdpSocket;
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    use tokio::sync::mpsc;
+
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    use crate::acct::spool::{DiskSpool, FsyncPolicy};
+    use crate::client::Client;
+    use crate::clock::MockClock;
+    use crate::core::avp::AVP;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2865;
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    use crate::core::rfc2866;
+    #[cfg(feature = "rfc2868")]
+    use crate::core::rfc2868;
+    #[cfg(feature = "rfc2868")]
+    use crate::core::tag::Tag;
+    use crate::proxy::{split_realm, LoadBalancing, ProxyError, ReverseProxy, UpstreamPool};
+    use crate::server::Server;
+
+    #[test]
+    fn test_split_realm() {
+        assert_eq!(
+            split_realm("alice@example.com"),
+            ("alice", Some("example.com"))
+        );
+        assert_eq!(
+            split_realm("alice@home@example.com"),
+            ("alice@home", Some("example.com"))
+        );
+        assert_eq!(split_realm("alice"), ("alice", None));
+        assert_eq!(split_realm("alice@"), ("alice@", None));
+    }
+
+    #[test]
+    fn test_from_config() {
+        let proxy = ReverseProxy::from_config(
+            "
+            # clients
+            client 192.0.2.1 s3cret
+            pool home upstream lb=user-name 127.0.0.1:1812 127.0.0.1:1813
+            pool other other 127.0.0.1:1814
+            realm Example.COM home
+            default other
+            strip-realm
+            ",
+        )
+        .unwrap();
+        assert!(proxy.strip_realm);
+        assert_eq!(proxy.route(Some("example.com")), Some("home"));
+        assert_eq!(proxy.route(Some("unknown.example.com")), Some("other"));
+        assert_eq!(proxy.route(None), Some("other"));
+        assert_eq!(proxy.pools["home"].get_secret(), b"upstream");
+        assert_eq!(proxy.pools["home"].get_servers().len(), 2);
+        assert_eq!(
+            proxy.pools["home"].get_load_balancing(),
+            LoadBalancing::StickyByUserName
+        );
+        assert_eq!(
+            proxy.pools["other"].get_load_balancing(),
+            LoadBalancing::RoundRobin
+        );
+
+        for (config, lineno) in [
+            ("client 192.0.2.300 s3cret", 1),
+            ("pool home upstream", 1),
+            ("pool home upstream 127.0.0.1", 1),
+            ("pool home upstream lb=random 127.0.0.1:1812", 1),
+            (
+                "pool home upstream 127.0.0.1:1812\nrealm example.com unknown",
+                2,
+            ),
+            ("default unknown", 1),
+            ("\nproxy-everything", 2),
+            ("accounting-buffer unknown /dev/null", 1),
+        ] {
+            match ReverseProxy::from_config(config) {
+                Err(ProxyError::InvalidConfigError(actual, _)) => assert_eq!(actual, lineno),
+                _ => panic!("{config:?} should be invalid"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_route_without_default_pool() {
+        let mut proxy = ReverseProxy::new();
+        proxy.add_pool("home", UpstreamPool::new(vec![], b"upstream"));
+        proxy.add_realm("example.com", "home");
+        assert_eq!(proxy.route(Some("EXAMPLE.com")), Some("home"));
+        assert_eq!(proxy.route(Some("unknown.example.com")), None);
+        assert_eq!(proxy.route(None), None);
+    }
+
+    #[test]
+    fn test_upstream_pool_rotation() {
+        let servers: Vec<SocketAddr> = vec![
+            "192.0.2.1:1812".parse().unwrap(),
+            "192.0.2.2:1812".parse().unwrap(),
+            "192.0.2.3:1812".parse().unwrap(),
+        ];
+        let pool = UpstreamPool::new(servers.clone(), b"secret");
+        assert_eq!(pool.rotation(None), servers);
+        assert_eq!(
+            pool.rotation(None),
+            vec![servers[1], servers[2], servers[0]]
+        );
+        assert_eq!(
+            pool.rotation(None),
+            vec![servers[2], servers[0], servers[1]]
+        );
+        assert_eq!(pool.rotation(None), servers);
+
+        assert!(UpstreamPool::new(vec![], b"secret")
+            .rotation(None)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_upstream_pool_stickiness() {
+        let servers: Vec<SocketAddr> = vec![
+            "192.0.2.1:1812".parse().unwrap(),
+            "192.0.2.2:1812".parse().unwrap(),
+            "192.0.2.3:1812".parse().unwrap(),
+        ];
+        let mut pool = UpstreamPool::new(servers.clone(), b"secret");
+
+        let mut request = Packet::new(Code::AccessRequest, b"secret");
+        rfc2865::add_user_name(&mut request, "alice@example.com");
+        rfc2865::add_calling_station_id(&mut request, "00-00-5E-00-53-01");
+        assert_eq!(pool.sticky_key(&request), None);
+        pool.set_load_balancing(LoadBalancing::StickyByCallingStationId);
+        assert_eq!(
+            pool.sticky_key(&request),
+            Some(b"00-00-5E-00-53-01".to_vec())
+        );
+        pool.set_load_balancing(LoadBalancing::StickyByUserName);
+        assert_eq!(
+            pool.sticky_key(&request),
+            Some(b"alice@example.com".to_vec())
+        );
+        assert_eq!(
+            pool.sticky_key(&Packet::new(Code::AccessRequest, b"secret")),
+            None
+        );
+
+        // the same key always goes to the same server
+        let order = pool.rotation(Some(b"alice@example.com"));
+        for _ in 0..10 {
+            assert_eq!(pool.rotation(Some(b"alice@example.com")), order);
+        }
+
+        // the keys are distributed over the servers
+        let firsts: HashSet<SocketAddr> = (0..100)
+            .map(|i| pool.rotation(Some(format!("user{i}").as_bytes()))[0])
+            .collect();
+        assert_eq!(firsts.len(), servers.len());
+    }
+
+    #[test]
+    fn test_upstream_pool_failover() {
+        let servers: Vec<SocketAddr> = vec![
+            "192.0.2.1:1812".parse().unwrap(),
+            "192.0.2.2:1812".parse().unwrap(),
+            "192.0.2.3:1812".parse().unwrap(),
+        ];
+        let clock = MockClock::new(Utc::now());
+        let mut pool = UpstreamPool::new(servers.clone(), b"secret");
+        pool.set_load_balancing(LoadBalancing::StickyByUserName);
+        pool.set_down_duration(Duration::from_secs(30));
+        pool.set_clock(Arc::new(clock.clone()));
+
+        // the conversation moves to the next server of its order while the server is down
+        let order = pool.rotation(Some(b"alice"));
+        pool.mark_down(order[0]);
+        assert!(!pool.is_up(&order[0]));
+        assert_eq!(
+            pool.rotation(Some(b"alice")),
+            vec![order[1], order[2], order[0]]
+        );
+        assert_eq!(
+            pool.rotation(Some(b"alice")),
+            vec![order[1], order[2], order[0]]
+        );
+
+        clock.advance(Duration::from_secs(30));
+        assert!(pool.is_up(&order[0]));
+        assert_eq!(pool.rotation(Some(b"alice")), order);
+
+        pool.mark_down(order[0]);
+        pool.mark_up(&order[0]);
+        assert_eq!(pool.rotation(Some(b"alice")), order);
+
+        // the round-robin skips the down servers too
+        pool.set_load_balancing(LoadBalancing::RoundRobin);
+        pool.mark_down(servers[0]);
+        assert_eq!(
+            pool.rotation(None),
+            vec![servers[1], servers[2], servers[0]]
+        );
+        assert_eq!(
+            pool.rotation(None),
+            vec![servers[1], servers[2], servers[0]]
+        );
+        assert_eq!(
+            pool.rotation(None),
+            vec![servers[2], servers[1], servers[0]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reverse_proxy() {
+        // the upstream server accepts `alice` by the password that is encrypted with its own secret
+        let upstream = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let upstream_addr = upstream.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = upstream.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"upstream").unwrap();
+                let user_name = rfc2865::lookup_user_name(&request).unwrap().unwrap();
+                let password = rfc2865::lookup_user_password(&request).unwrap().unwrap();
+                let mut response = if user_name == "alice" && password == b"p@ssw0rd" {
+                    request.make_response_packet(Code::AccessAccept)
+                } else {
+                    request.make_response_packet(Code::AccessReject)
+                };
+                rfc2865::add_reply_message(
+                    &mut response,
+                    format!("{}", rfc2865::lookup_all_proxy_state(&request).len()),
+                );
+                for proxy_state in rfc2865::lookup_all_proxy_state(&request) {
+                    rfc2865::add_proxy_state(&mut response, &proxy_state).unwrap();
+                }
+                #[cfg(feature = "rfc2868")]
+                rfc2868::add_tunnel_password(&mut response, Some(&Tag::new(1)), b"tunnel").unwrap();
+                upstream
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let proxy = ReverseProxy::from_config(&format!(
+            "
+            client 127.0.0.1 downstream
+            pool home upstream 127.0.0.1:1 {upstream_addr}
+            realm example.com home
+            strip-realm
+            "
+        ))
+        .unwrap();
+        let secret_provider = proxy.get_secret_provider();
+        let mut server = Server::listen("127.0.0.1", 0, proxy, secret_provider)
+            .await
+            .unwrap();
+        let proxy_addr = server.get_listen_address().unwrap();
+        tokio::spawn(async move { server.run(std::future::pending::<()>()).await });
+
+        let client = Client::new(None, Some(std::time::Duration::from_secs(10)));
+
+        let mut request = Packet::new(Code::AccessRequest, b"downstream");
+        rfc2865::add_user_name(&mut request, "alice@example.com");
+        rfc2865::add_user_password(&mut request, b"p@ssw0rd").unwrap();
+        rfc2865::add_proxy_state(&mut request, b"downstream-proxy").unwrap();
+        let response = client.send_packet(&proxy_addr, &request).await.unwrap();
+        assert_eq!(response.get_code(), Code::AccessAccept);
+        // the upstream server received the Proxy-States of the downstream proxy and of the proxy
+        assert_eq!(
+            rfc2865::lookup_reply_message(&response).unwrap().unwrap(),
+            "2"
+        );
+        assert_eq!(
+            rfc2865::lookup_all_proxy_state(&response),
+            vec![b"downstream-proxy".to_vec()]
+        );
+        // the encrypted attributes of a response are encrypted with the Request Authenticator
+        #[cfg(feature = "rfc2868")]
+        assert_eq!(
+            response
+                .lookup(rfc2868::TUNNEL_PASSWORD_TYPE)
+                .unwrap()
+                .decode_tunnel_password(b"downstream", request.get_authenticator())
+                .unwrap()
+                .0,
+            b"tunnel"
+        );
+
+        let mut request = Packet::new(Code::AccessRequest, b"downstream");
+        rfc2865::add_user_name(&mut request, "alice@example.com");
+        rfc2865::add_user_password(&mut request, b"wrong").unwrap();
+        let response = client.send_packet(&proxy_addr, &request).await.unwrap();
+        assert_eq!(response.get_code(), Code::AccessReject);
+        assert!(rfc2865::lookup_all_proxy_state(&response).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reverse_proxy_drops_invalid_upstream_response() {
+        // the upstream server answers the requests of `alice` properly, and the others improperly
+        let upstream = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let upstream_addr = upstream.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = upstream.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"upstream").unwrap();
+                let user_name = rfc2865::lookup_user_name(&request).unwrap().unwrap();
+                let mut response = request.make_response_packet(Code::AccessAccept);
+                if user_name != "stateless" {
+                    for proxy_state in rfc2865::lookup_all_proxy_state(&request) {
+                        rfc2865::add_proxy_state(&mut response, &proxy_state).unwrap();
+                    }
+                }
+                if user_name != "unsigned" && request.lookup(80).is_some() {
+                    response.add(AVP::from_bytes(80, &[0; 16]).unwrap());
+                }
+                let mut encoded = response.encode().unwrap();
+                if user_name == "forged" {
+                    encoded = Packet::decode(&encoded, b"upstream")
+                        .unwrap()
+                        .encode()
+                        .unwrap();
+                }
+                upstream.send_to(&encoded, addr).await.unwrap();
+            }
+        });
+
+        let proxy = ReverseProxy::from_config(&format!(
+            "
+            client 127.0.0.1 downstream
+            pool home upstream {upstream_addr}
+            default home
+            "
+        ))
+        .unwrap();
+        let secret_provider = proxy.get_secret_provider();
+        let mut server = Server::listen("127.0.0.1", 0, proxy, secret_provider)
+            .await
+            .unwrap();
+        let proxy_addr = server.get_listen_address().unwrap();
+        tokio::spawn(async move { server.run(std::future::pending::<()>()).await });
+
+        let client = Client::new(None, Some(Duration::from_millis(500)));
+        let request = |user_name: &str| {
+            let mut request = Packet::new(Code::AccessRequest, b"downstream");
+            rfc2865::add_user_name(&mut request, user_name);
+            request.add(AVP::from_bytes(80, &[0; 16]).unwrap());
+            request
+        };
+
+        let response = client
+            .send_packet(&proxy_addr, &request("alice"))
+            .await
+            .unwrap();
+        assert_eq!(response.get_code(), Code::AccessAccept);
+
+        // the Response Authenticator is not of the request, the Proxy-State is not returned,
+        // and the Message-Authenticator is missing; the proxy drops those
+        for user_name in ["forged", "stateless", "unsigned"] {
+            assert!(client
+                .send_packet(&proxy_addr, &request(user_name))
+                .await
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_make_upstream_request_with_chap_password() {
+        let proxy = ReverseProxy::from_config(
+            "
+            client 127.0.0.1 downstream
+            pool home upstream 127.0.0.1:1812
+            default home
+            ",
+        )
+        .unwrap();
+        let pool = proxy.pools.get("home").unwrap();
+
+        // the CHAP-Challenge is the Request Authenticator of the original request
+        let mut request = Packet::new(Code::AccessRequest, b"downstream");
+        rfc2865::add_user_name(&mut request, "alice");
+        rfc2865::add_chap_password(&mut request, [1; 17]).unwrap();
+        let upstream_request = proxy
+            .make_upstream_request(&request, pool, None, b"state")
+            .unwrap();
+        assert_eq!(
+            rfc2865::lookup_chap_challenge(&upstream_request).unwrap(),
+            request.get_authenticator().to_vec()
+        );
+
+        // the CHAP-Challenge of the original request is kept
+        rfc2865::add_chap_challenge(&mut request, b"challenge").unwrap();
+        let upstream_request = proxy
+            .make_upstream_request(&request, pool, None, b"state")
+            .unwrap();
+        assert_eq!(
+            rfc2865::lookup_all_chap_challenge(&upstream_request),
+            vec![b"challenge".to_vec()]
+        );
+    }
+
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    #[tokio::test]
+    async fn test_accounting_buffer() {
+        // the port of the upstream server that is not up yet
+        let upstream_addr = UdpSocket::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let path = std::env::temp_dir().join(format!("radius-proxy-spool-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let proxy = ReverseProxy::from_config(&format!(
+            "
+            client 127.0.0.1 downstream
+            pool acct upstream {upstream_addr}
+            default acct
+            accounting-buffer acct {}
+            ",
+            path.display()
+        ))
+        .unwrap();
+        let secret_provider = proxy.get_secret_provider();
+        let mut server = Server::listen("127.0.0.1", 0, proxy, secret_provider)
+            .await
+            .unwrap();
+        let proxy_addr = server.get_listen_address().unwrap();
+        tokio::spawn(async move { server.run(std::future::pending::<()>()).await });
+
+        let client = Client::new(None, Some(Duration::from_secs(10)));
+        let accounting_request = |user_name: &str| {
+            let mut packet = Packet::new(Code::AccountingRequest, b"downstream");
+            rfc2866::add_acct_status_type(&mut packet, rfc2866::ACCT_STATUS_TYPE_START);
+            rfc2865::add_user_name(&mut packet, user_name);
+            rfc2865::add_proxy_state(&mut packet, b"downstream-proxy").unwrap();
+            packet
+        };
+
+        // the request is acknowledged by the proxy while the upstream server is down, and spooled
+        let response = client
+            .send_packet(&proxy_addr, &accounting_request("alice"))
+            .await
+            .unwrap();
+        assert_eq!(response.get_code(), Code::AccountingResponse);
+        assert_eq!(
+            rfc2865::lookup_all_proxy_state(&response),
+            vec![b"downstream-proxy".to_vec()]
+        );
+        for _ in 0..100 {
+            if std::fs::metadata(&path).unwrap().len() > 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(std::fs::metadata(&path).unwrap().len() > 0);
+
+        // the spooled request is forwarded before the next one when the upstream server is up
+        let upstream = UdpSocket::bind(upstream_addr).await.unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = upstream.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"upstream").unwrap();
+                tx.send(rfc2865::lookup_user_name(&request).unwrap().unwrap())
+                    .unwrap();
+                let mut response = request.make_response_packet(Code::AccountingResponse);
+                for proxy_state in rfc2865::lookup_all_proxy_state(&request) {
+                    rfc2865::add_proxy_state(&mut response, &proxy_state).unwrap();
+                }
+                upstream
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let response = client
+            .send_packet(&proxy_addr, &accounting_request("bob"))
+            .await
+            .unwrap();
+        assert_eq!(response.get_code(), Code::AccountingResponse);
+        assert_eq!(rx.recv().await.unwrap(), "alice");
+        assert_eq!(rx.recv().await.unwrap(), "bob");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[cfg(all(feature = "rfc2866", feature = "rfc2869", feature = "rfc3162"))]
+    #[tokio::test]
+    async fn test_accounting_buffer_failover() {
+        // the port that is not listened, and the upstream server that responds
+        let unreachable_addr = UdpSocket::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let upstream = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let upstream_addr = upstream.local_addr().unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = upstream.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"upstream").unwrap();
+                tx.send(rfc2865::lookup_user_name(&request).unwrap().unwrap())
+                    .unwrap();
+                let mut response = request.make_response_packet(Code::AccountingResponse);
+                for proxy_state in rfc2865::lookup_all_proxy_state(&request) {
+                    rfc2865::add_proxy_state(&mut response, &proxy_state).unwrap();
+                }
+                upstream
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+        let accounting_request = |user_name: &str, secret: &[u8]| {
+            let mut packet = Packet::new(Code::AccountingRequest, secret);
+            rfc2866::add_acct_status_type(&mut packet, rfc2866::ACCT_STATUS_TYPE_START);
+            rfc2865::add_user_name(&mut packet, user_name);
+            packet
+        };
+
+        // the buffered requests fail over to the upstream server that responds, without being spooled
+        let path = std::env::temp_dir().join(format!(
+            "radius-proxy-spool-failover-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let proxy = ReverseProxy::from_config(&format!(
+            "
+            client 127.0.0.1 downstream
+            pool acct upstream {unreachable_addr} {upstream_addr}
+            default acct
+            accounting-buffer acct {}
+            ",
+            path.display()
+        ))
+        .unwrap();
+        let secret_provider = proxy.get_secret_provider();
+        let mut server = Server::listen("127.0.0.1", 0, proxy, secret_provider)
+            .await
+            .unwrap();
+        let proxy_addr = server.get_listen_address().unwrap();
+        tokio::spawn(async move { server.run(std::future::pending::<()>()).await });
+
+        let client = Client::new(None, Some(Duration::from_secs(10)));
+        for user_name in ["alice", "bob"] {
+            client
+                .send_packet(&proxy_addr, &accounting_request(user_name, b"downstream"))
+                .await
+                .unwrap();
+            assert_eq!(rx.recv().await.unwrap(), user_name);
+        }
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 0);
+        let _ = std::fs::remove_file(&path);
+
+        // the replayer walks the rotation on its clock, and stops when the proxy is dropped
+        let path = std::env::temp_dir().join(format!(
+            "radius-proxy-spool-replayer-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let mut spool = DiskSpool::open(&path, 4096, FsyncPolicy::Always).unwrap();
+        spool
+            .push(&accounting_request("carol", b"upstream"))
+            .unwrap();
+        let clock = MockClock::default();
+        let mut proxy = ReverseProxy::new();
+        proxy.add_pool(
+            "acct",
+            UpstreamPool::new(vec![unreachable_addr, upstream_addr], b"upstream"),
+        );
+        proxy.add_accounting_buffer("acct", spool).unwrap();
+        proxy.set_accounting_replay_interval(Duration::from_secs(30));
+        proxy.set_accounting_clock(Arc::new(clock.clone()));
+        proxy.start_accounting_replayer();
+
+        let wait_for_sleep = || async {
+            while clock.get_pending_sleeps_len() == 0 {
+                tokio::task::yield_now().await;
+            }
+        };
+        wait_for_sleep().await;
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(rx.recv().await.unwrap(), "carol");
+        wait_for_sleep().await;
+
+        drop(proxy);
+        clock.advance(Duration::from_secs(30));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(clock.get_pending_sleeps_len(), 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
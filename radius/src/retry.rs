@@ -0,0 +1,312 @@
+//! Retransmission policies for RADIUS clients.
+
+use std::time::Duration;
+
+use rand::Rng;
+
+/// RetryPolicy decides the timeout of each transmission of a request.
+///
+/// A request is retransmitted as it is (i.e. the same identifier and authenticator) when its timeout is exceeded,
+/// so this is applicable to both of authentication and accounting requests.
+pub trait RetryPolicy: 'static + Sync + Send {
+    /// Returns the timeout to wait for a response of the `attempt`-th (1-origin) transmission,
+    /// or `None` to give up the request.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The number of the transmission that is going to be made.
+    /// * `previous_timeout` - The timeout of the previous transmission; this is `None` for the first transmission.
+    /// * `elapsed` - The elapsed time since the first transmission.
+    fn next_timeout(
+        &self,
+        attempt: usize,
+        previous_timeout: Option<Duration>,
+        elapsed: Duration,
+    ) -> Option<Duration>;
+}
+
+/// The retransmission policy that is recommended by RFC 5080.
+///
+/// The timeout is doubled per transmission with the randomization factor of ±0.1,
+/// and it is capped by `max_timeout`. The default values are the ones of the RFC.
+/// see also: https://tools.ietf.org/html/rfc5080#section-2.2.1
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rfc5080RetryPolicy {
+    /// IRT: the initial retransmission timeout (default: 2 seconds).
+    pub initial_timeout: Duration,
+    /// MRT: the maximum retransmission timeout (default: 16 seconds).
+    pub max_timeout: Duration,
+    /// MRC: the maximum number of the transmissions (default: 5).
+    pub max_attempts: usize,
+    /// MRD: the maximum duration of the whole transmissions (default: 30 seconds).
+    pub max_duration: Duration,
+}
+
+impl Default for Rfc5080RetryPolicy {
+    fn default() -> Self {
+        Rfc5080RetryPolicy {
+            initial_timeout: Duration::from_secs(2),
+            max_timeout: Duration::from_secs(16),
+            max_attempts: 5,
+            max_duration: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy for Rfc5080RetryPolicy {
+    fn next_timeout(
+        &self,
+        attempt: usize,
+        previous_timeout: Option<Duration>,
+        elapsed: Duration,
+    ) -> Option<Duration> {
+        backoff(
+            attempt,
+            previous_timeout,
+            elapsed,
+            self.initial_timeout,
+            2.0,
+            self.max_timeout,
+            self.max_attempts,
+            Some(self.max_duration),
+            0.1,
+        )
+    }
+}
+
+/// The retransmission policy of the exponential backoff with the cap of the timeout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentialBackoffRetryPolicy {
+    /// The timeout of the first transmission.
+    pub initial_timeout: Duration,
+    /// The factor to multiply the previous timeout by; a non-finite value or a value less than `1.0` is treated as `1.0`.
+    pub multiplier: f64,
+    /// The cap of the timeout.
+    pub max_timeout: Duration,
+    /// The maximum number of the transmissions.
+    pub max_attempts: usize,
+    /// The maximum duration of the whole transmissions; `None` means unlimited.
+    pub max_duration: Option<Duration>,
+    /// The randomization factor of the timeout; e.g. `0.1` randomizes the timeout by ±10%.
+    /// A non-finite value is treated as `0.0`, i.e. no randomization.
+    pub jitter: f64,
+}
+
+impl RetryPolicy for ExponentialBackoffRetryPolicy {
+    fn next_timeout(
+        &self,
+        attempt: usize,
+        previous_timeout: Option<Duration>,
+        elapsed: Duration,
+    ) -> Option<Duration> {
+        backoff(
+            attempt,
+            previous_timeout,
+            elapsed,
+            self.initial_timeout,
+            self.multiplier,
+            self.max_timeout,
+            self.max_attempts,
+            self.max_duration,
+            self.jitter,
+        )
+    }
+}
+
+/// The policy that transmits a request only once, which is used by `Client::send_packet()`.
+pub(crate) struct NoRetryPolicy {
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl RetryPolicy for NoRetryPolicy {
+    fn next_timeout(
+        &self,
+        attempt: usize,
+        _previous_timeout: Option<Duration>,
+        _elapsed: Duration,
+    ) -> Option<Duration> {
+        if attempt > 1 {
+            return None;
+        }
+        Some(self.timeout.unwrap_or(Duration::MAX))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn backoff(
+    attempt: usize,
+    previous_timeout: Option<Duration>,
+    elapsed: Duration,
+    initial_timeout: Duration,
+    multiplier: f64,
+    max_timeout: Duration,
+    max_attempts: usize,
+    max_duration: Option<Duration>,
+    jitter: f64,
+) -> Option<Duration> {
+    if attempt > max_attempts {
+        return None;
+    }
+    let remaining = match max_duration {
+        Some(max_duration) if elapsed >= max_duration => return None,
+        Some(max_duration) => max_duration - elapsed,
+        None => Duration::MAX,
+    };
+
+    // the fields of `ExponentialBackoffRetryPolicy` are public, so the invalid values are clamped here
+    let multiplier = if multiplier.is_finite() && multiplier >= 1.0 {
+        multiplier
+    } else {
+        1.0
+    };
+    let jitter = if jitter.is_finite() { jitter } else { 0.0 };
+    // the durations that overflow saturate; those are capped by `max_timeout` and `remaining` anyway
+    let from_secs_f64 = |secs: f64| Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX);
+
+    // RT = base + RAND * basis, as RFC 5080 (and RFC 3315) defines
+    let randomize = |base: Duration, basis: Duration| {
+        if jitter <= 0.0 {
+            return base;
+        }
+        let rand = jitter * rand::thread_rng().gen_range(-1.0..=1.0);
+        from_secs_f64((base.as_secs_f64() + basis.as_secs_f64() * rand).max(0.0))
+    };
+
+    let timeout = match previous_timeout {
+        None => randomize(initial_timeout, initial_timeout),
+        Some(previous_timeout) => {
+            let timeout = randomize(
+                from_secs_f64(previous_timeout.as_secs_f64() * multiplier),
+                previous_timeout,
+            );
+            if timeout > max_timeout {
+                randomize(max_timeout, max_timeout)
+            } else {
+                timeout
+            }
+        }
+    };
+
+    Some(timeout.min(remaining))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::retry::{ExponentialBackoffRetryPolicy, RetryPolicy, Rfc5080RetryPolicy};
+
+    #[test]
+    fn test_rfc5080_retry_policy() {
+        let policy = Rfc5080RetryPolicy::default();
+
+        let first = policy.next_timeout(1, None, Duration::ZERO).unwrap();
+        assert!(first >= Duration::from_millis(1800) && first <= Duration::from_millis(2200));
+
+        let second = policy
+            .next_timeout(2, Some(Duration::from_secs(2)), Duration::from_secs(2))
+            .unwrap();
+        assert!(second >= Duration::from_millis(3800) && second <= Duration::from_millis(4200));
+
+        let capped = policy
+            .next_timeout(4, Some(Duration::from_secs(16)), Duration::from_secs(6))
+            .unwrap();
+        assert!(capped >= Duration::from_millis(14400) && capped <= Duration::from_millis(17600));
+
+        // MRD
+        assert_eq!(
+            policy.next_timeout(5, Some(Duration::from_secs(16)), Duration::from_secs(25)),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            policy.next_timeout(5, Some(Duration::from_secs(16)), Duration::from_secs(30)),
+            None
+        );
+        // MRC
+        assert_eq!(
+            policy.next_timeout(6, Some(Duration::from_secs(1)), Duration::from_secs(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_exponential_backoff_retry_policy() {
+        let policy = ExponentialBackoffRetryPolicy {
+            initial_timeout: Duration::from_secs(1),
+            multiplier: 3.0,
+            max_timeout: Duration::from_secs(5),
+            max_attempts: 3,
+            max_duration: None,
+            jitter: 0.0,
+        };
+
+        assert_eq!(
+            policy.next_timeout(1, None, Duration::ZERO),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.next_timeout(2, Some(Duration::from_secs(1)), Duration::from_secs(1)),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(
+            policy.next_timeout(3, Some(Duration::from_secs(3)), Duration::from_secs(4)),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            policy.next_timeout(4, Some(Duration::from_secs(5)), Duration::from_secs(9)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_exponential_backoff_retry_policy_with_invalid_values() {
+        let policy = |multiplier: f64, jitter: f64| ExponentialBackoffRetryPolicy {
+            initial_timeout: Duration::from_secs(1),
+            multiplier,
+            max_timeout: Duration::from_secs(5),
+            max_attempts: 3,
+            max_duration: None,
+            jitter,
+        };
+
+        // the multipliers that are non-finite or less than 1.0 are treated as 1.0
+        for multiplier in [-2.0, 0.5, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                policy(multiplier, 0.0).next_timeout(
+                    2,
+                    Some(Duration::from_secs(2)),
+                    Duration::from_secs(1)
+                ),
+                Some(Duration::from_secs(2))
+            );
+        }
+        // the overflowing timeout saturates at max_timeout
+        assert_eq!(
+            policy(f64::MAX, 0.0).next_timeout(2, Some(Duration::from_secs(2)), Duration::ZERO),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            policy(2.0, 0.0).next_timeout(2, Some(Duration::MAX), Duration::ZERO),
+            Some(Duration::from_secs(5))
+        );
+        // the non-finite jitters are treated as 0.0
+        for jitter in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                policy(3.0, jitter).next_timeout(1, None, Duration::ZERO),
+                Some(Duration::from_secs(1))
+            );
+            assert_eq!(
+                policy(3.0, jitter).next_timeout(
+                    2,
+                    Some(Duration::from_secs(1)),
+                    Duration::from_secs(1)
+                ),
+                Some(Duration::from_secs(3))
+            );
+        }
+        // a huge jitter saturates instead of panicking
+        assert!(policy(3.0, f64::MAX)
+            .next_timeout(2, Some(Duration::from_secs(1)), Duration::from_secs(1))
+            .is_some());
+    }
+}
@@ -2,29 +2,254 @@
 
 use async_trait::async_trait;
 use std::borrow::Borrow;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use std::future::Future;
 use std::io;
 use std::net::SocketAddr;
-use std::sync::{Arc, RwLock};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 
 use thiserror::Error;
 use tokio::net::UdpSocket;
+use tokio::time::timeout;
 
-use crate::core::packet::Packet;
+use crate::abuse::{AbuseDetector, AbuseKind};
+use crate::audit::{AuditEvent, AuditEventKind, AuditSink};
+use crate::conversation::{ConversationRecorder, Direction};
+use crate::core::code::Code;
+use crate::core::nas_profile::NasProfileRegistry;
+use crate::core::packet::{DuplicatePolicy, Packet};
 use crate::core::request::Request;
+#[cfg(all(feature = "socket-filter", target_os = "linux"))]
+use crate::socket_filter::{self, SocketFilter};
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
 const DEFAULT_BUFFER_SIZE: usize = 1500;
 const DEFAULT_SKIP_AUTHENTICITY_VALIDATION: bool = false;
 
+/// This enum represents the action of the server when a request handler doesn't finish by the deadline.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum HandlerTimeoutAction {
+    /// Sends no response, so that the client retransmits the request or fails over to another server like a lost packet.
+    #[default]
+    NoResponse,
+    /// Sends the failure response to the request; i.e. Access-Reject to Access-Request, Disconnect-NAK to Disconnect-Request
+    /// and CoA-NAK to CoA-Request. The other requests (e.g. Accounting-Request) have no failure response, so nothing is sent to those.
+    FailureResponse,
+}
+
+impl HandlerTimeoutAction {
+    fn failure_response(&self, request: &Packet) -> Option<Packet> {
+        if *self == HandlerTimeoutAction::NoResponse {
+            return None;
+        }
+        let code = match request.get_code() {
+            Code::AccessRequest => Code::AccessReject,
+            Code::DisconnectRequest => Code::DisconnectNAK,
+            Code::CoARequest => Code::CoANAK,
+            _ => return None,
+        };
+        Some(request.make_response_packet(code))
+    }
+}
+
+/// This enum represents the policy of the server when the queue of the requests that wait for a handler is full.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum QueueOverflowPolicy {
+    /// Drops the newly received request.
+    #[default]
+    DropNewest,
+    /// Drops the oldest request in the queue, and enqueues the newly received one;
+    /// the client of the oldest one is likely to have retransmitted that or given up already.
+    DropOldest,
+}
+
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+struct ConcurrencyState {
+    in_flight: usize,
+    queue: VecDeque<Job>,
+    // the Accounting-Requests when the authentication is prioritized
+    low_priority_queue: VecDeque<Job>,
+}
+
+impl ConcurrencyState {
+    fn queued_count(&self) -> usize {
+        self.queue.len() + self.low_priority_queue.len()
+    }
+}
+
+/// The limit of the number of the request handlers that run concurrently, with a bounded queue of the requests that wait for a handler.
+///
+/// The server shares this with the caller (see `Server::get_concurrency_limit()`), so that the saturation can be monitored while the server is running.
+pub struct ConcurrencyLimit {
+    max_concurrent_handlers: usize,
+    queue_capacity: usize,
+    overflow_policy: QueueOverflowPolicy,
+    prioritize_authentication: bool,
+    state: Mutex<ConcurrencyState>,
+    dropped_count: AtomicU64,
+}
+
+impl ConcurrencyLimit {
+    /// A constructor of the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_concurrent_handlers` - The maximum number of the request handlers that run concurrently; this must be positive.
+    /// * `queue_capacity` - The maximum number of the requests that wait for a handler. If this is `0`, the requests beyond the limit are dropped immediately.
+    pub fn new(max_concurrent_handlers: usize, queue_capacity: usize) -> Self {
+        assert!(
+            max_concurrent_handlers > 0,
+            "max_concurrent_handlers must be positive"
+        );
+        ConcurrencyLimit {
+            max_concurrent_handlers,
+            queue_capacity,
+            overflow_policy: QueueOverflowPolicy::default(),
+            prioritize_authentication: false,
+            state: Mutex::new(ConcurrencyState {
+                in_flight: 0,
+                queue: VecDeque::new(),
+                low_priority_queue: VecDeque::new(),
+            }),
+            dropped_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Set a policy for the requests when the queue is full (default: `QueueOverflowPolicy::DropNewest`).
+    pub fn set_overflow_policy(&mut self, overflow_policy: QueueOverflowPolicy) {
+        self.overflow_policy = overflow_policy;
+    }
+
+    /// Set a flag to specify whether to handle the queued requests before the queued Accounting-Requests (default: `false`).
+    ///
+    /// When the queue is full, a queued Accounting-Request is dropped in favor of the other request (e.g. Access-Request),
+    /// since the NAS retransmits that anyway; the `QueueOverflowPolicy` decides which of the Accounting-Requests is dropped.
+    pub fn set_prioritize_authentication(&mut self, prioritize_authentication: bool) {
+        self.prioritize_authentication = prioritize_authentication;
+    }
+
+    pub fn get_max_concurrent_handlers(&self) -> usize {
+        self.max_concurrent_handlers
+    }
+
+    pub fn get_queue_capacity(&self) -> usize {
+        self.queue_capacity
+    }
+
+    pub fn get_overflow_policy(&self) -> QueueOverflowPolicy {
+        self.overflow_policy
+    }
+
+    pub fn is_prioritize_authentication(&self) -> bool {
+        self.prioritize_authentication
+    }
+
+    /// Returns the number of the request handlers that are running.
+    pub fn get_in_flight_count(&self) -> usize {
+        self.state.lock().unwrap().in_flight
+    }
+
+    /// Returns the number of the requests that wait for a handler.
+    pub fn get_queued_count(&self) -> usize {
+        self.state.lock().unwrap().queued_count()
+    }
+
+    /// Returns the number of the requests that have been dropped because the queue was full.
+    pub fn get_dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    /// Returns whether all of the handlers are running, i.e. a new request has to wait in the queue.
+    pub fn is_saturated(&self) -> bool {
+        self.get_in_flight_count() >= self.max_concurrent_handlers
+    }
+
+    fn dispatch(self: &Arc<Self>, job: Job, code: Code) {
+        let mut state = self.state.lock().unwrap();
+        if state.in_flight < self.max_concurrent_handlers {
+            state.in_flight += 1;
+            tokio::spawn(self.clone().run_worker(job));
+            return;
+        }
+
+        let low_priority = self.prioritize_authentication && code == Code::AccountingRequest;
+        if state.queued_count() >= self.queue_capacity {
+            self.dropped_count.fetch_add(1, Ordering::Relaxed);
+            if self.queue_capacity == 0 || (low_priority && state.low_priority_queue.is_empty()) {
+                warn!("the request queue is full; the newest request is dropped");
+                return;
+            }
+            if !low_priority && !state.low_priority_queue.is_empty() {
+                // a queued Accounting-Request gives way to the other request
+                warn!("the request queue is full; a queued Accounting-Request is dropped");
+                match self.overflow_policy {
+                    QueueOverflowPolicy::DropNewest => state.low_priority_queue.pop_back(),
+                    QueueOverflowPolicy::DropOldest => state.low_priority_queue.pop_front(),
+                };
+            } else {
+                if self.overflow_policy == QueueOverflowPolicy::DropNewest {
+                    warn!("the request queue is full; the newest request is dropped");
+                    return;
+                }
+                warn!("the request queue is full; the oldest request is dropped");
+                if low_priority {
+                    state.low_priority_queue.pop_front();
+                } else {
+                    state.queue.pop_front();
+                }
+            }
+        }
+        if low_priority {
+            state.low_priority_queue.push_back(job);
+        } else {
+            state.queue.push_back(job);
+        }
+    }
+
+    async fn run_worker(self: Arc<Self>, mut job: Job) {
+        loop {
+            // the job runs in another task, so that a panic of the handler doesn't leak the slot of the worker
+            if let Err(e) = tokio::spawn(job).await {
+                error!("request handling is aborted; {}", e);
+            }
+            job = {
+                let mut state = self.state.lock().unwrap();
+                match state
+                    .queue
+                    .pop_front()
+                    .or_else(|| state.low_priority_queue.pop_front())
+                {
+                    Some(next) => next,
+                    None => {
+                        state.in_flight -= 1;
+                        return;
+                    }
+                }
+            };
+        }
+    }
+}
+
 /// A basic implementation of the RADIUS server.
 ///
 /// ## Example Usage
 /// - https://github.com/moznion/radius-rs/blob/HEAD/examples/server.rs
 pub struct Server<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> {
     skip_authenticity_validation: bool,
+    duplicate_policy: DuplicatePolicy,
+    audit_sink: Option<Arc<dyn AuditSink>>,
+    abuse_detector: Option<Arc<AbuseDetector>>,
+    conversation_recorder: Option<ConversationRecorder>,
+    nas_profile_registry: Option<Arc<NasProfileRegistry>>,
+    handler_timeout: Option<Duration>,
+    handler_timeout_action: HandlerTimeoutAction,
+    handler_timeout_count: Arc<AtomicU64>,
+    concurrency_limit: Option<Arc<ConcurrencyLimit>>,
     buf_size: usize,
     conn_arc: Arc<UdpSocket>,
     request_handler_arc: Arc<T>,
@@ -70,6 +295,15 @@ impl<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> Server<X, E, T, U>
 
         Ok(Server {
             skip_authenticity_validation: DEFAULT_SKIP_AUTHENTICITY_VALIDATION,
+            duplicate_policy: DuplicatePolicy::default(),
+            audit_sink: None,
+            abuse_detector: None,
+            conversation_recorder: None,
+            nas_profile_registry: None,
+            handler_timeout: None,
+            handler_timeout_action: HandlerTimeoutAction::default(),
+            handler_timeout_count: Arc::new(AtomicU64::new(0)),
+            concurrency_limit: None,
             buf_size: DEFAULT_BUFFER_SIZE,
             conn_arc,
             request_handler_arc,
@@ -107,6 +341,86 @@ impl<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> Server<X, E, T, U>
         self.skip_authenticity_validation = skip_authenticity_validation;
     }
 
+    /// Set a policy for the attributes that appear more than the permitted number of times in a request (default: `DuplicatePolicy::Allow`).
+    pub fn set_duplicate_policy(&mut self, duplicate_policy: DuplicatePolicy) {
+        self.duplicate_policy = duplicate_policy;
+    }
+
+    /// Set a sink of the audit events (default: none).
+    ///
+    /// See also `audit` module for the events that are emitted.
+    pub fn set_audit_sink<S: AuditSink>(&mut self, audit_sink: S) {
+        self.audit_sink = Some(Arc::new(audit_sink));
+    }
+
+    /// Set a detector of the abusive clients (default: none).
+    ///
+    /// The server counts the malformed requests, the requests that have an invalid authenticator and the Access-Rejects
+    /// (see `Request::audit_response()`) per client, and drops the requests from the clients that the detector bans.
+    pub fn set_abuse_detector(&mut self, abuse_detector: AbuseDetector) {
+        self.abuse_detector = Some(Arc::new(abuse_detector));
+    }
+
+    /// Set a recorder of the packets that this server receives and sends (default: none).
+    ///
+    /// All of the received requests are recorded (including the dropped ones), and the responses are recorded
+    /// when the handler sends them through `Request::audit_response()`.
+    pub fn set_conversation_recorder(&mut self, conversation_recorder: ConversationRecorder) {
+        self.conversation_recorder = Some(conversation_recorder);
+    }
+
+    /// Set a registry of the NAS profiles (default: none).
+    ///
+    /// The profile of the NAS that sent a request is looked up for the request (see `Request::get_nas_profile()`),
+    /// so that the handler can adapt the response to that (e.g. `profile.encode(&response)`).
+    pub fn set_nas_profile_registry(&mut self, nas_profile_registry: NasProfileRegistry) {
+        self.nas_profile_registry = Some(Arc::new(nas_profile_registry));
+    }
+
+    /// Set a deadline of the request handler for each request (default: none, i.e. the handler can take any time).
+    ///
+    /// When the handler doesn't finish by the deadline, the handling is cancelled, the `HandlerTimeout` audit event is emitted
+    /// and the server takes the action of `set_handler_timeout_action()`.
+    pub fn set_handler_timeout(&mut self, handler_timeout: Option<Duration>) {
+        self.handler_timeout = handler_timeout;
+    }
+
+    /// Set an action for the requests whose handler doesn't finish by the deadline (default: `HandlerTimeoutAction::NoResponse`).
+    pub fn set_handler_timeout_action(&mut self, handler_timeout_action: HandlerTimeoutAction) {
+        self.handler_timeout_action = handler_timeout_action;
+    }
+
+    /// Returns the number of the requests whose handler didn't finish by the deadline.
+    pub fn get_handler_timeout_count(&self) -> u64 {
+        self.handler_timeout_count.load(Ordering::Relaxed)
+    }
+
+    /// Set a limit of the number of the request handlers that run concurrently (default: none, i.e. unlimited).
+    ///
+    /// The requests beyond the limit wait in the bounded queue of the limit, and those beyond the queue are dropped by its `QueueOverflowPolicy`,
+    /// so that an overload doesn't balloon the memory.
+    pub fn set_concurrency_limit(&mut self, concurrency_limit: ConcurrencyLimit) {
+        self.concurrency_limit = Some(Arc::new(concurrency_limit));
+    }
+
+    /// Returns the limit of the concurrent request handlers to monitor the saturation, e.g. `get_queued_count()`.
+    pub fn get_concurrency_limit(&self) -> Option<Arc<ConcurrencyLimit>> {
+        self.concurrency_limit.clone()
+    }
+
+    /// Attach a filter to the listening socket to drop the obviously invalid datagrams in the kernel (e.g. `SocketFilter::for_authentication()`).
+    #[cfg(all(feature = "socket-filter", target_os = "linux"))]
+    pub fn set_socket_filter(&self, socket_filter: &SocketFilter) -> io::Result<()> {
+        socket_filter.attach(self.conn_arc.as_ref())
+    }
+
+    /// Returns the number of the datagrams that the kernel has dropped for the listening socket, including the ones that
+    /// the socket filter dropped; see `socket_filter::get_dropped_count()`.
+    #[cfg(all(feature = "socket-filter", target_os = "linux"))]
+    pub fn get_socket_dropped_count(&self) -> io::Result<u32> {
+        socket_filter::get_dropped_count(self.conn_arc.as_ref())
+    }
+
     /// Returns the listening address.
     pub fn get_listen_address(&self) -> io::Result<SocketAddr> {
         self.conn_arc.local_addr()
@@ -123,6 +437,8 @@ impl<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> Server<X, E, T, U>
             let (size, remote_addr) = conn.recv_from(&mut buf).await?;
 
             let request_data = buf[..size].to_vec();
+            // the code is to prioritize the request before decoding it; an empty datagram is dropped in the handling anyway
+            let request_code = request_data.first().copied().unwrap_or_default();
 
             let local_addr = match conn.local_addr() {
                 Ok(addr) => addr,
@@ -134,11 +450,27 @@ impl<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> Server<X, E, T, U>
                     continue;
                 }
             };
+            if let Some(conversation_recorder) = &self.conversation_recorder {
+                conversation_recorder.record(
+                    Direction::Incoming,
+                    remote_addr,
+                    local_addr,
+                    &request_data,
+                );
+            }
 
             let undergoing_requests_lock = self.undergoing_requests_lock_arc.clone();
             let skip_authenticity_validation = self.skip_authenticity_validation;
+            let duplicate_policy = self.duplicate_policy;
+            let audit_sink = self.audit_sink.clone();
+            let abuse_detector = self.abuse_detector.clone();
+            let conversation_recorder = self.conversation_recorder.clone();
+            let nas_profile_registry = self.nas_profile_registry.clone();
+            let handler_timeout = self.handler_timeout;
+            let handler_timeout_action = self.handler_timeout_action;
+            let handler_timeout_count = self.handler_timeout_count.clone();
 
-            tokio::spawn(async move {
+            let job = Box::pin(async move {
                 Self::process_request(
                     conn,
                     &request_data,
@@ -148,9 +480,26 @@ impl<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> Server<X, E, T, U>
                     request_handler,
                     secret_provider,
                     skip_authenticity_validation,
+                    duplicate_policy,
+                    audit_sink,
+                    abuse_detector,
+                    conversation_recorder,
+                    nas_profile_registry,
+                    handler_timeout,
+                    handler_timeout_action,
+                    handler_timeout_count,
                 )
                 .await;
             });
+            match &self.concurrency_limit {
+                Some(concurrency_limit) => {
+                    let code = Code::from(request_code);
+                    concurrency_limit.dispatch(job, code)
+                }
+                None => {
+                    tokio::spawn(job);
+                }
+            }
         }
     }
 
@@ -164,7 +513,36 @@ impl<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> Server<X, E, T, U>
         request_handler: Arc<T>,
         secret_provider: Arc<U>,
         skip_authenticity_validation: bool,
+        duplicate_policy: DuplicatePolicy,
+        audit_sink: Option<Arc<dyn AuditSink>>,
+        abuse_detector: Option<Arc<AbuseDetector>>,
+        conversation_recorder: Option<ConversationRecorder>,
+        nas_profile_registry: Option<Arc<NasProfileRegistry>>,
+        handler_timeout: Option<Duration>,
+        handler_timeout_action: HandlerTimeoutAction,
+        handler_timeout_count: Arc<AtomicU64>,
     ) {
+        let emit_audit_event = |kind: AuditEventKind, request: Option<&Packet>, reason: &str| {
+            if let Some(audit_sink) = &audit_sink {
+                let mut event = AuditEvent::new(kind, remote_addr, request);
+                event.set_reason(reason);
+                audit_sink.emit(event);
+            }
+        };
+        let record_abuse = |kind: AbuseKind| {
+            if let Some(abuse_detector) = &abuse_detector {
+                abuse_detector.record(remote_addr.ip(), kind);
+            }
+        };
+
+        if let Some(abuse_detector) = &abuse_detector {
+            if abuse_detector.is_banned(remote_addr.ip()) {
+                debug!("request from the banned client {} is dropped", remote_addr);
+                emit_audit_event(AuditEventKind::RequestDropped, None, "banned client");
+                return;
+            }
+        }
+
         let secret: Vec<u8> = match secret_provider.fetch_secret(remote_addr) {
             Ok(secret) => secret,
             Err(e) => {
@@ -182,21 +560,30 @@ impl<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> Server<X, E, T, U>
 
         if !skip_authenticity_validation && !Packet::is_authentic_request(request_data, &secret) {
             info!("packet validation failed; bad secret");
+            emit_audit_event(
+                AuditEventKind::RequestDropped,
+                None,
+                "invalid request authenticator",
+            );
+            record_abuse(AbuseKind::InvalidAuthenticator);
             return;
         }
 
-        let packet = match Packet::decode(request_data, &secret) {
-            Ok(packet) => packet,
-            Err(e) => {
-                error!(
-                    "failed to parse given request data to pack into the RADIUS packet; {}",
-                    e
-                );
-                debug!("failed request data => {:?}", request_data);
-                // TODO error handler support?
-                return;
-            }
-        };
+        let packet =
+            match Packet::decode_with_duplicate_policy(request_data, &secret, duplicate_policy) {
+                Ok(packet) => packet,
+                Err(e) => {
+                    error!(
+                        "failed to parse given request data to pack into the RADIUS packet; {}",
+                        e
+                    );
+                    debug!("failed request data => {:?}", request_data);
+                    // TODO error handler support?
+                    emit_audit_event(AuditEventKind::RequestDropped, None, &e.to_string());
+                    record_abuse(AbuseKind::MalformedPacket);
+                    return;
+                }
+            };
 
         let key = RequestKey {
             ip: remote_addr.to_string(),
@@ -212,22 +599,88 @@ impl<X, E: Debug, T: RequestHandler<X, E>, U: SecretProvider> Server<X, E, T, U>
             undergoing_requests.insert(key);
         }
 
-        match request_handler
-            .handle_radius_request(
-                conn.borrow(),
-                &Request::new(local_addr, remote_addr, packet),
-            )
-            .await
-        {
-            Ok(_) => {}
-            Err(e) => {
-                println!("{e:?}");
+        if let Some(audit_sink) = &audit_sink {
+            if let Some(event) = AuditEvent::for_accounting_request(remote_addr, &packet) {
+                audit_sink.emit(event);
+            }
+        }
+
+        let nas_profile =
+            nas_profile_registry.and_then(|registry| registry.lookup(remote_addr.ip(), &packet));
+        let mut request = Request::new(local_addr, remote_addr, packet);
+        request.set_nas_profile(nas_profile);
+        request.set_audit_sink(audit_sink.clone());
+        request.set_abuse_detector(abuse_detector.clone());
+        request.set_conversation_recorder(conversation_recorder.clone());
+        let handling = request_handler.handle_radius_request(conn.borrow(), &request);
+        // the handler's result is not `Send`, so only the error message is held across the await of the failure response
+        let result = match handler_timeout {
+            Some(handler_timeout) => timeout(handler_timeout, handling).await,
+            None => Ok(handling.await),
+        }
+        .map(|handled| handled.map(|_| ()).map_err(|e| format!("{e:?}")));
+        match result {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => {
+                error!("request handler for {} failed: {}", remote_addr, e);
+                emit_audit_event(AuditEventKind::HandlerError, Some(request.get_packet()), &e);
+            }
+            Err(_) => {
+                warn!(
+                    "request handler for {} didn't finish in {:?}",
+                    remote_addr,
+                    handler_timeout.unwrap_or_default()
+                );
+                handler_timeout_count.fetch_add(1, Ordering::Relaxed);
+                emit_audit_event(
+                    AuditEventKind::HandlerTimeout,
+                    Some(request.get_packet()),
+                    "handler timeout",
+                );
+                if let Some(response) =
+                    handler_timeout_action.failure_response(request.get_packet())
+                {
+                    Self::send_failure_response(
+                        &conn,
+                        &response,
+                        local_addr,
+                        remote_addr,
+                        conversation_recorder.as_ref(),
+                    )
+                    .await;
+                }
             }
         }
 
         let mut undergoing_requests = undergoing_requests_lock.write().unwrap();
         undergoing_requests.remove(&key_for_remove);
     }
+
+    async fn send_failure_response(
+        conn: &UdpSocket,
+        response: &Packet,
+        local_addr: SocketAddr,
+        remote_addr: SocketAddr,
+        conversation_recorder: Option<&ConversationRecorder>,
+    ) {
+        let encoded = match response.encode() {
+            Ok(encoded) => encoded,
+            Err(e) => {
+                error!("failed to encode the failure response; {}", e);
+                return;
+            }
+        };
+        if let Err(e) = conn.send_to(&encoded, remote_addr).await {
+            error!(
+                "failed to send the failure response to {}; {}",
+                remote_addr, e
+            );
+            return;
+        }
+        if let Some(conversation_recorder) = conversation_recorder {
+            conversation_recorder.record(Direction::Outgoing, local_addr, remote_addr, &encoded);
+        }
+    }
 }
 
 /// RequestHandler is a handler for the received RADIUS request.
@@ -264,3 +717,243 @@ struct RequestKey {
     ip: String,
     identifier: u8,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use tokio::net::UdpSocket;
+    use tokio::sync::{mpsc, Semaphore};
+
+    use crate::audit::{AuditEventKind, ChannelAuditSink};
+    use crate::client::{AuthOutcome, Client};
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::request::Request;
+    use crate::server::{
+        ConcurrencyLimit, HandlerTimeoutAction, QueueOverflowPolicy, RequestHandler,
+        SecretProvider, SecretProviderError, Server,
+    };
+
+    struct StalledRequestHandler {}
+
+    #[async_trait]
+    impl RequestHandler<(), io::Error> for StalledRequestHandler {
+        async fn handle_radius_request(
+            &self,
+            _conn: &UdpSocket,
+            _request: &Request,
+        ) -> Result<(), io::Error> {
+            std::future::pending().await
+        }
+    }
+
+    struct GatedRequestHandler {
+        gate: Arc<Semaphore>,
+    }
+
+    #[async_trait]
+    impl RequestHandler<(), io::Error> for GatedRequestHandler {
+        async fn handle_radius_request(
+            &self,
+            conn: &UdpSocket,
+            request: &Request,
+        ) -> Result<(), io::Error> {
+            self.gate.acquire().await.unwrap().forget();
+            let response = request
+                .get_packet()
+                .make_response_packet(Code::AccessAccept);
+            conn.send_to(&response.encode().unwrap(), request.get_remote_addr())
+                .await?;
+            Ok(())
+        }
+    }
+
+    struct StaticSecretProvider {}
+
+    impl SecretProvider for StaticSecretProvider {
+        fn fetch_secret(&self, _remote_addr: SocketAddr) -> Result<Vec<u8>, SecretProviderError> {
+            Ok(b"secret".to_vec())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handler_timeout() {
+        let mut server = Server::listen(
+            "127.0.0.1",
+            0,
+            StalledRequestHandler {},
+            StaticSecretProvider {},
+        )
+        .await
+        .unwrap();
+        let (sender, mut receiver) = mpsc::channel(10);
+        server.set_audit_sink(ChannelAuditSink::new(sender));
+        server.set_handler_timeout(Some(Duration::from_millis(50)));
+        let server_addr = server.get_listen_address().unwrap();
+
+        let client = Client::new(None, Some(Duration::from_millis(500)));
+        let request = Packet::new(Code::AccessRequest, b"secret");
+        let outcome = tokio::select! {
+            _ = server.run(std::future::pending::<()>()) => unreachable!(),
+            outcome = client.authenticate(&server_addr, &request) => outcome.unwrap(),
+        };
+        assert_eq!(outcome, AuthOutcome::Timeout);
+        assert_eq!(
+            receiver.recv().await.unwrap().get_kind(),
+            AuditEventKind::HandlerTimeout
+        );
+
+        server.set_handler_timeout_action(HandlerTimeoutAction::FailureResponse);
+        let outcome = tokio::select! {
+            _ = server.run(std::future::pending::<()>()) => unreachable!(),
+            outcome = client.authenticate(&server_addr, &request) => outcome.unwrap(),
+        };
+        assert!(matches!(outcome, AuthOutcome::Reject { .. }));
+        assert_eq!(server.get_handler_timeout_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit() {
+        for (overflow_policy, responded_identifiers) in [
+            (QueueOverflowPolicy::DropNewest, vec![1, 2]),
+            (QueueOverflowPolicy::DropOldest, vec![1, 3]),
+        ] {
+            let gate = Arc::new(Semaphore::new(0));
+            let mut server = Server::listen(
+                "127.0.0.1",
+                0,
+                GatedRequestHandler { gate: gate.clone() },
+                StaticSecretProvider {},
+            )
+            .await
+            .unwrap();
+            let mut concurrency_limit = ConcurrencyLimit::new(1, 1);
+            concurrency_limit.set_overflow_policy(overflow_policy);
+            server.set_concurrency_limit(concurrency_limit);
+            let concurrency_limit = server.get_concurrency_limit().unwrap();
+            let server_addr = server.get_listen_address().unwrap();
+            tokio::spawn(async move { server.run(std::future::pending::<()>()).await });
+
+            let conn = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+            for identifier in 1..=3 {
+                let request =
+                    Packet::new_with_identifier(Code::AccessRequest, b"secret", identifier);
+                conn.send_to(&request.encode().unwrap(), server_addr)
+                    .await
+                    .unwrap();
+            }
+            // the first one is handled, the second one waits in the queue, and the third one overflows
+            while concurrency_limit.get_dropped_count() == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            assert!(concurrency_limit.is_saturated());
+            assert_eq!(concurrency_limit.get_in_flight_count(), 1);
+            assert_eq!(concurrency_limit.get_queued_count(), 1);
+
+            gate.add_permits(2);
+            let mut buf = vec![0; 4096];
+            for identifier in responded_identifiers {
+                let (len, _) = conn.recv_from(&mut buf).await.unwrap();
+                assert_eq!(buf[..len][1], identifier);
+            }
+            while concurrency_limit.get_in_flight_count() > 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            assert_eq!(concurrency_limit.get_queued_count(), 0);
+            assert_eq!(concurrency_limit.get_dropped_count(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit_with_prioritized_authentication() {
+        let gate = Arc::new(Semaphore::new(0));
+        let mut server = Server::listen(
+            "127.0.0.1",
+            0,
+            GatedRequestHandler { gate: gate.clone() },
+            StaticSecretProvider {},
+        )
+        .await
+        .unwrap();
+        let mut concurrency_limit = ConcurrencyLimit::new(1, 2);
+        concurrency_limit.set_prioritize_authentication(true);
+        server.set_concurrency_limit(concurrency_limit);
+        let concurrency_limit = server.get_concurrency_limit().unwrap();
+        let server_addr = server.get_listen_address().unwrap();
+        tokio::spawn(async move { server.run(std::future::pending::<()>()).await });
+
+        let conn = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        for (identifier, code) in [
+            (1, Code::AccessRequest),
+            (2, Code::AccountingRequest),
+            (3, Code::AccessRequest),
+            // this takes the place of the queued Accounting-Request
+            (4, Code::AccessRequest),
+            // this is dropped rather than the queued Access-Requests
+            (5, Code::AccountingRequest),
+        ] {
+            let request = Packet::new_with_identifier(code, b"secret", identifier);
+            conn.send_to(&request.encode().unwrap(), server_addr)
+                .await
+                .unwrap();
+        }
+        while concurrency_limit.get_dropped_count() < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(concurrency_limit.get_queued_count(), 2);
+
+        gate.add_permits(3);
+        let mut buf = vec![0; 4096];
+        for identifier in [1, 3, 4] {
+            let (len, _) = conn.recv_from(&mut buf).await.unwrap();
+            assert_eq!(buf[..len][1], identifier);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accounting_request_waits_for_authentication() {
+        let gate = Arc::new(Semaphore::new(0));
+        let mut server = Server::listen(
+            "127.0.0.1",
+            0,
+            GatedRequestHandler { gate: gate.clone() },
+            StaticSecretProvider {},
+        )
+        .await
+        .unwrap();
+        let mut concurrency_limit = ConcurrencyLimit::new(1, 10);
+        concurrency_limit.set_prioritize_authentication(true);
+        server.set_concurrency_limit(concurrency_limit);
+        let concurrency_limit = server.get_concurrency_limit().unwrap();
+        let server_addr = server.get_listen_address().unwrap();
+        tokio::spawn(async move { server.run(std::future::pending::<()>()).await });
+
+        let conn = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        for (identifier, code) in [
+            (1, Code::AccessRequest),
+            (2, Code::AccountingRequest),
+            (3, Code::AccountingRequest),
+            (4, Code::AccessRequest),
+        ] {
+            let request = Packet::new_with_identifier(code, b"secret", identifier);
+            conn.send_to(&request.encode().unwrap(), server_addr)
+                .await
+                .unwrap();
+        }
+        while concurrency_limit.get_queued_count() < 3 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        gate.add_permits(4);
+        let mut buf = vec![0; 4096];
+        for identifier in [1, 4, 2, 3] {
+            let (len, _) = conn.recv_from(&mut buf).await.unwrap();
+            assert_eq!(buf[..len][1], identifier);
+        }
+    }
+}
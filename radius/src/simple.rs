@@ -0,0 +1,197 @@
+//! Convenience functions that cover the typical use cases in one call.
+
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::client::{AuthOutcome, Client, ClientError};
+use crate::core::avp::{AVPError, AVP};
+use crate::core::code::Code;
+use crate::core::packet::Packet;
+use crate::core::reply_message;
+use crate::core::rfc2865;
+use crate::retry::Rfc5080RetryPolicy;
+
+const DEFAULT_CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Error, Debug)]
+pub enum SimpleError {
+    /// This error is raised when the request cannot be built.
+    #[error("failed to build an Access-Request; {0}")]
+    RequestBuildingError(AVPError),
+
+    /// This error is raised when the client fails to get a response.
+    #[error("failed to get a response; {0}")]
+    ClientError(ClientError),
+
+    /// This error is raised when the response is invalid, e.g. its Response Authenticator is not authentic
+    /// or its code is not for an Access-Request.
+    #[error("invalid response; {0}")]
+    InvalidResponseError(String),
+}
+
+/// This enum represents the result of an Access-Request, with the response packet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessResult {
+    Accept(Packet),
+    Reject(Packet),
+    Challenge(Packet),
+}
+
+impl AccessResult {
+    /// Returns whether the access is accepted or not.
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, AccessResult::Accept(_))
+    }
+
+    /// Returns the response packet.
+    pub fn get_packet(&self) -> &Packet {
+        match self {
+            AccessResult::Accept(packet) => packet,
+            AccessResult::Reject(packet) => packet,
+            AccessResult::Challenge(packet) => packet,
+        }
+    }
+
+    /// Returns the `Reply-Message` values of the response.
+    pub fn get_reply_messages(&self) -> Vec<String> {
+        rfc2865::lookup_all_reply_message(self.get_packet()).unwrap_or_default()
+    }
+
+    /// Returns the text of the `Reply-Message` values of the response, which are joined in order.
+    pub fn get_reply_message_text(&self) -> Option<String> {
+        reply_message::lookup_reply_message_text(self.get_packet()).and_then(|text| text.ok())
+    }
+}
+
+/// Authenticate a user by PAP (i.e. `User-Name` and `User-Password`).
+///
+/// This sends an Access-Request to the server with the RFC 5080 retransmission policy and returns the result.
+/// The response whose Response Authenticator is not authentic is an `InvalidResponseError`.
+///
+/// # Arguments
+///
+/// * `server` - The server to send the request to, with the port (e.g. `radius.example.com:1812`).
+/// * `secret` - The shared-secret between the client and the server.
+/// * `user` - The value of `User-Name`.
+/// * `pass` - The value of `User-Password`.
+/// * `extra_attrs` - The attributes to add to the request (e.g. `NAS-Identifier`).
+pub async fn authenticate_pap(
+    server: &str,
+    secret: &[u8],
+    user: &str,
+    pass: &[u8],
+    extra_attrs: Vec<AVP>,
+) -> Result<AccessResult, SimpleError> {
+    let mut request = Packet::new(Code::AccessRequest, secret);
+    rfc2865::add_user_name(&mut request, user);
+    rfc2865::add_user_password(&mut request, pass).map_err(SimpleError::RequestBuildingError)?;
+    request.extend(extra_attrs);
+
+    let client = Client::new(Some(DEFAULT_CONNECTION_TIMEOUT), None);
+    let (request_data, response_data) = client
+        .exchange_with_host_with_retry(server, &request, &Rfc5080RetryPolicy::default())
+        .await
+        .map_err(SimpleError::ClientError)?;
+
+    match AuthOutcome::from_response(&request_data, &response_data, secret) {
+        AuthOutcome::Accept { response } => Ok(AccessResult::Accept(response)),
+        AuthOutcome::Reject { response, .. } => Ok(AccessResult::Reject(response)),
+        AuthOutcome::Challenge { response, .. } => Ok(AccessResult::Challenge(response)),
+        AuthOutcome::Timeout => Err(SimpleError::ClientError(ClientError::SocketTimeoutError())),
+        AuthOutcome::Invalid(reason) => Err(SimpleError::InvalidResponseError(reason)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::UdpSocket;
+
+    use crate::core::avp::AVP;
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2865;
+    use crate::simple::{authenticate_pap, SimpleError};
+
+    #[tokio::test]
+    async fn test_authenticate_pap() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"secret").unwrap();
+                let password = rfc2865::lookup_user_password(&request).unwrap().unwrap();
+                let mut response = if password == b"p@ssw0rd" {
+                    request.make_response_packet(Code::AccessAccept)
+                } else {
+                    request.make_response_packet(Code::AccessReject)
+                };
+                rfc2865::add_reply_message(
+                    &mut response,
+                    rfc2865::lookup_nas_identifier(&request).unwrap().unwrap(),
+                );
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let extra_attrs = vec![AVP::from_string(rfc2865::NAS_IDENTIFIER_TYPE, "nas1")];
+
+        let result = authenticate_pap(
+            &server_addr.to_string(),
+            b"secret",
+            "admin",
+            b"p@ssw0rd",
+            extra_attrs.clone(),
+        )
+        .await
+        .unwrap();
+        assert!(result.is_accepted());
+        assert_eq!(result.get_reply_messages(), vec!["nas1"]);
+        assert_eq!(result.get_reply_message_text(), Some("nas1".to_owned()));
+
+        let result = authenticate_pap(
+            &server_addr.to_string(),
+            b"secret",
+            "admin",
+            b"wrong",
+            extra_attrs,
+        )
+        .await
+        .unwrap();
+        assert!(!result.is_accepted());
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_pap_with_wrong_secret() {
+        // the server signs the responses with the other secret
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let server_addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = vec![0; 4096];
+            loop {
+                let (len, addr) = server.recv_from(&mut buf).await.unwrap();
+                let request = Packet::decode(&buf[..len], b"wrong").unwrap();
+                let response = request.make_response_packet(Code::AccessAccept);
+                server
+                    .send_to(&response.encode().unwrap(), addr)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let result = authenticate_pap(
+            &server_addr.to_string(),
+            b"secret",
+            "admin",
+            b"p@ssw0rd",
+            vec![],
+        )
+        .await;
+        assert!(matches!(result, Err(SimpleError::InvalidResponseError(_))));
+    }
+}
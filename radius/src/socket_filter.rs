@@ -0,0 +1,304 @@
+//! In-kernel filter of the obviously invalid datagrams for the server socket.
+//!
+//! `SocketFilter` compiles a classic BPF program that drops the datagrams whose length is out of the RADIUS packet length
+//! or inconsistent with the `Length` field of the header, and the datagrams whose code is not expected on the port
+//! (e.g. Accounting-Request on the authentication port), so that those don't reach the userspace.
+//! The kernel counts the datagrams that the filter drops in the drops of the socket, together with the ones that overflowed
+//! the receive buffer; `get_dropped_count()` reads that counter.
+//!
+//! This module is available with the `socket-filter` feature, and attaching the filter is available only on Linux.
+
+use crate::core::code::Code;
+use crate::core::packet::MAX_PACKET_LENGTH;
+
+const UDP_HEADER_LENGTH: u32 = 8;
+const RADIUS_PACKET_HEADER_LENGTH: u32 = 20;
+
+// the opcodes of the classic BPF; see `linux/filter.h`
+const BPF_LD_W_LEN: u16 = 0x80;
+const BPF_LD_B_ABS: u16 = 0x30;
+const BPF_LD_H_ABS: u16 = 0x28;
+const BPF_ALU_SUB_K: u16 = 0x14;
+const BPF_MISC_TAX: u16 = 0x07;
+const BPF_JMP_JEQ_K: u16 = 0x15;
+const BPF_JMP_JGT_K: u16 = 0x25;
+const BPF_JMP_JGE_K: u16 = 0x35;
+const BPF_JMP_JGT_X: u16 = 0x2d;
+const BPF_RET_K: u16 = 0x06;
+
+// `libc` doesn't define `SO_MEMINFO`; see `asm-generic/socket.h` and `arch/sparc/include/uapi/asm/socket.h`
+#[cfg(all(target_os = "linux", not(target_arch = "sparc64")))]
+const SO_MEMINFO: libc::c_int = 55;
+#[cfg(all(target_os = "linux", target_arch = "sparc64"))]
+const SO_MEMINFO: libc::c_int = 0x0057;
+// the number of the values of `SO_MEMINFO`; i.e. `SK_MEMINFO_VARS`
+#[cfg(target_os = "linux")]
+const SK_MEMINFO_VARS: usize = 9;
+
+/// This struct represents an instruction of the classic BPF (i.e. `struct sock_filter`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BpfInstruction {
+    pub code: u16,
+    pub jt: u8,
+    pub jf: u8,
+    pub k: u32,
+}
+
+/// The filter of the datagrams for the server socket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SocketFilter {
+    codes: Vec<Code>,
+    max_length: usize,
+}
+
+impl Default for SocketFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SocketFilter {
+    /// A constructor of the filter that accepts the datagrams of any code.
+    pub fn new() -> Self {
+        SocketFilter {
+            codes: vec![],
+            max_length: MAX_PACKET_LENGTH,
+        }
+    }
+
+    /// A constructor of the filter for the authentication port, i.e. Access-Request and Status-Server.
+    pub fn for_authentication() -> Self {
+        let mut filter = Self::new();
+        filter.set_codes(&[Code::AccessRequest, Code::StatusServer]);
+        filter
+    }
+
+    /// A constructor of the filter for the accounting port, i.e. Accounting-Request and Status-Server.
+    pub fn for_accounting() -> Self {
+        let mut filter = Self::new();
+        filter.set_codes(&[Code::AccountingRequest, Code::StatusServer]);
+        filter
+    }
+
+    /// A constructor of the filter for the dynamic authorization port of a NAS, i.e. Disconnect-Request and CoA-Request.
+    pub fn for_dynamic_authorization() -> Self {
+        let mut filter = Self::new();
+        filter.set_codes(&[Code::DisconnectRequest, Code::CoARequest]);
+        filter
+    }
+
+    /// Set the codes of the datagrams to accept; empty means any code.
+    pub fn set_codes(&mut self, codes: &[Code]) {
+        self.codes = vec![];
+        for code in codes {
+            if !self.codes.contains(code) {
+                self.codes.push(*code);
+            }
+        }
+    }
+
+    pub fn get_codes(&self) -> &[Code] {
+        &self.codes
+    }
+
+    /// Set the maximum length of the datagrams to accept (default: `4096`, the maximum length of a RADIUS packet).
+    pub fn set_max_length(&mut self, max_length: usize) {
+        self.max_length = max_length;
+    }
+
+    pub fn get_max_length(&self) -> usize {
+        self.max_length
+    }
+
+    /// Returns the BPF program of the filter.
+    ///
+    /// The program runs on the datagram including the UDP header, as the kernel runs the filter of a UDP socket.
+    pub fn compile(&self) -> Vec<BpfInstruction> {
+        // the code check loads the code, and compares it with each of the codes
+        let code_check_len = if self.codes.is_empty() {
+            0
+        } else {
+            1 + self.codes.len()
+        };
+        let accept = 8 + code_check_len;
+        let drop = accept + 1;
+        let instruction = |code: u16, k: u32| BpfInstruction {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        };
+        let jump = |at: usize, code: u16, k: u32, jt: usize, jf: usize| BpfInstruction {
+            code,
+            jt: (jt - at - 1) as u8,
+            jf: (jf - at - 1) as u8,
+            k,
+        };
+
+        let mut program = vec![
+            instruction(BPF_LD_W_LEN, 0),
+            jump(
+                1,
+                BPF_JMP_JGE_K,
+                UDP_HEADER_LENGTH + RADIUS_PACKET_HEADER_LENGTH,
+                2,
+                drop,
+            ),
+            jump(
+                2,
+                BPF_JMP_JGT_K,
+                UDP_HEADER_LENGTH + self.max_length as u32,
+                drop,
+                3,
+            ),
+            // the Length field must be in the range of the header length and the datagram length
+            instruction(BPF_ALU_SUB_K, UDP_HEADER_LENGTH),
+            instruction(BPF_MISC_TAX, 0),
+            instruction(BPF_LD_H_ABS, UDP_HEADER_LENGTH + 2),
+            jump(6, BPF_JMP_JGT_X, 0, drop, 7),
+            jump(7, BPF_JMP_JGE_K, RADIUS_PACKET_HEADER_LENGTH, 8, drop),
+        ];
+        if !self.codes.is_empty() {
+            program.push(instruction(BPF_LD_B_ABS, UDP_HEADER_LENGTH));
+            for (i, code) in self.codes.iter().enumerate() {
+                let at = program.len();
+                let next = if i == self.codes.len() - 1 {
+                    drop
+                } else {
+                    at + 1
+                };
+                program.push(jump(at, BPF_JMP_JEQ_K, *code as u32, accept, next));
+            }
+        }
+        program.push(instruction(BPF_RET_K, u32::MAX));
+        program.push(instruction(BPF_RET_K, 0));
+        program
+    }
+
+    /// Attach the filter to the socket; this replaces the filter that has been attached to the socket.
+    #[cfg(target_os = "linux")]
+    pub fn attach<S: std::os::fd::AsRawFd>(&self, socket: &S) -> std::io::Result<()> {
+        let mut program: Vec<libc::sock_filter> = self
+            .compile()
+            .iter()
+            .map(|instruction| libc::sock_filter {
+                code: instruction.code,
+                jt: instruction.jt,
+                jf: instruction.jf,
+                k: instruction.k,
+            })
+            .collect();
+        let fprog = libc::sock_fprog {
+            len: program.len() as libc::c_ushort,
+            filter: program.as_mut_ptr(),
+        };
+        // SAFETY: `fprog` points to `program` that lives through the call, and the kernel copies the program
+        let ret = unsafe {
+            libc::setsockopt(
+                socket.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_ATTACH_FILTER,
+                &fprog as *const libc::sock_fprog as *const libc::c_void,
+                std::mem::size_of::<libc::sock_fprog>() as libc::socklen_t,
+            )
+        };
+        if ret != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of the datagrams that the kernel has dropped for the socket; i.e. the datagrams that the filter dropped
+/// and the ones that overflowed the receive buffer (`SK_MEMINFO_DROPS` of `SO_MEMINFO`, Linux 4.12 or later).
+///
+/// The counter is of the socket and wraps around at `u32::MAX`.
+#[cfg(target_os = "linux")]
+pub fn get_dropped_count<S: std::os::fd::AsRawFd>(socket: &S) -> std::io::Result<u32> {
+    let mut meminfo = [0u32; SK_MEMINFO_VARS];
+    let mut len = std::mem::size_of_val(&meminfo) as libc::socklen_t;
+    // SAFETY: `meminfo` has `len` bytes, and the kernel writes up to `len` bytes and updates `len`
+    let ret = unsafe {
+        libc::getsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            SO_MEMINFO,
+            meminfo.as_mut_ptr() as *mut libc::c_void,
+            &mut len,
+        )
+    };
+    if ret != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let drops = libc::SK_MEMINFO_DROPS as usize;
+    if (len as usize) < (drops + 1) * std::mem::size_of::<u32>() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "the kernel doesn't report the drops of the socket",
+        ));
+    }
+    Ok(meminfo[drops])
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::net::UdpSocket;
+    use tokio::time::timeout;
+
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::socket_filter::{get_dropped_count, SocketFilter};
+
+    async fn is_received(server: &UdpSocket, datagram: &[u8]) -> bool {
+        let client = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        client
+            .send_to(datagram, server.local_addr().unwrap())
+            .await
+            .unwrap();
+        let mut buf = vec![0; 8192];
+        timeout(Duration::from_millis(100), server.recv_from(&mut buf))
+            .await
+            .is_ok()
+    }
+
+    #[tokio::test]
+    async fn test_attach() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        SocketFilter::for_authentication().attach(&server).unwrap();
+
+        let access_request = Packet::new(Code::AccessRequest, b"secret")
+            .encode()
+            .unwrap();
+        assert!(is_received(&server, &access_request).await);
+        let status_server = Packet::new(Code::StatusServer, b"secret").encode().unwrap();
+        assert!(is_received(&server, &status_server).await);
+
+        // the code is not for the authentication port
+        let accounting_request = Packet::new(Code::AccountingRequest, b"secret")
+            .encode()
+            .unwrap();
+        assert!(!is_received(&server, &accounting_request).await);
+        // shorter than the header
+        assert!(!is_received(&server, &access_request[..19]).await);
+        // the Length field exceeds the datagram
+        let mut truncated = access_request.clone();
+        truncated[3] += 1;
+        assert!(!is_received(&server, &truncated).await);
+        // the Length field is shorter than the header
+        let mut short_length = access_request.clone();
+        short_length[3] = 19;
+        assert!(!is_received(&server, &short_length).await);
+        // longer than the maximum length
+        let mut oversized = access_request.clone();
+        oversized.resize(4097, 0);
+        assert!(!is_received(&server, &oversized).await);
+        // the kernel counts the dropped datagrams
+        assert_eq!(get_dropped_count(&server).unwrap(), 5);
+
+        // any code is accepted without the codes
+        SocketFilter::new().attach(&server).unwrap();
+        assert!(is_received(&server, &accounting_request).await);
+    }
+}
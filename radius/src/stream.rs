@@ -0,0 +1,238 @@
+//! Decoder of the back-to-back RADIUS packets in a byte stream.
+//!
+//! The packets are delimited by the Length field of their headers, as they are written by the packet logs
+//! or sent over the stream transports (e.g. RADIUS over TCP; RFC 6613).
+
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::core::code::Code;
+use crate::core::packet::{Packet, MAX_PACKET_LENGTH, RADIUS_PACKET_HEADER_LENGTH};
+
+const READ_BUFFER_SIZE: usize = 4096;
+
+#[derive(Error, Debug)]
+pub enum PacketStreamError {
+    /// This error is raised when it failed to read the stream.
+    #[error("failed to read the packet stream; {0}")]
+    ReadingError(String),
+
+    /// This error is raised when the stream ends in the middle of a packet.
+    #[error("the packet stream ends in the middle of a packet; {0} bytes are left")]
+    TruncatedPacketError(usize),
+}
+
+/// `PacketStreamDecoder` reads the back-to-back packets from an `AsyncRead` and decodes them one by one.
+///
+/// When the stream is corrupted (e.g. a broken header, a partially written packet), the decoder skips
+/// the bytes one by one until it finds the next packet; it takes a candidate as the next packet only if
+/// the header is plausible, the packet can be decoded, and it is followed by another plausible header (or the end of the stream).
+/// The number of the skipped bytes is available by `get_skipped_bytes()`.
+pub struct PacketStreamDecoder<R> {
+    reader: R,
+    secret: Vec<u8>,
+    buffer: Vec<u8>,
+    is_eof: bool,
+    is_resyncing: bool,
+    skipped_bytes: usize,
+}
+
+impl<R: AsyncRead + Unpin> PacketStreamDecoder<R> {
+    pub fn new(reader: R, secret: &[u8]) -> Self {
+        PacketStreamDecoder {
+            reader,
+            secret: secret.to_vec(),
+            buffer: Vec::new(),
+            is_eof: false,
+            is_resyncing: false,
+            skipped_bytes: 0,
+        }
+    }
+
+    /// Returns the total number of the bytes that are skipped to resynchronize the corrupted stream.
+    pub fn get_skipped_bytes(&self) -> usize {
+        self.skipped_bytes
+    }
+
+    /// Returns the next packet of the stream, or `None` when the stream ends.
+    ///
+    /// If the stream ends in the middle of a packet, this returns `PacketStreamError::TruncatedPacketError`
+    /// (and `None` on the next call).
+    pub async fn next_packet(&mut self) -> Result<Option<Packet>, PacketStreamError> {
+        loop {
+            self.fill(RADIUS_PACKET_HEADER_LENGTH).await?;
+            if self.buffer.is_empty() {
+                return Ok(None);
+            }
+
+            let len = match plausible_length(&self.buffer) {
+                Some(len) => len,
+                None if self.buffer.len() < RADIUS_PACKET_HEADER_LENGTH => {
+                    return Err(self.truncate());
+                }
+                None => {
+                    self.skip();
+                    continue;
+                }
+            };
+
+            // peek the next header to confirm the boundary of the packet on resynchronizing
+            let required = if self.is_resyncing {
+                len + RADIUS_PACKET_HEADER_LENGTH
+            } else {
+                len
+            };
+            self.fill(required).await?;
+            if self.buffer.len() < len {
+                // the length may be broken (or a bogus header may be found on resynchronizing),
+                // so resynchronize with the rest of the stream
+                if self.buffer.len() <= RADIUS_PACKET_HEADER_LENGTH {
+                    return Err(self.truncate());
+                }
+                self.skip();
+                continue;
+            }
+            if self.is_resyncing
+                && self.buffer.len() > len
+                && plausible_length(&self.buffer[len..]).is_none()
+            {
+                self.skip();
+                continue;
+            }
+
+            match Packet::decode(&self.buffer[..len], &self.secret) {
+                Ok(packet) => {
+                    self.buffer.drain(..len);
+                    self.is_resyncing = false;
+                    return Ok(Some(packet));
+                }
+                Err(_) => self.skip(),
+            }
+        }
+    }
+
+    /// Read the stream until the buffer has the given number of bytes or the stream ends.
+    async fn fill(&mut self, size: usize) -> Result<(), PacketStreamError> {
+        let mut chunk = [0; READ_BUFFER_SIZE];
+        while self.buffer.len() < size && !self.is_eof {
+            match self.reader.read(&mut chunk).await {
+                Ok(0) => self.is_eof = true,
+                Ok(n) => self.buffer.extend_from_slice(&chunk[..n]),
+                Err(e) => return Err(PacketStreamError::ReadingError(e.to_string())),
+            }
+        }
+        Ok(())
+    }
+
+    fn skip(&mut self) {
+        if !self.is_resyncing {
+            warn!("the packet stream is corrupted; resynchronizing");
+        }
+        self.buffer.remove(0);
+        self.skipped_bytes += 1;
+        self.is_resyncing = true;
+    }
+
+    /// Discard the rest of the stream.
+    fn truncate(&mut self) -> PacketStreamError {
+        let left = self.buffer.len();
+        self.buffer.clear();
+        PacketStreamError::TruncatedPacketError(left)
+    }
+}
+
+/// Returns the length of the packet if the (partial) header at the beginning of the bytes looks valid.
+fn plausible_length(bs: &[u8]) -> Option<usize> {
+    if bs.len() < 4 || Code::from(bs[0]) == Code::Invalid {
+        return None;
+    }
+    let len = u16::from_be_bytes([bs[2], bs[3]]) as usize;
+    (RADIUS_PACKET_HEADER_LENGTH..=MAX_PACKET_LENGTH)
+        .contains(&len)
+        .then_some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::code::Code;
+    use crate::core::packet::Packet;
+    use crate::core::rfc2865;
+    use crate::stream::{PacketStreamDecoder, PacketStreamError};
+
+    fn encoded_packet(identifier: u8, user_name: &str) -> Vec<u8> {
+        let mut packet = Packet::new_with_identifier(Code::AccessRequest, b"secret", identifier);
+        rfc2865::add_user_name(&mut packet, user_name);
+        packet.encode().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_back_to_back_packets() {
+        let stream = [
+            encoded_packet(1, "alice"),
+            encoded_packet(2, "bob"),
+            encoded_packet(3, "carol"),
+        ]
+        .concat();
+        let mut decoder = PacketStreamDecoder::new(stream.as_slice(), b"secret");
+
+        for (identifier, user_name) in [(1, "alice"), (2, "bob"), (3, "carol")] {
+            let packet = decoder.next_packet().await.unwrap().unwrap();
+            assert_eq!(packet.get_identifier(), identifier);
+            assert_eq!(
+                rfc2865::lookup_user_name(&packet).unwrap().unwrap(),
+                user_name
+            );
+        }
+        assert!(decoder.next_packet().await.unwrap().is_none());
+        assert_eq!(decoder.get_skipped_bytes(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_resync_on_corruption() {
+        let stream = [
+            encoded_packet(1, "alice"),
+            vec![0xff, 0x00, 0x13],                  // garbage
+            encoded_packet(2, "bob")[..12].to_vec(), // partially written packet
+            encoded_packet(3, "carol"),
+        ]
+        .concat();
+        let mut decoder = PacketStreamDecoder::new(stream.as_slice(), b"secret");
+
+        let packet = decoder.next_packet().await.unwrap().unwrap();
+        assert_eq!(packet.get_identifier(), 1);
+        let packet = decoder.next_packet().await.unwrap().unwrap();
+        assert_eq!(packet.get_identifier(), 3);
+        assert_eq!(
+            rfc2865::lookup_user_name(&packet).unwrap().unwrap(),
+            "carol"
+        );
+        assert!(decoder.next_packet().await.unwrap().is_none());
+        assert_eq!(decoder.get_skipped_bytes(), 3 + 12);
+
+        // a bogus header that claims more bytes than the rest of the stream must be skipped as well
+        let stream = [
+            vec![0xff, 0x01, 0x00, 0x0f, 0xff], // garbage
+            encoded_packet(3, "carol"),
+        ]
+        .concat();
+        let mut decoder = PacketStreamDecoder::new(stream.as_slice(), b"secret");
+        let packet = decoder.next_packet().await.unwrap().unwrap();
+        assert_eq!(packet.get_identifier(), 3);
+        assert!(decoder.next_packet().await.unwrap().is_none());
+        assert_eq!(decoder.get_skipped_bytes(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_truncated_stream() {
+        let packet = encoded_packet(1, "alice");
+        let stream = [packet.clone(), packet[..10].to_vec()].concat();
+        let mut decoder = PacketStreamDecoder::new(stream.as_slice(), b"secret");
+
+        assert!(decoder.next_packet().await.unwrap().is_some());
+        assert!(matches!(
+            decoder.next_packet().await,
+            Err(PacketStreamError::TruncatedPacketError(10))
+        ));
+        assert!(decoder.next_packet().await.unwrap().is_none());
+    }
+}
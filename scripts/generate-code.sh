@@ -4,7 +4,7 @@ set -eu
 
 REPO_ROOT="$(cd ./"$(git rev-parse --show-cdup)" || exit; pwd)"
 DICTS_DIR="${REPO_ROOT}/dicts"
-SRC_DIR="${REPO_ROOT}/radius/src"
+SRC_DIR="${REPO_ROOT}/radius-proto/src"
 
 DICTS=$(ls "$DICTS_DIR")
 DICT_FILES=()
@@ -14,11 +14,11 @@ for DICT in ${DICTS[@]}; do
   DICT_NAME="${DICT##*.}"
   DICT_FILE="${DICTS_DIR}/dictionary.${DICT_NAME}"
   if [ -f "$DICT_FILE" ]; then
-    cat /dev/null > "${SRC_DIR}/core/${DICT_NAME}.rs"
+    cat /dev/null > "${SRC_DIR}/${DICT_NAME}.rs"
     DICT_FILES+=("$DICT_FILE")
   fi
 done
 
 # shellcheck disable=SC2068
-cargo run --bin code-generator -- --out-dir="${SRC_DIR}/core/" ${DICT_FILES[@]}
+cargo run --bin code-generator -- --out-dir="${SRC_DIR}/" --module-prefix=crate --core-path=crate --feature-gates --ungated-modules=rfc2865 ${DICT_FILES[@]}
 